@@ -154,7 +154,7 @@ impl CodePointInversionListBuilder {
     /// ```
     pub fn add_range_u32(&mut self, range: &impl RangeBounds<u32>) {
         let (start, end) = deconstruct_range(range);
-        if start <= end && end <= char::MAX as u32 {
+        if start <= end && end <= char::MAX as u32 + 1 {
             self.add(start, end);
         }
     }
@@ -717,6 +717,10 @@ mod tests {
         builder.add_range_u32(&(0xd800..=0xdfff));
         let expected = vec![0xd800, 0xe000];
         assert_eq!(builder.intervals, expected);
+
+        builder.add_range_u32(&(0x10fff0..=0x10ffff));
+        let expected = vec![0xd800, 0xe000, 0x10fff0, 0x110000];
+        assert_eq!(builder.intervals, expected);
     }
 
     #[test]
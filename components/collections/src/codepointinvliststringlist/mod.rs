@@ -0,0 +1,308 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module provides functionality for querying of sets of Unicode code points and strings.
+//!
+//! It depends on [`CodePointInversionList`] to efficiently represent Unicode code points, while
+//! it also maintains a list of strings in the set.
+//!
+//! It is an implementation of the existing [ICU4C UnicodeSet API](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/classicu_1_1UnicodeSet.html),
+//! including the sets of strings of the [properties of strings] in UTS #51.
+//!
+//! [properties of strings]: https://www.unicode.org/reports/tr51/#Emoji_Sets
+
+use crate::codepointinvlist::{CodePointInversionList, CodePointInversionListBuilder};
+#[cfg(feature = "serde")]
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use displaydoc::Display;
+use yoke::Yokeable;
+use zerofrom::ZeroFrom;
+use zerovec::{VarZeroSlice, VarZeroVec};
+
+/// A data structure providing a concrete implementation of a set of code points and strings,
+/// using an inversion list for the code points and a sorted list of the strings.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collections::codepointinvlist::CodePointInversionList;
+/// use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+/// use zerovec::VarZeroVec;
+///
+/// let cp_inv_list = CodePointInversionList::try_from_inversion_list_slice(&[0x41, 0x5B])
+///     .expect("valid inversion list");
+/// let str_list: VarZeroVec<str> = VarZeroVec::from(&["ch", "ll"]);
+/// let set = CodePointInversionListAndStringList::try_from(cp_inv_list, str_list)
+///     .expect("valid strings");
+///
+/// assert!(set.contains("A"));
+/// assert!(set.contains("ch"));
+/// assert!(!set.contains("c"));
+/// assert!(!set.contains("AB"));
+/// assert_eq!(set.size(), 28);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Yokeable, ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CodePointInversionListAndStringList<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    cp_inv_list: CodePointInversionList<'data>,
+    // Invariants:
+    //   - no string has exactly one code point, which belongs in the inversion list
+    //   - the strings are sorted and unique
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    str_list: VarZeroVec<'data, str>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> serde::Deserialize<'de> for CodePointInversionListAndStringList<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct Parts<'data> {
+            #[serde(borrow)]
+            cp_inv_list: CodePointInversionList<'data>,
+            #[serde(borrow)]
+            str_list: VarZeroVec<'data, str>,
+        }
+
+        let Parts {
+            cp_inv_list,
+            str_list,
+        } = Parts::deserialize(deserializer)?;
+        CodePointInversionListAndStringList::try_from(cp_inv_list, str_list).map_err(|e| {
+            Error::custom(format!(
+                "Cannot deserialize invalid CodePointInversionListAndStringList: {:?}",
+                e
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "databake")]
+impl databake::Bake for CodePointInversionListAndStringList<'_> {
+    fn bake(&self, env: &databake::CrateEnv) -> databake::TokenStream {
+        env.insert("icu_collections");
+        let cp_inv_list = self.cp_inv_list.bake(env);
+        let str_list = self.str_list.bake(env);
+        // Safe because our parts are safe.
+        databake::quote! { unsafe {
+            #[allow(unused_unsafe)]
+            ::icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList::from_parts_unchecked(#cp_inv_list, #str_list)
+        }}
+    }
+}
+
+impl<'data> CodePointInversionListAndStringList<'data> {
+    /// Returns a new [`CodePointInversionListAndStringList`] from the code points and the
+    /// strings of a set.
+    ///
+    /// The strings must be sorted and unique, and none of them can consist of a single code
+    /// point, which belongs in `cp_inv_list` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_collections::codepointinvlist::CodePointInversionList;
+    /// use icu_collections::codepointinvliststringlist::{
+    ///     CodePointInversionListAndStringList, CodePointInversionListAndStringListError,
+    /// };
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let cp_inv_list = CodePointInversionList::try_from_inversion_list_slice(&[0x41, 0x5B])
+    ///     .expect("valid inversion list");
+    ///
+    /// let str_list: VarZeroVec<str> = VarZeroVec::from(&["ll", "ch"]);
+    /// assert!(matches!(
+    ///     CodePointInversionListAndStringList::try_from(cp_inv_list.clone(), str_list),
+    ///     Err(CodePointInversionListAndStringListError::StringListNotSorted(_, _))
+    /// ));
+    ///
+    /// let str_list: VarZeroVec<str> = VarZeroVec::from(&["a"]);
+    /// assert!(matches!(
+    ///     CodePointInversionListAndStringList::try_from(cp_inv_list, str_list),
+    ///     Err(CodePointInversionListAndStringListError::InvalidStringLength(_))
+    /// ));
+    /// ```
+    pub fn try_from(
+        cp_inv_list: CodePointInversionList<'data>,
+        str_list: VarZeroVec<'data, str>,
+    ) -> Result<Self, CodePointInversionListAndStringListError> {
+        let mut previous: Option<&str> = None;
+        for s in str_list.iter() {
+            let mut chars = s.chars();
+            if let (Some(_), None) = (chars.next(), chars.next()) {
+                return Err(
+                    CodePointInversionListAndStringListError::InvalidStringLength(s.to_string()),
+                );
+            }
+            if let Some(previous) = previous {
+                if previous == s {
+                    return Err(
+                        CodePointInversionListAndStringListError::StringListNotUnique(
+                            s.to_string(),
+                        ),
+                    );
+                }
+                if previous > s {
+                    return Err(
+                        CodePointInversionListAndStringListError::StringListNotSorted(
+                            previous.to_string(),
+                            s.to_string(),
+                        ),
+                    );
+                }
+            }
+            previous = Some(s);
+        }
+
+        Ok(CodePointInversionListAndStringList {
+            cp_inv_list,
+            str_list,
+        })
+    }
+
+    #[doc(hidden)] // databake internal
+    pub const unsafe fn from_parts_unchecked(
+        cp_inv_list: CodePointInversionList<'data>,
+        str_list: VarZeroVec<'data, str>,
+    ) -> Self {
+        CodePointInversionListAndStringList {
+            cp_inv_list,
+            str_list,
+        }
+    }
+
+    /// Returns the number of elements in this set: its code points and its strings.
+    pub fn size(&self) -> usize {
+        self.cp_inv_list.size() + self.str_list.len()
+    }
+
+    /// Whether the set contains any strings.
+    pub fn has_strings(&self) -> bool {
+        !self.str_list.is_empty()
+    }
+
+    /// Whether the set contains `s`, either as a string or as a single code point.
+    pub fn contains(&self, s: &str) -> bool {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return self.cp_inv_list.contains(c);
+        }
+        self.str_list.binary_search(s).is_ok()
+    }
+
+    /// Whether the set contains the code point `cp`.
+    pub fn contains32(&self, cp: u32) -> bool {
+        self.cp_inv_list.contains32(cp)
+    }
+
+    /// Whether the set contains the code point `ch`.
+    pub fn contains_char(&self, ch: char) -> bool {
+        self.cp_inv_list.contains(ch)
+    }
+
+    /// Access the underlying [`CodePointInversionList`].
+    pub fn code_points(&self) -> &CodePointInversionList<'data> {
+        &self.cp_inv_list
+    }
+
+    /// Access the strings of the set, in ascending order.
+    pub fn strings(&self) -> &VarZeroSlice<str> {
+        &self.str_list
+    }
+}
+
+impl<'a> FromIterator<&'a str> for CodePointInversionListAndStringList<'_> {
+    /// Collects the code points and the strings of a set, which can be in any order.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut builder = CodePointInversionListBuilder::new();
+        let mut strings = Vec::new();
+        for s in iter {
+            let mut chars = s.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                builder.add_char(c);
+            } else {
+                strings.push(s);
+            }
+        }
+        strings.sort_unstable();
+        strings.dedup();
+
+        CodePointInversionListAndStringList {
+            cp_inv_list: builder.build(),
+            str_list: VarZeroVec::from(&strings),
+        }
+    }
+}
+
+/// Custom Errors for [`CodePointInversionListAndStringList`].
+#[derive(Display, Debug)]
+pub enum CodePointInversionListAndStringListError {
+    /// A string in the string list had an invalid length
+    #[displaydoc("Invalid string length for string: {0}")]
+    InvalidStringLength(String),
+    /// A string in the string list appears more than once
+    #[displaydoc("String list has duplicate: {0}")]
+    StringListNotUnique(String),
+    /// Two strings in the string list are not in sorted order
+    #[displaydoc("Strings in string list not in sorted order: ({0}, {1})")]
+    StringListNotSorted(String, String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodePointInversionListAndStringListError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter() {
+        let set: CodePointInversionListAndStringList =
+            ["ll", "b", "ch", "a", "ll", ""].into_iter().collect();
+
+        assert!(set.contains_char('a'));
+        assert!(set.contains32(0x62));
+        assert!(set.contains("ll"));
+        assert!(set.contains(""));
+        assert!(!set.contains("c"));
+        assert_eq!(set.strings().iter().collect::<Vec<_>>(), ["", "ch", "ll"]);
+        assert_eq!(set.size(), 5);
+        assert!(set.has_strings());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let cp_inv_list = CodePointInversionList::all();
+        let str_list: VarZeroVec<str> = VarZeroVec::from(&["ch", "ch"]);
+        assert!(matches!(
+            CodePointInversionListAndStringList::try_from(cp_inv_list, str_list),
+            Err(CodePointInversionListAndStringListError::StringListNotUnique(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_validation() {
+        let set: CodePointInversionListAndStringList = ["a", "ch"].into_iter().collect();
+        let json = serde_json::to_string(&set).expect("serializable");
+        assert_eq!(json, r#"{"cp_inv_list":[97,98],"str_list":["ch"]}"#);
+
+        let parsed: CodePointInversionListAndStringList =
+            serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(parsed, set);
+
+        let invalid = r#"{"cp_inv_list":[97,98],"str_list":["ll","ch"]}"#;
+        assert!(serde_json::from_str::<CodePointInversionListAndStringList>(invalid).is_err());
+    }
+}
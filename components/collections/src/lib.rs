@@ -14,6 +14,9 @@
 //! ICU4X [`CodePointInversionList`](`crate::codepointinvlist::CodePointInversionList`) provides necessary functionality for highly efficient querying of sets of Unicode characters.
 //! It is an implementation of the existing [ICU4C UnicodeSet API](https://unicode-org.github.io/icu-docs/apidoc/released/icu4c/classicu_1_1UnicodeSet.html).
 //!
+//! ICU4X [`CodePointInversionListAndStringList`](`crate::codepointinvliststringlist::CodePointInversionListAndStringList`) extends it
+//! with a sorted list of strings, for sets that contain strings as well as code points, like the emoji sequence sets of UTS #51.
+//!
 //! ICU4X [`Char16Trie`](`crate::char16trie::Char16Trie`) provides a data structure for a space-efficient and time-efficient lookup of
 //! sequences of 16-bit units (commonly but not necessarily UTF-16 code units)
 //! which map to integer values.
@@ -37,4 +40,5 @@ extern crate alloc;
 
 pub mod char16trie;
 pub mod codepointinvlist;
+pub mod codepointinvliststringlist;
 pub mod codepointtrie;
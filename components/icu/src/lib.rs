@@ -162,3 +162,11 @@ pub use icu_timezone as timezone;
 #[cfg(feature = "experimental")]
 #[doc(inline)]
 pub use icu_displaynames as displaynames;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_spoof as spoof;
+
+#[cfg(feature = "experimental")]
+#[doc(inline)]
+pub use icu_unicodeset_parse as unicodeset_parse;
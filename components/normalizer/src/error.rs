@@ -38,3 +38,51 @@ impl From<PropertiesError> for NormalizerError {
         }
     }
 }
+
+/// 🚧 \[Experimental\] Error from UTS 46 IDNA processing. When more than one
+/// error condition applies to a domain name, the first one encountered is reported.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2614">#2614</a>
+/// </div>
+#[cfg(feature = "experimental")]
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdnaError {
+    /// The input contains a code point that is disallowed by the IDNA Mapping Table
+    /// (taking `UseSTD3ASCIIRules` into account).
+    #[displaydoc("Disallowed code point: U+{0:04X}")]
+    DisallowedCodePoint(u32),
+    /// Punycode decoding or encoding failed.
+    #[displaydoc("Invalid Punycode")]
+    Punycode,
+    /// A label decoded from Punycode is not in the form that results from mapping
+    /// and normalization.
+    #[displaydoc("Label is not normalized")]
+    NotNormalized,
+    /// A label has hyphens in the third and fourth positions, or starts or ends
+    /// with a hyphen (`CheckHyphens`).
+    #[displaydoc("Invalid hyphen placement")]
+    Hyphen,
+    /// A label decoded from Punycode contains U+002E FULL STOP.
+    #[displaydoc("Label contains a full stop")]
+    FullStop,
+    /// A label begins with a combining mark.
+    #[displaydoc("Label begins with a combining mark")]
+    LeadingCombiningMark,
+    /// A zero-width joiner or non-joiner is not in a permitted context (`CheckJoiners`).
+    #[displaydoc("Invalid ContextJ code point context")]
+    ContextJ,
+    /// A label in a bidi domain name does not satisfy the rules of RFC 5893 (`CheckBidi`).
+    #[displaydoc("Bidi rule violation")]
+    Bidi,
+    /// The domain name or one of its labels is empty or too long (`VerifyDnsLength`).
+    #[displaydoc("Invalid DNS length")]
+    DnsLength,
+}
+
+#[cfg(all(feature = "experimental", feature = "std"))]
+impl std::error::Error for IdnaError {}
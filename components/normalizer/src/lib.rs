@@ -70,7 +70,15 @@ extern crate alloc;
 mod error;
 pub mod properties;
 pub mod provider;
+#[cfg(feature = "experimental")]
+pub mod punycode;
+#[cfg(feature = "experimental")]
+pub mod streaming;
+#[cfg(feature = "experimental")]
+pub mod uts46;
 
+#[cfg(feature = "experimental")]
+pub use crate::error::IdnaError;
 pub use crate::error::NormalizerError;
 
 use crate::provider::CanonicalDecompositionDataV1Marker;
@@ -78,6 +86,8 @@ use crate::provider::CompatibilityDecompositionSupplementV1Marker;
 use crate::provider::DecompositionDataV1;
 #[cfg(feature = "experimental")]
 use crate::provider::Uts46DecompositionSupplementV1Marker;
+#[cfg(feature = "experimental")]
+use crate::streaming::StreamingNormalizer;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::char::REPLACEMENT_CHARACTER;
@@ -977,6 +987,8 @@ where
     /// 2. Decomposition starts with a non-starter
     /// 3. Is not a backward-combining starter
     composition_passthrough_bound: u32,
+    /// Whether only contiguous compositions are performed (FCC).
+    only_contiguous: bool,
 }
 
 impl<'data, I> Composition<'data, I>
@@ -987,12 +999,14 @@ where
         decomposition: Decomposition<'data, I>,
         canonical_compositions: Char16Trie<'data>,
         composition_passthrough_bound: u16,
+        only_contiguous: bool,
     ) -> Self {
         Self {
             decomposition,
             canonical_compositions,
             unprocessed_starter: None,
             composition_passthrough_bound: u32::from(composition_passthrough_bound),
+            only_contiguous,
         }
     }
 
@@ -1146,7 +1160,7 @@ where
                         return Some(starter);
                     }
                     debug_assert!(ccc >= most_recent_skipped_ccc);
-                    if ccc != most_recent_skipped_ccc {
+                    if ccc != most_recent_skipped_ccc && !self.only_contiguous {
                         // Using the non-Hangul version as a micro-optimization, since
                         // we already rejected the case where `second` is a starter
                         // above, and conjoining jamo are starters.
@@ -1324,7 +1338,7 @@ macro_rules! composing_normalize_to {
                                 continue 'bufferloop;
                             }
                             debug_assert!(ccc >= most_recent_skipped_ccc);
-                            if ccc != most_recent_skipped_ccc {
+                            if ccc != most_recent_skipped_ccc && !$composition.only_contiguous {
                                 // Using the non-Hangul version as a micro-optimization, since
                                 // we already rejected the case where `second` is a starter
                                 // above, and conjoining jamo are starters.
@@ -1443,6 +1457,22 @@ macro_rules! decomposing_normalize_to {
     };
 }
 
+/// The result of a quick check of whether text is normalized, corresponding
+/// to the values of the `NFC_QC`, `NFD_QC`, `NFKC_QC`, and `NFKD_QC` properties
+/// in Unicode.
+///
+/// See [UAX #15](https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(clippy::exhaustive_enums)] // this type is stable
+pub enum QuickCheckResult {
+    /// The text is normalized.
+    Yes,
+    /// The text is not normalized.
+    No,
+    /// The text may or may not be normalized; a full check is needed.
+    Maybe,
+}
+
 macro_rules! normalizer_methods {
     () => {
         /// Normalize a string slice into a `String`.
@@ -1505,6 +1535,120 @@ macro_rules! normalizer_methods {
             }
             sink.finished()
         }
+
+        /// Quickly check whether a string slice is normalized without
+        /// normalizing it. If the result is [`QuickCheckResult::Maybe`],
+        /// use [`Self::is_normalized`] to get a definitive answer.
+        pub fn quick_check(&self, text: &str) -> QuickCheckResult {
+            self.quick_check_iter(text.chars())
+        }
+
+        /// Quickly check whether a slice of potentially-invalid UTF-16 is
+        /// normalized without normalizing it.
+        ///
+        /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+        pub fn quick_check_utf16(&self, text: &[u16]) -> QuickCheckResult {
+            self.quick_check_iter(text.chars())
+        }
+
+        /// Quickly check whether a slice of potentially-invalid UTF-8 is
+        /// normalized without normalizing it.
+        ///
+        /// Errors are mapped to the REPLACEMENT CHARACTER according
+        /// to the WHATWG Encoding Standard before checking.
+        pub fn quick_check_utf8(&self, text: &[u8]) -> QuickCheckResult {
+            self.quick_check_iter(text.chars())
+        }
+
+        /// Returns the length in bytes of the longest prefix of a string slice
+        /// that is normalized and that stays normalized regardless of what
+        /// follows it.
+        ///
+        /// After an edit, only the text from this index onwards needs to be
+        /// normalized again.
+        pub fn is_normalized_up_to(&self, text: &str) -> usize {
+            let mut sink = IsNormalizedSinkStr::new(text);
+            let _ = self.normalize_to(text, &mut sink);
+            let (normalized, rest) = text.split_at(text.len() - sink.expect.len());
+            self.last_boundary(
+                normalized.chars(),
+                normalized.len(),
+                rest.chars().next(),
+                char::len_utf8,
+            )
+        }
+
+        /// Splits a string slice into a prefix that is normalized and that
+        /// stays normalized regardless of what follows it and the rest
+        /// that still needs to be normalized.
+        ///
+        /// See [`Self::is_normalized_up_to`].
+        pub fn split_normalized<'a>(&self, text: &'a str) -> (&'a str, &'a str) {
+            text.split_at(self.is_normalized_up_to(text))
+        }
+
+        /// Returns the length in code units of the longest prefix of a slice
+        /// of potentially-invalid UTF-16 that is normalized and that stays
+        /// normalized regardless of what follows it.
+        ///
+        /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+        pub fn is_normalized_utf16_up_to(&self, text: &[u16]) -> usize {
+            let mut sink = IsNormalizedSinkUtf16::new(text);
+            let _ = self.normalize_utf16_to(text, &mut sink);
+            let (normalized, rest) = text.split_at(text.len() - sink.expect.len());
+            self.last_boundary(
+                normalized.chars(),
+                normalized.len(),
+                rest.chars().next(),
+                char::len_utf16,
+            )
+        }
+
+        /// Returns the length in bytes of the longest prefix of a slice of
+        /// potentially-invalid UTF-8 that is normalized and that stays
+        /// normalized regardless of what follows it.
+        ///
+        /// Errors are mapped to the REPLACEMENT CHARACTER according
+        /// to the WHATWG Encoding Standard before checking.
+        pub fn is_normalized_utf8_up_to(&self, text: &[u8]) -> usize {
+            let mut sink = IsNormalizedSinkUtf8::new(text);
+            let _ = self.normalize_utf8_to(text, &mut sink);
+            let (normalized, rest) = text.split_at(text.len() - sink.expect.len());
+            self.last_boundary(
+                normalized.chars(),
+                normalized.len(),
+                rest.chars().next(),
+                char::len_utf8,
+            )
+        }
+
+        /// Given the normalized prefix of some text (which is always valid,
+        /// because it equals normalizer output) and the character following it,
+        /// returns the length of the prefix up to the last boundary.
+        ///
+        /// The end of the text is not a boundary, since the text may be
+        /// continued with characters that interact with its last character.
+        fn last_boundary<I: DoubleEndedIterator<Item = char>>(
+            &self,
+            normalized: I,
+            normalized_len: usize,
+            next: Option<char>,
+            char_len: fn(char) -> usize,
+        ) -> usize {
+            if let Some(c) = next {
+                if self.is_boundary_before(c) {
+                    return normalized_len;
+                }
+            }
+            let mut up_to = normalized_len;
+            for c in normalized.rev() {
+                up_to -= char_len(c);
+                if self.is_boundary_before(c) {
+                    return up_to;
+                }
+            }
+            0
+        }
     };
 }
 
@@ -1724,6 +1868,144 @@ impl DecomposingNormalizer {
         )
     }
 
+    /// Creates a normalizer for input that arrives in chunks by using
+    /// the data already held by this normalizer.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn streaming(&self) -> StreamingNormalizer {
+        StreamingNormalizer::new_decomposing(self)
+    }
+
+    /// Whether the decomposition of `c` starts with a starter, in which
+    /// case the text before `c` normalizes independently of `c` and
+    /// the text after it.
+    pub(crate) fn is_boundary_before(&self, c: char) -> bool {
+        if u32::from(c) < u32::from(self.decomposition_passthrough_bound) {
+            return true;
+        }
+        let decomposition = self.normalize_iter(core::iter::empty());
+        !decomposition_starts_with_non_starter(decomposition.attach_trie_value(c).trie_val)
+    }
+
+    /// Check whether a string slice is in the FCD ("fast C or D") form, i.e.
+    /// whether replacing each character with its canonical decomposition yields
+    /// canonically ordered text.
+    ///
+    /// Only the canonical decomposition data is used, so the result does not
+    /// depend on which decomposing normalizer this is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_normalizer::DecomposingNormalizer;
+    ///
+    /// let nfd = DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// // Not NFD, but FCD: the dot below (220) of U+1EA1 precedes the diaeresis (230)
+    /// assert!(nfd.is_fcd("\u{1EA1}\u{0308}"));
+    /// assert!(!nfd.is_normalized("\u{1EA1}\u{0308}"));
+    /// // Not FCD: the diaeresis (230) of U+00E4 precedes the dot below (220)
+    /// assert!(!nfd.is_fcd("\u{00E4}\u{0323}"));
+    /// ```
+    pub fn is_fcd(&self, text: &str) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    /// Check whether a slice of potentially-invalid UTF-8 is in the FCD form.
+    ///
+    /// Errors are mapped to the REPLACEMENT CHARACTER according
+    /// to the WHATWG Encoding Standard before checking.
+    pub fn is_fcd_utf8(&self, text: &[u8]) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    /// Check whether a slice of potentially-invalid UTF-16 is in the FCD form.
+    ///
+    /// Unpaired surrogates are treated as the REPLACEMENT CHARACTER.
+    pub fn is_fcd_utf16(&self, text: &[u16]) -> bool {
+        self.is_fcd_iter(text.chars())
+    }
+
+    fn is_fcd_iter<I: Iterator<Item = char>>(&self, iter: I) -> bool {
+        let mut previous_trailing = CanonicalCombiningClass::NotReordered;
+        for c in iter {
+            let (leading, trailing) = self.fcd_combining_classes(c);
+            if leading != CanonicalCombiningClass::NotReordered && leading < previous_trailing {
+                return false;
+            }
+            previous_trailing = trailing;
+        }
+        true
+    }
+
+    /// The canonical combining classes of the first and the last character
+    /// of the canonical decomposition of `c`.
+    fn fcd_combining_classes(&self, c: char) -> (CanonicalCombiningClass, CanonicalCombiningClass) {
+        let trie = &self.decompositions.get().trie;
+        let trie_value = trie.get(c);
+        if trie_value <= BACKWARD_COMBINING_STARTER_MARKER {
+            // Decomposes to self or is a Hangul syllable, which decomposes to starters.
+            return (
+                CanonicalCombiningClass::NotReordered,
+                CanonicalCombiningClass::NotReordered,
+            );
+        }
+        if trie_value_has_ccc(trie_value) {
+            let ccc = ccc_from_trie_value(trie_value);
+            return (ccc, ccc);
+        }
+        let mut decomposition = Decomposition::new(
+            core::iter::once(c),
+            self.decompositions.get(),
+            self.tables.get(),
+        );
+        let first = decomposition.next().unwrap_or(c);
+        let last = decomposition.last().unwrap_or(first);
+        let ccc = |c| {
+            let trie_value = trie.get(c);
+            if trie_value_has_ccc(trie_value) {
+                ccc_from_trie_value(trie_value)
+            } else {
+                CanonicalCombiningClass::NotReordered
+            }
+        };
+        (ccc(first), ccc(last))
+    }
+
+    fn quick_check_iter<I: Iterator<Item = char>>(&self, iter: I) -> QuickCheckResult {
+        let decomposition = self.normalize_iter(core::iter::empty());
+        let mut previous_ccc = CanonicalCombiningClass::NotReordered;
+        for c in iter {
+            let ccc = if u32::from(c) < u32::from(self.decomposition_passthrough_bound) {
+                CanonicalCombiningClass::NotReordered
+            } else {
+                let c_and_trie_value = decomposition.attach_trie_value(c);
+                if c_and_trie_value.character != c {
+                    return QuickCheckResult::No;
+                }
+                if c_and_trie_value.starter_and_decomposes_to_self() {
+                    CanonicalCombiningClass::NotReordered
+                } else if trie_value_has_ccc(c_and_trie_value.trie_val) {
+                    ccc_from_trie_value(c_and_trie_value.trie_val)
+                } else {
+                    // Does not decompose to self
+                    return QuickCheckResult::No;
+                }
+            };
+            if ccc != CanonicalCombiningClass::NotReordered && ccc < previous_ccc {
+                return QuickCheckResult::No;
+            }
+            previous_ccc = ccc;
+        }
+        QuickCheckResult::Yes
+    }
+
     normalizer_methods!();
 
     decomposing_normalize_to!(
@@ -1988,6 +2270,7 @@ impl DecomposingNormalizer {
 pub struct ComposingNormalizer {
     decomposing_normalizer: DecomposingNormalizer,
     canonical_compositions: DataPayload<CanonicalCompositionsV1Marker>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizer {
@@ -2012,6 +2295,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2026,6 +2310,41 @@ impl ComposingNormalizer {
         ]
     );
 
+    /// FCC constructor.
+    ///
+    /// FCC ("fast C contiguous") is like NFC except that a combining character
+    /// only composes with the preceding starter if no other character intervenes
+    /// after canonical reordering. The output is in the FCD form (see
+    /// [`DecomposingNormalizer::is_fcd`]), which makes it suitable for processes,
+    /// such as collation, that skip normalization of FCD input.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_fcc_unstable<D>(data_provider: &D) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + ?Sized,
+    {
+        let mut normalizer = Self::try_new_nfc_unstable(data_provider)?;
+        normalizer.only_contiguous = true;
+        Ok(normalizer)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: skip,
+        error: NormalizerError,
+        functions: [
+            Self::try_new_fcc_unstable,
+            try_new_fcc_with_any_provider,
+            try_new_fcc_with_buffer_provider
+        ]
+    );
+
     /// NFKC constructor.
     ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
@@ -2049,6 +2368,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2117,6 +2437,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2140,9 +2461,81 @@ impl ComposingNormalizer {
             ),
             ZeroFrom::zero_from(&self.canonical_compositions.get().canonical_compositions),
             self.decomposing_normalizer.composition_passthrough_bound,
+            self.only_contiguous,
         )
     }
 
+    /// Creates a normalizer for input that arrives in chunks by using
+    /// the data already held by this normalizer.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn streaming(&self) -> StreamingNormalizer {
+        StreamingNormalizer::new_composing(self)
+    }
+
+    /// Whether `c` is a starter whose decomposition starts with a starter that
+    /// never combines backwards, in which case the text before `c` normalizes
+    /// independently of `c` and the text after it.
+    pub(crate) fn is_boundary_before(&self, c: char) -> bool {
+        if u32::from(c) < u32::from(self.decomposing_normalizer.composition_passthrough_bound) {
+            return true;
+        }
+        let decomposition = self
+            .decomposing_normalizer
+            .normalize_iter(core::iter::empty());
+        !decomposition.attach_trie_value(c).can_combine_backwards()
+    }
+
+    fn quick_check_iter<I: Iterator<Item = char>>(&self, iter: I) -> QuickCheckResult {
+        let decomposition = self
+            .decomposing_normalizer
+            .normalize_iter(core::iter::empty());
+        let compositions = &self.canonical_compositions.get().canonical_compositions;
+        let mut result = QuickCheckResult::Yes;
+        let mut previous_ccc = CanonicalCombiningClass::NotReordered;
+        for c in iter {
+            if u32::from(c) < u32::from(self.decomposing_normalizer.composition_passthrough_bound) {
+                previous_ccc = CanonicalCombiningClass::NotReordered;
+                continue;
+            }
+            let c_and_trie_value = decomposition.attach_trie_value(c);
+            let decomposes_to_self_with_ccc =
+                c_and_trie_value.character == c && trie_value_has_ccc(c_and_trie_value.trie_val);
+            let ccc = if decomposes_to_self_with_ccc {
+                ccc_from_trie_value(c_and_trie_value.trie_val)
+            } else {
+                CanonicalCombiningClass::NotReordered
+            };
+            if ccc != CanonicalCombiningClass::NotReordered && ccc < previous_ccc {
+                return QuickCheckResult::No;
+            }
+            previous_ccc = ccc;
+            if !decomposes_to_self_with_ccc
+                && !c_and_trie_value.potential_passthrough()
+                && !self
+                    .normalize_iter(core::iter::once(c))
+                    .eq(core::iter::once(c))
+            {
+                // Cannot occur in normalized text
+                return QuickCheckResult::No;
+            }
+            if c_and_trie_value.can_combine_backwards()
+                && (ccc == CanonicalCombiningClass::NotReordered
+                    || compositions.iter().next(c) != TrieResult::NoMatch)
+            {
+                // A starter that combines backwards or a non-starter that
+                // is the second character of some canonical composition
+                result = QuickCheckResult::Maybe;
+            }
+        }
+        result
+    }
+
     normalizer_methods!();
 
     composing_normalize_to!(
@@ -8,6 +8,7 @@
 #![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
 
 use icu_collections::char16trie::Char16Trie;
+use icu_collections::codepointinvlist::CodePointInversionList;
 use icu_collections::codepointtrie::CodePointTrie;
 use icu_provider::{yoke, zerofrom};
 use zerovec::ZeroVec;
@@ -113,3 +114,24 @@ pub struct NonRecursiveDecompositionSupplementV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub scalars24: ZeroVec<'data, char>,
 }
+
+/// The parts of the UTS 46 IDNA Mapping Table that are not captured by
+/// the UTS 46 normalization data: the sets of code points that are
+/// ignored or disallowed.
+#[icu_provider::data_struct(Uts46StatusV1Marker = "normalizer/uts46st@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake), databake(path = icu_normalizer::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct Uts46StatusV1<'data> {
+    /// Code points whose status is `ignored`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ignored: CodePointInversionList<'data>,
+    /// Code points whose status is `disallowed`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub disallowed: CodePointInversionList<'data>,
+    /// Code points whose status is `disallowed_STD3_valid` or
+    /// `disallowed_STD3_mapped`. These are valid or mapped when
+    /// `UseSTD3ASCIIRules` is false and disallowed otherwise.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub disallowed_std3: CodePointInversionList<'data>,
+}
@@ -0,0 +1,193 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Punycode as specified in [RFC 3492](https://www.rfc-editor.org/rfc/rfc3492).
+//!
+//! These functions operate on a single label without the `xn--` ACE prefix.
+//! See [`Uts46Processor`](crate::uts46::Uts46Processor) for the full IDNA
+//! processing.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! <a href="https://github.com/unicode-org/icu4x/issues/2614">#2614</a>
+//! </div>
+
+use crate::error::IdnaError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Bootstring parameters for Punycode (RFC 3492 section 5)
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+/// RFC 3492 section 6.1
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn decode_digit(c: u8) -> Option<u32> {
+    match c {
+        b'0'..=b'9' => Some(u32::from(c - b'0') + 26),
+        b'a'..=b'z' => Some(u32::from(c - b'a')),
+        b'A'..=b'Z' => Some(u32::from(c - b'A')),
+        _ => None,
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    debug_assert!(d < BASE);
+    // 0..=25 are 'a'..='z', 26..=35 are '0'..='9'
+    let byte = if d < 26 {
+        b'a' + d as u8
+    } else {
+        b'0' + (d - 26) as u8
+    };
+    char::from(byte)
+}
+
+/// Decodes a Punycode string into Unicode.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::punycode;
+///
+/// assert_eq!(punycode::decode("fa-hia").as_deref(), Ok("faß"));
+/// assert!(punycode::decode("a-€").is_err());
+/// ```
+pub fn decode(input: &str) -> Result<String, IdnaError> {
+    let (basic, extended) = input.rsplit_once(DELIMITER).unwrap_or(("", input));
+    if !basic.is_ascii() {
+        return Err(IdnaError::Punycode);
+    }
+    let mut output: Vec<char> = basic.chars().collect();
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut bytes = extended.bytes().peekable();
+    while bytes.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let digit = bytes
+                .next()
+                .and_then(decode_digit)
+                .ok_or(IdnaError::Punycode)?;
+            i = digit
+                .checked_mul(weight)
+                .and_then(|product| i.checked_add(product))
+                .ok_or(IdnaError::Punycode)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            weight = weight.checked_mul(BASE - t).ok_or(IdnaError::Punycode)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len).ok_or(IdnaError::Punycode)?;
+        i %= len;
+        let c = char::from_u32(n).ok_or(IdnaError::Punycode)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+/// Encodes a Unicode string as Punycode.
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::punycode;
+///
+/// assert_eq!(punycode::encode("faß").as_deref(), Ok("fa-hia"));
+/// assert_eq!(punycode::encode("ü").as_deref(), Ok("tda"));
+/// ```
+pub fn encode(input: &str) -> Result<String, IdnaError> {
+    let mut output = String::with_capacity(input.len());
+    let mut input_len: u32 = 0;
+    for c in input.chars() {
+        input_len += 1;
+        if c.is_ascii() {
+            output.push(c);
+        }
+    }
+    let basic_len = output.len() as u32;
+    if basic_len > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut processed = basic_len;
+    while processed < input_len {
+        // All remaining code points are at least `n`, so this is always found.
+        let m = input
+            .chars()
+            .map(u32::from)
+            .filter(|&c| c >= n)
+            .min()
+            .ok_or(IdnaError::Punycode)?;
+        delta = (m - n)
+            .checked_mul(processed + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(IdnaError::Punycode)?;
+        n = m;
+        for c in input.chars().map(u32::from) {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(IdnaError::Punycode)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, processed + 1, processed == basic_len);
+                delta = 0;
+                processed += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(output)
+}
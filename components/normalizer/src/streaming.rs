@@ -0,0 +1,440 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Normalization of text that arrives in chunks.
+//!
+//! [`StreamingNormalizer`] accepts input in arbitrary pieces, which may split UTF-8
+//! sequences and combining character sequences, and writes normalized output as soon
+//! as it is known not to be affected by upcoming input. Only the trailing segment
+//! that could still interact with the next chunk is held back.
+//!
+//! With the `std` feature, [`NormalizingReader`] and [`NormalizingWriter`] adapt this
+//! to `std::io`.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_normalizer::ComposingNormalizer;
+//!
+//! let normalizer =
+//!     ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable())
+//!         .expect("Data exists");
+//!
+//! let mut stream = normalizer.streaming();
+//! let mut out = String::new();
+//! // "ä" written as 'a' followed by the UTF-8 bytes of U+0308 split across chunks
+//! stream.push_utf8(b"Ba\xCC", &mut out).expect("String sink");
+//! stream.push_utf8(b"\x88r", &mut out).expect("String sink");
+//! stream.finish(&mut out).expect("String sink");
+//! assert_eq!(out, "Bär");
+//! ```
+
+use crate::ComposingNormalizer;
+use crate::DecomposingNormalizer;
+use alloc::string::String;
+use smallvec::SmallVec;
+use utf8_iter::Utf8CharsEx;
+
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+/// The normalizer that a `StreamingNormalizer` delegates to.
+enum Normalizer<'n> {
+    Decomposing(&'n DecomposingNormalizer),
+    Composing(&'n ComposingNormalizer),
+}
+
+impl Normalizer<'_> {
+    fn is_boundary_before(&self, c: char) -> bool {
+        match self {
+            Normalizer::Decomposing(n) => n.is_boundary_before(c),
+            Normalizer::Composing(n) => n.is_boundary_before(c),
+        }
+    }
+
+    fn normalize_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        match self {
+            Normalizer::Decomposing(n) => n.normalize_to(text, sink),
+            Normalizer::Composing(n) => n.normalize_to(text, sink),
+        }
+    }
+}
+
+/// Returns the length of the trailing bytes of `bytes` that form an incomplete
+/// but so far valid UTF-8 sequence.
+fn incomplete_utf8_suffix_len(bytes: &[u8]) -> usize {
+    for len in (1..=3).rev() {
+        if let Some(suffix) = bytes.len().checked_sub(len).and_then(|i| bytes.get(i..)) {
+            if let Err(e) = core::str::from_utf8(suffix) {
+                if e.valid_up_to() == 0 && e.error_len().is_none() {
+                    return len;
+                }
+            }
+        }
+    }
+    0
+}
+
+/// A normalizer that accepts its input in chunks.
+///
+/// Create one using [`ComposingNormalizer::streaming()`] or
+/// [`DecomposingNormalizer::streaming()`], push input with [`Self::push_str()`] or
+/// [`Self::push_utf8()`], and call [`Self::finish()`] at the end of the input.
+/// The output written to the sinks, concatenated, equals the normalization of the
+/// concatenated input.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+pub struct StreamingNormalizer<'n> {
+    normalizer: Normalizer<'n>,
+    /// Input that has not been normalized yet, because it may interact with
+    /// upcoming input.
+    pending: String,
+    /// The start of an incomplete UTF-8 sequence at the end of the last chunk.
+    incomplete_utf8: SmallVec<[u8; 3]>,
+}
+
+impl<'n> StreamingNormalizer<'n> {
+    pub(crate) fn new_decomposing(normalizer: &'n DecomposingNormalizer) -> Self {
+        Self::new(Normalizer::Decomposing(normalizer))
+    }
+
+    pub(crate) fn new_composing(normalizer: &'n ComposingNormalizer) -> Self {
+        Self::new(Normalizer::Composing(normalizer))
+    }
+
+    fn new(normalizer: Normalizer<'n>) -> Self {
+        Self {
+            normalizer,
+            pending: String::new(),
+            incomplete_utf8: SmallVec::new(),
+        }
+    }
+
+    /// Pushes a chunk of text, writing the normalization of the part of the input
+    /// so far that is no longer affected by upcoming input into `sink`.
+    pub fn push_str<W: core::fmt::Write + ?Sized>(
+        &mut self,
+        chunk: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        if !self.incomplete_utf8.is_empty() {
+            // A previous UTF-8 chunk ended in the middle of a sequence that
+            // the start of this `str` cannot complete.
+            self.pending.push(char::REPLACEMENT_CHARACTER);
+            self.incomplete_utf8.clear();
+        }
+        let scan_start = self.pending.len();
+        self.pending.push_str(chunk);
+        self.flush_stable(scan_start, sink)
+    }
+
+    /// Pushes a chunk of potentially-invalid UTF-8, writing the normalization of
+    /// the part of the input so far that is no longer affected by upcoming input
+    /// into `sink`.
+    ///
+    /// A UTF-8 sequence may be split across chunks. Errors are mapped to the
+    /// REPLACEMENT CHARACTER according to the WHATWG Encoding Standard.
+    pub fn push_utf8<W: core::fmt::Write + ?Sized>(
+        &mut self,
+        chunk: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        let scan_start = self.pending.len();
+        let mut chunk = chunk;
+        if !self.incomplete_utf8.is_empty() {
+            // Decode the sequence left over from the previous chunk together
+            // with enough bytes from this chunk to either complete it or
+            // find it to be an error.
+            let held = self.incomplete_utf8.len();
+            let mut joined: SmallVec<[u8; 6]> = SmallVec::new();
+            joined.extend_from_slice(&self.incomplete_utf8);
+            joined.extend_from_slice(chunk.get(..chunk.len().min(3)).unwrap_or_default());
+            self.incomplete_utf8.clear();
+            let unit_len = match core::str::from_utf8(&joined) {
+                Err(e) if e.valid_up_to() == 0 => match e.error_len() {
+                    Some(len) => len,
+                    None => {
+                        // Still incomplete, so all of `chunk` is in `joined`.
+                        self.incomplete_utf8.extend_from_slice(&joined);
+                        return Ok(());
+                    }
+                },
+                // The first character is valid.
+                _ => joined.chars().next().map_or(0, char::len_utf8),
+            };
+            self.pending
+                .extend(joined.get(..unit_len).unwrap_or_default().chars());
+            // The held bytes are a valid prefix, so the unit extends at least
+            // over all of them.
+            chunk = chunk
+                .get(unit_len.saturating_sub(held)..)
+                .unwrap_or_default();
+        }
+        let held = incomplete_utf8_suffix_len(chunk);
+        let (complete, incomplete) = chunk.split_at(chunk.len() - held);
+        self.pending.extend(complete.chars());
+        self.incomplete_utf8.extend_from_slice(incomplete);
+        self.flush_stable(scan_start, sink)
+    }
+
+    /// Signals the end of the input, writing the normalization of all the input
+    /// that has not been written yet into `sink`.
+    ///
+    /// The streaming normalizer can be reused for new input afterwards.
+    pub fn finish<W: core::fmt::Write + ?Sized>(&mut self, sink: &mut W) -> core::fmt::Result {
+        if !self.incomplete_utf8.is_empty() {
+            self.pending.push(char::REPLACEMENT_CHARACTER);
+            self.incomplete_utf8.clear();
+        }
+        let result = self.normalizer.normalize_to(&self.pending, sink);
+        self.pending.clear();
+        result
+    }
+
+    /// Normalizes `pending` up to its last boundary into `sink`. Boundaries before
+    /// `scan_start` have already been flushed.
+    fn flush_stable<W: core::fmt::Write + ?Sized>(
+        &mut self,
+        scan_start: usize,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        let boundary = self
+            .pending
+            .get(scan_start..)
+            .unwrap_or_default()
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| self.normalizer.is_boundary_before(c))
+            .map_or(0, |(i, _)| scan_start + i);
+        if boundary == 0 {
+            return Ok(());
+        }
+        let result = self
+            .normalizer
+            .normalize_to(self.pending.get(..boundary).unwrap_or_default(), sink);
+        self.pending.replace_range(..boundary, "");
+        result
+    }
+
+    /// Wraps a reader of UTF-8 into a reader of the normalized UTF-8.
+    #[cfg(feature = "std")]
+    pub fn reader<R: std::io::Read>(self, inner: R) -> NormalizingReader<'n, R> {
+        NormalizingReader {
+            inner,
+            stream: self,
+            output: Vec::new(),
+            output_pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Wraps a writer so that UTF-8 written to the returned writer is normalized
+    /// before being written to `inner`.
+    #[cfg(feature = "std")]
+    pub fn writer<W: std::io::Write>(self, inner: W) -> NormalizingWriter<'n, W> {
+        NormalizingWriter {
+            inner,
+            stream: self,
+            output: String::new(),
+            output_pos: 0,
+        }
+    }
+}
+
+/// A reader that normalizes the UTF-8 read from an inner reader.
+///
+/// Invalid UTF-8 is mapped to the REPLACEMENT CHARACTER according to the WHATWG
+/// Encoding Standard.
+///
+/// Created by [`StreamingNormalizer::reader()`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::DecomposingNormalizer;
+/// use std::io::Read;
+///
+/// let normalizer =
+///     DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable())
+///         .expect("Data exists");
+///
+/// let mut reader = normalizer.streaming().reader("Bär".as_bytes());
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).expect("Reading from a slice");
+/// assert_eq!(out, "Ba\u{0308}r");
+/// ```
+#[cfg(feature = "std")]
+pub struct NormalizingReader<'n, R: std::io::Read> {
+    inner: R,
+    stream: StreamingNormalizer<'n>,
+    /// Normalized output that has not been read yet, starting at `output_pos`.
+    output: Vec<u8>,
+    output_pos: usize,
+    eof: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> NormalizingReader<'_, R> {
+    /// Unwraps the inner reader.
+    ///
+    /// Any input read from the inner reader but not yet returned as output is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> std::io::Read for NormalizingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.output_pos == self.output.len() && !self.eof {
+            self.output.clear();
+            self.output_pos = 0;
+            let mut chunk = [0u8; 4096];
+            let read = self.inner.read(&mut chunk)?;
+            let mut output = String::new();
+            // Writing to a `String` cannot fail.
+            let _ = match chunk.get(..read) {
+                Some(bytes) if !bytes.is_empty() => self.stream.push_utf8(bytes, &mut output),
+                _ => {
+                    self.eof = true;
+                    self.stream.finish(&mut output)
+                }
+            };
+            self.output = output.into_bytes();
+        }
+        let available = self.output.get(self.output_pos..).unwrap_or_default();
+        let len = available.len().min(buf.len());
+        if let (Some(dst), Some(src)) = (buf.get_mut(..len), available.get(..len)) {
+            dst.copy_from_slice(src);
+        }
+        self.output_pos += len;
+        Ok(len)
+    }
+}
+
+/// A writer that normalizes UTF-8 before writing it to an inner writer.
+///
+/// Invalid UTF-8 is mapped to the REPLACEMENT CHARACTER according to the WHATWG
+/// Encoding Standard.
+///
+/// The end of the input must be signaled by calling [`Self::finish()`]; otherwise
+/// the trailing segment that could still interact with further input is never
+/// written. [`std::io::Write::flush()`] writes the output that is ready, but not
+/// the segment that is held back.
+///
+/// Output that the inner writer fails to accept is kept and written before any
+/// further input is accepted, so a failed write can be retried without losing or
+/// duplicating text. An error writing output for input that was already accepted
+/// is reported by the next call.
+///
+/// Created by [`StreamingNormalizer::writer()`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_normalizer::ComposingNormalizer;
+/// use std::io::Write;
+///
+/// let normalizer =
+///     ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable())
+///         .expect("Data exists");
+///
+/// let mut writer = normalizer.streaming().writer(Vec::new());
+/// writer.write_all(b"Ba\xCC").expect("Writing to a Vec");
+/// writer.write_all(b"\x88r").expect("Writing to a Vec");
+/// let out = writer.finish().expect("Writing to a Vec");
+/// assert_eq!(out, "Bär".as_bytes());
+/// ```
+#[cfg(feature = "std")]
+pub struct NormalizingWriter<'n, W: std::io::Write> {
+    inner: W,
+    stream: StreamingNormalizer<'n>,
+    /// Normalized output that has not been written yet, starting at `output_pos`.
+    output: String,
+    output_pos: usize,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> NormalizingWriter<'_, W> {
+    /// Writes the normalization of the input that is still held back to the inner
+    /// writer and returns the inner writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.write_output()?;
+        // Writing to a `String` cannot fail.
+        let _ = self.stream.finish(&mut self.output);
+        self.write_output()?;
+        Ok(self.inner)
+    }
+
+    /// Writes the pending output to the inner writer. On error, the output
+    /// that was not written stays pending.
+    fn write_output(&mut self) -> std::io::Result<()> {
+        while let Some(pending) = self
+            .output
+            .as_bytes()
+            .get(self.output_pos..)
+            .filter(|pending| !pending.is_empty())
+        {
+            match self.inner.write(pending) {
+                Ok(0) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::WriteZero,
+                        "failed to write the normalized output",
+                    ))
+                }
+                Ok(written) => self.output_pos += written,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.output.clear();
+        self.output_pos = 0;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for NormalizingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // Do not accept input while earlier output is pending, so that an
+        // error leaves the input to be written again by the caller.
+        self.write_output()?;
+        // Writing to a `String` cannot fail.
+        let _ = self.stream.push_utf8(buf, &mut self.output);
+        // The input has been accepted, so an error is reported by the next call.
+        let _ = self.write_output();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.write_output()?;
+        self.inner.flush()
+    }
+}
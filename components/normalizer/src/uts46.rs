@@ -0,0 +1,503 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] IDNA processing as specified in
+//! [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+//!
+//! [`Uts46Processor`] implements the ToASCII and ToUnicode operations on top of the UTS 46
+//! normalization provided by [`ComposingNormalizer`] and the Punycode implementation in
+//! [`punycode`](crate::punycode).
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! <a href="https://github.com/unicode-org/icu4x/issues/2614">#2614</a>
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_normalizer::uts46::Uts46Processor;
+//!
+//! let uts46 =
+//!     Uts46Processor::try_new_unstable(&icu_testdata::unstable(), Default::default())
+//!         .expect("Data exists");
+//!
+//! assert_eq!(
+//!     uts46.to_ascii("Bücher.example").as_deref(),
+//!     Ok("xn--bcher-kva.example")
+//! );
+//!
+//! let (unicode, result) = uts46.to_unicode("xn--bcher-kva.example");
+//! assert_eq!(unicode, "bücher.example");
+//! assert!(result.is_ok());
+//! ```
+
+use crate::error::IdnaError;
+use crate::provider::CanonicalCompositionsV1Marker;
+use crate::provider::CanonicalDecompositionDataV1Marker;
+use crate::provider::CanonicalDecompositionTablesV1Marker;
+use crate::provider::CompatibilityDecompositionTablesV1Marker;
+use crate::provider::Uts46DecompositionSupplementV1Marker;
+use crate::provider::Uts46StatusV1Marker;
+use crate::punycode;
+use crate::ComposingNormalizer;
+use crate::NormalizerError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::char::REPLACEMENT_CHARACTER;
+use icu_properties::maps::CodePointMapData;
+use icu_properties::provider::BidiClassV1Marker;
+use icu_properties::provider::CanonicalCombiningClassV1Marker;
+use icu_properties::provider::GeneralCategoryV1Marker;
+use icu_properties::provider::JoiningTypeV1Marker;
+use icu_properties::BidiClass;
+use icu_properties::CanonicalCombiningClass;
+use icu_properties::GeneralCategory;
+use icu_properties::GeneralCategoryGroup;
+use icu_properties::JoiningType;
+use icu_provider::prelude::*;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// The maximum length of a domain name in octets, excluding the root label.
+const MAX_DOMAIN_LENGTH: usize = 253;
+/// The maximum length of a label in octets.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// 🚧 \[Experimental\] Options for [`Uts46Processor`], corresponding to the
+/// input flags of the UTS 46 processing steps.
+///
+/// The default options match the ones used by the WHATWG URL Standard: nontransitional
+/// processing with `CheckBidi` and `CheckJoiners` enabled and all other flags disabled.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2614">#2614</a>
+/// </div>
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub struct Uts46Options {
+    /// `Transitional_Processing`: whether the deviation characters (ß, ς, ZWJ and ZWNJ)
+    /// are mapped as in IDNA2003 instead of being kept.
+    pub transitional_processing: bool,
+    /// `UseSTD3ASCIIRules`: whether characters with the status `disallowed_STD3_valid`
+    /// and `disallowed_STD3_mapped` are treated as disallowed.
+    pub use_std3_ascii_rules: bool,
+    /// `CheckHyphens`: whether labels may not start or end with a hyphen or have
+    /// hyphens in both the third and the fourth position.
+    pub check_hyphens: bool,
+    /// `CheckBidi`: whether the Bidi Rule of RFC 5893 is enforced.
+    pub check_bidi: bool,
+    /// `CheckJoiners`: whether the ContextJ rules of RFC 5892 Appendix A are enforced.
+    pub check_joiners: bool,
+    /// `VerifyDnsLength`: whether ToASCII checks the length of the domain name and
+    /// of its labels.
+    pub verify_dns_length: bool,
+}
+
+impl Default for Uts46Options {
+    fn default() -> Self {
+        Self {
+            transitional_processing: false,
+            use_std3_ascii_rules: false,
+            check_hyphens: false,
+            check_bidi: true,
+            check_joiners: true,
+            verify_dns_length: false,
+        }
+    }
+}
+
+/// 🚧 \[Experimental\] Performs the UTS 46 ToASCII and ToUnicode operations on domain names.
+///
+/// See the [module-level documentation](crate::uts46) for an example.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2614">#2614</a>
+/// </div>
+pub struct Uts46Processor {
+    normalizer: ComposingNormalizer,
+    status: DataPayload<Uts46StatusV1Marker>,
+    bidi_class: CodePointMapData<BidiClass>,
+    joining_type: CodePointMapData<JoiningType>,
+    general_category: CodePointMapData<GeneralCategory>,
+    canonical_combining_class: CodePointMapData<CanonicalCombiningClass>,
+    options: Uts46Options,
+}
+
+impl Uts46Processor {
+    /// Creates a new [`Uts46Processor`] with the given options.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        options: Uts46Options,
+    ) -> Result<Self, NormalizerError>
+    where
+        D: DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<Uts46DecompositionSupplementV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<CompatibilityDecompositionTablesV1Marker>
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + DataProvider<Uts46StatusV1Marker>
+            + DataProvider<BidiClassV1Marker>
+            + DataProvider<JoiningTypeV1Marker>
+            + DataProvider<GeneralCategoryV1Marker>
+            + DataProvider<CanonicalCombiningClassV1Marker>
+            + ?Sized,
+    {
+        let normalizer =
+            ComposingNormalizer::try_new_uts46_without_ignored_and_disallowed_unstable(
+                data_provider,
+            )?;
+        let status: DataPayload<Uts46StatusV1Marker> =
+            data_provider.load(Default::default())?.take_payload()?;
+
+        Ok(Self {
+            normalizer,
+            status,
+            bidi_class: icu_properties::maps::load_bidi_class(data_provider)?,
+            joining_type: icu_properties::maps::load_joining_type(data_provider)?,
+            general_category: icu_properties::maps::load_general_category(data_provider)?,
+            canonical_combining_class: icu_properties::maps::load_canonical_combining_class(
+                data_provider,
+            )?,
+            options,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        options: Uts46Options,
+        error: NormalizerError
+    );
+
+    /// Performs the UTS 46 ToASCII operation.
+    ///
+    /// Returns the ASCII form of the domain name, or the first error encountered.
+    pub fn to_ascii(&self, domain: &str) -> Result<String, IdnaError> {
+        let (unicode, result) = self.process(domain);
+        result?;
+
+        let mut ascii = String::with_capacity(unicode.len());
+        for (i, label) in unicode.split('.').enumerate() {
+            if i != 0 {
+                ascii.push('.');
+            }
+            if label.is_ascii() {
+                ascii.push_str(label);
+            } else {
+                ascii.push_str("xn--");
+                ascii.push_str(&punycode::encode(label)?);
+            }
+        }
+
+        if self.options.verify_dns_length {
+            // The root label and its dot are not counted.
+            let domain = ascii.strip_suffix('.').unwrap_or(&ascii);
+            if domain.is_empty()
+                || domain.len() > MAX_DOMAIN_LENGTH
+                || domain
+                    .split('.')
+                    .any(|label| label.is_empty() || label.len() > MAX_LABEL_LENGTH)
+            {
+                return Err(IdnaError::DnsLength);
+            }
+        }
+
+        Ok(ascii)
+    }
+
+    /// Performs the UTS 46 ToUnicode operation.
+    ///
+    /// The converted domain name is returned even if an error is encountered, in which
+    /// case disallowed code points other than ASCII are replaced with U+FFFD and labels that
+    /// fail to decode as Punycode are left as-is.
+    pub fn to_unicode(&self, domain: &str) -> (String, Result<(), IdnaError>) {
+        self.process(domain)
+    }
+
+    /// The Processing steps of UTS 46 section 4: map, normalize, break into labels,
+    /// convert and validate.
+    fn process(&self, domain: &str) -> (String, Result<(), IdnaError>) {
+        let mut errors = Errors(None);
+
+        // Step 1: Map. Only the ignored, disallowed, and deviation parts are done here;
+        // the rest of the mapping is part of the UTS 46 normalization.
+        let status = self.status.get();
+        let mut mapped = String::with_capacity(domain.len());
+        for c in domain.chars() {
+            if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.' {
+                // Fast path: always valid
+                mapped.push(c);
+            } else if status.ignored.contains(c) {
+                continue;
+            } else if status.disallowed.contains(c)
+                || (self.options.use_std3_ascii_rules && status.disallowed_std3.contains(c))
+            {
+                errors.record(IdnaError::DisallowedCodePoint(c as u32));
+                // ASCII characters are left in place, as UTS 46 does with all disallowed
+                // code points, since they can compose with a following combining mark,
+                // like `>` and U+0338 to `≯`.
+                mapped.push(if c.is_ascii() {
+                    c
+                } else {
+                    REPLACEMENT_CHARACTER
+                });
+            } else if self.options.transitional_processing {
+                match c {
+                    'ß' => mapped.push_str("ss"),
+                    'ς' => mapped.push('σ'),
+                    ZWNJ | ZWJ => continue,
+                    _ => mapped.push(c),
+                }
+            } else {
+                mapped.push(c);
+            }
+        }
+
+        // Step 2: Normalize.
+        let normalized = self.normalizer.normalize(&mapped);
+
+        // Steps 3 and 4: Break and Convert/Validate.
+        let mut output = String::with_capacity(normalized.len());
+        for (i, label) in normalized.split('.').enumerate() {
+            if i != 0 {
+                output.push('.');
+            }
+            if let Some(encoded) = label.strip_prefix("xn--") {
+                match punycode::decode(encoded) {
+                    Ok(decoded) => {
+                        // Punycode-encoded labels are always validated with the
+                        // nontransitional criteria.
+                        self.validate_label(&decoded, true, &mut errors);
+                        output.push_str(&decoded);
+                    }
+                    Err(e) => {
+                        errors.record(e);
+                        output.push_str(label);
+                    }
+                }
+            } else {
+                self.validate_label(label, false, &mut errors);
+                output.push_str(label);
+            }
+        }
+
+        // Validity criterion 8 concerns the domain name as a whole.
+        if self.options.check_bidi && self.is_bidi_domain_name(&output) {
+            for label in output.split('.') {
+                if !self.passes_bidi_rule(label) {
+                    errors.record(IdnaError::Bidi);
+                    break;
+                }
+            }
+        }
+
+        (output, errors.into_result())
+    }
+
+    /// The validity criteria of UTS 46 section 4.1, except for the Bidi criterion.
+    ///
+    /// `from_punycode` indicates that the label was decoded from Punycode and therefore
+    /// has not gone through the mapping and normalization steps.
+    fn validate_label(&self, label: &str, from_punycode: bool, errors: &mut Errors) {
+        let chars: Vec<char> = label.chars().collect();
+        let first = match chars.first() {
+            Some(&c) => c,
+            None => return,
+        };
+
+        // 1. The label must be in NFC, and 6. each code point must have the status valid
+        // (or deviation for nontransitional processing). Labels that went through the
+        // Map and Normalize steps satisfy this by construction; disallowed characters have
+        // already been reported.
+        if from_punycode {
+            let status = self.status.get();
+            for &c in chars.iter() {
+                if status.ignored.contains(c)
+                    || status.disallowed.contains(c)
+                    || (self.options.use_std3_ascii_rules && status.disallowed_std3.contains(c))
+                {
+                    errors.record(IdnaError::DisallowedCodePoint(c as u32));
+                }
+            }
+            if !self.normalizer.is_normalized(label) {
+                errors.record(IdnaError::NotNormalized);
+            }
+        }
+
+        // 2. and 3.
+        if self.options.check_hyphens
+            && ((chars.get(2) == Some(&'-') && chars.get(3) == Some(&'-'))
+                || first == '-'
+                || chars.last() == Some(&'-'))
+        {
+            errors.record(IdnaError::Hyphen);
+        }
+
+        // 4.
+        if chars.contains(&'.') {
+            errors.record(IdnaError::FullStop);
+        }
+
+        // 5.
+        if GeneralCategoryGroup::Mark.contains(self.general_category.as_borrowed().get(first)) {
+            errors.record(IdnaError::LeadingCombiningMark);
+        }
+
+        // 7.
+        if self.options.check_joiners && !self.passes_context_j(&chars) {
+            errors.record(IdnaError::ContextJ);
+        }
+    }
+
+    /// The ContextJ rules of RFC 5892 Appendix A.1 and A.2.
+    fn passes_context_j(&self, chars: &[char]) -> bool {
+        let ccc = self.canonical_combining_class.as_borrowed();
+        let jt = self.joining_type.as_borrowed();
+        for (i, &c) in chars.iter().enumerate() {
+            if c != ZWNJ && c != ZWJ {
+                continue;
+            }
+            let (before, after) = chars.split_at(i);
+            if before.last().map(|&b| ccc.get(b)) == Some(CanonicalCombiningClass::Virama) {
+                continue;
+            }
+            if c == ZWJ {
+                return false;
+            }
+            // (Joining_Type:{L,D})(Joining_Type:T)*‌(Joining_Type:T)*(Joining_Type:{R,D})
+            let left = before
+                .iter()
+                .rev()
+                .map(|&b| jt.get(b))
+                .find(|&t| t != JoiningType::Transparent);
+            let right = after
+                .iter()
+                .skip(1)
+                .map(|&a| jt.get(a))
+                .find(|&t| t != JoiningType::Transparent);
+            if !matches!(
+                left,
+                Some(JoiningType::LeftJoining) | Some(JoiningType::DualJoining)
+            ) || !matches!(
+                right,
+                Some(JoiningType::RightJoining) | Some(JoiningType::DualJoining)
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// A Bidi domain name contains at least one character of class R, AL, or AN
+    /// (RFC 5893 section 1.4).
+    fn is_bidi_domain_name(&self, domain: &str) -> bool {
+        let bc = self.bidi_class.as_borrowed();
+        domain.chars().any(|c| {
+            matches!(
+                bc.get(c),
+                BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+            )
+        })
+    }
+
+    /// The Bidi Rule of RFC 5893 section 2.
+    fn passes_bidi_rule(&self, label: &str) -> bool {
+        let bc = self.bidi_class.as_borrowed();
+        let mut classes = label.chars().map(|c| bc.get(c));
+        let first = match classes.next() {
+            Some(first) => first,
+            None => return true,
+        };
+        let last = label
+            .chars()
+            .rev()
+            .map(|c| bc.get(c))
+            .find(|&class| class != BidiClass::NonspacingMark);
+
+        match first {
+            BidiClass::RightToLeft | BidiClass::ArabicLetter => {
+                let mut has_en = false;
+                let mut has_an = false;
+                for class in classes {
+                    match class {
+                        BidiClass::EuropeanNumber => has_en = true,
+                        BidiClass::ArabicNumber => has_an = true,
+                        // 2.
+                        BidiClass::RightToLeft
+                        | BidiClass::ArabicLetter
+                        | BidiClass::EuropeanSeparator
+                        | BidiClass::CommonSeparator
+                        | BidiClass::EuropeanTerminator
+                        | BidiClass::OtherNeutral
+                        | BidiClass::BoundaryNeutral
+                        | BidiClass::NonspacingMark => {}
+                        _ => return false,
+                    }
+                }
+                // 3. and 4.
+                matches!(
+                    last,
+                    Some(BidiClass::RightToLeft)
+                        | Some(BidiClass::ArabicLetter)
+                        | Some(BidiClass::EuropeanNumber)
+                        | Some(BidiClass::ArabicNumber)
+                ) && !(has_en && has_an)
+            }
+            BidiClass::LeftToRight => {
+                // 5.
+                classes.all(|class| {
+                    matches!(
+                        class,
+                        BidiClass::LeftToRight
+                            | BidiClass::EuropeanNumber
+                            | BidiClass::EuropeanSeparator
+                            | BidiClass::CommonSeparator
+                            | BidiClass::EuropeanTerminator
+                            | BidiClass::OtherNeutral
+                            | BidiClass::BoundaryNeutral
+                            | BidiClass::NonspacingMark
+                    )
+                }) && matches!(
+                    // 6.
+                    last,
+                    Some(BidiClass::LeftToRight) | Some(BidiClass::EuropeanNumber)
+                )
+            }
+            // 1.
+            _ => false,
+        }
+    }
+}
+
+/// Keeps the first error encountered during processing.
+struct Errors(Option<IdnaError>);
+
+impl Errors {
+    fn record(&mut self, error: IdnaError) {
+        if self.0.is_none() {
+            self.0 = Some(error);
+        }
+    }
+
+    fn into_result(self) -> Result<(), IdnaError> {
+        match self.0 {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
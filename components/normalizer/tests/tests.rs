@@ -8,6 +8,7 @@ use icu_normalizer::properties::CanonicalDecomposition;
 use icu_normalizer::properties::Decomposed;
 use icu_normalizer::ComposingNormalizer;
 use icu_normalizer::DecomposingNormalizer;
+use icu_normalizer::QuickCheckResult;
 
 #[test]
 fn test_nfd_basic() {
@@ -116,6 +117,198 @@ fn test_nfkc_basic() {
     assert_eq!(normalizer.normalize("\u{0345}"), "\u{0345}"); // Iota subscript
 }
 
+#[test]
+fn test_fcc_basic() {
+    let normalizer: ComposingNormalizer =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable()).unwrap();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("e\u{0302}\u{0323}"), "ệ"); // Reordered first
+    assert_eq!(normalizer.normalize("\u{2126}"), "Ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﬁ"), "ﬁ"); // ligature unchanged
+    assert_eq!(normalizer.normalize("\u{1100}\u{1161}\u{11A8}"), "각");
+
+    // Discontiguous composition is not performed.
+    assert_eq!(
+        normalizer.normalize("a\u{0327}\u{0308}"),
+        "a\u{0327}\u{0308}"
+    );
+    assert_eq!(
+        normalizer.normalize_utf8("a\u{0327}\u{0308}b".as_bytes()),
+        "a\u{0327}\u{0308}b"
+    );
+    assert!(normalizer.is_normalized("a\u{0327}\u{0308}"));
+    assert!(!normalizer.is_normalized("\u{00E4}\u{0327}"));
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    assert_eq!(nfc.normalize("a\u{0327}\u{0308}"), "\u{00E4}\u{0327}");
+}
+
+#[test]
+fn test_is_fcd() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfkd_unstable(&icu_testdata::unstable()).unwrap();
+    let fcc: ComposingNormalizer =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+
+    for text in [
+        "abc",
+        "Bär",
+        "\u{1EA1}\u{0308}", // 220 then 230 after decomposition
+        "\u{0F73}\u{0F74}", // decomposes to 129 130, followed by 132
+        "각",
+        "\u{FDFA}",
+    ] {
+        assert!(nfd.is_fcd(text), "{:?}", text);
+        assert!(nfkd.is_fcd(text), "{:?}", text);
+        assert!(nfd.is_fcd_utf8(text.as_bytes()), "{:?}", text);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert!(nfd.is_fcd_utf16(&utf16), "{:?}", text);
+    }
+    for text in [
+        "\u{00E4}\u{0323}", // 230 then 220 after decomposition
+        "a\u{0301}\u{0327}",
+        "\u{0F73}\u{0F71}", // decomposes to 129 130, followed by 129
+        "\u{0344}\u{0323}", // special non-starter decomposition
+    ] {
+        assert!(!nfd.is_fcd(text), "{:?}", text);
+        assert!(!nfd.is_fcd_utf8(text.as_bytes()), "{:?}", text);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert!(!nfd.is_fcd_utf16(&utf16), "{:?}", text);
+    }
+
+    // FCC output is FCD unlike NFC output.
+    let text = "a\u{0327}\u{0308}";
+    assert!(nfd.is_fcd(&fcc.normalize(text)));
+    assert!(!nfd.is_fcd(&nfc.normalize(text)));
+}
+
+#[test]
+fn test_quick_check() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfkd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_unstable(&icu_testdata::unstable()).unwrap();
+
+    use QuickCheckResult::*;
+    for (text, nfd_qc, nfkd_qc, nfc_qc, nfkc_qc) in [
+        ("abc", Yes, Yes, Yes, Yes),
+        ("Bär", No, No, Yes, Yes),
+        ("e\u{0301}", Yes, Yes, Maybe, Maybe),
+        ("a\u{0323}\u{0301}", Yes, Yes, Maybe, Maybe),
+        ("a\u{0301}\u{0323}", No, No, No, No), // not canonically ordered
+        ("a\u{0334}", Yes, Yes, Yes, Yes),     // never composes
+        ("\u{0344}", No, No, No, No),          // special non-starter decomposition
+        ("\u{212B}", No, No, No, No),          // singleton
+        ("\u{1100}\u{1161}", Yes, Yes, Maybe, Maybe),
+        ("각", No, No, Yes, Yes),
+        ("\u{0CD5}", Yes, Yes, Maybe, Maybe), // starter that combines backwards
+        ("ﬁ", Yes, No, Yes, No),
+        ("\u{FF9E}", Yes, No, Yes, No),
+    ] {
+        assert_eq!(nfd.quick_check(text), nfd_qc, "NFD {:?}", text);
+        assert_eq!(nfkd.quick_check(text), nfkd_qc, "NFKD {:?}", text);
+        assert_eq!(nfc.quick_check(text), nfc_qc, "NFC {:?}", text);
+        assert_eq!(nfkc.quick_check(text), nfkc_qc, "NFKC {:?}", text);
+        assert_eq!(nfc.quick_check_utf8(text.as_bytes()), nfc_qc, "{:?}", text);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert_eq!(nfc.quick_check_utf16(&utf16), nfc_qc, "{:?}", text);
+    }
+
+    // The quick check never contradicts the full check.
+    for c in ('\u{0}'..='\u{FFFF}').chain('\u{1D15E}'..='\u{1D1C0}') {
+        for text in [c.to_string(), format!("a\u{0323}{}", c)] {
+            for (qc, is_normalized) in [
+                (nfc.quick_check(&text), nfc.is_normalized(&text)),
+                (nfkc.quick_check(&text), nfkc.is_normalized(&text)),
+                (nfd.quick_check(&text), nfd.is_normalized(&text)),
+                (nfkd.quick_check(&text), nfkd.is_normalized(&text)),
+            ] {
+                match qc {
+                    Yes => assert!(is_normalized, "{:?}", text),
+                    No => assert!(!is_normalized, "{:?}", text),
+                    Maybe => {}
+                }
+            }
+            assert_ne!(nfd.quick_check(&text), Maybe, "{:?}", text);
+        }
+    }
+}
+
+#[test]
+fn test_is_normalized_up_to() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+
+    assert_eq!(nfc.is_normalized_up_to("abc"), 2);
+    assert_eq!(nfc.is_normalized_up_to(""), 0);
+    assert_eq!(nfc.split_normalized("abc\u{0327}"), ("ab", "c\u{0327}"));
+    assert_eq!(nfc.split_normalized("abce\u{0301}"), ("abc", "e\u{0301}"));
+    assert_eq!(nfc.split_normalized("ae\u{0301}c"), ("a", "e\u{0301}c"));
+    assert_eq!(nfc.split_normalized("\u{212B}abc"), ("", "\u{212B}abc"));
+    assert_eq!(nfc.split_normalized("Bär\u{0323}"), ("Bä", "r\u{0323}"));
+    assert_eq!(nfd.split_normalized("abä"), ("ab", "ä"));
+    assert_eq!(
+        nfd.split_normalized("a\u{0308}\u{0323}b"),
+        ("", "a\u{0308}\u{0323}b")
+    );
+
+    for text in [
+        "abc",
+        "Bär e\u{0301}",
+        "ab\u{0301}\u{0323}c",
+        "\u{1100}\u{1161}\u{11A8}x",
+        "x\u{0F73}\u{0F71}",
+        "ﬁ\u{0344}",
+    ] {
+        let up_to = nfc.is_normalized_up_to(text);
+        let (prefix, rest) = text.split_at(up_to);
+        assert!(nfc.is_normalized(prefix), "{:?}", text);
+        // The prefix stays normalized when the text is continued with
+        // a character that composes with its last character.
+        assert!(
+            nfc.normalize(&format!("{}\u{0301}", text))
+                .starts_with(prefix),
+            "{:?}",
+            text
+        );
+        assert_eq!(
+            nfc.normalize(text),
+            format!("{}{}", prefix, nfc.normalize(rest)),
+            "{:?}",
+            text
+        );
+        assert_eq!(nfc.is_normalized_utf8_up_to(text.as_bytes()), up_to);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert_eq!(
+            nfc.is_normalized_utf16_up_to(&utf16),
+            prefix.encode_utf16().count(),
+            "{:?}",
+            text
+        );
+
+        let up_to = nfd.is_normalized_up_to(text);
+        let (prefix, rest) = text.split_at(up_to);
+        assert!(nfd.is_normalized(prefix), "{:?}", text);
+        assert_eq!(
+            nfd.normalize(text),
+            format!("{}{}", prefix, nfd.normalize(rest)),
+            "{:?}",
+            text
+        );
+    }
+}
+
 #[test]
 #[cfg(feature = "experimental")]
 fn test_uts46_basic() {
@@ -1435,3 +1628,365 @@ fn test_is_normalized() {
     assert!(nfc.is_normalized_utf16(fraction16));
     assert!(!nfkc.is_normalized_utf16(fraction16));
 }
+
+#[test]
+#[cfg(feature = "experimental")]
+fn test_streaming() {
+    let nfd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkd: DecomposingNormalizer =
+        DecomposingNormalizer::try_new_nfkd_unstable(&icu_testdata::unstable()).unwrap();
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    let nfkc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfkc_unstable(&icu_testdata::unstable()).unwrap();
+
+    // Combining sequences, Hangul jamo, half-width kana voicing, a discontiguous
+    // composition, non-BMP characters and invalid UTF-8.
+    let text: &[u8] = "Ba\u{0323}\u{0308}r e\u{0301}\u{0301} \u{1100}\u{1161}\u{11A8} \
+                       ﾍﾞ ﬁ \u{FDFA} 𝅗𝅥 a\u{0315}\u{0300}\u{05AE}\u{0300}b 🦀"
+        .as_bytes();
+    let mut text = text.to_vec();
+    text.extend_from_slice(b"\xE2\x82 \xF0\x9F\x92A\xC3");
+
+    for chunk_len in 1..=8 {
+        let mut nfd_stream = nfd.streaming();
+        let mut nfkd_stream = nfkd.streaming();
+        let mut nfc_stream = nfc.streaming();
+        let mut nfkc_stream = nfkc.streaming();
+        let mut nfd_out = String::new();
+        let mut nfkd_out = String::new();
+        let mut nfc_out = String::new();
+        let mut nfkc_out = String::new();
+        for chunk in text.chunks(chunk_len) {
+            nfd_stream.push_utf8(chunk, &mut nfd_out).unwrap();
+            nfkd_stream.push_utf8(chunk, &mut nfkd_out).unwrap();
+            nfc_stream.push_utf8(chunk, &mut nfc_out).unwrap();
+            nfkc_stream.push_utf8(chunk, &mut nfkc_out).unwrap();
+        }
+        nfd_stream.finish(&mut nfd_out).unwrap();
+        nfkd_stream.finish(&mut nfkd_out).unwrap();
+        nfc_stream.finish(&mut nfc_out).unwrap();
+        nfkc_stream.finish(&mut nfkc_out).unwrap();
+        assert_eq!(
+            nfd_out,
+            nfd.normalize_utf8(&text),
+            "chunk length {}",
+            chunk_len
+        );
+        assert_eq!(
+            nfkd_out,
+            nfkd.normalize_utf8(&text),
+            "chunk length {}",
+            chunk_len
+        );
+        assert_eq!(
+            nfc_out,
+            nfc.normalize_utf8(&text),
+            "chunk length {}",
+            chunk_len
+        );
+        assert_eq!(
+            nfkc_out,
+            nfkc.normalize_utf8(&text),
+            "chunk length {}",
+            chunk_len
+        );
+    }
+
+    // Output is written before the end of the input, holding back only the
+    // segment that can still change.
+    let mut stream = nfc.streaming();
+    let mut out = String::new();
+    stream.push_str("abc", &mut out).unwrap();
+    assert_eq!(out, "ab");
+    stream.push_str("\u{0327}\u{0301}", &mut out).unwrap();
+    assert_eq!(out, "ab");
+    stream.push_str("d", &mut out).unwrap();
+    assert_eq!(out, "ab\u{1E09}");
+    stream.finish(&mut out).unwrap();
+    assert_eq!(out, "ab\u{1E09}d");
+}
+
+#[test]
+#[cfg(all(feature = "experimental", feature = "std"))]
+fn test_streaming_writer_error() {
+    use std::io::Write;
+
+    /// Accepts at most two bytes per call and fails every other one of
+    /// the first calls.
+    struct Flaky {
+        written: Vec<u8>,
+        calls: usize,
+    }
+
+    impl Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            if self.calls < 16 && self.calls % 2 == 1 {
+                return Err(std::io::ErrorKind::BrokenPipe.into());
+            }
+            let len = buf.len().min(2);
+            self.written.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let nfc: ComposingNormalizer =
+        ComposingNormalizer::try_new_nfc_unstable(&icu_testdata::unstable()).unwrap();
+    let mut writer = nfc.streaming().writer(Flaky {
+        written: Vec::new(),
+        calls: 0,
+    });
+    let input = "Ba\u{0308}r e\u{0301}\u{0301} abc".as_bytes();
+    for chunk in input.chunks(3) {
+        // Retry each chunk until it is accepted, like `write_all` does for
+        // interrupted writes.
+        while writer.write(chunk).is_err() {}
+    }
+    while writer.flush().is_err() {}
+    let out = writer.finish().unwrap();
+    assert_eq!(out.written, "Bär é\u{0301} abc".as_bytes());
+}
+
+#[cfg(feature = "experimental")]
+fn unescape_idna_test_field(field: &str) -> String {
+    let mut result = String::new();
+    let mut rest = field;
+    while let Some(index) = rest.find("\\u") {
+        result.push_str(&rest[..index]);
+        let hex = &rest[index + 2..index + 6];
+        result.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap());
+        rest = &rest[index + 6..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// The status codes of `IdnaTestV2.txt` that `error` stands for. The codes follow the
+/// numbering of the steps in the version of UTS #46 that the checked-in file belongs to.
+#[cfg(feature = "experimental")]
+fn idna_status_codes(error: &icu_normalizer::IdnaError) -> &'static [&'static str] {
+    use icu_normalizer::IdnaError;
+    match error {
+        // Processing step 1, and the validity of the code points (V6)
+        IdnaError::DisallowedCodePoint(_) => &["P1", "V6", "U1"],
+        // Processing step 4, when a label fails to decode, and ToASCII step 3
+        IdnaError::Punycode => &["P4", "A3"],
+        IdnaError::NotNormalized => &["V1"],
+        IdnaError::Hyphen => &["V2", "V3"],
+        IdnaError::FullStop => &["V4"],
+        IdnaError::LeadingCombiningMark => &["V5"],
+        IdnaError::ContextJ => &["V7", "C1", "C2"],
+        IdnaError::Bidi => &["V8", "B1", "B2", "B3", "B4", "B5", "B6"],
+        // X4_2 is an empty label, which the ToASCII cases inherit from ToUnicode
+        IdnaError::DnsLength => &["A4_1", "A4_2", "X4_2"],
+        _ => &[],
+    }
+}
+
+/// Checks the output and result of an operation against an `IdnaTestV2.txt` test case. The
+/// output is not compared if there is none, as for a failed ToASCII.
+#[cfg(feature = "experimental")]
+fn check_idna_test_case(
+    operation: &str,
+    source: &str,
+    output: Option<&str>,
+    result: Result<(), icu_normalizer::IdnaError>,
+    expected: &str,
+    status: &str,
+) {
+    if let Some(output) = output {
+        // A U+FFFD in the output stands for the disallowed code point that it replaces.
+        let matches = output.chars().count() == expected.chars().count()
+            && output
+                .chars()
+                .zip(expected.chars())
+                .all(|(o, e)| o == e || o == '\u{FFFD}');
+        assert!(
+            matches,
+            "{} {:?} is {:?}, expected {:?}",
+            operation, source, output, expected
+        );
+    }
+    let codes: Vec<&str> = status
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .collect();
+    // The X codes mark the ToUnicode cases with an empty label, whose status codes come
+    // from ToASCII, which checks the length of the labels, so their status is not checked.
+    if operation == "toUnicode" && codes.iter().any(|code| code.starts_with('X')) {
+        return;
+    }
+    match result {
+        Ok(()) => assert!(
+            codes.is_empty(),
+            "{} {:?} should fail with {:?}",
+            operation,
+            source,
+            codes
+        ),
+        Err(e) => assert!(
+            idna_status_codes(&e)
+                .iter()
+                .any(|code| codes.contains(code)),
+            "{} {:?} failed with {:?}, expected {:?}",
+            operation,
+            source,
+            e,
+            codes
+        ),
+    }
+}
+
+/// Runs the test cases of `IdnaTestV2.txt`: for each line, compares the output of ToUnicode
+/// and of nontransitional and transitional ToASCII, and checks that an error is returned
+/// exactly when a status code is listed, and that it is one of the listed codes.
+#[test]
+#[cfg(feature = "experimental")]
+fn test_idna_conformance() {
+    use icu_normalizer::uts46::{Uts46Options, Uts46Processor};
+
+    let mut options = Uts46Options::default();
+    options.use_std3_ascii_rules = true;
+    options.check_hyphens = true;
+    options.check_bidi = true;
+    options.check_joiners = true;
+    options.verify_dns_length = true;
+    let nontransitional =
+        Uts46Processor::try_new_unstable(&icu_testdata::unstable(), options).expect("Data exists");
+    options.transitional_processing = true;
+    let transitional =
+        Uts46Processor::try_new_unstable(&icu_testdata::unstable(), options).expect("Data exists");
+
+    let test_data = include_str!("data/IdnaTestV2.txt");
+    for line in test_data.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        // 0: source
+        // 1: toUnicode, 2: toUnicodeStatus
+        // 3: toAsciiN, 4: toAsciiNStatus
+        // 5: toAsciiT, 6: toAsciiTStatus
+        // Blank values default to an earlier column.
+        let source = unescape_idna_test_field(fields[0]);
+        let to_unicode = match unescape_idna_test_field(fields[1]) {
+            s if s.is_empty() => source.clone(),
+            s => s,
+        };
+        let to_unicode_status = fields[2];
+        let to_ascii_n = match unescape_idna_test_field(fields[3]) {
+            s if s.is_empty() => to_unicode.clone(),
+            s => s,
+        };
+        let to_ascii_n_status = match fields[4] {
+            "" => to_unicode_status,
+            s => s,
+        };
+        let to_ascii_t = match unescape_idna_test_field(fields[5]) {
+            s if s.is_empty() => to_ascii_n.clone(),
+            s => s,
+        };
+        let to_ascii_t_status = match fields[6] {
+            "" => to_ascii_n_status,
+            s => s,
+        };
+
+        let (unicode, result) = nontransitional.to_unicode(&source);
+        check_idna_test_case(
+            "toUnicode",
+            &source,
+            Some(&unicode),
+            result,
+            &to_unicode,
+            to_unicode_status,
+        );
+
+        for (operation, processor, expected, status) in [
+            ("toAsciiN", &nontransitional, &to_ascii_n, to_ascii_n_status),
+            ("toAsciiT", &transitional, &to_ascii_t, to_ascii_t_status),
+        ] {
+            let (ascii, result) = match processor.to_ascii(&source) {
+                Ok(ascii) => (Some(ascii), Ok(())),
+                Err(e) => (None, Err(e)),
+            };
+            check_idna_test_case(
+                operation,
+                &source,
+                ascii.as_deref(),
+                result,
+                expected,
+                status,
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+fn test_idna_basic() {
+    use icu_normalizer::uts46::{Uts46Options, Uts46Processor};
+    use icu_normalizer::IdnaError;
+
+    let mut options = Uts46Options::default();
+    options.use_std3_ascii_rules = true;
+    options.check_hyphens = true;
+    options.verify_dns_length = true;
+    let nontransitional =
+        Uts46Processor::try_new_unstable(&icu_testdata::unstable(), options).expect("Data exists");
+    options.transitional_processing = true;
+    let transitional =
+        Uts46Processor::try_new_unstable(&icu_testdata::unstable(), options).expect("Data exists");
+
+    // Mapping and deviations
+    assert_eq!(
+        nontransitional.to_ascii("Bücher.EXAMPLE").as_deref(),
+        Ok("xn--bcher-kva.example")
+    );
+    assert_eq!(
+        nontransitional.to_ascii("faß.de").as_deref(),
+        Ok("xn--fa-hia.de")
+    );
+    assert_eq!(transitional.to_ascii("faß.de").as_deref(), Ok("fass.de"));
+    assert_eq!(
+        nontransitional.to_unicode("xn--fa-hia.de"),
+        (String::from("faß.de"), Ok(()))
+    );
+    assert_eq!(
+        nontransitional.to_ascii("a\u{3002}b\u{FF0E}c").as_deref(),
+        Ok("a.b.c")
+    );
+
+    // Validity criteria
+    assert_eq!(
+        nontransitional.to_ascii("a\u{FE12}b"),
+        Err(IdnaError::DisallowedCodePoint(0xFE12))
+    );
+    assert_eq!(nontransitional.to_ascii("a.b-.c"), Err(IdnaError::Hyphen));
+    assert_eq!(
+        nontransitional.to_ascii("xn--9.b"),
+        Err(IdnaError::Punycode)
+    );
+    assert_eq!(
+        nontransitional.to_ascii("a\u{200D}b"),
+        Err(IdnaError::ContextJ)
+    );
+    assert_eq!(
+        nontransitional.to_ascii("0a.\u{05D0}"),
+        Err(IdnaError::Bidi)
+    );
+    assert_eq!(
+        nontransitional.to_ascii(&"a".repeat(64)),
+        Err(IdnaError::DnsLength)
+    );
+    assert_eq!(nontransitional.to_ascii("a..b"), Err(IdnaError::DnsLength));
+}
@@ -0,0 +1,648 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The rules of [UAX #9](https://www.unicode.org/reports/tr9/), applied to the characters of
+//! a paragraph. The names of the rules and of the Bidi_Class values are those of UAX #9.
+
+// The indices are positions in the paragraph, or in an isolating run sequence, and the
+// vectors they index are all created with those lengths.
+#![allow(clippy::indexing_slicing)]
+
+use super::{BidiAuxiliaryPropertiesBorrowed, BidiLevel, BidiPairedBracketType};
+use crate::props::BidiClass;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+const L: BidiClass = BidiClass::LeftToRight;
+const R: BidiClass = BidiClass::RightToLeft;
+const AL: BidiClass = BidiClass::ArabicLetter;
+const EN: BidiClass = BidiClass::EuropeanNumber;
+const ES: BidiClass = BidiClass::EuropeanSeparator;
+const ET: BidiClass = BidiClass::EuropeanTerminator;
+const AN: BidiClass = BidiClass::ArabicNumber;
+const CS: BidiClass = BidiClass::CommonSeparator;
+const NSM: BidiClass = BidiClass::NonspacingMark;
+const BN: BidiClass = BidiClass::BoundaryNeutral;
+const B: BidiClass = BidiClass::ParagraphSeparator;
+const S: BidiClass = BidiClass::SegmentSeparator;
+const WS: BidiClass = BidiClass::WhiteSpace;
+const ON: BidiClass = BidiClass::OtherNeutral;
+const LRE: BidiClass = BidiClass::LeftToRightEmbedding;
+const LRO: BidiClass = BidiClass::LeftToRightOverride;
+const RLE: BidiClass = BidiClass::RightToLeftEmbedding;
+const RLO: BidiClass = BidiClass::RightToLeftOverride;
+const PDF: BidiClass = BidiClass::PopDirectionalFormat;
+const LRI: BidiClass = BidiClass::LeftToRightIsolate;
+const RLI: BidiClass = BidiClass::RightToLeftIsolate;
+const FSI: BidiClass = BidiClass::FirstStrongIsolate;
+const PDI: BidiClass = BidiClass::PopDirectionalIsolate;
+
+/// The maximum explicit embedding level (BD2).
+pub(super) const MAX_DEPTH: u8 = 125;
+
+/// The maximum nesting of paired brackets (BD16).
+const MAX_BRACKET_DEPTH: usize = 63;
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(class, LRI | RLI | FSI)
+}
+
+/// Whether the rule X9 removes characters of the class `class`.
+pub(super) fn is_removed_by_x9(class: BidiClass) -> bool {
+    matches!(class, RLE | LRE | RLO | LRO | PDF | BN)
+}
+
+/// The embedding direction of `level`, `L` or `R`.
+fn direction(level: u8) -> BidiClass {
+    if level % 2 == 0 {
+        L
+    } else {
+        R
+    }
+}
+
+/// Resolves the embedding levels of the characters of a paragraph, which ends after its
+/// paragraph separator, if any, and appends them to `levels`.
+///
+/// Returns the paragraph embedding level, which is `paragraph_level` if there is one.
+pub(super) fn resolve_paragraph(
+    auxiliary: BidiAuxiliaryPropertiesBorrowed,
+    chars: &[char],
+    initial: &[BidiClass],
+    paragraph_level: Option<u8>,
+    levels: &mut Vec<u8>,
+) -> u8 {
+    let matching_pdis = matching_pdis(initial);
+
+    // P2, P3
+    let paragraph_level = paragraph_level.unwrap_or_else(|| {
+        match first_strong(initial, &matching_pdis, 0..initial.len()) {
+            Some(R) => 1,
+            _ => 0,
+        }
+    });
+
+    // X1-X8
+    let mut classes = initial.to_vec();
+    let mut paragraph_levels = vec![paragraph_level; initial.len()];
+    explicit_levels(
+        initial,
+        &matching_pdis,
+        paragraph_level,
+        &mut classes,
+        &mut paragraph_levels,
+    );
+
+    // X9, X10: the sequences are determined, with their sos and eos, before any of them is
+    // resolved, as the resolution changes the levels.
+    let sequences = isolating_run_sequences(initial, &matching_pdis, &paragraph_levels)
+        .into_iter()
+        .map(|sequence| {
+            let (sos, eos) = sequence_boundaries(
+                &sequence,
+                initial,
+                &classes,
+                &paragraph_levels,
+                paragraph_level,
+            );
+            (sequence, sos, eos)
+        })
+        .collect::<Vec<_>>();
+    for (sequence, sos, eos) in sequences {
+        let level = paragraph_levels[sequence[0]];
+        let mut types = sequence.iter().map(|&i| classes[i]).collect::<Vec<_>>();
+        resolve_weak_types(&mut types, sos, eos);
+        resolve_paired_brackets(auxiliary, &sequence, chars, initial, &mut types, level, sos);
+        resolve_neutral_types(&mut types, level, sos, eos);
+        for (&i, class) in sequence.iter().zip(types) {
+            // I1, I2
+            paragraph_levels[i] = match (level % 2, class) {
+                (0, R) => level + 1,
+                (0, AN | EN) => level + 2,
+                (1, L | EN | AN) => level + 1,
+                _ => level,
+            };
+        }
+    }
+
+    // The characters removed by X9 take the level of the preceding character, so that they
+    // don't break runs of characters.
+    for i in 0..initial.len() {
+        if is_removed_by_x9(initial[i]) {
+            paragraph_levels[i] = match i {
+                0 => paragraph_level,
+                _ => paragraph_levels[i - 1],
+            };
+        }
+    }
+
+    levels.extend(paragraph_levels);
+    paragraph_level
+}
+
+/// The index of the matching PDI of each isolate initiator (BD9).
+fn matching_pdis(initial: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matching_pdis = vec![None; initial.len()];
+    let mut initiators = Vec::new();
+    for (i, &class) in initial.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            initiators.push(i);
+        } else if class == PDI {
+            if let Some(initiator) = initiators.pop() {
+                matching_pdis[initiator] = Some(i);
+            }
+        }
+    }
+    matching_pdis
+}
+
+/// The direction of the first strong character in `range`, skipping the characters between
+/// isolate initiators and their matching PDIs (P2).
+fn first_strong(
+    initial: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    range: Range<usize>,
+) -> Option<BidiClass> {
+    let mut i = range.start;
+    while i < range.end {
+        match initial[i] {
+            L => return Some(L),
+            R | AL => return Some(R),
+            class if is_isolate_initiator(class) => match matching_pdis[i] {
+                Some(pdi) => i = pdi,
+                None => return None,
+            },
+            _ => (),
+        }
+        i += 1;
+    }
+    None
+}
+
+/// An entry of the directional status stack.
+struct DirectionalStatus {
+    level: u8,
+    override_status: Option<BidiClass>,
+    isolate_status: bool,
+}
+
+/// Applies the rules X1 to X8.
+fn explicit_levels(
+    initial: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    paragraph_level: u8,
+    classes: &mut [BidiClass],
+    levels: &mut [u8],
+) {
+    let mut stack = Vec::with_capacity(usize::from(MAX_DEPTH) + 2);
+    stack.push(DirectionalStatus {
+        level: paragraph_level,
+        override_status: None,
+        isolate_status: false,
+    });
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+
+    for (i, &class) in initial.iter().enumerate() {
+        #[allow(clippy::unwrap_used)] // the stack always has its initial entry
+        let last = stack.last().unwrap();
+        match class {
+            RLE | LRE | RLO | LRO | RLI | LRI | FSI => {
+                let is_isolate = is_isolate_initiator(class);
+                let is_rtl = match class {
+                    RLE | RLO | RLI => true,
+                    FSI => {
+                        let end = matching_pdis[i].unwrap_or(initial.len());
+                        first_strong(initial, matching_pdis, i + 1..end) == Some(R)
+                    }
+                    _ => false,
+                };
+                levels[i] = last.level;
+                if is_isolate {
+                    if let Some(override_status) = last.override_status {
+                        classes[i] = override_status;
+                    }
+                }
+                let level = if is_rtl {
+                    (last.level + 1) | 1
+                } else {
+                    (last.level + 2) & !1
+                };
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    if is_isolate {
+                        valid_isolates += 1;
+                    }
+                    stack.push(DirectionalStatus {
+                        level,
+                        override_status: match class {
+                            LRO => Some(L),
+                            RLO => Some(R),
+                            _ => None,
+                        },
+                        isolate_status: is_isolate,
+                    });
+                } else if is_isolate {
+                    overflow_isolates += 1;
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while matches!(stack.last(), Some(status) if !status.isolate_status) {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolates -= 1;
+                }
+                #[allow(clippy::unwrap_used)] // a valid isolate is above the initial entry
+                let last = stack.last().unwrap();
+                levels[i] = last.level;
+                if let Some(override_status) = last.override_status {
+                    classes[i] = override_status;
+                }
+            }
+            PDF => {
+                levels[i] = last.level;
+                if overflow_isolates > 0 {
+                    // The PDF is in an overflow isolate
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !last.isolate_status && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            B => levels[i] = paragraph_level,
+            BN => levels[i] = last.level,
+            _ => {
+                levels[i] = last.level;
+                if let Some(override_status) = last.override_status {
+                    classes[i] = override_status;
+                }
+            }
+        }
+    }
+}
+
+/// The isolating run sequences of the paragraph (BD13), as the indices of their characters,
+/// without the characters removed by X9.
+fn isolating_run_sequences(
+    initial: &[BidiClass],
+    matching_pdis: &[Option<usize>],
+    levels: &[u8],
+) -> Vec<Vec<usize>> {
+    let indices = (0..initial.len())
+        .filter(|&i| !is_removed_by_x9(initial[i]))
+        .collect::<Vec<_>>();
+
+    // The level runs, as ranges of `indices`
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (k, &i) in indices.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if levels[indices[run.end - 1]] == levels[i] => run.end = k + 1,
+            _ => runs.push(k..k + 1),
+        }
+    }
+    let mut is_matched_pdi = vec![false; initial.len()];
+    for &pdi in matching_pdis.iter().flatten() {
+        is_matched_pdi[pdi] = true;
+    }
+
+    let mut sequences = Vec::new();
+    for run in &runs {
+        let first = indices[run.start];
+        if is_matched_pdi[first] {
+            // The run continues the sequence of the matching isolate initiator
+            continue;
+        }
+        let mut sequence = Vec::new();
+        let mut run = run.clone();
+        loop {
+            sequence.extend(indices[run.clone()].iter().copied());
+            let last = indices[run.end - 1];
+            let pdi = match is_isolate_initiator(initial[last]) {
+                true => matching_pdis[last],
+                false => None,
+            };
+            let next = pdi.and_then(|pdi| {
+                let k = indices.binary_search(&pdi).ok()?;
+                runs.get(runs.partition_point(|run| run.end <= k)).cloned()
+            });
+            match next {
+                Some(next) => run = next,
+                None => break,
+            }
+        }
+        sequences.push(sequence);
+    }
+    sequences
+}
+
+/// The sos and eos of an isolating run sequence (X10).
+fn sequence_boundaries(
+    sequence: &[usize],
+    initial: &[BidiClass],
+    classes: &[BidiClass],
+    levels: &[u8],
+    paragraph_level: u8,
+) -> (BidiClass, BidiClass) {
+    let (first, last) = match (sequence.first(), sequence.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return (direction(paragraph_level), direction(paragraph_level)),
+    };
+    let level = levels[first];
+    let preceding_level = (0..first)
+        .rev()
+        .find(|&i| !is_removed_by_x9(initial[i]))
+        .map_or(paragraph_level, |i| levels[i]);
+    let following_level = if is_isolate_initiator(classes[last]) {
+        paragraph_level
+    } else {
+        (last + 1..initial.len())
+            .find(|&i| !is_removed_by_x9(initial[i]))
+            .map_or(paragraph_level, |i| levels[i])
+    };
+    (
+        direction(preceding_level.max(level)),
+        direction(following_level.max(level)),
+    )
+}
+
+/// Applies the rules W1 to W7 to the types of an isolating run sequence.
+fn resolve_weak_types(types: &mut [BidiClass], sos: BidiClass, eos: BidiClass) {
+    // W1
+    let mut previous = sos;
+    for class in types.iter_mut() {
+        if *class == NSM {
+            *class = if is_isolate_initiator(previous) || previous == PDI {
+                ON
+            } else {
+                previous
+            };
+        }
+        previous = *class;
+    }
+
+    // W2, W3
+    let mut last_strong = sos;
+    for class in types.iter_mut() {
+        match *class {
+            L | R => last_strong = *class,
+            AL => {
+                last_strong = AL;
+                *class = R;
+            }
+            EN if last_strong == AL => *class = AN,
+            _ => (),
+        }
+    }
+
+    // W4
+    for i in 1..types.len().saturating_sub(1) {
+        match (types[i - 1], types[i], types[i + 1]) {
+            (EN, ES | CS, EN) => types[i] = EN,
+            (AN, CS, AN) => types[i] = AN,
+            _ => (),
+        }
+    }
+
+    // W5
+    let mut i = 0;
+    while i < types.len() {
+        if types[i] == ET {
+            let end = run_end(types, i, |class| class == ET);
+            let before = if i == 0 { sos } else { types[i - 1] };
+            let after = types.get(end).copied().unwrap_or(eos);
+            if before == EN || after == EN {
+                types[i..end].fill(EN);
+            }
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+
+    // W6
+    for class in types.iter_mut() {
+        if matches!(*class, ES | ET | CS) {
+            *class = ON;
+        }
+    }
+
+    // W7
+    let mut last_strong = sos;
+    for class in types.iter_mut() {
+        match *class {
+            L | R => last_strong = *class,
+            EN if last_strong == L => *class = L,
+            _ => (),
+        }
+    }
+}
+
+/// The end of the run of types that satisfy `predicate` from `start`.
+fn run_end(types: &[BidiClass], start: usize, predicate: impl Fn(BidiClass) -> bool) -> usize {
+    types[start..]
+        .iter()
+        .position(|&class| !predicate(class))
+        .map_or(types.len(), |i| start + i)
+}
+
+/// The strong direction of a type for the rule N0, in which `EN` and `AN` are `R`.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L => Some(L),
+        R | AL | EN | AN => Some(R),
+        _ => None,
+    }
+}
+
+/// The canonical equivalent of a bracket, for pairing brackets (BD16).
+pub(crate) fn canonical_bracket(ch: char) -> char {
+    match ch {
+        '\u{2329}' => '\u{3008}',
+        '\u{232A}' => '\u{3009}',
+        _ => ch,
+    }
+}
+
+/// The bracket pairs of an isolating run sequence (BD16), as positions in the sequence,
+/// sorted by their opening brackets.
+fn bracket_pairs(
+    auxiliary: BidiAuxiliaryPropertiesBorrowed,
+    sequence: &[usize],
+    chars: &[char],
+    types: &[BidiClass],
+) -> Vec<(usize, usize)> {
+    let mut openings: Vec<(char, usize)> = Vec::new();
+    let mut pairs = Vec::new();
+    for (k, &i) in sequence.iter().enumerate() {
+        if types[k] != ON {
+            continue;
+        }
+        let ch = chars[i];
+        match auxiliary.get_paired_bracket_type(ch) {
+            BidiPairedBracketType::Open => {
+                if openings.len() == MAX_BRACKET_DEPTH {
+                    break;
+                }
+                if let Some(closing) = auxiliary.get_paired_bracket(ch) {
+                    openings.push((canonical_bracket(closing), k));
+                }
+            }
+            BidiPairedBracketType::Close => {
+                let closing = canonical_bracket(ch);
+                if let Some(depth) = openings.iter().rposition(|&(c, _)| c == closing) {
+                    pairs.push((openings[depth].1, k));
+                    openings.truncate(depth);
+                }
+            }
+            BidiPairedBracketType::None => (),
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Applies the rule N0 to the types of an isolating run sequence.
+fn resolve_paired_brackets(
+    auxiliary: BidiAuxiliaryPropertiesBorrowed,
+    sequence: &[usize],
+    chars: &[char],
+    initial: &[BidiClass],
+    types: &mut [BidiClass],
+    level: u8,
+    sos: BidiClass,
+) {
+    let embedding = direction(level);
+    for (opening, closing) in bracket_pairs(auxiliary, sequence, chars, types) {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for &class in &types[opening + 1..closing] {
+            match strong_direction(class) {
+                Some(strong) if strong == embedding => found_embedding = true,
+                Some(_) => found_opposite = true,
+                None => (),
+            }
+        }
+        let resolved = if found_embedding {
+            embedding
+        } else if found_opposite {
+            let preceding = types[..opening]
+                .iter()
+                .rev()
+                .find_map(|&class| strong_direction(class))
+                .unwrap_or(sos);
+            if preceding != embedding {
+                preceding
+            } else {
+                embedding
+            }
+        } else {
+            continue;
+        };
+        for bracket in [opening, closing] {
+            types[bracket] = resolved;
+            // The nonspacing marks after a bracket take its type
+            for k in bracket + 1..sequence.len() {
+                if initial[sequence[k]] != NSM {
+                    break;
+                }
+                types[k] = resolved;
+            }
+        }
+    }
+}
+
+/// Applies the rules N1 and N2 to the types of an isolating run sequence.
+fn resolve_neutral_types(types: &mut [BidiClass], level: u8, sos: BidiClass, eos: BidiClass) {
+    let is_neutral_or_isolate =
+        |class: BidiClass| matches!(class, B | S | WS | ON | LRI | RLI | FSI | PDI);
+    let mut i = 0;
+    while i < types.len() {
+        if is_neutral_or_isolate(types[i]) {
+            let end = run_end(types, i, is_neutral_or_isolate);
+            let before = if i == 0 {
+                sos
+            } else {
+                strong_direction(types[i - 1]).unwrap_or(types[i - 1])
+            };
+            let after = types
+                .get(end)
+                .map_or(eos, |&class| strong_direction(class).unwrap_or(class));
+            let resolved = if before == after {
+                before
+            } else {
+                direction(level)
+            };
+            types[i..end].fill(resolved);
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Applies the rule L1 to the levels of the characters of a line of a paragraph: the
+/// separators, and the whitespace before them and at the end of the line, take the
+/// paragraph embedding level.
+pub(super) fn reset_whitespace_levels(
+    initial: &[BidiClass],
+    paragraph_level: BidiLevel,
+    levels: &mut [BidiLevel],
+) {
+    // The explicit formatting characters, which were removed by X9, are reset with the
+    // whitespace around them.
+    let is_whitespace = |class: BidiClass| {
+        matches!(
+            class,
+            WS | LRE | RLE | LRO | RLO | PDF | LRI | RLI | FSI | PDI | BN
+        )
+    };
+    let mut trailing = true;
+    for i in (0..initial.len()).rev() {
+        let class = initial[i];
+        if class == S || class == B {
+            levels[i] = paragraph_level;
+            trailing = true;
+        } else if trailing && is_whitespace(class) {
+            levels[i] = paragraph_level;
+        } else {
+            trailing = false;
+        }
+    }
+}
+
+/// The indices of the characters of a line in visual order from left to right, from their
+/// levels (rule L2).
+pub(super) fn visual_order(levels: &[BidiLevel]) -> Vec<usize> {
+    let mut order = (0..levels.len()).collect::<Vec<_>>();
+    let highest = levels.iter().max().map_or(0, |level| level.number());
+    let lowest_odd = levels
+        .iter()
+        .map(|level| level.number() | 1)
+        .min()
+        .unwrap_or(1);
+    for level in (lowest_odd..=highest).rev() {
+        let mut i = 0;
+        while i < levels.len() {
+            if levels[order[i]].number() >= level {
+                let end = order[i..]
+                    .iter()
+                    .position(|&j| levels[j].number() < level)
+                    .map_or(levels.len(), |k| i + k);
+                order[i..end].reverse();
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+    }
+    order
+}
@@ -0,0 +1,95 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module exposes tooling for running the [unicode bidi algorithm](https://unicode.org/reports/tr9/) using ICU4X data.
+//!
+//! With the `bidi` Cargo feature, [`BidiClassAdapter`] provides ICU4X data to the external
+//! [`unicode-bidi`](https://docs.rs/unicode-bidi) crate.
+//!
+//! 🚧 \[Experimental\] With the `experimental` Cargo feature, [`BidiResolver`] implements
+//! UAX #9, including the paired bracket algorithm, and the reordering of lines for display.
+//! It uses the Bidi_Class property and the [`BidiAuxiliaryProperties`]: Bidi_Paired_Bracket,
+//! Bidi_Paired_Bracket_Type and Bidi_Mirroring_Glyph.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_properties::bidi::{self, BidiResolver, ParagraphDirection};
+//! use icu_properties::maps;
+//!
+//! // This example text is defined using `concat!` because some browsers
+//! // and text editors have trouble displaying bidi strings.
+//! let text = concat![
+//!     "א", // RTL#1
+//!     "ב", // RTL#2
+//!     "(", // Paired bracket
+//!     "a", // LTR#1
+//!     "b", // LTR#2
+//!     ")", // Paired bracket
+//! ];
+//!
+//! let bidi_class = maps::load_bidi_class(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//! let auxiliary = bidi::load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//! let resolver = BidiResolver::new(bidi_class.as_borrowed(), auxiliary.as_borrowed());
+//!
+//! // Resolve the embedding levels within the text. The paragraph direction is that of
+//! // its first strong character.
+//! let bidi_info = resolver.resolve(text, ParagraphDirection::Auto);
+//!
+//! // This paragraph has embedding level 1 because its first strong character is RTL.
+//! assert_eq!(bidi_info.paragraphs().len(), 1);
+//! let paragraph = &bidi_info.paragraphs()[0];
+//! assert_eq!(paragraph.level().number(), 1);
+//! assert!(paragraph.level().is_rtl());
+//!
+//! // The brackets take the direction of the paragraph, as they enclose LTR text in RTL
+//! // context.
+//! let levels = bidi_info.levels().iter().map(|level| level.number()).collect::<Vec<_>>();
+//! assert_eq!(levels, [1, 1, 1, 2, 2, 1]);
+//!
+//! // Reordering is done after wrapping each paragraph into a sequence of lines. For this
+//! // example, a single line spans the entire paragraph. The brackets are mirrored, as they
+//! // are displayed right-to-left.
+//! let display = bidi_info.reorder_line(paragraph.range());
+//! assert_eq!(
+//!     display,
+//!     concat![
+//!         "(", // Paired bracket, mirrored
+//!         "a", // LTR#1
+//!         "b", // LTR#2
+//!         ")", // Paired bracket, mirrored
+//!         "ב", // RTL#2
+//!         "א", // RTL#1
+//!     ]
+//! );
+//! ```
+
+#[cfg(feature = "bidi")]
+mod adapter;
+#[cfg(feature = "experimental")]
+mod algorithm;
+#[cfg(feature = "experimental")]
+mod resolver;
+
+#[cfg(feature = "bidi")]
+pub use adapter::BidiClassAdapter;
+
+#[cfg(feature = "experimental")]
+pub(crate) use algorithm::canonical_bracket;
+#[cfg(all(feature = "experimental", feature = "serde"))]
+pub use resolver::load_bidi_auxiliary_properties_with_buffer_provider;
+#[cfg(feature = "experimental")]
+pub use resolver::{
+    load_bidi_auxiliary_properties_unstable, load_bidi_auxiliary_properties_with_any_provider,
+    BidiAuxiliaryProperties, BidiAuxiliaryPropertiesBorrowed, BidiInfo, BidiLevel,
+    BidiPairedBracketType, BidiParagraph, BidiResolver, ParagraphDirection,
+};
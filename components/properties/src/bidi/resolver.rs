@@ -0,0 +1,486 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::algorithm;
+use crate::error::PropertiesError;
+use crate::maps::CodePointMapDataBorrowed;
+use crate::props::BidiClass;
+use crate::provider::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+use icu_provider::prelude::*;
+
+/// The bits of the Bidi_Mirroring_Glyph in the values of [`BidiAuxiliaryPropertiesV1`].
+const MIRRORING_GLYPH_MASK: u32 = 0x1F_FFFF;
+/// The shift of the Bidi_Paired_Bracket_Type in the values of [`BidiAuxiliaryPropertiesV1`].
+const PAIRED_BRACKET_TYPE_SHIFT: u32 = 21;
+
+/// 🚧 \[Experimental\] The values of the Bidi_Paired_Bracket_Type property.
+///
+/// For more information, see [BD14 and BD15](https://www.unicode.org/reports/tr9/#BD14) in
+/// UAX #9.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum BidiPairedBracketType {
+    /// (`o`) an opening paired bracket
+    Open,
+    /// (`c`) a closing paired bracket
+    Close,
+    /// (`n`) not a paired bracket
+    None,
+}
+
+/// 🚧 \[Experimental\] A wrapper around the properties used by the bidi algorithm besides
+/// Bidi_Class: Bidi_Mirroring_Glyph, Bidi_Paired_Bracket and Bidi_Paired_Bracket_Type.
+///
+/// Most useful methods are on [`BidiAuxiliaryPropertiesBorrowed`] obtained by calling
+/// [`BidiAuxiliaryProperties::as_borrowed()`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+pub struct BidiAuxiliaryProperties {
+    data: DataPayload<BidiAuxiliaryPropertiesV1Marker>,
+}
+
+/// 🚧 \[Experimental\] A borrowed wrapper around the auxiliary bidi properties, returned by
+/// [`BidiAuxiliaryProperties::as_borrowed()`]. More efficient to query.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Clone, Copy)]
+pub struct BidiAuxiliaryPropertiesBorrowed<'a> {
+    data: &'a BidiAuxiliaryPropertiesV1<'a>,
+}
+
+impl BidiAuxiliaryProperties {
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (like
+    /// `get_mirroring_glyph()`) by consolidating it up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> BidiAuxiliaryPropertiesBorrowed<'_> {
+        BidiAuxiliaryPropertiesBorrowed {
+            data: self.data.get(),
+        }
+    }
+
+    /// Construct a new one from loaded data
+    ///
+    /// Typically it is preferable to use getters like
+    /// [`load_bidi_auxiliary_properties_unstable()`] instead
+    pub fn from_data(data: DataPayload<BidiAuxiliaryPropertiesV1Marker>) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> BidiAuxiliaryPropertiesBorrowed<'a> {
+    /// The value of the Bidi_Mirroring_Glyph property of `ch`: the character with the
+    /// mirrored glyph, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::bidi;
+    ///
+    /// let data = bidi::load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let auxiliary = data.as_borrowed();
+    ///
+    /// assert_eq!(auxiliary.get_mirroring_glyph('<'), Some('>'));
+    /// assert_eq!(auxiliary.get_mirroring_glyph('«'), Some('»'));
+    /// assert_eq!(auxiliary.get_mirroring_glyph('∛'), None); // Bidi_Mirrored, but no glyph
+    /// assert_eq!(auxiliary.get_mirroring_glyph('a'), None);
+    /// ```
+    pub fn get_mirroring_glyph(self, ch: char) -> Option<char> {
+        match self.data.trie.get32(ch as u32) & MIRRORING_GLYPH_MASK {
+            0 => None,
+            mirror => char::from_u32(mirror),
+        }
+    }
+
+    /// The value of the Bidi_Paired_Bracket_Type property of `ch`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::bidi::{self, BidiPairedBracketType};
+    ///
+    /// let data = bidi::load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let auxiliary = data.as_borrowed();
+    ///
+    /// assert_eq!(auxiliary.get_paired_bracket_type('['), BidiPairedBracketType::Open);
+    /// assert_eq!(auxiliary.get_paired_bracket_type('」'), BidiPairedBracketType::Close);
+    /// assert_eq!(auxiliary.get_paired_bracket_type('<'), BidiPairedBracketType::None);
+    /// ```
+    pub fn get_paired_bracket_type(self, ch: char) -> BidiPairedBracketType {
+        match self.data.trie.get32(ch as u32) >> PAIRED_BRACKET_TYPE_SHIFT {
+            1 => BidiPairedBracketType::Open,
+            2 => BidiPairedBracketType::Close,
+            _ => BidiPairedBracketType::None,
+        }
+    }
+
+    /// The value of the Bidi_Paired_Bracket property of `ch`: the bracket that pairs with it,
+    /// if it is a paired bracket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::bidi;
+    ///
+    /// let data = bidi::load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let auxiliary = data.as_borrowed();
+    ///
+    /// assert_eq!(auxiliary.get_paired_bracket('('), Some(')'));
+    /// assert_eq!(auxiliary.get_paired_bracket('}'), Some('{'));
+    /// assert_eq!(auxiliary.get_paired_bracket('<'), None);
+    /// ```
+    pub fn get_paired_bracket(self, ch: char) -> Option<char> {
+        if self.get_paired_bracket_type(ch) == BidiPairedBracketType::None {
+            None
+        } else {
+            self.get_mirroring_glyph(ch)
+        }
+    }
+}
+
+/// 🚧 \[Experimental\] Returns a [`BidiAuxiliaryProperties`] for the properties used by the
+/// bidi algorithm besides Bidi_Class.
+///
+/// [📚 Help choosing a constructor](icu_provider::constructors)
+/// <div class="stab unstable">
+/// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+/// </div>
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+pub fn load_bidi_auxiliary_properties_unstable(
+    provider: &(impl DataProvider<BidiAuxiliaryPropertiesV1Marker> + ?Sized),
+) -> Result<BidiAuxiliaryProperties, PropertiesError> {
+    Ok(BidiAuxiliaryProperties::from_data(
+        provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)?,
+    ))
+}
+
+icu_provider::gen_any_buffer_constructors!(
+    locale: skip,
+    options: skip,
+    result: Result<BidiAuxiliaryProperties, PropertiesError>,
+    functions: [
+        load_bidi_auxiliary_properties_unstable,
+        load_bidi_auxiliary_properties_with_any_provider,
+        load_bidi_auxiliary_properties_with_buffer_provider
+    ]
+);
+
+/// 🚧 \[Experimental\] An embedding level of the bidi algorithm, from 0 to 126. Even levels
+/// are left-to-right, and odd levels right-to-left.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct BidiLevel(u8);
+
+impl BidiLevel {
+    /// The left-to-right level 0.
+    pub const LTR: BidiLevel = BidiLevel(0);
+    /// The right-to-left level 1.
+    pub const RTL: BidiLevel = BidiLevel(1);
+
+    /// Returns the level `number`, if it is at most 126.
+    pub fn new(number: u8) -> Option<Self> {
+        if number <= algorithm::MAX_DEPTH + 1 {
+            Some(BidiLevel(number))
+        } else {
+            None
+        }
+    }
+
+    /// The number of this level.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Whether this level is left-to-right.
+    pub fn is_ltr(self) -> bool {
+        self.0 % 2 == 0
+    }
+
+    /// Whether this level is right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self.0 % 2 == 1
+    }
+}
+
+/// 🚧 \[Experimental\] The direction of a paragraph, which determines its embedding level.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ParagraphDirection {
+    /// Left-to-right, with embedding level 0.
+    LeftToRight,
+    /// Right-to-left, with embedding level 1.
+    RightToLeft,
+    /// The direction of the first strong character of the paragraph that is not in an
+    /// isolate, or left-to-right if there is none (rules P2 and P3).
+    Auto,
+}
+
+/// 🚧 \[Experimental\] An implementation of the Unicode Bidirectional Algorithm, [UAX #9],
+/// backed by ICU4X data.
+///
+/// [UAX #9]: https://www.unicode.org/reports/tr9/
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Clone, Copy)]
+pub struct BidiResolver<'a> {
+    bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+    auxiliary: BidiAuxiliaryPropertiesBorrowed<'a>,
+}
+
+impl<'a> BidiResolver<'a> {
+    /// Creates a [`BidiResolver`] from the Bidi_Class property and the
+    /// [`BidiAuxiliaryProperties`].
+    pub fn new(
+        bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+        auxiliary: BidiAuxiliaryPropertiesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            bidi_class,
+            auxiliary,
+        }
+    }
+
+    /// Splits `text` into paragraphs, and resolves the embedding levels of their characters
+    /// with the paragraph direction `direction`.
+    ///
+    /// A paragraph ends after a paragraph separator, like `'\n'`, or after `"\r\n"`.
+    #[allow(clippy::indexing_slicing)] // start < end <= chars.len()
+    pub fn resolve<'t>(self, text: &'t str, direction: ParagraphDirection) -> BidiInfo<'a, 't> {
+        let offsets = text.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+        let chars = text.chars().collect::<Vec<_>>();
+        let classes = chars
+            .iter()
+            .map(|&ch| self.bidi_class.get(ch))
+            .collect::<Vec<_>>();
+        let mut levels = Vec::with_capacity(chars.len());
+        let mut paragraphs = Vec::new();
+
+        let mut start = 0;
+        while start < chars.len() {
+            let mut end = classes
+                .iter()
+                .skip(start)
+                .position(|&class| class == BidiClass::ParagraphSeparator)
+                .map_or(chars.len(), |i| start + i + 1);
+            if chars.get(end - 1) == Some(&'\r') && chars.get(end) == Some(&'\n') {
+                end += 1;
+            }
+            let level = match direction {
+                ParagraphDirection::LeftToRight => Some(0),
+                ParagraphDirection::RightToLeft => Some(1),
+                ParagraphDirection::Auto => None,
+            };
+            let level = algorithm::resolve_paragraph(
+                self.auxiliary,
+                &chars[start..end],
+                &classes[start..end],
+                level,
+                &mut levels,
+            );
+            paragraphs.push(BidiParagraph {
+                range: offsets[start]..offsets.get(end).copied().unwrap_or(text.len()),
+                level: BidiLevel(level),
+            });
+            start = end;
+        }
+
+        BidiInfo {
+            text,
+            offsets,
+            classes,
+            levels: levels.into_iter().map(BidiLevel).collect(),
+            paragraphs,
+            auxiliary: self.auxiliary,
+        }
+    }
+}
+
+/// 🚧 \[Experimental\] A paragraph of a [`BidiInfo`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidiParagraph {
+    range: Range<usize>,
+    level: BidiLevel,
+}
+
+impl BidiParagraph {
+    /// The range of the paragraph in the text, in bytes. It includes its paragraph
+    /// separator.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The embedding level of the paragraph.
+    pub fn level(&self) -> BidiLevel {
+        self.level
+    }
+}
+
+/// 🚧 \[Experimental\] The embedding levels of a text, returned by [`BidiResolver::resolve()`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+pub struct BidiInfo<'a, 't> {
+    text: &'t str,
+    /// The offsets of the characters of the text.
+    offsets: Vec<usize>,
+    /// The Bidi_Class of the characters of the text.
+    classes: Vec<BidiClass>,
+    /// The embedding levels of the characters of the text, before the rule L1.
+    levels: Vec<BidiLevel>,
+    paragraphs: Vec<BidiParagraph>,
+    auxiliary: BidiAuxiliaryPropertiesBorrowed<'a>,
+}
+
+impl<'a, 't> BidiInfo<'a, 't> {
+    /// The text.
+    pub fn text(&self) -> &'t str {
+        self.text
+    }
+
+    /// The paragraphs of the text.
+    pub fn paragraphs(&self) -> &[BidiParagraph] {
+        &self.paragraphs
+    }
+
+    /// The resolved embedding levels of the characters of the text, one for each character.
+    ///
+    /// These are the levels before the rule L1, which depends on how the paragraphs are
+    /// broken into lines. The characters removed by the rule X9, like the explicit
+    /// embeddings, take the level of the preceding character.
+    pub fn levels(&self) -> &[BidiLevel] {
+        &self.levels
+    }
+
+    /// The embedding levels of the characters of the `line`, a range of the text in bytes,
+    /// after the rule L1: separators and whitespace at the end of the line take the
+    /// paragraph embedding level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries of the text.
+    #[allow(clippy::indexing_slicing)] // documented panic
+    pub fn line_levels(&self, line: Range<usize>) -> Vec<BidiLevel> {
+        let line = self.char_range(line);
+        let mut levels = self.levels[line.clone()].to_vec();
+        for paragraph in &self.paragraphs {
+            let range = self.char_range(paragraph.range());
+            let start = range.start.max(line.start);
+            let end = range.end.min(line.end);
+            if start < end {
+                algorithm::reset_whitespace_levels(
+                    &self.classes[start..end],
+                    paragraph.level,
+                    &mut levels[start - line.start..end - line.start],
+                );
+            }
+        }
+        levels
+    }
+
+    /// The offsets of the characters of the `line`, a range of the text in bytes, in visual
+    /// order from left to right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries of the text.
+    #[allow(clippy::indexing_slicing)] // documented panic
+    pub fn visual_order(&self, line: Range<usize>) -> Vec<usize> {
+        let start = self.char_range(line.clone()).start;
+        algorithm::visual_order(&self.line_levels(line))
+            .into_iter()
+            .map(|i| self.offsets[start + i])
+            .collect()
+    }
+
+    /// The text of the `line`, a range of the text in bytes, in visual order from left to
+    /// right, with the characters at right-to-left levels replaced by their
+    /// Bidi_Mirroring_Glyph (rule L4).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is not a range of character boundaries of the text.
+    #[allow(clippy::indexing_slicing)] // documented panic
+    pub fn reorder_line(&self, line: Range<usize>) -> String {
+        let levels = self.line_levels(line.clone());
+        let chars = self.text[line].chars().collect::<Vec<_>>();
+        algorithm::visual_order(&levels)
+            .into_iter()
+            .map(|i| {
+                let ch = chars[i];
+                if levels[i].is_rtl() {
+                    self.auxiliary.get_mirroring_glyph(ch).unwrap_or(ch)
+                } else {
+                    ch
+                }
+            })
+            .collect()
+    }
+
+    /// The range of the characters in the `range` of the text in bytes.
+    fn char_range(&self, range: Range<usize>) -> Range<usize> {
+        let index = |offset: usize| {
+            if offset == self.text.len() {
+                self.offsets.len()
+            } else {
+                #[allow(clippy::expect_used)] // documented panic
+                self.offsets
+                    .binary_search(&offset)
+                    .expect("The range should be on character boundaries")
+            }
+        };
+        index(range.start)..index(range.end)
+    }
+}
@@ -0,0 +1,503 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Lookup of the names of characters, and of characters by name.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! The names are the values of the Name property, like `LATIN SMALL LETTER E WITH ACUTE`, and
+//! of the Name_Alias property, like `BYTE ORDER MARK` for U+FEFF. The names of the Hangul
+//! syllables and of the ideographs that are named after their code point, like
+//! `CJK UNIFIED IDEOGRAPH-4E00`, are derived when they are looked up.
+//!
+//! Characters are looked up with the loose matching rule [UAX44-LM2] of the Unicode Character
+//! Database: case, whitespace, underscores and hyphens between letters or digits are ignored,
+//! except for the hyphen of `HANGUL JUNGSEONG O-E`.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::properties::char_names;
+//!
+//! let data = char_names::load_character_names_unstable(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//! let names = data.as_borrowed();
+//!
+//! assert_eq!(
+//!     names.name('é').map(|name| name.to_string()).as_deref(),
+//!     Some("LATIN SMALL LETTER E WITH ACUTE")
+//! );
+//! assert_eq!(
+//!     names.name('丁').map(|name| name.to_string()).as_deref(),
+//!     Some("CJK UNIFIED IDEOGRAPH-4E01")
+//! );
+//! assert!(names.name('\n').is_none());
+//!
+//! assert_eq!(names.char_for_name("Latin small letter e with acute"), Some('é'));
+//! assert_eq!(names.char_for_name("BYTE ORDER MARK"), Some('\u{FEFF}'));
+//! assert_eq!(names.char_for_name("line_feed"), Some('\n'));
+//! assert_eq!(names.char_for_name("hangul syllable gag"), Some('각'));
+//! ```
+//!
+//! [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+
+use crate::error::PropertiesError;
+use crate::provider::*;
+use core::fmt::{self, Write};
+use core::iter::Peekable;
+use icu_provider::prelude::*;
+use zerovec::vecs::Index32;
+use zerovec::VarZeroSlice;
+
+const HANGUL_SYLLABLE_PREFIX: &str = "HANGUL SYLLABLE ";
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = 19 * HANGUL_N_COUNT;
+
+/// The short names of the leading consonant jamo, from `Jamo.txt`.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+/// The short names of the vowel jamo, from `Jamo.txt`.
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+/// The short names of the trailing consonant jamo, from `Jamo.txt`.
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// The only character whose name has a medial hyphen that loose matching doesn't ignore.
+const HANGUL_JUNGSEONG_O_E: u32 = 0x1180;
+const HANGUL_JUNGSEONG_O_E_KEY: &str = "HANGULJUNGSEONGO-E";
+
+/// The characters of a name that the loose matching rule UAX44-LM2 doesn't ignore, in
+/// uppercase.
+struct LooseKey<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+    previous: Option<char>,
+    keep_hyphens: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for LooseKey<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            let c = self.chars.next()?;
+            let previous = self.previous.replace(c);
+            if c.is_whitespace() || c == '_' {
+                continue;
+            }
+            if c == '-'
+                && !self.keep_hyphens
+                && matches!(previous, Some(p) if p.is_ascii_alphanumeric())
+                && matches!(self.chars.peek(), Some(n) if n.is_ascii_alphanumeric())
+            {
+                continue;
+            }
+            return Some(c.to_ascii_uppercase());
+        }
+    }
+}
+
+fn loose_key<I: Iterator<Item = char>>(chars: I, keep_hyphens: bool) -> LooseKey<I> {
+    LooseKey {
+        chars: chars.peekable(),
+        previous: None,
+        keep_hyphens,
+    }
+}
+
+/// The indices of the tokens of an encoded name.
+fn token_indices(encoded: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    let mut bytes = encoded.iter().copied();
+    core::iter::from_fn(move || {
+        let first = bytes.next()?;
+        if first < 0x80 {
+            Some(usize::from(first))
+        } else {
+            Some(usize::from(first & 0x7F) << 8 | usize::from(bytes.next()?))
+        }
+    })
+}
+
+/// The characters of an encoded name.
+fn encoded_chars<'a>(
+    tokens: &'a VarZeroSlice<str, Index32>,
+    encoded: &'a [u8],
+) -> impl Iterator<Item = char> + 'a {
+    token_indices(encoded)
+        .enumerate()
+        .flat_map(move |(i, index)| {
+            let space = if i > 0 { Some(' ') } else { None };
+            space
+                .into_iter()
+                .chain(tokens.get(index).unwrap_or_default().chars())
+        })
+}
+
+/// The short names of the jamo of the Hangul syllable with the index `s`.
+fn hangul_jamo(s: u32) -> [&'static str; 3] {
+    let get = |names: &[&'static str], index: u32| -> &'static str {
+        names.get(index as usize).copied().unwrap_or_default()
+    };
+    [
+        get(&JAMO_L, s / HANGUL_N_COUNT),
+        get(&JAMO_V, (s % HANGUL_N_COUNT) / HANGUL_T_COUNT),
+        get(&JAMO_T, s % HANGUL_T_COUNT),
+    ]
+}
+
+/// The name of a character, returned by [`CharacterNamesBorrowed::name()`].
+///
+/// Use its [`Display`](fmt::Display) implementation to write it.
+#[derive(Clone, Copy, Debug)]
+pub struct CharacterName<'a> {
+    kind: NameKind<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NameKind<'a> {
+    Encoded {
+        tokens: &'a VarZeroSlice<str, Index32>,
+        encoded: &'a [u8],
+    },
+    CodePoint {
+        prefix: &'a str,
+        code_point: u32,
+    },
+    HangulSyllable(u32),
+}
+
+impl fmt::Display for CharacterName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            NameKind::Encoded { tokens, encoded } => {
+                for (i, index) in token_indices(encoded).enumerate() {
+                    if i > 0 {
+                        f.write_char(' ')?;
+                    }
+                    f.write_str(tokens.get(index).unwrap_or_default())?;
+                }
+                Ok(())
+            }
+            NameKind::CodePoint { prefix, code_point } => {
+                write!(f, "{}{:04X}", prefix, code_point)
+            }
+            NameKind::HangulSyllable(s) => {
+                f.write_str(HANGUL_SYLLABLE_PREFIX)?;
+                for jamo in hangul_jamo(s) {
+                    f.write_str(jamo)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A wrapper around the data of the names of characters.
+///
+/// Most useful methods are on [`CharacterNamesBorrowed`] obtained by calling
+/// [`CharacterNames::as_borrowed()`].
+pub struct CharacterNames {
+    data: DataPayload<CharacterNamesV1Marker>,
+}
+
+/// A borrowed wrapper around the data of the names of characters, returned by
+/// [`CharacterNames::as_borrowed()`]. More efficient to query.
+#[derive(Clone, Copy)]
+pub struct CharacterNamesBorrowed<'a> {
+    data: &'a CharacterNamesV1<'a>,
+}
+
+impl CharacterNames {
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (like `name()`) by
+    /// consolidating it up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> CharacterNamesBorrowed<'_> {
+        CharacterNamesBorrowed {
+            data: self.data.get(),
+        }
+    }
+
+    /// Construct a new one from loaded data
+    ///
+    /// Typically it is preferable to use getters like [`load_character_names_unstable()`] instead
+    pub fn from_data(data: DataPayload<CharacterNamesV1Marker>) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> CharacterNamesBorrowed<'a> {
+    /// The value of the Name property of `ch`, if it has one.
+    ///
+    /// Control characters, private use characters and unassigned code points have no name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::properties::char_names;
+    ///
+    /// let data = char_names::load_character_names_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let names = data.as_borrowed();
+    ///
+    /// let name = |ch| names.name(ch).map(|name| name.to_string());
+    ///
+    /// assert_eq!(name('-').as_deref(), Some("HYPHEN-MINUS"));
+    /// assert_eq!(name('각').as_deref(), Some("HANGUL SYLLABLE GAG"));
+    /// assert_eq!(name('\u{2F800}').as_deref(), Some("CJK COMPATIBILITY IDEOGRAPH-2F800"));
+    /// assert_eq!(name('\u{E000}'), None);
+    /// ```
+    pub fn name(self, ch: char) -> Option<CharacterName<'a>> {
+        let code_point = ch as u32;
+        let s = code_point.wrapping_sub(HANGUL_S_BASE);
+        let kind = if s < HANGUL_S_COUNT {
+            NameKind::HangulSyllable(s)
+        } else if let Some(prefix) = self.code_point_prefix(code_point) {
+            NameKind::CodePoint { prefix, code_point }
+        } else {
+            let index = self.data.code_points.binary_search(&code_point).ok()?;
+            NameKind::Encoded {
+                tokens: &self.data.tokens,
+                encoded: self.data.names.get(index)?,
+            }
+        };
+        Some(CharacterName { kind })
+    }
+
+    /// The values of the Name_Alias property of `ch`, in the order of `NameAliases.txt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::properties::char_names;
+    ///
+    /// let data = char_names::load_character_names_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let names = data.as_borrowed();
+    ///
+    /// assert_eq!(
+    ///     names.name_aliases('\n').map(|name| name.to_string()).collect::<Vec<_>>(),
+    ///     ["LINE FEED", "NEW LINE", "END OF LINE", "LF", "NL", "EOL"]
+    /// );
+    /// assert_eq!(names.name_aliases('a').count(), 0);
+    /// ```
+    pub fn name_aliases(self, ch: char) -> impl Iterator<Item = CharacterName<'a>> + 'a {
+        let code_point = ch as u32;
+        let data = self.data;
+        data.alias_code_points
+            .iter()
+            .zip(data.aliases.iter())
+            .skip_while(move |&(c, _)| c < code_point)
+            .take_while(move |&(c, _)| c == code_point)
+            .map(move |(_, encoded)| CharacterName {
+                kind: NameKind::Encoded {
+                    tokens: &data.tokens,
+                    encoded,
+                },
+            })
+    }
+
+    /// The character whose name or alias is `name`, with the loose matching rule
+    /// [UAX44-LM2].
+    ///
+    /// [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::properties::char_names;
+    ///
+    /// let data = char_names::load_character_names_unstable(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let names = data.as_borrowed();
+    ///
+    /// assert_eq!(names.char_for_name("hyphen minus"), Some('-'));
+    /// assert_eq!(names.char_for_name("NULL"), Some('\0'));
+    /// assert_eq!(names.char_for_name("cjk unified ideograph-4e00"), Some('一'));
+    /// assert_eq!(names.char_for_name("HANGUL JUNGSEONG OE"), Some('\u{116C}'));
+    /// assert_eq!(names.char_for_name("HANGUL JUNGSEONG O-E"), Some('\u{1180}'));
+    /// assert_eq!(names.char_for_name("LATIN SMALL LETTER KLINGON"), None);
+    /// ```
+    pub fn char_for_name(self, name: &str) -> Option<char> {
+        let code_point = self
+            .encoded_code_point(name)
+            .or_else(|| Self::hangul_syllable_code_point(name))
+            .or_else(|| self.prefixed_code_point(name))?;
+        char::from_u32(code_point)
+    }
+
+    /// The prefix of the name of `code_point`, if its name ends in its code point.
+    fn code_point_prefix(self, code_point: u32) -> Option<&'a str> {
+        let index = self
+            .data
+            .code_point_ranges
+            .iter()
+            .position(|(start, end)| start <= code_point && code_point <= end)?;
+        self.data.code_point_prefixes.get(index)
+    }
+
+    /// The code point and the encoded name or alias at `index` in `loose_order`.
+    fn encoded_entry(self, index: u32) -> Option<(u32, &'a [u8])> {
+        let index = index as usize;
+        let names_len = self.data.names.len();
+        if index < names_len {
+            Some((
+                self.data.code_points.get(index)?,
+                self.data.names.get(index)?,
+            ))
+        } else {
+            Some((
+                self.data.alias_code_points.get(index - names_len)?,
+                self.data.aliases.get(index - names_len)?,
+            ))
+        }
+    }
+
+    fn encoded_code_point(self, name: &str) -> Option<u32> {
+        let keep_hyphens = loose_key(name.chars(), true).eq(HANGUL_JUNGSEONG_O_E_KEY.chars());
+        let position = self
+            .data
+            .loose_order
+            .binary_search_by(|index| match self.encoded_entry(index) {
+                Some((code_point, encoded)) => loose_key(
+                    encoded_chars(&self.data.tokens, encoded),
+                    code_point == HANGUL_JUNGSEONG_O_E,
+                )
+                .cmp(loose_key(name.chars(), keep_hyphens)),
+                None => core::cmp::Ordering::Less,
+            })
+            .ok()?;
+        let (code_point, _) = self.encoded_entry(self.data.loose_order.get(position)?)?;
+        Some(code_point)
+    }
+
+    fn hangul_syllable_code_point(name: &str) -> Option<u32> {
+        let mut key = loose_key(name.chars(), false);
+        for expected in loose_key(HANGUL_SYLLABLE_PREFIX.chars(), false) {
+            if key.next()? != expected {
+                return None;
+            }
+        }
+        // The longest syllable names have seven characters after the prefix, like "GGWAELG".
+        let mut jamo = [0u8; 8];
+        let mut len = 0;
+        for c in key {
+            if !c.is_ascii() {
+                return None;
+            }
+            *jamo.get_mut(len)? = c as u8;
+            len += 1;
+        }
+        let jamo = jamo.get(..len)?;
+        for (l, l_name) in JAMO_L.iter().enumerate() {
+            let after_l = match jamo.strip_prefix(l_name.as_bytes()) {
+                Some(after_l) => after_l,
+                None => continue,
+            };
+            for (v, v_name) in JAMO_V.iter().enumerate() {
+                let after_v = match after_l.strip_prefix(v_name.as_bytes()) {
+                    Some(after_v) => after_v,
+                    None => continue,
+                };
+                if let Some(t) = JAMO_T
+                    .iter()
+                    .position(|t_name| t_name.as_bytes() == after_v)
+                {
+                    let s = (l as u32 * HANGUL_V_COUNT + v as u32) * HANGUL_T_COUNT + t as u32;
+                    return Some(HANGUL_S_BASE + s);
+                }
+            }
+        }
+        None
+    }
+
+    fn prefixed_code_point(self, name: &str) -> Option<u32> {
+        let ranges = self.data.code_point_ranges.iter();
+        let prefixes = self.data.code_point_prefixes.iter();
+        ranges.zip(prefixes).find_map(|((start, end), prefix)| {
+            let mut key = loose_key(name.chars(), false);
+            // The hyphen at the end of the prefix is medial in a name.
+            for expected in loose_key(prefix.trim_end_matches('-').chars(), false) {
+                if key.next()? != expected {
+                    return None;
+                }
+            }
+            let mut code_point = 0u32;
+            let mut digits = 0;
+            for c in key {
+                code_point = code_point.checked_mul(16)?.checked_add(c.to_digit(16)?)?;
+                digits += 1;
+            }
+            // The code point has no leading zeros beyond four digits.
+            let expected_digits = match code_point {
+                0..=0xFFFF => 4,
+                0x10000..=0xFFFFF => 5,
+                _ => 6,
+            };
+            if digits == expected_digits && start <= code_point && code_point <= end {
+                Some(code_point)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Returns a [`CharacterNames`] for looking up the names of characters, and characters by name.
+///
+/// [📚 Help choosing a constructor](icu_provider::constructors)
+/// <div class="stab unstable">
+/// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu::properties::char_names;
+///
+/// let data = char_names::load_character_names_unstable(&icu_testdata::unstable())
+///     .expect("The data should be valid");
+/// let names = data.as_borrowed();
+///
+/// let ch = names.char_for_name("SNOWMAN").expect("The name should be valid");
+/// assert_eq!(ch, '☃');
+/// assert_eq!(
+///     format!("U+{:04X} {}", ch as u32, names.name(ch).expect("A name")),
+///     "U+2603 SNOWMAN"
+/// );
+/// ```
+pub fn load_character_names_unstable(
+    provider: &(impl DataProvider<CharacterNamesV1Marker> + ?Sized),
+) -> Result<CharacterNames, PropertiesError> {
+    Ok(CharacterNames::from_data(
+        provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)?,
+    ))
+}
+
+icu_provider::gen_any_buffer_constructors!(
+    locale: skip,
+    options: skip,
+    result: Result<CharacterNames, PropertiesError>,
+    functions: [
+        load_character_names_unstable,
+        load_character_names_with_any_provider,
+        load_character_names_with_buffer_provider
+    ]
+);
@@ -28,6 +28,9 @@ pub enum PropertiesError {
     /// An unknown value was used for the [`GeneralCategoryGroup`](crate::GeneralCategoryGroup) property
     #[displaydoc("Unknown general category group: {0}")]
     UnknownGeneralCategoryGroup(u32),
+    /// An unknown name was used for a binary property
+    #[displaydoc("Unknown property name")]
+    UnknownPropertyName,
 }
 
 impl From<DataError> for PropertiesError {
@@ -0,0 +1,501 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Validation of identifiers and classification of pattern syntax
+//! characters, following [UAX #31](https://www.unicode.org/reports/tr31/).
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! The [`IdentifierValidator`] checks strings against the Default Identifier Syntax of
+//! UAX31-D1, customized with an [`IdentifierProfile`], and tells the characters that a
+//! lexer should treat as syntax or white space in patterns.
+
+use crate::error::PropertiesError;
+use crate::maps::{self, CodePointMapData};
+use crate::props::{
+    CanonicalCombiningClass, GeneralCategory, GeneralCategoryGroup, IndicSyllabicCategory,
+    JoiningType,
+};
+use crate::provider::*;
+use crate::sets::{self, CodePointSetData};
+use alloc::vec::Vec;
+use displaydoc::Display;
+use icu_provider::prelude::*;
+
+/// U+200C ZERO WIDTH NON-JOINER
+const ZWNJ: char = '\u{200C}';
+/// U+200D ZERO WIDTH JOINER
+const ZWJ: char = '\u{200D}';
+
+#[cfg(feature = "std")]
+impl std::error::Error for IdentifierError {}
+
+/// The reasons for which a string is not a valid identifier, with the byte offset of the
+/// offending character.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdentifierError {
+    /// The string is empty.
+    #[displaydoc("The identifier is empty")]
+    Empty,
+    /// The first character cannot start an identifier.
+    #[displaydoc("{character:?} at {position} cannot start an identifier")]
+    InvalidStart {
+        /// The byte offset of the character.
+        position: usize,
+        /// The character.
+        character: char,
+    },
+    /// A character cannot appear in an identifier.
+    #[displaydoc("{character:?} at {position} cannot appear in an identifier")]
+    InvalidCharacter {
+        /// The byte offset of the character.
+        position: usize,
+        /// The character.
+        character: char,
+    },
+    /// A medial character is at the end of the identifier, or is followed by another
+    /// medial character.
+    #[displaydoc("{character:?} at {position} must be followed by a non-medial character")]
+    MisplacedMedial {
+        /// The byte offset of the medial character.
+        position: usize,
+        /// The medial character.
+        character: char,
+    },
+    /// A ZERO WIDTH JOINER or ZERO WIDTH NON-JOINER is not in one of the contexts that
+    /// allow it.
+    #[displaydoc("{character:?} at {position} is not in a context that allows it")]
+    InvalidJoinControl {
+        /// The byte offset of the join control.
+        position: usize,
+        /// The join control.
+        character: char,
+    },
+}
+
+impl IdentifierError {
+    /// The byte offset in the string of the character that makes it invalid, `0` for an
+    /// empty string.
+    pub fn position(&self) -> usize {
+        match *self {
+            IdentifierError::Empty => 0,
+            IdentifierError::InvalidStart { position, .. }
+            | IdentifierError::InvalidCharacter { position, .. }
+            | IdentifierError::MisplacedMedial { position, .. }
+            | IdentifierError::InvalidJoinControl { position, .. } => position,
+        }
+    }
+}
+
+/// How an [`IdentifierValidator`] treats U+200C ZERO WIDTH NON-JOINER and U+200D ZERO
+/// WIDTH JOINER, which are not in the Default Identifier Syntax.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JoinControls {
+    /// The join controls are not allowed in identifiers.
+    Disallowed,
+    /// The join controls are allowed in the contexts of UAX31-R1a: ZERO WIDTH NON-JOINER
+    /// between characters that join (A1) or after a virama (A2), and ZERO WIDTH JOINER
+    /// after a virama, not followed by a dependent vowel (B).
+    Contextual,
+}
+
+/// A profile of the Default Identifier Syntax of UAX31-D1:
+///
+/// ```text
+/// <Identifier> := <Start> <Continue>* (<Medial> <Continue>+)*
+/// ```
+///
+/// The default profile uses ID_Start and ID_Continue, with no medial characters and no
+/// join controls.
+///
+/// # Examples
+///
+/// ```
+/// use icu_properties::identifier::{IdentifierProfile, JoinControls};
+///
+/// // The identifiers of a language that allows `_` and `$` to start them, and `-`
+/// // between their parts.
+/// let mut profile = IdentifierProfile::default();
+/// profile.nfkc_closed = true;
+/// profile.additional_start = vec!['_', '$'];
+/// profile.medial = vec!['-'];
+/// profile.join_controls = JoinControls::Contextual;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IdentifierProfile {
+    /// Whether to use XID_Start and XID_Continue, which are closed under NFKC, instead of
+    /// ID_Start and ID_Continue.
+    pub nfkc_closed: bool,
+    /// Characters to allow at the start of an identifier, and after it, in addition to
+    /// Start characters.
+    pub additional_start: Vec<char>,
+    /// Characters to allow after the start of an identifier, in addition to Continue
+    /// characters.
+    pub additional_continue: Vec<char>,
+    /// The Medial characters, which are allowed between Continue characters. Medial
+    /// characters that are also Continue characters are treated as Continue characters.
+    pub medial: Vec<char>,
+    /// How to treat ZERO WIDTH NON-JOINER and ZERO WIDTH JOINER.
+    pub join_controls: JoinControls,
+}
+
+impl Default for IdentifierProfile {
+    fn default() -> Self {
+        Self {
+            nfkc_closed: false,
+            additional_start: Vec::new(),
+            additional_continue: Vec::new(),
+            medial: Vec::new(),
+            join_controls: JoinControls::Disallowed,
+        }
+    }
+}
+
+/// Validates identifiers against a profile of the Default Identifier Syntax of
+/// [UAX #31](https://www.unicode.org/reports/tr31/), and classifies the characters of
+/// patterns.
+///
+/// # Examples
+///
+/// ```
+/// use icu_properties::identifier::{
+///     IdentifierError, IdentifierProfile, IdentifierValidator,
+/// };
+///
+/// let mut profile = IdentifierProfile::default();
+/// profile.additional_start = vec!['_'];
+/// profile.medial = vec!['-'];
+/// let validator =
+///     IdentifierValidator::try_new_unstable(&icu_testdata::unstable(), profile)
+///         .expect("The data should be valid");
+///
+/// assert_eq!(validator.validate("_naïve-parser2"), Ok(()));
+/// assert_eq!(validator.validate("変数"), Ok(()));
+///
+/// assert_eq!(
+///     validator.validate("2nd"),
+///     Err(IdentifierError::InvalidStart {
+///         position: 0,
+///         character: '2'
+///     })
+/// );
+/// assert_eq!(
+///     validator.validate("a--b"),
+///     Err(IdentifierError::MisplacedMedial {
+///         position: 1,
+///         character: '-'
+///     })
+/// );
+/// assert_eq!(
+///     validator.validate("price€").map_err(|e| e.position()),
+///     Err(5)
+/// );
+/// ```
+pub struct IdentifierValidator {
+    start: CodePointSetData,
+    continue_: CodePointSetData,
+    pattern_syntax: CodePointSetData,
+    pattern_white_space: CodePointSetData,
+    general_category: CodePointMapData<GeneralCategory>,
+    canonical_combining_class: CodePointMapData<CanonicalCombiningClass>,
+    joining_type: CodePointMapData<JoiningType>,
+    indic_syllabic_category: CodePointMapData<IndicSyllabicCategory>,
+    additional_start: Vec<char>,
+    additional_continue: Vec<char>,
+    medial: Vec<char>,
+    join_controls: JoinControls,
+}
+
+impl IdentifierValidator {
+    /// Creates an [`IdentifierValidator`] for a profile.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        profile: IdentifierProfile,
+    ) -> Result<Self, PropertiesError>
+    where
+        D: DataProvider<IdStartV1Marker>
+            + DataProvider<IdContinueV1Marker>
+            + DataProvider<XidStartV1Marker>
+            + DataProvider<XidContinueV1Marker>
+            + DataProvider<PatternSyntaxV1Marker>
+            + DataProvider<PatternWhiteSpaceV1Marker>
+            + DataProvider<GeneralCategoryV1Marker>
+            + DataProvider<CanonicalCombiningClassV1Marker>
+            + DataProvider<JoiningTypeV1Marker>
+            + DataProvider<IndicSyllabicCategoryV1Marker>
+            + ?Sized,
+    {
+        let (start, continue_) = if profile.nfkc_closed {
+            (
+                sets::load_xid_start(provider)?,
+                sets::load_xid_continue(provider)?,
+            )
+        } else {
+            (
+                sets::load_id_start(provider)?,
+                sets::load_id_continue(provider)?,
+            )
+        };
+        let sorted = |mut chars: Vec<char>| {
+            chars.sort_unstable();
+            chars.dedup();
+            chars
+        };
+        Ok(Self {
+            start,
+            continue_,
+            pattern_syntax: sets::load_pattern_syntax(provider)?,
+            pattern_white_space: sets::load_pattern_white_space(provider)?,
+            general_category: maps::load_general_category(provider)?,
+            canonical_combining_class: maps::load_canonical_combining_class(provider)?,
+            joining_type: maps::load_joining_type(provider)?,
+            indic_syllabic_category: maps::load_indic_syllabic_category(provider)?,
+            additional_start: sorted(profile.additional_start),
+            additional_continue: sorted(profile.additional_continue),
+            medial: sorted(profile.medial),
+            join_controls: profile.join_controls,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: skip,
+        profile: IdentifierProfile,
+        error: PropertiesError
+    );
+
+    /// Checks that a string is an identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::identifier::{
+    ///     IdentifierError, IdentifierProfile, IdentifierValidator, JoinControls,
+    /// };
+    ///
+    /// let mut profile = IdentifierProfile::default();
+    /// profile.join_controls = JoinControls::Contextual;
+    /// let validator =
+    ///     IdentifierValidator::try_new_unstable(&icu_testdata::unstable(), profile)
+    ///         .expect("The data should be valid");
+    ///
+    /// // A ZERO WIDTH NON-JOINER between a dual-joining HEH and a right-joining ALEF
+    /// assert_eq!(validator.validate("نامه\u{200C}ای"), Ok(()));
+    /// // A ZERO WIDTH JOINER after a virama
+    /// assert_eq!(validator.validate("क्\u{200D}ष"), Ok(()));
+    /// assert_eq!(
+    ///     validator.validate("a\u{200D}b"),
+    ///     Err(IdentifierError::InvalidJoinControl {
+    ///         position: 1,
+    ///         character: '\u{200D}'
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self, identifier: &str) -> Result<(), IdentifierError> {
+        let mut chars = identifier.char_indices();
+        match chars.next() {
+            None => return Err(IdentifierError::Empty),
+            Some((_, character)) if !self.is_start(character) => {
+                return Err(IdentifierError::InvalidStart {
+                    position: 0,
+                    character,
+                })
+            }
+            Some(_) => (),
+        }
+
+        // The last medial character, while it is not followed by a Continue character.
+        let mut pending_medial = None;
+        for (position, character) in chars {
+            if self.is_continue(character) {
+                pending_medial = None;
+            } else if (character == ZWNJ || character == ZWJ)
+                && self.join_controls == JoinControls::Contextual
+            {
+                if !self.is_join_control_allowed(identifier, position, character) {
+                    return Err(IdentifierError::InvalidJoinControl {
+                        position,
+                        character,
+                    });
+                }
+                pending_medial = None;
+            } else if self.is_medial(character) {
+                if let Some((position, character)) = pending_medial {
+                    return Err(IdentifierError::MisplacedMedial {
+                        position,
+                        character,
+                    });
+                }
+                pending_medial = Some((position, character));
+            } else {
+                return Err(IdentifierError::InvalidCharacter {
+                    position,
+                    character,
+                });
+            }
+        }
+        match pending_medial {
+            Some((position, character)) => Err(IdentifierError::MisplacedMedial {
+                position,
+                character,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether a character can start an identifier: it is ID_Start (or XID_Start if the
+    /// profile is closed under NFKC), or one of the additional Start characters of the profile.
+    pub fn is_start(&self, c: char) -> bool {
+        self.start.as_borrowed().contains(c) || self.additional_start.binary_search(&c).is_ok()
+    }
+
+    /// Whether a character can follow the start of an identifier: it is ID_Continue (or
+    /// XID_Continue if the profile is closed under NFKC), or one of the additional Start or
+    /// Continue characters of the profile.
+    ///
+    /// Join controls and medial characters are only allowed in some positions, so they are
+    /// not Continue characters.
+    pub fn is_continue(&self, c: char) -> bool {
+        self.continue_.as_borrowed().contains(c)
+            || self.additional_continue.binary_search(&c).is_ok()
+            || self.additional_start.binary_search(&c).is_ok()
+    }
+
+    /// Whether a character is one of the Medial characters of the profile.
+    pub fn is_medial(&self, c: char) -> bool {
+        self.medial.binary_search(&c).is_ok()
+    }
+
+    /// Whether a character is Pattern_Syntax: a character that patterns, like regular
+    /// expressions or the syntax of a programming language, can use as syntax, and that
+    /// never appears in default identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::identifier::{IdentifierProfile, IdentifierValidator};
+    ///
+    /// let validator = IdentifierValidator::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     IdentifierProfile::default(),
+    /// )
+    /// .expect("The data should be valid");
+    ///
+    /// assert!(validator.is_pattern_syntax('{'));
+    /// assert!(validator.is_pattern_syntax('→')); // U+2192 RIGHTWARDS ARROW
+    /// assert!(!validator.is_pattern_syntax('_'));
+    /// assert!(!validator.is_pattern_syntax('€')); // not reserved as syntax
+    /// ```
+    pub fn is_pattern_syntax(&self, c: char) -> bool {
+        self.pattern_syntax.as_borrowed().contains(c)
+    }
+
+    /// Whether a character is Pattern_White_Space: a character that patterns should treat
+    /// as white space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_properties::identifier::{IdentifierProfile, IdentifierValidator};
+    ///
+    /// let validator = IdentifierValidator::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     IdentifierProfile::default(),
+    /// )
+    /// .expect("The data should be valid");
+    ///
+    /// assert!(validator.is_pattern_white_space('\t'));
+    /// assert!(validator.is_pattern_white_space('\u{2028}')); // LINE SEPARATOR
+    /// assert!(!validator.is_pattern_white_space('\u{3000}')); // IDEOGRAPHIC SPACE
+    /// ```
+    pub fn is_pattern_white_space(&self, c: char) -> bool {
+        self.pattern_white_space.as_borrowed().contains(c)
+    }
+
+    /// Whether the join control at `position` is in one of the contexts of UAX31-R1a.
+    fn is_join_control_allowed(
+        &self,
+        identifier: &str,
+        position: usize,
+        join_control: char,
+    ) -> bool {
+        let (before, after) = match (
+            identifier.get(..position),
+            identifier.get(position + join_control.len_utf8()..),
+        ) {
+            (Some(before), Some(after)) => (before, after),
+            _ => return false,
+        };
+        if join_control == ZWNJ {
+            // A1: /$LJ $T* ZWNJ $T* $RJ/
+            let joining_type = self.joining_type.as_borrowed();
+            let is_transparent = |c: &char| joining_type.get(*c) == JoiningType::Transparent;
+            let joins_left = matches!(
+                before
+                    .chars()
+                    .rev()
+                    .find(|c| !is_transparent(c))
+                    .map(|c| joining_type.get(c)),
+                Some(JoiningType::DualJoining | JoiningType::LeftJoining)
+            );
+            let joins_right = matches!(
+                after
+                    .chars()
+                    .find(|c| !is_transparent(c))
+                    .map(|c| joining_type.get(c)),
+                Some(JoiningType::DualJoining | JoiningType::RightJoining)
+            );
+            // A2: /$L $M* $V $M₁* ZWNJ/
+            (joins_left && joins_right) || self.follows_virama(before)
+        } else {
+            // B: /$L $M* $V $M₁* ZWJ (?!$D)/
+            self.follows_virama(before)
+                && !matches!(
+                    after.chars().next(),
+                    Some(c) if self.indic_syllabic_category.as_borrowed().get(c)
+                        == IndicSyllabicCategory::VowelDependent
+                )
+        }
+    }
+
+    /// Whether a string ends with `$L $M* $V $M₁*`, where `$L` is a letter, `$M` a
+    /// nonspacing mark, `$V` a virama, and `$M₁` a nonspacing mark with a nonzero canonical
+    /// combining class.
+    fn follows_virama(&self, before: &str) -> bool {
+        let general_category = self.general_category.as_borrowed();
+        let canonical_combining_class = self.canonical_combining_class.as_borrowed();
+        let mut chars = before.chars().rev();
+        while let Some(c) = chars.next() {
+            if canonical_combining_class.get(c) == CanonicalCombiningClass::Virama {
+                let mut marks_and_letter = chars
+                    .clone()
+                    .skip_while(|&c| general_category.get(c) == GeneralCategory::NonspacingMark);
+                if matches!(
+                    marks_and_letter.next(),
+                    Some(c) if GeneralCategoryGroup::Letter.contains(general_category.get(c))
+                ) {
+                    return true;
+                }
+            }
+            // Viramas are nonspacing marks too, so the virama may be further back.
+            if general_category.get(c) != GeneralCategory::NonspacingMark
+                || canonical_combining_class.get(c) == CanonicalCombiningClass::NotReordered
+            {
+                return false;
+            }
+        }
+        false
+    }
+}
@@ -9,11 +9,23 @@
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //!
 //! APIs that return a [`CodePointSetData`] exist for binary properties and certain enumerated
-//! properties. See the [`sets`] module for more details.
+//! properties. See the [`sets`] module for more details. Sets that also contain strings are
+//! represented by a [`UnicodeSetData`].
 //!
 //! APIs that return a [`CodePointMapData`] exist for certain enumerated properties. See the
 //! [`maps`] module for more details.
 //!
+//! 🚧 \[Experimental\] With the `experimental` Cargo feature:
+//! - The values of enumerated properties can be looked up by name, and the binary properties
+//!   loaded by name. See the [`names`] module for more details.
+//! - The names of characters, and the characters with a given name, can be looked up with the
+//!   [`char_names`] module.
+//! - Text can be split into runs of a single script with the [`ScriptRunSegmenter`] of the
+//!   [`script`] module.
+//! - Identifiers can be validated following UAX #31 with the [`IdentifierValidator`] of the
+//!   [`identifier`] module.
+//! - The bidi algorithm is implemented by the [`BidiResolver`] of the [`bidi`] module.
+//!
 //! # Examples
 //!
 //! ## Property data as `CodePointSetData`s
@@ -58,8 +70,17 @@
 //! [`ICU4X`]: ../icu/index.html
 //! [Unicode Properties]: https://unicode-org.github.io/icu/userguide/strings/properties.html
 //! [`CodePointSetData`]: crate::sets::CodePointSetData
+//! [`UnicodeSetData`]: crate::sets::UnicodeSetData
 //! [`CodePointMapData`]: crate::maps::CodePointMapData
 //! [`sets`]: crate::sets
+//! [`names`]: crate::names
+//! [`char_names`]: crate::char_names
+//! [`ScriptRunSegmenter`]: crate::script::ScriptRunSegmenter
+//! [`script`]: crate::script
+//! [`IdentifierValidator`]: crate::identifier::IdentifierValidator
+//! [`identifier`]: crate::identifier
+//! [`BidiResolver`]: crate::bidi::BidiResolver
+//! [`bidi`]: crate::bidi
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -77,11 +98,18 @@
 )]
 #![warn(missing_docs)]
 
-#[cfg(feature = "bidi")]
-pub mod bidi;
+extern crate alloc;
 
+#[cfg(any(feature = "bidi", feature = "experimental"))]
+pub mod bidi;
+#[cfg(feature = "experimental")]
+pub mod char_names;
 mod error;
+#[cfg(feature = "experimental")]
+pub mod identifier;
 pub mod maps;
+#[cfg(feature = "experimental")]
+pub mod names;
 
 // NOTE: The Pernosco debugger has special knowledge
 // of the `CanonicalCombiningClass` struct inside the `props`
@@ -96,8 +124,10 @@ pub mod sets;
 mod trievalue;
 
 pub use props::{
-    BidiClass, CanonicalCombiningClass, EastAsianWidth, GeneralCategory, GeneralCategoryGroup,
-    GraphemeClusterBreak, LineBreak, Script, SentenceBreak, WordBreak,
+    Age, BidiClass, CanonicalCombiningClass, CodePointValue, EastAsianWidth, GeneralCategory,
+    GeneralCategoryGroup, GraphemeClusterBreak, HangulSyllableType, IndicSyllabicCategory,
+    JoiningType, LineBreak, NumericType, NumericValue, Script, SentenceBreak, VerticalOrientation,
+    WordBreak,
 };
 
 pub use error::PropertiesError;
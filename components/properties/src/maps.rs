@@ -492,6 +492,32 @@ make_map_property! {
     pub fn load_sentence_break();
 }
 
+make_map_property! {
+    property: "Joining_Type";
+    marker: JoiningTypeProperty;
+    value: crate::JoiningType;
+    keyed_data_marker: JoiningTypeV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Joining_Type Unicode enumerated
+    /// property. See [`JoiningType`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, JoiningType};
+    ///
+    /// let data = maps::load_joining_type(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let jt = data.as_borrowed();;
+    ///
+    /// assert_eq!(jt.get('ب'), JoiningType::DualJoining); // U+0628: Arabic Letter Beh
+    /// assert_eq!(jt.get('ا'), JoiningType::RightJoining); // U+0627: Arabic Letter Alef
+    /// assert_eq!(jt.get('\u{200D}'), JoiningType::JoinCausing); // U+200D: Zero Width Joiner
+    /// ```
+    ///
+    /// [`CodePointTrie`]: icu_collections::codepointtrie::CodePointTrie
+    pub fn load_joining_type();
+}
+
 make_map_property! {
     property: "Canonical_Combining_Class";
     marker: CanonicalCombiningClassProperty;
@@ -519,3 +545,204 @@ make_map_property! {
     /// [`CodePointTrie`]: icu_collections::codepointtrie::CodePointTrie
     pub fn load_canonical_combining_class();
 }
+
+make_map_property! {
+    property: "Numeric_Type";
+    marker: NumericTypeProperty;
+    value: crate::NumericType;
+    keyed_data_marker: NumericTypeV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Numeric_Type Unicode enumerated property. See
+    /// [`NumericType`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, NumericType};
+    ///
+    /// let data = maps::load_numeric_type(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let nt = data.as_borrowed();
+    ///
+    /// assert_eq!(nt.get('5'), NumericType::Decimal); // U+0035: Digit Five
+    /// assert_eq!(nt.get('²'), NumericType::Digit); // U+00B2: Superscript Two
+    /// assert_eq!(nt.get('½'), NumericType::Numeric); // U+00BD: Vulgar Fraction One Half
+    /// assert_eq!(nt.get('a'), NumericType::None); // U+0061: Latin Small Letter A
+    /// ```
+    pub fn load_numeric_type();
+}
+
+make_map_property! {
+    property: "Numeric_Value";
+    marker: NumericValueProperty;
+    value: crate::NumericValue;
+    keyed_data_marker: NumericValueV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Numeric_Value Unicode property. See
+    /// [`NumericValue`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::maps;
+    ///
+    /// let data = maps::load_numeric_value(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let nv = data.as_borrowed();
+    ///
+    /// assert_eq!(nv.get('5').to_rational(), Some((5, 1))); // U+0035: Digit Five
+    /// assert_eq!(nv.get('½').to_rational(), Some((1, 2))); // U+00BD: Vulgar Fraction One Half
+    /// assert_eq!(nv.get('万').to_rational(), Some((10_000, 1))); // U+4E07: CJK Unified Ideograph-4E07
+    /// assert_eq!(nv.get('a').to_rational(), None); // U+0061: Latin Small Letter A
+    /// ```
+    pub fn load_numeric_value();
+}
+
+make_map_property! {
+    property: "Indic_Syllabic_Category";
+    marker: IndicSyllabicCategoryProperty;
+    value: crate::IndicSyllabicCategory;
+    keyed_data_marker: IndicSyllabicCategoryV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Indic_Syllabic_Category Unicode enumerated
+    /// property. See [`IndicSyllabicCategory`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, IndicSyllabicCategory};
+    ///
+    /// let data = maps::load_indic_syllabic_category(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let insc = data.as_borrowed();
+    ///
+    /// assert_eq!(insc.get('क'), IndicSyllabicCategory::Consonant); // U+0915: Devanagari Letter Ka
+    /// assert_eq!(insc.get('\u{094D}'), IndicSyllabicCategory::Virama); // U+094D: Devanagari Sign Virama
+    /// assert_eq!(insc.get('a'), IndicSyllabicCategory::Other); // U+0061: Latin Small Letter A
+    /// ```
+    pub fn load_indic_syllabic_category();
+}
+
+make_map_property! {
+    property: "Hangul_Syllable_Type";
+    marker: HangulSyllableTypeProperty;
+    value: crate::HangulSyllableType;
+    keyed_data_marker: HangulSyllableTypeV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Hangul_Syllable_Type Unicode enumerated
+    /// property. See [`HangulSyllableType`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, HangulSyllableType};
+    ///
+    /// let data = maps::load_hangul_syllable_type(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let hst = data.as_borrowed();
+    ///
+    /// assert_eq!(hst.get('ᄀ'), HangulSyllableType::LeadingJamo); // U+1100: Hangul Choseong Kiyeok
+    /// assert_eq!(hst.get('가'), HangulSyllableType::LeadingVowelSyllable); // U+AC00: Hangul Syllable Ga
+    /// assert_eq!(hst.get('각'), HangulSyllableType::LeadingVowelTrailingSyllable); // U+AC01: Hangul Syllable Gag
+    /// assert_eq!(hst.get('a'), HangulSyllableType::NotApplicable); // U+0061: Latin Small Letter A
+    /// ```
+    pub fn load_hangul_syllable_type();
+}
+
+make_map_property! {
+    property: "Vertical_Orientation";
+    marker: VerticalOrientationProperty;
+    value: crate::VerticalOrientation;
+    keyed_data_marker: VerticalOrientationV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Vertical_Orientation Unicode enumerated
+    /// property. See [`VerticalOrientation`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, VerticalOrientation};
+    ///
+    /// let data = maps::load_vertical_orientation(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let vo = data.as_borrowed();
+    ///
+    /// assert_eq!(vo.get('a'), VerticalOrientation::Rotated); // U+0061: Latin Small Letter A
+    /// assert_eq!(vo.get('木'), VerticalOrientation::Upright); // U+6728
+    /// assert_eq!(vo.get('。'), VerticalOrientation::TransformedUpright); // U+3002: Ideographic Full Stop
+    /// ```
+    pub fn load_vertical_orientation();
+}
+
+make_map_property! {
+    property: "Age";
+    marker: AgeProperty;
+    value: crate::Age;
+    keyed_data_marker: AgeV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Age Unicode property. See [`Age`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, Age};
+    ///
+    /// let data = maps::load_age(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let age = data.as_borrowed();
+    ///
+    /// assert_eq!(age.get('a'), Age { major: 1, minor: 1 }); // U+0061: Latin Small Letter A
+    /// assert_eq!(age.get('€'), Age { major: 2, minor: 1 }); // U+20AC: Euro Sign
+    /// assert_eq!(age.get('🎃'), Age { major: 6, minor: 0 }); // U+1F383: Jack-O-Lantern
+    /// assert_eq!(age.get32(0x0378), Age::Unassigned);
+    /// ```
+    pub fn load_age();
+}
+
+make_map_property! {
+    property: "Bidi_Mirroring_Glyph";
+    marker: BidiMirroringGlyphProperty;
+    value: crate::CodePointValue;
+    keyed_data_marker: BidiMirroringGlyphV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Bidi_Mirroring_Glyph Unicode property. See
+    /// [`CodePointValue`].
+    ///
+    /// **Note:** See [`crate::bidi::BidiAuxiliaryProperties`] for the preferred API to look
+    /// up the properties used by the bidirectional algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, CodePointValue};
+    ///
+    /// let data = maps::load_bidi_mirroring_glyph(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let bmg = data.as_borrowed();
+    ///
+    /// assert_eq!(bmg.get('('), CodePointValue(Some(')'))); // U+0028: Left Parenthesis
+    /// assert_eq!(bmg.get('≤'), CodePointValue(Some('≥'))); // U+2264: Less-Than Or Equal To
+    /// assert_eq!(bmg.get('a'), CodePointValue(None)); // U+0061: Latin Small Letter A
+    /// ```
+    pub fn load_bidi_mirroring_glyph();
+}
+
+make_map_property! {
+    property: "Bidi_Paired_Bracket";
+    marker: BidiPairedBracketProperty;
+    value: crate::CodePointValue;
+    keyed_data_marker: BidiPairedBracketV1Marker;
+    func:
+    /// Return a [`CodePointMapData`] for the Bidi_Paired_Bracket Unicode property. See
+    /// [`CodePointValue`].
+    ///
+    /// **Note:** See [`crate::bidi::BidiAuxiliaryProperties`] for the preferred API to look
+    /// up the properties used by the bidirectional algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::{maps, CodePointValue};
+    ///
+    /// let data = maps::load_bidi_paired_bracket(&icu_testdata::unstable()).expect("The data should be valid!");
+    /// let bpb = data.as_borrowed();
+    ///
+    /// assert_eq!(bpb.get('('), CodePointValue(Some(')'))); // U+0028: Left Parenthesis
+    /// assert_eq!(bpb.get('\u{232A}'), CodePointValue(Some('\u{2329}'))); // U+232A: Right-Pointing Angle Bracket
+    /// assert_eq!(bpb.get('≤'), CodePointValue(None)); // U+2264: Less-Than Or Equal To
+    /// ```
+    pub fn load_bidi_paired_bracket();
+}
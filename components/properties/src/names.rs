@@ -0,0 +1,515 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Lookup of the values of enumerated properties by their names, and of
+//! the names by value.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! Names are matched with the loose matching rule [UAX44-LM3] of the Unicode Character
+//! Database: case, whitespace, underscores and hyphens are ignored, as is an initial "is".
+//! The names of the binary properties can be looked up with [`sets::load_for_name_unstable()`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_properties::{GeneralCategory, LineBreak, Script};
+//!
+//! let mapper = Script::get_name_to_enum_mapper(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//! let mapper = mapper.as_borrowed();
+//!
+//! assert_eq!(mapper.get_loose("Greek"), Some(Script::Greek));
+//! assert_eq!(mapper.get_loose("Grek"), Some(Script::Greek));
+//! assert_eq!(mapper.get_loose("is greek"), Some(Script::Greek));
+//! assert_eq!(mapper.get_loose("Klingon"), None);
+//!
+//! let mapper = GeneralCategory::get_enum_to_long_name_mapper(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//!
+//! assert_eq!(
+//!     mapper.as_borrowed().get(GeneralCategory::UppercaseLetter),
+//!     Some("Uppercase_Letter")
+//! );
+//!
+//! // A property=value pair, as found in regular expressions
+//! let (property, value) = "Line_Break=AL".split_once('=').unwrap();
+//! assert_eq!(property, "Line_Break");
+//! let mapper = LineBreak::get_name_to_enum_mapper(&icu_testdata::unstable())
+//!     .expect("The data should be valid");
+//! assert_eq!(mapper.as_borrowed().get_loose(value), Some(LineBreak::Alphabetic));
+//! ```
+//!
+//! [UAX44-LM3]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+//! [`sets::load_for_name_unstable()`]: crate::sets::load_for_name_unstable
+
+use crate::error::PropertiesError;
+use crate::provider::*;
+use crate::*;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use icu_collections::codepointtrie::TrieValue;
+use icu_provider::prelude::*;
+
+/// Whether loose matching ignores `c`.
+fn is_ignored(c: char) -> bool {
+    c.is_whitespace() || c == '_' || c == '-'
+}
+
+/// Returns the characters of `name` that the loose matching rule [UAX44-LM3] compares, in
+/// lowercase. These are the keys of the maps from names to values.
+///
+/// This does not remove an initial "is", which the rule also ignores.
+///
+/// # Examples
+///
+/// ```
+/// use icu_properties::names::loose_matching_chars;
+///
+/// assert!(loose_matching_chars("White_Space").eq(loose_matching_chars("WHITE SPACE")));
+/// assert_eq!(loose_matching_chars("Line-Break").collect::<String>(), "linebreak");
+/// ```
+///
+/// [UAX44-LM3]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+pub fn loose_matching_chars(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|&c| !is_ignored(c))
+        .map(|c| c.to_ascii_lowercase())
+}
+
+/// The rest of `name` after an initial "is", if it has one.
+fn strip_is(name: &str) -> Option<&str> {
+    let mut chars = name.char_indices().filter(|&(_, c)| !is_ignored(c));
+    match (chars.next(), chars.next()) {
+        (Some((_, 'i' | 'I')), Some((i, 's' | 'S'))) => name.get(i + 1..),
+        _ => None,
+    }
+}
+
+/// Whether `name` matches `candidate` with loose matching.
+pub(crate) fn loose_eq(name: &str, candidate: &str) -> bool {
+    let eq = |name: &str| loose_matching_chars(name).eq(loose_matching_chars(candidate));
+    eq(name) || matches!(strip_is(name), Some(rest) if eq(rest))
+}
+
+/// A wrapper around the data for looking up the values of an enumerated property by name.
+/// Access its data via the borrowed version, [`PropertyValueNameToEnumMapperBorrowed`].
+pub struct PropertyValueNameToEnumMapper<T> {
+    map: DataPayload<ErasedNameToEnumMapV1Marker>,
+    markers: PhantomData<fn() -> T>,
+}
+
+/// Private marker type for PropertyValueNameToEnumMapper
+/// to work for all properties at once
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ErasedNameToEnumMapV1Marker;
+impl DataMarker for ErasedNameToEnumMapV1Marker {
+    type Yokeable = PropertyValueNameToEnumMapV1<'static>;
+}
+
+impl<T> PropertyValueNameToEnumMapper<T> {
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (like `get_loose()`) by
+    /// consolidating it up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> PropertyValueNameToEnumMapperBorrowed<'_, T> {
+        PropertyValueNameToEnumMapperBorrowed {
+            map: self.map.get(),
+            markers: PhantomData,
+        }
+    }
+
+    /// Construct a new one from loaded data
+    ///
+    /// Typically it is preferable to use methods like [`Script::get_name_to_enum_mapper()`] instead
+    pub fn from_data<M>(data: DataPayload<M>) -> Self
+    where
+        M: DataMarker<Yokeable = PropertyValueNameToEnumMapV1<'static>>,
+    {
+        Self {
+            map: data.map_project(|m, _| m),
+            markers: PhantomData,
+        }
+    }
+}
+
+/// A borrowed wrapper around the data for looking up the values of an enumerated property by
+/// name, returned by [`PropertyValueNameToEnumMapper::as_borrowed()`]. More efficient to query.
+pub struct PropertyValueNameToEnumMapperBorrowed<'a, T> {
+    map: &'a PropertyValueNameToEnumMapV1<'a>,
+    markers: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Clone for PropertyValueNameToEnumMapperBorrowed<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for PropertyValueNameToEnumMapperBorrowed<'a, T> {}
+
+impl<'a, T> PropertyValueNameToEnumMapperBorrowed<'a, T> {
+    /// The `u32` representation of the value named `name`, with loose matching.
+    fn get_u32_loose(self, name: &str) -> Option<u32> {
+        let get = |name: &'_ str| {
+            self.map
+                .map
+                .get_copied_by(|probe| probe.chars().cmp(loose_matching_chars(name)))
+        };
+        // Only remove an initial "is" if the name doesn't match with it, like Line_Break=IS.
+        get(name).or_else(|| get(strip_is(name)?))
+    }
+}
+
+/// A wrapper around the data for looking up the short or long names of the values of an
+/// enumerated property. Access its data via the borrowed version,
+/// [`PropertyEnumToValueNameMapperBorrowed`].
+pub struct PropertyEnumToValueNameMapper<T> {
+    map: DataPayload<ErasedEnumToValueNameMapV1Marker>,
+    markers: PhantomData<fn(T)>,
+}
+
+/// Private marker type for PropertyEnumToValueNameMapper
+/// to work for all properties at once
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ErasedEnumToValueNameMapV1Marker;
+impl DataMarker for ErasedEnumToValueNameMapV1Marker {
+    type Yokeable = PropertyEnumToValueNameMapV1<'static>;
+}
+
+impl<T> PropertyEnumToValueNameMapper<T> {
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (like `get()`) by
+    /// consolidating it up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> PropertyEnumToValueNameMapperBorrowed<'_, T> {
+        PropertyEnumToValueNameMapperBorrowed {
+            map: self.map.get(),
+            markers: PhantomData,
+        }
+    }
+
+    /// Construct a new one from loaded data
+    ///
+    /// Typically it is preferable to use methods like [`Script::get_enum_to_short_name_mapper()`]
+    /// instead
+    pub fn from_data<M>(data: DataPayload<M>) -> Self
+    where
+        M: DataMarker<Yokeable = PropertyEnumToValueNameMapV1<'static>>,
+    {
+        Self {
+            map: data.map_project(|m, _| m),
+            markers: PhantomData,
+        }
+    }
+}
+
+/// A borrowed wrapper around the data for looking up the names of the values of an enumerated
+/// property, returned by [`PropertyEnumToValueNameMapper::as_borrowed()`]. More efficient to query.
+pub struct PropertyEnumToValueNameMapperBorrowed<'a, T> {
+    map: &'a PropertyEnumToValueNameMapV1<'a>,
+    markers: PhantomData<fn(T)>,
+}
+
+impl<'a, T> Clone for PropertyEnumToValueNameMapperBorrowed<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for PropertyEnumToValueNameMapperBorrowed<'a, T> {}
+
+impl<'a, T> PropertyEnumToValueNameMapperBorrowed<'a, T> {
+    /// The name of the value with the `u32` representation `value`, if it has one.
+    fn get_u32(self, value: u32) -> Option<&'a str> {
+        self.map
+            .names
+            .get(usize::try_from(value).ok()?)
+            .filter(|name| !name.is_empty())
+    }
+}
+
+macro_rules! make_name_mappers {
+    (
+        property: $property:literal;
+        value: $value_ty:ident;
+        name_to_enum: $name_to_enum_marker:ident;
+        enum_to_short_name: $short_marker:ident;
+        enum_to_long_name: $long_marker:ident;
+        to_u32: |$value:ident| $to_u32:expr;
+        example: $example_name:literal => $example_value:expr;
+    ) => {
+        impl $value_ty {
+            #[doc = core::concat!("Return a [`PropertyValueNameToEnumMapper`] for looking up the values of the ", $property, " property by name.")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = core::concat!("use icu_properties::", core::stringify!($value_ty), ";")]
+            ///
+            #[doc = core::concat!("let mapper = ", core::stringify!($value_ty), "::get_name_to_enum_mapper(&icu_testdata::unstable())")]
+            ///     .expect("The data should be valid");
+            #[doc = core::concat!("assert_eq!(mapper.as_borrowed().get_loose(\"", $example_name, "\"), Some(", core::stringify!($example_value), "));")]
+            /// ```
+            pub fn get_name_to_enum_mapper(
+                provider: &(impl DataProvider<$name_to_enum_marker> + ?Sized),
+            ) -> Result<PropertyValueNameToEnumMapper<Self>, PropertiesError> {
+                Ok(provider
+                    .load(Default::default())
+                    .and_then(DataResponse::take_payload)
+                    .map(PropertyValueNameToEnumMapper::from_data)?)
+            }
+
+            #[doc = core::concat!("Return a [`PropertyEnumToValueNameMapper`] for looking up the short names of the values of the ", $property, " property.")]
+            pub fn get_enum_to_short_name_mapper(
+                provider: &(impl DataProvider<$short_marker> + ?Sized),
+            ) -> Result<PropertyEnumToValueNameMapper<Self>, PropertiesError> {
+                Ok(provider
+                    .load(Default::default())
+                    .and_then(DataResponse::take_payload)
+                    .map(PropertyEnumToValueNameMapper::from_data)?)
+            }
+
+            #[doc = core::concat!("Return a [`PropertyEnumToValueNameMapper`] for looking up the long names of the values of the ", $property, " property.")]
+            pub fn get_enum_to_long_name_mapper(
+                provider: &(impl DataProvider<$long_marker> + ?Sized),
+            ) -> Result<PropertyEnumToValueNameMapper<Self>, PropertiesError> {
+                Ok(provider
+                    .load(Default::default())
+                    .and_then(DataResponse::take_payload)
+                    .map(PropertyEnumToValueNameMapper::from_data)?)
+            }
+        }
+
+        impl<'a> PropertyValueNameToEnumMapperBorrowed<'a, $value_ty> {
+            /// Get the value with the name `name`, using loose matching.
+            pub fn get_loose(self, name: &str) -> Option<$value_ty> {
+                TrieValue::try_from_u32(self.get_u32_loose(name)?).ok()
+            }
+        }
+
+        impl<'a> PropertyEnumToValueNameMapperBorrowed<'a, $value_ty> {
+            /// Get the name of `value`, if it has one.
+            pub fn get(self, $value: $value_ty) -> Option<&'a str> {
+                self.get_u32($to_u32)
+            }
+        }
+    };
+}
+
+make_name_mappers! {
+    property: "Bidi_Class";
+    value: BidiClass;
+    name_to_enum: BidiClassNameToValueV1Marker;
+    enum_to_short_name: BidiClassValueToShortNameV1Marker;
+    enum_to_long_name: BidiClassValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Arabic_Letter" => BidiClass::ArabicLetter;
+}
+
+make_name_mappers! {
+    property: "Canonical_Combining_Class";
+    value: CanonicalCombiningClass;
+    name_to_enum: CanonicalCombiningClassNameToValueV1Marker;
+    enum_to_short_name: CanonicalCombiningClassValueToShortNameV1Marker;
+    enum_to_long_name: CanonicalCombiningClassValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Above" => CanonicalCombiningClass::Above;
+}
+
+make_name_mappers! {
+    property: "East_Asian_Width";
+    value: EastAsianWidth;
+    name_to_enum: EastAsianWidthNameToValueV1Marker;
+    enum_to_short_name: EastAsianWidthValueToShortNameV1Marker;
+    enum_to_long_name: EastAsianWidthValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Wide" => EastAsianWidth::Wide;
+}
+
+make_name_mappers! {
+    property: "General_Category";
+    value: GeneralCategory;
+    name_to_enum: GeneralCategoryNameToValueV1Marker;
+    enum_to_short_name: GeneralCategoryValueToShortNameV1Marker;
+    enum_to_long_name: GeneralCategoryValueToLongNameV1Marker;
+    to_u32: |value| value as u32;
+    example: "Lu" => GeneralCategory::UppercaseLetter;
+}
+
+make_name_mappers! {
+    property: "Grapheme_Cluster_Break";
+    value: GraphemeClusterBreak;
+    name_to_enum: GraphemeClusterBreakNameToValueV1Marker;
+    enum_to_short_name: GraphemeClusterBreakValueToShortNameV1Marker;
+    enum_to_long_name: GraphemeClusterBreakValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Regional_Indicator" => GraphemeClusterBreak::RegionalIndicator;
+}
+
+make_name_mappers! {
+    property: "Joining_Type";
+    value: JoiningType;
+    name_to_enum: JoiningTypeNameToValueV1Marker;
+    enum_to_short_name: JoiningTypeValueToShortNameV1Marker;
+    enum_to_long_name: JoiningTypeValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Dual_Joining" => JoiningType::DualJoining;
+}
+
+make_name_mappers! {
+    property: "Line_Break";
+    value: LineBreak;
+    name_to_enum: LineBreakNameToValueV1Marker;
+    enum_to_short_name: LineBreakValueToShortNameV1Marker;
+    enum_to_long_name: LineBreakValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "IS" => LineBreak::InfixNumeric;
+}
+
+make_name_mappers! {
+    property: "Script";
+    value: Script;
+    name_to_enum: ScriptNameToValueV1Marker;
+    enum_to_short_name: ScriptValueToShortNameV1Marker;
+    enum_to_long_name: ScriptValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "Grek" => Script::Greek;
+}
+
+make_name_mappers! {
+    property: "Sentence_Break";
+    value: SentenceBreak;
+    name_to_enum: SentenceBreakNameToValueV1Marker;
+    enum_to_short_name: SentenceBreakValueToShortNameV1Marker;
+    enum_to_long_name: SentenceBreakValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "STerm" => SentenceBreak::STerm;
+}
+
+make_name_mappers! {
+    property: "Word_Break";
+    value: WordBreak;
+    name_to_enum: WordBreakNameToValueV1Marker;
+    enum_to_short_name: WordBreakValueToShortNameV1Marker;
+    enum_to_long_name: WordBreakValueToLongNameV1Marker;
+    to_u32: |value| value.0 as u32;
+    example: "ALetter" => WordBreak::ALetter;
+}
+
+impl GeneralCategoryGroup {
+    /// Return a [`PropertyValueNameToEnumMapper`] for looking up the values and groupings of
+    /// values of the General_Category property by name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu_properties::{GeneralCategory, GeneralCategoryGroup};
+    ///
+    /// let mapper = GeneralCategoryGroup::get_name_to_enum_mapper(&icu_testdata::unstable())
+    ///     .expect("The data should be valid");
+    /// let mapper = mapper.as_borrowed();
+    ///
+    /// assert_eq!(mapper.get_loose("L"), Some(GeneralCategoryGroup::Letter));
+    /// assert_eq!(mapper.get_loose("Cased_Letter"), Some(GeneralCategoryGroup::CasedLetter));
+    /// assert_eq!(
+    ///     mapper.get_loose("Lu"),
+    ///     Some(GeneralCategoryGroup::from(GeneralCategory::UppercaseLetter))
+    /// );
+    /// ```
+    pub fn get_name_to_enum_mapper(
+        provider: &(impl DataProvider<GeneralCategoryMaskNameToValueV1Marker> + ?Sized),
+    ) -> Result<PropertyValueNameToEnumMapper<Self>, PropertiesError> {
+        Ok(provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .map(PropertyValueNameToEnumMapper::from_data)?)
+    }
+}
+
+impl<'a> PropertyValueNameToEnumMapperBorrowed<'a, GeneralCategoryGroup> {
+    /// Get the value or grouping of values with the name `name`, using loose matching.
+    pub fn get_loose(self, name: &str) -> Option<GeneralCategoryGroup> {
+        self.get_u32_loose(name).map(GeneralCategoryGroup::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::loose_eq;
+
+    #[test]
+    fn test_loose_eq() {
+        assert!(loose_eq("White_Space", "WHITE SPACE"));
+        assert!(loose_eq("isWhite-Space", "White_Space"));
+        assert!(loose_eq("is", "is"));
+        assert!(loose_eq("Is-Alpha", "alpha"));
+        assert!(!loose_eq("White_Spaces", "White_Space"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use icu::properties::Script;
+
+        let provider = icu_testdata::unstable();
+        let from = Script::get_name_to_enum_mapper(&provider).unwrap();
+        let short = Script::get_enum_to_short_name_mapper(&provider).unwrap();
+        let long = Script::get_enum_to_long_name_mapper(&provider).unwrap();
+
+        for script in [
+            Script::Latin,
+            Script::Han,
+            Script::Common,
+            Script::Inherited,
+        ] {
+            let short_name = short.as_borrowed().get(script).unwrap();
+            let long_name = long.as_borrowed().get(script).unwrap();
+            assert_eq!(from.as_borrowed().get_loose(short_name), Some(script));
+            assert_eq!(from.as_borrowed().get_loose(long_name), Some(script));
+        }
+        assert_eq!(short.as_borrowed().get(Script::Latin), Some("Latn"));
+        assert_eq!(long.as_borrowed().get(Script::Inherited), Some("Inherited"));
+        assert_eq!(short.as_borrowed().get(Script(u16::MAX)), None);
+    }
+
+    #[test]
+    fn test_is_prefix() {
+        use icu::properties::{CanonicalCombiningClass, LineBreak};
+
+        let provider = icu_testdata::unstable();
+        let lb = LineBreak::get_name_to_enum_mapper(&provider).unwrap();
+        assert_eq!(
+            lb.as_borrowed().get_loose("IS"),
+            Some(LineBreak::InfixNumeric)
+        );
+        assert_eq!(
+            lb.as_borrowed().get_loose("is_IS"),
+            Some(LineBreak::InfixNumeric)
+        );
+        assert_eq!(
+            lb.as_borrowed().get_loose("isAL"),
+            Some(LineBreak::Alphabetic)
+        );
+        assert_eq!(
+            lb.as_borrowed().get_loose("is"),
+            Some(LineBreak::InfixNumeric)
+        );
+
+        let ccc = CanonicalCombiningClass::get_name_to_enum_mapper(&provider).unwrap();
+        assert_eq!(
+            ccc.as_borrowed().get_loose("230"),
+            Some(CanonicalCombiningClass::Above)
+        );
+        assert_eq!(
+            ccc.as_borrowed().get_loose("iota-subscript"),
+            Some(CanonicalCombiningClass::IotaSubscript)
+        );
+    }
+}
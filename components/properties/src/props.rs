@@ -468,6 +468,7 @@ impl Script {
     pub const Kaithi: Script = Script(120);
     pub const Kannada: Script = Script(21);
     pub const Katakana: Script = Script(22);
+    pub const KatakanaOrHiragana: Script = Script(54);
     pub const KayahLi: Script = Script(79);
     pub const Kharoshthi: Script = Script(57);
     pub const KhitanSmallScript: Script = Script(191);
@@ -785,6 +786,31 @@ impl SentenceBreak {
     pub const SContinue: SentenceBreak = SentenceBreak(14); // name="SC"
 }
 
+/// Enumerated property Joining_Type.
+/// See Section 9.2, Arabic Cursive Joining in The Unicode Standard for the summary of
+/// each property value.
+///
+/// The numeric value is compatible with `UJoiningType` in ICU4C.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(JoiningTypeULE)]
+pub struct JoiningType(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl JoiningType {
+    pub const NonJoining: JoiningType = JoiningType(0); // name="U"
+    pub const JoinCausing: JoiningType = JoiningType(1); // name="C"
+    pub const DualJoining: JoiningType = JoiningType(2); // name="D"
+    pub const LeftJoining: JoiningType = JoiningType(3); // name="L"
+    pub const RightJoining: JoiningType = JoiningType(4); // name="R"
+    pub const Transparent: JoiningType = JoiningType(5); // name="T"
+}
+
 /// Property Canonical_Combining_Class.
 /// See UAX #15:
 /// <https://www.unicode.org/reports/tr15/>.
@@ -868,3 +894,287 @@ impl CanonicalCombiningClass {
     pub const DoubleAbove: CanonicalCombiningClass = CanonicalCombiningClass(234); // name="DA"
     pub const IotaSubscript: CanonicalCombiningClass = CanonicalCombiningClass(240); // name="IS"
 }
+
+/// Enumerated property Numeric_Type.
+///
+/// See "Numeric Value" in UAX #44 for the summary of each property value:
+/// <https://www.unicode.org/reports/tr44/#Numeric_Value>
+///
+/// The numeric value is compatible with `UNumericType` in ICU4C.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(NumericTypeULE)]
+pub struct NumericType(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl NumericType {
+    pub const None: NumericType = NumericType(0); // name="None"
+    pub const Decimal: NumericType = NumericType(1); // name="De"
+    pub const Digit: NumericType = NumericType(2); // name="Di"
+    pub const Numeric: NumericType = NumericType(3); // name="Nu"
+}
+
+/// Property Numeric_Value: a rational number, or NaN for the code points that don't have a
+/// numeric value.
+///
+/// The numerator is stored as a mantissa multiplied by a power of ten, so that the large
+/// values, like 10<sup>12</sup>, fit in 32 bits with the fractions, like 1/16 and -1/2.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+pub struct NumericValue(
+    // The signed mantissa of the numerator in the high 17 bits, the exponent of ten of the
+    // numerator in the next 5 bits, and the denominator in the low 10 bits, zero for NaN.
+    #[doc(hidden)] pub u32,
+);
+
+impl NumericValue {
+    const DENOMINATOR_BITS: u32 = 10;
+    const EXPONENT_BITS: u32 = 5;
+    const MANTISSA_SHIFT: u32 = Self::DENOMINATOR_BITS + Self::EXPONENT_BITS;
+    /// The largest exponent whose power of ten fits in an `i64`.
+    const MAX_EXPONENT: u32 = 18;
+
+    /// The value of the code points that don't have a numeric value.
+    #[allow(non_upper_case_globals)]
+    pub const NaN: NumericValue = NumericValue(0);
+
+    /// Creates the value `numerator / denominator`, reduced to lowest terms.
+    ///
+    /// Returns `None` if the denominator is zero, or if the value cannot be represented: the
+    /// reduced denominator must be below 1024, and the reduced numerator a multiple of a
+    /// power of ten by a mantissa between -65536 and 65535.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::NumericValue;
+    ///
+    /// let half = NumericValue::from_rational(2, 4).expect("1/2 can be represented");
+    /// assert_eq!(half.to_rational(), Some((1, 2)));
+    /// assert_eq!(half.to_f64(), 0.5);
+    ///
+    /// let trillion = NumericValue::from_rational(1_000_000_000_000, 1)
+    ///     .expect("10^12 can be represented");
+    /// assert_eq!(trillion.to_rational(), Some((1_000_000_000_000, 1)));
+    ///
+    /// assert_eq!(NumericValue::from_rational(1, 0), None);
+    /// assert_eq!(NumericValue::NaN.to_rational(), None);
+    /// assert!(NumericValue::NaN.to_f64().is_nan());
+    /// ```
+    pub fn from_rational(numerator: i64, denominator: u32) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let gcd = {
+            let (mut a, mut b) = (numerator.unsigned_abs(), u64::from(denominator));
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            a
+        };
+        let mut mantissa = numerator / gcd as i64;
+        let denominator = (u64::from(denominator) / gcd) as u32;
+        if denominator >= 1 << Self::DENOMINATOR_BITS {
+            return None;
+        }
+        let mut exponent = 0;
+        while mantissa != 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        let mantissa_max = 1 << (31 - Self::MANTISSA_SHIFT);
+        if !(-mantissa_max..mantissa_max).contains(&mantissa) {
+            return None;
+        }
+        Some(Self(
+            ((mantissa as u32) << Self::MANTISSA_SHIFT)
+                | (exponent << Self::DENOMINATOR_BITS)
+                | denominator,
+        ))
+    }
+
+    /// The value as a fraction in lowest terms, `(numerator, denominator)`, or `None` for
+    /// [`NumericValue::NaN`].
+    pub fn to_rational(self) -> Option<(i64, u32)> {
+        let denominator = self.0 & ((1 << Self::DENOMINATOR_BITS) - 1);
+        if denominator == 0 {
+            return None;
+        }
+        let exponent = ((self.0 >> Self::DENOMINATOR_BITS) & ((1 << Self::EXPONENT_BITS) - 1))
+            .min(Self::MAX_EXPONENT);
+        let mantissa = i64::from((self.0 as i32) >> Self::MANTISSA_SHIFT);
+        Some((mantissa.saturating_mul(10i64.pow(exponent)), denominator))
+    }
+
+    /// The value as a floating point number, which is NaN for [`NumericValue::NaN`].
+    pub fn to_f64(self) -> f64 {
+        match self.to_rational() {
+            Some((numerator, denominator)) => numerator as f64 / f64::from(denominator),
+            None => f64::NAN,
+        }
+    }
+}
+
+impl zerovec::ule::AsULE for NumericValue {
+    type ULE = <u32 as zerovec::ule::AsULE>::ULE;
+
+    #[inline]
+    fn to_unaligned(self) -> Self::ULE {
+        <u32 as zerovec::ule::AsULE>::to_unaligned(self.0)
+    }
+
+    #[inline]
+    fn from_unaligned(unaligned: Self::ULE) -> Self {
+        Self(<u32 as zerovec::ule::AsULE>::from_unaligned(unaligned))
+    }
+}
+
+/// Enumerated property Indic_Syllabic_Category.
+///
+/// See `IndicSyllabicCategory.txt` in the Unicode Character Database for the summary of each
+/// property value: <https://www.unicode.org/Public/UCD/latest/ucd/IndicSyllabicCategory.txt>
+///
+/// The numeric value is compatible with `UIndicSyllabicCategory` in ICU4C.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(IndicSyllabicCategoryULE)]
+pub struct IndicSyllabicCategory(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl IndicSyllabicCategory {
+    pub const Other: IndicSyllabicCategory = IndicSyllabicCategory(0);
+    pub const Avagraha: IndicSyllabicCategory = IndicSyllabicCategory(1);
+    pub const Bindu: IndicSyllabicCategory = IndicSyllabicCategory(2);
+    pub const BrahmiJoiningNumber: IndicSyllabicCategory = IndicSyllabicCategory(3);
+    pub const CantillationMark: IndicSyllabicCategory = IndicSyllabicCategory(4);
+    pub const Consonant: IndicSyllabicCategory = IndicSyllabicCategory(5);
+    pub const ConsonantDead: IndicSyllabicCategory = IndicSyllabicCategory(6);
+    pub const ConsonantFinal: IndicSyllabicCategory = IndicSyllabicCategory(7);
+    pub const ConsonantHeadLetter: IndicSyllabicCategory = IndicSyllabicCategory(8);
+    pub const ConsonantInitialPostfixed: IndicSyllabicCategory = IndicSyllabicCategory(9);
+    pub const ConsonantKiller: IndicSyllabicCategory = IndicSyllabicCategory(10);
+    pub const ConsonantMedial: IndicSyllabicCategory = IndicSyllabicCategory(11);
+    pub const ConsonantPlaceholder: IndicSyllabicCategory = IndicSyllabicCategory(12);
+    pub const ConsonantPrecedingRepha: IndicSyllabicCategory = IndicSyllabicCategory(13);
+    pub const ConsonantPrefixed: IndicSyllabicCategory = IndicSyllabicCategory(14);
+    pub const ConsonantSubjoined: IndicSyllabicCategory = IndicSyllabicCategory(15);
+    pub const ConsonantSucceedingRepha: IndicSyllabicCategory = IndicSyllabicCategory(16);
+    pub const ConsonantWithStacker: IndicSyllabicCategory = IndicSyllabicCategory(17);
+    pub const GeminationMark: IndicSyllabicCategory = IndicSyllabicCategory(18);
+    pub const InvisibleStacker: IndicSyllabicCategory = IndicSyllabicCategory(19);
+    pub const Joiner: IndicSyllabicCategory = IndicSyllabicCategory(20);
+    pub const ModifyingLetter: IndicSyllabicCategory = IndicSyllabicCategory(21);
+    pub const NonJoiner: IndicSyllabicCategory = IndicSyllabicCategory(22);
+    pub const Nukta: IndicSyllabicCategory = IndicSyllabicCategory(23);
+    pub const Number: IndicSyllabicCategory = IndicSyllabicCategory(24);
+    pub const NumberJoiner: IndicSyllabicCategory = IndicSyllabicCategory(25);
+    pub const PureKiller: IndicSyllabicCategory = IndicSyllabicCategory(26);
+    pub const RegisterShifter: IndicSyllabicCategory = IndicSyllabicCategory(27);
+    pub const SyllableModifier: IndicSyllabicCategory = IndicSyllabicCategory(28);
+    pub const ToneLetter: IndicSyllabicCategory = IndicSyllabicCategory(29);
+    pub const ToneMark: IndicSyllabicCategory = IndicSyllabicCategory(30);
+    pub const Virama: IndicSyllabicCategory = IndicSyllabicCategory(31);
+    pub const Visarga: IndicSyllabicCategory = IndicSyllabicCategory(32);
+    pub const Vowel: IndicSyllabicCategory = IndicSyllabicCategory(33);
+    pub const VowelDependent: IndicSyllabicCategory = IndicSyllabicCategory(34);
+    pub const VowelIndependent: IndicSyllabicCategory = IndicSyllabicCategory(35);
+}
+
+/// Enumerated property Hangul_Syllable_Type.
+///
+/// See Section 3.12, Conjoining Jamo Behavior in The Unicode Standard for the summary of
+/// each property value.
+///
+/// The numeric value is compatible with `UHangulSyllableType` in ICU4C.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(HangulSyllableTypeULE)]
+pub struct HangulSyllableType(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl HangulSyllableType {
+    pub const NotApplicable: HangulSyllableType = HangulSyllableType(0); // name="NA"
+    pub const LeadingJamo: HangulSyllableType = HangulSyllableType(1); // name="L"
+    pub const VowelJamo: HangulSyllableType = HangulSyllableType(2); // name="V"
+    pub const TrailingJamo: HangulSyllableType = HangulSyllableType(3); // name="T"
+    pub const LeadingVowelSyllable: HangulSyllableType = HangulSyllableType(4); // name="LV"
+    pub const LeadingVowelTrailingSyllable: HangulSyllableType = HangulSyllableType(5); // name="LVT"
+}
+
+/// Enumerated property Vertical_Orientation.
+///
+/// See "Vertical Orientation Property Values" in UAX #50 for the summary of each property
+/// value: <https://www.unicode.org/reports/tr50/#vo>
+///
+/// The numeric value is compatible with `UVerticalOrientation` in ICU4C.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(VerticalOrientationULE)]
+pub struct VerticalOrientation(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl VerticalOrientation {
+    pub const Rotated: VerticalOrientation = VerticalOrientation(0); // name="R"
+    pub const TransformedRotated: VerticalOrientation = VerticalOrientation(1); // name="Tr"
+    pub const TransformedUpright: VerticalOrientation = VerticalOrientation(2); // name="Tu"
+    pub const Upright: VerticalOrientation = VerticalOrientation(3); // name="U"
+}
+
+/// Property Age: the version of Unicode in which a code point was assigned, with a major
+/// and a minor version number, or [`Age::Unassigned`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+#[zerovec::make_ule(AgeULE)]
+pub struct Age {
+    /// The major version number.
+    pub major: u8,
+    /// The minor version number.
+    pub minor: u8,
+}
+
+#[allow(non_upper_case_globals)]
+impl Age {
+    /// The age of the code points that are not assigned, version 0.0, which is older than
+    /// any version.
+    pub const Unassigned: Age = Age { major: 0, minor: 0 };
+}
+
+/// The value of a property whose values are code points, like Bidi_Mirroring_Glyph, or
+/// `None` for the code points that don't have a value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "datagen", derive(databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties))]
+#[allow(clippy::exhaustive_structs)] // newtype
+#[repr(transparent)]
+#[zerovec::make_ule(CodePointValueULE)]
+pub struct CodePointValue(pub Option<char>);
@@ -14,11 +14,13 @@ use crate::Script;
 
 use core::ops::RangeInclusive;
 use icu_collections::codepointinvlist::CodePointInversionList;
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
 use icu_collections::codepointtrie::{CodePointMapRange, CodePointTrie, TrieValue};
 use icu_provider::prelude::*;
 use zerofrom::ZeroFrom;
 
-use zerovec::{VarZeroVec, ZeroSlice, ZeroVecError};
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec, ZeroVecError};
 
 /// A set of characters with a particular property.
 ///
@@ -40,6 +42,28 @@ pub enum PropertyCodePointSetV1<'data> {
     // https://docs.rs/serde/latest/serde/trait.Serializer.html#tymethod.serialize_unit_variant
 }
 
+/// A set of characters and strings which share a particular property value.
+///
+/// This data enum is extensible, more backends may be added in the future.
+/// Old data can be used with newer code but not vice versa.
+#[derive(Debug, Eq, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_properties::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[non_exhaustive]
+pub enum PropertyUnicodeSetV1<'data> {
+    /// A set representing characters in an inversion list, and the strings in a list.
+    CPInversionListStrList(
+        #[cfg_attr(feature = "serde", serde(borrow))] CodePointInversionListAndStringList<'data>,
+    ),
+    // new variants should go BELOW existing ones
+    // Serde serializes based on variant name and index in the enum
+    // https://docs.rs/serde/latest/serde/trait.Serializer.html#tymethod.serialize_unit_variant
+}
+
 /// A map efficiently storing data about individual characters.
 ///
 /// This data enum is extensible, more backends may be added in the future.
@@ -108,6 +132,151 @@ impl<'data> ScriptWithExtensionsPropertyV1<'data> {
     }
 }
 
+/// A map from the names of the values of an enumerated property to the values.
+///
+/// The names are normalized with the loose matching rule [UAX44-LM3], except that an initial
+/// "is" is not removed, so that names like `IS` of Line_Break stay distinct. The values are the
+/// `u32` representations of the property values, or the masks of [`GeneralCategoryGroup`] for
+/// the General_Category_Mask property.
+///
+/// [UAX44-LM3]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+/// [`GeneralCategoryGroup`]: crate::GeneralCategoryGroup
+#[icu_provider::data_struct(
+    BidiClassNameToValueV1Marker = "propnames/from/bc@1",
+    CanonicalCombiningClassNameToValueV1Marker = "propnames/from/ccc@1",
+    EastAsianWidthNameToValueV1Marker = "propnames/from/ea@1",
+    GeneralCategoryNameToValueV1Marker = "propnames/from/gc@1",
+    GeneralCategoryMaskNameToValueV1Marker = "propnames/from/gcm@1",
+    GraphemeClusterBreakNameToValueV1Marker = "propnames/from/GCB@1",
+    JoiningTypeNameToValueV1Marker = "propnames/from/jt@1",
+    LineBreakNameToValueV1Marker = "propnames/from/lb@1",
+    ScriptNameToValueV1Marker = "propnames/from/sc@1",
+    SentenceBreakNameToValueV1Marker = "propnames/from/SB@1",
+    WordBreakNameToValueV1Marker = "propnames/from/WB@1"
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_properties::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct PropertyValueNameToEnumMapV1<'data> {
+    /// The names, in lowercase and without the whitespace, underscores and hyphens that the
+    /// loose matching rule UAX44-LM3 ignores, with the values they map to.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub map: ZeroMap<'data, str, u32>,
+}
+
+/// A map from the values of an enumerated property to their short or long names.
+///
+/// The name of a value is at the index of its `u32` representation. Values without a name,
+/// like the ICU script codes that are not in the Unicode Character Database, have an empty name.
+#[icu_provider::data_struct(
+    BidiClassValueToShortNameV1Marker = "propnames/to/short/bc@1",
+    BidiClassValueToLongNameV1Marker = "propnames/to/long/bc@1",
+    CanonicalCombiningClassValueToShortNameV1Marker = "propnames/to/short/ccc@1",
+    CanonicalCombiningClassValueToLongNameV1Marker = "propnames/to/long/ccc@1",
+    EastAsianWidthValueToShortNameV1Marker = "propnames/to/short/ea@1",
+    EastAsianWidthValueToLongNameV1Marker = "propnames/to/long/ea@1",
+    GeneralCategoryValueToShortNameV1Marker = "propnames/to/short/gc@1",
+    GeneralCategoryValueToLongNameV1Marker = "propnames/to/long/gc@1",
+    GraphemeClusterBreakValueToShortNameV1Marker = "propnames/to/short/GCB@1",
+    GraphemeClusterBreakValueToLongNameV1Marker = "propnames/to/long/GCB@1",
+    JoiningTypeValueToShortNameV1Marker = "propnames/to/short/jt@1",
+    JoiningTypeValueToLongNameV1Marker = "propnames/to/long/jt@1",
+    LineBreakValueToShortNameV1Marker = "propnames/to/short/lb@1",
+    LineBreakValueToLongNameV1Marker = "propnames/to/long/lb@1",
+    ScriptValueToShortNameV1Marker = "propnames/to/short/sc@1",
+    ScriptValueToLongNameV1Marker = "propnames/to/long/sc@1",
+    SentenceBreakValueToShortNameV1Marker = "propnames/to/short/SB@1",
+    SentenceBreakValueToLongNameV1Marker = "propnames/to/long/SB@1",
+    WordBreakValueToShortNameV1Marker = "propnames/to/short/WB@1",
+    WordBreakValueToLongNameV1Marker = "propnames/to/long/WB@1"
+)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_properties::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PropertyEnumToValueNameMapV1<'data> {
+    /// The names, indexed by value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, str>,
+}
+
+/// The Name and Name_Alias properties, for looking up the names of characters and the
+/// characters with a name.
+///
+/// The names that don't end in their code point are sequences of words from `tokens`,
+/// separated by spaces. Each word is encoded as its index in `tokens`, in one byte if it is
+/// below 0x80, or else in two big-endian bytes with the high bit of the first byte set.
+///
+/// The names of Hangul syllables, which are composed from the names of their jamo, are not
+/// included.
+#[icu_provider::data_struct(CharacterNamesV1Marker = "props/na@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_properties::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CharacterNamesV1<'data> {
+    /// The words of the names, the most frequent first.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub tokens: VarZeroVec<'data, str, Index32>,
+    /// The code points with an encoded name, in ascending order.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub code_points: ZeroVec<'data, u32>,
+    /// The encoded names, indexed like `code_points`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, [u8], Index32>,
+    /// The code points with aliases, in ascending order, once for each of their aliases.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alias_code_points: ZeroVec<'data, u32>,
+    /// The encoded aliases, indexed like `alias_code_points`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub aliases: VarZeroVec<'data, [u8], Index32>,
+    /// The indices of the names, followed by the indices of the aliases offset by the number of
+    /// names, sorted by their keys for the loose matching rule [UAX44-LM2].
+    ///
+    /// [UAX44-LM2]: https://www.unicode.org/reports/tr44/#UAX44-LM2
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub loose_order: ZeroVec<'data, u32>,
+    /// The inclusive ranges of code points whose names are a prefix followed by the code point
+    /// in hexadecimal, like `CJK UNIFIED IDEOGRAPH-4E00`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub code_point_ranges: ZeroVec<'data, (u32, u32)>,
+    /// The prefixes of the names, indexed like `code_point_ranges`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub code_point_prefixes: VarZeroVec<'data, str>,
+}
+
+/// The properties used by the bidi algorithm besides Bidi_Class: Bidi_Mirroring_Glyph and
+/// Bidi_Paired_Bracket_Type, packed into the values of a [`CodePointTrie`].
+///
+/// The low 21 bits of a value are the Bidi_Mirroring_Glyph of the code point, or zero if it
+/// has none, and the next 2 bits are its Bidi_Paired_Bracket_Type: 0 for `None`, 1 for
+/// `Open` and 2 for `Close`. The Bidi_Paired_Bracket of a paired bracket is its
+/// Bidi_Mirroring_Glyph.
+#[icu_provider::data_struct(BidiAuxiliaryPropertiesV1Marker = "props/bidiauxiliaryprops@1")]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen", 
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_properties::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct BidiAuxiliaryPropertiesV1<'data> {
+    /// The packed values of the properties.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub trie: CodePointTrie<'data, u32>,
+}
+
 // See CodePointSetData for documentation of these functions
 impl<'data> PropertyCodePointSetV1<'data> {
     #[inline]
@@ -154,6 +323,56 @@ impl<'data> PropertyCodePointSetV1<'data> {
     }
 }
 
+// See UnicodeSetData for documentation of these functions
+impl<'data> PropertyUnicodeSetV1<'data> {
+    #[inline]
+    pub(crate) fn contains(&self, s: &str) -> bool {
+        match *self {
+            Self::CPInversionListStrList(ref l) => l.contains(s),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn contains32(&self, cp: u32) -> bool {
+        match *self {
+            Self::CPInversionListStrList(ref l) => l.contains32(cp),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn contains_char(&self, ch: char) -> bool {
+        match *self {
+            Self::CPInversionListStrList(ref l) => l.contains_char(ch),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_code_point_inversion_list_string_list(
+        l: CodePointInversionListAndStringList<'static>,
+    ) -> Self {
+        Self::CPInversionListStrList(l)
+    }
+
+    #[inline]
+    pub(crate) fn as_code_point_inversion_list_string_list(
+        &'_ self,
+    ) -> Option<&'_ CodePointInversionListAndStringList<'data>> {
+        match *self {
+            Self::CPInversionListStrList(ref l) => Some(l),
+            // any other backing data structure that cannot return a CPInversionListStrList in O(1) time should return None
+        }
+    }
+
+    #[inline]
+    pub(crate) fn to_code_point_inversion_list_string_list(
+        &self,
+    ) -> CodePointInversionListAndStringList<'_> {
+        match *self {
+            Self::CPInversionListStrList(ref t) => ZeroFrom::zero_from(t),
+        }
+    }
+}
+
 // See CodePointMapData for documentation of these functions
 impl<'data, T: TrieValue> PropertyCodePointMapV1<'data, T> {
     #[inline]
@@ -359,5 +578,14 @@ expand!(
         (GraphemeClusterBreakV1Marker, "GCB", GraphemeClusterBreak),
         (WordBreakV1Marker, "WB", WordBreak),
         (SentenceBreakV1Marker, "SB", SentenceBreak),
+        (JoiningTypeV1Marker, "jt", JoiningType),
+        (NumericTypeV1Marker, "nt", NumericType),
+        (NumericValueV1Marker, "nv", NumericValue),
+        (IndicSyllabicCategoryV1Marker, "InSC", IndicSyllabicCategory),
+        (HangulSyllableTypeV1Marker, "hst", HangulSyllableType),
+        (VerticalOrientationV1Marker, "vo", VerticalOrientation),
+        (AgeV1Marker, "age", Age),
+        (BidiMirroringGlyphV1Marker, "bmg", CodePointValue),
+        (BidiPairedBracketV1Marker, "bpb", CodePointValue),
     )
 );
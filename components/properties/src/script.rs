@@ -4,14 +4,29 @@
 
 //! Data and APIs for supporting both Script and Script_Extensions property
 //! values in an efficient structure.
+//!
+//! 🚧 \[Experimental\] With the `experimental` Cargo feature, [`ScriptRunSegmenter`] splits
+//! text into runs of a single script.
 
+#[cfg(feature = "experimental")]
+use crate::bidi::{canonical_bracket, BidiAuxiliaryPropertiesBorrowed, BidiPairedBracketType};
 use crate::error::PropertiesError;
 use crate::props::Script;
 use crate::props::ScriptULE;
 use crate::provider::*;
 
+#[cfg(feature = "experimental")]
+use alloc::vec::Vec;
+#[cfg(feature = "experimental")]
+use core::char::DecodeUtf16;
+#[cfg(feature = "experimental")]
+use core::iter::Copied;
 use core::iter::FromIterator;
+#[cfg(feature = "experimental")]
+use core::ops::Range;
 use core::ops::RangeInclusive;
+#[cfg(feature = "experimental")]
+use core::str::CharIndices;
 use icu_collections::codepointinvlist::CodePointInversionList;
 use icu_provider::prelude::*;
 use zerovec::{ule::AsULE, ZeroSlice};
@@ -645,3 +660,372 @@ icu_provider::gen_any_buffer_constructors!(
         load_script_with_extensions_with_buffer_provider
     ]
 );
+
+/// The maximum number of unmatched opening brackets remembered by a [`ScriptRunIterator`].
+/// When it is exceeded, the outermost brackets are forgotten.
+#[cfg(feature = "experimental")]
+const BRACKET_STACK_DEPTH: usize = 32;
+
+/// 🚧 \[Experimental\] Splits text into runs of a single script, for the shaping of text and
+/// the selection of fonts, like `uscript_nextRun` in ICU4C.
+///
+/// The characters of the Common and Inherited scripts, like spaces, punctuation and
+/// combining marks, take the script of the run they are in. A run only ends at a character
+/// that cannot be written in the script of the run, according to its Script_Extensions: for
+/// example, U+3001 IDEOGRAPHIC COMMA continues a run of Hiragana, Katakana or Han, but not
+/// of Latin. A closing bracket takes the script of the run of its opening bracket (see
+/// Bidi_Paired_Bracket), so that the brackets around text in another script are in the
+/// same run as the text outside of them. The characters that are at the end of a run and
+/// could belong to the next one are left in the first one.
+///
+/// The runs of text that is only made of Common and Inherited characters are reported with
+/// [`Script::Common`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::properties::bidi;
+/// use icu::properties::script::{self, ScriptRunSegmenter};
+/// use icu::properties::Script;
+///
+/// let script_with_extensions =
+///     script::load_script_with_extensions_unstable(&icu_testdata::unstable())
+///         .expect("The data should be valid");
+/// let bidi_auxiliary = bidi::load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable())
+///     .expect("The data should be valid");
+/// let segmenter = ScriptRunSegmenter::new(
+///     script_with_extensions.as_borrowed(),
+///     bidi_auxiliary.as_borrowed(),
+/// );
+///
+/// let text = "Hello, мир! (日本語)";
+/// let runs = segmenter
+///     .segment_str(text)
+///     .map(|(range, script)| (&text[range], script))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     runs,
+///     [
+///         ("Hello, ", Script::Latin),
+///         ("мир! (", Script::Cyrillic),
+///         ("日本語", Script::Han),
+///         (")", Script::Cyrillic),
+///     ]
+/// );
+///
+/// // The offsets into UTF-16 text are in code units.
+/// let text = "ひらがなとカタカナ".encode_utf16().collect::<Vec<_>>();
+/// assert_eq!(
+///     segmenter.segment_utf16(&text).collect::<Vec<_>>(),
+///     [(0..5, Script::Hiragana), (5..9, Script::Katakana)]
+/// );
+/// ```
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[cfg(feature = "experimental")]
+#[derive(Clone, Copy)]
+pub struct ScriptRunSegmenter<'a> {
+    script_with_extensions: ScriptWithExtensionsBorrowed<'a>,
+    bidi_auxiliary: BidiAuxiliaryPropertiesBorrowed<'a>,
+}
+
+#[cfg(feature = "experimental")]
+impl<'a> ScriptRunSegmenter<'a> {
+    /// Creates a segmenter from the Script and Script_Extensions data, and from the
+    /// Bidi_Paired_Bracket data for the pairing of brackets.
+    pub fn new(
+        script_with_extensions: ScriptWithExtensionsBorrowed<'a>,
+        bidi_auxiliary: BidiAuxiliaryPropertiesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            script_with_extensions,
+            bidi_auxiliary,
+        }
+    }
+
+    /// Returns an iterator over the script runs of UTF-8 text, as ranges of byte offsets.
+    pub fn segment_str<'s>(self, input: &'s str) -> ScriptRunIterator<'a, CharIndices<'s>> {
+        ScriptRunIterator::new(self, input.char_indices(), input.len())
+    }
+
+    /// Returns an iterator over the script runs of potentially ill-formed UTF-16 text, as
+    /// ranges of code unit offsets. Unpaired surrogates are treated like U+FFFD REPLACEMENT
+    /// CHARACTER.
+    pub fn segment_utf16<'s>(
+        self,
+        input: &'s [u16],
+    ) -> ScriptRunIterator<'a, Utf16CharIndices<'s>> {
+        ScriptRunIterator::new(self, Utf16CharIndices::new(input), input.len())
+    }
+
+    /// The scripts that `ch` can be written in, or `None` if it takes the script of the
+    /// text around it.
+    fn char_scripts(self, ch: char) -> Option<CharScripts<'a>> {
+        let script = self.script_with_extensions.get_script_val(ch as u32);
+        let extensions = self
+            .script_with_extensions
+            .get_script_extensions_val(ch as u32);
+        let is_neutral = matches!(script, Script::Common | Script::Inherited);
+        if is_neutral && !self.has_extensions(ch) {
+            None
+        } else {
+            Some(CharScripts::Extensions {
+                extensions,
+                script: if is_neutral { None } else { Some(script) },
+            })
+        }
+    }
+
+    fn has_extensions(self, ch: char) -> bool {
+        self.script_with_extensions
+            .data
+            .trie
+            .get32(ch as u32)
+            .has_extensions()
+    }
+}
+
+/// The scripts that a character can be written in.
+#[cfg(feature = "experimental")]
+#[derive(Clone, Copy)]
+enum CharScripts<'a> {
+    /// The Script_Extensions of the character, and its Script if it is not Common or
+    /// Inherited.
+    Extensions {
+        extensions: ScriptExtensionsSet<'a>,
+        script: Option<Script>,
+    },
+    /// The script of the run of the opening bracket of a closing bracket.
+    Bracket(Script),
+}
+
+#[cfg(feature = "experimental")]
+impl CharScripts<'_> {
+    fn contains(self, script: Script) -> bool {
+        match self {
+            CharScripts::Extensions { extensions, .. } => extensions.contains(&script),
+            CharScripts::Bracket(bracket) => bracket == script,
+        }
+    }
+
+    /// The script of the character itself, which is preferred for the run.
+    fn script(self) -> Option<Script> {
+        match self {
+            CharScripts::Extensions { script, .. } => script,
+            CharScripts::Bracket(bracket) => Some(bracket),
+        }
+    }
+}
+
+/// An opening bracket that is not closed yet.
+#[cfg(feature = "experimental")]
+struct OpenBracket {
+    /// The canonical form of the closing bracket that pairs with it.
+    closing: char,
+    /// The index of the run that contains it.
+    run: usize,
+    /// The script of that run, once it is known.
+    script: Option<Script>,
+}
+
+/// 🚧 \[Experimental\] An iterator over the script runs of text, returned by
+/// [`ScriptRunSegmenter::segment_str()`] and [`ScriptRunSegmenter::segment_utf16()`].
+///
+/// Each item is the range of the run in the text, and its script.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[cfg(feature = "experimental")]
+pub struct ScriptRunIterator<'a, I> {
+    segmenter: ScriptRunSegmenter<'a>,
+    chars: I,
+    len: usize,
+    /// The first character of the next run, if it was read at the end of the previous one.
+    pending: Option<(usize, char, Option<CharScripts<'a>>)>,
+    brackets: Vec<OpenBracket>,
+    /// The index of the current run.
+    run: usize,
+    /// The scripts that all the characters of the current run can be written in, with the
+    /// preferred one first, or none if they can be written in any script.
+    scripts: Vec<Script>,
+    /// Whether a character of the current run has a Script that is not Common or Inherited.
+    resolved: bool,
+}
+
+#[cfg(feature = "experimental")]
+impl<'a, I: Iterator<Item = (usize, char)>> ScriptRunIterator<'a, I> {
+    fn new(segmenter: ScriptRunSegmenter<'a>, chars: I, len: usize) -> Self {
+        Self {
+            segmenter,
+            chars,
+            len,
+            pending: None,
+            brackets: Vec::new(),
+            run: 0,
+            scripts: Vec::new(),
+            resolved: false,
+        }
+    }
+
+    /// Reads the next character, and the scripts that it can be written in, pairing it with
+    /// an opening bracket if it is a closing bracket.
+    fn next_char(&mut self) -> Option<(usize, char, Option<CharScripts<'a>>)> {
+        let (offset, ch) = self.chars.next()?;
+        let mut scripts = self.segmenter.char_scripts(ch);
+        let auxiliary = self.segmenter.bidi_auxiliary;
+        if auxiliary.get_paired_bracket_type(ch) == BidiPairedBracketType::Close {
+            let closing = canonical_bracket(ch);
+            if let Some(index) = self.brackets.iter().rposition(|b| b.closing == closing) {
+                let run = self.brackets.get(index).map(|b| (b.run, b.script));
+                self.brackets.truncate(index);
+                match run {
+                    Some((run, _)) if run == self.run => (),
+                    Some((_, Some(script))) if script != Script::Common => {
+                        scripts = Some(CharScripts::Bracket(script))
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Some((offset, ch, scripts))
+    }
+
+    /// Adds a character to the current run if it can be written in the script of the run.
+    fn accept(&mut self, scripts: Option<CharScripts<'a>>) -> bool {
+        let scripts = match scripts {
+            Some(scripts) => scripts,
+            None => return true,
+        };
+        if self.scripts.is_empty() {
+            if let CharScripts::Extensions { extensions, .. } = scripts {
+                self.scripts.extend(extensions.iter());
+            }
+        } else if self.scripts.iter().any(|&script| scripts.contains(script)) {
+            self.scripts.retain(|&script| scripts.contains(script));
+        } else {
+            return false;
+        }
+        if let Some(script) = scripts.script() {
+            if !self.resolved {
+                if let Some(index) = self.scripts.iter().position(|&s| s == script) {
+                    self.scripts.remove(index);
+                    self.scripts.insert(0, script);
+                    self.resolved = true;
+                } else if self.scripts.is_empty() {
+                    self.scripts.push(script);
+                    self.resolved = true;
+                }
+            }
+        }
+        true
+    }
+
+    /// Remembers an opening bracket of the current run.
+    fn push_bracket(&mut self, ch: char) {
+        let auxiliary = self.segmenter.bidi_auxiliary;
+        if auxiliary.get_paired_bracket_type(ch) == BidiPairedBracketType::Open {
+            if let Some(closing) = auxiliary.get_paired_bracket(ch) {
+                if self.brackets.len() == BRACKET_STACK_DEPTH {
+                    self.brackets.remove(0);
+                }
+                self.brackets.push(OpenBracket {
+                    closing: canonical_bracket(closing),
+                    run: self.run,
+                    script: None,
+                });
+            }
+        }
+    }
+
+    /// The script of the current run.
+    fn script(&self) -> Script {
+        match self.scripts.first() {
+            Some(&script) if self.resolved || self.scripts.len() == 1 => script,
+            _ => Script::Common,
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl<'a, I: Iterator<Item = (usize, char)>> Iterator for ScriptRunIterator<'a, I> {
+    type Item = (Range<usize>, Script);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, ch, scripts) = match self.pending.take() {
+            Some(pending) => pending,
+            None => self.next_char()?,
+        };
+        self.run += 1;
+        self.scripts.clear();
+        self.resolved = false;
+        self.accept(scripts);
+        self.push_bracket(ch);
+
+        let mut end = self.len;
+        while let Some((offset, ch, scripts)) = self.next_char() {
+            if self.accept(scripts) {
+                self.push_bracket(ch);
+            } else {
+                self.pending = Some((offset, ch, scripts));
+                end = offset;
+                break;
+            }
+        }
+
+        let script = self.script();
+        for bracket in self.brackets.iter_mut().rev() {
+            if bracket.run != self.run {
+                break;
+            }
+            bracket.script = Some(script);
+        }
+        Some((start..end, script))
+    }
+}
+
+/// 🚧 \[Experimental\] An iterator over the characters of potentially ill-formed UTF-16 text
+/// and their offsets, in code units, with unpaired surrogates replaced by U+FFFD REPLACEMENT
+/// CHARACTER.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[cfg(feature = "experimental")]
+#[derive(Clone)]
+pub struct Utf16CharIndices<'s> {
+    chars: DecodeUtf16<Copied<core::slice::Iter<'s, u16>>>,
+    offset: usize,
+}
+
+#[cfg(feature = "experimental")]
+impl<'s> Utf16CharIndices<'s> {
+    /// Creates an iterator over the characters of `input`.
+    pub fn new(input: &'s [u16]) -> Self {
+        Self {
+            chars: char::decode_utf16(input.iter().copied()),
+            offset: 0,
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+impl Iterator for Utf16CharIndices<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.chars.next()?.unwrap_or(char::REPLACEMENT_CHARACTER);
+        let offset = self.offset;
+        // An unpaired surrogate is a single code unit, like U+FFFD.
+        self.offset += ch.len_utf16();
+        Some((offset, ch))
+    }
+}
@@ -5,12 +5,17 @@
 //! The functions in this module return a [`CodePointSetData`] containing
 //! the set of characters with a particular Unicode property.
 //!
+//! Sets that contain strings as well as characters, like the properties of strings of
+//! [`UTS #51`], are represented by a [`UnicodeSetData`].
+//!
 //! The descriptions of most properties are taken from [`TR44`], the documentation for the
 //! Unicode Character Database.  Some properties are instead defined in [`TR18`], the
 //! documentation for Unicode regular expressions. In particular, Annex C of this document
 //! defines properties for POSIX compatibility.
 //!
 //! [`CodePointSetData`]: crate::sets::CodePointSetData
+//! [`UnicodeSetData`]: crate::sets::UnicodeSetData
+//! [`UTS #51`]: https://www.unicode.org/reports/tr51/#Emoji_Sets
 //! [`TR44`]: https://www.unicode.org/reports/tr44
 //! [`TR18`]: https://www.unicode.org/reports/tr18
 
@@ -20,6 +25,7 @@ use crate::*;
 use core::iter::FromIterator;
 use core::ops::RangeInclusive;
 use icu_collections::codepointinvlist::CodePointInversionList;
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
 use icu_provider::prelude::*;
 
 /// A wrapper around code point set data. It is returned by APIs that return Unicode
@@ -178,6 +184,152 @@ impl<'a> CodePointSetDataBorrowed<'a> {
     }
 }
 
+/// A wrapper around a set of characters and strings, like the properties of strings of
+/// UTS #51. Access its data via the borrowed version, [`UnicodeSetDataBorrowed`].
+pub struct UnicodeSetData {
+    data: DataPayload<ErasedUnicodeSetlikeMarker>,
+}
+
+/// Private marker type for UnicodeSetData
+/// to work for all properties of strings at once
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ErasedUnicodeSetlikeMarker;
+impl DataMarker for ErasedUnicodeSetlikeMarker {
+    type Yokeable = PropertyUnicodeSetV1<'static>;
+}
+
+impl UnicodeSetData {
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (ex: `contains()`) by consolidating it
+    /// up front.
+    ///
+    /// ```rust
+    /// use icu_collections::codepointinvlist::CodePointInversionList;
+    /// use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+    /// use icu_properties::sets::UnicodeSetData;
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let cp_inv_list = CodePointInversionList::try_from_inversion_list_slice(&[0x1F525, 0x1F526])
+    ///     .expect("Valid inversion list");
+    /// let str_list: VarZeroVec<str> = VarZeroVec::from(&["\u{1F6E4}\u{FE0F}"]);
+    /// let data = UnicodeSetData::from_code_point_inversion_list_string_list(
+    ///     CodePointInversionListAndStringList::try_from(cp_inv_list, str_list)
+    ///         .expect("Valid set"),
+    /// );
+    ///
+    /// let emoji = data.as_borrowed();
+    ///
+    /// assert!(emoji.contains("🔥"));
+    /// assert!(emoji.contains("\u{1F6E4}\u{FE0F}"));
+    /// assert!(!emoji.contains("A"));
+    /// ```
+    #[inline]
+    pub fn as_borrowed(&self) -> UnicodeSetDataBorrowed<'_> {
+        UnicodeSetDataBorrowed {
+            set: self.data.get(),
+        }
+    }
+
+    /// Construct a new one from loaded data
+    pub fn from_data<M>(data: DataPayload<M>) -> Self
+    where
+        M: DataMarker<Yokeable = PropertyUnicodeSetV1<'static>>,
+    {
+        Self {
+            data: data.map_project(|m, _| m),
+        }
+    }
+
+    /// Construct a new one from an owned [`CodePointInversionListAndStringList`]
+    pub fn from_code_point_inversion_list_string_list(
+        cpilsl: CodePointInversionListAndStringList<'static>,
+    ) -> Self {
+        let set = PropertyUnicodeSetV1::from_code_point_inversion_list_string_list(cpilsl);
+        UnicodeSetData::from_data(DataPayload::<ErasedUnicodeSetlikeMarker>::from_owned(set))
+    }
+
+    /// Convert this type to a [`CodePointInversionListAndStringList`] as a borrowed value.
+    ///
+    /// The data backing this is extensible and supports multiple implementations.
+    /// Currently it is always [`CodePointInversionListAndStringList`]; however in the future more backends may be
+    /// added, and users may select which at data generation time.
+    ///
+    /// This method returns an `Option` in order to return `None` when the backing data provider
+    /// cannot return a [`CodePointInversionListAndStringList`], or cannot do so within the expected constant time
+    /// constraint.
+    pub fn as_code_point_inversion_list_string_list(
+        &self,
+    ) -> Option<&CodePointInversionListAndStringList<'_>> {
+        self.data.get().as_code_point_inversion_list_string_list()
+    }
+
+    /// Convert this type to a [`CodePointInversionListAndStringList`], borrowing if possible,
+    /// otherwise allocating a new [`CodePointInversionListAndStringList`].
+    ///
+    /// The data backing this is extensible and supports multiple implementations.
+    /// Currently it is always [`CodePointInversionListAndStringList`]; however in the future more backends may be
+    /// added, and users may select which at data generation time.
+    ///
+    /// The performance of the conversion to this specific return type will vary
+    /// depending on the data structure that is backing `self`.
+    pub fn to_code_point_inversion_list_string_list(
+        &self,
+    ) -> CodePointInversionListAndStringList<'_> {
+        self.data.get().to_code_point_inversion_list_string_list()
+    }
+}
+
+/// A borrowed wrapper around a set of characters and strings, returned by
+/// [`UnicodeSetData::as_borrowed()`]. More efficient to query.
+#[derive(Clone, Copy)]
+pub struct UnicodeSetDataBorrowed<'a> {
+    set: &'a PropertyUnicodeSetV1<'a>,
+}
+
+impl<'a> UnicodeSetDataBorrowed<'a> {
+    /// Check if the set contains the string. Strings consisting of one character
+    /// are treated as a character/code point.
+    ///
+    /// This matches ICU behavior for ICU's `UnicodeSet`.
+    ///
+    /// ```rust
+    /// use icu_collections::codepointinvlist::CodePointInversionList;
+    /// use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+    /// use icu_properties::sets::UnicodeSetData;
+    /// use zerovec::VarZeroVec;
+    ///
+    /// let cp_inv_list = CodePointInversionList::try_from_inversion_list_slice(&[0x61, 0x62])
+    ///     .expect("Valid inversion list");
+    /// let str_list: VarZeroVec<str> = VarZeroVec::from(&["🇺🇳"]);
+    /// let data = UnicodeSetData::from_code_point_inversion_list_string_list(
+    ///     CodePointInversionListAndStringList::try_from(cp_inv_list, str_list)
+    ///         .expect("Valid set"),
+    /// );
+    /// let set = data.as_borrowed();
+    ///
+    /// assert!(set.contains("🇺🇳")); // U+1F1FA U+1F1F3 FLAG: UNITED NATIONS
+    /// assert!(!set.contains("🇺"));
+    /// assert!(set.contains("a"));
+    /// ```
+    #[inline]
+    pub fn contains(self, s: &str) -> bool {
+        self.set.contains(s)
+    }
+
+    /// Check if the set contains a code point as a UTF32 code unit
+    #[inline]
+    pub fn contains32(self, cp: u32) -> bool {
+        self.set.contains32(cp)
+    }
+
+    /// Check if the set contains the code point corresponding to the Rust character.
+    #[inline]
+    pub fn contains_char(self, ch: char) -> bool {
+        self.set.contains_char(ch)
+    }
+}
+
 //
 // Binary property getter fns
 //
@@ -1633,6 +1785,304 @@ make_set_property! {
     pub fn load_xid_start();
 }
 
+//
+// Binary property lookup by name
+//
+
+macro_rules! make_load_for_name {
+    ($(($funcname:ident, $keyed_data_marker:ident, [$($name:literal),+]),)+) => {
+        /// 🚧 \[Experimental\] Return a [`CodePointSetData`] for the binary property with the
+        /// short or long name `name`, using the loose matching rule [UAX44-LM3].
+        ///
+        /// <div class="stab unstable">
+        /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+        /// including in SemVer minor releases. It can be enabled with the "experimental" feature
+        /// of the icu meta-crate. Use with caution.
+        /// </div>
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        /// <div class="stab unstable">
+        /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+        /// </div>
+        ///
+        /// [UAX44-LM3]: https://www.unicode.org/reports/tr44/#UAX44-LM3
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use icu_properties::sets;
+        ///
+        /// let data = sets::load_for_name_with_any_provider(&icu_testdata::any(), "is-Alphabetic")
+        ///     .expect("The data should be valid");
+        /// let alphabetic = data.as_borrowed();
+        ///
+        /// assert!(alphabetic.contains('A'));
+        /// assert!(!alphabetic.contains('3'));
+        ///
+        /// assert!(sets::load_for_name_with_any_provider(&icu_testdata::any(), "Alphabetical").is_err());
+        /// ```
+        #[cfg(feature = "experimental")]
+        pub fn load_for_name_unstable<P>(
+            provider: &P,
+            name: &str,
+        ) -> Result<CodePointSetData, PropertiesError>
+        where
+            P: ?Sized $(+ DataProvider<$keyed_data_marker>)+,
+        {
+            $(
+                if [$($name),+].iter().any(|candidate| names::loose_eq(name, candidate)) {
+                    return $funcname(provider);
+                }
+            )+
+            Err(PropertiesError::UnknownPropertyName)
+        }
+    };
+}
+
+make_load_for_name!(
+    (
+        load_ascii_hex_digit,
+        AsciiHexDigitV1Marker,
+        ["AHex", "ASCII_Hex_Digit"]
+    ),
+    (load_alnum, AlnumV1Marker, ["alnum"]),
+    (load_alphabetic, AlphabeticV1Marker, ["Alpha", "Alphabetic"]),
+    (
+        load_bidi_control,
+        BidiControlV1Marker,
+        ["Bidi_C", "Bidi_Control"]
+    ),
+    (
+        load_bidi_mirrored,
+        BidiMirroredV1Marker,
+        ["Bidi_M", "Bidi_Mirrored"]
+    ),
+    (load_blank, BlankV1Marker, ["blank"]),
+    (load_cased, CasedV1Marker, ["Cased"]),
+    (
+        load_case_ignorable,
+        CaseIgnorableV1Marker,
+        ["CI", "Case_Ignorable"]
+    ),
+    (
+        load_full_composition_exclusion,
+        FullCompositionExclusionV1Marker,
+        ["Comp_Ex", "Full_Composition_Exclusion"]
+    ),
+    (
+        load_changes_when_casefolded,
+        ChangesWhenCasefoldedV1Marker,
+        ["CWCF", "Changes_When_Casefolded"]
+    ),
+    (
+        load_changes_when_casemapped,
+        ChangesWhenCasemappedV1Marker,
+        ["CWCM", "Changes_When_Casemapped"]
+    ),
+    (
+        load_changes_when_nfkc_casefolded,
+        ChangesWhenNfkcCasefoldedV1Marker,
+        ["CWKCF", "Changes_When_NFKC_Casefolded"]
+    ),
+    (
+        load_changes_when_lowercased,
+        ChangesWhenLowercasedV1Marker,
+        ["CWL", "Changes_When_Lowercased"]
+    ),
+    (
+        load_changes_when_titlecased,
+        ChangesWhenTitlecasedV1Marker,
+        ["CWT", "Changes_When_Titlecased"]
+    ),
+    (
+        load_changes_when_uppercased,
+        ChangesWhenUppercasedV1Marker,
+        ["CWU", "Changes_When_Uppercased"]
+    ),
+    (load_dash, DashV1Marker, ["Dash"]),
+    (load_deprecated, DeprecatedV1Marker, ["Dep", "Deprecated"]),
+    (
+        load_default_ignorable_code_point,
+        DefaultIgnorableCodePointV1Marker,
+        ["DI", "Default_Ignorable_Code_Point"]
+    ),
+    (load_diacritic, DiacriticV1Marker, ["Dia", "Diacritic"]),
+    (
+        load_emoji_modifier_base,
+        EmojiModifierBaseV1Marker,
+        ["EBase", "Emoji_Modifier_Base"]
+    ),
+    (
+        load_emoji_component,
+        EmojiComponentV1Marker,
+        ["EComp", "Emoji_Component"]
+    ),
+    (
+        load_emoji_modifier,
+        EmojiModifierV1Marker,
+        ["EMod", "Emoji_Modifier"]
+    ),
+    (load_emoji, EmojiV1Marker, ["Emoji"]),
+    (
+        load_emoji_presentation,
+        EmojiPresentationV1Marker,
+        ["EPres", "Emoji_Presentation"]
+    ),
+    (load_extender, ExtenderV1Marker, ["Ext", "Extender"]),
+    (
+        load_extended_pictographic,
+        ExtendedPictographicV1Marker,
+        ["ExtPict", "Extended_Pictographic"]
+    ),
+    (load_graph, GraphV1Marker, ["graph"]),
+    (
+        load_grapheme_base,
+        GraphemeBaseV1Marker,
+        ["Gr_Base", "Grapheme_Base"]
+    ),
+    (
+        load_grapheme_extend,
+        GraphemeExtendV1Marker,
+        ["Gr_Ext", "Grapheme_Extend"]
+    ),
+    (
+        load_grapheme_link,
+        GraphemeLinkV1Marker,
+        ["Gr_Link", "Grapheme_Link"]
+    ),
+    (load_hex_digit, HexDigitV1Marker, ["Hex", "Hex_Digit"]),
+    (load_hyphen, HyphenV1Marker, ["Hyphen"]),
+    (load_id_continue, IdContinueV1Marker, ["IDC", "ID_Continue"]),
+    (
+        load_ideographic,
+        IdeographicV1Marker,
+        ["Ideo", "Ideographic"]
+    ),
+    (load_id_start, IdStartV1Marker, ["IDS", "ID_Start"]),
+    (
+        load_ids_binary_operator,
+        IdsBinaryOperatorV1Marker,
+        ["IDSB", "IDS_Binary_Operator"]
+    ),
+    (
+        load_ids_trinary_operator,
+        IdsTrinaryOperatorV1Marker,
+        ["IDST", "IDS_Trinary_Operator"]
+    ),
+    (
+        load_join_control,
+        JoinControlV1Marker,
+        ["Join_C", "Join_Control"]
+    ),
+    (
+        load_logical_order_exception,
+        LogicalOrderExceptionV1Marker,
+        ["LOE", "Logical_Order_Exception"]
+    ),
+    (load_lowercase, LowercaseV1Marker, ["Lower", "Lowercase"]),
+    (load_math, MathV1Marker, ["Math"]),
+    (
+        load_noncharacter_code_point,
+        NoncharacterCodePointV1Marker,
+        ["NChar", "Noncharacter_Code_Point"]
+    ),
+    (load_nfc_inert, NfcInertV1Marker, ["NFC_Inert"]),
+    (load_nfd_inert, NfdInertV1Marker, ["NFD_Inert"]),
+    (load_nfkc_inert, NfkcInertV1Marker, ["NFKC_Inert"]),
+    (load_nfkd_inert, NfkdInertV1Marker, ["NFKD_Inert"]),
+    (
+        load_pattern_syntax,
+        PatternSyntaxV1Marker,
+        ["Pat_Syn", "Pattern_Syntax"]
+    ),
+    (
+        load_pattern_white_space,
+        PatternWhiteSpaceV1Marker,
+        ["Pat_WS", "Pattern_White_Space"]
+    ),
+    (
+        load_prepended_concatenation_mark,
+        PrependedConcatenationMarkV1Marker,
+        ["PCM", "Prepended_Concatenation_Mark"]
+    ),
+    (load_print, PrintV1Marker, ["print"]),
+    (
+        load_quotation_mark,
+        QuotationMarkV1Marker,
+        ["QMark", "Quotation_Mark"]
+    ),
+    (load_radical, RadicalV1Marker, ["Radical"]),
+    (
+        load_regional_indicator,
+        RegionalIndicatorV1Marker,
+        ["RI", "Regional_Indicator"]
+    ),
+    (load_soft_dotted, SoftDottedV1Marker, ["SD", "Soft_Dotted"]),
+    (
+        load_segment_starter,
+        SegmentStarterV1Marker,
+        ["Segment_Starter"]
+    ),
+    (
+        load_case_sensitive,
+        CaseSensitiveV1Marker,
+        ["Sensitive", "Case_Sensitive"]
+    ),
+    (
+        load_sentence_terminal,
+        SentenceTerminalV1Marker,
+        ["STerm", "Sentence_Terminal"]
+    ),
+    (
+        load_terminal_punctuation,
+        TerminalPunctuationV1Marker,
+        ["Term", "Terminal_Punctuation"]
+    ),
+    (
+        load_unified_ideograph,
+        UnifiedIdeographV1Marker,
+        ["UIdeo", "Unified_Ideograph"]
+    ),
+    (load_uppercase, UppercaseV1Marker, ["Upper", "Uppercase"]),
+    (
+        load_variation_selector,
+        VariationSelectorV1Marker,
+        ["VS", "Variation_Selector"]
+    ),
+    (
+        load_white_space,
+        WhiteSpaceV1Marker,
+        ["WSpace", "White_Space", "space"]
+    ),
+    (load_xdigit, XdigitV1Marker, ["xdigit"]),
+    (
+        load_xid_continue,
+        XidContinueV1Marker,
+        ["XIDC", "XID_Continue"]
+    ),
+    (load_xid_start, XidStartV1Marker, ["XIDS", "XID_Start"]),
+);
+
+#[cfg(feature = "experimental")]
+#[doc = icu_provider::gen_any_buffer_docs!(ANY, icu_provider, load_for_name_unstable)]
+pub fn load_for_name_with_any_provider(
+    provider: &(impl AnyProvider + ?Sized),
+    name: &str,
+) -> Result<CodePointSetData, PropertiesError> {
+    use icu_provider::AsDowncastingAnyProvider;
+    load_for_name_unstable(&provider.as_downcasting(), name)
+}
+
+#[cfg(all(feature = "experimental", feature = "serde"))]
+#[doc = icu_provider::gen_any_buffer_docs!(BUFFER, icu_provider, load_for_name_unstable)]
+pub fn load_for_name_with_buffer_provider(
+    provider: &(impl BufferProvider + ?Sized),
+    name: &str,
+) -> Result<CodePointSetData, PropertiesError> {
+    use icu_provider::AsDeserializingBufferProvider;
+    load_for_name_unstable(&provider.as_deserializing(), name)
+}
+
 //
 // Enumerated property getter fns
 //
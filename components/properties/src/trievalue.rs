@@ -4,8 +4,9 @@
 
 use crate::script::ScriptWithExt;
 use crate::{
-    BidiClass, CanonicalCombiningClass, EastAsianWidth, GeneralCategory, GraphemeClusterBreak,
-    LineBreak, Script, SentenceBreak, WordBreak,
+    Age, BidiClass, CanonicalCombiningClass, CodePointValue, EastAsianWidth, GeneralCategory,
+    GraphemeClusterBreak, HangulSyllableType, IndicSyllabicCategory, JoiningType, LineBreak,
+    NumericType, NumericValue, Script, SentenceBreak, VerticalOrientation, WordBreak,
 };
 use core::convert::TryInto;
 use core::num::TryFromIntError;
@@ -94,3 +95,122 @@ impl TrieValue for SentenceBreak {
         u8::try_from(i).map(Self)
     }
 }
+
+impl TrieValue for JoiningType {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u8::try_from(i).map(Self)
+    }
+}
+
+impl From<JoiningType> for u32 {
+    fn from(value: JoiningType) -> Self {
+        value.0.into()
+    }
+}
+
+impl TrieValue for NumericType {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u8::try_from(i).map(Self)
+    }
+}
+
+impl From<NumericType> for u32 {
+    fn from(value: NumericType) -> Self {
+        value.0.into()
+    }
+}
+
+impl TrieValue for NumericValue {
+    type TryFromU32Error = core::convert::Infallible;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        Ok(Self(i))
+    }
+}
+
+impl From<NumericValue> for u32 {
+    fn from(value: NumericValue) -> Self {
+        value.0
+    }
+}
+
+impl TrieValue for IndicSyllabicCategory {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u8::try_from(i).map(Self)
+    }
+}
+
+impl From<IndicSyllabicCategory> for u32 {
+    fn from(value: IndicSyllabicCategory) -> Self {
+        value.0.into()
+    }
+}
+
+impl TrieValue for HangulSyllableType {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u8::try_from(i).map(Self)
+    }
+}
+
+impl From<HangulSyllableType> for u32 {
+    fn from(value: HangulSyllableType) -> Self {
+        value.0.into()
+    }
+}
+
+impl TrieValue for VerticalOrientation {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u8::try_from(i).map(Self)
+    }
+}
+
+impl From<VerticalOrientation> for u32 {
+    fn from(value: VerticalOrientation) -> Self {
+        value.0.into()
+    }
+}
+
+/// The trie values of [`Age`] have the major version in the high byte and the minor version
+/// in the low byte.
+impl TrieValue for Age {
+    type TryFromU32Error = TryFromIntError;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        u16::try_from(i).map(|i| Age {
+            major: (i >> 8) as u8,
+            minor: i as u8,
+        })
+    }
+}
+
+impl From<Age> for u32 {
+    fn from(age: Age) -> Self {
+        u32::from(age.major) << 8 | u32::from(age.minor)
+    }
+}
+
+/// The trie values of [`CodePointValue`] are the code points, and any value that is not a
+/// scalar value, like `0x11_0000`, stands for `None`.
+impl TrieValue for CodePointValue {
+    type TryFromU32Error = core::convert::Infallible;
+
+    fn try_from_u32(i: u32) -> Result<Self, Self::TryFromU32Error> {
+        Ok(Self(char::from_u32(i)))
+    }
+}
+
+impl From<CodePointValue> for u32 {
+    fn from(value: CodePointValue) -> Self {
+        value.0.map(u32::from).unwrap_or(0x11_0000)
+    }
+}
@@ -0,0 +1,186 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_properties::bidi::{
+    load_bidi_auxiliary_properties_unstable, BidiInfo, BidiResolver, ParagraphDirection,
+};
+use icu_properties::{maps, BidiClass};
+
+/// Checks the levels of a resolved paragraph after the rule L1 and its visual order against
+/// a test case. The characters removed by the rule X9 have no expected level, and are
+/// omitted from the expected order.
+fn check_levels_and_order(
+    info: &BidiInfo<'_, '_>,
+    expected_levels: &[Option<u8>],
+    expected_order: &[usize],
+    message: &str,
+) {
+    let text = info.text();
+    let levels = info
+        .line_levels(0..text.len())
+        .into_iter()
+        .zip(expected_levels)
+        .map(|(level, expected)| expected.map(|_| level.number()))
+        .collect::<Vec<_>>();
+    assert_eq!(levels, expected_levels, "{}", message);
+
+    let offsets = text.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+    let order = info
+        .visual_order(0..text.len())
+        .into_iter()
+        .map(|offset| offsets.binary_search(&offset).unwrap())
+        .filter(|&i| expected_levels[i].is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(order, expected_order, "{}", message);
+}
+
+fn parse_levels(levels: &str) -> Vec<Option<u8>> {
+    levels
+        .split_whitespace()
+        .map(|level| level.parse::<u8>().ok())
+        .collect()
+}
+
+fn parse_order(order: &str) -> Vec<usize> {
+    order
+        .split_whitespace()
+        .map(|index| index.parse::<usize>().unwrap())
+        .collect()
+}
+
+/// Runs the test cases of `BidiCharacterTest.txt`: for each line, the text, the paragraph
+/// direction, the resolved paragraph level, the resolved levels of the characters (`x` for
+/// the characters removed by the rule X9), and the visual order of the characters that
+/// are not removed.
+#[test]
+fn test_bidi_character_test() {
+    let bidi_class = maps::load_bidi_class(&icu_testdata::unstable()).unwrap();
+    let auxiliary = load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable()).unwrap();
+    let resolver = BidiResolver::new(bidi_class.as_borrowed(), auxiliary.as_borrowed());
+
+    let test_data = include_str!("data/BidiCharacterTest.txt");
+    for (line_number, line) in test_data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields = line.split(';').collect::<Vec<_>>();
+        assert_eq!(fields.len(), 5, "line {}", line_number + 1);
+
+        let text = fields[0]
+            .split_whitespace()
+            .map(|hex| char::from_u32(u32::from_str_radix(hex, 16).unwrap()).unwrap())
+            .collect::<String>();
+        let direction = match fields[1] {
+            "0" => ParagraphDirection::LeftToRight,
+            "1" => ParagraphDirection::RightToLeft,
+            "2" => ParagraphDirection::Auto,
+            direction => panic!("Unknown paragraph direction {}", direction),
+        };
+        let paragraph_level = fields[2].parse::<u8>().unwrap();
+
+        let info = resolver.resolve(&text, direction);
+        let message = format!("line {}: {}", line_number + 1, line);
+
+        assert_eq!(
+            info.paragraphs()[0].level().number(),
+            paragraph_level,
+            "{}",
+            message
+        );
+        check_levels_and_order(
+            &info,
+            &parse_levels(fields[3]),
+            &parse_order(fields[4]),
+            &message,
+        );
+    }
+}
+
+/// A character of each Bidi_Class, by its short name, to run `BidiTest.txt` on. None of
+/// them is a paired bracket.
+const BIDI_CLASS_CHARACTERS: &[(&str, char, BidiClass)] = &[
+    ("L", 'a', BidiClass::LeftToRight),
+    ("R", '\u{05D0}', BidiClass::RightToLeft),
+    ("EN", '0', BidiClass::EuropeanNumber),
+    ("ES", '+', BidiClass::EuropeanSeparator),
+    ("ET", '$', BidiClass::EuropeanTerminator),
+    ("AN", '\u{0660}', BidiClass::ArabicNumber),
+    ("CS", ',', BidiClass::CommonSeparator),
+    ("B", '\u{2029}', BidiClass::ParagraphSeparator),
+    ("S", '\t', BidiClass::SegmentSeparator),
+    ("WS", ' ', BidiClass::WhiteSpace),
+    ("ON", '!', BidiClass::OtherNeutral),
+    ("LRE", '\u{202A}', BidiClass::LeftToRightEmbedding),
+    ("LRO", '\u{202D}', BidiClass::LeftToRightOverride),
+    ("AL", '\u{0627}', BidiClass::ArabicLetter),
+    ("RLE", '\u{202B}', BidiClass::RightToLeftEmbedding),
+    ("RLO", '\u{202E}', BidiClass::RightToLeftOverride),
+    ("PDF", '\u{202C}', BidiClass::PopDirectionalFormat),
+    ("NSM", '\u{0300}', BidiClass::NonspacingMark),
+    ("BN", '\u{00AD}', BidiClass::BoundaryNeutral),
+    ("FSI", '\u{2068}', BidiClass::FirstStrongIsolate),
+    ("LRI", '\u{2066}', BidiClass::LeftToRightIsolate),
+    ("RLI", '\u{2067}', BidiClass::RightToLeftIsolate),
+    ("PDI", '\u{2069}', BidiClass::PopDirectionalIsolate),
+];
+
+/// Runs the test cases of `BidiTest.txt`: sequences of Bidi_Class values, each resolved
+/// with the paragraph directions in its bitset, which share the expected levels and visual
+/// order of the preceding `@Levels` and `@Reorder` lines. The sequences are run as text
+/// made of a character of each Bidi_Class.
+#[test]
+fn test_bidi_test() {
+    let bidi_class = maps::load_bidi_class(&icu_testdata::unstable()).unwrap();
+    let auxiliary = load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable()).unwrap();
+    let resolver = BidiResolver::new(bidi_class.as_borrowed(), auxiliary.as_borrowed());
+
+    for &(name, ch, class) in BIDI_CLASS_CHARACTERS {
+        assert_eq!(bidi_class.as_borrowed().get(ch), class, "{}", name);
+    }
+
+    let test_data = include_str!("data/BidiTest.txt");
+    let mut expected_levels = Vec::new();
+    let mut expected_order = Vec::new();
+    for (line_number, line) in test_data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if let Some(levels) = line.strip_prefix("@Levels:") {
+            expected_levels = parse_levels(levels);
+            continue;
+        }
+        if let Some(order) = line.strip_prefix("@Reorder:") {
+            expected_order = parse_order(order);
+            continue;
+        }
+        if line.is_empty() || line.starts_with('@') {
+            continue;
+        }
+        let (input, bitset) = line.split_once(';').unwrap();
+
+        let text = input
+            .split_whitespace()
+            .map(|name| {
+                BIDI_CLASS_CHARACTERS
+                    .iter()
+                    .find(|&&(n, _, _)| n == name)
+                    .unwrap_or_else(|| panic!("Unknown Bidi_Class {}", name))
+                    .1
+            })
+            .collect::<String>();
+        let bitset = u8::from_str_radix(bitset.trim(), 16).unwrap();
+
+        for (bit, direction) in [
+            (1, ParagraphDirection::Auto),
+            (2, ParagraphDirection::LeftToRight),
+            (4, ParagraphDirection::RightToLeft),
+        ] {
+            if bitset & bit == 0 {
+                continue;
+            }
+            let info = resolver.resolve(&text, direction);
+            let message = format!("line {}: {} ({:?})", line_number + 1, line, direction);
+            check_levels_and_order(&info, &expected_levels, &expected_order, &message);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_properties::identifier::{
+    IdentifierError, IdentifierProfile, IdentifierValidator, JoinControls,
+};
+
+fn validator(profile: IdentifierProfile) -> IdentifierValidator {
+    IdentifierValidator::try_new_unstable(&icu_testdata::unstable(), profile)
+        .expect("The data should be valid")
+}
+
+#[test]
+fn test_default_profile() {
+    let validator = validator(IdentifierProfile::default());
+
+    assert_eq!(validator.validate(""), Err(IdentifierError::Empty));
+    assert_eq!(validator.validate("x"), Ok(()));
+    assert_eq!(validator.validate("Δx_1"), Ok(()));
+    assert_eq!(
+        validator.validate("_x"),
+        Err(IdentifierError::InvalidStart {
+            position: 0,
+            character: '_'
+        })
+    );
+    assert_eq!(
+        validator.validate("ab cd"),
+        Err(IdentifierError::InvalidCharacter {
+            position: 2,
+            character: ' '
+        })
+    );
+    // Without a profile that allows them, join controls are invalid characters.
+    assert_eq!(
+        validator.validate("क्\u{200C}ष"),
+        Err(IdentifierError::InvalidCharacter {
+            position: 6,
+            character: '\u{200C}'
+        })
+    );
+}
+
+#[test]
+fn test_nfkc_closed() {
+    // U+037A GREEK YPOGEGRAMMENI is ID_Start, but its NFKC, U+0020 U+0345, is not an
+    // identifier, so it is not XID_Start.
+    assert_eq!(
+        validator(IdentifierProfile::default()).validate("\u{37A}"),
+        Ok(())
+    );
+    let mut profile = IdentifierProfile::default();
+    profile.nfkc_closed = true;
+    assert_eq!(
+        validator(profile).validate("\u{37A}"),
+        Err(IdentifierError::InvalidStart {
+            position: 0,
+            character: '\u{37A}'
+        })
+    );
+}
+
+#[test]
+fn test_additional_characters() {
+    let mut profile = IdentifierProfile::default();
+    profile.additional_start = vec!['$', '_'];
+    profile.additional_continue = vec!['\''];
+    profile.medial = vec!['-', '.', '·'];
+    let validator = validator(profile);
+
+    assert_eq!(validator.validate("$_"), Ok(()));
+    assert_eq!(validator.validate("x'"), Ok(()));
+    assert_eq!(validator.validate("a.b-c"), Ok(()));
+    // U+00B7 MIDDLE DOT is ID_Continue, so it can be repeated and end an identifier.
+    assert_eq!(validator.validate("l··"), Ok(()));
+    assert_eq!(
+        validator.validate("'x"),
+        Err(IdentifierError::InvalidStart {
+            position: 0,
+            character: '\''
+        })
+    );
+    assert_eq!(
+        validator.validate("-x"),
+        Err(IdentifierError::InvalidStart {
+            position: 0,
+            character: '-'
+        })
+    );
+    assert_eq!(
+        validator.validate("a.-b"),
+        Err(IdentifierError::MisplacedMedial {
+            position: 1,
+            character: '.'
+        })
+    );
+    assert_eq!(
+        validator.validate("ab-"),
+        Err(IdentifierError::MisplacedMedial {
+            position: 2,
+            character: '-'
+        })
+    );
+}
+
+#[test]
+fn test_join_controls() {
+    let mut profile = IdentifierProfile::default();
+    profile.join_controls = JoinControls::Contextual;
+    let validator = validator(profile);
+
+    // A1: U+0628 ARABIC LETTER BEH is dual-joining, and U+064E ARABIC FATHA transparent.
+    assert_eq!(validator.validate("ب\u{64E}\u{200C}\u{64E}ب"), Ok(()));
+    // U+0627 ARABIC LETTER ALEF is right-joining, so it does not join to the left.
+    assert_eq!(
+        validator.validate("ا\u{200C}ب"),
+        Err(IdentifierError::InvalidJoinControl {
+            position: 2,
+            character: '\u{200C}'
+        })
+    );
+    // A2: after a virama, with U+0941 DEVANAGARI VOWEL SIGN U as a nonspacing mark before it.
+    assert_eq!(validator.validate("क\u{941}्\u{200C}ष"), Ok(()));
+    // B: U+093F DEVANAGARI VOWEL SIGN I is a dependent vowel.
+    assert_eq!(validator.validate("क्\u{200D}ष"), Ok(()));
+    assert_eq!(
+        validator.validate("क्\u{200D}\u{93F}"),
+        Err(IdentifierError::InvalidJoinControl {
+            position: 6,
+            character: '\u{200D}'
+        })
+    );
+    // The virama must follow a letter.
+    assert_eq!(
+        validator.validate("x1्\u{200D}"),
+        Err(IdentifierError::InvalidJoinControl {
+            position: 5,
+            character: '\u{200D}'
+        })
+    );
+    assert_eq!(
+        validator.validate("\u{200C}x"),
+        Err(IdentifierError::InvalidStart {
+            position: 0,
+            character: '\u{200C}'
+        })
+    );
+}
+
+#[test]
+fn test_pattern_characters() {
+    let validator = validator(IdentifierProfile::default());
+
+    for c in ['(', '+', '«', '、'] {
+        assert!(validator.is_pattern_syntax(c), "{:?}", c);
+        assert!(!validator.is_pattern_white_space(c), "{:?}", c);
+    }
+    for c in [' ', '\n', '\u{200E}', '\u{2029}'] {
+        assert!(validator.is_pattern_white_space(c), "{:?}", c);
+        assert!(!validator.is_pattern_syntax(c), "{:?}", c);
+    }
+    for c in ['a', '_', '0', '\u{A0}'] {
+        assert!(!validator.is_pattern_syntax(c), "{:?}", c);
+        assert!(!validator.is_pattern_white_space(c), "{:?}", c);
+    }
+}
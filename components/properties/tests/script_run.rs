@@ -0,0 +1,138 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_properties::bidi::load_bidi_auxiliary_properties_unstable;
+use icu_properties::script::{load_script_with_extensions_unstable, ScriptRunSegmenter};
+use icu_properties::Script;
+
+fn runs(text: &str) -> Vec<(&str, Script)> {
+    let script_with_extensions =
+        load_script_with_extensions_unstable(&icu_testdata::unstable()).unwrap();
+    let bidi_auxiliary =
+        load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable()).unwrap();
+    let segmenter = ScriptRunSegmenter::new(
+        script_with_extensions.as_borrowed(),
+        bidi_auxiliary.as_borrowed(),
+    );
+
+    let runs = segmenter
+        .segment_str(text)
+        .map(|(range, script)| (&text[range], script))
+        .collect::<Vec<_>>();
+
+    // The runs over UTF-16 text are the same, with the offsets in code units.
+    let utf16 = text.encode_utf16().collect::<Vec<_>>();
+    let utf16_runs = segmenter
+        .segment_utf16(&utf16)
+        .map(|(range, script)| (String::from_utf16(&utf16[range]).unwrap(), script))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        utf16_runs,
+        runs.iter()
+            .map(|&(run, script)| (run.to_string(), script))
+            .collect::<Vec<_>>()
+    );
+
+    runs
+}
+
+#[test]
+fn test_common_and_inherited() {
+    assert_eq!(runs(""), []);
+    assert_eq!(runs(" 123 !"), [(" 123 !", Script::Common)]);
+    // Leading and trailing Common characters take the script of their run.
+    assert_eq!(
+        runs("«Ελλάδα» - Россия"),
+        [("«Ελλάδα» - ", Script::Greek), ("Россия", Script::Cyrillic)]
+    );
+    // U+0301 COMBINING ACUTE ACCENT is Inherited.
+    assert_eq!(
+        runs("e\u{301}д\u{301}"),
+        [("e\u{301}", Script::Latin), ("д\u{301}", Script::Cyrillic)]
+    );
+}
+
+#[test]
+fn test_script_extensions() {
+    // U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK is Common, with the Script_Extensions
+    // Hiragana and Katakana.
+    assert_eq!(
+        runs("らーめん、ラーメン"),
+        [
+            ("らーめん、", Script::Hiragana),
+            ("ラーメン", Script::Katakana)
+        ]
+    );
+    // U+3001 IDEOGRAPHIC COMMA cannot be written in Latin.
+    assert_eq!(
+        runs("abc、def"),
+        [
+            ("abc", Script::Latin),
+            ("、", Script::Common),
+            ("def", Script::Latin)
+        ]
+    );
+    // U+0640 ARABIC TATWEEL takes the script of the run among its Script_Extensions.
+    assert_eq!(runs("ـب"), [("ـب", Script::Arabic)]);
+    assert_eq!(runs("ـ"), [("ـ", Script::Common)]);
+    assert_eq!(runs("aـ"), [("a", Script::Latin), ("ـ", Script::Common)]);
+    // U+0660 ARABIC-INDIC DIGIT ZERO is Arabic, with the Script_Extensions Arabic and Thaana.
+    assert_eq!(runs("ބ٠"), [("ބ٠", Script::Thaana)]);
+}
+
+#[test]
+fn test_brackets() {
+    assert_eq!(
+        runs("abc (ελλάδα) def"),
+        [
+            ("abc (", Script::Latin),
+            ("ελλάδα", Script::Greek),
+            (") def", Script::Latin)
+        ]
+    );
+    // The brackets at the start of the text take the script of the first run.
+    assert_eq!(
+        runs("[ελλάδα] abc"),
+        [("[ελλάδα] ", Script::Greek), ("abc", Script::Latin)]
+    );
+    // Nested brackets, with an unmatched closing bracket.
+    assert_eq!(
+        runs("a (б [γ] б} ) a"),
+        [
+            ("a (", Script::Latin),
+            ("б [", Script::Cyrillic),
+            ("γ", Script::Greek),
+            ("] б} ", Script::Cyrillic),
+            (") a", Script::Latin),
+        ]
+    );
+    // U+2329 is canonically equivalent to U+3008, which pairs with U+3009.
+    assert_eq!(
+        runs("a\u{2329}γ\u{3009}"),
+        [
+            ("a\u{2329}", Script::Latin),
+            ("γ", Script::Greek),
+            ("\u{3009}", Script::Latin)
+        ]
+    );
+}
+
+#[test]
+fn test_utf16() {
+    let script_with_extensions =
+        load_script_with_extensions_unstable(&icu_testdata::unstable()).unwrap();
+    let bidi_auxiliary =
+        load_bidi_auxiliary_properties_unstable(&icu_testdata::unstable()).unwrap();
+    let segmenter = ScriptRunSegmenter::new(
+        script_with_extensions.as_borrowed(),
+        bidi_auxiliary.as_borrowed(),
+    );
+
+    // U+10330 GOTHIC LETTER AHSA is a surrogate pair, and an unpaired surrogate is Common.
+    let text = [0x61, 0xD800, 0x62, 0xD800, 0xDF30, 0xD800, 0xDF30];
+    assert_eq!(
+        segmenter.segment_utf16(&text).collect::<Vec<_>>(),
+        [(0..3, Script::Latin), (3..7, Script::Gothic)]
+    );
+}
@@ -0,0 +1,164 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Caseless matching that ignores differences in normalization, as defined in
+//! section 3.13 of the Unicode Standard, and the `NFKC_Casefold` mapping.
+//!
+//! See [`CaselessMatcher`].
+
+use crate::error::Error;
+use crate::provider::CaseMappingV1Marker;
+use crate::CaseMapping;
+use icu_normalizer::provider::*;
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu_properties::provider::DefaultIgnorableCodePointV1Marker;
+use icu_properties::sets::CodePointSetData;
+use icu_provider::prelude::*;
+
+/// Caseless matching of strings that also ignores differences in normalization, as
+/// defined in section 3.13 of the Unicode Standard.
+///
+/// For matching that only ignores case, see [`CaseMapping::eq_ignore_case`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_casemapping::CaselessMatcher;
+///
+/// let matcher = CaselessMatcher::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// assert!(matcher.canonical_caseless_eq("Éclair", "e\u{301}CLAIR"));
+/// assert!(!matcher.canonical_caseless_eq("ﬁle", "FILE\u{00AD}"));
+/// assert!(matcher.identifier_caseless_eq("ﬁle", "FILE\u{00AD}"));
+/// assert_eq!(matcher.nfkc_casefold("Ⅻ\u{00AD}ẞ"), "xiiss");
+/// ```
+pub struct CaselessMatcher {
+    case_mapping: CaseMapping,
+    nfd: DecomposingNormalizer,
+    nfkd: DecomposingNormalizer,
+    nfkc: ComposingNormalizer,
+    default_ignorable: CodePointSetData,
+}
+
+impl CaselessMatcher {
+    /// Creates a new [`CaselessMatcher`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, Error>
+    where
+        D: DataProvider<CaseMappingV1Marker>
+            + DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CompatibilityDecompositionSupplementV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + DataProvider<CompatibilityDecompositionTablesV1Marker>
+            + DataProvider<CanonicalCompositionsV1Marker>
+            + DataProvider<DefaultIgnorableCodePointV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            case_mapping: CaseMapping::try_new(provider)?,
+            nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+            nfkd: DecomposingNormalizer::try_new_nfkd_unstable(provider)?,
+            nfkc: ComposingNormalizer::try_new_nfkc_unstable(provider)?,
+            default_ignorable: icu_properties::sets::load_default_ignorable_code_point(provider)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: Error);
+
+    /// Returns whether two strings are equal under canonical caseless matching, i.e.
+    /// whether `NFD(toCasefold(NFD(a)))` equals `NFD(toCasefold(NFD(b)))`
+    /// (definition D145 in section 3.13 of Unicode).
+    pub fn canonical_caseless_eq(&self, a: &str, b: &str) -> bool {
+        self.canonical_caseless_chars(a)
+            .eq(self.canonical_caseless_chars(b))
+    }
+
+    /// Returns `NFD(toCasefold(NFD(src)))`, so that strings can be compared under
+    /// canonical caseless matching by comparing these keys, e.g. when storing user names.
+    pub fn canonical_caseless_key(&self, src: &str) -> String {
+        self.canonical_caseless_chars(src).collect()
+    }
+
+    /// Returns whether two strings are equal under compatibility caseless matching, i.e.
+    /// whether `NFKD(toCasefold(NFKD(toCasefold(NFD(a)))))` equals the same for `b`
+    /// (definition D146 in section 3.13 of Unicode).
+    pub fn compatibility_caseless_eq(&self, a: &str, b: &str) -> bool {
+        self.compatibility_caseless_chars(a)
+            .eq(self.compatibility_caseless_chars(b))
+    }
+
+    /// Returns `NFKD(toCasefold(NFKD(toCasefold(NFD(src)))))`, so that strings can be
+    /// compared under compatibility caseless matching by comparing these keys.
+    pub fn compatibility_caseless_key(&self, src: &str) -> String {
+        self.compatibility_caseless_chars(src).collect()
+    }
+
+    /// Returns whether two strings are equal under identifier caseless matching, i.e.
+    /// whether `NFKC_Casefold(NFD(a))` equals `NFKC_Casefold(NFD(b))`
+    /// (definition D147 in section 3.13 of Unicode).
+    ///
+    /// Unlike compatibility caseless matching, this ignores default ignorable
+    /// code points, like soft hyphens and variation selectors.
+    pub fn identifier_caseless_eq(&self, a: &str, b: &str) -> bool {
+        self.nfkc_casefold(a) == self.nfkc_casefold(b)
+    }
+
+    /// Returns the `NFKC_Casefold` mapping of `src`, which removes differences in
+    /// case, compatibility variants and default ignorable code points. The result is
+    /// in NFKC.
+    ///
+    /// This is the key for identifier caseless matching; see
+    /// [`CaselessMatcher::identifier_caseless_eq`].
+    pub fn nfkc_casefold(&self, src: &str) -> String {
+        // The NFKC_Casefold mapping of a character is derived by applying NFKC, full case
+        // folding and the removal of default ignorable code points until the result is
+        // stable, and a string is mapped by mapping the characters of its NFD. This is done
+        // in one pass by folding the NFKD of each character rather than its NFD, since
+        // compatibility decompositions can be cased even when the characters they decompose
+        // are not, like "MHz" for U+3392 ㎒. Each character is decomposed on its own, so that
+        // like in its mapping, the decomposition of U+037A GREEK YPOGEGRAMMENI is not
+        // reordered with the accents after it.
+        let default_ignorable = self.default_ignorable.as_borrowed();
+        let decomposed = self
+            .nfd
+            .normalize_iter(src.chars())
+            .flat_map(|c| self.nfkd.normalize_iter(core::iter::once(c)));
+        self.nfkc
+            .normalize_iter(
+                self.case_mapping
+                    .folded_chars(decomposed)
+                    .filter(|&c| !default_ignorable.contains(c)),
+            )
+            .collect()
+    }
+
+    fn canonical_caseless_chars<'a>(&'a self, src: &'a str) -> impl Iterator<Item = char> + 'a {
+        self.nfd.normalize_iter(
+            self.case_mapping
+                .folded_chars(self.nfd.normalize_iter(src.chars())),
+        )
+    }
+
+    fn compatibility_caseless_chars<'a>(&'a self, src: &'a str) -> impl Iterator<Item = char> + 'a {
+        let folded = self
+            .case_mapping
+            .folded_chars(self.nfd.normalize_iter(src.chars()));
+        self.nfkd.normalize_iter(
+            self.case_mapping
+                .folded_chars(self.nfkd.normalize_iter(folded)),
+        )
+    }
+}
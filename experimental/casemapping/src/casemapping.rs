@@ -8,10 +8,20 @@ use crate::internals::*;
 #[cfg(feature = "datagen")]
 use crate::provider::CaseMappingV1;
 use crate::provider::CaseMappingV1Marker;
+#[cfg(feature = "icu_properties")]
+use crate::titlecase::TitlecaseOptions;
+use core::cmp::Ordering;
+use core::fmt;
 #[cfg(feature = "datagen")]
 use icu_collections::codepointtrie::CodePointTrieHeader;
 use icu_locid::Locale;
+#[cfg(feature = "icu_properties")]
+use icu_properties::maps::CodePointMapDataBorrowed;
+#[cfg(feature = "icu_properties")]
+use icu_properties::GeneralCategory;
 use icu_provider::prelude::*;
+use std::borrow::Cow;
+use writeable::{LengthHint, Writeable};
 
 /// A struct with the ability to convert characters and strings to uppercase or lowercase,
 /// or fold them to a normalized form for case-insensitive comparison.
@@ -123,37 +133,265 @@ impl CaseMapping {
 
     /// Returns the full lowercase mapping of the given string.
     /// This function is context and locale sensitive.
+    ///
+    /// See [`CaseMapping::lowercase`] for a version that does not allocate.
     pub fn to_full_lowercase(&self, src: &str) -> String {
-        self.internals
-            .get()
-            .casemap
-            .full_lowercase(src, self.locale)
+        self.lowercase(src).write_to_string().into_owned()
     }
 
     /// Returns the full uppercase mapping of the given string.
     /// This function is context and locale sensitive.
+    ///
+    /// See [`CaseMapping::uppercase`] for a version that does not allocate.
     pub fn to_full_uppercase(&self, src: &str) -> String {
-        self.internals
-            .get()
-            .casemap
-            .full_uppercase(src, self.locale)
+        self.uppercase(src).write_to_string().into_owned()
+    }
+
+    /// Returns a [`Writeable`] of the full lowercase mapping of the given string.
+    /// This function is context and locale sensitive.
+    pub fn lowercase<'a>(&'a self, src: &'a str) -> CaseMapped<'a> {
+        self.mapped(Text::Str(src), self.locale, MappingKind::Lower)
+    }
+
+    /// Returns a [`Writeable`] of the full lowercase mapping of the given
+    /// potentially-invalid UTF-8.
+    ///
+    /// Errors are mapped to the REPLACEMENT CHARACTER according
+    /// to the WHATWG Encoding Standard.
+    pub fn lowercase_utf8<'a>(&'a self, src: &'a [u8]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf8(src), self.locale, MappingKind::Lower)
+    }
+
+    /// Returns a [`Writeable`] of the full lowercase mapping of the given
+    /// potentially-invalid UTF-16.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    pub fn lowercase_utf16<'a>(&'a self, src: &'a [u16]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf16(src), self.locale, MappingKind::Lower)
+    }
+
+    /// Returns a [`Writeable`] of the full uppercase mapping of the given string.
+    /// This function is context and locale sensitive.
+    pub fn uppercase<'a>(&'a self, src: &'a str) -> CaseMapped<'a> {
+        self.mapped(Text::Str(src), self.locale, MappingKind::Upper)
+    }
+
+    /// Returns a [`Writeable`] of the full uppercase mapping of the given
+    /// potentially-invalid UTF-8.
+    ///
+    /// Errors are mapped to the REPLACEMENT CHARACTER according
+    /// to the WHATWG Encoding Standard.
+    pub fn uppercase_utf8<'a>(&'a self, src: &'a [u8]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf8(src), self.locale, MappingKind::Upper)
+    }
+
+    /// Returns a [`Writeable`] of the full uppercase mapping of the given
+    /// potentially-invalid UTF-16.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    pub fn uppercase_utf16<'a>(&'a self, src: &'a [u16]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf16(src), self.locale, MappingKind::Upper)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given string.
+    /// This function is locale-independent and context-insensitive.
+    pub fn case_fold<'a>(&'a self, src: &'a str) -> CaseMapped<'a> {
+        self.mapped(Text::Str(src), CaseMapLocale::Root, MappingKind::Fold)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given
+    /// potentially-invalid UTF-8.
+    ///
+    /// Errors are mapped to the REPLACEMENT CHARACTER according
+    /// to the WHATWG Encoding Standard.
+    pub fn case_fold_utf8<'a>(&'a self, src: &'a [u8]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf8(src), CaseMapLocale::Root, MappingKind::Fold)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given
+    /// potentially-invalid UTF-16.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    pub fn case_fold_utf16<'a>(&'a self, src: &'a [u16]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf16(src), CaseMapLocale::Root, MappingKind::Fold)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given string, using
+    /// Turkic (T) mappings for dotted/dotless I.
+    /// This function is locale-independent and context-insensitive.
+    pub fn case_fold_turkic<'a>(&'a self, src: &'a str) -> CaseMapped<'a> {
+        self.mapped(Text::Str(src), CaseMapLocale::Turkish, MappingKind::Fold)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given
+    /// potentially-invalid UTF-8, using Turkic (T) mappings for dotted/dotless I.
+    ///
+    /// Errors are mapped to the REPLACEMENT CHARACTER according
+    /// to the WHATWG Encoding Standard.
+    pub fn case_fold_turkic_utf8<'a>(&'a self, src: &'a [u8]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf8(src), CaseMapLocale::Turkish, MappingKind::Fold)
+    }
+
+    /// Returns a [`Writeable`] of the full case folding of the given
+    /// potentially-invalid UTF-16, using Turkic (T) mappings for dotted/dotless I.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    pub fn case_fold_turkic_utf16<'a>(&'a self, src: &'a [u16]) -> CaseMapped<'a> {
+        self.mapped(Text::Utf16(src), CaseMapLocale::Turkish, MappingKind::Fold)
+    }
+
+    fn mapped<'a>(
+        &'a self,
+        src: Text<'a>,
+        locale: CaseMapLocale,
+        kind: MappingKind,
+    ) -> CaseMapped<'a> {
+        CaseMapped {
+            internals: &self.internals.get().casemap,
+            src,
+            locale,
+            kind,
+        }
+    }
+
+    /// Writes the full titlecase mapping of `src`, split into segments at `boundaries`,
+    /// to `sink`. See [`TitlecaseMapper`](crate::titlecase::TitlecaseMapper).
+    #[cfg(feature = "icu_properties")]
+    pub(crate) fn full_titlecase_to<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        boundaries: impl Iterator<Item = usize>,
+        options: TitlecaseOptions,
+        gc: CodePointMapDataBorrowed<GeneralCategory>,
+        sink: &mut W,
+    ) -> fmt::Result {
+        self.internals.get().casemap.full_titlecase_to(
+            src,
+            boundaries,
+            self.locale,
+            options,
+            gc,
+            sink,
+        )
     }
 
     /// Case-folds the characters in the given string.
     /// This function is locale-independent and context-insensitive.
+    ///
+    /// See [`CaseMapping::case_fold`] for a version that does not allocate.
     pub fn full_fold(&self, src: &str) -> String {
-        self.internals
-            .get()
-            .casemap
-            .full_folding(src, CaseMapLocale::Root)
+        self.case_fold(src).write_to_string().into_owned()
     }
 
     /// Case-folds the characters in the given string, using Turkic (T) mappings for dotted/dotless I.
     /// This function is locale-independent and context-insensitive.
+    ///
+    /// See [`CaseMapping::case_fold_turkic`] for a version that does not allocate.
     pub fn full_fold_turkic(&self, src: &str) -> String {
-        self.internals
-            .get()
-            .casemap
-            .full_folding(src, CaseMapLocale::Turkish)
+        self.case_fold_turkic(src).write_to_string().into_owned()
+    }
+
+    /// Returns whether two strings are equal after full case folding, which is
+    /// "default caseless matching" as defined in Unicode (definition D144 in section 3.13).
+    /// This does not allocate.
+    ///
+    /// Canonically equivalent strings in different normalization forms, like `"é"`
+    /// and `"e\u{301}"`, do not match; see [`CaselessMatcher`](crate::CaselessMatcher).
+    pub fn eq_ignore_case(&self, a: &str, b: &str) -> bool {
+        self.folded_chars(a.chars())
+            .eq(self.folded_chars(b.chars()))
+    }
+
+    /// Compares two strings by the code points of their full case folding, so that
+    /// strings match according to [`CaseMapping::eq_ignore_case`] if and only if they
+    /// compare as equal. This does not allocate.
+    ///
+    /// This is not a linguistic ordering; use a collator for sorting text for display.
+    pub fn cmp_ignore_case(&self, a: &str, b: &str) -> Ordering {
+        self.folded_chars(a.chars())
+            .cmp(self.folded_chars(b.chars()))
+    }
+
+    pub(crate) fn folded_chars<I: Iterator<Item = char>>(&self, iter: I) -> FoldedChars<I> {
+        FoldedChars::new(&self.internals.get().casemap, iter, CaseMapLocale::Root)
     }
 }
+
+#[derive(Copy, Clone)]
+enum Text<'a> {
+    Str(&'a str),
+    Utf8(&'a [u8]),
+    Utf16(&'a [u16]),
+}
+
+/// The [`Writeable`] implementation that is returned by the string case mapping methods
+/// of [`CaseMapping`], like [`CaseMapping::lowercase`]. See the [`writeable`] crate for
+/// how to consume this.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+pub struct CaseMapped<'a> {
+    internals: &'a CaseMappingInternals<'a>,
+    src: Text<'a>,
+    locale: CaseMapLocale,
+    kind: MappingKind,
+}
+
+impl<'a> Writeable for CaseMapped<'a> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self.src {
+            Text::Str(s) => self
+                .internals
+                .full_mapping_to(s, self.locale, self.kind, sink),
+            Text::Utf8(s) => self
+                .internals
+                .full_mapping_to(s, self.locale, self.kind, sink),
+            Text::Utf16(s) => self
+                .internals
+                .full_mapping_to(s, self.locale, self.kind, sink),
+        }
+    }
+
+    /// The length hint is exact. Since full case mappings can make text
+    /// both shorter and longer, computing it takes a pass over the input.
+    fn writeable_length_hint(&self) -> LengthHint {
+        struct LengthCounter(usize);
+        impl fmt::Write for LengthCounter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let mut counter = LengthCounter(0);
+        let _ = self.write_to(&mut counter);
+        LengthHint::exact(counter.0)
+    }
+
+    fn write_to_string(&self) -> Cow<str> {
+        // Most case mappings preserve the length, so this avoids the cost of
+        // computing the exact length hint.
+        let capacity = match self.src {
+            Text::Str(s) => s.len(),
+            Text::Utf8(s) => s.len(),
+            // The UTF-8 length of the input: surrogate pairs take four bytes,
+            // that is two per code unit.
+            Text::Utf16(s) => s
+                .iter()
+                .map(|&u| match u {
+                    0..=0x7F => 1,
+                    0x80..=0x7FF | 0xD800..=0xDFFF => 2,
+                    _ => 3,
+                })
+                .sum(),
+        };
+        let mut output = String::with_capacity(capacity);
+        let _ = self.write_to(&mut output);
+        Cow::Owned(output)
+    }
+}
+
+writeable::impl_display_with_writeable!(CaseMapped<'_>);
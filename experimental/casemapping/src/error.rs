@@ -5,6 +5,11 @@
 use core::char::DecodeUtf16Error;
 use displaydoc::Display;
 use icu_collections::codepointtrie::CodePointTrieError;
+#[cfg(feature = "icu_normalizer")]
+use icu_normalizer::NormalizerError;
+#[cfg(feature = "icu_properties")]
+use icu_properties::PropertiesError;
+use icu_provider::DataError;
 
 /// A list of possible errors for the [`CaseMapping`](crate::CaseMapping) struct
 ///
@@ -25,6 +30,9 @@ pub enum Error {
     /// An error occurred while building the code point trie
     #[displaydoc("Failed to build code point trie: {0}")]
     CodePointTrie(CodePointTrieError),
+    /// An error occurred while loading data
+    #[displaydoc("{0}")]
+    Data(DataError),
 }
 
 impl Error {
@@ -45,3 +53,29 @@ impl From<CodePointTrieError> for Error {
         Error::CodePointTrie(e)
     }
 }
+
+impl From<DataError> for Error {
+    fn from(e: DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+#[cfg(feature = "icu_normalizer")]
+impl From<NormalizerError> for Error {
+    fn from(e: NormalizerError) -> Self {
+        match e {
+            NormalizerError::Data(e) => Error::Data(e),
+            _ => Error::Validation("Unsupported normalization data"),
+        }
+    }
+}
+
+#[cfg(feature = "icu_properties")]
+impl From<PropertiesError> for Error {
+    fn from(e: PropertiesError) -> Self {
+        match e {
+            PropertiesError::PropDataLoad(e) => Error::Data(e),
+            _ => Error::Validation("Unsupported property data"),
+        }
+    }
+}
@@ -0,0 +1,468 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Data for Greek uppercasing, which removes accents and breathing marks
+// and maps the iota subscript to a capital iota, while keeping dialytika
+// and the tonos on a disjunctive eta.
+// See https://icu.unicode.org/design/case/greek-upper and GreekUpper in ICU4C.
+//
+// Each letter maps to its uppercase base letter in the low bits, ORed with
+// flags describing the letter and the diacritics of its canonical decomposition.
+// This data is hardcoded, like in ICU4C; it is derived from the canonical
+// decompositions and is not expected to change with new versions of Unicode.
+
+pub(crate) const UPPER_MASK: u32 = 0x3ff;
+
+pub(crate) const HAS_VOWEL: u32 = 0x1000;
+pub(crate) const HAS_YPOGEGRAMMENI: u32 = 0x2000;
+pub(crate) const HAS_ACCENT: u32 = 0x4000;
+pub(crate) const HAS_DIALYTIKA: u32 = 0x8000;
+// Must not be set by letter_data(), only by diacritic_data().
+pub(crate) const HAS_COMBINING_DIALYTIKA: u32 = 0x10000;
+pub(crate) const HAS_OTHER_GREEK_DIACRITIC: u32 = 0x20000;
+
+pub(crate) const HAS_VOWEL_AND_ACCENT: u32 = HAS_VOWEL | HAS_ACCENT;
+pub(crate) const HAS_VOWEL_AND_ACCENT_AND_DIALYTIKA: u32 = HAS_VOWEL_AND_ACCENT | HAS_DIALYTIKA;
+pub(crate) const HAS_EITHER_DIALYTIKA: u32 = HAS_DIALYTIKA | HAS_COMBINING_DIALYTIKA;
+
+// Returns the data for a Greek letter, or 0 if `c` is not a Greek letter.
+pub(crate) fn letter_data(c: char) -> u32 {
+    match c as u32 {
+        cp @ 0x370..=0x3ff => DATA_0370[(cp - 0x370) as usize],
+        cp @ 0x1f00..=0x1fff => DATA_1F00[(cp - 0x1f00) as usize],
+        // OHM SIGN
+        0x2126 => 0x03A9 | HAS_VOWEL,
+        _ => 0,
+    }
+}
+
+// Returns the flags for a combining diacritic that is removed or kept
+// after a Greek letter, or 0 if `c` is not one of those.
+pub(crate) fn diacritic_data(c: char) -> u32 {
+    match c {
+        '\u{300}' // varia
+        | '\u{301}' // tonos = oxia
+        | '\u{342}' // perispomeni
+        | '\u{302}' // circumflex can look like perispomeni
+        | '\u{303}' // tilde can look like perispomeni
+        | '\u{311}' // inverted breve can look like perispomeni
+        => HAS_ACCENT,
+        '\u{308}' => HAS_COMBINING_DIALYTIKA, // dialytika = diaeresis
+        '\u{344}' => HAS_COMBINING_DIALYTIKA | HAS_ACCENT, // dialytika tonos
+        '\u{345}' => HAS_YPOGEGRAMMENI, // ypogegrammeni = iota subscript
+        '\u{304}' // macron
+        | '\u{306}' // breve
+        | '\u{313}' // comma above
+        | '\u{314}' // reversed comma above
+        | '\u{343}' // koronis
+        => HAS_OTHER_GREEK_DIACRITIC,
+        _ => 0,
+    }
+}
+
+static DATA_0370: [u32; 144] = [
+    0x0370, // Ͱ
+    0x0370, // ͱ
+    0x0372, // Ͳ
+    0x0372, // ͳ
+    0,
+    0,
+    0x0376, // Ͷ
+    0x0376, // ͷ
+    0,
+    0,
+    0,
+    0x03FD, // ͻ
+    0x03FE, // ͼ
+    0x03FF, // ͽ
+    0,
+    0x037F, // Ϳ
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0x0391 | HAS_VOWEL | HAS_ACCENT, // Ά
+    0,
+    0x0395 | HAS_VOWEL | HAS_ACCENT, // Έ
+    0x0397 | HAS_VOWEL | HAS_ACCENT, // Ή
+    0x0399 | HAS_VOWEL | HAS_ACCENT, // Ί
+    0,
+    0x039F | HAS_VOWEL | HAS_ACCENT, // Ό
+    0,
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                 // Ύ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                 // Ώ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ΐ
+    0x0391 | HAS_VOWEL,                              // Α
+    0x0392,                                          // Β
+    0x0393,                                          // Γ
+    0x0394,                                          // Δ
+    0x0395 | HAS_VOWEL,                              // Ε
+    0x0396,                                          // Ζ
+    0x0397 | HAS_VOWEL,                              // Η
+    0x0398,                                          // Θ
+    0x0399 | HAS_VOWEL,                              // Ι
+    0x039A,                                          // Κ
+    0x039B,                                          // Λ
+    0x039C,                                          // Μ
+    0x039D,                                          // Ν
+    0x039E,                                          // Ξ
+    0x039F | HAS_VOWEL,                              // Ο
+    0x03A0,                                          // Π
+    0x03A1,                                          // Ρ
+    0,
+    0x03A3,                                          // Σ
+    0x03A4,                                          // Τ
+    0x03A5 | HAS_VOWEL,                              // Υ
+    0x03A6,                                          // Φ
+    0x03A7,                                          // Χ
+    0x03A8,                                          // Ψ
+    0x03A9 | HAS_VOWEL,                              // Ω
+    0x0399 | HAS_VOWEL | HAS_DIALYTIKA,              // Ϊ
+    0x03A5 | HAS_VOWEL | HAS_DIALYTIKA,              // Ϋ
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                 // ά
+    0x0395 | HAS_VOWEL | HAS_ACCENT,                 // έ
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                 // ή
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                 // ί
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ΰ
+    0x0391 | HAS_VOWEL,                              // α
+    0x0392,                                          // β
+    0x0393,                                          // γ
+    0x0394,                                          // δ
+    0x0395 | HAS_VOWEL,                              // ε
+    0x0396,                                          // ζ
+    0x0397 | HAS_VOWEL,                              // η
+    0x0398,                                          // θ
+    0x0399 | HAS_VOWEL,                              // ι
+    0x039A,                                          // κ
+    0x039B,                                          // λ
+    0x039C,                                          // μ
+    0x039D,                                          // ν
+    0x039E,                                          // ξ
+    0x039F | HAS_VOWEL,                              // ο
+    0x03A0,                                          // π
+    0x03A1,                                          // ρ
+    0x03A3,                                          // ς
+    0x03A3,                                          // σ
+    0x03A4,                                          // τ
+    0x03A5 | HAS_VOWEL,                              // υ
+    0x03A6,                                          // φ
+    0x03A7,                                          // χ
+    0x03A8,                                          // ψ
+    0x03A9 | HAS_VOWEL,                              // ω
+    0x0399 | HAS_VOWEL | HAS_DIALYTIKA,              // ϊ
+    0x03A5 | HAS_VOWEL | HAS_DIALYTIKA,              // ϋ
+    0x039F | HAS_VOWEL | HAS_ACCENT,                 // ό
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                 // ύ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                 // ώ
+    0x03CF,                                          // Ϗ
+    0x0392,                                          // ϐ
+    0x0398,                                          // ϑ
+    0x03D2,                                          // ϒ
+    0x03D2 | HAS_ACCENT,                             // ϓ
+    0x03D2 | HAS_DIALYTIKA,                          // ϔ
+    0x03A6,                                          // ϕ
+    0x03A0,                                          // ϖ
+    0x03CF,                                          // ϗ
+    0x03D8,                                          // Ϙ
+    0x03D8,                                          // ϙ
+    0x03DA,                                          // Ϛ
+    0x03DA,                                          // ϛ
+    0x03DC,                                          // Ϝ
+    0x03DC,                                          // ϝ
+    0x03DE,                                          // Ϟ
+    0x03DE,                                          // ϟ
+    0x03E0,                                          // Ϡ
+    0x03E0,                                          // ϡ
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0,
+    0x039A,             // ϰ
+    0x03A1,             // ϱ
+    0x03F9,             // ϲ
+    0x037F,             // ϳ
+    0x03F4,             // ϴ
+    0x0395 | HAS_VOWEL, // ϵ
+    0,
+    0x03F7, // Ϸ
+    0x03F7, // ϸ
+    0x03F9, // Ϲ
+    0x03FA, // Ϻ
+    0x03FA, // ϻ
+    0,
+    0x03FD, // Ͻ
+    0x03FE, // Ͼ
+    0x03FF, // Ͽ
+];
+
+static DATA_1F00: [u32; 256] = [
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἀ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἁ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἂ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἃ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἄ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἅ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἆ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἇ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἀ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἁ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἂ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἃ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἄ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἅ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἆ
+    0x0391 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἇ
+    0x0395 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἐ
+    0x0395 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἑ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἒ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἓ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἔ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἕ
+    0,
+    0,
+    0x0395 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἐ
+    0x0395 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἑ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἒ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἓ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἔ
+    0x0395 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἕ
+    0,
+    0,
+    0x0397 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἠ
+    0x0397 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἡ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἢ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἣ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἤ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἥ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἦ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἧ
+    0x0397 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἠ
+    0x0397 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἡ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἢ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἣ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἤ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἥ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἦ
+    0x0397 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἧ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἰ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ἱ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἲ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἳ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἴ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἵ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἶ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ἷ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἰ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ἱ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἲ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἳ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἴ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἵ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἶ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ἷ
+    0x039F | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ὀ
+    0x039F | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ὁ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὂ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὃ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὄ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὅ
+    0,
+    0,
+    0x039F | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ὀ
+    0x039F | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ὁ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὂ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὃ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὄ
+    0x039F | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὅ
+    0,
+    0,
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ὐ
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ὑ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὒ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὓ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὔ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὕ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὖ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὗ
+    0,
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ὑ
+    0,
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὓ
+    0,
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὕ
+    0,
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὗ
+    0x03A9 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,              // ὠ
+    0x03A9 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,              // ὡ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὢ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὣ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὤ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὥ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὦ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ὧ
+    0x03A9 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,              // Ὠ
+    0x03A9 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,              // Ὡ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὢ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὣ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὤ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὥ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὦ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // Ὧ
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                             // ὰ
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                             // ά
+    0x0395 | HAS_VOWEL | HAS_ACCENT,                             // ὲ
+    0x0395 | HAS_VOWEL | HAS_ACCENT,                             // έ
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                             // ὴ
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                             // ή
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                             // ὶ
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                             // ί
+    0x039F | HAS_VOWEL | HAS_ACCENT,                             // ὸ
+    0x039F | HAS_VOWEL | HAS_ACCENT,                             // ό
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                             // ὺ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                             // ύ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                             // ὼ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                             // ώ
+    0,
+    0,
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾀ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾁ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾂ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾃ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾄ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾅ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾆ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾇ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾈ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾉ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾊ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾋ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾌ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾍ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾎ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾏ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾐ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾑ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾒ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾓ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾔ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾕ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾖ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾗ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾘ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾙ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾚ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾛ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾜ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾝ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾞ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾟ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾠ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾡ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾢ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾣ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾤ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾥ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾦ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾧ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾨ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_OTHER_GREEK_DIACRITIC, // ᾩ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾪ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾫ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾬ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾭ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾮ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT | HAS_OTHER_GREEK_DIACRITIC, // ᾯ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,                     // ᾰ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,                     // ᾱ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT,                // ᾲ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI,                             // ᾳ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT,                // ᾴ
+    0,
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                     // ᾶ
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ᾷ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,      // Ᾰ
+    0x0391 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,      // Ᾱ
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                     // Ὰ
+    0x0391 | HAS_VOWEL | HAS_ACCENT,                     // Ά
+    0x0391 | HAS_VOWEL | HAS_YPOGEGRAMMENI,              // ᾼ
+    0,
+    0x0399 | HAS_VOWEL, // ι
+    0,
+    0,
+    0,
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῂ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI,              // ῃ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῄ
+    0,
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                     // ῆ
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῇ
+    0x0395 | HAS_VOWEL | HAS_ACCENT,                     // Ὲ
+    0x0395 | HAS_VOWEL | HAS_ACCENT,                     // Έ
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                     // Ὴ
+    0x0397 | HAS_VOWEL | HAS_ACCENT,                     // Ή
+    0x0397 | HAS_VOWEL | HAS_YPOGEGRAMMENI,              // ῌ
+    0,
+    0,
+    0,
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ῐ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ῑ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ῒ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ΐ
+    0,
+    0,
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                 // ῖ
+    0x0399 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ῗ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,  // Ῐ
+    0x0399 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC,  // Ῑ
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                 // Ὶ
+    0x0399 | HAS_VOWEL | HAS_ACCENT,                 // Ί
+    0,
+    0,
+    0,
+    0,
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ῠ
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // ῡ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ῢ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ΰ
+    0x03A1 | HAS_OTHER_GREEK_DIACRITIC,             // ῤ
+    0x03A1 | HAS_OTHER_GREEK_DIACRITIC,             // ῥ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                // ῦ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT | HAS_DIALYTIKA, // ῧ
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ῠ
+    0x03A5 | HAS_VOWEL | HAS_OTHER_GREEK_DIACRITIC, // Ῡ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                // Ὺ
+    0x03A5 | HAS_VOWEL | HAS_ACCENT,                // Ύ
+    0x03A1 | HAS_OTHER_GREEK_DIACRITIC,             // Ῥ
+    0,
+    0,
+    0,
+    0,
+    0,
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῲ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI,              // ῳ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῴ
+    0,
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                     // ῶ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI | HAS_ACCENT, // ῷ
+    0x039F | HAS_VOWEL | HAS_ACCENT,                     // Ὸ
+    0x039F | HAS_VOWEL | HAS_ACCENT,                     // Ό
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                     // Ὼ
+    0x03A9 | HAS_VOWEL | HAS_ACCENT,                     // Ώ
+    0x03A9 | HAS_VOWEL | HAS_YPOGEGRAMMENI,              // ῼ
+    0,
+    0,
+    0,
+];
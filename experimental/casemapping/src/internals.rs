@@ -3,15 +3,22 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use core::convert::TryFrom;
+use core::fmt;
 use core::num::TryFromIntError;
 use icu_collections::codepointinvlist::CodePointInversionListBuilder;
 #[cfg(feature = "datagen")]
 use icu_collections::codepointtrie::CodePointTrieHeader;
 use icu_collections::codepointtrie::{CodePointTrie, TrieValue};
 use icu_locid::Locale;
+#[cfg(feature = "icu_properties")]
+use icu_properties::maps::CodePointMapDataBorrowed;
+#[cfg(feature = "icu_properties")]
+use icu_properties::{GeneralCategory, GeneralCategoryGroup};
 use icu_provider::{yoke, zerofrom};
 #[cfg(feature = "datagen")]
 use std::collections::HashMap;
+use utf16_iter::Utf16Chars;
+use utf8_iter::Utf8Chars;
 use zerovec::ule::{AsULE, RawBytesULE};
 use zerovec::ZeroMap;
 #[cfg(feature = "datagen")]
@@ -21,6 +28,8 @@ use crate::error::Error;
 use crate::exceptions::{CaseMappingExceptions, ExceptionSlot};
 #[cfg(feature = "datagen")]
 use crate::exceptions_builder::CaseMappingExceptionsBuilder;
+#[cfg(feature = "icu_properties")]
+use crate::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum MappingKind {
@@ -542,10 +551,10 @@ impl<'data> CaseMappingInternals<'data> {
     }
 
     #[inline(always)]
-    fn full_helper(
+    fn full_helper<S: CaseMapText>(
         &self,
         c: char,
-        context: ContextIterator,
+        context: ContextIterator<S>,
         locale: CaseMapLocale,
         kind: MappingKind,
     ) -> FullMappingResult {
@@ -581,14 +590,16 @@ impl<'data> CaseMappingInternals<'data> {
                     return FullMappingResult::String(mapped_string);
                 }
             }
+            // Like in `simple_fold`, this comes before the delta, which is the lowercase
+            // mapping of uppercase Cherokee letters, which don't fold to lowercase.
+            if kind == MappingKind::Fold && self.exceptions.no_simple_case_folding(idx) {
+                return FullMappingResult::CodePoint(c);
+            }
             if data.is_relevant_to(kind) && self.exceptions.has_slot(idx, ExceptionSlot::Delta) {
                 let mapped = c as i32 + self.exceptions.delta(idx);
                 let mapped = char::from_u32(mapped as u32).expect("Checked in validate()");
                 return FullMappingResult::CodePoint(mapped);
             }
-            if kind == MappingKind::Fold && self.exceptions.no_simple_case_folding(idx) {
-                return FullMappingResult::CodePoint(c);
-            }
 
             if let Some(slot_char) = self.exceptions.slot_char_for_kind(idx, kind) {
                 FullMappingResult::CodePoint(slot_char)
@@ -598,49 +609,26 @@ impl<'data> CaseMappingInternals<'data> {
         }
     }
 
-    pub(crate) fn to_full_lower(
+    #[cfg(feature = "icu_properties")]
+    pub(crate) fn to_full_lower<S: CaseMapText>(
         &self,
         c: char,
-        context: ContextIterator,
+        context: ContextIterator<S>,
         locale: CaseMapLocale,
     ) -> FullMappingResult {
         self.full_helper(c, context, locale, MappingKind::Lower)
     }
 
-    pub(crate) fn to_full_upper(
-        &self,
-        c: char,
-        context: ContextIterator,
-        locale: CaseMapLocale,
-    ) -> FullMappingResult {
-        self.full_helper(c, context, locale, MappingKind::Upper)
-    }
-
-    // Titlecase mapping is not yet exposed
-    #[allow(dead_code)]
-    pub(crate) fn to_full_title(
+    #[cfg(feature = "icu_properties")]
+    pub(crate) fn to_full_title<S: CaseMapText>(
         &self,
         c: char,
-        context: ContextIterator,
+        context: ContextIterator<S>,
         locale: CaseMapLocale,
     ) -> FullMappingResult {
         self.full_helper(c, context, locale, MappingKind::Title)
     }
 
-    // Note: in ICU4C, case folding takes an options bag instead of a locale,
-    // with the only defined option being whether or not to use Turkic (T)
-    // mappings for dotted/dotless i. In ICU4X, we expose a similar locale-free
-    // API for case folding, but internally represent this as a Turkish locale
-    // to simplify shared code.
-    pub(crate) fn to_full_folding(
-        &self,
-        c: char,
-        context: ContextIterator,
-        locale: CaseMapLocale,
-    ) -> FullMappingResult {
-        self.full_helper(c, context, locale, MappingKind::Fold)
-    }
-
     // These constants are used for hardcoded locale-specific foldings.
     const I_DOT: &'static str = "\u{69}\u{307}";
     const J_DOT: &'static str = "\u{6a}\u{307}";
@@ -669,10 +657,10 @@ impl<'data> CaseMappingInternals<'data> {
         }
     }
 
-    fn full_lower_special_case(
+    fn full_lower_special_case<S: CaseMapText>(
         &self,
         c: char,
-        context: ContextIterator,
+        context: ContextIterator<S>,
         locale: CaseMapLocale,
     ) -> Option<FullMappingResult> {
         if locale == CaseMapLocale::Lithuanian {
@@ -734,10 +722,10 @@ impl<'data> CaseMappingInternals<'data> {
         None
     }
 
-    fn full_upper_or_title_special_case(
+    fn full_upper_or_title_special_case<S: CaseMapText>(
         &self,
         c: char,
-        context: ContextIterator,
+        context: ContextIterator<S>,
         locale: CaseMapLocale,
         _is_title: bool,
     ) -> Option<FullMappingResult> {
@@ -766,10 +754,10 @@ impl<'data> CaseMappingInternals<'data> {
         None
     }
 
-    fn full_fold_special_case(
+    fn full_fold_special_case<S: CaseMapText>(
         &self,
         c: char,
-        _context: ContextIterator,
+        _context: ContextIterator<S>,
         locale: CaseMapLocale,
     ) -> Option<FullMappingResult> {
         let is_turkic = locale == CaseMapLocale::Turkish;
@@ -785,109 +773,315 @@ impl<'data> CaseMappingInternals<'data> {
         }
     }
 
-    pub(crate) fn full_lowercase(&self, src: &str, locale: CaseMapLocale) -> String {
-        let mut result = String::with_capacity(src.len());
+    // Writes the full mapping of the given kind of `src` to `sink`.
+    //
+    // Note: in ICU4C, case folding takes an options bag instead of a locale,
+    // with the only defined option being whether or not to use Turkic (T)
+    // mappings for dotted/dotless i. In ICU4X, we expose a similar locale-free
+    // API for case folding, but internally represent this as a Turkish locale
+    // to simplify shared code.
+    pub(crate) fn full_mapping_to<S: CaseMapText, W: fmt::Write + ?Sized>(
+        &self,
+        src: S,
+        locale: CaseMapLocale,
+        kind: MappingKind,
+        sink: &mut W,
+    ) -> fmt::Result {
+        if locale == CaseMapLocale::Greek && kind == MappingKind::Upper {
+            return self.greek_upper_to(src, sink);
+        }
 
         // To speed up the copying of long runs where nothing changes, we keep track
         // of the start of the uncopied chunk, and don't copy it until we have to.
         let mut last_uncopied_idx = 0;
 
-        for (i, c) in src.char_indices() {
-            let context = ContextIterator::new(src, i);
-            match self.to_full_lower(c, context, locale) {
-                FullMappingResult::CodePoint(c2) => {
-                    if c == c2 {
-                        continue;
-                    }
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push(c2);
-                    last_uncopied_idx = i + c.len_utf8();
-                }
-                FullMappingResult::Remove => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    last_uncopied_idx = i + c.len_utf8();
-                }
-                FullMappingResult::String(s) => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push_str(s);
-                    last_uncopied_idx = i + c.len_utf8();
+        let len = src.len();
+        let mut chars = src.chars();
+        loop {
+            let i = len - S::remaining_len(&chars);
+            let c = match chars.next() {
+                Some(c) => c,
+                None => break,
+            };
+            let next_idx = len - S::remaining_len(&chars);
+            let context = ContextIterator {
+                before: src.slice(0, i),
+                after: src.slice(next_idx, len),
+            };
+            let mapping = self.full_helper(c, context, locale, kind);
+            if let FullMappingResult::CodePoint(c2) = mapping {
+                if c == c2 {
+                    continue;
                 }
             }
+            src.slice(last_uncopied_idx, i).write_to(sink)?;
+            mapping.write_to(sink)?;
+            last_uncopied_idx = next_idx;
         }
-        if last_uncopied_idx < src.len() {
-            result.push_str(&src[last_uncopied_idx..]);
-        }
-        result
+        src.slice(last_uncopied_idx, len).write_to(sink)
     }
 
-    pub(crate) fn full_uppercase(&self, src: &str, locale: CaseMapLocale) -> String {
-        let mut result = String::with_capacity(src.len());
+    // Writes the uppercase mapping of `src` to `sink` using the Greek rules:
+    // accents and breathing marks are removed from Greek letters, except for
+    // dialytika (which are also added where a removed tonos kept two vowels
+    // apart) and the tonos on the disjunctive eta ("ή", "or").
+    // This follows GreekUpper::toUpper in ICU4C.
+    fn greek_upper_to<S: CaseMapText, W: fmt::Write + ?Sized>(
+        &self,
+        src: S,
+        sink: &mut W,
+    ) -> fmt::Result {
+        use crate::greek::*;
+
+        // State bits carried from one letter to the next. These don't overlap
+        // with the bits used by the data.
+        const AFTER_CASED: u32 = 1;
+        const AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT: u32 = 2;
+        const AFTER_VOWEL_WITH_COMBINING_ACCENT: u32 = 4;
+        const AFTER_VOWEL_WITH_ACCENT: u32 =
+            AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT | AFTER_VOWEL_WITH_COMBINING_ACCENT;
+
+        let len = src.len();
+        let mut chars = src.chars();
+        let mut state = 0;
+        loop {
+            let i = len - S::remaining_len(&chars);
+            let c = match chars.next() {
+                Some(c) => c,
+                None => break,
+            };
+            let mut next_state = 0;
+            let case_data = self.lookup_data(c);
+            if case_data.is_ignorable() {
+                next_state |= state & AFTER_CASED;
+            } else if case_data.case_type() != CaseType::None {
+                next_state |= AFTER_CASED;
+            }
 
-        // To speed up the copying of long runs where nothing changes, we keep track
-        // of the start of the uncopied chunk, and don't copy it until we have to.
-        let mut last_uncopied_idx = 0;
+            let mut data = letter_data(c);
+            if data == 0 {
+                let next_idx = len - S::remaining_len(&chars);
+                let context = ContextIterator {
+                    before: src.slice(0, i),
+                    after: src.slice(next_idx, len),
+                };
+                self.full_helper(c, context, CaseMapLocale::Greek, MappingKind::Upper)
+                    .write_to(sink)?;
+                state = next_state;
+                continue;
+            }
 
-        for (i, c) in src.char_indices() {
-            let context = ContextIterator::new(src, i);
-            match self.to_full_upper(c, context, locale) {
-                FullMappingResult::CodePoint(c2) => {
-                    if c == c2 {
-                        continue;
-                    }
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push(c2);
-                    last_uncopied_idx = i + c.len_utf8();
+            let mut upper = data & UPPER_MASK;
+            // Add a dialytika to this iota or upsilon if we removed a tonos from
+            // the previous vowel, and that vowel did not also have a dialytika.
+            if data & HAS_VOWEL != 0
+                && state & AFTER_VOWEL_WITH_ACCENT != 0
+                && (upper == 0x399 || upper == 0x3a5)
+            {
+                data |= if state & AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT != 0 {
+                    HAS_DIALYTIKA
+                } else {
+                    HAS_COMBINING_DIALYTIKA
+                };
+            }
+            // Each ypogegrammeni turns into a trailing capital iota.
+            let mut num_ypogegrammeni = usize::from(data & HAS_YPOGEGRAMMENI != 0);
+            let has_precomposed_accent = data & HAS_ACCENT != 0;
+
+            // Consume the combining diacritics after this letter.
+            loop {
+                let mut peek = chars.clone();
+                let diacritic = peek.next().map_or(0, diacritic_data);
+                if diacritic == 0 {
+                    break;
                 }
-                FullMappingResult::Remove => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    last_uncopied_idx = i + c.len_utf8();
+                data |= diacritic;
+                if diacritic & HAS_YPOGEGRAMMENI != 0 {
+                    num_ypogegrammeni += 1;
                 }
-                FullMappingResult::String(s) => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push_str(s);
-                    last_uncopied_idx = i + c.len_utf8();
+                chars = peek;
+            }
+            if data & HAS_VOWEL_AND_ACCENT_AND_DIALYTIKA == HAS_VOWEL_AND_ACCENT {
+                next_state |= if has_precomposed_accent {
+                    AFTER_VOWEL_WITH_PRECOMPOSED_ACCENT
+                } else {
+                    AFTER_VOWEL_WITH_COMBINING_ACCENT
+                };
+            }
+
+            let mut add_tonos = false;
+            if upper == 0x397
+                && data & HAS_ACCENT != 0
+                && num_ypogegrammeni == 0
+                && state & AFTER_CASED == 0
+            {
+                // Keep the tonos on a disjunctive eta, which is a word by itself.
+                // This uses the same word boundary conditions as the Final_Sigma test.
+                let next_idx = len - S::remaining_len(&chars);
+                let context = ContextIterator {
+                    before: src.slice(0, i),
+                    after: src.slice(next_idx, len),
+                };
+                if !context.followed_by_cased_letter(self) {
+                    if has_precomposed_accent {
+                        upper = 0x389;
+                    } else {
+                        add_tonos = true;
+                    }
+                }
+            } else if data & HAS_DIALYTIKA != 0 {
+                // Preserve a vowel with a precomposed dialytika.
+                if upper == 0x399 {
+                    upper = 0x3aa;
+                    data &= !HAS_EITHER_DIALYTIKA;
+                } else if upper == 0x3a5 {
+                    upper = 0x3ab;
+                    data &= !HAS_EITHER_DIALYTIKA;
                 }
             }
+
+            sink.write_char(char::from_u32(upper).unwrap_or(c))?;
+            if data & HAS_EITHER_DIALYTIKA != 0 {
+                sink.write_char('\u{308}')?;
+            }
+            if add_tonos {
+                sink.write_char('\u{301}')?;
+            }
+            for _ in 0..num_ypogegrammeni {
+                sink.write_char('\u{399}')?;
+            }
+            state = next_state;
         }
-        if last_uncopied_idx < src.len() {
-            result.push_str(&src[last_uncopied_idx..]);
+        Ok(())
+    }
+
+    #[cfg(feature = "icu_properties")]
+    pub(crate) fn full_titlecase_to<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        boundaries: impl Iterator<Item = usize>,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+        gc: CodePointMapDataBorrowed<GeneralCategory>,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let mut segment_start = 0;
+        for segment_end in boundaries
+            .filter(|&i| src.is_char_boundary(i))
+            .chain(core::iter::once(src.len()))
+        {
+            if segment_end <= segment_start {
+                continue;
+            }
+            self.titlecase_segment_to(src, segment_start, segment_end, locale, options, gc, sink)?;
+            segment_start = segment_end;
         }
-        result
+        Ok(())
     }
 
-    pub(crate) fn full_folding(&self, src: &str, locale: CaseMapLocale) -> String {
-        let mut result = String::with_capacity(src.len());
+    // Titlecases `src[start..end]`, using the rest of `src` as context.
+    #[cfg(feature = "icu_properties")]
+    #[allow(clippy::too_many_arguments)]
+    fn titlecase_segment_to<W: fmt::Write + ?Sized>(
+        &self,
+        src: &str,
+        start: usize,
+        end: usize,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+        gc: CodePointMapDataBorrowed<GeneralCategory>,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let segment = &src[start..end];
+        let is_title_start = |c: char| match options.leading_adjustment {
+            // Like ICU, this is the first letter, number, symbol or private use character.
+            LeadingAdjustment::Auto => {
+                let gc = gc.get(c);
+                [
+                    GeneralCategoryGroup::Letter,
+                    GeneralCategoryGroup::Number,
+                    GeneralCategoryGroup::Symbol,
+                    GeneralCategoryGroup::PrivateUse,
+                ]
+                .iter()
+                .any(|group| group.contains(gc))
+            }
+            LeadingAdjustment::ToCased => self.lookup_data(c).case_type() != CaseType::None,
+            LeadingAdjustment::None => true,
+        };
+        let title_start = segment
+            .char_indices()
+            .find(|&(_, c)| is_title_start(c))
+            .map_or(end, |(i, _)| start + i);
+        sink.write_str(&src[start..title_start])?;
+        let c = match src[title_start..end].chars().next() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        let mut title_limit = title_start + c.len_utf8();
 
-        // To speed up the copying of long runs where nothing changes, we keep track
-        // of the start of the uncopied chunk, and don't copy it until we have to.
-        let mut last_uncopied_idx = 0;
+        let context = ContextIterator::new(src, title_start);
+        let mapping = self.to_full_title(c, context, locale);
+        match mapping {
+            FullMappingResult::CodePoint(title @ ('I' | '\u{cd}'))
+                if locale == CaseMapLocale::Dutch =>
+            {
+                sink.write_char(title)?;
+                title_limit += self.title_dutch_ij_to(title, &src[title_limit..end], sink)?;
+            }
+            _ => mapping.write_to(sink)?,
+        }
 
-        for (i, c) in src.char_indices() {
-            let context = ContextIterator::new(src, i);
-            match self.to_full_folding(c, context, locale) {
-                FullMappingResult::CodePoint(c2) => {
-                    if c == c2 {
-                        continue;
-                    }
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push(c2);
-                    last_uncopied_idx = i + c.len_utf8();
-                }
-                FullMappingResult::Remove => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    last_uncopied_idx = i + c.len_utf8();
-                }
-                FullMappingResult::String(s) => {
-                    result.push_str(&src[last_uncopied_idx..i]);
-                    result.push_str(s);
-                    last_uncopied_idx = i + c.len_utf8();
+        match options.trailing_case {
+            TrailingCase::Lower => {
+                for (i, c) in src[title_limit..end].char_indices() {
+                    let context = ContextIterator::new(src, title_limit + i);
+                    self.to_full_lower(c, context, locale).write_to(sink)?;
                 }
+                Ok(())
             }
+            TrailingCase::Unchanged => sink.write_str(&src[title_limit..end]),
         }
-        if last_uncopied_idx < src.len() {
-            result.push_str(&src[last_uncopied_idx..]);
+    }
+
+    // In Dutch, the digraph "ij" is titlecased as a unit, so "ijssel" becomes
+    // "IJssel". This also applies to "íj́" with acute accents.
+    // `title` is the titlecased first letter of the segment, which has already been
+    // written, and `rest` is the rest of the segment. Returns the length of the
+    // part of `rest` that this function has written.
+    #[cfg(feature = "icu_properties")]
+    fn title_dutch_ij_to<W: fmt::Write + ?Sized>(
+        &self,
+        title: char,
+        rest: &str,
+        sink: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        const ACUTE: char = '\u{301}';
+        let mut chars = rest.chars();
+        let mut with_acute = title == '\u{cd}';
+        let mut next = chars.next();
+        if title == 'I' && next == Some(ACUTE) {
+            with_acute = true;
+            next = chars.next();
         }
-        result
+        if !matches!(next, Some('j' | 'J')) {
+            return Ok(0);
+        }
+        // An i with acute must be followed by a j with acute.
+        if with_acute && chars.next() != Some(ACUTE) {
+            return Ok(0);
+        }
+        // There must not be any other accents.
+        if let Some(c) = chars.clone().next() {
+            if matches!(self.dot_type(c), DotType::Above | DotType::OtherAccent) {
+                return Ok(0);
+            }
+        }
+        let len = rest.len() - chars.as_str().len();
+        for c in rest[..len].chars() {
+            sink.write_char(if c == 'j' { 'J' } else { c })?;
+        }
+        Ok(len)
     }
 
     // Adds all simple case mappings and the full case folding for `c` to `set`.
@@ -1027,6 +1221,14 @@ pub enum FullMappingResult<'a> {
 }
 
 impl<'a> FullMappingResult<'a> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        match self {
+            FullMappingResult::CodePoint(c) => sink.write_char(*c),
+            FullMappingResult::String(s) => sink.write_str(s),
+            FullMappingResult::Remove => Ok(()),
+        }
+    }
+
     #[allow(dead_code)]
     fn add_to_set<S: ClosureSet>(&self, set: &mut S) {
         match self {
@@ -1056,12 +1258,134 @@ impl ClosureSet for CodePointInversionListBuilder {
     fn add_string(&mut self, _string: &str) {}
 }
 
-pub(crate) struct ContextIterator<'a> {
-    before: &'a str,
-    after: &'a str,
+// Text that can be case mapped: a `str`, or potentially ill-formed UTF-8 or
+// UTF-16, where errors are treated as U+FFFD REPLACEMENT CHARACTER.
+// Indices are in code units.
+pub(crate) trait CaseMapText: Copy {
+    type Chars: DoubleEndedIterator<Item = char> + Clone;
+    fn chars(self) -> Self::Chars;
+    // The number of code units that `chars` has not consumed yet.
+    fn remaining_len(chars: &Self::Chars) -> usize;
+    fn len(self) -> usize;
+    fn slice(self, start: usize, end: usize) -> Self;
+    fn write_to<W: fmt::Write + ?Sized>(self, sink: &mut W) -> fmt::Result;
 }
 
-impl<'a> ContextIterator<'a> {
+impl<'a> CaseMapText for &'a str {
+    type Chars = core::str::Chars<'a>;
+    fn chars(self) -> Self::Chars {
+        str::chars(self)
+    }
+    fn remaining_len(chars: &Self::Chars) -> usize {
+        chars.as_str().len()
+    }
+    fn len(self) -> usize {
+        str::len(self)
+    }
+    fn slice(self, start: usize, end: usize) -> Self {
+        &self[start..end]
+    }
+    fn write_to<W: fmt::Write + ?Sized>(self, sink: &mut W) -> fmt::Result {
+        sink.write_str(self)
+    }
+}
+
+impl<'a> CaseMapText for &'a [u8] {
+    type Chars = Utf8Chars<'a>;
+    fn chars(self) -> Self::Chars {
+        Utf8Chars::new(self)
+    }
+    fn remaining_len(chars: &Self::Chars) -> usize {
+        chars.as_slice().len()
+    }
+    fn len(self) -> usize {
+        <[u8]>::len(self)
+    }
+    fn slice(self, start: usize, end: usize) -> Self {
+        &self[start..end]
+    }
+    fn write_to<W: fmt::Write + ?Sized>(self, sink: &mut W) -> fmt::Result {
+        Utf8Chars::new(self).try_for_each(|c| sink.write_char(c))
+    }
+}
+
+impl<'a> CaseMapText for &'a [u16] {
+    type Chars = Utf16Chars<'a>;
+    fn chars(self) -> Self::Chars {
+        Utf16Chars::new(self)
+    }
+    fn remaining_len(chars: &Self::Chars) -> usize {
+        chars.as_slice().len()
+    }
+    fn len(self) -> usize {
+        <[u16]>::len(self)
+    }
+    fn slice(self, start: usize, end: usize) -> Self {
+        &self[start..end]
+    }
+    fn write_to<W: fmt::Write + ?Sized>(self, sink: &mut W) -> fmt::Result {
+        Utf16Chars::new(self).try_for_each(|c| sink.write_char(c))
+    }
+}
+
+// An iterator over the full case folding of the characters of another iterator.
+// Case folding is context-insensitive, so this can fold one character at a time.
+pub(crate) struct FoldedChars<'a, I> {
+    internals: &'a CaseMappingInternals<'a>,
+    iter: I,
+    locale: CaseMapLocale,
+    // The rest of a multi-character folding
+    pending: core::str::Chars<'a>,
+}
+
+impl<'a, I: Iterator<Item = char>> FoldedChars<'a, I> {
+    pub fn new(internals: &'a CaseMappingInternals<'a>, iter: I, locale: CaseMapLocale) -> Self {
+        Self {
+            internals,
+            iter,
+            locale,
+            pending: "".chars(),
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = char>> Iterator for FoldedChars<'a, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(c) = self.pending.next() {
+            return Some(c);
+        }
+        loop {
+            let c = self.iter.next()?;
+            let context = ContextIterator {
+                before: "",
+                after: "",
+            };
+            match self
+                .internals
+                .full_helper(c, context, self.locale, MappingKind::Fold)
+            {
+                FullMappingResult::CodePoint(c) => return Some(c),
+                FullMappingResult::String(s) => {
+                    self.pending = s.chars();
+                    if let Some(c) = self.pending.next() {
+                        return Some(c);
+                    }
+                }
+                FullMappingResult::Remove => {}
+            }
+        }
+    }
+}
+
+pub(crate) struct ContextIterator<S> {
+    before: S,
+    after: S,
+}
+
+#[cfg(feature = "icu_properties")]
+impl<'a> ContextIterator<&'a str> {
     // Returns a context iterator with the characters before
     // and after the character at a given index.
     pub fn new(s: &'a str, idx: usize) -> Self {
@@ -1071,7 +1395,9 @@ impl<'a> ContextIterator<'a> {
         let after = char_and_after.as_str();
         Self { before, after }
     }
+}
 
+impl<S: CaseMapText> ContextIterator<S> {
     fn preceded_by_soft_dotted(&self, mapping: &CaseMappingInternals) -> bool {
         for c in self.before.chars().rev() {
             match mapping.dot_type(c) {
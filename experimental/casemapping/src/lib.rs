@@ -18,16 +18,22 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "icu_normalizer")]
+mod caseless;
 mod casemapping;
 pub mod provider;
+pub mod titlecase;
 
 mod error;
 mod exceptions;
 #[cfg(feature = "datagen")]
 mod exceptions_builder;
+mod greek;
 mod internals;
 
-pub use casemapping::CaseMapping;
+#[cfg(feature = "icu_normalizer")]
+pub use caseless::CaselessMatcher;
+pub use casemapping::{CaseMapped, CaseMapping};
 pub use error::Error as CaseMappingError;
 #[cfg(feature = "datagen")]
 pub use internals::CaseMappingInternals;
@@ -0,0 +1,244 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Titlecasing strings, and its options.
+//!
+//! See [`TitlecaseMapper`].
+
+#[cfg(feature = "icu_properties")]
+use crate::error::Error;
+#[cfg(feature = "icu_properties")]
+use crate::provider::CaseMappingV1Marker;
+#[cfg(feature = "icu_properties")]
+use crate::CaseMapping;
+#[cfg(feature = "icu_properties")]
+use core::fmt;
+#[cfg(feature = "icu_properties")]
+use icu_locid::Locale;
+#[cfg(feature = "icu_properties")]
+use icu_properties::maps::CodePointMapData;
+#[cfg(feature = "icu_properties")]
+use icu_properties::provider::GeneralCategoryV1Marker;
+#[cfg(feature = "icu_properties")]
+use icu_properties::GeneralCategory;
+#[cfg(feature = "icu_properties")]
+use icu_provider::prelude::*;
+#[cfg(feature = "icu_segmenter")]
+use icu_segmenter::WordBreakSegmenter;
+#[cfg(feature = "icu_properties")]
+use std::borrow::Cow;
+#[cfg(feature = "icu_properties")]
+use writeable::Writeable;
+
+/// Where to start titlecasing within each segment. ICU calls this
+/// "break adjustment" or "head adjustment".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum LeadingAdjustment {
+    /// Titlecase the first letter, number, symbol or private use character of
+    /// each segment, copying the characters before it (like punctuation)
+    /// unchanged. This is the default in ICU.
+    Auto,
+    /// Titlecase the first cased character of each segment, copying
+    /// the uncased characters before it (like punctuation or digits)
+    /// unchanged. This corresponds to `U_TITLECASE_ADJUST_TO_CASED` in ICU.
+    ToCased,
+    /// Titlecase the first character of each segment, whatever it is.
+    /// This corresponds to `U_TITLECASE_NO_BREAK_ADJUSTMENT` in ICU.
+    None,
+}
+
+/// What to do with the characters of a segment after the one that
+/// is titlecased.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub enum TrailingCase {
+    /// Lowercase the rest of the segment.
+    Lower,
+    /// Leave the rest of the segment unchanged.
+    /// This corresponds to `U_TITLECASE_NO_LOWERCASE` in ICU.
+    Unchanged,
+}
+
+/// Options for titlecasing strings.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[non_exhaustive]
+pub struct TitlecaseOptions {
+    /// Where to start titlecasing within each segment. Defaults to
+    /// [`LeadingAdjustment::Auto`].
+    pub leading_adjustment: LeadingAdjustment,
+    /// What to do with the rest of each segment. Defaults to
+    /// [`TrailingCase::Lower`].
+    pub trailing_case: TrailingCase,
+}
+
+impl TitlecaseOptions {
+    /// Create a new `TitlecaseOptions` with the defaults.
+    pub const fn new() -> Self {
+        Self {
+            leading_adjustment: LeadingAdjustment::Auto,
+            trailing_case: TrailingCase::Lower,
+        }
+    }
+}
+
+impl Default for TitlecaseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A struct with the ability to titlecase strings, which are split into segments like
+/// words. The start of each segment is titlecased and the rest of it lowercased; see
+/// [`TitlecaseOptions`] for other behaviors.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2535">#2535</a>
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_casemapping::titlecase::{TitlecaseMapper, TitlecaseOptions};
+/// use writeable::Writeable;
+///
+/// let mapper = TitlecaseMapper::try_new(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// let words = [5, 6, 9, 10];
+/// assert_eq!(
+///     mapper
+///         .to_full_titlecase_with_boundaries("'twas the night", words, TitlecaseOptions::default())
+///         .write_to_string(),
+///     "'Twas The Night"
+/// );
+/// ```
+#[cfg(feature = "icu_properties")]
+pub struct TitlecaseMapper {
+    case_mapping: CaseMapping,
+    gc: CodePointMapData<GeneralCategory>,
+}
+
+#[cfg(feature = "icu_properties")]
+impl TitlecaseMapper {
+    /// A constructor which takes a [`DataProvider`] and creates a [`TitlecaseMapper`].
+    pub fn try_new<P>(provider: &P) -> Result<Self, Error>
+    where
+        P: DataProvider<CaseMappingV1Marker> + DataProvider<GeneralCategoryV1Marker> + ?Sized,
+    {
+        Self::try_new_with_locale(provider, &Locale::UND)
+    }
+
+    /// A constructor which takes a [`DataProvider`] and creates a [`TitlecaseMapper`] for
+    /// the given locale.
+    pub fn try_new_with_locale<P>(provider: &P, locale: &Locale) -> Result<Self, Error>
+    where
+        P: DataProvider<CaseMappingV1Marker> + DataProvider<GeneralCategoryV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            case_mapping: CaseMapping::try_new_with_locale(provider, locale)?,
+            gc: icu_properties::maps::load_general_category(provider)?,
+        })
+    }
+
+    /// Returns a [`Writeable`] of the full titlecase mapping of the given string, splitting
+    /// it into words with the given [`WordBreakSegmenter`].
+    /// This function is context and locale sensitive.
+    #[cfg(feature = "icu_segmenter")]
+    pub fn to_full_titlecase<'a>(
+        &'a self,
+        src: &'a str,
+        segmenter: &'a WordBreakSegmenter,
+        options: TitlecaseOptions,
+    ) -> impl Writeable + 'a {
+        Titlecased {
+            mapper: self,
+            src,
+            boundaries: move || segmenter.segment_str(src),
+            options,
+        }
+    }
+
+    /// Returns a [`Writeable`] of the full titlecase mapping of the given string, split
+    /// into segments at the given byte indices, which must be in increasing order. Indices
+    /// that are not character boundaries in `src` are ignored.
+    ///
+    /// See [`TitlecaseMapper::to_full_titlecase`].
+    pub fn to_full_titlecase_with_boundaries<'a, I>(
+        &'a self,
+        src: &'a str,
+        boundaries: I,
+        options: TitlecaseOptions,
+    ) -> impl Writeable + 'a
+    where
+        I: IntoIterator<Item = usize>,
+        I::IntoIter: Clone + 'a,
+    {
+        let boundaries = boundaries.into_iter();
+        Titlecased {
+            mapper: self,
+            src,
+            boundaries: move || boundaries.clone(),
+            options,
+        }
+    }
+}
+
+/// The full titlecase mapping of a string, where `boundaries` returns the ends of its
+/// segments each time it is written.
+#[cfg(feature = "icu_properties")]
+struct Titlecased<'a, F> {
+    mapper: &'a TitlecaseMapper,
+    src: &'a str,
+    boundaries: F,
+    options: TitlecaseOptions,
+}
+
+#[cfg(feature = "icu_properties")]
+impl<'a, F, I> Writeable for Titlecased<'a, F>
+where
+    F: Fn() -> I,
+    I: Iterator<Item = usize>,
+{
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.mapper.case_mapping.full_titlecase_to(
+            self.src,
+            (self.boundaries)(),
+            self.options,
+            self.mapper.gc.as_borrowed(),
+            sink,
+        )
+    }
+
+    fn write_to_string(&self) -> Cow<str> {
+        // Most case mappings preserve the length.
+        let mut output = String::with_capacity(self.src.len());
+        let _ = self.write_to(&mut output);
+        Cow::Owned(output)
+    }
+}
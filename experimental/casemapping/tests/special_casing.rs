@@ -0,0 +1,156 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_casemapping::titlecase::{TitlecaseMapper, TitlecaseOptions};
+use icu_casemapping::CaseMapping;
+use icu_locid::locale;
+use writeable::Writeable;
+
+fn case_mapping(locale: &icu_locid::Locale) -> CaseMapping {
+    CaseMapping::try_new_with_locale(&icu_testdata::unstable(), locale).expect("Data exists")
+}
+
+/// Titlecases `src` in the given locale as a single segment.
+fn titlecase(locale: &icu_locid::Locale, src: &str) -> String {
+    TitlecaseMapper::try_new_with_locale(&icu_testdata::unstable(), locale)
+        .expect("Data exists")
+        .to_full_titlecase_with_boundaries(src, [0, src.len()], TitlecaseOptions::default())
+        .write_to_string()
+        .into_owned()
+}
+
+#[test]
+fn test_lithuanian_lower() {
+    let cm = case_mapping(&locale!("lt"));
+    let root = case_mapping(&locale!("und"));
+
+    // The dot of i is kept when it is followed by accents above.
+    assert_eq!(cm.to_full_lowercase("I\u{0300}"), "i\u{0307}\u{0300}");
+    assert_eq!(cm.to_full_lowercase("J\u{0301}"), "j\u{0307}\u{0301}");
+    assert_eq!(
+        cm.to_full_lowercase("\u{012E}\u{0303}"),
+        "\u{012F}\u{0307}\u{0303}"
+    );
+    // An accent below does not count, but does not block an accent above.
+    assert_eq!(cm.to_full_lowercase("I\u{0328}"), "i\u{0328}");
+    assert_eq!(
+        cm.to_full_lowercase("I\u{0323}\u{0301}"),
+        "i\u{0307}\u{0323}\u{0301}"
+    );
+    // A starter ends the context.
+    assert_eq!(cm.to_full_lowercase("IX\u{0301}"), "ix\u{0301}");
+    assert_eq!(cm.to_full_lowercase("I"), "i");
+    // Precomposed accented capital I.
+    assert_eq!(cm.to_full_lowercase("\u{00CC}"), "i\u{0307}\u{0300}");
+    assert_eq!(cm.to_full_lowercase("\u{00CD}"), "i\u{0307}\u{0301}");
+    assert_eq!(cm.to_full_lowercase("\u{0128}"), "i\u{0307}\u{0303}");
+
+    // None of this applies to other languages.
+    assert_eq!(root.to_full_lowercase("I\u{0300}"), "i\u{0300}");
+    assert_eq!(root.to_full_lowercase("\u{00CC}"), "\u{00EC}");
+}
+
+#[test]
+fn test_lithuanian_upper_title() {
+    let cm = case_mapping(&locale!("lt"));
+    let root = case_mapping(&locale!("und"));
+
+    // A dot above after a soft-dotted letter is removed.
+    assert_eq!(cm.to_full_uppercase("i\u{0307}"), "I");
+    assert_eq!(cm.to_full_uppercase("i\u{0307}\u{0301}"), "I\u{0301}");
+    assert_eq!(cm.to_full_uppercase("j\u{0307}"), "J");
+    assert_eq!(cm.to_full_uppercase("\u{012F}\u{0307}"), "\u{012E}");
+    // Also with an accent below in between.
+    assert_eq!(cm.to_full_uppercase("i\u{0323}\u{0307}"), "I\u{0323}");
+    // But not after an accent above or a starter.
+    assert_eq!(
+        cm.to_full_uppercase("i\u{0301}\u{0307}"),
+        "I\u{0301}\u{0307}"
+    );
+    assert_eq!(cm.to_full_uppercase("ix\u{0307}"), "IX\u{0307}");
+    assert_eq!(cm.to_full_uppercase("a\u{0307}"), "A\u{0307}");
+
+    assert_eq!(titlecase(&locale!("lt"), "xi\u{0307}"), "Xi\u{0307}");
+
+    assert_eq!(root.to_full_uppercase("i\u{0307}"), "I\u{0307}");
+}
+
+#[test]
+fn test_turkic() {
+    for locale in [locale!("tr"), locale!("az")] {
+        let cm = case_mapping(&locale);
+        assert_eq!(cm.to_full_lowercase("I"), "\u{0131}");
+        assert_eq!(cm.to_full_lowercase("\u{0130}"), "i");
+        assert_eq!(
+            cm.to_full_lowercase("D\u{0130}YARBAKIR"),
+            "diyarbak\u{0131}r"
+        );
+        assert_eq!(cm.to_full_lowercase("\u{0130}STANBUL"), "istanbul");
+        // I followed by a dot above is canonically equivalent to U+0130.
+        assert_eq!(cm.to_full_lowercase("I\u{0307}"), "i");
+        assert_eq!(cm.to_full_lowercase("I\u{0323}\u{0307}"), "i\u{0323}");
+        // The dot above is kept if there is another accent above in between.
+        assert_eq!(
+            cm.to_full_lowercase("I\u{0300}\u{0307}"),
+            "\u{0131}\u{0300}\u{0307}"
+        );
+        assert_eq!(cm.to_full_lowercase("a\u{0307}"), "a\u{0307}");
+
+        assert_eq!(cm.to_full_uppercase("i"), "\u{0130}");
+        assert_eq!(cm.to_full_uppercase("\u{0131}"), "I");
+        assert_eq!(cm.to_full_uppercase("istanbul"), "\u{0130}STANBUL");
+        assert_eq!(titlecase(&locale, "istanbul"), "\u{0130}stanbul");
+        assert_eq!(titlecase(&locale, "ISPARTA"), "Isparta");
+        assert_eq!(titlecase(&locale, "IIRMAK"), "I\u{0131}rmak");
+    }
+
+    let cm = case_mapping(&locale!("und"));
+    assert_eq!(cm.full_fold_turkic("I\u{0130}"), "\u{0131}i");
+    assert_eq!(cm.full_fold("I\u{0130}"), "ii\u{0307}");
+    assert!(cm.eq_ignore_case("\u{0130}", "i\u{0307}"));
+}
+
+#[test]
+fn test_turkic_not_applied_to_root() {
+    let cm = case_mapping(&locale!("und"));
+    assert_eq!(cm.to_full_lowercase("I"), "i");
+    assert_eq!(cm.to_full_lowercase("\u{0130}"), "i\u{0307}");
+    assert_eq!(cm.to_full_lowercase("I\u{0307}"), "i\u{0307}");
+    assert_eq!(cm.to_full_uppercase("i"), "I");
+    assert_eq!(cm.to_full_uppercase("\u{0131}"), "I");
+}
+
+#[test]
+fn test_greek_upper() {
+    let cm = case_mapping(&locale!("el"));
+
+    // From ICU4C's StringCaseTest::TestGreekUpper.
+    for (src, expected) in [
+        ("άδικος, κείμενο, ίριδα", "ΑΔΙΚΟΣ, ΚΕΙΜΕΝΟ, ΙΡΙΔΑ"),
+        ("Πατάτα", "ΠΑΤΑΤΑ"),
+        ("Αέρας, Μυστήριο, Ωραίο", "ΑΕΡΑΣ, ΜΥΣΤΗΡΙΟ, ΩΡΑΙΟ"),
+        ("Μαΐου, Πόρος, Ρύθμιση", "ΜΑΪΟΥ, ΠΟΡΟΣ, ΡΥΘΜΙΣΗ"),
+        ("ΰ, Τηρώ, Μάιος", "Ϋ, ΤΗΡΩ, ΜΑΪΟΣ"),
+        ("άυλος", "ΑΫΛΟΣ"),
+        ("ΑΫΛΟΣ", "ΑΫΛΟΣ"),
+        (
+            "Άκλιτα ρήματα ή άκλιτες μετοχές",
+            "ΑΚΛΙΤΑ ΡΗΜΑΤΑ Ή ΑΚΛΙΤΕΣ ΜΕΤΟΧΕΣ",
+        ),
+        (
+            "Επειδή η αναγνώριση της αξιοπρέπειας",
+            "ΕΠΕΙΔΗ Η ΑΝΑΓΝΩΡΙΣΗ ΤΗΣ ΑΞΙΟΠΡΕΠΕΙΑΣ",
+        ),
+        ("νομικού ή διεθνούς", "ΝΟΜΙΚΟΥ Ή ΔΙΕΘΝΟΥΣ"),
+        ("ή", "Ή"),
+        ("ή.", "Ή."),
+        ("ρωμέικα", "ΡΩΜΕΪΚΑ"),
+    ] {
+        assert_eq!(cm.to_full_uppercase(src), expected, "{}", src);
+    }
+
+    // Other languages keep the accents.
+    let root = case_mapping(&locale!("und"));
+    assert_eq!(root.to_full_uppercase("άδικος"), "ΆΔΙΚΟΣ");
+}
@@ -2,10 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::vec;
 use alloc::vec::Vec;
-use icu_provider::{DataError, DataPayload};
+use icu_provider::DataPayload;
 
-use crate::dictionary::DictionarySegmenter;
+use crate::dictionary::{DictionarySegmenter, UserDictionaries};
 use crate::language::*;
 use crate::provider::*;
 
@@ -42,6 +43,8 @@ pub struct Dictionary {
     pub lao: Option<DataPayload<UCharDictionaryBreakDataV1Marker>>,
     pub thai: Option<DataPayload<UCharDictionaryBreakDataV1Marker>>,
     pub cj: Option<DataPayload<UCharDictionaryBreakDataV1Marker>>,
+    /// User dictionaries, whose words are kept together in the text of any language.
+    pub user: Vec<DataPayload<UCharDictionaryBreakDataV1Marker>>,
 }
 
 impl Dictionary {
@@ -58,7 +61,6 @@ impl Dictionary {
 }
 
 /// Return UTF-16 segment offset array using dictionary or lstm segmenter.
-#[allow(unused_variables)]
 pub fn complex_language_segment_utf16(
     dictionary: &Dictionary,
     lstm: &LstmPayloads,
@@ -68,38 +70,17 @@ pub fn complex_language_segment_utf16(
     let lang_iter = LanguageIteratorUtf16::new(input);
     let mut offset = 0;
     for str_per_lang in lang_iter {
-        #[cfg(feature = "lstm")]
-        {
-            if let Some(model) = lstm.best(str_per_lang[0] as u32) {
-                if let Ok(segmenter) = LstmSegmenter::try_new_unstable(model) {
-                    let breaks = segmenter.segment_utf16(&str_per_lang);
-                    let mut r: Vec<usize> = breaks.map(|n| offset + n).collect();
-                    result.append(&mut r);
-                    offset += str_per_lang.len();
-                    result.push(offset);
-                    continue;
-                }
-            }
-        }
-
-        if let Some(payload) = dictionary.best(str_per_lang[0] as u32) {
-            if let Ok(segmenter) = DictionarySegmenter::try_new_unstable(payload) {
-                let breaks = segmenter.segment_utf16(&str_per_lang);
-                let mut r: Vec<usize> = breaks.map(|n| offset + n).collect();
-                result.append(&mut r);
-                offset += str_per_lang.len();
-                continue;
-            }
+        let mut breaks = segment_run_utf16(dictionary, lstm, &str_per_lang);
+        if !dictionary.user.is_empty() {
+            breaks = UserDictionaries(&dictionary.user).apply_utf16(&str_per_lang, &breaks);
         }
-
+        result.extend(breaks.iter().map(|n| offset + n));
         offset += str_per_lang.len();
-        result.push(offset);
     }
     result
 }
 
 /// Return UTF-8 segment offset array using dictionary or lstm segmenter.
-#[allow(unused_variables)]
 pub fn complex_language_segment_str(
     dictionary: &Dictionary,
     lstm: &LstmPayloads,
@@ -109,38 +90,62 @@ pub fn complex_language_segment_str(
     let lang_iter = LanguageIterator::new(input);
     let mut offset = 0;
     for str_per_lang in lang_iter {
-        #[cfg(feature = "lstm")]
-        {
-            if let Some(model) = lstm.best(str_per_lang.chars().next().unwrap() as u32) {
-                if let Ok(segmenter) = LstmSegmenter::try_new_unstable(model) {
-                    let breaks = segmenter.segment_str(&str_per_lang);
-                    let mut r: Vec<usize> = breaks.map(|n| offset + n).collect();
-                    result.append(&mut r);
-                    offset += str_per_lang.chars().fold(0, |n, c| n + c.len_utf8());
-                    result.push(offset);
-                    continue;
-                }
-            }
+        let mut breaks = segment_run_str(dictionary, lstm, &str_per_lang);
+        if !dictionary.user.is_empty() {
+            breaks = UserDictionaries(&dictionary.user).apply_str(&str_per_lang, &breaks);
         }
+        result.extend(breaks.iter().map(|n| offset + n));
+        offset += str_per_lang.len();
+    }
+    result
+}
 
-        let segmenter = match dictionary.best(str_per_lang.chars().next().unwrap() as u32) {
-            Some(v) => DictionarySegmenter::try_new_unstable(v),
-            None => Err(DataError::custom("cannot find payload").into()),
-        };
-        match segmenter {
-            Ok(segmenter) => {
-                let breaks = segmenter.segment_str(&str_per_lang);
-                let mut r: Vec<usize> = breaks.map(|n| offset + n).collect();
-                result.append(&mut r);
-                offset += str_per_lang.chars().fold(0, |n, c| n + c.len_utf8());
+/// Return the UTF-16 segment offsets of a run of one language, including its end.
+#[allow(unused_variables)]
+fn segment_run_utf16(dictionary: &Dictionary, lstm: &LstmPayloads, input: &[u16]) -> Vec<usize> {
+    #[cfg(feature = "lstm")]
+    {
+        if let Some(model) = lstm.best(input[0] as u32) {
+            if let Ok(segmenter) = LstmSegmenter::try_new_unstable(model) {
+                let mut breaks: Vec<usize> = segmenter.segment_utf16(input).collect();
+                breaks.push(input.len());
+                return breaks;
             }
-            Err(_) => {
-                offset += str_per_lang.chars().fold(0, |n, c| n + c.len_utf8());
-                result.push(offset);
+        }
+    }
+
+    if let Some(payload) = dictionary.best(input[0] as u32) {
+        if let Ok(segmenter) = DictionarySegmenter::try_new_unstable(payload) {
+            return segmenter.segment_utf16(input).collect();
+        }
+    }
+
+    vec![input.len()]
+}
+
+/// Return the UTF-8 segment offsets of a run of one language, including its end.
+#[allow(unused_variables)]
+fn segment_run_str(dictionary: &Dictionary, lstm: &LstmPayloads, input: &str) -> Vec<usize> {
+    let first = input.chars().next().unwrap() as u32;
+
+    #[cfg(feature = "lstm")]
+    {
+        if let Some(model) = lstm.best(first) {
+            if let Ok(segmenter) = LstmSegmenter::try_new_unstable(model) {
+                let mut breaks: Vec<usize> = segmenter.segment_str(input).collect();
+                breaks.push(input.len());
+                return breaks;
             }
         }
     }
-    result
+
+    if let Some(payload) = dictionary.best(first) {
+        if let Ok(segmenter) = DictionarySegmenter::try_new_unstable(payload) {
+            return segmenter.segment_str(input).collect();
+        }
+    }
+
+    vec![input.len()]
 }
 
 #[cfg(test)]
@@ -169,6 +174,7 @@ mod tests {
             lao: None,
             thai: Some(payload),
             cj: None,
+            user: Vec::new(),
         };
         let payload = icu_testdata::buffer()
             .as_deserializing()
@@ -5,9 +5,10 @@
 use crate::provider::*;
 use crate::{indices::Utf16Indices, SegmenterError};
 
+use alloc::vec::Vec;
 use core::iter::Peekable;
 use core::str::CharIndices;
-use icu_collections::char16trie::{Char16Trie, TrieResult};
+use icu_collections::char16trie::{Char16Trie, Char16TrieIterator, TrieResult};
 use icu_provider::prelude::*;
 
 // SpacingMark and Extend for Myanmar shouldn't be first character of segment.
@@ -163,12 +164,87 @@ impl<'l> DictionarySegmenter<'l> {
     }
 }
 
+/// User dictionaries, whose words are kept together on top of the breaks that a dictionary
+/// or an LSTM model found in a run of text.
+pub struct UserDictionaries<'l>(pub &'l [DataPayload<UCharDictionaryBreakDataV1Marker>]);
+
+impl<'l> UserDictionaries<'l> {
+    /// Applies the user dictionaries to the breaks of an `str` (a UTF-8 string).
+    ///
+    /// `breaks` are the segment ends relative to the start of `input`, including its length.
+    pub fn apply_str(&self, input: &str, breaks: &[usize]) -> Vec<usize> {
+        self.merge(breaks, |start| {
+            self.longest_word::<char>(input[start..].char_indices())
+                .map(|len| start + len)
+        })
+    }
+
+    /// Applies the user dictionaries to the breaks of a UTF-16 string.
+    ///
+    /// `breaks` are the segment ends relative to the start of `input`, including its length.
+    pub fn apply_utf16(&self, input: &[u16], breaks: &[usize]) -> Vec<usize> {
+        self.merge(breaks, |start| {
+            self.longest_word::<u32>(Utf16Indices::new(&input[start..]))
+                .map(|len| start + len)
+        })
+    }
+
+    /// Replaces the segments starting at a break by a word of a user dictionary when that word
+    /// is longer than the segment, i.e. the longest word of either side wins.
+    fn merge(
+        &self,
+        breaks: &[usize],
+        longest_word_at: impl Fn(usize) -> Option<usize>,
+    ) -> Vec<usize> {
+        let mut result = Vec::with_capacity(breaks.len());
+        let mut start = 0;
+        let mut breaks = breaks.iter().copied().peekable();
+        while let Some(end) = breaks.next() {
+            start = match longest_word_at(start) {
+                Some(word_end) if word_end > end => {
+                    while breaks.next_if(|&b| b <= word_end).is_some() {}
+                    word_end
+                }
+                _ => end,
+            };
+            result.push(start);
+        }
+        result
+    }
+
+    /// Returns the length of the longest word of any user dictionary at the start of `iter`.
+    fn longest_word<'s, Y: DictionaryType<'l, 's> + ?Sized>(
+        &self,
+        iter: Y::IterAttr,
+    ) -> Option<usize> {
+        let mut longest = None;
+        for dictionary in self.0 {
+            let mut trie_iter = Char16TrieIterator::new(&dictionary.get().trie_data);
+            let mut chars = iter.clone().peekable();
+            while let Some((index, c)) = chars.next() {
+                let result = trie_iter.next(Y::to_char(c));
+                if let TrieResult::FinalValue(_) | TrieResult::Intermediate(_) = result {
+                    // A word doesn't end before a mark that extends its last character.
+                    if !matches!(chars.peek(), Some(&(_, next)) if is_grapheme_extend(Y::to_char(next)))
+                    {
+                        longest = longest.max(Some(index + Y::char_len(c)));
+                    }
+                }
+                if let TrieResult::FinalValue(_) | TrieResult::NoMatch = result {
+                    break;
+                }
+            }
+        }
+        longest
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "serde")]
 mod tests {
     use super::*;
     use icu_locid::{locale, Locale};
-    use zerovec::ZeroSlice;
+    use zerovec::{ZeroSlice, ZeroVec};
 
     fn get_payload(
         locale: Locale,
@@ -236,6 +312,28 @@ mod tests {
         assert_eq!(result, vec![5, 8]);
     }
 
+    #[test]
+    fn user_dictionary_test() {
+        // A Char16Trie containing "ディタ".
+        let data = UCharDictionaryBreakDataV1 {
+            trie_data: ZeroVec::from_slice_or_alloc(&[0x32, 0x30c7, 0x30a3, 0x30bf, 0x8000]),
+        };
+        let user = [DataPayload::<UCharDictionaryBreakDataV1Marker>::from_owned(
+            data,
+        )];
+        let user = UserDictionaries(&user);
+        let s = "エディタエディタ";
+        assert_eq!(user.apply_str(s, &[3, 6, 9, 12, 15, 24]), [3, 12, 15, 24]);
+        // A segment that is longer than the user word is kept.
+        assert_eq!(user.apply_str(s, &[3, 24]), [3, 24]);
+
+        let s_utf16: Vec<u16> = s.encode_utf16().collect();
+        assert_eq!(
+            user.apply_utf16(&s_utf16, &[1, 2, 3, 4, 5, 8]),
+            [1, 4, 5, 8]
+        );
+    }
+
     #[test]
     fn khmer_dictionary_test() {
         // TODO:
@@ -0,0 +1,355 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::ops::Range;
+use icu_properties::provider::{
+    EmojiModifierBaseV1Marker, EmojiModifierV1Marker, EmojiPresentationV1Marker, EmojiV1Marker,
+    ExtendedPictographicV1Marker, PropertyCodePointSetV1, RegionalIndicatorV1Marker,
+};
+use icu_properties::sets::CodePointSetData;
+use icu_provider::prelude::*;
+
+use crate::grapheme::{GraphemeClusterBreakIteratorUtf8, GraphemeClusterBreakSegmenter};
+use crate::provider::*;
+use crate::SegmenterError;
+
+const ZWJ: char = '\u{200D}';
+const TEXT_PRESENTATION_SELECTOR: char = '\u{FE0E}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// The presentation of an emoji character, as text (usually monochrome) or as emoji (usually
+/// colorful).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[allow(clippy::exhaustive_enums)] // there are only two presentations
+pub enum EmojiPresentation {
+    /// Text presentation, selected by U+FE0E VARIATION SELECTOR-15.
+    Text,
+    /// Emoji presentation, selected by U+FE0F VARIATION SELECTOR-16.
+    Emoji,
+}
+
+/// The kind of emoji that a grapheme cluster is, as defined in
+/// [UTS #51](https://unicode.org/reports/tr51/#Definitions).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum EmojiKind {
+    /// An emoji character with text presentation, by default or followed by U+FE0E.
+    TextPresentation,
+    /// An emoji character with emoji presentation, by default or followed by U+FE0F.
+    EmojiPresentation,
+    /// A keycap sequence, such as `1️⃣`: one of `0`–`9`, `#` and `*`, optionally followed by
+    /// U+FE0F, and followed by U+20E3 COMBINING ENCLOSING KEYCAP.
+    Keycap,
+    /// A flag sequence, such as `🇨🇦`: a pair of regional indicators.
+    Flag,
+    /// A tag sequence, such as `🏴󠁧󠁢󠁳󠁣󠁴󠁿`: an emoji followed by tag characters and U+E007F CANCEL TAG.
+    TagSequence,
+    /// A modifier sequence, such as `👋🏽`: an emoji modifier base followed by a skin tone
+    /// modifier.
+    ModifierSequence,
+    /// A ZWJ sequence, such as `👩‍🔬`: emoji joined by U+200D ZERO WIDTH JOINER.
+    ZwjSequence,
+}
+
+impl EmojiKind {
+    /// The presentation of a grapheme cluster of this kind. All sequences have emoji
+    /// presentation.
+    pub fn presentation(self) -> EmojiPresentation {
+        match self {
+            Self::TextPresentation => EmojiPresentation::Text,
+            _ => EmojiPresentation::Emoji,
+        }
+    }
+}
+
+/// Classifies grapheme clusters as emoji, using the emoji properties of
+/// [UTS #51](https://unicode.org/reports/tr51/) and the grapheme clusters of
+/// [`GraphemeClusterBreakSegmenter`].
+///
+/// A grapheme cluster is classified by its structure, so sequences that are well-formed but
+/// not recommended for general interchange (RGI), such as new ZWJ sequences, are classified as
+/// well. Platforms without a glyph for such a sequence display its elements separately.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_segmenter::{EmojiClassifier, EmojiKind};
+///
+/// let classifier = EmojiClassifier::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// assert!(classifier.is_single_emoji("👍🏽"));
+/// assert!(classifier.is_single_emoji("❤️"));
+/// // U+2764 HEAVY BLACK HEART has text presentation by default.
+/// assert!(!classifier.is_single_emoji("❤"));
+/// assert!(!classifier.is_single_emoji("👍👍"));
+///
+/// let kinds: Vec<_> = classifier
+///     .classify_graphemes("Hi 👋🏽🇨🇦")
+///     .map(|(_, kind)| kind)
+///     .collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         None,
+///         None,
+///         None,
+///         Some(EmojiKind::ModifierSequence),
+///         Some(EmojiKind::Flag),
+///     ]
+/// );
+/// ```
+pub struct EmojiClassifier {
+    grapheme: GraphemeClusterBreakSegmenter,
+    emoji: CodePointSetData,
+    emoji_presentation: CodePointSetData,
+    extended_pictographic: CodePointSetData,
+    emoji_modifier: CodePointSetData,
+    emoji_modifier_base: CodePointSetData,
+    regional_indicator: CodePointSetData,
+}
+
+fn load_set<M, D>(provider: &D) -> Result<CodePointSetData, SegmenterError>
+where
+    M: KeyedDataMarker<Yokeable = PropertyCodePointSetV1<'static>>,
+    D: DataProvider<M> + ?Sized,
+{
+    Ok(CodePointSetData::from_data(
+        provider.load(Default::default())?.take_payload()?,
+    ))
+}
+
+impl EmojiClassifier {
+    /// Construct an [`EmojiClassifier`].
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<GraphemeClusterBreakDataV1Marker>
+            + DataProvider<EmojiV1Marker>
+            + DataProvider<EmojiPresentationV1Marker>
+            + DataProvider<ExtendedPictographicV1Marker>
+            + DataProvider<EmojiModifierV1Marker>
+            + DataProvider<EmojiModifierBaseV1Marker>
+            + DataProvider<RegionalIndicatorV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            grapheme: GraphemeClusterBreakSegmenter::try_new_unstable(provider)?,
+            emoji: load_set::<EmojiV1Marker, _>(provider)?,
+            emoji_presentation: load_set::<EmojiPresentationV1Marker, _>(provider)?,
+            extended_pictographic: load_set::<ExtendedPictographicV1Marker, _>(provider)?,
+            emoji_modifier: load_set::<EmojiModifierV1Marker, _>(provider)?,
+            emoji_modifier_base: load_set::<EmojiModifierBaseV1Marker, _>(provider)?,
+            regional_indicator: load_set::<RegionalIndicatorV1Marker, _>(provider)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: SegmenterError);
+
+    /// Returns the default presentation of `c`, or `None` if it is not an emoji character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu_segmenter::{EmojiClassifier, EmojiPresentation};
+    ///
+    /// let classifier = EmojiClassifier::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(classifier.default_presentation('😀'), Some(EmojiPresentation::Emoji));
+    /// assert_eq!(classifier.default_presentation('©'), Some(EmojiPresentation::Text));
+    /// assert_eq!(classifier.default_presentation('a'), None);
+    /// ```
+    pub fn default_presentation(&self, c: char) -> Option<EmojiPresentation> {
+        if !self.emoji.as_borrowed().contains(c) {
+            None
+        } else if self.emoji_presentation.as_borrowed().contains(c) {
+            Some(EmojiPresentation::Emoji)
+        } else {
+            Some(EmojiPresentation::Text)
+        }
+    }
+
+    /// Whether `c` is Extended_Pictographic, that is, an emoji character or a pictographic
+    /// character reserved for future emoji.
+    pub fn is_extended_pictographic(&self, c: char) -> bool {
+        self.extended_pictographic.as_borrowed().contains(c)
+    }
+
+    /// Classifies `grapheme`, which should be a single grapheme cluster, or returns `None` if it
+    /// is not an emoji.
+    ///
+    /// Note that digits, `#` and `*` are emoji characters with text presentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu_segmenter::{EmojiClassifier, EmojiKind};
+    ///
+    /// let classifier = EmojiClassifier::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(classifier.classify("1️⃣"), Some(EmojiKind::Keycap));
+    /// assert_eq!(classifier.classify("🏳️‍🌈"), Some(EmojiKind::ZwjSequence));
+    /// assert_eq!(classifier.classify("☺\u{FE0E}"), Some(EmojiKind::TextPresentation));
+    /// assert_eq!(classifier.classify("a"), None);
+    /// ```
+    pub fn classify(&self, grapheme: &str) -> Option<EmojiKind> {
+        if grapheme.contains(ZWJ) {
+            return if grapheme
+                .split(ZWJ)
+                .all(|element| self.is_zwj_element(element))
+            {
+                Some(EmojiKind::ZwjSequence)
+            } else {
+                None
+            };
+        }
+
+        let mut chars = grapheme.chars();
+        let first = chars.next()?;
+        let rest = chars.as_str();
+
+        if self.regional_indicator.as_borrowed().contains(first) {
+            let mut chars = rest.chars();
+            if let (Some(second), None) = (chars.next(), chars.next()) {
+                if self.regional_indicator.as_borrowed().contains(second) {
+                    return Some(EmojiKind::Flag);
+                }
+            }
+        }
+
+        if matches!(first, '0'..='9' | '#' | '*') && matches!(rest, "\u{20E3}" | "\u{FE0F}\u{20E3}")
+        {
+            return Some(EmojiKind::Keycap);
+        }
+
+        if let Some(tagged) = rest.strip_suffix(CANCEL_TAG) {
+            let tags_start = tagged.find(is_tag).unwrap_or(tagged.len());
+            let (base_rest, tags) = tagged.split_at(tags_start);
+            return if !tags.is_empty()
+                && tags.chars().all(is_tag)
+                && self.classify_element(first, base_rest).is_some()
+            {
+                Some(EmojiKind::TagSequence)
+            } else {
+                None
+            };
+        }
+
+        self.classify_element(first, rest)
+    }
+
+    /// Whether `text` is a single grapheme cluster that is displayed as an emoji, such as a
+    /// message that a chat application could display with a large font.
+    pub fn is_single_emoji(&self, text: &str) -> bool {
+        self.classify(text).map(EmojiKind::presentation) == Some(EmojiPresentation::Emoji)
+    }
+
+    /// Returns an iterator over the grapheme clusters of `text`, as their byte ranges and
+    /// their [`EmojiKind`], or `None` for grapheme clusters that are not emoji.
+    pub fn classify_graphemes<'l, 's>(&'l self, text: &'s str) -> EmojiGraphemes<'l, 's> {
+        EmojiGraphemes {
+            classifier: self,
+            text,
+            breaks: self.grapheme.segment_str(text),
+            start: 0,
+        }
+    }
+
+    /// Classifies an emoji character `c` followed by `rest` as a presentation sequence or a
+    /// modifier sequence.
+    fn classify_element(&self, c: char, rest: &str) -> Option<EmojiKind> {
+        if !self.emoji.as_borrowed().contains(c) {
+            return None;
+        }
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => self
+                .default_presentation(c)
+                .map(|presentation| match presentation {
+                    EmojiPresentation::Text => EmojiKind::TextPresentation,
+                    EmojiPresentation::Emoji => EmojiKind::EmojiPresentation,
+                }),
+            (Some(TEXT_PRESENTATION_SELECTOR), None) => Some(EmojiKind::TextPresentation),
+            (Some(EMOJI_PRESENTATION_SELECTOR), None) => Some(EmojiKind::EmojiPresentation),
+            (Some(modifier), None)
+                if self.emoji_modifier_base.as_borrowed().contains(c)
+                    && self.emoji_modifier.as_borrowed().contains(modifier) =>
+            {
+                Some(EmojiKind::ModifierSequence)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether `element` can be joined with U+200D ZERO WIDTH JOINER in a ZWJ sequence. Besides
+    /// emoji, Extended_Pictographic characters are accepted for future emoji.
+    fn is_zwj_element(&self, element: &str) -> bool {
+        let mut chars = element.chars();
+        match chars.next() {
+            Some(c) if chars.as_str().is_empty() => {
+                self.emoji.as_borrowed().contains(c) || self.is_extended_pictographic(c)
+            }
+            Some(c) => {
+                self.classify_element(c, chars.as_str())
+                    .map(EmojiKind::presentation)
+                    == Some(EmojiPresentation::Emoji)
+            }
+            None => false,
+        }
+    }
+}
+
+fn is_tag(c: char) -> bool {
+    matches!(c, '\u{E0020}'..='\u{E007E}')
+}
+
+/// An iterator over the grapheme clusters of a string and their [`EmojiKind`].
+///
+/// Created by [`EmojiClassifier::classify_graphemes`].
+pub struct EmojiGraphemes<'l, 's> {
+    classifier: &'l EmojiClassifier,
+    text: &'s str,
+    breaks: GraphemeClusterBreakIteratorUtf8<'l, 's>,
+    start: usize,
+}
+
+impl<'l, 's> Iterator for EmojiGraphemes<'l, 's> {
+    type Item = (Range<usize>, Option<EmojiKind>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let end = self.breaks.next()?;
+            if end == self.start {
+                continue;
+            }
+            let range = self.start..end;
+            self.start = end;
+            let kind = self
+                .text
+                .get(range.clone())
+                .and_then(|grapheme| self.classifier.classify(grapheme));
+            return Some((range, kind));
+        }
+    }
+}
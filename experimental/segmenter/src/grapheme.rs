@@ -3,14 +3,15 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::vec::Vec;
-use core::str::CharIndices;
+use core::marker::PhantomData;
 use icu_provider::prelude::*;
 
 use crate::complex::{Dictionary, LstmPayloads};
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{
+    ChunkIndices, Latin1Indices, PotentiallyIllFormedUtf8Indices, TextChunks, Utf16Indices,
+};
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
-use utf8_iter::Utf8CharIndices;
 
 /// Grapheme cluster break iterator for an `str` (a UTF-8 string).
 pub type GraphemeClusterBreakIteratorUtf8<'l, 's> =
@@ -28,6 +29,10 @@ pub type GraphemeClusterBreakIteratorLatin1<'l, 's> =
 pub type GraphemeClusterBreakIteratorUtf16<'l, 's> =
     RuleBreakIterator<'l, 's, GraphemeClusterBreakTypeUtf16>;
 
+/// Grapheme cluster break iterator for text in [`TextChunks`].
+pub type GraphemeClusterBreakIteratorChunks<'l, 's, C> =
+    RuleBreakIterator<'l, 's, GraphemeClusterBreakTypeChunks<C>>;
+
 /// Segments a string into grapheme clusters.
 ///
 /// Supports loading grapheme cluster break data, and creating grapheme cluster break iterators for
@@ -71,6 +76,7 @@ pub type GraphemeClusterBreakIteratorUtf16<'l, 's> =
 /// ```
 pub struct GraphemeClusterBreakSegmenter {
     payload: DataPayload<GraphemeClusterBreakDataV1Marker>,
+    safe_points: SafePoints,
     dictionary: Dictionary,
     lstm: LstmPayloads,
 }
@@ -85,6 +91,11 @@ impl GraphemeClusterBreakSegmenter {
         let dictionary = Dictionary::default();
         let lstm = LstmPayloads::default();
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| prop,
+                Backtracking::IntermediateMatch,
+            ),
             payload,
             dictionary,
             lstm,
@@ -99,13 +110,17 @@ impl GraphemeClusterBreakSegmenter {
         input: &'s str,
     ) -> GraphemeClusterBreakIteratorUtf8<'l, 's> {
         GraphemeClusterBreakIteratorUtf8 {
-            iter: input.char_indices(),
+            iter: ChunkIndices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -117,13 +132,17 @@ impl GraphemeClusterBreakSegmenter {
         input: &'s [u8],
     ) -> GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
         GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8 {
-            iter: Utf8CharIndices::new(input),
+            iter: PotentiallyIllFormedUtf8Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
     /// Create a grapheme cluster break iterator for a Latin-1 (8-bit) string.
@@ -136,9 +155,13 @@ impl GraphemeClusterBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -152,9 +175,33 @@ impl GraphemeClusterBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
+            data: self.payload.get(),
+            safe_points: &self.safe_points,
+            dictionary: &self.dictionary,
+            lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
+        }
+    }
+
+    /// Create a grapheme cluster break iterator for text in [`TextChunks`], like a rope.
+    pub fn segment_chunks<'l, 's, C: TextChunks + ?Sized>(
+        &'l self,
+        input: &'s C,
+    ) -> GraphemeClusterBreakIteratorChunks<'l, 's, C> {
+        GraphemeClusterBreakIteratorChunks {
+            iter: ChunkIndices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 }
@@ -162,7 +209,7 @@ impl GraphemeClusterBreakSegmenter {
 pub struct GraphemeClusterBreakTypeUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for GraphemeClusterBreakTypeUtf8 {
-    type IterAttr = CharIndices<'s>;
+    type IterAttr = ChunkIndices<'s, str>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -180,7 +227,7 @@ impl<'l, 's> RuleBreakType<'l, 's> for GraphemeClusterBreakTypeUtf8 {
 pub struct GraphemeClusterBreakTypePotentiallyIllFormedUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for GraphemeClusterBreakTypePotentiallyIllFormedUtf8 {
-    type IterAttr = Utf8CharIndices<'s>;
+    type IterAttr = PotentiallyIllFormedUtf8Indices<'s>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -195,6 +242,26 @@ impl<'l, 's> RuleBreakType<'l, 's> for GraphemeClusterBreakTypePotentiallyIllFor
     }
 }
 
+pub struct GraphemeClusterBreakTypeChunks<C: ?Sized>(PhantomData<C>);
+
+impl<'l, 's, C: TextChunks + ?Sized + 's> RuleBreakType<'l, 's>
+    for GraphemeClusterBreakTypeChunks<C>
+{
+    type IterAttr = ChunkIndices<'s, C>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.current_pos_data.unwrap().1.len_utf8()
+    }
+
+    fn handle_complex_language(
+        _: &mut RuleBreakIterator<'l, 's, Self>,
+        _: Self::CharType,
+    ) -> Option<usize> {
+        panic!("not reachable")
+    }
+}
+
 pub struct GraphemeClusterBreakTypeLatin1;
 
 impl<'l, 's> RuleBreakType<'l, 's> for GraphemeClusterBreakTypeLatin1 {
@@ -0,0 +1,241 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+
+use crate::provider::*;
+use crate::SegmenterError;
+
+/// Options to tailor hyphenation behavior.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2259">#2259</a>
+/// </div>
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct HyphenationOptions {
+    /// The minimum number of characters before a hyphenation point, like `\lefthyphenmin`
+    /// in TeX. The default is 2.
+    pub left_min: usize,
+    /// The minimum number of characters after a hyphenation point, like `\righthyphenmin`
+    /// in TeX. The default is 3.
+    pub right_min: usize,
+}
+
+impl Default for HyphenationOptions {
+    fn default() -> Self {
+        Self {
+            left_min: 2,
+            right_min: 3,
+        }
+    }
+}
+
+/// Finds the points where words can be hyphenated, using the Liang hyphenation patterns of
+/// TeX for a language.
+///
+/// The hyphenation points complement the break opportunities of [`LineBreakSegmenter`]: when
+/// a word doesn't fit on a line, it can be broken at one of them with a hyphen inserted.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2259">#2259</a>
+/// </div>
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_locid::locale;
+/// use icu_segmenter::Hyphenator;
+///
+/// let hyphenator = Hyphenator::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     Default::default(),
+/// )
+/// .expect("Data exists");
+///
+/// // hy-phen-ation
+/// assert_eq!(hyphenator.hyphenate("hyphenation"), [2, 6]);
+/// // Only letters are hyphenated.
+/// assert_eq!(hyphenator.hyphenate("\"Hyphenation,\""), [3, 7]);
+/// ```
+///
+/// Find the hyphenation points in the segments of a line break iterator:
+///
+/// ```rust
+/// use icu_locid::locale;
+/// use icu_segmenter::{Hyphenator, LineBreakSegmenter};
+///
+/// let text = "The hyphenation table";
+///
+/// let segmenter =
+///     LineBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+///         .expect("Data exists");
+/// let hyphenator = Hyphenator::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     Default::default(),
+/// )
+/// .expect("Data exists");
+///
+/// let mut start = 0;
+/// let mut hyphenation_points = Vec::new();
+/// for end in segmenter.segment_str(text) {
+///     hyphenation_points.extend(
+///         hyphenator
+///             .hyphenate(&text[start..end])
+///             .into_iter()
+///             .map(|i| start + i),
+///     );
+///     start = end;
+/// }
+/// // The hy-phen-ation ta-ble
+/// assert_eq!(hyphenation_points, [6, 10, 18]);
+/// ```
+///
+/// [`LineBreakSegmenter`]: crate::LineBreakSegmenter
+pub struct Hyphenator {
+    payload: DataPayload<HyphenationPatternsV1Marker>,
+    options: HyphenationOptions,
+}
+
+impl Hyphenator {
+    /// Construct a [`Hyphenator`] for a locale with custom [`HyphenationOptions`].
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: HyphenationOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<HyphenationPatternsV1Marker> + ?Sized,
+    {
+        let payload = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { payload, options })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: HyphenationOptions,
+        error: SegmenterError
+    );
+
+    /// Returns the byte offsets in `text` where a hyphen can be inserted, in ascending order.
+    ///
+    /// Each run of alphabetic characters in `text` is hyphenated as a word, so `text` can be a
+    /// segment returned by a line break iterator, with punctuation and trailing whitespace.
+    pub fn hyphenate(&self, text: &str) -> Vec<usize> {
+        let mut points = Vec::new();
+        let mut word_start = None;
+        for (i, c) in text
+            .char_indices()
+            .chain(core::iter::once((text.len(), ' ')))
+        {
+            if c.is_alphabetic() {
+                word_start.get_or_insert(i);
+            } else if let Some(start) = word_start.take() {
+                self.hyphenate_word(&text[start..i], start, &mut points);
+            }
+        }
+        points
+    }
+
+    /// Appends the hyphenation points of `word`, which starts at `offset`, to `points`.
+    fn hyphenate_word(&self, word: &str, offset: usize, points: &mut Vec<usize>) {
+        let chars: Vec<(usize, char)> = word.char_indices().collect();
+        // Hyphenation points are before the characters in this range.
+        let first = self.options.left_min.max(1);
+        let last = match chars.len().checked_sub(self.options.right_min.max(1)) {
+            Some(last) if last >= first => last,
+            _ => return,
+        };
+        // The word is lowercased like the data, where a character can become several. The
+        // points in the data are before lowercase characters, which map back to the
+        // characters of the word they start.
+        let mut lowercase = String::new();
+        let mut lowercase_starts = Vec::new();
+        for (i, &(_, c)) in chars.iter().enumerate() {
+            for (j, l) in c.to_lowercase().enumerate() {
+                lowercase.push(l);
+                lowercase_starts.push(if j == 0 { Some(i) } else { None });
+            }
+        }
+        let to_point = |i: usize| match lowercase_starts.get(i) {
+            Some(&Some(i)) if (first..=last).contains(&i) => chars.get(i).map(|&(i, _)| offset + i),
+            _ => None,
+        };
+        let data = self.payload.get();
+
+        if let Some(exception) = data.exceptions.get(lowercase.as_str()) {
+            points.extend(exception.iter().filter_map(|&i| to_point(i as usize)));
+            return;
+        }
+
+        // The letters with the start and end of the word, and the highest hyphenation level of
+        // the matching patterns before each of them.
+        let letters: Vec<char> = core::iter::once('.')
+            .chain(lowercase.chars())
+            .chain(core::iter::once('.'))
+            .collect();
+        let mut levels = vec![0; letters.len() + 1];
+        let mut fragment = String::new();
+        for start in 0..letters.len() {
+            fragment.clear();
+            for &letter in &letters[start..] {
+                fragment.push(letter);
+                if let Some(pattern) = data.patterns.get(fragment.as_str()) {
+                    for (level, &value) in levels[start..].iter_mut().zip(pattern) {
+                        *level = value.max(*level);
+                    }
+                }
+            }
+        }
+
+        // An odd level allows hyphenation. The lowercase character at `i` is letter `i + 1`.
+        points.extend(
+            (0..lowercase_starts.len())
+                .filter(|&i| matches!(levels.get(i + 1), Some(level) if level % 2 == 1))
+                .filter_map(to_point),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zerovec::ZeroMap;
+
+    #[test]
+    fn exception_with_special_lowercase() {
+        // "İ" lowercases to "i\u{307}", so its exception has a point after three characters.
+        let mut exceptions = ZeroMap::new();
+        exceptions.insert("i\u{307}stanbul", &[3, 6][..]);
+        let payload =
+            DataPayload::<HyphenationPatternsV1Marker>::from_owned(HyphenationPatternsV1 {
+                patterns: ZeroMap::new(),
+                exceptions,
+            });
+        let hyphenator = Hyphenator {
+            payload,
+            options: Default::default(),
+        };
+
+        // İs-tan-bul
+        assert_eq!(hyphenator.hyphenate("İstanbul"), [3, 6]);
+        assert_eq!(hyphenator.hyphenate("İSTANBUL"), [3, 6]);
+    }
+}
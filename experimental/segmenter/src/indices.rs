@@ -2,6 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use utf8_iter::Utf8CharIndices;
+
 /// Similar to CharIndices for Latin-1 character
 #[derive(Clone)]
 pub struct Latin1Indices<'a> {
@@ -30,6 +32,17 @@ impl<'a> Iterator for Latin1Indices<'a> {
     }
 }
 
+impl<'a> SeekableIndices for Latin1Indices<'a> {
+    fn seek(&mut self, offset: usize) {
+        self.front_offset = offset.min(self.iter.len());
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(usize, u8)> {
+        let index = offset.checked_sub(1)?;
+        self.iter.get(index).map(|ch| (index, *ch))
+    }
+}
+
 /// Similar to CharIndices for UTF-16 character
 #[derive(Clone)]
 pub struct Utf16Indices<'a> {
@@ -73,6 +86,273 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
+impl<'a> SeekableIndices for Utf16Indices<'a> {
+    fn seek(&mut self, offset: usize) {
+        let offset = offset.min(self.iter.len());
+        self.front_offset = match (self.iter.get(offset.wrapping_sub(1)), self.iter.get(offset)) {
+            // Don't split surrogate pairs.
+            (Some(lead), Some(trail))
+                if (lead & 0xfc00) == 0xd800 && (trail & 0xfc00) == 0xdc00 =>
+            {
+                offset - 1
+            }
+            _ => offset,
+        };
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(usize, u32)> {
+        let index = offset.checked_sub(1)?;
+        let ch = *self.iter.get(index)? as u32;
+        if (ch & 0xfc00) == 0xdc00 {
+            if let Some(lead) = index.checked_sub(1).and_then(|i| self.iter.get(i)) {
+                let lead = *lead as u32;
+                if (lead & 0xfc00) == 0xd800 {
+                    // Combine low and high surrogates to UTF-32 code point.
+                    return Some((index - 1, ((lead & 0x3ff) << 10) + (ch & 0x3ff) + 0x10000));
+                }
+            }
+        }
+        Some((index, ch))
+    }
+}
+
+/// Similar to CharIndices for potentially ill-formed UTF-8 strings, with invalid sequences
+/// decoded as REPLACEMENT CHARACTER.
+#[derive(Clone)]
+pub struct PotentiallyIllFormedUtf8Indices<'a> {
+    front_offset: usize,
+    iter: Utf8CharIndices<'a>,
+    input: &'a [u8],
+}
+
+impl<'a> PotentiallyIllFormedUtf8Indices<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            front_offset: 0,
+            iter: Utf8CharIndices::new(input),
+            input,
+        }
+    }
+
+    /// Returns the characters from the last byte at or before `offset` that isn't a
+    /// continuation byte, with their start and end offsets. Decoding is synchronized there.
+    fn chars_around(&self, offset: usize) -> impl Iterator<Item = (usize, usize, char)> + 'a {
+        let input = self.input;
+        let mut start = offset.min(input.len());
+        while start > 0 && matches!(input.get(start), Some(b) if (b & 0xc0) == 0x80) {
+            start -= 1;
+        }
+        let mut iter = Utf8CharIndices::new(input.get(start..).unwrap_or_default()).peekable();
+        core::iter::from_fn(move || {
+            let (index, ch) = iter.next()?;
+            let end = iter.peek().map_or(input.len() - start, |(i, _)| *i);
+            Some((start + index, start + end, ch))
+        })
+    }
+}
+
+impl<'a> Iterator for PotentiallyIllFormedUtf8Indices<'a> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        self.iter
+            .next()
+            .map(|(index, ch)| (self.front_offset + index, ch))
+    }
+}
+
+impl<'a> SeekableIndices for PotentiallyIllFormedUtf8Indices<'a> {
+    fn seek(&mut self, offset: usize) {
+        self.front_offset = self
+            .chars_around(offset)
+            .find(|(_, end, _)| *end > offset)
+            .map_or(self.input.len(), |(start, _, _)| start);
+        self.iter = Utf8CharIndices::new(self.input.get(self.front_offset..).unwrap_or_default());
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(usize, char)> {
+        self.chars_around(offset.checked_sub(1)?)
+            .find(|(_, end, _)| *end >= offset)
+            .filter(|(_, end, _)| *end == offset)
+            .map(|(start, _, ch)| (start, ch))
+    }
+}
+
+/// Text that is stored in several chunks, like a rope in a text editor, which can be
+/// segmented without copying it into a single string.
+///
+/// Offsets are in UTF-8 bytes from the start of the text.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2259">#2259</a>
+/// </div>
+pub trait TextChunks {
+    /// Returns the length of the text in bytes.
+    fn len(&self) -> usize;
+
+    /// Returns whether the text is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the chunk containing the byte at `offset`, which is less than
+    /// [`len`](Self::len), and the offset at which the chunk starts.
+    ///
+    /// Chunks must not be empty or split characters.
+    fn chunk_at(&self, offset: usize) -> (&str, usize);
+}
+
+impl TextChunks for str {
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+
+    fn chunk_at(&self, _offset: usize) -> (&str, usize) {
+        (self, 0)
+    }
+}
+
+/// Similar to CharIndices for [`TextChunks`]
+pub struct ChunkIndices<'a, C: ?Sized> {
+    front_offset: usize,
+    text: &'a C,
+    chunk: &'a str,
+    chunk_start: usize,
+}
+
+impl<'a, C: ?Sized> Clone for ChunkIndices<'a, C> {
+    fn clone(&self) -> Self {
+        Self {
+            front_offset: self.front_offset,
+            text: self.text,
+            chunk: self.chunk,
+            chunk_start: self.chunk_start,
+        }
+    }
+}
+
+impl<'a, C: TextChunks + ?Sized> ChunkIndices<'a, C> {
+    pub fn new(text: &'a C) -> Self {
+        Self {
+            front_offset: 0,
+            text,
+            chunk: "",
+            chunk_start: 0,
+        }
+    }
+
+    /// Returns the chunk containing `offset` and the offset within it.
+    fn chunk_at(&self, offset: usize) -> Option<(&'a str, usize)> {
+        if offset >= self.text.len() {
+            return None;
+        }
+        let (chunk, chunk_start) = match offset.checked_sub(self.chunk_start) {
+            Some(index) if index < self.chunk.len() => (self.chunk, self.chunk_start),
+            _ => self.text.chunk_at(offset),
+        };
+        Some((chunk, offset.checked_sub(chunk_start)?))
+    }
+}
+
+impl<'a, C: TextChunks + ?Sized> Iterator for ChunkIndices<'a, C> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        let (chunk, index) = self.chunk_at(self.front_offset)?;
+        let ch = chunk.get(index..)?.chars().next()?;
+        self.chunk_start = self.front_offset - index;
+        self.chunk = chunk;
+        self.front_offset += ch.len_utf8();
+        Some((self.front_offset - ch.len_utf8(), ch))
+    }
+}
+
+/// Character iterators that can move to any position of their text.
+pub trait SeekableIndices: Iterator + Clone {
+    /// Moves the iterator to the character at `offset`, or the character containing it.
+    fn seek(&mut self, offset: usize);
+
+    /// Returns the character ending at `offset`, which is at a character boundary.
+    fn char_before(&self, offset: usize) -> Option<Self::Item>;
+}
+
+impl<'a, C: TextChunks + ?Sized> SeekableIndices for ChunkIndices<'a, C> {
+    fn seek(&mut self, offset: usize) {
+        self.front_offset = match self.chunk_at(offset) {
+            Some((chunk, mut index)) => {
+                let chunk_start = offset - index;
+                while !chunk.is_char_boundary(index) {
+                    index -= 1;
+                }
+                chunk_start + index
+            }
+            None => self.text.len(),
+        };
+    }
+
+    fn char_before(&self, offset: usize) -> Option<(usize, char)> {
+        let (chunk, index) = self.chunk_at(offset.checked_sub(1)?)?;
+        let ch = chunk.get(..=index)?.chars().next_back()?;
+        Some((offset - ch.len_utf8(), ch))
+    }
+}
+
+/// The number of characters before an offset that are looked at to decide whether
+/// segmentation can restart there.
+pub(crate) const SAFE_POINT_CONTEXT: usize = 4;
+
+/// Returns the last offset at or before `offset` at which segmentation can restart as if the
+/// text started there, or the start of the text.
+///
+/// `is_safe` is called with the characters before a candidate offset, nearest last, and the
+/// character at it. Segmenters know from their rules whether they break there regardless of
+/// the text before.
+pub fn safe_point<I, C>(iter: &I, offset: usize, mut is_safe: impl FnMut(&[C], C) -> bool) -> usize
+where
+    I: SeekableIndices<Item = (usize, C)>,
+    C: Copy,
+{
+    let mut iter = iter.clone();
+    iter.seek(offset);
+    let (mut offset, mut c) = match iter.next() {
+        Some(next) => next,
+        // At the end of the text, start with its last character.
+        None => match iter.char_before(offset) {
+            Some(before) => before,
+            None => return 0,
+        },
+    };
+    loop {
+        let mut context = [c; SAFE_POINT_CONTEXT];
+        let mut context_start = SAFE_POINT_CONTEXT;
+        let mut i = offset;
+        while context_start > 0 {
+            match iter.char_before(i) {
+                Some((index, before)) => {
+                    context_start -= 1;
+                    context[context_start] = before;
+                    i = index;
+                }
+                None => break,
+            }
+        }
+        if context_start == SAFE_POINT_CONTEXT || is_safe(&context[context_start..], c) {
+            return offset;
+        }
+        // The character before `offset` exists, it is the last one of the context.
+        c = context[SAFE_POINT_CONTEXT - 1];
+        offset = match iter.char_before(offset) {
+            Some((index, _)) => index,
+            None => return 0,
+        };
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::indices::*;
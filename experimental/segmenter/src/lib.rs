@@ -12,6 +12,9 @@
 //! - Line breaker that is compatible with [Unicode Standard Annex #14][UAX14] and CSS properties.
 //! - Grapheme cluster breaker, word breaker, and sentence breaker that are compatible with
 //!   [Unicode Standard Annex #29][UAX29].
+//! - Hyphenator that finds hyphenation points in words with the Liang patterns of TeX.
+//! - Emoji classifier that finds the kind of emoji of grapheme clusters, following
+//!   [Unicode Technical Standard #51][UTS51].
 //!
 //! <div class="stab unstable">
 //! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
@@ -22,6 +25,7 @@
 //!
 //! [UAX14]: https://www.unicode.org/reports/tr14/
 //! [UAX29]: https://www.unicode.org/reports/tr29/
+//! [UTS51]: https://www.unicode.org/reports/tr51/
 //!
 //! # Examples
 //!
@@ -89,7 +93,9 @@ extern crate alloc;
 
 mod complex;
 mod dictionary;
+mod emoji;
 mod error;
+mod hyphenation;
 mod indices;
 mod language;
 mod rule_segmenter;
@@ -116,24 +122,29 @@ mod lstm_error;
 mod math_helper;
 
 pub use crate::grapheme::{
-    GraphemeClusterBreakIteratorLatin1, GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8,
-    GraphemeClusterBreakIteratorUtf16, GraphemeClusterBreakIteratorUtf8,
-    GraphemeClusterBreakSegmenter,
+    GraphemeClusterBreakIteratorChunks, GraphemeClusterBreakIteratorLatin1,
+    GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8, GraphemeClusterBreakIteratorUtf16,
+    GraphemeClusterBreakIteratorUtf8, GraphemeClusterBreakSegmenter,
 };
 pub use crate::line::{
-    LineBreakIterator, LineBreakIteratorLatin1, LineBreakIteratorPotentiallyIllFormedUtf8,
-    LineBreakIteratorUtf16, LineBreakIteratorUtf8, LineBreakOptions, LineBreakRule,
-    LineBreakSegmenter, WordBreakRule,
+    LineBreakIterator, LineBreakIteratorChunks, LineBreakIteratorLatin1,
+    LineBreakIteratorPotentiallyIllFormedUtf8, LineBreakIteratorUtf16, LineBreakIteratorUtf8,
+    LineBreakOptions, LineBreakRule, LineBreakSegmenter, WordBreakRule,
 };
 pub use crate::sentence::{
-    SentenceBreakIteratorLatin1, SentenceBreakIteratorPotentiallyIllFormedUtf8,
-    SentenceBreakIteratorUtf16, SentenceBreakIteratorUtf8, SentenceBreakSegmenter,
+    SentenceBreakIteratorChunks, SentenceBreakIteratorLatin1,
+    SentenceBreakIteratorPotentiallyIllFormedUtf8, SentenceBreakIteratorUtf16,
+    SentenceBreakIteratorUtf8, SentenceBreakOptions, SentenceBreakSegmenter,
 };
 pub use crate::word::{
-    WordBreakIteratorLatin1, WordBreakIteratorPotentiallyIllFormedUtf8, WordBreakIteratorUtf16,
-    WordBreakIteratorUtf8, WordBreakSegmenter,
+    WordBreakIteratorChunks, WordBreakIteratorLatin1, WordBreakIteratorPotentiallyIllFormedUtf8,
+    WordBreakIteratorUtf16, WordBreakIteratorUtf8, WordBreakSegmenter,
 };
 
+pub use crate::emoji::{EmojiClassifier, EmojiGraphemes, EmojiKind, EmojiPresentation};
+pub use crate::hyphenation::{HyphenationOptions, Hyphenator};
+pub use crate::indices::TextChunks;
+pub use crate::provider::WordType;
 pub use crate::rule_segmenter::RuleBreakIterator;
 
 pub use error::SegmenterError;
@@ -6,6 +6,7 @@ use crate::complex::*;
 use crate::indices::*;
 use crate::language::*;
 use crate::provider::*;
+use crate::rule_segmenter::{Backtracking, SafePoints};
 use crate::symbols::*;
 use crate::SegmenterError;
 
@@ -13,10 +14,12 @@ use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::char;
-use core::str::CharIndices;
+use core::marker::PhantomData;
+use icu_locid::extensions_unicode_key as key;
+use icu_locid::extensions_unicode_value as value;
+use icu_locid::subtags_language as language;
 use icu_locid::{locale, Locale};
 use icu_provider::prelude::*;
-use utf8_iter::Utf8CharIndices;
 
 /// An enum specifies the strictness of line-breaking rules. It can be passed as
 /// an argument when creating a line breaker.
@@ -119,6 +122,35 @@ impl Default for LineBreakOptions {
     }
 }
 
+impl LineBreakOptions {
+    /// Applies the CLDR tailorings for `locale`: the `-u-lb-` and `-u-lw-` keywords override the
+    /// line and word break rules, and Chinese and Japanese set [`Self::ja_zh`].
+    fn apply_locale(&mut self, locale: &DataLocale) {
+        if let Some(lb) = locale.get_unicode_ext(&key!("lb")) {
+            if lb == value!("strict") {
+                self.line_break_rule = LineBreakRule::Strict;
+            } else if lb == value!("normal") {
+                self.line_break_rule = LineBreakRule::Normal;
+            } else if lb == value!("loose") {
+                self.line_break_rule = LineBreakRule::Loose;
+            }
+        }
+        if let Some(lw) = locale.get_unicode_ext(&key!("lw")) {
+            if lw == value!("normal") {
+                self.word_break_rule = WordBreakRule::Normal;
+            } else if lw == value!("breakall") {
+                self.word_break_rule = WordBreakRule::BreakAll;
+            } else if lw == value!("keepall") {
+                self.word_break_rule = WordBreakRule::KeepAll;
+            }
+        }
+        let language = locale.get_langid().language;
+        if language == language!("ja") || language == language!("zh") {
+            self.ja_zh = true;
+        }
+    }
+}
+
 /// Line break iterator for an `str` (a UTF-8 string).
 pub type LineBreakIteratorUtf8<'l, 's> = LineBreakIterator<'l, 's, LineBreakTypeUtf8>;
 
@@ -132,6 +164,9 @@ pub type LineBreakIteratorLatin1<'l, 's> = LineBreakIterator<'l, 's, LineBreakTy
 /// Line break iterator for a UTF-16 string.
 pub type LineBreakIteratorUtf16<'l, 's> = LineBreakIterator<'l, 's, LineBreakTypeUtf16>;
 
+/// Line break iterator for text in [`TextChunks`].
+pub type LineBreakIteratorChunks<'l, 's, C> = LineBreakIterator<'l, 's, LineBreakTypeChunks<C>>;
+
 /// Supports loading line break data, and creating line break iterators for different string
 /// encodings.
 ///
@@ -180,6 +215,24 @@ pub type LineBreakIteratorUtf16<'l, 's> = LineBreakIterator<'l, 's, LineBreakTyp
 /// assert_eq!(&breakpoints, &[1, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
 /// ```
 ///
+/// Segment a string with the tailorings of a locale:
+///
+/// ```rust
+/// use icu_locid::locale;
+/// use icu_segmenter::{LineBreakOptions, LineBreakSegmenter};
+///
+/// let segmenter = LineBreakSegmenter::try_new_with_locale_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en-u-lw-breakall").into(),
+///     LineBreakOptions::default(),
+/// )
+/// .expect("Data exists");
+///
+/// let breakpoints: Vec<usize> =
+///     segmenter.segment_str("Hello World").collect();
+/// assert_eq!(&breakpoints, &[1, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
+/// ```
+///
 /// Segment a Latin1 byte string:
 ///
 /// ```rust
@@ -196,6 +249,7 @@ pub type LineBreakIteratorUtf16<'l, 's> = LineBreakIterator<'l, 's, LineBreakTyp
 pub struct LineBreakSegmenter {
     options: LineBreakOptions,
     payload: DataPayload<LineBreakDataV1Marker>,
+    safe_points: SafePoints,
     dictionary: Dictionary,
     lstm: LstmPayloads,
 }
@@ -232,7 +286,30 @@ impl LineBreakSegmenter {
     where
         D: DataProvider<LineBreakDataV1Marker> + DataProvider<LstmDataV1Marker> + ?Sized,
     {
-        let payload = provider.load(Default::default())?.take_payload()?;
+        Self::try_new_with_locale_unstable(provider, &DataLocale::default(), options)
+    }
+
+    /// Construct a [`LineBreakSegmenter`] for a locale with custom [`LineBreakOptions`].
+    ///
+    /// The `-u-lb-` and `-u-lw-` keywords of the locale override
+    /// [`LineBreakOptions::line_break_rule`] and [`LineBreakOptions::word_break_rule`], and
+    /// [`LineBreakOptions::ja_zh`] is set for Chinese and Japanese.
+    #[cfg(feature = "lstm")]
+    pub fn try_new_with_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        mut options: LineBreakOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker> + DataProvider<LstmDataV1Marker> + ?Sized,
+    {
+        options.apply_locale(locale);
+        let payload = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
 
         let burmese = Self::load_lstm(provider, locale!("my")).ok();
         let khmer = Self::load_lstm(provider, locale!("km")).ok();
@@ -240,6 +317,11 @@ impl LineBreakSegmenter {
         let thai = Self::load_lstm(provider, locale!("th")).ok();
 
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| rule_start_property(options.word_break_rule, prop),
+                Backtracking::LastCharacter,
+            ),
             options,
             payload,
             dictionary: Dictionary::default(),
@@ -263,7 +345,32 @@ impl LineBreakSegmenter {
             + DataProvider<UCharDictionaryBreakDataV1Marker>
             + ?Sized,
     {
-        let payload = provider.load(Default::default())?.take_payload()?;
+        Self::try_new_with_locale_unstable(provider, &DataLocale::default(), options)
+    }
+
+    /// Construct a [`LineBreakSegmenter`] for a locale with custom [`LineBreakOptions`].
+    ///
+    /// The `-u-lb-` and `-u-lw-` keywords of the locale override
+    /// [`LineBreakOptions::line_break_rule`] and [`LineBreakOptions::word_break_rule`], and
+    /// [`LineBreakOptions::ja_zh`] is set for Chinese and Japanese.
+    #[cfg(not(feature = "lstm"))]
+    pub fn try_new_with_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        mut options: LineBreakOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker>
+            + DataProvider<UCharDictionaryBreakDataV1Marker>
+            + ?Sized,
+    {
+        options.apply_locale(locale);
+        let payload = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
 
         let khmer = Self::load_dictionary(provider, locale!("km")).ok();
         let lao = Self::load_dictionary(provider, locale!("lo")).ok();
@@ -271,6 +378,11 @@ impl LineBreakSegmenter {
         let thai = Self::load_dictionary(provider, locale!("th")).ok();
 
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| rule_start_property(options.word_break_rule, prop),
+                Backtracking::LastCharacter,
+            ),
             options,
             payload,
             dictionary: Dictionary {
@@ -279,6 +391,7 @@ impl LineBreakSegmenter {
                 lao,
                 thai,
                 cj: None,
+                user: Vec::new(),
             },
             lstm: LstmPayloads::default(),
         })
@@ -295,6 +408,17 @@ impl LineBreakSegmenter {
         ]
     );
 
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: LineBreakOptions,
+        error: SegmenterError,
+        functions: [
+            Self::try_new_with_locale_unstable,
+            try_new_with_locale_with_any_provider,
+            try_new_with_locale_with_buffer_provider
+        ]
+    );
+
     #[cfg(not(feature = "lstm"))]
     fn load_dictionary<D: DataProvider<UCharDictionaryBreakDataV1Marker> + ?Sized>(
         provider: &D,
@@ -321,17 +445,32 @@ impl LineBreakSegmenter {
             .take_payload()
     }
 
+    /// Adds a user dictionary, such as one built from a word list with
+    /// `icu_datagen::build_segmenter_dictionary`.
+    ///
+    /// In text that is segmented with a dictionary or an LSTM model, such as Thai, a word of a
+    /// user dictionary that starts at a break opportunity is then not broken when it is longer
+    /// than the segment found there.
+    pub fn add_user_dictionary(
+        &mut self,
+        dictionary: DataPayload<UCharDictionaryBreakDataV1Marker>,
+    ) {
+        self.dictionary.user.push(dictionary);
+    }
+
     /// Create a line break iterator for an `str` (a UTF-8 string).
     pub fn segment_str<'l, 's>(&'l self, input: &'s str) -> LineBreakIteratorUtf8<'l, 's> {
         LineBreakIterator {
-            iter: input.char_indices(),
+            iter: ChunkIndices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             options: &self.options,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            previous_breaks: Vec::new(),
         }
     }
     /// Create a line break iterator for a potentially ill-formed UTF8 string
@@ -342,14 +481,16 @@ impl LineBreakSegmenter {
         input: &'s [u8],
     ) -> LineBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
         LineBreakIterator {
-            iter: Utf8CharIndices::new(input),
+            iter: PotentiallyIllFormedUtf8Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             options: &self.options,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            previous_breaks: Vec::new(),
         }
     }
     /// Create a line break iterator for a Latin-1 (8-bit) string.
@@ -360,9 +501,11 @@ impl LineBreakSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             options: &self.options,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -374,9 +517,57 @@ impl LineBreakSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             options: &self.options,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            previous_breaks: Vec::new(),
+        }
+    }
+
+    /// Create a line break iterator for text in [`TextChunks`], like a rope.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu_segmenter::{LineBreakSegmenter, TextChunks};
+    ///
+    /// // A text stored in pieces of at most 4 bytes.
+    /// struct Pieces(Vec<String>);
+    ///
+    /// impl TextChunks for Pieces {
+    ///     fn len(&self) -> usize {
+    ///         self.0.iter().map(String::len).sum()
+    ///     }
+    ///
+    ///     fn chunk_at(&self, offset: usize) -> (&str, usize) {
+    ///         (&self.0[offset / 4], offset / 4 * 4)
+    ///     }
+    /// }
+    ///
+    /// let text = Pieces(vec!["Hell".into(), "o Wo".into(), "rld".into()]);
+    ///
+    /// let segmenter =
+    ///     LineBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+    ///         .expect("Data exists");
+    /// let breakpoints: Vec<usize> = segmenter.segment_chunks(&text).collect();
+    /// assert_eq!(&breakpoints, &[6, 11]);
+    /// ```
+    pub fn segment_chunks<'l, 's, C: TextChunks + ?Sized>(
+        &'l self,
+        input: &'s C,
+    ) -> LineBreakIteratorChunks<'l, 's, C> {
+        LineBreakIterator {
+            iter: ChunkIndices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            safe_points: &self.safe_points,
+            options: &self.options,
+            dictionary: &self.dictionary,
+            lstm: &self.lstm,
+            previous_breaks: Vec::new(),
         }
     }
 }
@@ -580,7 +771,7 @@ fn use_complex_breaking_utf32(codepoint: u32) -> bool {
 /// This is implemented by ICU4X for several common string types.
 pub trait LineBreakType<'l, 's> {
     /// The iterator over characters.
-    type IterAttr: Iterator<Item = (usize, Self::CharType)> + Clone;
+    type IterAttr: SeekableIndices<Item = (usize, Self::CharType)>;
 
     /// The character type.
     type CharType: Copy + Into<u32>;
@@ -620,15 +811,28 @@ pub struct LineBreakIterator<'l, 's, Y: LineBreakType<'l, 's> + ?Sized> {
     current_pos_data: Option<(usize, Y::CharType)>,
     result_cache: Vec<usize>,
     data: &'l RuleBreakDataV1<'l>,
+    safe_points: &'l SafePoints,
     options: &'l LineBreakOptions,
     dictionary: &'l Dictionary,
     lstm: &'l LstmPayloads,
+    // The states at the line break opportunities before the current one, nearest last, for
+    // `previous`.
+    previous_breaks: Vec<LineBreakState<Y::IterAttr, Y::CharType>>,
+}
+
+/// The state of a [`LineBreakIterator`] after it returned a line break opportunity.
+struct LineBreakState<I, C> {
+    offset: usize,
+    iter: I,
+    current_pos_data: Option<(usize, C)>,
+    result_cache: Vec<usize>,
 }
 
 impl<'l, 's, Y: LineBreakType<'l, 's>> Iterator for LineBreakIterator<'l, 's, Y> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.previous_breaks.clear();
         if self.check_eof() {
             return None;
         }
@@ -765,6 +969,178 @@ impl<'l, 's, Y: LineBreakType<'l, 's>> Iterator for LineBreakIterator<'l, 's, Y>
     }
 }
 
+/// The property with which the rules start at a character, which is the one that
+/// [`LineBreakIterator`] uses on the left of a pair.
+fn rule_start_property(word_break_rule: WordBreakRule, prop: u8) -> u8 {
+    match (word_break_rule, prop) {
+        (WordBreakRule::BreakAll, AL | NU | SA) => ID,
+        _ => prop,
+    }
+}
+
+impl<'l, 's, Y: LineBreakType<'l, 's>> LineBreakIterator<'l, 's, Y> {
+    /// Moves the iterator to the first line break opportunity after `offset`, and returns
+    /// it.
+    ///
+    /// Returns `None` if `offset` is at or after the end of the text. Following calls to
+    /// [`next`](Iterator::next) continue after the returned break opportunity.
+    ///
+    /// Only the text since the last offset before `offset` where the rules break regardless
+    /// of the text before is segmented.
+    pub fn following(&mut self, offset: usize) -> Option<usize> {
+        if offset >= self.len {
+            self.reset(self.len);
+            return None;
+        }
+        let start = safe_point(&self.iter, offset, |context, c| {
+            self.is_safe_point(context, c)
+        });
+        self.reset(start);
+        self.find(|&i| i > offset)
+    }
+
+    /// Moves the iterator to the last line break opportunity before `offset`, or the start
+    /// of the text, and returns it.
+    ///
+    /// Returns `None` if `offset` is 0. Following calls to [`next`](Iterator::next) continue
+    /// after the returned break opportunity.
+    ///
+    /// Only the text since the last offset before `offset` where the rules break regardless
+    /// of the text before is segmented.
+    pub fn preceding(&mut self, offset: usize) -> Option<usize> {
+        if offset == 0 {
+            return None;
+        }
+        if self.len == 0 {
+            self.reset(0);
+            return Some(0);
+        }
+        // The end of the text is the last break opportunity.
+        self.segment_before(offset.min(self.len + 1));
+        self.pop_previous_break()
+    }
+
+    /// Returns whether `offset` is a line break opportunity. The start of the text is one.
+    ///
+    /// If it is, following calls to [`next`](Iterator::next) continue after `offset`.
+    /// Otherwise they continue after the next line break opportunity, like after [`following`](Self::following).
+    pub fn is_boundary(&mut self, offset: usize) -> bool {
+        if offset == 0 {
+            self.reset(0);
+            return true;
+        }
+        self.following(offset - 1) == Some(offset)
+    }
+
+    /// Moves the iterator to the line break opportunity before the most recently returned one, and
+    /// returns it.
+    ///
+    /// Returns `None` if the iterator is at the start of the text.
+    pub fn previous(&mut self) -> Option<usize> {
+        if self.previous_breaks.is_empty() {
+            let offset = self.current_offset();
+            if offset == 0 {
+                return None;
+            }
+            self.segment_before(offset);
+        }
+        self.pop_previous_break()
+    }
+
+    /// Segments the text before `offset` from a safe point, and keeps the states at its line
+    /// break opportunities for [`previous`](Self::previous), which then doesn't segment the
+    /// text again.
+    fn segment_before(&mut self, offset: usize) {
+        let start = safe_point(&self.iter, offset - 1, |context, c| {
+            self.is_safe_point(context, c)
+        });
+        self.reset(start);
+        // The safe point is a break opportunity too.
+        let mut breaks = vec![LineBreakState {
+            offset: start,
+            iter: self.iter.clone(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+        }];
+        while let Some(i) = self.next() {
+            if i >= offset {
+                break;
+            }
+            breaks.push(LineBreakState {
+                offset: i,
+                iter: self.iter.clone(),
+                current_pos_data: self.current_pos_data,
+                result_cache: self.result_cache.clone(),
+            });
+        }
+        self.previous_breaks = breaks;
+    }
+
+    /// Moves the iterator back to the last break opportunity kept by
+    /// [`segment_before`](Self::segment_before).
+    fn pop_previous_break(&mut self) -> Option<usize> {
+        let state = self.previous_breaks.pop()?;
+        self.iter = state.iter;
+        self.current_pos_data = state.current_pos_data;
+        self.result_cache = state.result_cache;
+        Some(state.offset)
+    }
+
+    /// Returns whether the rules break between `context`, the characters before an offset,
+    /// and `right`, the character at it, whatever the text before them.
+    fn is_safe_point(&self, context: &[Y::CharType], right: Y::CharType) -> bool {
+        if self.options.line_break_rule == LineBreakRule::Anywhere {
+            return true;
+        }
+        let left = match context.last() {
+            Some(&left) => left,
+            None => return true,
+        };
+        // Text in complex languages is segmented from the start of its run.
+        if Y::use_complex_breaking(self, left) || Y::use_complex_breaking(self, right) {
+            return false;
+        }
+        let start = |prop| rule_start_property(self.options.word_break_rule, prop);
+        let left_prop = start(Y::get_linebreak_property_with_rule(self, left));
+        let right_prop = Y::get_linebreak_property_with_rule(self, right);
+        if self.options.word_break_rule == WordBreakRule::KeepAll
+            && is_non_break_by_keepall(left_prop, right_prop)
+        {
+            return false;
+        }
+        if self.options.line_break_rule == LineBreakRule::Loose
+            && is_break_utf32_by_loose(right.into(), left_prop, right_prop, self.options.ja_zh)
+                == Some(false)
+        {
+            return false;
+        }
+        self.safe_points.breaks_in_any_state(
+            self.data,
+            context.iter().map(|&c| {
+                let prop = Y::get_linebreak_property_with_rule(self, c);
+                (prop, start(prop))
+            }),
+            right_prop,
+        )
+    }
+
+    /// The offset of the most recently returned line break opportunity.
+    fn current_offset(&self) -> usize {
+        match self.current_pos_data {
+            Some((i, _)) => i,
+            None => self.iter.clone().next().map_or(self.len, |(i, _)| i),
+        }
+    }
+
+    /// Restarts segmentation at `offset`, as if the text started there.
+    fn reset(&mut self, offset: usize) {
+        self.iter.seek(offset);
+        self.current_pos_data = None;
+        self.result_cache.clear();
+        self.previous_breaks.clear();
+    }
+}
+
 impl<'l, 's, Y: LineBreakType<'l, 's>> LineBreakIterator<'l, 's, Y> {
     #[inline]
     fn check_eof(&mut self) -> bool {
@@ -807,7 +1183,7 @@ impl<'l, 's, Y: LineBreakType<'l, 's>> LineBreakIterator<'l, 's, Y> {
 pub struct LineBreakTypeUtf8;
 
 impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf8 {
-    type IterAttr = CharIndices<'s>;
+    type IterAttr = ChunkIndices<'s, str>;
     type CharType = char;
 
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: char) -> u8 {
@@ -838,7 +1214,7 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf8 {
 pub struct LineBreakTypePotentiallyIllFormedUtf8;
 
 impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypePotentiallyIllFormedUtf8 {
-    type IterAttr = Utf8CharIndices<'s>;
+    type IterAttr = PotentiallyIllFormedUtf8Indices<'s>;
     type CharType = char;
 
     fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<Self>, c: char) -> u8 {
@@ -895,21 +1271,54 @@ where
     iter.current_pos_data = start_point;
     let breaks = complex_language_segment_str(iter.dictionary, iter.lstm, &s);
     iter.result_cache = breaks;
-    let mut i = iter.current_pos_data.unwrap().1.len_utf8();
+    // The offset of the current character in `s`.
+    let mut i = left_codepoint.len_utf8();
     loop {
         if i == *iter.result_cache.first().unwrap() {
             // Re-calculate breaking offset
             iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
             return Some(iter.current_pos_data.unwrap().0);
         }
+        i += T::get_current_position_character_len(iter);
         iter.current_pos_data = iter.iter.next();
         if iter.current_pos_data.is_none() {
             iter.result_cache.clear();
             return Some(iter.len);
         }
-        i += T::get_current_position_character_len(iter);
     }
 }
+pub struct LineBreakTypeChunks<C: ?Sized>(PhantomData<C>);
+
+impl<'l, 's, C: TextChunks + ?Sized + 's> LineBreakType<'l, 's> for LineBreakTypeChunks<C> {
+    type IterAttr = ChunkIndices<'s, C>;
+    type CharType = char;
+
+    fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> u8 {
+        get_linebreak_property_with_rule(
+            &iterator.data.property_table,
+            c,
+            iterator.options.line_break_rule,
+            iterator.options.word_break_rule,
+        )
+    }
+
+    #[inline]
+    fn use_complex_breaking(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> bool {
+        use_complex_breaking_utf32(&iterator.data.property_table, c as u32)
+    }
+
+    fn get_current_position_character_len(iterator: &LineBreakIterator<'l, 's, Self>) -> usize {
+        iterator.current_pos_data.unwrap().1.len_utf8()
+    }
+
+    fn handle_complex_language(
+        iter: &mut LineBreakIterator<'l, 's, Self>,
+        left_codepoint: char,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
+    }
+}
+
 pub struct LineBreakTypeLatin1;
 
 impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeLatin1 {
@@ -12,7 +12,7 @@
 use alloc::borrow::Cow;
 use icu_collections::codepointtrie::CodePointTrie;
 use icu_provider::prelude::*;
-use zerovec::{ZeroMap, ZeroVec};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
 
 #[cfg(feature = "lstm")]
 use crate::lstm_error::Error;
@@ -21,10 +21,10 @@ use ndarray::{Array, Array1, Array2};
 
 /// Pre-processed Unicode data in the form of tables to be used for rule-based breaking.
 #[icu_provider::data_struct(
-    LineBreakDataV1Marker = "segmenter/line@1",
-    WordBreakDataV1Marker = "segmenter/word@1",
-    GraphemeClusterBreakDataV1Marker = "segmenter/grapheme@1",
-    SentenceBreakDataV1Marker = "segmenter/sentence@1"
+    LineBreakDataV1Marker = "segmenter/line@2",
+    WordBreakDataV1Marker = "segmenter/word@2",
+    GraphemeClusterBreakDataV1Marker = "segmenter/grapheme@2",
+    SentenceBreakDataV1Marker = "segmenter/sentence@2"
 )]
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(
@@ -58,6 +58,11 @@ pub struct RuleBreakDataV1<'data> {
     /// The index of "SA" state (or 127 if the complex language isn't handled) for
     /// [`Self::break_state_table`].
     pub complex_property: u8,
+
+    /// The [`WordType`] of a segment by the state before its end, indexed like the rows of
+    /// [`Self::break_state_table`]. This is empty for segmenters other than the word segmenter.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_status_table: RuleStatusTable<'data>,
 }
 
 /// Property table for rule-based breaking.
@@ -84,6 +89,53 @@ pub struct RuleBreakStateTable<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))] pub ZeroVec<'data, i8>,
 );
 
+/// Rule status table for rule-based breaking.
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RuleStatusTable<'data>(
+    #[cfg_attr(feature = "serde", serde(borrow))] pub ZeroVec<'data, WordType>,
+);
+
+/// The type of a segment returned by the word segmenter, which corresponds to the
+/// rule status of word break iterators in ICU4C: each variant stands for one of the
+/// `UBRK_WORD_*` ranges of rule statuses, from `UBRK_WORD_NONE` (0–99) to `UBRK_WORD_IDEO`
+/// (400–499).
+///
+/// See [`RuleBreakIterator::word_type`](crate::RuleBreakIterator::word_type).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(WordTypeULE)]
+#[repr(u8)]
+pub enum WordType {
+    /// The segment is not a word, like whitespace, punctuation, or emoji.
+    None = 0,
+    /// The segment is a number.
+    Number = 1,
+    /// The segment is a word made of letters, including words found by the dictionary
+    /// or LSTM segmenters in scripts like Thai or Chinese.
+    ///
+    /// Unlike in ICU4C, where they are [`WordType::Ideo`], this includes the Chinese and
+    /// Japanese words found by the dictionary, because they share a break state with the
+    /// other text segmented by a dictionary or LSTM.
+    Letter = 2,
+    /// The segment is a word made of kana, like `UBRK_WORD_KANA`. Like in ICU4C, the
+    /// default word break rules do not return this type; runs of Katakana are
+    /// [`WordType::Ideo`].
+    Kana = 3,
+    /// The segment is a word made of ideographs or Katakana, like `UBRK_WORD_IDEO`.
+    Ideo = 4,
+}
+
 /// char16trie data for dictionary break
 #[icu_provider::data_struct(UCharDictionaryBreakDataV1Marker = "segmenter/dictionary@1")]
 #[derive(Debug, PartialEq, Clone)]
@@ -99,6 +151,53 @@ pub struct UCharDictionaryBreakDataV1<'data> {
     pub trie_data: ZeroVec<'data, u16>,
 }
 
+/// Abbreviations after which the sentence segmenter does not break, such as "Mr." in English.
+///
+/// This data comes from the CLDR sentence break suppressions (`segments/*/suppressions`).
+#[icu_provider::data_struct(
+    SentenceBreakSuppressionsV1Marker = "segmenter/sentence/suppressions@1"
+)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct SentenceBreakSuppressionsV1<'data> {
+    /// The suppressed abbreviations, sorted so that they can be binary searched.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub suppressions: VarZeroVec<'data, str>,
+}
+
+/// Liang hyphenation patterns for a language, as used by TeX.
+///
+/// This data comes from the hyph-utf8 pattern files (`hyph-*.pat.txt` and `hyph-*.hyp.txt`).
+#[icu_provider::data_struct(HyphenationPatternsV1Marker = "segmenter/hyphenation@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct HyphenationPatternsV1<'data> {
+    /// The patterns, from their lowercase letters, with `.` marking the start or end of a word,
+    /// to the hyphenation levels around them. There is one more level than letters, and the
+    /// `i`th level is the one before the `i`th letter.
+    ///
+    /// Words are lowercased character by character with [`char::to_lowercase`], so a letter
+    /// can become several.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap<'data, str, [u8]>,
+
+    /// Words with explicit hyphenation points, from the lowercase word to the indices of the
+    /// lowercase characters before which it can be hyphenated.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exceptions: ZeroMap<'data, str, [u8]>,
+}
+
 /// The struct that stores a LSTM's matrix.
 #[derive(PartialEq, Debug, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
 #[cfg_attr(
@@ -3,14 +3,16 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::{Dictionary, LstmPayloads};
-use crate::provider::RuleBreakDataV1;
+use crate::indices::{safe_point, SeekableIndices};
+use crate::provider::{RuleBreakDataV1, SentenceBreakSuppressionsV1, WordType};
 use crate::symbols::*;
+use alloc::vec::Vec;
 
 /// A trait allowing for RuleBreakIterator to be generalized to multiple string
 /// encoding methods and granularity such as grapheme cluster, word, etc.
 pub trait RuleBreakType<'l, 's> {
     /// The iterator over characters.
-    type IterAttr: Iterator<Item = (usize, Self::CharType)> + Clone;
+    type IterAttr: SeekableIndices<Item = (usize, Self::CharType)>;
 
     /// The character type.
     type CharType: Copy + Into<u32>;
@@ -40,22 +42,358 @@ pub struct RuleBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
     pub(crate) iter: Y::IterAttr,
     pub(crate) len: usize,
     pub(crate) current_pos_data: Option<(usize, Y::CharType)>,
-    pub(crate) result_cache: alloc::vec::Vec<usize>,
+    pub(crate) result_cache: Vec<usize>,
+    // The state before the most recently returned break, for the rule status.
+    pub(crate) boundary_property: u8,
     pub(crate) data: &'l RuleBreakDataV1<'l>,
+    pub(crate) safe_points: &'l SafePoints,
     pub(crate) dictionary: &'l Dictionary,
     pub(crate) lstm: &'l LstmPayloads,
+    // Abbreviations after which sentence breaks are suppressed, if enabled.
+    pub(crate) suppressions: Option<&'l SentenceBreakSuppressionsV1<'l>>,
+    // The states at the breaks before the current one, nearest last, for `previous`.
+    pub(crate) previous_breaks: Vec<BreakState<Y::IterAttr, Y::CharType>>,
+}
+
+/// The state of a [`RuleBreakIterator`] after it returned a break.
+pub(crate) struct BreakState<I, C> {
+    offset: usize,
+    iter: I,
+    current_pos_data: Option<(usize, C)>,
+    result_cache: Vec<usize>,
+    boundary_property: u8,
 }
 
 impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.previous_breaks.clear();
+        let suppressions = match self.suppressions {
+            Some(suppressions) => suppressions,
+            None => return self.next_rule_break(),
+        };
+        // The segment starts at the previous break, which is not moved by suppressed breaks.
+        let segment_start = self.current_offset();
+        loop {
+            let offset = self.next_rule_break()?;
+            if offset == self.len
+                || !Self::is_suppressed(suppressions, &self.iter, segment_start, offset)
+            {
+                return Some(offset);
+            }
+        }
+    }
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's>> RuleBreakIterator<'l, 's, Y> {
+    /// Moves the iterator to the first break opportunity after `offset`, and returns it.
+    ///
+    /// Returns `None` if `offset` is at or after the end of the text. Following calls to
+    /// [`next`](Iterator::next) continue after the returned break opportunity.
+    ///
+    /// Only the text since the last offset before `offset` where the rules break regardless
+    /// of the text before is segmented.
+    pub fn following(&mut self, offset: usize) -> Option<usize> {
+        if offset >= self.len {
+            self.reset(self.len);
+            return None;
+        }
+        let start = safe_point(&self.iter, offset, |context, c| {
+            self.is_safe_point(context, c)
+        });
+        self.reset(start);
+        self.find(|&i| i > offset)
+    }
+
+    /// Moves the iterator to the last break opportunity before `offset`, and returns it.
+    ///
+    /// Returns `None` if `offset` is 0. Following calls to [`next`](Iterator::next) continue
+    /// after the returned break opportunity.
+    ///
+    /// Only the text since the last offset before `offset` where the rules break regardless
+    /// of the text before is segmented.
+    pub fn preceding(&mut self, offset: usize) -> Option<usize> {
+        if offset == 0 {
+            return None;
+        }
+        if self.len == 0 {
+            self.reset(0);
+            return Some(0);
+        }
+        // The end of the text is the last break opportunity.
+        self.segment_before(offset.min(self.len + 1));
+        self.pop_previous_break()
+    }
+
+    /// Returns whether `offset` is a break opportunity. The start of the text is one.
+    ///
+    /// If it is, following calls to [`next`](Iterator::next) continue after `offset`.
+    /// Otherwise they continue after the next break opportunity, like after [`following`](Self::following).
+    pub fn is_boundary(&mut self, offset: usize) -> bool {
+        if offset == 0 {
+            self.reset(0);
+            // Skip the break at the start of the text, which was just checked.
+            self.next();
+            return true;
+        }
+        self.following(offset - 1) == Some(offset)
+    }
+
+    /// Moves the iterator to the break opportunity before the most recently returned one, and
+    /// returns it.
+    ///
+    /// Returns `None` if the iterator is at the start of the text.
+    pub fn previous(&mut self) -> Option<usize> {
+        if self.previous_breaks.is_empty() {
+            let offset = self.current_offset();
+            if offset == 0 {
+                return None;
+            }
+            self.segment_before(offset);
+        }
+        self.pop_previous_break()
+    }
+
+    /// Segments the text before `offset` from a safe point, and keeps the states at its breaks
+    /// for [`previous`](Self::previous), which then doesn't segment the text again.
+    fn segment_before(&mut self, offset: usize) {
+        let mut start = offset;
+        loop {
+            start = safe_point(&self.iter, start - 1, |context, c| {
+                self.is_safe_point(context, c)
+            });
+            self.reset(start);
+            let mut breaks = Vec::new();
+            while let Some(i) = self.next() {
+                if i >= offset {
+                    break;
+                }
+                // The rule status of the break at the safe point depends on the text before it.
+                if i > start || start == 0 {
+                    breaks.push(BreakState {
+                        offset: i,
+                        iter: self.iter.clone(),
+                        current_pos_data: self.current_pos_data,
+                        result_cache: self.result_cache.clone(),
+                        boundary_property: self.boundary_property,
+                    });
+                }
+            }
+            if !breaks.is_empty() || start == 0 {
+                self.previous_breaks = breaks;
+                return;
+            }
+        }
+    }
+
+    /// Moves the iterator back to the last break kept by [`segment_before`](Self::segment_before).
+    fn pop_previous_break(&mut self) -> Option<usize> {
+        let state = self.previous_breaks.pop()?;
+        self.iter = state.iter;
+        self.current_pos_data = state.current_pos_data;
+        self.result_cache = state.result_cache;
+        self.boundary_property = state.boundary_property;
+        Some(state.offset)
+    }
+
+    /// Returns whether the rules break between `context`, the characters before an offset,
+    /// and `right`, the character at it, whatever the text before them.
+    fn is_safe_point(&self, context: &[Y::CharType], right: Y::CharType) -> bool {
+        let complex = self.data.complex_property;
+        let right = self.get_break_property(right);
+        // Text in complex languages is segmented from the start of its run.
+        right != complex
+            && context.last().map(|&c| self.get_break_property(c)) != Some(complex)
+            && self.safe_points.breaks_in_any_state(
+                self.data,
+                context.iter().map(|&c| {
+                    let prop = self.get_break_property(c);
+                    (prop, prop)
+                }),
+                right,
+            )
+    }
+
+    /// The offset of the most recently returned break opportunity.
+    fn current_offset(&self) -> usize {
+        match self.current_pos_data {
+            Some((i, _)) => i,
+            None => self.iter.clone().next().map_or(self.len, |(i, _)| i),
+        }
+    }
+
+    /// Restarts segmentation at `offset`, as if the text started there.
+    fn reset(&mut self, offset: usize) {
+        self.iter.seek(offset);
+        self.current_pos_data = None;
+        self.result_cache.clear();
+        self.boundary_property = 0;
+        self.previous_breaks.clear();
+    }
+}
+
+/// How an iterator goes back to an earlier break when a rule that spans several characters
+/// doesn't match, for [`SafePoints`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Backtracking {
+    /// Back to the last character read, like the line break iterators. Their options can
+    /// also end a rule at any character.
+    LastCharacter,
+    /// Back to the last character read in the state of a property, or at an intermediate
+    /// match, like [`RuleBreakIterator`].
+    IntermediateMatch,
+}
+
+/// Where an iterator goes back to if the current rule doesn't match: not in a rule, before
+/// the characters that a [`SafePoints`] looks at, or to one of them.
+const NO_MARKER: usize = 0;
+const MARKER_BEFORE: usize = 1;
+const MARKER_IN_CONTEXT: usize = 2;
+const MARKERS: usize = 3;
+
+/// A set of states of the state machine, by marker.
+#[derive(Clone, Copy, Default)]
+struct StateSet([[u64; 4]; MARKERS]);
+
+impl StateSet {
+    fn insert(&mut self, marker: usize, state: u8) {
+        self.0[marker][state as usize / 64] |= 1 << (state % 64);
+    }
+
+    fn iter(self) -> impl Iterator<Item = (usize, u8)> {
+        (0..MARKERS).flat_map(move |marker| {
+            (0..4).flat_map(move |i| {
+                let mut word = self.0[marker][i];
+                core::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros();
+                    word &= word - 1;
+                    Some((marker, (i * 64) as u8 + bit as u8))
+                })
+            })
+        })
+    }
+}
+
+/// The states in which the state machine of a segmenter can be after a character of each
+/// break property, whatever the text before it. They are computed when the segmenter is
+/// created, so that finding where segmentation can restart, like in [`safe_point`], only
+/// follows the characters before an offset from these states.
+pub(crate) struct SafePoints {
+    after: Vec<StateSet>,
+    backtracking: Backtracking,
+}
+
+impl SafePoints {
+    /// Computes the states after each break property of `data`, where `start` is the state in
+    /// which the rules start at a character with a property.
+    pub(crate) fn new(
+        data: &RuleBreakDataV1,
+        start: impl Fn(u8) -> u8,
+        backtracking: Backtracking,
+    ) -> Self {
+        // Before the character, these are all but the start and the end of the text.
+        let mut any = StateSet::default();
+        for s in 0..data.property_count {
+            if s != data.sot_property && s != data.eot_property {
+                any.insert(NO_MARKER, s);
+                any.insert(MARKER_BEFORE, s);
+            }
+        }
+        let mut safe_points = Self {
+            after: Vec::new(),
+            backtracking,
+        };
+        safe_points.after = (0..data.property_count)
+            .map(|prop| safe_points.step(data, &any, prop, start(prop)))
+            .collect();
+        safe_points
+    }
+
+    /// The states after a character with the property `prop`, from the states `before` it.
+    fn step(&self, data: &RuleBreakDataV1, before: &StateSet, prop: u8, start: u8) -> StateSet {
+        // The rules start at the character after a break, or when the iterator goes back to
+        // it. This includes more states than can be reached, which only makes the safe points
+        // fewer.
+        let mut after = StateSet::default();
+        after.insert(NO_MARKER, start);
+        for (marker, s) in before.iter() {
+            let next_state = state(data, s, prop);
+            if next_state < 0 {
+                // The rules break or keep going from this character, in the state in which
+                // they start at it, or go back to a character where they restart.
+                continue;
+            }
+            let (next_state, next_marker) = match self.backtracking {
+                Backtracking::LastCharacter => (next_state, MARKER_IN_CONTEXT),
+                Backtracking::IntermediateMatch
+                    if marker == NO_MARKER
+                        || s as i8 <= data.last_codepoint_property
+                        || next_state & INTERMEDIATE_MATCH_RULE != 0 =>
+                {
+                    (next_state & !INTERMEDIATE_MATCH_RULE, MARKER_IN_CONTEXT)
+                }
+                Backtracking::IntermediateMatch => (next_state, marker),
+            };
+            after.insert(next_marker, next_state as u8);
+        }
+        after
+    }
+
+    /// Returns whether the state machine of `data` breaks before a character with the
+    /// property `right`, after characters with the properties of `context`, whatever its
+    /// state before them.
+    ///
+    /// The items of `context` are the property of a character and the state in which the
+    /// rules start at it. The segmenters start the rules at the character after every break,
+    /// so they can restart at an offset where this holds as if the text started there.
+    pub(crate) fn breaks_in_any_state(
+        &self,
+        data: &RuleBreakDataV1,
+        mut context: impl Iterator<Item = (u8, u8)>,
+        right: u8,
+    ) -> bool {
+        let mut states = match context.next() {
+            Some((prop, _)) => match self.after.get(prop as usize) {
+                Some(&states) => states,
+                None => return false,
+            },
+            None => return true,
+        };
+        for (prop, start) in context {
+            states = self.step(data, &states, prop, start);
+        }
+        states
+            .iter()
+            .all(|(marker, s)| match state(data, s, right) {
+                BREAK_RULE | UNKNOWN_RULE => true,
+                // Outside of a rule this is a break, and in the context the iterator goes back
+                // to a character where the rules restart in a state that is included above.
+                NOT_MATCH_RULE => marker != MARKER_BEFORE,
+                _ => false,
+            })
+    }
+}
+
+/// The state of the state machine of `data` after a character with the property `right`, in
+/// the state `left`.
+fn state(data: &RuleBreakDataV1, left: u8, right: u8) -> i8 {
+    let idx = left as usize * data.property_count as usize + right as usize;
+    data.break_state_table.0.get(idx).unwrap_or(KEEP_RULE)
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's>> RuleBreakIterator<'l, 's, Y> {
+    fn next_rule_break(&mut self) -> Option<usize> {
         // If we have break point cache by previous run, return this result
         if !self.result_cache.is_empty() {
             let mut i = 0;
             loop {
                 if i == *self.result_cache.first().unwrap() {
                     self.result_cache = self.result_cache.iter().skip(1).map(|r| r - i).collect();
+                    self.boundary_property = self.data.complex_property;
                     return Some(self.current_pos_data.unwrap().0);
                 }
                 i += Y::get_current_position_character_len(self);
@@ -63,6 +401,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
                 if self.current_pos_data.is_none() {
                     // Reach EOF
                     self.result_cache.clear();
+                    self.boundary_property = self.data.complex_property;
                     return Some(self.len);
                 }
             }
@@ -74,6 +413,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
             // SOT x anything
             let right_prop = self.get_current_break_property();
             if self.is_break_from_table(self.data.sot_property, right_prop) {
+                self.boundary_property = self.data.sot_property;
                 return Some(current_pos_data.0);
             }
         }
@@ -84,6 +424,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
             self.current_pos_data = self.iter.next();
 
             if self.current_pos_data.is_none() {
+                self.boundary_property = left_prop;
                 return Some(self.len);
             }
             let right_prop = self.get_current_break_property();
@@ -93,10 +434,12 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
             if right_prop == self.data.complex_property {
                 if left_prop != self.data.complex_property {
                     // break before SA
+                    self.boundary_property = left_prop;
                     return Some(self.current_pos_data.unwrap().0);
                 }
                 let break_offset = Y::handle_complex_language(self, left_codepoint);
                 if break_offset.is_some() {
+                    self.boundary_property = self.data.complex_property;
                     return break_offset;
                 }
             }
@@ -108,8 +451,10 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
                 // This isn't simple rule set. We need marker to restore iterator to previous position.
                 let mut previous_iter = self.iter.clone();
                 let mut previous_pos_data = self.current_pos_data;
+                // The state before the marker.
+                let mut previous_left_prop = left_prop;
 
-                loop {
+                let left_state = loop {
                     self.current_pos_data = self.iter.next();
                     if self.current_pos_data.is_none() {
                         // Reached EOF. But we are analyzing multiple characters now, so next break may be previous point.
@@ -119,9 +464,11 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
                         {
                             self.iter = previous_iter;
                             self.current_pos_data = previous_pos_data;
+                            self.boundary_property = previous_left_prop;
                             return Some(previous_pos_data.unwrap().0);
                         }
                         // EOF
+                        self.boundary_property = break_state as u8;
                         return Some(self.len);
                     }
 
@@ -129,7 +476,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
                     let prop = self.get_current_break_property();
                     break_state = self.get_break_state_from_table(break_state as u8, prop);
                     if break_state < 0 {
-                        break;
+                        break previous_break_state as u8;
                     }
                     if previous_break_state >= 0
                         && previous_break_state <= self.data.last_codepoint_property
@@ -137,32 +484,143 @@ impl<'l, 's, Y: RuleBreakType<'l, 's>> Iterator for RuleBreakIterator<'l, 's, Y>
                         // Move marker
                         previous_iter = self.iter.clone();
                         previous_pos_data = self.current_pos_data;
+                        previous_left_prop = previous_break_state as u8;
                     }
                     if (break_state & INTERMEDIATE_MATCH_RULE) != 0 {
                         break_state -= INTERMEDIATE_MATCH_RULE;
                         previous_iter = self.iter.clone();
                         previous_pos_data = self.current_pos_data;
+                        previous_left_prop = previous_break_state as u8;
                     }
-                }
+                };
                 if break_state == KEEP_RULE {
                     continue;
                 }
                 if break_state == NOT_MATCH_RULE {
                     self.iter = previous_iter;
                     self.current_pos_data = previous_pos_data;
+                    self.boundary_property = previous_left_prop;
                     return Some(previous_pos_data.unwrap().0);
                 }
+                self.boundary_property = left_state;
                 return Some(self.current_pos_data.unwrap().0);
             }
 
             if self.is_break_from_table(left_prop, right_prop) {
+                self.boundary_property = left_prop;
                 return Some(self.current_pos_data.unwrap().0);
             }
         }
     }
-}
 
-impl<'l, 's, Y: RuleBreakType<'l, 's>> RuleBreakIterator<'l, 's, Y> {
+    /// Returns whether the break at `offset` follows a suppressed abbreviation, like "Mr." in
+    /// "Mr. Smith", in the segment starting at `start`.
+    ///
+    /// Like ICU4C's `SimpleFilteredSentenceBreakIterator`, this matches backwards from the
+    /// break, so only the last word before it is looked at.
+    fn is_suppressed(
+        suppressions: &SentenceBreakSuppressionsV1,
+        iter: &Y::IterAttr,
+        start: usize,
+        offset: usize,
+    ) -> bool {
+        let char_before = |offset: usize| {
+            iter.char_before(offset)
+                .filter(|&(i, _)| i >= start)
+                .map(|(i, c)| {
+                    (
+                        i,
+                        char::from_u32(c.into()).unwrap_or(char::REPLACEMENT_CHARACTER),
+                    )
+                })
+        };
+        // Find the end of the last word before the break, skipping the spaces following it.
+        let mut end = offset;
+        loop {
+            match char_before(end) {
+                // Paragraph separators always end a sentence.
+                Some((_, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')) | None => return false,
+                Some((i, c)) if c.is_whitespace() => end = i,
+                Some(_) => break,
+            }
+        }
+        // Whether the text from `word_start` to `end` is a suppressed abbreviation.
+        let is_abbreviation = |word_start: usize| {
+            suppressions
+                .suppressions
+                .binary_search_by(|abbreviation| {
+                    let mut word = iter.clone();
+                    word.seek(word_start);
+                    // Comparing by code points is the same as comparing the UTF-8.
+                    abbreviation
+                        .chars()
+                        .cmp(word.take_while(|&(i, _)| i < end).map(|(_, c)| {
+                            char::from_u32(c.into()).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }))
+                })
+                .is_ok()
+        };
+        // An abbreviation has to start the word or follow a non-letter, as in "(Mr.".
+        let mut word_start = end;
+        loop {
+            match char_before(word_start) {
+                Some((i, c)) if !c.is_whitespace() => {
+                    if !c.is_alphabetic() && word_start < end && is_abbreviation(word_start) {
+                        return true;
+                    }
+                    word_start = i;
+                }
+                _ => return is_abbreviation(word_start),
+            }
+        }
+    }
+
+    /// Returns the type of the segment that ends at the most recently returned break
+    /// opportunity, like the rule status of word break iterators in ICU4C.
+    ///
+    /// Segments found by the dictionary or LSTM segmenters, like Thai or Chinese words, are
+    /// [`WordType::Letter`], and runs of Katakana are [`WordType::Ideo`]. This is always
+    /// [`WordType::None`] for segmenters other than the word segmenter, and before the first
+    /// break opportunity or at the start of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu_segmenter::{WordBreakSegmenter, WordType};
+    /// let segmenter =
+    ///     WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+    ///         .expect("Data exists");
+    ///
+    /// let mut iter = segmenter.segment_str("Hello 123!");
+    /// let mut segments = Vec::new();
+    /// while let Some(i) = iter.next() {
+    ///     segments.push((i, iter.word_type()));
+    /// }
+    /// assert_eq!(
+    ///     &segments,
+    ///     &[
+    ///         (0, WordType::None),
+    ///         (5, WordType::Letter),
+    ///         (6, WordType::None),
+    ///         (9, WordType::Number),
+    ///         (10, WordType::None),
+    ///     ]
+    /// );
+    /// ```
+    pub fn word_type(&self) -> WordType {
+        self.data
+            .rule_status_table
+            .0
+            .get(self.boundary_property as usize)
+            .unwrap_or(WordType::None)
+    }
+
+    /// Returns whether the segment that ends at the most recently returned break opportunity
+    /// is a word, i.e. a number or made of letters. See [`Self::word_type`].
+    pub fn is_word_like(&self) -> bool {
+        self.word_type() != WordType::None
+    }
+
     pub(crate) fn get_current_break_property(&self) -> u8 {
         self.get_break_property(self.get_current_codepoint())
     }
@@ -3,14 +3,32 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::vec::Vec;
-use core::str::CharIndices;
+use core::marker::PhantomData;
 use icu_provider::prelude::*;
 
 use crate::complex::{Dictionary, LstmPayloads};
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{
+    ChunkIndices, Latin1Indices, PotentiallyIllFormedUtf8Indices, TextChunks, Utf16Indices,
+};
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
-use utf8_iter::Utf8CharIndices;
+
+/// Options to tailor sentence breaking behavior.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// <a href="https://github.com/unicode-org/icu4x/issues/2259">#2259</a>
+/// </div>
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct SentenceBreakOptions {
+    /// Use `true` to suppress sentence breaks after the locale's abbreviations, such as "Mr."
+    /// in English, using the CLDR sentence break suppressions. This is equivalent to the
+    /// `ss=standard` Unicode extension keyword in ICU.
+    pub suppressions: bool,
+}
 
 /// Sentence break iterator for an `str` (a UTF-8 string).
 pub type SentenceBreakIteratorUtf8<'l, 's> = RuleBreakIterator<'l, 's, SentenceBreakTypeUtf8>;
@@ -25,6 +43,10 @@ pub type SentenceBreakIteratorLatin1<'l, 's> = RuleBreakIterator<'l, 's, Sentenc
 /// Sentence break iterator for a UTF-16 string.
 pub type SentenceBreakIteratorUtf16<'l, 's> = RuleBreakIterator<'l, 's, SentenceBreakTypeUtf16>;
 
+/// Sentence break iterator for text in [`TextChunks`].
+pub type SentenceBreakIteratorChunks<'l, 's, C> =
+    RuleBreakIterator<'l, 's, SentenceBreakTypeChunks<C>>;
+
 /// Supports loading sentence break data, and creating sentence break iterators for different string
 /// encodings.
 ///
@@ -62,10 +84,38 @@ pub type SentenceBreakIteratorUtf16<'l, 's> = RuleBreakIterator<'l, 's, Sentence
 ///     segmenter.segment_latin1(b"Hello World").collect();
 /// assert_eq!(&breakpoints, &[0, 11]);
 /// ```
+///
+/// Suppress breaks after abbreviations:
+///
+/// ```rust
+/// use icu_locid::locale;
+/// use icu_segmenter::{SentenceBreakOptions, SentenceBreakSegmenter};
+///
+/// let text = "Mr. Smith arrived. He sat down.";
+///
+/// let segmenter =
+///     SentenceBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+///         .expect("Data exists");
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 4, 19, 31]);
+///
+/// let mut options = SentenceBreakOptions::default();
+/// options.suppressions = true;
+/// let segmenter = SentenceBreakSegmenter::try_new_with_options_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data exists");
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 19, 31]);
+/// ```
 pub struct SentenceBreakSegmenter {
     payload: DataPayload<SentenceBreakDataV1Marker>,
+    safe_points: SafePoints,
     dictionary: Dictionary,
     lstm: LstmPayloads,
+    suppressions: Option<DataPayload<SentenceBreakSuppressionsV1Marker>>,
 }
 
 impl SentenceBreakSegmenter {
@@ -78,24 +128,88 @@ impl SentenceBreakSegmenter {
         let dictionary = Dictionary::default();
         let lstm = LstmPayloads::default();
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| prop,
+                Backtracking::IntermediateMatch,
+            ),
             payload,
             dictionary,
             lstm,
+            suppressions: None,
         })
     }
 
     icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: SegmenterError);
 
+    /// Construct a [`SentenceBreakSegmenter`] for a locale with custom [`SentenceBreakOptions`].
+    ///
+    /// This uses the CLDR tailorings for the locale, such as ";" ending questions in Greek.
+    pub fn try_new_with_options_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: SentenceBreakOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<SentenceBreakDataV1Marker>
+            + DataProvider<SentenceBreakSuppressionsV1Marker>
+            + ?Sized,
+    {
+        let payload: DataPayload<SentenceBreakDataV1Marker> = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let mut segmenter = Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| prop,
+                Backtracking::IntermediateMatch,
+            ),
+            payload,
+            dictionary: Dictionary::default(),
+            lstm: LstmPayloads::default(),
+            suppressions: None,
+        };
+        if options.suppressions {
+            segmenter.suppressions = Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            );
+        }
+        Ok(segmenter)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: SentenceBreakOptions,
+        error: SegmenterError,
+        functions: [
+            Self::try_new_with_options_unstable,
+            try_new_with_options_with_any_provider,
+            try_new_with_options_with_buffer_provider
+        ]
+    );
+
     /// Create a sentence break iterator for an `str` (a UTF-8 string).
     pub fn segment_str<'l, 's>(&'l self, input: &'s str) -> SentenceBreakIteratorUtf8<'l, 's> {
         SentenceBreakIteratorUtf8 {
-            iter: input.char_indices(),
+            iter: ChunkIndices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: self.suppressions.as_ref().map(|p| p.get()),
+            previous_breaks: Vec::new(),
         }
     }
     /// Create a sentence break iterator for a potentially ill-formed UTF8 string
@@ -106,13 +220,17 @@ impl SentenceBreakSegmenter {
         input: &'s [u8],
     ) -> SentenceBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
         SentenceBreakIteratorPotentiallyIllFormedUtf8 {
-            iter: Utf8CharIndices::new(input),
+            iter: PotentiallyIllFormedUtf8Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: self.suppressions.as_ref().map(|p| p.get()),
+            previous_breaks: Vec::new(),
         }
     }
     /// Create a sentence break iterator for a Latin-1 (8-bit) string.
@@ -125,9 +243,13 @@ impl SentenceBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: self.suppressions.as_ref().map(|p| p.get()),
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -138,9 +260,33 @@ impl SentenceBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: self.suppressions.as_ref().map(|p| p.get()),
+            previous_breaks: Vec::new(),
+        }
+    }
+
+    /// Create a sentence break iterator for text in [`TextChunks`], like a rope.
+    pub fn segment_chunks<'l, 's, C: TextChunks + ?Sized>(
+        &'l self,
+        input: &'s C,
+    ) -> SentenceBreakIteratorChunks<'l, 's, C> {
+        SentenceBreakIteratorChunks {
+            iter: ChunkIndices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            boundary_property: 0,
+            data: self.payload.get(),
+            safe_points: &self.safe_points,
+            dictionary: &self.dictionary,
+            lstm: &self.lstm,
+            suppressions: self.suppressions.as_ref().map(|p| p.get()),
+            previous_breaks: Vec::new(),
         }
     }
 }
@@ -148,7 +294,7 @@ impl SentenceBreakSegmenter {
 pub struct SentenceBreakTypeUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for SentenceBreakTypeUtf8 {
-    type IterAttr = CharIndices<'s>;
+    type IterAttr = ChunkIndices<'s, str>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -165,7 +311,7 @@ impl<'l, 's> RuleBreakType<'l, 's> for SentenceBreakTypeUtf8 {
 pub struct SentenceBreakTypePotentiallyIllFormedUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for SentenceBreakTypePotentiallyIllFormedUtf8 {
-    type IterAttr = Utf8CharIndices<'s>;
+    type IterAttr = PotentiallyIllFormedUtf8Indices<'s>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -180,6 +326,24 @@ impl<'l, 's> RuleBreakType<'l, 's> for SentenceBreakTypePotentiallyIllFormedUtf8
     }
 }
 
+pub struct SentenceBreakTypeChunks<C: ?Sized>(PhantomData<C>);
+
+impl<'l, 's, C: TextChunks + ?Sized + 's> RuleBreakType<'l, 's> for SentenceBreakTypeChunks<C> {
+    type IterAttr = ChunkIndices<'s, C>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.current_pos_data.unwrap().1.len_utf8()
+    }
+
+    fn handle_complex_language(
+        _: &mut RuleBreakIterator<'l, 's, Self>,
+        _: Self::CharType,
+    ) -> Option<usize> {
+        panic!("not reachable")
+    }
+}
+
 pub struct SentenceBreakTypeLatin1;
 
 impl<'l, 's> RuleBreakType<'l, 's> for SentenceBreakTypeLatin1 {
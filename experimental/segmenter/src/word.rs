@@ -5,16 +5,17 @@
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::str::CharIndices;
+use core::marker::PhantomData;
 use icu_locid::{locale, Locale};
 use icu_provider::prelude::*;
 
 use crate::complex::*;
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{
+    ChunkIndices, Latin1Indices, PotentiallyIllFormedUtf8Indices, TextChunks, Utf16Indices,
+};
 use crate::provider::*;
 use crate::rule_segmenter::*;
 use crate::SegmenterError;
-use utf8_iter::Utf8CharIndices;
 
 /// Word break iterator for an `str` (a UTF-8 string).
 pub type WordBreakIteratorUtf8<'l, 's> = RuleBreakIterator<'l, 's, WordBreakTypeUtf8>;
@@ -28,6 +29,9 @@ pub type WordBreakIteratorLatin1<'l, 's> = RuleBreakIterator<'l, 's, WordBreakTy
 /// Word break iterator for a UTF-16 string.
 pub type WordBreakIteratorUtf16<'l, 's> = RuleBreakIterator<'l, 's, WordBreakTypeUtf16>;
 
+/// Word break iterator for text in [`TextChunks`].
+pub type WordBreakIteratorChunks<'l, 's, C> = RuleBreakIterator<'l, 's, WordBreakTypeChunks<C>>;
+
 /// Supports loading word break data, and creating word break iterators for different string
 /// encodings.
 ///
@@ -67,6 +71,7 @@ pub type WordBreakIteratorUtf16<'l, 's> = RuleBreakIterator<'l, 's, WordBreakTyp
 /// ```
 pub struct WordBreakSegmenter {
     payload: DataPayload<WordBreakDataV1Marker>,
+    safe_points: SafePoints,
     dictionary: Dictionary,
     lstm: LstmPayloads,
 }
@@ -81,7 +86,28 @@ impl WordBreakSegmenter {
             + DataProvider<LstmDataV1Marker>
             + ?Sized,
     {
-        let payload = provider.load(Default::default())?.take_payload()?;
+        Self::try_new_with_locale_unstable(provider, &DataLocale::default())
+    }
+
+    /// Construct a [`WordBreakSegmenter`] with the CLDR tailorings for a locale, such as colons
+    /// within words in Finnish and Swedish.
+    #[cfg(feature = "lstm")]
+    pub fn try_new_with_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<UCharDictionaryBreakDataV1Marker>
+            + DataProvider<LstmDataV1Marker>
+            + ?Sized,
+    {
+        let payload = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
 
         let cj = Self::load_dictionary(provider, locale!("ja")).ok();
 
@@ -101,6 +127,11 @@ impl WordBreakSegmenter {
         };
 
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| prop,
+                Backtracking::IntermediateMatch,
+            ),
             payload,
             dictionary: Dictionary {
                 burmese: None,
@@ -108,6 +139,7 @@ impl WordBreakSegmenter {
                 lao: None,
                 thai: None,
                 cj,
+                user: Vec::new(),
             },
             lstm,
         })
@@ -121,7 +153,27 @@ impl WordBreakSegmenter {
             + DataProvider<UCharDictionaryBreakDataV1Marker>
             + ?Sized,
     {
-        let payload = provider.load(Default::default())?.take_payload()?;
+        Self::try_new_with_locale_unstable(provider, &DataLocale::default())
+    }
+
+    /// Construct a [`WordBreakSegmenter`] with the CLDR tailorings for a locale, such as colons
+    /// within words in Finnish and Swedish.
+    #[cfg(not(feature = "lstm"))]
+    pub fn try_new_with_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<UCharDictionaryBreakDataV1Marker>
+            + ?Sized,
+    {
+        let payload = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
 
         let dictionary = if cfg!(feature = "lstm") {
             let cj = Self::load_dictionary(provider, locale!("ja")).ok();
@@ -131,6 +183,7 @@ impl WordBreakSegmenter {
                 lao: None,
                 thai: None,
                 cj,
+                user: Vec::new(),
             }
         } else {
             let cj = Self::load_dictionary(provider, locale!("ja")).ok();
@@ -144,10 +197,16 @@ impl WordBreakSegmenter {
                 lao,
                 thai,
                 cj,
+                user: Vec::new(),
             }
         };
 
         Ok(Self {
+            safe_points: SafePoints::new(
+                payload.get(),
+                |prop| prop,
+                Backtracking::IntermediateMatch,
+            ),
             payload,
             dictionary,
             lstm: LstmPayloads::default(),
@@ -156,6 +215,17 @@ impl WordBreakSegmenter {
 
     icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: SegmenterError);
 
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        functions: [
+            Self::try_new_with_locale_unstable,
+            try_new_with_locale_with_any_provider,
+            try_new_with_locale_with_buffer_provider
+        ]
+    );
+
     fn load_dictionary<D: DataProvider<UCharDictionaryBreakDataV1Marker> + ?Sized>(
         provider: &D,
         locale: Locale,
@@ -181,16 +251,33 @@ impl WordBreakSegmenter {
             .take_payload()
     }
 
+    /// Adds a user dictionary, such as one built from a word list with
+    /// `icu_datagen::build_segmenter_dictionary`.
+    ///
+    /// In text that is segmented with a dictionary or an LSTM model, such as Chinese, Japanese
+    /// or Thai, a word of a user dictionary that starts at a break is then kept together when
+    /// it is longer than the segment found there.
+    pub fn add_user_dictionary(
+        &mut self,
+        dictionary: DataPayload<UCharDictionaryBreakDataV1Marker>,
+    ) {
+        self.dictionary.user.push(dictionary);
+    }
+
     /// Create a word break iterator for an `str` (a UTF-8 string).
     pub fn segment_str<'l, 's>(&'l self, input: &'s str) -> WordBreakIteratorUtf8<'l, 's> {
         WordBreakIteratorUtf8 {
-            iter: input.char_indices(),
+            iter: ChunkIndices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -202,13 +289,17 @@ impl WordBreakSegmenter {
         input: &'s [u8],
     ) -> WordBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
         WordBreakIteratorPotentiallyIllFormedUtf8 {
-            iter: Utf8CharIndices::new(input),
+            iter: PotentiallyIllFormedUtf8Indices::new(input),
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -219,9 +310,13 @@ impl WordBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 
@@ -232,9 +327,50 @@ impl WordBreakSegmenter {
             len: input.len(),
             current_pos_data: None,
             result_cache: Vec::new(),
+            boundary_property: 0,
             data: self.payload.get(),
+            safe_points: &self.safe_points,
             dictionary: &self.dictionary,
             lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
+        }
+    }
+
+    /// Create a word break iterator for text in [`TextChunks`], like a rope.
+    ///
+    /// # Examples
+    ///
+    /// Find the word around an offset without segmenting the whole text:
+    ///
+    /// ```rust
+    /// use icu_segmenter::WordBreakSegmenter;
+    /// let segmenter =
+    ///     WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+    ///         .expect("Data exists");
+    ///
+    /// let text = "First paragraph.\nHello World";
+    /// let mut iter = segmenter.segment_chunks(text);
+    /// assert_eq!(iter.preceding(20), Some(17));
+    /// assert_eq!(iter.following(20), Some(22));
+    /// assert_eq!(iter.next(), Some(23));
+    /// ```
+    pub fn segment_chunks<'l, 's, C: TextChunks + ?Sized>(
+        &'l self,
+        input: &'s C,
+    ) -> WordBreakIteratorChunks<'l, 's, C> {
+        WordBreakIteratorChunks {
+            iter: ChunkIndices::new(input),
+            len: input.len(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            boundary_property: 0,
+            data: self.payload.get(),
+            safe_points: &self.safe_points,
+            dictionary: &self.dictionary,
+            lstm: &self.lstm,
+            suppressions: None,
+            previous_breaks: Vec::new(),
         }
     }
 }
@@ -242,7 +378,7 @@ impl WordBreakSegmenter {
 pub struct WordBreakTypeUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf8 {
-    type IterAttr = CharIndices<'s>;
+    type IterAttr = ChunkIndices<'s, str>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -259,7 +395,7 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf8 {
 pub struct WordBreakTypePotentiallyIllFormedUtf8;
 
 impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypePotentiallyIllFormedUtf8 {
-    type IterAttr = Utf8CharIndices<'s>;
+    type IterAttr = PotentiallyIllFormedUtf8Indices<'s>;
     type CharType = char;
 
     fn get_current_position_character_len(iter: &RuleBreakIterator<Self>) -> usize {
@@ -302,19 +438,38 @@ where
     iter.current_pos_data = start_point;
     let breaks = complex_language_segment_str(iter.dictionary, iter.lstm, &s);
     iter.result_cache = breaks;
-    let mut i = iter.current_pos_data.unwrap().1.len_utf8();
+    // The offset of the current character in `s`.
+    let mut i = left_codepoint.len_utf8();
     loop {
         if i == *iter.result_cache.first().unwrap() {
             // Re-calculate breaking offset
             iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
             return Some(iter.current_pos_data.unwrap().0);
         }
+        i += T::get_current_position_character_len(iter);
         iter.current_pos_data = iter.iter.next();
         if iter.current_pos_data.is_none() {
             iter.result_cache.clear();
             return Some(iter.len);
         }
-        i += T::get_current_position_character_len(iter);
+    }
+}
+
+pub struct WordBreakTypeChunks<C: ?Sized>(PhantomData<C>);
+
+impl<'l, 's, C: TextChunks + ?Sized + 's> RuleBreakType<'l, 's> for WordBreakTypeChunks<C> {
+    type IterAttr = ChunkIndices<'s, C>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.current_pos_data.unwrap().1.len_utf8()
+    }
+
+    fn handle_complex_language(
+        iter: &mut RuleBreakIterator<'l, 's, Self>,
+        left_codepoint: Self::CharType,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
     }
 }
 
@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::{
+    GraphemeClusterBreakSegmenter, LineBreakSegmenter, SentenceBreakSegmenter, TextChunks,
+    WordBreakSegmenter,
+};
+
+const TEXT: &str = "Hello, World! 👨‍👩‍👧 Café.\r\nภาษาไทยภาษาไทย is Thai.\nEnd?\u{2029}  Last line";
+
+/// A text split into chunks of at most `size` bytes.
+struct Chunks {
+    chunks: Vec<&'static str>,
+    starts: Vec<usize>,
+}
+
+impl Chunks {
+    fn new(text: &'static str, size: usize) -> Self {
+        let mut chunks = Vec::new();
+        let mut starts = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let mut end = size.min(rest.len());
+            while !rest.is_char_boundary(end) {
+                end += 1;
+            }
+            starts.push(text.len() - rest.len());
+            chunks.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+        Self { chunks, starts }
+    }
+}
+
+impl TextChunks for Chunks {
+    fn len(&self) -> usize {
+        self.chunks.iter().map(|c| c.len()).sum()
+    }
+
+    fn chunk_at(&self, offset: usize) -> (&str, usize) {
+        let i = match self.starts.binary_search(&offset) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        (self.chunks[i], self.starts[i])
+    }
+}
+
+/// Checks `following` and `preceding` at every offset against the breaks of the whole text,
+/// where the start of the text is a break too.
+macro_rules! check_random_access {
+    ($segmenter:expr, $chunks:expr, $breaks:expr) => {
+        let breaks: &[usize] = $breaks;
+        for offset in 0..=TEXT.len() {
+            let mut iter = $segmenter.segment_chunks($chunks);
+            let expected = breaks.iter().copied().find(|&i| i > offset);
+            assert_eq!(iter.following(offset), expected, "following({offset})");
+            if let Some(i) = expected {
+                let rest: Vec<usize> = iter.collect();
+                let expected_rest: Vec<usize> = breaks.iter().copied().filter(|&b| b > i).collect();
+                assert_eq!(rest, expected_rest, "after following({offset})");
+            }
+
+            let mut iter = $segmenter.segment_chunks($chunks);
+            let expected = core::iter::once(0)
+                .chain(breaks.iter().copied())
+                .filter(|&i| i < offset)
+                .last();
+            assert_eq!(iter.preceding(offset), expected, "preceding({offset})");
+            if let Some(i) = expected {
+                assert_eq!(
+                    iter.next(),
+                    breaks.iter().copied().find(|&b| b > i),
+                    "after preceding({offset})"
+                );
+            }
+        }
+    };
+}
+
+#[test]
+fn chunks_match_str() {
+    let provider = icu_testdata::unstable();
+    let grapheme = GraphemeClusterBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let word = WordBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let sentence = SentenceBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let line = LineBreakSegmenter::try_new_unstable(&provider).unwrap();
+
+    for size in [1, 2, 3, 7, 100] {
+        let chunks = Chunks::new(TEXT, size);
+        assert_eq!(
+            grapheme.segment_chunks(&chunks).collect::<Vec<_>>(),
+            grapheme.segment_str(TEXT).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            word.segment_chunks(&chunks).collect::<Vec<_>>(),
+            word.segment_str(TEXT).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            sentence.segment_chunks(&chunks).collect::<Vec<_>>(),
+            sentence.segment_str(TEXT).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            line.segment_chunks(&chunks).collect::<Vec<_>>(),
+            line.segment_str(TEXT).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn following_and_preceding() {
+    let provider = icu_testdata::unstable();
+    let chunks = Chunks::new(TEXT, 5);
+
+    let segmenter = GraphemeClusterBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let breaks: Vec<usize> = segmenter.segment_str(TEXT).collect();
+    check_random_access!(segmenter, &chunks, &breaks);
+
+    let segmenter = WordBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let breaks: Vec<usize> = segmenter.segment_str(TEXT).collect();
+    check_random_access!(segmenter, &chunks, &breaks);
+
+    let segmenter = SentenceBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let breaks: Vec<usize> = segmenter.segment_str(TEXT).collect();
+    check_random_access!(segmenter, &chunks, &breaks);
+
+    let segmenter = LineBreakSegmenter::try_new_unstable(&provider).unwrap();
+    let breaks: Vec<usize> = segmenter.segment_str(TEXT).collect();
+    check_random_access!(segmenter, &chunks, &breaks);
+}
+
+#[test]
+fn word_type_after_following() {
+    let segmenter = WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap();
+    let mut iter = segmenter.segment_chunks(TEXT);
+    assert_eq!(iter.following(2), Some(5));
+    assert!(iter.is_word_like());
+    assert_eq!(iter.next(), Some(6));
+    assert!(!iter.is_word_like());
+}
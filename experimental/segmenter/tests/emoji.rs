@@ -0,0 +1,120 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::{EmojiClassifier, EmojiKind, EmojiPresentation};
+
+fn classifier() -> EmojiClassifier {
+    EmojiClassifier::try_new_unstable(&icu_testdata::unstable()).expect("Data exists")
+}
+
+#[test]
+fn test_presentation() {
+    let classifier = classifier();
+
+    assert_eq!(
+        classifier.default_presentation('⌚'),
+        Some(EmojiPresentation::Emoji)
+    );
+    assert_eq!(
+        classifier.default_presentation('☺'),
+        Some(EmojiPresentation::Text)
+    );
+    assert_eq!(
+        classifier.default_presentation('1'),
+        Some(EmojiPresentation::Text)
+    );
+    assert_eq!(classifier.default_presentation('\u{FE0F}'), None);
+
+    assert_eq!(
+        classifier.classify("⌚"),
+        Some(EmojiKind::EmojiPresentation)
+    );
+    assert_eq!(
+        classifier.classify("⌚\u{FE0E}"),
+        Some(EmojiKind::TextPresentation)
+    );
+    assert_eq!(classifier.classify("☺"), Some(EmojiKind::TextPresentation));
+    assert_eq!(
+        classifier.classify("☺\u{FE0F}"),
+        Some(EmojiKind::EmojiPresentation)
+    );
+    assert_eq!(classifier.classify("1"), Some(EmojiKind::TextPresentation));
+    assert_eq!(classifier.classify(""), None);
+    assert_eq!(classifier.classify("a\u{FE0F}"), None);
+    assert_eq!(classifier.classify("⌚\u{FE0F}\u{FE0F}"), None);
+}
+
+#[test]
+fn test_sequences() {
+    let classifier = classifier();
+
+    assert_eq!(
+        classifier.classify("#\u{FE0F}\u{20E3}"),
+        Some(EmojiKind::Keycap)
+    );
+    assert_eq!(classifier.classify("7\u{20E3}"), Some(EmojiKind::Keycap));
+    assert_eq!(classifier.classify("a\u{20E3}"), None);
+
+    assert_eq!(classifier.classify("🇯🇵"), Some(EmojiKind::Flag));
+    // A single regional indicator is an emoji character.
+    assert_eq!(classifier.classify("🇯"), Some(EmojiKind::EmojiPresentation));
+    assert_eq!(classifier.classify("🇯🇵🇯"), None);
+
+    // England: U+1F3F4 WAVING BLACK FLAG with the tags "gbeng"
+    assert_eq!(
+        classifier.classify("🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"),
+        Some(EmojiKind::TagSequence)
+    );
+    assert_eq!(classifier.classify("🏴\u{E007F}"), None);
+    assert_eq!(classifier.classify("a\u{E0067}\u{E007F}"), None);
+
+    assert_eq!(
+        classifier.classify("👋🏿"),
+        Some(EmojiKind::ModifierSequence)
+    );
+    // U+2764 HEAVY BLACK HEART is not an emoji modifier base.
+    assert_eq!(classifier.classify("❤🏿"), None);
+
+    // Family: man, woman, girl
+    assert_eq!(classifier.classify("👨‍👩‍👧"), Some(EmojiKind::ZwjSequence));
+    // Woman health worker: woman with a skin tone, U+2695 STAFF OF AESCULAPIUS with U+FE0F
+    assert_eq!(
+        classifier.classify("👩🏽\u{200D}⚕\u{FE0F}"),
+        Some(EmojiKind::ZwjSequence)
+    );
+    assert_eq!(classifier.classify("👨\u{200D}"), None);
+    assert_eq!(classifier.classify("👨\u{200D}a"), None);
+    assert_eq!(classifier.classify("👨\u{200D}☺\u{FE0E}"), None);
+}
+
+#[test]
+fn test_classify_graphemes() {
+    let classifier = classifier();
+
+    let text = "ok ☺👍🏻!🇫🇷";
+    let graphemes: Vec<_> = classifier
+        .classify_graphemes(text)
+        .map(|(range, kind)| (&text[range], kind))
+        .collect();
+    assert_eq!(
+        graphemes,
+        [
+            ("o", None),
+            ("k", None),
+            (" ", None),
+            ("☺", Some(EmojiKind::TextPresentation)),
+            ("👍🏻", Some(EmojiKind::ModifierSequence)),
+            ("!", None),
+            ("🇫🇷", Some(EmojiKind::Flag)),
+        ]
+    );
+    assert_eq!(classifier.classify_graphemes("").count(), 0);
+
+    assert!(classifier.is_single_emoji("🧑‍🚀"));
+    assert!(classifier.is_single_emoji("0\u{FE0F}\u{20E3}"));
+    assert!(!classifier.is_single_emoji("☺"));
+    assert!(!classifier.is_single_emoji("🇫🇷🇫🇷"));
+    assert!(!classifier.is_single_emoji(" 🇫🇷"));
+    assert!(!classifier.is_single_emoji(""));
+}
@@ -0,0 +1,186 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::{
+    GraphemeClusterBreakSegmenter, LineBreakOptions, LineBreakRule, LineBreakSegmenter,
+    SentenceBreakSegmenter, WordBreakRule, WordBreakSegmenter,
+};
+
+const TEXT: &str = "Hello, World! 👨‍👩‍👧 Café.\r\nภาษาไทยภาษาไทย is Thai.\nEnd?\u{2029}  Last line";
+const LATIN1_TEXT: &[u8] =
+    b"Hello, World!  Caf\xe9.\r\nIt is \xabLatin-1\xbb.\nEnd?\x85  Last line";
+const ILL_FORMED_TEXT: &[u8] = b"Hello, \xf0\x9f World!\r\n\xe0\xb8\xa0\xe0\xb8\xb2\x80\xe0\xb8\xa9\xe0\xb8\xb2 End.\xff\nLast \xc3line";
+
+/// Checks `following`, `preceding`, `is_boundary` and `previous` at every offset of a text
+/// of `$len` code units against all its breaks, where the start of the text is a break too.
+macro_rules! check_random_access {
+    ($segment:expr, $len:expr) => {
+        let len: usize = $len;
+        let breaks: Vec<usize> = $segment.collect();
+        let mut all: Vec<usize> = core::iter::once(0).chain(breaks.iter().copied()).collect();
+        all.dedup();
+        for offset in 0..=len + 1 {
+            let mut iter = $segment;
+            let expected = breaks.iter().copied().find(|&i| i > offset);
+            assert_eq!(iter.following(offset), expected, "following({offset})");
+            if let Some(i) = expected {
+                let rest: Vec<usize> = iter.collect();
+                let expected_rest: Vec<usize> = breaks.iter().copied().filter(|&b| b > i).collect();
+                assert_eq!(rest, expected_rest, "after following({offset})");
+            }
+
+            let mut iter = $segment;
+            let expected = all.iter().copied().filter(|&i| i < offset).last();
+            assert_eq!(iter.preceding(offset), expected, "preceding({offset})");
+            if let Some(i) = expected {
+                assert_eq!(
+                    iter.next(),
+                    breaks.iter().copied().find(|&b| b > i),
+                    "after preceding({offset})"
+                );
+            }
+
+            let mut iter = $segment;
+            let expected = all.contains(&offset);
+            assert_eq!(iter.is_boundary(offset), expected, "is_boundary({offset})");
+            if expected {
+                assert_eq!(
+                    iter.next(),
+                    breaks.iter().copied().find(|&b| b > offset),
+                    "after is_boundary({offset})"
+                );
+            }
+        }
+
+        // Iterate backwards from the end with `previous`.
+        let mut iter = $segment;
+        assert_eq!(iter.previous(), None);
+        assert_eq!(iter.following(len), None);
+        let mut backwards: Vec<usize> = core::iter::from_fn(|| iter.previous()).collect();
+        backwards.reverse();
+        assert_eq!(backwards, all[..all.len() - 1]);
+        assert_eq!(iter.next(), breaks.iter().copied().find(|&b| b > 0));
+    };
+}
+
+macro_rules! check_all_encodings {
+    ($segmenter:expr) => {
+        let segmenter = $segmenter;
+        check_random_access!(segmenter.segment_str(TEXT), TEXT.len());
+        check_random_access!(segmenter.segment_utf8(TEXT.as_bytes()), TEXT.len());
+        check_random_access!(
+            segmenter.segment_utf8(ILL_FORMED_TEXT),
+            ILL_FORMED_TEXT.len()
+        );
+        check_random_access!(segmenter.segment_latin1(LATIN1_TEXT), LATIN1_TEXT.len());
+        let utf16: Vec<u16> = TEXT.encode_utf16().collect();
+        check_random_access!(segmenter.segment_utf16(&utf16), utf16.len());
+    };
+}
+
+#[test]
+fn grapheme() {
+    check_all_encodings!(
+        GraphemeClusterBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap()
+    );
+}
+
+#[test]
+fn word() {
+    check_all_encodings!(WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap());
+}
+
+#[test]
+fn sentence() {
+    check_all_encodings!(
+        SentenceBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap()
+    );
+}
+
+#[test]
+fn line() {
+    check_all_encodings!(LineBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap());
+}
+
+#[test]
+fn line_options() {
+    for (line_break_rule, word_break_rule) in [
+        (LineBreakRule::Loose, WordBreakRule::Normal),
+        (LineBreakRule::Normal, WordBreakRule::BreakAll),
+        (LineBreakRule::Strict, WordBreakRule::KeepAll),
+        (LineBreakRule::Anywhere, WordBreakRule::Normal),
+    ] {
+        let mut options = LineBreakOptions::default();
+        options.line_break_rule = line_break_rule;
+        options.word_break_rule = word_break_rule;
+        check_all_encodings!(LineBreakSegmenter::try_new_with_options_unstable(
+            &icu_testdata::unstable(),
+            options
+        )
+        .unwrap());
+    }
+}
+
+#[test]
+fn word_type_after_preceding() {
+    let segmenter = WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap();
+    let mut iter = segmenter.segment_str(TEXT);
+    let types: Vec<(usize, _)> = core::iter::from_fn(|| Some((iter.next()?, iter.word_type())))
+        .skip(1)
+        .collect();
+
+    for &(i, word_type) in &types {
+        let mut iter = segmenter.segment_str(TEXT);
+        assert_eq!(iter.preceding(i + 1), Some(i));
+        assert_eq!(iter.word_type(), word_type, "word_type() at {i}");
+    }
+
+    let mut iter = segmenter.segment_str(TEXT);
+    assert_eq!(iter.following(TEXT.len()), None);
+    for &(i, word_type) in types.iter().rev().skip(1) {
+        assert_eq!(iter.previous(), Some(i));
+        assert_eq!(iter.word_type(), word_type, "word_type() at {i}");
+    }
+}
+
+/// Checks that iterating backwards from the end of `$text` with `previous` returns all its
+/// breaks, where the start of the text is a break too.
+macro_rules! check_previous {
+    ($segmenter:expr, $text:expr) => {
+        let segmenter = $segmenter;
+        let text: &str = $text;
+        let mut breaks: Vec<usize> = core::iter::once(0)
+            .chain(segmenter.segment_str(text))
+            .collect();
+        breaks.dedup();
+        let mut iter = segmenter.segment_str(text);
+        assert_eq!(iter.following(text.len()), None);
+        let mut backwards: Vec<usize> = core::iter::from_fn(|| iter.previous()).collect();
+        backwards.reverse();
+        assert_eq!(backwards, breaks[..breaks.len() - 1]);
+    };
+}
+
+#[test]
+fn previous_in_long_runs_of_letters() {
+    // No offset within a run of letters is a safe point, so `previous` looks at every
+    // character of the run.
+    let text = format!("{} {}.", "a".repeat(20_000), "b".repeat(20_000));
+    check_previous!(
+        GraphemeClusterBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap(),
+        &text
+    );
+    check_previous!(
+        WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap(),
+        &text
+    );
+    check_previous!(
+        SentenceBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap(),
+        &text
+    );
+    check_previous!(
+        LineBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).unwrap(),
+        &text
+    );
+}
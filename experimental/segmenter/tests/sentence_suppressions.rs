@@ -0,0 +1,78 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid::locale;
+use icu_segmenter::{SentenceBreakOptions, SentenceBreakSegmenter};
+
+fn segmenter_with_suppressions(locale: &icu_provider::DataLocale) -> SentenceBreakSegmenter {
+    let mut options = SentenceBreakOptions::default();
+    options.suppressions = true;
+    SentenceBreakSegmenter::try_new_with_options_unstable(
+        &icu_testdata::unstable(),
+        locale,
+        options,
+    )
+    .expect("Data exists")
+}
+
+#[test]
+fn suppressions_en() {
+    let segmenter = segmenter_with_suppressions(&locale!("en").into());
+    let root =
+        SentenceBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).expect("Data exists");
+
+    let s = "Mr. Smith met Dr. Jones. They talked, e.g. About the plan. Then left.";
+    let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+    assert_eq!(breaks, [0, 25, 59, s.len()]);
+    let breaks: Vec<usize> = root.segment_str(s).collect();
+    assert_eq!(breaks, [0, 4, 18, 25, 43, 59, s.len()]);
+
+    // Same with other encodings.
+    let breaks: Vec<usize> = segmenter.segment_latin1(s.as_bytes()).collect();
+    assert_eq!(breaks, [0, 25, 59, s.len()]);
+    let utf16: Vec<u16> = s.encode_utf16().collect();
+    let breaks: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+    assert_eq!(breaks, [0, 25, 59, s.len()]);
+}
+
+#[test]
+fn suppressions_word_boundaries() {
+    let segmenter = segmenter_with_suppressions(&locale!("en").into());
+
+    // Abbreviations only match whole words, but may follow punctuation.
+    let s = "It was Hmr. Then (Mr. Smith) came.";
+    let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+    assert_eq!(breaks, [0, 12, s.len()]);
+
+    // Paragraph separators always break.
+    let s = "Ask Mr. \nSmith.";
+    let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+    assert_eq!(breaks, [0, 9, s.len()]);
+
+    // Abbreviations are case-sensitive.
+    let s = "I said mr. Smith.";
+    let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+    assert_eq!(breaks, [0, 11, s.len()]);
+}
+
+#[test]
+fn suppressions_locale() {
+    // "Sra." is only an abbreviation in Spanish.
+    let s = "Hola Sra. García.";
+    let breaks: Vec<usize> = segmenter_with_suppressions(&locale!("es-AR").into())
+        .segment_str(s)
+        .collect();
+    assert_eq!(breaks, [0, s.len()]);
+    let breaks: Vec<usize> = segmenter_with_suppressions(&locale!("en").into())
+        .segment_str(s)
+        .collect();
+    assert_eq!(breaks, [0, 10, s.len()]);
+
+    // Locales without suppressions fall back to the root locale, which has none.
+    let s = "Mr. Smith.";
+    let breaks: Vec<usize> = segmenter_with_suppressions(&locale!("th").into())
+        .segment_str(s)
+        .collect();
+    assert_eq!(breaks, [0, 4, s.len()]);
+}
@@ -103,6 +103,43 @@ impl Iterator for TestContentIterator {
     }
 }
 
+/// Checks `following`, `preceding` and `previous` at every character boundary of a test case
+/// against its breaks, where the start of the text is a break too. These start segmenting
+/// from a safe point before the offset, so this checks that no rule looks before one.
+macro_rules! check_random_access {
+    ($segmenter:expr, $s:expr, $breaks:expr, $line:expr) => {
+        let s: &str = $s;
+        let mut all: Vec<usize> = core::iter::once(0).chain($breaks.iter().copied()).collect();
+        all.dedup();
+        for offset in (0..=s.len()).filter(|&i| s.is_char_boundary(i)) {
+            let mut iter = $segmenter.segment_str(s);
+            let expected = all.iter().copied().find(|&b| b > offset);
+            assert_eq!(
+                iter.following(offset),
+                expected,
+                "following({}): {}",
+                offset,
+                $line
+            );
+
+            let mut iter = $segmenter.segment_str(s);
+            let expected = all.iter().copied().filter(|&b| b < offset).last();
+            assert_eq!(
+                iter.preceding(offset),
+                expected,
+                "preceding({}): {}",
+                offset,
+                $line
+            );
+        }
+        let mut iter = $segmenter.segment_str(s);
+        iter.following(s.len());
+        let mut backwards: Vec<usize> = core::iter::from_fn(|| iter.previous()).collect();
+        backwards.reverse();
+        assert_eq!(backwards, all[..all.len() - 1], "previous(): {}", $line);
+    };
+}
+
 #[test]
 fn run_line_break_test() {
     let test_iter = TestContentIterator::new("./tests/testdata/LineBreakTest.txt");
@@ -113,6 +150,7 @@ fn run_line_break_test() {
         let iter = segmenter.segment_str(&s);
         let result: Vec<usize> = iter.collect();
         assert_eq!(result, test.break_result_utf8, "{}", test.original_line);
+        check_random_access!(segmenter, &s, result, test.original_line);
 
         let iter = segmenter.segment_utf16(&test.utf16_vec);
         let result: Vec<usize> = iter.collect();
@@ -145,6 +183,7 @@ fn run_word_break_test() {
         let iter = segmenter.segment_str(&s);
         let result: Vec<usize> = iter.collect();
         assert_eq!(result, test.break_result_utf8, "{}", test.original_line);
+        check_random_access!(segmenter, &s, result, test.original_line);
 
         let iter = segmenter.segment_utf16(&test.utf16_vec);
         let result: Vec<usize> = iter.collect();
@@ -177,6 +216,7 @@ fn run_grapheme_break_test() {
         let iter = segmenter.segment_str(&s);
         let result: Vec<usize> = iter.collect();
         assert_eq!(result, test.break_result_utf8, "{}", test.original_line);
+        check_random_access!(segmenter, &s, result, test.original_line);
 
         let iter = segmenter.segment_utf16(&test.utf16_vec);
         let result: Vec<usize> = iter.collect();
@@ -209,6 +249,7 @@ fn run_sentence_break_test() {
         let iter = segmenter.segment_str(&s);
         let result: Vec<usize> = iter.collect();
         assert_eq!(result, test.break_result_utf8, "{}", test.original_line);
+        check_random_access!(segmenter, &s, result, test.original_line);
 
         let iter = segmenter.segment_utf16(&test.utf16_vec);
         let result: Vec<usize> = iter.collect();
@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid::locale;
+use icu_provider::DataLocale;
+use icu_segmenter::{LineBreakOptions, LineBreakSegmenter, WordBreakSegmenter};
+
+fn line_breaks(locale: &DataLocale, s: &str) -> Vec<usize> {
+    LineBreakSegmenter::try_new_with_locale_unstable(
+        &icu_testdata::unstable(),
+        locale,
+        LineBreakOptions::default(),
+    )
+    .expect("Data exists")
+    .segment_str(s)
+    .collect()
+}
+
+#[test]
+fn line_break_keywords() {
+    // lb=loose allows breaks before small kana in Japanese.
+    let s = "サ\u{3041}サ";
+    assert_eq!(line_breaks(&locale!("ja").into(), s), [6, 9]);
+    assert_eq!(line_breaks(&locale!("ja-u-lb-loose").into(), s), [3, 6, 9]);
+    assert_eq!(line_breaks(&locale!("ja-u-lb-strict").into(), s), [6, 9]);
+
+    // lb=normal allows breaks before U+301C WAVE DASH only in Chinese and Japanese.
+    let s = "サ\u{301C}サ";
+    assert_eq!(line_breaks(&locale!("ja-u-lb-normal").into(), s), [3, 6, 9]);
+    assert_eq!(line_breaks(&locale!("zh-u-lb-normal").into(), s), [3, 6, 9]);
+    assert_eq!(line_breaks(&locale!("en-u-lb-normal").into(), s), [6, 9]);
+
+    // lw=breakall allows breaks between letters, and unknown values are ignored.
+    let s = "Hello World";
+    assert_eq!(
+        line_breaks(&locale!("en-u-lw-breakall").into(), s),
+        [1, 2, 3, 4, 6, 7, 8, 9, 10, 11]
+    );
+    assert_eq!(line_breaks(&locale!("en-u-lw-phrase").into(), s), [6, 11]);
+}
+
+#[test]
+fn word_break_colon() {
+    // Colons are MidLetter in UAX #29. CLDR root tailors them not to be, but not
+    // for Finnish and Swedish; the root data here is not tailored.
+    for locale in [locale!("en"), locale!("sv")] {
+        let segmenter = WordBreakSegmenter::try_new_with_locale_unstable(
+            &icu_testdata::unstable(),
+            &locale.into(),
+        )
+        .expect("Data exists");
+        let breaks: Vec<usize> = segmenter.segment_str("S:t Eriksgatan").collect();
+        assert_eq!(breaks, [0, 3, 4, 14]);
+    }
+}
@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_segmenter::{GraphemeClusterBreakSegmenter, WordBreakSegmenter, WordType};
+
+fn segments_with_types<I: Iterator<Item = usize>>(
+    mut iter: I,
+    word_type: impl Fn(&I) -> WordType,
+) -> Vec<(usize, WordType)> {
+    let mut result = Vec::new();
+    while let Some(i) = iter.next() {
+        result.push((i, word_type(&iter)));
+    }
+    result
+}
+
+#[test]
+fn word_type_latin() {
+    let segmenter =
+        WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).expect("Data exists");
+
+    let s = "Don't stop, 3.14 x_1 a\u{301}!";
+    let expected = vec![
+        (0, WordType::None),
+        (5, WordType::Letter),
+        (6, WordType::None),
+        (10, WordType::Letter),
+        (11, WordType::None),
+        (12, WordType::None),
+        (16, WordType::Number),
+        (17, WordType::None),
+        (20, WordType::Number),
+        (21, WordType::None),
+        (24, WordType::Letter),
+        (25, WordType::None),
+    ];
+    assert_eq!(
+        segments_with_types(segmenter.segment_str(s), |iter| iter.word_type()),
+        expected
+    );
+    assert_eq!(
+        segments_with_types(segmenter.segment_latin1(b"ab 12"), |iter| iter.word_type()),
+        vec![
+            (0, WordType::None),
+            (2, WordType::Letter),
+            (3, WordType::None),
+            (5, WordType::Number),
+        ]
+    );
+
+    let utf16: Vec<u16> = "Hello 123".encode_utf16().collect();
+    let mut iter = segmenter.segment_utf16(&utf16);
+    assert!(!iter.is_word_like());
+    assert_eq!(iter.next(), Some(0));
+    assert!(!iter.is_word_like());
+    assert_eq!(iter.next(), Some(5));
+    assert!(iter.is_word_like());
+    assert_eq!(iter.next(), Some(6));
+    assert!(!iter.is_word_like());
+    assert_eq!(iter.next(), Some(9));
+    assert_eq!(iter.word_type(), WordType::Number);
+}
+
+#[test]
+fn word_type_katakana() {
+    let segmenter =
+        WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).expect("Data exists");
+
+    // Like in ICU4C, runs of Katakana are ideographic words.
+    assert_eq!(
+        segments_with_types(segmenter.segment_str("カタカナ 12"), |iter| iter
+            .word_type()),
+        vec![
+            (0, WordType::None),
+            (12, WordType::Ideo),
+            (13, WordType::None),
+            (15, WordType::Number),
+        ]
+    );
+}
+
+#[test]
+fn word_type_complex() {
+    let segmenter =
+        WordBreakSegmenter::try_new_unstable(&icu_testdata::unstable()).expect("Data exists");
+
+    // Words found by the dictionary or LSTM segmenters are letters.
+    let s = "aภาษาไทยภาษาไทย. ";
+    let segments = segments_with_types(segmenter.segment_str(s), |iter| iter.word_type());
+    assert_eq!(segments.first(), Some(&(0, WordType::None)));
+    assert_eq!(segments[1], (1, WordType::Letter));
+    assert!(segments.len() > 4);
+    for &(i, word_type) in &segments[2..segments.len() - 2] {
+        assert_eq!(word_type, WordType::Letter, "segment ending at {}", i);
+    }
+    assert_eq!(segments[segments.len() - 2], (s.len() - 1, WordType::None));
+    assert_eq!(segments[segments.len() - 1], (s.len(), WordType::None));
+
+    let utf16: Vec<u16> = s.encode_utf16().collect();
+    let segments_utf16 =
+        segments_with_types(segmenter.segment_utf16(&utf16), |iter| iter.word_type());
+    assert_eq!(
+        segments
+            .iter()
+            .map(|&(_, word_type)| word_type)
+            .collect::<Vec<_>>(),
+        segments_utf16
+            .iter()
+            .map(|&(_, word_type)| word_type)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn word_type_other_segmenters() {
+    let segmenter = GraphemeClusterBreakSegmenter::try_new_unstable(&icu_testdata::unstable())
+        .expect("Data exists");
+    let mut iter = segmenter.segment_str("ab");
+    while iter.next().is_some() {
+        assert_eq!(iter.word_type(), WordType::None);
+    }
+}
@@ -0,0 +1,348 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::SpoofError;
+use crate::identifier::{IdentifierStatus, IdentifierTypes};
+use crate::provider::*;
+use crate::script_set::ScriptSet;
+use alloc::string::String;
+use icu_normalizer::provider::{
+    CanonicalDecompositionDataV1Marker, CanonicalDecompositionTablesV1Marker,
+};
+use icu_normalizer::DecomposingNormalizer;
+use icu_properties::provider::ScriptWithExtensionsPropertyV1Marker;
+use icu_properties::script::ScriptWithExtensions;
+use icu_properties::Script;
+use icu_provider::prelude::*;
+
+/// The kinds of confusability of two confusable strings, as defined in
+/// [UTS #39, section 4](https://www.unicode.org/reports/tr39/#Confusable_Detection).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ConfusableType {
+    /// The resolved script sets of the strings have a script in common, like "l" and "1".
+    SingleScript,
+    /// The resolved script sets of the strings have no script in common, and one of them is
+    /// empty, like "pаypal" (with a Cyrillic "а") and "paypal".
+    MixedScript,
+    /// The resolved script sets of the strings have no script in common, and neither is
+    /// empty, like "ѕсоре" (in Cyrillic) and "scope".
+    WholeScript,
+}
+
+/// The restriction levels of UTS #39, from the most to the least restrictive, as defined in
+/// [UTS #39, section 5.2](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[non_exhaustive]
+pub enum RestrictionLevel {
+    /// All the characters are in the ASCII range.
+    Ascii,
+    /// All the characters are from a single script, as given by their augmented script sets.
+    SingleScript,
+    /// All the characters are from Latin and one of the combinations Han + Hiragana +
+    /// Katakana, Han + Bopomofo or Han + Hangul.
+    HighlyRestrictive,
+    /// All the characters are from Latin and a single other script, other than Cyrillic and
+    /// Greek.
+    ModeratelyRestrictive,
+    /// All the characters are allowed in identifiers, from any scripts.
+    MinimallyRestrictive,
+    /// Some characters are not allowed in identifiers.
+    Unrestricted,
+}
+
+/// Detects strings that could be mistaken for one another, and identifiers that mix
+/// scripts in suspicious ways, following [UTS #39](https://www.unicode.org/reports/tr39/).
+///
+/// # Examples
+///
+/// ```
+/// use icu_spoof::{ConfusableType, RestrictionLevel, SpoofChecker};
+///
+/// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data exists");
+///
+/// // "pаypal" has a Cyrillic "а"
+/// assert!(checker.are_confusable("pаypal", "paypal"));
+/// assert_eq!(
+///     checker.confusable_type("pаypal", "paypal"),
+///     Some(ConfusableType::MixedScript)
+/// );
+/// assert!(checker.is_mixed_script("pаypal"));
+/// assert_eq!(
+///     checker.restriction_level("pаypal"),
+///     RestrictionLevel::MinimallyRestrictive
+/// );
+///
+/// assert!(!checker.are_confusable("paypal", "ebay"));
+/// ```
+pub struct SpoofChecker {
+    nfd: DecomposingNormalizer,
+    script_with_extensions: ScriptWithExtensions,
+    confusables: DataPayload<ConfusablesV1Marker>,
+    identifier_status: DataPayload<IdentifierStatusV1Marker>,
+    identifier_type: DataPayload<IdentifierTypeV1Marker>,
+}
+
+impl SpoofChecker {
+    /// Creates a [`SpoofChecker`] from the confusable, identifier and script data.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, SpoofError>
+    where
+        D: DataProvider<ConfusablesV1Marker>
+            + DataProvider<IdentifierStatusV1Marker>
+            + DataProvider<IdentifierTypeV1Marker>
+            + DataProvider<ScriptWithExtensionsPropertyV1Marker>
+            + DataProvider<CanonicalDecompositionDataV1Marker>
+            + DataProvider<CanonicalDecompositionTablesV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+            script_with_extensions: ScriptWithExtensions::from_data(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            confusables: provider.load(Default::default())?.take_payload()?,
+            identifier_status: provider.load(Default::default())?.take_payload()?,
+            identifier_type: provider.load(Default::default())?.take_payload()?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: SpoofError);
+
+    /// Returns the skeleton of a string: two strings are confusable if and only if their
+    /// skeletons are equal.
+    ///
+    /// The skeleton is the NFD of the string with each character of its NFD replaced by its
+    /// prototype from the confusable mappings. It is meant for comparisons, not for display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::SpoofChecker;
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(checker.skeleton("paypal"), checker.skeleton("pаypal"));
+    /// assert_eq!(checker.skeleton("1ℓ"), "ll");
+    /// assert_eq!(checker.skeleton("m"), "rn");
+    /// ```
+    pub fn skeleton(&self, text: &str) -> String {
+        let prototypes = &self.confusables.get().prototypes;
+        let mut mapped = String::with_capacity(text.len());
+        for c in self.nfd.normalize(text).chars() {
+            match prototypes.get(&c) {
+                Some(prototype) => mapped.push_str(prototype),
+                None => mapped.push(c),
+            }
+        }
+        self.nfd.normalize(&mapped)
+    }
+
+    /// Whether two strings are confusable, that is, whether they have the same skeleton.
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.skeleton(a) == self.skeleton(b)
+    }
+
+    /// Returns how two strings are confusable, or `None` if they are not confusable.
+    ///
+    /// Whole-script confusables are mixed-script confusables too; for them this returns
+    /// [`ConfusableType::WholeScript`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::{ConfusableType, SpoofChecker};
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(
+    ///     checker.confusable_type("l", "1"),
+    ///     Some(ConfusableType::SingleScript)
+    /// );
+    /// assert_eq!(
+    ///     checker.confusable_type("ѕсоре", "scope"),
+    ///     Some(ConfusableType::WholeScript)
+    /// );
+    /// assert_eq!(checker.confusable_type("scope", "skope"), None);
+    /// ```
+    pub fn confusable_type(&self, a: &str, b: &str) -> Option<ConfusableType> {
+        if !self.are_confusable(a, b) {
+            return None;
+        }
+        let a = self.resolved_script_set(a);
+        let b = self.resolved_script_set(b);
+        Some(if a.intersects(&b) {
+            ConfusableType::SingleScript
+        } else if a.is_empty() || b.is_empty() {
+            ConfusableType::MixedScript
+        } else {
+            ConfusableType::WholeScript
+        })
+    }
+
+    /// Whether a string mixes scripts, that is, whether no script is in the augmented script
+    /// sets of all its characters.
+    ///
+    /// Characters like digits and punctuation, which are used with every script, do not
+    /// make a string mixed-script.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::SpoofChecker;
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert!(checker.is_mixed_script("pаypal"));
+    /// assert!(!checker.is_mixed_script("paypal.com"));
+    /// // Han, Hiragana and Katakana are all used for Japanese
+    /// assert!(!checker.is_mixed_script("東京タワーへようこそ"));
+    /// ```
+    pub fn is_mixed_script(&self, text: &str) -> bool {
+        self.resolved_script_set(text).is_empty()
+    }
+
+    /// Returns the most restrictive [`RestrictionLevel`] that a string satisfies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::{RestrictionLevel, SpoofChecker};
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(checker.restriction_level("paypal"), RestrictionLevel::Ascii);
+    /// assert_eq!(
+    ///     checker.restriction_level("ελλάδα"),
+    ///     RestrictionLevel::SingleScript
+    /// );
+    /// assert_eq!(
+    ///     checker.restriction_level("icu東京"),
+    ///     RestrictionLevel::HighlyRestrictive
+    /// );
+    /// assert_eq!(
+    ///     checker.restriction_level("iphoneসেট"),
+    ///     RestrictionLevel::ModeratelyRestrictive
+    /// );
+    /// assert_eq!(
+    ///     checker.restriction_level("pаypal"),
+    ///     RestrictionLevel::MinimallyRestrictive
+    /// );
+    /// assert_eq!(
+    ///     checker.restriction_level("paypal♥"),
+    ///     RestrictionLevel::Unrestricted
+    /// );
+    /// ```
+    pub fn restriction_level(&self, text: &str) -> RestrictionLevel {
+        if !text
+            .chars()
+            .all(|c| self.identifier_status(c) == IdentifierStatus::Allowed)
+        {
+            return RestrictionLevel::Unrestricted;
+        }
+        if text.is_ascii() {
+            return RestrictionLevel::Ascii;
+        }
+        if !self.resolved_script_set(text).is_empty() {
+            return RestrictionLevel::SingleScript;
+        }
+
+        // The resolved script set of the characters that cannot be Latin.
+        let script_with_extensions = self.script_with_extensions.as_borrowed();
+        let mut non_latin = ScriptSet::ALL;
+        for c in text.chars() {
+            let augmented = ScriptSet::augmented(script_with_extensions, c);
+            if !augmented.contains(Script::Latin) {
+                non_latin.intersect_with(&augmented);
+            }
+        }
+        if non_latin.contains_han_combination() {
+            RestrictionLevel::HighlyRestrictive
+        } else if !non_latin.is_empty()
+            && !non_latin.contains(Script::Cyrillic)
+            && !non_latin.contains(Script::Greek)
+        {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+
+    /// Returns the Identifier_Status of a character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::{IdentifierStatus, SpoofChecker};
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(checker.identifier_status('a'), IdentifierStatus::Allowed);
+    /// assert_eq!(checker.identifier_status('♥'), IdentifierStatus::Restricted);
+    /// ```
+    pub fn identifier_status(&self, c: char) -> IdentifierStatus {
+        if self.identifier_status.get().allowed.contains(c) {
+            IdentifierStatus::Allowed
+        } else {
+            IdentifierStatus::Restricted
+        }
+    }
+
+    /// Returns the Identifier_Type values of a character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_spoof::{IdentifierType, SpoofChecker};
+    ///
+    /// let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data exists");
+    ///
+    /// assert_eq!(
+    ///     checker.identifier_types('a').iter().collect::<Vec<_>>(),
+    ///     [IdentifierType::Recommended]
+    /// );
+    /// // U+018D LATIN SMALL LETTER TURNED DELTA
+    /// assert_eq!(
+    ///     checker.identifier_types('ƍ').iter().collect::<Vec<_>>(),
+    ///     [IdentifierType::Obsolete, IdentifierType::Technical]
+    /// );
+    /// ```
+    pub fn identifier_types(&self, c: char) -> IdentifierTypes {
+        IdentifierTypes(self.identifier_type.get().trie.get(c))
+    }
+
+    /// The resolved script set of a string: the intersection of the augmented script sets of
+    /// its characters.
+    fn resolved_script_set(&self, text: &str) -> ScriptSet {
+        let script_with_extensions = self.script_with_extensions.as_borrowed();
+        let mut resolved = ScriptSet::ALL;
+        for c in text.chars() {
+            resolved.intersect_with(&ScriptSet::augmented(script_with_extensions, c));
+        }
+        resolved
+    }
+}
@@ -0,0 +1,36 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_normalizer::NormalizerError;
+use icu_provider::prelude::DataError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpoofError {}
+
+/// A list of error outcomes for various operations in the `icu_spoof` crate.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug)]
+#[non_exhaustive]
+pub enum SpoofError {
+    /// An error originating inside of the [data provider](icu_provider).
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// An error loading the normalization data.
+    #[displaydoc("{0}")]
+    Normalizer(NormalizerError),
+}
+
+impl From<DataError> for SpoofError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+impl From<NormalizerError> for SpoofError {
+    fn from(e: NormalizerError) -> Self {
+        Self::Normalizer(e)
+    }
+}
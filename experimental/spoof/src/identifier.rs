@@ -0,0 +1,73 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+/// The Identifier_Status property of UTS #39, which tells whether a character is in the
+/// General Security Profile for identifiers.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IdentifierStatus {
+    /// The character is allowed in identifiers.
+    Allowed,
+    /// The character is not allowed in identifiers.
+    Restricted,
+}
+
+/// A value of the Identifier_Type property of UTS #39, which tells why a character is
+/// [`Restricted`](IdentifierStatus::Restricted), or that it is recommended.
+///
+/// A character can have several Identifier_Type values; see [`IdentifierTypes`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct IdentifierType(pub u8);
+
+#[allow(missing_docs)] // These constants don't need individual documentation.
+#[allow(non_upper_case_globals)]
+impl IdentifierType {
+    pub const NotCharacter: IdentifierType = IdentifierType(0);
+    pub const Deprecated: IdentifierType = IdentifierType(1);
+    pub const DefaultIgnorable: IdentifierType = IdentifierType(2);
+    pub const NotNfkc: IdentifierType = IdentifierType(3);
+    pub const NotXid: IdentifierType = IdentifierType(4);
+    pub const Exclusion: IdentifierType = IdentifierType(5);
+    pub const Obsolete: IdentifierType = IdentifierType(6);
+    pub const Technical: IdentifierType = IdentifierType(7);
+    pub const UncommonUse: IdentifierType = IdentifierType(8);
+    pub const LimitedUse: IdentifierType = IdentifierType(9);
+    pub const Inclusion: IdentifierType = IdentifierType(10);
+    pub const Recommended: IdentifierType = IdentifierType(11);
+}
+
+/// The set of [`IdentifierType`] values of a character.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IdentifierTypes(pub(crate) u16);
+
+impl IdentifierTypes {
+    /// Whether the set contains the given [`IdentifierType`].
+    pub fn contains(self, identifier_type: IdentifierType) -> bool {
+        identifier_type.0 < 16 && self.0 & (1 << identifier_type.0) != 0
+    }
+
+    /// Returns the [`IdentifierType`] values of the set, in increasing order.
+    pub fn iter(self) -> impl Iterator<Item = IdentifierType> {
+        (0..16)
+            .map(IdentifierType)
+            .filter(move |&identifier_type| self.contains(identifier_type))
+    }
+}
@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Unicode security mechanisms: the detection of confusable strings and
+//! the restriction levels of identifiers, as defined in [UTS #39].
+//!
+//! This module is published as its own crate ([`icu_spoof`](https://docs.rs/icu_spoof/latest/icu_spoof/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! The [`SpoofChecker`] computes the skeletons of strings, which tell whether two strings
+//! can be mistaken for one another, and classifies identifiers by the scripts they mix
+//! and by the Identifier_Status and Identifier_Type of their characters.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_spoof::{RestrictionLevel, SpoofChecker};
+//!
+//! let checker = SpoofChecker::try_new_unstable(&icu_testdata::unstable())
+//!     .expect("Data exists");
+//!
+//! let registered = ["paypal", "ebay"];
+//!
+//! // "pаypal" has a Cyrillic "а"
+//! let username = "pаypal";
+//! assert!(registered
+//!     .iter()
+//!     .any(|name| checker.are_confusable(name, username)));
+//! assert!(checker.restriction_level(username) > RestrictionLevel::HighlyRestrictive);
+//! ```
+//!
+//! [`ICU4X`]: ../icu/index.html
+//! [UTS #39]: https://www.unicode.org/reports/tr39/
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod checker;
+mod error;
+mod identifier;
+pub mod provider;
+mod script_set;
+
+pub use checker::{ConfusableType, RestrictionLevel, SpoofChecker};
+pub use error::SpoofError;
+pub use identifier::{IdentifierStatus, IdentifierType, IdentifierTypes};
+
+#[doc(inline)]
+pub use SpoofError as Error;
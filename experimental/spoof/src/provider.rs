@@ -0,0 +1,64 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_collections::codepointinvlist::CodePointInversionList;
+use icu_collections::codepointtrie::CodePointTrie;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap;
+
+/// The confusable mappings of UTS #39, from `confusables.txt`.
+#[icu_provider::data_struct(ConfusablesV1Marker = "spoof/confusables@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_spoof::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct ConfusablesV1<'data> {
+    /// The prototype of each character that is not its own prototype.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub prototypes: ZeroMap<'data, char, str>,
+}
+
+/// The characters whose Identifier_Status is Allowed, from `IdentifierStatus.txt`.
+#[icu_provider::data_struct(IdentifierStatusV1Marker = "spoof/idstatus@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_spoof::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct IdentifierStatusV1<'data> {
+    /// The characters that are Allowed. All other characters are Restricted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub allowed: CodePointInversionList<'data>,
+}
+
+/// The Identifier_Type of each character, from `IdentifierType.txt`.
+#[icu_provider::data_struct(IdentifierTypeV1Marker = "spoof/idtype@1")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_spoof::provider),
+)]
+#[yoke(prove_covariance_manually)]
+pub struct IdentifierTypeV1<'data> {
+    /// The set of Identifier_Type values of each character, with bit `n` set for the
+    /// [`IdentifierType`](crate::IdentifierType) with value `n`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub trie: CodePointTrie<'data, u16>,
+}
@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_properties::script::ScriptWithExtensionsBorrowed;
+use icu_properties::Script;
+
+// The scripts that only appear in augmented script sets, which have no constants in
+// `icu_properties` because no character has them as its Script value.
+const HAN_WITH_BOPOMOFO: Script = Script(172);
+const JAPANESE: Script = Script(105);
+const KOREAN: Script = Script(119);
+
+/// The number of 64-bit words of a [`ScriptSet`]; all `Script` values are below 256.
+const WORDS: usize = 4;
+
+/// A set of scripts, used for the augmented and resolved script sets of UTS #39.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ScriptSet([u64; WORDS]);
+
+impl ScriptSet {
+    /// The set of all scripts, which is the resolved script set of the empty string.
+    pub(crate) const ALL: ScriptSet = ScriptSet([u64::MAX; WORDS]);
+
+    pub(crate) const EMPTY: ScriptSet = ScriptSet([0; WORDS]);
+
+    fn insert(&mut self, script: Script) {
+        let index = script.0 as usize;
+        if let Some(word) = self.0.get_mut(index / 64) {
+            *word |= 1 << (index % 64);
+        }
+    }
+
+    pub(crate) fn contains(&self, script: Script) -> bool {
+        let index = script.0 as usize;
+        matches!(self.0.get(index / 64), Some(word) if word & (1 << (index % 64)) != 0)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    pub(crate) fn intersects(&self, other: &ScriptSet) -> bool {
+        self.0.iter().zip(other.0.iter()).any(|(a, b)| a & b != 0)
+    }
+
+    pub(crate) fn intersect_with(&mut self, other: &ScriptSet) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a &= b;
+        }
+    }
+
+    /// The augmented script set of a character: its Script_Extensions, with Han adding
+    /// Hanb, Jpan and Kore, Hiragana and Katakana adding Jpan, Hangul adding Kore and
+    /// Bopomofo adding Hanb. The characters whose Script_Extensions are {Common} or
+    /// {Inherited} can be used with any script, so their set is [`ScriptSet::ALL`].
+    pub(crate) fn augmented(
+        script_with_extensions: ScriptWithExtensionsBorrowed,
+        c: char,
+    ) -> ScriptSet {
+        let mut set = ScriptSet::EMPTY;
+        for script in script_with_extensions
+            .get_script_extensions_val(c as u32)
+            .iter()
+        {
+            match script {
+                Script::Common | Script::Inherited => return ScriptSet::ALL,
+                Script::Han => {
+                    set.insert(HAN_WITH_BOPOMOFO);
+                    set.insert(JAPANESE);
+                    set.insert(KOREAN);
+                }
+                Script::Hiragana | Script::Katakana => set.insert(JAPANESE),
+                Script::Hangul => set.insert(KOREAN),
+                Script::Bopomofo => set.insert(HAN_WITH_BOPOMOFO),
+                _ => (),
+            }
+            set.insert(script);
+        }
+        set
+    }
+
+    /// Whether the set contains one of the scripts that combine Han with another script.
+    pub(crate) fn contains_han_combination(&self) -> bool {
+        self.contains(HAN_WITH_BOPOMOFO) || self.contains(JAPANESE) || self.contains(KOREAN)
+    }
+}
@@ -0,0 +1,142 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_spoof::{ConfusableType, IdentifierStatus, IdentifierType, RestrictionLevel, SpoofChecker};
+
+fn checker() -> SpoofChecker {
+    SpoofChecker::try_new_unstable(&icu_testdata::unstable()).expect("Data exists")
+}
+
+#[test]
+fn test_skeleton() {
+    let checker = checker();
+
+    assert_eq!(checker.skeleton(""), "");
+    assert_eq!(checker.skeleton("abc"), "abc");
+    // The skeleton is in NFD, whatever the normalization of the input.
+    assert_eq!(checker.skeleton("\u{E9}"), "e\u{301}");
+    assert_eq!(checker.skeleton("e\u{301}"), "e\u{301}");
+    // U+0450 CYRILLIC SMALL LETTER IE WITH GRAVE decomposes to U+0435 CYRILLIC SMALL LETTER IE
+    // and U+0300 COMBINING GRAVE ACCENT.
+    assert_eq!(checker.skeleton("\u{450}"), "e\u{300}");
+    assert!(checker.are_confusable("caf\u{E9}", "caf\u{435}\u{301}"));
+    // The prototypes can be longer than their source.
+    assert!(checker.are_confusable("modern", "rnodern"));
+}
+
+#[test]
+fn test_confusable_type() {
+    let checker = checker();
+
+    assert_eq!(
+        checker.confusable_type("paypal", "paypal"),
+        Some(ConfusableType::SingleScript)
+    );
+    assert_eq!(
+        checker.confusable_type("paypa1", "paypal"),
+        Some(ConfusableType::SingleScript)
+    );
+    // U+0440 CYRILLIC SMALL LETTER ER and U+0430 CYRILLIC SMALL LETTER A
+    assert_eq!(
+        checker.confusable_type("раураl", "paypal"),
+        Some(ConfusableType::MixedScript)
+    );
+    assert_eq!(
+        checker.confusable_type("сосо", "coco"),
+        Some(ConfusableType::WholeScript)
+    );
+    // U+03BF GREEK SMALL LETTER OMICRON and U+043E CYRILLIC SMALL LETTER O
+    assert_eq!(
+        checker.confusable_type("ο", "о"),
+        Some(ConfusableType::WholeScript)
+    );
+    assert_eq!(checker.confusable_type("paypal", "paypals"), None);
+}
+
+#[test]
+fn test_mixed_script() {
+    let checker = checker();
+
+    assert!(!checker.is_mixed_script(""));
+    assert!(!checker.is_mixed_script("123-456"));
+    assert!(!checker.is_mixed_script("Ελλάδα 2004"));
+    // U+30FC KATAKANA-HIRAGANA PROLONGED SOUND MARK has the Script_Extensions Hiragana and
+    // Katakana.
+    assert!(!checker.is_mixed_script("ラーメン"));
+    assert!(!checker.is_mixed_script("한국어 漢字"));
+    assert!(checker.is_mixed_script("한국어 ひらがな"));
+    assert!(checker.is_mixed_script("abcδ"));
+}
+
+#[test]
+fn test_restriction_level() {
+    let checker = checker();
+
+    assert_eq!(checker.restriction_level(""), RestrictionLevel::Ascii);
+    assert_eq!(
+        checker.restriction_level("user_name-1"),
+        RestrictionLevel::Ascii
+    );
+    assert_eq!(
+        checker.restriction_level("ユーザー"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        checker.restriction_level("café"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        checker.restriction_level("abcカタカナ漢字"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("abc한국어漢字"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("abcㄅㄆ漢字"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("abcعربي"),
+        RestrictionLevel::ModeratelyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("abcδ"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("abcعربيδ"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(
+        checker.restriction_level("user name"),
+        RestrictionLevel::Unrestricted
+    );
+}
+
+#[test]
+fn test_identifier_data() {
+    let checker = checker();
+
+    assert_eq!(checker.identifier_status('_'), IdentifierStatus::Allowed);
+    assert_eq!(checker.identifier_status('ж'), IdentifierStatus::Allowed);
+    assert_eq!(
+        checker.identifier_status('\u{200B}'),
+        IdentifierStatus::Restricted
+    );
+    assert_eq!(
+        checker.identifier_status('\u{E000}'),
+        IdentifierStatus::Restricted
+    );
+
+    let types = checker.identifier_types('\u{E000}');
+    assert!(types.contains(IdentifierType::NotCharacter));
+    assert!(!types.contains(IdentifierType::Recommended));
+    // U+FB01 LATIN SMALL LIGATURE FI
+    assert_eq!(
+        checker.identifier_types('ﬁ').iter().collect::<Vec<_>>(),
+        [IdentifierType::NotNfkc]
+    );
+}
@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_properties::PropertiesError;
+use icu_provider::DataError;
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// A list of error outcomes for parsing a UnicodeSet pattern.
+///
+/// The offsets are byte offsets into the pattern.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// A character that is not allowed at this position
+    #[displaydoc("Unexpected character at offset {0}")]
+    UnexpectedChar(usize),
+    /// The pattern ended before the set was closed
+    #[displaydoc("Unexpected end of pattern")]
+    UnexpectedEnd,
+    /// A malformed escape sequence, or one for an unsupported code point
+    #[displaydoc("Invalid escape at offset {0}")]
+    InvalidEscape(usize),
+    /// A range whose end is before its start
+    #[displaydoc("Invalid range at offset {0}")]
+    InvalidRange(usize),
+    /// A property or property value that is unknown
+    #[displaydoc("Unknown property at offset {0}")]
+    UnknownProperty(usize),
+    /// An error occurred while loading data
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl From<DataError> for ParseError {
+    fn from(e: DataError) -> Self {
+        ParseError::Data(e)
+    }
+}
+
+impl ParseError {
+    /// Converts the error of resolving the property expression at `offset`.
+    pub(crate) fn from_properties(e: PropertiesError, offset: usize) -> Self {
+        match e {
+            PropertiesError::PropDataLoad(e) => ParseError::Data(e),
+            _ => ParseError::UnknownProperty(offset),
+        }
+    }
+}
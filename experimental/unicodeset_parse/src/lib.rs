@@ -0,0 +1,81 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Parsing of UnicodeSet patterns into sets of code points and strings.
+//!
+//! This module is published as its own crate ([`icu_unicodeset_parse`](https://docs.rs/icu_unicodeset_parse/latest/icu_unicodeset_parse/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! A pattern like `[[:L:]&[:Script=Latn:]-[a-z]]` describes a set of code points and strings,
+//! with the [UnicodeSet syntax] of ICU. The parsed [`UnicodeSet`] contains a
+//! [`CodePointInversionList`] of its code points, and can be serialized back to a pattern.
+//!
+//! The syntax consists of the following elements, between which whitespace is ignored:
+//!
+//! - Sets in brackets, like `[abc]`, and their complement, like `[^abc]`, which has no strings.
+//! - Code points, which are literal characters like `a`, escape sequences like `\u0061`,
+//!   `\U00000061`, `\x61`, `\x{61}`, `\t`, `\n` and `\r`, or any other character escaped with a
+//!   backslash, like `\[`. Character names like `\N{LATIN SMALL LETTER A}` are not supported.
+//! - Ranges of code points, like `a-z`. A `-` at the start or end of a set is a literal.
+//! - Strings, like `{ch}`.
+//! - Property expressions, like `[:Script=Latn:]`, `\p{Script=Latn}`, and their complements
+//!   `[:^Script=Latn:]`, `\P{Script=Latn}` and `[:Script≠Latn:]`. A value without a property
+//!   name is a value of General_Category, a value of Script or a binary property, in this
+//!   order, or one of `Any`, `ASCII` and `Assigned`. Names are matched loosely, as in
+//!   [`icu_properties::names`].
+//! - Set operations between nested sets: a union if nothing separates them, like
+//!   `[[a-z][0-9]]`, a difference with `-`, like `[[a-z]-[aeiou]]`, and an intersection
+//!   with `&`, like `[[:L:]&[:Greek:]]`. Operations are applied from left to right.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_unicodeset_parse::parse_with_any_provider;
+//!
+//! let set = parse_with_any_provider(&icu_testdata::any(), "[\\p{Emoji}-[0-9#*]{🇺🇳}]")
+//!     .expect("valid pattern");
+//!
+//! assert!(set.contains('🎃'));
+//! assert!(!set.contains('0'));
+//! assert!(set.contains_str("🇺🇳"));
+//! assert_eq!(set.strings(), ["🇺🇳"]);
+//! ```
+//!
+//! [UnicodeSet syntax]: https://unicode-org.github.io/icu/userguide/strings/unicodeset.html
+//! [`CodePointInversionList`]: icu_collections::codepointinvlist::CodePointInversionList
+//! [`ICU4X`]: ../icu/index.html
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+mod parse;
+mod unicodeset;
+
+pub use error::ParseError;
+#[cfg(feature = "serde")]
+pub use parse::parse_with_buffer_provider;
+pub use parse::{parse_unstable, parse_with_any_provider};
+pub use unicodeset::UnicodeSet;
@@ -0,0 +1,743 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{ParseError, UnicodeSet};
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use icu_collections::codepointinvlist::CodePointInversionListBuilder;
+use icu_properties::names::loose_matching_chars;
+use icu_properties::provider::*;
+use icu_properties::*;
+use icu_provider::prelude::*;
+
+/// Looks up the code points of a property expression, with the name of a property and the
+/// name of a value, or only the name of a value or a binary property.
+type Resolver<'a> =
+    dyn Fn(&str, Option<&str>) -> Result<CodePointInversionListBuilder, PropertiesError> + 'a;
+
+/// Whether `name` matches one of `candidates`, ignoring case, whitespace, underscores and hyphens.
+fn is_any_of(name: &str, candidates: &[&str]) -> bool {
+    candidates
+        .iter()
+        .any(|candidate| loose_matching_chars(name).eq(loose_matching_chars(candidate)))
+}
+
+/// A set of code points and strings that is being parsed.
+#[derive(Default)]
+struct PartialSet {
+    code_points: CodePointInversionListBuilder,
+    strings: BTreeSet<String>,
+}
+
+/// An operation between a set and the set that follows it in a pattern.
+#[derive(Copy, Clone)]
+enum Operation {
+    Union,
+    Difference,
+    Intersection,
+}
+
+impl PartialSet {
+    fn apply(&mut self, operation: Operation, other: PartialSet) {
+        let PartialSet {
+            code_points,
+            strings,
+        } = other;
+        let code_points = code_points.build();
+        match operation {
+            Operation::Union => {
+                self.code_points.add_set(&code_points);
+                self.strings.extend(strings);
+            }
+            Operation::Difference => {
+                self.code_points.remove_set(&code_points);
+                self.strings.retain(|s| !strings.contains(s));
+            }
+            Operation::Intersection => {
+                self.code_points.retain_set(&code_points);
+                self.strings.retain(|s| strings.contains(s));
+            }
+        }
+    }
+}
+
+/// The last element of a set, which determines what a following `-` or `&` means.
+#[derive(Copy, Clone, PartialEq)]
+enum Last {
+    /// The start of the set.
+    Start,
+    /// A code point, which can start a range.
+    CodePoint(u32),
+    /// A nested set, which can be followed by a set operation.
+    Set,
+    /// A range, a string, or a literal `-`.
+    Other,
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    offset: usize,
+    resolve: &'a Resolver<'a>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        self.pattern.get(self.offset..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// The error for the character at the current offset.
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(_) => ParseError::UnexpectedChar(self.offset),
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.offset += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.next();
+        }
+    }
+
+    /// Whether a set or a property expression starts at the current offset.
+    fn at_set_start(&self) -> bool {
+        let rest = self.rest();
+        rest.starts_with('[') || rest.starts_with("\\p") || rest.starts_with("\\P")
+    }
+
+    fn parse(mut self) -> Result<UnicodeSet, ParseError> {
+        self.skip_whitespace();
+        if !self.at_set_start() {
+            return Err(self.unexpected());
+        }
+        let set = self.parse_set()?;
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+        Ok(UnicodeSet::from_parts(
+            set.code_points.build(),
+            set.strings.into_iter().collect(),
+        ))
+    }
+
+    /// Parses a set in brackets or a property expression.
+    fn parse_set(&mut self) -> Result<PartialSet, ParseError> {
+        if !self.rest().starts_with('[') || self.rest().starts_with("[:") {
+            return self.parse_property();
+        }
+        self.expect('[')?;
+        self.skip_whitespace();
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.next();
+        }
+
+        let mut set = PartialSet::default();
+        let mut last = Last::Start;
+        loop {
+            self.skip_whitespace();
+            let offset = self.offset;
+            match self.peek() {
+                None => return Err(ParseError::UnexpectedEnd),
+                Some(']') => {
+                    self.next();
+                    break;
+                }
+                Some('-') => {
+                    self.next();
+                    self.skip_whitespace();
+                    if last == Last::Start || self.peek() == Some(']') {
+                        set.code_points.add_char('-');
+                        last = Last::Other;
+                        continue;
+                    }
+                    match last {
+                        Last::Set if self.at_set_start() => {
+                            let other = self.parse_set()?;
+                            set.apply(Operation::Difference, other);
+                        }
+                        Last::CodePoint(start) if !self.at_set_start() => {
+                            if self.peek() == Some('{') {
+                                return Err(self.unexpected());
+                            }
+                            let end = self.parse_code_point()?;
+                            if end < start {
+                                return Err(ParseError::InvalidRange(offset));
+                            }
+                            set.code_points.add_range_u32(&(start..=end));
+                            last = Last::Other;
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+                Some('&') => {
+                    self.next();
+                    self.skip_whitespace();
+                    if last != Last::Set || !self.at_set_start() {
+                        return Err(ParseError::UnexpectedChar(offset));
+                    }
+                    let other = self.parse_set()?;
+                    set.apply(Operation::Intersection, other);
+                }
+                Some('{') => {
+                    let string = self.parse_string()?;
+                    let mut chars = string.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => set.code_points.add_char(c),
+                        _ => {
+                            set.strings.insert(string);
+                        }
+                    }
+                    last = Last::Other;
+                }
+                Some(_) if self.at_set_start() => {
+                    let other = self.parse_set()?;
+                    set.apply(Operation::Union, other);
+                    last = Last::Set;
+                }
+                Some(_) => {
+                    let c = self.parse_code_point()?;
+                    set.code_points.add_u32(c);
+                    last = Last::CodePoint(c);
+                }
+            }
+        }
+
+        if negated {
+            set.code_points.complement();
+            set.strings.clear();
+        }
+        Ok(set)
+    }
+
+    /// Parses a property expression like `[:Script=Latn:]`, `\p{L}` or `\P{Emoji}`.
+    fn parse_property(&mut self) -> Result<PartialSet, ParseError> {
+        let offset = self.offset;
+        let (negated, expression) = if self.rest().starts_with("[:") {
+            self.offset += 2;
+            let end = self.rest().find(":]").ok_or(ParseError::UnexpectedEnd)?;
+            let expression = self.rest().get(..end).unwrap_or_default().trim_start();
+            self.offset += end + 2;
+            match expression.strip_prefix('^') {
+                Some(expression) => (true, expression),
+                None => (false, expression),
+            }
+        } else {
+            self.expect('\\')?;
+            let negated = self.next() == Some('P');
+            self.expect('{')?;
+            let end = self.rest().find('}').ok_or(ParseError::UnexpectedEnd)?;
+            let expression = self.rest().get(..end).unwrap_or_default();
+            self.offset += end + 1;
+            (negated, expression)
+        };
+
+        let (name, value, negated) = if let Some((name, value)) = expression.split_once('≠') {
+            (name, Some(value.trim()), !negated)
+        } else if let Some((name, value)) = expression.split_once('=') {
+            (name, Some(value.trim()), negated)
+        } else {
+            (expression, None, negated)
+        };
+        let mut code_points = (self.resolve)(name.trim(), value)
+            .map_err(|e| ParseError::from_properties(e, offset))?;
+        if negated {
+            code_points.complement();
+        }
+        Ok(PartialSet {
+            code_points,
+            strings: BTreeSet::new(),
+        })
+    }
+
+    /// Parses a string element like `{ch}`.
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.expect('{')?;
+        let mut string = String::new();
+        loop {
+            self.skip_whitespace();
+            let offset = self.offset;
+            match self.next() {
+                None => return Err(ParseError::UnexpectedEnd),
+                Some('}') => return Ok(string),
+                Some('\\') => {
+                    let c = char::from_u32(self.parse_escape(offset)?)
+                        .ok_or(ParseError::InvalidEscape(offset))?;
+                    string.push(c);
+                }
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    /// Parses a literal or escaped code point.
+    fn parse_code_point(&mut self) -> Result<u32, ParseError> {
+        let offset = self.offset;
+        match self.next() {
+            None => Err(ParseError::UnexpectedEnd),
+            Some('\\') => self.parse_escape(offset),
+            Some('[' | ']' | '{' | '&' | '-') => Err(ParseError::UnexpectedChar(offset)),
+            Some(c) => Ok(c as u32),
+        }
+    }
+
+    /// Parses the escape sequence after the backslash at `offset`.
+    fn parse_escape(&mut self, offset: usize) -> Result<u32, ParseError> {
+        match self.next() {
+            None => Err(ParseError::UnexpectedEnd),
+            Some('u') => self.parse_hex(offset, 4, 4),
+            Some('U') => self.parse_hex(offset, 8, 8),
+            Some('x') if self.peek() == Some('{') => {
+                self.next();
+                let c = self.parse_hex(offset, 1, 6)?;
+                self.expect('}')
+                    .map_err(|_| ParseError::InvalidEscape(offset))?;
+                Ok(c)
+            }
+            Some('x') => self.parse_hex(offset, 2, 2),
+            // Character names are not supported.
+            Some('N') => Err(ParseError::InvalidEscape(offset)),
+            Some('t') => Ok('\t' as u32),
+            Some('n') => Ok('\n' as u32),
+            Some('r') => Ok('\r' as u32),
+            Some(c) => Ok(c as u32),
+        }
+    }
+
+    /// Parses a code point of `min` to `max` hexadecimal digits, for the escape at `offset`.
+    fn parse_hex(&mut self, offset: usize, min: usize, max: usize) -> Result<u32, ParseError> {
+        let mut value = 0;
+        let mut digits = 0;
+        while digits < max {
+            match self.peek().and_then(|c| c.to_digit(16)) {
+                Some(digit) => {
+                    value = value * 16 + digit;
+                    digits += 1;
+                    self.next();
+                }
+                None => break,
+            }
+        }
+        if digits < min || value > char::MAX as u32 {
+            return Err(ParseError::InvalidEscape(offset));
+        }
+        Ok(value)
+    }
+}
+
+/// Adds the code points of `data` to a new builder.
+fn to_builder(data: &sets::CodePointSetData) -> CodePointInversionListBuilder {
+    let mut builder = CodePointInversionListBuilder::new();
+    builder.add_set(&data.to_code_point_inversion_list());
+    builder
+}
+
+macro_rules! make_parse {
+    ($($keyed_data_marker:ident,)+) => {
+        /// Parses a UnicodeSet pattern like `[[:L:]&[:Script=Latn:]-[a-z]]` to the set of code
+        /// points and strings it describes.
+        ///
+        /// See the [crate documentation](crate) for the syntax of the patterns.
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        /// <div class="stab unstable">
+        /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+        /// </div>
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use icu_unicodeset_parse::{parse_with_any_provider, ParseError};
+        ///
+        /// let set = parse_with_any_provider(&icu_testdata::any(), "[[:L:]&[:Script=Latn:]-[a-z]]")
+        ///     .expect("valid pattern");
+        ///
+        /// assert!(set.contains('A'));
+        /// assert!(set.contains('é'));
+        /// assert!(!set.contains('a'));
+        /// assert!(!set.contains('α'));
+        ///
+        /// assert_eq!(
+        ///     parse_with_any_provider(&icu_testdata::any(), "[a-z\\p{Klingon}]"),
+        ///     Err(ParseError::UnknownProperty(4))
+        /// );
+        /// ```
+        pub fn parse_unstable<P>(provider: &P, pattern: &str) -> Result<UnicodeSet, ParseError>
+        where
+            P: ?Sized $(+ DataProvider<$keyed_data_marker>)+,
+        {
+            Parser {
+                pattern,
+                offset: 0,
+                resolve: &|name, value| resolve(provider, name, value),
+            }
+            .parse()
+        }
+
+        /// Looks up the code points of a property expression.
+        fn resolve<P>(
+            provider: &P,
+            name: &str,
+            value: Option<&str>,
+        ) -> Result<CodePointInversionListBuilder, PropertiesError>
+        where
+            P: ?Sized $(+ DataProvider<$keyed_data_marker>)+,
+        {
+            let value = match value {
+                Some(value) => value,
+                None => return resolve_value(provider, name),
+            };
+
+            macro_rules! get_set_for_value {
+                ($value_ty:ident, $load:ident) => {{
+                    let value = $value_ty::get_name_to_enum_mapper(provider)?
+                        .as_borrowed()
+                        .get_loose(value)
+                        .ok_or(PropertiesError::UnknownPropertyName)?;
+                    to_builder(&maps::$load(provider)?.as_borrowed().get_set_for_value(value))
+                }};
+            }
+
+            Ok(if is_any_of(name, &["gc", "General_Category"]) {
+                let group = GeneralCategoryGroup::get_name_to_enum_mapper(provider)?
+                    .as_borrowed()
+                    .get_loose(value)
+                    .ok_or(PropertiesError::UnknownPropertyName)?;
+                to_builder(&sets::load_for_general_category_group(provider, group)?)
+            } else if is_any_of(name, &["sc", "Script"]) {
+                get_set_for_value!(Script, load_script)
+            } else if is_any_of(name, &["scx", "Script_Extensions"]) {
+                let script = Script::get_name_to_enum_mapper(provider)?
+                    .as_borrowed()
+                    .get_loose(value)
+                    .ok_or(PropertiesError::UnknownPropertyName)?;
+                let mut builder = CodePointInversionListBuilder::new();
+                builder.add_set(
+                    &script::load_script_with_extensions_unstable(provider)?
+                        .as_borrowed()
+                        .get_script_extensions_set(script),
+                );
+                builder
+            } else if is_any_of(name, &["bc", "Bidi_Class"]) {
+                get_set_for_value!(BidiClass, load_bidi_class)
+            } else if is_any_of(name, &["ccc", "Canonical_Combining_Class"]) {
+                get_set_for_value!(CanonicalCombiningClass, load_canonical_combining_class)
+            } else if is_any_of(name, &["ea", "East_Asian_Width"]) {
+                get_set_for_value!(EastAsianWidth, load_east_asian_width)
+            } else if is_any_of(name, &["GCB", "Grapheme_Cluster_Break"]) {
+                get_set_for_value!(GraphemeClusterBreak, load_grapheme_cluster_break)
+            } else if is_any_of(name, &["jt", "Joining_Type"]) {
+                get_set_for_value!(JoiningType, load_joining_type)
+            } else if is_any_of(name, &["lb", "Line_Break"]) {
+                get_set_for_value!(LineBreak, load_line_break)
+            } else if is_any_of(name, &["SB", "Sentence_Break"]) {
+                get_set_for_value!(SentenceBreak, load_sentence_break)
+            } else if is_any_of(name, &["WB", "Word_Break"]) {
+                get_set_for_value!(WordBreak, load_word_break)
+            } else {
+                let mut builder = to_builder(&sets::load_for_name_unstable(provider, name)?);
+                if is_any_of(value, &["N", "No", "F", "False"]) {
+                    builder.complement();
+                } else if !is_any_of(value, &["Y", "Yes", "T", "True"]) {
+                    return Err(PropertiesError::UnknownPropertyName);
+                }
+                builder
+            })
+        }
+
+        /// Looks up the code points of a property expression without a property name, which is
+        /// a General_Category value, a Script value or a binary property, in this order.
+        fn resolve_value<P>(
+            provider: &P,
+            name: &str,
+        ) -> Result<CodePointInversionListBuilder, PropertiesError>
+        where
+            P: ?Sized $(+ DataProvider<$keyed_data_marker>)+,
+        {
+            let mut builder = CodePointInversionListBuilder::new();
+            if is_any_of(name, &["Any"]) {
+                builder.add_range_u32(&(0..=char::MAX as u32));
+            } else if is_any_of(name, &["ASCII"]) {
+                builder.add_range_u32(&(0..=0x7F));
+            } else if is_any_of(name, &["Assigned"]) {
+                builder = to_builder(&sets::load_for_general_category_group(
+                    provider,
+                    GeneralCategoryGroup::Unassigned,
+                )?);
+                builder.complement();
+            } else if let Some(group) = GeneralCategoryGroup::get_name_to_enum_mapper(provider)?
+                .as_borrowed()
+                .get_loose(name)
+            {
+                builder = to_builder(&sets::load_for_general_category_group(provider, group)?);
+            } else if let Some(script) = Script::get_name_to_enum_mapper(provider)?
+                .as_borrowed()
+                .get_loose(name)
+            {
+                builder = to_builder(
+                    &maps::load_script(provider)?
+                        .as_borrowed()
+                        .get_set_for_value(script),
+                );
+            } else {
+                builder = to_builder(&sets::load_for_name_unstable(provider, name)?);
+            }
+            Ok(builder)
+        }
+    };
+}
+
+make_parse!(
+    AsciiHexDigitV1Marker,
+    AlnumV1Marker,
+    AlphabeticV1Marker,
+    BidiControlV1Marker,
+    BidiMirroredV1Marker,
+    BlankV1Marker,
+    CasedV1Marker,
+    CaseIgnorableV1Marker,
+    FullCompositionExclusionV1Marker,
+    ChangesWhenCasefoldedV1Marker,
+    ChangesWhenCasemappedV1Marker,
+    ChangesWhenNfkcCasefoldedV1Marker,
+    ChangesWhenLowercasedV1Marker,
+    ChangesWhenTitlecasedV1Marker,
+    ChangesWhenUppercasedV1Marker,
+    DashV1Marker,
+    DeprecatedV1Marker,
+    DefaultIgnorableCodePointV1Marker,
+    DiacriticV1Marker,
+    EmojiModifierBaseV1Marker,
+    EmojiComponentV1Marker,
+    EmojiModifierV1Marker,
+    EmojiV1Marker,
+    EmojiPresentationV1Marker,
+    ExtenderV1Marker,
+    ExtendedPictographicV1Marker,
+    GraphV1Marker,
+    GraphemeBaseV1Marker,
+    GraphemeExtendV1Marker,
+    GraphemeLinkV1Marker,
+    HexDigitV1Marker,
+    HyphenV1Marker,
+    IdContinueV1Marker,
+    IdeographicV1Marker,
+    IdStartV1Marker,
+    IdsBinaryOperatorV1Marker,
+    IdsTrinaryOperatorV1Marker,
+    JoinControlV1Marker,
+    LogicalOrderExceptionV1Marker,
+    LowercaseV1Marker,
+    MathV1Marker,
+    NoncharacterCodePointV1Marker,
+    NfcInertV1Marker,
+    NfdInertV1Marker,
+    NfkcInertV1Marker,
+    NfkdInertV1Marker,
+    PatternSyntaxV1Marker,
+    PatternWhiteSpaceV1Marker,
+    PrependedConcatenationMarkV1Marker,
+    PrintV1Marker,
+    QuotationMarkV1Marker,
+    RadicalV1Marker,
+    RegionalIndicatorV1Marker,
+    SoftDottedV1Marker,
+    SegmentStarterV1Marker,
+    CaseSensitiveV1Marker,
+    SentenceTerminalV1Marker,
+    TerminalPunctuationV1Marker,
+    UnifiedIdeographV1Marker,
+    UppercaseV1Marker,
+    VariationSelectorV1Marker,
+    WhiteSpaceV1Marker,
+    XdigitV1Marker,
+    XidContinueV1Marker,
+    XidStartV1Marker,
+    GeneralCategoryV1Marker,
+    BidiClassV1Marker,
+    CanonicalCombiningClassV1Marker,
+    EastAsianWidthV1Marker,
+    GraphemeClusterBreakV1Marker,
+    JoiningTypeV1Marker,
+    LineBreakV1Marker,
+    ScriptV1Marker,
+    SentenceBreakV1Marker,
+    WordBreakV1Marker,
+    ScriptWithExtensionsPropertyV1Marker,
+    BidiClassNameToValueV1Marker,
+    CanonicalCombiningClassNameToValueV1Marker,
+    EastAsianWidthNameToValueV1Marker,
+    GeneralCategoryMaskNameToValueV1Marker,
+    GraphemeClusterBreakNameToValueV1Marker,
+    JoiningTypeNameToValueV1Marker,
+    LineBreakNameToValueV1Marker,
+    ScriptNameToValueV1Marker,
+    SentenceBreakNameToValueV1Marker,
+    WordBreakNameToValueV1Marker,
+);
+
+#[doc = icu_provider::gen_any_buffer_docs!(ANY, icu_provider, parse_unstable)]
+pub fn parse_with_any_provider(
+    provider: &(impl AnyProvider + ?Sized),
+    pattern: &str,
+) -> Result<UnicodeSet, ParseError> {
+    use icu_provider::AsDowncastingAnyProvider;
+    parse_unstable(&provider.as_downcasting(), pattern)
+}
+
+#[cfg(feature = "serde")]
+#[doc = icu_provider::gen_any_buffer_docs!(BUFFER, icu_provider, parse_unstable)]
+pub fn parse_with_buffer_provider(
+    provider: &(impl BufferProvider + ?Sized),
+    pattern: &str,
+) -> Result<UnicodeSet, ParseError> {
+    use icu_provider::AsDeserializingBufferProvider;
+    parse_unstable(&provider.as_deserializing(), pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use writeable::Writeable;
+
+    fn parse(pattern: &str) -> Result<UnicodeSet, ParseError> {
+        parse_with_any_provider(&icu_testdata::any(), pattern)
+    }
+
+    fn pattern(pattern: &str) -> String {
+        parse(pattern)
+            .expect("valid pattern")
+            .write_to_string()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_syntax() {
+        assert_eq!(pattern("[]"), "[]");
+        assert_eq!(pattern("[a]"), "[a]");
+        assert_eq!(pattern(" [ z a-c d ] "), "[a-dz]");
+        assert_eq!(pattern("[ab]"), "[ab]");
+        assert_eq!(pattern("[-a]"), "[\\-a]");
+        assert_eq!(pattern("[a-]"), "[\\-a]");
+        assert_eq!(pattern("[\\[\\]\\-\\&\\{\\}\\\\]"), "[\\&\\-\\[-\\]\\{\\}]");
+        assert_eq!(
+            pattern("[\\u0041\\U0001F600\\x42\\x{43}]"),
+            "[A-C\\U0001F600]"
+        );
+        assert_eq!(pattern("[\\x{D800}]"), "[\\uD800]");
+        assert_eq!(pattern("[\\t é]"), "[\\u0009é]");
+        assert_eq!(pattern("[{ch}{}{a}{c h}]"), "[a{}{ch}]");
+        assert_eq!(pattern("[[a-z]-[aeiou]]"), "[b-df-hj-np-tv-z]");
+        assert_eq!(pattern("[[a-z]&[x-\\u00FF]]"), "[x-z]");
+        assert_eq!(pattern("[[a-c]-[b]x]"), "[acx]");
+        assert_eq!(pattern("[[a{ab}{bc}]-[{bc}]]"), "[a{ab}]");
+        assert_eq!(pattern("[[{ab}{bc}]&[{bc}]]"), "[{bc}]");
+        assert_eq!(pattern("[^a-z]"), "[^a-z]");
+        assert_eq!(pattern("[^\\u0000-\\U0010FFFF]"), "[]");
+        assert_eq!(pattern("[^a{ab}]"), "[^a]");
+        assert_eq!(pattern("[^[^a]]"), "[a]");
+        assert_eq!(pattern("[\\x00-\\U0010FFFF]"), "[\\u0000-\\U0010FFFF]");
+        assert_eq!(pattern("[\\x00-`b-\\U0010FFFF]"), "[^a]");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("a"), Err(ParseError::UnexpectedChar(0)));
+        assert_eq!(parse("[a"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(parse("[a]b"), Err(ParseError::UnexpectedChar(3)));
+        assert_eq!(parse("[z-a]"), Err(ParseError::InvalidRange(2)));
+        assert_eq!(parse("[a-c-e]"), Err(ParseError::UnexpectedChar(5)));
+        assert_eq!(parse("[a&[b]]"), Err(ParseError::UnexpectedChar(2)));
+        assert_eq!(parse("[[a]-b]"), Err(ParseError::UnexpectedChar(5)));
+        assert_eq!(parse("[a-{bc}]"), Err(ParseError::UnexpectedChar(3)));
+        assert_eq!(parse("[\\u004]"), Err(ParseError::InvalidEscape(1)));
+        assert_eq!(parse("[\\x{110000}]"), Err(ParseError::InvalidEscape(1)));
+        assert_eq!(parse("[{\\uD800}]"), Err(ParseError::InvalidEscape(2)));
+        assert_eq!(
+            parse("[\\N{LATIN SMALL LETTER A}]"),
+            Err(ParseError::InvalidEscape(1))
+        );
+        assert_eq!(
+            parse("[:Script=Klingon:]"),
+            Err(ParseError::UnknownProperty(0))
+        );
+        assert_eq!(
+            parse("[a\\p{Alphabetic=Maybe}]"),
+            Err(ParseError::UnknownProperty(2))
+        );
+        assert_eq!(parse("[:L"), Err(ParseError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_properties() {
+        let set = parse("[:L:]").expect("valid pattern");
+        assert!(set.contains('a'));
+        assert!(!set.contains('1'));
+        assert_eq!(parse("\\p{gc=Letter}"), Ok(set.clone()));
+        assert_eq!(parse("[\\p{General_Category = letter}]"), Ok(set.clone()));
+        assert_eq!(parse("[^\\P{L}]"), Ok(set.clone()));
+        assert_eq!(parse("[:^gc≠L:]"), Ok(set));
+
+        let set = parse("[:Greek:]").expect("valid pattern");
+        assert!(set.contains('α'));
+        assert!(!set.contains('a'));
+        assert!(!set.contains('\u{0342}'));
+        assert_eq!(parse("[:sc=Grek:]"), Ok(set));
+        let set = parse("[:scx=Grek:]").expect("valid pattern");
+        assert!(set.contains('α'));
+        assert!(set.contains('\u{0342}'));
+
+        let set = parse("[:Alphabetic:]").expect("valid pattern");
+        assert_eq!(parse("[:Alpha=Yes:]"), Ok(set.clone()));
+        assert_eq!(parse("[:^Alpha=No:]"), Ok(set));
+
+        assert_eq!(pattern("[:ASCII:]"), "[\\u0000-\\u007F]");
+        assert_eq!(pattern("[:Any:]"), "[\\u0000-\\U0010FFFF]");
+        assert_eq!(pattern("[[:Lu:]&[a-zA-Z]]"), "[A-Z]");
+        assert_eq!(
+            pattern("[[:ccc=230:]&[\\u0300-\\u0305]]"),
+            "[\\u0300-\\u0305]"
+        );
+        assert_eq!(pattern("[[:lb=ID:]&[あ-う]]"), "[あいう]");
+        assert_eq!(pattern("[[:Cs:]&[\\uD7FF-\\uD801]]"), "[\\uD800\\uD801]");
+        assert_eq!(
+            pattern("[[:Assigned:]&[\\u0380-\\u0385]]"),
+            "[\\u0384\\u0385]"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for source in [
+            "[[:Greek:]{αβ}]",
+            "[[:^L:]-[\\u0000-\\u00FF]]",
+            "[^[:Cn:][:Cs:]]",
+            "[\\u0000 \\U0010FFFF {\\u0000}]",
+        ] {
+            let set = parse(source).expect("valid pattern");
+            let canonical = set.write_to_string().into_owned();
+            assert_eq!(parse(&canonical), Ok(set), "{}", canonical);
+        }
+    }
+}
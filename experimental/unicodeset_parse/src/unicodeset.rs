@@ -0,0 +1,165 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_collections::codepointinvlist::CodePointInversionList;
+use writeable::Writeable;
+
+/// A set of code points and strings, as described by a UnicodeSet pattern.
+///
+/// The [`Writeable`] and [`Display`](core::fmt::Display) implementations serialize the set
+/// to its canonical pattern, which parses back to the same set:
+///
+/// - Code points come first, in ascending order, as single characters or as ranges `a-z`;
+///   two adjacent code points are written as two characters.
+/// - A set with more than one range that starts at U+0000 and ends at U+10FFFF, and has no
+///   strings, is written as the negation `[^...]` of its complement.
+/// - Strings come last, in ascending order, as `{string}`.
+/// - Syntax characters are escaped with a backslash, and characters that are not ASCII
+///   graphic or alphanumeric as `\uXXXX` or `\UXXXXXXXX`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the "experimental" feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu_unicodeset_parse::parse_with_any_provider;
+/// use writeable::assert_writeable_eq;
+///
+/// let set = parse_with_any_provider(&icu_testdata::any(), "[ {ch} b-c a \\- \\u0020 ]")
+///     .expect("valid pattern");
+///
+/// assert!(set.contains('-'));
+/// assert!(set.contains_str("ch"));
+/// assert_writeable_eq!(set, "[\\u0020\\-a-c{ch}]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnicodeSet {
+    code_points: CodePointInversionList<'static>,
+    strings: Vec<String>,
+}
+
+impl UnicodeSet {
+    /// Creates a set from its code points and its strings, which are sorted and deduplicated.
+    pub fn from_parts(
+        code_points: CodePointInversionList<'static>,
+        mut strings: Vec<String>,
+    ) -> Self {
+        strings.sort_unstable();
+        strings.dedup();
+        Self {
+            code_points,
+            strings,
+        }
+    }
+
+    /// Returns the code points and the sorted strings of the set.
+    pub fn into_parts(self) -> (CodePointInversionList<'static>, Vec<String>) {
+        (self.code_points, self.strings)
+    }
+
+    /// The code points of the set.
+    pub fn code_points(&self) -> &CodePointInversionList<'static> {
+        &self.code_points
+    }
+
+    /// The strings of the set, in ascending order.
+    ///
+    /// A string element of a pattern with a single code point, like `{a}`, is a code point of
+    /// the set and not one of its strings.
+    pub fn strings(&self) -> &[String] {
+        &self.strings
+    }
+
+    /// Whether the set contains the code point `c`.
+    pub fn contains(&self, c: char) -> bool {
+        self.code_points.contains(c)
+    }
+
+    /// Whether the set contains `s`, either as a string or as a single code point.
+    pub fn contains_str(&self, s: &str) -> bool {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.code_points.contains(c),
+            _ => self.strings.binary_search_by(|t| t.as_str().cmp(s)).is_ok(),
+        }
+    }
+}
+
+/// Writes `c` to a pattern, escaped if needed.
+fn write_char<W: fmt::Write + ?Sized>(c: char, sink: &mut W) -> fmt::Result {
+    match c {
+        '[' | ']' | '-' | '&' | '{' | '}' | '\\' | '^' | '$' | ':' => {
+            sink.write_char('\\')?;
+            sink.write_char(c)
+        }
+        _ if c.is_ascii_graphic() || (!c.is_ascii() && c.is_alphanumeric()) => sink.write_char(c),
+        _ if c <= '\u{FFFF}' => write!(sink, "\\u{:04X}", c as u32),
+        _ => write!(sink, "\\U{:08X}", c as u32),
+    }
+}
+
+/// Writes the code point `cp` to a pattern, which is escaped if it is a surrogate.
+fn write_code_point<W: fmt::Write + ?Sized>(cp: u32, sink: &mut W) -> fmt::Result {
+    match char::from_u32(cp) {
+        Some(c) => write_char(c, sink),
+        None => write!(sink, "\\u{:04X}", cp),
+    }
+}
+
+/// Writes the range of code points from `start` to `end` to a pattern.
+fn write_range<W: fmt::Write + ?Sized>(start: u32, end: u32, sink: &mut W) -> fmt::Result {
+    write_code_point(start, sink)?;
+    if start != end {
+        if start + 1 != end {
+            sink.write_char('-')?;
+        }
+        write_code_point(end, sink)?;
+    }
+    Ok(())
+}
+
+impl Writeable for UnicodeSet {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        sink.write_char('[')?;
+        let ranges = self.code_points.get_inversion_list_vec();
+        let negated = self.strings.is_empty()
+            && ranges.len() > 2
+            && ranges.first() == Some(&0)
+            && ranges.last() == Some(&(char::MAX as u32 + 1));
+        if negated {
+            // The gaps between the ranges are the ranges of the complement.
+            sink.write_char('^')?;
+            for gap in ranges
+                .get(1..ranges.len() - 1)
+                .unwrap_or_default()
+                .chunks(2)
+            {
+                if let [start, end] = gap {
+                    write_range(*start, end - 1, sink)?;
+                }
+            }
+        } else {
+            for range in self.code_points.iter_ranges() {
+                write_range(*range.start(), *range.end(), sink)?;
+            }
+        }
+        for string in &self.strings {
+            sink.write_char('{')?;
+            for c in string.chars() {
+                write_char(c, sink)?;
+            }
+            sink.write_char('}')?;
+        }
+        sink.write_char(']')
+    }
+}
+
+writeable::impl_display_with_writeable!(UnicodeSet);
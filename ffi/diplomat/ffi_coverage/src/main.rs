@@ -327,6 +327,7 @@ lazy_static::lazy_static! {
 
         // Experimental
         "icu::casemapping",
+        "icu::spoof",
 
         // Stuff that does not need to be exposed over FFI
         // Especially for stuff that are Rust specific like conversion traits
@@ -105,6 +105,27 @@ fn main() -> eyre::Result<()> {
                 )
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("HYPHENATION_ROOT")
+                .long("hyphenation-root")
+                .value_name("PATH")
+                .help(
+                    "Path to a local directory with hyph-utf8 hyphenation patterns (see\n\
+                    https://github.com/hyphenation/tex-hyphen/tree/master/hyph-utf8/tex/generic/hyph-utf8/patterns/txt).",
+                )
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("UCD_ROOT")
+                .long("ucd-root")
+                .value_name("PATH")
+                .help(
+                    "Path to a local directory with the Unicode Character Database files (see\n\
+                    https://www.unicode.org/Public/14.0.0/ucd/), as well as IdnaMappingTable.txt and the\n\
+                    UTS #39 security files.",
+                )
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("TRIE_TYPE")
                 .long("trie-type")
@@ -287,6 +308,14 @@ fn main() -> eyre::Result<()> {
         source_data = source_data.with_icuexport(PathBuf::from(path))?;
     }
 
+    if let Some(path) = matches.value_of("HYPHENATION_ROOT") {
+        source_data = source_data.with_hyphenation(PathBuf::from(path))?;
+    }
+
+    if let Some(path) = matches.value_of("UCD_ROOT") {
+        source_data = source_data.with_ucd(PathBuf::from(path))?;
+    }
+
     if matches.value_of("TRIE_TYPE") == Some("fast") {
         source_data = source_data.with_fast_tries();
     }
@@ -354,6 +383,10 @@ fn main() -> eyre::Result<()> {
             eyre::eyre!(
                 "Either --icuexport-tag or --icuexport-root or --input-from-testdata must be specified"
             )
+        } else if icu_datagen::is_missing_hyphenation_error(e) {
+            eyre::eyre!("--hyphenation-root must be specified")
+        } else if icu_datagen::is_missing_ucd_error(e) {
+            eyre::eyre!("--ucd-root must be specified")
         } else {
             e.into()
         }
@@ -10,6 +10,12 @@ pub(crate) const MISSING_CLDR_ERROR: DataError =
 pub(crate) const MISSING_ICUEXPORT_ERROR: DataError =
     DataErrorKind::MissingSourceData.with_str_context("icuexport");
 
+pub(crate) const MISSING_HYPHENATION_ERROR: DataError =
+    DataErrorKind::MissingSourceData.with_str_context("hyphenation");
+
+pub(crate) const MISSING_UCD_ERROR: DataError =
+    DataErrorKind::MissingSourceData.with_str_context("ucd");
+
 /// Identifies errors that are due to missing CLDR data.
 ///
 /// See [`datagen`](crate::datagen).
@@ -26,6 +32,22 @@ pub fn is_missing_icuexport_error(mut e: DataError) -> bool {
     e == MISSING_ICUEXPORT_ERROR
 }
 
+/// Identifies errors that are due to missing hyphenation patterns.
+///
+/// See [`datagen`](crate::datagen).
+pub fn is_missing_hyphenation_error(mut e: DataError) -> bool {
+    e.key = None;
+    e == MISSING_HYPHENATION_ERROR
+}
+
+/// Identifies errors that are due to missing Unicode Character Database files.
+///
+/// See [`datagen`](crate::datagen).
+pub fn is_missing_ucd_error(mut e: DataError) -> bool {
+    e.key = None;
+    e == MISSING_UCD_ERROR
+}
+
 pub(crate) fn data_error_from_toml(other: toml::de::Error) -> DataError {
     DataError::custom("Toml deserialize").with_display_context(&other)
 }
@@ -66,6 +66,8 @@ mod transform;
 pub use error::*;
 pub use registry::all_keys;
 pub use source::*;
+#[cfg(feature = "experimental")]
+pub use transform::segmenter::build_segmenter_dictionary;
 
 use icu_locid::LanguageIdentifier;
 use icu_provider::datagen::*;
@@ -98,6 +100,10 @@ impl DatagenProvider {
                     )
                     .expect("testdata is valid")
                     .with_icuexport(icu_testdata::paths::icuexport_toml_root())
+                    .expect("testdata is valid")
+                    .with_hyphenation(icu_testdata::paths::hyphenation_root())
+                    .expect("testdata is valid")
+                    .with_ucd(icu_testdata::paths::ucd_root())
                     .expect("testdata is valid"),
             };
         }
@@ -129,7 +135,7 @@ impl AnyProvider for DatagenProvider {
 /// ```
 pub fn keys<S: AsRef<str>>(strings: &[S]) -> Vec<DataKey> {
     let keys = strings.iter().map(AsRef::as_ref).collect::<HashSet<&str>>();
-    all_keys()
+    registry::selectable_keys()
         .into_iter()
         .filter(|k| keys.contains(&*k.path()))
         .collect()
@@ -166,7 +172,7 @@ pub fn keys_from_file<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<DataKey>>
     let keys = BufReader::new(std::fs::File::open(path.as_ref())?)
         .lines()
         .collect::<std::io::Result<HashSet<String>>>()?;
-    Ok(all_keys()
+    Ok(registry::selectable_keys()
         .into_iter()
         .filter(|k| keys.contains(&*k.path()))
         .collect())
@@ -213,7 +219,7 @@ pub fn keys_from_bin<P: AsRef<Path>>(path: P) -> std::io::Result<Vec<DataKey>> {
         }
     }
 
-    Ok(all_keys()
+    Ok(registry::selectable_keys()
         .into_iter()
         .filter(|k| candidates.contains(k.path().as_bytes()))
         .collect())
@@ -257,9 +263,10 @@ pub enum Out {
 ///   contained (strictly, i.e. `en` != `en-US`) in the slice will be generated.
 ///   Otherwise, all locales supported by the source data will be generated.
 /// * `keys`: The keys for which to generate data. See [`all_keys`], [`keys`], [`keys_from_file`], [`keys_from_bin`].
-/// * `sources`: The underlying source data. CLDR and/or ICU data can be missing if no
-///   requested key requires them, otherwise an error satisfying [`is_missing_cldr_error`]
-///   or [`is_missing_icuexport_error`] will be returned.
+/// * `sources`: The underlying source data. CLDR, ICU, hyphenation, and/or UCD data can be missing
+///   if no requested key requires them, otherwise an error satisfying [`is_missing_cldr_error`],
+///   [`is_missing_icuexport_error`], [`is_missing_hyphenation_error`], or [`is_missing_ucd_error`]
+///   will be returned.
 /// * `out`: The output format and location. See the documentation on [`Out`]
 pub fn datagen(
     locales: Option<&[LanguageIdentifier]>,
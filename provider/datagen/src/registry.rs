@@ -24,10 +24,13 @@ use icu_casemapping::provider::*;
 use icu_displaynames::provider::*;
 #[cfg(feature = "experimental")]
 use icu_segmenter::provider::*;
+#[cfg(feature = "experimental")]
+use icu_spoof::provider::*;
 
 macro_rules! registry {
     ($($marker:ident,)+ #[cfg(feature = "experimental")] { $($exp_marker:ident,)+ }) => {
-        /// List of all supported keys
+        /// List of all supported keys, except for `segmenter/hyphenation@1`, whose source data
+        /// is usually not available. It can be selected with [`keys`](crate::keys).
         pub fn all_keys() -> Vec<DataKey> {
             vec![
                 $(
@@ -45,6 +48,7 @@ macro_rules! registry {
             crate::DatagenProvider,
             [
                 HelloWorldV1Marker,
+                HyphenationPatternsV1Marker,
                 $($marker,)+
                 $($exp_marker,)+
             ]
@@ -66,6 +70,10 @@ macro_rules! registry {
             if key.path() == HelloWorldV1Marker::KEY.path() {
                 return HelloWorldV1Marker.bake(env);
             }
+            #[cfg(feature = "experimental")]
+            if key == HyphenationPatternsV1Marker::KEY {
+                return HyphenationPatternsV1Marker.bake(env);
+            }
             $(
                 if key == $marker::KEY {
                     return $marker.bake(env);
@@ -83,6 +91,7 @@ macro_rules! registry {
 }
 
 registry!(
+    AgeV1Marker,
     AliasesV1Marker,
     AlnumV1Marker,
     AlphabeticV1Marker,
@@ -91,6 +100,8 @@ registry!(
     BidiClassV1Marker,
     BidiControlV1Marker,
     BidiMirroredV1Marker,
+    BidiMirroringGlyphV1Marker,
+    BidiPairedBracketV1Marker,
     BlankV1Marker,
     BuddhistDateLengthsV1Marker,
     BuddhistDateSymbolsV1Marker,
@@ -144,6 +155,7 @@ registry!(
     GraphV1Marker,
     GregorianDateLengthsV1Marker,
     GregorianDateSymbolsV1Marker,
+    HangulSyllableTypeV1Marker,
     HexDigitV1Marker,
     HyphenV1Marker,
     IdContinueV1Marker,
@@ -153,6 +165,7 @@ registry!(
     IdsTrinaryOperatorV1Marker,
     IndianDateLengthsV1Marker,
     IndianDateSymbolsV1Marker,
+    IndicSyllabicCategoryV1Marker,
     JapaneseDateLengthsV1Marker,
     JapaneseDateSymbolsV1Marker,
     JapaneseErasV1Marker,
@@ -160,6 +173,7 @@ registry!(
     JapaneseExtendedDateSymbolsV1Marker,
     JapaneseExtendedErasV1Marker,
     JoinControlV1Marker,
+    JoiningTypeV1Marker,
     LikelySubtagsV1Marker,
     LineBreakV1Marker,
     LocaleFallbackLikelySubtagsV1Marker,
@@ -178,6 +192,8 @@ registry!(
     NfkdInertV1Marker,
     NoncharacterCodePointV1Marker,
     NonRecursiveDecompositionSupplementV1Marker,
+    NumericTypeV1Marker,
+    NumericValueV1Marker,
     OrdinalV1Marker,
     OrListV1Marker,
     PatternSyntaxV1Marker,
@@ -201,7 +217,9 @@ registry!(
     UnitListV1Marker,
     UppercaseV1Marker,
     Uts46DecompositionSupplementV1Marker,
+    Uts46StatusV1Marker,
     VariationSelectorV1Marker,
+    VerticalOrientationV1Marker,
     WeekDataV1Marker,
     WhiteSpaceV1Marker,
     WordBreakV1Marker,
@@ -210,23 +228,69 @@ registry!(
     XidStartV1Marker,
     #[cfg(feature = "experimental")]
     {
+        BidiAuxiliaryPropertiesV1Marker,
+        BidiClassNameToValueV1Marker,
+        BidiClassValueToLongNameV1Marker,
+        BidiClassValueToShortNameV1Marker,
+        CanonicalCombiningClassNameToValueV1Marker,
+        CanonicalCombiningClassValueToLongNameV1Marker,
+        CanonicalCombiningClassValueToShortNameV1Marker,
+        CharacterNamesV1Marker,
+        EastAsianWidthNameToValueV1Marker,
+        EastAsianWidthValueToLongNameV1Marker,
+        EastAsianWidthValueToShortNameV1Marker,
+        GeneralCategoryMaskNameToValueV1Marker,
+        GeneralCategoryNameToValueV1Marker,
+        GeneralCategoryValueToLongNameV1Marker,
+        GeneralCategoryValueToShortNameV1Marker,
+        GraphemeClusterBreakNameToValueV1Marker,
+        GraphemeClusterBreakValueToLongNameV1Marker,
+        GraphemeClusterBreakValueToShortNameV1Marker,
+        JoiningTypeNameToValueV1Marker,
+        JoiningTypeValueToLongNameV1Marker,
+        JoiningTypeValueToShortNameV1Marker,
+        LineBreakNameToValueV1Marker,
+        LineBreakValueToLongNameV1Marker,
+        LineBreakValueToShortNameV1Marker,
+        ScriptNameToValueV1Marker,
+        ScriptValueToLongNameV1Marker,
+        ScriptValueToShortNameV1Marker,
+        SentenceBreakNameToValueV1Marker,
+        SentenceBreakValueToLongNameV1Marker,
+        SentenceBreakValueToShortNameV1Marker,
+        WordBreakNameToValueV1Marker,
+        WordBreakValueToLongNameV1Marker,
+        WordBreakValueToShortNameV1Marker,
         CaseMappingV1Marker,
+        ConfusablesV1Marker,
         DateSkeletonPatternsV1Marker,
         TerritoryDisplayNamesV1Marker,
         GraphemeClusterBreakDataV1Marker,
+        IdentifierStatusV1Marker,
+        IdentifierTypeV1Marker,
         LineBreakDataV1Marker,
         LstmDataV1Marker,
         SentenceBreakDataV1Marker,
+        SentenceBreakSuppressionsV1Marker,
         UCharDictionaryBreakDataV1Marker,
         WordBreakDataV1Marker,
     }
 );
 
+/// The keys that can be selected by name: [`all_keys`] and the keys that are left out of it.
+pub(crate) fn selectable_keys() -> Vec<DataKey> {
+    #[allow(unused_mut)]
+    let mut keys = all_keys();
+    #[cfg(feature = "experimental")]
+    keys.push(HyphenationPatternsV1Marker::KEY);
+    keys
+}
+
 #[test]
 fn no_key_collisions() {
     let mut map = std::collections::BTreeMap::new();
     let mut failed = false;
-    for key in all_keys() {
+    for key in selectable_keys() {
         if let Some(colliding_key) = map.insert(key.hashed(), key) {
             println!(
                 "{:?} and {:?} collide at {:?}",
@@ -25,8 +25,10 @@ use zip::ZipArchive;
 pub struct SourceData {
     cldr_paths: Option<Arc<CldrCache>>,
     icuexport_paths: Option<Arc<SerdeCache>>,
+    hyphenation_paths: Option<Arc<SerdeCache>>,
     segmenter_paths: Arc<SerdeCache>,
     segmenter_lstm_paths: Arc<SerdeCache>,
+    ucd_paths: Option<Arc<SerdeCache>>,
     trie_type: IcuTrieType,
     collation_han_database: CollationHanDatabase,
 }
@@ -38,10 +40,12 @@ impl Default for SourceData {
         Self {
             cldr_paths: None,
             icuexport_paths: None,
+            hyphenation_paths: None,
             segmenter_paths: Arc::new(SerdeCache::new(&segmenter_path).expect("valid dir")),
             segmenter_lstm_paths: Arc::new(
                 SerdeCache::new(segmenter_path.join("lstm")).expect("valid dir"),
             ),
+            ucd_paths: None,
             trie_type: IcuTrieType::Small,
             collation_han_database: CollationHanDatabase::Implicit,
         }
@@ -73,6 +77,30 @@ impl SourceData {
         })
     }
 
+    /// Adds hyphenation patterns to this `DataSource`. The root should point to a local
+    /// directory or ZIP file with the `hyph-*.pat.txt` and `hyph-*.hyp.txt` files of
+    /// hyph-utf8 (see [tex-hyphen](
+    /// https://github.com/hyphenation/tex-hyphen/tree/master/hyph-utf8/tex/generic/hyph-utf8/patterns/txt)).
+    pub fn with_hyphenation(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            hyphenation_paths: Some(Arc::new(SerdeCache::new(root)?)),
+            ..self
+        })
+    }
+
+    /// Adds Unicode Character Database files to this `DataSource`. The root should point to a
+    /// local directory or ZIP file with the contents of the UCD (see
+    /// [`UCD.zip`](https://www.unicode.org/Public/14.0.0/ucd/)), together with
+    /// `IdnaMappingTable.txt` from [UTS #46](https://www.unicode.org/Public/idna/14.0.0/)
+    /// and the UTS #39 [security files](https://www.unicode.org/Public/security/14.0.0/)
+    /// of the same version.
+    pub fn with_ucd(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            ucd_paths: Some(Arc::new(SerdeCache::new(root)?)),
+            ..self
+        })
+    }
+
     /// Adds CLDR data to this `DataSource`. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     pub fn with_cldr_for_tag(
@@ -166,6 +194,13 @@ impl SourceData {
             .ok_or(crate::error::MISSING_ICUEXPORT_ERROR)
     }
 
+    /// Path to hyphenation patterns.
+    pub(crate) fn hyphenation(&self) -> Result<&SerdeCache, DataError> {
+        self.hyphenation_paths
+            .as_deref()
+            .ok_or(crate::error::MISSING_HYPHENATION_ERROR)
+    }
+
     /// Path to segmenter data.
     pub(crate) fn segmenter(&self) -> Result<&SerdeCache, DataError> {
         Ok(&self.segmenter_paths)
@@ -175,6 +210,13 @@ impl SourceData {
         Ok(&self.segmenter_lstm_paths)
     }
 
+    /// Path to Unicode Character Database files that are not part of the ICU export.
+    pub(crate) fn ucd(&self) -> Result<&SerdeCache, DataError> {
+        self.ucd_paths
+            .as_deref()
+            .ok_or(crate::error::MISSING_UCD_ERROR)
+    }
+
     pub(crate) fn trie_type(&self) -> IcuTrieType {
         self.trie_type
     }
@@ -258,7 +300,7 @@ impl SerdeCache {
         parser: fn(&[u8]) -> Result<S, DataError>,
     ) -> Result<&S, DataError>
     where
-        for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
+        S: 'static + Send + Sync,
     {
         match self.cache.get(path) {
             Some(x) => x,
@@ -292,6 +334,31 @@ impl SerdeCache {
         })
     }
 
+    /// Reads a plain-text file, such as one in the semicolon-delimited format of the
+    /// Unicode Character Database, and parses it with the given function.
+    pub fn read_and_parse_txt<S>(
+        &self,
+        path: &str,
+        parser: fn(&str) -> Result<S, DataError>,
+    ) -> Result<&S, DataError>
+    where
+        S: 'static + Send + Sync,
+    {
+        match self.cache.get(path) {
+            Some(x) => x,
+            None => {
+                let buf = self.root.read_to_buf(path)?;
+                let text = std::str::from_utf8(&buf)
+                    .map_err(|e| DataError::custom("UTF-8").with_display_context(&e))
+                    .and_then(parser)
+                    .map_err(|e| e.with_path_context(&path))?;
+                self.cache.insert(path.to_string(), Box::new(text))
+            }
+        }
+        .downcast_ref::<S>()
+        .ok_or_else(|| DataError::custom("Cache error").with_type_context::<S>())
+    }
+
     pub fn list(&self, path: &str) -> Result<impl Iterator<Item = PathBuf>, DataError> {
         self.root.list(path)
     }
@@ -17,5 +17,6 @@ pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
 pub mod plurals;
+pub mod suppressions;
 pub mod time_zones;
 pub mod week_data;
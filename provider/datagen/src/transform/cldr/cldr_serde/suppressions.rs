@@ -0,0 +1,41 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON suppressions.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-segments-full/segments/en/suppressions.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Suppression {
+    pub suppression: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct SentenceBreak {
+    pub standard: Vec<Suppression>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Segmentations {
+    #[serde(rename = "SentenceBreak")]
+    pub sentence_break: SentenceBreak,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangSuppressions {
+    pub segmentations: Segmentations,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangSuppressions>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub segments: LangData,
+}
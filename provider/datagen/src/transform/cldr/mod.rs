@@ -13,5 +13,6 @@ pub mod fallback;
 pub mod list;
 pub mod locale_canonicalizer;
 pub mod plurals;
+pub mod segmenter;
 pub mod source;
 pub mod time_zones;
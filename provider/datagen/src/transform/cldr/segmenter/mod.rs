@@ -0,0 +1,91 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::*;
+use std::collections::BTreeSet;
+
+impl DataProvider<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<SentenceBreakSuppressionsV1Marker>, DataError> {
+        // CLDR has no suppressions for the root locale.
+        if req.locale.is_empty() {
+            return Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(DataPayload::from_owned(Default::default())),
+            });
+        }
+
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::suppressions::Resource = self
+            .source
+            .cldr()?
+            .segments()
+            .read_and_parse(&langid, "suppressions.json")?;
+
+        let suppressions = resource
+            .segments
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .segmentations
+            .sentence_break
+            .standard
+            .iter()
+            .map(|s| s.suppression.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(SentenceBreakSuppressionsV1 {
+                suppressions: suppressions.as_slice().into(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .segments()
+            .list_langs()?
+            .map(DataLocale::from)
+            .chain(core::iter::once(DataLocale::default()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<SentenceBreakSuppressionsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert!(data.get().suppressions.binary_search("Mr.").is_ok());
+        assert!(data.get().suppressions.binary_search("Mr").is_err());
+    }
+}
@@ -66,6 +66,13 @@ impl CldrCache {
         )
     }
 
+    pub fn segments(&self) -> CldrDirLang<'_> {
+        CldrDirLang(
+            &self.cache,
+            format!("cldr-segments-{}/segments", self.locale_subset),
+        )
+    }
+
     pub fn bcp47(&self) -> CldrDirNoLang<'_> {
         CldrDirNoLang(&self.cache, "cldr-bcp47/bcp47".to_string())
     }
@@ -148,6 +148,11 @@ mod tests {
             case_mapping.full_fold(uppercase_greek),
             case_mapping.full_fold(lowercase_greek)
         );
+        // Cherokee folds to uppercase.
+        assert_eq!(
+            case_mapping.full_fold("\u{13A0}\u{AB70}"),
+            "\u{13A0}\u{13A0}"
+        );
 
         let lowercase_turkish_1 = "istanbul, not constantınople";
         let uppercase_turkish_1 = "İSTANBUL, NOT CONSTANTINOPLE";
@@ -270,4 +275,327 @@ mod tests {
         assert_eq!(case_mapping.full_fold(initial), simple);
         assert_eq!(case_mapping.full_fold_turkic(initial), turkic);
     }
+
+    // These tests are taken from StringCaseTest::TestGreekUpper in ICU4C.
+    #[test]
+    fn test_greek_upper() {
+        let greek_locale = Locale::from_str("el").expect("Parsing was successful");
+        let case_mapping =
+            CaseMapping::try_new_with_locale(&crate::DatagenProvider::for_test(), &greek_locale)
+                .expect("Loading was successful");
+        let root_case_mapping = CaseMapping::try_new(&crate::DatagenProvider::for_test())
+            .expect("Loading was successful");
+
+        let assert_greek_upper = |s: &str, expected: &str| {
+            assert_eq!(case_mapping.to_full_uppercase(s), expected, "{}", s);
+            let utf16: Vec<u16> = s.encode_utf16().collect();
+            assert_eq!(
+                case_mapping.uppercase_utf16(&utf16).to_string(),
+                expected,
+                "{}",
+                s
+            );
+        };
+
+        // https://unicode-org.atlassian.net/browse/ICU-5456
+        assert_greek_upper("άδικος, κείμενο, ίριδα", "ΑΔΙΚΟΣ, ΚΕΙΜΕΝΟ, ΙΡΙΔΑ");
+        // https://bugzilla.mozilla.org/show_bug.cgi?id=307039
+        // https://bug307039.bmoattachments.org/attachment.cgi?id=194893
+        assert_greek_upper("Πατάτα", "ΠΑΤΑΤΑ");
+        assert_greek_upper("Αέρας, Μυστήριο, Ωραίο", "ΑΕΡΑΣ, ΜΥΣΤΗΡΙΟ, ΩΡΑΙΟ");
+        assert_greek_upper("Μαΐου, Πόρος, Ρύθμιση", "ΜΑΪΟΥ, ΠΟΡΟΣ, ΡΥΘΜΙΣΗ");
+        assert_greek_upper("ΰ, Τηρώ, Μάιος", "Ϋ, ΤΗΡΩ, ΜΑΪΟΣ");
+        assert_greek_upper("άυλος", "ΑΫΛΟΣ");
+        assert_greek_upper("ΑΫΛΟΣ", "ΑΫΛΟΣ");
+        assert_greek_upper(
+            "Άκλιτα ρήματα ή άκλιτες μετοχές",
+            "ΑΚΛΙΤΑ ΡΗΜΑΤΑ Ή ΑΚΛΙΤΕΣ ΜΕΤΟΧΕΣ",
+        );
+        // http://www.unicode.org/udhr/d/udhr_ell_monotonic.html
+        assert_greek_upper(
+            "Επειδή η αναγνώριση της αξιοπρέπειας",
+            "ΕΠΕΙΔΗ Η ΑΝΑΓΝΩΡΙΣΗ ΤΗΣ ΑΞΙΟΠΡΕΠΕΙΑΣ",
+        );
+        assert_greek_upper("νομικού ή διεθνούς", "ΝΟΜΙΚΟΥ Ή ΔΙΕΘΝΟΥΣ");
+        // http://unicode.org/udhr/d/udhr_ell_polytonic.html
+        assert_greek_upper("Ἐπειδὴ ἡ ἀναγνώριση", "ΕΠΕΙΔΗ Η ΑΝΑΓΝΩΡΙΣΗ");
+        assert_greek_upper("νομικοῦ ἢ διεθνοῦς", "ΝΟΜΙΚΟΥ Ή ΔΙΕΘΝΟΥΣ");
+        // From Google bug report
+        assert_greek_upper("Νέο, Δημιουργία", "ΝΕΟ, ΔΗΜΙΟΥΡΓΙΑ");
+        // http://crbug.com/234797
+        assert_greek_upper(
+            "Ελάτε να φάτε τα καλύτερα παϊδάκια!",
+            "ΕΛΑΤΕ ΝΑ ΦΑΤΕ ΤΑ ΚΑΛΥΤΕΡΑ ΠΑΪΔΑΚΙΑ!",
+        );
+        assert_greek_upper("Μαΐου, τρόλεϊ", "ΜΑΪΟΥ, ΤΡΟΛΕΪ");
+        assert_greek_upper("Το ένα ή το άλλο.", "ΤΟ ΕΝΑ Ή ΤΟ ΑΛΛΟ.");
+        // http://multilingualtypesetting.co.uk/blog/greek-typesetting-tips/
+        assert_greek_upper("ρωμέικα", "ΡΩΜΕΪΚΑ");
+        assert_greek_upper("ή.", "Ή.");
+
+        // The ypogegrammeni becomes a capital iota, and accents and breathing marks
+        // are removed, whether precomposed or combining.
+        assert_greek_upper("ᾅ ᾼ α\u{313}\u{301}\u{345}", "ΑΙ ΑΙ ΑΙ");
+        // A disjunctive eta keeps its tonos, also when it is combining.
+        assert_greek_upper("ή η\u{301}", "Ή Η\u{301}");
+        // but not when it has an ypogegrammeni or is part of a word.
+        assert_greek_upper("ῄ ήσουν", "ΗΙ ΗΣΟΥΝ");
+        // Combining accents lead to a combining dialytika.
+        assert_greek_upper("α\u{301}υλος", "ΑΥ\u{308}ΛΟΣ");
+        // Other characters are uppercased normally.
+        assert_greek_upper("abc ß", "ABC SS");
+
+        // Greek rules only apply to uppercasing in the Greek locale.
+        assert_eq!(root_case_mapping.to_full_uppercase("άδικος"), "ΆΔΙΚΟΣ");
+        assert_eq!(case_mapping.to_full_lowercase("ΆΔΙΚΟΣ"), "άδικος");
+    }
+
+    #[test]
+    fn test_writeable() {
+        use writeable::{LengthHint, Writeable};
+
+        let case_mapping = CaseMapping::try_new(&crate::DatagenProvider::for_test())
+            .expect("Loading was successful");
+        let turkish_case_mapping = CaseMapping::try_new_with_locale(
+            &crate::DatagenProvider::for_test(),
+            &Locale::from_str("tr").expect("Parsing was successful"),
+        )
+        .expect("Loading was successful");
+
+        for text in [
+            "",
+            "abc",
+            "aBIΣßΣ/\u{5ffff}",
+            "Süßmayrstraße",
+            "\u{1c7}\u{1c8}\u{1c9}",
+            "ΙΕΣΥΣ ΧΡΙΣΤΟΣ",
+            "I İ I\u{307} I\u{327}\u{307}",
+            "Aßµ\u{fb03}\u{1040c}İı",
+            "\u{2126}\u{212a}\u{23a}",
+        ] {
+            let utf16: Vec<u16> = text.encode_utf16().collect();
+            for (writeables, expected) in [
+                (
+                    [
+                        case_mapping.lowercase(text),
+                        case_mapping.lowercase_utf8(text.as_bytes()),
+                        case_mapping.lowercase_utf16(&utf16),
+                    ],
+                    case_mapping.to_full_lowercase(text),
+                ),
+                (
+                    [
+                        case_mapping.uppercase(text),
+                        case_mapping.uppercase_utf8(text.as_bytes()),
+                        case_mapping.uppercase_utf16(&utf16),
+                    ],
+                    case_mapping.to_full_uppercase(text),
+                ),
+                (
+                    [
+                        case_mapping.case_fold(text),
+                        case_mapping.case_fold_utf8(text.as_bytes()),
+                        case_mapping.case_fold_utf16(&utf16),
+                    ],
+                    case_mapping.full_fold(text),
+                ),
+                (
+                    [
+                        turkish_case_mapping.lowercase(text),
+                        turkish_case_mapping.lowercase_utf8(text.as_bytes()),
+                        turkish_case_mapping.lowercase_utf16(&utf16),
+                    ],
+                    turkish_case_mapping.to_full_lowercase(text),
+                ),
+            ] {
+                for writeable in writeables {
+                    assert_eq!(writeable.write_to_string(), expected, "{:?}", text);
+                    assert_eq!(
+                        writeable.writeable_length_hint(),
+                        LengthHint::exact(expected.len()),
+                        "{:?}",
+                        text
+                    );
+                }
+            }
+        }
+
+        // Writing into an existing buffer
+        let mut buffer = String::from("Name: ");
+        case_mapping
+            .uppercase("straße")
+            .write_to(&mut buffer)
+            .expect("Writing to a String succeeds");
+        assert_eq!(buffer, "Name: STRASSE");
+        assert_eq!(format!("{}", case_mapping.lowercase("ΑΣ")), "ας");
+
+        // Ill-formed input
+        assert_eq!(
+            case_mapping
+                .uppercase_utf8(b"a\xFFb\xE2\x82c")
+                .write_to_string(),
+            "A\u{FFFD}B\u{FFFD}C"
+        );
+        assert_eq!(
+            case_mapping
+                .lowercase_utf16(&[0x41, 0xD800, 0x3A3, 0xDC00])
+                .write_to_string(),
+            "a\u{FFFD}\u{3c3}\u{FFFD}"
+        );
+    }
+
+    #[test]
+    fn test_full_titlecase() {
+        use icu_casemapping::titlecase::{
+            LeadingAdjustment, TitlecaseMapper, TitlecaseOptions, TrailingCase,
+        };
+        use icu_segmenter::WordBreakSegmenter;
+        use writeable::Writeable;
+
+        let provider = crate::DatagenProvider::for_test();
+        let segmenter =
+            WordBreakSegmenter::try_new_unstable(&provider).expect("Loading was successful");
+        let mapper = TitlecaseMapper::try_new(&provider).expect("Loading was successful");
+        let dutch_mapper = TitlecaseMapper::try_new_with_locale(
+            &provider,
+            &Locale::from_str("nl").expect("Parsing was successful"),
+        )
+        .expect("Loading was successful");
+        let turkish_mapper = TitlecaseMapper::try_new_with_locale(
+            &provider,
+            &Locale::from_str("tr").expect("Parsing was successful"),
+        )
+        .expect("Loading was successful");
+
+        let default_options = TitlecaseOptions::default();
+        let title_with = |mapper: &TitlecaseMapper, s: &str, options| {
+            mapper
+                .to_full_titlecase(s, &segmenter, options)
+                .write_to_string()
+                .into_owned()
+        };
+        let title = |mapper: &TitlecaseMapper, s: &str| title_with(mapper, s, default_options);
+
+        assert_eq!(title(&mapper, "hello WORLD"), "Hello World");
+        assert_eq!(title(&mapper, "don't stop"), "Don't Stop");
+        assert_eq!(title(&mapper, "'twas the night"), "'Twas The Night");
+        assert_eq!(title(&mapper, "3rd PLACE"), "3rd Place");
+        // Titlecase letters and full mappings
+        assert_eq!(title(&mapper, "\u{1c6}ungla"), "\u{1c5}ungla");
+        assert_eq!(title(&mapper, "\u{fb00}ord"), "Fford");
+        assert_eq!(title(&mapper, "ΣΊΣΥΦΟΣ"), "Σίσυφος");
+        // Locale-sensitive
+        assert_eq!(title(&mapper, "istanbul"), "Istanbul");
+        assert_eq!(title(&turkish_mapper, "istanbul"), "İstanbul");
+        assert_eq!(title(&turkish_mapper, "IRMAK"), "Irmak");
+
+        // Dutch IJ
+        assert_eq!(title(&mapper, "ijssel"), "Ijssel");
+        assert_eq!(
+            title(&dutch_mapper, "ijssel igloo IJMUIDEN"),
+            "IJssel Igloo IJmuiden"
+        );
+        assert_eq!(title(&dutch_mapper, "ijssel"), "IJssel");
+        assert_eq!(title(&dutch_mapper, "íj́ssel"), "ÍJ́ssel");
+        assert_eq!(
+            title(&dutch_mapper, "i\u{301}j\u{301}ssel"),
+            "I\u{301}J\u{301}ssel"
+        );
+        assert_eq!(title(&dutch_mapper, "íjssel"), "Íjssel");
+        assert_eq!(title(&dutch_mapper, "ij\u{308}"), "Ij\u{308}");
+
+        // Options
+        let mut options = TitlecaseOptions::default();
+        options.trailing_case = TrailingCase::Unchanged;
+        assert_eq!(title_with(&mapper, "hello wORLD", options), "Hello WORLD");
+        let mut options = TitlecaseOptions::default();
+        options.leading_adjustment = LeadingAdjustment::ToCased;
+        assert_eq!(title_with(&mapper, "3rd PLACE", options), "3Rd Place");
+        let mut options = TitlecaseOptions::default();
+        options.leading_adjustment = LeadingAdjustment::None;
+        assert_eq!(title_with(&mapper, "3rd PLACE", options), "3rd Place");
+
+        // Caller-provided boundaries
+        let title_with_boundaries = |s: &str, boundaries: &[usize]| {
+            mapper
+                .to_full_titlecase_with_boundaries(s, boundaries.iter().copied(), default_options)
+                .write_to_string()
+                .into_owned()
+        };
+        assert_eq!(title_with_boundaries("abc def", &[2, 4]), "AbC Def");
+        assert_eq!(title_with_boundaries("äbc", &[1, 2]), "ÄBc");
+        // Numbers, symbols and private use characters start titlecasing, but punctuation
+        // and marks don't.
+        assert_eq!(title_with_boundaries("\u{2460}ABC", &[]), "\u{2460}abc");
+        assert_eq!(title_with_boundaries("$ABC", &[]), "$abc");
+        assert_eq!(title_with_boundaries("\u{e000}ABC", &[]), "\u{e000}abc");
+        assert_eq!(title_with_boundaries("(abc)", &[]), "(Abc)");
+        assert_eq!(title_with_boundaries("\u{301}abc", &[]), "\u{301}Abc");
+    }
+
+    #[test]
+    fn test_caseless_matching() {
+        use core::cmp::Ordering;
+        use icu_casemapping::CaselessMatcher;
+
+        let provider = crate::DatagenProvider::for_test();
+        let case_mapping = CaseMapping::try_new(&provider).expect("Loading was successful");
+        let matcher = CaselessMatcher::try_new_unstable(&provider).unwrap();
+
+        // Default caseless matching
+        assert!(case_mapping.eq_ignore_case("Straße", "STRASSE"));
+        assert!(case_mapping.eq_ignore_case("\u{1C5}ungla", "\u{1C4}UNGLA"));
+        assert!(case_mapping.eq_ignore_case("ﬁle", "FILE"));
+        assert!(case_mapping.eq_ignore_case("", ""));
+        assert!(!case_mapping.eq_ignore_case("Straße", "STRASS"));
+        assert!(!case_mapping.eq_ignore_case("é", "e\u{301}"));
+        assert_eq!(
+            case_mapping.cmp_ignore_case("Straße", "STRASSE"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            case_mapping.cmp_ignore_case("apple", "BANANA"),
+            Ordering::Less
+        );
+        assert_eq!(case_mapping.cmp_ignore_case("ß", "SR"), Ordering::Greater);
+        assert_eq!(case_mapping.cmp_ignore_case("ss", "SSA"), Ordering::Less);
+
+        // Canonical caseless matching
+        assert!(matcher.canonical_caseless_eq("é", "E\u{301}"));
+        assert!(matcher.canonical_caseless_eq("\u{212B}", "å"));
+        assert!(matcher.canonical_caseless_eq("A\u{30A}", "å"));
+        // U+0345 COMBINING GREEK YPOGEGRAMMENI folds to ι, which is only found
+        // after decomposing U+1FB3 GREEK SMALL LETTER ALPHA WITH YPOGEGRAMMENI
+        assert!(matcher.canonical_caseless_eq("\u{1FB3}", "α\u{345}"));
+        assert!(matcher.canonical_caseless_eq("\u{1FBC}", "ΑΙ"));
+        assert!(!matcher.canonical_caseless_eq("①", "1"));
+        assert_eq!(matcher.canonical_caseless_key("Éclair"), "e\u{301}clair");
+
+        // Compatibility caseless matching
+        assert!(matcher.compatibility_caseless_eq("①", "1"));
+        assert!(matcher.compatibility_caseless_eq("ｆｏｏ", "FOO"));
+        assert!(matcher.compatibility_caseless_eq("㎒", "MHZ"));
+        assert!(matcher.compatibility_caseless_eq("Ǆ", "dz\u{30C}"));
+        assert!(!matcher.compatibility_caseless_eq("a", "b"));
+        assert_eq!(matcher.compatibility_caseless_key("ﬁ①"), "fi1");
+        assert!(!matcher.compatibility_caseless_eq("A\u{00AD}B", "ab"));
+
+        // Identifier caseless matching
+        assert!(matcher.identifier_caseless_eq("A\u{00AD}B", "ab"));
+        assert!(matcher.identifier_caseless_eq("ﬁ\u{FE0F}", "FI"));
+        assert!(matcher.identifier_caseless_eq("Ǆ", "dž"));
+        assert!(matcher.identifier_caseless_eq("\u{1FB3}", "ΑΙ"));
+        assert!(!matcher.identifier_caseless_eq("a", "b"));
+        assert_eq!(matcher.nfkc_casefold("Straße"), "strasse");
+        assert_eq!(matcher.nfkc_casefold("Ⅻ\u{00AD}㎒"), "xiimhz");
+        assert_eq!(matcher.nfkc_casefold("E\u{301}"), "é");
+        assert_eq!(
+            matcher.nfkc_casefold("\u{AB70}\u{13A0}"),
+            "\u{13A0}\u{13A0}"
+        );
+        // U+0345 folds to ι after the accent that follows it in NFD.
+        assert_eq!(matcher.nfkc_casefold("ά\u{345}\u{301}"), "ά\u{301}ι");
+        assert_eq!(matcher.nfkc_casefold("\u{37A}\u{301}"), " ί");
+        assert_eq!(matcher.nfkc_casefold(""), "");
+    }
 }
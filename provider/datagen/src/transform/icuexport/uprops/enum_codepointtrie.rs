@@ -9,7 +9,7 @@ use icu_provider::datagen::*;
 use icu_provider::prelude::*;
 use std::convert::TryFrom;
 
-fn get_enumerated<'a>(
+pub(super) fn get_enumerated<'a>(
     source: &'a SourceData,
     key: &str,
 ) -> Result<&'a super::uprops_serde::enumerated::EnumeratedPropertyMap, DataError> {
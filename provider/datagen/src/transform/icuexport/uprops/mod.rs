@@ -7,5 +7,6 @@
 
 mod bin_uniset;
 mod enum_codepointtrie;
+mod names;
 mod script;
 mod uprops_serde;
@@ -0,0 +1,416 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers for the names of the values of enumerated properties, which are taken from
+//! `PropertyValueAliases.txt`. The values of the names are the ones of the code points with
+//! them in the ICU export, or the constants of `icu_properties` for names that no code point
+//! has and for Joining_Type, which is read from the UCD.
+
+#![cfg(feature = "experimental")]
+
+use super::enum_codepointtrie::get_enumerated;
+use crate::transform::ucd::props::parse_joining_type;
+use icu_properties::names::loose_matching_chars;
+use icu_properties::provider::*;
+use icu_properties::{GraphemeClusterBreak, Script, WordBreak};
+use icu_provider::datagen::*;
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use zerovec::{VarZeroVec, ZeroMap};
+
+/// The names of a value in `PropertyValueAliases.txt`.
+struct ValueAliases {
+    /// The short name, the long name and any other aliases.
+    names: Vec<String>,
+    /// The short names of the values in a grouping of General_Category values.
+    members: Vec<String>,
+}
+
+/// Parses `PropertyValueAliases.txt` into the aliases of the values of each property.
+fn parse_property_value_aliases(
+    text: &str,
+) -> Result<BTreeMap<String, Vec<ValueAliases>>, DataError> {
+    let mut properties = BTreeMap::<String, Vec<ValueAliases>>::new();
+    for line in text.lines() {
+        let (line, comment) = line.split_once('#').unwrap_or((line, ""));
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';').map(str::trim);
+        let property = fields.next().unwrap_or_default();
+        // The numeric values of Canonical_Combining_Class are matched like names too.
+        let numeric = if property == "ccc" {
+            fields.next()
+        } else {
+            None
+        };
+        let mut names: Vec<String> = fields.map(String::from).collect();
+        if names.len() < 2 {
+            return Err(DataError::custom("Property value aliases line").with_display_context(line));
+        }
+        names.extend(numeric.map(String::from));
+        let aliases = ValueAliases {
+            names,
+            members: comment
+                .split('|')
+                .map(str::trim)
+                .filter(|member| !member.is_empty())
+                .map(String::from)
+                .collect(),
+        };
+        properties
+            .entry(property.to_string())
+            .or_default()
+            .push(aliases);
+    }
+    Ok(properties)
+}
+
+/// The value of a name in `PropertyValueAliases.txt` that no code point has in the ICU export.
+fn value_without_code_points(property: &str, short_name: &str) -> Option<u32> {
+    Some(match (property, short_name) {
+        ("sc", "Hrkt") => Script::KatakanaOrHiragana.0 as u32,
+        ("GCB", "EB") => GraphemeClusterBreak::EBase.0 as u32,
+        ("GCB", "EBG") => GraphemeClusterBreak::EBaseGAZ.0 as u32,
+        ("GCB", "EM") => GraphemeClusterBreak::EModifier.0 as u32,
+        ("GCB", "GAZ") => GraphemeClusterBreak::GlueAfterZwj.0 as u32,
+        ("WB", "EB") => WordBreak::EBase.0 as u32,
+        ("WB", "EBG") => WordBreak::EBaseGAZ.0 as u32,
+        ("WB", "EM") => WordBreak::EModifier.0 as u32,
+        ("WB", "GAZ") => WordBreak::GlueAfterZwj.0 as u32,
+        _ => return None,
+    })
+}
+
+impl crate::DatagenProvider {
+    /// The aliases of the values of `property`, with the value.
+    fn get_value_aliases(&self, property: &str) -> Result<Vec<(&ValueAliases, u32)>, DataError> {
+        let values: BTreeMap<&str, u32> = if property == "jt" {
+            BTreeMap::new()
+        } else {
+            get_enumerated(&self.source, property)?
+                .ranges
+                .iter()
+                .map(|range| (range.name.as_str(), range.v))
+                .collect()
+        };
+        self.source
+            .ucd()?
+            .read_and_parse_txt("PropertyValueAliases.txt", parse_property_value_aliases)?
+            .get(property)
+            .ok_or_else(|| {
+                DataError::custom("Missing property value aliases").with_display_context(property)
+            })?
+            .iter()
+            // The groupings of General_Category values are not values.
+            .filter(|aliases| aliases.members.is_empty())
+            .map(|aliases| {
+                let short_name = aliases.names[0].as_str();
+                let value = if property == "ccc" {
+                    // The numeric value is the last name.
+                    aliases.names.last().and_then(|name| name.parse().ok())
+                } else if property == "jt" {
+                    parse_joining_type(short_name).ok().map(u32::from)
+                } else {
+                    values
+                        .get(short_name)
+                        .copied()
+                        .or_else(|| value_without_code_points(property, short_name))
+                };
+                let value = value.ok_or_else(|| {
+                    DataError::custom("Missing property value")
+                        .with_display_context(&format!("{}={}", property, short_name))
+                })?;
+                Ok((aliases, value))
+            })
+            .collect()
+    }
+
+    /// The map from the loosely matched names of `(aliases, value)` pairs to the values.
+    fn get_name_to_enum_map<'a>(
+        &self,
+        property: &str,
+        values: impl Iterator<Item = (&'a ValueAliases, u32)>,
+    ) -> Result<PropertyValueNameToEnumMapV1<'static>, DataError> {
+        let mut map = BTreeMap::new();
+        for (aliases, value) in values {
+            for name in &aliases.names {
+                match map.insert(loose_matching_chars(name).collect::<String>(), value) {
+                    Some(other) if other != value => {
+                        return Err(DataError::custom("Ambiguous property value name")
+                            .with_display_context(&format!("{}={}", property, name)))
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Ok(PropertyValueNameToEnumMapV1 {
+            map: map
+                .iter()
+                .map(|(name, value)| (name.as_str(), value))
+                .collect::<ZeroMap<_, _>>(),
+        })
+    }
+
+    /// The map from the values of `property` to the names at `index` of their aliases.
+    fn get_enum_to_name_map(
+        &self,
+        property: &str,
+        index: usize,
+    ) -> Result<PropertyEnumToValueNameMapV1<'static>, DataError> {
+        let mut names = Vec::<&str>::new();
+        let aliases = self.get_value_aliases(property)?;
+        for &(aliases, value) in &aliases {
+            let value = value as usize;
+            if names.len() <= value {
+                names.resize(value + 1, "");
+            }
+            names[value] = &aliases.names[index];
+        }
+        Ok(PropertyEnumToValueNameMapV1 {
+            names: VarZeroVec::from(&names),
+        })
+    }
+}
+
+macro_rules! expand {
+    ($(($name_to_enum:ident, $short:ident, $long:ident, $prop_name:literal)),+,) => {
+        $(
+            impl DataProvider<$name_to_enum> for crate::DatagenProvider {
+                fn load(&self, _: DataRequest) -> Result<DataResponse<$name_to_enum>, DataError> {
+                    let aliases = self.get_value_aliases($prop_name)?;
+                    let data_struct =
+                        self.get_name_to_enum_map($prop_name, aliases.into_iter())?;
+                    Ok(DataResponse {
+                        metadata: DataResponseMetadata::default(),
+                        payload: Some(DataPayload::from_owned(data_struct)),
+                    })
+                }
+            }
+
+            impl IterableDataProvider<$name_to_enum> for crate::DatagenProvider {
+                fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                    self.get_value_aliases($prop_name)?;
+                    Ok(vec![Default::default()])
+                }
+            }
+
+            impl DataProvider<$short> for crate::DatagenProvider {
+                fn load(&self, _: DataRequest) -> Result<DataResponse<$short>, DataError> {
+                    let data_struct = self.get_enum_to_name_map($prop_name, 0)?;
+                    Ok(DataResponse {
+                        metadata: DataResponseMetadata::default(),
+                        payload: Some(DataPayload::from_owned(data_struct)),
+                    })
+                }
+            }
+
+            impl IterableDataProvider<$short> for crate::DatagenProvider {
+                fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                    self.get_value_aliases($prop_name)?;
+                    Ok(vec![Default::default()])
+                }
+            }
+
+            impl DataProvider<$long> for crate::DatagenProvider {
+                fn load(&self, _: DataRequest) -> Result<DataResponse<$long>, DataError> {
+                    let data_struct = self.get_enum_to_name_map($prop_name, 1)?;
+                    Ok(DataResponse {
+                        metadata: DataResponseMetadata::default(),
+                        payload: Some(DataPayload::from_owned(data_struct)),
+                    })
+                }
+            }
+
+            impl IterableDataProvider<$long> for crate::DatagenProvider {
+                fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                    self.get_value_aliases($prop_name)?;
+                    Ok(vec![Default::default()])
+                }
+            }
+        )+
+    };
+}
+
+expand!(
+    (
+        CanonicalCombiningClassNameToValueV1Marker,
+        CanonicalCombiningClassValueToShortNameV1Marker,
+        CanonicalCombiningClassValueToLongNameV1Marker,
+        "ccc"
+    ),
+    (
+        GeneralCategoryNameToValueV1Marker,
+        GeneralCategoryValueToShortNameV1Marker,
+        GeneralCategoryValueToLongNameV1Marker,
+        "gc"
+    ),
+    (
+        BidiClassNameToValueV1Marker,
+        BidiClassValueToShortNameV1Marker,
+        BidiClassValueToLongNameV1Marker,
+        "bc"
+    ),
+    (
+        ScriptNameToValueV1Marker,
+        ScriptValueToShortNameV1Marker,
+        ScriptValueToLongNameV1Marker,
+        "sc"
+    ),
+    (
+        EastAsianWidthNameToValueV1Marker,
+        EastAsianWidthValueToShortNameV1Marker,
+        EastAsianWidthValueToLongNameV1Marker,
+        "ea"
+    ),
+    (
+        LineBreakNameToValueV1Marker,
+        LineBreakValueToShortNameV1Marker,
+        LineBreakValueToLongNameV1Marker,
+        "lb"
+    ),
+    (
+        GraphemeClusterBreakNameToValueV1Marker,
+        GraphemeClusterBreakValueToShortNameV1Marker,
+        GraphemeClusterBreakValueToLongNameV1Marker,
+        "GCB"
+    ),
+    (
+        WordBreakNameToValueV1Marker,
+        WordBreakValueToShortNameV1Marker,
+        WordBreakValueToLongNameV1Marker,
+        "WB"
+    ),
+    (
+        SentenceBreakNameToValueV1Marker,
+        SentenceBreakValueToShortNameV1Marker,
+        SentenceBreakValueToLongNameV1Marker,
+        "SB"
+    ),
+    (
+        JoiningTypeNameToValueV1Marker,
+        JoiningTypeValueToShortNameV1Marker,
+        JoiningTypeValueToLongNameV1Marker,
+        "jt"
+    ),
+);
+
+impl DataProvider<GeneralCategoryMaskNameToValueV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _: DataRequest,
+    ) -> Result<DataResponse<GeneralCategoryMaskNameToValueV1Marker>, DataError> {
+        let aliases = self
+            .source
+            .ucd()?
+            .read_and_parse_txt("PropertyValueAliases.txt", parse_property_value_aliases)?
+            .get("gc")
+            .ok_or_else(|| DataError::custom("Missing property value aliases"))?;
+        let values: BTreeMap<&str, u32> = self
+            .get_value_aliases("gc")?
+            .into_iter()
+            .map(|(aliases, value)| (aliases.names[0].as_str(), value))
+            .collect();
+        // The masks of single values have the bit of the value, the ones of groupings have the
+        // bits of their members.
+        let mut masks = Vec::new();
+        for aliases in aliases {
+            let mask = if aliases.members.is_empty() {
+                values.get(aliases.names[0].as_str()).map(|&v| 1 << v)
+            } else {
+                aliases
+                    .members
+                    .iter()
+                    .map(|member| values.get(member.as_str()).map(|&v| 1 << v))
+                    .sum()
+            };
+            if let Some(mask) = mask {
+                masks.push((aliases, mask));
+            }
+        }
+        let data_struct = self.get_name_to_enum_map("gcm", masks.into_iter())?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<GeneralCategoryMaskNameToValueV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        get_enumerated(&self.source, "gc")?;
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_properties::{GeneralCategory, GeneralCategoryGroup, LineBreak, Script};
+
+    #[test]
+    fn test_aliases_syntax() {
+        let aliases = parse_property_value_aliases(
+            "# Comment\n\nccc ; 230 ; A ; Above\ngc ; L ; Letter # Ll | Lm | Lo | Lt | Lu\n",
+        )
+        .unwrap();
+        let ccc = &aliases["ccc"][0];
+        assert_eq!(ccc.names, ["A", "Above", "230"]);
+        assert!(ccc.members.is_empty());
+        let gc = &aliases["gc"][0];
+        assert_eq!(gc.names, ["L", "Letter"]);
+        assert_eq!(gc.members, ["Ll", "Lm", "Lo", "Lt", "Lu"]);
+        assert!(parse_property_value_aliases("sc ; Latn\n").is_err());
+    }
+
+    #[test]
+    fn test_names() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<ScriptNameToValueV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let map = &payload.get().map;
+        assert_eq!(map.get_copied("latn"), Some(Script::Latin.0 as u32));
+        assert_eq!(map.get_copied("latin"), Some(Script::Latin.0 as u32));
+        assert_eq!(map.get_copied("Latin"), None);
+        // No code point has this script.
+        assert_eq!(
+            map.get_copied("katakanaorhiragana"),
+            Some(Script::KatakanaOrHiragana.0 as u32)
+        );
+
+        let payload: DataPayload<CanonicalCombiningClassNameToValueV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        assert_eq!(payload.get().map.get_copied("ccc133"), Some(133));
+        assert_eq!(payload.get().map.get_copied("133"), Some(133));
+
+        let payload: DataPayload<LineBreakValueToLongNameV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        assert_eq!(
+            payload.get().names.get(LineBreak::InfixNumeric.0 as usize),
+            Some("Infix_Numeric")
+        );
+
+        let payload: DataPayload<GeneralCategoryMaskNameToValueV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let map = &payload.get().map;
+        assert_eq!(
+            map.get_copied("casedletter")
+                .map(GeneralCategoryGroup::from),
+            Some(GeneralCategoryGroup::CasedLetter)
+        );
+        assert_eq!(
+            map.get_copied("lu").map(GeneralCategoryGroup::from),
+            Some(GeneralCategory::UppercaseLetter.into())
+        );
+    }
+}
@@ -6,6 +6,7 @@ pub mod cldr;
 pub mod icuexport;
 #[cfg(feature = "experimental")]
 pub mod segmenter;
+pub mod ucd;
 
 use icu_provider::datagen::*;
 use icu_provider::hello_world::*;
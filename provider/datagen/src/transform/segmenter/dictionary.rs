@@ -0,0 +1,313 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Building segmenter dictionaries from word lists.
+//!
+//! The dictionaries are serialized in the format of ICU4C's `UCharsTrie`, which can be read
+//! with [`Char16Trie`](icu_collections::char16trie::Char16Trie). Unlike ICU4C's builder,
+//! this doesn't share identical sub-tries, which only makes the output somewhat larger.
+
+use icu_provider::DataError;
+use icu_segmenter::provider::UCharDictionaryBreakDataV1;
+use std::collections::BTreeMap;
+use zerovec::ZeroVec;
+
+// See components/collections/src/char16trie/trie.rs for the reader of this format.
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MAX_ONE_UNIT_VALUE: u32 = 0x3fff;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+const MAX_ONE_UNIT_NODE_VALUE: u32 = 0xfe;
+const MAX_TWO_UNIT_NODE_VALUE: u32 = 0xfd_ffff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 = 0x4040;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+const MAX_ONE_UNIT_DELTA: usize = 0xfbff;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = 0xfc00;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+
+/// Builds dictionary data for the segmenter from a plain word-frequency list.
+///
+/// Each line of `word_list` contains a word, optionally followed by whitespace and its
+/// frequency as a non-negative integer. Empty lines and lines starting with `#` are
+/// ignored. The frequency is stored as the value of the word and defaults to 0; the
+/// frequencies of repeated words are added up.
+///
+/// The result can be added to a word segmenter as a user dictionary, which is used in
+/// addition to the built-in dictionaries.
+///
+/// # Examples
+///
+/// ```
+/// use icu_collections::char16trie::{Char16Trie, TrieResult};
+///
+/// let dictionary = icu_datagen::build_segmenter_dictionary("東京都\t120\n都庁\t30\n")
+///     .expect("valid word list");
+///
+/// let trie = Char16Trie::new(dictionary.trie_data);
+/// let mut iter = trie.iter();
+/// assert_eq!(iter.next('都'), TrieResult::NoValue);
+/// assert_eq!(iter.next('庁'), TrieResult::FinalValue(30));
+/// ```
+pub fn build_segmenter_dictionary(
+    word_list: &str,
+) -> Result<UCharDictionaryBreakDataV1<'static>, DataError> {
+    let mut words = BTreeMap::<Vec<u16>, u32>::new();
+    for line in word_list.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let word = fields.next().unwrap_or_default();
+        let frequency = match fields.next() {
+            Some(frequency) => frequency
+                .parse::<u32>()
+                .ok()
+                .filter(|&f| f <= i32::MAX as u32)
+                .ok_or_else(|| {
+                    DataError::custom("Invalid word frequency").with_display_context(line)
+                })?,
+            None => 0,
+        };
+        if fields.next().is_some() {
+            return Err(DataError::custom("Invalid word list line").with_display_context(line));
+        }
+        let value = words.entry(word.encode_utf16().collect()).or_default();
+        *value = value.saturating_add(frequency).min(i32::MAX as u32);
+    }
+    if words.is_empty() {
+        return Err(DataError::custom("Empty word list"));
+    }
+
+    let entries: Vec<(Vec<u16>, u32)> = words.into_iter().collect();
+    Ok(UCharDictionaryBreakDataV1 {
+        trie_data: ZeroVec::alloc_from_slice(&write_node(&entries, 0)),
+    })
+}
+
+/// Serializes the node for the entries sharing the first `depth` units, which must be
+/// sorted by their units.
+fn write_node(entries: &[(Vec<u16>, u32)], depth: usize) -> Vec<u16> {
+    let (value, rest) = match entries.split_first() {
+        Some(((word, value), rest)) if word.len() == depth => (Some(*value), rest),
+        _ => (None, entries),
+    };
+    let (first, last) = match (rest.first(), rest.last()) {
+        (Some((first, _)), Some((last, _))) => (first, last),
+        // Only the word ending here is left.
+        _ => return encode_value(value.unwrap_or_default(), VALUE_IS_FINAL),
+    };
+
+    let mut out = Vec::new();
+    if first[depth] == last[depth] {
+        // All remaining words continue with the same units: linear-match node.
+        let length = first[depth..]
+            .iter()
+            .zip(&last[depth..])
+            .take_while(|(a, b)| a == b)
+            .count()
+            .min(MAX_LINEAR_MATCH_LENGTH);
+        push_node_lead(&mut out, MIN_LINEAR_MATCH + length as u16 - 1, value);
+        out.extend_from_slice(&first[depth..depth + length]);
+        out.extend(write_node(rest, depth + length));
+    } else {
+        let mut groups: Vec<&[(Vec<u16>, u32)]> = Vec::new();
+        let mut start = 0;
+        for i in 1..=rest.len() {
+            if i == rest.len() || rest[i].0[depth] != rest[start].0[depth] {
+                groups.push(&rest[start..i]);
+                start = i;
+            }
+        }
+        // Branch node, storing the number of branches minus one.
+        let length = groups.len() - 1;
+        if length < MIN_LINEAR_MATCH as usize {
+            push_node_lead(&mut out, length as u16, value);
+        } else {
+            push_node_lead(&mut out, 0, value);
+            out.push(length as u16);
+        }
+        out.extend(write_branch_sub_node(&groups, depth));
+    }
+    out
+}
+
+/// Serializes a binary search over the branches, dropping down to a linear list for the
+/// last few branches.
+fn write_branch_sub_node(groups: &[&[(Vec<u16>, u32)]], depth: usize) -> Vec<u16> {
+    let mut out = Vec::new();
+    if groups.len() > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Units less than the middle one jump past the other half.
+        let middle = groups.len() / 2;
+        let less = write_branch_sub_node(&groups[..middle], depth);
+        let greater_or_equal = write_branch_sub_node(&groups[middle..], depth);
+        out.push(groups[middle][0].0[depth]);
+        push_delta(&mut out, greater_or_equal.len());
+        out.extend(greater_or_equal);
+        out.extend(less);
+        return out;
+    }
+
+    // Each branch but the last is a unit followed by either the final value of a word
+    // ending there, or the jump delta to its node as a non-final value. The last branch's
+    // node directly follows its unit, and the other nodes follow that.
+    let (last, groups) = match groups.split_last() {
+        Some(split) => split,
+        None => return out,
+    };
+    let mut tail = vec![last[0].0[depth]];
+    tail.extend(write_node(last, depth + 1));
+    let mut nodes = Vec::new();
+    let mut node_offsets = Vec::new();
+    for group in groups {
+        if group.len() == 1 && group[0].0.len() == depth + 1 {
+            node_offsets.push(None);
+        } else {
+            node_offsets.push(Some(nodes.len()));
+            nodes.extend(write_node(group, depth + 1));
+        }
+    }
+
+    let mut entries = Vec::new();
+    for (group, offset) in groups.iter().zip(node_offsets).rev() {
+        let mut entry = vec![group[0].0[depth]];
+        match offset {
+            Some(offset) => entry.extend(encode_value(
+                (entries.len() + tail.len() + offset) as u32,
+                0,
+            )),
+            None => entry.extend(encode_value(group[0].1, VALUE_IS_FINAL)),
+        }
+        entry.extend(entries);
+        entries = entry;
+    }
+    out.extend(entries);
+    out.extend(tail);
+    out.extend(nodes);
+    out
+}
+
+/// Encodes a value, or a jump delta within a linear branch list.
+fn encode_value(value: u32, flag: u16) -> Vec<u16> {
+    if value <= MAX_ONE_UNIT_VALUE {
+        vec![flag | value as u16]
+    } else if value < ((THREE_UNIT_VALUE_LEAD - MIN_TWO_UNIT_VALUE_LEAD) as u32) << 16 {
+        vec![
+            flag | (MIN_TWO_UNIT_VALUE_LEAD + (value >> 16) as u16),
+            value as u16,
+        ]
+    } else {
+        vec![
+            flag | THREE_UNIT_VALUE_LEAD,
+            (value >> 16) as u16,
+            value as u16,
+        ]
+    }
+}
+
+/// Pushes the lead unit of a node, with the optional value of a word ending before it.
+fn push_node_lead(out: &mut Vec<u16>, node: u16, value: Option<u32>) {
+    match value {
+        None => out.push(node),
+        Some(value) if value <= MAX_ONE_UNIT_NODE_VALUE => {
+            out.push(((value as u16 + 1) << 6) | node)
+        }
+        Some(value) if value <= MAX_TWO_UNIT_NODE_VALUE => {
+            out.push((MIN_TWO_UNIT_NODE_VALUE_LEAD + (((value >> 16) as u16) << 6)) | node);
+            out.push(value as u16);
+        }
+        Some(value) => {
+            out.push(THREE_UNIT_NODE_VALUE_LEAD | node);
+            out.push((value >> 16) as u16);
+            out.push(value as u16);
+        }
+    }
+}
+
+/// Pushes a jump delta of a binary search branch.
+fn push_delta(out: &mut Vec<u16>, delta: usize) {
+    if delta <= MAX_ONE_UNIT_DELTA {
+        out.push(delta as u16);
+    } else if delta < ((THREE_UNIT_DELTA_LEAD - MIN_TWO_UNIT_DELTA_LEAD) as usize) << 16 {
+        out.push(MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16);
+        out.push(delta as u16);
+    } else {
+        out.push(THREE_UNIT_DELTA_LEAD);
+        out.push((delta >> 16) as u16);
+        out.push(delta as u16);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_collections::char16trie::{Char16Trie, TrieResult};
+
+    fn lookup(trie: &Char16Trie, word: &str) -> TrieResult {
+        let mut iter = trie.iter();
+        let mut result = TrieResult::NoMatch;
+        for c in word.chars() {
+            result = iter.next(c);
+        }
+        result
+    }
+
+    #[test]
+    fn matches_icu4c_format() {
+        let data = build_segmenter_dictionary("a 1\nab 100").unwrap();
+        assert_eq!(data.trie_data.to_vec(), [48, 97, 176, 98, 32868]);
+    }
+
+    #[test]
+    fn round_trip() {
+        // Many branches, long linear matches, supplementary characters, and values
+        // of every size.
+        let mut words = Vec::new();
+        for (i, c) in ('a'..='z')
+            .chain('α'..='ω')
+            .chain('\u{20000}'..='\u{20010}')
+            .enumerate()
+        {
+            let value = 1 << (i % 31);
+            words.push((format!("{c}"), value));
+            words.push((format!("{c}{c}x"), value + 1));
+            words.push((format!("{c}longer-than-sixteen-units{i}"), value + 2));
+            words.push((format!("{c}longer-than-sixteen-units{i}!"), 3));
+        }
+        let list: String = words.iter().map(|(w, v)| format!("{w}\t{v}\n")).collect();
+        let trie = Char16Trie::new(build_segmenter_dictionary(&list).unwrap().trie_data);
+
+        for (word, value) in &words {
+            let result = lookup(&trie, word);
+            let value = *value as i32;
+            assert!(
+                result == TrieResult::FinalValue(value)
+                    || result == TrieResult::Intermediate(value),
+                "{word}: {result:?}"
+            );
+        }
+        assert_eq!(lookup(&trie, "aa"), TrieResult::NoValue);
+        assert_eq!(lookup(&trie, "aab"), TrieResult::NoMatch);
+        assert_eq!(lookup(&trie, "longer"), TrieResult::NoMatch);
+        assert_eq!(lookup(&trie, "\u{20001}long"), TrieResult::NoValue);
+    }
+
+    #[test]
+    fn word_list_syntax() {
+        let trie = Char16Trie::new(
+            build_segmenter_dictionary("# comment\n\n  word 2 \nword\t3\nother\n")
+                .unwrap()
+                .trie_data,
+        );
+        assert_eq!(lookup(&trie, "word"), TrieResult::FinalValue(5));
+        assert_eq!(lookup(&trie, "other"), TrieResult::FinalValue(0));
+
+        assert!(build_segmenter_dictionary("word -1").is_err());
+        assert!(build_segmenter_dictionary("two words 1").is_err());
+        assert!(build_segmenter_dictionary("# nothing").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by the hyph-utf8 hyphenation patterns.
+
+use icu_locid::{langid, LanguageIdentifier};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::*;
+use std::collections::BTreeMap;
+use zerovec::ZeroMap;
+
+type Levels = BTreeMap<String, Vec<u8>>;
+
+/// Parses a `hyph-*.pat.txt` file, with patterns like `hy3ph` separated by whitespace.
+fn parse_patterns(text: &str) -> Result<Levels, DataError> {
+    let mut patterns = Levels::new();
+    for pattern in text.lines().flat_map(|line| {
+        line.split('%')
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+    }) {
+        let mut letters = String::new();
+        let mut levels = vec![0];
+        for c in pattern.chars() {
+            match c.to_digit(10) {
+                Some(level) => *levels.last_mut().expect("not empty") = level as u8,
+                None => {
+                    letters.push(c);
+                    levels.push(0);
+                }
+            }
+        }
+        if letters.is_empty() {
+            return Err(
+                DataError::custom("Invalid hyphenation pattern").with_display_context(pattern)
+            );
+        }
+        patterns.insert(letters, levels);
+    }
+    Ok(patterns)
+}
+
+/// Parses a `hyph-*.hyp.txt` file, with words like `ta-ble` separated by whitespace.
+fn parse_exceptions(text: &str) -> Result<Levels, DataError> {
+    let mut exceptions = Levels::new();
+    for exception in text.lines().flat_map(|line| {
+        line.split('%')
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+    }) {
+        let mut word = String::new();
+        let mut points = Vec::new();
+        for c in exception.chars() {
+            if c == '-' {
+                let point = u8::try_from(word.chars().count()).map_err(|_| {
+                    DataError::custom("Hyphenation exception too long")
+                        .with_display_context(exception)
+                })?;
+                points.push(point);
+            } else {
+                // By character like the segmenter, so a letter can become several.
+                word.extend(c.to_lowercase());
+            }
+        }
+        exceptions.insert(word, points);
+    }
+    Ok(exceptions)
+}
+
+/// Returns the locale of a hyph-utf8 language tag, if ICU4X supports it.
+fn locale_for_tag(tag: &str) -> Option<LanguageIdentifier> {
+    match tag {
+        // These are the US English patterns, which is what `en` is in CLDR.
+        "en-us" => Some(langid!("en")),
+        // Private use tags like `la-x-classic` are not supported.
+        _ => tag.parse().ok(),
+    }
+}
+
+impl crate::DatagenProvider {
+    /// The hyph-utf8 language tags of the pattern files, with their locales.
+    fn hyphenation_tags(&self) -> Result<Vec<(String, LanguageIdentifier)>, DataError> {
+        Ok(self
+            .source
+            .hyphenation()?
+            .list("")?
+            .filter_map(|path| {
+                let tag = path
+                    .to_str()?
+                    .strip_prefix("hyph-")?
+                    .strip_suffix(".pat.txt")?
+                    .to_string();
+                let locale = locale_for_tag(&tag)?;
+                Some((tag, locale))
+            })
+            .collect())
+    }
+}
+
+impl DataProvider<HyphenationPatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<HyphenationPatternsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+        let tag = self
+            .hyphenation_tags()?
+            .into_iter()
+            .find(|(_, locale)| *locale == langid)
+            .map(|(tag, _)| tag)
+            .ok_or(DataErrorKind::MissingLocale.into_error())?;
+
+        let hyphenation = self.source.hyphenation()?;
+        let patterns =
+            hyphenation.read_and_parse_txt(&format!("hyph-{}.pat.txt", tag), parse_patterns)?;
+        let exceptions_path = format!("hyph-{}.hyp.txt", tag);
+        let exceptions = if hyphenation
+            .list("")?
+            .any(|path| path.to_str() == Some(exceptions_path.as_str()))
+        {
+            Some(hyphenation.read_and_parse_txt(&exceptions_path, parse_exceptions)?)
+        } else {
+            None
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(HyphenationPatternsV1 {
+                patterns: patterns
+                    .iter()
+                    .map(|(letters, levels)| (letters.as_str(), levels.as_slice()))
+                    .collect::<ZeroMap<_, _>>(),
+                exceptions: exceptions
+                    .into_iter()
+                    .flatten()
+                    .map(|(word, points)| (word.as_str(), points.as_slice()))
+                    .collect::<ZeroMap<_, _>>(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<HyphenationPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .hyphenation_tags()?
+            .into_iter()
+            .map(|(_, locale)| DataLocale::from(locale))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+
+    #[test]
+    fn pattern_syntax() {
+        let patterns = parse_patterns("% Liang's example\n.hy3ph he2n\n1na  n2at\n").unwrap();
+        assert_eq!(patterns.get(".hyph"), Some(&vec![0, 0, 0, 3, 0, 0]));
+        assert_eq!(patterns.get("na"), Some(&vec![1, 0, 0]));
+        assert_eq!(patterns.len(), 4);
+        assert!(parse_patterns("12").is_err());
+
+        let exceptions = parse_exceptions("as-so-ciate Ta-ble\n").unwrap();
+        assert_eq!(exceptions.get("associate"), Some(&vec![2, 4]));
+        assert_eq!(exceptions.get("table"), Some(&vec![2]));
+    }
+
+    #[test]
+    fn test_basic() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let data: DataPayload<HyphenationPatternsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+
+        assert_eq!(data.get().patterns.get("hyph"), Some(&[0, 0, 3, 0, 0][..]));
+        assert_eq!(data.get().exceptions.get("table"), Some(&[2][..]));
+
+        assert!(
+            IterableDataProvider::<HyphenationPatternsV1Marker>::supported_locales(&provider)
+                .unwrap()
+                .contains(&locale!("en").into())
+        );
+    }
+}
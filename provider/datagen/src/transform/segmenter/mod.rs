@@ -6,7 +6,7 @@
 
 use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
 use icu_collections::codepointtrie::CodePointTrie;
-use icu_locid::{langid, locale};
+use icu_locid::{langid, locale, LanguageIdentifier};
 use icu_properties::{
     maps, sets, EastAsianWidth, GeneralCategory, GraphemeClusterBreak, LineBreak, Script,
     SentenceBreak, WordBreak,
@@ -18,8 +18,12 @@ use icu_segmenter::symbols::*;
 use std::fmt::Debug;
 use zerovec::ZeroVec;
 
+mod dictionary;
+mod hyphenation;
 mod lstm;
 
+pub use dictionary::build_segmenter_dictionary;
+
 // state machine name define by builtin name
 // [[tables]]
 // name = "Double_Quote"
@@ -34,6 +38,11 @@ mod lstm;
 // [[tables]]
 // name = "ABC"
 // codepoint = [32, 33, ...]
+//
+// word type of a segment ending in this state, for the word segmenter
+// [[tables]]
+// name = "Numeric"
+// rule_status = "number"
 #[derive(serde::Deserialize, Debug)]
 struct SegmenterProperty {
     name: String,
@@ -44,6 +53,8 @@ struct SegmenterProperty {
     right: Option<String>,
     // This combine state is an intermediate match rule.
     interm_break_state: Option<bool>,
+    // "number", "letter", "kana" or "ideo" if a segment ending in this state is a word.
+    rule_status: Option<String>,
 }
 
 // state machine break result define
@@ -64,6 +75,7 @@ struct SegmenterState {
 // segmenter_type: builtin type. word, sentence or grapheme.
 // tables: state machine name defines.
 // rules: state machine rules.
+// tailorings: locale tailorings of the code point properties.
 //
 // segmenter_type = "word"
 // [[tables]]
@@ -75,6 +87,21 @@ struct SegmenterRuleTable {
     segmenter_type: String,
     tables: Vec<SegmenterProperty>,
     rules: Vec<SegmenterState>,
+    #[serde(default)]
+    tailorings: Vec<SegmenterTailoring>,
+}
+
+// locale tailoring of the code point properties, from CLDR
+// Tailorings for "und" apply to all locales, in the order they are defined.
+// [[tailorings]]
+// locales = ["fi", "sv"]
+// property = "MidLetter"
+// codepoint = [0x003A, ...]
+#[derive(serde::Deserialize, Debug)]
+struct SegmenterTailoring {
+    locales: Vec<String>,
+    property: String,
+    codepoint: Vec<u32>,
 }
 
 fn set_break_state(
@@ -120,6 +147,18 @@ fn get_word_segmenter_value_from_name(name: &str) -> WordBreak {
     }
 }
 
+fn get_word_type_from_name(name: &str) -> WordType {
+    match name {
+        "number" => WordType::Number,
+        "letter" => WordType::Letter,
+        "kana" => WordType::Kana,
+        "ideo" => WordType::Ideo,
+        _ => {
+            panic!("Invalid rule status: {}", name)
+        }
+    }
+}
+
 fn get_grapheme_segmenter_value_from_name(name: &str) -> GraphemeClusterBreak {
     match name {
         "Control" => GraphemeClusterBreak::Control,
@@ -222,12 +261,8 @@ fn is_cjk_fullwidth(eaw: maps::CodePointMapDataBorrowed<EastAsianWidth>, codepoi
 }
 
 impl crate::DatagenProvider {
-    fn generate_rule_break_data(
-        &self,
-        key: DataKey,
-    ) -> Result<RuleBreakDataV1<'static>, DataError> {
-        let segmenter = self
-            .source
+    fn load_rule_table(&self, key: DataKey) -> Result<&SegmenterRuleTable, DataError> {
+        self.source
             .segmenter()?
             .read_and_parse_toml::<SegmenterRuleTable>(&format!(
                 "{}.toml",
@@ -235,7 +270,35 @@ impl crate::DatagenProvider {
                     .split(&['/', '@'])
                     .nth(1)
                     .expect("DataKey format should be valid!")
-            ))?;
+            ))
+    }
+
+    fn rule_break_locales(&self, key: DataKey) -> Result<Vec<DataLocale>, DataError> {
+        let mut locales = vec![DataLocale::default()];
+        for tailoring in &self.load_rule_table(key)?.tailorings {
+            for locale in &tailoring.locales {
+                if locale != "und" {
+                    let locale = locale
+                        .parse::<LanguageIdentifier>()
+                        .map_err(|e| {
+                            DataError::custom("tailoring locale").with_display_context(&e)
+                        })?
+                        .into();
+                    if !locales.contains(&locale) {
+                        locales.push(locale);
+                    }
+                }
+            }
+        }
+        Ok(locales)
+    }
+
+    fn generate_rule_break_data(
+        &self,
+        key: DataKey,
+        locale: &DataLocale,
+    ) -> Result<RuleBreakDataV1<'static>, DataError> {
+        let segmenter = self.load_rule_table(key)?;
 
         let data = maps::load_word_break(self).expect("The data should be valid!");
         let wb = data.as_borrowed();
@@ -278,14 +341,21 @@ impl crate::DatagenProvider {
 
         let mut properties_map = vec![0; CODEPOINT_TABLE_LEN];
         let mut properties_names = Vec::<String>::new();
+        let mut rule_status_table = Vec::<WordType>::new();
         let mut simple_properties_count = 0;
 
         properties_names.push("Unknown".to_string());
+        rule_status_table.push(WordType::None);
         simple_properties_count += 1;
 
         for p in &segmenter.tables {
             let property_index = if !properties_names.contains(&p.name) {
                 properties_names.push(p.name.clone());
+                rule_status_table.push(
+                    p.rule_status
+                        .as_deref()
+                        .map_or(WordType::None, get_word_type_from_name),
+                );
                 (properties_names.len() - 1) as u8
             } else {
                 continue;
@@ -467,9 +537,27 @@ impl crate::DatagenProvider {
             }
         }
 
+        let langid = locale.get_langid().to_string();
+        for tailoring in &segmenter.tailorings {
+            if tailoring.locales.iter().any(|l| l == "und" || *l == langid) {
+                let property_index = get_index_from_name(&properties_names, &tailoring.property)
+                    .expect("Tailored property should be defined!")
+                    as u8;
+                for c in &tailoring.codepoint {
+                    properties_map[*c as usize] = property_index;
+                }
+            }
+        }
+
         // sot and eot
         properties_names.push("sot".to_string());
         properties_names.push("eot".to_string());
+        rule_status_table.push(WordType::None);
+        rule_status_table.push(WordType::None);
+        if rule_status_table.iter().all(|&s| s == WordType::None) {
+            // Only the word segmenter has rule statuses.
+            rule_status_table.clear();
+        }
 
         let rule_size = properties_names.len() * properties_names.len();
         let mut break_state_table = vec![UNKNOWN_RULE; rule_size];
@@ -616,13 +704,14 @@ impl crate::DatagenProvider {
             sot_property: (property_length - 2) as u8,
             eot_property: (property_length - 1) as u8,
             complex_property: complex_property as u8,
+            rule_status_table: RuleStatusTable(ZeroVec::alloc_from_slice(&rule_status_table)),
         })
     }
 }
 
 impl DataProvider<LineBreakDataV1Marker> for crate::DatagenProvider {
-    fn load(&self, _req: DataRequest) -> Result<DataResponse<LineBreakDataV1Marker>, DataError> {
-        let break_data = self.generate_rule_break_data(LineBreakDataV1Marker::KEY)?;
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LineBreakDataV1Marker>, DataError> {
+        let break_data = self.generate_rule_break_data(LineBreakDataV1Marker::KEY, req.locale)?;
 
         Ok(DataResponse {
             metadata: DataResponseMetadata::default(),
@@ -634,9 +723,10 @@ impl DataProvider<LineBreakDataV1Marker> for crate::DatagenProvider {
 impl DataProvider<GraphemeClusterBreakDataV1Marker> for crate::DatagenProvider {
     fn load(
         &self,
-        _req: DataRequest,
+        req: DataRequest,
     ) -> Result<DataResponse<GraphemeClusterBreakDataV1Marker>, DataError> {
-        let break_data = self.generate_rule_break_data(GraphemeClusterBreakDataV1Marker::KEY)?;
+        let break_data =
+            self.generate_rule_break_data(GraphemeClusterBreakDataV1Marker::KEY, req.locale)?;
 
         Ok(DataResponse {
             metadata: DataResponseMetadata::default(),
@@ -646,8 +736,8 @@ impl DataProvider<GraphemeClusterBreakDataV1Marker> for crate::DatagenProvider {
 }
 
 impl DataProvider<WordBreakDataV1Marker> for crate::DatagenProvider {
-    fn load(&self, _req: DataRequest) -> Result<DataResponse<WordBreakDataV1Marker>, DataError> {
-        let break_data = self.generate_rule_break_data(WordBreakDataV1Marker::KEY)?;
+    fn load(&self, req: DataRequest) -> Result<DataResponse<WordBreakDataV1Marker>, DataError> {
+        let break_data = self.generate_rule_break_data(WordBreakDataV1Marker::KEY, req.locale)?;
 
         Ok(DataResponse {
             metadata: DataResponseMetadata::default(),
@@ -657,11 +747,9 @@ impl DataProvider<WordBreakDataV1Marker> for crate::DatagenProvider {
 }
 
 impl DataProvider<SentenceBreakDataV1Marker> for crate::DatagenProvider {
-    fn load(
-        &self,
-        _req: DataRequest,
-    ) -> Result<DataResponse<SentenceBreakDataV1Marker>, DataError> {
-        let break_data = self.generate_rule_break_data(SentenceBreakDataV1Marker::KEY)?;
+    fn load(&self, req: DataRequest) -> Result<DataResponse<SentenceBreakDataV1Marker>, DataError> {
+        let break_data =
+            self.generate_rule_break_data(SentenceBreakDataV1Marker::KEY, req.locale)?;
 
         Ok(DataResponse {
             metadata: DataResponseMetadata::default(),
@@ -672,25 +760,25 @@ impl DataProvider<SentenceBreakDataV1Marker> for crate::DatagenProvider {
 
 impl IterableDataProvider<LineBreakDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-        Ok(vec![Default::default()])
+        self.rule_break_locales(LineBreakDataV1Marker::KEY)
     }
 }
 
 impl IterableDataProvider<GraphemeClusterBreakDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-        Ok(vec![Default::default()])
+        self.rule_break_locales(GraphemeClusterBreakDataV1Marker::KEY)
     }
 }
 
 impl IterableDataProvider<WordBreakDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-        Ok(vec![Default::default()])
+        self.rule_break_locales(WordBreakDataV1Marker::KEY)
     }
 }
 
 impl IterableDataProvider<SentenceBreakDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-        Ok(vec![Default::default()])
+        self.rule_break_locales(SentenceBreakDataV1Marker::KEY)
     }
 }
 
@@ -754,6 +842,7 @@ impl IterableDataProvider<UCharDictionaryBreakDataV1Marker> for crate::DatagenPr
 #[cfg(test)]
 mod tests {
     use super::*;
+    use icu_locid::Locale;
 
     #[test]
     fn load_grapheme_cluster_data() {
@@ -769,4 +858,102 @@ mod tests {
             "Grapheme cluster data doesn't handle SA"
         );
     }
+
+    #[test]
+    fn word_break_colon() {
+        let provider = crate::DatagenProvider::for_test();
+        // The root data is untailored, so there is no locale-specific data.
+        assert_eq!(
+            IterableDataProvider::<WordBreakDataV1Marker>::supported_locales(&provider)
+                .expect("Loading should succeed!"),
+            [DataLocale::default()]
+        );
+        let segment = |locale: Locale, s: &str| -> Vec<usize> {
+            icu_segmenter::WordBreakSegmenter::try_new_with_locale_unstable(
+                &provider,
+                &locale.into(),
+            )
+            .expect("Loading should succeed!")
+            .segment_str(s)
+            .collect()
+        };
+
+        // Colons are MidLetter in UAX #29, as in the Finnish and Swedish CLDR tailorings.
+        assert_eq!(segment(locale!("und"), "S:t Eriksgatan"), [0, 3, 4, 14]);
+        assert_eq!(segment(locale!("sv"), "S:t Eriksgatan"), [0, 3, 4, 14]);
+        assert_eq!(segment(locale!("fi"), "EU:n"), [0, 4]);
+    }
+
+    #[test]
+    fn sentence_break_tailoring() {
+        let provider = crate::DatagenProvider::for_test();
+        let segment = |locale: Locale, s: &str| -> Vec<usize> {
+            icu_segmenter::SentenceBreakSegmenter::try_new_with_options_unstable(
+                &provider,
+                &locale.into(),
+                Default::default(),
+            )
+            .expect("Loading should succeed!")
+            .segment_str(s)
+            .collect()
+        };
+
+        let s = "Τι κάνεις; Καλά.";
+        assert_eq!(segment(locale!("und"), s), [0, s.len()]);
+        assert_eq!(segment(locale!("el"), s), [0, 19, s.len()]);
+    }
+
+    #[test]
+    fn user_dictionary() {
+        let provider = crate::DatagenProvider::for_test();
+        let mut segmenter = icu_segmenter::WordBreakSegmenter::try_new_unstable(&provider)
+            .expect("Loading should succeed!");
+
+        let s = "東京都庁";
+        let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(breaks, [0, 6, 12]);
+
+        // The longest word of either dictionary wins.
+        let dictionary = build_segmenter_dictionary("東京都\t120\n").expect("Valid word list");
+        segmenter.add_user_dictionary(DataPayload::from_owned(dictionary));
+        let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(breaks, [0, 9, 12]);
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        let breaks: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+        assert_eq!(breaks, [0, 3, 4]);
+    }
+
+    #[test]
+    fn user_dictionary_thai_line_break() {
+        let provider = crate::DatagenProvider::for_test();
+        let mut segmenter = icu_segmenter::LineBreakSegmenter::try_new_unstable(&provider)
+            .expect("Loading should succeed!");
+
+        let s = "ภาษาไทยภาษาไทย";
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        let breaks: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+        assert_eq!(breaks, [4, 7, 11, 14]);
+
+        let dictionary = build_segmenter_dictionary("ภาษาไทย\t10\n").expect("Valid word list");
+        segmenter.add_user_dictionary(DataPayload::from_owned(dictionary));
+        let breaks: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+        assert_eq!(breaks, [7, 14]);
+        let breaks: Vec<usize> = segmenter.segment_str(s).collect();
+        assert_eq!(breaks, [21, 42]);
+    }
+
+    #[test]
+    fn tailored_locales() {
+        let provider = crate::DatagenProvider::for_test();
+        assert_eq!(
+            IterableDataProvider::<SentenceBreakDataV1Marker>::supported_locales(&provider)
+                .unwrap(),
+            [DataLocale::default(), locale!("el").into()]
+        );
+        assert_eq!(
+            IterableDataProvider::<GraphemeClusterBreakDataV1Marker>::supported_locales(&provider)
+                .unwrap(),
+            [DataLocale::default()]
+        );
+    }
 }
@@ -0,0 +1,245 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers for the properties used by the bidi algorithm besides Bidi_Class, read from
+//! `BidiMirroring.txt` and `BidiBrackets.txt`.
+
+use super::props::{parse_code_point_value, parse_values};
+use crate::SourceData;
+use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+use icu_properties::provider::*;
+use icu_properties::CodePointValue;
+use icu_provider::datagen::*;
+use icu_provider::prelude::*;
+
+/// The shift of the Bidi_Paired_Bracket_Type in the values of `BidiAuxiliaryPropertiesV1`.
+const PAIRED_BRACKET_TYPE_SHIFT: u32 = 21;
+
+/// The contents of `BidiBrackets.txt`.
+struct BidiBrackets {
+    /// The Bidi_Paired_Bracket of each code point
+    paired_brackets: Vec<CodePointValue>,
+    /// The Bidi_Paired_Bracket_Type of each code point, as stored in
+    /// `BidiAuxiliaryPropertiesV1`: 0 for none, 1 for open and 2 for close
+    paired_bracket_types: Vec<u32>,
+}
+
+fn parse_paired_bracket_type(value: &str) -> Result<u32, DataError> {
+    match value {
+        "o" => Ok(1),
+        "c" => Ok(2),
+        _ => Err(DataError::custom("Bidi_Paired_Bracket_Type").with_display_context(value)),
+    }
+}
+
+fn parse_bidi_brackets(text: &str) -> Result<BidiBrackets, DataError> {
+    Ok(BidiBrackets {
+        paired_brackets: parse_values(
+            "BidiBrackets.txt",
+            text,
+            1,
+            CodePointValue(None),
+            parse_code_point_value,
+        )?,
+        paired_bracket_types: parse_values(
+            "BidiBrackets.txt",
+            text,
+            2,
+            0,
+            parse_paired_bracket_type,
+        )?,
+    })
+}
+
+fn parse_bidi_mirroring(text: &str) -> Result<Vec<CodePointValue>, DataError> {
+    parse_values(
+        "BidiMirroring.txt",
+        text,
+        1,
+        CodePointValue(None),
+        parse_code_point_value,
+    )
+}
+
+fn get_bidi_brackets(source: &SourceData) -> Result<&BidiBrackets, DataError> {
+    source
+        .ucd()?
+        .read_and_parse_txt("BidiBrackets.txt", parse_bidi_brackets)
+}
+
+fn get_bidi_mirroring_glyphs(source: &SourceData) -> Result<&Vec<CodePointValue>, DataError> {
+    source
+        .ucd()?
+        .read_and_parse_txt("BidiMirroring.txt", parse_bidi_mirroring)
+}
+
+fn code_point_map(
+    source: &SourceData,
+    values: &[CodePointValue],
+) -> PropertyCodePointMapV1<'static, CodePointValue> {
+    PropertyCodePointMapV1::CodePointTrie(
+        CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(values),
+            default_value: CodePointValue(None),
+            error_value: CodePointValue(None),
+            trie_type: source.trie_type().to_internal(),
+        }
+        .build(),
+    )
+}
+
+impl DataProvider<BidiMirroringGlyphV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<BidiMirroringGlyphV1Marker>, DataError> {
+        let bmg = get_bidi_mirroring_glyphs(&self.source)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(code_point_map(&self.source, bmg))),
+        })
+    }
+}
+
+impl IterableDataProvider<BidiMirroringGlyphV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        get_bidi_mirroring_glyphs(&self.source)?;
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<BidiPairedBracketV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<BidiPairedBracketV1Marker>, DataError> {
+        let bpb = &get_bidi_brackets(&self.source)?.paired_brackets;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(code_point_map(&self.source, bpb))),
+        })
+    }
+}
+
+impl IterableDataProvider<BidiPairedBracketV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        get_bidi_brackets(&self.source)?;
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<BidiAuxiliaryPropertiesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _: DataRequest,
+    ) -> Result<DataResponse<BidiAuxiliaryPropertiesV1Marker>, DataError> {
+        let bmg = get_bidi_mirroring_glyphs(&self.source)?;
+        let brackets = get_bidi_brackets(&self.source)?;
+
+        // The low bits hold the Bidi_Mirroring_Glyph, which is also the Bidi_Paired_Bracket
+        // of the paired brackets.
+        let mut values = vec![0u32; 0x11_0000];
+        for (cp, value) in values.iter_mut().enumerate() {
+            let bpt = brackets.paired_bracket_types[cp];
+            if bpt != 0 && brackets.paired_brackets[cp] != bmg[cp] {
+                return Err(DataError::custom(
+                    "Bidi_Paired_Bracket differs from Bidi_Mirroring_Glyph",
+                )
+                .with_display_context(&format!("U+{:04X}", cp)));
+            }
+            *value =
+                bmg[cp].0.map(u32::from).unwrap_or_default() | bpt << PAIRED_BRACKET_TYPE_SHIFT;
+        }
+
+        let trie = CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(&values),
+            default_value: 0,
+            error_value: 0,
+            trie_type: self.source.trie_type().to_internal(),
+        }
+        .build();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(BidiAuxiliaryPropertiesV1 { trie })),
+        })
+    }
+}
+
+impl IterableDataProvider<BidiAuxiliaryPropertiesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        get_bidi_mirroring_glyphs(&self.source)?;
+        get_bidi_brackets(&self.source)?;
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "experimental")]
+    use icu_properties::bidi::{BidiAuxiliaryProperties, BidiPairedBracketType};
+    use icu_properties::maps::CodePointMapData;
+
+    #[test]
+    fn test_bidi_mirroring_glyph() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<BidiMirroringGlyphV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = CodePointMapData::from_data(payload);
+        let bmg = data.as_borrowed();
+
+        assert_eq!(bmg.get('('), CodePointValue(Some(')')));
+        assert_eq!(bmg.get('»'), CodePointValue(Some('«')));
+        assert_eq!(bmg.get('a'), CodePointValue(None));
+        assert_eq!(bmg.get32(0x10_FFFF), CodePointValue(None));
+    }
+
+    #[test]
+    fn test_bidi_paired_bracket() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<BidiPairedBracketV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = CodePointMapData::from_data(payload);
+        let bpb = data.as_borrowed();
+
+        assert_eq!(bpb.get('['), CodePointValue(Some(']')));
+        assert_eq!(bpb.get('\u{FF63}'), CodePointValue(Some('\u{FF62}')));
+        // Mirrored, but not a bracket
+        assert_eq!(bpb.get('»'), CodePointValue(None));
+    }
+
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn test_bidi_auxiliary_properties() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<BidiAuxiliaryPropertiesV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = BidiAuxiliaryProperties::from_data(payload);
+        let auxiliary = data.as_borrowed();
+
+        assert_eq!(auxiliary.get_mirroring_glyph('('), Some(')'));
+        assert_eq!(auxiliary.get_mirroring_glyph('≤'), Some('≥'));
+        assert_eq!(auxiliary.get_mirroring_glyph('\u{FF63}'), Some('\u{FF62}'));
+        assert_eq!(auxiliary.get_mirroring_glyph('a'), None);
+
+        assert_eq!(
+            auxiliary.get_paired_bracket_type('\u{2329}'),
+            BidiPairedBracketType::Open
+        );
+        assert_eq!(auxiliary.get_paired_bracket('\u{2329}'), Some('\u{232A}'));
+        assert_eq!(
+            auxiliary.get_paired_bracket_type('\u{FF63}'),
+            BidiPairedBracketType::Close
+        );
+        assert_eq!(
+            auxiliary.get_paired_bracket_type('≤'),
+            BidiPairedBracketType::None
+        );
+        assert_eq!(auxiliary.get_paired_bracket('≤'), None);
+    }
+}
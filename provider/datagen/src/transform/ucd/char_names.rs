@@ -0,0 +1,273 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use icu_properties::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// The Hangul syllables, whose names are derived at runtime.
+const HANGUL_SYLLABLES: core::ops::RangeInclusive<u32> = 0xAC00..=0xD7A3;
+
+/// The one character whose name has a medial hyphen that is not ignored by UAX44-LM2.
+const HANGUL_JUNGSEONG_O_E: u32 = 0x1180;
+
+/// The contents of `DerivedName.txt`.
+struct DerivedNames {
+    /// Code points and their names, in code point order.
+    names: Vec<(u32, String)>,
+    /// Ranges of code points whose names are a prefix followed by the code point.
+    ranges: Vec<(u32, u32, String)>,
+}
+
+fn parse_code_point(s: &str) -> Result<u32, DataError> {
+    u32::from_str_radix(s.trim(), 16)
+        .map_err(|e| DataError::custom("UCD code point").with_display_context(&e))
+}
+
+fn parse_line(line: &str) -> Option<Vec<&str>> {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    if line.is_empty() {
+        None
+    } else {
+        Some(line.split(';').map(str::trim).collect())
+    }
+}
+
+fn parse_derived_names(text: &str) -> Result<DerivedNames, DataError> {
+    let mut names = Vec::new();
+    let mut ranges = Vec::new();
+    for fields in text.lines().filter_map(parse_line) {
+        let (range, name) = match fields.as_slice() {
+            [range, name] => (range, name),
+            _ => {
+                return Err(DataError::custom("DerivedName.txt line")
+                    .with_display_context(&fields.join(";")))
+            }
+        };
+        if let Some(prefix) = name.strip_suffix('*') {
+            let (start, end) = match range.split_once("..") {
+                Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+                None => {
+                    let cp = parse_code_point(range)?;
+                    (cp, cp)
+                }
+            };
+            if !HANGUL_SYLLABLES.contains(&start) {
+                ranges.push((start, end, prefix.to_string()));
+            }
+        } else {
+            let cp = parse_code_point(range)?;
+            if !HANGUL_SYLLABLES.contains(&cp) {
+                names.push((cp, name.to_string()));
+            }
+        }
+    }
+    names.sort_unstable();
+    ranges.sort_unstable();
+    Ok(DerivedNames { names, ranges })
+}
+
+/// The contents of `NameAliases.txt`, in code point order, keeping the order of the aliases
+/// of each code point.
+fn parse_name_aliases(text: &str) -> Result<Vec<(u32, String)>, DataError> {
+    let mut aliases =
+        text.lines()
+            .filter_map(parse_line)
+            .map(|fields| match fields.as_slice() {
+                [cp, alias, _type] => Ok((parse_code_point(cp)?, alias.to_string())),
+                _ => Err(DataError::custom("NameAliases.txt line")
+                    .with_display_context(&fields.join(";"))),
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+    aliases.sort_by_key(|&(cp, _)| cp);
+    Ok(aliases)
+}
+
+/// The key of a name for the loose matching rule UAX44-LM2: uppercase, without whitespace,
+/// underscores and medial hyphens.
+fn loose_key(name: &str, keep_hyphens: bool) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, &c)| {
+            if c.is_whitespace() || c == '_' {
+                return false;
+            }
+            let medial = i > 0
+                && chars[i - 1].is_ascii_alphanumeric()
+                && matches!(chars.get(i + 1), Some(n) if n.is_ascii_alphanumeric());
+            !(c == '-' && medial && !keep_hyphens)
+        })
+        .map(|(_, c)| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// A table of the words of the names, the most frequent first, so that they mostly have
+/// one-byte indices.
+struct Tokens<'a> {
+    words: Vec<&'a str>,
+    indices: HashMap<&'a str, usize>,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(names: impl Iterator<Item = &'a str>) -> Result<Self, DataError> {
+        let mut counts = HashMap::<&str, usize>::new();
+        for word in names.flat_map(|name| name.split(' ')) {
+            *counts.entry(word).or_default() += 1;
+        }
+        let mut words = counts.into_iter().collect::<Vec<_>>();
+        words.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        if words.len() > 0x7FFF {
+            return Err(DataError::custom("Too many words in character names"));
+        }
+        let words = words.into_iter().map(|(word, _)| word).collect::<Vec<_>>();
+        let indices = words.iter().enumerate().map(|(i, &w)| (w, i)).collect();
+        Ok(Self { words, indices })
+    }
+
+    fn encode(&self, name: &str) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        for word in name.split(' ') {
+            let index = self.indices[word];
+            if index < 0x80 {
+                encoded.push(index as u8);
+            } else {
+                encoded.push(0x80 | (index >> 8) as u8);
+                encoded.push(index as u8);
+            }
+        }
+        encoded
+    }
+}
+
+impl DataProvider<CharacterNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<CharacterNamesV1Marker>, DataError> {
+        let ucd = self.source.ucd()?;
+        let derived = ucd.read_and_parse_txt("extracted/DerivedName.txt", parse_derived_names)?;
+        let aliases = ucd.read_and_parse_txt("NameAliases.txt", parse_name_aliases)?;
+
+        let tokens = Tokens::new(
+            derived
+                .names
+                .iter()
+                .chain(aliases.iter())
+                .map(|(_, name)| name.as_str()),
+        )?;
+
+        let mut loose_order = derived
+            .names
+            .iter()
+            .chain(aliases.iter())
+            .enumerate()
+            .map(|(i, (cp, name))| (loose_key(name, *cp == HANGUL_JUNGSEONG_O_E), i as u32))
+            .collect::<Vec<_>>();
+        loose_order.sort_unstable();
+        if let Some(w) = loose_order.windows(2).find(|w| w[0].0 == w[1].0) {
+            return Err(
+                DataError::custom("Character names collide under loose matching")
+                    .with_display_context(&w[0].0),
+            );
+        }
+
+        let encode = |names: &[(u32, String)]| -> VarZeroVec<'static, [u8], Index32> {
+            let encoded = names
+                .iter()
+                .map(|(_, name)| tokens.encode(name))
+                .collect::<Vec<_>>();
+            VarZeroVec::from(&encoded)
+        };
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(CharacterNamesV1 {
+                tokens: VarZeroVec::from(&tokens.words),
+                code_points: derived.names.iter().map(|&(cp, _)| cp).collect(),
+                names: encode(&derived.names),
+                alias_code_points: aliases.iter().map(|&(cp, _)| cp).collect(),
+                aliases: encode(aliases),
+                loose_order: loose_order.into_iter().map(|(_, i)| i).collect(),
+                code_point_ranges: derived
+                    .ranges
+                    .iter()
+                    .map(|&(start, end, _)| (start, end))
+                    .collect::<ZeroVec<_>>(),
+                code_point_prefixes: VarZeroVec::from(
+                    &derived
+                        .ranges
+                        .iter()
+                        .map(|(_, _, prefix)| prefix.as_str())
+                        .collect::<Vec<_>>(),
+                ),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CharacterNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+#[test]
+fn test_char_names() {
+    use icu_properties::char_names::CharacterNames;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let payload: DataPayload<CharacterNamesV1Marker> = provider
+        .load(Default::default())
+        .and_then(DataResponse::take_payload)
+        .expect("Loading was successful");
+    let data = CharacterNames::from_data(payload);
+    let names = data.as_borrowed();
+
+    let name = |ch| names.name(ch).map(|name| name.to_string());
+    assert_eq!(name(' ').as_deref(), Some("SPACE"));
+    assert_eq!(name('\u{1180}').as_deref(), Some("HANGUL JUNGSEONG O-E"));
+    assert_eq!(name('\u{D7A3}').as_deref(), Some("HANGUL SYLLABLE HIH"));
+    assert_eq!(name('\u{18D08}').as_deref(), Some("TANGUT IDEOGRAPH-18D08"));
+    assert_eq!(name('\u{31350}'), None);
+    assert_eq!(name('\u{FFFF}'), None);
+
+    assert_eq!(names.char_for_name("space"), Some(' '));
+    assert_eq!(names.char_for_name("SP"), Some(' '));
+    assert_eq!(
+        names.char_for_name("Hangul Jungseong O-E"),
+        Some('\u{1180}')
+    );
+    assert_eq!(names.char_for_name("hangul_jungseong_oe"), Some('\u{116C}'));
+    assert_eq!(names.char_for_name("HANGUL SYLLABLE GGWAELG"), Some('꽭'));
+    assert_eq!(names.char_for_name("HANGUL SYLLABLE A"), Some('아'));
+    assert_eq!(names.char_for_name("HANGUL SYLLABLE"), None);
+    assert_eq!(names.char_for_name("CJK UNIFIED IDEOGRAPH-04E00"), None);
+    assert_eq!(
+        names.char_for_name("CJK UNIFIED IDEOGRAPH-3134A"),
+        Some('\u{3134A}')
+    );
+    assert_eq!(names.char_for_name("CJK UNIFIED IDEOGRAPH-3134B"), None);
+    assert_eq!(names.char_for_name("LATIN CAPITAL LETTER GHA"), Some('Ƣ'));
+    assert_eq!(names.char_for_name("LATIN CAPITAL LETTER OI"), Some('Ƣ'));
+
+    // Every name and alias round-trips through loose matching
+    for ch in (0..=0x10FFFF).filter_map(char::from_u32) {
+        if let Some(name) = names.name(ch) {
+            assert_eq!(names.char_for_name(&name.to_string()), Some(ch), "{}", name);
+        }
+        for alias in names.name_aliases(ch) {
+            assert_eq!(
+                names.char_for_name(&alias.to_string()),
+                Some(ch),
+                "{}",
+                alias
+            );
+        }
+    }
+}
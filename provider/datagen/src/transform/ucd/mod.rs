@@ -0,0 +1,12 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by Unicode Character
+//! Database files that are not covered by the ICU export.
+
+mod bidi_data;
+mod char_names;
+pub(crate) mod props;
+mod spoof;
+mod uts46;
@@ -0,0 +1,414 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Providers for the code point maps of the properties that are read from the data files of
+//! the UCD.
+
+use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+use icu_properties::provider::*;
+use icu_properties::{
+    Age, CodePointValue, HangulSyllableType, IndicSyllabicCategory, JoiningType, NumericType,
+    NumericValue, VerticalOrientation,
+};
+use icu_provider::datagen::*;
+use icu_provider::prelude::*;
+
+fn parse_code_point(s: &str) -> Result<u32, DataError> {
+    u32::from_str_radix(s.trim(), 16)
+        .map_err(|e| DataError::custom("UCD code point").with_display_context(&e))
+}
+
+fn parse_range(range: &str) -> Result<(u32, u32), DataError> {
+    Ok(match range.split_once("..") {
+        Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+        None => {
+            let cp = parse_code_point(range)?;
+            (cp, cp)
+        }
+    })
+}
+
+/// The values of all code points of a UCD data file, whose lines have a code point or a range
+/// of code points followed by the value in the field `field`. The code points that are not
+/// listed have the value `default`.
+pub(super) fn parse_values<T: Copy>(
+    file: &'static str,
+    text: &str,
+    field: usize,
+    default: T,
+    parse_value: fn(&str) -> Result<T, DataError>,
+) -> Result<Vec<T>, DataError> {
+    let mut values = vec![default; 0x11_0000];
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        let value = match fields.get(field) {
+            Some(value) => parse_value(value)?,
+            None => {
+                return Err(DataError::custom("UCD data line")
+                    .with_display_context(file)
+                    .with_display_context(line))
+            }
+        };
+        let (start, end) = parse_range(fields[0])?;
+        for v in values
+            .iter_mut()
+            .take(end as usize + 1)
+            .skip(start as usize)
+        {
+            *v = value;
+        }
+    }
+    Ok(values)
+}
+
+/// Parses a value of the Age property, a version like `3.2`.
+fn parse_age(value: &str) -> Result<Age, DataError> {
+    let error = || DataError::custom("Could not parse Age").with_display_context(value);
+    let (major, minor) = value.split_once('.').ok_or_else(error)?;
+    Ok(Age {
+        major: major.parse().map_err(|_| error())?,
+        minor: minor.parse().map_err(|_| error())?,
+    })
+}
+
+/// Parses a value of the Numeric_Value property, an integer or a fraction like `-1/2`.
+fn parse_numeric_value(value: &str) -> Result<NumericValue, DataError> {
+    let error = || DataError::custom("Could not parse Numeric_Value").with_display_context(value);
+    let (numerator, denominator) = match value.split_once('/') {
+        Some((numerator, denominator)) => (numerator, denominator),
+        None => (value, "1"),
+    };
+    NumericValue::from_rational(
+        numerator.parse().map_err(|_| error())?,
+        denominator.parse().map_err(|_| error())?,
+    )
+    .ok_or_else(error)
+}
+
+fn parse_numeric_type(name: &str) -> Result<NumericType, DataError> {
+    Ok(match name {
+        "Decimal" => NumericType::Decimal,
+        "Digit" => NumericType::Digit,
+        "Numeric" => NumericType::Numeric,
+        _ => return Err(DataError::custom("Numeric_Type").with_display_context(name)),
+    })
+}
+
+fn parse_hangul_syllable_type(name: &str) -> Result<HangulSyllableType, DataError> {
+    Ok(match name {
+        "L" => HangulSyllableType::LeadingJamo,
+        "V" => HangulSyllableType::VowelJamo,
+        "T" => HangulSyllableType::TrailingJamo,
+        "LV" => HangulSyllableType::LeadingVowelSyllable,
+        "LVT" => HangulSyllableType::LeadingVowelTrailingSyllable,
+        _ => return Err(DataError::custom("Hangul_Syllable_Type").with_display_context(name)),
+    })
+}
+
+/// Parses a short name of a value of the Joining_Type property.
+pub(crate) fn parse_joining_type(name: &str) -> Result<JoiningType, DataError> {
+    Ok(match name {
+        "U" => JoiningType::NonJoining,
+        "C" => JoiningType::JoinCausing,
+        "D" => JoiningType::DualJoining,
+        "L" => JoiningType::LeftJoining,
+        "R" => JoiningType::RightJoining,
+        "T" => JoiningType::Transparent,
+        _ => return Err(DataError::custom("Joining_Type").with_display_context(name)),
+    })
+}
+
+fn parse_vertical_orientation(name: &str) -> Result<VerticalOrientation, DataError> {
+    Ok(match name {
+        "R" => VerticalOrientation::Rotated,
+        "Tr" => VerticalOrientation::TransformedRotated,
+        "Tu" => VerticalOrientation::TransformedUpright,
+        "U" => VerticalOrientation::Upright,
+        _ => return Err(DataError::custom("Vertical_Orientation").with_display_context(name)),
+    })
+}
+
+fn parse_indic_syllabic_category(name: &str) -> Result<IndicSyllabicCategory, DataError> {
+    Ok(match name {
+        "Avagraha" => IndicSyllabicCategory::Avagraha,
+        "Bindu" => IndicSyllabicCategory::Bindu,
+        "Brahmi_Joining_Number" => IndicSyllabicCategory::BrahmiJoiningNumber,
+        "Cantillation_Mark" => IndicSyllabicCategory::CantillationMark,
+        "Consonant" => IndicSyllabicCategory::Consonant,
+        "Consonant_Dead" => IndicSyllabicCategory::ConsonantDead,
+        "Consonant_Final" => IndicSyllabicCategory::ConsonantFinal,
+        "Consonant_Head_Letter" => IndicSyllabicCategory::ConsonantHeadLetter,
+        "Consonant_Initial_Postfixed" => IndicSyllabicCategory::ConsonantInitialPostfixed,
+        "Consonant_Killer" => IndicSyllabicCategory::ConsonantKiller,
+        "Consonant_Medial" => IndicSyllabicCategory::ConsonantMedial,
+        "Consonant_Placeholder" => IndicSyllabicCategory::ConsonantPlaceholder,
+        "Consonant_Preceding_Repha" => IndicSyllabicCategory::ConsonantPrecedingRepha,
+        "Consonant_Prefixed" => IndicSyllabicCategory::ConsonantPrefixed,
+        "Consonant_Subjoined" => IndicSyllabicCategory::ConsonantSubjoined,
+        "Consonant_Succeeding_Repha" => IndicSyllabicCategory::ConsonantSucceedingRepha,
+        "Consonant_With_Stacker" => IndicSyllabicCategory::ConsonantWithStacker,
+        "Gemination_Mark" => IndicSyllabicCategory::GeminationMark,
+        "Invisible_Stacker" => IndicSyllabicCategory::InvisibleStacker,
+        "Joiner" => IndicSyllabicCategory::Joiner,
+        "Modifying_Letter" => IndicSyllabicCategory::ModifyingLetter,
+        "Non_Joiner" => IndicSyllabicCategory::NonJoiner,
+        "Nukta" => IndicSyllabicCategory::Nukta,
+        "Number" => IndicSyllabicCategory::Number,
+        "Number_Joiner" => IndicSyllabicCategory::NumberJoiner,
+        "Pure_Killer" => IndicSyllabicCategory::PureKiller,
+        "Register_Shifter" => IndicSyllabicCategory::RegisterShifter,
+        "Syllable_Modifier" => IndicSyllabicCategory::SyllableModifier,
+        "Tone_Letter" => IndicSyllabicCategory::ToneLetter,
+        "Tone_Mark" => IndicSyllabicCategory::ToneMark,
+        "Virama" => IndicSyllabicCategory::Virama,
+        "Visarga" => IndicSyllabicCategory::Visarga,
+        "Vowel" => IndicSyllabicCategory::Vowel,
+        "Vowel_Dependent" => IndicSyllabicCategory::VowelDependent,
+        "Vowel_Independent" => IndicSyllabicCategory::VowelIndependent,
+        _ => return Err(DataError::custom("Indic_Syllabic_Category").with_display_context(name)),
+    })
+}
+
+pub(super) fn parse_code_point_value(value: &str) -> Result<CodePointValue, DataError> {
+    let cp = parse_code_point(value)?;
+    char::from_u32(cp)
+        .map(|ch| CodePointValue(Some(ch)))
+        .ok_or_else(|| DataError::custom("UCD code point").with_display_context(&cp))
+}
+
+fn parse_derived_age(text: &str) -> Result<Vec<Age>, DataError> {
+    parse_values("DerivedAge.txt", text, 1, Age::Unassigned, parse_age)
+}
+
+fn parse_derived_numeric_values(text: &str) -> Result<Vec<NumericValue>, DataError> {
+    // The third field is the value as a decimal number, the fourth one the exact value.
+    parse_values(
+        "DerivedNumericValues.txt",
+        text,
+        3,
+        NumericValue::NaN,
+        parse_numeric_value,
+    )
+}
+
+fn parse_derived_numeric_type(text: &str) -> Result<Vec<NumericType>, DataError> {
+    parse_values(
+        "DerivedNumericType.txt",
+        text,
+        1,
+        NumericType::None,
+        parse_numeric_type,
+    )
+}
+
+fn parse_hangul_syllable_types(text: &str) -> Result<Vec<HangulSyllableType>, DataError> {
+    parse_values(
+        "HangulSyllableType.txt",
+        text,
+        1,
+        HangulSyllableType::NotApplicable,
+        parse_hangul_syllable_type,
+    )
+}
+
+fn parse_derived_joining_types(text: &str) -> Result<Vec<JoiningType>, DataError> {
+    parse_values(
+        "DerivedJoiningType.txt",
+        text,
+        1,
+        JoiningType::NonJoining,
+        parse_joining_type,
+    )
+}
+
+fn parse_vertical_orientations(text: &str) -> Result<Vec<VerticalOrientation>, DataError> {
+    parse_values(
+        "VerticalOrientation.txt",
+        text,
+        1,
+        VerticalOrientation::Rotated,
+        parse_vertical_orientation,
+    )
+}
+
+fn parse_indic_syllabic_categories(text: &str) -> Result<Vec<IndicSyllabicCategory>, DataError> {
+    parse_values(
+        "IndicSyllabicCategory.txt",
+        text,
+        1,
+        IndicSyllabicCategory::Other,
+        parse_indic_syllabic_category,
+    )
+}
+
+macro_rules! expand {
+    ($(($marker:ident, $file:literal, $parse:ident, $default:expr)),+,) => {
+        $(
+            impl DataProvider<$marker> for crate::DatagenProvider {
+                fn load(&self, _: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    let values = self.source.ucd()?.read_and_parse_txt($file, $parse)?;
+
+                    let code_point_trie = CodePointTrieBuilder {
+                        data: CodePointTrieBuilderData::ValuesByCodePoint(values),
+                        default_value: $default,
+                        error_value: $default,
+                        trie_type: self.source.trie_type().to_internal(),
+                    }
+                    .build();
+
+                    let data_struct = PropertyCodePointMapV1::CodePointTrie(code_point_trie);
+                    Ok(DataResponse {
+                        metadata: DataResponseMetadata::default(),
+                        payload: Some(DataPayload::from_owned(data_struct)),
+                    })
+                }
+            }
+
+            impl IterableDataProvider<$marker> for crate::DatagenProvider {
+                fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                    self.source.ucd()?.read_and_parse_txt($file, $parse)?;
+                    Ok(vec![Default::default()])
+                }
+            }
+        )+
+    };
+}
+
+expand!(
+    (
+        AgeV1Marker,
+        "DerivedAge.txt",
+        parse_derived_age,
+        Age::Unassigned
+    ),
+    (
+        NumericValueV1Marker,
+        "extracted/DerivedNumericValues.txt",
+        parse_derived_numeric_values,
+        NumericValue::NaN
+    ),
+    (
+        NumericTypeV1Marker,
+        "extracted/DerivedNumericType.txt",
+        parse_derived_numeric_type,
+        NumericType::None
+    ),
+    (
+        HangulSyllableTypeV1Marker,
+        "HangulSyllableType.txt",
+        parse_hangul_syllable_types,
+        HangulSyllableType::NotApplicable
+    ),
+    (
+        JoiningTypeV1Marker,
+        "extracted/DerivedJoiningType.txt",
+        parse_derived_joining_types,
+        JoiningType::NonJoining
+    ),
+    (
+        VerticalOrientationV1Marker,
+        "VerticalOrientation.txt",
+        parse_vertical_orientations,
+        VerticalOrientation::Rotated
+    ),
+    (
+        IndicSyllabicCategoryV1Marker,
+        "IndicSyllabicCategory.txt",
+        parse_indic_syllabic_categories,
+        IndicSyllabicCategory::Other
+    ),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_properties::maps::CodePointMapData;
+
+    #[test]
+    fn test_age() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<AgeV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = CodePointMapData::from_data(payload);
+        let age = data.as_borrowed();
+
+        assert_eq!(age.get('a'), Age { major: 1, minor: 1 });
+        assert_eq!(age.get('€'), Age { major: 2, minor: 1 });
+        assert_eq!(
+            age.get32(0x1FAE0),
+            Age {
+                major: 14,
+                minor: 0
+            }
+        ); // MELTING FACE
+        assert_eq!(age.get32(0x0378), Age::Unassigned);
+    }
+
+    #[test]
+    fn test_joining_type() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<JoiningTypeV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = CodePointMapData::from_data(payload);
+        let jt = data.as_borrowed();
+
+        assert_eq!(jt.get('\u{0628}'), JoiningType::DualJoining); // ARABIC LETTER BEH
+        assert_eq!(jt.get('\u{0627}'), JoiningType::RightJoining); // ARABIC LETTER ALEF
+        assert_eq!(jt.get('\u{200D}'), JoiningType::JoinCausing); // ZERO WIDTH JOINER
+        assert_eq!(jt.get('\u{0300}'), JoiningType::Transparent); // COMBINING GRAVE ACCENT
+        assert_eq!(jt.get('a'), JoiningType::NonJoining);
+    }
+
+    #[test]
+    fn test_numeric_value() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<NumericValueV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let data = CodePointMapData::from_data(payload);
+        let nv = data.as_borrowed();
+
+        assert_eq!(nv.get('7').to_rational(), Some((7, 1)));
+        assert_eq!(nv.get('٣').to_rational(), Some((3, 1))); // U+0663: Arabic-Indic Digit Three
+        assert_eq!(nv.get('\u{0F33}').to_rational(), Some((-1, 2))); // U+0F33: Tibetan Digit Half Zero
+        assert_eq!(nv.get('\u{09F4}').to_rational(), Some((1, 16))); // U+09F4: Bengali Currency Numerator One
+        assert_eq!(nv.get('兆').to_rational(), Some((1_000_000_000_000, 1))); // U+5146
+        assert_eq!(nv.get('\u{12432}').to_rational(), Some((216_000, 1))); // U+12432: Cuneiform Numeric Sign Shar2 Times Gal Plus Disj
+        assert_eq!(nv.get('a'), NumericValue::NaN);
+    }
+
+    #[test]
+    fn test_parse_values() {
+        let text = "# DerivedAge.txt\n\n0000..001F    ; 1.1 #  [32] <control-0000>..<control-001F>\n\n0220          ; 3.2 #       LATIN CAPITAL LETTER N WITH LONG RIGHT LEG\n";
+        let values = parse_derived_age(text).unwrap();
+        assert_eq!(values[0x1F], Age { major: 1, minor: 1 });
+        assert_eq!(values[0x20], Age::Unassigned);
+        assert_eq!(values[0x220], Age { major: 3, minor: 2 });
+        assert!(parse_derived_age("0000..001F ; one").is_err());
+        assert!(parse_derived_age("0000..001F").is_err());
+
+        assert_eq!(
+            parse_numeric_value("11/12").unwrap().to_rational(),
+            Some((11, 12))
+        );
+        assert_eq!(
+            parse_numeric_value("1000000000000").unwrap().to_rational(),
+            Some((1_000_000_000_000, 1))
+        );
+        assert!(parse_numeric_value("1/0").is_err());
+        assert!(parse_numeric_value("one").is_err());
+    }
+}
@@ -0,0 +1,252 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "experimental")]
+
+use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+use icu_collections::codepointinvlist::{CodePointInversionList, CodePointInversionListBuilder};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_spoof::provider::*;
+use icu_spoof::IdentifierType;
+use std::collections::BTreeMap;
+use zerovec::ZeroMap;
+
+fn parse_code_point(s: &str) -> Result<u32, DataError> {
+    u32::from_str_radix(s.trim(), 16)
+        .map_err(|e| DataError::custom("UTS #39 code point").with_display_context(&e))
+}
+
+fn parse_char(s: &str) -> Result<char, DataError> {
+    let cp = parse_code_point(s)?;
+    char::from_u32(cp)
+        .ok_or_else(|| DataError::custom("UTS #39 code point").with_display_context(&cp))
+}
+
+fn parse_range(range: &str) -> Result<(u32, u32), DataError> {
+    Ok(match range.split_once("..") {
+        Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+        None => {
+            let cp = parse_code_point(range)?;
+            (cp, cp)
+        }
+    })
+}
+
+/// The lines of a UTS #39 data file, as their `;`-separated fields.
+fn parse_lines<'a>(
+    file: &'static str,
+    text: &'a str,
+    fields: usize,
+) -> impl Iterator<Item = Result<Vec<&'a str>, DataError>> {
+    text.trim_start_matches('\u{FEFF}')
+        .lines()
+        .filter_map(move |line| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                return None;
+            }
+            let line_fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+            Some(if line_fields.len() < fields {
+                Err(DataError::custom("UTS #39 data line")
+                    .with_display_context(file)
+                    .with_display_context(line))
+            } else {
+                Ok(line_fields)
+            })
+        })
+}
+
+/// The prototypes of `confusables.txt`.
+fn parse_confusables(text: &str) -> Result<BTreeMap<char, String>, DataError> {
+    let mut prototypes = BTreeMap::new();
+    for fields in parse_lines("confusables.txt", text, 2) {
+        let fields = fields?;
+        let source = parse_char(fields[0])?;
+        let prototype = fields[1]
+            .split_whitespace()
+            .map(parse_char)
+            .collect::<Result<String, _>>()?;
+        prototypes.insert(source, prototype);
+    }
+    Ok(prototypes)
+}
+
+/// The Allowed characters of `IdentifierStatus.txt`.
+fn parse_identifier_status(text: &str) -> Result<CodePointInversionList<'static>, DataError> {
+    let mut allowed = CodePointInversionListBuilder::new();
+    for fields in parse_lines("IdentifierStatus.txt", text, 2) {
+        let fields = fields?;
+        let (start, end) = parse_range(fields[0])?;
+        match fields[1] {
+            "Allowed" => allowed.add_range_u32(&(start..=end)),
+            "Restricted" => (),
+            status => {
+                return Err(DataError::custom("Identifier_Status").with_display_context(status))
+            }
+        }
+    }
+    Ok(allowed.build())
+}
+
+fn parse_identifier_type(name: &str) -> Result<IdentifierType, DataError> {
+    Ok(match name {
+        "Not_Character" => IdentifierType::NotCharacter,
+        "Deprecated" => IdentifierType::Deprecated,
+        "Default_Ignorable" => IdentifierType::DefaultIgnorable,
+        "Not_NFKC" => IdentifierType::NotNfkc,
+        "Not_XID" => IdentifierType::NotXid,
+        "Exclusion" => IdentifierType::Exclusion,
+        "Obsolete" => IdentifierType::Obsolete,
+        "Technical" => IdentifierType::Technical,
+        "Uncommon_Use" => IdentifierType::UncommonUse,
+        "Limited_Use" => IdentifierType::LimitedUse,
+        "Inclusion" => IdentifierType::Inclusion,
+        "Recommended" => IdentifierType::Recommended,
+        _ => return Err(DataError::custom("Identifier_Type").with_display_context(name)),
+    })
+}
+
+/// The sets of Identifier_Type values of all code points from `IdentifierType.txt`, as bits.
+fn parse_identifier_types(text: &str) -> Result<Vec<u16>, DataError> {
+    let mut values = vec![1 << IdentifierType::NotCharacter.0; 0x11_0000];
+    for fields in parse_lines("IdentifierType.txt", text, 2) {
+        let fields = fields?;
+        let (start, end) = parse_range(fields[0])?;
+        let mut bits = 0;
+        for name in fields[1].split_whitespace() {
+            bits |= 1 << parse_identifier_type(name)?.0;
+        }
+        for value in values
+            .iter_mut()
+            .take(end as usize + 1)
+            .skip(start as usize)
+        {
+            *value = bits;
+        }
+    }
+    Ok(values)
+}
+
+impl DataProvider<ConfusablesV1Marker> for crate::DatagenProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<ConfusablesV1Marker>, DataError> {
+        let prototypes = self
+            .source
+            .ucd()?
+            .read_and_parse_txt("confusables.txt", parse_confusables)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(ConfusablesV1 {
+                prototypes: prototypes
+                    .iter()
+                    .map(|(source, prototype)| (source, prototype.as_str()))
+                    .collect::<ZeroMap<_, _>>(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<ConfusablesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+impl DataProvider<IdentifierStatusV1Marker> for crate::DatagenProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<IdentifierStatusV1Marker>, DataError> {
+        let allowed = self
+            .source
+            .ucd()?
+            .read_and_parse_txt("IdentifierStatus.txt", parse_identifier_status)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(IdentifierStatusV1 {
+                allowed: allowed.clone(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<IdentifierStatusV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+impl DataProvider<IdentifierTypeV1Marker> for crate::DatagenProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<IdentifierTypeV1Marker>, DataError> {
+        let values = self
+            .source
+            .ucd()?
+            .read_and_parse_txt("IdentifierType.txt", parse_identifier_types)?;
+
+        let trie = CodePointTrieBuilder {
+            data: CodePointTrieBuilderData::ValuesByCodePoint(values),
+            default_value: 1 << IdentifierType::NotCharacter.0,
+            error_value: 1 << IdentifierType::NotCharacter.0,
+            trie_type: self.source.trie_type().to_internal(),
+        }
+        .build();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(IdentifierTypeV1 { trie })),
+        })
+    }
+}
+
+impl IterableDataProvider<IdentifierTypeV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_confusables() {
+        let prototypes = parse_confusables(
+            "\u{FEFF}# confusables.txt\n\n\
+             0430 ;\t0061 ;\tMA\t# ( а → a ) CYRILLIC SMALL LETTER A → LATIN SMALL LETTER A\n\
+             006D ;\t0072 006E ;\tMA\t# ( m → rn )\n",
+        )
+        .unwrap();
+        assert_eq!(prototypes.get(&'а').map(String::as_str), Some("a"));
+        assert_eq!(prototypes.get(&'m').map(String::as_str), Some("rn"));
+        assert!(parse_confusables("0430 ; 0061 ; MA\nD800 ; 0061 ; MA\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_identifier_types() {
+        let values =
+            parse_identifier_types("0041..005A    ; Recommended\n018D ; Technical Obsolete\n")
+                .unwrap();
+        assert_eq!(values[0x41], 1 << IdentifierType::Recommended.0);
+        assert_eq!(
+            values[0x18D],
+            1 << IdentifierType::Technical.0 | 1 << IdentifierType::Obsolete.0
+        );
+        assert_eq!(values[0x5B], 1 << IdentifierType::NotCharacter.0);
+        assert!(parse_identifier_types("0041 ; Recommended Unknown\n").is_err());
+    }
+
+    #[test]
+    fn test_confusables() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let payload: DataPayload<ConfusablesV1Marker> = provider
+            .load(Default::default())
+            .and_then(DataResponse::take_payload)
+            .expect("Loading was successful");
+        let prototypes = &payload.get().prototypes;
+
+        assert_eq!(prototypes.get(&'а'), Some("a")); // U+0430: Cyrillic Small Letter A
+        assert_eq!(prototypes.get(&'0'), Some("O"));
+        assert_eq!(prototypes.get(&'a'), None);
+    }
+}
@@ -0,0 +1,85 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_collections::codepointinvlist::{CodePointInversionList, CodePointInversionListBuilder};
+use icu_normalizer::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+
+/// The code point sets parsed out of `IdnaMappingTable.txt`.
+struct IdnaStatusSets {
+    ignored: CodePointInversionList<'static>,
+    disallowed: CodePointInversionList<'static>,
+    disallowed_std3: CodePointInversionList<'static>,
+}
+
+fn parse_code_point(s: &str) -> Result<u32, DataError> {
+    u32::from_str_radix(s.trim(), 16)
+        .map_err(|e| DataError::custom("IDNA mapping table code point").with_display_context(&e))
+}
+
+fn parse_idna_mapping_table(text: &str) -> Result<IdnaStatusSets, DataError> {
+    let mut ignored = CodePointInversionListBuilder::new();
+    let mut disallowed = CodePointInversionListBuilder::new();
+    let mut disallowed_std3 = CodePointInversionListBuilder::new();
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';');
+        let (range, status) = match (fields.next(), fields.next()) {
+            (Some(range), Some(status)) => (range.trim(), status.trim()),
+            _ => {
+                return Err(DataError::custom("IDNA mapping table line")
+                    .with_display_context(&line.to_string()))
+            }
+        };
+        let (start, end) = match range.split_once("..") {
+            Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+            None => {
+                let cp = parse_code_point(range)?;
+                (cp, cp)
+            }
+        };
+        match status {
+            "ignored" => ignored.add_range_u32(&(start..=end)),
+            "disallowed" => disallowed.add_range_u32(&(start..=end)),
+            "disallowed_STD3_valid" | "disallowed_STD3_mapped" => {
+                disallowed_std3.add_range_u32(&(start..=end))
+            }
+            // `valid`, `mapped` and `deviation` are covered by the normalization data
+            _ => (),
+        }
+    }
+    Ok(IdnaStatusSets {
+        ignored: ignored.build(),
+        disallowed: disallowed.build(),
+        disallowed_std3: disallowed_std3.build(),
+    })
+}
+
+impl DataProvider<Uts46StatusV1Marker> for crate::DatagenProvider {
+    fn load(&self, _req: DataRequest) -> Result<DataResponse<Uts46StatusV1Marker>, DataError> {
+        let sets = self
+            .source
+            .ucd()?
+            .read_and_parse_txt("IdnaMappingTable.txt", parse_idna_mapping_table)?;
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: Some(DataPayload::from_owned(Uts46StatusV1 {
+                ignored: sets.ignored.clone(),
+                disallowed: sets.disallowed.clone(),
+                disallowed_std3: sets.disallowed_std3.clone(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<Uts46StatusV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![DataLocale::default()])
+    }
+}
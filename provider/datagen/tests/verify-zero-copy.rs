@@ -8,7 +8,7 @@ use icu_provider_adapters::filter::Filterable;
 
 use icu_provider::prelude::*;
 
-use icu_datagen::{all_keys, CldrLocaleSubset, DatagenProvider, SourceData};
+use icu_datagen::{all_keys, keys, CldrLocaleSubset, DatagenProvider, SourceData};
 use std::cmp;
 use std::collections::BTreeSet;
 use std::mem::ManuallyDrop;
@@ -56,6 +56,10 @@ fn main() {
             )
             .unwrap()
             .with_icuexport(icu_testdata::paths::icuexport_toml_root())
+            .unwrap()
+            .with_hyphenation(icu_testdata::paths::hyphenation_root())
+            .unwrap()
+            .with_ucd(icu_testdata::paths::ucd_root())
             .unwrap(),
     }
     .filterable("icu4x-datagen locales")
@@ -68,7 +72,10 @@ fn main() {
     // violations for total_bytes_allocated (but not net_bytes_allocated)
     let mut total_violations = BTreeSet::new();
 
-    for key in all_keys().into_iter() {
+    for key in all_keys()
+        .into_iter()
+        .chain(keys(&["segmenter/hyphenation@1"]))
+    {
         let mut max_total_violation = 0;
         let mut max_net_violation = 0;
 
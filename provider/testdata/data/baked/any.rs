@@ -142,6 +142,9 @@ impl AnyProvider for BakedDataProvider {
         #[cfg(feature = "icu_normalizer")]
         const UTS46DECOMPOSITIONSUPPLEMENTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_normalizer::provider::Uts46DecompositionSupplementV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_normalizer")]
+        const UTS46STATUSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_normalizer::provider::Uts46StatusV1Marker::KEY.hashed();
         #[cfg(feature = "icu_plurals")]
         const CARDINALV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_plurals::provider::CardinalV1Marker::KEY.hashed();
@@ -149,24 +152,56 @@ impl AnyProvider for BakedDataProvider {
         const ORDINALV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_plurals::provider::OrdinalV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const AGEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::AgeV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const ALPHABETICV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::AlphabeticV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
         const ASCIIHEXDIGITV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::AsciiHexDigitV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const BIDIAUXILIARYPROPERTIESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiAuxiliaryPropertiesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const BIDICLASSNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiClassNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const BIDICLASSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::BidiClassV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const BIDICLASSVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiClassValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const BIDICLASSVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiClassValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const BIDICONTROLV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::BidiControlV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
         const BIDIMIRROREDV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::BidiMirroredV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const BIDIMIRRORINGGLYPHV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiMirroringGlyphV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const BIDIPAIREDBRACKETV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::BidiPairedBracketV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const CANONICALCOMBININGCLASSNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::CanonicalCombiningClassNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const CANONICALCOMBININGCLASSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::CanonicalCombiningClassV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const CANONICALCOMBININGCLASSVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::CanonicalCombiningClassValueToLongNameV1Marker::KEY
+                .hashed();
+        #[cfg(feature = "icu_properties")]
+        const CANONICALCOMBININGCLASSVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::CanonicalCombiningClassValueToShortNameV1Marker::KEY
+                .hashed();
+        #[cfg(feature = "icu_properties")]
         const CASEIGNORABLEV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::CaseIgnorableV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -188,6 +223,9 @@ impl AnyProvider for BakedDataProvider {
         const CHANGESWHENUPPERCASEDV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::ChangesWhenUppercasedV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const CHARACTERNAMESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::CharacterNamesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const DASHV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::DashV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -200,9 +238,18 @@ impl AnyProvider for BakedDataProvider {
         const DIACRITICV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::DiacriticV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const EASTASIANWIDTHNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::EastAsianWidthNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const EASTASIANWIDTHV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::EastAsianWidthV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const EASTASIANWIDTHVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::EastAsianWidthValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const EASTASIANWIDTHVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::EastAsianWidthValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const EMOJICOMPONENTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::EmojiComponentV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -224,18 +271,42 @@ impl AnyProvider for BakedDataProvider {
         const EXTENDERV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::ExtenderV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const GENERALCATEGORYMASKNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GeneralCategoryMaskNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const GENERALCATEGORYNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GeneralCategoryNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const GENERALCATEGORYV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::GeneralCategoryV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const GENERALCATEGORYVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GeneralCategoryValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const GENERALCATEGORYVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GeneralCategoryValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const GRAPHEMEBASEV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::GraphemeBaseV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const GRAPHEMECLUSTERBREAKNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GraphemeClusterBreakNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const GRAPHEMECLUSTERBREAKV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::GraphemeClusterBreakV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const GRAPHEMECLUSTERBREAKVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GraphemeClusterBreakValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const GRAPHEMECLUSTERBREAKVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::GraphemeClusterBreakValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const GRAPHEMEEXTENDV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::GraphemeExtendV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const HANGULSYLLABLETYPEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::HangulSyllableTypeV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const HEXDIGITV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::HexDigitV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -254,12 +325,36 @@ impl AnyProvider for BakedDataProvider {
         const IDSTRINARYOPERATORV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::IdsTrinaryOperatorV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const INDICSYLLABICCATEGORYV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::IndicSyllabicCategoryV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const JOINCONTROLV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::JoinControlV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const JOININGTYPENAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::JoiningTypeNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const JOININGTYPEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::JoiningTypeV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const JOININGTYPEVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::JoiningTypeValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const JOININGTYPEVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::JoiningTypeValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const LINEBREAKNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::LineBreakNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const LINEBREAKV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::LineBreakV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const LINEBREAKVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::LineBreakValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const LINEBREAKVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::LineBreakValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const LOGICALORDEREXCEPTIONV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::LogicalOrderExceptionV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -272,6 +367,12 @@ impl AnyProvider for BakedDataProvider {
         const NONCHARACTERCODEPOINTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::NoncharacterCodePointV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const NUMERICTYPEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::NumericTypeV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const NUMERICVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::NumericValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const PATTERNSYNTAXV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::PatternSyntaxV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -287,15 +388,33 @@ impl AnyProvider for BakedDataProvider {
         const REGIONALINDICATORV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::RegionalIndicatorV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const SCRIPTNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::ScriptNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const SCRIPTV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::ScriptV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const SCRIPTVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::ScriptValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const SCRIPTVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::ScriptValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const SCRIPTWITHEXTENSIONSPROPERTYV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::ScriptWithExtensionsPropertyV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const SENTENCEBREAKNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::SentenceBreakNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const SENTENCEBREAKV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::SentenceBreakV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const SENTENCEBREAKVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::SentenceBreakValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const SENTENCEBREAKVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::SentenceBreakValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const SENTENCETERMINALV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::SentenceTerminalV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -314,12 +433,24 @@ impl AnyProvider for BakedDataProvider {
         const VARIATIONSELECTORV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::VariationSelectorV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const VERTICALORIENTATIONV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::VerticalOrientationV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const WHITESPACEV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::WhiteSpaceV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const WORDBREAKNAMETOVALUEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::WordBreakNameToValueV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const WORDBREAKV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::WordBreakV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
+        const WORDBREAKVALUETOLONGNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::WordBreakValueToLongNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
+        const WORDBREAKVALUETOSHORTNAMEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_properties::provider::WordBreakValueToShortNameV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_properties")]
         const XIDCONTINUEV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_properties::provider::XidContinueV1Marker::KEY.hashed();
         #[cfg(feature = "icu_properties")]
@@ -340,6 +471,9 @@ impl AnyProvider for BakedDataProvider {
         const GRAPHEMECLUSTERBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker::KEY.hashed();
         #[cfg(feature = "icu_segmenter")]
+        const HYPHENATIONPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_segmenter::provider::HyphenationPatternsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_segmenter")]
         const LINEBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::LineBreakDataV1Marker::KEY.hashed();
         #[cfg(feature = "icu_segmenter")]
@@ -349,11 +483,23 @@ impl AnyProvider for BakedDataProvider {
         const SENTENCEBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::SentenceBreakDataV1Marker::KEY.hashed();
         #[cfg(feature = "icu_segmenter")]
+        const SENTENCEBREAKSUPPRESSIONSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_segmenter::provider::SentenceBreakSuppressionsV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_segmenter")]
         const UCHARDICTIONARYBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::UCharDictionaryBreakDataV1Marker::KEY.hashed();
         #[cfg(feature = "icu_segmenter")]
         const WORDBREAKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_segmenter::provider::WordBreakDataV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_spoof")]
+        const CONFUSABLESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_spoof::provider::ConfusablesV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_spoof")]
+        const IDENTIFIERSTATUSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_spoof::provider::IdentifierStatusV1Marker::KEY.hashed();
+        #[cfg(feature = "icu_spoof")]
+        const IDENTIFIERTYPEV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_spoof::provider::IdentifierTypeV1Marker::KEY.hashed();
         #[cfg(feature = "icu_timezone")]
         const METAZONEPERIODV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_timezone::provider::MetazonePeriodV1Marker::KEY.hashed();
@@ -649,6 +795,12 @@ impl AnyProvider for BakedDataProvider {
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_normalizer")]
+            UTS46STATUSV1MARKER => normalizer::uts46st_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_plurals")]
             CARDINALV1MARKER => plurals::cardinal_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
@@ -662,6 +814,12 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            AGEV1MARKER => props::age_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             ALPHABETICV1MARKER => props::alpha_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -674,12 +832,36 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            BIDIAUXILIARYPROPERTIESV1MARKER => props::bidiauxiliaryprops_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            BIDICLASSNAMETOVALUEV1MARKER => propnames::from::bc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             BIDICLASSV1MARKER => props::bc_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            BIDICLASSVALUETOLONGNAMEV1MARKER => propnames::to::long::bc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            BIDICLASSVALUETOSHORTNAMEV1MARKER => propnames::to::short::bc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             BIDICONTROLV1MARKER => props::bidi_c_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -692,12 +874,42 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            BIDIMIRRORINGGLYPHV1MARKER => props::bmg_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            BIDIPAIREDBRACKETV1MARKER => props::bpb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            CANONICALCOMBININGCLASSNAMETOVALUEV1MARKER => propnames::from::ccc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             CANONICALCOMBININGCLASSV1MARKER => props::ccc_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            CANONICALCOMBININGCLASSVALUETOLONGNAMEV1MARKER => propnames::to::long::ccc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            CANONICALCOMBININGCLASSVALUETOSHORTNAMEV1MARKER => propnames::to::short::ccc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             CASEIGNORABLEV1MARKER => props::ci_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -740,6 +952,12 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            CHARACTERNAMESV1MARKER => props::na_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             DASHV1MARKER => props::dash_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -764,12 +982,30 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            EASTASIANWIDTHNAMETOVALUEV1MARKER => propnames::from::ea_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             EASTASIANWIDTHV1MARKER => props::ea_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            EASTASIANWIDTHVALUETOLONGNAMEV1MARKER => propnames::to::long::ea_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            EASTASIANWIDTHVALUETOSHORTNAMEV1MARKER => propnames::to::short::ea_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             EMOJICOMPONENTV1MARKER => props::ecomp_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -812,30 +1048,78 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            GENERALCATEGORYMASKNAMETOVALUEV1MARKER => propnames::from::gcm_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            GENERALCATEGORYNAMETOVALUEV1MARKER => propnames::from::gc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             GENERALCATEGORYV1MARKER => props::gc_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            GENERALCATEGORYVALUETOLONGNAMEV1MARKER => propnames::to::long::gc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            GENERALCATEGORYVALUETOSHORTNAMEV1MARKER => propnames::to::short::gc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             GRAPHEMEBASEV1MARKER => props::gr_base_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            GRAPHEMECLUSTERBREAKNAMETOVALUEV1MARKER => propnames::from::gcb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             GRAPHEMECLUSTERBREAKV1MARKER => props::gcb_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            GRAPHEMECLUSTERBREAKVALUETOLONGNAMEV1MARKER => propnames::to::long::gcb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            GRAPHEMECLUSTERBREAKVALUETOSHORTNAMEV1MARKER => propnames::to::short::gcb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             GRAPHEMEEXTENDV1MARKER => props::gr_ext_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            HANGULSYLLABLETYPEV1MARKER => props::hst_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             HEXDIGITV1MARKER => props::hex_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -872,18 +1156,66 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            INDICSYLLABICCATEGORYV1MARKER => props::insc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             JOINCONTROLV1MARKER => props::join_c_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            JOININGTYPENAMETOVALUEV1MARKER => propnames::from::jt_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            JOININGTYPEV1MARKER => props::jt_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            JOININGTYPEVALUETOLONGNAMEV1MARKER => propnames::to::long::jt_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            JOININGTYPEVALUETOSHORTNAMEV1MARKER => propnames::to::short::jt_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            LINEBREAKNAMETOVALUEV1MARKER => propnames::from::lb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             LINEBREAKV1MARKER => props::lb_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            LINEBREAKVALUETOLONGNAMEV1MARKER => propnames::to::long::lb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            LINEBREAKVALUETOSHORTNAMEV1MARKER => propnames::to::short::lb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             LOGICALORDEREXCEPTIONV1MARKER => props::loe_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -908,6 +1240,18 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            NUMERICTYPEV1MARKER => props::nt_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            NUMERICVALUEV1MARKER => props::nv_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             PATTERNSYNTAXV1MARKER => props::pat_syn_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -938,24 +1282,60 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            SCRIPTNAMETOVALUEV1MARKER => propnames::from::sc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             SCRIPTV1MARKER => props::sc_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            SCRIPTVALUETOLONGNAMEV1MARKER => propnames::to::long::sc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            SCRIPTVALUETOSHORTNAMEV1MARKER => propnames::to::short::sc_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             SCRIPTWITHEXTENSIONSPROPERTYV1MARKER => props::scx_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            SENTENCEBREAKNAMETOVALUEV1MARKER => propnames::from::sb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             SENTENCEBREAKV1MARKER => props::sb_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            SENTENCEBREAKVALUETOLONGNAMEV1MARKER => propnames::to::long::sb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            SENTENCEBREAKVALUETOSHORTNAMEV1MARKER => propnames::to::short::sb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             SENTENCETERMINALV1MARKER => props::sterm_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -992,18 +1372,42 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            VERTICALORIENTATIONV1MARKER => props::vo_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             WHITESPACEV1MARKER => props::wspace_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            WORDBREAKNAMETOVALUEV1MARKER => propnames::from::wb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             WORDBREAKV1MARKER => props::wb_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_properties")]
+            WORDBREAKVALUETOLONGNAMEV1MARKER => propnames::to::long::wb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
+            WORDBREAKVALUETOSHORTNAMEV1MARKER => propnames::to::short::wb_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_properties")]
             XIDCONTINUEV1MARKER => props::xidc_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
@@ -1036,13 +1440,19 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_segmenter")]
-            GRAPHEMECLUSTERBREAKDATAV1MARKER => segmenter::grapheme_v1::DATA
+            GRAPHEMECLUSTERBREAKDATAV1MARKER => segmenter::grapheme_v2::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_segmenter")]
+            HYPHENATIONPATTERNSV1MARKER => segmenter::hyphenation_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_segmenter")]
-            LINEBREAKDATAV1MARKER => segmenter::line_v1::DATA
+            LINEBREAKDATAV1MARKER => segmenter::line_v2::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
@@ -1054,7 +1464,13 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_segmenter")]
-            SENTENCEBREAKDATAV1MARKER => segmenter::sentence_v1::DATA
+            SENTENCEBREAKDATAV1MARKER => segmenter::sentence_v2::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_segmenter")]
+            SENTENCEBREAKSUPPRESSIONSV1MARKER => segmenter::sentence::suppressions_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
@@ -1066,7 +1482,25 @@ impl AnyProvider for BakedDataProvider {
                 .map(AnyPayload::from_static_ref)
                 .ok_or(DataErrorKind::MissingLocale),
             #[cfg(feature = "icu_segmenter")]
-            WORDBREAKDATAV1MARKER => segmenter::word_v1::DATA
+            WORDBREAKDATAV1MARKER => segmenter::word_v2::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_spoof")]
+            CONFUSABLESV1MARKER => spoof::confusables_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_spoof")]
+            IDENTIFIERSTATUSV1MARKER => spoof::idstatus_v1::DATA
+                .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                .copied()
+                .map(AnyPayload::from_static_ref)
+                .ok_or(DataErrorKind::MissingLocale),
+            #[cfg(feature = "icu_spoof")]
+            IDENTIFIERTYPEV1MARKER => spoof::idtype_v1::DATA
                 .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                 .copied()
                 .map(AnyPayload::from_static_ref)
@@ -10,8 +10,10 @@ mod list;
 mod locid_transform;
 mod normalizer;
 mod plurals;
+mod propnames;
 mod props;
 mod segmenter;
+mod spoof;
 mod time_zone;
 /// This data provider was programmatically generated by [`icu_datagen`](
 /// https://unicode-org.github.io/icu4x-docs/doc/icu_datagen/enum.Out.html#variant.Module).
@@ -669,6 +671,19 @@ impl DataProvider<::icu_normalizer::provider::Uts46DecompositionSupplementV1Mark
         })
     }
 }
+#[cfg(feature = "icu_normalizer")]
+impl DataProvider<::icu_normalizer::provider::Uts46StatusV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_normalizer::provider::Uts46StatusV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *normalizer::uts46st_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_normalizer::provider::Uts46StatusV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_plurals")]
 impl DataProvider<::icu_plurals::provider::CardinalV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::CardinalV1Marker>, DataError> {
@@ -696,6 +711,19 @@ impl DataProvider<::icu_plurals::provider::OrdinalV1Marker> for BakedDataProvide
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::AgeV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::AgeV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::age_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::AgeV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::AlphabeticV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::AlphabeticV1Marker>, DataError> {
         Ok(DataResponse {
@@ -722,6 +750,32 @@ impl DataProvider<::icu_properties::provider::AsciiHexDigitV1Marker> for BakedDa
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiAuxiliaryPropertiesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiAuxiliaryPropertiesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::bidiauxiliaryprops_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiAuxiliaryPropertiesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiClassNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiClassNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::bc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiClassNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::BidiClassV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiClassV1Marker>, DataError> {
         Ok(DataResponse {
@@ -735,6 +789,32 @@ impl DataProvider<::icu_properties::provider::BidiClassV1Marker> for BakedDataPr
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiClassValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiClassValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::bc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiClassValueToLongNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiClassValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiClassValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::bc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiClassValueToShortNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::BidiControlV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiControlV1Marker>, DataError> {
         Ok(DataResponse {
@@ -761,6 +841,47 @@ impl DataProvider<::icu_properties::provider::BidiMirroredV1Marker> for BakedDat
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiMirroringGlyphV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiMirroringGlyphV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::bmg_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiMirroringGlyphV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::BidiPairedBracketV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::BidiPairedBracketV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::bpb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::BidiPairedBracketV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::CanonicalCombiningClassNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CanonicalCombiningClassNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::ccc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::CanonicalCombiningClassNameToValueV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::CanonicalCombiningClassV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CanonicalCombiningClassV1Marker>, DataError> {
         Ok(DataResponse {
@@ -774,6 +895,36 @@ impl DataProvider<::icu_properties::provider::CanonicalCombiningClassV1Marker> f
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::CanonicalCombiningClassValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CanonicalCombiningClassValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::ccc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::CanonicalCombiningClassValueToLongNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::CanonicalCombiningClassValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CanonicalCombiningClassValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::ccc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::CanonicalCombiningClassValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::CaseIgnorableV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CaseIgnorableV1Marker>, DataError> {
         Ok(DataResponse {
@@ -865,6 +1016,19 @@ impl DataProvider<::icu_properties::provider::ChangesWhenUppercasedV1Marker> for
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::CharacterNamesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::CharacterNamesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::na_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::CharacterNamesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::DashV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::DashV1Marker>, DataError> {
         Ok(DataResponse {
@@ -917,6 +1081,19 @@ impl DataProvider<::icu_properties::provider::DiacriticV1Marker> for BakedDataPr
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::EastAsianWidthNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::EastAsianWidthNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::ea_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::EastAsianWidthNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::EastAsianWidthV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::EastAsianWidthV1Marker>, DataError> {
         Ok(DataResponse {
@@ -930,6 +1107,36 @@ impl DataProvider<::icu_properties::provider::EastAsianWidthV1Marker> for BakedD
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::EastAsianWidthValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::EastAsianWidthValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::ea_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::EastAsianWidthValueToLongNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::EastAsianWidthValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::EastAsianWidthValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::ea_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::EastAsianWidthValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::EmojiComponentV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::EmojiComponentV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1021,6 +1228,34 @@ impl DataProvider<::icu_properties::provider::ExtenderV1Marker> for BakedDataPro
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GeneralCategoryMaskNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GeneralCategoryMaskNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::gcm_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GeneralCategoryMaskNameToValueV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GeneralCategoryNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GeneralCategoryNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::gc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GeneralCategoryNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::GeneralCategoryV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GeneralCategoryV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1034,6 +1269,36 @@ impl DataProvider<::icu_properties::provider::GeneralCategoryV1Marker> for Baked
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GeneralCategoryValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GeneralCategoryValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::gc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GeneralCategoryValueToLongNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GeneralCategoryValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GeneralCategoryValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::gc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GeneralCategoryValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::GraphemeBaseV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeBaseV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1047,6 +1312,21 @@ impl DataProvider<::icu_properties::provider::GraphemeBaseV1Marker> for BakedDat
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GraphemeClusterBreakNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeClusterBreakNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::gcb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GraphemeClusterBreakNameToValueV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::GraphemeClusterBreakV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeClusterBreakV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1060,6 +1340,36 @@ impl DataProvider<::icu_properties::provider::GraphemeClusterBreakV1Marker> for
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GraphemeClusterBreakValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeClusterBreakValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::gcb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GraphemeClusterBreakValueToLongNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::GraphemeClusterBreakValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeClusterBreakValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::gcb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::GraphemeClusterBreakValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::GraphemeExtendV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::GraphemeExtendV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1073,6 +1383,19 @@ impl DataProvider<::icu_properties::provider::GraphemeExtendV1Marker> for BakedD
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::HangulSyllableTypeV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::HangulSyllableTypeV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::hst_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::HangulSyllableTypeV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::HexDigitV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::HexDigitV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1151,6 +1474,19 @@ impl DataProvider<::icu_properties::provider::IdsTrinaryOperatorV1Marker> for Ba
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::IndicSyllabicCategoryV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::IndicSyllabicCategoryV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::insc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::IndicSyllabicCategoryV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::JoinControlV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::JoinControlV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1164,6 +1500,73 @@ impl DataProvider<::icu_properties::provider::JoinControlV1Marker> for BakedData
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::JoiningTypeNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::JoiningTypeNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::jt_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::JoiningTypeNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::JoiningTypeV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::JoiningTypeV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::jt_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::JoiningTypeV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::JoiningTypeValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::JoiningTypeValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::jt_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::JoiningTypeValueToLongNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::JoiningTypeValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::JoiningTypeValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::jt_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::JoiningTypeValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::LineBreakNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::LineBreakNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::lb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::LineBreakNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::LineBreakV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::LineBreakV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1177,6 +1580,32 @@ impl DataProvider<::icu_properties::provider::LineBreakV1Marker> for BakedDataPr
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::LineBreakValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::LineBreakValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::lb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::LineBreakValueToLongNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::LineBreakValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::LineBreakValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::lb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::LineBreakValueToShortNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::LogicalOrderExceptionV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::LogicalOrderExceptionV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1229,6 +1658,32 @@ impl DataProvider<::icu_properties::provider::NoncharacterCodePointV1Marker> for
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::NumericTypeV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::NumericTypeV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::nt_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::NumericTypeV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::NumericValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::NumericValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::nv_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::NumericValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::PatternSyntaxV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::PatternSyntaxV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1294,6 +1749,19 @@ impl DataProvider<::icu_properties::provider::RegionalIndicatorV1Marker> for Bak
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::ScriptNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::ScriptNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::sc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::ScriptNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::ScriptV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::ScriptV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1307,6 +1775,32 @@ impl DataProvider<::icu_properties::provider::ScriptV1Marker> for BakedDataProvi
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::ScriptValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::ScriptValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::sc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::ScriptValueToLongNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::ScriptValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::ScriptValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::sc_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::ScriptValueToShortNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::ScriptWithExtensionsPropertyV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::ScriptWithExtensionsPropertyV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1322,6 +1816,19 @@ impl DataProvider<::icu_properties::provider::ScriptWithExtensionsPropertyV1Mark
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::SentenceBreakNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::SentenceBreakNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::sb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::SentenceBreakNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::SentenceBreakV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::SentenceBreakV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1335,6 +1842,36 @@ impl DataProvider<::icu_properties::provider::SentenceBreakV1Marker> for BakedDa
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::SentenceBreakValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::SentenceBreakValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::sb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::SentenceBreakValueToLongNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::SentenceBreakValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::SentenceBreakValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::sb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_properties::provider::SentenceBreakValueToShortNameV1Marker::KEY, req)
+                    })?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::SentenceTerminalV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::SentenceTerminalV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1413,6 +1950,19 @@ impl DataProvider<::icu_properties::provider::VariationSelectorV1Marker> for Bak
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::VerticalOrientationV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::VerticalOrientationV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *props::vo_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::VerticalOrientationV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::WhiteSpaceV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::WhiteSpaceV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1426,6 +1976,19 @@ impl DataProvider<::icu_properties::provider::WhiteSpaceV1Marker> for BakedDataP
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::WordBreakNameToValueV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::WordBreakNameToValueV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::from::wb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::WordBreakNameToValueV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::WordBreakV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::WordBreakV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1439,6 +2002,32 @@ impl DataProvider<::icu_properties::provider::WordBreakV1Marker> for BakedDataPr
     }
 }
 #[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::WordBreakValueToLongNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::WordBreakValueToLongNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::long::wb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::WordBreakValueToLongNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
+impl DataProvider<::icu_properties::provider::WordBreakValueToShortNameV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::WordBreakValueToShortNameV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *propnames::to::short::wb_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_properties::provider::WordBreakValueToShortNameV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_properties")]
 impl DataProvider<::icu_properties::provider::XidContinueV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::XidContinueV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1532,7 +2121,7 @@ impl DataProvider<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker> f
         Ok(DataResponse {
             metadata: Default::default(),
             payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
-                *segmenter::grapheme_v1::DATA
+                *segmenter::grapheme_v2::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker::KEY, req))?,
             ))),
@@ -1540,12 +2129,25 @@ impl DataProvider<::icu_segmenter::provider::GraphemeClusterBreakDataV1Marker> f
     }
 }
 #[cfg(feature = "icu_segmenter")]
+impl DataProvider<::icu_segmenter::provider::HyphenationPatternsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_segmenter::provider::HyphenationPatternsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *segmenter::hyphenation_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::HyphenationPatternsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_segmenter")]
 impl DataProvider<::icu_segmenter::provider::LineBreakDataV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_segmenter::provider::LineBreakDataV1Marker>, DataError> {
         Ok(DataResponse {
             metadata: Default::default(),
             payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
-                *segmenter::line_v1::DATA
+                *segmenter::line_v2::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::LineBreakDataV1Marker::KEY, req))?,
             ))),
@@ -1571,7 +2173,7 @@ impl DataProvider<::icu_segmenter::provider::SentenceBreakDataV1Marker> for Bake
         Ok(DataResponse {
             metadata: Default::default(),
             payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
-                *segmenter::sentence_v1::DATA
+                *segmenter::sentence_v2::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::SentenceBreakDataV1Marker::KEY, req))?,
             ))),
@@ -1579,6 +2181,19 @@ impl DataProvider<::icu_segmenter::provider::SentenceBreakDataV1Marker> for Bake
     }
 }
 #[cfg(feature = "icu_segmenter")]
+impl DataProvider<::icu_segmenter::provider::SentenceBreakSuppressionsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_segmenter::provider::SentenceBreakSuppressionsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *segmenter::sentence::suppressions_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::SentenceBreakSuppressionsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_segmenter")]
 impl DataProvider<::icu_segmenter::provider::UCharDictionaryBreakDataV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_segmenter::provider::UCharDictionaryBreakDataV1Marker>, DataError> {
         Ok(DataResponse {
@@ -1597,13 +2212,52 @@ impl DataProvider<::icu_segmenter::provider::WordBreakDataV1Marker> for BakedDat
         Ok(DataResponse {
             metadata: Default::default(),
             payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
-                *segmenter::word_v1::DATA
+                *segmenter::word_v2::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_segmenter::provider::WordBreakDataV1Marker::KEY, req))?,
             ))),
         })
     }
 }
+#[cfg(feature = "icu_spoof")]
+impl DataProvider<::icu_spoof::provider::ConfusablesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_spoof::provider::ConfusablesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *spoof::confusables_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_spoof::provider::ConfusablesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_spoof")]
+impl DataProvider<::icu_spoof::provider::IdentifierStatusV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_spoof::provider::IdentifierStatusV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *spoof::idstatus_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_spoof::provider::IdentifierStatusV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+#[cfg(feature = "icu_spoof")]
+impl DataProvider<::icu_spoof::provider::IdentifierTypeV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_spoof::provider::IdentifierTypeV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *spoof::idtype_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_spoof::provider::IdentifierTypeV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 #[cfg(feature = "icu_timezone")]
 impl DataProvider<::icu_timezone::provider::MetazonePeriodV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::MetazonePeriodV1Marker>, DataError> {
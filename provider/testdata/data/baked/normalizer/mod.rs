@@ -6,3 +6,4 @@ pub mod nfdex_v1;
 pub mod nfkd_v1;
 pub mod nfkdex_v1;
 pub mod uts46d_v1;
+pub mod uts46st_v1;
@@ -0,0 +1,508 @@
+// @generated
+#![cfg(feature = "icu_normalizer")]
+type DataStruct =
+    <::icu_normalizer::provider::Uts46StatusV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_normalizer::provider::Uts46StatusV1 {
+    ignored: unsafe {
+        #[allow(unused_unsafe)]
+        ::icu_collections::codepointinvlist::CodePointInversionList::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    173u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8, 79u8, 3u8, 0u8, 0u8, 80u8, 3u8,
+                    0u8, 0u8, 11u8, 24u8, 0u8, 0u8, 14u8, 24u8, 0u8, 0u8, 15u8, 24u8, 0u8, 0u8,
+                    16u8, 24u8, 0u8, 0u8, 11u8, 32u8, 0u8, 0u8, 12u8, 32u8, 0u8, 0u8, 96u8, 32u8,
+                    0u8, 0u8, 97u8, 32u8, 0u8, 0u8, 100u8, 32u8, 0u8, 0u8, 101u8, 32u8, 0u8, 0u8,
+                    0u8, 254u8, 0u8, 0u8, 16u8, 254u8, 0u8, 0u8, 255u8, 254u8, 0u8, 0u8, 0u8,
+                    255u8, 0u8, 0u8, 160u8, 188u8, 1u8, 0u8, 164u8, 188u8, 1u8, 0u8, 0u8, 1u8,
+                    14u8, 0u8, 240u8, 1u8, 14u8, 0u8,
+                ])
+            },
+            270usize,
+        )
+    },
+    disallowed: unsafe {
+        #[allow(unused_unsafe)]
+        ::icu_collections::codepointinvlist::CodePointInversionList::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 120u8, 3u8, 0u8, 0u8, 122u8, 3u8,
+                    0u8, 0u8, 128u8, 3u8, 0u8, 0u8, 132u8, 3u8, 0u8, 0u8, 139u8, 3u8, 0u8, 0u8,
+                    140u8, 3u8, 0u8, 0u8, 141u8, 3u8, 0u8, 0u8, 142u8, 3u8, 0u8, 0u8, 162u8, 3u8,
+                    0u8, 0u8, 163u8, 3u8, 0u8, 0u8, 192u8, 4u8, 0u8, 0u8, 193u8, 4u8, 0u8, 0u8,
+                    48u8, 5u8, 0u8, 0u8, 49u8, 5u8, 0u8, 0u8, 87u8, 5u8, 0u8, 0u8, 89u8, 5u8, 0u8,
+                    0u8, 139u8, 5u8, 0u8, 0u8, 141u8, 5u8, 0u8, 0u8, 144u8, 5u8, 0u8, 0u8, 145u8,
+                    5u8, 0u8, 0u8, 200u8, 5u8, 0u8, 0u8, 208u8, 5u8, 0u8, 0u8, 235u8, 5u8, 0u8,
+                    0u8, 239u8, 5u8, 0u8, 0u8, 245u8, 5u8, 0u8, 0u8, 6u8, 6u8, 0u8, 0u8, 28u8, 6u8,
+                    0u8, 0u8, 29u8, 6u8, 0u8, 0u8, 221u8, 6u8, 0u8, 0u8, 222u8, 6u8, 0u8, 0u8,
+                    14u8, 7u8, 0u8, 0u8, 16u8, 7u8, 0u8, 0u8, 75u8, 7u8, 0u8, 0u8, 77u8, 7u8, 0u8,
+                    0u8, 178u8, 7u8, 0u8, 0u8, 192u8, 7u8, 0u8, 0u8, 251u8, 7u8, 0u8, 0u8, 253u8,
+                    7u8, 0u8, 0u8, 46u8, 8u8, 0u8, 0u8, 48u8, 8u8, 0u8, 0u8, 63u8, 8u8, 0u8, 0u8,
+                    64u8, 8u8, 0u8, 0u8, 92u8, 8u8, 0u8, 0u8, 94u8, 8u8, 0u8, 0u8, 95u8, 8u8, 0u8,
+                    0u8, 96u8, 8u8, 0u8, 0u8, 107u8, 8u8, 0u8, 0u8, 112u8, 8u8, 0u8, 0u8, 143u8,
+                    8u8, 0u8, 0u8, 152u8, 8u8, 0u8, 0u8, 226u8, 8u8, 0u8, 0u8, 227u8, 8u8, 0u8,
+                    0u8, 132u8, 9u8, 0u8, 0u8, 133u8, 9u8, 0u8, 0u8, 141u8, 9u8, 0u8, 0u8, 143u8,
+                    9u8, 0u8, 0u8, 145u8, 9u8, 0u8, 0u8, 147u8, 9u8, 0u8, 0u8, 169u8, 9u8, 0u8,
+                    0u8, 170u8, 9u8, 0u8, 0u8, 177u8, 9u8, 0u8, 0u8, 178u8, 9u8, 0u8, 0u8, 179u8,
+                    9u8, 0u8, 0u8, 182u8, 9u8, 0u8, 0u8, 186u8, 9u8, 0u8, 0u8, 188u8, 9u8, 0u8,
+                    0u8, 197u8, 9u8, 0u8, 0u8, 199u8, 9u8, 0u8, 0u8, 201u8, 9u8, 0u8, 0u8, 203u8,
+                    9u8, 0u8, 0u8, 207u8, 9u8, 0u8, 0u8, 215u8, 9u8, 0u8, 0u8, 216u8, 9u8, 0u8,
+                    0u8, 220u8, 9u8, 0u8, 0u8, 222u8, 9u8, 0u8, 0u8, 223u8, 9u8, 0u8, 0u8, 228u8,
+                    9u8, 0u8, 0u8, 230u8, 9u8, 0u8, 0u8, 255u8, 9u8, 0u8, 0u8, 1u8, 10u8, 0u8, 0u8,
+                    4u8, 10u8, 0u8, 0u8, 5u8, 10u8, 0u8, 0u8, 11u8, 10u8, 0u8, 0u8, 15u8, 10u8,
+                    0u8, 0u8, 17u8, 10u8, 0u8, 0u8, 19u8, 10u8, 0u8, 0u8, 41u8, 10u8, 0u8, 0u8,
+                    42u8, 10u8, 0u8, 0u8, 49u8, 10u8, 0u8, 0u8, 50u8, 10u8, 0u8, 0u8, 52u8, 10u8,
+                    0u8, 0u8, 53u8, 10u8, 0u8, 0u8, 55u8, 10u8, 0u8, 0u8, 56u8, 10u8, 0u8, 0u8,
+                    58u8, 10u8, 0u8, 0u8, 60u8, 10u8, 0u8, 0u8, 61u8, 10u8, 0u8, 0u8, 62u8, 10u8,
+                    0u8, 0u8, 67u8, 10u8, 0u8, 0u8, 71u8, 10u8, 0u8, 0u8, 73u8, 10u8, 0u8, 0u8,
+                    75u8, 10u8, 0u8, 0u8, 78u8, 10u8, 0u8, 0u8, 81u8, 10u8, 0u8, 0u8, 82u8, 10u8,
+                    0u8, 0u8, 89u8, 10u8, 0u8, 0u8, 93u8, 10u8, 0u8, 0u8, 94u8, 10u8, 0u8, 0u8,
+                    95u8, 10u8, 0u8, 0u8, 102u8, 10u8, 0u8, 0u8, 119u8, 10u8, 0u8, 0u8, 129u8,
+                    10u8, 0u8, 0u8, 132u8, 10u8, 0u8, 0u8, 133u8, 10u8, 0u8, 0u8, 142u8, 10u8, 0u8,
+                    0u8, 143u8, 10u8, 0u8, 0u8, 146u8, 10u8, 0u8, 0u8, 147u8, 10u8, 0u8, 0u8,
+                    169u8, 10u8, 0u8, 0u8, 170u8, 10u8, 0u8, 0u8, 177u8, 10u8, 0u8, 0u8, 178u8,
+                    10u8, 0u8, 0u8, 180u8, 10u8, 0u8, 0u8, 181u8, 10u8, 0u8, 0u8, 186u8, 10u8, 0u8,
+                    0u8, 188u8, 10u8, 0u8, 0u8, 198u8, 10u8, 0u8, 0u8, 199u8, 10u8, 0u8, 0u8,
+                    202u8, 10u8, 0u8, 0u8, 203u8, 10u8, 0u8, 0u8, 206u8, 10u8, 0u8, 0u8, 208u8,
+                    10u8, 0u8, 0u8, 209u8, 10u8, 0u8, 0u8, 224u8, 10u8, 0u8, 0u8, 228u8, 10u8, 0u8,
+                    0u8, 230u8, 10u8, 0u8, 0u8, 242u8, 10u8, 0u8, 0u8, 249u8, 10u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 1u8, 11u8, 0u8, 0u8, 4u8, 11u8, 0u8, 0u8, 5u8, 11u8, 0u8, 0u8,
+                    13u8, 11u8, 0u8, 0u8, 15u8, 11u8, 0u8, 0u8, 17u8, 11u8, 0u8, 0u8, 19u8, 11u8,
+                    0u8, 0u8, 41u8, 11u8, 0u8, 0u8, 42u8, 11u8, 0u8, 0u8, 49u8, 11u8, 0u8, 0u8,
+                    50u8, 11u8, 0u8, 0u8, 52u8, 11u8, 0u8, 0u8, 53u8, 11u8, 0u8, 0u8, 58u8, 11u8,
+                    0u8, 0u8, 60u8, 11u8, 0u8, 0u8, 69u8, 11u8, 0u8, 0u8, 71u8, 11u8, 0u8, 0u8,
+                    73u8, 11u8, 0u8, 0u8, 75u8, 11u8, 0u8, 0u8, 78u8, 11u8, 0u8, 0u8, 85u8, 11u8,
+                    0u8, 0u8, 88u8, 11u8, 0u8, 0u8, 92u8, 11u8, 0u8, 0u8, 94u8, 11u8, 0u8, 0u8,
+                    95u8, 11u8, 0u8, 0u8, 100u8, 11u8, 0u8, 0u8, 102u8, 11u8, 0u8, 0u8, 120u8,
+                    11u8, 0u8, 0u8, 130u8, 11u8, 0u8, 0u8, 132u8, 11u8, 0u8, 0u8, 133u8, 11u8, 0u8,
+                    0u8, 139u8, 11u8, 0u8, 0u8, 142u8, 11u8, 0u8, 0u8, 145u8, 11u8, 0u8, 0u8,
+                    146u8, 11u8, 0u8, 0u8, 150u8, 11u8, 0u8, 0u8, 153u8, 11u8, 0u8, 0u8, 155u8,
+                    11u8, 0u8, 0u8, 156u8, 11u8, 0u8, 0u8, 157u8, 11u8, 0u8, 0u8, 158u8, 11u8, 0u8,
+                    0u8, 160u8, 11u8, 0u8, 0u8, 163u8, 11u8, 0u8, 0u8, 165u8, 11u8, 0u8, 0u8,
+                    168u8, 11u8, 0u8, 0u8, 171u8, 11u8, 0u8, 0u8, 174u8, 11u8, 0u8, 0u8, 186u8,
+                    11u8, 0u8, 0u8, 190u8, 11u8, 0u8, 0u8, 195u8, 11u8, 0u8, 0u8, 198u8, 11u8, 0u8,
+                    0u8, 201u8, 11u8, 0u8, 0u8, 202u8, 11u8, 0u8, 0u8, 206u8, 11u8, 0u8, 0u8,
+                    208u8, 11u8, 0u8, 0u8, 209u8, 11u8, 0u8, 0u8, 215u8, 11u8, 0u8, 0u8, 216u8,
+                    11u8, 0u8, 0u8, 230u8, 11u8, 0u8, 0u8, 251u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+                    0u8, 13u8, 12u8, 0u8, 0u8, 14u8, 12u8, 0u8, 0u8, 17u8, 12u8, 0u8, 0u8, 18u8,
+                    12u8, 0u8, 0u8, 41u8, 12u8, 0u8, 0u8, 42u8, 12u8, 0u8, 0u8, 58u8, 12u8, 0u8,
+                    0u8, 60u8, 12u8, 0u8, 0u8, 69u8, 12u8, 0u8, 0u8, 70u8, 12u8, 0u8, 0u8, 73u8,
+                    12u8, 0u8, 0u8, 74u8, 12u8, 0u8, 0u8, 78u8, 12u8, 0u8, 0u8, 85u8, 12u8, 0u8,
+                    0u8, 87u8, 12u8, 0u8, 0u8, 88u8, 12u8, 0u8, 0u8, 91u8, 12u8, 0u8, 0u8, 93u8,
+                    12u8, 0u8, 0u8, 94u8, 12u8, 0u8, 0u8, 96u8, 12u8, 0u8, 0u8, 100u8, 12u8, 0u8,
+                    0u8, 102u8, 12u8, 0u8, 0u8, 112u8, 12u8, 0u8, 0u8, 119u8, 12u8, 0u8, 0u8,
+                    141u8, 12u8, 0u8, 0u8, 142u8, 12u8, 0u8, 0u8, 145u8, 12u8, 0u8, 0u8, 146u8,
+                    12u8, 0u8, 0u8, 169u8, 12u8, 0u8, 0u8, 170u8, 12u8, 0u8, 0u8, 180u8, 12u8, 0u8,
+                    0u8, 181u8, 12u8, 0u8, 0u8, 186u8, 12u8, 0u8, 0u8, 188u8, 12u8, 0u8, 0u8,
+                    197u8, 12u8, 0u8, 0u8, 198u8, 12u8, 0u8, 0u8, 201u8, 12u8, 0u8, 0u8, 202u8,
+                    12u8, 0u8, 0u8, 206u8, 12u8, 0u8, 0u8, 213u8, 12u8, 0u8, 0u8, 215u8, 12u8, 0u8,
+                    0u8, 221u8, 12u8, 0u8, 0u8, 223u8, 12u8, 0u8, 0u8, 224u8, 12u8, 0u8, 0u8,
+                    228u8, 12u8, 0u8, 0u8, 230u8, 12u8, 0u8, 0u8, 240u8, 12u8, 0u8, 0u8, 241u8,
+                    12u8, 0u8, 0u8, 243u8, 12u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 13u8, 13u8, 0u8,
+                    0u8, 14u8, 13u8, 0u8, 0u8, 17u8, 13u8, 0u8, 0u8, 18u8, 13u8, 0u8, 0u8, 69u8,
+                    13u8, 0u8, 0u8, 70u8, 13u8, 0u8, 0u8, 73u8, 13u8, 0u8, 0u8, 74u8, 13u8, 0u8,
+                    0u8, 80u8, 13u8, 0u8, 0u8, 84u8, 13u8, 0u8, 0u8, 100u8, 13u8, 0u8, 0u8, 102u8,
+                    13u8, 0u8, 0u8, 128u8, 13u8, 0u8, 0u8, 129u8, 13u8, 0u8, 0u8, 132u8, 13u8, 0u8,
+                    0u8, 133u8, 13u8, 0u8, 0u8, 151u8, 13u8, 0u8, 0u8, 154u8, 13u8, 0u8, 0u8,
+                    178u8, 13u8, 0u8, 0u8, 179u8, 13u8, 0u8, 0u8, 188u8, 13u8, 0u8, 0u8, 189u8,
+                    13u8, 0u8, 0u8, 190u8, 13u8, 0u8, 0u8, 192u8, 13u8, 0u8, 0u8, 199u8, 13u8, 0u8,
+                    0u8, 202u8, 13u8, 0u8, 0u8, 203u8, 13u8, 0u8, 0u8, 207u8, 13u8, 0u8, 0u8,
+                    213u8, 13u8, 0u8, 0u8, 214u8, 13u8, 0u8, 0u8, 215u8, 13u8, 0u8, 0u8, 216u8,
+                    13u8, 0u8, 0u8, 224u8, 13u8, 0u8, 0u8, 230u8, 13u8, 0u8, 0u8, 240u8, 13u8, 0u8,
+                    0u8, 242u8, 13u8, 0u8, 0u8, 245u8, 13u8, 0u8, 0u8, 1u8, 14u8, 0u8, 0u8, 59u8,
+                    14u8, 0u8, 0u8, 63u8, 14u8, 0u8, 0u8, 92u8, 14u8, 0u8, 0u8, 129u8, 14u8, 0u8,
+                    0u8, 131u8, 14u8, 0u8, 0u8, 132u8, 14u8, 0u8, 0u8, 133u8, 14u8, 0u8, 0u8,
+                    134u8, 14u8, 0u8, 0u8, 139u8, 14u8, 0u8, 0u8, 140u8, 14u8, 0u8, 0u8, 164u8,
+                    14u8, 0u8, 0u8, 165u8, 14u8, 0u8, 0u8, 166u8, 14u8, 0u8, 0u8, 167u8, 14u8, 0u8,
+                    0u8, 190u8, 14u8, 0u8, 0u8, 192u8, 14u8, 0u8, 0u8, 197u8, 14u8, 0u8, 0u8,
+                    198u8, 14u8, 0u8, 0u8, 199u8, 14u8, 0u8, 0u8, 200u8, 14u8, 0u8, 0u8, 206u8,
+                    14u8, 0u8, 0u8, 208u8, 14u8, 0u8, 0u8, 218u8, 14u8, 0u8, 0u8, 220u8, 14u8, 0u8,
+                    0u8, 224u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 72u8, 15u8, 0u8, 0u8, 73u8,
+                    15u8, 0u8, 0u8, 109u8, 15u8, 0u8, 0u8, 113u8, 15u8, 0u8, 0u8, 152u8, 15u8, 0u8,
+                    0u8, 153u8, 15u8, 0u8, 0u8, 189u8, 15u8, 0u8, 0u8, 190u8, 15u8, 0u8, 0u8,
+                    205u8, 15u8, 0u8, 0u8, 206u8, 15u8, 0u8, 0u8, 219u8, 15u8, 0u8, 0u8, 0u8, 16u8,
+                    0u8, 0u8, 160u8, 16u8, 0u8, 0u8, 199u8, 16u8, 0u8, 0u8, 200u8, 16u8, 0u8, 0u8,
+                    205u8, 16u8, 0u8, 0u8, 206u8, 16u8, 0u8, 0u8, 208u8, 16u8, 0u8, 0u8, 95u8,
+                    17u8, 0u8, 0u8, 97u8, 17u8, 0u8, 0u8, 73u8, 18u8, 0u8, 0u8, 74u8, 18u8, 0u8,
+                    0u8, 78u8, 18u8, 0u8, 0u8, 80u8, 18u8, 0u8, 0u8, 87u8, 18u8, 0u8, 0u8, 88u8,
+                    18u8, 0u8, 0u8, 89u8, 18u8, 0u8, 0u8, 90u8, 18u8, 0u8, 0u8, 94u8, 18u8, 0u8,
+                    0u8, 96u8, 18u8, 0u8, 0u8, 137u8, 18u8, 0u8, 0u8, 138u8, 18u8, 0u8, 0u8, 142u8,
+                    18u8, 0u8, 0u8, 144u8, 18u8, 0u8, 0u8, 177u8, 18u8, 0u8, 0u8, 178u8, 18u8, 0u8,
+                    0u8, 182u8, 18u8, 0u8, 0u8, 184u8, 18u8, 0u8, 0u8, 191u8, 18u8, 0u8, 0u8,
+                    192u8, 18u8, 0u8, 0u8, 193u8, 18u8, 0u8, 0u8, 194u8, 18u8, 0u8, 0u8, 198u8,
+                    18u8, 0u8, 0u8, 200u8, 18u8, 0u8, 0u8, 215u8, 18u8, 0u8, 0u8, 216u8, 18u8, 0u8,
+                    0u8, 17u8, 19u8, 0u8, 0u8, 18u8, 19u8, 0u8, 0u8, 22u8, 19u8, 0u8, 0u8, 24u8,
+                    19u8, 0u8, 0u8, 91u8, 19u8, 0u8, 0u8, 93u8, 19u8, 0u8, 0u8, 125u8, 19u8, 0u8,
+                    0u8, 128u8, 19u8, 0u8, 0u8, 154u8, 19u8, 0u8, 0u8, 160u8, 19u8, 0u8, 0u8,
+                    246u8, 19u8, 0u8, 0u8, 248u8, 19u8, 0u8, 0u8, 254u8, 19u8, 0u8, 0u8, 0u8, 20u8,
+                    0u8, 0u8, 128u8, 22u8, 0u8, 0u8, 129u8, 22u8, 0u8, 0u8, 157u8, 22u8, 0u8, 0u8,
+                    160u8, 22u8, 0u8, 0u8, 249u8, 22u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 22u8, 23u8,
+                    0u8, 0u8, 31u8, 23u8, 0u8, 0u8, 55u8, 23u8, 0u8, 0u8, 64u8, 23u8, 0u8, 0u8,
+                    84u8, 23u8, 0u8, 0u8, 96u8, 23u8, 0u8, 0u8, 109u8, 23u8, 0u8, 0u8, 110u8, 23u8,
+                    0u8, 0u8, 113u8, 23u8, 0u8, 0u8, 114u8, 23u8, 0u8, 0u8, 116u8, 23u8, 0u8, 0u8,
+                    128u8, 23u8, 0u8, 0u8, 180u8, 23u8, 0u8, 0u8, 182u8, 23u8, 0u8, 0u8, 222u8,
+                    23u8, 0u8, 0u8, 224u8, 23u8, 0u8, 0u8, 234u8, 23u8, 0u8, 0u8, 240u8, 23u8, 0u8,
+                    0u8, 250u8, 23u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 6u8, 24u8, 0u8, 0u8, 7u8,
+                    24u8, 0u8, 0u8, 14u8, 24u8, 0u8, 0u8, 15u8, 24u8, 0u8, 0u8, 26u8, 24u8, 0u8,
+                    0u8, 32u8, 24u8, 0u8, 0u8, 121u8, 24u8, 0u8, 0u8, 128u8, 24u8, 0u8, 0u8, 171u8,
+                    24u8, 0u8, 0u8, 176u8, 24u8, 0u8, 0u8, 246u8, 24u8, 0u8, 0u8, 0u8, 25u8, 0u8,
+                    0u8, 31u8, 25u8, 0u8, 0u8, 32u8, 25u8, 0u8, 0u8, 44u8, 25u8, 0u8, 0u8, 48u8,
+                    25u8, 0u8, 0u8, 60u8, 25u8, 0u8, 0u8, 64u8, 25u8, 0u8, 0u8, 65u8, 25u8, 0u8,
+                    0u8, 68u8, 25u8, 0u8, 0u8, 110u8, 25u8, 0u8, 0u8, 112u8, 25u8, 0u8, 0u8, 117u8,
+                    25u8, 0u8, 0u8, 128u8, 25u8, 0u8, 0u8, 172u8, 25u8, 0u8, 0u8, 176u8, 25u8, 0u8,
+                    0u8, 202u8, 25u8, 0u8, 0u8, 208u8, 25u8, 0u8, 0u8, 219u8, 25u8, 0u8, 0u8,
+                    222u8, 25u8, 0u8, 0u8, 28u8, 26u8, 0u8, 0u8, 30u8, 26u8, 0u8, 0u8, 95u8, 26u8,
+                    0u8, 0u8, 96u8, 26u8, 0u8, 0u8, 125u8, 26u8, 0u8, 0u8, 127u8, 26u8, 0u8, 0u8,
+                    138u8, 26u8, 0u8, 0u8, 144u8, 26u8, 0u8, 0u8, 154u8, 26u8, 0u8, 0u8, 160u8,
+                    26u8, 0u8, 0u8, 174u8, 26u8, 0u8, 0u8, 176u8, 26u8, 0u8, 0u8, 207u8, 26u8, 0u8,
+                    0u8, 0u8, 27u8, 0u8, 0u8, 77u8, 27u8, 0u8, 0u8, 80u8, 27u8, 0u8, 0u8, 127u8,
+                    27u8, 0u8, 0u8, 128u8, 27u8, 0u8, 0u8, 244u8, 27u8, 0u8, 0u8, 252u8, 27u8, 0u8,
+                    0u8, 56u8, 28u8, 0u8, 0u8, 59u8, 28u8, 0u8, 0u8, 74u8, 28u8, 0u8, 0u8, 77u8,
+                    28u8, 0u8, 0u8, 137u8, 28u8, 0u8, 0u8, 144u8, 28u8, 0u8, 0u8, 187u8, 28u8, 0u8,
+                    0u8, 189u8, 28u8, 0u8, 0u8, 200u8, 28u8, 0u8, 0u8, 208u8, 28u8, 0u8, 0u8,
+                    251u8, 28u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 22u8, 31u8, 0u8, 0u8, 24u8, 31u8,
+                    0u8, 0u8, 30u8, 31u8, 0u8, 0u8, 32u8, 31u8, 0u8, 0u8, 70u8, 31u8, 0u8, 0u8,
+                    72u8, 31u8, 0u8, 0u8, 78u8, 31u8, 0u8, 0u8, 80u8, 31u8, 0u8, 0u8, 88u8, 31u8,
+                    0u8, 0u8, 89u8, 31u8, 0u8, 0u8, 90u8, 31u8, 0u8, 0u8, 91u8, 31u8, 0u8, 0u8,
+                    92u8, 31u8, 0u8, 0u8, 93u8, 31u8, 0u8, 0u8, 94u8, 31u8, 0u8, 0u8, 95u8, 31u8,
+                    0u8, 0u8, 126u8, 31u8, 0u8, 0u8, 128u8, 31u8, 0u8, 0u8, 181u8, 31u8, 0u8, 0u8,
+                    182u8, 31u8, 0u8, 0u8, 197u8, 31u8, 0u8, 0u8, 198u8, 31u8, 0u8, 0u8, 212u8,
+                    31u8, 0u8, 0u8, 214u8, 31u8, 0u8, 0u8, 220u8, 31u8, 0u8, 0u8, 221u8, 31u8, 0u8,
+                    0u8, 240u8, 31u8, 0u8, 0u8, 242u8, 31u8, 0u8, 0u8, 245u8, 31u8, 0u8, 0u8,
+                    246u8, 31u8, 0u8, 0u8, 255u8, 31u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 14u8, 32u8,
+                    0u8, 0u8, 16u8, 32u8, 0u8, 0u8, 36u8, 32u8, 0u8, 0u8, 39u8, 32u8, 0u8, 0u8,
+                    40u8, 32u8, 0u8, 0u8, 47u8, 32u8, 0u8, 0u8, 97u8, 32u8, 0u8, 0u8, 100u8, 32u8,
+                    0u8, 0u8, 101u8, 32u8, 0u8, 0u8, 112u8, 32u8, 0u8, 0u8, 114u8, 32u8, 0u8, 0u8,
+                    116u8, 32u8, 0u8, 0u8, 143u8, 32u8, 0u8, 0u8, 144u8, 32u8, 0u8, 0u8, 157u8,
+                    32u8, 0u8, 0u8, 160u8, 32u8, 0u8, 0u8, 193u8, 32u8, 0u8, 0u8, 208u8, 32u8, 0u8,
+                    0u8, 241u8, 32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 50u8, 33u8, 0u8, 0u8, 51u8,
+                    33u8, 0u8, 0u8, 131u8, 33u8, 0u8, 0u8, 132u8, 33u8, 0u8, 0u8, 140u8, 33u8, 0u8,
+                    0u8, 144u8, 33u8, 0u8, 0u8, 39u8, 36u8, 0u8, 0u8, 64u8, 36u8, 0u8, 0u8, 75u8,
+                    36u8, 0u8, 0u8, 96u8, 36u8, 0u8, 0u8, 136u8, 36u8, 0u8, 0u8, 156u8, 36u8, 0u8,
+                    0u8, 116u8, 43u8, 0u8, 0u8, 118u8, 43u8, 0u8, 0u8, 150u8, 43u8, 0u8, 0u8,
+                    151u8, 43u8, 0u8, 0u8, 244u8, 44u8, 0u8, 0u8, 249u8, 44u8, 0u8, 0u8, 38u8,
+                    45u8, 0u8, 0u8, 39u8, 45u8, 0u8, 0u8, 40u8, 45u8, 0u8, 0u8, 45u8, 45u8, 0u8,
+                    0u8, 46u8, 45u8, 0u8, 0u8, 48u8, 45u8, 0u8, 0u8, 104u8, 45u8, 0u8, 0u8, 111u8,
+                    45u8, 0u8, 0u8, 113u8, 45u8, 0u8, 0u8, 127u8, 45u8, 0u8, 0u8, 151u8, 45u8, 0u8,
+                    0u8, 160u8, 45u8, 0u8, 0u8, 167u8, 45u8, 0u8, 0u8, 168u8, 45u8, 0u8, 0u8,
+                    175u8, 45u8, 0u8, 0u8, 176u8, 45u8, 0u8, 0u8, 183u8, 45u8, 0u8, 0u8, 184u8,
+                    45u8, 0u8, 0u8, 191u8, 45u8, 0u8, 0u8, 192u8, 45u8, 0u8, 0u8, 199u8, 45u8, 0u8,
+                    0u8, 200u8, 45u8, 0u8, 0u8, 207u8, 45u8, 0u8, 0u8, 208u8, 45u8, 0u8, 0u8,
+                    215u8, 45u8, 0u8, 0u8, 216u8, 45u8, 0u8, 0u8, 223u8, 45u8, 0u8, 0u8, 224u8,
+                    45u8, 0u8, 0u8, 94u8, 46u8, 0u8, 0u8, 128u8, 46u8, 0u8, 0u8, 154u8, 46u8, 0u8,
+                    0u8, 155u8, 46u8, 0u8, 0u8, 244u8, 46u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 214u8,
+                    47u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 64u8, 48u8, 0u8, 0u8, 65u8, 48u8, 0u8,
+                    0u8, 151u8, 48u8, 0u8, 0u8, 153u8, 48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 5u8,
+                    49u8, 0u8, 0u8, 48u8, 49u8, 0u8, 0u8, 49u8, 49u8, 0u8, 0u8, 100u8, 49u8, 0u8,
+                    0u8, 101u8, 49u8, 0u8, 0u8, 143u8, 49u8, 0u8, 0u8, 144u8, 49u8, 0u8, 0u8,
+                    228u8, 49u8, 0u8, 0u8, 240u8, 49u8, 0u8, 0u8, 31u8, 50u8, 0u8, 0u8, 32u8, 50u8,
+                    0u8, 0u8, 194u8, 51u8, 0u8, 0u8, 195u8, 51u8, 0u8, 0u8, 199u8, 51u8, 0u8, 0u8,
+                    200u8, 51u8, 0u8, 0u8, 216u8, 51u8, 0u8, 0u8, 217u8, 51u8, 0u8, 0u8, 141u8,
+                    164u8, 0u8, 0u8, 144u8, 164u8, 0u8, 0u8, 199u8, 164u8, 0u8, 0u8, 208u8, 164u8,
+                    0u8, 0u8, 44u8, 166u8, 0u8, 0u8, 64u8, 166u8, 0u8, 0u8, 248u8, 166u8, 0u8, 0u8,
+                    0u8, 167u8, 0u8, 0u8, 203u8, 167u8, 0u8, 0u8, 208u8, 167u8, 0u8, 0u8, 210u8,
+                    167u8, 0u8, 0u8, 211u8, 167u8, 0u8, 0u8, 212u8, 167u8, 0u8, 0u8, 213u8, 167u8,
+                    0u8, 0u8, 218u8, 167u8, 0u8, 0u8, 242u8, 167u8, 0u8, 0u8, 45u8, 168u8, 0u8,
+                    0u8, 48u8, 168u8, 0u8, 0u8, 58u8, 168u8, 0u8, 0u8, 64u8, 168u8, 0u8, 0u8,
+                    120u8, 168u8, 0u8, 0u8, 128u8, 168u8, 0u8, 0u8, 198u8, 168u8, 0u8, 0u8, 206u8,
+                    168u8, 0u8, 0u8, 218u8, 168u8, 0u8, 0u8, 224u8, 168u8, 0u8, 0u8, 84u8, 169u8,
+                    0u8, 0u8, 95u8, 169u8, 0u8, 0u8, 125u8, 169u8, 0u8, 0u8, 128u8, 169u8, 0u8,
+                    0u8, 206u8, 169u8, 0u8, 0u8, 207u8, 169u8, 0u8, 0u8, 218u8, 169u8, 0u8, 0u8,
+                    222u8, 169u8, 0u8, 0u8, 255u8, 169u8, 0u8, 0u8, 0u8, 170u8, 0u8, 0u8, 55u8,
+                    170u8, 0u8, 0u8, 64u8, 170u8, 0u8, 0u8, 78u8, 170u8, 0u8, 0u8, 80u8, 170u8,
+                    0u8, 0u8, 90u8, 170u8, 0u8, 0u8, 92u8, 170u8, 0u8, 0u8, 195u8, 170u8, 0u8, 0u8,
+                    219u8, 170u8, 0u8, 0u8, 247u8, 170u8, 0u8, 0u8, 1u8, 171u8, 0u8, 0u8, 7u8,
+                    171u8, 0u8, 0u8, 9u8, 171u8, 0u8, 0u8, 15u8, 171u8, 0u8, 0u8, 17u8, 171u8, 0u8,
+                    0u8, 23u8, 171u8, 0u8, 0u8, 32u8, 171u8, 0u8, 0u8, 39u8, 171u8, 0u8, 0u8, 40u8,
+                    171u8, 0u8, 0u8, 47u8, 171u8, 0u8, 0u8, 48u8, 171u8, 0u8, 0u8, 108u8, 171u8,
+                    0u8, 0u8, 112u8, 171u8, 0u8, 0u8, 238u8, 171u8, 0u8, 0u8, 240u8, 171u8, 0u8,
+                    0u8, 250u8, 171u8, 0u8, 0u8, 0u8, 172u8, 0u8, 0u8, 164u8, 215u8, 0u8, 0u8,
+                    176u8, 215u8, 0u8, 0u8, 199u8, 215u8, 0u8, 0u8, 203u8, 215u8, 0u8, 0u8, 252u8,
+                    215u8, 0u8, 0u8, 0u8, 249u8, 0u8, 0u8, 110u8, 250u8, 0u8, 0u8, 112u8, 250u8,
+                    0u8, 0u8, 218u8, 250u8, 0u8, 0u8, 0u8, 251u8, 0u8, 0u8, 7u8, 251u8, 0u8, 0u8,
+                    19u8, 251u8, 0u8, 0u8, 24u8, 251u8, 0u8, 0u8, 29u8, 251u8, 0u8, 0u8, 55u8,
+                    251u8, 0u8, 0u8, 56u8, 251u8, 0u8, 0u8, 61u8, 251u8, 0u8, 0u8, 62u8, 251u8,
+                    0u8, 0u8, 63u8, 251u8, 0u8, 0u8, 64u8, 251u8, 0u8, 0u8, 66u8, 251u8, 0u8, 0u8,
+                    67u8, 251u8, 0u8, 0u8, 69u8, 251u8, 0u8, 0u8, 70u8, 251u8, 0u8, 0u8, 195u8,
+                    251u8, 0u8, 0u8, 211u8, 251u8, 0u8, 0u8, 144u8, 253u8, 0u8, 0u8, 146u8, 253u8,
+                    0u8, 0u8, 200u8, 253u8, 0u8, 0u8, 207u8, 253u8, 0u8, 0u8, 208u8, 253u8, 0u8,
+                    0u8, 240u8, 253u8, 0u8, 0u8, 18u8, 254u8, 0u8, 0u8, 19u8, 254u8, 0u8, 0u8,
+                    25u8, 254u8, 0u8, 0u8, 32u8, 254u8, 0u8, 0u8, 48u8, 254u8, 0u8, 0u8, 49u8,
+                    254u8, 0u8, 0u8, 82u8, 254u8, 0u8, 0u8, 84u8, 254u8, 0u8, 0u8, 103u8, 254u8,
+                    0u8, 0u8, 104u8, 254u8, 0u8, 0u8, 108u8, 254u8, 0u8, 0u8, 112u8, 254u8, 0u8,
+                    0u8, 117u8, 254u8, 0u8, 0u8, 118u8, 254u8, 0u8, 0u8, 253u8, 254u8, 0u8, 0u8,
+                    255u8, 254u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 1u8, 255u8, 0u8, 0u8, 160u8,
+                    255u8, 0u8, 0u8, 161u8, 255u8, 0u8, 0u8, 191u8, 255u8, 0u8, 0u8, 194u8, 255u8,
+                    0u8, 0u8, 200u8, 255u8, 0u8, 0u8, 202u8, 255u8, 0u8, 0u8, 208u8, 255u8, 0u8,
+                    0u8, 210u8, 255u8, 0u8, 0u8, 216u8, 255u8, 0u8, 0u8, 218u8, 255u8, 0u8, 0u8,
+                    221u8, 255u8, 0u8, 0u8, 224u8, 255u8, 0u8, 0u8, 231u8, 255u8, 0u8, 0u8, 232u8,
+                    255u8, 0u8, 0u8, 239u8, 255u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 12u8, 0u8, 1u8,
+                    0u8, 13u8, 0u8, 1u8, 0u8, 39u8, 0u8, 1u8, 0u8, 40u8, 0u8, 1u8, 0u8, 59u8, 0u8,
+                    1u8, 0u8, 60u8, 0u8, 1u8, 0u8, 62u8, 0u8, 1u8, 0u8, 63u8, 0u8, 1u8, 0u8, 78u8,
+                    0u8, 1u8, 0u8, 80u8, 0u8, 1u8, 0u8, 94u8, 0u8, 1u8, 0u8, 128u8, 0u8, 1u8, 0u8,
+                    251u8, 0u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 3u8, 1u8, 1u8, 0u8, 7u8, 1u8, 1u8,
+                    0u8, 52u8, 1u8, 1u8, 0u8, 55u8, 1u8, 1u8, 0u8, 143u8, 1u8, 1u8, 0u8, 144u8,
+                    1u8, 1u8, 0u8, 157u8, 1u8, 1u8, 0u8, 160u8, 1u8, 1u8, 0u8, 161u8, 1u8, 1u8,
+                    0u8, 208u8, 1u8, 1u8, 0u8, 254u8, 1u8, 1u8, 0u8, 128u8, 2u8, 1u8, 0u8, 157u8,
+                    2u8, 1u8, 0u8, 160u8, 2u8, 1u8, 0u8, 209u8, 2u8, 1u8, 0u8, 224u8, 2u8, 1u8,
+                    0u8, 252u8, 2u8, 1u8, 0u8, 0u8, 3u8, 1u8, 0u8, 36u8, 3u8, 1u8, 0u8, 45u8, 3u8,
+                    1u8, 0u8, 75u8, 3u8, 1u8, 0u8, 80u8, 3u8, 1u8, 0u8, 123u8, 3u8, 1u8, 0u8,
+                    128u8, 3u8, 1u8, 0u8, 158u8, 3u8, 1u8, 0u8, 159u8, 3u8, 1u8, 0u8, 196u8, 3u8,
+                    1u8, 0u8, 200u8, 3u8, 1u8, 0u8, 214u8, 3u8, 1u8, 0u8, 0u8, 4u8, 1u8, 0u8,
+                    158u8, 4u8, 1u8, 0u8, 160u8, 4u8, 1u8, 0u8, 170u8, 4u8, 1u8, 0u8, 176u8, 4u8,
+                    1u8, 0u8, 212u8, 4u8, 1u8, 0u8, 216u8, 4u8, 1u8, 0u8, 252u8, 4u8, 1u8, 0u8,
+                    0u8, 5u8, 1u8, 0u8, 40u8, 5u8, 1u8, 0u8, 48u8, 5u8, 1u8, 0u8, 100u8, 5u8, 1u8,
+                    0u8, 111u8, 5u8, 1u8, 0u8, 123u8, 5u8, 1u8, 0u8, 124u8, 5u8, 1u8, 0u8, 139u8,
+                    5u8, 1u8, 0u8, 140u8, 5u8, 1u8, 0u8, 147u8, 5u8, 1u8, 0u8, 148u8, 5u8, 1u8,
+                    0u8, 150u8, 5u8, 1u8, 0u8, 151u8, 5u8, 1u8, 0u8, 162u8, 5u8, 1u8, 0u8, 163u8,
+                    5u8, 1u8, 0u8, 178u8, 5u8, 1u8, 0u8, 179u8, 5u8, 1u8, 0u8, 186u8, 5u8, 1u8,
+                    0u8, 187u8, 5u8, 1u8, 0u8, 189u8, 5u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 55u8, 7u8,
+                    1u8, 0u8, 64u8, 7u8, 1u8, 0u8, 86u8, 7u8, 1u8, 0u8, 96u8, 7u8, 1u8, 0u8, 104u8,
+                    7u8, 1u8, 0u8, 128u8, 7u8, 1u8, 0u8, 134u8, 7u8, 1u8, 0u8, 135u8, 7u8, 1u8,
+                    0u8, 177u8, 7u8, 1u8, 0u8, 178u8, 7u8, 1u8, 0u8, 187u8, 7u8, 1u8, 0u8, 0u8,
+                    8u8, 1u8, 0u8, 6u8, 8u8, 1u8, 0u8, 8u8, 8u8, 1u8, 0u8, 9u8, 8u8, 1u8, 0u8,
+                    10u8, 8u8, 1u8, 0u8, 54u8, 8u8, 1u8, 0u8, 55u8, 8u8, 1u8, 0u8, 57u8, 8u8, 1u8,
+                    0u8, 60u8, 8u8, 1u8, 0u8, 61u8, 8u8, 1u8, 0u8, 63u8, 8u8, 1u8, 0u8, 86u8, 8u8,
+                    1u8, 0u8, 87u8, 8u8, 1u8, 0u8, 159u8, 8u8, 1u8, 0u8, 167u8, 8u8, 1u8, 0u8,
+                    176u8, 8u8, 1u8, 0u8, 224u8, 8u8, 1u8, 0u8, 243u8, 8u8, 1u8, 0u8, 244u8, 8u8,
+                    1u8, 0u8, 246u8, 8u8, 1u8, 0u8, 251u8, 8u8, 1u8, 0u8, 28u8, 9u8, 1u8, 0u8,
+                    31u8, 9u8, 1u8, 0u8, 58u8, 9u8, 1u8, 0u8, 63u8, 9u8, 1u8, 0u8, 64u8, 9u8, 1u8,
+                    0u8, 128u8, 9u8, 1u8, 0u8, 184u8, 9u8, 1u8, 0u8, 188u8, 9u8, 1u8, 0u8, 208u8,
+                    9u8, 1u8, 0u8, 210u8, 9u8, 1u8, 0u8, 4u8, 10u8, 1u8, 0u8, 5u8, 10u8, 1u8, 0u8,
+                    7u8, 10u8, 1u8, 0u8, 12u8, 10u8, 1u8, 0u8, 20u8, 10u8, 1u8, 0u8, 21u8, 10u8,
+                    1u8, 0u8, 24u8, 10u8, 1u8, 0u8, 25u8, 10u8, 1u8, 0u8, 54u8, 10u8, 1u8, 0u8,
+                    56u8, 10u8, 1u8, 0u8, 59u8, 10u8, 1u8, 0u8, 63u8, 10u8, 1u8, 0u8, 73u8, 10u8,
+                    1u8, 0u8, 80u8, 10u8, 1u8, 0u8, 89u8, 10u8, 1u8, 0u8, 96u8, 10u8, 1u8, 0u8,
+                    160u8, 10u8, 1u8, 0u8, 192u8, 10u8, 1u8, 0u8, 231u8, 10u8, 1u8, 0u8, 235u8,
+                    10u8, 1u8, 0u8, 247u8, 10u8, 1u8, 0u8, 0u8, 11u8, 1u8, 0u8, 54u8, 11u8, 1u8,
+                    0u8, 57u8, 11u8, 1u8, 0u8, 86u8, 11u8, 1u8, 0u8, 88u8, 11u8, 1u8, 0u8, 115u8,
+                    11u8, 1u8, 0u8, 120u8, 11u8, 1u8, 0u8, 146u8, 11u8, 1u8, 0u8, 153u8, 11u8, 1u8,
+                    0u8, 157u8, 11u8, 1u8, 0u8, 169u8, 11u8, 1u8, 0u8, 176u8, 11u8, 1u8, 0u8, 0u8,
+                    12u8, 1u8, 0u8, 73u8, 12u8, 1u8, 0u8, 128u8, 12u8, 1u8, 0u8, 179u8, 12u8, 1u8,
+                    0u8, 192u8, 12u8, 1u8, 0u8, 243u8, 12u8, 1u8, 0u8, 250u8, 12u8, 1u8, 0u8, 40u8,
+                    13u8, 1u8, 0u8, 48u8, 13u8, 1u8, 0u8, 58u8, 13u8, 1u8, 0u8, 96u8, 14u8, 1u8,
+                    0u8, 127u8, 14u8, 1u8, 0u8, 128u8, 14u8, 1u8, 0u8, 170u8, 14u8, 1u8, 0u8,
+                    171u8, 14u8, 1u8, 0u8, 174u8, 14u8, 1u8, 0u8, 176u8, 14u8, 1u8, 0u8, 178u8,
+                    14u8, 1u8, 0u8, 0u8, 15u8, 1u8, 0u8, 40u8, 15u8, 1u8, 0u8, 48u8, 15u8, 1u8,
+                    0u8, 90u8, 15u8, 1u8, 0u8, 112u8, 15u8, 1u8, 0u8, 138u8, 15u8, 1u8, 0u8, 176u8,
+                    15u8, 1u8, 0u8, 204u8, 15u8, 1u8, 0u8, 224u8, 15u8, 1u8, 0u8, 247u8, 15u8, 1u8,
+                    0u8, 0u8, 16u8, 1u8, 0u8, 78u8, 16u8, 1u8, 0u8, 82u8, 16u8, 1u8, 0u8, 118u8,
+                    16u8, 1u8, 0u8, 127u8, 16u8, 1u8, 0u8, 189u8, 16u8, 1u8, 0u8, 190u8, 16u8, 1u8,
+                    0u8, 195u8, 16u8, 1u8, 0u8, 208u8, 16u8, 1u8, 0u8, 233u8, 16u8, 1u8, 0u8,
+                    240u8, 16u8, 1u8, 0u8, 250u8, 16u8, 1u8, 0u8, 0u8, 17u8, 1u8, 0u8, 53u8, 17u8,
+                    1u8, 0u8, 54u8, 17u8, 1u8, 0u8, 72u8, 17u8, 1u8, 0u8, 80u8, 17u8, 1u8, 0u8,
+                    119u8, 17u8, 1u8, 0u8, 128u8, 17u8, 1u8, 0u8, 224u8, 17u8, 1u8, 0u8, 225u8,
+                    17u8, 1u8, 0u8, 245u8, 17u8, 1u8, 0u8, 0u8, 18u8, 1u8, 0u8, 18u8, 18u8, 1u8,
+                    0u8, 19u8, 18u8, 1u8, 0u8, 63u8, 18u8, 1u8, 0u8, 128u8, 18u8, 1u8, 0u8, 135u8,
+                    18u8, 1u8, 0u8, 136u8, 18u8, 1u8, 0u8, 137u8, 18u8, 1u8, 0u8, 138u8, 18u8, 1u8,
+                    0u8, 142u8, 18u8, 1u8, 0u8, 143u8, 18u8, 1u8, 0u8, 158u8, 18u8, 1u8, 0u8,
+                    159u8, 18u8, 1u8, 0u8, 170u8, 18u8, 1u8, 0u8, 176u8, 18u8, 1u8, 0u8, 235u8,
+                    18u8, 1u8, 0u8, 240u8, 18u8, 1u8, 0u8, 250u8, 18u8, 1u8, 0u8, 0u8, 19u8, 1u8,
+                    0u8, 4u8, 19u8, 1u8, 0u8, 5u8, 19u8, 1u8, 0u8, 13u8, 19u8, 1u8, 0u8, 15u8,
+                    19u8, 1u8, 0u8, 17u8, 19u8, 1u8, 0u8, 19u8, 19u8, 1u8, 0u8, 41u8, 19u8, 1u8,
+                    0u8, 42u8, 19u8, 1u8, 0u8, 49u8, 19u8, 1u8, 0u8, 50u8, 19u8, 1u8, 0u8, 52u8,
+                    19u8, 1u8, 0u8, 53u8, 19u8, 1u8, 0u8, 58u8, 19u8, 1u8, 0u8, 59u8, 19u8, 1u8,
+                    0u8, 69u8, 19u8, 1u8, 0u8, 71u8, 19u8, 1u8, 0u8, 73u8, 19u8, 1u8, 0u8, 75u8,
+                    19u8, 1u8, 0u8, 78u8, 19u8, 1u8, 0u8, 80u8, 19u8, 1u8, 0u8, 81u8, 19u8, 1u8,
+                    0u8, 87u8, 19u8, 1u8, 0u8, 88u8, 19u8, 1u8, 0u8, 93u8, 19u8, 1u8, 0u8, 100u8,
+                    19u8, 1u8, 0u8, 102u8, 19u8, 1u8, 0u8, 109u8, 19u8, 1u8, 0u8, 112u8, 19u8, 1u8,
+                    0u8, 117u8, 19u8, 1u8, 0u8, 0u8, 20u8, 1u8, 0u8, 92u8, 20u8, 1u8, 0u8, 93u8,
+                    20u8, 1u8, 0u8, 98u8, 20u8, 1u8, 0u8, 128u8, 20u8, 1u8, 0u8, 200u8, 20u8, 1u8,
+                    0u8, 208u8, 20u8, 1u8, 0u8, 218u8, 20u8, 1u8, 0u8, 128u8, 21u8, 1u8, 0u8,
+                    182u8, 21u8, 1u8, 0u8, 184u8, 21u8, 1u8, 0u8, 222u8, 21u8, 1u8, 0u8, 0u8, 22u8,
+                    1u8, 0u8, 69u8, 22u8, 1u8, 0u8, 80u8, 22u8, 1u8, 0u8, 90u8, 22u8, 1u8, 0u8,
+                    96u8, 22u8, 1u8, 0u8, 109u8, 22u8, 1u8, 0u8, 128u8, 22u8, 1u8, 0u8, 186u8,
+                    22u8, 1u8, 0u8, 192u8, 22u8, 1u8, 0u8, 202u8, 22u8, 1u8, 0u8, 0u8, 23u8, 1u8,
+                    0u8, 27u8, 23u8, 1u8, 0u8, 29u8, 23u8, 1u8, 0u8, 44u8, 23u8, 1u8, 0u8, 48u8,
+                    23u8, 1u8, 0u8, 71u8, 23u8, 1u8, 0u8, 0u8, 24u8, 1u8, 0u8, 60u8, 24u8, 1u8,
+                    0u8, 160u8, 24u8, 1u8, 0u8, 243u8, 24u8, 1u8, 0u8, 255u8, 24u8, 1u8, 0u8, 7u8,
+                    25u8, 1u8, 0u8, 9u8, 25u8, 1u8, 0u8, 10u8, 25u8, 1u8, 0u8, 12u8, 25u8, 1u8,
+                    0u8, 20u8, 25u8, 1u8, 0u8, 21u8, 25u8, 1u8, 0u8, 23u8, 25u8, 1u8, 0u8, 24u8,
+                    25u8, 1u8, 0u8, 54u8, 25u8, 1u8, 0u8, 55u8, 25u8, 1u8, 0u8, 57u8, 25u8, 1u8,
+                    0u8, 59u8, 25u8, 1u8, 0u8, 71u8, 25u8, 1u8, 0u8, 80u8, 25u8, 1u8, 0u8, 90u8,
+                    25u8, 1u8, 0u8, 160u8, 25u8, 1u8, 0u8, 168u8, 25u8, 1u8, 0u8, 170u8, 25u8, 1u8,
+                    0u8, 216u8, 25u8, 1u8, 0u8, 218u8, 25u8, 1u8, 0u8, 229u8, 25u8, 1u8, 0u8, 0u8,
+                    26u8, 1u8, 0u8, 72u8, 26u8, 1u8, 0u8, 80u8, 26u8, 1u8, 0u8, 163u8, 26u8, 1u8,
+                    0u8, 176u8, 26u8, 1u8, 0u8, 249u8, 26u8, 1u8, 0u8, 0u8, 28u8, 1u8, 0u8, 9u8,
+                    28u8, 1u8, 0u8, 10u8, 28u8, 1u8, 0u8, 55u8, 28u8, 1u8, 0u8, 56u8, 28u8, 1u8,
+                    0u8, 70u8, 28u8, 1u8, 0u8, 80u8, 28u8, 1u8, 0u8, 109u8, 28u8, 1u8, 0u8, 112u8,
+                    28u8, 1u8, 0u8, 144u8, 28u8, 1u8, 0u8, 146u8, 28u8, 1u8, 0u8, 168u8, 28u8, 1u8,
+                    0u8, 169u8, 28u8, 1u8, 0u8, 183u8, 28u8, 1u8, 0u8, 0u8, 29u8, 1u8, 0u8, 7u8,
+                    29u8, 1u8, 0u8, 8u8, 29u8, 1u8, 0u8, 10u8, 29u8, 1u8, 0u8, 11u8, 29u8, 1u8,
+                    0u8, 55u8, 29u8, 1u8, 0u8, 58u8, 29u8, 1u8, 0u8, 59u8, 29u8, 1u8, 0u8, 60u8,
+                    29u8, 1u8, 0u8, 62u8, 29u8, 1u8, 0u8, 63u8, 29u8, 1u8, 0u8, 72u8, 29u8, 1u8,
+                    0u8, 80u8, 29u8, 1u8, 0u8, 90u8, 29u8, 1u8, 0u8, 96u8, 29u8, 1u8, 0u8, 102u8,
+                    29u8, 1u8, 0u8, 103u8, 29u8, 1u8, 0u8, 105u8, 29u8, 1u8, 0u8, 106u8, 29u8, 1u8,
+                    0u8, 143u8, 29u8, 1u8, 0u8, 144u8, 29u8, 1u8, 0u8, 146u8, 29u8, 1u8, 0u8,
+                    147u8, 29u8, 1u8, 0u8, 153u8, 29u8, 1u8, 0u8, 160u8, 29u8, 1u8, 0u8, 170u8,
+                    29u8, 1u8, 0u8, 224u8, 30u8, 1u8, 0u8, 249u8, 30u8, 1u8, 0u8, 176u8, 31u8, 1u8,
+                    0u8, 177u8, 31u8, 1u8, 0u8, 192u8, 31u8, 1u8, 0u8, 242u8, 31u8, 1u8, 0u8,
+                    255u8, 31u8, 1u8, 0u8, 154u8, 35u8, 1u8, 0u8, 0u8, 36u8, 1u8, 0u8, 111u8, 36u8,
+                    1u8, 0u8, 112u8, 36u8, 1u8, 0u8, 117u8, 36u8, 1u8, 0u8, 128u8, 36u8, 1u8, 0u8,
+                    68u8, 37u8, 1u8, 0u8, 144u8, 47u8, 1u8, 0u8, 243u8, 47u8, 1u8, 0u8, 0u8, 48u8,
+                    1u8, 0u8, 47u8, 52u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 71u8, 70u8, 1u8, 0u8, 0u8,
+                    104u8, 1u8, 0u8, 57u8, 106u8, 1u8, 0u8, 64u8, 106u8, 1u8, 0u8, 95u8, 106u8,
+                    1u8, 0u8, 96u8, 106u8, 1u8, 0u8, 106u8, 106u8, 1u8, 0u8, 110u8, 106u8, 1u8,
+                    0u8, 191u8, 106u8, 1u8, 0u8, 192u8, 106u8, 1u8, 0u8, 202u8, 106u8, 1u8, 0u8,
+                    208u8, 106u8, 1u8, 0u8, 238u8, 106u8, 1u8, 0u8, 240u8, 106u8, 1u8, 0u8, 246u8,
+                    106u8, 1u8, 0u8, 0u8, 107u8, 1u8, 0u8, 70u8, 107u8, 1u8, 0u8, 80u8, 107u8, 1u8,
+                    0u8, 90u8, 107u8, 1u8, 0u8, 91u8, 107u8, 1u8, 0u8, 98u8, 107u8, 1u8, 0u8, 99u8,
+                    107u8, 1u8, 0u8, 120u8, 107u8, 1u8, 0u8, 125u8, 107u8, 1u8, 0u8, 144u8, 107u8,
+                    1u8, 0u8, 64u8, 110u8, 1u8, 0u8, 155u8, 110u8, 1u8, 0u8, 0u8, 111u8, 1u8, 0u8,
+                    75u8, 111u8, 1u8, 0u8, 79u8, 111u8, 1u8, 0u8, 136u8, 111u8, 1u8, 0u8, 143u8,
+                    111u8, 1u8, 0u8, 160u8, 111u8, 1u8, 0u8, 224u8, 111u8, 1u8, 0u8, 229u8, 111u8,
+                    1u8, 0u8, 240u8, 111u8, 1u8, 0u8, 242u8, 111u8, 1u8, 0u8, 0u8, 112u8, 1u8, 0u8,
+                    248u8, 135u8, 1u8, 0u8, 0u8, 136u8, 1u8, 0u8, 214u8, 140u8, 1u8, 0u8, 0u8,
+                    141u8, 1u8, 0u8, 9u8, 141u8, 1u8, 0u8, 240u8, 175u8, 1u8, 0u8, 244u8, 175u8,
+                    1u8, 0u8, 245u8, 175u8, 1u8, 0u8, 252u8, 175u8, 1u8, 0u8, 253u8, 175u8, 1u8,
+                    0u8, 255u8, 175u8, 1u8, 0u8, 0u8, 176u8, 1u8, 0u8, 35u8, 177u8, 1u8, 0u8, 80u8,
+                    177u8, 1u8, 0u8, 83u8, 177u8, 1u8, 0u8, 100u8, 177u8, 1u8, 0u8, 104u8, 177u8,
+                    1u8, 0u8, 112u8, 177u8, 1u8, 0u8, 252u8, 178u8, 1u8, 0u8, 0u8, 188u8, 1u8, 0u8,
+                    107u8, 188u8, 1u8, 0u8, 112u8, 188u8, 1u8, 0u8, 125u8, 188u8, 1u8, 0u8, 128u8,
+                    188u8, 1u8, 0u8, 137u8, 188u8, 1u8, 0u8, 144u8, 188u8, 1u8, 0u8, 154u8, 188u8,
+                    1u8, 0u8, 156u8, 188u8, 1u8, 0u8, 164u8, 188u8, 1u8, 0u8, 0u8, 207u8, 1u8, 0u8,
+                    46u8, 207u8, 1u8, 0u8, 48u8, 207u8, 1u8, 0u8, 71u8, 207u8, 1u8, 0u8, 80u8,
+                    207u8, 1u8, 0u8, 196u8, 207u8, 1u8, 0u8, 0u8, 208u8, 1u8, 0u8, 246u8, 208u8,
+                    1u8, 0u8, 0u8, 209u8, 1u8, 0u8, 39u8, 209u8, 1u8, 0u8, 41u8, 209u8, 1u8, 0u8,
+                    115u8, 209u8, 1u8, 0u8, 123u8, 209u8, 1u8, 0u8, 235u8, 209u8, 1u8, 0u8, 0u8,
+                    210u8, 1u8, 0u8, 70u8, 210u8, 1u8, 0u8, 224u8, 210u8, 1u8, 0u8, 244u8, 210u8,
+                    1u8, 0u8, 0u8, 211u8, 1u8, 0u8, 87u8, 211u8, 1u8, 0u8, 96u8, 211u8, 1u8, 0u8,
+                    121u8, 211u8, 1u8, 0u8, 0u8, 212u8, 1u8, 0u8, 85u8, 212u8, 1u8, 0u8, 86u8,
+                    212u8, 1u8, 0u8, 157u8, 212u8, 1u8, 0u8, 158u8, 212u8, 1u8, 0u8, 160u8, 212u8,
+                    1u8, 0u8, 162u8, 212u8, 1u8, 0u8, 163u8, 212u8, 1u8, 0u8, 165u8, 212u8, 1u8,
+                    0u8, 167u8, 212u8, 1u8, 0u8, 169u8, 212u8, 1u8, 0u8, 173u8, 212u8, 1u8, 0u8,
+                    174u8, 212u8, 1u8, 0u8, 186u8, 212u8, 1u8, 0u8, 187u8, 212u8, 1u8, 0u8, 188u8,
+                    212u8, 1u8, 0u8, 189u8, 212u8, 1u8, 0u8, 196u8, 212u8, 1u8, 0u8, 197u8, 212u8,
+                    1u8, 0u8, 6u8, 213u8, 1u8, 0u8, 7u8, 213u8, 1u8, 0u8, 11u8, 213u8, 1u8, 0u8,
+                    13u8, 213u8, 1u8, 0u8, 21u8, 213u8, 1u8, 0u8, 22u8, 213u8, 1u8, 0u8, 29u8,
+                    213u8, 1u8, 0u8, 30u8, 213u8, 1u8, 0u8, 58u8, 213u8, 1u8, 0u8, 59u8, 213u8,
+                    1u8, 0u8, 63u8, 213u8, 1u8, 0u8, 64u8, 213u8, 1u8, 0u8, 69u8, 213u8, 1u8, 0u8,
+                    70u8, 213u8, 1u8, 0u8, 71u8, 213u8, 1u8, 0u8, 74u8, 213u8, 1u8, 0u8, 81u8,
+                    213u8, 1u8, 0u8, 82u8, 213u8, 1u8, 0u8, 166u8, 214u8, 1u8, 0u8, 168u8, 214u8,
+                    1u8, 0u8, 204u8, 215u8, 1u8, 0u8, 206u8, 215u8, 1u8, 0u8, 140u8, 218u8, 1u8,
+                    0u8, 155u8, 218u8, 1u8, 0u8, 160u8, 218u8, 1u8, 0u8, 161u8, 218u8, 1u8, 0u8,
+                    176u8, 218u8, 1u8, 0u8, 0u8, 223u8, 1u8, 0u8, 31u8, 223u8, 1u8, 0u8, 0u8,
+                    224u8, 1u8, 0u8, 7u8, 224u8, 1u8, 0u8, 8u8, 224u8, 1u8, 0u8, 25u8, 224u8, 1u8,
+                    0u8, 27u8, 224u8, 1u8, 0u8, 34u8, 224u8, 1u8, 0u8, 35u8, 224u8, 1u8, 0u8, 37u8,
+                    224u8, 1u8, 0u8, 38u8, 224u8, 1u8, 0u8, 43u8, 224u8, 1u8, 0u8, 0u8, 225u8, 1u8,
+                    0u8, 45u8, 225u8, 1u8, 0u8, 48u8, 225u8, 1u8, 0u8, 62u8, 225u8, 1u8, 0u8, 64u8,
+                    225u8, 1u8, 0u8, 74u8, 225u8, 1u8, 0u8, 78u8, 225u8, 1u8, 0u8, 80u8, 225u8,
+                    1u8, 0u8, 144u8, 226u8, 1u8, 0u8, 175u8, 226u8, 1u8, 0u8, 192u8, 226u8, 1u8,
+                    0u8, 250u8, 226u8, 1u8, 0u8, 255u8, 226u8, 1u8, 0u8, 0u8, 227u8, 1u8, 0u8,
+                    224u8, 231u8, 1u8, 0u8, 231u8, 231u8, 1u8, 0u8, 232u8, 231u8, 1u8, 0u8, 236u8,
+                    231u8, 1u8, 0u8, 237u8, 231u8, 1u8, 0u8, 239u8, 231u8, 1u8, 0u8, 240u8, 231u8,
+                    1u8, 0u8, 255u8, 231u8, 1u8, 0u8, 0u8, 232u8, 1u8, 0u8, 197u8, 232u8, 1u8, 0u8,
+                    199u8, 232u8, 1u8, 0u8, 215u8, 232u8, 1u8, 0u8, 0u8, 233u8, 1u8, 0u8, 76u8,
+                    233u8, 1u8, 0u8, 80u8, 233u8, 1u8, 0u8, 90u8, 233u8, 1u8, 0u8, 94u8, 233u8,
+                    1u8, 0u8, 96u8, 233u8, 1u8, 0u8, 113u8, 236u8, 1u8, 0u8, 181u8, 236u8, 1u8,
+                    0u8, 1u8, 237u8, 1u8, 0u8, 62u8, 237u8, 1u8, 0u8, 0u8, 238u8, 1u8, 0u8, 4u8,
+                    238u8, 1u8, 0u8, 5u8, 238u8, 1u8, 0u8, 32u8, 238u8, 1u8, 0u8, 33u8, 238u8, 1u8,
+                    0u8, 35u8, 238u8, 1u8, 0u8, 36u8, 238u8, 1u8, 0u8, 37u8, 238u8, 1u8, 0u8, 39u8,
+                    238u8, 1u8, 0u8, 40u8, 238u8, 1u8, 0u8, 41u8, 238u8, 1u8, 0u8, 51u8, 238u8,
+                    1u8, 0u8, 52u8, 238u8, 1u8, 0u8, 56u8, 238u8, 1u8, 0u8, 57u8, 238u8, 1u8, 0u8,
+                    58u8, 238u8, 1u8, 0u8, 59u8, 238u8, 1u8, 0u8, 60u8, 238u8, 1u8, 0u8, 66u8,
+                    238u8, 1u8, 0u8, 67u8, 238u8, 1u8, 0u8, 71u8, 238u8, 1u8, 0u8, 72u8, 238u8,
+                    1u8, 0u8, 73u8, 238u8, 1u8, 0u8, 74u8, 238u8, 1u8, 0u8, 75u8, 238u8, 1u8, 0u8,
+                    76u8, 238u8, 1u8, 0u8, 77u8, 238u8, 1u8, 0u8, 80u8, 238u8, 1u8, 0u8, 81u8,
+                    238u8, 1u8, 0u8, 83u8, 238u8, 1u8, 0u8, 84u8, 238u8, 1u8, 0u8, 85u8, 238u8,
+                    1u8, 0u8, 87u8, 238u8, 1u8, 0u8, 88u8, 238u8, 1u8, 0u8, 89u8, 238u8, 1u8, 0u8,
+                    90u8, 238u8, 1u8, 0u8, 91u8, 238u8, 1u8, 0u8, 92u8, 238u8, 1u8, 0u8, 93u8,
+                    238u8, 1u8, 0u8, 94u8, 238u8, 1u8, 0u8, 95u8, 238u8, 1u8, 0u8, 96u8, 238u8,
+                    1u8, 0u8, 97u8, 238u8, 1u8, 0u8, 99u8, 238u8, 1u8, 0u8, 100u8, 238u8, 1u8, 0u8,
+                    101u8, 238u8, 1u8, 0u8, 103u8, 238u8, 1u8, 0u8, 107u8, 238u8, 1u8, 0u8, 108u8,
+                    238u8, 1u8, 0u8, 115u8, 238u8, 1u8, 0u8, 116u8, 238u8, 1u8, 0u8, 120u8, 238u8,
+                    1u8, 0u8, 121u8, 238u8, 1u8, 0u8, 125u8, 238u8, 1u8, 0u8, 126u8, 238u8, 1u8,
+                    0u8, 127u8, 238u8, 1u8, 0u8, 128u8, 238u8, 1u8, 0u8, 138u8, 238u8, 1u8, 0u8,
+                    139u8, 238u8, 1u8, 0u8, 156u8, 238u8, 1u8, 0u8, 161u8, 238u8, 1u8, 0u8, 164u8,
+                    238u8, 1u8, 0u8, 165u8, 238u8, 1u8, 0u8, 170u8, 238u8, 1u8, 0u8, 171u8, 238u8,
+                    1u8, 0u8, 188u8, 238u8, 1u8, 0u8, 240u8, 238u8, 1u8, 0u8, 242u8, 238u8, 1u8,
+                    0u8, 0u8, 240u8, 1u8, 0u8, 44u8, 240u8, 1u8, 0u8, 48u8, 240u8, 1u8, 0u8, 148u8,
+                    240u8, 1u8, 0u8, 160u8, 240u8, 1u8, 0u8, 175u8, 240u8, 1u8, 0u8, 177u8, 240u8,
+                    1u8, 0u8, 192u8, 240u8, 1u8, 0u8, 193u8, 240u8, 1u8, 0u8, 208u8, 240u8, 1u8,
+                    0u8, 209u8, 240u8, 1u8, 0u8, 246u8, 240u8, 1u8, 0u8, 1u8, 241u8, 1u8, 0u8,
+                    174u8, 241u8, 1u8, 0u8, 230u8, 241u8, 1u8, 0u8, 3u8, 242u8, 1u8, 0u8, 16u8,
+                    242u8, 1u8, 0u8, 60u8, 242u8, 1u8, 0u8, 64u8, 242u8, 1u8, 0u8, 73u8, 242u8,
+                    1u8, 0u8, 80u8, 242u8, 1u8, 0u8, 82u8, 242u8, 1u8, 0u8, 96u8, 242u8, 1u8, 0u8,
+                    102u8, 242u8, 1u8, 0u8, 0u8, 243u8, 1u8, 0u8, 216u8, 246u8, 1u8, 0u8, 221u8,
+                    246u8, 1u8, 0u8, 237u8, 246u8, 1u8, 0u8, 240u8, 246u8, 1u8, 0u8, 253u8, 246u8,
+                    1u8, 0u8, 0u8, 247u8, 1u8, 0u8, 116u8, 247u8, 1u8, 0u8, 128u8, 247u8, 1u8, 0u8,
+                    217u8, 247u8, 1u8, 0u8, 224u8, 247u8, 1u8, 0u8, 236u8, 247u8, 1u8, 0u8, 240u8,
+                    247u8, 1u8, 0u8, 241u8, 247u8, 1u8, 0u8, 0u8, 248u8, 1u8, 0u8, 12u8, 248u8,
+                    1u8, 0u8, 16u8, 248u8, 1u8, 0u8, 72u8, 248u8, 1u8, 0u8, 80u8, 248u8, 1u8, 0u8,
+                    90u8, 248u8, 1u8, 0u8, 96u8, 248u8, 1u8, 0u8, 136u8, 248u8, 1u8, 0u8, 144u8,
+                    248u8, 1u8, 0u8, 174u8, 248u8, 1u8, 0u8, 176u8, 248u8, 1u8, 0u8, 178u8, 248u8,
+                    1u8, 0u8, 0u8, 249u8, 1u8, 0u8, 84u8, 250u8, 1u8, 0u8, 96u8, 250u8, 1u8, 0u8,
+                    110u8, 250u8, 1u8, 0u8, 112u8, 250u8, 1u8, 0u8, 117u8, 250u8, 1u8, 0u8, 120u8,
+                    250u8, 1u8, 0u8, 125u8, 250u8, 1u8, 0u8, 128u8, 250u8, 1u8, 0u8, 135u8, 250u8,
+                    1u8, 0u8, 144u8, 250u8, 1u8, 0u8, 173u8, 250u8, 1u8, 0u8, 176u8, 250u8, 1u8,
+                    0u8, 187u8, 250u8, 1u8, 0u8, 192u8, 250u8, 1u8, 0u8, 198u8, 250u8, 1u8, 0u8,
+                    208u8, 250u8, 1u8, 0u8, 218u8, 250u8, 1u8, 0u8, 224u8, 250u8, 1u8, 0u8, 232u8,
+                    250u8, 1u8, 0u8, 240u8, 250u8, 1u8, 0u8, 247u8, 250u8, 1u8, 0u8, 0u8, 251u8,
+                    1u8, 0u8, 147u8, 251u8, 1u8, 0u8, 148u8, 251u8, 1u8, 0u8, 203u8, 251u8, 1u8,
+                    0u8, 240u8, 251u8, 1u8, 0u8, 250u8, 251u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 224u8,
+                    166u8, 2u8, 0u8, 0u8, 167u8, 2u8, 0u8, 57u8, 183u8, 2u8, 0u8, 64u8, 183u8, 2u8,
+                    0u8, 30u8, 184u8, 2u8, 0u8, 32u8, 184u8, 2u8, 0u8, 162u8, 206u8, 2u8, 0u8,
+                    176u8, 206u8, 2u8, 0u8, 225u8, 235u8, 2u8, 0u8, 0u8, 248u8, 2u8, 0u8, 104u8,
+                    248u8, 2u8, 0u8, 105u8, 248u8, 2u8, 0u8, 116u8, 248u8, 2u8, 0u8, 117u8, 248u8,
+                    2u8, 0u8, 31u8, 249u8, 2u8, 0u8, 32u8, 249u8, 2u8, 0u8, 95u8, 249u8, 2u8, 0u8,
+                    96u8, 249u8, 2u8, 0u8, 191u8, 249u8, 2u8, 0u8, 192u8, 249u8, 2u8, 0u8, 30u8,
+                    250u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 75u8, 19u8, 3u8, 0u8, 0u8, 1u8, 14u8, 0u8,
+                    240u8, 1u8, 14u8, 0u8, 0u8, 0u8, 17u8, 0u8,
+                ])
+            },
+            969635usize,
+        )
+    },
+    disallowed_std3: unsafe {
+        #[allow(unused_unsafe)]
+        ::icu_collections::codepointinvlist::CodePointInversionList::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8,
+                    0u8, 58u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 97u8, 0u8,
+                    0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8,
+                    161u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 175u8, 0u8,
+                    0u8, 0u8, 176u8, 0u8, 0u8, 0u8, 180u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8,
+                    184u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 216u8, 2u8, 0u8, 0u8, 222u8, 2u8,
+                    0u8, 0u8, 122u8, 3u8, 0u8, 0u8, 123u8, 3u8, 0u8, 0u8, 126u8, 3u8, 0u8, 0u8,
+                    127u8, 3u8, 0u8, 0u8, 132u8, 3u8, 0u8, 0u8, 134u8, 3u8, 0u8, 0u8, 189u8, 31u8,
+                    0u8, 0u8, 190u8, 31u8, 0u8, 0u8, 191u8, 31u8, 0u8, 0u8, 194u8, 31u8, 0u8, 0u8,
+                    205u8, 31u8, 0u8, 0u8, 208u8, 31u8, 0u8, 0u8, 221u8, 31u8, 0u8, 0u8, 224u8,
+                    31u8, 0u8, 0u8, 237u8, 31u8, 0u8, 0u8, 240u8, 31u8, 0u8, 0u8, 253u8, 31u8, 0u8,
+                    0u8, 255u8, 31u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 11u8, 32u8, 0u8, 0u8, 23u8,
+                    32u8, 0u8, 0u8, 24u8, 32u8, 0u8, 0u8, 47u8, 32u8, 0u8, 0u8, 48u8, 32u8, 0u8,
+                    0u8, 60u8, 32u8, 0u8, 0u8, 61u8, 32u8, 0u8, 0u8, 62u8, 32u8, 0u8, 0u8, 63u8,
+                    32u8, 0u8, 0u8, 71u8, 32u8, 0u8, 0u8, 74u8, 32u8, 0u8, 0u8, 95u8, 32u8, 0u8,
+                    0u8, 96u8, 32u8, 0u8, 0u8, 122u8, 32u8, 0u8, 0u8, 123u8, 32u8, 0u8, 0u8, 124u8,
+                    32u8, 0u8, 0u8, 127u8, 32u8, 0u8, 0u8, 138u8, 32u8, 0u8, 0u8, 139u8, 32u8, 0u8,
+                    0u8, 140u8, 32u8, 0u8, 0u8, 143u8, 32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 2u8,
+                    33u8, 0u8, 0u8, 5u8, 33u8, 0u8, 0u8, 7u8, 33u8, 0u8, 0u8, 96u8, 34u8, 0u8, 0u8,
+                    97u8, 34u8, 0u8, 0u8, 110u8, 34u8, 0u8, 0u8, 112u8, 34u8, 0u8, 0u8, 116u8,
+                    36u8, 0u8, 0u8, 136u8, 36u8, 0u8, 0u8, 156u8, 36u8, 0u8, 0u8, 182u8, 36u8, 0u8,
+                    0u8, 116u8, 42u8, 0u8, 0u8, 119u8, 42u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 1u8,
+                    48u8, 0u8, 0u8, 155u8, 48u8, 0u8, 0u8, 157u8, 48u8, 0u8, 0u8, 0u8, 50u8, 0u8,
+                    0u8, 31u8, 50u8, 0u8, 0u8, 32u8, 50u8, 0u8, 0u8, 68u8, 50u8, 0u8, 0u8, 41u8,
+                    251u8, 0u8, 0u8, 42u8, 251u8, 0u8, 0u8, 94u8, 252u8, 0u8, 0u8, 100u8, 252u8,
+                    0u8, 0u8, 250u8, 253u8, 0u8, 0u8, 252u8, 253u8, 0u8, 0u8, 16u8, 254u8, 0u8,
+                    0u8, 17u8, 254u8, 0u8, 0u8, 19u8, 254u8, 0u8, 0u8, 23u8, 254u8, 0u8, 0u8, 51u8,
+                    254u8, 0u8, 0u8, 57u8, 254u8, 0u8, 0u8, 71u8, 254u8, 0u8, 0u8, 81u8, 254u8,
+                    0u8, 0u8, 84u8, 254u8, 0u8, 0u8, 88u8, 254u8, 0u8, 0u8, 89u8, 254u8, 0u8, 0u8,
+                    93u8, 254u8, 0u8, 0u8, 95u8, 254u8, 0u8, 0u8, 99u8, 254u8, 0u8, 0u8, 100u8,
+                    254u8, 0u8, 0u8, 103u8, 254u8, 0u8, 0u8, 104u8, 254u8, 0u8, 0u8, 108u8, 254u8,
+                    0u8, 0u8, 112u8, 254u8, 0u8, 0u8, 113u8, 254u8, 0u8, 0u8, 114u8, 254u8, 0u8,
+                    0u8, 115u8, 254u8, 0u8, 0u8, 116u8, 254u8, 0u8, 0u8, 117u8, 254u8, 0u8, 0u8,
+                    118u8, 254u8, 0u8, 0u8, 119u8, 254u8, 0u8, 0u8, 120u8, 254u8, 0u8, 0u8, 121u8,
+                    254u8, 0u8, 0u8, 122u8, 254u8, 0u8, 0u8, 123u8, 254u8, 0u8, 0u8, 124u8, 254u8,
+                    0u8, 0u8, 125u8, 254u8, 0u8, 0u8, 126u8, 254u8, 0u8, 0u8, 127u8, 254u8, 0u8,
+                    0u8, 1u8, 255u8, 0u8, 0u8, 13u8, 255u8, 0u8, 0u8, 15u8, 255u8, 0u8, 0u8, 16u8,
+                    255u8, 0u8, 0u8, 26u8, 255u8, 0u8, 0u8, 33u8, 255u8, 0u8, 0u8, 59u8, 255u8,
+                    0u8, 0u8, 65u8, 255u8, 0u8, 0u8, 91u8, 255u8, 0u8, 0u8, 95u8, 255u8, 0u8, 0u8,
+                    227u8, 255u8, 0u8, 0u8, 228u8, 255u8, 0u8, 0u8, 1u8, 241u8, 1u8, 0u8, 11u8,
+                    241u8, 1u8, 0u8, 16u8, 241u8, 1u8, 0u8, 42u8, 241u8, 1u8, 0u8,
+                ])
+            },
+            371usize,
+        )
+    },
+};
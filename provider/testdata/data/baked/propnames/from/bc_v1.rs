@@ -0,0 +1,78 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: BidiClassNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    46u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 16u8, 0u8, 28u8, 0u8, 29u8,
+                    0u8, 31u8, 0u8, 46u8, 0u8, 61u8, 0u8, 63u8, 0u8, 65u8, 0u8, 67u8, 0u8, 69u8,
+                    0u8, 83u8, 0u8, 100u8, 0u8, 118u8, 0u8, 136u8, 0u8, 139u8, 0u8, 140u8, 0u8,
+                    151u8, 0u8, 171u8, 0u8, 189u8, 0u8, 208u8, 0u8, 211u8, 0u8, 214u8, 0u8, 217u8,
+                    0u8, 231u8, 0u8, 234u8, 0u8, 236u8, 0u8, 248u8, 0u8, 10u8, 1u8, 13u8, 1u8,
+                    16u8, 1u8, 36u8, 1u8, 57u8, 1u8, 58u8, 1u8, 69u8, 1u8, 89u8, 1u8, 107u8, 1u8,
+                    126u8, 1u8, 129u8, 1u8, 132u8, 1u8, 135u8, 1u8, 136u8, 1u8, 152u8, 1u8, 162u8,
+                    1u8, 97u8, 108u8, 97u8, 110u8, 97u8, 114u8, 97u8, 98u8, 105u8, 99u8, 108u8,
+                    101u8, 116u8, 116u8, 101u8, 114u8, 97u8, 114u8, 97u8, 98u8, 105u8, 99u8, 110u8,
+                    117u8, 109u8, 98u8, 101u8, 114u8, 98u8, 98u8, 110u8, 98u8, 111u8, 117u8, 110u8,
+                    100u8, 97u8, 114u8, 121u8, 110u8, 101u8, 117u8, 116u8, 114u8, 97u8, 108u8,
+                    99u8, 111u8, 109u8, 109u8, 111u8, 110u8, 115u8, 101u8, 112u8, 97u8, 114u8,
+                    97u8, 116u8, 111u8, 114u8, 99u8, 115u8, 101u8, 110u8, 101u8, 115u8, 101u8,
+                    116u8, 101u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 110u8, 117u8,
+                    109u8, 98u8, 101u8, 114u8, 101u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8,
+                    110u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 101u8,
+                    117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 116u8, 101u8, 114u8, 109u8,
+                    105u8, 110u8, 97u8, 116u8, 111u8, 114u8, 102u8, 105u8, 114u8, 115u8, 116u8,
+                    115u8, 116u8, 114u8, 111u8, 110u8, 103u8, 105u8, 115u8, 111u8, 108u8, 97u8,
+                    116u8, 101u8, 102u8, 115u8, 105u8, 108u8, 108u8, 101u8, 102u8, 116u8, 116u8,
+                    111u8, 114u8, 105u8, 103u8, 104u8, 116u8, 108u8, 101u8, 102u8, 116u8, 116u8,
+                    111u8, 114u8, 105u8, 103u8, 104u8, 116u8, 101u8, 109u8, 98u8, 101u8, 100u8,
+                    100u8, 105u8, 110u8, 103u8, 108u8, 101u8, 102u8, 116u8, 116u8, 111u8, 114u8,
+                    105u8, 103u8, 104u8, 116u8, 105u8, 115u8, 111u8, 108u8, 97u8, 116u8, 101u8,
+                    108u8, 101u8, 102u8, 116u8, 116u8, 111u8, 114u8, 105u8, 103u8, 104u8, 116u8,
+                    111u8, 118u8, 101u8, 114u8, 114u8, 105u8, 100u8, 101u8, 108u8, 114u8, 101u8,
+                    108u8, 114u8, 105u8, 108u8, 114u8, 111u8, 110u8, 111u8, 110u8, 115u8, 112u8,
+                    97u8, 99u8, 105u8, 110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 110u8, 115u8,
+                    109u8, 111u8, 110u8, 111u8, 116u8, 104u8, 101u8, 114u8, 110u8, 101u8, 117u8,
+                    116u8, 114u8, 97u8, 108u8, 112u8, 97u8, 114u8, 97u8, 103u8, 114u8, 97u8, 112u8,
+                    104u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 112u8,
+                    100u8, 102u8, 112u8, 100u8, 105u8, 112u8, 111u8, 112u8, 100u8, 105u8, 114u8,
+                    101u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 102u8, 111u8, 114u8,
+                    109u8, 97u8, 116u8, 112u8, 111u8, 112u8, 100u8, 105u8, 114u8, 101u8, 99u8,
+                    116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 105u8, 115u8, 111u8, 108u8, 97u8,
+                    116u8, 101u8, 114u8, 114u8, 105u8, 103u8, 104u8, 116u8, 116u8, 111u8, 108u8,
+                    101u8, 102u8, 116u8, 114u8, 105u8, 103u8, 104u8, 116u8, 116u8, 111u8, 108u8,
+                    101u8, 102u8, 116u8, 101u8, 109u8, 98u8, 101u8, 100u8, 100u8, 105u8, 110u8,
+                    103u8, 114u8, 105u8, 103u8, 104u8, 116u8, 116u8, 111u8, 108u8, 101u8, 102u8,
+                    116u8, 105u8, 115u8, 111u8, 108u8, 97u8, 116u8, 101u8, 114u8, 105u8, 103u8,
+                    104u8, 116u8, 116u8, 111u8, 108u8, 101u8, 102u8, 116u8, 111u8, 118u8, 101u8,
+                    114u8, 114u8, 105u8, 100u8, 101u8, 114u8, 108u8, 101u8, 114u8, 108u8, 105u8,
+                    114u8, 108u8, 111u8, 115u8, 115u8, 101u8, 103u8, 109u8, 101u8, 110u8, 116u8,
+                    115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 119u8, 104u8,
+                    105u8, 116u8, 101u8, 115u8, 112u8, 97u8, 99u8, 101u8, 119u8, 115u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    13u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+                    0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 19u8,
+                    0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 20u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 17u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 16u8,
+                    0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+                    0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8,
+                    0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,126 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: CanonicalCombiningClassNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    137u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 10u8,
+                    0u8, 12u8, 0u8, 15u8, 0u8, 17u8, 0u8, 20u8, 0u8, 23u8, 0u8, 25u8, 0u8, 28u8,
+                    0u8, 31u8, 0u8, 34u8, 0u8, 36u8, 0u8, 38u8, 0u8, 40u8, 0u8, 42u8, 0u8, 44u8,
+                    0u8, 46u8, 0u8, 48u8, 0u8, 51u8, 0u8, 54u8, 0u8, 56u8, 0u8, 59u8, 0u8, 62u8,
+                    0u8, 65u8, 0u8, 67u8, 0u8, 70u8, 0u8, 73u8, 0u8, 76u8, 0u8, 79u8, 0u8, 82u8,
+                    0u8, 84u8, 0u8, 87u8, 0u8, 90u8, 0u8, 93u8, 0u8, 96u8, 0u8, 98u8, 0u8, 101u8,
+                    0u8, 103u8, 0u8, 105u8, 0u8, 107u8, 0u8, 109u8, 0u8, 111u8, 0u8, 113u8, 0u8,
+                    115u8, 0u8, 117u8, 0u8, 119u8, 0u8, 121u8, 0u8, 123u8, 0u8, 125u8, 0u8, 126u8,
+                    0u8, 127u8, 0u8, 128u8, 0u8, 130u8, 0u8, 131u8, 0u8, 133u8, 0u8, 134u8, 0u8,
+                    139u8, 0u8, 148u8, 0u8, 158u8, 0u8, 160u8, 0u8, 162u8, 0u8, 165u8, 0u8, 169u8,
+                    0u8, 172u8, 0u8, 176u8, 0u8, 189u8, 0u8, 207u8, 0u8, 220u8, 0u8, 237u8, 0u8,
+                    238u8, 0u8, 243u8, 0u8, 252u8, 0u8, 6u8, 1u8, 8u8, 1u8, 10u8, 1u8, 15u8, 1u8,
+                    21u8, 1u8, 27u8, 1u8, 32u8, 1u8, 38u8, 1u8, 43u8, 1u8, 49u8, 1u8, 55u8, 1u8,
+                    60u8, 1u8, 66u8, 1u8, 72u8, 1u8, 78u8, 1u8, 83u8, 1u8, 88u8, 1u8, 93u8, 1u8,
+                    98u8, 1u8, 103u8, 1u8, 108u8, 1u8, 113u8, 1u8, 118u8, 1u8, 123u8, 1u8, 128u8,
+                    1u8, 133u8, 1u8, 138u8, 1u8, 143u8, 1u8, 148u8, 1u8, 153u8, 1u8, 158u8, 1u8,
+                    163u8, 1u8, 168u8, 1u8, 173u8, 1u8, 178u8, 1u8, 183u8, 1u8, 188u8, 1u8, 193u8,
+                    1u8, 198u8, 1u8, 203u8, 1u8, 205u8, 1u8, 207u8, 1u8, 218u8, 1u8, 229u8, 1u8,
+                    233u8, 1u8, 243u8, 1u8, 0u8, 2u8, 2u8, 2u8, 13u8, 2u8, 15u8, 2u8, 16u8, 2u8,
+                    20u8, 2u8, 22u8, 2u8, 34u8, 2u8, 36u8, 2u8, 41u8, 2u8, 43u8, 2u8, 50u8, 2u8,
+                    51u8, 2u8, 56u8, 2u8, 62u8, 2u8, 48u8, 49u8, 49u8, 48u8, 49u8, 48u8, 51u8,
+                    49u8, 48u8, 55u8, 49u8, 49u8, 49u8, 49u8, 56u8, 49u8, 50u8, 49u8, 50u8, 50u8,
+                    49u8, 50u8, 57u8, 49u8, 51u8, 49u8, 51u8, 48u8, 49u8, 51u8, 50u8, 49u8, 51u8,
+                    51u8, 49u8, 52u8, 49u8, 53u8, 49u8, 54u8, 49u8, 55u8, 49u8, 56u8, 49u8, 57u8,
+                    50u8, 48u8, 50u8, 48u8, 48u8, 50u8, 48u8, 50u8, 50u8, 49u8, 50u8, 49u8, 52u8,
+                    50u8, 49u8, 54u8, 50u8, 49u8, 56u8, 50u8, 50u8, 50u8, 50u8, 48u8, 50u8, 50u8,
+                    50u8, 50u8, 50u8, 52u8, 50u8, 50u8, 54u8, 50u8, 50u8, 56u8, 50u8, 51u8, 50u8,
+                    51u8, 48u8, 50u8, 51u8, 50u8, 50u8, 51u8, 51u8, 50u8, 51u8, 52u8, 50u8, 52u8,
+                    50u8, 52u8, 48u8, 50u8, 53u8, 50u8, 54u8, 50u8, 55u8, 50u8, 56u8, 50u8, 57u8,
+                    51u8, 48u8, 51u8, 49u8, 51u8, 50u8, 51u8, 51u8, 51u8, 52u8, 51u8, 53u8, 51u8,
+                    54u8, 54u8, 55u8, 56u8, 56u8, 52u8, 57u8, 57u8, 49u8, 97u8, 97u8, 98u8, 111u8,
+                    118u8, 101u8, 97u8, 98u8, 111u8, 118u8, 101u8, 108u8, 101u8, 102u8, 116u8,
+                    97u8, 98u8, 111u8, 118u8, 101u8, 114u8, 105u8, 103u8, 104u8, 116u8, 97u8,
+                    108u8, 97u8, 114u8, 97u8, 116u8, 97u8, 97u8, 116u8, 97u8, 114u8, 97u8, 116u8,
+                    98u8, 97u8, 116u8, 98u8, 108u8, 97u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8,
+                    100u8, 97u8, 98u8, 111u8, 118u8, 101u8, 97u8, 116u8, 116u8, 97u8, 99u8, 104u8,
+                    101u8, 100u8, 97u8, 98u8, 111u8, 118u8, 101u8, 114u8, 105u8, 103u8, 104u8,
+                    116u8, 97u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8, 100u8, 98u8, 101u8, 108u8,
+                    111u8, 119u8, 97u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8, 100u8, 98u8, 101u8,
+                    108u8, 111u8, 119u8, 108u8, 101u8, 102u8, 116u8, 98u8, 98u8, 101u8, 108u8,
+                    111u8, 119u8, 98u8, 101u8, 108u8, 111u8, 119u8, 108u8, 101u8, 102u8, 116u8,
+                    98u8, 101u8, 108u8, 111u8, 119u8, 114u8, 105u8, 103u8, 104u8, 116u8, 98u8,
+                    108u8, 98u8, 114u8, 99u8, 99u8, 99u8, 49u8, 48u8, 99u8, 99u8, 99u8, 49u8, 48u8,
+                    51u8, 99u8, 99u8, 99u8, 49u8, 48u8, 55u8, 99u8, 99u8, 99u8, 49u8, 49u8, 99u8,
+                    99u8, 99u8, 49u8, 49u8, 56u8, 99u8, 99u8, 99u8, 49u8, 50u8, 99u8, 99u8, 99u8,
+                    49u8, 50u8, 50u8, 99u8, 99u8, 99u8, 49u8, 50u8, 57u8, 99u8, 99u8, 99u8, 49u8,
+                    51u8, 99u8, 99u8, 99u8, 49u8, 51u8, 48u8, 99u8, 99u8, 99u8, 49u8, 51u8, 50u8,
+                    99u8, 99u8, 99u8, 49u8, 51u8, 51u8, 99u8, 99u8, 99u8, 49u8, 52u8, 99u8, 99u8,
+                    99u8, 49u8, 53u8, 99u8, 99u8, 99u8, 49u8, 54u8, 99u8, 99u8, 99u8, 49u8, 55u8,
+                    99u8, 99u8, 99u8, 49u8, 56u8, 99u8, 99u8, 99u8, 49u8, 57u8, 99u8, 99u8, 99u8,
+                    50u8, 48u8, 99u8, 99u8, 99u8, 50u8, 49u8, 99u8, 99u8, 99u8, 50u8, 50u8, 99u8,
+                    99u8, 99u8, 50u8, 51u8, 99u8, 99u8, 99u8, 50u8, 52u8, 99u8, 99u8, 99u8, 50u8,
+                    53u8, 99u8, 99u8, 99u8, 50u8, 54u8, 99u8, 99u8, 99u8, 50u8, 55u8, 99u8, 99u8,
+                    99u8, 50u8, 56u8, 99u8, 99u8, 99u8, 50u8, 57u8, 99u8, 99u8, 99u8, 51u8, 48u8,
+                    99u8, 99u8, 99u8, 51u8, 49u8, 99u8, 99u8, 99u8, 51u8, 50u8, 99u8, 99u8, 99u8,
+                    51u8, 51u8, 99u8, 99u8, 99u8, 51u8, 52u8, 99u8, 99u8, 99u8, 51u8, 53u8, 99u8,
+                    99u8, 99u8, 51u8, 54u8, 99u8, 99u8, 99u8, 56u8, 52u8, 99u8, 99u8, 99u8, 57u8,
+                    49u8, 100u8, 97u8, 100u8, 98u8, 100u8, 111u8, 117u8, 98u8, 108u8, 101u8, 97u8,
+                    98u8, 111u8, 118u8, 101u8, 100u8, 111u8, 117u8, 98u8, 108u8, 101u8, 98u8,
+                    101u8, 108u8, 111u8, 119u8, 104u8, 97u8, 110u8, 114u8, 104u8, 97u8, 110u8,
+                    114u8, 101u8, 97u8, 100u8, 105u8, 110u8, 103u8, 105u8, 111u8, 116u8, 97u8,
+                    115u8, 117u8, 98u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 115u8,
+                    107u8, 97u8, 110u8, 97u8, 118u8, 111u8, 105u8, 99u8, 105u8, 110u8, 103u8,
+                    107u8, 118u8, 108u8, 108u8, 101u8, 102u8, 116u8, 110u8, 107u8, 110u8, 111u8,
+                    116u8, 114u8, 101u8, 111u8, 114u8, 100u8, 101u8, 114u8, 101u8, 100u8, 110u8,
+                    114u8, 110u8, 117u8, 107u8, 116u8, 97u8, 111u8, 118u8, 111u8, 118u8, 101u8,
+                    114u8, 108u8, 97u8, 121u8, 114u8, 114u8, 105u8, 103u8, 104u8, 116u8, 118u8,
+                    105u8, 114u8, 97u8, 109u8, 97u8, 118u8, 114u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8,
+                    0u8, 107u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 12u8,
+                    0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+                    130u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+                    0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 18u8,
+                    0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8,
+                    202u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 216u8, 0u8,
+                    0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 220u8, 0u8, 0u8, 0u8,
+                    222u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8, 228u8, 0u8,
+                    0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8,
+                    233u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 240u8, 0u8,
+                    0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 28u8,
+                    0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8,
+                    32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8,
+                    0u8, 36u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 84u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 230u8,
+                    0u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 228u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8,
+                    0u8, 228u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 216u8,
+                    0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8,
+                    0u8, 216u8, 0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 220u8,
+                    0u8, 0u8, 0u8, 220u8, 0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 222u8, 0u8, 0u8,
+                    0u8, 218u8, 0u8, 0u8, 0u8, 222u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 103u8,
+                    0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8,
+                    12u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+                    0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8,
+                    14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8,
+                    0u8, 18u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+                    0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 25u8,
+                    0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8,
+                    29u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                    0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 36u8, 0u8,
+                    0u8, 0u8, 84u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8,
+                    233u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 233u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 8u8,
+                    0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8,
+                    7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8,
+                    0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,30 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: EastAsianWidthNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 10u8, 0u8, 11u8, 0u8, 20u8, 0u8, 21u8,
+                    0u8, 30u8, 0u8, 31u8, 0u8, 33u8, 0u8, 39u8, 0u8, 46u8, 0u8, 47u8, 0u8, 97u8,
+                    97u8, 109u8, 98u8, 105u8, 103u8, 117u8, 111u8, 117u8, 115u8, 102u8, 102u8,
+                    117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 116u8, 104u8, 104u8, 104u8, 97u8,
+                    108u8, 102u8, 119u8, 105u8, 100u8, 116u8, 104u8, 110u8, 110u8, 97u8, 110u8,
+                    97u8, 114u8, 114u8, 111u8, 119u8, 110u8, 101u8, 117u8, 116u8, 114u8, 97u8,
+                    108u8, 119u8, 119u8, 105u8, 100u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                    2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,89 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GeneralCategoryNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 20u8, 0u8, 22u8, 0u8, 27u8,
+                    0u8, 29u8, 0u8, 49u8, 0u8, 56u8, 0u8, 58u8, 0u8, 72u8, 0u8, 87u8, 0u8, 100u8,
+                    0u8, 105u8, 0u8, 118u8, 0u8, 134u8, 0u8, 140u8, 0u8, 158u8, 0u8, 170u8, 0u8,
+                    183u8, 0u8, 185u8, 0u8, 187u8, 0u8, 189u8, 0u8, 204u8, 0u8, 206u8, 0u8, 208u8,
+                    0u8, 218u8, 0u8, 220u8, 0u8, 222u8, 0u8, 224u8, 0u8, 238u8, 0u8, 252u8, 0u8,
+                    254u8, 0u8, 0u8, 1u8, 2u8, 1u8, 16u8, 1u8, 31u8, 1u8, 42u8, 1u8, 53u8, 1u8,
+                    69u8, 1u8, 80u8, 1u8, 98u8, 1u8, 100u8, 1u8, 102u8, 1u8, 104u8, 1u8, 106u8,
+                    1u8, 108u8, 1u8, 110u8, 1u8, 120u8, 1u8, 122u8, 1u8, 124u8, 1u8, 126u8, 1u8,
+                    128u8, 1u8, 130u8, 1u8, 144u8, 1u8, 155u8, 1u8, 164u8, 1u8, 179u8, 1u8, 189u8,
+                    1u8, 204u8, 1u8, 206u8, 1u8, 208u8, 1u8, 99u8, 99u8, 99u8, 102u8, 99u8, 108u8,
+                    111u8, 115u8, 101u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 99u8, 110u8, 99u8, 110u8, 116u8, 114u8, 108u8, 99u8,
+                    111u8, 99u8, 111u8, 110u8, 110u8, 101u8, 99u8, 116u8, 111u8, 114u8, 112u8,
+                    117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 99u8,
+                    111u8, 110u8, 116u8, 114u8, 111u8, 108u8, 99u8, 115u8, 99u8, 117u8, 114u8,
+                    114u8, 101u8, 110u8, 99u8, 121u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8,
+                    100u8, 97u8, 115u8, 104u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8,
+                    116u8, 105u8, 111u8, 110u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8,
+                    110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 100u8, 105u8, 103u8, 105u8, 116u8,
+                    101u8, 110u8, 99u8, 108u8, 111u8, 115u8, 105u8, 110u8, 103u8, 109u8, 97u8,
+                    114u8, 107u8, 102u8, 105u8, 110u8, 97u8, 108u8, 112u8, 117u8, 110u8, 99u8,
+                    116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 102u8, 111u8, 114u8, 109u8,
+                    97u8, 116u8, 105u8, 110u8, 105u8, 116u8, 105u8, 97u8, 108u8, 112u8, 117u8,
+                    110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 108u8, 101u8,
+                    116u8, 116u8, 101u8, 114u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 108u8,
+                    105u8, 110u8, 101u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8,
+                    114u8, 108u8, 108u8, 108u8, 109u8, 108u8, 111u8, 108u8, 111u8, 119u8, 101u8,
+                    114u8, 99u8, 97u8, 115u8, 101u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8,
+                    108u8, 116u8, 108u8, 117u8, 109u8, 97u8, 116u8, 104u8, 115u8, 121u8, 109u8,
+                    98u8, 111u8, 108u8, 109u8, 99u8, 109u8, 101u8, 109u8, 110u8, 109u8, 111u8,
+                    100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 108u8, 101u8, 116u8, 116u8, 101u8,
+                    114u8, 109u8, 111u8, 100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 115u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 110u8, 100u8, 110u8, 108u8, 110u8, 111u8, 110u8,
+                    111u8, 110u8, 115u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 109u8, 97u8,
+                    114u8, 107u8, 111u8, 112u8, 101u8, 110u8, 112u8, 117u8, 110u8, 99u8, 116u8,
+                    117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+                    108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+                    110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+                    112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+                    111u8, 116u8, 104u8, 101u8, 114u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8,
+                    112u8, 97u8, 114u8, 97u8, 103u8, 114u8, 97u8, 112u8, 104u8, 115u8, 101u8,
+                    112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 112u8, 99u8, 112u8, 100u8,
+                    112u8, 101u8, 112u8, 102u8, 112u8, 105u8, 112u8, 111u8, 112u8, 114u8, 105u8,
+                    118u8, 97u8, 116u8, 101u8, 117u8, 115u8, 101u8, 112u8, 115u8, 115u8, 99u8,
+                    115u8, 107u8, 115u8, 109u8, 115u8, 111u8, 115u8, 112u8, 97u8, 99u8, 101u8,
+                    115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 115u8, 112u8,
+                    97u8, 99u8, 105u8, 110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 115u8, 117u8,
+                    114u8, 114u8, 111u8, 103u8, 97u8, 116u8, 101u8, 116u8, 105u8, 116u8, 108u8,
+                    101u8, 99u8, 97u8, 115u8, 101u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8,
+                    117u8, 110u8, 97u8, 115u8, 115u8, 105u8, 103u8, 110u8, 101u8, 100u8, 117u8,
+                    112u8, 112u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 108u8, 101u8, 116u8,
+                    116u8, 101u8, 114u8, 122u8, 108u8, 122u8, 112u8, 122u8, 115u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+                    0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 9u8,
+                    0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8,
+                    16u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8,
+                    0u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 26u8,
+                    0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 23u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 22u8, 0u8,
+                    0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 28u8,
+                    0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                    25u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8,
+                    0u8, 12u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 14u8,
+                    0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,44 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GraphemeClusterBreakNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 9u8, 0u8, 11u8, 0u8, 13u8, 0u8, 18u8,
+                    0u8, 26u8, 0u8, 29u8, 0u8, 31u8, 0u8, 40u8, 0u8, 42u8, 0u8, 48u8, 0u8, 51u8,
+                    0u8, 63u8, 0u8, 64u8, 0u8, 66u8, 0u8, 68u8, 0u8, 71u8, 0u8, 76u8, 0u8, 78u8,
+                    0u8, 85u8, 0u8, 102u8, 0u8, 104u8, 0u8, 106u8, 0u8, 117u8, 0u8, 118u8, 0u8,
+                    119u8, 0u8, 121u8, 0u8, 99u8, 110u8, 99u8, 111u8, 110u8, 116u8, 114u8, 111u8,
+                    108u8, 99u8, 114u8, 101u8, 98u8, 101u8, 98u8, 97u8, 115u8, 101u8, 101u8, 98u8,
+                    97u8, 115u8, 101u8, 103u8, 97u8, 122u8, 101u8, 98u8, 103u8, 101u8, 109u8,
+                    101u8, 109u8, 111u8, 100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 101u8, 120u8,
+                    101u8, 120u8, 116u8, 101u8, 110u8, 100u8, 103u8, 97u8, 122u8, 103u8, 108u8,
+                    117u8, 101u8, 97u8, 102u8, 116u8, 101u8, 114u8, 122u8, 119u8, 106u8, 108u8,
+                    108u8, 102u8, 108u8, 118u8, 108u8, 118u8, 116u8, 111u8, 116u8, 104u8, 101u8,
+                    114u8, 112u8, 112u8, 112u8, 114u8, 101u8, 112u8, 101u8, 110u8, 100u8, 114u8,
+                    101u8, 103u8, 105u8, 111u8, 110u8, 97u8, 108u8, 105u8, 110u8, 100u8, 105u8,
+                    99u8, 97u8, 116u8, 111u8, 114u8, 114u8, 105u8, 115u8, 109u8, 115u8, 112u8,
+                    97u8, 99u8, 105u8, 110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 116u8, 118u8,
+                    120u8, 120u8, 122u8, 119u8, 106u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                    0u8, 13u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+                    0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 16u8,
+                    0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                    6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8,
+                    0u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,104 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GeneralCategoryMaskNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    80u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 12u8, 0u8, 14u8, 0u8, 16u8, 0u8, 32u8,
+                    0u8, 34u8, 0u8, 39u8, 0u8, 41u8, 0u8, 54u8, 0u8, 74u8, 0u8, 81u8, 0u8, 83u8,
+                    0u8, 97u8, 0u8, 112u8, 0u8, 125u8, 0u8, 130u8, 0u8, 143u8, 0u8, 159u8, 0u8,
+                    165u8, 0u8, 183u8, 0u8, 184u8, 0u8, 186u8, 0u8, 192u8, 0u8, 204u8, 0u8, 217u8,
+                    0u8, 219u8, 0u8, 221u8, 0u8, 223u8, 0u8, 238u8, 0u8, 240u8, 0u8, 242u8, 0u8,
+                    243u8, 0u8, 247u8, 0u8, 1u8, 1u8, 3u8, 1u8, 5u8, 1u8, 7u8, 1u8, 21u8, 1u8,
+                    35u8, 1u8, 36u8, 1u8, 38u8, 1u8, 40u8, 1u8, 42u8, 1u8, 56u8, 1u8, 62u8, 1u8,
+                    77u8, 1u8, 82u8, 1u8, 93u8, 1u8, 104u8, 1u8, 120u8, 1u8, 131u8, 1u8, 132u8,
+                    1u8, 150u8, 1u8, 152u8, 1u8, 154u8, 1u8, 156u8, 1u8, 158u8, 1u8, 160u8, 1u8,
+                    162u8, 1u8, 172u8, 1u8, 174u8, 1u8, 179u8, 1u8, 190u8, 1u8, 191u8, 1u8, 193u8,
+                    1u8, 202u8, 1u8, 204u8, 1u8, 206u8, 1u8, 208u8, 1u8, 222u8, 1u8, 233u8, 1u8,
+                    242u8, 1u8, 248u8, 1u8, 7u8, 2u8, 17u8, 2u8, 32u8, 2u8, 33u8, 2u8, 35u8, 2u8,
+                    37u8, 2u8, 99u8, 99u8, 97u8, 115u8, 101u8, 100u8, 108u8, 101u8, 116u8, 116u8,
+                    101u8, 114u8, 99u8, 99u8, 99u8, 102u8, 99u8, 108u8, 111u8, 115u8, 101u8, 112u8,
+                    117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 99u8,
+                    110u8, 99u8, 110u8, 116u8, 114u8, 108u8, 99u8, 111u8, 99u8, 111u8, 109u8, 98u8,
+                    105u8, 110u8, 105u8, 110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 99u8, 111u8,
+                    110u8, 110u8, 101u8, 99u8, 116u8, 111u8, 114u8, 112u8, 117u8, 110u8, 99u8,
+                    116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 99u8, 111u8, 110u8, 116u8,
+                    114u8, 111u8, 108u8, 99u8, 115u8, 99u8, 117u8, 114u8, 114u8, 101u8, 110u8,
+                    99u8, 121u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 100u8, 97u8, 115u8,
+                    104u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 100u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 110u8, 117u8, 109u8,
+                    98u8, 101u8, 114u8, 100u8, 105u8, 103u8, 105u8, 116u8, 101u8, 110u8, 99u8,
+                    108u8, 111u8, 115u8, 105u8, 110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 102u8,
+                    105u8, 110u8, 97u8, 108u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8,
+                    116u8, 105u8, 111u8, 110u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 105u8,
+                    110u8, 105u8, 116u8, 105u8, 97u8, 108u8, 112u8, 117u8, 110u8, 99u8, 116u8,
+                    117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 108u8, 108u8, 99u8, 108u8, 101u8,
+                    116u8, 116u8, 101u8, 114u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 110u8,
+                    117u8, 109u8, 98u8, 101u8, 114u8, 108u8, 105u8, 110u8, 101u8, 115u8, 101u8,
+                    112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 108u8, 108u8, 108u8, 109u8,
+                    108u8, 111u8, 108u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8,
+                    108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 108u8, 116u8, 108u8, 117u8, 109u8,
+                    109u8, 97u8, 114u8, 107u8, 109u8, 97u8, 116u8, 104u8, 115u8, 121u8, 109u8,
+                    98u8, 111u8, 108u8, 109u8, 99u8, 109u8, 101u8, 109u8, 110u8, 109u8, 111u8,
+                    100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 108u8, 101u8, 116u8, 116u8, 101u8,
+                    114u8, 109u8, 111u8, 100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 115u8, 121u8,
+                    109u8, 98u8, 111u8, 108u8, 110u8, 110u8, 100u8, 110u8, 108u8, 110u8, 111u8,
+                    110u8, 111u8, 110u8, 115u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 109u8,
+                    97u8, 114u8, 107u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 111u8, 112u8,
+                    101u8, 110u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 111u8, 116u8, 104u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8,
+                    114u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8,
+                    114u8, 110u8, 117u8, 109u8, 98u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8,
+                    114u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 111u8, 116u8, 104u8, 101u8, 114u8, 115u8, 121u8, 109u8, 98u8, 111u8,
+                    108u8, 112u8, 112u8, 97u8, 114u8, 97u8, 103u8, 114u8, 97u8, 112u8, 104u8,
+                    115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 112u8, 99u8,
+                    112u8, 100u8, 112u8, 101u8, 112u8, 102u8, 112u8, 105u8, 112u8, 111u8, 112u8,
+                    114u8, 105u8, 118u8, 97u8, 116u8, 101u8, 117u8, 115u8, 101u8, 112u8, 115u8,
+                    112u8, 117u8, 110u8, 99u8, 116u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8,
+                    97u8, 116u8, 105u8, 111u8, 110u8, 115u8, 115u8, 99u8, 115u8, 101u8, 112u8,
+                    97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 115u8, 107u8, 115u8, 109u8, 115u8,
+                    111u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8,
+                    116u8, 111u8, 114u8, 115u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 109u8,
+                    97u8, 114u8, 107u8, 115u8, 117u8, 114u8, 114u8, 111u8, 103u8, 97u8, 116u8,
+                    101u8, 115u8, 121u8, 109u8, 98u8, 111u8, 108u8, 116u8, 105u8, 116u8, 108u8,
+                    101u8, 99u8, 97u8, 115u8, 101u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8,
+                    117u8, 110u8, 97u8, 115u8, 115u8, 105u8, 103u8, 110u8, 101u8, 100u8, 117u8,
+                    112u8, 112u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 108u8, 101u8, 116u8,
+                    116u8, 101u8, 114u8, 122u8, 122u8, 108u8, 122u8, 112u8, 122u8, 115u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 128u8, 7u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 32u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 0u8,
+                    2u8, 0u8, 192u8, 1u8, 0u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8,
+                    0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 8u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    2u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 1u8, 0u8,
+                    0u8, 0u8, 0u8, 16u8, 62u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8,
+                    0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 2u8,
+                    0u8, 0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 1u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 14u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8,
+                    0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                    0u8, 16u8, 0u8, 1u8, 128u8, 7u8, 0u8, 32u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8,
+                    0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 248u8, 48u8, 0u8, 64u8,
+                    0u8, 0u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8,
+                    0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                    0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 248u8, 48u8, 0u8, 0u8, 248u8, 48u8, 0u8, 0u8,
+                    0u8, 15u8, 0u8, 0u8, 0u8, 2u8, 0u8, 112u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+                    0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 8u8, 0u8, 16u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 15u8, 8u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                    0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 64u8,
+                    0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,32 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: JoiningTypeNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 13u8, 0u8, 24u8, 0u8, 25u8,
+                    0u8, 36u8, 0u8, 46u8, 0u8, 47u8, 0u8, 59u8, 0u8, 60u8, 0u8, 71u8, 0u8, 99u8,
+                    100u8, 100u8, 117u8, 97u8, 108u8, 106u8, 111u8, 105u8, 110u8, 105u8, 110u8,
+                    103u8, 106u8, 111u8, 105u8, 110u8, 99u8, 97u8, 117u8, 115u8, 105u8, 110u8,
+                    103u8, 108u8, 108u8, 101u8, 102u8, 116u8, 106u8, 111u8, 105u8, 110u8, 105u8,
+                    110u8, 103u8, 110u8, 111u8, 110u8, 106u8, 111u8, 105u8, 110u8, 105u8, 110u8,
+                    103u8, 114u8, 114u8, 105u8, 103u8, 104u8, 116u8, 106u8, 111u8, 105u8, 110u8,
+                    105u8, 110u8, 103u8, 116u8, 116u8, 114u8, 97u8, 110u8, 115u8, 112u8, 97u8,
+                    114u8, 101u8, 110u8, 116u8, 117u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
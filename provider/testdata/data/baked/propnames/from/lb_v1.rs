@@ -0,0 +1,100 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: LineBreakNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    81u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 14u8, 0u8, 23u8, 0u8, 25u8,
+                    0u8, 27u8, 0u8, 29u8, 0u8, 31u8, 0u8, 41u8, 0u8, 52u8, 0u8, 61u8, 0u8, 73u8,
+                    0u8, 87u8, 0u8, 89u8, 0u8, 91u8, 0u8, 93u8, 0u8, 109u8, 0u8, 125u8, 0u8, 127u8,
+                    0u8, 140u8, 0u8, 154u8, 0u8, 180u8, 0u8, 195u8, 0u8, 197u8, 0u8, 199u8, 0u8,
+                    201u8, 0u8, 206u8, 0u8, 208u8, 0u8, 217u8, 0u8, 219u8, 0u8, 230u8, 0u8, 232u8,
+                    0u8, 236u8, 0u8, 238u8, 0u8, 240u8, 0u8, 252u8, 0u8, 254u8, 0u8, 0u8, 1u8, 6u8,
+                    1u8, 8u8, 1u8, 19u8, 1u8, 21u8, 1u8, 33u8, 1u8, 44u8, 1u8, 55u8, 1u8, 57u8,
+                    1u8, 59u8, 1u8, 61u8, 1u8, 63u8, 1u8, 65u8, 1u8, 73u8, 1u8, 87u8, 1u8, 95u8,
+                    1u8, 97u8, 1u8, 107u8, 1u8, 109u8, 1u8, 111u8, 1u8, 118u8, 1u8, 120u8, 1u8,
+                    135u8, 1u8, 137u8, 1u8, 151u8, 1u8, 153u8, 1u8, 166u8, 1u8, 168u8, 1u8, 177u8,
+                    1u8, 194u8, 1u8, 196u8, 1u8, 198u8, 1u8, 200u8, 1u8, 202u8, 1u8, 207u8, 1u8,
+                    216u8, 1u8, 218u8, 1u8, 225u8, 1u8, 227u8, 1u8, 237u8, 1u8, 239u8, 1u8, 241u8,
+                    1u8, 244u8, 1u8, 97u8, 105u8, 97u8, 108u8, 97u8, 108u8, 112u8, 104u8, 97u8,
+                    98u8, 101u8, 116u8, 105u8, 99u8, 97u8, 109u8, 98u8, 105u8, 103u8, 117u8, 111u8,
+                    117u8, 115u8, 98u8, 50u8, 98u8, 97u8, 98u8, 98u8, 98u8, 107u8, 98u8, 114u8,
+                    101u8, 97u8, 107u8, 97u8, 102u8, 116u8, 101u8, 114u8, 98u8, 114u8, 101u8, 97u8,
+                    107u8, 98u8, 101u8, 102u8, 111u8, 114u8, 101u8, 98u8, 114u8, 101u8, 97u8,
+                    107u8, 98u8, 111u8, 116u8, 104u8, 98u8, 114u8, 101u8, 97u8, 107u8, 115u8,
+                    121u8, 109u8, 98u8, 111u8, 108u8, 115u8, 99u8, 97u8, 114u8, 114u8, 105u8, 97u8,
+                    103u8, 101u8, 114u8, 101u8, 116u8, 117u8, 114u8, 110u8, 99u8, 98u8, 99u8,
+                    106u8, 99u8, 108u8, 99u8, 108u8, 111u8, 115u8, 101u8, 112u8, 97u8, 114u8,
+                    101u8, 110u8, 116u8, 104u8, 101u8, 115u8, 105u8, 115u8, 99u8, 108u8, 111u8,
+                    115u8, 101u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8,
+                    111u8, 110u8, 99u8, 109u8, 99u8, 111u8, 109u8, 98u8, 105u8, 110u8, 105u8,
+                    110u8, 103u8, 109u8, 97u8, 114u8, 107u8, 99u8, 111u8, 109u8, 112u8, 108u8,
+                    101u8, 120u8, 99u8, 111u8, 110u8, 116u8, 101u8, 120u8, 116u8, 99u8, 111u8,
+                    110u8, 100u8, 105u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 106u8, 97u8,
+                    112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 115u8, 116u8, 97u8, 114u8, 116u8,
+                    101u8, 114u8, 99u8, 111u8, 110u8, 116u8, 105u8, 110u8, 103u8, 101u8, 110u8,
+                    116u8, 98u8, 114u8, 101u8, 97u8, 107u8, 99u8, 112u8, 99u8, 114u8, 101u8, 98u8,
+                    101u8, 98u8, 97u8, 115u8, 101u8, 101u8, 109u8, 101u8, 109u8, 111u8, 100u8,
+                    105u8, 102u8, 105u8, 101u8, 114u8, 101u8, 120u8, 101u8, 120u8, 99u8, 108u8,
+                    97u8, 109u8, 97u8, 116u8, 105u8, 111u8, 110u8, 103u8, 108u8, 103u8, 108u8,
+                    117u8, 101u8, 104u8, 50u8, 104u8, 51u8, 104u8, 101u8, 98u8, 114u8, 101u8,
+                    119u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 104u8, 108u8, 104u8, 121u8,
+                    104u8, 121u8, 112u8, 104u8, 101u8, 110u8, 105u8, 100u8, 105u8, 100u8, 101u8,
+                    111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8, 105u8, 110u8, 105u8,
+                    110u8, 102u8, 105u8, 120u8, 110u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+                    105u8, 110u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 98u8, 108u8, 101u8,
+                    105u8, 110u8, 115u8, 101u8, 112u8, 101u8, 114u8, 97u8, 98u8, 108u8, 101u8,
+                    105u8, 115u8, 106u8, 108u8, 106u8, 116u8, 106u8, 118u8, 108u8, 102u8, 108u8,
+                    105u8, 110u8, 101u8, 102u8, 101u8, 101u8, 100u8, 109u8, 97u8, 110u8, 100u8,
+                    97u8, 116u8, 111u8, 114u8, 121u8, 98u8, 114u8, 101u8, 97u8, 107u8, 110u8,
+                    101u8, 120u8, 116u8, 108u8, 105u8, 110u8, 101u8, 110u8, 108u8, 110u8, 111u8,
+                    110u8, 115u8, 116u8, 97u8, 114u8, 116u8, 101u8, 114u8, 110u8, 115u8, 110u8,
+                    117u8, 110u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 111u8, 112u8, 111u8,
+                    112u8, 101u8, 110u8, 112u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8,
+                    105u8, 111u8, 110u8, 112u8, 111u8, 112u8, 111u8, 115u8, 116u8, 102u8, 105u8,
+                    120u8, 110u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 112u8, 114u8, 112u8,
+                    114u8, 101u8, 102u8, 105u8, 120u8, 110u8, 117u8, 109u8, 101u8, 114u8, 105u8,
+                    99u8, 113u8, 117u8, 113u8, 117u8, 111u8, 116u8, 97u8, 116u8, 105u8, 111u8,
+                    110u8, 114u8, 101u8, 103u8, 105u8, 111u8, 110u8, 97u8, 108u8, 105u8, 110u8,
+                    100u8, 105u8, 99u8, 97u8, 116u8, 111u8, 114u8, 114u8, 105u8, 115u8, 97u8,
+                    115u8, 103u8, 115u8, 112u8, 115u8, 112u8, 97u8, 99u8, 101u8, 115u8, 117u8,
+                    114u8, 114u8, 111u8, 103u8, 97u8, 116u8, 101u8, 115u8, 121u8, 117u8, 110u8,
+                    107u8, 110u8, 111u8, 119u8, 110u8, 119u8, 106u8, 119u8, 111u8, 114u8, 100u8,
+                    106u8, 111u8, 105u8, 110u8, 101u8, 114u8, 120u8, 120u8, 122u8, 119u8, 122u8,
+                    119u8, 106u8, 122u8, 119u8, 115u8, 112u8, 97u8, 99u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                    3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8,
+                    0u8, 10u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 8u8, 0u8,
+                    0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8,
+                    0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                    36u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8,
+                    0u8, 41u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+                    0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 32u8,
+                    0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+                    13u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8,
+                    0u8, 16u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 17u8,
+                    0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8,
+                    29u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8,
+                    0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+                    0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 23u8,
+                    0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8,
+                    24u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8,
+                    0u8, 25u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 0u8,
+                    0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 42u8,
+                    0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,12 @@
+// @generated
+pub mod bc_v1;
+pub mod ccc_v1;
+pub mod ea_v1;
+pub mod gc_v1;
+pub mod gcb_v1;
+pub mod gcm_v1;
+pub mod jt_v1;
+pub mod lb_v1;
+pub mod sb_v1;
+pub mod sc_v1;
+pub mod wb_v1;
@@ -0,0 +1,40 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: SentenceBreakNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    27u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 7u8, 0u8, 9u8, 0u8, 14u8, 0u8, 16u8,
+                    0u8, 18u8, 0u8, 24u8, 0u8, 26u8, 0u8, 32u8, 0u8, 34u8, 0u8, 36u8, 0u8, 38u8,
+                    0u8, 43u8, 0u8, 45u8, 0u8, 52u8, 0u8, 59u8, 0u8, 64u8, 0u8, 66u8, 0u8, 75u8,
+                    0u8, 77u8, 0u8, 80u8, 0u8, 82u8, 0u8, 84u8, 0u8, 89u8, 0u8, 91u8, 0u8, 96u8,
+                    0u8, 97u8, 116u8, 97u8, 116u8, 101u8, 114u8, 109u8, 99u8, 108u8, 99u8, 108u8,
+                    111u8, 115u8, 101u8, 99u8, 114u8, 101u8, 120u8, 101u8, 120u8, 116u8, 101u8,
+                    110u8, 100u8, 102u8, 111u8, 102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 108u8,
+                    101u8, 108u8, 102u8, 108u8, 111u8, 108u8, 111u8, 119u8, 101u8, 114u8, 110u8,
+                    117u8, 110u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 111u8, 108u8, 101u8,
+                    116u8, 116u8, 101u8, 114u8, 111u8, 116u8, 104u8, 101u8, 114u8, 115u8, 99u8,
+                    115u8, 99u8, 111u8, 110u8, 116u8, 105u8, 110u8, 117u8, 101u8, 115u8, 101u8,
+                    115u8, 101u8, 112u8, 115u8, 112u8, 115u8, 116u8, 115u8, 116u8, 101u8, 114u8,
+                    109u8, 117u8, 112u8, 117u8, 112u8, 112u8, 101u8, 114u8, 120u8, 120u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8,
+                    0u8, 3u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+                    0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8,
+                    0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 7u8,
+                    0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8,
+                    0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,334 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::ScriptNameToValueV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    65u8, 1u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 9u8, 0u8, 13u8, 0u8, 17u8, 0u8, 37u8,
+                    0u8, 41u8, 0u8, 47u8, 0u8, 55u8, 0u8, 59u8, 0u8, 63u8, 0u8, 70u8, 0u8, 74u8,
+                    0u8, 78u8, 0u8, 86u8, 0u8, 90u8, 0u8, 95u8, 0u8, 99u8, 0u8, 107u8, 0u8, 112u8,
+                    0u8, 116u8, 0u8, 120u8, 0u8, 127u8, 0u8, 136u8, 0u8, 140u8, 0u8, 144u8, 0u8,
+                    152u8, 0u8, 156u8, 0u8, 162u8, 0u8, 166u8, 0u8, 173u8, 0u8, 177u8, 0u8, 185u8,
+                    0u8, 189u8, 0u8, 194u8, 0u8, 198u8, 0u8, 216u8, 0u8, 220u8, 0u8, 224u8, 0u8,
+                    230u8, 0u8, 247u8, 0u8, 253u8, 0u8, 1u8, 1u8, 5u8, 1u8, 13u8, 1u8, 23u8, 1u8,
+                    27u8, 1u8, 33u8, 1u8, 37u8, 1u8, 43u8, 1u8, 47u8, 1u8, 51u8, 1u8, 60u8, 1u8,
+                    67u8, 1u8, 78u8, 1u8, 86u8, 1u8, 90u8, 1u8, 97u8, 1u8, 101u8, 1u8, 111u8, 1u8,
+                    115u8, 1u8, 125u8, 1u8, 129u8, 1u8, 134u8, 1u8, 138u8, 1u8, 142u8, 1u8, 150u8,
+                    1u8, 154u8, 1u8, 173u8, 1u8, 177u8, 1u8, 184u8, 1u8, 188u8, 1u8, 195u8, 1u8,
+                    199u8, 1u8, 207u8, 1u8, 211u8, 1u8, 219u8, 1u8, 223u8, 1u8, 233u8, 1u8, 237u8,
+                    1u8, 241u8, 1u8, 245u8, 1u8, 251u8, 1u8, 255u8, 1u8, 6u8, 2u8, 11u8, 2u8, 15u8,
+                    2u8, 23u8, 2u8, 27u8, 2u8, 39u8, 2u8, 47u8, 2u8, 51u8, 2u8, 54u8, 2u8, 58u8,
+                    2u8, 64u8, 2u8, 68u8, 2u8, 82u8, 2u8, 86u8, 2u8, 93u8, 2u8, 97u8, 2u8, 103u8,
+                    2u8, 107u8, 2u8, 113u8, 2u8, 117u8, 2u8, 125u8, 2u8, 129u8, 2u8, 133u8, 2u8,
+                    137u8, 2u8, 141u8, 2u8, 145u8, 2u8, 160u8, 2u8, 169u8, 2u8, 189u8, 2u8, 210u8,
+                    2u8, 214u8, 2u8, 218u8, 2u8, 226u8, 2u8, 232u8, 2u8, 236u8, 2u8, 240u8, 2u8,
+                    247u8, 2u8, 255u8, 2u8, 17u8, 3u8, 24u8, 3u8, 28u8, 3u8, 38u8, 3u8, 55u8, 3u8,
+                    60u8, 3u8, 64u8, 3u8, 68u8, 3u8, 74u8, 3u8, 83u8, 3u8, 87u8, 3u8, 91u8, 3u8,
+                    95u8, 3u8, 99u8, 3u8, 102u8, 3u8, 106u8, 3u8, 111u8, 3u8, 115u8, 3u8, 119u8,
+                    3u8, 125u8, 3u8, 129u8, 3u8, 134u8, 3u8, 138u8, 3u8, 142u8, 3u8, 149u8, 3u8,
+                    156u8, 3u8, 160u8, 3u8, 164u8, 3u8, 170u8, 3u8, 174u8, 3u8, 180u8, 3u8, 188u8,
+                    3u8, 192u8, 3u8, 196u8, 3u8, 203u8, 3u8, 212u8, 3u8, 216u8, 3u8, 223u8, 3u8,
+                    227u8, 3u8, 237u8, 3u8, 241u8, 3u8, 248u8, 3u8, 4u8, 4u8, 15u8, 4u8, 19u8, 4u8,
+                    30u8, 4u8, 34u8, 4u8, 46u8, 4u8, 50u8, 4u8, 54u8, 4u8, 69u8, 4u8, 88u8, 4u8,
+                    92u8, 4u8, 96u8, 4u8, 100u8, 4u8, 104u8, 4u8, 113u8, 4u8, 116u8, 4u8, 120u8,
+                    4u8, 124u8, 4u8, 128u8, 4u8, 135u8, 4u8, 142u8, 4u8, 146u8, 4u8, 155u8, 4u8,
+                    159u8, 4u8, 170u8, 4u8, 174u8, 4u8, 178u8, 4u8, 182u8, 4u8, 191u8, 4u8, 194u8,
+                    4u8, 198u8, 4u8, 202u8, 4u8, 207u8, 4u8, 227u8, 4u8, 231u8, 4u8, 236u8, 4u8,
+                    243u8, 4u8, 247u8, 4u8, 3u8, 5u8, 12u8, 5u8, 27u8, 5u8, 36u8, 5u8, 46u8, 5u8,
+                    56u8, 5u8, 71u8, 5u8, 80u8, 5u8, 89u8, 5u8, 94u8, 5u8, 98u8, 5u8, 102u8, 5u8,
+                    107u8, 5u8, 111u8, 5u8, 115u8, 5u8, 122u8, 5u8, 126u8, 5u8, 137u8, 5u8, 141u8,
+                    5u8, 150u8, 5u8, 154u8, 5u8, 163u8, 5u8, 167u8, 5u8, 171u8, 5u8, 178u8, 5u8,
+                    182u8, 5u8, 186u8, 5u8, 190u8, 5u8, 200u8, 5u8, 204u8, 5u8, 208u8, 5u8, 222u8,
+                    5u8, 226u8, 5u8, 230u8, 5u8, 236u8, 5u8, 240u8, 5u8, 244u8, 5u8, 249u8, 5u8,
+                    253u8, 5u8, 6u8, 6u8, 10u8, 6u8, 14u8, 6u8, 18u8, 6u8, 28u8, 6u8, 32u8, 6u8,
+                    39u8, 6u8, 46u8, 6u8, 50u8, 6u8, 54u8, 6u8, 58u8, 6u8, 65u8, 6u8, 76u8, 6u8,
+                    80u8, 6u8, 84u8, 6u8, 91u8, 6u8, 95u8, 6u8, 102u8, 6u8, 106u8, 6u8, 110u8, 6u8,
+                    121u8, 6u8, 125u8, 6u8, 132u8, 6u8, 136u8, 6u8, 145u8, 6u8, 149u8, 6u8, 160u8,
+                    6u8, 164u8, 6u8, 170u8, 6u8, 177u8, 6u8, 181u8, 6u8, 189u8, 6u8, 194u8, 6u8,
+                    201u8, 6u8, 208u8, 6u8, 212u8, 6u8, 217u8, 6u8, 221u8, 6u8, 225u8, 6u8, 230u8,
+                    6u8, 234u8, 6u8, 238u8, 6u8, 244u8, 6u8, 250u8, 6u8, 254u8, 6u8, 2u8, 7u8, 8u8,
+                    7u8, 12u8, 7u8, 16u8, 7u8, 20u8, 7u8, 26u8, 7u8, 30u8, 7u8, 37u8, 7u8, 41u8,
+                    7u8, 49u8, 7u8, 53u8, 7u8, 60u8, 7u8, 64u8, 7u8, 68u8, 7u8, 72u8, 7u8, 80u8,
+                    7u8, 87u8, 7u8, 90u8, 7u8, 94u8, 7u8, 98u8, 7u8, 106u8, 7u8, 112u8, 7u8, 116u8,
+                    7u8, 126u8, 7u8, 130u8, 7u8, 134u8, 7u8, 138u8, 7u8, 142u8, 7u8, 148u8, 7u8,
+                    150u8, 7u8, 154u8, 7u8, 169u8, 7u8, 173u8, 7u8, 177u8, 7u8, 181u8, 7u8, 97u8,
+                    100u8, 108u8, 97u8, 109u8, 97u8, 100u8, 108u8, 109u8, 97u8, 103u8, 104u8, 98u8,
+                    97u8, 104u8, 111u8, 109u8, 97u8, 110u8, 97u8, 116u8, 111u8, 108u8, 105u8, 97u8,
+                    110u8, 104u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8,
+                    115u8, 97u8, 114u8, 97u8, 98u8, 97u8, 114u8, 97u8, 98u8, 105u8, 99u8, 97u8,
+                    114u8, 109u8, 101u8, 110u8, 105u8, 97u8, 110u8, 97u8, 114u8, 109u8, 105u8,
+                    97u8, 114u8, 109u8, 110u8, 97u8, 118u8, 101u8, 115u8, 116u8, 97u8, 110u8, 97u8,
+                    118u8, 115u8, 116u8, 98u8, 97u8, 108u8, 105u8, 98u8, 97u8, 108u8, 105u8, 110u8,
+                    101u8, 115u8, 101u8, 98u8, 97u8, 109u8, 117u8, 98u8, 97u8, 109u8, 117u8, 109u8,
+                    98u8, 97u8, 115u8, 115u8, 98u8, 97u8, 115u8, 115u8, 97u8, 118u8, 97u8, 104u8,
+                    98u8, 97u8, 116u8, 97u8, 107u8, 98u8, 97u8, 116u8, 107u8, 98u8, 101u8, 110u8,
+                    103u8, 98u8, 101u8, 110u8, 103u8, 97u8, 108u8, 105u8, 98u8, 104u8, 97u8, 105u8,
+                    107u8, 115u8, 117u8, 107u8, 105u8, 98u8, 104u8, 107u8, 115u8, 98u8, 111u8,
+                    112u8, 111u8, 98u8, 111u8, 112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 98u8,
+                    114u8, 97u8, 104u8, 98u8, 114u8, 97u8, 104u8, 109u8, 105u8, 98u8, 114u8, 97u8,
+                    105u8, 98u8, 114u8, 97u8, 105u8, 108u8, 108u8, 101u8, 98u8, 117u8, 103u8,
+                    105u8, 98u8, 117u8, 103u8, 105u8, 110u8, 101u8, 115u8, 101u8, 98u8, 117u8,
+                    104u8, 100u8, 98u8, 117u8, 104u8, 105u8, 100u8, 99u8, 97u8, 107u8, 109u8, 99u8,
+                    97u8, 110u8, 97u8, 100u8, 105u8, 97u8, 110u8, 97u8, 98u8, 111u8, 114u8, 105u8,
+                    103u8, 105u8, 110u8, 97u8, 108u8, 99u8, 97u8, 110u8, 115u8, 99u8, 97u8, 114u8,
+                    105u8, 99u8, 97u8, 114u8, 105u8, 97u8, 110u8, 99u8, 97u8, 117u8, 99u8, 97u8,
+                    115u8, 105u8, 97u8, 110u8, 97u8, 108u8, 98u8, 97u8, 110u8, 105u8, 97u8, 110u8,
+                    99u8, 104u8, 97u8, 107u8, 109u8, 97u8, 99u8, 104u8, 97u8, 109u8, 99u8, 104u8,
+                    101u8, 114u8, 99u8, 104u8, 101u8, 114u8, 111u8, 107u8, 101u8, 101u8, 99u8,
+                    104u8, 111u8, 114u8, 97u8, 115u8, 109u8, 105u8, 97u8, 110u8, 99u8, 104u8,
+                    114u8, 115u8, 99u8, 111u8, 109u8, 109u8, 111u8, 110u8, 99u8, 111u8, 112u8,
+                    116u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 99u8, 112u8, 109u8, 110u8, 99u8,
+                    112u8, 114u8, 116u8, 99u8, 117u8, 110u8, 101u8, 105u8, 102u8, 111u8, 114u8,
+                    109u8, 99u8, 121u8, 112u8, 114u8, 105u8, 111u8, 116u8, 99u8, 121u8, 112u8,
+                    114u8, 111u8, 109u8, 105u8, 110u8, 111u8, 97u8, 110u8, 99u8, 121u8, 114u8,
+                    105u8, 108u8, 108u8, 105u8, 99u8, 99u8, 121u8, 114u8, 108u8, 100u8, 101u8,
+                    115u8, 101u8, 114u8, 101u8, 116u8, 100u8, 101u8, 118u8, 97u8, 100u8, 101u8,
+                    118u8, 97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 100u8, 105u8, 97u8, 107u8,
+                    100u8, 105u8, 118u8, 101u8, 115u8, 97u8, 107u8, 117u8, 114u8, 117u8, 100u8,
+                    111u8, 103u8, 114u8, 100u8, 111u8, 103u8, 114u8, 97u8, 100u8, 115u8, 114u8,
+                    116u8, 100u8, 117u8, 112u8, 108u8, 100u8, 117u8, 112u8, 108u8, 111u8, 121u8,
+                    97u8, 110u8, 101u8, 103u8, 121u8, 112u8, 101u8, 103u8, 121u8, 112u8, 116u8,
+                    105u8, 97u8, 110u8, 104u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8,
+                    112u8, 104u8, 115u8, 101u8, 108u8, 98u8, 97u8, 101u8, 108u8, 98u8, 97u8, 115u8,
+                    97u8, 110u8, 101u8, 108u8, 121u8, 109u8, 101u8, 108u8, 121u8, 109u8, 97u8,
+                    105u8, 99u8, 101u8, 116u8, 104u8, 105u8, 101u8, 116u8, 104u8, 105u8, 111u8,
+                    112u8, 105u8, 99u8, 103u8, 101u8, 111u8, 114u8, 103u8, 101u8, 111u8, 114u8,
+                    103u8, 105u8, 97u8, 110u8, 103u8, 108u8, 97u8, 103u8, 103u8, 108u8, 97u8,
+                    103u8, 111u8, 108u8, 105u8, 116u8, 105u8, 99u8, 103u8, 111u8, 110u8, 103u8,
+                    103u8, 111u8, 110u8, 109u8, 103u8, 111u8, 116u8, 104u8, 103u8, 111u8, 116u8,
+                    104u8, 105u8, 99u8, 103u8, 114u8, 97u8, 110u8, 103u8, 114u8, 97u8, 110u8,
+                    116u8, 104u8, 97u8, 103u8, 114u8, 101u8, 101u8, 107u8, 103u8, 114u8, 101u8,
+                    107u8, 103u8, 117u8, 106u8, 97u8, 114u8, 97u8, 116u8, 105u8, 103u8, 117u8,
+                    106u8, 114u8, 103u8, 117u8, 110u8, 106u8, 97u8, 108u8, 97u8, 103u8, 111u8,
+                    110u8, 100u8, 105u8, 103u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8, 105u8,
+                    103u8, 117u8, 114u8, 117u8, 104u8, 97u8, 110u8, 104u8, 97u8, 110u8, 103u8,
+                    104u8, 97u8, 110u8, 103u8, 117u8, 108u8, 104u8, 97u8, 110u8, 105u8, 104u8,
+                    97u8, 110u8, 105u8, 102u8, 105u8, 114u8, 111u8, 104u8, 105u8, 110u8, 103u8,
+                    121u8, 97u8, 104u8, 97u8, 110u8, 111u8, 104u8, 97u8, 110u8, 117u8, 110u8,
+                    111u8, 111u8, 104u8, 97u8, 116u8, 114u8, 104u8, 97u8, 116u8, 114u8, 97u8,
+                    110u8, 104u8, 101u8, 98u8, 114u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+                    104u8, 105u8, 114u8, 97u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8,
+                    104u8, 108u8, 117u8, 119u8, 104u8, 109u8, 110u8, 103u8, 104u8, 109u8, 110u8,
+                    112u8, 104u8, 114u8, 107u8, 116u8, 104u8, 117u8, 110u8, 103u8, 105u8, 109u8,
+                    112u8, 101u8, 114u8, 105u8, 97u8, 108u8, 97u8, 114u8, 97u8, 109u8, 97u8, 105u8,
+                    99u8, 105u8, 110u8, 104u8, 101u8, 114u8, 105u8, 116u8, 101u8, 100u8, 105u8,
+                    110u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+                    108u8, 112u8, 97u8, 104u8, 108u8, 97u8, 118u8, 105u8, 105u8, 110u8, 115u8,
+                    99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 112u8,
+                    97u8, 114u8, 116u8, 104u8, 105u8, 97u8, 110u8, 105u8, 116u8, 97u8, 108u8,
+                    106u8, 97u8, 118u8, 97u8, 106u8, 97u8, 118u8, 97u8, 110u8, 101u8, 115u8, 101u8,
+                    107u8, 97u8, 105u8, 116u8, 104u8, 105u8, 107u8, 97u8, 108u8, 105u8, 107u8,
+                    97u8, 110u8, 97u8, 107u8, 97u8, 110u8, 110u8, 97u8, 100u8, 97u8, 107u8, 97u8,
+                    116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 107u8, 97u8, 116u8, 97u8, 107u8, 97u8,
+                    110u8, 97u8, 111u8, 114u8, 104u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8,
+                    107u8, 97u8, 121u8, 97u8, 104u8, 108u8, 105u8, 107u8, 104u8, 97u8, 114u8,
+                    107u8, 104u8, 97u8, 114u8, 111u8, 115u8, 104u8, 116u8, 104u8, 105u8, 107u8,
+                    104u8, 105u8, 116u8, 97u8, 110u8, 115u8, 109u8, 97u8, 108u8, 108u8, 115u8,
+                    99u8, 114u8, 105u8, 112u8, 116u8, 107u8, 104u8, 109u8, 101u8, 114u8, 107u8,
+                    104u8, 109u8, 114u8, 107u8, 104u8, 111u8, 106u8, 107u8, 104u8, 111u8, 106u8,
+                    107u8, 105u8, 107u8, 104u8, 117u8, 100u8, 97u8, 119u8, 97u8, 100u8, 105u8,
+                    107u8, 105u8, 116u8, 115u8, 107u8, 110u8, 100u8, 97u8, 107u8, 116u8, 104u8,
+                    105u8, 108u8, 97u8, 110u8, 97u8, 108u8, 97u8, 111u8, 108u8, 97u8, 111u8, 111u8,
+                    108u8, 97u8, 116u8, 105u8, 110u8, 108u8, 97u8, 116u8, 110u8, 108u8, 101u8,
+                    112u8, 99u8, 108u8, 101u8, 112u8, 99u8, 104u8, 97u8, 108u8, 105u8, 109u8, 98u8,
+                    108u8, 105u8, 109u8, 98u8, 117u8, 108u8, 105u8, 110u8, 97u8, 108u8, 105u8,
+                    110u8, 98u8, 108u8, 105u8, 110u8, 101u8, 97u8, 114u8, 97u8, 108u8, 105u8,
+                    110u8, 101u8, 97u8, 114u8, 98u8, 108u8, 105u8, 115u8, 117u8, 108u8, 121u8,
+                    99u8, 105u8, 108u8, 121u8, 99u8, 105u8, 97u8, 110u8, 108u8, 121u8, 100u8,
+                    105u8, 108u8, 121u8, 100u8, 105u8, 97u8, 110u8, 109u8, 97u8, 104u8, 97u8,
+                    106u8, 97u8, 110u8, 105u8, 109u8, 97u8, 104u8, 106u8, 109u8, 97u8, 107u8, 97u8,
+                    109u8, 97u8, 107u8, 97u8, 115u8, 97u8, 114u8, 109u8, 97u8, 108u8, 97u8, 121u8,
+                    97u8, 108u8, 97u8, 109u8, 109u8, 97u8, 110u8, 100u8, 109u8, 97u8, 110u8, 100u8,
+                    97u8, 105u8, 99u8, 109u8, 97u8, 110u8, 105u8, 109u8, 97u8, 110u8, 105u8, 99u8,
+                    104u8, 97u8, 101u8, 97u8, 110u8, 109u8, 97u8, 114u8, 99u8, 109u8, 97u8, 114u8,
+                    99u8, 104u8, 101u8, 110u8, 109u8, 97u8, 115u8, 97u8, 114u8, 97u8, 109u8, 103u8,
+                    111u8, 110u8, 100u8, 105u8, 109u8, 101u8, 100u8, 101u8, 102u8, 97u8, 105u8,
+                    100u8, 114u8, 105u8, 110u8, 109u8, 101u8, 100u8, 102u8, 109u8, 101u8, 101u8,
+                    116u8, 101u8, 105u8, 109u8, 97u8, 121u8, 101u8, 107u8, 109u8, 101u8, 110u8,
+                    100u8, 109u8, 101u8, 110u8, 100u8, 101u8, 107u8, 105u8, 107u8, 97u8, 107u8,
+                    117u8, 105u8, 109u8, 101u8, 114u8, 99u8, 109u8, 101u8, 114u8, 111u8, 109u8,
+                    101u8, 114u8, 111u8, 105u8, 116u8, 105u8, 99u8, 99u8, 117u8, 114u8, 115u8,
+                    105u8, 118u8, 101u8, 109u8, 101u8, 114u8, 111u8, 105u8, 116u8, 105u8, 99u8,
+                    104u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8, 115u8,
+                    109u8, 105u8, 97u8, 111u8, 109u8, 108u8, 121u8, 109u8, 109u8, 111u8, 100u8,
+                    105u8, 109u8, 111u8, 110u8, 103u8, 109u8, 111u8, 110u8, 103u8, 111u8, 108u8,
+                    105u8, 97u8, 110u8, 109u8, 114u8, 111u8, 109u8, 114u8, 111u8, 111u8, 109u8,
+                    116u8, 101u8, 105u8, 109u8, 117u8, 108u8, 116u8, 109u8, 117u8, 108u8, 116u8,
+                    97u8, 110u8, 105u8, 109u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 109u8,
+                    121u8, 109u8, 114u8, 110u8, 97u8, 98u8, 97u8, 116u8, 97u8, 101u8, 97u8, 110u8,
+                    110u8, 97u8, 110u8, 100u8, 110u8, 97u8, 110u8, 100u8, 105u8, 110u8, 97u8,
+                    103u8, 97u8, 114u8, 105u8, 110u8, 97u8, 114u8, 98u8, 110u8, 98u8, 97u8, 116u8,
+                    110u8, 101u8, 119u8, 97u8, 110u8, 101u8, 119u8, 116u8, 97u8, 105u8, 108u8,
+                    117u8, 101u8, 110u8, 107u8, 111u8, 110u8, 107u8, 111u8, 111u8, 110u8, 115u8,
+                    104u8, 117u8, 110u8, 117u8, 115u8, 104u8, 117u8, 110u8, 121u8, 105u8, 97u8,
+                    107u8, 101u8, 110u8, 103u8, 112u8, 117u8, 97u8, 99u8, 104u8, 117u8, 101u8,
+                    104u8, 109u8, 111u8, 110u8, 103u8, 111u8, 103u8, 97u8, 109u8, 111u8, 103u8,
+                    104u8, 97u8, 109u8, 111u8, 108u8, 99u8, 104u8, 105u8, 107u8, 105u8, 111u8,
+                    108u8, 99u8, 107u8, 111u8, 108u8, 100u8, 104u8, 117u8, 110u8, 103u8, 97u8,
+                    114u8, 105u8, 97u8, 110u8, 111u8, 108u8, 100u8, 105u8, 116u8, 97u8, 108u8,
+                    105u8, 99u8, 111u8, 108u8, 100u8, 110u8, 111u8, 114u8, 116u8, 104u8, 97u8,
+                    114u8, 97u8, 98u8, 105u8, 97u8, 110u8, 111u8, 108u8, 100u8, 112u8, 101u8,
+                    114u8, 109u8, 105u8, 99u8, 111u8, 108u8, 100u8, 112u8, 101u8, 114u8, 115u8,
+                    105u8, 97u8, 110u8, 111u8, 108u8, 100u8, 115u8, 111u8, 103u8, 100u8, 105u8,
+                    97u8, 110u8, 111u8, 108u8, 100u8, 115u8, 111u8, 117u8, 116u8, 104u8, 97u8,
+                    114u8, 97u8, 98u8, 105u8, 97u8, 110u8, 111u8, 108u8, 100u8, 116u8, 117u8,
+                    114u8, 107u8, 105u8, 99u8, 111u8, 108u8, 100u8, 117u8, 121u8, 103u8, 104u8,
+                    117u8, 114u8, 111u8, 114u8, 105u8, 121u8, 97u8, 111u8, 114u8, 107u8, 104u8,
+                    111u8, 114u8, 121u8, 97u8, 111u8, 115u8, 97u8, 103u8, 101u8, 111u8, 115u8,
+                    103u8, 101u8, 111u8, 115u8, 109u8, 97u8, 111u8, 115u8, 109u8, 97u8, 110u8,
+                    121u8, 97u8, 111u8, 117u8, 103u8, 114u8, 112u8, 97u8, 104u8, 97u8, 119u8,
+                    104u8, 104u8, 109u8, 111u8, 110u8, 103u8, 112u8, 97u8, 108u8, 109u8, 112u8,
+                    97u8, 108u8, 109u8, 121u8, 114u8, 101u8, 110u8, 101u8, 112u8, 97u8, 117u8,
+                    99u8, 112u8, 97u8, 117u8, 99u8, 105u8, 110u8, 104u8, 97u8, 117u8, 112u8, 101u8,
+                    114u8, 109u8, 112u8, 104u8, 97u8, 103u8, 112u8, 104u8, 97u8, 103u8, 115u8,
+                    112u8, 97u8, 112u8, 104u8, 108u8, 105u8, 112u8, 104u8, 108u8, 112u8, 112u8,
+                    104u8, 110u8, 120u8, 112u8, 104u8, 111u8, 101u8, 110u8, 105u8, 99u8, 105u8,
+                    97u8, 110u8, 112u8, 108u8, 114u8, 100u8, 112u8, 114u8, 116u8, 105u8, 112u8,
+                    115u8, 97u8, 108u8, 116u8, 101u8, 114u8, 112u8, 97u8, 104u8, 108u8, 97u8,
+                    118u8, 105u8, 113u8, 97u8, 97u8, 99u8, 113u8, 97u8, 97u8, 105u8, 114u8, 101u8,
+                    106u8, 97u8, 110u8, 103u8, 114u8, 106u8, 110u8, 103u8, 114u8, 111u8, 104u8,
+                    103u8, 114u8, 117u8, 110u8, 105u8, 99u8, 114u8, 117u8, 110u8, 114u8, 115u8,
+                    97u8, 109u8, 97u8, 114u8, 105u8, 116u8, 97u8, 110u8, 115u8, 97u8, 109u8, 114u8,
+                    115u8, 97u8, 114u8, 98u8, 115u8, 97u8, 117u8, 114u8, 115u8, 97u8, 117u8, 114u8,
+                    97u8, 115u8, 104u8, 116u8, 114u8, 97u8, 115u8, 103u8, 110u8, 119u8, 115u8,
+                    104u8, 97u8, 114u8, 97u8, 100u8, 97u8, 115u8, 104u8, 97u8, 118u8, 105u8, 97u8,
+                    110u8, 115u8, 104u8, 97u8, 119u8, 115u8, 104u8, 114u8, 100u8, 115u8, 105u8,
+                    100u8, 100u8, 115u8, 105u8, 100u8, 100u8, 104u8, 97u8, 109u8, 115u8, 105u8,
+                    103u8, 110u8, 119u8, 114u8, 105u8, 116u8, 105u8, 110u8, 103u8, 115u8, 105u8,
+                    110u8, 100u8, 115u8, 105u8, 110u8, 104u8, 115u8, 105u8, 110u8, 104u8, 97u8,
+                    108u8, 97u8, 115u8, 111u8, 103u8, 100u8, 115u8, 111u8, 103u8, 100u8, 105u8,
+                    97u8, 110u8, 115u8, 111u8, 103u8, 111u8, 115u8, 111u8, 114u8, 97u8, 115u8,
+                    111u8, 114u8, 97u8, 115u8, 111u8, 109u8, 112u8, 101u8, 110u8, 103u8, 115u8,
+                    111u8, 121u8, 111u8, 115u8, 111u8, 121u8, 111u8, 109u8, 98u8, 111u8, 115u8,
+                    117u8, 110u8, 100u8, 115u8, 117u8, 110u8, 100u8, 97u8, 110u8, 101u8, 115u8,
+                    101u8, 115u8, 121u8, 108u8, 111u8, 115u8, 121u8, 108u8, 111u8, 116u8, 105u8,
+                    110u8, 97u8, 103u8, 114u8, 105u8, 115u8, 121u8, 114u8, 99u8, 115u8, 121u8,
+                    114u8, 105u8, 97u8, 99u8, 116u8, 97u8, 103u8, 97u8, 108u8, 111u8, 103u8, 116u8,
+                    97u8, 103u8, 98u8, 116u8, 97u8, 103u8, 98u8, 97u8, 110u8, 119u8, 97u8, 116u8,
+                    97u8, 105u8, 108u8, 101u8, 116u8, 97u8, 105u8, 116u8, 104u8, 97u8, 109u8,
+                    116u8, 97u8, 105u8, 118u8, 105u8, 101u8, 116u8, 116u8, 97u8, 107u8, 114u8,
+                    116u8, 97u8, 107u8, 114u8, 105u8, 116u8, 97u8, 108u8, 101u8, 116u8, 97u8,
+                    108u8, 117u8, 116u8, 97u8, 109u8, 105u8, 108u8, 116u8, 97u8, 109u8, 108u8,
+                    116u8, 97u8, 110u8, 103u8, 116u8, 97u8, 110u8, 103u8, 115u8, 97u8, 116u8, 97u8,
+                    110u8, 103u8, 117u8, 116u8, 116u8, 97u8, 118u8, 116u8, 116u8, 101u8, 108u8,
+                    117u8, 116u8, 101u8, 108u8, 117u8, 103u8, 117u8, 116u8, 102u8, 110u8, 103u8,
+                    116u8, 103u8, 108u8, 103u8, 116u8, 104u8, 97u8, 97u8, 116u8, 104u8, 97u8, 97u8,
+                    110u8, 97u8, 116u8, 104u8, 97u8, 105u8, 116u8, 105u8, 98u8, 101u8, 116u8, 97u8,
+                    110u8, 116u8, 105u8, 98u8, 116u8, 116u8, 105u8, 102u8, 105u8, 110u8, 97u8,
+                    103u8, 104u8, 116u8, 105u8, 114u8, 104u8, 116u8, 105u8, 114u8, 104u8, 117u8,
+                    116u8, 97u8, 116u8, 110u8, 115u8, 97u8, 116u8, 111u8, 116u8, 111u8, 117u8,
+                    103u8, 97u8, 114u8, 117u8, 103u8, 97u8, 114u8, 105u8, 116u8, 105u8, 99u8,
+                    117u8, 110u8, 107u8, 110u8, 111u8, 119u8, 110u8, 118u8, 97u8, 105u8, 118u8,
+                    97u8, 105u8, 105u8, 118u8, 105u8, 116u8, 104u8, 118u8, 105u8, 116u8, 104u8,
+                    107u8, 117u8, 113u8, 105u8, 119u8, 97u8, 110u8, 99u8, 104u8, 111u8, 119u8,
+                    97u8, 114u8, 97u8, 119u8, 97u8, 114u8, 97u8, 110u8, 103u8, 99u8, 105u8, 116u8,
+                    105u8, 119u8, 99u8, 104u8, 111u8, 120u8, 112u8, 101u8, 111u8, 120u8, 115u8,
+                    117u8, 120u8, 121u8, 101u8, 122u8, 105u8, 121u8, 101u8, 122u8, 105u8, 100u8,
+                    105u8, 121u8, 105u8, 121u8, 105u8, 105u8, 105u8, 122u8, 97u8, 110u8, 97u8,
+                    98u8, 97u8, 122u8, 97u8, 114u8, 115u8, 113u8, 117u8, 97u8, 114u8, 101u8, 122u8,
+                    97u8, 110u8, 98u8, 122u8, 105u8, 110u8, 104u8, 122u8, 121u8, 121u8, 121u8,
+                    122u8, 122u8, 122u8, 122u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    167u8, 0u8, 0u8, 0u8, 167u8, 0u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8, 161u8, 0u8,
+                    0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8,
+                    0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8,
+                    117u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 130u8, 0u8,
+                    0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8,
+                    63u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8,
+                    0u8, 168u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+                    0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 46u8,
+                    0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8,
+                    44u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8,
+                    0u8, 104u8, 0u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8, 118u8,
+                    0u8, 0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                    189u8, 0u8, 0u8, 0u8, 189u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+                    0u8, 7u8, 0u8, 0u8, 0u8, 193u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 101u8, 0u8,
+                    0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 193u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8,
+                    0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8,
+                    190u8, 0u8, 0u8, 0u8, 190u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8, 178u8, 0u8,
+                    0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 71u8,
+                    0u8, 0u8, 0u8, 71u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8,
+                    185u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8,
+                    0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 56u8,
+                    0u8, 0u8, 0u8, 179u8, 0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8,
+                    13u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+                    0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 179u8,
+                    0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8,
+                    18u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 182u8, 0u8, 0u8,
+                    0u8, 43u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 162u8, 0u8, 0u8, 0u8, 162u8,
+                    0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8,
+                    20u8, 0u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8, 186u8, 0u8,
+                    0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8,
+                    78u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 79u8, 0u8, 0u8,
+                    0u8, 22u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 54u8, 0u8,
+                    0u8, 0u8, 79u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 191u8,
+                    0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 157u8, 0u8, 0u8, 0u8,
+                    157u8, 0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 21u8, 0u8,
+                    0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8,
+                    24u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8,
+                    0u8, 82u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 83u8, 0u8,
+                    0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 83u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 131u8,
+                    0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 108u8, 0u8, 0u8,
+                    0u8, 108u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 180u8,
+                    0u8, 0u8, 0u8, 180u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 84u8, 0u8, 0u8, 0u8,
+                    84u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 169u8, 0u8,
+                    0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8, 0u8,
+                    181u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 140u8, 0u8,
+                    0u8, 0u8, 141u8, 0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8, 141u8, 0u8, 0u8, 0u8,
+                    86u8, 0u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8,
+                    0u8, 27u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 149u8,
+                    0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8,
+                    0u8, 28u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 187u8,
+                    0u8, 0u8, 0u8, 187u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8,
+                    0u8, 170u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 87u8, 0u8,
+                    0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8,
+                    29u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 109u8, 0u8,
+                    0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 89u8,
+                    0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8,
+                    88u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8,
+                    0u8, 31u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 50u8,
+                    0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8,
+                    144u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 165u8, 0u8, 0u8, 0u8, 165u8, 0u8,
+                    0u8, 0u8, 89u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 122u8,
+                    0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8,
+                    92u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+                    0u8, 1u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 182u8,
+                    0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 126u8, 0u8, 0u8, 0u8,
+                    126u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 111u8, 0u8,
+                    0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8,
+                    51u8, 0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 166u8, 0u8,
+                    0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8,
+                    33u8, 0u8, 0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 184u8, 0u8,
+                    0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 176u8, 0u8, 0u8, 0u8,
+                    176u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 58u8, 0u8,
+                    0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 42u8,
+                    0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8,
+                    106u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 153u8, 0u8, 0u8, 0u8, 153u8, 0u8,
+                    0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8, 35u8,
+                    0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8,
+                    0u8, 127u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 60u8, 0u8,
+                    0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 38u8,
+                    0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8,
+                    158u8, 0u8, 0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 196u8, 0u8,
+                    0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 99u8,
+                    0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 197u8, 0u8, 0u8, 0u8, 197u8, 0u8, 0u8, 0u8,
+                    188u8, 0u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8, 188u8, 0u8,
+                    0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8, 0u8,
+                    192u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 177u8, 0u8,
+                    0u8, 0u8, 177u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 103u8,
+                    0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,58 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: WordBreakNameToValueV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyValueNameToEnumMapV1 {
+    map: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 9u8, 0u8, 20u8, 0u8, 22u8, 0u8, 24u8,
+                    0u8, 29u8, 0u8, 37u8, 0u8, 40u8, 0u8, 42u8, 0u8, 51u8, 0u8, 53u8, 0u8, 59u8,
+                    0u8, 71u8, 0u8, 73u8, 0u8, 79u8, 0u8, 82u8, 0u8, 94u8, 0u8, 106u8, 0u8, 108u8,
+                    0u8, 110u8, 0u8, 118u8, 0u8, 120u8, 0u8, 122u8, 0u8, 124u8, 0u8, 133u8, 0u8,
+                    139u8, 0u8, 148u8, 0u8, 150u8, 0u8, 152u8, 0u8, 159u8, 0u8, 161u8, 0u8, 163u8,
+                    0u8, 170u8, 0u8, 175u8, 0u8, 192u8, 0u8, 194u8, 0u8, 205u8, 0u8, 207u8, 0u8,
+                    216u8, 0u8, 218u8, 0u8, 97u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 99u8,
+                    114u8, 100u8, 111u8, 117u8, 98u8, 108u8, 101u8, 113u8, 117u8, 111u8, 116u8,
+                    101u8, 100u8, 113u8, 101u8, 98u8, 101u8, 98u8, 97u8, 115u8, 101u8, 101u8, 98u8,
+                    97u8, 115u8, 101u8, 103u8, 97u8, 122u8, 101u8, 98u8, 103u8, 101u8, 109u8,
+                    101u8, 109u8, 111u8, 100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 101u8, 120u8,
+                    101u8, 120u8, 116u8, 101u8, 110u8, 100u8, 101u8, 120u8, 116u8, 101u8, 110u8,
+                    100u8, 110u8, 117u8, 109u8, 108u8, 101u8, 116u8, 102u8, 111u8, 102u8, 111u8,
+                    114u8, 109u8, 97u8, 116u8, 103u8, 97u8, 122u8, 103u8, 108u8, 117u8, 101u8,
+                    97u8, 102u8, 116u8, 101u8, 114u8, 122u8, 119u8, 106u8, 104u8, 101u8, 98u8,
+                    114u8, 101u8, 119u8, 108u8, 101u8, 116u8, 116u8, 101u8, 114u8, 104u8, 108u8,
+                    107u8, 97u8, 107u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 108u8, 101u8,
+                    108u8, 102u8, 109u8, 98u8, 109u8, 105u8, 100u8, 108u8, 101u8, 116u8, 116u8,
+                    101u8, 114u8, 109u8, 105u8, 100u8, 110u8, 117u8, 109u8, 109u8, 105u8, 100u8,
+                    110u8, 117u8, 109u8, 108u8, 101u8, 116u8, 109u8, 108u8, 109u8, 110u8, 110u8,
+                    101u8, 119u8, 108u8, 105u8, 110u8, 101u8, 110u8, 108u8, 110u8, 117u8, 110u8,
+                    117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 111u8, 116u8, 104u8, 101u8, 114u8,
+                    114u8, 101u8, 103u8, 105u8, 111u8, 110u8, 97u8, 108u8, 105u8, 110u8, 100u8,
+                    105u8, 99u8, 97u8, 116u8, 111u8, 114u8, 114u8, 105u8, 115u8, 105u8, 110u8,
+                    103u8, 108u8, 101u8, 113u8, 117u8, 111u8, 116u8, 101u8, 115u8, 113u8, 119u8,
+                    115u8, 101u8, 103u8, 115u8, 112u8, 97u8, 99u8, 101u8, 120u8, 120u8, 122u8,
+                    119u8, 106u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    1u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8,
+                    0u8, 17u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 18u8, 0u8,
+                    0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 9u8,
+                    0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+                    20u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8,
+                    0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 10u8, 0u8,
+                    0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 11u8,
+                    0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8,
+                    12u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    0u8, 13u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8,
+                    0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,46 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: BidiClassValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 26u8, 0u8, 41u8, 0u8, 59u8, 0u8, 78u8, 0u8,
+            91u8, 0u8, 107u8, 0u8, 126u8, 0u8, 143u8, 0u8, 154u8, 0u8, 167u8, 0u8, 190u8, 0u8,
+            212u8, 0u8, 225u8, 0u8, 248u8, 0u8, 14u8, 1u8, 36u8, 1u8, 51u8, 1u8, 67u8, 1u8, 87u8,
+            1u8, 108u8, 1u8, 129u8, 1u8, 76u8, 101u8, 102u8, 116u8, 95u8, 84u8, 111u8, 95u8, 82u8,
+            105u8, 103u8, 104u8, 116u8, 82u8, 105u8, 103u8, 104u8, 116u8, 95u8, 84u8, 111u8, 95u8,
+            76u8, 101u8, 102u8, 116u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 95u8,
+            78u8, 117u8, 109u8, 98u8, 101u8, 114u8, 69u8, 117u8, 114u8, 111u8, 112u8, 101u8, 97u8,
+            110u8, 95u8, 83u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 69u8, 117u8,
+            114u8, 111u8, 112u8, 101u8, 97u8, 110u8, 95u8, 84u8, 101u8, 114u8, 109u8, 105u8, 110u8,
+            97u8, 116u8, 111u8, 114u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 95u8, 78u8, 117u8,
+            109u8, 98u8, 101u8, 114u8, 67u8, 111u8, 109u8, 109u8, 111u8, 110u8, 95u8, 83u8, 101u8,
+            112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 80u8, 97u8, 114u8, 97u8, 103u8, 114u8,
+            97u8, 112u8, 104u8, 95u8, 83u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8,
+            83u8, 101u8, 103u8, 109u8, 101u8, 110u8, 116u8, 95u8, 83u8, 101u8, 112u8, 97u8, 114u8,
+            97u8, 116u8, 111u8, 114u8, 87u8, 104u8, 105u8, 116u8, 101u8, 95u8, 83u8, 112u8, 97u8,
+            99u8, 101u8, 79u8, 116u8, 104u8, 101u8, 114u8, 95u8, 78u8, 101u8, 117u8, 116u8, 114u8,
+            97u8, 108u8, 76u8, 101u8, 102u8, 116u8, 95u8, 84u8, 111u8, 95u8, 82u8, 105u8, 103u8,
+            104u8, 116u8, 95u8, 69u8, 109u8, 98u8, 101u8, 100u8, 100u8, 105u8, 110u8, 103u8, 76u8,
+            101u8, 102u8, 116u8, 95u8, 84u8, 111u8, 95u8, 82u8, 105u8, 103u8, 104u8, 116u8, 95u8,
+            79u8, 118u8, 101u8, 114u8, 114u8, 105u8, 100u8, 101u8, 65u8, 114u8, 97u8, 98u8, 105u8,
+            99u8, 95u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 82u8, 105u8, 103u8, 104u8, 116u8,
+            95u8, 84u8, 111u8, 95u8, 76u8, 101u8, 102u8, 116u8, 95u8, 69u8, 109u8, 98u8, 101u8,
+            100u8, 100u8, 105u8, 110u8, 103u8, 82u8, 105u8, 103u8, 104u8, 116u8, 95u8, 84u8, 111u8,
+            95u8, 76u8, 101u8, 102u8, 116u8, 95u8, 79u8, 118u8, 101u8, 114u8, 114u8, 105u8, 100u8,
+            101u8, 80u8, 111u8, 112u8, 95u8, 68u8, 105u8, 114u8, 101u8, 99u8, 116u8, 105u8, 111u8,
+            110u8, 97u8, 108u8, 95u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 78u8, 111u8, 110u8,
+            115u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 95u8, 77u8, 97u8, 114u8, 107u8, 66u8,
+            111u8, 117u8, 110u8, 100u8, 97u8, 114u8, 121u8, 95u8, 78u8, 101u8, 117u8, 116u8, 114u8,
+            97u8, 108u8, 70u8, 105u8, 114u8, 115u8, 116u8, 95u8, 83u8, 116u8, 114u8, 111u8, 110u8,
+            103u8, 95u8, 73u8, 115u8, 111u8, 108u8, 97u8, 116u8, 101u8, 76u8, 101u8, 102u8, 116u8,
+            95u8, 84u8, 111u8, 95u8, 82u8, 105u8, 103u8, 104u8, 116u8, 95u8, 73u8, 115u8, 111u8,
+            108u8, 97u8, 116u8, 101u8, 82u8, 105u8, 103u8, 104u8, 116u8, 95u8, 84u8, 111u8, 95u8,
+            76u8, 101u8, 102u8, 116u8, 95u8, 73u8, 115u8, 111u8, 108u8, 97u8, 116u8, 101u8, 80u8,
+            111u8, 112u8, 95u8, 68u8, 105u8, 114u8, 101u8, 99u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+            108u8, 95u8, 73u8, 115u8, 111u8, 108u8, 97u8, 116u8, 101u8,
+        ])
+    },
+};
@@ -0,0 +1,76 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: CanonicalCombiningClassValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            241u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 20u8, 0u8, 20u8, 0u8, 20u8, 0u8, 20u8, 0u8,
+            20u8, 0u8, 31u8, 0u8, 36u8, 0u8, 48u8, 0u8, 54u8, 0u8, 59u8, 0u8, 64u8, 0u8, 69u8, 0u8,
+            74u8, 0u8, 79u8, 0u8, 84u8, 0u8, 89u8, 0u8, 94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 109u8,
+            0u8, 114u8, 0u8, 119u8, 0u8, 124u8, 0u8, 129u8, 0u8, 134u8, 0u8, 139u8, 0u8, 144u8,
+            0u8, 149u8, 0u8, 154u8, 0u8, 159u8, 0u8, 164u8, 0u8, 169u8, 0u8, 174u8, 0u8, 179u8,
+            0u8, 184u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8, 0u8, 189u8,
+            0u8, 194u8, 0u8, 194u8, 0u8, 194u8, 0u8, 194u8, 0u8, 194u8, 0u8, 194u8, 0u8, 194u8,
+            0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8,
+            0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 199u8, 0u8, 205u8, 0u8, 205u8,
+            0u8, 205u8, 0u8, 205u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8,
+            0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 211u8, 0u8, 217u8,
+            0u8, 217u8, 0u8, 217u8, 0u8, 217u8, 0u8, 223u8, 0u8, 223u8, 0u8, 223u8, 0u8, 223u8,
+            0u8, 223u8, 0u8, 223u8, 0u8, 223u8, 0u8, 229u8, 0u8, 235u8, 0u8, 235u8, 0u8, 241u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8,
+            0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 247u8, 0u8, 10u8, 1u8, 10u8, 1u8, 24u8, 1u8,
+            24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 24u8, 1u8,
+            24u8, 1u8, 24u8, 1u8, 24u8, 1u8, 38u8, 1u8, 38u8, 1u8, 58u8, 1u8, 58u8, 1u8, 68u8, 1u8,
+            68u8, 1u8, 73u8, 1u8, 73u8, 1u8, 84u8, 1u8, 84u8, 1u8, 88u8, 1u8, 88u8, 1u8, 93u8, 1u8,
+            93u8, 1u8, 103u8, 1u8, 103u8, 1u8, 108u8, 1u8, 108u8, 1u8, 119u8, 1u8, 131u8, 1u8,
+            143u8, 1u8, 143u8, 1u8, 143u8, 1u8, 143u8, 1u8, 143u8, 1u8, 143u8, 1u8, 78u8, 111u8,
+            116u8, 95u8, 82u8, 101u8, 111u8, 114u8, 100u8, 101u8, 114u8, 101u8, 100u8, 79u8, 118u8,
+            101u8, 114u8, 108u8, 97u8, 121u8, 72u8, 97u8, 110u8, 95u8, 82u8, 101u8, 97u8, 100u8,
+            105u8, 110u8, 103u8, 78u8, 117u8, 107u8, 116u8, 97u8, 75u8, 97u8, 110u8, 97u8, 95u8,
+            86u8, 111u8, 105u8, 99u8, 105u8, 110u8, 103u8, 86u8, 105u8, 114u8, 97u8, 109u8, 97u8,
+            67u8, 67u8, 67u8, 49u8, 48u8, 67u8, 67u8, 67u8, 49u8, 49u8, 67u8, 67u8, 67u8, 49u8,
+            50u8, 67u8, 67u8, 67u8, 49u8, 51u8, 67u8, 67u8, 67u8, 49u8, 52u8, 67u8, 67u8, 67u8,
+            49u8, 53u8, 67u8, 67u8, 67u8, 49u8, 54u8, 67u8, 67u8, 67u8, 49u8, 55u8, 67u8, 67u8,
+            67u8, 49u8, 56u8, 67u8, 67u8, 67u8, 49u8, 57u8, 67u8, 67u8, 67u8, 50u8, 48u8, 67u8,
+            67u8, 67u8, 50u8, 49u8, 67u8, 67u8, 67u8, 50u8, 50u8, 67u8, 67u8, 67u8, 50u8, 51u8,
+            67u8, 67u8, 67u8, 50u8, 52u8, 67u8, 67u8, 67u8, 50u8, 53u8, 67u8, 67u8, 67u8, 50u8,
+            54u8, 67u8, 67u8, 67u8, 50u8, 55u8, 67u8, 67u8, 67u8, 50u8, 56u8, 67u8, 67u8, 67u8,
+            50u8, 57u8, 67u8, 67u8, 67u8, 51u8, 48u8, 67u8, 67u8, 67u8, 51u8, 49u8, 67u8, 67u8,
+            67u8, 51u8, 50u8, 67u8, 67u8, 67u8, 51u8, 51u8, 67u8, 67u8, 67u8, 51u8, 52u8, 67u8,
+            67u8, 67u8, 51u8, 53u8, 67u8, 67u8, 67u8, 51u8, 54u8, 67u8, 67u8, 67u8, 56u8, 52u8,
+            67u8, 67u8, 67u8, 57u8, 49u8, 67u8, 67u8, 67u8, 49u8, 48u8, 51u8, 67u8, 67u8, 67u8,
+            49u8, 48u8, 55u8, 67u8, 67u8, 67u8, 49u8, 49u8, 56u8, 67u8, 67u8, 67u8, 49u8, 50u8,
+            50u8, 67u8, 67u8, 67u8, 49u8, 50u8, 57u8, 67u8, 67u8, 67u8, 49u8, 51u8, 48u8, 67u8,
+            67u8, 67u8, 49u8, 51u8, 50u8, 67u8, 67u8, 67u8, 49u8, 51u8, 51u8, 65u8, 116u8, 116u8,
+            97u8, 99u8, 104u8, 101u8, 100u8, 95u8, 66u8, 101u8, 108u8, 111u8, 119u8, 95u8, 76u8,
+            101u8, 102u8, 116u8, 65u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8, 100u8, 95u8, 66u8,
+            101u8, 108u8, 111u8, 119u8, 65u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8, 100u8, 95u8,
+            65u8, 98u8, 111u8, 118u8, 101u8, 65u8, 116u8, 116u8, 97u8, 99u8, 104u8, 101u8, 100u8,
+            95u8, 65u8, 98u8, 111u8, 118u8, 101u8, 95u8, 82u8, 105u8, 103u8, 104u8, 116u8, 66u8,
+            101u8, 108u8, 111u8, 119u8, 95u8, 76u8, 101u8, 102u8, 116u8, 66u8, 101u8, 108u8, 111u8,
+            119u8, 66u8, 101u8, 108u8, 111u8, 119u8, 95u8, 82u8, 105u8, 103u8, 104u8, 116u8, 76u8,
+            101u8, 102u8, 116u8, 82u8, 105u8, 103u8, 104u8, 116u8, 65u8, 98u8, 111u8, 118u8, 101u8,
+            95u8, 76u8, 101u8, 102u8, 116u8, 65u8, 98u8, 111u8, 118u8, 101u8, 65u8, 98u8, 111u8,
+            118u8, 101u8, 95u8, 82u8, 105u8, 103u8, 104u8, 116u8, 68u8, 111u8, 117u8, 98u8, 108u8,
+            101u8, 95u8, 66u8, 101u8, 108u8, 111u8, 119u8, 68u8, 111u8, 117u8, 98u8, 108u8, 101u8,
+            95u8, 65u8, 98u8, 111u8, 118u8, 101u8, 73u8, 111u8, 116u8, 97u8, 95u8, 83u8, 117u8,
+            98u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8,
+        ])
+    },
+};
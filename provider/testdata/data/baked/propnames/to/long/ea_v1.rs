@@ -0,0 +1,16 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: EastAsianWidthValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 16u8, 0u8, 25u8, 0u8, 34u8, 0u8, 40u8, 0u8,
+            78u8, 101u8, 117u8, 116u8, 114u8, 97u8, 108u8, 65u8, 109u8, 98u8, 105u8, 103u8, 117u8,
+            111u8, 117u8, 115u8, 72u8, 97u8, 108u8, 102u8, 119u8, 105u8, 100u8, 116u8, 104u8, 70u8,
+            117u8, 108u8, 108u8, 119u8, 105u8, 100u8, 116u8, 104u8, 78u8, 97u8, 114u8, 114u8,
+            111u8, 119u8, 87u8, 105u8, 100u8, 101u8,
+        ])
+    },
+};
@@ -0,0 +1,48 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GeneralCategoryValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8, 73u8, 0u8,
+            85u8, 0u8, 100u8, 0u8, 114u8, 0u8, 126u8, 0u8, 140u8, 0u8, 153u8, 0u8, 165u8, 0u8,
+            180u8, 0u8, 194u8, 0u8, 213u8, 0u8, 220u8, 0u8, 226u8, 0u8, 237u8, 0u8, 246u8, 0u8,
+            6u8, 1u8, 22u8, 1u8, 39u8, 1u8, 60u8, 1u8, 77u8, 1u8, 88u8, 1u8, 103u8, 1u8, 118u8,
+            1u8, 130u8, 1u8, 149u8, 1u8, 85u8, 110u8, 97u8, 115u8, 115u8, 105u8, 103u8, 110u8,
+            101u8, 100u8, 85u8, 112u8, 112u8, 101u8, 114u8, 99u8, 97u8, 115u8, 101u8, 95u8, 76u8,
+            101u8, 116u8, 116u8, 101u8, 114u8, 76u8, 111u8, 119u8, 101u8, 114u8, 99u8, 97u8, 115u8,
+            101u8, 95u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 84u8, 105u8, 116u8, 108u8, 101u8,
+            99u8, 97u8, 115u8, 101u8, 95u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 77u8, 111u8,
+            100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 95u8, 76u8, 101u8, 116u8, 116u8, 101u8,
+            114u8, 79u8, 116u8, 104u8, 101u8, 114u8, 95u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8,
+            78u8, 111u8, 110u8, 115u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 95u8, 77u8, 97u8,
+            114u8, 107u8, 69u8, 110u8, 99u8, 108u8, 111u8, 115u8, 105u8, 110u8, 103u8, 95u8, 77u8,
+            97u8, 114u8, 107u8, 83u8, 112u8, 97u8, 99u8, 105u8, 110u8, 103u8, 95u8, 77u8, 97u8,
+            114u8, 107u8, 68u8, 101u8, 99u8, 105u8, 109u8, 97u8, 108u8, 95u8, 78u8, 117u8, 109u8,
+            98u8, 101u8, 114u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 95u8, 78u8, 117u8, 109u8,
+            98u8, 101u8, 114u8, 79u8, 116u8, 104u8, 101u8, 114u8, 95u8, 78u8, 117u8, 109u8, 98u8,
+            101u8, 114u8, 83u8, 112u8, 97u8, 99u8, 101u8, 95u8, 83u8, 101u8, 112u8, 97u8, 114u8,
+            97u8, 116u8, 111u8, 114u8, 76u8, 105u8, 110u8, 101u8, 95u8, 83u8, 101u8, 112u8, 97u8,
+            114u8, 97u8, 116u8, 111u8, 114u8, 80u8, 97u8, 114u8, 97u8, 103u8, 114u8, 97u8, 112u8,
+            104u8, 95u8, 83u8, 101u8, 112u8, 97u8, 114u8, 97u8, 116u8, 111u8, 114u8, 67u8, 111u8,
+            110u8, 116u8, 114u8, 111u8, 108u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 80u8, 114u8,
+            105u8, 118u8, 97u8, 116u8, 101u8, 95u8, 85u8, 115u8, 101u8, 83u8, 117u8, 114u8, 114u8,
+            111u8, 103u8, 97u8, 116u8, 101u8, 68u8, 97u8, 115u8, 104u8, 95u8, 80u8, 117u8, 110u8,
+            99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 79u8, 112u8, 101u8, 110u8, 95u8,
+            80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 67u8, 108u8,
+            111u8, 115u8, 101u8, 95u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8,
+            111u8, 110u8, 67u8, 111u8, 110u8, 110u8, 101u8, 99u8, 116u8, 111u8, 114u8, 95u8, 80u8,
+            117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 79u8, 116u8, 104u8,
+            101u8, 114u8, 95u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8,
+            110u8, 77u8, 97u8, 116u8, 104u8, 95u8, 83u8, 121u8, 109u8, 98u8, 111u8, 108u8, 67u8,
+            117u8, 114u8, 114u8, 101u8, 110u8, 99u8, 121u8, 95u8, 83u8, 121u8, 109u8, 98u8, 111u8,
+            108u8, 77u8, 111u8, 100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 95u8, 83u8, 121u8, 109u8,
+            98u8, 111u8, 108u8, 79u8, 116u8, 104u8, 101u8, 114u8, 95u8, 83u8, 121u8, 109u8, 98u8,
+            111u8, 108u8, 73u8, 110u8, 105u8, 116u8, 105u8, 97u8, 108u8, 95u8, 80u8, 117u8, 110u8,
+            99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8, 70u8, 105u8, 110u8, 97u8, 108u8,
+            95u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+        ])
+    },
+};
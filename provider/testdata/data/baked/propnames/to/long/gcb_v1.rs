@@ -0,0 +1,22 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GraphemeClusterBreakValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 12u8, 0u8, 14u8, 0u8, 20u8, 0u8, 21u8, 0u8,
+            23u8, 0u8, 25u8, 0u8, 28u8, 0u8, 29u8, 0u8, 30u8, 0u8, 41u8, 0u8, 48u8, 0u8, 66u8, 0u8,
+            72u8, 0u8, 82u8, 0u8, 92u8, 0u8, 106u8, 0u8, 79u8, 116u8, 104u8, 101u8, 114u8, 67u8,
+            111u8, 110u8, 116u8, 114u8, 111u8, 108u8, 67u8, 82u8, 69u8, 120u8, 116u8, 101u8, 110u8,
+            100u8, 76u8, 76u8, 70u8, 76u8, 86u8, 76u8, 86u8, 84u8, 84u8, 86u8, 83u8, 112u8, 97u8,
+            99u8, 105u8, 110u8, 103u8, 77u8, 97u8, 114u8, 107u8, 80u8, 114u8, 101u8, 112u8, 101u8,
+            110u8, 100u8, 82u8, 101u8, 103u8, 105u8, 111u8, 110u8, 97u8, 108u8, 95u8, 73u8, 110u8,
+            100u8, 105u8, 99u8, 97u8, 116u8, 111u8, 114u8, 69u8, 95u8, 66u8, 97u8, 115u8, 101u8,
+            69u8, 95u8, 66u8, 97u8, 115u8, 101u8, 95u8, 71u8, 65u8, 90u8, 69u8, 95u8, 77u8, 111u8,
+            100u8, 105u8, 102u8, 105u8, 101u8, 114u8, 71u8, 108u8, 117u8, 101u8, 95u8, 65u8, 102u8,
+            116u8, 101u8, 114u8, 95u8, 90u8, 119u8, 106u8, 90u8, 87u8, 74u8,
+        ])
+    },
+};
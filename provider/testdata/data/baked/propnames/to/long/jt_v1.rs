@@ -0,0 +1,18 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: JoiningTypeValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 23u8, 0u8, 35u8, 0u8, 47u8, 0u8, 60u8, 0u8,
+            78u8, 111u8, 110u8, 95u8, 74u8, 111u8, 105u8, 110u8, 105u8, 110u8, 103u8, 74u8, 111u8,
+            105u8, 110u8, 95u8, 67u8, 97u8, 117u8, 115u8, 105u8, 110u8, 103u8, 68u8, 117u8, 97u8,
+            108u8, 95u8, 74u8, 111u8, 105u8, 110u8, 105u8, 110u8, 103u8, 76u8, 101u8, 102u8, 116u8,
+            95u8, 74u8, 111u8, 105u8, 110u8, 105u8, 110u8, 103u8, 82u8, 105u8, 103u8, 104u8, 116u8,
+            95u8, 74u8, 111u8, 105u8, 110u8, 105u8, 110u8, 103u8, 84u8, 114u8, 97u8, 110u8, 115u8,
+            112u8, 97u8, 114u8, 101u8, 110u8, 116u8,
+        ])
+    },
+};
@@ -0,0 +1,52 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: LineBreakValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            43u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 16u8, 0u8, 26u8, 0u8, 36u8, 0u8, 47u8, 0u8,
+            59u8, 0u8, 74u8, 0u8, 90u8, 0u8, 107u8, 0u8, 121u8, 0u8, 136u8, 0u8, 147u8, 0u8, 151u8,
+            0u8, 157u8, 0u8, 168u8, 0u8, 179u8, 0u8, 192u8, 0u8, 201u8, 0u8, 211u8, 0u8, 218u8,
+            0u8, 234u8, 0u8, 249u8, 0u8, 7u8, 1u8, 16u8, 1u8, 31u8, 1u8, 40u8, 1u8, 45u8, 1u8,
+            58u8, 1u8, 65u8, 1u8, 74u8, 1u8, 85u8, 1u8, 87u8, 1u8, 89u8, 1u8, 91u8, 1u8, 93u8, 1u8,
+            95u8, 1u8, 112u8, 1u8, 140u8, 1u8, 153u8, 1u8, 171u8, 1u8, 177u8, 1u8, 187u8, 1u8,
+            85u8, 110u8, 107u8, 110u8, 111u8, 119u8, 110u8, 65u8, 109u8, 98u8, 105u8, 103u8, 117u8,
+            111u8, 117u8, 115u8, 65u8, 108u8, 112u8, 104u8, 97u8, 98u8, 101u8, 116u8, 105u8, 99u8,
+            66u8, 114u8, 101u8, 97u8, 107u8, 95u8, 66u8, 111u8, 116u8, 104u8, 66u8, 114u8, 101u8,
+            97u8, 107u8, 95u8, 65u8, 102u8, 116u8, 101u8, 114u8, 66u8, 114u8, 101u8, 97u8, 107u8,
+            95u8, 66u8, 101u8, 102u8, 111u8, 114u8, 101u8, 77u8, 97u8, 110u8, 100u8, 97u8, 116u8,
+            111u8, 114u8, 121u8, 95u8, 66u8, 114u8, 101u8, 97u8, 107u8, 67u8, 111u8, 110u8, 116u8,
+            105u8, 110u8, 103u8, 101u8, 110u8, 116u8, 95u8, 66u8, 114u8, 101u8, 97u8, 107u8, 67u8,
+            108u8, 111u8, 115u8, 101u8, 95u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8,
+            105u8, 111u8, 110u8, 67u8, 111u8, 109u8, 98u8, 105u8, 110u8, 105u8, 110u8, 103u8, 95u8,
+            77u8, 97u8, 114u8, 107u8, 67u8, 97u8, 114u8, 114u8, 105u8, 97u8, 103u8, 101u8, 95u8,
+            82u8, 101u8, 116u8, 117u8, 114u8, 110u8, 69u8, 120u8, 99u8, 108u8, 97u8, 109u8, 97u8,
+            116u8, 105u8, 111u8, 110u8, 71u8, 108u8, 117u8, 101u8, 72u8, 121u8, 112u8, 104u8,
+            101u8, 110u8, 73u8, 100u8, 101u8, 111u8, 103u8, 114u8, 97u8, 112u8, 104u8, 105u8, 99u8,
+            73u8, 110u8, 115u8, 101u8, 112u8, 97u8, 114u8, 97u8, 98u8, 108u8, 101u8, 73u8, 110u8,
+            102u8, 105u8, 120u8, 95u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 76u8, 105u8,
+            110u8, 101u8, 95u8, 70u8, 101u8, 101u8, 100u8, 78u8, 111u8, 110u8, 115u8, 116u8, 97u8,
+            114u8, 116u8, 101u8, 114u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 79u8, 112u8,
+            101u8, 110u8, 95u8, 80u8, 117u8, 110u8, 99u8, 116u8, 117u8, 97u8, 116u8, 105u8, 111u8,
+            110u8, 80u8, 111u8, 115u8, 116u8, 102u8, 105u8, 120u8, 95u8, 78u8, 117u8, 109u8, 101u8,
+            114u8, 105u8, 99u8, 80u8, 114u8, 101u8, 102u8, 105u8, 120u8, 95u8, 78u8, 117u8, 109u8,
+            101u8, 114u8, 105u8, 99u8, 81u8, 117u8, 111u8, 116u8, 97u8, 116u8, 105u8, 111u8, 110u8,
+            67u8, 111u8, 109u8, 112u8, 108u8, 101u8, 120u8, 95u8, 67u8, 111u8, 110u8, 116u8, 101u8,
+            120u8, 116u8, 83u8, 117u8, 114u8, 114u8, 111u8, 103u8, 97u8, 116u8, 101u8, 83u8, 112u8,
+            97u8, 99u8, 101u8, 66u8, 114u8, 101u8, 97u8, 107u8, 95u8, 83u8, 121u8, 109u8, 98u8,
+            111u8, 108u8, 115u8, 90u8, 87u8, 83u8, 112u8, 97u8, 99u8, 101u8, 78u8, 101u8, 120u8,
+            116u8, 95u8, 76u8, 105u8, 110u8, 101u8, 87u8, 111u8, 114u8, 100u8, 95u8, 74u8, 111u8,
+            105u8, 110u8, 101u8, 114u8, 72u8, 50u8, 72u8, 51u8, 74u8, 76u8, 74u8, 84u8, 74u8, 86u8,
+            67u8, 108u8, 111u8, 115u8, 101u8, 95u8, 80u8, 97u8, 114u8, 101u8, 110u8, 116u8, 104u8,
+            101u8, 115u8, 105u8, 115u8, 67u8, 111u8, 110u8, 100u8, 105u8, 116u8, 105u8, 111u8,
+            110u8, 97u8, 108u8, 95u8, 74u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 95u8,
+            83u8, 116u8, 97u8, 114u8, 116u8, 101u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+            95u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8, 82u8, 101u8, 103u8, 105u8, 111u8, 110u8,
+            97u8, 108u8, 95u8, 73u8, 110u8, 100u8, 105u8, 99u8, 97u8, 116u8, 111u8, 114u8, 69u8,
+            95u8, 66u8, 97u8, 115u8, 101u8, 69u8, 95u8, 77u8, 111u8, 100u8, 105u8, 102u8, 105u8,
+            101u8, 114u8, 90u8, 87u8, 74u8,
+        ])
+    },
+};
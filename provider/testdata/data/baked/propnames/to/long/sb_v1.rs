@@ -0,0 +1,19 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: SentenceBreakValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 15u8, 0u8, 21u8, 0u8, 26u8, 0u8,
+            33u8, 0u8, 40u8, 0u8, 43u8, 0u8, 45u8, 0u8, 50u8, 0u8, 55u8, 0u8, 57u8, 0u8, 63u8, 0u8,
+            65u8, 0u8, 79u8, 116u8, 104u8, 101u8, 114u8, 65u8, 84u8, 101u8, 114u8, 109u8, 67u8,
+            108u8, 111u8, 115u8, 101u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 76u8, 111u8, 119u8,
+            101u8, 114u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8, 79u8, 76u8, 101u8, 116u8,
+            116u8, 101u8, 114u8, 83u8, 101u8, 112u8, 83u8, 112u8, 83u8, 84u8, 101u8, 114u8, 109u8,
+            85u8, 112u8, 112u8, 101u8, 114u8, 67u8, 82u8, 69u8, 120u8, 116u8, 101u8, 110u8, 100u8,
+            76u8, 70u8, 83u8, 67u8, 111u8, 110u8, 116u8, 105u8, 110u8, 117u8, 101u8,
+        ])
+    },
+};
@@ -0,0 +1,146 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: ScriptValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            198u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 15u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8, 0u8,
+            44u8, 0u8, 52u8, 0u8, 58u8, 0u8, 66u8, 0u8, 73u8, 0u8, 83u8, 0u8, 91u8, 0u8, 99u8, 0u8,
+            105u8, 0u8, 110u8, 0u8, 118u8, 0u8, 126u8, 0u8, 129u8, 0u8, 135u8, 0u8, 141u8, 0u8,
+            149u8, 0u8, 156u8, 0u8, 164u8, 0u8, 169u8, 0u8, 172u8, 0u8, 177u8, 0u8, 186u8, 0u8,
+            195u8, 0u8, 202u8, 0u8, 207u8, 0u8, 217u8, 0u8, 222u8, 0u8, 227u8, 0u8, 234u8, 0u8,
+            240u8, 0u8, 245u8, 0u8, 251u8, 0u8, 1u8, 1u8, 5u8, 1u8, 12u8, 1u8, 31u8, 1u8, 33u8,
+            1u8, 40u8, 1u8, 47u8, 1u8, 52u8, 1u8, 60u8, 1u8, 67u8, 1u8, 74u8, 1u8, 79u8, 1u8, 87u8,
+            1u8, 94u8, 1u8, 101u8, 1u8, 107u8, 1u8, 115u8, 1u8, 135u8, 1u8, 143u8, 1u8, 153u8, 1u8,
+            163u8, 1u8, 175u8, 1u8, 186u8, 1u8, 194u8, 1u8, 205u8, 1u8, 213u8, 1u8, 218u8, 1u8,
+            218u8, 1u8, 224u8, 1u8, 228u8, 1u8, 228u8, 1u8, 228u8, 1u8, 228u8, 1u8, 228u8, 1u8,
+            248u8, 1u8, 248u8, 1u8, 248u8, 1u8, 248u8, 1u8, 4u8, 2u8, 17u8, 2u8, 17u8, 2u8, 25u8,
+            2u8, 33u8, 2u8, 33u8, 2u8, 33u8, 2u8, 39u8, 2u8, 47u8, 2u8, 54u8, 2u8, 54u8, 2u8, 74u8,
+            2u8, 77u8, 2u8, 87u8, 2u8, 97u8, 2u8, 105u8, 2u8, 115u8, 2u8, 119u8, 2u8, 119u8, 2u8,
+            119u8, 2u8, 119u8, 2u8, 119u8, 2u8, 119u8, 2u8, 119u8, 2u8, 122u8, 2u8, 122u8, 2u8,
+            131u8, 2u8, 131u8, 2u8, 138u8, 2u8, 144u8, 2u8, 144u8, 2u8, 152u8, 2u8, 158u8, 2u8,
+            164u8, 2u8, 172u8, 2u8, 178u8, 2u8, 188u8, 2u8, 199u8, 2u8, 208u8, 2u8, 208u8, 2u8,
+            220u8, 2u8, 236u8, 2u8, 243u8, 2u8, 249u8, 2u8, 249u8, 2u8, 255u8, 2u8, 9u8, 3u8, 30u8,
+            3u8, 45u8, 3u8, 45u8, 3u8, 67u8, 3u8, 76u8, 3u8, 84u8, 3u8, 84u8, 3u8, 84u8, 3u8, 89u8,
+            3u8, 93u8, 3u8, 93u8, 3u8, 110u8, 3u8, 119u8, 3u8, 127u8, 3u8, 134u8, 3u8, 141u8, 3u8,
+            141u8, 3u8, 141u8, 3u8, 154u8, 3u8, 170u8, 3u8, 187u8, 3u8, 196u8, 3u8, 205u8, 3u8,
+            214u8, 3u8, 225u8, 3u8, 225u8, 3u8, 225u8, 3u8, 228u8, 3u8, 233u8, 3u8, 240u8, 3u8,
+            252u8, 3u8, 1u8, 4u8, 7u8, 4u8, 7u8, 4u8, 28u8, 4u8, 34u8, 4u8, 41u8, 4u8, 59u8, 4u8,
+            67u8, 4u8, 71u8, 4u8, 77u8, 4u8, 81u8, 4u8, 88u8, 4u8, 99u8, 4u8, 106u8, 4u8, 111u8,
+            4u8, 120u8, 4u8, 127u8, 4u8, 131u8, 4u8, 136u8, 4u8, 136u8, 4u8, 136u8, 4u8, 136u8,
+            4u8, 149u8, 4u8, 156u8, 4u8, 172u8, 4u8, 177u8, 4u8, 190u8, 4u8, 197u8, 4u8, 208u8,
+            4u8, 223u8, 4u8, 230u8, 4u8, 241u8, 4u8, 248u8, 4u8, 14u8, 5u8, 25u8, 5u8, 31u8, 5u8,
+            41u8, 5u8, 52u8, 5u8, 71u8, 5u8, 77u8, 5u8, 89u8, 5u8, 99u8, 5u8, 105u8, 5u8, 109u8,
+            5u8, 67u8, 111u8, 109u8, 109u8, 111u8, 110u8, 73u8, 110u8, 104u8, 101u8, 114u8, 105u8,
+            116u8, 101u8, 100u8, 65u8, 114u8, 97u8, 98u8, 105u8, 99u8, 65u8, 114u8, 109u8, 101u8,
+            110u8, 105u8, 97u8, 110u8, 66u8, 101u8, 110u8, 103u8, 97u8, 108u8, 105u8, 66u8, 111u8,
+            112u8, 111u8, 109u8, 111u8, 102u8, 111u8, 67u8, 104u8, 101u8, 114u8, 111u8, 107u8,
+            101u8, 101u8, 67u8, 111u8, 112u8, 116u8, 105u8, 99u8, 67u8, 121u8, 114u8, 105u8, 108u8,
+            108u8, 105u8, 99u8, 68u8, 101u8, 115u8, 101u8, 114u8, 101u8, 116u8, 68u8, 101u8, 118u8,
+            97u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 69u8, 116u8, 104u8, 105u8, 111u8, 112u8,
+            105u8, 99u8, 71u8, 101u8, 111u8, 114u8, 103u8, 105u8, 97u8, 110u8, 71u8, 111u8, 116u8,
+            104u8, 105u8, 99u8, 71u8, 114u8, 101u8, 101u8, 107u8, 71u8, 117u8, 106u8, 97u8, 114u8,
+            97u8, 116u8, 105u8, 71u8, 117u8, 114u8, 109u8, 117u8, 107u8, 104u8, 105u8, 72u8, 97u8,
+            110u8, 72u8, 97u8, 110u8, 103u8, 117u8, 108u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8,
+            72u8, 105u8, 114u8, 97u8, 103u8, 97u8, 110u8, 97u8, 75u8, 97u8, 110u8, 110u8, 97u8,
+            100u8, 97u8, 75u8, 97u8, 116u8, 97u8, 107u8, 97u8, 110u8, 97u8, 75u8, 104u8, 109u8,
+            101u8, 114u8, 76u8, 97u8, 111u8, 76u8, 97u8, 116u8, 105u8, 110u8, 77u8, 97u8, 108u8,
+            97u8, 121u8, 97u8, 108u8, 97u8, 109u8, 77u8, 111u8, 110u8, 103u8, 111u8, 108u8, 105u8,
+            97u8, 110u8, 77u8, 121u8, 97u8, 110u8, 109u8, 97u8, 114u8, 79u8, 103u8, 104u8, 97u8,
+            109u8, 79u8, 108u8, 100u8, 95u8, 73u8, 116u8, 97u8, 108u8, 105u8, 99u8, 79u8, 114u8,
+            105u8, 121u8, 97u8, 82u8, 117u8, 110u8, 105u8, 99u8, 83u8, 105u8, 110u8, 104u8, 97u8,
+            108u8, 97u8, 83u8, 121u8, 114u8, 105u8, 97u8, 99u8, 84u8, 97u8, 109u8, 105u8, 108u8,
+            84u8, 101u8, 108u8, 117u8, 103u8, 117u8, 84u8, 104u8, 97u8, 97u8, 110u8, 97u8, 84u8,
+            104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 101u8, 116u8, 97u8, 110u8, 67u8, 97u8, 110u8,
+            97u8, 100u8, 105u8, 97u8, 110u8, 95u8, 65u8, 98u8, 111u8, 114u8, 105u8, 103u8, 105u8,
+            110u8, 97u8, 108u8, 89u8, 105u8, 84u8, 97u8, 103u8, 97u8, 108u8, 111u8, 103u8, 72u8,
+            97u8, 110u8, 117u8, 110u8, 111u8, 111u8, 66u8, 117u8, 104u8, 105u8, 100u8, 84u8, 97u8,
+            103u8, 98u8, 97u8, 110u8, 119u8, 97u8, 66u8, 114u8, 97u8, 105u8, 108u8, 108u8, 101u8,
+            67u8, 121u8, 112u8, 114u8, 105u8, 111u8, 116u8, 76u8, 105u8, 109u8, 98u8, 117u8, 76u8,
+            105u8, 110u8, 101u8, 97u8, 114u8, 95u8, 66u8, 79u8, 115u8, 109u8, 97u8, 110u8, 121u8,
+            97u8, 83u8, 104u8, 97u8, 118u8, 105u8, 97u8, 110u8, 84u8, 97u8, 105u8, 95u8, 76u8,
+            101u8, 85u8, 103u8, 97u8, 114u8, 105u8, 116u8, 105u8, 99u8, 75u8, 97u8, 116u8, 97u8,
+            107u8, 97u8, 110u8, 97u8, 95u8, 79u8, 114u8, 95u8, 72u8, 105u8, 114u8, 97u8, 103u8,
+            97u8, 110u8, 97u8, 66u8, 117u8, 103u8, 105u8, 110u8, 101u8, 115u8, 101u8, 71u8, 108u8,
+            97u8, 103u8, 111u8, 108u8, 105u8, 116u8, 105u8, 99u8, 75u8, 104u8, 97u8, 114u8, 111u8,
+            115u8, 104u8, 116u8, 104u8, 105u8, 83u8, 121u8, 108u8, 111u8, 116u8, 105u8, 95u8, 78u8,
+            97u8, 103u8, 114u8, 105u8, 78u8, 101u8, 119u8, 95u8, 84u8, 97u8, 105u8, 95u8, 76u8,
+            117u8, 101u8, 84u8, 105u8, 102u8, 105u8, 110u8, 97u8, 103u8, 104u8, 79u8, 108u8, 100u8,
+            95u8, 80u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 66u8, 97u8, 108u8, 105u8, 110u8,
+            101u8, 115u8, 101u8, 66u8, 97u8, 116u8, 97u8, 107u8, 66u8, 114u8, 97u8, 104u8, 109u8,
+            105u8, 67u8, 104u8, 97u8, 109u8, 69u8, 103u8, 121u8, 112u8, 116u8, 105u8, 97u8, 110u8,
+            95u8, 72u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8, 112u8, 104u8, 115u8, 80u8,
+            97u8, 104u8, 97u8, 119u8, 104u8, 95u8, 72u8, 109u8, 111u8, 110u8, 103u8, 79u8, 108u8,
+            100u8, 95u8, 72u8, 117u8, 110u8, 103u8, 97u8, 114u8, 105u8, 97u8, 110u8, 74u8, 97u8,
+            118u8, 97u8, 110u8, 101u8, 115u8, 101u8, 75u8, 97u8, 121u8, 97u8, 104u8, 95u8, 76u8,
+            105u8, 76u8, 101u8, 112u8, 99u8, 104u8, 97u8, 76u8, 105u8, 110u8, 101u8, 97u8, 114u8,
+            95u8, 65u8, 77u8, 97u8, 110u8, 100u8, 97u8, 105u8, 99u8, 77u8, 101u8, 114u8, 111u8,
+            105u8, 116u8, 105u8, 99u8, 95u8, 72u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8,
+            112u8, 104u8, 115u8, 78u8, 107u8, 111u8, 79u8, 108u8, 100u8, 95u8, 84u8, 117u8, 114u8,
+            107u8, 105u8, 99u8, 79u8, 108u8, 100u8, 95u8, 80u8, 101u8, 114u8, 109u8, 105u8, 99u8,
+            80u8, 104u8, 97u8, 103u8, 115u8, 95u8, 80u8, 97u8, 80u8, 104u8, 111u8, 101u8, 110u8,
+            105u8, 99u8, 105u8, 97u8, 110u8, 77u8, 105u8, 97u8, 111u8, 86u8, 97u8, 105u8, 67u8,
+            117u8, 110u8, 101u8, 105u8, 102u8, 111u8, 114u8, 109u8, 85u8, 110u8, 107u8, 110u8,
+            111u8, 119u8, 110u8, 67u8, 97u8, 114u8, 105u8, 97u8, 110u8, 84u8, 97u8, 105u8, 95u8,
+            84u8, 104u8, 97u8, 109u8, 76u8, 121u8, 99u8, 105u8, 97u8, 110u8, 76u8, 121u8, 100u8,
+            105u8, 97u8, 110u8, 79u8, 108u8, 95u8, 67u8, 104u8, 105u8, 107u8, 105u8, 82u8, 101u8,
+            106u8, 97u8, 110u8, 103u8, 83u8, 97u8, 117u8, 114u8, 97u8, 115u8, 104u8, 116u8, 114u8,
+            97u8, 83u8, 105u8, 103u8, 110u8, 87u8, 114u8, 105u8, 116u8, 105u8, 110u8, 103u8, 83u8,
+            117u8, 110u8, 100u8, 97u8, 110u8, 101u8, 115u8, 101u8, 77u8, 101u8, 101u8, 116u8,
+            101u8, 105u8, 95u8, 77u8, 97u8, 121u8, 101u8, 107u8, 73u8, 109u8, 112u8, 101u8, 114u8,
+            105u8, 97u8, 108u8, 95u8, 65u8, 114u8, 97u8, 109u8, 97u8, 105u8, 99u8, 65u8, 118u8,
+            101u8, 115u8, 116u8, 97u8, 110u8, 67u8, 104u8, 97u8, 107u8, 109u8, 97u8, 75u8, 97u8,
+            105u8, 116u8, 104u8, 105u8, 77u8, 97u8, 110u8, 105u8, 99u8, 104u8, 97u8, 101u8, 97u8,
+            110u8, 73u8, 110u8, 115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8, 110u8, 97u8,
+            108u8, 95u8, 80u8, 97u8, 104u8, 108u8, 97u8, 118u8, 105u8, 80u8, 115u8, 97u8, 108u8,
+            116u8, 101u8, 114u8, 95u8, 80u8, 97u8, 104u8, 108u8, 97u8, 118u8, 105u8, 73u8, 110u8,
+            115u8, 99u8, 114u8, 105u8, 112u8, 116u8, 105u8, 111u8, 110u8, 97u8, 108u8, 95u8, 80u8,
+            97u8, 114u8, 116u8, 104u8, 105u8, 97u8, 110u8, 83u8, 97u8, 109u8, 97u8, 114u8, 105u8,
+            116u8, 97u8, 110u8, 84u8, 97u8, 105u8, 95u8, 86u8, 105u8, 101u8, 116u8, 66u8, 97u8,
+            109u8, 117u8, 109u8, 76u8, 105u8, 115u8, 117u8, 79u8, 108u8, 100u8, 95u8, 83u8, 111u8,
+            117u8, 116u8, 104u8, 95u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 110u8, 66u8, 97u8,
+            115u8, 115u8, 97u8, 95u8, 86u8, 97u8, 104u8, 68u8, 117u8, 112u8, 108u8, 111u8, 121u8,
+            97u8, 110u8, 69u8, 108u8, 98u8, 97u8, 115u8, 97u8, 110u8, 71u8, 114u8, 97u8, 110u8,
+            116u8, 104u8, 97u8, 77u8, 101u8, 110u8, 100u8, 101u8, 95u8, 75u8, 105u8, 107u8, 97u8,
+            107u8, 117u8, 105u8, 77u8, 101u8, 114u8, 111u8, 105u8, 116u8, 105u8, 99u8, 95u8, 67u8,
+            117u8, 114u8, 115u8, 105u8, 118u8, 101u8, 79u8, 108u8, 100u8, 95u8, 78u8, 111u8, 114u8,
+            116u8, 104u8, 95u8, 65u8, 114u8, 97u8, 98u8, 105u8, 97u8, 110u8, 78u8, 97u8, 98u8,
+            97u8, 116u8, 97u8, 101u8, 97u8, 110u8, 80u8, 97u8, 108u8, 109u8, 121u8, 114u8, 101u8,
+            110u8, 101u8, 75u8, 104u8, 117u8, 100u8, 97u8, 119u8, 97u8, 100u8, 105u8, 87u8, 97u8,
+            114u8, 97u8, 110u8, 103u8, 95u8, 67u8, 105u8, 116u8, 105u8, 77u8, 114u8, 111u8, 78u8,
+            117u8, 115u8, 104u8, 117u8, 83u8, 104u8, 97u8, 114u8, 97u8, 100u8, 97u8, 83u8, 111u8,
+            114u8, 97u8, 95u8, 83u8, 111u8, 109u8, 112u8, 101u8, 110u8, 103u8, 84u8, 97u8, 107u8,
+            114u8, 105u8, 84u8, 97u8, 110u8, 103u8, 117u8, 116u8, 65u8, 110u8, 97u8, 116u8, 111u8,
+            108u8, 105u8, 97u8, 110u8, 95u8, 72u8, 105u8, 101u8, 114u8, 111u8, 103u8, 108u8, 121u8,
+            112u8, 104u8, 115u8, 75u8, 104u8, 111u8, 106u8, 107u8, 105u8, 84u8, 105u8, 114u8,
+            104u8, 117u8, 116u8, 97u8, 67u8, 97u8, 117u8, 99u8, 97u8, 115u8, 105u8, 97u8, 110u8,
+            95u8, 65u8, 108u8, 98u8, 97u8, 110u8, 105u8, 97u8, 110u8, 77u8, 97u8, 104u8, 97u8,
+            106u8, 97u8, 110u8, 105u8, 65u8, 104u8, 111u8, 109u8, 72u8, 97u8, 116u8, 114u8, 97u8,
+            110u8, 77u8, 111u8, 100u8, 105u8, 77u8, 117u8, 108u8, 116u8, 97u8, 110u8, 105u8, 80u8,
+            97u8, 117u8, 95u8, 67u8, 105u8, 110u8, 95u8, 72u8, 97u8, 117u8, 83u8, 105u8, 100u8,
+            100u8, 104u8, 97u8, 109u8, 65u8, 100u8, 108u8, 97u8, 109u8, 66u8, 104u8, 97u8, 105u8,
+            107u8, 115u8, 117u8, 107u8, 105u8, 77u8, 97u8, 114u8, 99u8, 104u8, 101u8, 110u8, 78u8,
+            101u8, 119u8, 97u8, 79u8, 115u8, 97u8, 103u8, 101u8, 77u8, 97u8, 115u8, 97u8, 114u8,
+            97u8, 109u8, 95u8, 71u8, 111u8, 110u8, 100u8, 105u8, 83u8, 111u8, 121u8, 111u8, 109u8,
+            98u8, 111u8, 90u8, 97u8, 110u8, 97u8, 98u8, 97u8, 122u8, 97u8, 114u8, 95u8, 83u8,
+            113u8, 117u8, 97u8, 114u8, 101u8, 68u8, 111u8, 103u8, 114u8, 97u8, 71u8, 117u8, 110u8,
+            106u8, 97u8, 108u8, 97u8, 95u8, 71u8, 111u8, 110u8, 100u8, 105u8, 77u8, 97u8, 107u8,
+            97u8, 115u8, 97u8, 114u8, 77u8, 101u8, 100u8, 101u8, 102u8, 97u8, 105u8, 100u8, 114u8,
+            105u8, 110u8, 72u8, 97u8, 110u8, 105u8, 102u8, 105u8, 95u8, 82u8, 111u8, 104u8, 105u8,
+            110u8, 103u8, 121u8, 97u8, 83u8, 111u8, 103u8, 100u8, 105u8, 97u8, 110u8, 79u8, 108u8,
+            100u8, 95u8, 83u8, 111u8, 103u8, 100u8, 105u8, 97u8, 110u8, 69u8, 108u8, 121u8, 109u8,
+            97u8, 105u8, 99u8, 78u8, 121u8, 105u8, 97u8, 107u8, 101u8, 110u8, 103u8, 95u8, 80u8,
+            117u8, 97u8, 99u8, 104u8, 117u8, 101u8, 95u8, 72u8, 109u8, 111u8, 110u8, 103u8, 78u8,
+            97u8, 110u8, 100u8, 105u8, 110u8, 97u8, 103u8, 97u8, 114u8, 105u8, 87u8, 97u8, 110u8,
+            99u8, 104u8, 111u8, 67u8, 104u8, 111u8, 114u8, 97u8, 115u8, 109u8, 105u8, 97u8, 110u8,
+            68u8, 105u8, 118u8, 101u8, 115u8, 95u8, 65u8, 107u8, 117u8, 114u8, 117u8, 75u8, 104u8,
+            105u8, 116u8, 97u8, 110u8, 95u8, 83u8, 109u8, 97u8, 108u8, 108u8, 95u8, 83u8, 99u8,
+            114u8, 105u8, 112u8, 116u8, 89u8, 101u8, 122u8, 105u8, 100u8, 105u8, 67u8, 121u8,
+            112u8, 114u8, 111u8, 95u8, 77u8, 105u8, 110u8, 111u8, 97u8, 110u8, 79u8, 108u8, 100u8,
+            95u8, 85u8, 121u8, 103u8, 104u8, 117u8, 114u8, 84u8, 97u8, 110u8, 103u8, 115u8, 97u8,
+            84u8, 111u8, 116u8, 111u8, 86u8, 105u8, 116u8, 104u8, 107u8, 117u8, 113u8, 105u8,
+        ])
+    },
+};
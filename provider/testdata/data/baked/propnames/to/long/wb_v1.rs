@@ -0,0 +1,30 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: WordBreakValueToLongNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 12u8, 0u8, 18u8, 0u8, 26u8, 0u8, 35u8, 0u8,
+            41u8, 0u8, 48u8, 0u8, 60u8, 0u8, 62u8, 0u8, 68u8, 0u8, 70u8, 0u8, 79u8, 0u8, 86u8, 0u8,
+            104u8, 0u8, 117u8, 0u8, 129u8, 0u8, 141u8, 0u8, 147u8, 0u8, 157u8, 0u8, 167u8, 0u8,
+            181u8, 0u8, 184u8, 0u8, 79u8, 116u8, 104u8, 101u8, 114u8, 65u8, 76u8, 101u8, 116u8,
+            116u8, 101u8, 114u8, 70u8, 111u8, 114u8, 109u8, 97u8, 116u8, 75u8, 97u8, 116u8, 97u8,
+            107u8, 97u8, 110u8, 97u8, 77u8, 105u8, 100u8, 76u8, 101u8, 116u8, 116u8, 101u8, 114u8,
+            77u8, 105u8, 100u8, 78u8, 117u8, 109u8, 78u8, 117u8, 109u8, 101u8, 114u8, 105u8, 99u8,
+            69u8, 120u8, 116u8, 101u8, 110u8, 100u8, 78u8, 117u8, 109u8, 76u8, 101u8, 116u8, 67u8,
+            82u8, 69u8, 120u8, 116u8, 101u8, 110u8, 100u8, 76u8, 70u8, 77u8, 105u8, 100u8, 78u8,
+            117u8, 109u8, 76u8, 101u8, 116u8, 78u8, 101u8, 119u8, 108u8, 105u8, 110u8, 101u8, 82u8,
+            101u8, 103u8, 105u8, 111u8, 110u8, 97u8, 108u8, 95u8, 73u8, 110u8, 100u8, 105u8, 99u8,
+            97u8, 116u8, 111u8, 114u8, 72u8, 101u8, 98u8, 114u8, 101u8, 119u8, 95u8, 76u8, 101u8,
+            116u8, 116u8, 101u8, 114u8, 83u8, 105u8, 110u8, 103u8, 108u8, 101u8, 95u8, 81u8, 117u8,
+            111u8, 116u8, 101u8, 68u8, 111u8, 117u8, 98u8, 108u8, 101u8, 95u8, 81u8, 117u8, 111u8,
+            116u8, 101u8, 69u8, 95u8, 66u8, 97u8, 115u8, 101u8, 69u8, 95u8, 66u8, 97u8, 115u8,
+            101u8, 95u8, 71u8, 65u8, 90u8, 69u8, 95u8, 77u8, 111u8, 100u8, 105u8, 102u8, 105u8,
+            101u8, 114u8, 71u8, 108u8, 117u8, 101u8, 95u8, 65u8, 102u8, 116u8, 101u8, 114u8, 95u8,
+            90u8, 119u8, 106u8, 90u8, 87u8, 74u8, 87u8, 83u8, 101u8, 103u8, 83u8, 112u8, 97u8,
+            99u8, 101u8,
+        ])
+    },
+};
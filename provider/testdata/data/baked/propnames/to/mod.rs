@@ -0,0 +1,3 @@
+// @generated
+pub mod long;
+pub mod short;
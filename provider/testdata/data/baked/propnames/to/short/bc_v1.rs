@@ -0,0 +1,18 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: BidiClassValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 10u8,
+            0u8, 12u8, 0u8, 13u8, 0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 21u8, 0u8, 24u8, 0u8, 26u8,
+            0u8, 29u8, 0u8, 32u8, 0u8, 35u8, 0u8, 38u8, 0u8, 40u8, 0u8, 43u8, 0u8, 46u8, 0u8, 49u8,
+            0u8, 76u8, 82u8, 69u8, 78u8, 69u8, 83u8, 69u8, 84u8, 65u8, 78u8, 67u8, 83u8, 66u8,
+            83u8, 87u8, 83u8, 79u8, 78u8, 76u8, 82u8, 69u8, 76u8, 82u8, 79u8, 65u8, 76u8, 82u8,
+            76u8, 69u8, 82u8, 76u8, 79u8, 80u8, 68u8, 70u8, 78u8, 83u8, 77u8, 66u8, 78u8, 70u8,
+            83u8, 73u8, 76u8, 82u8, 73u8, 82u8, 76u8, 73u8, 80u8, 68u8, 73u8,
+        ])
+    },
+};
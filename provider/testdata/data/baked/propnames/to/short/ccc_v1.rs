@@ -0,0 +1,63 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: CanonicalCombiningClassValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            241u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+            0u8, 8u8, 0u8, 10u8, 0u8, 12u8, 0u8, 14u8, 0u8, 19u8, 0u8, 24u8, 0u8, 29u8, 0u8, 34u8,
+            0u8, 39u8, 0u8, 44u8, 0u8, 49u8, 0u8, 54u8, 0u8, 59u8, 0u8, 64u8, 0u8, 69u8, 0u8, 74u8,
+            0u8, 79u8, 0u8, 84u8, 0u8, 89u8, 0u8, 94u8, 0u8, 99u8, 0u8, 104u8, 0u8, 109u8, 0u8,
+            114u8, 0u8, 119u8, 0u8, 124u8, 0u8, 129u8, 0u8, 134u8, 0u8, 139u8, 0u8, 144u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8,
+            149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 149u8, 0u8, 154u8, 0u8,
+            154u8, 0u8, 154u8, 0u8, 154u8, 0u8, 154u8, 0u8, 154u8, 0u8, 154u8, 0u8, 159u8, 0u8,
+            159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 159u8, 0u8,
+            159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 159u8, 0u8, 165u8, 0u8, 165u8, 0u8, 165u8, 0u8,
+            165u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8,
+            171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 171u8, 0u8, 177u8, 0u8, 177u8, 0u8,
+            177u8, 0u8, 177u8, 0u8, 183u8, 0u8, 183u8, 0u8, 183u8, 0u8, 183u8, 0u8, 183u8, 0u8,
+            183u8, 0u8, 183u8, 0u8, 189u8, 0u8, 195u8, 0u8, 195u8, 0u8, 201u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 207u8, 0u8,
+            207u8, 0u8, 207u8, 0u8, 207u8, 0u8, 211u8, 0u8, 211u8, 0u8, 214u8, 0u8, 214u8, 0u8,
+            214u8, 0u8, 214u8, 0u8, 214u8, 0u8, 214u8, 0u8, 214u8, 0u8, 214u8, 0u8, 214u8, 0u8,
+            214u8, 0u8, 214u8, 0u8, 214u8, 0u8, 217u8, 0u8, 217u8, 0u8, 221u8, 0u8, 221u8, 0u8,
+            223u8, 0u8, 223u8, 0u8, 224u8, 0u8, 224u8, 0u8, 226u8, 0u8, 226u8, 0u8, 227u8, 0u8,
+            227u8, 0u8, 228u8, 0u8, 228u8, 0u8, 230u8, 0u8, 230u8, 0u8, 231u8, 0u8, 231u8, 0u8,
+            233u8, 0u8, 235u8, 0u8, 237u8, 0u8, 237u8, 0u8, 237u8, 0u8, 237u8, 0u8, 237u8, 0u8,
+            237u8, 0u8, 78u8, 82u8, 79u8, 86u8, 72u8, 65u8, 78u8, 82u8, 78u8, 75u8, 75u8, 86u8,
+            86u8, 82u8, 67u8, 67u8, 67u8, 49u8, 48u8, 67u8, 67u8, 67u8, 49u8, 49u8, 67u8, 67u8,
+            67u8, 49u8, 50u8, 67u8, 67u8, 67u8, 49u8, 51u8, 67u8, 67u8, 67u8, 49u8, 52u8, 67u8,
+            67u8, 67u8, 49u8, 53u8, 67u8, 67u8, 67u8, 49u8, 54u8, 67u8, 67u8, 67u8, 49u8, 55u8,
+            67u8, 67u8, 67u8, 49u8, 56u8, 67u8, 67u8, 67u8, 49u8, 57u8, 67u8, 67u8, 67u8, 50u8,
+            48u8, 67u8, 67u8, 67u8, 50u8, 49u8, 67u8, 67u8, 67u8, 50u8, 50u8, 67u8, 67u8, 67u8,
+            50u8, 51u8, 67u8, 67u8, 67u8, 50u8, 52u8, 67u8, 67u8, 67u8, 50u8, 53u8, 67u8, 67u8,
+            67u8, 50u8, 54u8, 67u8, 67u8, 67u8, 50u8, 55u8, 67u8, 67u8, 67u8, 50u8, 56u8, 67u8,
+            67u8, 67u8, 50u8, 57u8, 67u8, 67u8, 67u8, 51u8, 48u8, 67u8, 67u8, 67u8, 51u8, 49u8,
+            67u8, 67u8, 67u8, 51u8, 50u8, 67u8, 67u8, 67u8, 51u8, 51u8, 67u8, 67u8, 67u8, 51u8,
+            52u8, 67u8, 67u8, 67u8, 51u8, 53u8, 67u8, 67u8, 67u8, 51u8, 54u8, 67u8, 67u8, 67u8,
+            56u8, 52u8, 67u8, 67u8, 67u8, 57u8, 49u8, 67u8, 67u8, 67u8, 49u8, 48u8, 51u8, 67u8,
+            67u8, 67u8, 49u8, 48u8, 55u8, 67u8, 67u8, 67u8, 49u8, 49u8, 56u8, 67u8, 67u8, 67u8,
+            49u8, 50u8, 50u8, 67u8, 67u8, 67u8, 49u8, 50u8, 57u8, 67u8, 67u8, 67u8, 49u8, 51u8,
+            48u8, 67u8, 67u8, 67u8, 49u8, 51u8, 50u8, 67u8, 67u8, 67u8, 49u8, 51u8, 51u8, 65u8,
+            84u8, 66u8, 76u8, 65u8, 84u8, 66u8, 65u8, 84u8, 65u8, 65u8, 84u8, 65u8, 82u8, 66u8,
+            76u8, 66u8, 66u8, 82u8, 76u8, 82u8, 65u8, 76u8, 65u8, 65u8, 82u8, 68u8, 66u8, 68u8,
+            65u8, 73u8, 83u8,
+        ])
+    },
+};
@@ -0,0 +1,20 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GeneralCategoryValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 10u8, 0u8, 12u8,
+            0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 20u8, 0u8, 22u8, 0u8, 24u8, 0u8, 26u8, 0u8, 28u8,
+            0u8, 30u8, 0u8, 32u8, 0u8, 34u8, 0u8, 36u8, 0u8, 38u8, 0u8, 40u8, 0u8, 42u8, 0u8, 44u8,
+            0u8, 46u8, 0u8, 48u8, 0u8, 50u8, 0u8, 52u8, 0u8, 54u8, 0u8, 56u8, 0u8, 58u8, 0u8, 67u8,
+            110u8, 76u8, 117u8, 76u8, 108u8, 76u8, 116u8, 76u8, 109u8, 76u8, 111u8, 77u8, 110u8,
+            77u8, 101u8, 77u8, 99u8, 78u8, 100u8, 78u8, 108u8, 78u8, 111u8, 90u8, 115u8, 90u8,
+            108u8, 90u8, 112u8, 67u8, 99u8, 67u8, 102u8, 67u8, 111u8, 67u8, 115u8, 80u8, 100u8,
+            80u8, 115u8, 80u8, 101u8, 80u8, 99u8, 80u8, 111u8, 83u8, 109u8, 83u8, 99u8, 83u8,
+            107u8, 83u8, 111u8, 80u8, 105u8, 80u8, 102u8,
+        ])
+    },
+};
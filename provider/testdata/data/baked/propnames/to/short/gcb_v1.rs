@@ -0,0 +1,17 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: GraphemeClusterBreakValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 9u8, 0u8, 11u8,
+            0u8, 13u8, 0u8, 16u8, 0u8, 17u8, 0u8, 18u8, 0u8, 20u8, 0u8, 22u8, 0u8, 24u8, 0u8, 26u8,
+            0u8, 29u8, 0u8, 31u8, 0u8, 34u8, 0u8, 88u8, 88u8, 67u8, 78u8, 67u8, 82u8, 69u8, 88u8,
+            76u8, 76u8, 70u8, 76u8, 86u8, 76u8, 86u8, 84u8, 84u8, 86u8, 83u8, 77u8, 80u8, 80u8,
+            82u8, 73u8, 69u8, 66u8, 69u8, 66u8, 71u8, 69u8, 77u8, 71u8, 65u8, 90u8, 90u8, 87u8,
+            74u8,
+        ])
+    },
+};
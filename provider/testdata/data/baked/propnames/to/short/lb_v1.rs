@@ -0,0 +1,23 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: LineBreakValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            43u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 10u8, 0u8, 12u8,
+            0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 20u8, 0u8, 22u8, 0u8, 24u8, 0u8, 26u8, 0u8, 28u8,
+            0u8, 30u8, 0u8, 32u8, 0u8, 34u8, 0u8, 36u8, 0u8, 38u8, 0u8, 40u8, 0u8, 42u8, 0u8, 44u8,
+            0u8, 46u8, 0u8, 48u8, 0u8, 50u8, 0u8, 52u8, 0u8, 54u8, 0u8, 56u8, 0u8, 58u8, 0u8, 60u8,
+            0u8, 62u8, 0u8, 64u8, 0u8, 66u8, 0u8, 68u8, 0u8, 70u8, 0u8, 72u8, 0u8, 74u8, 0u8, 76u8,
+            0u8, 78u8, 0u8, 80u8, 0u8, 82u8, 0u8, 84u8, 0u8, 88u8, 88u8, 65u8, 73u8, 65u8, 76u8,
+            66u8, 50u8, 66u8, 65u8, 66u8, 66u8, 66u8, 75u8, 67u8, 66u8, 67u8, 76u8, 67u8, 77u8,
+            67u8, 82u8, 69u8, 88u8, 71u8, 76u8, 72u8, 89u8, 73u8, 68u8, 73u8, 78u8, 73u8, 83u8,
+            76u8, 70u8, 78u8, 83u8, 78u8, 85u8, 79u8, 80u8, 80u8, 79u8, 80u8, 82u8, 81u8, 85u8,
+            83u8, 65u8, 83u8, 71u8, 83u8, 80u8, 83u8, 89u8, 90u8, 87u8, 78u8, 76u8, 87u8, 74u8,
+            72u8, 50u8, 72u8, 51u8, 74u8, 76u8, 74u8, 84u8, 74u8, 86u8, 67u8, 80u8, 67u8, 74u8,
+            72u8, 76u8, 82u8, 73u8, 69u8, 66u8, 69u8, 77u8, 90u8, 87u8, 74u8,
+        ])
+    },
+};
@@ -0,0 +1,16 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: SentenceBreakValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 10u8, 0u8, 12u8,
+            0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 20u8, 0u8, 22u8, 0u8, 24u8, 0u8, 26u8, 0u8, 28u8,
+            0u8, 88u8, 88u8, 65u8, 84u8, 67u8, 76u8, 70u8, 79u8, 76u8, 79u8, 78u8, 85u8, 76u8,
+            69u8, 83u8, 69u8, 83u8, 80u8, 83u8, 84u8, 85u8, 80u8, 67u8, 82u8, 69u8, 88u8, 76u8,
+            70u8, 83u8, 67u8,
+        ])
+    },
+};
@@ -0,0 +1,88 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: ScriptValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            198u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 12u8, 0u8, 16u8, 0u8, 20u8, 0u8,
+            24u8, 0u8, 28u8, 0u8, 32u8, 0u8, 36u8, 0u8, 40u8, 0u8, 44u8, 0u8, 48u8, 0u8, 52u8, 0u8,
+            56u8, 0u8, 60u8, 0u8, 64u8, 0u8, 68u8, 0u8, 72u8, 0u8, 76u8, 0u8, 80u8, 0u8, 84u8, 0u8,
+            88u8, 0u8, 92u8, 0u8, 96u8, 0u8, 100u8, 0u8, 104u8, 0u8, 108u8, 0u8, 112u8, 0u8, 116u8,
+            0u8, 120u8, 0u8, 124u8, 0u8, 128u8, 0u8, 132u8, 0u8, 136u8, 0u8, 140u8, 0u8, 144u8,
+            0u8, 148u8, 0u8, 152u8, 0u8, 156u8, 0u8, 160u8, 0u8, 164u8, 0u8, 168u8, 0u8, 172u8,
+            0u8, 176u8, 0u8, 180u8, 0u8, 184u8, 0u8, 188u8, 0u8, 192u8, 0u8, 196u8, 0u8, 200u8,
+            0u8, 204u8, 0u8, 208u8, 0u8, 212u8, 0u8, 216u8, 0u8, 220u8, 0u8, 224u8, 0u8, 228u8,
+            0u8, 232u8, 0u8, 236u8, 0u8, 240u8, 0u8, 244u8, 0u8, 248u8, 0u8, 252u8, 0u8, 0u8, 1u8,
+            0u8, 1u8, 4u8, 1u8, 8u8, 1u8, 8u8, 1u8, 8u8, 1u8, 8u8, 1u8, 8u8, 1u8, 12u8, 1u8, 12u8,
+            1u8, 12u8, 1u8, 12u8, 1u8, 16u8, 1u8, 20u8, 1u8, 20u8, 1u8, 24u8, 1u8, 28u8, 1u8, 28u8,
+            1u8, 28u8, 1u8, 32u8, 1u8, 36u8, 1u8, 40u8, 1u8, 40u8, 1u8, 44u8, 1u8, 48u8, 1u8, 52u8,
+            1u8, 56u8, 1u8, 60u8, 1u8, 64u8, 1u8, 68u8, 1u8, 68u8, 1u8, 68u8, 1u8, 68u8, 1u8, 68u8,
+            1u8, 68u8, 1u8, 68u8, 1u8, 72u8, 1u8, 72u8, 1u8, 76u8, 1u8, 76u8, 1u8, 80u8, 1u8, 84u8,
+            1u8, 84u8, 1u8, 88u8, 1u8, 92u8, 1u8, 96u8, 1u8, 100u8, 1u8, 104u8, 1u8, 108u8, 1u8,
+            112u8, 1u8, 116u8, 1u8, 116u8, 1u8, 120u8, 1u8, 124u8, 1u8, 128u8, 1u8, 132u8, 1u8,
+            132u8, 1u8, 136u8, 1u8, 140u8, 1u8, 144u8, 1u8, 148u8, 1u8, 148u8, 1u8, 152u8, 1u8,
+            156u8, 1u8, 160u8, 1u8, 160u8, 1u8, 160u8, 1u8, 164u8, 1u8, 168u8, 1u8, 168u8, 1u8,
+            172u8, 1u8, 176u8, 1u8, 180u8, 1u8, 184u8, 1u8, 188u8, 1u8, 188u8, 1u8, 188u8, 1u8,
+            192u8, 1u8, 196u8, 1u8, 200u8, 1u8, 204u8, 1u8, 208u8, 1u8, 212u8, 1u8, 216u8, 1u8,
+            216u8, 1u8, 216u8, 1u8, 220u8, 1u8, 224u8, 1u8, 228u8, 1u8, 232u8, 1u8, 236u8, 1u8,
+            240u8, 1u8, 240u8, 1u8, 244u8, 1u8, 248u8, 1u8, 252u8, 1u8, 0u8, 2u8, 4u8, 2u8, 8u8,
+            2u8, 12u8, 2u8, 16u8, 2u8, 20u8, 2u8, 24u8, 2u8, 28u8, 2u8, 32u8, 2u8, 36u8, 2u8, 40u8,
+            2u8, 44u8, 2u8, 48u8, 2u8, 48u8, 2u8, 48u8, 2u8, 48u8, 2u8, 52u8, 2u8, 56u8, 2u8, 60u8,
+            2u8, 64u8, 2u8, 68u8, 2u8, 72u8, 2u8, 76u8, 2u8, 80u8, 2u8, 84u8, 2u8, 88u8, 2u8, 92u8,
+            2u8, 96u8, 2u8, 100u8, 2u8, 104u8, 2u8, 108u8, 2u8, 112u8, 2u8, 116u8, 2u8, 120u8, 2u8,
+            124u8, 2u8, 128u8, 2u8, 132u8, 2u8, 136u8, 2u8, 90u8, 121u8, 121u8, 121u8, 90u8, 105u8,
+            110u8, 104u8, 65u8, 114u8, 97u8, 98u8, 65u8, 114u8, 109u8, 110u8, 66u8, 101u8, 110u8,
+            103u8, 66u8, 111u8, 112u8, 111u8, 67u8, 104u8, 101u8, 114u8, 67u8, 111u8, 112u8, 116u8,
+            67u8, 121u8, 114u8, 108u8, 68u8, 115u8, 114u8, 116u8, 68u8, 101u8, 118u8, 97u8, 69u8,
+            116u8, 104u8, 105u8, 71u8, 101u8, 111u8, 114u8, 71u8, 111u8, 116u8, 104u8, 71u8, 114u8,
+            101u8, 107u8, 71u8, 117u8, 106u8, 114u8, 71u8, 117u8, 114u8, 117u8, 72u8, 97u8, 110u8,
+            105u8, 72u8, 97u8, 110u8, 103u8, 72u8, 101u8, 98u8, 114u8, 72u8, 105u8, 114u8, 97u8,
+            75u8, 110u8, 100u8, 97u8, 75u8, 97u8, 110u8, 97u8, 75u8, 104u8, 109u8, 114u8, 76u8,
+            97u8, 111u8, 111u8, 76u8, 97u8, 116u8, 110u8, 77u8, 108u8, 121u8, 109u8, 77u8, 111u8,
+            110u8, 103u8, 77u8, 121u8, 109u8, 114u8, 79u8, 103u8, 97u8, 109u8, 73u8, 116u8, 97u8,
+            108u8, 79u8, 114u8, 121u8, 97u8, 82u8, 117u8, 110u8, 114u8, 83u8, 105u8, 110u8, 104u8,
+            83u8, 121u8, 114u8, 99u8, 84u8, 97u8, 109u8, 108u8, 84u8, 101u8, 108u8, 117u8, 84u8,
+            104u8, 97u8, 97u8, 84u8, 104u8, 97u8, 105u8, 84u8, 105u8, 98u8, 116u8, 67u8, 97u8,
+            110u8, 115u8, 89u8, 105u8, 105u8, 105u8, 84u8, 103u8, 108u8, 103u8, 72u8, 97u8, 110u8,
+            111u8, 66u8, 117u8, 104u8, 100u8, 84u8, 97u8, 103u8, 98u8, 66u8, 114u8, 97u8, 105u8,
+            67u8, 112u8, 114u8, 116u8, 76u8, 105u8, 109u8, 98u8, 76u8, 105u8, 110u8, 98u8, 79u8,
+            115u8, 109u8, 97u8, 83u8, 104u8, 97u8, 119u8, 84u8, 97u8, 108u8, 101u8, 85u8, 103u8,
+            97u8, 114u8, 72u8, 114u8, 107u8, 116u8, 66u8, 117u8, 103u8, 105u8, 71u8, 108u8, 97u8,
+            103u8, 75u8, 104u8, 97u8, 114u8, 83u8, 121u8, 108u8, 111u8, 84u8, 97u8, 108u8, 117u8,
+            84u8, 102u8, 110u8, 103u8, 88u8, 112u8, 101u8, 111u8, 66u8, 97u8, 108u8, 105u8, 66u8,
+            97u8, 116u8, 107u8, 66u8, 114u8, 97u8, 104u8, 67u8, 104u8, 97u8, 109u8, 69u8, 103u8,
+            121u8, 112u8, 72u8, 109u8, 110u8, 103u8, 72u8, 117u8, 110u8, 103u8, 74u8, 97u8, 118u8,
+            97u8, 75u8, 97u8, 108u8, 105u8, 76u8, 101u8, 112u8, 99u8, 76u8, 105u8, 110u8, 97u8,
+            77u8, 97u8, 110u8, 100u8, 77u8, 101u8, 114u8, 111u8, 78u8, 107u8, 111u8, 111u8, 79u8,
+            114u8, 107u8, 104u8, 80u8, 101u8, 114u8, 109u8, 80u8, 104u8, 97u8, 103u8, 80u8, 104u8,
+            110u8, 120u8, 80u8, 108u8, 114u8, 100u8, 86u8, 97u8, 105u8, 105u8, 88u8, 115u8, 117u8,
+            120u8, 90u8, 122u8, 122u8, 122u8, 67u8, 97u8, 114u8, 105u8, 76u8, 97u8, 110u8, 97u8,
+            76u8, 121u8, 99u8, 105u8, 76u8, 121u8, 100u8, 105u8, 79u8, 108u8, 99u8, 107u8, 82u8,
+            106u8, 110u8, 103u8, 83u8, 97u8, 117u8, 114u8, 83u8, 103u8, 110u8, 119u8, 83u8, 117u8,
+            110u8, 100u8, 77u8, 116u8, 101u8, 105u8, 65u8, 114u8, 109u8, 105u8, 65u8, 118u8, 115u8,
+            116u8, 67u8, 97u8, 107u8, 109u8, 75u8, 116u8, 104u8, 105u8, 77u8, 97u8, 110u8, 105u8,
+            80u8, 104u8, 108u8, 105u8, 80u8, 104u8, 108u8, 112u8, 80u8, 114u8, 116u8, 105u8, 83u8,
+            97u8, 109u8, 114u8, 84u8, 97u8, 118u8, 116u8, 66u8, 97u8, 109u8, 117u8, 76u8, 105u8,
+            115u8, 117u8, 83u8, 97u8, 114u8, 98u8, 66u8, 97u8, 115u8, 115u8, 68u8, 117u8, 112u8,
+            108u8, 69u8, 108u8, 98u8, 97u8, 71u8, 114u8, 97u8, 110u8, 77u8, 101u8, 110u8, 100u8,
+            77u8, 101u8, 114u8, 99u8, 78u8, 97u8, 114u8, 98u8, 78u8, 98u8, 97u8, 116u8, 80u8, 97u8,
+            108u8, 109u8, 83u8, 105u8, 110u8, 100u8, 87u8, 97u8, 114u8, 97u8, 77u8, 114u8, 111u8,
+            111u8, 78u8, 115u8, 104u8, 117u8, 83u8, 104u8, 114u8, 100u8, 83u8, 111u8, 114u8, 97u8,
+            84u8, 97u8, 107u8, 114u8, 84u8, 97u8, 110u8, 103u8, 72u8, 108u8, 117u8, 119u8, 75u8,
+            104u8, 111u8, 106u8, 84u8, 105u8, 114u8, 104u8, 65u8, 103u8, 104u8, 98u8, 77u8, 97u8,
+            104u8, 106u8, 65u8, 104u8, 111u8, 109u8, 72u8, 97u8, 116u8, 114u8, 77u8, 111u8, 100u8,
+            105u8, 77u8, 117u8, 108u8, 116u8, 80u8, 97u8, 117u8, 99u8, 83u8, 105u8, 100u8, 100u8,
+            65u8, 100u8, 108u8, 109u8, 66u8, 104u8, 107u8, 115u8, 77u8, 97u8, 114u8, 99u8, 78u8,
+            101u8, 119u8, 97u8, 79u8, 115u8, 103u8, 101u8, 71u8, 111u8, 110u8, 109u8, 83u8, 111u8,
+            121u8, 111u8, 90u8, 97u8, 110u8, 98u8, 68u8, 111u8, 103u8, 114u8, 71u8, 111u8, 110u8,
+            103u8, 77u8, 97u8, 107u8, 97u8, 77u8, 101u8, 100u8, 102u8, 82u8, 111u8, 104u8, 103u8,
+            83u8, 111u8, 103u8, 100u8, 83u8, 111u8, 103u8, 111u8, 69u8, 108u8, 121u8, 109u8, 72u8,
+            109u8, 110u8, 112u8, 78u8, 97u8, 110u8, 100u8, 87u8, 99u8, 104u8, 111u8, 67u8, 104u8,
+            114u8, 115u8, 68u8, 105u8, 97u8, 107u8, 75u8, 105u8, 116u8, 115u8, 89u8, 101u8, 122u8,
+            105u8, 67u8, 112u8, 109u8, 110u8, 79u8, 117u8, 103u8, 114u8, 84u8, 110u8, 115u8, 97u8,
+            84u8, 111u8, 116u8, 111u8, 86u8, 105u8, 116u8, 104u8,
+        ])
+    },
+};
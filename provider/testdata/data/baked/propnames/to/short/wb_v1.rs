@@ -0,0 +1,19 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: WordBreakValueToShortNameV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyEnumToValueNameMapV1 {
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 6u8, 0u8, 8u8, 0u8, 10u8, 0u8, 12u8,
+            0u8, 14u8, 0u8, 16u8, 0u8, 18u8, 0u8, 24u8, 0u8, 26u8, 0u8, 28u8, 0u8, 30u8, 0u8, 32u8,
+            0u8, 34u8, 0u8, 36u8, 0u8, 38u8, 0u8, 40u8, 0u8, 43u8, 0u8, 45u8, 0u8, 48u8, 0u8, 51u8,
+            0u8, 88u8, 88u8, 76u8, 69u8, 70u8, 79u8, 75u8, 65u8, 77u8, 76u8, 77u8, 78u8, 78u8,
+            85u8, 69u8, 88u8, 67u8, 82u8, 69u8, 120u8, 116u8, 101u8, 110u8, 100u8, 76u8, 70u8,
+            77u8, 66u8, 78u8, 76u8, 82u8, 73u8, 72u8, 76u8, 83u8, 81u8, 68u8, 81u8, 69u8, 66u8,
+            69u8, 66u8, 71u8, 69u8, 77u8, 71u8, 65u8, 90u8, 90u8, 87u8, 74u8, 87u8, 83u8, 101u8,
+            103u8, 83u8, 112u8, 97u8, 99u8, 101u8,
+        ])
+    },
+};
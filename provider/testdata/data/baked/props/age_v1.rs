@@ -0,0 +1,1770 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = <::icu_properties::provider::AgeV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 983040u32,
+            shifted12_high_start: 240u16,
+            index3_null_offset: 1830u16,
+            data_null_offset: 3029u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 74u8, 0u8,
+                132u8, 0u8, 194u8, 0u8, 217u8, 0u8, 9u8, 1u8, 0u8, 0u8, 73u8, 1u8, 137u8, 1u8,
+                186u8, 1u8, 250u8, 1u8, 42u8, 2u8, 99u8, 2u8, 158u8, 2u8, 222u8, 2u8, 15u8, 3u8,
+                71u8, 3u8, 131u8, 3u8, 195u8, 3u8, 3u8, 4u8, 51u8, 4u8, 115u8, 4u8, 179u8, 4u8,
+                232u8, 4u8, 40u8, 5u8, 104u8, 5u8, 168u8, 5u8, 232u8, 5u8, 25u8, 6u8, 87u8, 6u8,
+                151u8, 6u8, 211u8, 6u8, 19u8, 7u8, 81u8, 7u8, 144u8, 7u8, 206u8, 7u8, 13u8, 8u8,
+                73u8, 8u8, 137u8, 8u8, 197u8, 8u8, 3u8, 9u8, 65u8, 9u8, 129u8, 9u8, 191u8, 9u8,
+                255u8, 9u8, 61u8, 10u8, 125u8, 10u8, 187u8, 10u8, 251u8, 10u8, 59u8, 11u8, 122u8,
+                11u8, 185u8, 11u8, 248u8, 11u8, 56u8, 12u8, 120u8, 12u8, 176u8, 12u8, 228u8, 12u8,
+                34u8, 13u8, 4u8, 12u8, 30u8, 12u8, 47u8, 12u8, 69u8, 12u8, 101u8, 12u8, 131u8,
+                12u8, 160u8, 12u8, 191u8, 12u8, 223u8, 12u8, 223u8, 12u8, 236u8, 12u8, 9u8, 13u8,
+                41u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8,
+                52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8,
+                51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8,
+                51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8,
+                51u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8,
+                52u8, 13u8, 51u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 51u8, 13u8, 51u8, 13u8,
+                51u8, 13u8, 52u8, 13u8, 84u8, 13u8, 51u8, 13u8, 51u8, 13u8, 52u8, 13u8, 0u8, 0u8,
+                16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 0u8,
+                0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 74u8, 0u8, 90u8, 0u8, 106u8, 0u8,
+                122u8, 0u8, 132u8, 0u8, 148u8, 0u8, 164u8, 0u8, 180u8, 0u8, 194u8, 0u8, 210u8, 0u8,
+                226u8, 0u8, 242u8, 0u8, 217u8, 0u8, 233u8, 0u8, 249u8, 0u8, 9u8, 1u8, 9u8, 1u8,
+                25u8, 1u8, 41u8, 1u8, 57u8, 1u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 73u8,
+                1u8, 89u8, 1u8, 105u8, 1u8, 121u8, 1u8, 137u8, 1u8, 153u8, 1u8, 169u8, 1u8, 185u8,
+                1u8, 186u8, 1u8, 202u8, 1u8, 218u8, 1u8, 234u8, 1u8, 250u8, 1u8, 10u8, 2u8, 26u8,
+                2u8, 42u8, 2u8, 42u8, 2u8, 58u8, 2u8, 74u8, 2u8, 90u8, 2u8, 99u8, 2u8, 115u8, 2u8,
+                131u8, 2u8, 147u8, 2u8, 158u8, 2u8, 174u8, 2u8, 190u8, 2u8, 206u8, 2u8, 222u8, 2u8,
+                238u8, 2u8, 254u8, 2u8, 14u8, 3u8, 15u8, 3u8, 31u8, 3u8, 47u8, 3u8, 63u8, 3u8,
+                71u8, 3u8, 87u8, 3u8, 103u8, 3u8, 119u8, 3u8, 131u8, 3u8, 147u8, 3u8, 163u8, 3u8,
+                179u8, 3u8, 195u8, 3u8, 211u8, 3u8, 227u8, 3u8, 243u8, 3u8, 3u8, 4u8, 19u8, 4u8,
+                35u8, 4u8, 51u8, 4u8, 51u8, 4u8, 67u8, 4u8, 83u8, 4u8, 99u8, 4u8, 115u8, 4u8,
+                131u8, 4u8, 147u8, 4u8, 163u8, 4u8, 179u8, 4u8, 195u8, 4u8, 211u8, 4u8, 227u8, 4u8,
+                232u8, 4u8, 248u8, 4u8, 8u8, 5u8, 24u8, 5u8, 40u8, 5u8, 56u8, 5u8, 72u8, 5u8, 88u8,
+                5u8, 104u8, 5u8, 120u8, 5u8, 136u8, 5u8, 152u8, 5u8, 168u8, 5u8, 184u8, 5u8, 200u8,
+                5u8, 216u8, 5u8, 232u8, 5u8, 248u8, 5u8, 8u8, 6u8, 24u8, 6u8, 25u8, 6u8, 41u8, 6u8,
+                57u8, 6u8, 73u8, 6u8, 87u8, 6u8, 103u8, 6u8, 119u8, 6u8, 135u8, 6u8, 151u8, 6u8,
+                167u8, 6u8, 183u8, 6u8, 199u8, 6u8, 211u8, 6u8, 227u8, 6u8, 243u8, 6u8, 3u8, 7u8,
+                19u8, 7u8, 35u8, 7u8, 51u8, 7u8, 67u8, 7u8, 81u8, 7u8, 97u8, 7u8, 113u8, 7u8,
+                129u8, 7u8, 144u8, 7u8, 160u8, 7u8, 176u8, 7u8, 192u8, 7u8, 206u8, 7u8, 222u8, 7u8,
+                238u8, 7u8, 254u8, 7u8, 13u8, 8u8, 29u8, 8u8, 45u8, 8u8, 61u8, 8u8, 73u8, 8u8,
+                89u8, 8u8, 105u8, 8u8, 121u8, 8u8, 137u8, 8u8, 153u8, 8u8, 169u8, 8u8, 185u8, 8u8,
+                197u8, 8u8, 213u8, 8u8, 229u8, 8u8, 245u8, 8u8, 3u8, 9u8, 19u8, 9u8, 35u8, 9u8,
+                51u8, 9u8, 65u8, 9u8, 81u8, 9u8, 97u8, 9u8, 113u8, 9u8, 129u8, 9u8, 145u8, 9u8,
+                161u8, 9u8, 177u8, 9u8, 191u8, 9u8, 207u8, 9u8, 223u8, 9u8, 239u8, 9u8, 255u8, 9u8,
+                15u8, 10u8, 31u8, 10u8, 47u8, 10u8, 61u8, 10u8, 77u8, 10u8, 93u8, 10u8, 109u8,
+                10u8, 125u8, 10u8, 141u8, 10u8, 157u8, 10u8, 173u8, 10u8, 187u8, 10u8, 203u8, 10u8,
+                219u8, 10u8, 235u8, 10u8, 251u8, 10u8, 11u8, 11u8, 27u8, 11u8, 43u8, 11u8, 59u8,
+                11u8, 75u8, 11u8, 91u8, 11u8, 107u8, 11u8, 122u8, 11u8, 138u8, 11u8, 154u8, 11u8,
+                170u8, 11u8, 185u8, 11u8, 201u8, 11u8, 217u8, 11u8, 233u8, 11u8, 248u8, 11u8, 8u8,
+                12u8, 24u8, 12u8, 40u8, 12u8, 56u8, 12u8, 72u8, 12u8, 88u8, 12u8, 104u8, 12u8,
+                120u8, 12u8, 136u8, 12u8, 152u8, 12u8, 168u8, 12u8, 176u8, 12u8, 192u8, 12u8,
+                208u8, 12u8, 224u8, 12u8, 228u8, 12u8, 244u8, 12u8, 4u8, 13u8, 20u8, 13u8, 34u8,
+                13u8, 50u8, 13u8, 66u8, 13u8, 82u8, 13u8, 166u8, 0u8, 166u8, 0u8, 98u8, 13u8,
+                111u8, 13u8, 166u8, 0u8, 127u8, 13u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 137u8,
+                13u8, 0u8, 0u8, 0u8, 0u8, 153u8, 13u8, 0u8, 0u8, 0u8, 0u8, 169u8, 13u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 185u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 192u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 200u8, 13u8, 216u8,
+                13u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 225u8, 13u8, 241u8, 13u8, 166u8, 0u8,
+                166u8, 0u8, 225u8, 13u8, 166u8, 0u8, 1u8, 14u8, 233u8, 13u8, 17u8, 14u8, 186u8,
+                4u8, 1u8, 14u8, 166u8, 0u8, 1u8, 14u8, 17u8, 14u8, 166u8, 0u8, 166u8, 0u8, 216u8,
+                13u8, 33u8, 14u8, 48u8, 14u8, 5u8, 11u8, 248u8, 4u8, 64u8, 14u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 80u8, 14u8, 96u8, 14u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 105u8, 14u8, 166u8, 0u8, 5u8, 11u8, 166u8, 0u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 121u8, 14u8, 137u8, 14u8, 151u8, 14u8, 222u8, 2u8,
+                167u8, 14u8, 222u8, 2u8, 170u8, 14u8, 186u8, 14u8, 198u8, 14u8, 166u8, 0u8, 166u8,
+                0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 214u8, 14u8, 230u8, 14u8, 246u8, 14u8,
+                6u8, 15u8, 230u8, 14u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                22u8, 15u8, 166u8, 0u8, 166u8, 0u8, 38u8, 15u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8,
+                168u8, 5u8, 54u8, 15u8, 56u8, 1u8, 70u8, 15u8, 86u8, 15u8, 86u8, 15u8, 102u8, 15u8,
+                56u8, 1u8, 106u8, 15u8, 115u8, 15u8, 248u8, 4u8, 248u8, 4u8, 131u8, 15u8, 248u8,
+                4u8, 64u8, 14u8, 147u8, 15u8, 56u8, 1u8, 56u8, 1u8, 248u8, 4u8, 161u8, 15u8, 168u8,
+                5u8, 168u8, 5u8, 168u8, 5u8, 199u8, 5u8, 168u8, 5u8, 201u8, 5u8, 177u8, 15u8,
+                177u8, 15u8, 200u8, 5u8, 193u8, 15u8, 208u8, 15u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 224u8, 15u8, 104u8, 5u8,
+                104u8, 5u8, 240u8, 15u8, 242u8, 2u8, 242u8, 2u8, 0u8, 16u8, 14u8, 16u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 30u8, 16u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 46u8, 16u8,
+                57u8, 16u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 73u8, 16u8, 89u8, 16u8, 89u8, 16u8,
+                94u8, 16u8, 110u8, 16u8, 168u8, 5u8, 168u8, 5u8, 126u8, 16u8, 56u8, 1u8, 56u8, 1u8,
+                56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 142u8, 16u8, 248u8, 4u8, 248u8, 4u8,
+                248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 154u8, 16u8, 242u8, 2u8, 165u8, 16u8, 175u8,
+                16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 191u8, 16u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                225u8, 10u8, 0u8, 0u8, 207u8, 16u8, 0u8, 0u8, 0u8, 0u8, 207u8, 16u8, 223u8, 16u8,
+                0u8, 0u8, 24u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 166u8, 1u8, 166u8, 1u8, 67u8,
+                8u8, 0u8, 0u8, 239u8, 16u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 255u8, 16u8,
+                13u8, 17u8, 28u8, 17u8, 43u8, 17u8, 156u8, 1u8, 59u8, 17u8, 75u8, 17u8, 91u8, 17u8,
+                107u8, 17u8, 0u8, 0u8, 123u8, 17u8, 139u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                155u8, 17u8, 168u8, 17u8, 184u8, 17u8, 0u8, 0u8, 0u8, 0u8, 197u8, 17u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 0u8, 213u8, 17u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 17u8, 218u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 245u8, 17u8, 166u8,
+                0u8, 2u8, 18u8, 222u8, 2u8, 222u8, 2u8, 13u8, 18u8, 28u8, 18u8, 40u8, 18u8, 52u8,
+                18u8, 0u8, 0u8, 0u8, 0u8, 68u8, 18u8, 213u8, 11u8, 202u8, 11u8, 213u8, 11u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 84u8,
+                18u8, 13u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 89u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 18u8, 0u8, 0u8, 105u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 121u8, 18u8, 137u8, 18u8, 151u8, 18u8, 246u8, 4u8, 167u8, 18u8, 176u8, 18u8,
+                168u8, 5u8, 188u8, 18u8, 168u8, 5u8, 204u8, 18u8, 0u8, 0u8, 158u8, 10u8, 0u8, 0u8,
+                216u8, 18u8, 231u8, 18u8, 246u8, 18u8, 239u8, 17u8, 0u8, 0u8, 6u8, 19u8, 0u8, 0u8,
+                13u8, 19u8, 29u8, 19u8, 222u8, 2u8, 109u8, 1u8, 222u8, 2u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8,
+                222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8, 222u8, 2u8,
+                222u8, 2u8, 222u8, 2u8, 45u8, 19u8, 154u8, 16u8, 238u8, 2u8, 242u8, 2u8, 61u8,
+                19u8, 77u8, 19u8, 87u8, 19u8, 99u8, 19u8, 87u8, 19u8, 109u8, 19u8, 87u8, 19u8,
+                117u8, 19u8, 130u8, 19u8, 144u8, 19u8, 148u8, 19u8, 164u8, 19u8, 248u8, 4u8, 248u8,
+                4u8, 180u8, 19u8, 248u8, 4u8, 248u8, 4u8, 180u8, 19u8, 196u8, 19u8, 212u8, 19u8,
+                248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 228u8,
+                19u8, 242u8, 19u8, 248u8, 4u8, 248u8, 4u8, 252u8, 19u8, 248u8, 4u8, 248u8, 4u8,
+                248u8, 4u8, 12u8, 20u8, 28u8, 20u8, 248u8, 4u8, 44u8, 20u8, 60u8, 20u8, 60u8, 20u8,
+                60u8, 20u8, 60u8, 20u8, 242u8, 2u8, 242u8, 2u8, 248u8, 4u8, 76u8, 20u8, 242u8, 2u8,
+                91u8, 20u8, 104u8, 20u8, 120u8, 20u8, 213u8, 11u8, 213u8, 11u8, 166u8, 0u8, 183u8,
+                4u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 136u8, 20u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 152u8,
+                20u8, 213u8, 11u8, 168u8, 20u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 184u8, 20u8, 150u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 199u8, 20u8, 50u8, 4u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 148u8, 2u8, 142u8, 3u8, 0u8, 0u8, 215u8, 20u8, 150u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 166u8, 0u8, 231u8,
+                20u8, 248u8, 4u8, 242u8, 2u8, 247u8, 20u8, 222u8, 2u8, 0u8, 0u8, 7u8, 21u8, 0u8,
+                0u8, 0u8, 0u8, 23u8, 21u8, 39u8, 21u8, 0u8, 0u8, 55u8, 21u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 70u8, 21u8, 104u8, 9u8, 0u8, 0u8, 0u8, 0u8, 86u8, 21u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 102u8, 21u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 4u8, 0u8, 0u8, 146u8, 4u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 118u8, 21u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8,
+                56u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 134u8, 21u8, 10u8, 5u8, 150u8, 21u8, 163u8, 21u8, 169u8, 21u8, 185u8,
+                21u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8, 166u8, 0u8,
+                166u8, 0u8, 166u8, 0u8, 5u8, 11u8, 166u8, 0u8, 201u8, 21u8, 213u8, 21u8, 228u8,
+                21u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8,
+                242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8,
+                242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 244u8,
+                21u8, 213u8, 11u8, 242u8, 2u8, 242u8, 2u8, 4u8, 22u8, 16u8, 22u8, 242u8, 2u8, 28u8,
+                22u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 44u8, 22u8,
+                248u8, 4u8, 60u8, 22u8, 240u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8,
+                242u8, 2u8, 71u8, 22u8, 87u8, 22u8, 103u8, 22u8, 119u8, 22u8, 135u8, 22u8, 151u8,
+                22u8, 213u8, 11u8, 165u8, 22u8, 248u8, 4u8, 248u8, 4u8, 181u8, 22u8, 177u8, 15u8,
+                104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 197u8, 22u8, 242u8, 2u8, 242u8, 2u8, 242u8,
+                2u8, 242u8, 2u8, 213u8, 22u8, 227u8, 22u8, 168u8, 5u8, 243u8, 22u8, 242u8, 2u8,
+                242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 3u8, 23u8, 168u8, 5u8, 19u8, 23u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 35u8, 23u8, 50u8, 23u8, 87u8, 19u8,
+                88u8, 19u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 66u8, 23u8, 82u8, 23u8, 86u8, 23u8,
+                168u8, 5u8, 102u8, 23u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 118u8,
+                23u8, 123u8, 23u8, 123u8, 6u8, 139u8, 23u8, 154u8, 23u8, 162u8, 23u8, 178u8, 23u8,
+                87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 194u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8,
+                23u8, 210u8, 23u8, 210u8, 23u8, 168u8, 5u8, 168u8, 5u8, 200u8, 5u8, 177u8, 15u8,
+                120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8,
+                120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8,
+                120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8,
+                120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8,
+                120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8, 120u8, 12u8,
+                120u8, 12u8, 120u8, 12u8, 226u8, 23u8, 168u8, 5u8, 242u8, 23u8, 168u8, 5u8, 168u8,
+                5u8, 253u8, 23u8, 0u8, 0u8, 0u8, 0u8, 13u8, 24u8, 222u8, 2u8, 222u8, 2u8, 222u8,
+                2u8, 29u8, 24u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8,
+                4u8, 64u8, 14u8, 213u8, 11u8, 213u8, 11u8, 206u8, 11u8, 43u8, 24u8, 0u8, 0u8, 57u8,
+                24u8, 73u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 87u8,
+                24u8, 101u8, 24u8, 144u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 3u8, 0u8, 0u8, 0u8, 0u8, 117u8,
+                24u8, 133u8, 24u8, 133u8, 24u8, 149u8, 24u8, 222u8, 2u8, 64u8, 14u8, 165u8, 24u8,
+                0u8, 0u8, 181u8, 24u8, 168u8, 1u8, 190u8, 24u8, 206u8, 24u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 3u8, 150u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 148u8, 2u8, 50u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 156u8, 1u8, 213u8, 16u8, 222u8, 24u8, 53u8, 7u8, 235u8, 24u8, 251u8,
+                24u8, 56u8, 1u8, 0u8, 25u8, 8u8, 25u8, 106u8, 15u8, 106u8, 15u8, 213u8, 11u8,
+                213u8, 11u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8,
+                56u8, 1u8, 109u8, 15u8, 23u8, 25u8, 56u8, 1u8, 56u8, 1u8, 35u8, 25u8, 248u8, 4u8,
+                248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 51u8, 25u8, 67u8, 25u8, 83u8, 25u8, 213u8,
+                11u8, 213u8, 11u8, 242u8, 2u8, 242u8, 2u8, 82u8, 23u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                242u8, 2u8, 99u8, 25u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 139u8, 17u8, 87u8,
+                19u8, 115u8, 25u8, 133u8, 24u8, 131u8, 25u8, 147u8, 25u8, 133u8, 24u8, 163u8, 25u8,
+                87u8, 19u8, 87u8, 19u8, 179u8, 25u8, 56u8, 1u8, 195u8, 25u8, 248u8, 4u8, 248u8,
+                4u8, 211u8, 25u8, 221u8, 25u8, 213u8, 11u8, 213u8, 11u8, 133u8, 24u8, 133u8, 24u8,
+                237u8, 25u8, 133u8, 24u8, 245u8, 25u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8,
+                106u8, 15u8, 246u8, 14u8, 5u8, 26u8, 5u8, 26u8, 17u8, 26u8, 5u8, 26u8, 9u8, 26u8,
+                87u8, 19u8, 87u8, 19u8, 33u8, 26u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 37u8, 26u8,
+                53u8, 26u8, 53u8, 26u8, 66u8, 26u8, 70u8, 26u8, 84u8, 26u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8,
+                87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8,
+                87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8,
+                87u8, 19u8, 34u8, 26u8, 87u8, 19u8, 35u8, 26u8, 33u8, 26u8, 213u8, 11u8, 100u8,
+                26u8, 24u8, 6u8, 24u8, 6u8, 105u8, 26u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 121u8, 26u8, 56u8, 1u8, 56u8, 1u8, 131u8, 26u8, 168u8, 5u8, 43u8,
+                23u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 88u8, 19u8, 147u8, 26u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 210u8, 23u8, 163u8, 26u8, 104u8, 5u8, 179u8, 26u8, 242u8,
+                2u8, 195u8, 26u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 123u8, 6u8,
+                123u8, 6u8, 123u8, 6u8, 211u8, 26u8, 210u8, 23u8, 208u8, 23u8, 210u8, 23u8, 210u8,
+                23u8, 227u8, 26u8, 239u8, 26u8, 248u8, 4u8, 251u8, 26u8, 10u8, 27u8, 26u8, 27u8,
+                168u8, 5u8, 168u8, 5u8, 87u8, 19u8, 87u8, 19u8, 213u8, 11u8, 213u8, 11u8, 87u8,
+                19u8, 87u8, 19u8, 42u8, 27u8, 34u8, 26u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 26u8,
+                23u8, 168u8, 5u8, 208u8, 5u8, 168u8, 5u8, 58u8, 27u8, 87u8, 19u8, 74u8, 27u8, 87u8,
+                27u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 168u8, 5u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 103u8, 27u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 119u8, 27u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 135u8, 27u8, 89u8, 16u8, 89u8, 16u8, 151u8, 27u8, 167u8, 27u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 168u8, 5u8, 199u8,
+                5u8, 183u8, 27u8, 183u8, 27u8, 189u8, 27u8, 201u8, 27u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 89u8, 16u8, 89u8, 16u8, 151u8, 27u8, 89u8, 16u8, 89u8,
+                16u8, 167u8, 27u8, 213u8, 11u8, 24u8, 6u8, 217u8, 27u8, 213u8, 11u8, 213u8, 11u8,
+                183u8, 27u8, 233u8, 27u8, 213u8, 11u8, 249u8, 27u8, 2u8, 28u8, 232u8, 5u8, 232u8,
+                5u8, 232u8, 5u8, 232u8, 5u8, 246u8, 5u8, 16u8, 28u8, 232u8, 5u8, 32u8, 28u8, 168u8,
+                5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 48u8, 28u8, 123u8, 6u8, 64u8, 28u8, 80u8,
+                28u8, 123u8, 6u8, 123u8, 6u8, 123u8, 6u8, 96u8, 28u8, 108u8, 28u8, 87u8, 19u8,
+                87u8, 19u8, 34u8, 26u8, 123u8, 6u8, 123u8, 6u8, 123u8, 6u8, 123u8, 6u8, 124u8,
+                28u8, 140u8, 28u8, 192u8, 15u8, 36u8, 26u8, 87u8, 19u8, 156u8, 28u8, 87u8, 19u8,
+                159u8, 28u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 175u8, 28u8,
+                190u8, 28u8, 205u8, 28u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 179u8, 25u8, 221u8,
+                28u8, 237u8, 28u8, 249u8, 28u8, 0u8, 29u8, 15u8, 29u8, 27u8, 29u8, 43u8, 29u8,
+                56u8, 29u8, 36u8, 26u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8,
+                26u8, 5u8, 26u8, 72u8, 29u8, 201u8, 27u8, 213u8, 11u8, 87u8, 19u8, 87u8, 19u8,
+                87u8, 19u8, 87u8, 19u8, 33u8, 26u8, 221u8, 28u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 97u8, 19u8, 88u8, 29u8, 98u8,
+                29u8, 213u8, 11u8, 213u8, 11u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8,
+                36u8, 26u8, 221u8, 28u8, 8u8, 26u8, 213u8, 11u8, 123u8, 6u8, 123u8, 6u8, 123u8,
+                6u8, 114u8, 29u8, 80u8, 28u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 210u8, 23u8,
+                130u8, 29u8, 143u8, 29u8, 210u8, 23u8, 159u8, 29u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 89u8, 16u8, 89u8, 16u8, 89u8, 16u8, 175u8,
+                29u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 191u8, 29u8, 207u8,
+                29u8, 219u8, 29u8, 183u8, 27u8, 229u8, 29u8, 240u8, 29u8, 0u8, 30u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 16u8, 30u8, 249u8, 27u8, 249u8, 27u8, 16u8,
+                30u8, 27u8, 30u8, 213u8, 11u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8,
+                51u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 67u8, 30u8, 73u8, 30u8, 87u8, 30u8,
+                24u8, 6u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 103u8, 30u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 119u8, 30u8, 5u8, 26u8, 5u8, 26u8, 121u8, 30u8, 131u8,
+                30u8, 5u8, 26u8, 8u8, 26u8, 5u8, 26u8, 5u8, 26u8, 145u8, 30u8, 120u8, 30u8, 130u8,
+                30u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 161u8, 30u8, 43u8, 30u8,
+                43u8, 30u8, 172u8, 30u8, 51u8, 30u8, 49u8, 30u8, 188u8, 30u8, 89u8, 16u8, 90u8,
+                16u8, 195u8, 30u8, 167u8, 27u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 89u8, 16u8, 211u8, 30u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 227u8, 30u8, 249u8, 27u8,
+                249u8, 27u8, 249u8, 27u8, 243u8, 30u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 3u8, 31u8, 87u8, 19u8, 87u8, 19u8, 18u8, 31u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 104u8, 5u8, 104u8, 5u8, 104u8,
+                5u8, 104u8, 5u8, 104u8, 5u8, 104u8, 5u8, 34u8, 31u8, 50u8, 31u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 66u8, 31u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8,
+                82u8, 31u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8,
+                168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 199u8, 5u8, 0u8, 28u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 98u8,
+                31u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8,
+                5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8,
+                5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8,
+                5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8,
+                5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8,
+                5u8, 114u8, 31u8, 87u8, 19u8, 88u8, 19u8, 130u8, 31u8, 24u8, 6u8, 24u8, 6u8, 24u8,
+                6u8, 24u8, 6u8, 25u8, 6u8, 217u8, 27u8, 87u8, 19u8, 89u8, 19u8, 35u8, 26u8, 87u8,
+                19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 35u8, 26u8, 255u8, 28u8, 156u8, 28u8,
+                144u8, 31u8, 87u8, 19u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 89u8, 16u8, 89u8, 16u8, 89u8, 16u8, 89u8, 16u8,
+                89u8, 16u8, 160u8, 31u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 123u8, 6u8, 123u8, 6u8, 123u8, 6u8, 123u8, 6u8, 176u8, 31u8,
+                123u8, 6u8, 123u8, 6u8, 192u8, 31u8, 207u8, 31u8, 123u8, 6u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 223u8, 31u8, 201u8, 27u8, 5u8, 26u8, 5u8, 26u8,
+                5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8,
+                26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8,
+                5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8,
+                26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8,
+                239u8, 31u8, 253u8, 31u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8,
+                5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8,
+                26u8, 5u8, 26u8, 5u8, 26u8, 13u8, 32u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 23u8, 32u8, 213u8, 11u8, 213u8, 11u8, 20u8, 32u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 82u8, 26u8, 39u8,
+                32u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 41u8, 32u8, 82u8, 31u8, 213u8, 11u8, 213u8, 11u8,
+                57u8, 32u8, 69u8, 32u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8,
+                43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8,
+                43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 47u8, 30u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8,
+                19u8, 179u8, 25u8, 85u8, 32u8, 103u8, 30u8, 93u8, 19u8, 94u8, 32u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 24u8, 6u8, 24u8, 6u8, 110u8, 32u8, 24u8, 6u8, 159u8,
+                29u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8, 24u8, 6u8,
+                120u8, 32u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 136u8, 32u8, 133u8, 24u8, 133u8, 24u8, 152u8, 32u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 162u8, 32u8, 176u8, 32u8, 213u8, 11u8,
+                248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 248u8, 4u8, 221u8, 25u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 89u8, 16u8, 192u8, 32u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8,
+                56u8, 1u8, 113u8, 15u8, 104u8, 5u8, 208u8, 32u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 224u8, 32u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 230u8, 32u8, 246u8, 32u8, 4u8, 33u8, 19u8, 33u8, 133u8,
+                24u8, 133u8, 24u8, 133u8, 24u8, 29u8, 33u8, 42u8, 33u8, 133u8, 24u8, 25u8, 33u8,
+                56u8, 33u8, 71u8, 33u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8,
+                24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 83u8, 33u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 91u8, 33u8, 133u8,
+                24u8, 133u8, 24u8, 133u8, 24u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 143u8, 29u8, 87u8, 31u8, 209u8,
+                23u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 24u8, 6u8, 25u8, 6u8, 213u8, 11u8, 213u8,
+                11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                121u8, 30u8, 107u8, 33u8, 121u8, 33u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 249u8, 27u8, 249u8, 27u8,
+                252u8, 27u8, 251u8, 27u8, 137u8, 33u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 24u8, 6u8, 25u8, 6u8, 213u8, 11u8, 249u8, 27u8, 249u8,
+                27u8, 249u8, 27u8, 151u8, 33u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 57u8, 26u8, 25u8, 6u8, 87u8, 19u8, 87u8, 19u8, 87u8,
+                19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8,
+                19u8, 87u8, 19u8, 87u8, 19u8, 98u8, 19u8, 34u8, 26u8, 213u8, 11u8, 213u8, 11u8,
+                5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 5u8, 26u8, 167u8, 33u8, 11u8, 26u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 88u8, 16u8, 89u8, 16u8, 89u8, 16u8, 89u8,
+                16u8, 183u8, 33u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 248u8, 27u8,
+                249u8, 27u8, 249u8, 27u8, 251u8, 27u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 199u8, 33u8, 123u8, 6u8, 215u8, 33u8, 228u8,
+                33u8, 242u8, 33u8, 2u8, 34u8, 16u8, 34u8, 24u8, 34u8, 40u8, 34u8, 51u8, 34u8, 66u8,
+                34u8, 51u8, 34u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 86u8, 12u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 242u8, 2u8, 242u8, 2u8, 244u8, 21u8, 242u8,
+                2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 242u8, 2u8, 247u8, 20u8,
+                245u8, 5u8, 82u8, 34u8, 231u8, 5u8, 231u8, 5u8, 87u8, 19u8, 35u8, 26u8, 98u8, 34u8,
+                168u8, 5u8, 114u8, 34u8, 130u8, 34u8, 146u8, 34u8, 161u8, 34u8, 177u8, 34u8, 193u8,
+                34u8, 209u8, 34u8, 222u8, 34u8, 233u8, 34u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                12u8, 28u8, 232u8, 5u8, 58u8, 13u8, 168u8, 5u8, 168u8, 5u8, 249u8, 34u8, 103u8,
+                27u8, 59u8, 13u8, 9u8, 35u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 232u8, 5u8, 232u8,
+                5u8, 25u8, 35u8, 41u8, 35u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 48u8, 35u8, 232u8,
+                5u8, 64u8, 35u8, 232u8, 5u8, 232u8, 5u8, 80u8, 35u8, 95u8, 35u8, 232u8, 5u8, 111u8,
+                35u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 127u8, 35u8, 141u8, 35u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 149u8, 35u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 165u8,
+                35u8, 181u8, 35u8, 232u8, 5u8, 197u8, 35u8, 205u8, 35u8, 87u8, 19u8, 87u8, 19u8,
+                211u8, 35u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 92u8, 22u8, 227u8,
+                35u8, 242u8, 35u8, 2u8, 36u8, 18u8, 36u8, 33u8, 36u8, 87u8, 19u8, 87u8, 19u8, 87u8,
+                19u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 199u8, 35u8, 49u8, 36u8,
+                85u8, 32u8, 65u8, 36u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 81u8, 36u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8, 87u8, 19u8,
+                87u8, 19u8, 97u8, 36u8, 253u8, 27u8, 107u8, 17u8, 115u8, 25u8, 87u8, 19u8, 87u8,
+                19u8, 87u8, 19u8, 33u8, 26u8, 221u8, 28u8, 87u8, 19u8, 87u8, 19u8, 33u8, 26u8,
+                87u8, 19u8, 89u8, 19u8, 201u8, 27u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8,
+                11u8, 113u8, 36u8, 129u8, 36u8, 145u8, 36u8, 161u8, 36u8, 177u8, 36u8, 193u8, 36u8,
+                208u8, 36u8, 223u8, 36u8, 239u8, 36u8, 253u8, 36u8, 10u8, 37u8, 26u8, 37u8, 42u8,
+                37u8, 43u8, 30u8, 58u8, 37u8, 89u8, 16u8, 249u8, 27u8, 249u8, 27u8, 249u8, 27u8,
+                249u8, 27u8, 249u8, 27u8, 74u8, 37u8, 91u8, 16u8, 90u8, 37u8, 106u8, 37u8, 122u8,
+                37u8, 129u8, 37u8, 131u8, 37u8, 147u8, 37u8, 163u8, 37u8, 116u8, 32u8, 159u8, 29u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 179u8, 37u8, 183u8, 27u8, 183u8, 27u8, 18u8,
+                32u8, 213u8, 11u8, 213u8, 11u8, 0u8, 30u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 106u8, 12u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                133u8, 24u8, 195u8, 37u8, 213u8, 11u8, 213u8, 11u8, 168u8, 5u8, 168u8, 5u8, 168u8,
+                5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8,
+                5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8, 5u8, 168u8,
+                5u8, 168u8, 5u8, 168u8, 5u8, 211u8, 37u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8, 232u8, 5u8,
+                232u8, 5u8, 232u8, 5u8, 246u8, 5u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8,
+                23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8, 210u8, 23u8,
+                210u8, 23u8, 210u8, 23u8, 227u8, 37u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8, 30u8, 43u8,
+                30u8, 243u8, 37u8, 213u8, 11u8, 133u8, 24u8, 3u8, 38u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8,
+                183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 183u8, 27u8, 18u8,
+                32u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 18u8, 38u8, 213u8,
+                11u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8, 133u8, 24u8,
+                213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8, 213u8, 11u8,
+                213u8, 11u8, 213u8, 11u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8,
+                56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8, 1u8, 56u8,
+                1u8, 56u8, 1u8, 56u8, 1u8, 213u8, 11u8, 124u8, 0u8, 156u8, 0u8, 188u8, 0u8, 220u8,
+                0u8, 252u8, 0u8, 28u8, 1u8, 60u8, 1u8, 92u8, 1u8, 124u8, 1u8, 156u8, 1u8, 188u8,
+                1u8, 213u8, 1u8, 245u8, 1u8, 21u8, 2u8, 53u8, 2u8, 85u8, 2u8, 117u8, 2u8, 149u8,
+                2u8, 181u8, 2u8, 213u8, 2u8, 245u8, 2u8, 5u8, 3u8, 37u8, 3u8, 69u8, 3u8, 101u8,
+                3u8, 133u8, 3u8, 165u8, 3u8, 165u8, 3u8, 165u8, 3u8, 165u8, 3u8, 165u8, 3u8, 165u8,
+                3u8, 170u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8,
+                3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8,
+                3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8,
+                3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 208u8, 3u8, 165u8,
+                3u8, 165u8, 3u8, 240u8, 3u8, 14u8, 4u8, 46u8, 4u8, 78u8, 4u8, 110u8, 4u8, 110u8,
+                4u8, 110u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8,
+                4u8, 110u8, 4u8, 110u8, 4u8, 116u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8, 4u8, 110u8,
+                4u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8,
+                3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 202u8, 3u8, 148u8,
+                4u8, 178u8, 4u8, 210u8, 4u8, 242u8, 4u8, 18u8, 5u8, 50u8, 5u8, 82u8, 5u8, 114u8,
+                5u8, 146u8, 5u8, 178u8, 5u8, 204u8, 5u8, 236u8, 5u8, 12u8, 6u8, 44u8, 6u8, 76u8,
+                6u8, 108u8, 6u8, 140u8, 6u8, 172u8, 6u8, 199u8, 6u8, 231u8, 6u8, 241u8, 6u8, 17u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 45u8, 7u8, 77u8, 7u8, 77u8, 7u8,
+                107u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 139u8, 7u8,
+                167u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 199u8, 7u8, 228u8, 7u8, 38u8, 7u8, 0u8, 8u8, 32u8, 8u8, 32u8,
+                8u8, 32u8, 8u8, 32u8, 8u8, 32u8, 8u8, 32u8, 8u8, 32u8, 8u8, 32u8, 8u8, 34u8, 8u8,
+                32u8, 8u8, 66u8, 8u8, 85u8, 8u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 102u8, 8u8, 134u8, 8u8, 157u8, 8u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 189u8, 8u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 205u8, 8u8, 237u8, 8u8, 13u8, 9u8,
+                45u8, 9u8, 67u8, 9u8, 139u8, 7u8, 99u8, 9u8, 38u8, 7u8, 115u8, 9u8, 147u8, 9u8,
+                170u8, 9u8, 38u8, 7u8, 186u8, 9u8, 218u8, 9u8, 38u8, 7u8, 243u8, 9u8, 19u8, 10u8,
+                51u8, 10u8, 83u8, 10u8, 115u8, 10u8, 147u8, 10u8, 179u8, 10u8, 211u8, 10u8, 38u8,
+                7u8, 243u8, 10u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8,
+                11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8,
+                11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8,
+                11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8,
+                11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 19u8, 11u8, 38u8, 11u8, 77u8,
+                7u8, 77u8, 7u8, 77u8, 7u8, 77u8, 7u8, 77u8, 7u8, 77u8, 7u8, 77u8, 7u8, 54u8, 11u8,
+                85u8, 11u8, 139u8, 7u8, 139u8, 7u8, 139u8, 7u8, 139u8, 7u8, 139u8, 7u8, 139u8, 7u8,
+                139u8, 7u8, 107u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8,
+                11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8, 118u8, 11u8,
+                118u8, 11u8, 118u8, 11u8, 120u8, 11u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 19u8, 11u8, 152u8, 11u8, 38u8, 7u8, 243u8, 10u8, 184u8, 11u8,
+                184u8, 11u8, 184u8, 11u8, 184u8, 11u8, 184u8, 11u8, 184u8, 11u8, 184u8, 11u8,
+                184u8, 11u8, 184u8, 11u8, 196u8, 11u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 243u8, 10u8, 228u8,
+                11u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8,
+                38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8, 7u8, 38u8,
+                7u8, 38u8, 7u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 4u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 2u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 1u8, 1u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 3u8, 0u8,
+                1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 2u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8,
+                3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 5u8, 0u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 1u8, 4u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 11u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 11u8, 0u8,
+                1u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 6u8, 1u8, 0u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 4u8, 1u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 5u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 2u8, 0u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 6u8, 1u8,
+                7u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 4u8, 1u8, 1u8, 1u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 1u8, 1u8, 6u8, 3u8,
+                14u8, 0u8, 4u8, 1u8, 1u8, 1u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 4u8, 0u8, 4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 4u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 6u8, 1u8, 7u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                14u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 11u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 8u8, 0u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 7u8, 0u8, 5u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 6u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 2u8, 6u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 0u8, 5u8, 0u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 7u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8,
+                2u8, 10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 0u8, 0u8, 4u8, 0u8, 1u8, 1u8, 4u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 11u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 1u8, 4u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 4u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 11u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 7u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 14u8, 0u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 12u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 9u8, 0u8, 7u8, 0u8, 1u8, 1u8, 1u8, 1u8, 11u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 7u8, 0u8, 1u8, 1u8, 1u8, 1u8, 13u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 10u8, 0u8, 10u8, 0u8, 5u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 1u8, 1u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 8u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 13u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 0u8, 0u8, 12u8, 0u8, 1u8, 1u8, 1u8, 1u8, 12u8, 0u8, 1u8, 1u8, 0u8, 0u8, 12u8,
+                0u8, 1u8, 1u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 12u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 12u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 12u8, 0u8, 12u8, 0u8, 1u8, 1u8, 1u8, 1u8,
+                12u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 12u8, 0u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 3u8, 0u8, 5u8, 1u8,
+                5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 3u8, 0u8, 2u8, 0u8,
+                0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                3u8, 0u8, 2u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8, 1u8, 3u8, 0u8, 4u8, 1u8, 4u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 0u8,
+                6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                5u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 1u8, 3u8, 0u8,
+                3u8, 0u8, 5u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 1u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 4u8, 1u8, 4u8, 1u8,
+                1u8, 1u8, 4u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 1u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 1u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 4u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 3u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 14u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 0u8, 0u8, 3u8, 2u8, 3u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 14u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 10u8, 0u8, 7u8, 0u8, 7u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 14u8, 0u8,
+                9u8, 0u8, 6u8, 0u8, 5u8, 2u8, 5u8, 0u8, 5u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                2u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8,
+                1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 1u8, 4u8, 1u8, 3u8, 2u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 5u8, 1u8, 0u8, 0u8,
+                6u8, 3u8, 6u8, 3u8, 6u8, 3u8, 6u8, 3u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 2u8, 0u8, 2u8, 1u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 6u8, 0u8, 6u8, 2u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8,
+                10u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 4u8, 0u8, 4u8, 1u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 5u8, 0u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 2u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 2u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 10u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8, 4u8, 0u8, 3u8, 2u8, 3u8, 2u8, 4u8, 1u8,
+                3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 4u8, 1u8, 4u8, 1u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 4u8, 1u8, 4u8, 1u8, 5u8, 0u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 7u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 1u8, 1u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 1u8, 1u8, 5u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 6u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 6u8, 1u8, 5u8, 1u8,
+                6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 0u8, 0u8, 13u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 12u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                10u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 14u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8,
+                2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 1u8, 10u8, 0u8, 11u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8, 4u8, 0u8,
+                0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 4u8, 0u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8, 4u8, 0u8, 4u8, 1u8,
+                1u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 12u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 4u8, 1u8, 4u8,
+                1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 1u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 2u8, 3u8, 2u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 2u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8,
+                6u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 6u8, 0u8, 6u8, 0u8, 8u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 6u8, 1u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 9u8, 0u8, 11u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 14u8, 0u8, 14u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 7u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 0u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8,
+                1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 13u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 8u8, 0u8, 8u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8,
+                0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 7u8, 0u8, 7u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 6u8, 1u8, 6u8, 1u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8,
+                2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8,
+                2u8, 4u8, 0u8, 14u8, 0u8, 14u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 3u8, 2u8, 3u8, 2u8, 4u8, 0u8, 4u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8,
+                2u8, 1u8, 1u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 2u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8,
+                1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 7u8, 0u8, 7u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 13u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8,
+                1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 7u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8,
+                0u8, 4u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8,
+                1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 4u8, 0u8, 4u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 4u8, 0u8, 4u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8, 4u8, 0u8, 4u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 1u8, 5u8, 1u8,
+                5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8, 5u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 6u8, 1u8, 6u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8,
+                5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+                0u8, 5u8, 2u8, 5u8, 2u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 7u8, 0u8, 13u8, 0u8, 13u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 7u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 9u8, 0u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8,
+                0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 0u8, 0u8, 11u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 13u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 11u8, 0u8, 12u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 12u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8,
+                0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 14u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 5u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8,
+                0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 4u8, 0u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 0u8, 0u8, 3u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 4u8, 1u8,
+                4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+                1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8,
+                0u8, 6u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8,
+                0u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8,
+                1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8,
+                0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 1u8, 6u8,
+                1u8, 6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 7u8, 0u8, 7u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 11u8, 0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 5u8, 2u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 5u8, 2u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 6u8, 1u8, 12u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 5u8, 2u8, 5u8, 2u8, 6u8,
+                0u8, 6u8, 0u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8,
+                2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 13u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 2u8, 5u8, 2u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8,
+                0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 7u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                7u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 6u8, 1u8,
+                6u8, 1u8, 6u8, 1u8, 6u8, 1u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 9u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8,
+                7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 1u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 1u8, 6u8, 0u8, 6u8, 1u8, 6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 1u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 7u8, 0u8, 7u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8,
+                6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 8u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                12u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 12u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 6u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8, 0u8, 7u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 13u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 12u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 10u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8,
+                9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8,
+                10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 13u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 13u8, 0u8, 13u8, 0u8, 14u8, 0u8, 11u8, 0u8, 12u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8,
+                0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 9u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 13u8, 0u8, 13u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8,
+                0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 8u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 13u8, 0u8, 14u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8, 0u8, 10u8,
+                0u8, 10u8, 0u8, 10u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8,
+                11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 0u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8,
+                0u8, 13u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8,
+                1u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8, 13u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 5u8, 2u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 2u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::Age {
+            major: 0u8,
+            minor: 0u8,
+        },
+    ),
+);
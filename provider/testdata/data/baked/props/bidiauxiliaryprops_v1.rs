@@ -0,0 +1,383 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: BidiAuxiliaryPropertiesV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::BidiAuxiliaryPropertiesV1 {
+    trie: ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 65536u32,
+            shifted12_high_start: 16u16,
+            index3_null_offset: 3u16,
+            data_null_offset: 186u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 126u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 192u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 138u8, 1u8, 163u8, 1u8, 163u8, 1u8, 164u8, 1u8, 0u8, 0u8, 16u8, 0u8,
+                32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 126u8, 0u8,
+                142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8,
+                186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8,
+                234u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 202u8, 0u8,
+                218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8, 186u8, 0u8,
+                202u8, 0u8, 218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8,
+                192u8, 0u8, 208u8, 0u8, 224u8, 0u8, 240u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8,
+                234u8, 0u8, 186u8, 0u8, 202u8, 0u8, 218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 202u8, 0u8,
+                218u8, 0u8, 234u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 254u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 11u8, 1u8,
+                22u8, 1u8, 186u8, 0u8, 186u8, 0u8, 29u8, 1u8, 44u8, 1u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                59u8, 1u8, 73u8, 1u8, 89u8, 1u8, 94u8, 1u8, 108u8, 1u8, 122u8, 1u8, 134u8, 1u8,
+                150u8, 1u8, 166u8, 1u8, 182u8, 1u8, 196u8, 1u8, 212u8, 1u8, 221u8, 1u8, 237u8, 1u8,
+                253u8, 1u8, 13u8, 2u8, 28u8, 2u8, 186u8, 0u8, 40u8, 2u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 51u8, 2u8,
+                67u8, 2u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 80u8, 2u8, 94u8, 2u8,
+                109u8, 2u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 125u8, 2u8, 141u8, 2u8, 157u8, 2u8,
+                173u8, 2u8, 189u8, 2u8, 205u8, 2u8, 217u8, 2u8, 228u8, 2u8, 186u8, 0u8, 186u8, 0u8,
+                242u8, 2u8, 1u8, 3u8, 186u8, 0u8, 186u8, 0u8, 15u8, 3u8, 22u8, 3u8, 38u8, 3u8,
+                54u8, 3u8, 70u8, 3u8, 86u8, 3u8, 102u8, 3u8, 118u8, 3u8, 133u8, 3u8, 148u8, 3u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 159u8, 3u8, 174u8, 3u8, 188u8, 3u8, 204u8, 3u8, 186u8, 0u8, 186u8, 0u8,
+                215u8, 3u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 228u8, 3u8,
+                244u8, 3u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 0u8, 4u8, 15u8, 4u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                23u8, 4u8, 33u8, 4u8, 186u8, 0u8, 48u8, 4u8, 186u8, 0u8, 62u8, 4u8, 78u8, 4u8,
+                186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8, 186u8, 0u8,
+                186u8, 0u8, 186u8, 0u8, 68u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 96u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 128u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 157u8, 0u8, 189u8, 0u8, 3u8, 0u8, 208u8, 0u8,
+                239u8, 0u8, 15u8, 1u8, 3u8, 0u8, 47u8, 1u8, 79u8, 1u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 106u8, 1u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                41u8, 0u8, 32u8, 0u8, 40u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                62u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 93u8, 0u8, 32u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 91u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 125u8, 0u8, 32u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 123u8, 0u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 187u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 59u8, 15u8, 32u8, 0u8, 58u8, 15u8, 64u8,
+                0u8, 61u8, 15u8, 32u8, 0u8, 60u8, 15u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 156u8, 22u8, 32u8, 0u8, 155u8, 22u8, 64u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 58u8, 32u8, 0u8, 0u8, 57u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 70u8,
+                32u8, 32u8, 0u8, 69u8, 32u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 126u8, 32u8, 32u8, 0u8,
+                125u8, 32u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 142u8, 32u8, 32u8, 0u8, 141u8, 32u8, 64u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 11u8, 34u8, 0u8, 0u8, 12u8, 34u8, 0u8, 0u8, 13u8, 34u8, 0u8, 0u8, 8u8, 34u8,
+                0u8, 0u8, 9u8, 34u8, 0u8, 0u8, 10u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 245u8, 41u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 254u8, 43u8, 0u8, 0u8, 163u8, 41u8, 0u8, 0u8, 155u8,
+                41u8, 0u8, 0u8, 160u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 238u8, 42u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                61u8, 34u8, 0u8, 0u8, 60u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 205u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 76u8, 34u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 69u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 83u8, 34u8, 0u8, 0u8, 82u8, 34u8, 0u8,
+                0u8, 85u8, 34u8, 0u8, 0u8, 84u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 101u8, 34u8, 0u8, 0u8, 100u8, 34u8, 0u8, 0u8, 103u8, 34u8, 0u8, 0u8, 102u8,
+                34u8, 0u8, 0u8, 105u8, 34u8, 0u8, 0u8, 104u8, 34u8, 0u8, 0u8, 107u8, 34u8, 0u8,
+                0u8, 106u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 111u8, 34u8,
+                0u8, 0u8, 110u8, 34u8, 0u8, 0u8, 113u8, 34u8, 0u8, 0u8, 112u8, 34u8, 0u8, 0u8,
+                115u8, 34u8, 0u8, 0u8, 114u8, 34u8, 0u8, 0u8, 117u8, 34u8, 0u8, 0u8, 116u8, 34u8,
+                0u8, 0u8, 119u8, 34u8, 0u8, 0u8, 118u8, 34u8, 0u8, 0u8, 121u8, 34u8, 0u8, 0u8,
+                120u8, 34u8, 0u8, 0u8, 123u8, 34u8, 0u8, 0u8, 122u8, 34u8, 0u8, 0u8, 125u8, 34u8,
+                0u8, 0u8, 124u8, 34u8, 0u8, 0u8, 127u8, 34u8, 0u8, 0u8, 126u8, 34u8, 0u8, 0u8,
+                129u8, 34u8, 0u8, 0u8, 128u8, 34u8, 0u8, 0u8, 131u8, 34u8, 0u8, 0u8, 130u8, 34u8,
+                0u8, 0u8, 133u8, 34u8, 0u8, 0u8, 132u8, 34u8, 0u8, 0u8, 135u8, 34u8, 0u8, 0u8,
+                134u8, 34u8, 0u8, 0u8, 137u8, 34u8, 0u8, 0u8, 136u8, 34u8, 0u8, 0u8, 139u8, 34u8,
+                0u8, 0u8, 138u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 144u8, 34u8, 0u8, 0u8, 143u8, 34u8, 0u8, 0u8, 146u8, 34u8, 0u8, 0u8,
+                145u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 184u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 163u8, 34u8, 0u8, 0u8, 162u8, 34u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 222u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 228u8, 42u8, 0u8, 0u8, 227u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 229u8, 42u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 177u8, 34u8, 0u8, 0u8, 176u8, 34u8, 0u8, 0u8, 179u8, 34u8, 0u8, 0u8,
+                178u8, 34u8, 0u8, 0u8, 181u8, 34u8, 0u8, 0u8, 180u8, 34u8, 0u8, 0u8, 183u8, 34u8,
+                0u8, 0u8, 182u8, 34u8, 0u8, 0u8, 220u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 202u8,
+                34u8, 0u8, 0u8, 201u8, 34u8, 0u8, 0u8, 204u8, 34u8, 0u8, 0u8, 203u8, 34u8, 0u8,
+                0u8, 67u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 34u8,
+                0u8, 0u8, 208u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 215u8, 34u8, 0u8, 0u8, 214u8, 34u8, 0u8, 0u8, 217u8,
+                34u8, 0u8, 0u8, 216u8, 34u8, 0u8, 0u8, 219u8, 34u8, 0u8, 0u8, 218u8, 34u8, 0u8,
+                0u8, 221u8, 34u8, 0u8, 0u8, 220u8, 34u8, 0u8, 0u8, 223u8, 34u8, 0u8, 0u8, 222u8,
+                34u8, 0u8, 0u8, 225u8, 34u8, 0u8, 0u8, 224u8, 34u8, 0u8, 0u8, 227u8, 34u8, 0u8,
+                0u8, 226u8, 34u8, 0u8, 0u8, 229u8, 34u8, 0u8, 0u8, 228u8, 34u8, 0u8, 0u8, 231u8,
+                34u8, 0u8, 0u8, 230u8, 34u8, 0u8, 0u8, 233u8, 34u8, 0u8, 0u8, 232u8, 34u8, 0u8,
+                0u8, 235u8, 34u8, 0u8, 0u8, 234u8, 34u8, 0u8, 0u8, 237u8, 34u8, 0u8, 0u8, 236u8,
+                34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 241u8, 34u8, 0u8, 0u8,
+                240u8, 34u8, 0u8, 0u8, 250u8, 34u8, 0u8, 0u8, 251u8, 34u8, 0u8, 0u8, 252u8, 34u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 253u8, 34u8, 0u8, 0u8, 254u8, 34u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 242u8, 34u8, 0u8, 0u8, 243u8, 34u8, 0u8, 0u8,
+                244u8, 34u8, 0u8, 0u8, 246u8, 34u8, 0u8, 0u8, 247u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 35u8, 32u8,
+                0u8, 8u8, 35u8, 64u8, 0u8, 11u8, 35u8, 32u8, 0u8, 10u8, 35u8, 64u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 42u8, 35u8, 32u8, 0u8, 41u8, 35u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 39u8, 32u8, 0u8,
+                104u8, 39u8, 64u8, 0u8, 107u8, 39u8, 32u8, 0u8, 106u8, 39u8, 64u8, 0u8, 109u8,
+                39u8, 32u8, 0u8, 108u8, 39u8, 64u8, 0u8, 111u8, 39u8, 32u8, 0u8, 110u8, 39u8, 64u8,
+                0u8, 113u8, 39u8, 32u8, 0u8, 112u8, 39u8, 64u8, 0u8, 115u8, 39u8, 32u8, 0u8, 114u8,
+                39u8, 64u8, 0u8, 117u8, 39u8, 32u8, 0u8, 116u8, 39u8, 64u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 196u8, 39u8, 0u8, 0u8, 195u8, 39u8, 0u8, 0u8, 198u8, 39u8,
+                32u8, 0u8, 197u8, 39u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 201u8, 39u8, 0u8, 0u8,
+                200u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 203u8, 39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 214u8, 39u8, 0u8, 0u8, 213u8, 39u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 184u8, 34u8, 0u8, 0u8, 222u8, 39u8, 0u8, 0u8, 221u8,
+                39u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 227u8, 39u8, 0u8, 0u8,
+                226u8, 39u8, 0u8, 0u8, 229u8, 39u8, 0u8, 0u8, 228u8, 39u8, 0u8, 0u8, 231u8, 39u8,
+                32u8, 0u8, 230u8, 39u8, 64u8, 0u8, 233u8, 39u8, 32u8, 0u8, 232u8, 39u8, 64u8, 0u8,
+                235u8, 39u8, 32u8, 0u8, 234u8, 39u8, 64u8, 0u8, 237u8, 39u8, 32u8, 0u8, 236u8,
+                39u8, 64u8, 0u8, 239u8, 39u8, 32u8, 0u8, 238u8, 39u8, 64u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 132u8, 41u8, 32u8, 0u8, 131u8, 41u8,
+                64u8, 0u8, 134u8, 41u8, 32u8, 0u8, 133u8, 41u8, 64u8, 0u8, 136u8, 41u8, 32u8, 0u8,
+                135u8, 41u8, 64u8, 0u8, 138u8, 41u8, 32u8, 0u8, 137u8, 41u8, 64u8, 0u8, 140u8,
+                41u8, 32u8, 0u8, 139u8, 41u8, 64u8, 0u8, 144u8, 41u8, 32u8, 0u8, 143u8, 41u8, 64u8,
+                0u8, 142u8, 41u8, 32u8, 0u8, 141u8, 41u8, 64u8, 0u8, 146u8, 41u8, 32u8, 0u8, 145u8,
+                41u8, 64u8, 0u8, 148u8, 41u8, 32u8, 0u8, 147u8, 41u8, 64u8, 0u8, 150u8, 41u8, 32u8,
+                0u8, 149u8, 41u8, 64u8, 0u8, 152u8, 41u8, 32u8, 0u8, 151u8, 41u8, 64u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 33u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 34u8, 0u8, 0u8, 165u8, 41u8, 0u8, 0u8,
+                164u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 169u8, 41u8, 0u8,
+                0u8, 168u8, 41u8, 0u8, 0u8, 171u8, 41u8, 0u8, 0u8, 170u8, 41u8, 0u8, 0u8, 173u8,
+                41u8, 0u8, 0u8, 172u8, 41u8, 0u8, 0u8, 175u8, 41u8, 0u8, 0u8, 174u8, 41u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 152u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 193u8, 41u8, 0u8, 0u8, 192u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 197u8, 41u8, 0u8, 0u8, 196u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8,
+                41u8, 0u8, 0u8, 207u8, 41u8, 0u8, 0u8, 210u8, 41u8, 0u8, 0u8, 209u8, 41u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 213u8, 41u8, 0u8, 0u8, 212u8, 41u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 217u8, 41u8, 32u8, 0u8, 216u8, 41u8, 64u8, 0u8,
+                219u8, 41u8, 32u8, 0u8, 218u8, 41u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 233u8, 41u8, 0u8, 0u8, 232u8, 41u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 34u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 249u8, 41u8, 0u8, 0u8, 248u8, 41u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 253u8, 41u8, 32u8, 0u8, 252u8, 41u8, 64u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 44u8, 42u8, 0u8, 0u8,
+                43u8, 42u8, 0u8, 0u8, 46u8, 42u8, 0u8, 0u8, 45u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 53u8, 42u8, 0u8,
+                0u8, 52u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 42u8, 0u8,
+                0u8, 60u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 101u8, 42u8, 0u8, 0u8, 100u8, 42u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 122u8, 42u8, 0u8, 0u8, 121u8, 42u8, 0u8, 0u8, 124u8,
+                42u8, 0u8, 0u8, 123u8, 42u8, 0u8, 0u8, 126u8, 42u8, 0u8, 0u8, 125u8, 42u8, 0u8,
+                0u8, 128u8, 42u8, 0u8, 0u8, 127u8, 42u8, 0u8, 0u8, 130u8, 42u8, 0u8, 0u8, 129u8,
+                42u8, 0u8, 0u8, 132u8, 42u8, 0u8, 0u8, 131u8, 42u8, 0u8, 0u8, 134u8, 42u8, 0u8,
+                0u8, 133u8, 42u8, 0u8, 0u8, 136u8, 42u8, 0u8, 0u8, 135u8, 42u8, 0u8, 0u8, 138u8,
+                42u8, 0u8, 0u8, 137u8, 42u8, 0u8, 0u8, 140u8, 42u8, 0u8, 0u8, 139u8, 42u8, 0u8,
+                0u8, 142u8, 42u8, 0u8, 0u8, 141u8, 42u8, 0u8, 0u8, 144u8, 42u8, 0u8, 0u8, 143u8,
+                42u8, 0u8, 0u8, 146u8, 42u8, 0u8, 0u8, 145u8, 42u8, 0u8, 0u8, 148u8, 42u8, 0u8,
+                0u8, 147u8, 42u8, 0u8, 0u8, 150u8, 42u8, 0u8, 0u8, 149u8, 42u8, 0u8, 0u8, 152u8,
+                42u8, 0u8, 0u8, 151u8, 42u8, 0u8, 0u8, 154u8, 42u8, 0u8, 0u8, 153u8, 42u8, 0u8,
+                0u8, 156u8, 42u8, 0u8, 0u8, 155u8, 42u8, 0u8, 0u8, 158u8, 42u8, 0u8, 0u8, 157u8,
+                42u8, 0u8, 0u8, 160u8, 42u8, 0u8, 0u8, 159u8, 42u8, 0u8, 0u8, 162u8, 42u8, 0u8,
+                0u8, 161u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 167u8, 42u8, 0u8, 0u8, 166u8, 42u8, 0u8, 0u8, 169u8, 42u8, 0u8, 0u8, 168u8,
+                42u8, 0u8, 0u8, 171u8, 42u8, 0u8, 0u8, 170u8, 42u8, 0u8, 0u8, 173u8, 42u8, 0u8,
+                0u8, 172u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 176u8, 42u8, 0u8, 0u8, 175u8, 42u8,
+                0u8, 0u8, 178u8, 42u8, 0u8, 0u8, 177u8, 42u8, 0u8, 0u8, 180u8, 42u8, 0u8, 0u8,
+                179u8, 42u8, 0u8, 0u8, 182u8, 42u8, 0u8, 0u8, 181u8, 42u8, 0u8, 0u8, 184u8, 42u8,
+                0u8, 0u8, 183u8, 42u8, 0u8, 0u8, 186u8, 42u8, 0u8, 0u8, 185u8, 42u8, 0u8, 0u8,
+                188u8, 42u8, 0u8, 0u8, 187u8, 42u8, 0u8, 0u8, 190u8, 42u8, 0u8, 0u8, 189u8, 42u8,
+                0u8, 0u8, 192u8, 42u8, 0u8, 0u8, 191u8, 42u8, 0u8, 0u8, 194u8, 42u8, 0u8, 0u8,
+                193u8, 42u8, 0u8, 0u8, 196u8, 42u8, 0u8, 0u8, 195u8, 42u8, 0u8, 0u8, 198u8, 42u8,
+                0u8, 0u8, 197u8, 42u8, 0u8, 0u8, 200u8, 42u8, 0u8, 0u8, 199u8, 42u8, 0u8, 0u8,
+                202u8, 42u8, 0u8, 0u8, 201u8, 42u8, 0u8, 0u8, 204u8, 42u8, 0u8, 0u8, 203u8, 42u8,
+                0u8, 0u8, 206u8, 42u8, 0u8, 0u8, 205u8, 42u8, 0u8, 0u8, 208u8, 42u8, 0u8, 0u8,
+                207u8, 42u8, 0u8, 0u8, 210u8, 42u8, 0u8, 0u8, 209u8, 42u8, 0u8, 0u8, 212u8, 42u8,
+                0u8, 0u8, 211u8, 42u8, 0u8, 0u8, 214u8, 42u8, 0u8, 0u8, 213u8, 42u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 166u8, 34u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 169u8, 34u8, 0u8, 0u8,
+                168u8, 34u8, 0u8, 0u8, 171u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 237u8, 42u8, 0u8, 0u8, 236u8, 42u8, 0u8, 0u8, 36u8, 34u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 248u8, 42u8, 0u8, 0u8, 247u8,
+                42u8, 0u8, 0u8, 250u8, 42u8, 0u8, 0u8, 249u8, 42u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 31u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 46u8, 0u8, 0u8, 2u8, 46u8, 0u8, 0u8, 5u8, 46u8, 0u8, 0u8, 4u8, 46u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 10u8, 46u8, 0u8, 0u8,
+                9u8, 46u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 13u8, 46u8, 0u8, 0u8, 12u8, 46u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 29u8, 46u8, 0u8, 0u8, 28u8, 46u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 33u8, 46u8, 0u8, 0u8, 32u8, 46u8, 0u8, 0u8, 35u8, 46u8, 32u8, 0u8, 34u8, 46u8,
+                64u8, 0u8, 37u8, 46u8, 32u8, 0u8, 36u8, 46u8, 64u8, 0u8, 39u8, 46u8, 32u8, 0u8,
+                38u8, 46u8, 64u8, 0u8, 41u8, 46u8, 32u8, 0u8, 40u8, 46u8, 64u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 86u8, 46u8, 32u8, 0u8, 85u8, 46u8, 64u8, 0u8, 88u8, 46u8,
+                32u8, 0u8, 87u8, 46u8, 64u8, 0u8, 90u8, 46u8, 32u8, 0u8, 89u8, 46u8, 64u8, 0u8,
+                92u8, 46u8, 32u8, 0u8, 91u8, 46u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 48u8, 32u8, 0u8, 8u8, 48u8, 64u8,
+                0u8, 11u8, 48u8, 32u8, 0u8, 10u8, 48u8, 64u8, 0u8, 13u8, 48u8, 32u8, 0u8, 12u8,
+                48u8, 64u8, 0u8, 15u8, 48u8, 32u8, 0u8, 14u8, 48u8, 64u8, 0u8, 17u8, 48u8, 32u8,
+                0u8, 16u8, 48u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 48u8,
+                32u8, 0u8, 20u8, 48u8, 64u8, 0u8, 23u8, 48u8, 32u8, 0u8, 22u8, 48u8, 64u8, 0u8,
+                25u8, 48u8, 32u8, 0u8, 24u8, 48u8, 64u8, 0u8, 27u8, 48u8, 32u8, 0u8, 26u8, 48u8,
+                64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 90u8, 254u8, 32u8, 0u8, 89u8, 254u8, 64u8, 0u8, 92u8,
+                254u8, 32u8, 0u8, 91u8, 254u8, 64u8, 0u8, 94u8, 254u8, 32u8, 0u8, 93u8, 254u8,
+                64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 101u8, 254u8, 0u8, 0u8, 100u8, 254u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 9u8, 255u8, 32u8, 0u8, 8u8, 255u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 255u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 28u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 61u8, 255u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 59u8, 255u8, 64u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 93u8, 255u8, 32u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 91u8, 255u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 96u8, 255u8,
+                32u8, 0u8, 95u8, 255u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 255u8, 32u8, 0u8,
+                98u8, 255u8, 64u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        0u32,
+    ),
+};
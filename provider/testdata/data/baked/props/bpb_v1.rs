@@ -0,0 +1,211 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::BidiPairedBracketV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 65536u32,
+            shifted12_high_start: 16u16,
+            index3_null_offset: 2u16,
+            data_null_offset: 126u32,
+            null_value: 1114112u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 132u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 85u8, 1u8, 110u8, 1u8, 110u8, 1u8, 111u8, 1u8, 0u8, 0u8, 16u8, 0u8,
+                32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 126u8, 0u8,
+                142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8,
+                126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8,
+                174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 142u8, 0u8,
+                158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8,
+                142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 132u8, 0u8, 148u8, 0u8, 164u8, 0u8, 180u8, 0u8,
+                126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8,
+                174u8, 0u8, 126u8, 0u8, 142u8, 0u8, 158u8, 0u8, 174u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                194u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 207u8, 0u8, 126u8, 0u8, 126u8, 0u8, 214u8, 0u8, 229u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 244u8, 0u8, 126u8, 0u8, 0u8, 1u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 11u8, 1u8,
+                27u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 38u8, 1u8, 126u8, 0u8,
+                48u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 64u8, 1u8, 80u8, 1u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 89u8, 1u8, 126u8, 0u8, 101u8, 1u8, 126u8, 0u8, 126u8, 0u8,
+                115u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 139u8, 1u8, 155u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 167u8, 1u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 182u8, 1u8, 126u8, 0u8, 126u8, 0u8, 192u8, 1u8,
+                126u8, 0u8, 206u8, 1u8, 222u8, 1u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8,
+                126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 126u8, 0u8, 68u8, 0u8, 76u8, 0u8,
+                76u8, 0u8, 76u8, 0u8, 76u8, 0u8, 76u8, 0u8, 76u8, 0u8, 92u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 124u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 152u8, 0u8,
+                168u8, 0u8, 2u8, 0u8, 187u8, 0u8, 218u8, 0u8, 2u8, 0u8, 2u8, 0u8, 250u8, 0u8, 26u8,
+                1u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 53u8, 1u8, 238u8, 255u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 41u8, 0u8, 0u8, 1u8, 40u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 93u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 91u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 125u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 123u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 59u8, 15u8, 0u8, 1u8, 58u8, 15u8, 0u8,
+                1u8, 61u8, 15u8, 0u8, 1u8, 60u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 156u8, 22u8, 0u8, 1u8, 155u8, 22u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 70u8, 32u8, 0u8, 1u8, 69u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 126u8, 32u8,
+                0u8, 1u8, 125u8, 32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 142u8, 32u8, 0u8, 1u8, 141u8,
+                32u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 9u8, 35u8, 0u8, 1u8, 8u8, 35u8, 0u8, 1u8, 11u8, 35u8, 0u8, 1u8,
+                10u8, 35u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 42u8, 35u8, 0u8, 1u8, 41u8, 35u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                105u8, 39u8, 0u8, 1u8, 104u8, 39u8, 0u8, 1u8, 107u8, 39u8, 0u8, 1u8, 106u8, 39u8,
+                0u8, 1u8, 109u8, 39u8, 0u8, 1u8, 108u8, 39u8, 0u8, 1u8, 111u8, 39u8, 0u8, 1u8,
+                110u8, 39u8, 0u8, 1u8, 113u8, 39u8, 0u8, 1u8, 112u8, 39u8, 0u8, 1u8, 115u8, 39u8,
+                0u8, 1u8, 114u8, 39u8, 0u8, 1u8, 117u8, 39u8, 0u8, 1u8, 116u8, 39u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 198u8, 39u8, 0u8, 1u8, 197u8, 39u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 231u8, 39u8, 0u8, 1u8, 230u8, 39u8, 0u8, 1u8, 233u8, 39u8, 0u8,
+                1u8, 232u8, 39u8, 0u8, 1u8, 235u8, 39u8, 0u8, 1u8, 234u8, 39u8, 0u8, 1u8, 237u8,
+                39u8, 0u8, 1u8, 236u8, 39u8, 0u8, 1u8, 239u8, 39u8, 0u8, 1u8, 238u8, 39u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 41u8, 0u8,
+                1u8, 131u8, 41u8, 0u8, 1u8, 134u8, 41u8, 0u8, 1u8, 133u8, 41u8, 0u8, 1u8, 136u8,
+                41u8, 0u8, 1u8, 135u8, 41u8, 0u8, 1u8, 138u8, 41u8, 0u8, 1u8, 137u8, 41u8, 0u8,
+                1u8, 140u8, 41u8, 0u8, 1u8, 139u8, 41u8, 0u8, 1u8, 144u8, 41u8, 0u8, 1u8, 143u8,
+                41u8, 0u8, 1u8, 142u8, 41u8, 0u8, 1u8, 141u8, 41u8, 0u8, 1u8, 146u8, 41u8, 0u8,
+                1u8, 145u8, 41u8, 0u8, 1u8, 148u8, 41u8, 0u8, 1u8, 147u8, 41u8, 0u8, 1u8, 150u8,
+                41u8, 0u8, 1u8, 149u8, 41u8, 0u8, 1u8, 152u8, 41u8, 0u8, 1u8, 151u8, 41u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 217u8, 41u8, 0u8, 1u8, 216u8, 41u8, 0u8, 1u8, 219u8, 41u8, 0u8, 1u8, 218u8,
+                41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 253u8, 41u8, 0u8, 1u8, 252u8, 41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 35u8, 46u8, 0u8, 1u8, 34u8, 46u8, 0u8, 1u8, 37u8, 46u8, 0u8,
+                1u8, 36u8, 46u8, 0u8, 1u8, 39u8, 46u8, 0u8, 1u8, 38u8, 46u8, 0u8, 1u8, 41u8, 46u8,
+                0u8, 1u8, 40u8, 46u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 86u8, 46u8,
+                0u8, 1u8, 85u8, 46u8, 0u8, 1u8, 88u8, 46u8, 0u8, 1u8, 87u8, 46u8, 0u8, 1u8, 90u8,
+                46u8, 0u8, 1u8, 89u8, 46u8, 0u8, 1u8, 92u8, 46u8, 0u8, 1u8, 91u8, 46u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                9u8, 48u8, 0u8, 1u8, 8u8, 48u8, 0u8, 1u8, 11u8, 48u8, 0u8, 1u8, 10u8, 48u8, 0u8,
+                1u8, 13u8, 48u8, 0u8, 1u8, 12u8, 48u8, 0u8, 1u8, 15u8, 48u8, 0u8, 1u8, 14u8, 48u8,
+                0u8, 1u8, 17u8, 48u8, 0u8, 1u8, 16u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 21u8, 48u8, 0u8, 1u8, 20u8, 48u8, 0u8, 1u8, 23u8, 48u8, 0u8, 1u8, 22u8,
+                48u8, 0u8, 1u8, 25u8, 48u8, 0u8, 1u8, 24u8, 48u8, 0u8, 1u8, 27u8, 48u8, 0u8, 1u8,
+                26u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 90u8, 254u8, 0u8, 1u8, 89u8, 254u8, 0u8,
+                1u8, 92u8, 254u8, 0u8, 1u8, 91u8, 254u8, 0u8, 1u8, 94u8, 254u8, 0u8, 1u8, 93u8,
+                254u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 9u8, 255u8, 0u8, 1u8, 8u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 61u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 59u8,
+                255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 93u8,
+                255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 91u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                96u8, 255u8, 0u8, 1u8, 95u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 99u8, 255u8, 0u8,
+                1u8, 98u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::CodePointValue(None),
+    ),
+);
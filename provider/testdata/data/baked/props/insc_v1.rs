@@ -0,0 +1,419 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: IndicSyllabicCategoryV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 73728u32,
+            shifted12_high_start: 18u16,
+            index3_null_offset: 4u16,
+            data_null_offset: 64u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 96u8, 0u8, 148u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 212u8, 0u8, 18u8,
+                1u8, 82u8, 1u8, 144u8, 1u8, 207u8, 1u8, 13u8, 2u8, 76u8, 2u8, 138u8, 2u8, 202u8,
+                2u8, 8u8, 3u8, 70u8, 3u8, 132u8, 3u8, 196u8, 3u8, 2u8, 4u8, 66u8, 4u8, 128u8, 4u8,
+                192u8, 4u8, 254u8, 4u8, 62u8, 5u8, 126u8, 5u8, 189u8, 5u8, 253u8, 5u8, 60u8, 6u8,
+                124u8, 6u8, 156u8, 6u8, 220u8, 6u8, 28u8, 7u8, 89u8, 7u8, 255u8, 2u8, 18u8, 3u8,
+                30u8, 3u8, 18u8, 3u8, 57u8, 3u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 96u8, 0u8, 112u8, 0u8, 128u8, 0u8, 144u8,
+                0u8, 148u8, 0u8, 164u8, 0u8, 180u8, 0u8, 196u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8,
+                0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8,
+                96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 212u8, 0u8, 228u8, 0u8, 244u8, 0u8, 4u8, 1u8, 18u8, 1u8, 34u8, 1u8,
+                50u8, 1u8, 66u8, 1u8, 82u8, 1u8, 98u8, 1u8, 114u8, 1u8, 130u8, 1u8, 144u8, 1u8,
+                160u8, 1u8, 176u8, 1u8, 192u8, 1u8, 207u8, 1u8, 223u8, 1u8, 239u8, 1u8, 255u8, 1u8,
+                13u8, 2u8, 29u8, 2u8, 45u8, 2u8, 61u8, 2u8, 76u8, 2u8, 92u8, 2u8, 108u8, 2u8,
+                124u8, 2u8, 138u8, 2u8, 154u8, 2u8, 170u8, 2u8, 186u8, 2u8, 202u8, 2u8, 218u8, 2u8,
+                234u8, 2u8, 250u8, 2u8, 8u8, 3u8, 24u8, 3u8, 40u8, 3u8, 56u8, 3u8, 70u8, 3u8, 86u8,
+                3u8, 102u8, 3u8, 118u8, 3u8, 132u8, 3u8, 148u8, 3u8, 164u8, 3u8, 180u8, 3u8, 196u8,
+                3u8, 212u8, 3u8, 228u8, 3u8, 244u8, 3u8, 2u8, 4u8, 18u8, 4u8, 34u8, 4u8, 50u8, 4u8,
+                66u8, 4u8, 82u8, 4u8, 98u8, 4u8, 114u8, 4u8, 128u8, 4u8, 144u8, 4u8, 160u8, 4u8,
+                176u8, 4u8, 192u8, 4u8, 208u8, 4u8, 224u8, 4u8, 240u8, 4u8, 254u8, 4u8, 14u8, 5u8,
+                30u8, 5u8, 46u8, 5u8, 62u8, 5u8, 78u8, 5u8, 94u8, 5u8, 110u8, 5u8, 126u8, 5u8,
+                142u8, 5u8, 158u8, 5u8, 174u8, 5u8, 189u8, 5u8, 205u8, 5u8, 221u8, 5u8, 237u8, 5u8,
+                253u8, 5u8, 13u8, 6u8, 29u8, 6u8, 45u8, 6u8, 60u8, 6u8, 76u8, 6u8, 92u8, 6u8,
+                108u8, 6u8, 124u8, 6u8, 140u8, 6u8, 156u8, 6u8, 172u8, 6u8, 156u8, 6u8, 172u8, 6u8,
+                188u8, 6u8, 204u8, 6u8, 220u8, 6u8, 236u8, 6u8, 252u8, 6u8, 12u8, 7u8, 28u8, 7u8,
+                44u8, 7u8, 60u8, 7u8, 76u8, 7u8, 89u8, 7u8, 105u8, 7u8, 121u8, 7u8, 137u8, 7u8,
+                233u8, 0u8, 233u8, 0u8, 153u8, 7u8, 164u8, 7u8, 180u8, 7u8, 196u8, 7u8, 211u8, 7u8,
+                226u8, 7u8, 240u8, 7u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 230u8, 0u8, 16u8, 8u8, 230u8, 0u8, 31u8, 8u8, 230u8,
+                0u8, 47u8, 8u8, 63u8, 8u8, 78u8, 8u8, 233u8, 0u8, 233u8, 0u8, 94u8, 8u8, 106u8,
+                8u8, 116u8, 8u8, 131u8, 8u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 147u8, 8u8, 108u8, 1u8, 163u8,
+                8u8, 179u8, 8u8, 45u8, 2u8, 233u8, 0u8, 195u8, 8u8, 211u8, 8u8, 233u8, 0u8, 233u8,
+                0u8, 116u8, 3u8, 227u8, 8u8, 242u8, 8u8, 2u8, 9u8, 64u8, 0u8, 64u8, 0u8, 233u8,
+                0u8, 18u8, 9u8, 233u8, 0u8, 233u8, 0u8, 34u8, 9u8, 47u8, 9u8, 63u8, 9u8, 75u8, 9u8,
+                48u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 91u8, 9u8, 230u8, 0u8, 233u8, 0u8, 107u8, 9u8, 119u8, 9u8, 48u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 135u8, 9u8, 233u8, 0u8, 150u8, 9u8, 166u8, 9u8, 233u8, 0u8, 233u8,
+                0u8, 182u8, 9u8, 198u8, 9u8, 233u8, 0u8, 233u8, 0u8, 214u8, 9u8, 227u8, 9u8, 243u8,
+                9u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 3u8, 10u8, 17u8, 10u8, 31u8, 10u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 42u8, 10u8, 54u8, 10u8, 70u8,
+                10u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 91u8, 7u8, 84u8, 10u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 100u8, 10u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 116u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 116u8, 10u8, 233u8, 0u8, 129u8, 10u8, 64u8, 0u8, 233u8, 0u8,
+                145u8, 10u8, 159u8, 10u8, 174u8, 10u8, 214u8, 0u8, 231u8, 0u8, 233u8, 0u8, 190u8,
+                10u8, 202u8, 10u8, 48u8, 0u8, 218u8, 10u8, 232u8, 10u8, 248u8, 10u8, 233u8, 0u8,
+                6u8, 11u8, 233u8, 0u8, 22u8, 11u8, 37u8, 11u8, 64u8, 0u8, 64u8, 0u8, 53u8, 11u8,
+                233u8, 0u8, 233u8, 0u8, 68u8, 11u8, 151u8, 2u8, 48u8, 0u8, 84u8, 11u8, 100u8, 11u8,
+                227u8, 0u8, 233u8, 0u8, 154u8, 8u8, 116u8, 11u8, 132u8, 11u8, 48u8, 0u8, 233u8,
+                0u8, 147u8, 11u8, 233u8, 0u8, 233u8, 0u8, 233u8, 0u8, 163u8, 11u8, 179u8, 11u8,
+                64u8, 0u8, 195u8, 11u8, 211u8, 11u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 227u8, 11u8, 243u8, 11u8, 0u8, 12u8, 48u8, 0u8, 16u8, 12u8, 32u8, 12u8, 233u8,
+                0u8, 42u8, 12u8, 49u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 58u8,
+                12u8, 230u8, 0u8, 233u8, 0u8, 155u8, 8u8, 74u8, 12u8, 88u8, 12u8, 98u8, 12u8,
+                114u8, 12u8, 130u8, 12u8, 233u8, 0u8, 233u8, 0u8, 146u8, 12u8, 153u8, 3u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 162u8, 12u8, 233u8, 0u8, 156u8, 8u8, 178u8, 12u8, 194u8,
+                12u8, 210u8, 12u8, 233u8, 0u8, 223u8, 12u8, 213u8, 0u8, 232u8, 0u8, 233u8, 0u8,
+                239u8, 12u8, 255u8, 12u8, 48u8, 0u8, 187u8, 6u8, 53u8, 0u8, 225u8, 0u8, 235u8, 3u8,
+                151u8, 8u8, 15u8, 13u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 31u8, 13u8,
+                109u8, 1u8, 46u8, 13u8, 223u8, 0u8, 233u8, 0u8, 62u8, 13u8, 78u8, 13u8, 48u8, 0u8,
+                94u8, 13u8, 98u8, 1u8, 114u8, 1u8, 110u8, 13u8, 8u8, 3u8, 126u8, 13u8, 142u8, 13u8,
+                14u8, 10u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 219u8, 0u8, 233u8, 0u8, 233u8, 0u8, 158u8, 13u8, 172u8, 13u8,
+                188u8, 13u8, 204u8, 13u8, 64u8, 0u8, 219u8, 13u8, 233u8, 0u8, 233u8, 0u8, 64u8,
+                9u8, 235u8, 13u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 219u8, 0u8, 233u8, 0u8,
+                255u8, 0u8, 251u8, 13u8, 11u8, 14u8, 19u8, 14u8, 64u8, 0u8, 64u8, 0u8, 219u8, 0u8,
+                233u8, 0u8, 233u8, 0u8, 35u8, 14u8, 51u8, 14u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                223u8, 0u8, 233u8, 0u8, 67u8, 14u8, 80u8, 14u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 233u8, 0u8, 96u8, 14u8, 112u8, 14u8, 128u8, 14u8, 144u8, 14u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 223u8, 0u8, 233u8, 0u8, 151u8, 8u8, 160u8, 14u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 176u8, 14u8, 188u8, 14u8, 233u8,
+                0u8, 204u8, 14u8, 220u8, 14u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 236u8, 14u8, 233u8, 0u8, 233u8, 0u8, 251u8, 14u8, 11u8, 15u8, 64u8, 0u8, 27u8,
+                15u8, 233u8, 0u8, 233u8, 0u8, 40u8, 15u8, 56u8, 15u8, 72u8, 15u8, 233u8, 0u8,
+                233u8, 0u8, 84u8, 15u8, 94u8, 15u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 110u8, 15u8, 233u8, 0u8, 255u8, 0u8, 126u8, 15u8,
+                142u8, 15u8, 188u8, 6u8, 158u8, 15u8, 86u8, 5u8, 233u8, 0u8, 172u8, 15u8, 44u8,
+                7u8, 188u8, 15u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 204u8, 15u8, 233u8,
+                0u8, 233u8, 0u8, 219u8, 15u8, 235u8, 15u8, 48u8, 0u8, 251u8, 15u8, 233u8, 0u8, 7u8,
+                16u8, 20u8, 16u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 233u8, 0u8, 36u8, 16u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 69u8, 0u8, 85u8, 0u8, 85u8, 0u8,
+                85u8, 0u8, 101u8, 0u8, 133u8, 0u8, 165u8, 0u8, 197u8, 0u8, 229u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 245u8, 0u8, 20u8, 1u8, 52u8, 1u8, 84u8, 1u8, 4u8, 0u8, 116u8, 1u8, 4u8,
+                0u8, 132u8, 1u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 164u8, 1u8, 196u8, 1u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 228u8, 1u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 2u8, 36u8, 2u8, 68u8, 2u8, 100u8, 2u8, 132u8, 2u8, 164u8, 2u8, 196u8,
+                2u8, 223u8, 2u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 28u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 32u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 34u8, 34u8, 23u8, 1u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 34u8, 34u8, 0u8, 4u8, 4u8, 0u8, 0u8,
+                34u8, 34u8, 34u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 35u8, 35u8, 34u8, 34u8,
+                0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 12u8,
+                2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 0u8,
+                35u8, 35u8, 0u8, 0u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 23u8, 1u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 31u8, 6u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8,
+                35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                28u8, 0u8, 2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 0u8, 0u8,
+                0u8, 35u8, 35u8, 0u8, 0u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 23u8, 0u8,
+                34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 31u8, 0u8,
+                0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 2u8, 18u8, 12u8, 12u8, 0u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 0u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8,
+                23u8, 1u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8, 0u8, 34u8,
+                34u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                4u8, 4u8, 4u8, 23u8, 23u8, 23u8, 0u8, 2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 0u8, 0u8, 35u8, 35u8, 0u8, 0u8, 35u8, 35u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 23u8, 1u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8,
+                0u8, 0u8, 34u8, 34u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 21u8, 0u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 5u8, 0u8,
+                0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 34u8, 34u8,
+                34u8, 0u8, 34u8, 34u8, 34u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 32u8, 2u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8,
+                35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 23u8, 1u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                0u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 34u8, 34u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 6u8, 0u8, 0u8, 35u8, 35u8, 34u8,
+                34u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+                2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 35u8,
+                35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 23u8, 1u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8, 31u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8,
+                5u8, 0u8, 35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 0u8, 17u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 32u8, 2u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 26u8, 26u8, 1u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8,
+                0u8, 34u8, 34u8, 34u8, 31u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 6u8, 6u8, 34u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 6u8, 6u8, 6u8, 6u8, 6u8, 6u8, 0u8, 2u8, 2u8, 32u8, 0u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 0u8, 34u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 0u8, 0u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 26u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 34u8, 30u8, 30u8, 30u8, 30u8,
+                10u8, 2u8, 26u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 26u8, 34u8, 11u8, 11u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8,
+                0u8, 30u8, 30u8, 30u8, 30u8, 0u8, 2u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 0u8, 28u8, 0u8, 28u8, 0u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 2u8,
+                32u8, 34u8, 34u8, 2u8, 2u8, 26u8, 1u8, 0u8, 0u8, 8u8, 8u8, 8u8, 8u8, 8u8, 15u8,
+                15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 0u8, 15u8, 15u8, 15u8,
+                15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8,
+                15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8,
+                15u8, 15u8, 15u8, 15u8, 15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 2u8, 30u8, 32u8, 19u8, 26u8, 11u8,
+                11u8, 11u8, 11u8, 5u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                0u8, 12u8, 0u8, 0u8, 12u8, 0u8, 5u8, 5u8, 35u8, 35u8, 35u8, 35u8, 34u8, 34u8, 34u8,
+                34u8, 5u8, 5u8, 5u8, 5u8, 11u8, 11u8, 5u8, 34u8, 30u8, 30u8, 5u8, 5u8, 34u8, 34u8,
+                30u8, 30u8, 30u8, 30u8, 30u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 11u8, 34u8, 34u8, 34u8, 34u8, 30u8, 30u8, 30u8,
+                30u8, 30u8, 30u8, 30u8, 5u8, 30u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 30u8, 30u8, 34u8, 34u8, 0u8, 0u8, 5u8, 5u8, 34u8, 34u8, 26u8, 26u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 34u8, 34u8, 26u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 34u8, 34u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 0u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 2u8, 32u8, 34u8, 27u8, 27u8, 28u8, 16u8,
+                10u8, 28u8, 28u8, 26u8, 19u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                28u8, 0u8, 0u8, 12u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 15u8, 15u8,
+                15u8, 0u8, 0u8, 0u8, 0u8, 7u8, 7u8, 2u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 34u8,
+                28u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 33u8, 33u8, 33u8, 33u8, 33u8, 33u8, 33u8,
+                33u8, 33u8, 33u8, 33u8, 0u8, 0u8, 29u8, 29u8, 29u8, 29u8, 29u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+                30u8, 30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 35u8, 35u8, 35u8, 5u8, 5u8, 11u8, 11u8, 15u8,
+                7u8, 7u8, 9u8, 15u8, 15u8, 15u8, 15u8, 0u8, 19u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 2u8, 30u8, 30u8, 30u8,
+                30u8, 30u8, 26u8, 28u8, 28u8, 0u8, 0u8, 28u8, 2u8, 2u8, 2u8, 7u8, 32u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 23u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 2u8, 7u8, 32u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 15u8, 15u8, 15u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 26u8, 19u8, 15u8, 15u8, 5u8, 5u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 1u8, 5u8, 5u8, 5u8, 7u8, 7u8, 5u8, 5u8, 5u8,
+                5u8, 35u8, 35u8, 23u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 7u8,
+                7u8, 26u8, 26u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 15u8, 15u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 7u8, 7u8, 7u8,
+                7u8, 2u8, 2u8, 28u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 4u8,
+                4u8, 4u8, 0u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 6u8, 4u8,
+                17u8, 17u8, 4u8, 4u8, 4u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 20u8,
+                0u8, 0u8, 12u8, 12u8, 12u8, 12u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 28u8, 28u8, 28u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 35u8, 35u8, 34u8, 35u8, 35u8, 35u8, 31u8, 5u8, 5u8, 5u8, 5u8, 2u8, 5u8, 5u8,
+                5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 33u8, 33u8,
+                5u8, 5u8, 5u8, 5u8, 33u8, 15u8, 15u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 15u8, 5u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 11u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8,
+                2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+                4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 2u8, 2u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 34u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 33u8, 33u8, 33u8, 33u8, 33u8,
+                33u8, 33u8, 33u8, 33u8, 30u8, 30u8, 30u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 7u8, 7u8, 7u8, 26u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 7u8, 32u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 23u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 11u8, 11u8, 11u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 34u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 34u8, 34u8,
+                34u8, 11u8, 11u8, 11u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8,
+                7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 12u8, 12u8, 12u8, 0u8, 0u8, 0u8, 5u8, 30u8, 30u8, 30u8, 5u8, 5u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                30u8, 29u8, 30u8, 29u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 32u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                35u8, 35u8, 5u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 7u8, 7u8, 7u8,
+                7u8, 7u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 30u8, 26u8, 0u8,
+                0u8, 5u8, 34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8,
+                2u8, 32u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 23u8, 23u8, 23u8, 0u8, 0u8, 0u8, 0u8, 19u8, 2u8, 2u8, 32u8,
+                17u8, 17u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 26u8, 35u8, 35u8, 34u8, 34u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 25u8, 2u8, 2u8, 32u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8,
+                32u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 34u8,
+                34u8, 34u8, 19u8, 26u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 0u8, 0u8, 0u8, 0u8, 5u8, 34u8, 34u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 33u8, 33u8, 33u8, 33u8, 33u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 31u8, 1u8, 14u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 23u8, 34u8, 34u8, 0u8,
+                34u8, 2u8, 34u8, 34u8, 34u8, 34u8, 2u8, 31u8, 23u8, 18u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 4u8, 0u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 2u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 23u8, 26u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 32u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 0u8, 0u8, 35u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                23u8, 23u8, 1u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 35u8, 35u8, 34u8, 34u8, 0u8, 0u8, 4u8, 4u8, 4u8, 4u8,
+                4u8, 4u8, 4u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 2u8, 2u8, 32u8, 23u8, 1u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 28u8, 2u8, 17u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 5u8, 2u8, 32u8, 31u8, 23u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 2u8, 2u8, 32u8, 31u8, 23u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8,
+                35u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 2u8, 32u8, 31u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 2u8, 32u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 31u8, 23u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 0u8, 0u8, 11u8, 11u8, 11u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 26u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 2u8, 32u8, 31u8, 23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 0u8, 0u8, 35u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8,
+                5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 0u8, 34u8, 34u8, 0u8, 0u8, 2u8, 2u8, 26u8, 19u8, 14u8, 11u8, 13u8, 11u8,
+                23u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 0u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 34u8, 34u8, 34u8, 34u8, 2u8, 32u8,
+                31u8, 1u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                35u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 28u8, 26u8, 2u8, 2u8, 2u8, 2u8, 32u8, 14u8, 11u8, 11u8, 11u8, 11u8, 12u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 0u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                35u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 5u8, 5u8,
+                5u8, 5u8, 14u8, 14u8, 14u8, 14u8, 14u8, 14u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 2u8,
+                32u8, 18u8, 19u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8,
+                35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 35u8, 35u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 34u8, 34u8, 0u8, 34u8, 34u8, 34u8, 34u8, 2u8, 2u8, 32u8, 31u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 24u8, 24u8, 24u8,
+                24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 24u8, 0u8, 0u8, 0u8, 15u8,
+                15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 15u8, 34u8,
+                34u8, 34u8, 34u8, 34u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 0u8, 35u8, 5u8, 5u8,
+                5u8, 5u8, 34u8, 34u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 34u8, 0u8, 34u8, 34u8,
+                0u8, 34u8, 2u8, 32u8, 23u8, 34u8, 26u8, 19u8, 13u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 35u8, 35u8, 35u8, 35u8, 35u8, 35u8, 0u8, 35u8, 35u8, 0u8, 35u8,
+                35u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 34u8, 34u8, 34u8, 34u8,
+                34u8, 0u8, 34u8, 34u8, 2u8, 32u8, 19u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 12u8, 34u8, 34u8, 34u8, 34u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::IndicSyllabicCategory(0u8),
+    ),
+);
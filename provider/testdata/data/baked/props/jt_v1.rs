@@ -0,0 +1,472 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::JoiningTypeV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 918016u32,
+            shifted12_high_start: 225u16,
+            index3_null_offset: 385u16,
+            data_null_offset: 0u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 83u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 163u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 1u8, 77u8, 1u8,
+                125u8, 1u8, 189u8, 1u8, 245u8, 1u8, 53u8, 2u8, 117u8, 2u8, 170u8, 2u8, 234u8, 2u8,
+                32u8, 3u8, 94u8, 3u8, 158u8, 3u8, 219u8, 3u8, 21u8, 4u8, 82u8, 4u8, 145u8, 4u8,
+                208u8, 4u8, 15u8, 5u8, 78u8, 5u8, 141u8, 5u8, 78u8, 5u8, 204u8, 5u8, 12u8, 6u8,
+                74u8, 6u8, 136u8, 6u8, 200u8, 6u8, 8u8, 7u8, 71u8, 7u8, 12u8, 6u8, 129u8, 7u8,
+                163u8, 7u8, 226u8, 7u8, 33u8, 8u8, 87u8, 8u8, 110u8, 8u8, 169u8, 8u8, 184u8, 8u8,
+                245u8, 8u8, 29u8, 9u8, 87u8, 9u8, 145u8, 9u8, 203u8, 4u8, 163u8, 5u8, 188u8, 5u8,
+                201u8, 5u8, 223u8, 5u8, 255u8, 5u8, 26u8, 6u8, 50u8, 6u8, 81u8, 6u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8,
+                188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 188u8, 5u8, 113u8, 6u8, 0u8, 0u8,
+                16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 83u8,
+                0u8, 99u8, 0u8, 115u8, 0u8, 131u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 147u8, 0u8, 163u8, 0u8, 179u8, 0u8, 195u8, 0u8, 163u8, 0u8, 179u8, 0u8,
+                195u8, 0u8, 211u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8,
+                0u8, 32u8, 0u8, 48u8, 0u8, 224u8, 0u8, 240u8, 0u8, 0u8, 1u8, 16u8, 1u8, 0u8, 0u8,
+                16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8,
+                0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 15u8, 1u8, 31u8, 1u8, 47u8, 1u8, 63u8, 1u8,
+                77u8, 1u8, 93u8, 1u8, 109u8, 1u8, 125u8, 1u8, 141u8, 1u8, 157u8, 1u8, 173u8, 1u8,
+                189u8, 1u8, 205u8, 1u8, 221u8, 1u8, 237u8, 1u8, 245u8, 1u8, 5u8, 2u8, 21u8, 2u8,
+                37u8, 2u8, 53u8, 2u8, 69u8, 2u8, 85u8, 2u8, 101u8, 2u8, 117u8, 2u8, 133u8, 2u8,
+                149u8, 2u8, 165u8, 2u8, 170u8, 2u8, 186u8, 2u8, 202u8, 2u8, 218u8, 2u8, 234u8, 2u8,
+                250u8, 2u8, 10u8, 3u8, 26u8, 3u8, 32u8, 3u8, 48u8, 3u8, 64u8, 3u8, 80u8, 3u8, 94u8,
+                3u8, 110u8, 3u8, 126u8, 3u8, 142u8, 3u8, 158u8, 3u8, 174u8, 3u8, 190u8, 3u8, 206u8,
+                3u8, 219u8, 3u8, 235u8, 3u8, 251u8, 3u8, 11u8, 4u8, 21u8, 4u8, 37u8, 4u8, 53u8,
+                4u8, 69u8, 4u8, 82u8, 4u8, 98u8, 4u8, 114u8, 4u8, 130u8, 4u8, 145u8, 4u8, 161u8,
+                4u8, 177u8, 4u8, 193u8, 4u8, 208u8, 4u8, 224u8, 4u8, 240u8, 4u8, 0u8, 5u8, 15u8,
+                5u8, 31u8, 5u8, 47u8, 5u8, 63u8, 5u8, 78u8, 5u8, 94u8, 5u8, 110u8, 5u8, 126u8, 5u8,
+                141u8, 5u8, 157u8, 5u8, 173u8, 5u8, 189u8, 5u8, 78u8, 5u8, 94u8, 5u8, 110u8, 5u8,
+                126u8, 5u8, 204u8, 5u8, 220u8, 5u8, 236u8, 5u8, 252u8, 5u8, 12u8, 6u8, 28u8, 6u8,
+                44u8, 6u8, 60u8, 6u8, 74u8, 6u8, 90u8, 6u8, 106u8, 6u8, 122u8, 6u8, 136u8, 6u8,
+                152u8, 6u8, 168u8, 6u8, 184u8, 6u8, 200u8, 6u8, 216u8, 6u8, 232u8, 6u8, 248u8, 6u8,
+                8u8, 7u8, 24u8, 7u8, 40u8, 7u8, 56u8, 7u8, 71u8, 7u8, 87u8, 7u8, 103u8, 7u8, 119u8,
+                7u8, 12u8, 6u8, 28u8, 6u8, 44u8, 6u8, 60u8, 6u8, 129u8, 7u8, 145u8, 7u8, 161u8,
+                7u8, 177u8, 7u8, 163u8, 7u8, 179u8, 7u8, 195u8, 7u8, 211u8, 7u8, 226u8, 7u8, 242u8,
+                7u8, 2u8, 8u8, 18u8, 8u8, 33u8, 8u8, 49u8, 8u8, 65u8, 8u8, 81u8, 8u8, 87u8, 8u8,
+                103u8, 8u8, 119u8, 8u8, 135u8, 8u8, 110u8, 8u8, 126u8, 8u8, 142u8, 8u8, 158u8, 8u8,
+                169u8, 8u8, 185u8, 8u8, 201u8, 8u8, 217u8, 8u8, 184u8, 8u8, 200u8, 8u8, 216u8, 8u8,
+                232u8, 8u8, 245u8, 8u8, 5u8, 9u8, 21u8, 9u8, 37u8, 9u8, 29u8, 9u8, 45u8, 9u8, 61u8,
+                9u8, 77u8, 9u8, 87u8, 9u8, 103u8, 9u8, 119u8, 9u8, 135u8, 9u8, 145u8, 9u8, 161u8,
+                9u8, 177u8, 9u8, 193u8, 9u8, 203u8, 4u8, 219u8, 4u8, 235u8, 4u8, 251u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 134u8, 0u8, 209u8, 9u8, 0u8, 0u8, 224u8, 9u8, 128u8, 0u8, 237u8,
+                9u8, 251u8, 9u8, 115u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 134u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 9u8, 10u8, 0u8, 0u8, 177u8, 4u8, 0u8, 0u8, 177u8, 4u8, 0u8, 0u8,
+                177u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 10u8, 153u8, 8u8, 31u8, 10u8, 0u8,
+                0u8, 0u8, 0u8, 45u8, 10u8, 0u8, 0u8, 15u8, 2u8, 15u8, 2u8, 15u8, 2u8, 15u8, 2u8,
+                15u8, 2u8, 61u8, 10u8, 72u8, 10u8, 15u8, 2u8, 79u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 95u8, 10u8, 109u8, 10u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 121u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 133u8,
+                10u8, 147u8, 10u8, 160u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 196u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 207u8, 0u8, 0u8, 0u8, 0u8, 0u8, 176u8, 10u8,
+                126u8, 0u8, 0u8, 0u8, 136u8, 0u8, 207u8, 0u8, 209u8, 0u8, 0u8, 0u8, 190u8, 10u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 204u8, 10u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 135u8,
+                0u8, 219u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 52u8, 4u8, 235u8, 10u8, 244u8, 10u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 254u8, 10u8,
+                0u8, 0u8, 195u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 12u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 147u8, 0u8, 128u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8,
+                0u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8,
+                0u8, 147u8, 0u8, 0u8, 0u8, 0u8, 0u8, 28u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 170u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 113u8, 0u8, 44u8, 11u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 58u8, 11u8, 0u8, 0u8, 70u8, 11u8, 0u8, 0u8,
+                15u8, 2u8, 15u8, 2u8, 15u8, 2u8, 86u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 175u8, 4u8, 0u8, 0u8, 147u8, 0u8, 102u8, 11u8, 0u8, 0u8, 0u8, 0u8, 170u8, 8u8,
+                0u8, 0u8, 140u8, 0u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 114u8, 11u8, 0u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 0u8, 0u8, 253u8, 5u8,
+                125u8, 11u8, 138u8, 11u8, 0u8, 0u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 154u8, 11u8, 127u8, 0u8, 0u8, 0u8, 37u8, 5u8, 122u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 170u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 147u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 184u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 196u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 211u8, 11u8, 0u8, 0u8, 0u8, 0u8,
+                227u8, 11u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 243u8, 11u8, 1u8, 12u8, 16u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 30u8, 12u8, 46u8, 12u8,
+                53u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 69u8, 12u8, 15u8, 2u8, 83u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 38u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 99u8, 12u8, 25u8, 4u8, 115u8, 12u8, 0u8, 0u8, 131u8, 12u8,
+                145u8, 12u8, 0u8, 0u8, 0u8, 0u8, 161u8, 12u8, 177u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 127u8, 0u8, 0u8, 0u8, 0u8, 0u8, 139u8, 0u8, 204u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                193u8, 12u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 205u8, 12u8, 126u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 216u8, 12u8, 206u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 125u8, 0u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 232u8, 12u8, 29u8, 11u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 245u8, 12u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 173u8,
+                8u8, 0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 0u8, 0u8, 38u8, 5u8, 128u8, 0u8, 0u8, 0u8,
+                221u8, 0u8, 206u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 139u8, 0u8, 103u8, 8u8, 114u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 13u8, 77u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 17u8, 13u8, 128u8, 0u8, 37u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 33u8, 13u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                129u8, 4u8, 6u8, 6u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 134u8, 0u8,
+                49u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 142u8, 9u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 13u8,
+                125u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 204u8, 12u8, 128u8, 0u8, 0u8, 0u8, 76u8, 13u8, 0u8, 0u8, 0u8, 0u8, 89u8,
+                13u8, 121u8, 0u8, 104u8, 13u8, 0u8, 0u8, 0u8, 0u8, 137u8, 0u8, 120u8, 13u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 136u8, 13u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 145u8, 0u8, 151u8, 13u8, 166u8, 13u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 181u8, 13u8, 6u8, 6u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 196u8, 13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 176u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 206u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 113u8, 0u8,
+                208u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 124u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 36u8, 5u8,
+                207u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 147u8, 0u8,
+                197u8, 0u8, 147u8, 0u8, 204u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 186u8, 11u8, 144u8, 0u8, 212u8, 13u8, 0u8, 0u8, 28u8, 11u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 10u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 217u8, 13u8, 147u8, 0u8, 147u8, 0u8,
+                198u8, 0u8, 123u8, 0u8, 124u8, 0u8, 136u8, 0u8, 146u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 48u8, 4u8, 228u8, 13u8, 242u8, 13u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 135u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8, 0u8, 0u8, 15u8, 2u8, 15u8, 2u8, 15u8, 2u8,
+                15u8, 2u8, 2u8, 14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 147u8, 0u8,
+                147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 147u8, 0u8, 147u8, 0u8,
+                147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8,
+                147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 147u8, 0u8, 0u8, 0u8,
+                121u8, 0u8, 137u8, 0u8, 161u8, 0u8, 192u8, 0u8, 224u8, 0u8, 0u8, 1u8, 32u8, 1u8,
+                64u8, 1u8, 96u8, 1u8, 107u8, 1u8, 129u8, 1u8, 144u8, 1u8, 176u8, 1u8, 207u8, 1u8,
+                239u8, 1u8, 129u8, 1u8, 15u8, 2u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 33u8, 2u8, 129u8, 1u8, 65u8, 2u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 91u8, 2u8, 123u8, 2u8, 154u8, 2u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 185u8, 2u8, 129u8, 1u8,
+                217u8, 2u8, 249u8, 2u8, 25u8, 3u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 57u8, 3u8,
+                84u8, 3u8, 106u8, 3u8, 138u8, 3u8, 168u8, 3u8, 197u8, 3u8, 227u8, 3u8, 1u8, 4u8,
+                33u8, 4u8, 62u8, 4u8, 88u8, 4u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 117u8, 4u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                134u8, 4u8, 129u8, 1u8, 154u8, 4u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 185u8, 4u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 201u8, 4u8,
+                222u8, 4u8, 250u8, 4u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 26u8, 5u8, 129u8, 1u8,
+                129u8, 1u8, 58u8, 5u8, 80u8, 5u8, 129u8, 1u8, 129u8, 1u8, 99u8, 5u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 129u8, 1u8,
+                129u8, 1u8, 129u8, 1u8, 129u8, 1u8, 131u8, 5u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8,
+                0u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8,
+                4u8, 4u8, 4u8, 2u8, 4u8, 2u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 1u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 5u8, 4u8, 4u8,
+                4u8, 0u8, 4u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8,
+                4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+                4u8, 2u8, 4u8, 2u8, 4u8, 2u8, 2u8, 4u8, 4u8, 0u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 4u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+                2u8, 2u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 4u8, 5u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 4u8, 4u8, 2u8,
+                2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 4u8,
+                2u8, 4u8, 2u8, 2u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 4u8, 4u8, 2u8,
+                2u8, 2u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 2u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 4u8, 2u8, 4u8, 4u8, 4u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 2u8, 2u8, 2u8, 0u8, 4u8, 2u8, 4u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 4u8,
+                4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 1u8, 1u8,
+                1u8, 2u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 4u8, 0u8, 4u8, 2u8, 2u8, 4u8, 4u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8,
+                0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+                0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8,
+                0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8,
+                0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+                5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 1u8, 5u8, 5u8, 5u8, 0u8, 5u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 5u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 1u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8,
+                5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 2u8, 2u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8,
+                0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 5u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 0u8, 4u8, 0u8, 4u8, 4u8, 0u8, 0u8,
+                3u8, 4u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 3u8, 2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8,
+                2u8, 4u8, 0u8, 0u8, 4u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 4u8,
+                2u8, 4u8, 4u8, 4u8, 2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 4u8, 2u8, 4u8, 4u8, 2u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 4u8,
+                4u8, 4u8, 2u8, 2u8, 0u8, 3u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 4u8, 2u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 5u8, 2u8, 2u8, 2u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 4u8, 4u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 2u8, 0u8, 2u8, 2u8, 4u8, 4u8, 4u8, 0u8, 2u8, 4u8, 4u8, 2u8, 2u8, 4u8, 2u8,
+                2u8, 0u8, 2u8, 4u8, 4u8, 2u8, 0u8, 0u8, 0u8, 0u8, 4u8, 2u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 5u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 0u8,
+                0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8,
+                5u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 0u8,
+                5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 0u8, 5u8, 0u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8,
+                5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                5u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8,
+                0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8,
+                0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                0u8, 0u8, 0u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 5u8,
+                5u8, 5u8, 5u8, 5u8, 0u8, 5u8, 5u8, 0u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::JoiningType(0u8),
+    ),
+);
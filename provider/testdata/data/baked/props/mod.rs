@@ -1,9 +1,13 @@
 // @generated
+pub mod age_v1;
 pub mod ahex_v1;
 pub mod alpha_v1;
 pub mod bc_v1;
 pub mod bidi_c_v1;
 pub mod bidi_m_v1;
+pub mod bidiauxiliaryprops_v1;
+pub mod bmg_v1;
+pub mod bpb_v1;
 pub mod cased_v1;
 pub mod casemap_v1;
 pub mod ccc_v1;
@@ -30,17 +34,23 @@ pub mod gcb_v1;
 pub mod gr_base_v1;
 pub mod gr_ext_v1;
 pub mod hex_v1;
+pub mod hst_v1;
 pub mod idc_v1;
 pub mod ideo_v1;
 pub mod ids_v1;
 pub mod idsb_v1;
 pub mod idst_v1;
+pub mod insc_v1;
 pub mod join_c_v1;
+pub mod jt_v1;
 pub mod lb_v1;
 pub mod loe_v1;
 pub mod lower_v1;
 pub mod math_v1;
+pub mod na_v1;
 pub mod nchar_v1;
+pub mod nt_v1;
+pub mod nv_v1;
 pub mod pat_syn_v1;
 pub mod pat_ws_v1;
 pub mod qmark_v1;
@@ -54,6 +64,7 @@ pub mod sterm_v1;
 pub mod term_v1;
 pub mod uideo_v1;
 pub mod upper_v1;
+pub mod vo_v1;
 pub mod vs_v1;
 pub mod wb_v1;
 pub mod wspace_v1;
@@ -0,0 +1,2367 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::CharacterNamesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::CharacterNamesV1 {
+    tokens: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            190u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8,
+            15u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8,
+            39u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8,
+            69u8, 0u8, 0u8, 0u8, 77u8, 0u8, 0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8,
+            87u8, 0u8, 0u8, 0u8, 88u8, 0u8, 0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8,
+            103u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+            120u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 124u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8,
+            133u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8,
+            151u8, 0u8, 0u8, 0u8, 155u8, 0u8, 0u8, 0u8, 165u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8,
+            179u8, 0u8, 0u8, 0u8, 182u8, 0u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8, 193u8, 0u8, 0u8, 0u8,
+            199u8, 0u8, 0u8, 0u8, 206u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 212u8, 0u8, 0u8, 0u8,
+            216u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8,
+            227u8, 0u8, 0u8, 0u8, 228u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 233u8, 0u8, 0u8, 0u8,
+            238u8, 0u8, 0u8, 0u8, 239u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8, 247u8, 0u8, 0u8, 0u8,
+            248u8, 0u8, 0u8, 0u8, 249u8, 0u8, 0u8, 0u8, 254u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 0u8,
+            0u8, 1u8, 0u8, 0u8, 9u8, 1u8, 0u8, 0u8, 14u8, 1u8, 0u8, 0u8, 19u8, 1u8, 0u8, 0u8, 24u8,
+            1u8, 0u8, 0u8, 27u8, 1u8, 0u8, 0u8, 35u8, 1u8, 0u8, 0u8, 41u8, 1u8, 0u8, 0u8, 50u8,
+            1u8, 0u8, 0u8, 51u8, 1u8, 0u8, 0u8, 60u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 0u8, 74u8,
+            1u8, 0u8, 0u8, 84u8, 1u8, 0u8, 0u8, 90u8, 1u8, 0u8, 0u8, 94u8, 1u8, 0u8, 0u8, 97u8,
+            1u8, 0u8, 0u8, 102u8, 1u8, 0u8, 0u8, 107u8, 1u8, 0u8, 0u8, 116u8, 1u8, 0u8, 0u8, 121u8,
+            1u8, 0u8, 0u8, 124u8, 1u8, 0u8, 0u8, 125u8, 1u8, 0u8, 0u8, 130u8, 1u8, 0u8, 0u8, 131u8,
+            1u8, 0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 141u8, 1u8, 0u8, 0u8, 148u8, 1u8, 0u8, 0u8, 151u8,
+            1u8, 0u8, 0u8, 153u8, 1u8, 0u8, 0u8, 154u8, 1u8, 0u8, 0u8, 158u8, 1u8, 0u8, 0u8, 168u8,
+            1u8, 0u8, 0u8, 176u8, 1u8, 0u8, 0u8, 181u8, 1u8, 0u8, 0u8, 185u8, 1u8, 0u8, 0u8, 192u8,
+            1u8, 0u8, 0u8, 198u8, 1u8, 0u8, 0u8, 205u8, 1u8, 0u8, 0u8, 208u8, 1u8, 0u8, 0u8, 211u8,
+            1u8, 0u8, 0u8, 219u8, 1u8, 0u8, 0u8, 230u8, 1u8, 0u8, 0u8, 234u8, 1u8, 0u8, 0u8, 239u8,
+            1u8, 0u8, 0u8, 243u8, 1u8, 0u8, 0u8, 246u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 0u8,
+            2u8, 0u8, 0u8, 8u8, 2u8, 0u8, 0u8, 12u8, 2u8, 0u8, 0u8, 15u8, 2u8, 0u8, 0u8, 20u8, 2u8,
+            0u8, 0u8, 26u8, 2u8, 0u8, 0u8, 37u8, 2u8, 0u8, 0u8, 43u8, 2u8, 0u8, 0u8, 45u8, 2u8,
+            0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 57u8, 2u8, 0u8, 0u8, 65u8, 2u8, 0u8, 0u8, 68u8, 2u8,
+            0u8, 0u8, 71u8, 2u8, 0u8, 0u8, 76u8, 2u8, 0u8, 0u8, 79u8, 2u8, 0u8, 0u8, 84u8, 2u8,
+            0u8, 0u8, 88u8, 2u8, 0u8, 0u8, 91u8, 2u8, 0u8, 0u8, 95u8, 2u8, 0u8, 0u8, 101u8, 2u8,
+            0u8, 0u8, 105u8, 2u8, 0u8, 0u8, 113u8, 2u8, 0u8, 0u8, 115u8, 2u8, 0u8, 0u8, 116u8, 2u8,
+            0u8, 0u8, 119u8, 2u8, 0u8, 0u8, 123u8, 2u8, 0u8, 0u8, 127u8, 2u8, 0u8, 0u8, 135u8, 2u8,
+            0u8, 0u8, 140u8, 2u8, 0u8, 0u8, 143u8, 2u8, 0u8, 0u8, 146u8, 2u8, 0u8, 0u8, 150u8, 2u8,
+            0u8, 0u8, 156u8, 2u8, 0u8, 0u8, 167u8, 2u8, 0u8, 0u8, 174u8, 2u8, 0u8, 0u8, 178u8, 2u8,
+            0u8, 0u8, 181u8, 2u8, 0u8, 0u8, 185u8, 2u8, 0u8, 0u8, 189u8, 2u8, 0u8, 0u8, 202u8, 2u8,
+            0u8, 0u8, 207u8, 2u8, 0u8, 0u8, 211u8, 2u8, 0u8, 0u8, 215u8, 2u8, 0u8, 0u8, 216u8, 2u8,
+            0u8, 0u8, 223u8, 2u8, 0u8, 0u8, 232u8, 2u8, 0u8, 0u8, 237u8, 2u8, 0u8, 0u8, 244u8, 2u8,
+            0u8, 0u8, 245u8, 2u8, 0u8, 0u8, 248u8, 2u8, 0u8, 0u8, 255u8, 2u8, 0u8, 0u8, 12u8, 3u8,
+            0u8, 0u8, 15u8, 3u8, 0u8, 0u8, 19u8, 3u8, 0u8, 0u8, 24u8, 3u8, 0u8, 0u8, 29u8, 3u8,
+            0u8, 0u8, 34u8, 3u8, 0u8, 0u8, 38u8, 3u8, 0u8, 0u8, 42u8, 3u8, 0u8, 0u8, 45u8, 3u8,
+            0u8, 0u8, 48u8, 3u8, 0u8, 0u8, 52u8, 3u8, 0u8, 0u8, 58u8, 3u8, 0u8, 0u8, 59u8, 3u8,
+            0u8, 0u8, 60u8, 3u8, 0u8, 0u8, 63u8, 3u8, 0u8, 0u8, 68u8, 3u8, 0u8, 0u8, 72u8, 3u8,
+            0u8, 0u8, 75u8, 3u8, 0u8, 0u8, 78u8, 3u8, 0u8, 0u8, 84u8, 3u8, 0u8, 0u8, 90u8, 3u8,
+            0u8, 0u8, 93u8, 3u8, 0u8, 0u8, 98u8, 3u8, 0u8, 0u8, 105u8, 3u8, 0u8, 0u8, 107u8, 3u8,
+            0u8, 0u8, 109u8, 3u8, 0u8, 0u8, 113u8, 3u8, 0u8, 0u8, 117u8, 3u8, 0u8, 0u8, 121u8, 3u8,
+            0u8, 0u8, 128u8, 3u8, 0u8, 0u8, 133u8, 3u8, 0u8, 0u8, 143u8, 3u8, 0u8, 0u8, 152u8, 3u8,
+            0u8, 0u8, 156u8, 3u8, 0u8, 0u8, 159u8, 3u8, 0u8, 0u8, 160u8, 3u8, 0u8, 0u8, 164u8, 3u8,
+            0u8, 0u8, 169u8, 3u8, 0u8, 0u8, 177u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8, 0u8, 181u8, 3u8,
+            0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 190u8, 3u8, 0u8, 0u8, 197u8, 3u8, 0u8, 0u8, 200u8, 3u8,
+            0u8, 0u8, 207u8, 3u8, 0u8, 0u8, 213u8, 3u8, 0u8, 0u8, 224u8, 3u8, 0u8, 0u8, 231u8, 3u8,
+            0u8, 0u8, 239u8, 3u8, 0u8, 0u8, 241u8, 3u8, 0u8, 0u8, 247u8, 3u8, 0u8, 0u8, 250u8, 3u8,
+            0u8, 0u8, 251u8, 3u8, 0u8, 0u8, 6u8, 4u8, 0u8, 0u8, 14u8, 4u8, 0u8, 0u8, 24u8, 4u8,
+            0u8, 0u8, 35u8, 4u8, 0u8, 0u8, 43u8, 4u8, 0u8, 0u8, 47u8, 4u8, 0u8, 0u8, 53u8, 4u8,
+            0u8, 0u8, 59u8, 4u8, 0u8, 0u8, 64u8, 4u8, 0u8, 0u8, 70u8, 4u8, 0u8, 0u8, 73u8, 4u8,
+            0u8, 0u8, 78u8, 4u8, 0u8, 0u8, 85u8, 4u8, 0u8, 0u8, 90u8, 4u8, 0u8, 0u8, 94u8, 4u8,
+            0u8, 0u8, 101u8, 4u8, 0u8, 0u8, 111u8, 4u8, 0u8, 0u8, 121u8, 4u8, 0u8, 0u8, 129u8, 4u8,
+            0u8, 0u8, 134u8, 4u8, 0u8, 0u8, 140u8, 4u8, 0u8, 0u8, 143u8, 4u8, 0u8, 0u8, 148u8, 4u8,
+            0u8, 0u8, 156u8, 4u8, 0u8, 0u8, 163u8, 4u8, 0u8, 0u8, 174u8, 4u8, 0u8, 0u8, 181u8, 4u8,
+            0u8, 0u8, 188u8, 4u8, 0u8, 0u8, 192u8, 4u8, 0u8, 0u8, 197u8, 4u8, 0u8, 0u8, 203u8, 4u8,
+            0u8, 0u8, 212u8, 4u8, 0u8, 0u8, 215u8, 4u8, 0u8, 0u8, 220u8, 4u8, 0u8, 0u8, 226u8, 4u8,
+            0u8, 0u8, 229u8, 4u8, 0u8, 0u8, 232u8, 4u8, 0u8, 0u8, 237u8, 4u8, 0u8, 0u8, 245u8, 4u8,
+            0u8, 0u8, 251u8, 4u8, 0u8, 0u8, 1u8, 5u8, 0u8, 0u8, 6u8, 5u8, 0u8, 0u8, 12u8, 5u8, 0u8,
+            0u8, 15u8, 5u8, 0u8, 0u8, 22u8, 5u8, 0u8, 0u8, 29u8, 5u8, 0u8, 0u8, 35u8, 5u8, 0u8,
+            0u8, 40u8, 5u8, 0u8, 0u8, 44u8, 5u8, 0u8, 0u8, 49u8, 5u8, 0u8, 0u8, 53u8, 5u8, 0u8,
+            0u8, 68u8, 5u8, 0u8, 0u8, 71u8, 5u8, 0u8, 0u8, 76u8, 5u8, 0u8, 0u8, 88u8, 5u8, 0u8,
+            0u8, 90u8, 5u8, 0u8, 0u8, 99u8, 5u8, 0u8, 0u8, 106u8, 5u8, 0u8, 0u8, 116u8, 5u8, 0u8,
+            0u8, 122u8, 5u8, 0u8, 0u8, 135u8, 5u8, 0u8, 0u8, 148u8, 5u8, 0u8, 0u8, 163u8, 5u8, 0u8,
+            0u8, 176u8, 5u8, 0u8, 0u8, 181u8, 5u8, 0u8, 0u8, 188u8, 5u8, 0u8, 0u8, 194u8, 5u8, 0u8,
+            0u8, 200u8, 5u8, 0u8, 0u8, 204u8, 5u8, 0u8, 0u8, 209u8, 5u8, 0u8, 0u8, 215u8, 5u8, 0u8,
+            0u8, 228u8, 5u8, 0u8, 0u8, 231u8, 5u8, 0u8, 0u8, 237u8, 5u8, 0u8, 0u8, 242u8, 5u8, 0u8,
+            0u8, 244u8, 5u8, 0u8, 0u8, 1u8, 6u8, 0u8, 0u8, 6u8, 6u8, 0u8, 0u8, 13u8, 6u8, 0u8, 0u8,
+            16u8, 6u8, 0u8, 0u8, 23u8, 6u8, 0u8, 0u8, 28u8, 6u8, 0u8, 0u8, 35u8, 6u8, 0u8, 0u8,
+            40u8, 6u8, 0u8, 0u8, 51u8, 6u8, 0u8, 0u8, 59u8, 6u8, 0u8, 0u8, 67u8, 6u8, 0u8, 0u8,
+            72u8, 6u8, 0u8, 0u8, 83u8, 6u8, 0u8, 0u8, 94u8, 6u8, 0u8, 0u8, 106u8, 6u8, 0u8, 0u8,
+            116u8, 6u8, 0u8, 0u8, 128u8, 6u8, 0u8, 0u8, 130u8, 6u8, 0u8, 0u8, 132u8, 6u8, 0u8, 0u8,
+            136u8, 6u8, 0u8, 0u8, 140u8, 6u8, 0u8, 0u8, 145u8, 6u8, 0u8, 0u8, 147u8, 6u8, 0u8, 0u8,
+            151u8, 6u8, 0u8, 0u8, 158u8, 6u8, 0u8, 0u8, 160u8, 6u8, 0u8, 0u8, 168u8, 6u8, 0u8, 0u8,
+            175u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 190u8, 6u8, 0u8, 0u8, 193u8, 6u8, 0u8, 0u8,
+            200u8, 6u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 217u8, 6u8, 0u8, 0u8, 222u8, 6u8, 0u8, 0u8,
+            235u8, 6u8, 0u8, 0u8, 245u8, 6u8, 0u8, 0u8, 252u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8,
+            5u8, 7u8, 0u8, 0u8, 8u8, 7u8, 0u8, 0u8, 17u8, 7u8, 0u8, 0u8, 21u8, 7u8, 0u8, 0u8, 26u8,
+            7u8, 0u8, 0u8, 31u8, 7u8, 0u8, 0u8, 35u8, 7u8, 0u8, 0u8, 38u8, 7u8, 0u8, 0u8, 48u8,
+            7u8, 0u8, 0u8, 57u8, 7u8, 0u8, 0u8, 62u8, 7u8, 0u8, 0u8, 73u8, 7u8, 0u8, 0u8, 84u8,
+            7u8, 0u8, 0u8, 98u8, 7u8, 0u8, 0u8, 102u8, 7u8, 0u8, 0u8, 109u8, 7u8, 0u8, 0u8, 117u8,
+            7u8, 0u8, 0u8, 126u8, 7u8, 0u8, 0u8, 132u8, 7u8, 0u8, 0u8, 138u8, 7u8, 0u8, 0u8, 145u8,
+            7u8, 0u8, 0u8, 150u8, 7u8, 0u8, 0u8, 154u8, 7u8, 0u8, 0u8, 165u8, 7u8, 0u8, 0u8, 175u8,
+            7u8, 0u8, 0u8, 185u8, 7u8, 0u8, 0u8, 196u8, 7u8, 0u8, 0u8, 203u8, 7u8, 0u8, 0u8, 210u8,
+            7u8, 0u8, 0u8, 215u8, 7u8, 0u8, 0u8, 222u8, 7u8, 0u8, 0u8, 232u8, 7u8, 0u8, 0u8, 243u8,
+            7u8, 0u8, 0u8, 253u8, 7u8, 0u8, 0u8, 7u8, 8u8, 0u8, 0u8, 16u8, 8u8, 0u8, 0u8, 21u8,
+            8u8, 0u8, 0u8, 26u8, 8u8, 0u8, 0u8, 34u8, 8u8, 0u8, 0u8, 39u8, 8u8, 0u8, 0u8, 48u8,
+            8u8, 0u8, 0u8, 54u8, 8u8, 0u8, 0u8, 63u8, 8u8, 0u8, 0u8, 67u8, 8u8, 0u8, 0u8, 71u8,
+            8u8, 0u8, 0u8, 78u8, 8u8, 0u8, 0u8, 83u8, 8u8, 0u8, 0u8, 90u8, 8u8, 0u8, 0u8, 96u8,
+            8u8, 0u8, 0u8, 109u8, 8u8, 0u8, 0u8, 121u8, 8u8, 0u8, 0u8, 133u8, 8u8, 0u8, 0u8, 139u8,
+            8u8, 0u8, 0u8, 146u8, 8u8, 0u8, 0u8, 154u8, 8u8, 0u8, 0u8, 163u8, 8u8, 0u8, 0u8, 169u8,
+            8u8, 0u8, 0u8, 172u8, 8u8, 0u8, 0u8, 179u8, 8u8, 0u8, 0u8, 183u8, 8u8, 0u8, 0u8, 194u8,
+            8u8, 0u8, 0u8, 202u8, 8u8, 0u8, 0u8, 206u8, 8u8, 0u8, 0u8, 210u8, 8u8, 0u8, 0u8, 214u8,
+            8u8, 0u8, 0u8, 217u8, 8u8, 0u8, 0u8, 220u8, 8u8, 0u8, 0u8, 223u8, 8u8, 0u8, 0u8, 226u8,
+            8u8, 0u8, 0u8, 237u8, 8u8, 0u8, 0u8, 244u8, 8u8, 0u8, 0u8, 251u8, 8u8, 0u8, 0u8, 0u8,
+            9u8, 0u8, 0u8, 7u8, 9u8, 0u8, 0u8, 16u8, 9u8, 0u8, 0u8, 24u8, 9u8, 0u8, 0u8, 33u8, 9u8,
+            0u8, 0u8, 39u8, 9u8, 0u8, 0u8, 43u8, 9u8, 0u8, 0u8, 46u8, 9u8, 0u8, 0u8, 54u8, 9u8,
+            0u8, 0u8, 59u8, 9u8, 0u8, 0u8, 67u8, 9u8, 0u8, 0u8, 74u8, 9u8, 0u8, 0u8, 81u8, 9u8,
+            0u8, 0u8, 86u8, 9u8, 0u8, 0u8, 95u8, 9u8, 0u8, 0u8, 104u8, 9u8, 0u8, 0u8, 112u8, 9u8,
+            0u8, 0u8, 124u8, 9u8, 0u8, 0u8, 126u8, 9u8, 0u8, 0u8, 130u8, 9u8, 0u8, 0u8, 139u8, 9u8,
+            0u8, 0u8, 147u8, 9u8, 0u8, 0u8, 151u8, 9u8, 0u8, 0u8, 159u8, 9u8, 0u8, 0u8, 165u8, 9u8,
+            0u8, 0u8, 168u8, 9u8, 0u8, 0u8, 176u8, 9u8, 0u8, 0u8, 185u8, 9u8, 0u8, 0u8, 190u8, 9u8,
+            0u8, 0u8, 193u8, 9u8, 0u8, 0u8, 196u8, 9u8, 0u8, 0u8, 202u8, 9u8, 0u8, 0u8, 204u8, 9u8,
+            0u8, 0u8, 206u8, 9u8, 0u8, 0u8, 210u8, 9u8, 0u8, 0u8, 218u8, 9u8, 0u8, 0u8, 221u8, 9u8,
+            0u8, 0u8, 227u8, 9u8, 0u8, 0u8, 236u8, 9u8, 0u8, 0u8, 244u8, 9u8, 0u8, 0u8, 250u8, 9u8,
+            0u8, 0u8, 1u8, 10u8, 0u8, 0u8, 4u8, 10u8, 0u8, 0u8, 8u8, 10u8, 0u8, 0u8, 26u8, 10u8,
+            0u8, 0u8, 42u8, 10u8, 0u8, 0u8, 57u8, 10u8, 0u8, 0u8, 78u8, 10u8, 0u8, 0u8, 98u8, 10u8,
+            0u8, 0u8, 103u8, 10u8, 0u8, 0u8, 109u8, 10u8, 0u8, 0u8, 114u8, 10u8, 0u8, 0u8, 117u8,
+            10u8, 0u8, 0u8, 130u8, 10u8, 0u8, 0u8, 145u8, 10u8, 0u8, 0u8, 151u8, 10u8, 0u8, 0u8,
+            166u8, 10u8, 0u8, 0u8, 179u8, 10u8, 0u8, 0u8, 191u8, 10u8, 0u8, 0u8, 209u8, 10u8, 0u8,
+            0u8, 226u8, 10u8, 0u8, 0u8, 232u8, 10u8, 0u8, 0u8, 243u8, 10u8, 0u8, 0u8, 250u8, 10u8,
+            0u8, 0u8, 255u8, 10u8, 0u8, 0u8, 5u8, 11u8, 0u8, 0u8, 11u8, 11u8, 0u8, 0u8, 16u8, 11u8,
+            0u8, 0u8, 21u8, 11u8, 0u8, 0u8, 32u8, 11u8, 0u8, 0u8, 41u8, 11u8, 0u8, 0u8, 48u8, 11u8,
+            0u8, 0u8, 50u8, 11u8, 0u8, 0u8, 58u8, 11u8, 0u8, 0u8, 68u8, 11u8, 0u8, 0u8, 75u8, 11u8,
+            0u8, 0u8, 78u8, 11u8, 0u8, 0u8, 81u8, 11u8, 0u8, 0u8, 89u8, 11u8, 0u8, 0u8, 97u8, 11u8,
+            0u8, 0u8, 101u8, 11u8, 0u8, 0u8, 103u8, 11u8, 0u8, 0u8, 106u8, 11u8, 0u8, 0u8, 109u8,
+            11u8, 0u8, 0u8, 112u8, 11u8, 0u8, 0u8, 115u8, 11u8, 0u8, 0u8, 118u8, 11u8, 0u8, 0u8,
+            124u8, 11u8, 0u8, 0u8, 127u8, 11u8, 0u8, 0u8, 133u8, 11u8, 0u8, 0u8, 138u8, 11u8, 0u8,
+            0u8, 144u8, 11u8, 0u8, 0u8, 154u8, 11u8, 0u8, 0u8, 160u8, 11u8, 0u8, 0u8, 168u8, 11u8,
+            0u8, 0u8, 176u8, 11u8, 0u8, 0u8, 183u8, 11u8, 0u8, 0u8, 186u8, 11u8, 0u8, 0u8, 192u8,
+            11u8, 0u8, 0u8, 198u8, 11u8, 0u8, 0u8, 211u8, 11u8, 0u8, 0u8, 216u8, 11u8, 0u8, 0u8,
+            221u8, 11u8, 0u8, 0u8, 225u8, 11u8, 0u8, 0u8, 230u8, 11u8, 0u8, 0u8, 238u8, 11u8, 0u8,
+            0u8, 241u8, 11u8, 0u8, 0u8, 248u8, 11u8, 0u8, 0u8, 255u8, 11u8, 0u8, 0u8, 6u8, 12u8,
+            0u8, 0u8, 8u8, 12u8, 0u8, 0u8, 13u8, 12u8, 0u8, 0u8, 17u8, 12u8, 0u8, 0u8, 21u8, 12u8,
+            0u8, 0u8, 24u8, 12u8, 0u8, 0u8, 27u8, 12u8, 0u8, 0u8, 30u8, 12u8, 0u8, 0u8, 33u8, 12u8,
+            0u8, 0u8, 39u8, 12u8, 0u8, 0u8, 42u8, 12u8, 0u8, 0u8, 45u8, 12u8, 0u8, 0u8, 47u8, 12u8,
+            0u8, 0u8, 50u8, 12u8, 0u8, 0u8, 53u8, 12u8, 0u8, 0u8, 55u8, 12u8, 0u8, 0u8, 60u8, 12u8,
+            0u8, 0u8, 64u8, 12u8, 0u8, 0u8, 70u8, 12u8, 0u8, 0u8, 76u8, 12u8, 0u8, 0u8, 82u8, 12u8,
+            0u8, 0u8, 88u8, 12u8, 0u8, 0u8, 94u8, 12u8, 0u8, 0u8, 100u8, 12u8, 0u8, 0u8, 107u8,
+            12u8, 0u8, 0u8, 112u8, 12u8, 0u8, 0u8, 120u8, 12u8, 0u8, 0u8, 125u8, 12u8, 0u8, 0u8,
+            127u8, 12u8, 0u8, 0u8, 131u8, 12u8, 0u8, 0u8, 135u8, 12u8, 0u8, 0u8, 139u8, 12u8, 0u8,
+            0u8, 151u8, 12u8, 0u8, 0u8, 157u8, 12u8, 0u8, 0u8, 167u8, 12u8, 0u8, 0u8, 174u8, 12u8,
+            0u8, 0u8, 182u8, 12u8, 0u8, 0u8, 193u8, 12u8, 0u8, 0u8, 201u8, 12u8, 0u8, 0u8, 209u8,
+            12u8, 0u8, 0u8, 211u8, 12u8, 0u8, 0u8, 214u8, 12u8, 0u8, 0u8, 218u8, 12u8, 0u8, 0u8,
+            222u8, 12u8, 0u8, 0u8, 230u8, 12u8, 0u8, 0u8, 237u8, 12u8, 0u8, 0u8, 242u8, 12u8, 0u8,
+            0u8, 248u8, 12u8, 0u8, 0u8, 255u8, 12u8, 0u8, 0u8, 6u8, 13u8, 0u8, 0u8, 15u8, 13u8,
+            0u8, 0u8, 27u8, 13u8, 0u8, 0u8, 33u8, 13u8, 0u8, 0u8, 38u8, 13u8, 0u8, 0u8, 40u8, 13u8,
+            0u8, 0u8, 44u8, 13u8, 0u8, 0u8, 48u8, 13u8, 0u8, 0u8, 55u8, 13u8, 0u8, 0u8, 62u8, 13u8,
+            0u8, 0u8, 71u8, 13u8, 0u8, 0u8, 77u8, 13u8, 0u8, 0u8, 83u8, 13u8, 0u8, 0u8, 89u8, 13u8,
+            0u8, 0u8, 100u8, 13u8, 0u8, 0u8, 111u8, 13u8, 0u8, 0u8, 122u8, 13u8, 0u8, 0u8, 133u8,
+            13u8, 0u8, 0u8, 141u8, 13u8, 0u8, 0u8, 145u8, 13u8, 0u8, 0u8, 148u8, 13u8, 0u8, 0u8,
+            150u8, 13u8, 0u8, 0u8, 153u8, 13u8, 0u8, 0u8, 156u8, 13u8, 0u8, 0u8, 159u8, 13u8, 0u8,
+            0u8, 161u8, 13u8, 0u8, 0u8, 166u8, 13u8, 0u8, 0u8, 178u8, 13u8, 0u8, 0u8, 189u8, 13u8,
+            0u8, 0u8, 192u8, 13u8, 0u8, 0u8, 199u8, 13u8, 0u8, 0u8, 203u8, 13u8, 0u8, 0u8, 206u8,
+            13u8, 0u8, 0u8, 209u8, 13u8, 0u8, 0u8, 213u8, 13u8, 0u8, 0u8, 216u8, 13u8, 0u8, 0u8,
+            220u8, 13u8, 0u8, 0u8, 233u8, 13u8, 0u8, 0u8, 244u8, 13u8, 0u8, 0u8, 1u8, 14u8, 0u8,
+            0u8, 12u8, 14u8, 0u8, 0u8, 25u8, 14u8, 0u8, 0u8, 38u8, 14u8, 0u8, 0u8, 49u8, 14u8, 0u8,
+            0u8, 59u8, 14u8, 0u8, 0u8, 76u8, 14u8, 0u8, 0u8, 89u8, 14u8, 0u8, 0u8, 101u8, 14u8,
+            0u8, 0u8, 104u8, 14u8, 0u8, 0u8, 113u8, 14u8, 0u8, 0u8, 124u8, 14u8, 0u8, 0u8, 135u8,
+            14u8, 0u8, 0u8, 139u8, 14u8, 0u8, 0u8, 147u8, 14u8, 0u8, 0u8, 156u8, 14u8, 0u8, 0u8,
+            160u8, 14u8, 0u8, 0u8, 167u8, 14u8, 0u8, 0u8, 180u8, 14u8, 0u8, 0u8, 198u8, 14u8, 0u8,
+            0u8, 206u8, 14u8, 0u8, 0u8, 209u8, 14u8, 0u8, 0u8, 223u8, 14u8, 0u8, 0u8, 235u8, 14u8,
+            0u8, 0u8, 249u8, 14u8, 0u8, 0u8, 5u8, 15u8, 0u8, 0u8, 17u8, 15u8, 0u8, 0u8, 29u8, 15u8,
+            0u8, 0u8, 40u8, 15u8, 0u8, 0u8, 58u8, 15u8, 0u8, 0u8, 71u8, 15u8, 0u8, 0u8, 74u8, 15u8,
+            0u8, 0u8, 78u8, 15u8, 0u8, 0u8, 84u8, 15u8, 0u8, 0u8, 88u8, 15u8, 0u8, 0u8, 95u8, 15u8,
+            0u8, 0u8, 106u8, 15u8, 0u8, 0u8, 115u8, 15u8, 0u8, 0u8, 118u8, 15u8, 0u8, 0u8, 127u8,
+            15u8, 0u8, 0u8, 129u8, 15u8, 0u8, 0u8, 134u8, 15u8, 0u8, 0u8, 144u8, 15u8, 0u8, 0u8,
+            153u8, 15u8, 0u8, 0u8, 159u8, 15u8, 0u8, 0u8, 163u8, 15u8, 0u8, 0u8, 167u8, 15u8, 0u8,
+            0u8, 175u8, 15u8, 0u8, 0u8, 178u8, 15u8, 0u8, 0u8, 181u8, 15u8, 0u8, 0u8, 184u8, 15u8,
+            0u8, 0u8, 187u8, 15u8, 0u8, 0u8, 190u8, 15u8, 0u8, 0u8, 198u8, 15u8, 0u8, 0u8, 207u8,
+            15u8, 0u8, 0u8, 216u8, 15u8, 0u8, 0u8, 228u8, 15u8, 0u8, 0u8, 235u8, 15u8, 0u8, 0u8,
+            240u8, 15u8, 0u8, 0u8, 253u8, 15u8, 0u8, 0u8, 8u8, 16u8, 0u8, 0u8, 20u8, 16u8, 0u8,
+            0u8, 33u8, 16u8, 0u8, 0u8, 44u8, 16u8, 0u8, 0u8, 54u8, 16u8, 0u8, 0u8, 69u8, 16u8, 0u8,
+            0u8, 74u8, 16u8, 0u8, 0u8, 79u8, 16u8, 0u8, 0u8, 83u8, 16u8, 0u8, 0u8, 97u8, 16u8, 0u8,
+            0u8, 99u8, 16u8, 0u8, 0u8, 102u8, 16u8, 0u8, 0u8, 108u8, 16u8, 0u8, 0u8, 116u8, 16u8,
+            0u8, 0u8, 123u8, 16u8, 0u8, 0u8, 126u8, 16u8, 0u8, 0u8, 130u8, 16u8, 0u8, 0u8, 138u8,
+            16u8, 0u8, 0u8, 141u8, 16u8, 0u8, 0u8, 148u8, 16u8, 0u8, 0u8, 154u8, 16u8, 0u8, 0u8,
+            157u8, 16u8, 0u8, 0u8, 161u8, 16u8, 0u8, 0u8, 174u8, 16u8, 0u8, 0u8, 185u8, 16u8, 0u8,
+            0u8, 198u8, 16u8, 0u8, 0u8, 202u8, 16u8, 0u8, 0u8, 204u8, 16u8, 0u8, 0u8, 209u8, 16u8,
+            0u8, 0u8, 216u8, 16u8, 0u8, 0u8, 228u8, 16u8, 0u8, 0u8, 238u8, 16u8, 0u8, 0u8, 241u8,
+            16u8, 0u8, 0u8, 244u8, 16u8, 0u8, 0u8, 248u8, 16u8, 0u8, 0u8, 254u8, 16u8, 0u8, 0u8,
+            1u8, 17u8, 0u8, 0u8, 5u8, 17u8, 0u8, 0u8, 8u8, 17u8, 0u8, 0u8, 11u8, 17u8, 0u8, 0u8,
+            15u8, 17u8, 0u8, 0u8, 20u8, 17u8, 0u8, 0u8, 24u8, 17u8, 0u8, 0u8, 29u8, 17u8, 0u8, 0u8,
+            36u8, 17u8, 0u8, 0u8, 45u8, 17u8, 0u8, 0u8, 54u8, 17u8, 0u8, 0u8, 64u8, 17u8, 0u8, 0u8,
+            69u8, 17u8, 0u8, 0u8, 77u8, 17u8, 0u8, 0u8, 80u8, 17u8, 0u8, 0u8, 83u8, 17u8, 0u8, 0u8,
+            91u8, 17u8, 0u8, 0u8, 99u8, 17u8, 0u8, 0u8, 102u8, 17u8, 0u8, 0u8, 109u8, 17u8, 0u8,
+            0u8, 116u8, 17u8, 0u8, 0u8, 122u8, 17u8, 0u8, 0u8, 131u8, 17u8, 0u8, 0u8, 149u8, 17u8,
+            0u8, 0u8, 160u8, 17u8, 0u8, 0u8, 163u8, 17u8, 0u8, 0u8, 166u8, 17u8, 0u8, 0u8, 171u8,
+            17u8, 0u8, 0u8, 178u8, 17u8, 0u8, 0u8, 187u8, 17u8, 0u8, 0u8, 196u8, 17u8, 0u8, 0u8,
+            202u8, 17u8, 0u8, 0u8, 215u8, 17u8, 0u8, 0u8, 226u8, 17u8, 0u8, 0u8, 237u8, 17u8, 0u8,
+            0u8, 249u8, 17u8, 0u8, 0u8, 4u8, 18u8, 0u8, 0u8, 15u8, 18u8, 0u8, 0u8, 31u8, 18u8, 0u8,
+            0u8, 48u8, 18u8, 0u8, 0u8, 64u8, 18u8, 0u8, 0u8, 81u8, 18u8, 0u8, 0u8, 96u8, 18u8, 0u8,
+            0u8, 109u8, 18u8, 0u8, 0u8, 121u8, 18u8, 0u8, 0u8, 127u8, 18u8, 0u8, 0u8, 130u8, 18u8,
+            0u8, 0u8, 133u8, 18u8, 0u8, 0u8, 143u8, 18u8, 0u8, 0u8, 148u8, 18u8, 0u8, 0u8, 150u8,
+            18u8, 0u8, 0u8, 155u8, 18u8, 0u8, 0u8, 163u8, 18u8, 0u8, 0u8, 169u8, 18u8, 0u8, 0u8,
+            176u8, 18u8, 0u8, 0u8, 183u8, 18u8, 0u8, 0u8, 186u8, 18u8, 0u8, 0u8, 189u8, 18u8, 0u8,
+            0u8, 193u8, 18u8, 0u8, 0u8, 195u8, 18u8, 0u8, 0u8, 204u8, 18u8, 0u8, 0u8, 212u8, 18u8,
+            0u8, 0u8, 220u8, 18u8, 0u8, 0u8, 231u8, 18u8, 0u8, 0u8, 237u8, 18u8, 0u8, 0u8, 245u8,
+            18u8, 0u8, 0u8, 254u8, 18u8, 0u8, 0u8, 8u8, 19u8, 0u8, 0u8, 14u8, 19u8, 0u8, 0u8, 17u8,
+            19u8, 0u8, 0u8, 19u8, 19u8, 0u8, 0u8, 38u8, 19u8, 0u8, 0u8, 51u8, 19u8, 0u8, 0u8, 63u8,
+            19u8, 0u8, 0u8, 80u8, 19u8, 0u8, 0u8, 91u8, 19u8, 0u8, 0u8, 109u8, 19u8, 0u8, 0u8,
+            125u8, 19u8, 0u8, 0u8, 138u8, 19u8, 0u8, 0u8, 151u8, 19u8, 0u8, 0u8, 162u8, 19u8, 0u8,
+            0u8, 179u8, 19u8, 0u8, 0u8, 195u8, 19u8, 0u8, 0u8, 205u8, 19u8, 0u8, 0u8, 220u8, 19u8,
+            0u8, 0u8, 233u8, 19u8, 0u8, 0u8, 245u8, 19u8, 0u8, 0u8, 7u8, 20u8, 0u8, 0u8, 24u8,
+            20u8, 0u8, 0u8, 36u8, 20u8, 0u8, 0u8, 46u8, 20u8, 0u8, 0u8, 49u8, 20u8, 0u8, 0u8, 52u8,
+            20u8, 0u8, 0u8, 55u8, 20u8, 0u8, 0u8, 58u8, 20u8, 0u8, 0u8, 65u8, 20u8, 0u8, 0u8, 67u8,
+            20u8, 0u8, 0u8, 78u8, 20u8, 0u8, 0u8, 86u8, 20u8, 0u8, 0u8, 92u8, 20u8, 0u8, 0u8, 98u8,
+            20u8, 0u8, 0u8, 103u8, 20u8, 0u8, 0u8, 106u8, 20u8, 0u8, 0u8, 114u8, 20u8, 0u8, 0u8,
+            121u8, 20u8, 0u8, 0u8, 132u8, 20u8, 0u8, 0u8, 140u8, 20u8, 0u8, 0u8, 154u8, 20u8, 0u8,
+            0u8, 159u8, 20u8, 0u8, 0u8, 166u8, 20u8, 0u8, 0u8, 169u8, 20u8, 0u8, 0u8, 175u8, 20u8,
+            0u8, 0u8, 183u8, 20u8, 0u8, 0u8, 189u8, 20u8, 0u8, 0u8, 195u8, 20u8, 0u8, 0u8, 201u8,
+            20u8, 0u8, 0u8, 204u8, 20u8, 0u8, 0u8, 206u8, 20u8, 0u8, 0u8, 212u8, 20u8, 0u8, 0u8,
+            226u8, 20u8, 0u8, 0u8, 240u8, 20u8, 0u8, 0u8, 252u8, 20u8, 0u8, 0u8, 6u8, 21u8, 0u8,
+            0u8, 16u8, 21u8, 0u8, 0u8, 26u8, 21u8, 0u8, 0u8, 38u8, 21u8, 0u8, 0u8, 48u8, 21u8, 0u8,
+            0u8, 58u8, 21u8, 0u8, 0u8, 70u8, 21u8, 0u8, 0u8, 87u8, 21u8, 0u8, 0u8, 101u8, 21u8,
+            0u8, 0u8, 113u8, 21u8, 0u8, 0u8, 117u8, 21u8, 0u8, 0u8, 127u8, 21u8, 0u8, 0u8, 136u8,
+            21u8, 0u8, 0u8, 141u8, 21u8, 0u8, 0u8, 146u8, 21u8, 0u8, 0u8, 151u8, 21u8, 0u8, 0u8,
+            156u8, 21u8, 0u8, 0u8, 163u8, 21u8, 0u8, 0u8, 167u8, 21u8, 0u8, 0u8, 171u8, 21u8, 0u8,
+            0u8, 173u8, 21u8, 0u8, 0u8, 179u8, 21u8, 0u8, 0u8, 183u8, 21u8, 0u8, 0u8, 186u8, 21u8,
+            0u8, 0u8, 189u8, 21u8, 0u8, 0u8, 191u8, 21u8, 0u8, 0u8, 194u8, 21u8, 0u8, 0u8, 201u8,
+            21u8, 0u8, 0u8, 204u8, 21u8, 0u8, 0u8, 207u8, 21u8, 0u8, 0u8, 210u8, 21u8, 0u8, 0u8,
+            220u8, 21u8, 0u8, 0u8, 230u8, 21u8, 0u8, 0u8, 240u8, 21u8, 0u8, 0u8, 250u8, 21u8, 0u8,
+            0u8, 5u8, 22u8, 0u8, 0u8, 18u8, 22u8, 0u8, 0u8, 20u8, 22u8, 0u8, 0u8, 25u8, 22u8, 0u8,
+            0u8, 31u8, 22u8, 0u8, 0u8, 37u8, 22u8, 0u8, 0u8, 40u8, 22u8, 0u8, 0u8, 43u8, 22u8, 0u8,
+            0u8, 46u8, 22u8, 0u8, 0u8, 56u8, 22u8, 0u8, 0u8, 62u8, 22u8, 0u8, 0u8, 67u8, 22u8, 0u8,
+            0u8, 70u8, 22u8, 0u8, 0u8, 81u8, 22u8, 0u8, 0u8, 87u8, 22u8, 0u8, 0u8, 90u8, 22u8, 0u8,
+            0u8, 97u8, 22u8, 0u8, 0u8, 103u8, 22u8, 0u8, 0u8, 106u8, 22u8, 0u8, 0u8, 110u8, 22u8,
+            0u8, 0u8, 120u8, 22u8, 0u8, 0u8, 124u8, 22u8, 0u8, 0u8, 132u8, 22u8, 0u8, 0u8, 144u8,
+            22u8, 0u8, 0u8, 156u8, 22u8, 0u8, 0u8, 159u8, 22u8, 0u8, 0u8, 164u8, 22u8, 0u8, 0u8,
+            172u8, 22u8, 0u8, 0u8, 180u8, 22u8, 0u8, 0u8, 188u8, 22u8, 0u8, 0u8, 198u8, 22u8, 0u8,
+            0u8, 206u8, 22u8, 0u8, 0u8, 211u8, 22u8, 0u8, 0u8, 217u8, 22u8, 0u8, 0u8, 223u8, 22u8,
+            0u8, 0u8, 229u8, 22u8, 0u8, 0u8, 235u8, 22u8, 0u8, 0u8, 241u8, 22u8, 0u8, 0u8, 247u8,
+            22u8, 0u8, 0u8, 253u8, 22u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 4u8, 23u8, 0u8, 0u8, 11u8,
+            23u8, 0u8, 0u8, 14u8, 23u8, 0u8, 0u8, 18u8, 23u8, 0u8, 0u8, 22u8, 23u8, 0u8, 0u8, 31u8,
+            23u8, 0u8, 0u8, 38u8, 23u8, 0u8, 0u8, 41u8, 23u8, 0u8, 0u8, 43u8, 23u8, 0u8, 0u8, 48u8,
+            23u8, 0u8, 0u8, 53u8, 23u8, 0u8, 0u8, 63u8, 23u8, 0u8, 0u8, 68u8, 23u8, 0u8, 0u8, 73u8,
+            23u8, 0u8, 0u8, 80u8, 23u8, 0u8, 0u8, 82u8, 23u8, 0u8, 0u8, 85u8, 23u8, 0u8, 0u8, 87u8,
+            23u8, 0u8, 0u8, 90u8, 23u8, 0u8, 0u8, 97u8, 23u8, 0u8, 0u8, 104u8, 23u8, 0u8, 0u8,
+            109u8, 23u8, 0u8, 0u8, 111u8, 23u8, 0u8, 0u8, 114u8, 23u8, 0u8, 0u8, 118u8, 23u8, 0u8,
+            0u8, 123u8, 23u8, 0u8, 0u8, 133u8, 23u8, 0u8, 0u8, 135u8, 23u8, 0u8, 0u8, 139u8, 23u8,
+            0u8, 0u8, 141u8, 23u8, 0u8, 0u8, 145u8, 23u8, 0u8, 0u8, 147u8, 23u8, 0u8, 0u8, 151u8,
+            23u8, 0u8, 0u8, 155u8, 23u8, 0u8, 0u8, 160u8, 23u8, 0u8, 0u8, 163u8, 23u8, 0u8, 0u8,
+            165u8, 23u8, 0u8, 0u8, 168u8, 23u8, 0u8, 0u8, 171u8, 23u8, 0u8, 0u8, 176u8, 23u8, 0u8,
+            0u8, 181u8, 23u8, 0u8, 0u8, 187u8, 23u8, 0u8, 0u8, 203u8, 23u8, 0u8, 0u8, 218u8, 23u8,
+            0u8, 0u8, 234u8, 23u8, 0u8, 0u8, 247u8, 23u8, 0u8, 0u8, 11u8, 24u8, 0u8, 0u8, 13u8,
+            24u8, 0u8, 0u8, 17u8, 24u8, 0u8, 0u8, 19u8, 24u8, 0u8, 0u8, 23u8, 24u8, 0u8, 0u8, 27u8,
+            24u8, 0u8, 0u8, 32u8, 24u8, 0u8, 0u8, 38u8, 24u8, 0u8, 0u8, 44u8, 24u8, 0u8, 0u8, 46u8,
+            24u8, 0u8, 0u8, 48u8, 24u8, 0u8, 0u8, 52u8, 24u8, 0u8, 0u8, 56u8, 24u8, 0u8, 0u8, 61u8,
+            24u8, 0u8, 0u8, 65u8, 24u8, 0u8, 0u8, 69u8, 24u8, 0u8, 0u8, 74u8, 24u8, 0u8, 0u8, 80u8,
+            24u8, 0u8, 0u8, 83u8, 24u8, 0u8, 0u8, 89u8, 24u8, 0u8, 0u8, 93u8, 24u8, 0u8, 0u8, 76u8,
+            69u8, 84u8, 84u8, 69u8, 82u8, 76u8, 65u8, 84u8, 73u8, 78u8, 87u8, 73u8, 84u8, 72u8,
+            72u8, 65u8, 78u8, 71u8, 85u8, 76u8, 67u8, 65u8, 80u8, 73u8, 84u8, 65u8, 76u8, 83u8,
+            77u8, 65u8, 76u8, 76u8, 65u8, 82u8, 65u8, 66u8, 73u8, 67u8, 70u8, 79u8, 82u8, 77u8,
+            67u8, 72u8, 79u8, 83u8, 69u8, 79u8, 78u8, 71u8, 74u8, 79u8, 78u8, 71u8, 83u8, 69u8,
+            79u8, 78u8, 71u8, 74u8, 85u8, 78u8, 71u8, 83u8, 69u8, 79u8, 78u8, 71u8, 73u8, 83u8,
+            79u8, 76u8, 65u8, 84u8, 69u8, 68u8, 83u8, 73u8, 71u8, 78u8, 79u8, 67u8, 65u8, 82u8,
+            79u8, 78u8, 85u8, 65u8, 66u8, 79u8, 86u8, 69u8, 65u8, 67u8, 85u8, 84u8, 69u8, 70u8,
+            73u8, 78u8, 65u8, 76u8, 83u8, 84u8, 82u8, 79u8, 75u8, 69u8, 87u8, 72u8, 73u8, 84u8,
+            69u8, 65u8, 66u8, 76u8, 65u8, 67u8, 75u8, 65u8, 78u8, 68u8, 69u8, 68u8, 79u8, 84u8,
+            68u8, 79u8, 85u8, 66u8, 76u8, 69u8, 77u8, 65u8, 67u8, 82u8, 79u8, 78u8, 73u8, 77u8,
+            69u8, 68u8, 73u8, 65u8, 76u8, 71u8, 82u8, 65u8, 86u8, 69u8, 77u8, 65u8, 82u8, 75u8,
+            67u8, 73u8, 82u8, 67u8, 85u8, 77u8, 70u8, 76u8, 69u8, 88u8, 68u8, 73u8, 65u8, 69u8,
+            82u8, 69u8, 83u8, 73u8, 83u8, 66u8, 82u8, 69u8, 86u8, 69u8, 70u8, 79u8, 82u8, 72u8,
+            79u8, 79u8, 75u8, 73u8, 78u8, 73u8, 84u8, 73u8, 65u8, 76u8, 83u8, 89u8, 77u8, 66u8,
+            79u8, 76u8, 67u8, 69u8, 68u8, 73u8, 76u8, 76u8, 65u8, 79u8, 70u8, 65u8, 76u8, 69u8,
+            70u8, 76u8, 69u8, 70u8, 84u8, 78u8, 71u8, 73u8, 78u8, 86u8, 69u8, 82u8, 84u8, 69u8,
+            68u8, 67u8, 76u8, 76u8, 73u8, 78u8, 69u8, 84u8, 72u8, 65u8, 77u8, 90u8, 65u8, 83u8,
+            83u8, 80u8, 65u8, 67u8, 69u8, 84u8, 87u8, 79u8, 90u8, 82u8, 84u8, 73u8, 76u8, 68u8,
+            69u8, 89u8, 68u8, 81u8, 85u8, 79u8, 84u8, 65u8, 84u8, 73u8, 79u8, 78u8, 82u8, 73u8,
+            71u8, 72u8, 84u8, 67u8, 72u8, 69u8, 83u8, 83u8, 68u8, 73u8, 71u8, 73u8, 84u8, 76u8,
+            65u8, 77u8, 76u8, 73u8, 71u8, 65u8, 84u8, 85u8, 82u8, 69u8, 79u8, 71u8, 79u8, 78u8,
+            69u8, 75u8, 83u8, 69u8, 80u8, 65u8, 82u8, 65u8, 84u8, 79u8, 82u8, 74u8, 82u8, 69u8,
+            67u8, 89u8, 67u8, 76u8, 73u8, 78u8, 71u8, 82u8, 69u8, 86u8, 69u8, 82u8, 83u8, 69u8,
+            68u8, 83u8, 73u8, 78u8, 71u8, 76u8, 69u8, 84u8, 65u8, 66u8, 85u8, 76u8, 65u8, 84u8,
+            73u8, 79u8, 78u8, 67u8, 73u8, 82u8, 67u8, 76u8, 69u8, 77u8, 65u8, 76u8, 69u8, 79u8,
+            78u8, 69u8, 84u8, 72u8, 82u8, 69u8, 69u8, 66u8, 69u8, 76u8, 79u8, 87u8, 67u8, 72u8,
+            65u8, 82u8, 65u8, 67u8, 84u8, 69u8, 82u8, 67u8, 82u8, 79u8, 83u8, 83u8, 69u8, 78u8,
+            68u8, 72u8, 73u8, 78u8, 68u8, 69u8, 88u8, 75u8, 83u8, 81u8, 85u8, 65u8, 82u8, 69u8,
+            83u8, 85u8, 73u8, 84u8, 84u8, 82u8, 73u8, 71u8, 82u8, 65u8, 77u8, 89u8, 69u8, 72u8,
+            65u8, 69u8, 66u8, 70u8, 79u8, 85u8, 82u8, 72u8, 79u8, 82u8, 73u8, 90u8, 79u8, 78u8,
+            84u8, 65u8, 76u8, 80u8, 76u8, 65u8, 83u8, 84u8, 73u8, 67u8, 83u8, 80u8, 82u8, 73u8,
+            77u8, 69u8, 65u8, 82u8, 69u8, 65u8, 66u8, 82u8, 65u8, 67u8, 75u8, 69u8, 84u8, 66u8,
+            85u8, 76u8, 76u8, 69u8, 84u8, 67u8, 79u8, 78u8, 84u8, 82u8, 79u8, 76u8, 68u8, 73u8,
+            69u8, 69u8, 90u8, 72u8, 80u8, 79u8, 73u8, 78u8, 84u8, 73u8, 78u8, 71u8, 80u8, 85u8,
+            78u8, 67u8, 84u8, 85u8, 65u8, 84u8, 73u8, 79u8, 78u8, 82u8, 73u8, 78u8, 71u8, 83u8,
+            84u8, 65u8, 82u8, 84u8, 84u8, 65u8, 73u8, 76u8, 84u8, 69u8, 72u8, 84u8, 79u8, 78u8,
+            69u8, 84u8, 85u8, 82u8, 78u8, 69u8, 68u8, 86u8, 69u8, 82u8, 84u8, 73u8, 67u8, 65u8,
+            76u8, 90u8, 69u8, 82u8, 79u8, 66u8, 65u8, 82u8, 67u8, 79u8, 77u8, 77u8, 65u8, 68u8,
+            69u8, 86u8, 73u8, 67u8, 69u8, 69u8, 88u8, 67u8, 76u8, 65u8, 77u8, 65u8, 84u8, 73u8,
+            79u8, 78u8, 70u8, 69u8, 77u8, 65u8, 76u8, 69u8, 73u8, 78u8, 77u8, 69u8, 68u8, 73u8,
+            85u8, 77u8, 77u8, 73u8, 68u8, 68u8, 76u8, 69u8, 81u8, 85u8, 69u8, 83u8, 84u8, 73u8,
+            79u8, 78u8, 83u8, 69u8, 84u8, 65u8, 73u8, 78u8, 65u8, 78u8, 71u8, 76u8, 69u8, 66u8,
+            69u8, 72u8, 67u8, 76u8, 73u8, 67u8, 75u8, 67u8, 85u8, 82u8, 76u8, 68u8, 65u8, 68u8,
+            68u8, 65u8, 83u8, 72u8, 68u8, 73u8, 71u8, 82u8, 65u8, 77u8, 68u8, 79u8, 84u8, 83u8,
+            68u8, 82u8, 65u8, 85u8, 71u8, 72u8, 84u8, 83u8, 68u8, 90u8, 70u8, 70u8, 69u8, 72u8,
+            70u8, 73u8, 86u8, 69u8, 70u8, 76u8, 65u8, 71u8, 70u8, 82u8, 65u8, 67u8, 84u8, 73u8,
+            79u8, 78u8, 71u8, 72u8, 65u8, 73u8, 78u8, 72u8, 65u8, 72u8, 72u8, 69u8, 72u8, 72u8,
+            79u8, 82u8, 78u8, 72u8, 89u8, 80u8, 72u8, 69u8, 78u8, 73u8, 78u8, 70u8, 79u8, 82u8,
+            77u8, 65u8, 84u8, 73u8, 79u8, 78u8, 73u8, 83u8, 79u8, 76u8, 65u8, 84u8, 69u8, 74u8,
+            69u8, 69u8, 77u8, 75u8, 65u8, 70u8, 75u8, 72u8, 65u8, 72u8, 75u8, 73u8, 78u8, 71u8,
+            76u8, 69u8, 70u8, 84u8, 45u8, 84u8, 79u8, 45u8, 82u8, 73u8, 71u8, 72u8, 84u8, 77u8,
+            65u8, 68u8, 68u8, 65u8, 77u8, 69u8, 69u8, 77u8, 78u8, 79u8, 79u8, 78u8, 80u8, 80u8,
+            65u8, 82u8, 84u8, 73u8, 65u8, 76u8, 80u8, 69u8, 78u8, 84u8, 65u8, 71u8, 82u8, 65u8,
+            77u8, 80u8, 73u8, 69u8, 67u8, 69u8, 80u8, 82u8, 73u8, 86u8, 65u8, 84u8, 69u8, 81u8,
+            81u8, 65u8, 70u8, 81u8, 85u8, 65u8, 82u8, 84u8, 69u8, 82u8, 82u8, 73u8, 71u8, 72u8,
+            84u8, 45u8, 84u8, 79u8, 45u8, 76u8, 69u8, 70u8, 84u8, 83u8, 65u8, 68u8, 83u8, 69u8,
+            69u8, 78u8, 83u8, 72u8, 69u8, 69u8, 78u8, 83u8, 72u8, 73u8, 70u8, 84u8, 83u8, 72u8,
+            79u8, 71u8, 73u8, 83u8, 84u8, 65u8, 82u8, 83u8, 84u8, 79u8, 80u8, 83u8, 85u8, 78u8,
+            84u8, 65u8, 72u8, 84u8, 72u8, 69u8, 72u8, 84u8, 79u8, 80u8, 66u8, 65u8, 82u8, 86u8,
+            87u8, 87u8, 65u8, 87u8, 87u8, 73u8, 68u8, 84u8, 72u8, 89u8, 65u8, 78u8, 71u8, 89u8,
+            73u8, 78u8, 90u8, 65u8, 72u8, 65u8, 67u8, 67u8, 69u8, 78u8, 84u8, 66u8, 65u8, 76u8,
+            76u8, 79u8, 84u8, 66u8, 79u8, 88u8, 67u8, 76u8, 79u8, 85u8, 68u8, 68u8, 73u8, 65u8,
+            77u8, 79u8, 78u8, 68u8, 69u8, 77u8, 69u8, 78u8, 70u8, 65u8, 67u8, 69u8, 70u8, 69u8,
+            69u8, 68u8, 71u8, 69u8, 65u8, 82u8, 71u8, 76u8, 79u8, 84u8, 84u8, 65u8, 76u8, 72u8,
+            69u8, 65u8, 82u8, 84u8, 73u8, 78u8, 84u8, 82u8, 79u8, 68u8, 85u8, 67u8, 69u8, 82u8,
+            73u8, 78u8, 86u8, 73u8, 83u8, 73u8, 66u8, 76u8, 69u8, 76u8, 79u8, 78u8, 71u8, 76u8,
+            79u8, 87u8, 77u8, 77u8, 79u8, 79u8, 78u8, 77u8, 85u8, 83u8, 73u8, 67u8, 78u8, 79u8,
+            45u8, 66u8, 82u8, 69u8, 65u8, 75u8, 79u8, 69u8, 79u8, 78u8, 80u8, 65u8, 80u8, 69u8,
+            82u8, 82u8, 65u8, 73u8, 78u8, 82u8, 69u8, 86u8, 69u8, 82u8, 83u8, 69u8, 83u8, 73u8,
+            88u8, 83u8, 78u8, 79u8, 87u8, 77u8, 65u8, 78u8, 83u8, 84u8, 82u8, 73u8, 78u8, 71u8,
+            83u8, 85u8, 80u8, 69u8, 82u8, 83u8, 67u8, 82u8, 73u8, 80u8, 84u8, 84u8, 82u8, 65u8,
+            70u8, 70u8, 73u8, 67u8, 85u8, 77u8, 66u8, 82u8, 69u8, 76u8, 76u8, 65u8, 85u8, 80u8,
+            86u8, 85u8, 76u8, 71u8, 65u8, 82u8, 87u8, 65u8, 89u8, 88u8, 65u8, 67u8, 75u8, 78u8,
+            79u8, 87u8, 76u8, 69u8, 68u8, 71u8, 69u8, 65u8, 67u8, 84u8, 73u8, 86u8, 65u8, 84u8,
+            69u8, 65u8, 80u8, 79u8, 83u8, 84u8, 82u8, 79u8, 80u8, 72u8, 69u8, 65u8, 80u8, 80u8,
+            76u8, 73u8, 67u8, 65u8, 84u8, 73u8, 79u8, 78u8, 65u8, 83u8, 84u8, 69u8, 82u8, 73u8,
+            83u8, 75u8, 66u8, 65u8, 76u8, 76u8, 66u8, 69u8, 65u8, 77u8, 69u8, 68u8, 66u8, 73u8,
+            83u8, 72u8, 79u8, 80u8, 66u8, 82u8, 69u8, 65u8, 75u8, 67u8, 65u8, 78u8, 67u8, 69u8,
+            76u8, 67u8, 65u8, 82u8, 67u8, 65u8, 82u8, 69u8, 84u8, 67u8, 72u8, 73u8, 69u8, 85u8,
+            67u8, 72u8, 67u8, 73u8, 69u8, 85u8, 67u8, 67u8, 76u8, 85u8, 66u8, 67u8, 79u8, 77u8,
+            77u8, 65u8, 78u8, 68u8, 67u8, 79u8, 77u8, 77u8, 69u8, 82u8, 67u8, 73u8, 65u8, 76u8,
+            67u8, 79u8, 78u8, 86u8, 69u8, 82u8, 71u8, 73u8, 78u8, 71u8, 67u8, 82u8, 79u8, 83u8,
+            83u8, 73u8, 78u8, 71u8, 67u8, 85u8, 82u8, 76u8, 89u8, 68u8, 65u8, 71u8, 71u8, 69u8,
+            82u8, 68u8, 65u8, 76u8, 68u8, 65u8, 77u8, 77u8, 65u8, 68u8, 73u8, 65u8, 71u8, 79u8,
+            78u8, 65u8, 76u8, 68u8, 73u8, 71u8, 82u8, 65u8, 80u8, 72u8, 68u8, 73u8, 82u8, 69u8,
+            67u8, 84u8, 73u8, 79u8, 78u8, 65u8, 76u8, 68u8, 79u8, 84u8, 76u8, 69u8, 83u8, 83u8,
+            68u8, 79u8, 85u8, 66u8, 76u8, 69u8, 68u8, 68u8, 79u8, 87u8, 78u8, 69u8, 65u8, 82u8,
+            84u8, 72u8, 69u8, 73u8, 71u8, 72u8, 84u8, 72u8, 69u8, 77u8, 66u8, 69u8, 68u8, 68u8,
+            73u8, 78u8, 71u8, 69u8, 78u8, 71u8, 69u8, 78u8, 84u8, 82u8, 89u8, 69u8, 83u8, 67u8,
+            65u8, 80u8, 69u8, 69u8, 83u8, 72u8, 69u8, 84u8, 72u8, 70u8, 65u8, 84u8, 72u8, 65u8,
+            70u8, 65u8, 84u8, 72u8, 65u8, 84u8, 65u8, 78u8, 70u8, 73u8, 71u8, 85u8, 82u8, 69u8,
+            70u8, 73u8, 76u8, 76u8, 69u8, 82u8, 70u8, 73u8, 82u8, 83u8, 84u8, 70u8, 76u8, 79u8,
+            87u8, 69u8, 82u8, 71u8, 72u8, 65u8, 71u8, 82u8, 69u8, 65u8, 84u8, 69u8, 82u8, 71u8,
+            85u8, 65u8, 82u8, 68u8, 69u8, 68u8, 72u8, 65u8, 77u8, 77u8, 69u8, 82u8, 72u8, 69u8,
+            65u8, 86u8, 89u8, 72u8, 69u8, 82u8, 69u8, 72u8, 73u8, 69u8, 85u8, 72u8, 72u8, 73u8,
+            71u8, 72u8, 72u8, 73u8, 71u8, 72u8, 45u8, 82u8, 69u8, 86u8, 69u8, 82u8, 83u8, 69u8,
+            68u8, 45u8, 57u8, 72u8, 79u8, 84u8, 73u8, 69u8, 85u8, 78u8, 71u8, 73u8, 69u8, 85u8,
+            78u8, 71u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 73u8, 74u8, 73u8, 78u8, 68u8,
+            73u8, 67u8, 65u8, 84u8, 79u8, 82u8, 73u8, 78u8, 72u8, 73u8, 66u8, 73u8, 84u8, 73u8,
+            78u8, 84u8, 69u8, 82u8, 76u8, 65u8, 67u8, 69u8, 68u8, 74u8, 79u8, 73u8, 78u8, 69u8,
+            82u8, 74u8, 85u8, 83u8, 84u8, 73u8, 70u8, 73u8, 67u8, 65u8, 84u8, 73u8, 79u8, 78u8,
+            75u8, 65u8, 80u8, 89u8, 69u8, 79u8, 85u8, 78u8, 77u8, 73u8, 69u8, 85u8, 77u8, 75u8,
+            65u8, 80u8, 89u8, 69u8, 79u8, 85u8, 78u8, 80u8, 72u8, 73u8, 69u8, 85u8, 80u8, 72u8,
+            75u8, 65u8, 80u8, 89u8, 69u8, 79u8, 85u8, 78u8, 80u8, 73u8, 69u8, 85u8, 80u8, 75u8,
+            65u8, 83u8, 82u8, 65u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8, 75u8, 73u8, 89u8,
+            69u8, 79u8, 75u8, 75u8, 78u8, 73u8, 71u8, 72u8, 84u8, 76u8, 65u8, 78u8, 69u8, 76u8,
+            65u8, 78u8, 69u8, 83u8, 76u8, 69u8, 65u8, 68u8, 69u8, 82u8, 76u8, 69u8, 70u8, 84u8,
+            45u8, 80u8, 79u8, 73u8, 78u8, 84u8, 73u8, 78u8, 71u8, 76u8, 69u8, 71u8, 76u8, 69u8,
+            83u8, 83u8, 69u8, 82u8, 76u8, 73u8, 78u8, 69u8, 83u8, 76u8, 74u8, 76u8, 79u8, 67u8,
+            75u8, 73u8, 78u8, 71u8, 45u8, 83u8, 72u8, 73u8, 70u8, 84u8, 76u8, 79u8, 87u8, 45u8,
+            57u8, 77u8, 65u8, 75u8, 83u8, 85u8, 82u8, 65u8, 77u8, 65u8, 78u8, 77u8, 65u8, 82u8,
+            66u8, 85u8, 84u8, 65u8, 77u8, 69u8, 82u8, 71u8, 69u8, 77u8, 69u8, 83u8, 83u8, 65u8,
+            71u8, 69u8, 77u8, 73u8, 69u8, 85u8, 77u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 80u8,
+            73u8, 69u8, 85u8, 80u8, 77u8, 79u8, 78u8, 79u8, 71u8, 82u8, 65u8, 77u8, 77u8, 79u8,
+            85u8, 78u8, 84u8, 65u8, 73u8, 78u8, 78u8, 73u8, 69u8, 85u8, 78u8, 78u8, 73u8, 69u8,
+            85u8, 78u8, 45u8, 67u8, 73u8, 69u8, 85u8, 67u8, 78u8, 73u8, 69u8, 85u8, 78u8, 45u8,
+            72u8, 73u8, 69u8, 85u8, 72u8, 78u8, 73u8, 69u8, 85u8, 78u8, 45u8, 75u8, 73u8, 89u8,
+            69u8, 79u8, 75u8, 78u8, 73u8, 69u8, 85u8, 78u8, 45u8, 83u8, 73u8, 79u8, 83u8, 78u8,
+            73u8, 69u8, 85u8, 78u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 78u8, 74u8, 78u8,
+            79u8, 78u8, 79u8, 68u8, 69u8, 78u8, 79u8, 84u8, 69u8, 78u8, 79u8, 84u8, 69u8, 83u8,
+            79u8, 73u8, 79u8, 80u8, 69u8, 78u8, 79u8, 82u8, 68u8, 73u8, 78u8, 65u8, 76u8, 79u8,
+            85u8, 79u8, 86u8, 69u8, 82u8, 82u8, 73u8, 68u8, 69u8, 80u8, 65u8, 78u8, 83u8, 73u8,
+            79u8, 83u8, 80u8, 65u8, 82u8, 69u8, 78u8, 84u8, 72u8, 69u8, 83u8, 73u8, 83u8, 80u8,
+            65u8, 87u8, 78u8, 80u8, 69u8, 82u8, 80u8, 72u8, 73u8, 69u8, 85u8, 80u8, 72u8, 80u8,
+            72u8, 73u8, 69u8, 85u8, 80u8, 72u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 80u8, 73u8,
+            67u8, 75u8, 80u8, 73u8, 69u8, 85u8, 80u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 80u8,
+            72u8, 73u8, 69u8, 85u8, 80u8, 72u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 83u8, 73u8,
+            79u8, 83u8, 80u8, 73u8, 76u8, 67u8, 82u8, 79u8, 87u8, 80u8, 76u8, 85u8, 83u8, 80u8,
+            79u8, 73u8, 78u8, 84u8, 80u8, 79u8, 80u8, 80u8, 82u8, 79u8, 84u8, 69u8, 67u8, 84u8,
+            69u8, 68u8, 81u8, 85u8, 65u8, 68u8, 81u8, 85u8, 69u8, 69u8, 78u8, 81u8, 85u8, 73u8,
+            76u8, 76u8, 82u8, 65u8, 89u8, 83u8, 82u8, 69u8, 72u8, 82u8, 69u8, 83u8, 84u8, 82u8,
+            73u8, 67u8, 84u8, 69u8, 68u8, 82u8, 69u8, 84u8, 82u8, 79u8, 70u8, 76u8, 69u8, 88u8,
+            82u8, 73u8, 69u8, 85u8, 76u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 72u8, 73u8, 69u8,
+            85u8, 72u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 78u8, 73u8, 69u8, 85u8, 78u8, 82u8,
+            73u8, 71u8, 72u8, 84u8, 45u8, 80u8, 79u8, 73u8, 78u8, 84u8, 73u8, 78u8, 71u8, 82u8,
+            79u8, 79u8, 75u8, 83u8, 65u8, 76u8, 84u8, 73u8, 82u8, 69u8, 83u8, 69u8, 76u8, 69u8,
+            67u8, 84u8, 69u8, 68u8, 83u8, 69u8, 77u8, 73u8, 67u8, 79u8, 76u8, 79u8, 78u8, 83u8,
+            72u8, 65u8, 68u8, 68u8, 65u8, 83u8, 72u8, 65u8, 80u8, 69u8, 83u8, 83u8, 72u8, 65u8,
+            80u8, 73u8, 78u8, 71u8, 83u8, 72u8, 65u8, 82u8, 80u8, 83u8, 73u8, 79u8, 83u8, 83u8,
+            73u8, 79u8, 83u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 83u8, 73u8, 79u8, 83u8,
+            45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 83u8, 73u8, 79u8, 83u8, 45u8, 82u8, 73u8, 69u8,
+            85u8, 76u8, 83u8, 73u8, 79u8, 83u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 83u8,
+            77u8, 73u8, 76u8, 73u8, 78u8, 71u8, 83u8, 79u8, 76u8, 73u8, 68u8, 85u8, 83u8, 83u8,
+            80u8, 65u8, 68u8, 69u8, 83u8, 81u8, 85u8, 65u8, 82u8, 69u8, 68u8, 83u8, 83u8, 65u8,
+            78u8, 71u8, 73u8, 69u8, 85u8, 78u8, 71u8, 83u8, 83u8, 65u8, 78u8, 71u8, 75u8, 73u8,
+            89u8, 69u8, 79u8, 75u8, 83u8, 83u8, 65u8, 78u8, 71u8, 78u8, 73u8, 69u8, 85u8, 78u8,
+            83u8, 83u8, 65u8, 78u8, 71u8, 82u8, 73u8, 69u8, 85u8, 76u8, 83u8, 83u8, 65u8, 78u8,
+            71u8, 83u8, 73u8, 79u8, 83u8, 83u8, 84u8, 65u8, 70u8, 70u8, 83u8, 85u8, 75u8, 85u8,
+            78u8, 83u8, 87u8, 65u8, 80u8, 80u8, 73u8, 78u8, 71u8, 83u8, 87u8, 65u8, 83u8, 72u8,
+            83u8, 89u8, 77u8, 77u8, 69u8, 84u8, 82u8, 73u8, 67u8, 83u8, 89u8, 82u8, 73u8, 65u8,
+            67u8, 84u8, 69u8, 76u8, 69u8, 80u8, 72u8, 79u8, 78u8, 69u8, 84u8, 69u8, 88u8, 84u8,
+            84u8, 72u8, 65u8, 76u8, 84u8, 72u8, 73u8, 69u8, 85u8, 84u8, 72u8, 84u8, 72u8, 79u8,
+            82u8, 78u8, 84u8, 72u8, 85u8, 78u8, 68u8, 69u8, 82u8, 84u8, 73u8, 75u8, 69u8, 85u8,
+            84u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8,
+            84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 45u8, 82u8, 73u8, 69u8, 85u8, 76u8, 84u8, 82u8,
+            65u8, 78u8, 83u8, 77u8, 73u8, 83u8, 83u8, 73u8, 79u8, 78u8, 84u8, 82u8, 73u8, 80u8,
+            76u8, 69u8, 84u8, 87u8, 79u8, 45u8, 87u8, 65u8, 89u8, 85u8, 78u8, 68u8, 69u8, 82u8,
+            84u8, 73u8, 69u8, 85u8, 78u8, 73u8, 86u8, 69u8, 82u8, 83u8, 65u8, 76u8, 85u8, 82u8,
+            65u8, 78u8, 85u8, 83u8, 85u8, 83u8, 69u8, 87u8, 73u8, 84u8, 72u8, 79u8, 85u8, 84u8,
+            87u8, 89u8, 78u8, 78u8, 89u8, 69u8, 79u8, 82u8, 73u8, 78u8, 72u8, 73u8, 69u8, 85u8,
+            72u8, 89u8, 69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 89u8, 79u8, 71u8, 72u8, 90u8,
+            65u8, 73u8, 78u8, 65u8, 45u8, 69u8, 85u8, 65u8, 45u8, 79u8, 65u8, 45u8, 85u8, 65u8,
+            67u8, 75u8, 65u8, 68u8, 73u8, 65u8, 69u8, 83u8, 67u8, 85u8, 76u8, 65u8, 80u8, 73u8,
+            85u8, 83u8, 65u8, 70u8, 82u8, 73u8, 67u8, 65u8, 78u8, 65u8, 76u8, 69u8, 77u8, 66u8,
+            73u8, 67u8, 65u8, 76u8, 69u8, 82u8, 84u8, 65u8, 76u8, 73u8, 71u8, 78u8, 69u8, 68u8,
+            65u8, 76u8, 84u8, 69u8, 82u8, 78u8, 65u8, 84u8, 69u8, 65u8, 76u8, 86u8, 69u8, 79u8,
+            76u8, 65u8, 82u8, 65u8, 77u8, 80u8, 69u8, 82u8, 83u8, 65u8, 78u8, 68u8, 65u8, 78u8,
+            67u8, 72u8, 79u8, 82u8, 65u8, 78u8, 75u8, 72u8, 65u8, 80u8, 67u8, 65u8, 81u8, 85u8,
+            65u8, 82u8, 73u8, 85u8, 83u8, 65u8, 82u8, 65u8, 69u8, 65u8, 65u8, 82u8, 65u8, 69u8,
+            65u8, 45u8, 69u8, 79u8, 65u8, 82u8, 65u8, 69u8, 65u8, 45u8, 73u8, 65u8, 82u8, 65u8,
+            69u8, 65u8, 45u8, 85u8, 65u8, 82u8, 73u8, 69u8, 83u8, 65u8, 83u8, 67u8, 69u8, 78u8,
+            68u8, 73u8, 78u8, 71u8, 65u8, 83u8, 84u8, 69u8, 82u8, 73u8, 83u8, 75u8, 83u8, 65u8,
+            83u8, 84u8, 69u8, 82u8, 73u8, 83u8, 77u8, 65u8, 83u8, 84u8, 82u8, 79u8, 78u8, 79u8,
+            77u8, 73u8, 67u8, 65u8, 76u8, 65u8, 84u8, 65u8, 84u8, 79u8, 77u8, 66u8, 65u8, 67u8,
+            75u8, 83u8, 80u8, 65u8, 67u8, 69u8, 66u8, 65u8, 67u8, 75u8, 87u8, 65u8, 82u8, 68u8,
+            66u8, 65u8, 78u8, 75u8, 66u8, 65u8, 83u8, 69u8, 66u8, 65u8, 76u8, 76u8, 66u8, 69u8,
+            72u8, 73u8, 78u8, 68u8, 66u8, 69u8, 76u8, 66u8, 69u8, 86u8, 69u8, 82u8, 65u8, 71u8,
+            69u8, 66u8, 73u8, 79u8, 72u8, 65u8, 90u8, 65u8, 82u8, 68u8, 66u8, 76u8, 79u8, 67u8,
+            75u8, 66u8, 79u8, 77u8, 66u8, 80u8, 72u8, 66u8, 82u8, 79u8, 75u8, 69u8, 78u8, 66u8,
+            83u8, 66u8, 89u8, 66u8, 89u8, 84u8, 69u8, 67u8, 65u8, 68u8, 85u8, 67u8, 69u8, 85u8,
+            83u8, 67u8, 65u8, 78u8, 67u8, 65u8, 78u8, 67u8, 69u8, 82u8, 67u8, 65u8, 80u8, 82u8,
+            73u8, 67u8, 79u8, 82u8, 78u8, 67u8, 65u8, 82u8, 82u8, 73u8, 65u8, 71u8, 69u8, 67u8,
+            65u8, 83u8, 84u8, 76u8, 69u8, 67u8, 65u8, 85u8, 84u8, 73u8, 79u8, 78u8, 67u8, 67u8,
+            72u8, 67u8, 69u8, 78u8, 84u8, 67u8, 69u8, 79u8, 78u8, 71u8, 67u8, 72u8, 73u8, 69u8,
+            85u8, 77u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 67u8, 69u8, 79u8, 78u8, 71u8,
+            67u8, 72u8, 73u8, 69u8, 85u8, 77u8, 67u8, 73u8, 69u8, 85u8, 67u8, 67u8, 69u8, 79u8,
+            78u8, 71u8, 67u8, 72u8, 73u8, 69u8, 85u8, 77u8, 83u8, 73u8, 79u8, 83u8, 67u8, 69u8,
+            79u8, 78u8, 71u8, 67u8, 72u8, 73u8, 69u8, 85u8, 77u8, 83u8, 83u8, 65u8, 78u8, 71u8,
+            67u8, 73u8, 69u8, 85u8, 67u8, 67u8, 69u8, 79u8, 78u8, 71u8, 67u8, 72u8, 73u8, 69u8,
+            85u8, 77u8, 83u8, 83u8, 65u8, 78u8, 71u8, 83u8, 73u8, 79u8, 83u8, 67u8, 69u8, 82u8,
+            69u8, 83u8, 67u8, 72u8, 65u8, 73u8, 78u8, 83u8, 67u8, 72u8, 69u8, 67u8, 75u8, 67u8,
+            72u8, 73u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 45u8, 72u8, 73u8, 69u8, 85u8,
+            72u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8,
+            75u8, 72u8, 67u8, 72u8, 73u8, 82u8, 79u8, 78u8, 67u8, 72u8, 73u8, 84u8, 85u8, 69u8,
+            85u8, 77u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 67u8, 72u8, 73u8, 84u8, 85u8,
+            69u8, 85u8, 77u8, 67u8, 73u8, 69u8, 85u8, 67u8, 67u8, 72u8, 73u8, 84u8, 85u8, 69u8,
+            85u8, 77u8, 83u8, 73u8, 79u8, 83u8, 67u8, 72u8, 73u8, 84u8, 85u8, 69u8, 85u8, 77u8,
+            83u8, 83u8, 65u8, 78u8, 71u8, 67u8, 73u8, 69u8, 85u8, 67u8, 67u8, 72u8, 73u8, 84u8,
+            85u8, 69u8, 85u8, 77u8, 83u8, 83u8, 65u8, 78u8, 71u8, 83u8, 73u8, 79u8, 83u8, 67u8,
+            72u8, 85u8, 82u8, 67u8, 72u8, 67u8, 73u8, 69u8, 85u8, 67u8, 45u8, 73u8, 69u8, 85u8,
+            78u8, 71u8, 67u8, 73u8, 82u8, 67u8, 76u8, 69u8, 68u8, 67u8, 76u8, 79u8, 83u8, 69u8,
+            67u8, 76u8, 79u8, 83u8, 69u8, 68u8, 67u8, 79u8, 70u8, 70u8, 73u8, 78u8, 67u8, 79u8,
+            76u8, 79u8, 78u8, 67u8, 79u8, 77u8, 69u8, 84u8, 67u8, 79u8, 78u8, 74u8, 85u8, 78u8,
+            67u8, 84u8, 73u8, 79u8, 78u8, 67u8, 79u8, 80u8, 89u8, 82u8, 73u8, 71u8, 72u8, 84u8,
+            67u8, 79u8, 82u8, 78u8, 69u8, 82u8, 83u8, 67u8, 82u8, 67u8, 82u8, 69u8, 83u8, 67u8,
+            69u8, 78u8, 84u8, 67u8, 82u8, 79u8, 83u8, 83u8, 66u8, 79u8, 78u8, 69u8, 83u8, 67u8,
+            82u8, 79u8, 83u8, 83u8, 69u8, 68u8, 67u8, 83u8, 73u8, 67u8, 85u8, 80u8, 67u8, 85u8,
+            82u8, 82u8, 69u8, 78u8, 67u8, 89u8, 68u8, 65u8, 77u8, 77u8, 65u8, 84u8, 65u8, 78u8,
+            68u8, 65u8, 84u8, 65u8, 68u8, 66u8, 68u8, 67u8, 49u8, 68u8, 67u8, 50u8, 68u8, 67u8,
+            51u8, 68u8, 67u8, 52u8, 68u8, 67u8, 83u8, 68u8, 69u8, 71u8, 82u8, 69u8, 69u8, 68u8,
+            69u8, 76u8, 68u8, 69u8, 76u8, 69u8, 84u8, 69u8, 68u8, 69u8, 76u8, 84u8, 65u8, 68u8,
+            69u8, 78u8, 84u8, 65u8, 76u8, 68u8, 69u8, 83u8, 67u8, 69u8, 78u8, 68u8, 73u8, 78u8,
+            71u8, 68u8, 72u8, 65u8, 82u8, 77u8, 65u8, 68u8, 73u8, 83u8, 65u8, 66u8, 76u8, 69u8,
+            68u8, 68u8, 73u8, 86u8, 73u8, 83u8, 73u8, 79u8, 78u8, 68u8, 73u8, 86u8, 79u8, 82u8,
+            67u8, 69u8, 68u8, 76u8, 69u8, 68u8, 79u8, 76u8, 76u8, 65u8, 82u8, 68u8, 79u8, 84u8,
+            84u8, 69u8, 68u8, 68u8, 79u8, 87u8, 78u8, 45u8, 80u8, 79u8, 73u8, 78u8, 84u8, 73u8,
+            78u8, 71u8, 68u8, 82u8, 73u8, 86u8, 69u8, 68u8, 82u8, 79u8, 80u8, 83u8, 69u8, 65u8,
+            83u8, 84u8, 69u8, 73u8, 71u8, 72u8, 84u8, 69u8, 76u8, 76u8, 73u8, 80u8, 83u8, 73u8,
+            83u8, 69u8, 78u8, 81u8, 69u8, 78u8, 81u8, 85u8, 73u8, 82u8, 89u8, 69u8, 78u8, 84u8,
+            82u8, 89u8, 45u8, 49u8, 69u8, 78u8, 84u8, 82u8, 89u8, 45u8, 50u8, 69u8, 79u8, 69u8,
+            79u8, 45u8, 69u8, 85u8, 69u8, 79u8, 45u8, 79u8, 69u8, 79u8, 45u8, 85u8, 69u8, 79u8,
+            76u8, 69u8, 79u8, 77u8, 69u8, 79u8, 84u8, 69u8, 80u8, 65u8, 69u8, 81u8, 85u8, 65u8,
+            76u8, 83u8, 69u8, 83u8, 65u8, 69u8, 83u8, 67u8, 69u8, 84u8, 69u8, 84u8, 66u8, 69u8,
+            84u8, 88u8, 69u8, 85u8, 69u8, 85u8, 45u8, 69u8, 85u8, 69u8, 85u8, 45u8, 85u8, 70u8,
+            65u8, 67u8, 69u8, 45u8, 49u8, 70u8, 65u8, 67u8, 69u8, 45u8, 50u8, 70u8, 65u8, 67u8,
+            69u8, 45u8, 51u8, 70u8, 65u8, 67u8, 69u8, 45u8, 52u8, 70u8, 65u8, 67u8, 69u8, 45u8,
+            53u8, 70u8, 65u8, 67u8, 69u8, 45u8, 54u8, 70u8, 65u8, 76u8, 76u8, 73u8, 78u8, 71u8,
+            70u8, 65u8, 82u8, 83u8, 73u8, 70u8, 69u8, 77u8, 73u8, 78u8, 73u8, 78u8, 69u8, 70u8,
+            69u8, 82u8, 82u8, 89u8, 70u8, 70u8, 70u8, 73u8, 76u8, 69u8, 70u8, 73u8, 82u8, 69u8,
+            70u8, 76u8, 65u8, 84u8, 70u8, 76u8, 69u8, 85u8, 82u8, 45u8, 68u8, 69u8, 45u8, 76u8,
+            73u8, 83u8, 70u8, 76u8, 79u8, 82u8, 65u8, 76u8, 70u8, 79u8, 82u8, 77u8, 65u8, 84u8,
+            84u8, 73u8, 78u8, 71u8, 70u8, 79u8, 82u8, 87u8, 65u8, 82u8, 68u8, 70u8, 79u8, 85u8,
+            78u8, 84u8, 65u8, 73u8, 78u8, 70u8, 79u8, 85u8, 82u8, 45u8, 80u8, 69u8, 82u8, 45u8,
+            69u8, 77u8, 70u8, 82u8, 65u8, 71u8, 77u8, 69u8, 78u8, 84u8, 70u8, 82u8, 79u8, 87u8,
+            78u8, 73u8, 78u8, 71u8, 70u8, 83u8, 70u8, 83u8, 73u8, 70u8, 85u8, 69u8, 76u8, 70u8,
+            85u8, 76u8, 76u8, 70u8, 85u8, 78u8, 67u8, 84u8, 73u8, 79u8, 78u8, 70u8, 85u8, 78u8,
+            69u8, 82u8, 65u8, 76u8, 71u8, 65u8, 77u8, 77u8, 65u8, 71u8, 69u8, 77u8, 73u8, 78u8,
+            73u8, 71u8, 69u8, 78u8, 69u8, 82u8, 73u8, 67u8, 71u8, 82u8, 65u8, 80u8, 72u8, 73u8,
+            67u8, 71u8, 82u8, 65u8, 86u8, 69u8, 89u8, 65u8, 82u8, 68u8, 71u8, 82u8, 69u8, 65u8,
+            84u8, 69u8, 82u8, 45u8, 84u8, 72u8, 65u8, 78u8, 71u8, 82u8, 79u8, 85u8, 78u8, 68u8,
+            71u8, 82u8, 79u8, 85u8, 80u8, 71u8, 83u8, 72u8, 65u8, 73u8, 82u8, 72u8, 65u8, 76u8,
+            70u8, 72u8, 65u8, 78u8, 68u8, 76u8, 69u8, 83u8, 72u8, 69u8, 65u8, 68u8, 73u8, 78u8,
+            71u8, 72u8, 69u8, 65u8, 68u8, 83u8, 84u8, 79u8, 78u8, 69u8, 72u8, 69u8, 65u8, 86u8,
+            69u8, 78u8, 72u8, 69u8, 76u8, 77u8, 69u8, 84u8, 72u8, 69u8, 82u8, 77u8, 69u8, 83u8,
+            72u8, 73u8, 69u8, 85u8, 72u8, 45u8, 77u8, 73u8, 69u8, 85u8, 77u8, 72u8, 73u8, 69u8,
+            85u8, 72u8, 45u8, 78u8, 73u8, 69u8, 85u8, 78u8, 72u8, 73u8, 69u8, 85u8, 72u8, 45u8,
+            80u8, 73u8, 69u8, 85u8, 80u8, 72u8, 73u8, 69u8, 85u8, 72u8, 45u8, 82u8, 73u8, 69u8,
+            85u8, 76u8, 72u8, 73u8, 83u8, 84u8, 79u8, 82u8, 73u8, 67u8, 72u8, 79u8, 76u8, 69u8,
+            72u8, 79u8, 80u8, 72u8, 84u8, 72u8, 84u8, 74u8, 72u8, 84u8, 83u8, 72u8, 85u8, 66u8,
+            72u8, 86u8, 72u8, 87u8, 65u8, 73u8, 82u8, 72u8, 89u8, 80u8, 72u8, 69u8, 78u8, 45u8,
+            77u8, 73u8, 78u8, 85u8, 83u8, 72u8, 89u8, 80u8, 72u8, 69u8, 78u8, 65u8, 84u8, 73u8,
+            79u8, 78u8, 73u8, 45u8, 65u8, 73u8, 45u8, 65u8, 82u8, 65u8, 69u8, 65u8, 73u8, 45u8,
+            69u8, 85u8, 73u8, 45u8, 79u8, 73u8, 45u8, 85u8, 73u8, 45u8, 89u8, 65u8, 73u8, 67u8,
+            69u8, 73u8, 68u8, 76u8, 69u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 67u8, 72u8, 73u8,
+            69u8, 85u8, 67u8, 72u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 67u8, 73u8, 69u8, 85u8,
+            67u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8,
+            73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 77u8, 73u8, 69u8, 85u8, 77u8, 73u8, 69u8, 85u8,
+            78u8, 71u8, 45u8, 80u8, 65u8, 78u8, 83u8, 73u8, 79u8, 83u8, 73u8, 69u8, 85u8, 78u8,
+            71u8, 45u8, 80u8, 72u8, 73u8, 69u8, 85u8, 80u8, 72u8, 73u8, 69u8, 85u8, 78u8, 71u8,
+            45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 83u8, 73u8,
+            79u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 83u8, 83u8, 65u8, 78u8, 71u8, 75u8,
+            73u8, 89u8, 69u8, 79u8, 75u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 84u8, 72u8, 73u8,
+            69u8, 85u8, 84u8, 72u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 84u8, 73u8, 75u8, 69u8,
+            85u8, 84u8, 73u8, 78u8, 68u8, 73u8, 78u8, 83u8, 69u8, 82u8, 84u8, 73u8, 79u8, 78u8,
+            73u8, 78u8, 84u8, 69u8, 82u8, 76u8, 79u8, 67u8, 75u8, 69u8, 68u8, 73u8, 78u8, 84u8,
+            69u8, 82u8, 82u8, 79u8, 66u8, 65u8, 78u8, 71u8, 73u8, 79u8, 84u8, 65u8, 74u8, 65u8,
+            80u8, 65u8, 78u8, 69u8, 83u8, 69u8, 74u8, 69u8, 82u8, 85u8, 83u8, 65u8, 76u8, 69u8,
+            77u8, 74u8, 85u8, 78u8, 79u8, 74u8, 85u8, 80u8, 73u8, 84u8, 69u8, 82u8, 75u8, 65u8,
+            80u8, 89u8, 69u8, 79u8, 85u8, 78u8, 82u8, 73u8, 69u8, 85u8, 76u8, 75u8, 65u8, 80u8,
+            89u8, 69u8, 79u8, 85u8, 78u8, 83u8, 83u8, 65u8, 78u8, 71u8, 80u8, 73u8, 69u8, 85u8,
+            80u8, 75u8, 65u8, 83u8, 82u8, 65u8, 84u8, 65u8, 78u8, 75u8, 69u8, 89u8, 75u8, 73u8,
+            89u8, 69u8, 79u8, 75u8, 45u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 75u8, 73u8,
+            89u8, 69u8, 79u8, 75u8, 45u8, 72u8, 73u8, 69u8, 85u8, 72u8, 75u8, 73u8, 89u8, 69u8,
+            79u8, 75u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8, 75u8, 73u8, 89u8, 69u8,
+            79u8, 75u8, 45u8, 78u8, 73u8, 69u8, 85u8, 78u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8,
+            45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 45u8, 82u8,
+            73u8, 69u8, 85u8, 76u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 45u8, 83u8, 73u8, 79u8,
+            83u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 45u8, 83u8, 73u8, 79u8, 83u8, 45u8, 75u8,
+            73u8, 89u8, 69u8, 79u8, 75u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 45u8, 84u8, 73u8,
+            75u8, 69u8, 85u8, 84u8, 75u8, 82u8, 65u8, 76u8, 65u8, 75u8, 69u8, 76u8, 65u8, 77u8,
+            66u8, 68u8, 65u8, 76u8, 65u8, 83u8, 84u8, 76u8, 65u8, 84u8, 69u8, 82u8, 65u8, 76u8,
+            76u8, 69u8, 70u8, 84u8, 45u8, 72u8, 65u8, 78u8, 68u8, 69u8, 68u8, 76u8, 69u8, 70u8,
+            84u8, 87u8, 65u8, 82u8, 68u8, 83u8, 76u8, 69u8, 79u8, 76u8, 69u8, 83u8, 83u8, 45u8,
+            84u8, 72u8, 65u8, 78u8, 76u8, 70u8, 76u8, 73u8, 66u8, 82u8, 65u8, 76u8, 73u8, 71u8,
+            72u8, 84u8, 72u8, 79u8, 85u8, 83u8, 69u8, 76u8, 73u8, 71u8, 72u8, 84u8, 78u8, 73u8,
+            78u8, 71u8, 76u8, 73u8, 76u8, 73u8, 84u8, 72u8, 76u8, 73u8, 78u8, 75u8, 76u8, 79u8,
+            79u8, 80u8, 76u8, 79u8, 82u8, 82u8, 65u8, 73u8, 78u8, 69u8, 76u8, 82u8, 69u8, 76u8,
+            82u8, 73u8, 76u8, 82u8, 77u8, 76u8, 82u8, 79u8, 77u8, 65u8, 80u8, 77u8, 65u8, 82u8,
+            82u8, 73u8, 65u8, 71u8, 69u8, 77u8, 65u8, 83u8, 67u8, 85u8, 76u8, 73u8, 78u8, 69u8,
+            77u8, 65u8, 84u8, 69u8, 82u8, 73u8, 65u8, 76u8, 83u8, 77u8, 65u8, 84u8, 72u8, 69u8,
+            77u8, 65u8, 84u8, 73u8, 67u8, 65u8, 76u8, 77u8, 69u8, 82u8, 67u8, 85u8, 82u8, 89u8,
+            77u8, 73u8, 67u8, 82u8, 79u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 67u8, 72u8, 73u8,
+            69u8, 85u8, 67u8, 72u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 72u8, 73u8, 69u8, 85u8,
+            72u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 77u8,
+            73u8, 69u8, 85u8, 77u8, 45u8, 80u8, 65u8, 78u8, 83u8, 73u8, 79u8, 83u8, 77u8, 73u8,
+            69u8, 85u8, 77u8, 45u8, 82u8, 73u8, 69u8, 85u8, 76u8, 77u8, 73u8, 69u8, 85u8, 77u8,
+            45u8, 83u8, 73u8, 79u8, 83u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 83u8, 83u8, 65u8,
+            78u8, 71u8, 83u8, 73u8, 79u8, 83u8, 77u8, 73u8, 76u8, 76u8, 69u8, 77u8, 73u8, 78u8,
+            85u8, 83u8, 77u8, 77u8, 83u8, 80u8, 77u8, 85u8, 76u8, 84u8, 73u8, 80u8, 76u8, 73u8,
+            67u8, 65u8, 84u8, 73u8, 79u8, 78u8, 77u8, 87u8, 78u8, 65u8, 75u8, 78u8, 65u8, 82u8,
+            82u8, 79u8, 87u8, 78u8, 65u8, 84u8, 73u8, 79u8, 78u8, 65u8, 76u8, 78u8, 65u8, 84u8,
+            85u8, 82u8, 65u8, 76u8, 78u8, 66u8, 72u8, 78u8, 66u8, 83u8, 80u8, 78u8, 69u8, 71u8,
+            65u8, 84u8, 73u8, 86u8, 69u8, 78u8, 69u8, 76u8, 78u8, 69u8, 80u8, 84u8, 85u8, 78u8,
+            69u8, 78u8, 69u8, 85u8, 84u8, 69u8, 82u8, 78u8, 69u8, 87u8, 78u8, 69u8, 88u8, 84u8,
+            78u8, 73u8, 69u8, 85u8, 78u8, 45u8, 80u8, 65u8, 78u8, 83u8, 73u8, 79u8, 83u8, 78u8,
+            73u8, 69u8, 85u8, 78u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 78u8, 73u8, 69u8, 85u8,
+            78u8, 45u8, 84u8, 72u8, 73u8, 69u8, 85u8, 84u8, 72u8, 78u8, 73u8, 78u8, 69u8, 78u8,
+            76u8, 78u8, 78u8, 66u8, 83u8, 80u8, 78u8, 79u8, 77u8, 73u8, 78u8, 65u8, 76u8, 78u8,
+            79u8, 78u8, 45u8, 66u8, 82u8, 69u8, 65u8, 75u8, 73u8, 78u8, 71u8, 78u8, 79u8, 78u8,
+            45u8, 74u8, 79u8, 73u8, 78u8, 69u8, 82u8, 78u8, 79u8, 84u8, 78u8, 85u8, 76u8, 78u8,
+            85u8, 76u8, 76u8, 78u8, 85u8, 77u8, 66u8, 69u8, 82u8, 79u8, 45u8, 69u8, 79u8, 45u8,
+            69u8, 79u8, 79u8, 45u8, 79u8, 79u8, 45u8, 85u8, 79u8, 45u8, 89u8, 65u8, 79u8, 45u8,
+            89u8, 65u8, 69u8, 79u8, 45u8, 89u8, 69u8, 79u8, 67u8, 84u8, 69u8, 84u8, 79u8, 78u8,
+            69u8, 45u8, 87u8, 65u8, 89u8, 79u8, 80u8, 69u8, 82u8, 65u8, 84u8, 73u8, 78u8, 71u8,
+            79u8, 80u8, 72u8, 73u8, 85u8, 67u8, 72u8, 85u8, 83u8, 79u8, 80u8, 80u8, 79u8, 83u8,
+            73u8, 84u8, 73u8, 79u8, 78u8, 79u8, 82u8, 68u8, 69u8, 82u8, 79u8, 82u8, 84u8, 72u8,
+            79u8, 68u8, 79u8, 88u8, 79u8, 83u8, 67u8, 79u8, 85u8, 84u8, 79u8, 85u8, 84u8, 76u8,
+            73u8, 78u8, 69u8, 68u8, 79u8, 86u8, 69u8, 82u8, 76u8, 73u8, 78u8, 69u8, 80u8, 65u8,
+            68u8, 80u8, 65u8, 68u8, 68u8, 73u8, 78u8, 71u8, 80u8, 65u8, 76u8, 65u8, 84u8, 65u8,
+            76u8, 80u8, 65u8, 76u8, 76u8, 65u8, 83u8, 80u8, 65u8, 82u8, 65u8, 71u8, 82u8, 65u8,
+            80u8, 72u8, 80u8, 65u8, 82u8, 84u8, 73u8, 65u8, 76u8, 76u8, 89u8, 45u8, 82u8, 69u8,
+            67u8, 89u8, 67u8, 76u8, 69u8, 68u8, 80u8, 65u8, 82u8, 84u8, 78u8, 69u8, 82u8, 83u8,
+            72u8, 73u8, 80u8, 80u8, 68u8, 70u8, 80u8, 68u8, 73u8, 80u8, 69u8, 65u8, 67u8, 69u8,
+            80u8, 69u8, 82u8, 67u8, 69u8, 78u8, 84u8, 80u8, 69u8, 82u8, 77u8, 65u8, 78u8, 69u8,
+            78u8, 84u8, 80u8, 69u8, 82u8, 77u8, 73u8, 84u8, 84u8, 69u8, 68u8, 80u8, 69u8, 82u8,
+            83u8, 79u8, 78u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 67u8, 72u8, 73u8, 69u8, 85u8,
+            67u8, 72u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 67u8, 73u8, 69u8, 85u8, 67u8, 80u8,
+            73u8, 69u8, 85u8, 80u8, 45u8, 72u8, 73u8, 69u8, 85u8, 72u8, 80u8, 73u8, 69u8, 85u8,
+            80u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8,
+            78u8, 73u8, 69u8, 85u8, 78u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 82u8, 73u8, 69u8,
+            85u8, 76u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 83u8, 73u8, 79u8, 83u8, 45u8, 67u8,
+            73u8, 69u8, 85u8, 67u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 83u8, 73u8, 79u8, 83u8,
+            45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 83u8,
+            73u8, 79u8, 83u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 80u8, 73u8, 69u8, 85u8, 80u8,
+            45u8, 83u8, 73u8, 79u8, 83u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 80u8, 73u8,
+            69u8, 85u8, 80u8, 45u8, 83u8, 83u8, 65u8, 78u8, 71u8, 83u8, 73u8, 79u8, 83u8, 80u8,
+            73u8, 69u8, 85u8, 80u8, 45u8, 84u8, 72u8, 73u8, 69u8, 85u8, 84u8, 72u8, 80u8, 73u8,
+            69u8, 85u8, 80u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 80u8, 73u8, 83u8, 67u8,
+            69u8, 83u8, 80u8, 76u8, 68u8, 80u8, 76u8, 85u8, 80u8, 76u8, 85u8, 83u8, 45u8, 77u8,
+            73u8, 78u8, 85u8, 83u8, 80u8, 76u8, 85u8, 84u8, 79u8, 80u8, 77u8, 80u8, 79u8, 85u8,
+            78u8, 68u8, 80u8, 82u8, 69u8, 67u8, 69u8, 68u8, 69u8, 68u8, 80u8, 82u8, 69u8, 83u8,
+            69u8, 84u8, 80u8, 82u8, 73u8, 86u8, 65u8, 67u8, 89u8, 80u8, 82u8, 79u8, 71u8, 82u8,
+            65u8, 77u8, 80u8, 85u8, 49u8, 80u8, 85u8, 50u8, 80u8, 85u8, 77u8, 80u8, 81u8, 80u8,
+            81u8, 85u8, 65u8, 68u8, 82u8, 85u8, 80u8, 76u8, 69u8, 81u8, 85u8, 65u8, 82u8, 84u8,
+            69u8, 82u8, 83u8, 81u8, 85u8, 73u8, 78u8, 67u8, 85u8, 78u8, 88u8, 82u8, 65u8, 68u8,
+            73u8, 79u8, 65u8, 67u8, 84u8, 73u8, 86u8, 69u8, 82u8, 69u8, 67u8, 79u8, 82u8, 68u8,
+            82u8, 69u8, 67u8, 89u8, 67u8, 76u8, 69u8, 68u8, 82u8, 69u8, 70u8, 69u8, 82u8, 69u8,
+            78u8, 67u8, 69u8, 82u8, 69u8, 71u8, 73u8, 83u8, 84u8, 69u8, 82u8, 69u8, 68u8, 82u8,
+            69u8, 84u8, 85u8, 82u8, 78u8, 82u8, 72u8, 79u8, 82u8, 73u8, 82u8, 73u8, 69u8, 85u8,
+            76u8, 45u8, 75u8, 65u8, 80u8, 89u8, 69u8, 79u8, 85u8, 78u8, 80u8, 73u8, 69u8, 85u8,
+            80u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8,
+            82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 82u8, 73u8,
+            69u8, 85u8, 76u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 45u8, 83u8, 73u8, 79u8,
+            83u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 77u8, 73u8, 69u8, 85u8, 77u8, 82u8, 73u8,
+            69u8, 85u8, 76u8, 45u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8, 75u8, 73u8, 89u8, 69u8,
+            79u8, 75u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 77u8, 73u8, 69u8, 85u8, 77u8, 45u8,
+            83u8, 73u8, 79u8, 83u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 80u8, 65u8, 78u8, 83u8,
+            73u8, 79u8, 83u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 80u8, 72u8, 73u8, 69u8, 85u8,
+            80u8, 72u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 82u8,
+            73u8, 69u8, 85u8, 76u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 72u8, 73u8, 69u8,
+            85u8, 72u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8,
+            83u8, 73u8, 79u8, 83u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 83u8, 73u8, 79u8, 83u8,
+            82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 83u8, 83u8, 65u8, 78u8, 71u8, 83u8, 73u8, 79u8,
+            83u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 84u8, 72u8, 73u8, 69u8, 85u8, 84u8, 72u8,
+            82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 82u8, 73u8,
+            69u8, 85u8, 76u8, 45u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 45u8, 72u8, 73u8, 69u8,
+            85u8, 72u8, 82u8, 73u8, 69u8, 85u8, 76u8, 45u8, 89u8, 69u8, 79u8, 82u8, 73u8, 78u8,
+            72u8, 73u8, 69u8, 85u8, 72u8, 82u8, 73u8, 71u8, 72u8, 84u8, 45u8, 72u8, 65u8, 78u8,
+            68u8, 69u8, 68u8, 82u8, 73u8, 71u8, 72u8, 84u8, 87u8, 65u8, 82u8, 68u8, 83u8, 82u8,
+            76u8, 69u8, 82u8, 76u8, 73u8, 82u8, 76u8, 77u8, 82u8, 76u8, 79u8, 82u8, 79u8, 84u8,
+            65u8, 84u8, 69u8, 68u8, 82u8, 83u8, 83u8, 65u8, 71u8, 73u8, 84u8, 84u8, 65u8, 82u8,
+            73u8, 85u8, 83u8, 83u8, 65u8, 73u8, 76u8, 66u8, 79u8, 65u8, 84u8, 83u8, 65u8, 84u8,
+            85u8, 82u8, 78u8, 83u8, 67u8, 65u8, 76u8, 69u8, 83u8, 83u8, 67u8, 72u8, 87u8, 65u8,
+            83u8, 67u8, 73u8, 83u8, 67u8, 79u8, 82u8, 80u8, 73u8, 85u8, 83u8, 83u8, 69u8, 67u8,
+            84u8, 73u8, 79u8, 78u8, 83u8, 69u8, 77u8, 73u8, 83u8, 69u8, 88u8, 84u8, 73u8, 76u8,
+            69u8, 83u8, 69u8, 81u8, 85u8, 69u8, 78u8, 67u8, 69u8, 83u8, 69u8, 83u8, 81u8, 85u8,
+            73u8, 81u8, 85u8, 65u8, 68u8, 82u8, 65u8, 84u8, 69u8, 83u8, 69u8, 86u8, 69u8, 78u8,
+            83u8, 69u8, 88u8, 84u8, 73u8, 76u8, 69u8, 83u8, 71u8, 67u8, 83u8, 72u8, 65u8, 75u8,
+            84u8, 73u8, 83u8, 72u8, 65u8, 77u8, 82u8, 79u8, 67u8, 75u8, 83u8, 72u8, 73u8, 69u8,
+            76u8, 68u8, 83u8, 72u8, 73u8, 78u8, 84u8, 79u8, 83u8, 72u8, 82u8, 73u8, 78u8, 69u8,
+            83u8, 72u8, 89u8, 83u8, 73u8, 83u8, 73u8, 67u8, 75u8, 76u8, 69u8, 83u8, 73u8, 78u8,
+            71u8, 76u8, 69u8, 45u8, 83u8, 72u8, 73u8, 70u8, 84u8, 45u8, 50u8, 83u8, 73u8, 78u8,
+            71u8, 76u8, 69u8, 45u8, 83u8, 72u8, 73u8, 70u8, 84u8, 45u8, 51u8, 83u8, 73u8, 79u8,
+            83u8, 45u8, 67u8, 72u8, 73u8, 69u8, 85u8, 67u8, 72u8, 83u8, 73u8, 79u8, 83u8, 45u8,
+            67u8, 73u8, 69u8, 85u8, 67u8, 83u8, 73u8, 79u8, 83u8, 45u8, 72u8, 73u8, 69u8, 85u8,
+            72u8, 83u8, 73u8, 79u8, 83u8, 45u8, 73u8, 69u8, 85u8, 78u8, 71u8, 83u8, 73u8, 79u8,
+            83u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8, 83u8, 73u8, 79u8, 83u8, 45u8,
+            77u8, 73u8, 69u8, 85u8, 77u8, 83u8, 73u8, 79u8, 83u8, 45u8, 78u8, 73u8, 69u8, 85u8,
+            78u8, 83u8, 73u8, 79u8, 83u8, 45u8, 80u8, 72u8, 73u8, 69u8, 85u8, 80u8, 72u8, 83u8,
+            73u8, 79u8, 83u8, 45u8, 80u8, 73u8, 69u8, 85u8, 80u8, 45u8, 75u8, 73u8, 89u8, 69u8,
+            79u8, 75u8, 83u8, 73u8, 79u8, 83u8, 45u8, 83u8, 83u8, 65u8, 78u8, 71u8, 83u8, 73u8,
+            79u8, 83u8, 83u8, 73u8, 79u8, 83u8, 45u8, 84u8, 72u8, 73u8, 69u8, 85u8, 84u8, 72u8,
+            83u8, 73u8, 84u8, 69u8, 83u8, 73u8, 88u8, 45u8, 80u8, 69u8, 82u8, 45u8, 69u8, 77u8,
+            83u8, 73u8, 88u8, 84u8, 69u8, 69u8, 78u8, 84u8, 72u8, 83u8, 75u8, 65u8, 84u8, 69u8,
+            83u8, 75u8, 73u8, 69u8, 82u8, 83u8, 75u8, 85u8, 76u8, 76u8, 83u8, 76u8, 65u8, 83u8,
+            72u8, 83u8, 76u8, 73u8, 68u8, 73u8, 78u8, 71u8, 83u8, 76u8, 79u8, 87u8, 83u8, 78u8,
+            79u8, 87u8, 83u8, 79u8, 83u8, 79u8, 67u8, 67u8, 69u8, 82u8, 83u8, 79u8, 70u8, 84u8,
+            83u8, 79u8, 72u8, 83u8, 79u8, 83u8, 83u8, 80u8, 83u8, 80u8, 65u8, 83u8, 80u8, 82u8,
+            73u8, 78u8, 71u8, 83u8, 83u8, 83u8, 50u8, 83u8, 83u8, 51u8, 83u8, 83u8, 65u8, 83u8,
+            83u8, 65u8, 78u8, 71u8, 65u8, 82u8, 65u8, 69u8, 65u8, 83u8, 83u8, 65u8, 78u8, 71u8,
+            67u8, 73u8, 69u8, 85u8, 67u8, 83u8, 83u8, 65u8, 78u8, 71u8, 72u8, 73u8, 69u8, 85u8,
+            72u8, 83u8, 83u8, 65u8, 78u8, 71u8, 80u8, 73u8, 69u8, 85u8, 80u8, 83u8, 83u8, 65u8,
+            78u8, 71u8, 84u8, 73u8, 75u8, 69u8, 85u8, 84u8, 83u8, 83u8, 65u8, 78u8, 71u8, 89u8,
+            69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 83u8, 84u8, 83u8, 84u8, 65u8, 84u8, 69u8,
+            83u8, 84u8, 82u8, 73u8, 80u8, 69u8, 83u8, 84u8, 82u8, 79u8, 78u8, 71u8, 83u8, 84u8,
+            83u8, 83u8, 84u8, 88u8, 83u8, 85u8, 66u8, 83u8, 85u8, 66u8, 83u8, 84u8, 73u8, 84u8,
+            85u8, 84u8, 69u8, 83u8, 87u8, 79u8, 82u8, 68u8, 83u8, 83u8, 87u8, 85u8, 78u8, 71u8,
+            83u8, 89u8, 78u8, 83u8, 89u8, 78u8, 67u8, 72u8, 82u8, 79u8, 78u8, 79u8, 85u8, 83u8,
+            83u8, 89u8, 83u8, 84u8, 69u8, 77u8, 84u8, 65u8, 66u8, 84u8, 65u8, 84u8, 87u8, 69u8,
+            69u8, 76u8, 84u8, 65u8, 85u8, 82u8, 85u8, 83u8, 84u8, 69u8, 78u8, 84u8, 69u8, 78u8,
+            84u8, 84u8, 69u8, 82u8, 77u8, 73u8, 78u8, 65u8, 84u8, 79u8, 82u8, 84u8, 72u8, 73u8,
+            78u8, 84u8, 72u8, 79u8, 85u8, 83u8, 65u8, 78u8, 68u8, 84u8, 72u8, 82u8, 69u8, 69u8,
+            45u8, 80u8, 69u8, 82u8, 45u8, 69u8, 77u8, 84u8, 72u8, 85u8, 78u8, 68u8, 69u8, 82u8,
+            83u8, 84u8, 79u8, 82u8, 77u8, 84u8, 73u8, 69u8, 84u8, 73u8, 77u8, 69u8, 83u8, 84u8,
+            73u8, 82u8, 79u8, 78u8, 73u8, 65u8, 78u8, 84u8, 82u8, 65u8, 78u8, 83u8, 77u8, 73u8,
+            84u8, 84u8, 82u8, 73u8, 65u8, 78u8, 71u8, 76u8, 69u8, 84u8, 82u8, 73u8, 65u8, 78u8,
+            71u8, 85u8, 76u8, 65u8, 82u8, 84u8, 82u8, 73u8, 67u8, 79u8, 76u8, 79u8, 78u8, 84u8,
+            82u8, 85u8, 67u8, 75u8, 84u8, 89u8, 80u8, 69u8, 45u8, 49u8, 84u8, 89u8, 80u8, 69u8,
+            45u8, 50u8, 84u8, 89u8, 80u8, 69u8, 45u8, 51u8, 84u8, 89u8, 80u8, 69u8, 45u8, 52u8,
+            84u8, 89u8, 80u8, 69u8, 45u8, 53u8, 84u8, 89u8, 80u8, 69u8, 45u8, 54u8, 84u8, 89u8,
+            80u8, 69u8, 45u8, 55u8, 85u8, 45u8, 65u8, 85u8, 45u8, 65u8, 69u8, 85u8, 45u8, 69u8,
+            79u8, 45u8, 69u8, 85u8, 85u8, 45u8, 85u8, 85u8, 45u8, 89u8, 69u8, 85u8, 78u8, 73u8,
+            84u8, 85u8, 78u8, 77u8, 65u8, 82u8, 82u8, 73u8, 69u8, 68u8, 85u8, 80u8, 83u8, 73u8,
+            76u8, 79u8, 78u8, 85u8, 82u8, 78u8, 85u8, 83u8, 85u8, 83u8, 69u8, 45u8, 49u8, 85u8,
+            83u8, 69u8, 45u8, 50u8, 86u8, 69u8, 82u8, 84u8, 73u8, 67u8, 65u8, 76u8, 76u8, 89u8,
+            86u8, 69u8, 83u8, 84u8, 65u8, 86u8, 73u8, 82u8, 71u8, 79u8, 86u8, 79u8, 76u8, 84u8,
+            65u8, 71u8, 69u8, 86u8, 84u8, 86u8, 84u8, 83u8, 87u8, 65u8, 87u8, 65u8, 69u8, 87u8,
+            65u8, 73u8, 84u8, 73u8, 78u8, 71u8, 87u8, 65u8, 82u8, 78u8, 73u8, 78u8, 71u8, 87u8,
+            65u8, 84u8, 69u8, 82u8, 87u8, 69u8, 87u8, 69u8, 79u8, 87u8, 69u8, 83u8, 84u8, 87u8,
+            72u8, 69u8, 69u8, 76u8, 87u8, 72u8, 69u8, 69u8, 76u8, 67u8, 72u8, 65u8, 73u8, 82u8,
+            87u8, 73u8, 87u8, 73u8, 78u8, 68u8, 87u8, 74u8, 87u8, 79u8, 82u8, 68u8, 89u8, 65u8,
+            89u8, 65u8, 45u8, 79u8, 89u8, 65u8, 45u8, 85u8, 89u8, 65u8, 45u8, 89u8, 79u8, 89u8,
+            65u8, 69u8, 89u8, 69u8, 89u8, 69u8, 78u8, 89u8, 69u8, 79u8, 89u8, 69u8, 79u8, 45u8,
+            79u8, 89u8, 69u8, 79u8, 45u8, 85u8, 89u8, 69u8, 79u8, 45u8, 89u8, 65u8, 89u8, 69u8,
+            83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 75u8, 72u8, 73u8, 69u8, 85u8, 75u8, 72u8,
+            89u8, 69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 75u8, 73u8, 89u8, 69u8, 79u8,
+            75u8, 89u8, 69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 80u8, 65u8, 78u8, 83u8,
+            73u8, 79u8, 83u8, 89u8, 69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 83u8, 73u8,
+            79u8, 83u8, 89u8, 69u8, 83u8, 73u8, 69u8, 85u8, 78u8, 71u8, 45u8, 83u8, 83u8, 65u8,
+            78u8, 71u8, 75u8, 73u8, 89u8, 69u8, 79u8, 75u8, 89u8, 73u8, 89u8, 73u8, 45u8, 85u8,
+            89u8, 79u8, 89u8, 79u8, 45u8, 73u8, 89u8, 79u8, 45u8, 79u8, 89u8, 79u8, 45u8, 89u8,
+            65u8, 89u8, 79u8, 45u8, 89u8, 65u8, 69u8, 89u8, 79u8, 45u8, 89u8, 69u8, 79u8, 89u8,
+            82u8, 89u8, 85u8, 89u8, 85u8, 45u8, 65u8, 89u8, 85u8, 45u8, 69u8, 89u8, 85u8, 45u8,
+            69u8, 79u8, 89u8, 85u8, 45u8, 73u8, 89u8, 85u8, 45u8, 85u8, 89u8, 85u8, 45u8, 89u8,
+            69u8, 89u8, 85u8, 45u8, 89u8, 69u8, 79u8, 90u8, 87u8, 74u8, 90u8, 87u8, 78u8, 66u8,
+            83u8, 80u8, 90u8, 87u8, 78u8, 74u8, 90u8, 87u8, 83u8, 80u8,
+        ])
+    },
+    code_points: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            32u8, 0u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 35u8, 0u8, 0u8, 0u8,
+            36u8, 0u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8,
+            40u8, 0u8, 0u8, 0u8, 41u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8,
+            44u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8,
+            48u8, 0u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8,
+            52u8, 0u8, 0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 55u8, 0u8, 0u8, 0u8,
+            56u8, 0u8, 0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8,
+            60u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8,
+            64u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8,
+            68u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8, 0u8, 71u8, 0u8, 0u8, 0u8,
+            72u8, 0u8, 0u8, 0u8, 73u8, 0u8, 0u8, 0u8, 74u8, 0u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8,
+            76u8, 0u8, 0u8, 0u8, 77u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 79u8, 0u8, 0u8, 0u8,
+            80u8, 0u8, 0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 83u8, 0u8, 0u8, 0u8,
+            84u8, 0u8, 0u8, 0u8, 85u8, 0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8,
+            88u8, 0u8, 0u8, 0u8, 89u8, 0u8, 0u8, 0u8, 90u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8,
+            92u8, 0u8, 0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8,
+            96u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8,
+            100u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8,
+            104u8, 0u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8,
+            108u8, 0u8, 0u8, 0u8, 109u8, 0u8, 0u8, 0u8, 110u8, 0u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8,
+            112u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8,
+            116u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8,
+            120u8, 0u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8,
+            124u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8, 0u8, 126u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8,
+            161u8, 0u8, 0u8, 0u8, 162u8, 0u8, 0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8,
+            165u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 167u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8, 0u8,
+            169u8, 0u8, 0u8, 0u8, 170u8, 0u8, 0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 172u8, 0u8, 0u8, 0u8,
+            173u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 176u8, 0u8, 0u8, 0u8,
+            177u8, 0u8, 0u8, 0u8, 178u8, 0u8, 0u8, 0u8, 179u8, 0u8, 0u8, 0u8, 180u8, 0u8, 0u8, 0u8,
+            181u8, 0u8, 0u8, 0u8, 182u8, 0u8, 0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8,
+            185u8, 0u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8, 187u8, 0u8, 0u8, 0u8, 188u8, 0u8, 0u8, 0u8,
+            189u8, 0u8, 0u8, 0u8, 190u8, 0u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8, 0u8,
+            193u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8, 0u8, 196u8, 0u8, 0u8, 0u8,
+            197u8, 0u8, 0u8, 0u8, 198u8, 0u8, 0u8, 0u8, 199u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8,
+            201u8, 0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 203u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8,
+            205u8, 0u8, 0u8, 0u8, 206u8, 0u8, 0u8, 0u8, 207u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8,
+            209u8, 0u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 211u8, 0u8, 0u8, 0u8, 212u8, 0u8, 0u8, 0u8,
+            213u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 215u8, 0u8, 0u8, 0u8, 216u8, 0u8, 0u8, 0u8,
+            217u8, 0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 219u8, 0u8, 0u8, 0u8, 220u8, 0u8, 0u8, 0u8,
+            221u8, 0u8, 0u8, 0u8, 222u8, 0u8, 0u8, 0u8, 223u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8,
+            225u8, 0u8, 0u8, 0u8, 226u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 228u8, 0u8, 0u8, 0u8,
+            229u8, 0u8, 0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 231u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8,
+            233u8, 0u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 235u8, 0u8, 0u8, 0u8, 236u8, 0u8, 0u8, 0u8,
+            237u8, 0u8, 0u8, 0u8, 238u8, 0u8, 0u8, 0u8, 239u8, 0u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8,
+            241u8, 0u8, 0u8, 0u8, 242u8, 0u8, 0u8, 0u8, 243u8, 0u8, 0u8, 0u8, 244u8, 0u8, 0u8, 0u8,
+            245u8, 0u8, 0u8, 0u8, 246u8, 0u8, 0u8, 0u8, 247u8, 0u8, 0u8, 0u8, 248u8, 0u8, 0u8, 0u8,
+            249u8, 0u8, 0u8, 0u8, 250u8, 0u8, 0u8, 0u8, 251u8, 0u8, 0u8, 0u8, 252u8, 0u8, 0u8, 0u8,
+            253u8, 0u8, 0u8, 0u8, 254u8, 0u8, 0u8, 0u8, 255u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+            1u8, 1u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8, 3u8, 1u8, 0u8, 0u8, 4u8, 1u8, 0u8, 0u8, 5u8,
+            1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 7u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 9u8, 1u8,
+            0u8, 0u8, 10u8, 1u8, 0u8, 0u8, 11u8, 1u8, 0u8, 0u8, 12u8, 1u8, 0u8, 0u8, 13u8, 1u8,
+            0u8, 0u8, 14u8, 1u8, 0u8, 0u8, 15u8, 1u8, 0u8, 0u8, 16u8, 1u8, 0u8, 0u8, 17u8, 1u8,
+            0u8, 0u8, 18u8, 1u8, 0u8, 0u8, 19u8, 1u8, 0u8, 0u8, 20u8, 1u8, 0u8, 0u8, 21u8, 1u8,
+            0u8, 0u8, 22u8, 1u8, 0u8, 0u8, 23u8, 1u8, 0u8, 0u8, 24u8, 1u8, 0u8, 0u8, 25u8, 1u8,
+            0u8, 0u8, 26u8, 1u8, 0u8, 0u8, 27u8, 1u8, 0u8, 0u8, 28u8, 1u8, 0u8, 0u8, 29u8, 1u8,
+            0u8, 0u8, 30u8, 1u8, 0u8, 0u8, 31u8, 1u8, 0u8, 0u8, 32u8, 1u8, 0u8, 0u8, 33u8, 1u8,
+            0u8, 0u8, 34u8, 1u8, 0u8, 0u8, 35u8, 1u8, 0u8, 0u8, 36u8, 1u8, 0u8, 0u8, 37u8, 1u8,
+            0u8, 0u8, 38u8, 1u8, 0u8, 0u8, 39u8, 1u8, 0u8, 0u8, 40u8, 1u8, 0u8, 0u8, 41u8, 1u8,
+            0u8, 0u8, 42u8, 1u8, 0u8, 0u8, 43u8, 1u8, 0u8, 0u8, 44u8, 1u8, 0u8, 0u8, 45u8, 1u8,
+            0u8, 0u8, 46u8, 1u8, 0u8, 0u8, 47u8, 1u8, 0u8, 0u8, 48u8, 1u8, 0u8, 0u8, 49u8, 1u8,
+            0u8, 0u8, 50u8, 1u8, 0u8, 0u8, 51u8, 1u8, 0u8, 0u8, 52u8, 1u8, 0u8, 0u8, 53u8, 1u8,
+            0u8, 0u8, 54u8, 1u8, 0u8, 0u8, 55u8, 1u8, 0u8, 0u8, 56u8, 1u8, 0u8, 0u8, 57u8, 1u8,
+            0u8, 0u8, 58u8, 1u8, 0u8, 0u8, 59u8, 1u8, 0u8, 0u8, 60u8, 1u8, 0u8, 0u8, 61u8, 1u8,
+            0u8, 0u8, 62u8, 1u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8, 64u8, 1u8, 0u8, 0u8, 65u8, 1u8,
+            0u8, 0u8, 66u8, 1u8, 0u8, 0u8, 67u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 0u8, 69u8, 1u8,
+            0u8, 0u8, 70u8, 1u8, 0u8, 0u8, 71u8, 1u8, 0u8, 0u8, 72u8, 1u8, 0u8, 0u8, 73u8, 1u8,
+            0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 75u8, 1u8, 0u8, 0u8, 76u8, 1u8, 0u8, 0u8, 77u8, 1u8,
+            0u8, 0u8, 78u8, 1u8, 0u8, 0u8, 79u8, 1u8, 0u8, 0u8, 80u8, 1u8, 0u8, 0u8, 81u8, 1u8,
+            0u8, 0u8, 82u8, 1u8, 0u8, 0u8, 83u8, 1u8, 0u8, 0u8, 84u8, 1u8, 0u8, 0u8, 85u8, 1u8,
+            0u8, 0u8, 86u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8, 88u8, 1u8, 0u8, 0u8, 89u8, 1u8,
+            0u8, 0u8, 90u8, 1u8, 0u8, 0u8, 91u8, 1u8, 0u8, 0u8, 92u8, 1u8, 0u8, 0u8, 93u8, 1u8,
+            0u8, 0u8, 94u8, 1u8, 0u8, 0u8, 95u8, 1u8, 0u8, 0u8, 96u8, 1u8, 0u8, 0u8, 97u8, 1u8,
+            0u8, 0u8, 98u8, 1u8, 0u8, 0u8, 99u8, 1u8, 0u8, 0u8, 100u8, 1u8, 0u8, 0u8, 101u8, 1u8,
+            0u8, 0u8, 102u8, 1u8, 0u8, 0u8, 103u8, 1u8, 0u8, 0u8, 104u8, 1u8, 0u8, 0u8, 105u8, 1u8,
+            0u8, 0u8, 106u8, 1u8, 0u8, 0u8, 107u8, 1u8, 0u8, 0u8, 108u8, 1u8, 0u8, 0u8, 109u8, 1u8,
+            0u8, 0u8, 110u8, 1u8, 0u8, 0u8, 111u8, 1u8, 0u8, 0u8, 112u8, 1u8, 0u8, 0u8, 113u8, 1u8,
+            0u8, 0u8, 114u8, 1u8, 0u8, 0u8, 115u8, 1u8, 0u8, 0u8, 116u8, 1u8, 0u8, 0u8, 117u8, 1u8,
+            0u8, 0u8, 118u8, 1u8, 0u8, 0u8, 119u8, 1u8, 0u8, 0u8, 120u8, 1u8, 0u8, 0u8, 121u8, 1u8,
+            0u8, 0u8, 122u8, 1u8, 0u8, 0u8, 123u8, 1u8, 0u8, 0u8, 124u8, 1u8, 0u8, 0u8, 125u8, 1u8,
+            0u8, 0u8, 126u8, 1u8, 0u8, 0u8, 127u8, 1u8, 0u8, 0u8, 128u8, 1u8, 0u8, 0u8, 129u8, 1u8,
+            0u8, 0u8, 130u8, 1u8, 0u8, 0u8, 131u8, 1u8, 0u8, 0u8, 132u8, 1u8, 0u8, 0u8, 133u8, 1u8,
+            0u8, 0u8, 134u8, 1u8, 0u8, 0u8, 135u8, 1u8, 0u8, 0u8, 136u8, 1u8, 0u8, 0u8, 137u8, 1u8,
+            0u8, 0u8, 138u8, 1u8, 0u8, 0u8, 139u8, 1u8, 0u8, 0u8, 140u8, 1u8, 0u8, 0u8, 141u8, 1u8,
+            0u8, 0u8, 142u8, 1u8, 0u8, 0u8, 143u8, 1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 145u8, 1u8,
+            0u8, 0u8, 146u8, 1u8, 0u8, 0u8, 147u8, 1u8, 0u8, 0u8, 148u8, 1u8, 0u8, 0u8, 149u8, 1u8,
+            0u8, 0u8, 150u8, 1u8, 0u8, 0u8, 151u8, 1u8, 0u8, 0u8, 152u8, 1u8, 0u8, 0u8, 153u8, 1u8,
+            0u8, 0u8, 154u8, 1u8, 0u8, 0u8, 155u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 157u8, 1u8,
+            0u8, 0u8, 158u8, 1u8, 0u8, 0u8, 159u8, 1u8, 0u8, 0u8, 160u8, 1u8, 0u8, 0u8, 161u8, 1u8,
+            0u8, 0u8, 162u8, 1u8, 0u8, 0u8, 163u8, 1u8, 0u8, 0u8, 164u8, 1u8, 0u8, 0u8, 165u8, 1u8,
+            0u8, 0u8, 166u8, 1u8, 0u8, 0u8, 167u8, 1u8, 0u8, 0u8, 168u8, 1u8, 0u8, 0u8, 169u8, 1u8,
+            0u8, 0u8, 170u8, 1u8, 0u8, 0u8, 171u8, 1u8, 0u8, 0u8, 172u8, 1u8, 0u8, 0u8, 173u8, 1u8,
+            0u8, 0u8, 174u8, 1u8, 0u8, 0u8, 175u8, 1u8, 0u8, 0u8, 176u8, 1u8, 0u8, 0u8, 177u8, 1u8,
+            0u8, 0u8, 178u8, 1u8, 0u8, 0u8, 179u8, 1u8, 0u8, 0u8, 180u8, 1u8, 0u8, 0u8, 181u8, 1u8,
+            0u8, 0u8, 182u8, 1u8, 0u8, 0u8, 183u8, 1u8, 0u8, 0u8, 184u8, 1u8, 0u8, 0u8, 185u8, 1u8,
+            0u8, 0u8, 186u8, 1u8, 0u8, 0u8, 187u8, 1u8, 0u8, 0u8, 188u8, 1u8, 0u8, 0u8, 189u8, 1u8,
+            0u8, 0u8, 190u8, 1u8, 0u8, 0u8, 191u8, 1u8, 0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 193u8, 1u8,
+            0u8, 0u8, 194u8, 1u8, 0u8, 0u8, 195u8, 1u8, 0u8, 0u8, 196u8, 1u8, 0u8, 0u8, 197u8, 1u8,
+            0u8, 0u8, 198u8, 1u8, 0u8, 0u8, 199u8, 1u8, 0u8, 0u8, 200u8, 1u8, 0u8, 0u8, 201u8, 1u8,
+            0u8, 0u8, 202u8, 1u8, 0u8, 0u8, 203u8, 1u8, 0u8, 0u8, 204u8, 1u8, 0u8, 0u8, 205u8, 1u8,
+            0u8, 0u8, 206u8, 1u8, 0u8, 0u8, 207u8, 1u8, 0u8, 0u8, 208u8, 1u8, 0u8, 0u8, 209u8, 1u8,
+            0u8, 0u8, 210u8, 1u8, 0u8, 0u8, 211u8, 1u8, 0u8, 0u8, 212u8, 1u8, 0u8, 0u8, 213u8, 1u8,
+            0u8, 0u8, 214u8, 1u8, 0u8, 0u8, 215u8, 1u8, 0u8, 0u8, 216u8, 1u8, 0u8, 0u8, 217u8, 1u8,
+            0u8, 0u8, 218u8, 1u8, 0u8, 0u8, 219u8, 1u8, 0u8, 0u8, 220u8, 1u8, 0u8, 0u8, 221u8, 1u8,
+            0u8, 0u8, 222u8, 1u8, 0u8, 0u8, 223u8, 1u8, 0u8, 0u8, 224u8, 1u8, 0u8, 0u8, 225u8, 1u8,
+            0u8, 0u8, 226u8, 1u8, 0u8, 0u8, 227u8, 1u8, 0u8, 0u8, 228u8, 1u8, 0u8, 0u8, 229u8, 1u8,
+            0u8, 0u8, 230u8, 1u8, 0u8, 0u8, 231u8, 1u8, 0u8, 0u8, 232u8, 1u8, 0u8, 0u8, 233u8, 1u8,
+            0u8, 0u8, 234u8, 1u8, 0u8, 0u8, 235u8, 1u8, 0u8, 0u8, 236u8, 1u8, 0u8, 0u8, 237u8, 1u8,
+            0u8, 0u8, 238u8, 1u8, 0u8, 0u8, 239u8, 1u8, 0u8, 0u8, 240u8, 1u8, 0u8, 0u8, 241u8, 1u8,
+            0u8, 0u8, 242u8, 1u8, 0u8, 0u8, 243u8, 1u8, 0u8, 0u8, 244u8, 1u8, 0u8, 0u8, 245u8, 1u8,
+            0u8, 0u8, 246u8, 1u8, 0u8, 0u8, 247u8, 1u8, 0u8, 0u8, 248u8, 1u8, 0u8, 0u8, 249u8, 1u8,
+            0u8, 0u8, 250u8, 1u8, 0u8, 0u8, 251u8, 1u8, 0u8, 0u8, 252u8, 1u8, 0u8, 0u8, 253u8, 1u8,
+            0u8, 0u8, 254u8, 1u8, 0u8, 0u8, 255u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 1u8, 2u8,
+            0u8, 0u8, 2u8, 2u8, 0u8, 0u8, 3u8, 2u8, 0u8, 0u8, 4u8, 2u8, 0u8, 0u8, 5u8, 2u8, 0u8,
+            0u8, 6u8, 2u8, 0u8, 0u8, 7u8, 2u8, 0u8, 0u8, 8u8, 2u8, 0u8, 0u8, 9u8, 2u8, 0u8, 0u8,
+            10u8, 2u8, 0u8, 0u8, 11u8, 2u8, 0u8, 0u8, 12u8, 2u8, 0u8, 0u8, 13u8, 2u8, 0u8, 0u8,
+            14u8, 2u8, 0u8, 0u8, 15u8, 2u8, 0u8, 0u8, 16u8, 2u8, 0u8, 0u8, 17u8, 2u8, 0u8, 0u8,
+            18u8, 2u8, 0u8, 0u8, 19u8, 2u8, 0u8, 0u8, 20u8, 2u8, 0u8, 0u8, 21u8, 2u8, 0u8, 0u8,
+            22u8, 2u8, 0u8, 0u8, 23u8, 2u8, 0u8, 0u8, 24u8, 2u8, 0u8, 0u8, 25u8, 2u8, 0u8, 0u8,
+            26u8, 2u8, 0u8, 0u8, 27u8, 2u8, 0u8, 0u8, 28u8, 2u8, 0u8, 0u8, 29u8, 2u8, 0u8, 0u8,
+            30u8, 2u8, 0u8, 0u8, 31u8, 2u8, 0u8, 0u8, 32u8, 2u8, 0u8, 0u8, 33u8, 2u8, 0u8, 0u8,
+            34u8, 2u8, 0u8, 0u8, 35u8, 2u8, 0u8, 0u8, 36u8, 2u8, 0u8, 0u8, 37u8, 2u8, 0u8, 0u8,
+            38u8, 2u8, 0u8, 0u8, 39u8, 2u8, 0u8, 0u8, 40u8, 2u8, 0u8, 0u8, 41u8, 2u8, 0u8, 0u8,
+            42u8, 2u8, 0u8, 0u8, 43u8, 2u8, 0u8, 0u8, 44u8, 2u8, 0u8, 0u8, 45u8, 2u8, 0u8, 0u8,
+            46u8, 2u8, 0u8, 0u8, 47u8, 2u8, 0u8, 0u8, 48u8, 2u8, 0u8, 0u8, 49u8, 2u8, 0u8, 0u8,
+            50u8, 2u8, 0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 52u8, 2u8, 0u8, 0u8, 53u8, 2u8, 0u8, 0u8,
+            54u8, 2u8, 0u8, 0u8, 55u8, 2u8, 0u8, 0u8, 56u8, 2u8, 0u8, 0u8, 57u8, 2u8, 0u8, 0u8,
+            58u8, 2u8, 0u8, 0u8, 59u8, 2u8, 0u8, 0u8, 60u8, 2u8, 0u8, 0u8, 61u8, 2u8, 0u8, 0u8,
+            62u8, 2u8, 0u8, 0u8, 63u8, 2u8, 0u8, 0u8, 64u8, 2u8, 0u8, 0u8, 65u8, 2u8, 0u8, 0u8,
+            66u8, 2u8, 0u8, 0u8, 67u8, 2u8, 0u8, 0u8, 68u8, 2u8, 0u8, 0u8, 69u8, 2u8, 0u8, 0u8,
+            70u8, 2u8, 0u8, 0u8, 71u8, 2u8, 0u8, 0u8, 72u8, 2u8, 0u8, 0u8, 73u8, 2u8, 0u8, 0u8,
+            74u8, 2u8, 0u8, 0u8, 75u8, 2u8, 0u8, 0u8, 76u8, 2u8, 0u8, 0u8, 77u8, 2u8, 0u8, 0u8,
+            78u8, 2u8, 0u8, 0u8, 79u8, 2u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 1u8, 17u8, 0u8, 0u8,
+            2u8, 17u8, 0u8, 0u8, 3u8, 17u8, 0u8, 0u8, 4u8, 17u8, 0u8, 0u8, 5u8, 17u8, 0u8, 0u8,
+            6u8, 17u8, 0u8, 0u8, 7u8, 17u8, 0u8, 0u8, 8u8, 17u8, 0u8, 0u8, 9u8, 17u8, 0u8, 0u8,
+            10u8, 17u8, 0u8, 0u8, 11u8, 17u8, 0u8, 0u8, 12u8, 17u8, 0u8, 0u8, 13u8, 17u8, 0u8, 0u8,
+            14u8, 17u8, 0u8, 0u8, 15u8, 17u8, 0u8, 0u8, 16u8, 17u8, 0u8, 0u8, 17u8, 17u8, 0u8, 0u8,
+            18u8, 17u8, 0u8, 0u8, 19u8, 17u8, 0u8, 0u8, 20u8, 17u8, 0u8, 0u8, 21u8, 17u8, 0u8, 0u8,
+            22u8, 17u8, 0u8, 0u8, 23u8, 17u8, 0u8, 0u8, 24u8, 17u8, 0u8, 0u8, 25u8, 17u8, 0u8, 0u8,
+            26u8, 17u8, 0u8, 0u8, 27u8, 17u8, 0u8, 0u8, 28u8, 17u8, 0u8, 0u8, 29u8, 17u8, 0u8, 0u8,
+            30u8, 17u8, 0u8, 0u8, 31u8, 17u8, 0u8, 0u8, 32u8, 17u8, 0u8, 0u8, 33u8, 17u8, 0u8, 0u8,
+            34u8, 17u8, 0u8, 0u8, 35u8, 17u8, 0u8, 0u8, 36u8, 17u8, 0u8, 0u8, 37u8, 17u8, 0u8, 0u8,
+            38u8, 17u8, 0u8, 0u8, 39u8, 17u8, 0u8, 0u8, 40u8, 17u8, 0u8, 0u8, 41u8, 17u8, 0u8, 0u8,
+            42u8, 17u8, 0u8, 0u8, 43u8, 17u8, 0u8, 0u8, 44u8, 17u8, 0u8, 0u8, 45u8, 17u8, 0u8, 0u8,
+            46u8, 17u8, 0u8, 0u8, 47u8, 17u8, 0u8, 0u8, 48u8, 17u8, 0u8, 0u8, 49u8, 17u8, 0u8, 0u8,
+            50u8, 17u8, 0u8, 0u8, 51u8, 17u8, 0u8, 0u8, 52u8, 17u8, 0u8, 0u8, 53u8, 17u8, 0u8, 0u8,
+            54u8, 17u8, 0u8, 0u8, 55u8, 17u8, 0u8, 0u8, 56u8, 17u8, 0u8, 0u8, 57u8, 17u8, 0u8, 0u8,
+            58u8, 17u8, 0u8, 0u8, 59u8, 17u8, 0u8, 0u8, 60u8, 17u8, 0u8, 0u8, 61u8, 17u8, 0u8, 0u8,
+            62u8, 17u8, 0u8, 0u8, 63u8, 17u8, 0u8, 0u8, 64u8, 17u8, 0u8, 0u8, 65u8, 17u8, 0u8, 0u8,
+            66u8, 17u8, 0u8, 0u8, 67u8, 17u8, 0u8, 0u8, 68u8, 17u8, 0u8, 0u8, 69u8, 17u8, 0u8, 0u8,
+            70u8, 17u8, 0u8, 0u8, 71u8, 17u8, 0u8, 0u8, 72u8, 17u8, 0u8, 0u8, 73u8, 17u8, 0u8, 0u8,
+            74u8, 17u8, 0u8, 0u8, 75u8, 17u8, 0u8, 0u8, 76u8, 17u8, 0u8, 0u8, 77u8, 17u8, 0u8, 0u8,
+            78u8, 17u8, 0u8, 0u8, 79u8, 17u8, 0u8, 0u8, 80u8, 17u8, 0u8, 0u8, 81u8, 17u8, 0u8, 0u8,
+            82u8, 17u8, 0u8, 0u8, 83u8, 17u8, 0u8, 0u8, 84u8, 17u8, 0u8, 0u8, 85u8, 17u8, 0u8, 0u8,
+            86u8, 17u8, 0u8, 0u8, 87u8, 17u8, 0u8, 0u8, 88u8, 17u8, 0u8, 0u8, 89u8, 17u8, 0u8, 0u8,
+            90u8, 17u8, 0u8, 0u8, 91u8, 17u8, 0u8, 0u8, 92u8, 17u8, 0u8, 0u8, 93u8, 17u8, 0u8, 0u8,
+            94u8, 17u8, 0u8, 0u8, 95u8, 17u8, 0u8, 0u8, 96u8, 17u8, 0u8, 0u8, 97u8, 17u8, 0u8, 0u8,
+            98u8, 17u8, 0u8, 0u8, 99u8, 17u8, 0u8, 0u8, 100u8, 17u8, 0u8, 0u8, 101u8, 17u8, 0u8,
+            0u8, 102u8, 17u8, 0u8, 0u8, 103u8, 17u8, 0u8, 0u8, 104u8, 17u8, 0u8, 0u8, 105u8, 17u8,
+            0u8, 0u8, 106u8, 17u8, 0u8, 0u8, 107u8, 17u8, 0u8, 0u8, 108u8, 17u8, 0u8, 0u8, 109u8,
+            17u8, 0u8, 0u8, 110u8, 17u8, 0u8, 0u8, 111u8, 17u8, 0u8, 0u8, 112u8, 17u8, 0u8, 0u8,
+            113u8, 17u8, 0u8, 0u8, 114u8, 17u8, 0u8, 0u8, 115u8, 17u8, 0u8, 0u8, 116u8, 17u8, 0u8,
+            0u8, 117u8, 17u8, 0u8, 0u8, 118u8, 17u8, 0u8, 0u8, 119u8, 17u8, 0u8, 0u8, 120u8, 17u8,
+            0u8, 0u8, 121u8, 17u8, 0u8, 0u8, 122u8, 17u8, 0u8, 0u8, 123u8, 17u8, 0u8, 0u8, 124u8,
+            17u8, 0u8, 0u8, 125u8, 17u8, 0u8, 0u8, 126u8, 17u8, 0u8, 0u8, 127u8, 17u8, 0u8, 0u8,
+            128u8, 17u8, 0u8, 0u8, 129u8, 17u8, 0u8, 0u8, 130u8, 17u8, 0u8, 0u8, 131u8, 17u8, 0u8,
+            0u8, 132u8, 17u8, 0u8, 0u8, 133u8, 17u8, 0u8, 0u8, 134u8, 17u8, 0u8, 0u8, 135u8, 17u8,
+            0u8, 0u8, 136u8, 17u8, 0u8, 0u8, 137u8, 17u8, 0u8, 0u8, 138u8, 17u8, 0u8, 0u8, 139u8,
+            17u8, 0u8, 0u8, 140u8, 17u8, 0u8, 0u8, 141u8, 17u8, 0u8, 0u8, 142u8, 17u8, 0u8, 0u8,
+            143u8, 17u8, 0u8, 0u8, 144u8, 17u8, 0u8, 0u8, 145u8, 17u8, 0u8, 0u8, 146u8, 17u8, 0u8,
+            0u8, 147u8, 17u8, 0u8, 0u8, 148u8, 17u8, 0u8, 0u8, 149u8, 17u8, 0u8, 0u8, 150u8, 17u8,
+            0u8, 0u8, 151u8, 17u8, 0u8, 0u8, 152u8, 17u8, 0u8, 0u8, 153u8, 17u8, 0u8, 0u8, 154u8,
+            17u8, 0u8, 0u8, 155u8, 17u8, 0u8, 0u8, 156u8, 17u8, 0u8, 0u8, 157u8, 17u8, 0u8, 0u8,
+            158u8, 17u8, 0u8, 0u8, 159u8, 17u8, 0u8, 0u8, 160u8, 17u8, 0u8, 0u8, 161u8, 17u8, 0u8,
+            0u8, 162u8, 17u8, 0u8, 0u8, 163u8, 17u8, 0u8, 0u8, 164u8, 17u8, 0u8, 0u8, 165u8, 17u8,
+            0u8, 0u8, 166u8, 17u8, 0u8, 0u8, 167u8, 17u8, 0u8, 0u8, 168u8, 17u8, 0u8, 0u8, 169u8,
+            17u8, 0u8, 0u8, 170u8, 17u8, 0u8, 0u8, 171u8, 17u8, 0u8, 0u8, 172u8, 17u8, 0u8, 0u8,
+            173u8, 17u8, 0u8, 0u8, 174u8, 17u8, 0u8, 0u8, 175u8, 17u8, 0u8, 0u8, 176u8, 17u8, 0u8,
+            0u8, 177u8, 17u8, 0u8, 0u8, 178u8, 17u8, 0u8, 0u8, 179u8, 17u8, 0u8, 0u8, 180u8, 17u8,
+            0u8, 0u8, 181u8, 17u8, 0u8, 0u8, 182u8, 17u8, 0u8, 0u8, 183u8, 17u8, 0u8, 0u8, 184u8,
+            17u8, 0u8, 0u8, 185u8, 17u8, 0u8, 0u8, 186u8, 17u8, 0u8, 0u8, 187u8, 17u8, 0u8, 0u8,
+            188u8, 17u8, 0u8, 0u8, 189u8, 17u8, 0u8, 0u8, 190u8, 17u8, 0u8, 0u8, 191u8, 17u8, 0u8,
+            0u8, 192u8, 17u8, 0u8, 0u8, 193u8, 17u8, 0u8, 0u8, 194u8, 17u8, 0u8, 0u8, 195u8, 17u8,
+            0u8, 0u8, 196u8, 17u8, 0u8, 0u8, 197u8, 17u8, 0u8, 0u8, 198u8, 17u8, 0u8, 0u8, 199u8,
+            17u8, 0u8, 0u8, 200u8, 17u8, 0u8, 0u8, 201u8, 17u8, 0u8, 0u8, 202u8, 17u8, 0u8, 0u8,
+            203u8, 17u8, 0u8, 0u8, 204u8, 17u8, 0u8, 0u8, 205u8, 17u8, 0u8, 0u8, 206u8, 17u8, 0u8,
+            0u8, 207u8, 17u8, 0u8, 0u8, 208u8, 17u8, 0u8, 0u8, 209u8, 17u8, 0u8, 0u8, 210u8, 17u8,
+            0u8, 0u8, 211u8, 17u8, 0u8, 0u8, 212u8, 17u8, 0u8, 0u8, 213u8, 17u8, 0u8, 0u8, 214u8,
+            17u8, 0u8, 0u8, 215u8, 17u8, 0u8, 0u8, 216u8, 17u8, 0u8, 0u8, 217u8, 17u8, 0u8, 0u8,
+            218u8, 17u8, 0u8, 0u8, 219u8, 17u8, 0u8, 0u8, 220u8, 17u8, 0u8, 0u8, 221u8, 17u8, 0u8,
+            0u8, 222u8, 17u8, 0u8, 0u8, 223u8, 17u8, 0u8, 0u8, 224u8, 17u8, 0u8, 0u8, 225u8, 17u8,
+            0u8, 0u8, 226u8, 17u8, 0u8, 0u8, 227u8, 17u8, 0u8, 0u8, 228u8, 17u8, 0u8, 0u8, 229u8,
+            17u8, 0u8, 0u8, 230u8, 17u8, 0u8, 0u8, 231u8, 17u8, 0u8, 0u8, 232u8, 17u8, 0u8, 0u8,
+            233u8, 17u8, 0u8, 0u8, 234u8, 17u8, 0u8, 0u8, 235u8, 17u8, 0u8, 0u8, 236u8, 17u8, 0u8,
+            0u8, 237u8, 17u8, 0u8, 0u8, 238u8, 17u8, 0u8, 0u8, 239u8, 17u8, 0u8, 0u8, 240u8, 17u8,
+            0u8, 0u8, 241u8, 17u8, 0u8, 0u8, 242u8, 17u8, 0u8, 0u8, 243u8, 17u8, 0u8, 0u8, 244u8,
+            17u8, 0u8, 0u8, 245u8, 17u8, 0u8, 0u8, 246u8, 17u8, 0u8, 0u8, 247u8, 17u8, 0u8, 0u8,
+            248u8, 17u8, 0u8, 0u8, 249u8, 17u8, 0u8, 0u8, 250u8, 17u8, 0u8, 0u8, 251u8, 17u8, 0u8,
+            0u8, 252u8, 17u8, 0u8, 0u8, 253u8, 17u8, 0u8, 0u8, 254u8, 17u8, 0u8, 0u8, 255u8, 17u8,
+            0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 1u8, 32u8, 0u8, 0u8, 2u8, 32u8, 0u8, 0u8, 3u8, 32u8,
+            0u8, 0u8, 4u8, 32u8, 0u8, 0u8, 5u8, 32u8, 0u8, 0u8, 6u8, 32u8, 0u8, 0u8, 7u8, 32u8,
+            0u8, 0u8, 8u8, 32u8, 0u8, 0u8, 9u8, 32u8, 0u8, 0u8, 10u8, 32u8, 0u8, 0u8, 11u8, 32u8,
+            0u8, 0u8, 12u8, 32u8, 0u8, 0u8, 13u8, 32u8, 0u8, 0u8, 14u8, 32u8, 0u8, 0u8, 15u8, 32u8,
+            0u8, 0u8, 16u8, 32u8, 0u8, 0u8, 17u8, 32u8, 0u8, 0u8, 18u8, 32u8, 0u8, 0u8, 19u8, 32u8,
+            0u8, 0u8, 20u8, 32u8, 0u8, 0u8, 21u8, 32u8, 0u8, 0u8, 22u8, 32u8, 0u8, 0u8, 23u8, 32u8,
+            0u8, 0u8, 24u8, 32u8, 0u8, 0u8, 25u8, 32u8, 0u8, 0u8, 26u8, 32u8, 0u8, 0u8, 27u8, 32u8,
+            0u8, 0u8, 28u8, 32u8, 0u8, 0u8, 29u8, 32u8, 0u8, 0u8, 30u8, 32u8, 0u8, 0u8, 31u8, 32u8,
+            0u8, 0u8, 32u8, 32u8, 0u8, 0u8, 33u8, 32u8, 0u8, 0u8, 34u8, 32u8, 0u8, 0u8, 35u8, 32u8,
+            0u8, 0u8, 36u8, 32u8, 0u8, 0u8, 37u8, 32u8, 0u8, 0u8, 38u8, 32u8, 0u8, 0u8, 39u8, 32u8,
+            0u8, 0u8, 40u8, 32u8, 0u8, 0u8, 41u8, 32u8, 0u8, 0u8, 42u8, 32u8, 0u8, 0u8, 43u8, 32u8,
+            0u8, 0u8, 44u8, 32u8, 0u8, 0u8, 45u8, 32u8, 0u8, 0u8, 46u8, 32u8, 0u8, 0u8, 47u8, 32u8,
+            0u8, 0u8, 48u8, 32u8, 0u8, 0u8, 49u8, 32u8, 0u8, 0u8, 50u8, 32u8, 0u8, 0u8, 51u8, 32u8,
+            0u8, 0u8, 52u8, 32u8, 0u8, 0u8, 53u8, 32u8, 0u8, 0u8, 54u8, 32u8, 0u8, 0u8, 55u8, 32u8,
+            0u8, 0u8, 56u8, 32u8, 0u8, 0u8, 57u8, 32u8, 0u8, 0u8, 58u8, 32u8, 0u8, 0u8, 59u8, 32u8,
+            0u8, 0u8, 60u8, 32u8, 0u8, 0u8, 61u8, 32u8, 0u8, 0u8, 62u8, 32u8, 0u8, 0u8, 63u8, 32u8,
+            0u8, 0u8, 64u8, 32u8, 0u8, 0u8, 65u8, 32u8, 0u8, 0u8, 66u8, 32u8, 0u8, 0u8, 67u8, 32u8,
+            0u8, 0u8, 68u8, 32u8, 0u8, 0u8, 69u8, 32u8, 0u8, 0u8, 70u8, 32u8, 0u8, 0u8, 71u8, 32u8,
+            0u8, 0u8, 72u8, 32u8, 0u8, 0u8, 73u8, 32u8, 0u8, 0u8, 74u8, 32u8, 0u8, 0u8, 75u8, 32u8,
+            0u8, 0u8, 76u8, 32u8, 0u8, 0u8, 77u8, 32u8, 0u8, 0u8, 78u8, 32u8, 0u8, 0u8, 79u8, 32u8,
+            0u8, 0u8, 80u8, 32u8, 0u8, 0u8, 81u8, 32u8, 0u8, 0u8, 82u8, 32u8, 0u8, 0u8, 83u8, 32u8,
+            0u8, 0u8, 84u8, 32u8, 0u8, 0u8, 85u8, 32u8, 0u8, 0u8, 86u8, 32u8, 0u8, 0u8, 87u8, 32u8,
+            0u8, 0u8, 88u8, 32u8, 0u8, 0u8, 89u8, 32u8, 0u8, 0u8, 90u8, 32u8, 0u8, 0u8, 91u8, 32u8,
+            0u8, 0u8, 92u8, 32u8, 0u8, 0u8, 93u8, 32u8, 0u8, 0u8, 94u8, 32u8, 0u8, 0u8, 95u8, 32u8,
+            0u8, 0u8, 96u8, 32u8, 0u8, 0u8, 97u8, 32u8, 0u8, 0u8, 98u8, 32u8, 0u8, 0u8, 99u8, 32u8,
+            0u8, 0u8, 100u8, 32u8, 0u8, 0u8, 102u8, 32u8, 0u8, 0u8, 103u8, 32u8, 0u8, 0u8, 104u8,
+            32u8, 0u8, 0u8, 105u8, 32u8, 0u8, 0u8, 106u8, 32u8, 0u8, 0u8, 107u8, 32u8, 0u8, 0u8,
+            108u8, 32u8, 0u8, 0u8, 109u8, 32u8, 0u8, 0u8, 110u8, 32u8, 0u8, 0u8, 111u8, 32u8, 0u8,
+            0u8, 0u8, 38u8, 0u8, 0u8, 1u8, 38u8, 0u8, 0u8, 2u8, 38u8, 0u8, 0u8, 3u8, 38u8, 0u8,
+            0u8, 4u8, 38u8, 0u8, 0u8, 5u8, 38u8, 0u8, 0u8, 6u8, 38u8, 0u8, 0u8, 7u8, 38u8, 0u8,
+            0u8, 8u8, 38u8, 0u8, 0u8, 9u8, 38u8, 0u8, 0u8, 10u8, 38u8, 0u8, 0u8, 11u8, 38u8, 0u8,
+            0u8, 12u8, 38u8, 0u8, 0u8, 13u8, 38u8, 0u8, 0u8, 14u8, 38u8, 0u8, 0u8, 15u8, 38u8, 0u8,
+            0u8, 16u8, 38u8, 0u8, 0u8, 17u8, 38u8, 0u8, 0u8, 18u8, 38u8, 0u8, 0u8, 19u8, 38u8, 0u8,
+            0u8, 20u8, 38u8, 0u8, 0u8, 21u8, 38u8, 0u8, 0u8, 22u8, 38u8, 0u8, 0u8, 23u8, 38u8, 0u8,
+            0u8, 24u8, 38u8, 0u8, 0u8, 25u8, 38u8, 0u8, 0u8, 26u8, 38u8, 0u8, 0u8, 27u8, 38u8, 0u8,
+            0u8, 28u8, 38u8, 0u8, 0u8, 29u8, 38u8, 0u8, 0u8, 30u8, 38u8, 0u8, 0u8, 31u8, 38u8, 0u8,
+            0u8, 32u8, 38u8, 0u8, 0u8, 33u8, 38u8, 0u8, 0u8, 34u8, 38u8, 0u8, 0u8, 35u8, 38u8, 0u8,
+            0u8, 36u8, 38u8, 0u8, 0u8, 37u8, 38u8, 0u8, 0u8, 38u8, 38u8, 0u8, 0u8, 39u8, 38u8, 0u8,
+            0u8, 40u8, 38u8, 0u8, 0u8, 41u8, 38u8, 0u8, 0u8, 42u8, 38u8, 0u8, 0u8, 43u8, 38u8, 0u8,
+            0u8, 44u8, 38u8, 0u8, 0u8, 45u8, 38u8, 0u8, 0u8, 46u8, 38u8, 0u8, 0u8, 47u8, 38u8, 0u8,
+            0u8, 48u8, 38u8, 0u8, 0u8, 49u8, 38u8, 0u8, 0u8, 50u8, 38u8, 0u8, 0u8, 51u8, 38u8, 0u8,
+            0u8, 52u8, 38u8, 0u8, 0u8, 53u8, 38u8, 0u8, 0u8, 54u8, 38u8, 0u8, 0u8, 55u8, 38u8, 0u8,
+            0u8, 56u8, 38u8, 0u8, 0u8, 57u8, 38u8, 0u8, 0u8, 58u8, 38u8, 0u8, 0u8, 59u8, 38u8, 0u8,
+            0u8, 60u8, 38u8, 0u8, 0u8, 61u8, 38u8, 0u8, 0u8, 62u8, 38u8, 0u8, 0u8, 63u8, 38u8, 0u8,
+            0u8, 64u8, 38u8, 0u8, 0u8, 65u8, 38u8, 0u8, 0u8, 66u8, 38u8, 0u8, 0u8, 67u8, 38u8, 0u8,
+            0u8, 68u8, 38u8, 0u8, 0u8, 69u8, 38u8, 0u8, 0u8, 70u8, 38u8, 0u8, 0u8, 71u8, 38u8, 0u8,
+            0u8, 72u8, 38u8, 0u8, 0u8, 73u8, 38u8, 0u8, 0u8, 74u8, 38u8, 0u8, 0u8, 75u8, 38u8, 0u8,
+            0u8, 76u8, 38u8, 0u8, 0u8, 77u8, 38u8, 0u8, 0u8, 78u8, 38u8, 0u8, 0u8, 79u8, 38u8, 0u8,
+            0u8, 80u8, 38u8, 0u8, 0u8, 81u8, 38u8, 0u8, 0u8, 82u8, 38u8, 0u8, 0u8, 83u8, 38u8, 0u8,
+            0u8, 84u8, 38u8, 0u8, 0u8, 85u8, 38u8, 0u8, 0u8, 86u8, 38u8, 0u8, 0u8, 87u8, 38u8, 0u8,
+            0u8, 88u8, 38u8, 0u8, 0u8, 89u8, 38u8, 0u8, 0u8, 90u8, 38u8, 0u8, 0u8, 91u8, 38u8, 0u8,
+            0u8, 92u8, 38u8, 0u8, 0u8, 93u8, 38u8, 0u8, 0u8, 94u8, 38u8, 0u8, 0u8, 95u8, 38u8, 0u8,
+            0u8, 96u8, 38u8, 0u8, 0u8, 97u8, 38u8, 0u8, 0u8, 98u8, 38u8, 0u8, 0u8, 99u8, 38u8, 0u8,
+            0u8, 100u8, 38u8, 0u8, 0u8, 101u8, 38u8, 0u8, 0u8, 102u8, 38u8, 0u8, 0u8, 103u8, 38u8,
+            0u8, 0u8, 104u8, 38u8, 0u8, 0u8, 105u8, 38u8, 0u8, 0u8, 106u8, 38u8, 0u8, 0u8, 107u8,
+            38u8, 0u8, 0u8, 108u8, 38u8, 0u8, 0u8, 109u8, 38u8, 0u8, 0u8, 110u8, 38u8, 0u8, 0u8,
+            111u8, 38u8, 0u8, 0u8, 112u8, 38u8, 0u8, 0u8, 113u8, 38u8, 0u8, 0u8, 114u8, 38u8, 0u8,
+            0u8, 115u8, 38u8, 0u8, 0u8, 116u8, 38u8, 0u8, 0u8, 117u8, 38u8, 0u8, 0u8, 118u8, 38u8,
+            0u8, 0u8, 119u8, 38u8, 0u8, 0u8, 120u8, 38u8, 0u8, 0u8, 121u8, 38u8, 0u8, 0u8, 122u8,
+            38u8, 0u8, 0u8, 123u8, 38u8, 0u8, 0u8, 124u8, 38u8, 0u8, 0u8, 125u8, 38u8, 0u8, 0u8,
+            126u8, 38u8, 0u8, 0u8, 127u8, 38u8, 0u8, 0u8, 128u8, 38u8, 0u8, 0u8, 129u8, 38u8, 0u8,
+            0u8, 130u8, 38u8, 0u8, 0u8, 131u8, 38u8, 0u8, 0u8, 132u8, 38u8, 0u8, 0u8, 133u8, 38u8,
+            0u8, 0u8, 134u8, 38u8, 0u8, 0u8, 135u8, 38u8, 0u8, 0u8, 136u8, 38u8, 0u8, 0u8, 137u8,
+            38u8, 0u8, 0u8, 138u8, 38u8, 0u8, 0u8, 139u8, 38u8, 0u8, 0u8, 140u8, 38u8, 0u8, 0u8,
+            141u8, 38u8, 0u8, 0u8, 142u8, 38u8, 0u8, 0u8, 143u8, 38u8, 0u8, 0u8, 144u8, 38u8, 0u8,
+            0u8, 145u8, 38u8, 0u8, 0u8, 146u8, 38u8, 0u8, 0u8, 147u8, 38u8, 0u8, 0u8, 148u8, 38u8,
+            0u8, 0u8, 149u8, 38u8, 0u8, 0u8, 150u8, 38u8, 0u8, 0u8, 151u8, 38u8, 0u8, 0u8, 152u8,
+            38u8, 0u8, 0u8, 153u8, 38u8, 0u8, 0u8, 154u8, 38u8, 0u8, 0u8, 155u8, 38u8, 0u8, 0u8,
+            156u8, 38u8, 0u8, 0u8, 157u8, 38u8, 0u8, 0u8, 158u8, 38u8, 0u8, 0u8, 159u8, 38u8, 0u8,
+            0u8, 160u8, 38u8, 0u8, 0u8, 161u8, 38u8, 0u8, 0u8, 162u8, 38u8, 0u8, 0u8, 163u8, 38u8,
+            0u8, 0u8, 164u8, 38u8, 0u8, 0u8, 165u8, 38u8, 0u8, 0u8, 166u8, 38u8, 0u8, 0u8, 167u8,
+            38u8, 0u8, 0u8, 168u8, 38u8, 0u8, 0u8, 169u8, 38u8, 0u8, 0u8, 170u8, 38u8, 0u8, 0u8,
+            171u8, 38u8, 0u8, 0u8, 172u8, 38u8, 0u8, 0u8, 173u8, 38u8, 0u8, 0u8, 174u8, 38u8, 0u8,
+            0u8, 175u8, 38u8, 0u8, 0u8, 176u8, 38u8, 0u8, 0u8, 177u8, 38u8, 0u8, 0u8, 178u8, 38u8,
+            0u8, 0u8, 179u8, 38u8, 0u8, 0u8, 180u8, 38u8, 0u8, 0u8, 181u8, 38u8, 0u8, 0u8, 182u8,
+            38u8, 0u8, 0u8, 183u8, 38u8, 0u8, 0u8, 184u8, 38u8, 0u8, 0u8, 185u8, 38u8, 0u8, 0u8,
+            186u8, 38u8, 0u8, 0u8, 187u8, 38u8, 0u8, 0u8, 188u8, 38u8, 0u8, 0u8, 189u8, 38u8, 0u8,
+            0u8, 190u8, 38u8, 0u8, 0u8, 191u8, 38u8, 0u8, 0u8, 192u8, 38u8, 0u8, 0u8, 193u8, 38u8,
+            0u8, 0u8, 194u8, 38u8, 0u8, 0u8, 195u8, 38u8, 0u8, 0u8, 196u8, 38u8, 0u8, 0u8, 197u8,
+            38u8, 0u8, 0u8, 198u8, 38u8, 0u8, 0u8, 199u8, 38u8, 0u8, 0u8, 200u8, 38u8, 0u8, 0u8,
+            201u8, 38u8, 0u8, 0u8, 202u8, 38u8, 0u8, 0u8, 203u8, 38u8, 0u8, 0u8, 204u8, 38u8, 0u8,
+            0u8, 205u8, 38u8, 0u8, 0u8, 206u8, 38u8, 0u8, 0u8, 207u8, 38u8, 0u8, 0u8, 208u8, 38u8,
+            0u8, 0u8, 209u8, 38u8, 0u8, 0u8, 210u8, 38u8, 0u8, 0u8, 211u8, 38u8, 0u8, 0u8, 212u8,
+            38u8, 0u8, 0u8, 213u8, 38u8, 0u8, 0u8, 214u8, 38u8, 0u8, 0u8, 215u8, 38u8, 0u8, 0u8,
+            216u8, 38u8, 0u8, 0u8, 217u8, 38u8, 0u8, 0u8, 218u8, 38u8, 0u8, 0u8, 219u8, 38u8, 0u8,
+            0u8, 220u8, 38u8, 0u8, 0u8, 221u8, 38u8, 0u8, 0u8, 222u8, 38u8, 0u8, 0u8, 223u8, 38u8,
+            0u8, 0u8, 224u8, 38u8, 0u8, 0u8, 225u8, 38u8, 0u8, 0u8, 226u8, 38u8, 0u8, 0u8, 227u8,
+            38u8, 0u8, 0u8, 228u8, 38u8, 0u8, 0u8, 229u8, 38u8, 0u8, 0u8, 230u8, 38u8, 0u8, 0u8,
+            231u8, 38u8, 0u8, 0u8, 232u8, 38u8, 0u8, 0u8, 233u8, 38u8, 0u8, 0u8, 234u8, 38u8, 0u8,
+            0u8, 235u8, 38u8, 0u8, 0u8, 236u8, 38u8, 0u8, 0u8, 237u8, 38u8, 0u8, 0u8, 238u8, 38u8,
+            0u8, 0u8, 239u8, 38u8, 0u8, 0u8, 240u8, 38u8, 0u8, 0u8, 241u8, 38u8, 0u8, 0u8, 242u8,
+            38u8, 0u8, 0u8, 243u8, 38u8, 0u8, 0u8, 244u8, 38u8, 0u8, 0u8, 245u8, 38u8, 0u8, 0u8,
+            246u8, 38u8, 0u8, 0u8, 247u8, 38u8, 0u8, 0u8, 248u8, 38u8, 0u8, 0u8, 249u8, 38u8, 0u8,
+            0u8, 250u8, 38u8, 0u8, 0u8, 251u8, 38u8, 0u8, 0u8, 252u8, 38u8, 0u8, 0u8, 253u8, 38u8,
+            0u8, 0u8, 254u8, 38u8, 0u8, 0u8, 255u8, 38u8, 0u8, 0u8, 112u8, 254u8, 0u8, 0u8, 113u8,
+            254u8, 0u8, 0u8, 114u8, 254u8, 0u8, 0u8, 115u8, 254u8, 0u8, 0u8, 116u8, 254u8, 0u8,
+            0u8, 118u8, 254u8, 0u8, 0u8, 119u8, 254u8, 0u8, 0u8, 120u8, 254u8, 0u8, 0u8, 121u8,
+            254u8, 0u8, 0u8, 122u8, 254u8, 0u8, 0u8, 123u8, 254u8, 0u8, 0u8, 124u8, 254u8, 0u8,
+            0u8, 125u8, 254u8, 0u8, 0u8, 126u8, 254u8, 0u8, 0u8, 127u8, 254u8, 0u8, 0u8, 128u8,
+            254u8, 0u8, 0u8, 129u8, 254u8, 0u8, 0u8, 130u8, 254u8, 0u8, 0u8, 131u8, 254u8, 0u8,
+            0u8, 132u8, 254u8, 0u8, 0u8, 133u8, 254u8, 0u8, 0u8, 134u8, 254u8, 0u8, 0u8, 135u8,
+            254u8, 0u8, 0u8, 136u8, 254u8, 0u8, 0u8, 137u8, 254u8, 0u8, 0u8, 138u8, 254u8, 0u8,
+            0u8, 139u8, 254u8, 0u8, 0u8, 140u8, 254u8, 0u8, 0u8, 141u8, 254u8, 0u8, 0u8, 142u8,
+            254u8, 0u8, 0u8, 143u8, 254u8, 0u8, 0u8, 144u8, 254u8, 0u8, 0u8, 145u8, 254u8, 0u8,
+            0u8, 146u8, 254u8, 0u8, 0u8, 147u8, 254u8, 0u8, 0u8, 148u8, 254u8, 0u8, 0u8, 149u8,
+            254u8, 0u8, 0u8, 150u8, 254u8, 0u8, 0u8, 151u8, 254u8, 0u8, 0u8, 152u8, 254u8, 0u8,
+            0u8, 153u8, 254u8, 0u8, 0u8, 154u8, 254u8, 0u8, 0u8, 155u8, 254u8, 0u8, 0u8, 156u8,
+            254u8, 0u8, 0u8, 157u8, 254u8, 0u8, 0u8, 158u8, 254u8, 0u8, 0u8, 159u8, 254u8, 0u8,
+            0u8, 160u8, 254u8, 0u8, 0u8, 161u8, 254u8, 0u8, 0u8, 162u8, 254u8, 0u8, 0u8, 163u8,
+            254u8, 0u8, 0u8, 164u8, 254u8, 0u8, 0u8, 165u8, 254u8, 0u8, 0u8, 166u8, 254u8, 0u8,
+            0u8, 167u8, 254u8, 0u8, 0u8, 168u8, 254u8, 0u8, 0u8, 169u8, 254u8, 0u8, 0u8, 170u8,
+            254u8, 0u8, 0u8, 171u8, 254u8, 0u8, 0u8, 172u8, 254u8, 0u8, 0u8, 173u8, 254u8, 0u8,
+            0u8, 174u8, 254u8, 0u8, 0u8, 175u8, 254u8, 0u8, 0u8, 176u8, 254u8, 0u8, 0u8, 177u8,
+            254u8, 0u8, 0u8, 178u8, 254u8, 0u8, 0u8, 179u8, 254u8, 0u8, 0u8, 180u8, 254u8, 0u8,
+            0u8, 181u8, 254u8, 0u8, 0u8, 182u8, 254u8, 0u8, 0u8, 183u8, 254u8, 0u8, 0u8, 184u8,
+            254u8, 0u8, 0u8, 185u8, 254u8, 0u8, 0u8, 186u8, 254u8, 0u8, 0u8, 187u8, 254u8, 0u8,
+            0u8, 188u8, 254u8, 0u8, 0u8, 189u8, 254u8, 0u8, 0u8, 190u8, 254u8, 0u8, 0u8, 191u8,
+            254u8, 0u8, 0u8, 192u8, 254u8, 0u8, 0u8, 193u8, 254u8, 0u8, 0u8, 194u8, 254u8, 0u8,
+            0u8, 195u8, 254u8, 0u8, 0u8, 196u8, 254u8, 0u8, 0u8, 197u8, 254u8, 0u8, 0u8, 198u8,
+            254u8, 0u8, 0u8, 199u8, 254u8, 0u8, 0u8, 200u8, 254u8, 0u8, 0u8, 201u8, 254u8, 0u8,
+            0u8, 202u8, 254u8, 0u8, 0u8, 203u8, 254u8, 0u8, 0u8, 204u8, 254u8, 0u8, 0u8, 205u8,
+            254u8, 0u8, 0u8, 206u8, 254u8, 0u8, 0u8, 207u8, 254u8, 0u8, 0u8, 208u8, 254u8, 0u8,
+            0u8, 209u8, 254u8, 0u8, 0u8, 210u8, 254u8, 0u8, 0u8, 211u8, 254u8, 0u8, 0u8, 212u8,
+            254u8, 0u8, 0u8, 213u8, 254u8, 0u8, 0u8, 214u8, 254u8, 0u8, 0u8, 215u8, 254u8, 0u8,
+            0u8, 216u8, 254u8, 0u8, 0u8, 217u8, 254u8, 0u8, 0u8, 218u8, 254u8, 0u8, 0u8, 219u8,
+            254u8, 0u8, 0u8, 220u8, 254u8, 0u8, 0u8, 221u8, 254u8, 0u8, 0u8, 222u8, 254u8, 0u8,
+            0u8, 223u8, 254u8, 0u8, 0u8, 224u8, 254u8, 0u8, 0u8, 225u8, 254u8, 0u8, 0u8, 226u8,
+            254u8, 0u8, 0u8, 227u8, 254u8, 0u8, 0u8, 228u8, 254u8, 0u8, 0u8, 229u8, 254u8, 0u8,
+            0u8, 230u8, 254u8, 0u8, 0u8, 231u8, 254u8, 0u8, 0u8, 232u8, 254u8, 0u8, 0u8, 233u8,
+            254u8, 0u8, 0u8, 234u8, 254u8, 0u8, 0u8, 235u8, 254u8, 0u8, 0u8, 236u8, 254u8, 0u8,
+            0u8, 237u8, 254u8, 0u8, 0u8, 238u8, 254u8, 0u8, 0u8, 239u8, 254u8, 0u8, 0u8, 240u8,
+            254u8, 0u8, 0u8, 241u8, 254u8, 0u8, 0u8, 242u8, 254u8, 0u8, 0u8, 243u8, 254u8, 0u8,
+            0u8, 244u8, 254u8, 0u8, 0u8, 245u8, 254u8, 0u8, 0u8, 246u8, 254u8, 0u8, 0u8, 247u8,
+            254u8, 0u8, 0u8, 248u8, 254u8, 0u8, 0u8, 249u8, 254u8, 0u8, 0u8, 250u8, 254u8, 0u8,
+            0u8, 251u8, 254u8, 0u8, 0u8, 252u8, 254u8, 0u8, 0u8, 255u8, 254u8, 0u8, 0u8,
+        ])
+    },
+    names: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            11u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 5u8,
+            0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 26u8, 0u8,
+            0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 36u8, 0u8,
+            0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 44u8, 0u8,
+            0u8, 0u8, 46u8, 0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 54u8, 0u8,
+            0u8, 0u8, 57u8, 0u8, 0u8, 0u8, 60u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 65u8, 0u8,
+            0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 70u8, 0u8, 0u8, 0u8, 73u8, 0u8, 0u8, 0u8, 76u8, 0u8,
+            0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 86u8, 0u8, 0u8, 0u8, 90u8, 0u8,
+            0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 98u8, 0u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 107u8, 0u8,
+            0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 115u8, 0u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 123u8, 0u8,
+            0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 140u8, 0u8,
+            0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 158u8, 0u8,
+            0u8, 0u8, 162u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 170u8, 0u8, 0u8, 0u8, 175u8, 0u8,
+            0u8, 0u8, 180u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 189u8, 0u8, 0u8, 0u8, 193u8, 0u8,
+            0u8, 0u8, 196u8, 0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 203u8, 0u8, 0u8, 0u8, 206u8, 0u8,
+            0u8, 0u8, 209u8, 0u8, 0u8, 0u8, 212u8, 0u8, 0u8, 0u8, 216u8, 0u8, 0u8, 0u8, 220u8, 0u8,
+            0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 228u8, 0u8, 0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 237u8, 0u8,
+            0u8, 0u8, 241u8, 0u8, 0u8, 0u8, 245u8, 0u8, 0u8, 0u8, 249u8, 0u8, 0u8, 0u8, 253u8, 0u8,
+            0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 5u8, 1u8, 0u8, 0u8, 10u8, 1u8, 0u8, 0u8, 14u8, 1u8, 0u8,
+            0u8, 18u8, 1u8, 0u8, 0u8, 23u8, 1u8, 0u8, 0u8, 28u8, 1u8, 0u8, 0u8, 32u8, 1u8, 0u8,
+            0u8, 36u8, 1u8, 0u8, 0u8, 40u8, 1u8, 0u8, 0u8, 44u8, 1u8, 0u8, 0u8, 49u8, 1u8, 0u8,
+            0u8, 54u8, 1u8, 0u8, 0u8, 59u8, 1u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8, 67u8, 1u8, 0u8,
+            0u8, 71u8, 1u8, 0u8, 0u8, 73u8, 1u8, 0u8, 0u8, 77u8, 1u8, 0u8, 0u8, 78u8, 1u8, 0u8,
+            0u8, 81u8, 1u8, 0u8, 0u8, 84u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8, 90u8, 1u8, 0u8,
+            0u8, 93u8, 1u8, 0u8, 0u8, 96u8, 1u8, 0u8, 0u8, 99u8, 1u8, 0u8, 0u8, 102u8, 1u8, 0u8,
+            0u8, 103u8, 1u8, 0u8, 0u8, 106u8, 1u8, 0u8, 0u8, 112u8, 1u8, 0u8, 0u8, 118u8, 1u8, 0u8,
+            0u8, 121u8, 1u8, 0u8, 0u8, 125u8, 1u8, 0u8, 0u8, 128u8, 1u8, 0u8, 0u8, 129u8, 1u8, 0u8,
+            0u8, 132u8, 1u8, 0u8, 0u8, 135u8, 1u8, 0u8, 0u8, 138u8, 1u8, 0u8, 0u8, 141u8, 1u8, 0u8,
+            0u8, 144u8, 1u8, 0u8, 0u8, 147u8, 1u8, 0u8, 0u8, 150u8, 1u8, 0u8, 0u8, 152u8, 1u8, 0u8,
+            0u8, 153u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 162u8, 1u8, 0u8, 0u8, 168u8, 1u8, 0u8,
+            0u8, 175u8, 1u8, 0u8, 0u8, 182u8, 1u8, 0u8, 0u8, 189u8, 1u8, 0u8, 0u8, 192u8, 1u8, 0u8,
+            0u8, 198u8, 1u8, 0u8, 0u8, 204u8, 1u8, 0u8, 0u8, 210u8, 1u8, 0u8, 0u8, 216u8, 1u8, 0u8,
+            0u8, 222u8, 1u8, 0u8, 0u8, 229u8, 1u8, 0u8, 0u8, 233u8, 1u8, 0u8, 0u8, 239u8, 1u8, 0u8,
+            0u8, 245u8, 1u8, 0u8, 0u8, 251u8, 1u8, 0u8, 0u8, 1u8, 2u8, 0u8, 0u8, 7u8, 2u8, 0u8,
+            0u8, 13u8, 2u8, 0u8, 0u8, 19u8, 2u8, 0u8, 0u8, 25u8, 2u8, 0u8, 0u8, 31u8, 2u8, 0u8,
+            0u8, 36u8, 2u8, 0u8, 0u8, 42u8, 2u8, 0u8, 0u8, 48u8, 2u8, 0u8, 0u8, 54u8, 2u8, 0u8,
+            0u8, 60u8, 2u8, 0u8, 0u8, 66u8, 2u8, 0u8, 0u8, 72u8, 2u8, 0u8, 0u8, 75u8, 2u8, 0u8,
+            0u8, 81u8, 2u8, 0u8, 0u8, 87u8, 2u8, 0u8, 0u8, 93u8, 2u8, 0u8, 0u8, 99u8, 2u8, 0u8,
+            0u8, 105u8, 2u8, 0u8, 0u8, 111u8, 2u8, 0u8, 0u8, 116u8, 2u8, 0u8, 0u8, 122u8, 2u8, 0u8,
+            0u8, 128u8, 2u8, 0u8, 0u8, 134u8, 2u8, 0u8, 0u8, 140u8, 2u8, 0u8, 0u8, 146u8, 2u8, 0u8,
+            0u8, 152u8, 2u8, 0u8, 0u8, 159u8, 2u8, 0u8, 0u8, 163u8, 2u8, 0u8, 0u8, 169u8, 2u8, 0u8,
+            0u8, 175u8, 2u8, 0u8, 0u8, 181u8, 2u8, 0u8, 0u8, 187u8, 2u8, 0u8, 0u8, 193u8, 2u8, 0u8,
+            0u8, 199u8, 2u8, 0u8, 0u8, 205u8, 2u8, 0u8, 0u8, 211u8, 2u8, 0u8, 0u8, 217u8, 2u8, 0u8,
+            0u8, 222u8, 2u8, 0u8, 0u8, 228u8, 2u8, 0u8, 0u8, 234u8, 2u8, 0u8, 0u8, 240u8, 2u8, 0u8,
+            0u8, 246u8, 2u8, 0u8, 0u8, 252u8, 2u8, 0u8, 0u8, 2u8, 3u8, 0u8, 0u8, 5u8, 3u8, 0u8,
+            0u8, 11u8, 3u8, 0u8, 0u8, 17u8, 3u8, 0u8, 0u8, 23u8, 3u8, 0u8, 0u8, 29u8, 3u8, 0u8,
+            0u8, 35u8, 3u8, 0u8, 0u8, 41u8, 3u8, 0u8, 0u8, 46u8, 3u8, 0u8, 0u8, 52u8, 3u8, 0u8,
+            0u8, 58u8, 3u8, 0u8, 0u8, 64u8, 3u8, 0u8, 0u8, 70u8, 3u8, 0u8, 0u8, 76u8, 3u8, 0u8,
+            0u8, 82u8, 3u8, 0u8, 0u8, 88u8, 3u8, 0u8, 0u8, 94u8, 3u8, 0u8, 0u8, 100u8, 3u8, 0u8,
+            0u8, 106u8, 3u8, 0u8, 0u8, 112u8, 3u8, 0u8, 0u8, 119u8, 3u8, 0u8, 0u8, 126u8, 3u8, 0u8,
+            0u8, 132u8, 3u8, 0u8, 0u8, 138u8, 3u8, 0u8, 0u8, 144u8, 3u8, 0u8, 0u8, 150u8, 3u8, 0u8,
+            0u8, 156u8, 3u8, 0u8, 0u8, 162u8, 3u8, 0u8, 0u8, 168u8, 3u8, 0u8, 0u8, 174u8, 3u8, 0u8,
+            0u8, 180u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 193u8, 3u8, 0u8, 0u8, 200u8, 3u8, 0u8,
+            0u8, 206u8, 3u8, 0u8, 0u8, 212u8, 3u8, 0u8, 0u8, 218u8, 3u8, 0u8, 0u8, 224u8, 3u8, 0u8,
+            0u8, 230u8, 3u8, 0u8, 0u8, 236u8, 3u8, 0u8, 0u8, 242u8, 3u8, 0u8, 0u8, 248u8, 3u8, 0u8,
+            0u8, 255u8, 3u8, 0u8, 0u8, 6u8, 4u8, 0u8, 0u8, 12u8, 4u8, 0u8, 0u8, 18u8, 4u8, 0u8,
+            0u8, 24u8, 4u8, 0u8, 0u8, 30u8, 4u8, 0u8, 0u8, 36u8, 4u8, 0u8, 0u8, 42u8, 4u8, 0u8,
+            0u8, 48u8, 4u8, 0u8, 0u8, 54u8, 4u8, 0u8, 0u8, 60u8, 4u8, 0u8, 0u8, 66u8, 4u8, 0u8,
+            0u8, 72u8, 4u8, 0u8, 0u8, 78u8, 4u8, 0u8, 0u8, 84u8, 4u8, 0u8, 0u8, 90u8, 4u8, 0u8,
+            0u8, 97u8, 4u8, 0u8, 0u8, 103u8, 4u8, 0u8, 0u8, 108u8, 4u8, 0u8, 0u8, 113u8, 4u8, 0u8,
+            0u8, 119u8, 4u8, 0u8, 0u8, 125u8, 4u8, 0u8, 0u8, 131u8, 4u8, 0u8, 0u8, 137u8, 4u8, 0u8,
+            0u8, 142u8, 4u8, 0u8, 0u8, 148u8, 4u8, 0u8, 0u8, 154u8, 4u8, 0u8, 0u8, 160u8, 4u8, 0u8,
+            0u8, 166u8, 4u8, 0u8, 0u8, 172u8, 4u8, 0u8, 0u8, 178u8, 4u8, 0u8, 0u8, 185u8, 4u8, 0u8,
+            0u8, 192u8, 4u8, 0u8, 0u8, 198u8, 4u8, 0u8, 0u8, 204u8, 4u8, 0u8, 0u8, 210u8, 4u8, 0u8,
+            0u8, 216u8, 4u8, 0u8, 0u8, 222u8, 4u8, 0u8, 0u8, 228u8, 4u8, 0u8, 0u8, 234u8, 4u8, 0u8,
+            0u8, 240u8, 4u8, 0u8, 0u8, 250u8, 4u8, 0u8, 0u8, 255u8, 4u8, 0u8, 0u8, 4u8, 5u8, 0u8,
+            0u8, 10u8, 5u8, 0u8, 0u8, 16u8, 5u8, 0u8, 0u8, 22u8, 5u8, 0u8, 0u8, 28u8, 5u8, 0u8,
+            0u8, 35u8, 5u8, 0u8, 0u8, 42u8, 5u8, 0u8, 0u8, 47u8, 5u8, 0u8, 0u8, 52u8, 5u8, 0u8,
+            0u8, 58u8, 5u8, 0u8, 0u8, 64u8, 5u8, 0u8, 0u8, 70u8, 5u8, 0u8, 0u8, 76u8, 5u8, 0u8,
+            0u8, 82u8, 5u8, 0u8, 0u8, 88u8, 5u8, 0u8, 0u8, 94u8, 5u8, 0u8, 0u8, 100u8, 5u8, 0u8,
+            0u8, 106u8, 5u8, 0u8, 0u8, 112u8, 5u8, 0u8, 0u8, 118u8, 5u8, 0u8, 0u8, 124u8, 5u8, 0u8,
+            0u8, 130u8, 5u8, 0u8, 0u8, 136u8, 5u8, 0u8, 0u8, 142u8, 5u8, 0u8, 0u8, 148u8, 5u8, 0u8,
+            0u8, 154u8, 5u8, 0u8, 0u8, 160u8, 5u8, 0u8, 0u8, 166u8, 5u8, 0u8, 0u8, 172u8, 5u8, 0u8,
+            0u8, 178u8, 5u8, 0u8, 0u8, 184u8, 5u8, 0u8, 0u8, 190u8, 5u8, 0u8, 0u8, 196u8, 5u8, 0u8,
+            0u8, 202u8, 5u8, 0u8, 0u8, 208u8, 5u8, 0u8, 0u8, 215u8, 5u8, 0u8, 0u8, 222u8, 5u8, 0u8,
+            0u8, 229u8, 5u8, 0u8, 0u8, 236u8, 5u8, 0u8, 0u8, 242u8, 5u8, 0u8, 0u8, 248u8, 5u8, 0u8,
+            0u8, 255u8, 5u8, 0u8, 0u8, 6u8, 6u8, 0u8, 0u8, 12u8, 6u8, 0u8, 0u8, 18u8, 6u8, 0u8,
+            0u8, 24u8, 6u8, 0u8, 0u8, 30u8, 6u8, 0u8, 0u8, 36u8, 6u8, 0u8, 0u8, 43u8, 6u8, 0u8,
+            0u8, 50u8, 6u8, 0u8, 0u8, 56u8, 6u8, 0u8, 0u8, 62u8, 6u8, 0u8, 0u8, 68u8, 6u8, 0u8,
+            0u8, 74u8, 6u8, 0u8, 0u8, 80u8, 6u8, 0u8, 0u8, 87u8, 6u8, 0u8, 0u8, 94u8, 6u8, 0u8,
+            0u8, 100u8, 6u8, 0u8, 0u8, 106u8, 6u8, 0u8, 0u8, 112u8, 6u8, 0u8, 0u8, 118u8, 6u8, 0u8,
+            0u8, 124u8, 6u8, 0u8, 0u8, 130u8, 6u8, 0u8, 0u8, 136u8, 6u8, 0u8, 0u8, 143u8, 6u8, 0u8,
+            0u8, 150u8, 6u8, 0u8, 0u8, 156u8, 6u8, 0u8, 0u8, 161u8, 6u8, 0u8, 0u8, 166u8, 6u8, 0u8,
+            0u8, 172u8, 6u8, 0u8, 0u8, 179u8, 6u8, 0u8, 0u8, 186u8, 6u8, 0u8, 0u8, 192u8, 6u8, 0u8,
+            0u8, 197u8, 6u8, 0u8, 0u8, 202u8, 6u8, 0u8, 0u8, 207u8, 6u8, 0u8, 0u8, 213u8, 6u8, 0u8,
+            0u8, 219u8, 6u8, 0u8, 0u8, 225u8, 6u8, 0u8, 0u8, 231u8, 6u8, 0u8, 0u8, 238u8, 6u8, 0u8,
+            0u8, 244u8, 6u8, 0u8, 0u8, 251u8, 6u8, 0u8, 0u8, 5u8, 7u8, 0u8, 0u8, 12u8, 7u8, 0u8,
+            0u8, 19u8, 7u8, 0u8, 0u8, 26u8, 7u8, 0u8, 0u8, 31u8, 7u8, 0u8, 0u8, 36u8, 7u8, 0u8,
+            0u8, 43u8, 7u8, 0u8, 0u8, 50u8, 7u8, 0u8, 0u8, 54u8, 7u8, 0u8, 0u8, 59u8, 7u8, 0u8,
+            0u8, 64u8, 7u8, 0u8, 0u8, 69u8, 7u8, 0u8, 0u8, 76u8, 7u8, 0u8, 0u8, 84u8, 7u8, 0u8,
+            0u8, 90u8, 7u8, 0u8, 0u8, 96u8, 7u8, 0u8, 0u8, 104u8, 7u8, 0u8, 0u8, 111u8, 7u8, 0u8,
+            0u8, 118u8, 7u8, 0u8, 0u8, 123u8, 7u8, 0u8, 0u8, 130u8, 7u8, 0u8, 0u8, 136u8, 7u8, 0u8,
+            0u8, 142u8, 7u8, 0u8, 0u8, 148u8, 7u8, 0u8, 0u8, 154u8, 7u8, 0u8, 0u8, 158u8, 7u8, 0u8,
+            0u8, 163u8, 7u8, 0u8, 0u8, 168u8, 7u8, 0u8, 0u8, 174u8, 7u8, 0u8, 0u8, 179u8, 7u8, 0u8,
+            0u8, 185u8, 7u8, 0u8, 0u8, 191u8, 7u8, 0u8, 0u8, 200u8, 7u8, 0u8, 0u8, 204u8, 7u8, 0u8,
+            0u8, 209u8, 7u8, 0u8, 0u8, 214u8, 7u8, 0u8, 0u8, 219u8, 7u8, 0u8, 0u8, 224u8, 7u8, 0u8,
+            0u8, 231u8, 7u8, 0u8, 0u8, 241u8, 7u8, 0u8, 0u8, 248u8, 7u8, 0u8, 0u8, 253u8, 7u8, 0u8,
+            0u8, 5u8, 8u8, 0u8, 0u8, 10u8, 8u8, 0u8, 0u8, 15u8, 8u8, 0u8, 0u8, 23u8, 8u8, 0u8, 0u8,
+            28u8, 8u8, 0u8, 0u8, 34u8, 8u8, 0u8, 0u8, 40u8, 8u8, 0u8, 0u8, 46u8, 8u8, 0u8, 0u8,
+            52u8, 8u8, 0u8, 0u8, 58u8, 8u8, 0u8, 0u8, 64u8, 8u8, 0u8, 0u8, 70u8, 8u8, 0u8, 0u8,
+            76u8, 8u8, 0u8, 0u8, 84u8, 8u8, 0u8, 0u8, 92u8, 8u8, 0u8, 0u8, 100u8, 8u8, 0u8, 0u8,
+            108u8, 8u8, 0u8, 0u8, 116u8, 8u8, 0u8, 0u8, 124u8, 8u8, 0u8, 0u8, 132u8, 8u8, 0u8, 0u8,
+            140u8, 8u8, 0u8, 0u8, 145u8, 8u8, 0u8, 0u8, 153u8, 8u8, 0u8, 0u8, 161u8, 8u8, 0u8, 0u8,
+            170u8, 8u8, 0u8, 0u8, 179u8, 8u8, 0u8, 0u8, 185u8, 8u8, 0u8, 0u8, 191u8, 8u8, 0u8, 0u8,
+            197u8, 8u8, 0u8, 0u8, 203u8, 8u8, 0u8, 0u8, 209u8, 8u8, 0u8, 0u8, 215u8, 8u8, 0u8, 0u8,
+            221u8, 8u8, 0u8, 0u8, 227u8, 8u8, 0u8, 0u8, 233u8, 8u8, 0u8, 0u8, 239u8, 8u8, 0u8, 0u8,
+            247u8, 8u8, 0u8, 0u8, 255u8, 8u8, 0u8, 0u8, 5u8, 9u8, 0u8, 0u8, 11u8, 9u8, 0u8, 0u8,
+            17u8, 9u8, 0u8, 0u8, 22u8, 9u8, 0u8, 0u8, 30u8, 9u8, 0u8, 0u8, 35u8, 9u8, 0u8, 0u8,
+            41u8, 9u8, 0u8, 0u8, 47u8, 9u8, 0u8, 0u8, 52u8, 9u8, 0u8, 0u8, 57u8, 9u8, 0u8, 0u8,
+            63u8, 9u8, 0u8, 0u8, 69u8, 9u8, 0u8, 0u8, 78u8, 9u8, 0u8, 0u8, 87u8, 9u8, 0u8, 0u8,
+            93u8, 9u8, 0u8, 0u8, 99u8, 9u8, 0u8, 0u8, 107u8, 9u8, 0u8, 0u8, 115u8, 9u8, 0u8, 0u8,
+            122u8, 9u8, 0u8, 0u8, 129u8, 9u8, 0u8, 0u8, 136u8, 9u8, 0u8, 0u8, 143u8, 9u8, 0u8, 0u8,
+            150u8, 9u8, 0u8, 0u8, 157u8, 9u8, 0u8, 0u8, 164u8, 9u8, 0u8, 0u8, 171u8, 9u8, 0u8, 0u8,
+            178u8, 9u8, 0u8, 0u8, 185u8, 9u8, 0u8, 0u8, 192u8, 9u8, 0u8, 0u8, 199u8, 9u8, 0u8, 0u8,
+            206u8, 9u8, 0u8, 0u8, 213u8, 9u8, 0u8, 0u8, 220u8, 9u8, 0u8, 0u8, 227u8, 9u8, 0u8, 0u8,
+            234u8, 9u8, 0u8, 0u8, 241u8, 9u8, 0u8, 0u8, 248u8, 9u8, 0u8, 0u8, 255u8, 9u8, 0u8, 0u8,
+            6u8, 10u8, 0u8, 0u8, 13u8, 10u8, 0u8, 0u8, 20u8, 10u8, 0u8, 0u8, 27u8, 10u8, 0u8, 0u8,
+            34u8, 10u8, 0u8, 0u8, 41u8, 10u8, 0u8, 0u8, 48u8, 10u8, 0u8, 0u8, 55u8, 10u8, 0u8, 0u8,
+            60u8, 10u8, 0u8, 0u8, 65u8, 10u8, 0u8, 0u8, 71u8, 10u8, 0u8, 0u8, 77u8, 10u8, 0u8, 0u8,
+            87u8, 10u8, 0u8, 0u8, 93u8, 10u8, 0u8, 0u8, 98u8, 10u8, 0u8, 0u8, 103u8, 10u8, 0u8,
+            0u8, 109u8, 10u8, 0u8, 0u8, 115u8, 10u8, 0u8, 0u8, 122u8, 10u8, 0u8, 0u8, 129u8, 10u8,
+            0u8, 0u8, 135u8, 10u8, 0u8, 0u8, 141u8, 10u8, 0u8, 0u8, 149u8, 10u8, 0u8, 0u8, 157u8,
+            10u8, 0u8, 0u8, 165u8, 10u8, 0u8, 0u8, 173u8, 10u8, 0u8, 0u8, 180u8, 10u8, 0u8, 0u8,
+            187u8, 10u8, 0u8, 0u8, 196u8, 10u8, 0u8, 0u8, 205u8, 10u8, 0u8, 0u8, 211u8, 10u8, 0u8,
+            0u8, 217u8, 10u8, 0u8, 0u8, 223u8, 10u8, 0u8, 0u8, 229u8, 10u8, 0u8, 0u8, 235u8, 10u8,
+            0u8, 0u8, 241u8, 10u8, 0u8, 0u8, 248u8, 10u8, 0u8, 0u8, 255u8, 10u8, 0u8, 0u8, 5u8,
+            11u8, 0u8, 0u8, 11u8, 11u8, 0u8, 0u8, 17u8, 11u8, 0u8, 0u8, 23u8, 11u8, 0u8, 0u8, 31u8,
+            11u8, 0u8, 0u8, 39u8, 11u8, 0u8, 0u8, 47u8, 11u8, 0u8, 0u8, 54u8, 11u8, 0u8, 0u8, 61u8,
+            11u8, 0u8, 0u8, 67u8, 11u8, 0u8, 0u8, 72u8, 11u8, 0u8, 0u8, 78u8, 11u8, 0u8, 0u8, 84u8,
+            11u8, 0u8, 0u8, 90u8, 11u8, 0u8, 0u8, 96u8, 11u8, 0u8, 0u8, 102u8, 11u8, 0u8, 0u8,
+            111u8, 11u8, 0u8, 0u8, 119u8, 11u8, 0u8, 0u8, 125u8, 11u8, 0u8, 0u8, 131u8, 11u8, 0u8,
+            0u8, 137u8, 11u8, 0u8, 0u8, 143u8, 11u8, 0u8, 0u8, 147u8, 11u8, 0u8, 0u8, 151u8, 11u8,
+            0u8, 0u8, 155u8, 11u8, 0u8, 0u8, 159u8, 11u8, 0u8, 0u8, 163u8, 11u8, 0u8, 0u8, 167u8,
+            11u8, 0u8, 0u8, 171u8, 11u8, 0u8, 0u8, 175u8, 11u8, 0u8, 0u8, 179u8, 11u8, 0u8, 0u8,
+            183u8, 11u8, 0u8, 0u8, 187u8, 11u8, 0u8, 0u8, 191u8, 11u8, 0u8, 0u8, 195u8, 11u8, 0u8,
+            0u8, 199u8, 11u8, 0u8, 0u8, 203u8, 11u8, 0u8, 0u8, 207u8, 11u8, 0u8, 0u8, 211u8, 11u8,
+            0u8, 0u8, 215u8, 11u8, 0u8, 0u8, 219u8, 11u8, 0u8, 0u8, 223u8, 11u8, 0u8, 0u8, 227u8,
+            11u8, 0u8, 0u8, 231u8, 11u8, 0u8, 0u8, 235u8, 11u8, 0u8, 0u8, 239u8, 11u8, 0u8, 0u8,
+            243u8, 11u8, 0u8, 0u8, 247u8, 11u8, 0u8, 0u8, 251u8, 11u8, 0u8, 0u8, 255u8, 11u8, 0u8,
+            0u8, 3u8, 12u8, 0u8, 0u8, 7u8, 12u8, 0u8, 0u8, 11u8, 12u8, 0u8, 0u8, 15u8, 12u8, 0u8,
+            0u8, 19u8, 12u8, 0u8, 0u8, 23u8, 12u8, 0u8, 0u8, 27u8, 12u8, 0u8, 0u8, 31u8, 12u8, 0u8,
+            0u8, 35u8, 12u8, 0u8, 0u8, 39u8, 12u8, 0u8, 0u8, 43u8, 12u8, 0u8, 0u8, 47u8, 12u8, 0u8,
+            0u8, 51u8, 12u8, 0u8, 0u8, 55u8, 12u8, 0u8, 0u8, 59u8, 12u8, 0u8, 0u8, 63u8, 12u8, 0u8,
+            0u8, 67u8, 12u8, 0u8, 0u8, 71u8, 12u8, 0u8, 0u8, 75u8, 12u8, 0u8, 0u8, 79u8, 12u8, 0u8,
+            0u8, 83u8, 12u8, 0u8, 0u8, 87u8, 12u8, 0u8, 0u8, 91u8, 12u8, 0u8, 0u8, 95u8, 12u8, 0u8,
+            0u8, 99u8, 12u8, 0u8, 0u8, 103u8, 12u8, 0u8, 0u8, 107u8, 12u8, 0u8, 0u8, 111u8, 12u8,
+            0u8, 0u8, 115u8, 12u8, 0u8, 0u8, 119u8, 12u8, 0u8, 0u8, 123u8, 12u8, 0u8, 0u8, 127u8,
+            12u8, 0u8, 0u8, 131u8, 12u8, 0u8, 0u8, 135u8, 12u8, 0u8, 0u8, 139u8, 12u8, 0u8, 0u8,
+            143u8, 12u8, 0u8, 0u8, 147u8, 12u8, 0u8, 0u8, 151u8, 12u8, 0u8, 0u8, 155u8, 12u8, 0u8,
+            0u8, 159u8, 12u8, 0u8, 0u8, 163u8, 12u8, 0u8, 0u8, 167u8, 12u8, 0u8, 0u8, 171u8, 12u8,
+            0u8, 0u8, 175u8, 12u8, 0u8, 0u8, 179u8, 12u8, 0u8, 0u8, 183u8, 12u8, 0u8, 0u8, 187u8,
+            12u8, 0u8, 0u8, 191u8, 12u8, 0u8, 0u8, 195u8, 12u8, 0u8, 0u8, 199u8, 12u8, 0u8, 0u8,
+            203u8, 12u8, 0u8, 0u8, 207u8, 12u8, 0u8, 0u8, 211u8, 12u8, 0u8, 0u8, 215u8, 12u8, 0u8,
+            0u8, 219u8, 12u8, 0u8, 0u8, 223u8, 12u8, 0u8, 0u8, 227u8, 12u8, 0u8, 0u8, 231u8, 12u8,
+            0u8, 0u8, 235u8, 12u8, 0u8, 0u8, 239u8, 12u8, 0u8, 0u8, 243u8, 12u8, 0u8, 0u8, 247u8,
+            12u8, 0u8, 0u8, 251u8, 12u8, 0u8, 0u8, 255u8, 12u8, 0u8, 0u8, 3u8, 13u8, 0u8, 0u8, 7u8,
+            13u8, 0u8, 0u8, 11u8, 13u8, 0u8, 0u8, 15u8, 13u8, 0u8, 0u8, 19u8, 13u8, 0u8, 0u8, 22u8,
+            13u8, 0u8, 0u8, 25u8, 13u8, 0u8, 0u8, 29u8, 13u8, 0u8, 0u8, 33u8, 13u8, 0u8, 0u8, 37u8,
+            13u8, 0u8, 0u8, 40u8, 13u8, 0u8, 0u8, 44u8, 13u8, 0u8, 0u8, 48u8, 13u8, 0u8, 0u8, 51u8,
+            13u8, 0u8, 0u8, 55u8, 13u8, 0u8, 0u8, 59u8, 13u8, 0u8, 0u8, 63u8, 13u8, 0u8, 0u8, 67u8,
+            13u8, 0u8, 0u8, 70u8, 13u8, 0u8, 0u8, 74u8, 13u8, 0u8, 0u8, 78u8, 13u8, 0u8, 0u8, 82u8,
+            13u8, 0u8, 0u8, 86u8, 13u8, 0u8, 0u8, 90u8, 13u8, 0u8, 0u8, 94u8, 13u8, 0u8, 0u8, 97u8,
+            13u8, 0u8, 0u8, 101u8, 13u8, 0u8, 0u8, 105u8, 13u8, 0u8, 0u8, 109u8, 13u8, 0u8, 0u8,
+            113u8, 13u8, 0u8, 0u8, 117u8, 13u8, 0u8, 0u8, 121u8, 13u8, 0u8, 0u8, 125u8, 13u8, 0u8,
+            0u8, 129u8, 13u8, 0u8, 0u8, 133u8, 13u8, 0u8, 0u8, 137u8, 13u8, 0u8, 0u8, 141u8, 13u8,
+            0u8, 0u8, 145u8, 13u8, 0u8, 0u8, 149u8, 13u8, 0u8, 0u8, 153u8, 13u8, 0u8, 0u8, 157u8,
+            13u8, 0u8, 0u8, 161u8, 13u8, 0u8, 0u8, 165u8, 13u8, 0u8, 0u8, 169u8, 13u8, 0u8, 0u8,
+            173u8, 13u8, 0u8, 0u8, 177u8, 13u8, 0u8, 0u8, 181u8, 13u8, 0u8, 0u8, 185u8, 13u8, 0u8,
+            0u8, 189u8, 13u8, 0u8, 0u8, 193u8, 13u8, 0u8, 0u8, 197u8, 13u8, 0u8, 0u8, 201u8, 13u8,
+            0u8, 0u8, 205u8, 13u8, 0u8, 0u8, 209u8, 13u8, 0u8, 0u8, 213u8, 13u8, 0u8, 0u8, 217u8,
+            13u8, 0u8, 0u8, 221u8, 13u8, 0u8, 0u8, 225u8, 13u8, 0u8, 0u8, 229u8, 13u8, 0u8, 0u8,
+            233u8, 13u8, 0u8, 0u8, 237u8, 13u8, 0u8, 0u8, 241u8, 13u8, 0u8, 0u8, 245u8, 13u8, 0u8,
+            0u8, 249u8, 13u8, 0u8, 0u8, 253u8, 13u8, 0u8, 0u8, 1u8, 14u8, 0u8, 0u8, 5u8, 14u8, 0u8,
+            0u8, 9u8, 14u8, 0u8, 0u8, 13u8, 14u8, 0u8, 0u8, 17u8, 14u8, 0u8, 0u8, 21u8, 14u8, 0u8,
+            0u8, 25u8, 14u8, 0u8, 0u8, 29u8, 14u8, 0u8, 0u8, 33u8, 14u8, 0u8, 0u8, 37u8, 14u8, 0u8,
+            0u8, 41u8, 14u8, 0u8, 0u8, 45u8, 14u8, 0u8, 0u8, 49u8, 14u8, 0u8, 0u8, 53u8, 14u8, 0u8,
+            0u8, 57u8, 14u8, 0u8, 0u8, 61u8, 14u8, 0u8, 0u8, 65u8, 14u8, 0u8, 0u8, 69u8, 14u8, 0u8,
+            0u8, 73u8, 14u8, 0u8, 0u8, 77u8, 14u8, 0u8, 0u8, 81u8, 14u8, 0u8, 0u8, 85u8, 14u8, 0u8,
+            0u8, 89u8, 14u8, 0u8, 0u8, 93u8, 14u8, 0u8, 0u8, 97u8, 14u8, 0u8, 0u8, 101u8, 14u8,
+            0u8, 0u8, 105u8, 14u8, 0u8, 0u8, 109u8, 14u8, 0u8, 0u8, 113u8, 14u8, 0u8, 0u8, 117u8,
+            14u8, 0u8, 0u8, 121u8, 14u8, 0u8, 0u8, 125u8, 14u8, 0u8, 0u8, 129u8, 14u8, 0u8, 0u8,
+            133u8, 14u8, 0u8, 0u8, 137u8, 14u8, 0u8, 0u8, 141u8, 14u8, 0u8, 0u8, 145u8, 14u8, 0u8,
+            0u8, 149u8, 14u8, 0u8, 0u8, 153u8, 14u8, 0u8, 0u8, 157u8, 14u8, 0u8, 0u8, 161u8, 14u8,
+            0u8, 0u8, 165u8, 14u8, 0u8, 0u8, 169u8, 14u8, 0u8, 0u8, 173u8, 14u8, 0u8, 0u8, 177u8,
+            14u8, 0u8, 0u8, 181u8, 14u8, 0u8, 0u8, 185u8, 14u8, 0u8, 0u8, 189u8, 14u8, 0u8, 0u8,
+            193u8, 14u8, 0u8, 0u8, 197u8, 14u8, 0u8, 0u8, 201u8, 14u8, 0u8, 0u8, 205u8, 14u8, 0u8,
+            0u8, 209u8, 14u8, 0u8, 0u8, 213u8, 14u8, 0u8, 0u8, 217u8, 14u8, 0u8, 0u8, 221u8, 14u8,
+            0u8, 0u8, 225u8, 14u8, 0u8, 0u8, 229u8, 14u8, 0u8, 0u8, 233u8, 14u8, 0u8, 0u8, 237u8,
+            14u8, 0u8, 0u8, 241u8, 14u8, 0u8, 0u8, 245u8, 14u8, 0u8, 0u8, 249u8, 14u8, 0u8, 0u8,
+            253u8, 14u8, 0u8, 0u8, 1u8, 15u8, 0u8, 0u8, 5u8, 15u8, 0u8, 0u8, 9u8, 15u8, 0u8, 0u8,
+            13u8, 15u8, 0u8, 0u8, 17u8, 15u8, 0u8, 0u8, 21u8, 15u8, 0u8, 0u8, 25u8, 15u8, 0u8, 0u8,
+            29u8, 15u8, 0u8, 0u8, 33u8, 15u8, 0u8, 0u8, 37u8, 15u8, 0u8, 0u8, 41u8, 15u8, 0u8, 0u8,
+            45u8, 15u8, 0u8, 0u8, 49u8, 15u8, 0u8, 0u8, 53u8, 15u8, 0u8, 0u8, 57u8, 15u8, 0u8, 0u8,
+            61u8, 15u8, 0u8, 0u8, 65u8, 15u8, 0u8, 0u8, 69u8, 15u8, 0u8, 0u8, 73u8, 15u8, 0u8, 0u8,
+            77u8, 15u8, 0u8, 0u8, 81u8, 15u8, 0u8, 0u8, 85u8, 15u8, 0u8, 0u8, 89u8, 15u8, 0u8, 0u8,
+            93u8, 15u8, 0u8, 0u8, 97u8, 15u8, 0u8, 0u8, 101u8, 15u8, 0u8, 0u8, 105u8, 15u8, 0u8,
+            0u8, 109u8, 15u8, 0u8, 0u8, 113u8, 15u8, 0u8, 0u8, 117u8, 15u8, 0u8, 0u8, 121u8, 15u8,
+            0u8, 0u8, 125u8, 15u8, 0u8, 0u8, 129u8, 15u8, 0u8, 0u8, 133u8, 15u8, 0u8, 0u8, 137u8,
+            15u8, 0u8, 0u8, 141u8, 15u8, 0u8, 0u8, 145u8, 15u8, 0u8, 0u8, 148u8, 15u8, 0u8, 0u8,
+            151u8, 15u8, 0u8, 0u8, 154u8, 15u8, 0u8, 0u8, 157u8, 15u8, 0u8, 0u8, 160u8, 15u8, 0u8,
+            0u8, 163u8, 15u8, 0u8, 0u8, 165u8, 15u8, 0u8, 0u8, 168u8, 15u8, 0u8, 0u8, 171u8, 15u8,
+            0u8, 0u8, 175u8, 15u8, 0u8, 0u8, 180u8, 15u8, 0u8, 0u8, 185u8, 15u8, 0u8, 0u8, 188u8,
+            15u8, 0u8, 0u8, 191u8, 15u8, 0u8, 0u8, 193u8, 15u8, 0u8, 0u8, 197u8, 15u8, 0u8, 0u8,
+            200u8, 15u8, 0u8, 0u8, 203u8, 15u8, 0u8, 0u8, 206u8, 15u8, 0u8, 0u8, 208u8, 15u8, 0u8,
+            0u8, 211u8, 15u8, 0u8, 0u8, 215u8, 15u8, 0u8, 0u8, 219u8, 15u8, 0u8, 0u8, 223u8, 15u8,
+            0u8, 0u8, 228u8, 15u8, 0u8, 0u8, 233u8, 15u8, 0u8, 0u8, 237u8, 15u8, 0u8, 0u8, 241u8,
+            15u8, 0u8, 0u8, 246u8, 15u8, 0u8, 0u8, 251u8, 15u8, 0u8, 0u8, 253u8, 15u8, 0u8, 0u8,
+            0u8, 16u8, 0u8, 0u8, 1u8, 16u8, 0u8, 0u8, 4u8, 16u8, 0u8, 0u8, 8u8, 16u8, 0u8, 0u8,
+            12u8, 16u8, 0u8, 0u8, 15u8, 16u8, 0u8, 0u8, 19u8, 16u8, 0u8, 0u8, 21u8, 16u8, 0u8, 0u8,
+            24u8, 16u8, 0u8, 0u8, 28u8, 16u8, 0u8, 0u8, 32u8, 16u8, 0u8, 0u8, 38u8, 16u8, 0u8, 0u8,
+            42u8, 16u8, 0u8, 0u8, 46u8, 16u8, 0u8, 0u8, 51u8, 16u8, 0u8, 0u8, 56u8, 16u8, 0u8, 0u8,
+            63u8, 16u8, 0u8, 0u8, 64u8, 16u8, 0u8, 0u8, 66u8, 16u8, 0u8, 0u8, 69u8, 16u8, 0u8, 0u8,
+            71u8, 16u8, 0u8, 0u8, 74u8, 16u8, 0u8, 0u8, 78u8, 16u8, 0u8, 0u8, 80u8, 16u8, 0u8, 0u8,
+            86u8, 16u8, 0u8, 0u8, 92u8, 16u8, 0u8, 0u8, 95u8, 16u8, 0u8, 0u8, 98u8, 16u8, 0u8, 0u8,
+            100u8, 16u8, 0u8, 0u8, 102u8, 16u8, 0u8, 0u8, 104u8, 16u8, 0u8, 0u8, 107u8, 16u8, 0u8,
+            0u8, 113u8, 16u8, 0u8, 0u8, 115u8, 16u8, 0u8, 0u8, 118u8, 16u8, 0u8, 0u8, 122u8, 16u8,
+            0u8, 0u8, 128u8, 16u8, 0u8, 0u8, 134u8, 16u8, 0u8, 0u8, 137u8, 16u8, 0u8, 0u8, 140u8,
+            16u8, 0u8, 0u8, 143u8, 16u8, 0u8, 0u8, 148u8, 16u8, 0u8, 0u8, 152u8, 16u8, 0u8, 0u8,
+            156u8, 16u8, 0u8, 0u8, 160u8, 16u8, 0u8, 0u8, 164u8, 16u8, 0u8, 0u8, 167u8, 16u8, 0u8,
+            0u8, 171u8, 16u8, 0u8, 0u8, 178u8, 16u8, 0u8, 0u8, 183u8, 16u8, 0u8, 0u8, 186u8, 16u8,
+            0u8, 0u8, 189u8, 16u8, 0u8, 0u8, 193u8, 16u8, 0u8, 0u8, 196u8, 16u8, 0u8, 0u8, 199u8,
+            16u8, 0u8, 0u8, 202u8, 16u8, 0u8, 0u8, 206u8, 16u8, 0u8, 0u8, 209u8, 16u8, 0u8, 0u8,
+            212u8, 16u8, 0u8, 0u8, 215u8, 16u8, 0u8, 0u8, 217u8, 16u8, 0u8, 0u8, 220u8, 16u8, 0u8,
+            0u8, 224u8, 16u8, 0u8, 0u8, 228u8, 16u8, 0u8, 0u8, 232u8, 16u8, 0u8, 0u8, 236u8, 16u8,
+            0u8, 0u8, 239u8, 16u8, 0u8, 0u8, 243u8, 16u8, 0u8, 0u8, 247u8, 16u8, 0u8, 0u8, 251u8,
+            16u8, 0u8, 0u8, 1u8, 17u8, 0u8, 0u8, 7u8, 17u8, 0u8, 0u8, 13u8, 17u8, 0u8, 0u8, 19u8,
+            17u8, 0u8, 0u8, 25u8, 17u8, 0u8, 0u8, 31u8, 17u8, 0u8, 0u8, 36u8, 17u8, 0u8, 0u8, 41u8,
+            17u8, 0u8, 0u8, 47u8, 17u8, 0u8, 0u8, 49u8, 17u8, 0u8, 0u8, 51u8, 17u8, 0u8, 0u8, 53u8,
+            17u8, 0u8, 0u8, 55u8, 17u8, 0u8, 0u8, 58u8, 17u8, 0u8, 0u8, 61u8, 17u8, 0u8, 0u8, 63u8,
+            17u8, 0u8, 0u8, 65u8, 17u8, 0u8, 0u8, 67u8, 17u8, 0u8, 0u8, 71u8, 17u8, 0u8, 0u8, 75u8,
+            17u8, 0u8, 0u8, 77u8, 17u8, 0u8, 0u8, 79u8, 17u8, 0u8, 0u8, 82u8, 17u8, 0u8, 0u8, 85u8,
+            17u8, 0u8, 0u8, 89u8, 17u8, 0u8, 0u8, 96u8, 17u8, 0u8, 0u8, 103u8, 17u8, 0u8, 0u8,
+            105u8, 17u8, 0u8, 0u8, 112u8, 17u8, 0u8, 0u8, 116u8, 17u8, 0u8, 0u8, 121u8, 17u8, 0u8,
+            0u8, 126u8, 17u8, 0u8, 0u8, 128u8, 17u8, 0u8, 0u8, 136u8, 17u8, 0u8, 0u8, 140u8, 17u8,
+            0u8, 0u8, 144u8, 17u8, 0u8, 0u8, 148u8, 17u8, 0u8, 0u8, 153u8, 17u8, 0u8, 0u8, 157u8,
+            17u8, 0u8, 0u8, 162u8, 17u8, 0u8, 0u8, 167u8, 17u8, 0u8, 0u8, 170u8, 17u8, 0u8, 0u8,
+            173u8, 17u8, 0u8, 0u8, 176u8, 17u8, 0u8, 0u8, 178u8, 17u8, 0u8, 0u8, 180u8, 17u8, 0u8,
+            0u8, 183u8, 17u8, 0u8, 0u8, 187u8, 17u8, 0u8, 0u8, 191u8, 17u8, 0u8, 0u8, 195u8, 17u8,
+            0u8, 0u8, 200u8, 17u8, 0u8, 0u8, 203u8, 17u8, 0u8, 0u8, 207u8, 17u8, 0u8, 0u8, 212u8,
+            17u8, 0u8, 0u8, 215u8, 17u8, 0u8, 0u8, 219u8, 17u8, 0u8, 0u8, 223u8, 17u8, 0u8, 0u8,
+            227u8, 17u8, 0u8, 0u8, 231u8, 17u8, 0u8, 0u8, 235u8, 17u8, 0u8, 0u8, 239u8, 17u8, 0u8,
+            0u8, 243u8, 17u8, 0u8, 0u8, 247u8, 17u8, 0u8, 0u8, 251u8, 17u8, 0u8, 0u8, 0u8, 18u8,
+            0u8, 0u8, 5u8, 18u8, 0u8, 0u8, 10u8, 18u8, 0u8, 0u8, 15u8, 18u8, 0u8, 0u8, 21u8, 18u8,
+            0u8, 0u8, 27u8, 18u8, 0u8, 0u8, 33u8, 18u8, 0u8, 0u8, 35u8, 18u8, 0u8, 0u8, 37u8, 18u8,
+            0u8, 0u8, 39u8, 18u8, 0u8, 0u8, 41u8, 18u8, 0u8, 0u8, 43u8, 18u8, 0u8, 0u8, 45u8, 18u8,
+            0u8, 0u8, 47u8, 18u8, 0u8, 0u8, 49u8, 18u8, 0u8, 0u8, 51u8, 18u8, 0u8, 0u8, 53u8, 18u8,
+            0u8, 0u8, 55u8, 18u8, 0u8, 0u8, 57u8, 18u8, 0u8, 0u8, 59u8, 18u8, 0u8, 0u8, 61u8, 18u8,
+            0u8, 0u8, 63u8, 18u8, 0u8, 0u8, 65u8, 18u8, 0u8, 0u8, 67u8, 18u8, 0u8, 0u8, 69u8, 18u8,
+            0u8, 0u8, 71u8, 18u8, 0u8, 0u8, 73u8, 18u8, 0u8, 0u8, 75u8, 18u8, 0u8, 0u8, 79u8, 18u8,
+            0u8, 0u8, 83u8, 18u8, 0u8, 0u8, 87u8, 18u8, 0u8, 0u8, 91u8, 18u8, 0u8, 0u8, 95u8, 18u8,
+            0u8, 0u8, 99u8, 18u8, 0u8, 0u8, 103u8, 18u8, 0u8, 0u8, 107u8, 18u8, 0u8, 0u8, 111u8,
+            18u8, 0u8, 0u8, 115u8, 18u8, 0u8, 0u8, 119u8, 18u8, 0u8, 0u8, 123u8, 18u8, 0u8, 0u8,
+            127u8, 18u8, 0u8, 0u8, 131u8, 18u8, 0u8, 0u8, 135u8, 18u8, 0u8, 0u8, 139u8, 18u8, 0u8,
+            0u8, 143u8, 18u8, 0u8, 0u8, 147u8, 18u8, 0u8, 0u8, 151u8, 18u8, 0u8, 0u8, 155u8, 18u8,
+            0u8, 0u8, 159u8, 18u8, 0u8, 0u8, 163u8, 18u8, 0u8, 0u8, 167u8, 18u8, 0u8, 0u8, 173u8,
+            18u8, 0u8, 0u8, 179u8, 18u8, 0u8, 0u8, 184u8, 18u8, 0u8, 0u8, 189u8, 18u8, 0u8, 0u8,
+            194u8, 18u8, 0u8, 0u8, 199u8, 18u8, 0u8, 0u8, 204u8, 18u8, 0u8, 0u8, 208u8, 18u8, 0u8,
+            0u8, 214u8, 18u8, 0u8, 0u8, 220u8, 18u8, 0u8, 0u8, 226u8, 18u8, 0u8, 0u8, 232u8, 18u8,
+            0u8, 0u8, 238u8, 18u8, 0u8, 0u8, 244u8, 18u8, 0u8, 0u8, 250u8, 18u8, 0u8, 0u8, 1u8,
+            19u8, 0u8, 0u8, 6u8, 19u8, 0u8, 0u8, 11u8, 19u8, 0u8, 0u8, 16u8, 19u8, 0u8, 0u8, 21u8,
+            19u8, 0u8, 0u8, 24u8, 19u8, 0u8, 0u8, 27u8, 19u8, 0u8, 0u8, 30u8, 19u8, 0u8, 0u8, 33u8,
+            19u8, 0u8, 0u8, 36u8, 19u8, 0u8, 0u8, 39u8, 19u8, 0u8, 0u8, 42u8, 19u8, 0u8, 0u8, 47u8,
+            19u8, 0u8, 0u8, 52u8, 19u8, 0u8, 0u8, 58u8, 19u8, 0u8, 0u8, 64u8, 19u8, 0u8, 0u8, 69u8,
+            19u8, 0u8, 0u8, 74u8, 19u8, 0u8, 0u8, 80u8, 19u8, 0u8, 0u8, 86u8, 19u8, 0u8, 0u8, 92u8,
+            19u8, 0u8, 0u8, 98u8, 19u8, 0u8, 0u8, 101u8, 19u8, 0u8, 0u8, 104u8, 19u8, 0u8, 0u8,
+            109u8, 19u8, 0u8, 0u8, 111u8, 19u8, 0u8, 0u8, 115u8, 19u8, 0u8, 0u8, 120u8, 19u8, 0u8,
+            0u8, 122u8, 19u8, 0u8, 0u8, 124u8, 19u8, 0u8, 0u8, 126u8, 19u8, 0u8, 0u8, 128u8, 19u8,
+            0u8, 0u8, 133u8, 19u8, 0u8, 0u8, 136u8, 19u8, 0u8, 0u8, 138u8, 19u8, 0u8, 0u8, 143u8,
+            19u8, 0u8, 0u8, 149u8, 19u8, 0u8, 0u8, 155u8, 19u8, 0u8, 0u8, 158u8, 19u8, 0u8, 0u8,
+            163u8, 19u8, 0u8, 0u8, 167u8, 19u8, 0u8, 0u8, 171u8, 19u8, 0u8, 0u8, 177u8, 19u8, 0u8,
+            0u8, 181u8, 19u8, 0u8, 0u8, 185u8, 19u8, 0u8, 0u8, 193u8, 19u8, 0u8, 0u8, 198u8, 19u8,
+            0u8, 0u8, 203u8, 19u8, 0u8, 0u8, 206u8, 19u8, 0u8, 0u8, 209u8, 19u8, 0u8, 0u8, 213u8,
+            19u8, 0u8, 0u8, 216u8, 19u8, 0u8, 0u8, 219u8, 19u8, 0u8, 0u8, 224u8, 19u8, 0u8, 0u8,
+            226u8, 19u8, 0u8, 0u8, 230u8, 19u8, 0u8, 0u8, 232u8, 19u8, 0u8, 0u8, 234u8, 19u8, 0u8,
+            0u8, 236u8, 19u8, 0u8, 0u8, 238u8, 19u8, 0u8, 0u8, 240u8, 19u8, 0u8, 0u8, 242u8, 19u8,
+            0u8, 0u8, 247u8, 19u8, 0u8, 0u8, 249u8, 19u8, 0u8, 0u8, 251u8, 19u8, 0u8, 0u8, 253u8,
+            19u8, 0u8, 0u8, 255u8, 19u8, 0u8, 0u8, 3u8, 20u8, 0u8, 0u8, 5u8, 20u8, 0u8, 0u8, 9u8,
+            20u8, 0u8, 0u8, 14u8, 20u8, 0u8, 0u8, 19u8, 20u8, 0u8, 0u8, 24u8, 20u8, 0u8, 0u8, 29u8,
+            20u8, 0u8, 0u8, 35u8, 20u8, 0u8, 0u8, 41u8, 20u8, 0u8, 0u8, 43u8, 20u8, 0u8, 0u8, 46u8,
+            20u8, 0u8, 0u8, 53u8, 20u8, 0u8, 0u8, 59u8, 20u8, 0u8, 0u8, 65u8, 20u8, 0u8, 0u8, 70u8,
+            20u8, 0u8, 0u8, 74u8, 20u8, 0u8, 0u8, 78u8, 20u8, 0u8, 0u8, 80u8, 20u8, 0u8, 0u8, 82u8,
+            20u8, 0u8, 0u8, 86u8, 20u8, 0u8, 0u8, 91u8, 20u8, 0u8, 0u8, 97u8, 20u8, 0u8, 0u8, 99u8,
+            20u8, 0u8, 0u8, 103u8, 20u8, 0u8, 0u8, 112u8, 20u8, 0u8, 0u8, 120u8, 20u8, 0u8, 0u8,
+            128u8, 20u8, 0u8, 0u8, 134u8, 20u8, 0u8, 0u8, 140u8, 20u8, 0u8, 0u8, 145u8, 20u8, 0u8,
+            0u8, 152u8, 20u8, 0u8, 0u8, 157u8, 20u8, 0u8, 0u8, 161u8, 20u8, 0u8, 0u8, 171u8, 20u8,
+            0u8, 0u8, 174u8, 20u8, 0u8, 0u8, 179u8, 20u8, 0u8, 0u8, 184u8, 20u8, 0u8, 0u8, 190u8,
+            20u8, 0u8, 0u8, 199u8, 20u8, 0u8, 0u8, 201u8, 20u8, 0u8, 0u8, 207u8, 20u8, 0u8, 0u8,
+            213u8, 20u8, 0u8, 0u8, 216u8, 20u8, 0u8, 0u8, 222u8, 20u8, 0u8, 0u8, 226u8, 20u8, 0u8,
+            0u8, 228u8, 20u8, 0u8, 0u8, 230u8, 20u8, 0u8, 0u8, 234u8, 20u8, 0u8, 0u8, 240u8, 20u8,
+            0u8, 0u8, 245u8, 20u8, 0u8, 0u8, 251u8, 20u8, 0u8, 0u8, 253u8, 20u8, 0u8, 0u8, 3u8,
+            21u8, 0u8, 0u8, 5u8, 21u8, 0u8, 0u8, 10u8, 21u8, 0u8, 0u8, 12u8, 21u8, 0u8, 0u8, 14u8,
+            21u8, 0u8, 0u8, 18u8, 21u8, 0u8, 0u8, 20u8, 21u8, 0u8, 0u8, 24u8, 21u8, 0u8, 0u8, 29u8,
+            21u8, 0u8, 0u8, 31u8, 21u8, 0u8, 0u8, 36u8, 21u8, 0u8, 0u8, 41u8, 21u8, 0u8, 0u8, 45u8,
+            21u8, 0u8, 0u8, 51u8, 21u8, 0u8, 0u8, 60u8, 21u8, 0u8, 0u8, 65u8, 21u8, 0u8, 0u8, 72u8,
+            21u8, 0u8, 0u8, 77u8, 21u8, 0u8, 0u8, 81u8, 21u8, 0u8, 0u8, 86u8, 21u8, 0u8, 0u8, 91u8,
+            21u8, 0u8, 0u8, 96u8, 21u8, 0u8, 0u8, 101u8, 21u8, 0u8, 0u8, 106u8, 21u8, 0u8, 0u8,
+            111u8, 21u8, 0u8, 0u8, 116u8, 21u8, 0u8, 0u8, 121u8, 21u8, 0u8, 0u8, 126u8, 21u8, 0u8,
+            0u8, 131u8, 21u8, 0u8, 0u8, 136u8, 21u8, 0u8, 0u8, 141u8, 21u8, 0u8, 0u8, 150u8, 21u8,
+            0u8, 0u8, 159u8, 21u8, 0u8, 0u8, 167u8, 21u8, 0u8, 0u8, 175u8, 21u8, 0u8, 0u8, 184u8,
+            21u8, 0u8, 0u8, 193u8, 21u8, 0u8, 0u8, 201u8, 21u8, 0u8, 0u8, 209u8, 21u8, 0u8, 0u8,
+            217u8, 21u8, 0u8, 0u8, 225u8, 21u8, 0u8, 0u8, 233u8, 21u8, 0u8, 0u8, 241u8, 21u8, 0u8,
+            0u8, 246u8, 21u8, 0u8, 0u8, 251u8, 21u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 5u8, 22u8, 0u8,
+            0u8, 10u8, 22u8, 0u8, 0u8, 15u8, 22u8, 0u8, 0u8, 22u8, 22u8, 0u8, 0u8, 29u8, 22u8, 0u8,
+            0u8, 34u8, 22u8, 0u8, 0u8, 39u8, 22u8, 0u8, 0u8, 44u8, 22u8, 0u8, 0u8, 49u8, 22u8, 0u8,
+            0u8, 55u8, 22u8, 0u8, 0u8, 61u8, 22u8, 0u8, 0u8, 67u8, 22u8, 0u8, 0u8, 73u8, 22u8, 0u8,
+            0u8, 79u8, 22u8, 0u8, 0u8, 85u8, 22u8, 0u8, 0u8, 91u8, 22u8, 0u8, 0u8, 97u8, 22u8, 0u8,
+            0u8, 103u8, 22u8, 0u8, 0u8, 109u8, 22u8, 0u8, 0u8, 115u8, 22u8, 0u8, 0u8, 121u8, 22u8,
+            0u8, 0u8, 127u8, 22u8, 0u8, 0u8, 133u8, 22u8, 0u8, 0u8, 139u8, 22u8, 0u8, 0u8, 145u8,
+            22u8, 0u8, 0u8, 151u8, 22u8, 0u8, 0u8, 157u8, 22u8, 0u8, 0u8, 163u8, 22u8, 0u8, 0u8,
+            169u8, 22u8, 0u8, 0u8, 175u8, 22u8, 0u8, 0u8, 181u8, 22u8, 0u8, 0u8, 187u8, 22u8, 0u8,
+            0u8, 193u8, 22u8, 0u8, 0u8, 199u8, 22u8, 0u8, 0u8, 205u8, 22u8, 0u8, 0u8, 211u8, 22u8,
+            0u8, 0u8, 217u8, 22u8, 0u8, 0u8, 223u8, 22u8, 0u8, 0u8, 229u8, 22u8, 0u8, 0u8, 235u8,
+            22u8, 0u8, 0u8, 241u8, 22u8, 0u8, 0u8, 247u8, 22u8, 0u8, 0u8, 253u8, 22u8, 0u8, 0u8,
+            3u8, 23u8, 0u8, 0u8, 9u8, 23u8, 0u8, 0u8, 14u8, 23u8, 0u8, 0u8, 19u8, 23u8, 0u8, 0u8,
+            24u8, 23u8, 0u8, 0u8, 29u8, 23u8, 0u8, 0u8, 35u8, 23u8, 0u8, 0u8, 41u8, 23u8, 0u8, 0u8,
+            47u8, 23u8, 0u8, 0u8, 53u8, 23u8, 0u8, 0u8, 59u8, 23u8, 0u8, 0u8, 65u8, 23u8, 0u8, 0u8,
+            71u8, 23u8, 0u8, 0u8, 77u8, 23u8, 0u8, 0u8, 82u8, 23u8, 0u8, 0u8, 87u8, 23u8, 0u8, 0u8,
+            92u8, 23u8, 0u8, 0u8, 97u8, 23u8, 0u8, 0u8, 103u8, 23u8, 0u8, 0u8, 109u8, 23u8, 0u8,
+            0u8, 115u8, 23u8, 0u8, 0u8, 121u8, 23u8, 0u8, 0u8, 127u8, 23u8, 0u8, 0u8, 133u8, 23u8,
+            0u8, 0u8, 139u8, 23u8, 0u8, 0u8, 145u8, 23u8, 0u8, 0u8, 151u8, 23u8, 0u8, 0u8, 157u8,
+            23u8, 0u8, 0u8, 163u8, 23u8, 0u8, 0u8, 169u8, 23u8, 0u8, 0u8, 175u8, 23u8, 0u8, 0u8,
+            181u8, 23u8, 0u8, 0u8, 187u8, 23u8, 0u8, 0u8, 193u8, 23u8, 0u8, 0u8, 198u8, 23u8, 0u8,
+            0u8, 203u8, 23u8, 0u8, 0u8, 208u8, 23u8, 0u8, 0u8, 213u8, 23u8, 0u8, 0u8, 219u8, 23u8,
+            0u8, 0u8, 225u8, 23u8, 0u8, 0u8, 231u8, 23u8, 0u8, 0u8, 237u8, 23u8, 0u8, 0u8, 243u8,
+            23u8, 0u8, 0u8, 249u8, 23u8, 0u8, 0u8, 255u8, 23u8, 0u8, 0u8, 5u8, 24u8, 0u8, 0u8,
+            11u8, 24u8, 0u8, 0u8, 17u8, 24u8, 0u8, 0u8, 23u8, 24u8, 0u8, 0u8, 29u8, 24u8, 0u8, 0u8,
+            35u8, 24u8, 0u8, 0u8, 41u8, 24u8, 0u8, 0u8, 48u8, 24u8, 0u8, 0u8, 55u8, 24u8, 0u8, 0u8,
+            60u8, 24u8, 0u8, 0u8, 65u8, 24u8, 0u8, 0u8, 70u8, 24u8, 0u8, 0u8, 75u8, 24u8, 0u8, 0u8,
+            86u8, 24u8, 0u8, 0u8, 97u8, 24u8, 0u8, 0u8, 107u8, 24u8, 0u8, 0u8, 117u8, 24u8, 0u8,
+            0u8, 127u8, 24u8, 0u8, 0u8, 137u8, 24u8, 0u8, 0u8, 144u8, 24u8, 0u8, 0u8, 151u8, 24u8,
+            0u8, 0u8, 52u8, 112u8, 31u8, 59u8, 31u8, 130u8, 179u8, 12u8, 129u8, 241u8, 12u8, 130u8,
+            208u8, 12u8, 129u8, 147u8, 128u8, 214u8, 42u8, 129u8, 60u8, 60u8, 129u8, 60u8, 128u8,
+            216u8, 129u8, 70u8, 12u8, 110u8, 130u8, 72u8, 130u8, 39u8, 128u8, 165u8, 129u8, 99u8,
+            62u8, 108u8, 62u8, 74u8, 62u8, 53u8, 62u8, 75u8, 62u8, 89u8, 62u8, 128u8, 132u8, 62u8,
+            128u8, 202u8, 62u8, 131u8, 32u8, 62u8, 129u8, 247u8, 62u8, 130u8, 170u8, 129u8, 210u8,
+            129u8, 88u8, 130u8, 123u8, 12u8, 130u8, 5u8, 12u8, 130u8, 47u8, 12u8, 117u8, 31u8,
+            128u8, 228u8, 129u8, 161u8, 1u8, 4u8, 0u8, 21u8, 1u8, 4u8, 0u8, 88u8, 1u8, 4u8, 0u8,
+            46u8, 1u8, 4u8, 0u8, 58u8, 1u8, 4u8, 0u8, 24u8, 1u8, 4u8, 0u8, 128u8, 130u8, 1u8, 4u8,
+            0u8, 44u8, 1u8, 4u8, 0u8, 80u8, 1u8, 4u8, 0u8, 28u8, 1u8, 4u8, 0u8, 67u8, 1u8, 4u8,
+            0u8, 82u8, 1u8, 4u8, 0u8, 47u8, 1u8, 4u8, 0u8, 128u8, 193u8, 1u8, 4u8, 0u8, 43u8, 1u8,
+            4u8, 0u8, 13u8, 1u8, 4u8, 0u8, 128u8, 150u8, 1u8, 4u8, 0u8, 128u8, 155u8, 1u8, 4u8,
+            0u8, 55u8, 1u8, 4u8, 0u8, 51u8, 1u8, 4u8, 0u8, 49u8, 1u8, 4u8, 0u8, 15u8, 1u8, 4u8,
+            0u8, 128u8, 170u8, 1u8, 4u8, 0u8, 128u8, 171u8, 1u8, 4u8, 0u8, 128u8, 211u8, 1u8, 4u8,
+            0u8, 57u8, 1u8, 4u8, 0u8, 54u8, 42u8, 83u8, 94u8, 128u8, 201u8, 129u8, 99u8, 60u8,
+            83u8, 94u8, 32u8, 128u8, 177u8, 128u8, 192u8, 48u8, 30u8, 128u8, 177u8, 1u8, 5u8, 0u8,
+            21u8, 1u8, 5u8, 0u8, 88u8, 1u8, 5u8, 0u8, 46u8, 1u8, 5u8, 0u8, 58u8, 1u8, 5u8, 0u8,
+            24u8, 1u8, 5u8, 0u8, 128u8, 130u8, 1u8, 5u8, 0u8, 44u8, 1u8, 5u8, 0u8, 80u8, 1u8, 5u8,
+            0u8, 28u8, 1u8, 5u8, 0u8, 67u8, 1u8, 5u8, 0u8, 82u8, 1u8, 5u8, 0u8, 47u8, 1u8, 5u8,
+            0u8, 128u8, 193u8, 1u8, 5u8, 0u8, 43u8, 1u8, 5u8, 0u8, 13u8, 1u8, 5u8, 0u8, 128u8,
+            150u8, 1u8, 5u8, 0u8, 128u8, 155u8, 1u8, 5u8, 0u8, 55u8, 1u8, 5u8, 0u8, 51u8, 1u8, 5u8,
+            0u8, 49u8, 1u8, 5u8, 0u8, 15u8, 1u8, 5u8, 0u8, 128u8, 170u8, 1u8, 5u8, 0u8, 128u8,
+            171u8, 1u8, 5u8, 0u8, 128u8, 211u8, 1u8, 5u8, 0u8, 57u8, 1u8, 5u8, 0u8, 54u8, 42u8,
+            128u8, 231u8, 94u8, 107u8, 48u8, 60u8, 128u8, 231u8, 94u8, 56u8, 128u8, 196u8, 52u8,
+            45u8, 112u8, 31u8, 129u8, 186u8, 12u8, 130u8, 231u8, 12u8, 129u8, 221u8, 12u8, 131u8,
+            159u8, 12u8, 129u8, 174u8, 109u8, 131u8, 28u8, 12u8, 33u8, 129u8, 213u8, 12u8, 130u8,
+            22u8, 129u8, 56u8, 129u8, 12u8, 129u8, 27u8, 26u8, 120u8, 59u8, 31u8, 130u8, 176u8,
+            12u8, 131u8, 68u8, 128u8, 139u8, 130u8, 247u8, 12u8, 27u8, 129u8, 230u8, 12u8, 130u8,
+            228u8, 12u8, 128u8, 205u8, 53u8, 128u8, 205u8, 75u8, 17u8, 128u8, 177u8, 130u8, 142u8,
+            12u8, 129u8, 69u8, 12u8, 116u8, 25u8, 39u8, 128u8, 205u8, 74u8, 130u8, 138u8, 129u8,
+            56u8, 129u8, 12u8, 129u8, 84u8, 26u8, 120u8, 59u8, 31u8, 128u8, 209u8, 128u8, 134u8,
+            74u8, 128u8, 157u8, 128u8, 209u8, 128u8, 134u8, 74u8, 130u8, 52u8, 128u8, 209u8, 128u8,
+            134u8, 75u8, 130u8, 241u8, 45u8, 117u8, 31u8, 1u8, 4u8, 0u8, 21u8, 2u8, 30u8, 1u8, 4u8,
+            0u8, 21u8, 2u8, 17u8, 1u8, 4u8, 0u8, 21u8, 2u8, 32u8, 1u8, 4u8, 0u8, 21u8, 2u8, 56u8,
+            1u8, 4u8, 0u8, 21u8, 2u8, 33u8, 1u8, 4u8, 0u8, 21u8, 2u8, 101u8, 16u8, 1u8, 4u8, 0u8,
+            87u8, 1u8, 4u8, 0u8, 46u8, 2u8, 39u8, 1u8, 4u8, 0u8, 24u8, 2u8, 30u8, 1u8, 4u8, 0u8,
+            24u8, 2u8, 17u8, 1u8, 4u8, 0u8, 24u8, 2u8, 32u8, 1u8, 4u8, 0u8, 24u8, 2u8, 33u8, 1u8,
+            4u8, 0u8, 28u8, 2u8, 30u8, 1u8, 4u8, 0u8, 28u8, 2u8, 17u8, 1u8, 4u8, 0u8, 28u8, 2u8,
+            32u8, 1u8, 4u8, 0u8, 28u8, 2u8, 33u8, 1u8, 4u8, 0u8, 128u8, 248u8, 1u8, 4u8, 0u8, 43u8,
+            2u8, 56u8, 1u8, 4u8, 0u8, 13u8, 2u8, 30u8, 1u8, 4u8, 0u8, 13u8, 2u8, 17u8, 1u8, 4u8,
+            0u8, 13u8, 2u8, 32u8, 1u8, 4u8, 0u8, 13u8, 2u8, 56u8, 1u8, 4u8, 0u8, 13u8, 2u8, 33u8,
+            130u8, 153u8, 12u8, 1u8, 4u8, 0u8, 13u8, 2u8, 19u8, 1u8, 4u8, 0u8, 15u8, 2u8, 30u8,
+            1u8, 4u8, 0u8, 15u8, 2u8, 17u8, 1u8, 4u8, 0u8, 15u8, 2u8, 32u8, 1u8, 4u8, 0u8, 15u8,
+            2u8, 33u8, 1u8, 4u8, 0u8, 57u8, 2u8, 17u8, 1u8, 4u8, 0u8, 129u8, 117u8, 1u8, 5u8, 0u8,
+            129u8, 92u8, 51u8, 1u8, 5u8, 0u8, 21u8, 2u8, 30u8, 1u8, 5u8, 0u8, 21u8, 2u8, 17u8, 1u8,
+            5u8, 0u8, 21u8, 2u8, 32u8, 1u8, 5u8, 0u8, 21u8, 2u8, 56u8, 1u8, 5u8, 0u8, 21u8, 2u8,
+            33u8, 1u8, 5u8, 0u8, 21u8, 2u8, 101u8, 16u8, 1u8, 5u8, 0u8, 87u8, 1u8, 5u8, 0u8, 46u8,
+            2u8, 39u8, 1u8, 5u8, 0u8, 24u8, 2u8, 30u8, 1u8, 5u8, 0u8, 24u8, 2u8, 17u8, 1u8, 5u8,
+            0u8, 24u8, 2u8, 32u8, 1u8, 5u8, 0u8, 24u8, 2u8, 33u8, 1u8, 5u8, 0u8, 28u8, 2u8, 30u8,
+            1u8, 5u8, 0u8, 28u8, 2u8, 17u8, 1u8, 5u8, 0u8, 28u8, 2u8, 32u8, 1u8, 5u8, 0u8, 28u8,
+            2u8, 33u8, 1u8, 5u8, 0u8, 128u8, 248u8, 1u8, 5u8, 0u8, 43u8, 2u8, 56u8, 1u8, 5u8, 0u8,
+            13u8, 2u8, 30u8, 1u8, 5u8, 0u8, 13u8, 2u8, 17u8, 1u8, 5u8, 0u8, 13u8, 2u8, 32u8, 1u8,
+            5u8, 0u8, 13u8, 2u8, 56u8, 1u8, 5u8, 0u8, 13u8, 2u8, 33u8, 129u8, 238u8, 12u8, 1u8,
+            5u8, 0u8, 13u8, 2u8, 19u8, 1u8, 5u8, 0u8, 15u8, 2u8, 30u8, 1u8, 5u8, 0u8, 15u8, 2u8,
+            17u8, 1u8, 5u8, 0u8, 15u8, 2u8, 32u8, 1u8, 5u8, 0u8, 15u8, 2u8, 33u8, 1u8, 5u8, 0u8,
+            57u8, 2u8, 17u8, 1u8, 5u8, 0u8, 129u8, 117u8, 1u8, 5u8, 0u8, 57u8, 2u8, 33u8, 1u8, 4u8,
+            0u8, 21u8, 2u8, 27u8, 1u8, 5u8, 0u8, 21u8, 2u8, 27u8, 1u8, 4u8, 0u8, 21u8, 2u8, 34u8,
+            1u8, 5u8, 0u8, 21u8, 2u8, 34u8, 1u8, 4u8, 0u8, 21u8, 2u8, 65u8, 1u8, 5u8, 0u8, 21u8,
+            2u8, 65u8, 1u8, 4u8, 0u8, 46u8, 2u8, 17u8, 1u8, 5u8, 0u8, 46u8, 2u8, 17u8, 1u8, 4u8,
+            0u8, 46u8, 2u8, 32u8, 1u8, 5u8, 0u8, 46u8, 2u8, 32u8, 1u8, 4u8, 0u8, 46u8, 2u8, 25u8,
+            16u8, 1u8, 5u8, 0u8, 46u8, 2u8, 25u8, 16u8, 1u8, 4u8, 0u8, 46u8, 2u8, 14u8, 1u8, 5u8,
+            0u8, 46u8, 2u8, 14u8, 1u8, 4u8, 0u8, 58u8, 2u8, 14u8, 1u8, 5u8, 0u8, 58u8, 2u8, 14u8,
+            1u8, 4u8, 0u8, 58u8, 2u8, 19u8, 1u8, 5u8, 0u8, 58u8, 2u8, 19u8, 1u8, 4u8, 0u8, 24u8,
+            2u8, 27u8, 1u8, 5u8, 0u8, 24u8, 2u8, 27u8, 1u8, 4u8, 0u8, 24u8, 2u8, 34u8, 1u8, 5u8,
+            0u8, 24u8, 2u8, 34u8, 1u8, 4u8, 0u8, 24u8, 2u8, 25u8, 16u8, 1u8, 5u8, 0u8, 24u8, 2u8,
+            25u8, 16u8, 1u8, 4u8, 0u8, 24u8, 2u8, 65u8, 1u8, 5u8, 0u8, 24u8, 2u8, 65u8, 1u8, 4u8,
+            0u8, 24u8, 2u8, 14u8, 1u8, 5u8, 0u8, 24u8, 2u8, 14u8, 1u8, 4u8, 0u8, 44u8, 2u8, 32u8,
+            1u8, 5u8, 0u8, 44u8, 2u8, 32u8, 1u8, 4u8, 0u8, 44u8, 2u8, 34u8, 1u8, 5u8, 0u8, 44u8,
+            2u8, 34u8, 1u8, 4u8, 0u8, 44u8, 2u8, 25u8, 16u8, 1u8, 5u8, 0u8, 44u8, 2u8, 25u8, 16u8,
+            1u8, 4u8, 0u8, 44u8, 2u8, 39u8, 1u8, 5u8, 0u8, 44u8, 2u8, 39u8, 1u8, 4u8, 0u8, 80u8,
+            2u8, 32u8, 1u8, 5u8, 0u8, 80u8, 2u8, 32u8, 1u8, 4u8, 0u8, 80u8, 2u8, 19u8, 1u8, 5u8,
+            0u8, 80u8, 2u8, 19u8, 1u8, 4u8, 0u8, 28u8, 2u8, 56u8, 1u8, 5u8, 0u8, 28u8, 2u8, 56u8,
+            1u8, 4u8, 0u8, 28u8, 2u8, 27u8, 1u8, 5u8, 0u8, 28u8, 2u8, 27u8, 1u8, 4u8, 0u8, 28u8,
+            2u8, 34u8, 1u8, 5u8, 0u8, 28u8, 2u8, 34u8, 1u8, 4u8, 0u8, 28u8, 2u8, 65u8, 1u8, 5u8,
+            0u8, 28u8, 2u8, 65u8, 1u8, 4u8, 0u8, 28u8, 2u8, 25u8, 16u8, 1u8, 5u8, 0u8, 128u8,
+            238u8, 28u8, 1u8, 4u8, 64u8, 129u8, 11u8, 1u8, 5u8, 64u8, 129u8, 11u8, 1u8, 4u8, 0u8,
+            67u8, 2u8, 32u8, 1u8, 5u8, 0u8, 67u8, 2u8, 32u8, 1u8, 4u8, 0u8, 82u8, 2u8, 39u8, 1u8,
+            5u8, 0u8, 82u8, 2u8, 39u8, 1u8, 5u8, 0u8, 130u8, 115u8, 1u8, 4u8, 0u8, 47u8, 2u8, 17u8,
+            1u8, 5u8, 0u8, 47u8, 2u8, 17u8, 1u8, 4u8, 0u8, 47u8, 2u8, 39u8, 1u8, 5u8, 0u8, 47u8,
+            2u8, 39u8, 1u8, 4u8, 0u8, 47u8, 2u8, 14u8, 1u8, 5u8, 0u8, 47u8, 2u8, 14u8, 1u8, 4u8,
+            0u8, 47u8, 2u8, 116u8, 25u8, 1u8, 5u8, 0u8, 47u8, 2u8, 116u8, 25u8, 1u8, 4u8, 0u8,
+            47u8, 2u8, 19u8, 1u8, 5u8, 0u8, 47u8, 2u8, 19u8, 1u8, 4u8, 0u8, 43u8, 2u8, 17u8, 1u8,
+            5u8, 0u8, 43u8, 2u8, 17u8, 1u8, 4u8, 0u8, 43u8, 2u8, 39u8, 1u8, 5u8, 0u8, 43u8, 2u8,
+            39u8, 1u8, 4u8, 0u8, 43u8, 2u8, 14u8, 1u8, 5u8, 0u8, 43u8, 2u8, 14u8, 1u8, 5u8, 0u8,
+            43u8, 130u8, 232u8, 129u8, 176u8, 128u8, 214u8, 1u8, 4u8, 0u8, 128u8, 244u8, 1u8, 5u8,
+            0u8, 128u8, 244u8, 1u8, 4u8, 0u8, 13u8, 2u8, 27u8, 1u8, 5u8, 0u8, 13u8, 2u8, 27u8, 1u8,
+            4u8, 0u8, 13u8, 2u8, 34u8, 1u8, 5u8, 0u8, 13u8, 2u8, 34u8, 1u8, 4u8, 0u8, 13u8, 2u8,
+            26u8, 17u8, 1u8, 5u8, 0u8, 13u8, 2u8, 26u8, 17u8, 1u8, 4u8, 64u8, 128u8, 197u8, 1u8,
+            5u8, 64u8, 128u8, 197u8, 1u8, 4u8, 0u8, 55u8, 2u8, 17u8, 1u8, 5u8, 0u8, 55u8, 2u8,
+            17u8, 1u8, 4u8, 0u8, 55u8, 2u8, 39u8, 1u8, 5u8, 0u8, 55u8, 2u8, 39u8, 1u8, 4u8, 0u8,
+            55u8, 2u8, 14u8, 1u8, 5u8, 0u8, 55u8, 2u8, 14u8, 1u8, 4u8, 0u8, 51u8, 2u8, 17u8, 1u8,
+            5u8, 0u8, 51u8, 2u8, 17u8, 1u8, 4u8, 0u8, 51u8, 2u8, 32u8, 1u8, 5u8, 0u8, 51u8, 2u8,
+            32u8, 1u8, 4u8, 0u8, 51u8, 2u8, 39u8, 1u8, 5u8, 0u8, 51u8, 2u8, 39u8, 1u8, 4u8, 0u8,
+            51u8, 2u8, 14u8, 1u8, 5u8, 0u8, 51u8, 2u8, 14u8, 1u8, 4u8, 0u8, 49u8, 2u8, 39u8, 1u8,
+            5u8, 0u8, 49u8, 2u8, 39u8, 1u8, 4u8, 0u8, 49u8, 2u8, 14u8, 1u8, 5u8, 0u8, 49u8, 2u8,
+            14u8, 1u8, 4u8, 0u8, 49u8, 2u8, 19u8, 1u8, 5u8, 0u8, 49u8, 2u8, 19u8, 1u8, 4u8, 0u8,
+            15u8, 2u8, 56u8, 1u8, 5u8, 0u8, 15u8, 2u8, 56u8, 1u8, 4u8, 0u8, 15u8, 2u8, 27u8, 1u8,
+            5u8, 0u8, 15u8, 2u8, 27u8, 1u8, 4u8, 0u8, 15u8, 2u8, 34u8, 1u8, 5u8, 0u8, 15u8, 2u8,
+            34u8, 1u8, 4u8, 0u8, 15u8, 2u8, 101u8, 16u8, 1u8, 5u8, 0u8, 15u8, 2u8, 101u8, 16u8,
+            1u8, 4u8, 0u8, 15u8, 2u8, 26u8, 17u8, 1u8, 5u8, 0u8, 15u8, 2u8, 26u8, 17u8, 1u8, 4u8,
+            0u8, 15u8, 2u8, 65u8, 1u8, 5u8, 0u8, 15u8, 2u8, 65u8, 1u8, 4u8, 0u8, 128u8, 171u8, 2u8,
+            32u8, 1u8, 5u8, 0u8, 128u8, 171u8, 2u8, 32u8, 1u8, 4u8, 0u8, 57u8, 2u8, 32u8, 1u8, 5u8,
+            0u8, 57u8, 2u8, 32u8, 1u8, 4u8, 0u8, 57u8, 2u8, 33u8, 1u8, 4u8, 0u8, 54u8, 2u8, 17u8,
+            1u8, 5u8, 0u8, 54u8, 2u8, 17u8, 1u8, 4u8, 0u8, 54u8, 2u8, 25u8, 16u8, 1u8, 5u8, 0u8,
+            54u8, 2u8, 25u8, 16u8, 1u8, 4u8, 0u8, 54u8, 2u8, 14u8, 1u8, 5u8, 0u8, 54u8, 2u8, 14u8,
+            1u8, 5u8, 0u8, 128u8, 191u8, 51u8, 1u8, 5u8, 0u8, 88u8, 2u8, 19u8, 1u8, 4u8, 0u8, 88u8,
+            2u8, 36u8, 1u8, 4u8, 0u8, 88u8, 2u8, 128u8, 169u8, 1u8, 5u8, 0u8, 88u8, 2u8, 128u8,
+            169u8, 1u8, 4u8, 0u8, 105u8, 128u8, 202u8, 1u8, 5u8, 0u8, 105u8, 128u8, 202u8, 1u8,
+            4u8, 0u8, 129u8, 55u8, 13u8, 1u8, 4u8, 0u8, 46u8, 2u8, 36u8, 1u8, 5u8, 0u8, 46u8, 2u8,
+            36u8, 1u8, 4u8, 0u8, 129u8, 141u8, 58u8, 1u8, 4u8, 0u8, 58u8, 2u8, 36u8, 1u8, 4u8, 0u8,
+            58u8, 2u8, 128u8, 169u8, 1u8, 5u8, 0u8, 58u8, 2u8, 128u8, 169u8, 1u8, 5u8, 0u8, 106u8,
+            129u8, 233u8, 1u8, 4u8, 0u8, 69u8, 24u8, 1u8, 4u8, 0u8, 131u8, 25u8, 1u8, 4u8, 0u8,
+            129u8, 55u8, 24u8, 1u8, 4u8, 0u8, 128u8, 130u8, 2u8, 36u8, 1u8, 5u8, 0u8, 128u8, 130u8,
+            2u8, 36u8, 1u8, 4u8, 0u8, 44u8, 2u8, 36u8, 1u8, 4u8, 0u8, 130u8, 42u8, 1u8, 5u8, 0u8,
+            130u8, 70u8, 1u8, 4u8, 0u8, 130u8, 97u8, 1u8, 4u8, 0u8, 28u8, 2u8, 19u8, 1u8, 4u8, 0u8,
+            82u8, 2u8, 36u8, 1u8, 5u8, 0u8, 82u8, 2u8, 36u8, 1u8, 5u8, 0u8, 47u8, 2u8, 109u8, 1u8,
+            5u8, 0u8, 130u8, 117u8, 2u8, 19u8, 1u8, 4u8, 0u8, 106u8, 128u8, 193u8, 1u8, 4u8, 0u8,
+            43u8, 2u8, 42u8, 36u8, 1u8, 5u8, 0u8, 43u8, 2u8, 128u8, 191u8, 60u8, 129u8, 28u8, 1u8,
+            4u8, 0u8, 13u8, 2u8, 116u8, 56u8, 1u8, 4u8, 0u8, 13u8, 2u8, 128u8, 138u8, 1u8, 5u8,
+            0u8, 13u8, 2u8, 128u8, 138u8, 1u8, 4u8, 0u8, 129u8, 54u8, 1u8, 5u8, 0u8, 129u8, 54u8,
+            1u8, 4u8, 0u8, 128u8, 150u8, 2u8, 36u8, 1u8, 5u8, 0u8, 128u8, 150u8, 2u8, 36u8, 1u8,
+            0u8, 131u8, 177u8, 1u8, 4u8, 0u8, 105u8, 53u8, 1u8, 5u8, 0u8, 105u8, 53u8, 1u8, 4u8,
+            0u8, 128u8, 247u8, 1u8, 0u8, 69u8, 128u8, 247u8, 130u8, 130u8, 1u8, 5u8, 0u8, 49u8,
+            2u8, 130u8, 200u8, 36u8, 1u8, 4u8, 0u8, 49u8, 2u8, 36u8, 1u8, 5u8, 0u8, 49u8, 2u8,
+            36u8, 1u8, 4u8, 0u8, 49u8, 2u8, 129u8, 80u8, 36u8, 1u8, 4u8, 0u8, 15u8, 2u8, 128u8,
+            138u8, 1u8, 5u8, 0u8, 15u8, 2u8, 128u8, 138u8, 1u8, 4u8, 0u8, 131u8, 128u8, 1u8, 4u8,
+            0u8, 128u8, 170u8, 2u8, 36u8, 1u8, 4u8, 0u8, 57u8, 2u8, 36u8, 1u8, 5u8, 0u8, 57u8, 2u8,
+            36u8, 1u8, 4u8, 0u8, 54u8, 2u8, 19u8, 1u8, 5u8, 0u8, 54u8, 2u8, 19u8, 1u8, 4u8, 0u8,
+            98u8, 1u8, 4u8, 0u8, 98u8, 69u8, 1u8, 5u8, 0u8, 98u8, 69u8, 1u8, 5u8, 0u8, 98u8, 2u8,
+            103u8, 1u8, 0u8, 53u8, 2u8, 19u8, 1u8, 4u8, 0u8, 105u8, 128u8, 132u8, 1u8, 5u8, 0u8,
+            105u8, 128u8, 132u8, 1u8, 0u8, 45u8, 128u8, 187u8, 128u8, 165u8, 2u8, 19u8, 1u8, 0u8,
+            129u8, 130u8, 1u8, 0u8, 129u8, 234u8, 122u8, 1u8, 0u8, 130u8, 119u8, 122u8, 1u8, 0u8,
+            129u8, 146u8, 122u8, 1u8, 0u8, 129u8, 80u8, 122u8, 1u8, 4u8, 0u8, 128u8, 129u8, 2u8,
+            14u8, 1u8, 4u8, 0u8, 58u8, 2u8, 5u8, 0u8, 54u8, 2u8, 14u8, 1u8, 5u8, 0u8, 128u8, 129u8,
+            2u8, 14u8, 1u8, 4u8, 0u8, 129u8, 31u8, 1u8, 4u8, 0u8, 47u8, 2u8, 5u8, 0u8, 67u8, 1u8,
+            5u8, 0u8, 129u8, 31u8, 1u8, 4u8, 0u8, 129u8, 49u8, 1u8, 4u8, 0u8, 43u8, 2u8, 5u8, 0u8,
+            67u8, 1u8, 5u8, 0u8, 129u8, 49u8, 1u8, 4u8, 0u8, 21u8, 2u8, 14u8, 1u8, 5u8, 0u8, 21u8,
+            2u8, 14u8, 1u8, 4u8, 0u8, 28u8, 2u8, 14u8, 1u8, 5u8, 0u8, 28u8, 2u8, 14u8, 1u8, 4u8,
+            0u8, 13u8, 2u8, 14u8, 1u8, 5u8, 0u8, 13u8, 2u8, 14u8, 1u8, 4u8, 0u8, 15u8, 2u8, 14u8,
+            1u8, 5u8, 0u8, 15u8, 2u8, 14u8, 1u8, 4u8, 0u8, 15u8, 2u8, 33u8, 23u8, 27u8, 1u8, 5u8,
+            0u8, 15u8, 2u8, 33u8, 23u8, 27u8, 1u8, 4u8, 0u8, 15u8, 2u8, 33u8, 23u8, 17u8, 1u8, 5u8,
+            0u8, 15u8, 2u8, 33u8, 23u8, 17u8, 1u8, 4u8, 0u8, 15u8, 2u8, 33u8, 23u8, 14u8, 1u8, 5u8,
+            0u8, 15u8, 2u8, 33u8, 23u8, 14u8, 1u8, 4u8, 0u8, 15u8, 2u8, 33u8, 23u8, 30u8, 1u8, 5u8,
+            0u8, 15u8, 2u8, 33u8, 23u8, 30u8, 1u8, 5u8, 0u8, 106u8, 24u8, 1u8, 4u8, 0u8, 21u8, 2u8,
+            33u8, 23u8, 27u8, 1u8, 5u8, 0u8, 21u8, 2u8, 33u8, 23u8, 27u8, 1u8, 4u8, 0u8, 21u8, 2u8,
+            25u8, 16u8, 23u8, 27u8, 1u8, 5u8, 0u8, 21u8, 2u8, 25u8, 16u8, 23u8, 27u8, 1u8, 4u8,
+            0u8, 87u8, 2u8, 27u8, 1u8, 5u8, 0u8, 87u8, 2u8, 27u8, 1u8, 4u8, 0u8, 44u8, 2u8, 19u8,
+            1u8, 5u8, 0u8, 44u8, 2u8, 19u8, 1u8, 4u8, 0u8, 44u8, 2u8, 14u8, 1u8, 5u8, 0u8, 44u8,
+            2u8, 14u8, 1u8, 4u8, 0u8, 82u8, 2u8, 14u8, 1u8, 5u8, 0u8, 82u8, 2u8, 14u8, 1u8, 4u8,
+            0u8, 13u8, 2u8, 65u8, 1u8, 5u8, 0u8, 13u8, 2u8, 65u8, 1u8, 4u8, 0u8, 13u8, 2u8, 65u8,
+            23u8, 27u8, 1u8, 5u8, 0u8, 13u8, 2u8, 65u8, 23u8, 27u8, 1u8, 4u8, 0u8, 98u8, 2u8, 14u8,
+            1u8, 5u8, 0u8, 98u8, 2u8, 14u8, 1u8, 5u8, 0u8, 67u8, 2u8, 14u8, 1u8, 4u8, 0u8, 128u8,
+            129u8, 1u8, 4u8, 0u8, 58u8, 2u8, 5u8, 0u8, 54u8, 1u8, 5u8, 0u8, 128u8, 129u8, 1u8, 4u8,
+            0u8, 44u8, 2u8, 17u8, 1u8, 5u8, 0u8, 44u8, 2u8, 17u8, 1u8, 4u8, 0u8, 130u8, 71u8, 1u8,
+            4u8, 0u8, 129u8, 130u8, 1u8, 4u8, 0u8, 43u8, 2u8, 30u8, 1u8, 5u8, 0u8, 43u8, 2u8, 30u8,
+            1u8, 4u8, 0u8, 21u8, 2u8, 101u8, 16u8, 23u8, 17u8, 1u8, 5u8, 0u8, 21u8, 2u8, 101u8,
+            16u8, 23u8, 17u8, 1u8, 4u8, 0u8, 87u8, 2u8, 17u8, 1u8, 5u8, 0u8, 87u8, 2u8, 17u8, 1u8,
+            4u8, 0u8, 13u8, 2u8, 19u8, 23u8, 17u8, 1u8, 5u8, 0u8, 13u8, 2u8, 19u8, 23u8, 17u8, 1u8,
+            4u8, 0u8, 21u8, 2u8, 26u8, 30u8, 1u8, 5u8, 0u8, 21u8, 2u8, 26u8, 30u8, 1u8, 4u8, 0u8,
+            21u8, 2u8, 45u8, 34u8, 1u8, 5u8, 0u8, 21u8, 2u8, 45u8, 34u8, 1u8, 4u8, 0u8, 24u8, 2u8,
+            26u8, 30u8, 1u8, 5u8, 0u8, 24u8, 2u8, 26u8, 30u8, 1u8, 4u8, 0u8, 24u8, 2u8, 45u8, 34u8,
+            1u8, 5u8, 0u8, 24u8, 2u8, 45u8, 34u8, 1u8, 4u8, 0u8, 28u8, 2u8, 26u8, 30u8, 1u8, 5u8,
+            0u8, 28u8, 2u8, 26u8, 30u8, 1u8, 4u8, 0u8, 28u8, 2u8, 45u8, 34u8, 1u8, 5u8, 0u8, 28u8,
+            2u8, 45u8, 34u8, 1u8, 4u8, 0u8, 13u8, 2u8, 26u8, 30u8, 1u8, 5u8, 0u8, 13u8, 2u8, 26u8,
+            30u8, 1u8, 4u8, 0u8, 13u8, 2u8, 45u8, 34u8, 1u8, 5u8, 0u8, 13u8, 2u8, 45u8, 34u8, 1u8,
+            4u8, 0u8, 55u8, 2u8, 26u8, 30u8, 1u8, 5u8, 0u8, 55u8, 2u8, 26u8, 30u8, 1u8, 4u8, 0u8,
+            55u8, 2u8, 45u8, 34u8, 1u8, 5u8, 0u8, 55u8, 2u8, 45u8, 34u8, 1u8, 4u8, 0u8, 15u8, 2u8,
+            26u8, 30u8, 1u8, 5u8, 0u8, 15u8, 2u8, 26u8, 30u8, 1u8, 4u8, 0u8, 15u8, 2u8, 45u8, 34u8,
+            1u8, 5u8, 0u8, 15u8, 2u8, 45u8, 34u8, 1u8, 4u8, 0u8, 51u8, 2u8, 110u8, 76u8, 1u8, 5u8,
+            0u8, 51u8, 2u8, 110u8, 76u8, 1u8, 4u8, 0u8, 49u8, 2u8, 110u8, 76u8, 1u8, 5u8, 0u8,
+            49u8, 2u8, 110u8, 76u8, 1u8, 4u8, 0u8, 129u8, 133u8, 1u8, 5u8, 0u8, 129u8, 133u8, 1u8,
+            4u8, 0u8, 80u8, 2u8, 14u8, 1u8, 5u8, 0u8, 80u8, 2u8, 14u8, 1u8, 4u8, 0u8, 43u8, 2u8,
+            128u8, 191u8, 60u8, 129u8, 28u8, 1u8, 5u8, 0u8, 58u8, 2u8, 123u8, 1u8, 4u8, 0u8, 129u8,
+            57u8, 1u8, 5u8, 0u8, 129u8, 57u8, 1u8, 4u8, 0u8, 54u8, 2u8, 36u8, 1u8, 5u8, 0u8, 54u8,
+            2u8, 36u8, 1u8, 4u8, 0u8, 21u8, 2u8, 25u8, 16u8, 1u8, 5u8, 0u8, 21u8, 2u8, 25u8, 16u8,
+            1u8, 4u8, 0u8, 24u8, 2u8, 39u8, 1u8, 5u8, 0u8, 24u8, 2u8, 39u8, 1u8, 4u8, 0u8, 13u8,
+            2u8, 33u8, 23u8, 27u8, 1u8, 5u8, 0u8, 13u8, 2u8, 33u8, 23u8, 27u8, 1u8, 4u8, 0u8, 13u8,
+            2u8, 56u8, 23u8, 27u8, 1u8, 5u8, 0u8, 13u8, 2u8, 56u8, 23u8, 27u8, 1u8, 4u8, 0u8, 13u8,
+            2u8, 25u8, 16u8, 1u8, 5u8, 0u8, 13u8, 2u8, 25u8, 16u8, 1u8, 4u8, 0u8, 13u8, 2u8, 25u8,
+            16u8, 23u8, 27u8, 1u8, 5u8, 0u8, 13u8, 2u8, 25u8, 16u8, 23u8, 27u8, 1u8, 4u8, 0u8,
+            57u8, 2u8, 27u8, 1u8, 5u8, 0u8, 57u8, 2u8, 27u8, 1u8, 5u8, 0u8, 47u8, 2u8, 123u8, 1u8,
+            5u8, 0u8, 43u8, 2u8, 123u8, 1u8, 5u8, 0u8, 49u8, 2u8, 123u8, 1u8, 5u8, 0u8, 128u8,
+            238u8, 67u8, 1u8, 5u8, 0u8, 129u8, 224u8, 128u8, 236u8, 1u8, 5u8, 0u8, 130u8, 239u8,
+            128u8, 236u8, 1u8, 4u8, 0u8, 21u8, 2u8, 19u8, 1u8, 4u8, 0u8, 46u8, 2u8, 19u8, 1u8, 5u8,
+            0u8, 46u8, 2u8, 19u8, 1u8, 4u8, 0u8, 47u8, 2u8, 109u8, 1u8, 4u8, 0u8, 49u8, 2u8, 128u8,
+            235u8, 19u8, 1u8, 5u8, 0u8, 51u8, 2u8, 129u8, 110u8, 103u8, 1u8, 5u8, 0u8, 54u8, 2u8,
+            129u8, 110u8, 103u8, 1u8, 4u8, 0u8, 128u8, 187u8, 128u8, 165u8, 1u8, 5u8, 0u8, 128u8,
+            187u8, 128u8, 165u8, 1u8, 4u8, 0u8, 88u8, 2u8, 19u8, 1u8, 4u8, 0u8, 15u8, 109u8, 1u8,
+            4u8, 0u8, 106u8, 128u8, 170u8, 1u8, 4u8, 0u8, 24u8, 2u8, 19u8, 1u8, 5u8, 0u8, 24u8,
+            2u8, 19u8, 1u8, 4u8, 0u8, 67u8, 2u8, 19u8, 1u8, 5u8, 0u8, 67u8, 2u8, 19u8, 1u8, 4u8,
+            0u8, 5u8, 128u8, 155u8, 2u8, 36u8, 103u8, 1u8, 5u8, 0u8, 128u8, 155u8, 2u8, 36u8,
+            103u8, 1u8, 4u8, 0u8, 55u8, 2u8, 19u8, 1u8, 5u8, 0u8, 55u8, 2u8, 19u8, 1u8, 4u8, 0u8,
+            57u8, 2u8, 19u8, 1u8, 5u8, 0u8, 57u8, 2u8, 19u8, 3u8, 8u8, 129u8, 22u8, 3u8, 8u8,
+            129u8, 103u8, 3u8, 8u8, 129u8, 43u8, 3u8, 8u8, 129u8, 119u8, 3u8, 8u8, 131u8, 81u8,
+            3u8, 8u8, 129u8, 81u8, 3u8, 8u8, 129u8, 39u8, 3u8, 8u8, 129u8, 66u8, 3u8, 8u8, 131u8,
+            80u8, 3u8, 8u8, 129u8, 93u8, 3u8, 8u8, 129u8, 106u8, 3u8, 8u8, 129u8, 9u8, 3u8, 8u8,
+            128u8, 225u8, 3u8, 8u8, 131u8, 78u8, 3u8, 8u8, 128u8, 224u8, 3u8, 8u8, 129u8, 21u8,
+            3u8, 8u8, 129u8, 116u8, 3u8, 8u8, 129u8, 63u8, 3u8, 8u8, 129u8, 5u8, 3u8, 8u8, 129u8,
+            46u8, 3u8, 8u8, 129u8, 104u8, 3u8, 8u8, 129u8, 48u8, 3u8, 8u8, 130u8, 168u8, 3u8, 8u8,
+            129u8, 120u8, 3u8, 8u8, 129u8, 83u8, 3u8, 8u8, 129u8, 105u8, 3u8, 8u8, 129u8, 82u8,
+            3u8, 8u8, 130u8, 102u8, 3u8, 8u8, 129u8, 40u8, 3u8, 8u8, 129u8, 17u8, 3u8, 8u8, 130u8,
+            215u8, 3u8, 8u8, 130u8, 216u8, 3u8, 8u8, 130u8, 224u8, 3u8, 8u8, 129u8, 68u8, 3u8, 8u8,
+            130u8, 219u8, 3u8, 8u8, 130u8, 221u8, 3u8, 8u8, 130u8, 220u8, 3u8, 8u8, 130u8, 222u8,
+            3u8, 8u8, 130u8, 218u8, 3u8, 8u8, 130u8, 213u8, 3u8, 8u8, 130u8, 212u8, 3u8, 8u8,
+            130u8, 223u8, 3u8, 8u8, 129u8, 67u8, 3u8, 8u8, 129u8, 19u8, 3u8, 8u8, 130u8, 103u8,
+            3u8, 8u8, 129u8, 94u8, 3u8, 8u8, 131u8, 51u8, 3u8, 8u8, 129u8, 97u8, 3u8, 8u8, 129u8,
+            96u8, 3u8, 8u8, 131u8, 50u8, 3u8, 8u8, 129u8, 95u8, 3u8, 8u8, 131u8, 53u8, 3u8, 8u8,
+            131u8, 54u8, 3u8, 8u8, 131u8, 48u8, 3u8, 8u8, 131u8, 46u8, 3u8, 8u8, 131u8, 45u8, 3u8,
+            8u8, 131u8, 49u8, 3u8, 8u8, 131u8, 55u8, 3u8, 8u8, 131u8, 52u8, 3u8, 8u8, 131u8, 47u8,
+            3u8, 8u8, 129u8, 201u8, 3u8, 8u8, 129u8, 203u8, 3u8, 8u8, 129u8, 189u8, 3u8, 8u8,
+            129u8, 191u8, 3u8, 8u8, 129u8, 59u8, 3u8, 8u8, 129u8, 10u8, 3u8, 8u8, 130u8, 92u8, 3u8,
+            8u8, 130u8, 85u8, 3u8, 8u8, 130u8, 88u8, 3u8, 8u8, 130u8, 89u8, 3u8, 8u8, 130u8, 86u8,
+            3u8, 8u8, 129u8, 102u8, 3u8, 8u8, 130u8, 83u8, 3u8, 8u8, 130u8, 82u8, 3u8, 8u8, 130u8,
+            91u8, 3u8, 8u8, 130u8, 87u8, 3u8, 8u8, 129u8, 132u8, 3u8, 8u8, 129u8, 205u8, 3u8, 8u8,
+            129u8, 200u8, 3u8, 8u8, 129u8, 202u8, 3u8, 8u8, 129u8, 188u8, 3u8, 8u8, 129u8, 190u8,
+            3u8, 8u8, 129u8, 197u8, 3u8, 8u8, 129u8, 196u8, 3u8, 8u8, 129u8, 199u8, 3u8, 8u8,
+            129u8, 187u8, 3u8, 8u8, 129u8, 64u8, 3u8, 8u8, 129u8, 18u8, 3u8, 8u8, 131u8, 79u8, 3u8,
+            8u8, 129u8, 131u8, 3u8, 8u8, 130u8, 114u8, 3u8, 8u8, 129u8, 47u8, 3u8, 8u8, 129u8,
+            44u8, 3u8, 8u8, 129u8, 45u8, 3u8, 8u8, 129u8, 121u8, 3u8, 8u8, 128u8, 252u8, 3u8, 10u8,
+            128u8, 252u8, 3u8, 10u8, 21u8, 3u8, 10u8, 87u8, 3u8, 10u8, 131u8, 153u8, 3u8, 10u8,
+            131u8, 157u8, 3u8, 10u8, 129u8, 253u8, 3u8, 10u8, 24u8, 3u8, 10u8, 131u8, 160u8, 3u8,
+            10u8, 131u8, 158u8, 3u8, 10u8, 13u8, 3u8, 10u8, 131u8, 139u8, 3u8, 10u8, 131u8, 140u8,
+            3u8, 10u8, 128u8, 197u8, 3u8, 10u8, 131u8, 171u8, 3u8, 10u8, 15u8, 3u8, 10u8, 131u8,
+            145u8, 3u8, 10u8, 131u8, 144u8, 3u8, 10u8, 131u8, 149u8, 3u8, 10u8, 131u8, 178u8, 3u8,
+            10u8, 130u8, 11u8, 3u8, 10u8, 131u8, 169u8, 3u8, 10u8, 28u8, 3u8, 10u8, 129u8, 136u8,
+            3u8, 10u8, 129u8, 137u8, 3u8, 10u8, 131u8, 154u8, 3u8, 10u8, 131u8, 156u8, 3u8, 10u8,
+            129u8, 255u8, 3u8, 10u8, 130u8, 0u8, 3u8, 10u8, 129u8, 254u8, 3u8, 10u8, 131u8, 161u8,
+            3u8, 10u8, 131u8, 162u8, 3u8, 10u8, 130u8, 181u8, 3u8, 10u8, 130u8, 180u8, 3u8, 10u8,
+            130u8, 186u8, 3u8, 10u8, 130u8, 182u8, 3u8, 10u8, 130u8, 183u8, 3u8, 10u8, 131u8,
+            174u8, 3u8, 10u8, 131u8, 175u8, 3u8, 10u8, 131u8, 176u8, 3u8, 10u8, 131u8, 173u8, 3u8,
+            10u8, 131u8, 172u8, 3u8, 10u8, 131u8, 121u8, 3u8, 10u8, 131u8, 122u8, 3u8, 10u8, 131u8,
+            123u8, 3u8, 10u8, 131u8, 125u8, 3u8, 10u8, 131u8, 124u8, 3u8, 10u8, 131u8, 179u8, 3u8,
+            10u8, 131u8, 181u8, 3u8, 10u8, 131u8, 180u8, 3u8, 10u8, 131u8, 185u8, 3u8, 10u8, 131u8,
+            184u8, 3u8, 10u8, 131u8, 183u8, 3u8, 10u8, 131u8, 182u8, 3u8, 10u8, 130u8, 13u8, 3u8,
+            10u8, 130u8, 12u8, 3u8, 10u8, 131u8, 170u8, 3u8, 10u8, 130u8, 74u8, 3u8, 10u8, 130u8,
+            79u8, 3u8, 10u8, 130u8, 77u8, 3u8, 10u8, 130u8, 78u8, 3u8, 10u8, 130u8, 76u8, 3u8,
+            10u8, 130u8, 75u8, 3u8, 10u8, 129u8, 152u8, 3u8, 10u8, 129u8, 153u8, 3u8, 10u8, 129u8,
+            155u8, 3u8, 10u8, 129u8, 154u8, 3u8, 10u8, 131u8, 77u8, 3u8, 10u8, 129u8, 135u8, 3u8,
+            10u8, 131u8, 155u8, 3u8, 10u8, 131u8, 163u8, 3u8, 10u8, 130u8, 184u8, 3u8, 10u8, 130u8,
+            185u8, 3u8, 9u8, 129u8, 22u8, 3u8, 9u8, 129u8, 103u8, 3u8, 9u8, 130u8, 112u8, 3u8, 9u8,
+            129u8, 43u8, 3u8, 9u8, 129u8, 44u8, 3u8, 9u8, 129u8, 45u8, 3u8, 9u8, 129u8, 119u8, 3u8,
+            9u8, 129u8, 81u8, 3u8, 9u8, 130u8, 253u8, 3u8, 9u8, 130u8, 255u8, 3u8, 9u8, 131u8, 4u8,
+            3u8, 9u8, 131u8, 7u8, 3u8, 9u8, 131u8, 9u8, 3u8, 9u8, 131u8, 3u8, 3u8, 9u8, 129u8,
+            82u8, 3u8, 9u8, 129u8, 39u8, 3u8, 9u8, 129u8, 66u8, 3u8, 9u8, 129u8, 68u8, 3u8, 9u8,
+            129u8, 93u8, 3u8, 9u8, 129u8, 106u8, 3u8, 9u8, 129u8, 9u8, 3u8, 9u8, 128u8, 225u8, 3u8,
+            9u8, 128u8, 224u8, 3u8, 9u8, 129u8, 21u8, 3u8, 9u8, 129u8, 116u8, 3u8, 9u8, 129u8,
+            63u8, 3u8, 9u8, 129u8, 5u8, 3u8, 9u8, 130u8, 111u8, 3u8, 9u8, 130u8, 113u8, 3u8, 9u8,
+            129u8, 46u8, 3u8, 9u8, 129u8, 48u8, 3u8, 9u8, 129u8, 47u8, 3u8, 9u8, 130u8, 167u8, 3u8,
+            9u8, 130u8, 169u8, 3u8, 9u8, 129u8, 120u8, 3u8, 9u8, 129u8, 121u8, 3u8, 9u8, 130u8,
+            254u8, 3u8, 9u8, 129u8, 83u8, 3u8, 9u8, 131u8, 10u8, 3u8, 9u8, 131u8, 11u8, 3u8, 9u8,
+            129u8, 105u8, 3u8, 9u8, 131u8, 0u8, 3u8, 9u8, 131u8, 1u8, 3u8, 9u8, 131u8, 6u8, 3u8,
+            9u8, 131u8, 5u8, 3u8, 9u8, 130u8, 251u8, 3u8, 9u8, 131u8, 8u8, 3u8, 9u8, 131u8, 2u8,
+            3u8, 9u8, 130u8, 252u8, 3u8, 9u8, 131u8, 12u8, 3u8, 9u8, 130u8, 145u8, 3u8, 9u8, 130u8,
+            147u8, 3u8, 9u8, 129u8, 40u8, 3u8, 9u8, 130u8, 148u8, 3u8, 9u8, 130u8, 149u8, 3u8, 9u8,
+            130u8, 146u8, 3u8, 9u8, 130u8, 143u8, 3u8, 9u8, 130u8, 144u8, 3u8, 9u8, 129u8, 17u8,
+            3u8, 9u8, 130u8, 217u8, 3u8, 9u8, 129u8, 67u8, 3u8, 9u8, 130u8, 214u8, 3u8, 9u8, 129u8,
+            19u8, 3u8, 9u8, 129u8, 94u8, 3u8, 9u8, 129u8, 97u8, 3u8, 9u8, 129u8, 96u8, 3u8, 9u8,
+            129u8, 95u8, 3u8, 9u8, 129u8, 59u8, 3u8, 9u8, 129u8, 10u8, 3u8, 9u8, 130u8, 90u8, 3u8,
+            9u8, 129u8, 102u8, 3u8, 9u8, 130u8, 84u8, 3u8, 9u8, 129u8, 132u8, 3u8, 9u8, 131u8,
+            167u8, 3u8, 9u8, 131u8, 166u8, 3u8, 9u8, 129u8, 64u8, 3u8, 9u8, 129u8, 18u8, 3u8, 9u8,
+            130u8, 60u8, 3u8, 9u8, 130u8, 62u8, 3u8, 9u8, 130u8, 59u8, 3u8, 9u8, 130u8, 61u8, 3u8,
+            9u8, 129u8, 131u8, 3u8, 9u8, 130u8, 109u8, 3u8, 9u8, 130u8, 110u8, 3u8, 9u8, 130u8,
+            106u8, 3u8, 9u8, 130u8, 108u8, 3u8, 9u8, 130u8, 107u8, 3u8, 9u8, 129u8, 104u8, 128u8,
+            183u8, 129u8, 74u8, 128u8, 182u8, 129u8, 74u8, 128u8, 183u8, 52u8, 128u8, 182u8, 52u8,
+            131u8, 104u8, 52u8, 130u8, 33u8, 52u8, 131u8, 57u8, 52u8, 128u8, 251u8, 52u8, 100u8,
+            52u8, 131u8, 102u8, 52u8, 130u8, 51u8, 52u8, 108u8, 128u8, 173u8, 52u8, 108u8, 128u8,
+            173u8, 130u8, 175u8, 108u8, 128u8, 173u8, 129u8, 15u8, 128u8, 146u8, 31u8, 128u8,
+            158u8, 31u8, 128u8, 139u8, 130u8, 174u8, 128u8, 139u8, 128u8, 251u8, 125u8, 128u8,
+            183u8, 125u8, 128u8, 182u8, 125u8, 90u8, 109u8, 26u8, 107u8, 48u8, 26u8, 128u8, 192u8,
+            48u8, 42u8, 70u8, 59u8, 31u8, 60u8, 70u8, 59u8, 31u8, 70u8, 129u8, 33u8, 59u8, 31u8,
+            70u8, 129u8, 7u8, 59u8, 31u8, 42u8, 26u8, 59u8, 31u8, 60u8, 26u8, 59u8, 31u8, 26u8,
+            129u8, 33u8, 59u8, 31u8, 26u8, 129u8, 7u8, 59u8, 31u8, 128u8, 232u8, 26u8, 128u8,
+            232u8, 95u8, 131u8, 111u8, 95u8, 74u8, 25u8, 129u8, 26u8, 53u8, 25u8, 129u8, 26u8,
+            90u8, 129u8, 248u8, 130u8, 73u8, 129u8, 71u8, 48u8, 66u8, 130u8, 202u8, 66u8, 128u8,
+            146u8, 128u8, 243u8, 128u8, 158u8, 128u8, 243u8, 129u8, 72u8, 128u8, 237u8, 130u8,
+            30u8, 128u8, 146u8, 129u8, 58u8, 128u8, 158u8, 129u8, 58u8, 130u8, 156u8, 128u8, 196u8,
+            52u8, 129u8, 62u8, 130u8, 150u8, 12u8, 129u8, 62u8, 131u8, 99u8, 131u8, 103u8, 12u8,
+            92u8, 26u8, 92u8, 129u8, 123u8, 92u8, 69u8, 92u8, 69u8, 26u8, 92u8, 69u8, 129u8, 123u8,
+            92u8, 128u8, 223u8, 70u8, 129u8, 27u8, 120u8, 59u8, 31u8, 70u8, 129u8, 84u8, 120u8,
+            59u8, 31u8, 130u8, 246u8, 31u8, 26u8, 112u8, 31u8, 130u8, 96u8, 130u8, 197u8, 129u8,
+            125u8, 77u8, 131u8, 106u8, 128u8, 223u8, 130u8, 94u8, 129u8, 71u8, 129u8, 159u8, 128u8,
+            139u8, 95u8, 128u8, 134u8, 131u8, 62u8, 42u8, 83u8, 94u8, 2u8, 129u8, 76u8, 60u8, 83u8,
+            94u8, 2u8, 129u8, 76u8, 26u8, 117u8, 31u8, 117u8, 112u8, 31u8, 112u8, 117u8, 31u8,
+            131u8, 108u8, 12u8, 130u8, 8u8, 69u8, 129u8, 69u8, 12u8, 22u8, 130u8, 121u8, 95u8,
+            22u8, 131u8, 14u8, 95u8, 128u8, 192u8, 128u8, 216u8, 69u8, 129u8, 88u8, 129u8, 207u8,
+            128u8, 208u8, 53u8, 129u8, 158u8, 129u8, 144u8, 131u8, 133u8, 128u8, 228u8, 130u8,
+            151u8, 12u8, 131u8, 92u8, 125u8, 45u8, 129u8, 125u8, 128u8, 254u8, 100u8, 31u8, 75u8,
+            25u8, 100u8, 130u8, 240u8, 92u8, 89u8, 25u8, 100u8, 128u8, 132u8, 25u8, 100u8, 53u8,
+            25u8, 100u8, 89u8, 25u8, 31u8, 129u8, 242u8, 78u8, 131u8, 112u8, 107u8, 89u8, 127u8,
+            115u8, 130u8, 140u8, 52u8, 131u8, 152u8, 129u8, 15u8, 130u8, 40u8, 128u8, 215u8, 128u8,
+            190u8, 131u8, 107u8, 128u8, 190u8, 66u8, 128u8, 190u8, 129u8, 70u8, 128u8, 146u8,
+            128u8, 141u8, 128u8, 158u8, 128u8, 141u8, 128u8, 253u8, 131u8, 86u8, 128u8, 141u8,
+            129u8, 72u8, 128u8, 237u8, 128u8, 141u8, 129u8, 13u8, 129u8, 111u8, 129u8, 109u8,
+            128u8, 213u8, 129u8, 111u8, 129u8, 109u8, 129u8, 13u8, 6u8, 7u8, 129u8, 91u8, 128u8,
+            213u8, 6u8, 7u8, 129u8, 91u8, 130u8, 157u8, 62u8, 129u8, 90u8, 130u8, 173u8, 62u8,
+            129u8, 90u8, 22u8, 128u8, 166u8, 2u8, 129u8, 77u8, 128u8, 180u8, 128u8, 207u8, 128u8,
+            203u8, 129u8, 211u8, 22u8, 128u8, 164u8, 20u8, 128u8, 164u8, 130u8, 127u8, 131u8,
+            105u8, 128u8, 166u8, 129u8, 157u8, 129u8, 51u8, 129u8, 235u8, 129u8, 51u8, 129u8,
+            212u8, 130u8, 191u8, 22u8, 129u8, 113u8, 20u8, 129u8, 113u8, 128u8, 178u8, 128u8,
+            179u8, 128u8, 178u8, 128u8, 179u8, 2u8, 129u8, 194u8, 128u8, 178u8, 128u8, 179u8, 2u8,
+            128u8, 211u8, 129u8, 86u8, 128u8, 207u8, 2u8, 128u8, 200u8, 129u8, 245u8, 129u8, 8u8,
+            129u8, 169u8, 20u8, 128u8, 163u8, 128u8, 153u8, 22u8, 128u8, 163u8, 128u8, 153u8,
+            131u8, 36u8, 69u8, 131u8, 19u8, 130u8, 29u8, 128u8, 188u8, 95u8, 22u8, 42u8, 99u8,
+            81u8, 22u8, 60u8, 99u8, 81u8, 20u8, 42u8, 99u8, 81u8, 20u8, 128u8, 208u8, 99u8, 81u8,
+            20u8, 60u8, 99u8, 81u8, 20u8, 128u8, 240u8, 99u8, 81u8, 131u8, 61u8, 23u8, 129u8,
+            217u8, 129u8, 184u8, 12u8, 130u8, 243u8, 12u8, 129u8, 170u8, 12u8, 129u8, 178u8, 129u8,
+            149u8, 130u8, 193u8, 78u8, 129u8, 195u8, 130u8, 249u8, 78u8, 40u8, 130u8, 131u8, 78u8,
+            40u8, 130u8, 99u8, 128u8, 164u8, 23u8, 129u8, 216u8, 130u8, 21u8, 38u8, 129u8, 139u8,
+            131u8, 35u8, 129u8, 2u8, 23u8, 131u8, 42u8, 130u8, 207u8, 38u8, 128u8, 175u8, 128u8,
+            174u8, 85u8, 35u8, 130u8, 56u8, 85u8, 35u8, 130u8, 116u8, 85u8, 35u8, 130u8, 26u8,
+            85u8, 35u8, 129u8, 118u8, 85u8, 35u8, 131u8, 150u8, 85u8, 35u8, 131u8, 143u8, 85u8,
+            35u8, 129u8, 42u8, 85u8, 35u8, 128u8, 241u8, 131u8, 147u8, 40u8, 129u8, 236u8, 20u8,
+            130u8, 35u8, 128u8, 184u8, 20u8, 129u8, 98u8, 128u8, 184u8, 22u8, 129u8, 98u8, 128u8,
+            184u8, 20u8, 128u8, 166u8, 2u8, 129u8, 77u8, 128u8, 253u8, 128u8, 157u8, 128u8, 194u8,
+            130u8, 118u8, 128u8, 157u8, 128u8, 194u8, 130u8, 141u8, 113u8, 12u8, 128u8, 241u8,
+            73u8, 12u8, 130u8, 101u8, 131u8, 23u8, 129u8, 127u8, 130u8, 163u8, 130u8, 229u8, 129u8,
+            156u8, 131u8, 98u8, 130u8, 43u8, 129u8, 180u8, 130u8, 122u8, 131u8, 135u8, 130u8,
+            125u8, 131u8, 27u8, 131u8, 21u8, 129u8, 181u8, 129u8, 151u8, 130u8, 225u8, 20u8, 61u8,
+            128u8, 145u8, 20u8, 61u8, 129u8, 75u8, 20u8, 61u8, 129u8, 85u8, 20u8, 61u8, 128u8,
+            219u8, 20u8, 61u8, 129u8, 23u8, 20u8, 61u8, 129u8, 61u8, 22u8, 61u8, 128u8, 145u8,
+            22u8, 61u8, 129u8, 75u8, 22u8, 61u8, 129u8, 85u8, 22u8, 61u8, 128u8, 219u8, 22u8, 61u8,
+            129u8, 23u8, 22u8, 61u8, 129u8, 61u8, 22u8, 129u8, 100u8, 84u8, 20u8, 128u8, 188u8,
+            84u8, 20u8, 128u8, 181u8, 84u8, 22u8, 128u8, 226u8, 84u8, 20u8, 129u8, 100u8, 84u8,
+            22u8, 128u8, 188u8, 84u8, 22u8, 128u8, 181u8, 84u8, 20u8, 128u8, 226u8, 84u8, 129u8,
+            8u8, 131u8, 73u8, 128u8, 157u8, 129u8, 52u8, 128u8, 242u8, 129u8, 52u8, 128u8, 218u8,
+            128u8, 242u8, 129u8, 53u8, 128u8, 218u8, 131u8, 58u8, 129u8, 53u8, 128u8, 195u8, 130u8,
+            27u8, 12u8, 128u8, 195u8, 130u8, 158u8, 12u8, 128u8, 195u8, 129u8, 92u8, 12u8, 131u8,
+            146u8, 129u8, 112u8, 78u8, 129u8, 246u8, 129u8, 112u8, 78u8, 129u8, 126u8, 68u8, 38u8,
+            68u8, 38u8, 35u8, 131u8, 114u8, 91u8, 68u8, 38u8, 35u8, 131u8, 115u8, 91u8, 68u8, 38u8,
+            35u8, 131u8, 116u8, 91u8, 68u8, 38u8, 35u8, 131u8, 117u8, 91u8, 68u8, 38u8, 35u8,
+            131u8, 118u8, 91u8, 68u8, 38u8, 35u8, 131u8, 119u8, 91u8, 68u8, 38u8, 35u8, 131u8,
+            120u8, 91u8, 68u8, 38u8, 35u8, 130u8, 44u8, 130u8, 139u8, 22u8, 129u8, 126u8, 68u8,
+            38u8, 130u8, 245u8, 128u8, 199u8, 38u8, 130u8, 203u8, 128u8, 199u8, 38u8, 130u8, 209u8,
+            128u8, 199u8, 12u8, 131u8, 148u8, 38u8, 97u8, 130u8, 14u8, 97u8, 130u8, 15u8, 97u8,
+            130u8, 16u8, 97u8, 130u8, 17u8, 97u8, 130u8, 18u8, 97u8, 130u8, 19u8, 20u8, 72u8, 2u8,
+            25u8, 60u8, 20u8, 72u8, 2u8, 53u8, 127u8, 22u8, 72u8, 2u8, 20u8, 25u8, 60u8, 22u8,
+            72u8, 2u8, 53u8, 20u8, 127u8, 129u8, 41u8, 35u8, 128u8, 174u8, 129u8, 41u8, 35u8,
+            128u8, 175u8, 126u8, 35u8, 129u8, 0u8, 128u8, 174u8, 126u8, 35u8, 129u8, 29u8, 128u8,
+            175u8, 126u8, 35u8, 129u8, 29u8, 128u8, 174u8, 126u8, 35u8, 129u8, 0u8, 128u8, 175u8,
+            20u8, 128u8, 133u8, 22u8, 128u8, 133u8, 129u8, 2u8, 23u8, 129u8, 65u8, 129u8, 148u8,
+            129u8, 218u8, 131u8, 91u8, 129u8, 107u8, 40u8, 129u8, 140u8, 131u8, 24u8, 129u8, 142u8,
+            128u8, 254u8, 128u8, 186u8, 129u8, 107u8, 40u8, 130u8, 58u8, 129u8, 162u8, 38u8, 130u8,
+            28u8, 130u8, 196u8, 20u8, 128u8, 164u8, 75u8, 129u8, 30u8, 128u8, 229u8, 60u8, 75u8,
+            129u8, 30u8, 128u8, 229u8, 42u8, 131u8, 142u8, 12u8, 129u8, 6u8, 131u8, 136u8, 12u8,
+            128u8, 239u8, 113u8, 12u8, 128u8, 239u8, 73u8, 12u8, 130u8, 95u8, 113u8, 23u8, 73u8,
+            12u8, 73u8, 23u8, 113u8, 12u8, 73u8, 2u8, 19u8, 12u8, 73u8, 2u8, 19u8, 23u8, 73u8,
+            23u8, 113u8, 12u8, 107u8, 73u8, 2u8, 19u8, 12u8, 90u8, 73u8, 2u8, 19u8, 12u8, 115u8,
+            20u8, 72u8, 115u8, 22u8, 72u8, 115u8, 5u8, 20u8, 72u8, 130u8, 137u8, 38u8, 129u8,
+            239u8, 38u8, 131u8, 127u8, 130u8, 204u8, 38u8, 129u8, 209u8, 130u8, 41u8, 131u8, 129u8,
+            130u8, 164u8, 129u8, 192u8, 130u8, 201u8, 130u8, 100u8, 131u8, 134u8, 129u8, 198u8,
+            22u8, 128u8, 194u8, 130u8, 128u8, 131u8, 33u8, 131u8, 29u8, 130u8, 242u8, 131u8, 31u8,
+            131u8, 67u8, 128u8, 217u8, 129u8, 166u8, 129u8, 101u8, 130u8, 105u8, 20u8, 128u8,
+            128u8, 129u8, 35u8, 20u8, 128u8, 128u8, 128u8, 145u8, 22u8, 128u8, 128u8, 129u8, 35u8,
+            22u8, 128u8, 128u8, 128u8, 145u8, 128u8, 203u8, 129u8, 129u8, 131u8, 65u8, 128u8,
+            166u8, 129u8, 167u8, 128u8, 180u8, 128u8, 200u8, 22u8, 128u8, 203u8, 129u8, 118u8,
+            128u8, 180u8, 23u8, 128u8, 200u8, 106u8, 20u8, 128u8, 163u8, 128u8, 153u8, 106u8, 22u8,
+            128u8, 163u8, 128u8, 153u8, 20u8, 128u8, 181u8, 114u8, 83u8, 128u8, 230u8, 129u8, 25u8,
+            129u8, 237u8, 128u8, 222u8, 130u8, 190u8, 129u8, 65u8, 128u8, 222u8, 131u8, 63u8,
+            130u8, 57u8, 2u8, 20u8, 78u8, 129u8, 206u8, 128u8, 230u8, 129u8, 25u8, 129u8, 193u8,
+            129u8, 50u8, 128u8, 245u8, 129u8, 145u8, 130u8, 188u8, 42u8, 128u8, 210u8, 128u8,
+            206u8, 22u8, 129u8, 124u8, 42u8, 128u8, 210u8, 128u8, 206u8, 20u8, 129u8, 124u8, 42u8,
+            128u8, 210u8, 128u8, 206u8, 22u8, 42u8, 129u8, 24u8, 129u8, 37u8, 20u8, 42u8, 129u8,
+            24u8, 129u8, 37u8, 129u8, 244u8, 131u8, 64u8, 12u8, 129u8, 3u8, 20u8, 129u8, 243u8,
+            131u8, 110u8, 42u8, 129u8, 208u8, 128u8, 245u8, 129u8, 101u8, 129u8, 86u8, 130u8, 20u8,
+            128u8, 235u8, 114u8, 20u8, 72u8, 114u8, 22u8, 83u8, 22u8, 131u8, 113u8, 129u8, 79u8,
+            42u8, 129u8, 251u8, 129u8, 79u8, 42u8, 129u8, 252u8, 129u8, 160u8, 38u8, 35u8, 129u8,
+            127u8, 129u8, 3u8, 72u8, 2u8, 19u8, 23u8, 53u8, 127u8, 16u8, 128u8, 152u8, 131u8, 13u8,
+            129u8, 14u8, 128u8, 152u8, 130u8, 120u8, 129u8, 14u8, 128u8, 152u8, 45u8, 128u8, 152u8,
+            22u8, 78u8, 128u8, 198u8, 131u8, 37u8, 131u8, 38u8, 131u8, 39u8, 129u8, 204u8, 129u8,
+            183u8, 130u8, 63u8, 131u8, 56u8, 128u8, 186u8, 129u8, 129u8, 130u8, 69u8, 128u8, 186u8,
+            2u8, 130u8, 53u8, 130u8, 136u8, 38u8, 35u8, 130u8, 126u8, 129u8, 42u8, 128u8, 207u8,
+            128u8, 198u8, 130u8, 48u8, 130u8, 32u8, 128u8, 133u8, 114u8, 130u8, 64u8, 130u8, 23u8,
+            131u8, 22u8, 83u8, 89u8, 129u8, 214u8, 131u8, 60u8, 130u8, 80u8, 131u8, 59u8, 130u8,
+            211u8, 2u8, 128u8, 217u8, 131u8, 100u8, 130u8, 98u8, 129u8, 165u8, 38u8, 130u8, 55u8,
+            130u8, 46u8, 38u8, 130u8, 38u8, 130u8, 238u8, 129u8, 220u8, 128u8, 198u8, 22u8, 83u8,
+            20u8, 128u8, 133u8, 2u8, 90u8, 116u8, 22u8, 131u8, 85u8, 6u8, 128u8, 250u8, 11u8, 7u8,
+            6u8, 131u8, 97u8, 2u8, 128u8, 250u8, 16u8, 6u8, 129u8, 222u8, 11u8, 7u8, 6u8, 103u8,
+            130u8, 34u8, 6u8, 130u8, 104u8, 11u8, 7u8, 6u8, 128u8, 249u8, 11u8, 7u8, 6u8, 128u8,
+            249u8, 29u8, 7u8, 6u8, 128u8, 234u8, 11u8, 7u8, 6u8, 128u8, 234u8, 29u8, 7u8, 6u8,
+            129u8, 20u8, 11u8, 7u8, 6u8, 129u8, 20u8, 29u8, 7u8, 6u8, 129u8, 89u8, 11u8, 7u8, 6u8,
+            129u8, 89u8, 29u8, 7u8, 6u8, 129u8, 108u8, 11u8, 7u8, 6u8, 129u8, 108u8, 29u8, 7u8,
+            6u8, 0u8, 50u8, 11u8, 7u8, 6u8, 0u8, 41u8, 2u8, 128u8, 147u8, 16u8, 11u8, 7u8, 6u8,
+            0u8, 41u8, 2u8, 128u8, 147u8, 16u8, 18u8, 7u8, 6u8, 0u8, 41u8, 2u8, 50u8, 16u8, 11u8,
+            7u8, 6u8, 0u8, 41u8, 2u8, 50u8, 16u8, 18u8, 7u8, 6u8, 0u8, 128u8, 172u8, 2u8, 50u8,
+            16u8, 11u8, 7u8, 6u8, 0u8, 128u8, 172u8, 2u8, 50u8, 16u8, 18u8, 7u8, 6u8, 0u8, 41u8,
+            2u8, 50u8, 76u8, 11u8, 7u8, 6u8, 0u8, 41u8, 2u8, 50u8, 76u8, 18u8, 7u8, 6u8, 0u8, 86u8,
+            2u8, 50u8, 16u8, 11u8, 7u8, 6u8, 0u8, 86u8, 2u8, 50u8, 16u8, 18u8, 7u8, 6u8, 0u8, 86u8,
+            2u8, 50u8, 16u8, 37u8, 7u8, 6u8, 0u8, 86u8, 2u8, 50u8, 16u8, 29u8, 7u8, 6u8, 0u8, 41u8,
+            11u8, 7u8, 6u8, 0u8, 41u8, 18u8, 7u8, 6u8, 0u8, 121u8, 11u8, 7u8, 6u8, 0u8, 121u8,
+            18u8, 7u8, 6u8, 0u8, 121u8, 37u8, 7u8, 6u8, 0u8, 121u8, 29u8, 7u8, 6u8, 0u8, 104u8,
+            129u8, 36u8, 11u8, 7u8, 6u8, 0u8, 104u8, 129u8, 36u8, 18u8, 7u8, 6u8, 0u8, 104u8, 11u8,
+            7u8, 6u8, 0u8, 104u8, 18u8, 7u8, 6u8, 0u8, 104u8, 37u8, 7u8, 6u8, 0u8, 104u8, 29u8,
+            7u8, 6u8, 0u8, 128u8, 168u8, 11u8, 7u8, 6u8, 0u8, 128u8, 168u8, 18u8, 7u8, 6u8, 0u8,
+            128u8, 168u8, 37u8, 7u8, 6u8, 0u8, 128u8, 168u8, 29u8, 7u8, 6u8, 0u8, 128u8, 142u8,
+            11u8, 7u8, 6u8, 0u8, 128u8, 142u8, 18u8, 7u8, 6u8, 0u8, 128u8, 142u8, 37u8, 7u8, 6u8,
+            0u8, 128u8, 142u8, 29u8, 7u8, 6u8, 0u8, 128u8, 136u8, 11u8, 7u8, 6u8, 0u8, 128u8,
+            136u8, 18u8, 7u8, 6u8, 0u8, 128u8, 136u8, 37u8, 7u8, 6u8, 0u8, 128u8, 136u8, 29u8, 7u8,
+            6u8, 0u8, 128u8, 144u8, 11u8, 7u8, 6u8, 0u8, 128u8, 144u8, 18u8, 7u8, 6u8, 0u8, 128u8,
+            144u8, 37u8, 7u8, 6u8, 0u8, 128u8, 144u8, 29u8, 7u8, 6u8, 0u8, 128u8, 233u8, 11u8, 7u8,
+            6u8, 0u8, 128u8, 233u8, 18u8, 7u8, 6u8, 0u8, 129u8, 115u8, 11u8, 7u8, 6u8, 0u8, 129u8,
+            115u8, 18u8, 7u8, 6u8, 0u8, 129u8, 78u8, 11u8, 7u8, 6u8, 0u8, 129u8, 78u8, 18u8, 7u8,
+            6u8, 0u8, 129u8, 134u8, 11u8, 7u8, 6u8, 0u8, 129u8, 134u8, 18u8, 7u8, 6u8, 0u8, 128u8,
+            160u8, 11u8, 7u8, 6u8, 0u8, 128u8, 160u8, 18u8, 7u8, 6u8, 0u8, 128u8, 160u8, 37u8, 7u8,
+            6u8, 0u8, 128u8, 160u8, 29u8, 7u8, 6u8, 0u8, 128u8, 161u8, 11u8, 7u8, 6u8, 0u8, 128u8,
+            161u8, 18u8, 7u8, 6u8, 0u8, 128u8, 161u8, 37u8, 7u8, 6u8, 0u8, 128u8, 161u8, 29u8, 7u8,
+            6u8, 0u8, 128u8, 159u8, 11u8, 7u8, 6u8, 0u8, 128u8, 159u8, 18u8, 7u8, 6u8, 0u8, 128u8,
+            159u8, 37u8, 7u8, 6u8, 0u8, 128u8, 159u8, 29u8, 7u8, 6u8, 0u8, 124u8, 11u8, 7u8, 6u8,
+            0u8, 124u8, 18u8, 7u8, 6u8, 0u8, 124u8, 37u8, 7u8, 6u8, 0u8, 124u8, 29u8, 7u8, 6u8,
+            0u8, 128u8, 167u8, 11u8, 7u8, 6u8, 0u8, 128u8, 167u8, 18u8, 7u8, 6u8, 0u8, 128u8,
+            167u8, 37u8, 7u8, 6u8, 0u8, 128u8, 167u8, 29u8, 7u8, 6u8, 0u8, 128u8, 176u8, 11u8, 7u8,
+            6u8, 0u8, 128u8, 176u8, 18u8, 7u8, 6u8, 0u8, 128u8, 176u8, 37u8, 7u8, 6u8, 0u8, 128u8,
+            176u8, 29u8, 7u8, 6u8, 0u8, 119u8, 11u8, 7u8, 6u8, 0u8, 119u8, 18u8, 7u8, 6u8, 0u8,
+            119u8, 37u8, 7u8, 6u8, 0u8, 119u8, 29u8, 7u8, 6u8, 0u8, 128u8, 135u8, 11u8, 7u8, 6u8,
+            0u8, 128u8, 135u8, 18u8, 7u8, 6u8, 0u8, 128u8, 135u8, 37u8, 7u8, 6u8, 0u8, 128u8,
+            135u8, 29u8, 7u8, 6u8, 0u8, 128u8, 131u8, 11u8, 7u8, 6u8, 0u8, 128u8, 131u8, 18u8, 7u8,
+            6u8, 0u8, 128u8, 131u8, 37u8, 7u8, 6u8, 0u8, 128u8, 131u8, 29u8, 7u8, 6u8, 0u8, 128u8,
+            156u8, 11u8, 7u8, 6u8, 0u8, 128u8, 156u8, 18u8, 7u8, 6u8, 0u8, 128u8, 156u8, 37u8, 7u8,
+            6u8, 0u8, 128u8, 156u8, 29u8, 7u8, 6u8, 0u8, 128u8, 143u8, 11u8, 7u8, 6u8, 0u8, 128u8,
+            143u8, 18u8, 7u8, 6u8, 0u8, 128u8, 143u8, 37u8, 7u8, 6u8, 0u8, 128u8, 143u8, 29u8, 7u8,
+            6u8, 0u8, 63u8, 11u8, 7u8, 6u8, 0u8, 63u8, 18u8, 7u8, 6u8, 0u8, 63u8, 37u8, 7u8, 6u8,
+            0u8, 63u8, 29u8, 7u8, 6u8, 0u8, 128u8, 148u8, 11u8, 7u8, 6u8, 0u8, 128u8, 148u8, 18u8,
+            7u8, 6u8, 0u8, 128u8, 148u8, 37u8, 7u8, 6u8, 0u8, 128u8, 148u8, 29u8, 7u8, 6u8, 0u8,
+            128u8, 149u8, 11u8, 7u8, 6u8, 0u8, 128u8, 149u8, 18u8, 7u8, 6u8, 0u8, 128u8, 149u8,
+            37u8, 7u8, 6u8, 0u8, 128u8, 149u8, 29u8, 7u8, 6u8, 0u8, 128u8, 137u8, 11u8, 7u8, 6u8,
+            0u8, 128u8, 137u8, 18u8, 7u8, 6u8, 0u8, 128u8, 137u8, 37u8, 7u8, 6u8, 0u8, 128u8,
+            137u8, 29u8, 7u8, 6u8, 0u8, 128u8, 172u8, 11u8, 7u8, 6u8, 0u8, 128u8, 172u8, 18u8, 7u8,
+            6u8, 0u8, 41u8, 129u8, 34u8, 11u8, 7u8, 6u8, 0u8, 41u8, 129u8, 34u8, 18u8, 7u8, 6u8,
+            0u8, 86u8, 11u8, 7u8, 6u8, 0u8, 86u8, 18u8, 7u8, 6u8, 0u8, 86u8, 37u8, 7u8, 6u8, 0u8,
+            86u8, 29u8, 7u8, 6u8, 64u8, 63u8, 2u8, 41u8, 2u8, 128u8, 147u8, 16u8, 11u8, 7u8, 6u8,
+            64u8, 63u8, 2u8, 41u8, 2u8, 128u8, 147u8, 16u8, 18u8, 7u8, 6u8, 64u8, 63u8, 2u8, 41u8,
+            2u8, 50u8, 16u8, 11u8, 7u8, 6u8, 64u8, 63u8, 2u8, 41u8, 2u8, 50u8, 16u8, 18u8, 7u8,
+            6u8, 64u8, 63u8, 2u8, 41u8, 2u8, 50u8, 76u8, 11u8, 7u8, 6u8, 64u8, 63u8, 2u8, 41u8,
+            2u8, 50u8, 76u8, 18u8, 7u8, 6u8, 64u8, 63u8, 2u8, 41u8, 11u8, 7u8, 6u8, 64u8, 63u8,
+            2u8, 41u8, 18u8, 7u8, 108u8, 128u8, 173u8, 128u8, 196u8, 52u8,
+        ])
+    },
+    alias_code_points: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 2u8,
+            0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 4u8, 0u8,
+            0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 6u8, 0u8, 0u8,
+            0u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8,
+            8u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8, 0u8, 0u8, 0u8, 9u8,
+            0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8,
+            0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 11u8,
+            0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 12u8, 0u8, 0u8, 0u8, 13u8,
+            0u8, 0u8, 0u8, 13u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 14u8,
+            0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 16u8,
+            0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 18u8,
+            0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 19u8, 0u8, 0u8, 0u8, 20u8,
+            0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 22u8,
+            0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 24u8,
+            0u8, 0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 25u8, 0u8, 0u8, 0u8, 26u8,
+            0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 28u8,
+            0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 29u8,
+            0u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 0u8, 30u8,
+            0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 0u8, 32u8,
+            0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 128u8, 0u8, 0u8, 0u8, 128u8,
+            0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 130u8, 0u8, 0u8, 0u8, 130u8,
+            0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8, 0u8, 132u8, 0u8, 0u8, 0u8, 132u8,
+            0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8, 134u8,
+            0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 136u8,
+            0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 137u8,
+            0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 139u8,
+            0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 140u8,
+            0u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 141u8, 0u8, 0u8, 0u8, 141u8, 0u8, 0u8, 0u8, 141u8,
+            0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 143u8,
+            0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 143u8, 0u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 144u8,
+            0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 146u8,
+            0u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 147u8,
+            0u8, 0u8, 0u8, 148u8, 0u8, 0u8, 0u8, 148u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 149u8,
+            0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 151u8,
+            0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 152u8,
+            0u8, 0u8, 0u8, 153u8, 0u8, 0u8, 0u8, 153u8, 0u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 154u8,
+            0u8, 0u8, 0u8, 155u8, 0u8, 0u8, 0u8, 155u8, 0u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 156u8,
+            0u8, 0u8, 0u8, 157u8, 0u8, 0u8, 0u8, 157u8, 0u8, 0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 158u8,
+            0u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 173u8,
+            0u8, 0u8, 0u8, 162u8, 1u8, 0u8, 0u8, 163u8, 1u8, 0u8, 0u8, 236u8, 17u8, 0u8, 0u8,
+            237u8, 17u8, 0u8, 0u8, 238u8, 17u8, 0u8, 0u8, 239u8, 17u8, 0u8, 0u8, 11u8, 32u8, 0u8,
+            0u8, 12u8, 32u8, 0u8, 0u8, 13u8, 32u8, 0u8, 0u8, 14u8, 32u8, 0u8, 0u8, 15u8, 32u8, 0u8,
+            0u8, 42u8, 32u8, 0u8, 0u8, 43u8, 32u8, 0u8, 0u8, 44u8, 32u8, 0u8, 0u8, 45u8, 32u8, 0u8,
+            0u8, 46u8, 32u8, 0u8, 0u8, 47u8, 32u8, 0u8, 0u8, 95u8, 32u8, 0u8, 0u8, 96u8, 32u8, 0u8,
+            0u8, 102u8, 32u8, 0u8, 0u8, 103u8, 32u8, 0u8, 0u8, 104u8, 32u8, 0u8, 0u8, 105u8, 32u8,
+            0u8, 0u8, 255u8, 254u8, 0u8, 0u8, 255u8, 254u8, 0u8, 0u8, 255u8, 254u8, 0u8, 0u8,
+        ])
+    },
+    aliases: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            184u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 8u8,
+            0u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 14u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 20u8,
+            0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 28u8, 0u8, 0u8, 0u8, 30u8,
+            0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 34u8, 0u8, 0u8, 0u8, 36u8, 0u8, 0u8, 0u8, 38u8,
+            0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 46u8,
+            0u8, 0u8, 0u8, 48u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 55u8,
+            0u8, 0u8, 0u8, 58u8, 0u8, 0u8, 0u8, 61u8, 0u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 65u8,
+            0u8, 0u8, 0u8, 67u8, 0u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 71u8, 0u8, 0u8, 0u8, 73u8,
+            0u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 82u8, 0u8, 0u8, 0u8, 84u8,
+            0u8, 0u8, 0u8, 88u8, 0u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 96u8,
+            0u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 109u8,
+            0u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 119u8,
+            0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 124u8, 0u8, 0u8, 0u8, 127u8, 0u8, 0u8, 0u8, 129u8,
+            0u8, 0u8, 0u8, 133u8, 0u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 141u8,
+            0u8, 0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 151u8, 0u8, 0u8, 0u8, 153u8,
+            0u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 158u8, 0u8, 0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 162u8,
+            0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 170u8, 0u8, 0u8, 0u8, 173u8,
+            0u8, 0u8, 0u8, 175u8, 0u8, 0u8, 0u8, 179u8, 0u8, 0u8, 0u8, 182u8, 0u8, 0u8, 0u8, 184u8,
+            0u8, 0u8, 0u8, 188u8, 0u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 193u8, 0u8, 0u8, 0u8, 197u8,
+            0u8, 0u8, 0u8, 200u8, 0u8, 0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8, 206u8,
+            0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 211u8, 0u8, 0u8, 0u8, 213u8, 0u8, 0u8, 0u8, 219u8,
+            0u8, 0u8, 0u8, 221u8, 0u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 229u8, 0u8, 0u8, 0u8, 235u8,
+            0u8, 0u8, 0u8, 237u8, 0u8, 0u8, 0u8, 238u8, 0u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 243u8,
+            0u8, 0u8, 0u8, 245u8, 0u8, 0u8, 0u8, 250u8, 0u8, 0u8, 0u8, 252u8, 0u8, 0u8, 0u8, 1u8,
+            1u8, 0u8, 0u8, 3u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 9u8, 1u8, 0u8, 0u8, 11u8, 1u8,
+            0u8, 0u8, 16u8, 1u8, 0u8, 0u8, 21u8, 1u8, 0u8, 0u8, 23u8, 1u8, 0u8, 0u8, 26u8, 1u8,
+            0u8, 0u8, 29u8, 1u8, 0u8, 0u8, 31u8, 1u8, 0u8, 0u8, 36u8, 1u8, 0u8, 0u8, 41u8, 1u8,
+            0u8, 0u8, 43u8, 1u8, 0u8, 0u8, 48u8, 1u8, 0u8, 0u8, 53u8, 1u8, 0u8, 0u8, 55u8, 1u8,
+            0u8, 0u8, 60u8, 1u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8, 65u8, 1u8, 0u8, 0u8, 69u8, 1u8,
+            0u8, 0u8, 71u8, 1u8, 0u8, 0u8, 73u8, 1u8, 0u8, 0u8, 77u8, 1u8, 0u8, 0u8, 79u8, 1u8,
+            0u8, 0u8, 81u8, 1u8, 0u8, 0u8, 85u8, 1u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8, 92u8, 1u8,
+            0u8, 0u8, 96u8, 1u8, 0u8, 0u8, 98u8, 1u8, 0u8, 0u8, 103u8, 1u8, 0u8, 0u8, 107u8, 1u8,
+            0u8, 0u8, 109u8, 1u8, 0u8, 0u8, 114u8, 1u8, 0u8, 0u8, 116u8, 1u8, 0u8, 0u8, 119u8, 1u8,
+            0u8, 0u8, 121u8, 1u8, 0u8, 0u8, 125u8, 1u8, 0u8, 0u8, 127u8, 1u8, 0u8, 0u8, 132u8, 1u8,
+            0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 139u8, 1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 149u8, 1u8,
+            0u8, 0u8, 151u8, 1u8, 0u8, 0u8, 155u8, 1u8, 0u8, 0u8, 157u8, 1u8, 0u8, 0u8, 163u8, 1u8,
+            0u8, 0u8, 165u8, 1u8, 0u8, 0u8, 169u8, 1u8, 0u8, 0u8, 171u8, 1u8, 0u8, 0u8, 176u8, 1u8,
+            0u8, 0u8, 178u8, 1u8, 0u8, 0u8, 182u8, 1u8, 0u8, 0u8, 184u8, 1u8, 0u8, 0u8, 190u8, 1u8,
+            0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 196u8, 1u8, 0u8, 0u8, 198u8, 1u8, 0u8, 0u8, 204u8, 1u8,
+            0u8, 0u8, 206u8, 1u8, 0u8, 0u8, 208u8, 1u8, 0u8, 0u8, 210u8, 1u8, 0u8, 0u8, 215u8, 1u8,
+            0u8, 0u8, 220u8, 1u8, 0u8, 0u8, 224u8, 1u8, 0u8, 0u8, 228u8, 1u8, 0u8, 0u8, 232u8, 1u8,
+            0u8, 0u8, 236u8, 1u8, 0u8, 0u8, 238u8, 1u8, 0u8, 0u8, 240u8, 1u8, 0u8, 0u8, 242u8, 1u8,
+            0u8, 0u8, 244u8, 1u8, 0u8, 0u8, 246u8, 1u8, 0u8, 0u8, 248u8, 1u8, 0u8, 0u8, 250u8, 1u8,
+            0u8, 0u8, 252u8, 1u8, 0u8, 0u8, 254u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 2u8, 2u8,
+            0u8, 0u8, 4u8, 2u8, 0u8, 0u8, 6u8, 2u8, 0u8, 0u8, 8u8, 2u8, 0u8, 0u8, 10u8, 2u8, 0u8,
+            0u8, 12u8, 2u8, 0u8, 0u8, 14u8, 2u8, 0u8, 0u8, 19u8, 2u8, 0u8, 0u8, 21u8, 2u8, 0u8,
+            0u8, 130u8, 178u8, 130u8, 177u8, 102u8, 40u8, 130u8, 54u8, 131u8, 69u8, 102u8, 40u8,
+            129u8, 114u8, 131u8, 88u8, 79u8, 40u8, 129u8, 114u8, 130u8, 10u8, 79u8, 40u8, 129u8,
+            122u8, 130u8, 3u8, 129u8, 250u8, 129u8, 249u8, 128u8, 212u8, 129u8, 138u8, 129u8,
+            143u8, 129u8, 168u8, 129u8, 163u8, 129u8, 175u8, 77u8, 71u8, 90u8, 71u8, 130u8, 66u8,
+            131u8, 96u8, 48u8, 128u8, 185u8, 130u8, 165u8, 48u8, 79u8, 40u8, 48u8, 130u8, 124u8,
+            130u8, 171u8, 130u8, 1u8, 48u8, 71u8, 107u8, 71u8, 131u8, 137u8, 7u8, 128u8, 185u8,
+            130u8, 24u8, 129u8, 182u8, 130u8, 248u8, 129u8, 215u8, 128u8, 162u8, 130u8, 195u8,
+            129u8, 32u8, 74u8, 131u8, 66u8, 128u8, 162u8, 114u8, 129u8, 32u8, 108u8, 131u8, 41u8,
+            129u8, 223u8, 130u8, 129u8, 128u8, 246u8, 129u8, 240u8, 111u8, 96u8, 74u8, 129u8,
+            225u8, 111u8, 96u8, 53u8, 129u8, 226u8, 111u8, 96u8, 75u8, 129u8, 227u8, 111u8, 96u8,
+            89u8, 129u8, 228u8, 130u8, 161u8, 128u8, 212u8, 130u8, 155u8, 131u8, 94u8, 130u8, 81u8,
+            131u8, 93u8, 79u8, 40u8, 129u8, 122u8, 129u8, 171u8, 130u8, 9u8, 128u8, 221u8, 129u8,
+            179u8, 79u8, 40u8, 115u8, 130u8, 2u8, 131u8, 90u8, 131u8, 89u8, 128u8, 246u8, 130u8,
+            7u8, 128u8, 140u8, 66u8, 89u8, 130u8, 25u8, 66u8, 130u8, 36u8, 128u8, 140u8, 66u8,
+            75u8, 130u8, 49u8, 66u8, 130u8, 50u8, 128u8, 140u8, 66u8, 53u8, 130u8, 244u8, 66u8,
+            131u8, 20u8, 128u8, 140u8, 66u8, 74u8, 131u8, 126u8, 66u8, 131u8, 130u8, 131u8, 71u8,
+            129u8, 232u8, 129u8, 231u8, 130u8, 199u8, 77u8, 130u8, 198u8, 129u8, 6u8, 130u8, 187u8,
+            130u8, 233u8, 130u8, 65u8, 128u8, 220u8, 130u8, 210u8, 129u8, 4u8, 129u8, 173u8, 129u8,
+            50u8, 128u8, 220u8, 129u8, 4u8, 130u8, 159u8, 81u8, 130u8, 93u8, 130u8, 166u8, 48u8,
+            130u8, 162u8, 102u8, 40u8, 129u8, 87u8, 93u8, 131u8, 76u8, 79u8, 40u8, 129u8, 87u8,
+            93u8, 130u8, 6u8, 77u8, 71u8, 118u8, 90u8, 71u8, 118u8, 130u8, 68u8, 77u8, 71u8, 2u8,
+            129u8, 16u8, 90u8, 71u8, 2u8, 129u8, 16u8, 130u8, 67u8, 48u8, 71u8, 118u8, 107u8, 71u8,
+            118u8, 131u8, 138u8, 128u8, 151u8, 48u8, 130u8, 31u8, 128u8, 151u8, 48u8, 128u8, 240u8,
+            130u8, 226u8, 128u8, 151u8, 48u8, 129u8, 164u8, 128u8, 151u8, 48u8, 128u8, 208u8,
+            130u8, 227u8, 128u8, 201u8, 48u8, 128u8, 185u8, 128u8, 201u8, 81u8, 130u8, 250u8, 70u8,
+            128u8, 162u8, 53u8, 131u8, 43u8, 131u8, 74u8, 70u8, 128u8, 162u8, 75u8, 131u8, 44u8,
+            131u8, 75u8, 111u8, 96u8, 128u8, 204u8, 129u8, 229u8, 128u8, 154u8, 129u8, 128u8, 74u8,
+            128u8, 154u8, 131u8, 131u8, 130u8, 236u8, 128u8, 154u8, 129u8, 128u8, 53u8, 128u8,
+            154u8, 131u8, 132u8, 130u8, 237u8, 118u8, 131u8, 109u8, 131u8, 84u8, 131u8, 87u8,
+            128u8, 221u8, 77u8, 129u8, 185u8, 129u8, 38u8, 131u8, 141u8, 130u8, 154u8, 102u8, 40u8,
+            129u8, 1u8, 93u8, 102u8, 40u8, 129u8, 73u8, 93u8, 131u8, 72u8, 79u8, 40u8, 129u8, 1u8,
+            93u8, 79u8, 40u8, 129u8, 73u8, 93u8, 130u8, 4u8, 102u8, 40u8, 128u8, 204u8, 131u8,
+            70u8, 70u8, 130u8, 45u8, 77u8, 128u8, 189u8, 131u8, 34u8, 70u8, 77u8, 128u8, 189u8,
+            131u8, 26u8, 96u8, 131u8, 30u8, 128u8, 189u8, 129u8, 219u8, 128u8, 204u8, 131u8, 101u8,
+            131u8, 83u8, 130u8, 189u8, 131u8, 95u8, 128u8, 227u8, 130u8, 194u8, 130u8, 234u8,
+            129u8, 38u8, 130u8, 230u8, 128u8, 215u8, 130u8, 235u8, 128u8, 227u8, 129u8, 150u8,
+            130u8, 160u8, 131u8, 40u8, 1u8, 4u8, 0u8, 128u8, 255u8, 1u8, 5u8, 0u8, 128u8, 255u8,
+            3u8, 9u8, 131u8, 165u8, 3u8, 9u8, 131u8, 168u8, 3u8, 9u8, 131u8, 82u8, 3u8, 9u8, 131u8,
+            164u8, 131u8, 189u8, 131u8, 188u8, 131u8, 186u8, 130u8, 134u8, 131u8, 17u8, 130u8,
+            132u8, 131u8, 15u8, 130u8, 205u8, 130u8, 135u8, 131u8, 18u8, 130u8, 172u8, 130u8,
+            152u8, 131u8, 151u8, 130u8, 133u8, 131u8, 16u8, 130u8, 37u8, 130u8, 206u8, 129u8,
+            177u8, 130u8, 192u8, 31u8, 129u8, 172u8, 131u8, 187u8,
+        ])
+    },
+    loose_order: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            24u8, 5u8, 0u8, 0u8, 23u8, 5u8, 0u8, 0u8, 123u8, 3u8, 0u8, 0u8, 121u8, 3u8, 0u8, 0u8,
+            115u8, 0u8, 0u8, 0u8, 170u8, 3u8, 0u8, 0u8, 21u8, 4u8, 0u8, 0u8, 25u8, 5u8, 0u8, 0u8,
+            83u8, 4u8, 0u8, 0u8, 6u8, 0u8, 0u8, 0u8, 17u8, 4u8, 0u8, 0u8, 163u8, 3u8, 0u8, 0u8,
+            166u8, 5u8, 0u8, 0u8, 7u8, 0u8, 0u8, 0u8, 165u8, 5u8, 0u8, 0u8, 208u8, 3u8, 0u8, 0u8,
+            133u8, 4u8, 0u8, 0u8, 134u8, 4u8, 0u8, 0u8, 128u8, 4u8, 0u8, 0u8, 131u8, 4u8, 0u8, 0u8,
+            132u8, 4u8, 0u8, 0u8, 126u8, 4u8, 0u8, 0u8, 135u8, 4u8, 0u8, 0u8, 136u8, 4u8, 0u8, 0u8,
+            130u8, 4u8, 0u8, 0u8, 215u8, 4u8, 0u8, 0u8, 216u8, 4u8, 0u8, 0u8, 214u8, 4u8, 0u8, 0u8,
+            217u8, 4u8, 0u8, 0u8, 155u8, 4u8, 0u8, 0u8, 154u8, 4u8, 0u8, 0u8, 253u8, 4u8, 0u8, 0u8,
+            252u8, 4u8, 0u8, 0u8, 145u8, 4u8, 0u8, 0u8, 144u8, 4u8, 0u8, 0u8, 149u8, 4u8, 0u8, 0u8,
+            148u8, 4u8, 0u8, 0u8, 143u8, 4u8, 0u8, 0u8, 142u8, 4u8, 0u8, 0u8, 157u8, 4u8, 0u8, 0u8,
+            158u8, 4u8, 0u8, 0u8, 156u8, 4u8, 0u8, 0u8, 159u8, 4u8, 0u8, 0u8, 203u8, 4u8, 0u8, 0u8,
+            204u8, 4u8, 0u8, 0u8, 202u8, 4u8, 0u8, 0u8, 205u8, 4u8, 0u8, 0u8, 183u8, 4u8, 0u8, 0u8,
+            182u8, 4u8, 0u8, 0u8, 223u8, 4u8, 0u8, 0u8, 224u8, 4u8, 0u8, 0u8, 222u8, 4u8, 0u8, 0u8,
+            225u8, 4u8, 0u8, 0u8, 219u8, 4u8, 0u8, 0u8, 220u8, 4u8, 0u8, 0u8, 218u8, 4u8, 0u8, 0u8,
+            221u8, 4u8, 0u8, 0u8, 175u8, 4u8, 0u8, 0u8, 176u8, 4u8, 0u8, 0u8, 174u8, 4u8, 0u8, 0u8,
+            177u8, 4u8, 0u8, 0u8, 141u8, 4u8, 0u8, 0u8, 247u8, 4u8, 0u8, 0u8, 248u8, 4u8, 0u8, 0u8,
+            246u8, 4u8, 0u8, 0u8, 249u8, 4u8, 0u8, 0u8, 171u8, 4u8, 0u8, 0u8, 172u8, 4u8, 0u8, 0u8,
+            170u8, 4u8, 0u8, 0u8, 173u8, 4u8, 0u8, 0u8, 231u8, 4u8, 0u8, 0u8, 232u8, 4u8, 0u8, 0u8,
+            230u8, 4u8, 0u8, 0u8, 233u8, 4u8, 0u8, 0u8, 179u8, 4u8, 0u8, 0u8, 180u8, 4u8, 0u8, 0u8,
+            178u8, 4u8, 0u8, 0u8, 181u8, 4u8, 0u8, 0u8, 235u8, 4u8, 0u8, 0u8, 236u8, 4u8, 0u8, 0u8,
+            234u8, 4u8, 0u8, 0u8, 237u8, 4u8, 0u8, 0u8, 239u8, 4u8, 0u8, 0u8, 240u8, 4u8, 0u8, 0u8,
+            238u8, 4u8, 0u8, 0u8, 241u8, 4u8, 0u8, 0u8, 243u8, 4u8, 0u8, 0u8, 244u8, 4u8, 0u8, 0u8,
+            242u8, 4u8, 0u8, 0u8, 245u8, 4u8, 0u8, 0u8, 227u8, 4u8, 0u8, 0u8, 228u8, 4u8, 0u8, 0u8,
+            226u8, 4u8, 0u8, 0u8, 229u8, 4u8, 0u8, 0u8, 187u8, 4u8, 0u8, 0u8, 186u8, 4u8, 0u8, 0u8,
+            199u8, 4u8, 0u8, 0u8, 200u8, 4u8, 0u8, 0u8, 198u8, 4u8, 0u8, 0u8, 201u8, 4u8, 0u8, 0u8,
+            191u8, 4u8, 0u8, 0u8, 192u8, 4u8, 0u8, 0u8, 190u8, 4u8, 0u8, 0u8, 193u8, 4u8, 0u8, 0u8,
+            195u8, 4u8, 0u8, 0u8, 196u8, 4u8, 0u8, 0u8, 194u8, 4u8, 0u8, 0u8, 197u8, 4u8, 0u8, 0u8,
+            207u8, 4u8, 0u8, 0u8, 208u8, 4u8, 0u8, 0u8, 206u8, 4u8, 0u8, 0u8, 209u8, 4u8, 0u8, 0u8,
+            163u8, 4u8, 0u8, 0u8, 164u8, 4u8, 0u8, 0u8, 162u8, 4u8, 0u8, 0u8, 161u8, 4u8, 0u8, 0u8,
+            160u8, 4u8, 0u8, 0u8, 165u8, 4u8, 0u8, 0u8, 185u8, 4u8, 0u8, 0u8, 184u8, 4u8, 0u8, 0u8,
+            167u8, 4u8, 0u8, 0u8, 168u8, 4u8, 0u8, 0u8, 166u8, 4u8, 0u8, 0u8, 169u8, 4u8, 0u8, 0u8,
+            251u8, 4u8, 0u8, 0u8, 250u8, 4u8, 0u8, 0u8, 147u8, 4u8, 0u8, 0u8, 146u8, 4u8, 0u8, 0u8,
+            255u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 254u8, 4u8, 0u8, 0u8, 1u8, 5u8, 0u8, 0u8,
+            151u8, 4u8, 0u8, 0u8, 152u8, 4u8, 0u8, 0u8, 150u8, 4u8, 0u8, 0u8, 153u8, 4u8, 0u8, 0u8,
+            211u8, 4u8, 0u8, 0u8, 212u8, 4u8, 0u8, 0u8, 210u8, 4u8, 0u8, 0u8, 213u8, 4u8, 0u8, 0u8,
+            189u8, 4u8, 0u8, 0u8, 188u8, 4u8, 0u8, 0u8, 9u8, 5u8, 0u8, 0u8, 8u8, 5u8, 0u8, 0u8,
+            5u8, 5u8, 0u8, 0u8, 4u8, 5u8, 0u8, 0u8, 7u8, 5u8, 0u8, 0u8, 6u8, 5u8, 0u8, 0u8, 3u8,
+            5u8, 0u8, 0u8, 2u8, 5u8, 0u8, 0u8, 137u8, 4u8, 0u8, 0u8, 138u8, 4u8, 0u8, 0u8, 139u8,
+            4u8, 0u8, 0u8, 140u8, 4u8, 0u8, 0u8, 129u8, 4u8, 0u8, 0u8, 127u8, 4u8, 0u8, 0u8, 198u8,
+            3u8, 0u8, 0u8, 136u8, 3u8, 0u8, 0u8, 10u8, 0u8, 0u8, 0u8, 81u8, 3u8, 0u8, 0u8, 96u8,
+            4u8, 0u8, 0u8, 25u8, 4u8, 0u8, 0u8, 27u8, 5u8, 0u8, 0u8, 142u8, 3u8, 0u8, 0u8, 143u8,
+            3u8, 0u8, 0u8, 144u8, 3u8, 0u8, 0u8, 60u8, 4u8, 0u8, 0u8, 233u8, 3u8, 0u8, 0u8, 234u8,
+            3u8, 0u8, 0u8, 26u8, 5u8, 0u8, 0u8, 161u8, 3u8, 0u8, 0u8, 219u8, 3u8, 0u8, 0u8, 216u8,
+            3u8, 0u8, 0u8, 220u8, 3u8, 0u8, 0u8, 221u8, 3u8, 0u8, 0u8, 217u8, 3u8, 0u8, 0u8, 218u8,
+            3u8, 0u8, 0u8, 7u8, 4u8, 0u8, 0u8, 6u8, 4u8, 0u8, 0u8, 225u8, 3u8, 0u8, 0u8, 102u8,
+            4u8, 0u8, 0u8, 228u8, 3u8, 0u8, 0u8, 65u8, 4u8, 0u8, 0u8, 64u8, 4u8, 0u8, 0u8, 15u8,
+            4u8, 0u8, 0u8, 227u8, 3u8, 0u8, 0u8, 86u8, 4u8, 0u8, 0u8, 152u8, 3u8, 0u8, 0u8, 91u8,
+            3u8, 0u8, 0u8, 54u8, 4u8, 0u8, 0u8, 153u8, 3u8, 0u8, 0u8, 92u8, 3u8, 0u8, 0u8, 149u8,
+            3u8, 0u8, 0u8, 185u8, 3u8, 0u8, 0u8, 69u8, 4u8, 0u8, 0u8, 222u8, 3u8, 0u8, 0u8, 131u8,
+            3u8, 0u8, 0u8, 126u8, 3u8, 0u8, 0u8, 140u8, 3u8, 0u8, 0u8, 93u8, 4u8, 0u8, 0u8, 84u8,
+            4u8, 0u8, 0u8, 249u8, 3u8, 0u8, 0u8, 193u8, 5u8, 0u8, 0u8, 96u8, 5u8, 0u8, 0u8, 95u8,
+            5u8, 0u8, 0u8, 101u8, 0u8, 0u8, 0u8, 28u8, 5u8, 0u8, 0u8, 49u8, 3u8, 0u8, 0u8, 192u8,
+            5u8, 0u8, 0u8, 162u8, 3u8, 0u8, 0u8, 69u8, 5u8, 0u8, 0u8, 68u8, 5u8, 0u8, 0u8, 141u8,
+            5u8, 0u8, 0u8, 201u8, 3u8, 0u8, 0u8, 207u8, 3u8, 0u8, 0u8, 71u8, 3u8, 0u8, 0u8, 80u8,
+            3u8, 0u8, 0u8, 44u8, 5u8, 0u8, 0u8, 78u8, 4u8, 0u8, 0u8, 105u8, 4u8, 0u8, 0u8, 159u8,
+            3u8, 0u8, 0u8, 142u8, 5u8, 0u8, 0u8, 119u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 49u8,
+            4u8, 0u8, 0u8, 81u8, 4u8, 0u8, 0u8, 29u8, 5u8, 0u8, 0u8, 107u8, 5u8, 0u8, 0u8, 110u8,
+            5u8, 0u8, 0u8, 79u8, 3u8, 0u8, 0u8, 165u8, 3u8, 0u8, 0u8, 53u8, 4u8, 0u8, 0u8, 104u8,
+            4u8, 0u8, 0u8, 80u8, 4u8, 0u8, 0u8, 62u8, 0u8, 0u8, 0u8, 95u8, 3u8, 0u8, 0u8, 127u8,
+            3u8, 0u8, 0u8, 46u8, 4u8, 0u8, 0u8, 26u8, 0u8, 0u8, 0u8, 130u8, 3u8, 0u8, 0u8, 12u8,
+            0u8, 0u8, 0u8, 32u8, 0u8, 0u8, 0u8, 97u8, 3u8, 0u8, 0u8, 138u8, 3u8, 0u8, 0u8, 157u8,
+            5u8, 0u8, 0u8, 104u8, 0u8, 0u8, 0u8, 45u8, 5u8, 0u8, 0u8, 18u8, 4u8, 0u8, 0u8, 74u8,
+            4u8, 0u8, 0u8, 167u8, 3u8, 0u8, 0u8, 166u8, 3u8, 0u8, 0u8, 158u8, 5u8, 0u8, 0u8, 124u8,
+            4u8, 0u8, 0u8, 99u8, 0u8, 0u8, 0u8, 47u8, 3u8, 0u8, 0u8, 52u8, 5u8, 0u8, 0u8, 55u8,
+            5u8, 0u8, 0u8, 57u8, 5u8, 0u8, 0u8, 59u8, 5u8, 0u8, 0u8, 61u8, 5u8, 0u8, 0u8, 132u8,
+            5u8, 0u8, 0u8, 111u8, 0u8, 0u8, 0u8, 90u8, 5u8, 0u8, 0u8, 89u8, 5u8, 0u8, 0u8, 137u8,
+            3u8, 0u8, 0u8, 60u8, 5u8, 0u8, 0u8, 54u8, 5u8, 0u8, 0u8, 131u8, 5u8, 0u8, 0u8, 58u8,
+            5u8, 0u8, 0u8, 56u8, 5u8, 0u8, 0u8, 103u8, 0u8, 0u8, 0u8, 254u8, 3u8, 0u8, 0u8, 255u8,
+            3u8, 0u8, 0u8, 0u8, 4u8, 0u8, 0u8, 1u8, 4u8, 0u8, 0u8, 2u8, 4u8, 0u8, 0u8, 3u8, 4u8,
+            0u8, 0u8, 24u8, 0u8, 0u8, 0u8, 21u8, 0u8, 0u8, 0u8, 20u8, 0u8, 0u8, 0u8, 25u8, 0u8,
+            0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 23u8, 0u8, 0u8, 0u8, 22u8, 0u8, 0u8, 0u8, 19u8, 0u8,
+            0u8, 0u8, 18u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 0u8, 10u8, 4u8, 0u8, 0u8, 13u8, 4u8,
+            0u8, 0u8, 12u8, 4u8, 0u8, 0u8, 11u8, 4u8, 0u8, 0u8, 75u8, 4u8, 0u8, 0u8, 182u8, 0u8,
+            0u8, 0u8, 44u8, 4u8, 0u8, 0u8, 53u8, 5u8, 0u8, 0u8, 4u8, 0u8, 0u8, 0u8, 107u8, 3u8,
+            0u8, 0u8, 48u8, 3u8, 0u8, 0u8, 32u8, 4u8, 0u8, 0u8, 33u8, 4u8, 0u8, 0u8, 75u8, 3u8,
+            0u8, 0u8, 46u8, 3u8, 0u8, 0u8, 45u8, 3u8, 0u8, 0u8, 38u8, 3u8, 0u8, 0u8, 66u8, 3u8,
+            0u8, 0u8, 86u8, 3u8, 0u8, 0u8, 37u8, 3u8, 0u8, 0u8, 88u8, 4u8, 0u8, 0u8, 191u8, 3u8,
+            0u8, 0u8, 239u8, 3u8, 0u8, 0u8, 232u8, 3u8, 0u8, 0u8, 35u8, 3u8, 0u8, 0u8, 16u8, 3u8,
+            0u8, 0u8, 18u8, 3u8, 0u8, 0u8, 34u8, 3u8, 0u8, 0u8, 148u8, 5u8, 0u8, 0u8, 35u8, 5u8,
+            0u8, 0u8, 70u8, 5u8, 0u8, 0u8, 149u8, 5u8, 0u8, 0u8, 105u8, 5u8, 0u8, 0u8, 17u8, 5u8,
+            0u8, 0u8, 19u8, 5u8, 0u8, 0u8, 66u8, 5u8, 0u8, 0u8, 22u8, 5u8, 0u8, 0u8, 15u8, 3u8,
+            0u8, 0u8, 21u8, 5u8, 0u8, 0u8, 17u8, 3u8, 0u8, 0u8, 38u8, 5u8, 0u8, 0u8, 71u8, 5u8,
+            0u8, 0u8, 20u8, 5u8, 0u8, 0u8, 150u8, 5u8, 0u8, 0u8, 29u8, 0u8, 0u8, 0u8, 106u8, 5u8,
+            0u8, 0u8, 75u8, 5u8, 0u8, 0u8, 74u8, 5u8, 0u8, 0u8, 67u8, 5u8, 0u8, 0u8, 18u8, 5u8,
+            0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 88u8, 3u8, 0u8, 0u8, 92u8, 4u8, 0u8, 0u8, 169u8, 3u8,
+            0u8, 0u8, 190u8, 3u8, 0u8, 0u8, 105u8, 0u8, 0u8, 0u8, 114u8, 4u8, 0u8, 0u8, 43u8, 5u8,
+            0u8, 0u8, 33u8, 3u8, 0u8, 0u8, 22u8, 3u8, 0u8, 0u8, 77u8, 5u8, 0u8, 0u8, 187u8, 3u8,
+            0u8, 0u8, 118u8, 3u8, 0u8, 0u8, 104u8, 3u8, 0u8, 0u8, 113u8, 4u8, 0u8, 0u8, 26u8, 4u8,
+            0u8, 0u8, 22u8, 4u8, 0u8, 0u8, 100u8, 3u8, 0u8, 0u8, 42u8, 5u8, 0u8, 0u8, 112u8, 4u8,
+            0u8, 0u8, 106u8, 3u8, 0u8, 0u8, 103u8, 3u8, 0u8, 0u8, 20u8, 3u8, 0u8, 0u8, 83u8, 3u8,
+            0u8, 0u8, 78u8, 5u8, 0u8, 0u8, 190u8, 5u8, 0u8, 0u8, 123u8, 4u8, 0u8, 0u8, 14u8, 0u8,
+            0u8, 0u8, 112u8, 3u8, 0u8, 0u8, 47u8, 4u8, 0u8, 0u8, 23u8, 4u8, 0u8, 0u8, 108u8, 4u8,
+            0u8, 0u8, 107u8, 4u8, 0u8, 0u8, 200u8, 3u8, 0u8, 0u8, 64u8, 0u8, 0u8, 0u8, 30u8, 0u8,
+            0u8, 0u8, 80u8, 5u8, 0u8, 0u8, 81u8, 5u8, 0u8, 0u8, 25u8, 3u8, 0u8, 0u8, 16u8, 4u8,
+            0u8, 0u8, 171u8, 3u8, 0u8, 0u8, 100u8, 2u8, 0u8, 0u8, 95u8, 2u8, 0u8, 0u8, 77u8, 2u8,
+            0u8, 0u8, 96u8, 2u8, 0u8, 0u8, 78u8, 2u8, 0u8, 0u8, 29u8, 2u8, 0u8, 0u8, 98u8, 2u8,
+            0u8, 0u8, 97u8, 2u8, 0u8, 0u8, 99u8, 2u8, 0u8, 0u8, 93u8, 2u8, 0u8, 0u8, 75u8, 2u8,
+            0u8, 0u8, 94u8, 2u8, 0u8, 0u8, 76u8, 2u8, 0u8, 0u8, 27u8, 2u8, 0u8, 0u8, 92u8, 2u8,
+            0u8, 0u8, 110u8, 2u8, 0u8, 0u8, 33u8, 2u8, 0u8, 0u8, 26u8, 2u8, 0u8, 0u8, 88u8, 2u8,
+            0u8, 0u8, 87u8, 2u8, 0u8, 0u8, 80u8, 2u8, 0u8, 0u8, 82u8, 2u8, 0u8, 0u8, 85u8, 2u8,
+            0u8, 0u8, 90u8, 2u8, 0u8, 0u8, 83u8, 2u8, 0u8, 0u8, 84u8, 2u8, 0u8, 0u8, 89u8, 2u8,
+            0u8, 0u8, 81u8, 2u8, 0u8, 0u8, 44u8, 2u8, 0u8, 0u8, 102u8, 2u8, 0u8, 0u8, 58u8, 2u8,
+            0u8, 0u8, 42u8, 2u8, 0u8, 0u8, 59u8, 2u8, 0u8, 0u8, 30u8, 2u8, 0u8, 0u8, 15u8, 2u8,
+            0u8, 0u8, 105u8, 2u8, 0u8, 0u8, 21u8, 2u8, 0u8, 0u8, 43u8, 2u8, 0u8, 0u8, 17u8, 2u8,
+            0u8, 0u8, 107u8, 2u8, 0u8, 0u8, 108u8, 2u8, 0u8, 0u8, 34u8, 2u8, 0u8, 0u8, 37u8, 2u8,
+            0u8, 0u8, 106u8, 2u8, 0u8, 0u8, 36u8, 2u8, 0u8, 0u8, 79u8, 2u8, 0u8, 0u8, 32u8, 2u8,
+            0u8, 0u8, 101u8, 2u8, 0u8, 0u8, 22u8, 2u8, 0u8, 0u8, 55u8, 2u8, 0u8, 0u8, 54u8, 2u8,
+            0u8, 0u8, 45u8, 2u8, 0u8, 0u8, 46u8, 2u8, 0u8, 0u8, 57u8, 2u8, 0u8, 0u8, 48u8, 2u8,
+            0u8, 0u8, 53u8, 2u8, 0u8, 0u8, 49u8, 2u8, 0u8, 0u8, 51u8, 2u8, 0u8, 0u8, 50u8, 2u8,
+            0u8, 0u8, 52u8, 2u8, 0u8, 0u8, 56u8, 2u8, 0u8, 0u8, 47u8, 2u8, 0u8, 0u8, 20u8, 2u8,
+            0u8, 0u8, 41u8, 2u8, 0u8, 0u8, 39u8, 2u8, 0u8, 0u8, 24u8, 2u8, 0u8, 0u8, 70u8, 2u8,
+            0u8, 0u8, 69u8, 2u8, 0u8, 0u8, 74u8, 2u8, 0u8, 0u8, 68u8, 2u8, 0u8, 0u8, 71u8, 2u8,
+            0u8, 0u8, 60u8, 2u8, 0u8, 0u8, 64u8, 2u8, 0u8, 0u8, 61u8, 2u8, 0u8, 0u8, 73u8, 2u8,
+            0u8, 0u8, 65u8, 2u8, 0u8, 0u8, 66u8, 2u8, 0u8, 0u8, 63u8, 2u8, 0u8, 0u8, 67u8, 2u8,
+            0u8, 0u8, 72u8, 2u8, 0u8, 0u8, 62u8, 2u8, 0u8, 0u8, 28u8, 2u8, 0u8, 0u8, 103u8, 2u8,
+            0u8, 0u8, 86u8, 2u8, 0u8, 0u8, 16u8, 2u8, 0u8, 0u8, 35u8, 2u8, 0u8, 0u8, 23u8, 2u8,
+            0u8, 0u8, 40u8, 2u8, 0u8, 0u8, 25u8, 2u8, 0u8, 0u8, 19u8, 2u8, 0u8, 0u8, 31u8, 2u8,
+            0u8, 0u8, 18u8, 2u8, 0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 109u8, 2u8, 0u8, 0u8, 104u8, 2u8,
+            0u8, 0u8, 91u8, 2u8, 0u8, 0u8, 205u8, 2u8, 0u8, 0u8, 204u8, 2u8, 0u8, 0u8, 209u8, 2u8,
+            0u8, 0u8, 6u8, 3u8, 0u8, 0u8, 4u8, 3u8, 0u8, 0u8, 7u8, 3u8, 0u8, 0u8, 5u8, 3u8, 0u8,
+            0u8, 203u8, 2u8, 0u8, 0u8, 254u8, 2u8, 0u8, 0u8, 251u8, 2u8, 0u8, 0u8, 252u8, 2u8, 0u8,
+            0u8, 241u8, 2u8, 0u8, 0u8, 3u8, 3u8, 0u8, 0u8, 245u8, 2u8, 0u8, 0u8, 206u8, 2u8, 0u8,
+            0u8, 183u8, 2u8, 0u8, 0u8, 11u8, 3u8, 0u8, 0u8, 13u8, 3u8, 0u8, 0u8, 12u8, 3u8, 0u8,
+            0u8, 9u8, 3u8, 0u8, 0u8, 10u8, 3u8, 0u8, 0u8, 210u8, 2u8, 0u8, 0u8, 185u8, 2u8, 0u8,
+            0u8, 211u8, 2u8, 0u8, 0u8, 198u8, 2u8, 0u8, 0u8, 239u8, 2u8, 0u8, 0u8, 240u8, 2u8, 0u8,
+            0u8, 233u8, 2u8, 0u8, 0u8, 238u8, 2u8, 0u8, 0u8, 235u8, 2u8, 0u8, 0u8, 234u8, 2u8, 0u8,
+            0u8, 236u8, 2u8, 0u8, 0u8, 237u8, 2u8, 0u8, 0u8, 186u8, 2u8, 0u8, 0u8, 187u8, 2u8, 0u8,
+            0u8, 188u8, 2u8, 0u8, 0u8, 212u8, 2u8, 0u8, 0u8, 215u8, 2u8, 0u8, 0u8, 214u8, 2u8, 0u8,
+            0u8, 216u8, 2u8, 0u8, 0u8, 213u8, 2u8, 0u8, 0u8, 250u8, 2u8, 0u8, 0u8, 208u8, 2u8, 0u8,
+            0u8, 2u8, 3u8, 0u8, 0u8, 199u8, 2u8, 0u8, 0u8, 244u8, 2u8, 0u8, 0u8, 243u8, 2u8, 0u8,
+            0u8, 242u8, 2u8, 0u8, 0u8, 200u8, 2u8, 0u8, 0u8, 190u8, 2u8, 0u8, 0u8, 197u8, 2u8, 0u8,
+            0u8, 228u8, 2u8, 0u8, 0u8, 231u8, 2u8, 0u8, 0u8, 191u8, 2u8, 0u8, 0u8, 219u8, 2u8, 0u8,
+            0u8, 192u8, 2u8, 0u8, 0u8, 224u8, 2u8, 0u8, 0u8, 225u8, 2u8, 0u8, 0u8, 220u8, 2u8, 0u8,
+            0u8, 230u8, 2u8, 0u8, 0u8, 196u8, 2u8, 0u8, 0u8, 193u8, 2u8, 0u8, 0u8, 227u8, 2u8, 0u8,
+            0u8, 226u8, 2u8, 0u8, 0u8, 194u8, 2u8, 0u8, 0u8, 229u8, 2u8, 0u8, 0u8, 195u8, 2u8, 0u8,
+            0u8, 221u8, 2u8, 0u8, 0u8, 222u8, 2u8, 0u8, 0u8, 232u8, 2u8, 0u8, 0u8, 201u8, 2u8, 0u8,
+            0u8, 246u8, 2u8, 0u8, 0u8, 249u8, 2u8, 0u8, 0u8, 248u8, 2u8, 0u8, 0u8, 247u8, 2u8, 0u8,
+            0u8, 253u8, 2u8, 0u8, 0u8, 184u8, 2u8, 0u8, 0u8, 14u8, 3u8, 0u8, 0u8, 223u8, 2u8, 0u8,
+            0u8, 202u8, 2u8, 0u8, 0u8, 173u8, 5u8, 0u8, 0u8, 207u8, 2u8, 0u8, 0u8, 189u8, 2u8, 0u8,
+            0u8, 217u8, 2u8, 0u8, 0u8, 218u8, 2u8, 0u8, 0u8, 8u8, 3u8, 0u8, 0u8, 255u8, 2u8, 0u8,
+            0u8, 174u8, 5u8, 0u8, 0u8, 171u8, 5u8, 0u8, 0u8, 1u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+            0u8, 172u8, 5u8, 0u8, 0u8, 112u8, 2u8, 0u8, 0u8, 113u8, 2u8, 0u8, 0u8, 178u8, 2u8, 0u8,
+            0u8, 133u8, 2u8, 0u8, 0u8, 173u8, 2u8, 0u8, 0u8, 174u8, 2u8, 0u8, 0u8, 176u8, 2u8, 0u8,
+            0u8, 175u8, 2u8, 0u8, 0u8, 134u8, 2u8, 0u8, 0u8, 117u8, 2u8, 0u8, 0u8, 116u8, 2u8, 0u8,
+            0u8, 139u8, 2u8, 0u8, 0u8, 137u8, 2u8, 0u8, 0u8, 138u8, 2u8, 0u8, 0u8, 130u8, 2u8, 0u8,
+            0u8, 165u8, 2u8, 0u8, 0u8, 164u8, 2u8, 0u8, 0u8, 111u8, 2u8, 0u8, 0u8, 132u8, 2u8, 0u8,
+            0u8, 167u8, 2u8, 0u8, 0u8, 172u8, 2u8, 0u8, 0u8, 171u8, 2u8, 0u8, 0u8, 169u8, 2u8, 0u8,
+            0u8, 170u8, 2u8, 0u8, 0u8, 168u8, 2u8, 0u8, 0u8, 120u8, 2u8, 0u8, 0u8, 143u8, 2u8, 0u8,
+            0u8, 123u8, 2u8, 0u8, 0u8, 142u8, 2u8, 0u8, 0u8, 145u8, 2u8, 0u8, 0u8, 146u8, 2u8, 0u8,
+            0u8, 181u8, 2u8, 0u8, 0u8, 182u8, 2u8, 0u8, 0u8, 144u8, 2u8, 0u8, 0u8, 177u8, 2u8, 0u8,
+            0u8, 125u8, 2u8, 0u8, 0u8, 152u8, 2u8, 0u8, 0u8, 153u8, 2u8, 0u8, 0u8, 154u8, 2u8, 0u8,
+            0u8, 156u8, 2u8, 0u8, 0u8, 155u8, 2u8, 0u8, 0u8, 121u8, 2u8, 0u8, 0u8, 122u8, 2u8, 0u8,
+            0u8, 127u8, 2u8, 0u8, 0u8, 126u8, 2u8, 0u8, 0u8, 128u8, 2u8, 0u8, 0u8, 114u8, 2u8, 0u8,
+            0u8, 115u8, 2u8, 0u8, 0u8, 135u8, 2u8, 0u8, 0u8, 179u8, 2u8, 0u8, 0u8, 136u8, 2u8, 0u8,
+            0u8, 119u8, 2u8, 0u8, 0u8, 118u8, 2u8, 0u8, 0u8, 140u8, 2u8, 0u8, 0u8, 141u8, 2u8, 0u8,
+            0u8, 180u8, 2u8, 0u8, 0u8, 131u8, 2u8, 0u8, 0u8, 166u8, 2u8, 0u8, 0u8, 124u8, 2u8, 0u8,
+            0u8, 151u8, 2u8, 0u8, 0u8, 150u8, 2u8, 0u8, 0u8, 147u8, 2u8, 0u8, 0u8, 148u8, 2u8, 0u8,
+            0u8, 149u8, 2u8, 0u8, 0u8, 129u8, 2u8, 0u8, 0u8, 157u8, 2u8, 0u8, 0u8, 159u8, 2u8, 0u8,
+            0u8, 158u8, 2u8, 0u8, 0u8, 163u8, 2u8, 0u8, 0u8, 162u8, 2u8, 0u8, 0u8, 161u8, 2u8, 0u8,
+            0u8, 160u8, 2u8, 0u8, 0u8, 122u8, 4u8, 0u8, 0u8, 97u8, 4u8, 0u8, 0u8, 89u8, 4u8, 0u8,
+            0u8, 79u8, 4u8, 0u8, 0u8, 93u8, 5u8, 0u8, 0u8, 31u8, 4u8, 0u8, 0u8, 106u8, 4u8, 0u8,
+            0u8, 94u8, 5u8, 0u8, 0u8, 36u8, 3u8, 0u8, 0u8, 53u8, 3u8, 0u8, 0u8, 39u8, 4u8, 0u8,
+            0u8, 30u8, 5u8, 0u8, 0u8, 108u8, 5u8, 0u8, 0u8, 111u8, 5u8, 0u8, 0u8, 147u8, 3u8, 0u8,
+            0u8, 230u8, 3u8, 0u8, 0u8, 31u8, 5u8, 0u8, 0u8, 112u8, 5u8, 0u8, 0u8, 109u8, 5u8, 0u8,
+            0u8, 31u8, 3u8, 0u8, 0u8, 54u8, 3u8, 0u8, 0u8, 82u8, 3u8, 0u8, 0u8, 13u8, 0u8, 0u8,
+            0u8, 118u8, 4u8, 0u8, 0u8, 100u8, 5u8, 0u8, 0u8, 99u8, 5u8, 0u8, 0u8, 76u8, 5u8, 0u8,
+            0u8, 85u8, 5u8, 0u8, 0u8, 79u8, 5u8, 0u8, 0u8, 82u8, 5u8, 0u8, 0u8, 122u8, 3u8, 0u8,
+            0u8, 120u8, 3u8, 0u8, 0u8, 34u8, 4u8, 0u8, 0u8, 76u8, 3u8, 0u8, 0u8, 96u8, 0u8, 0u8,
+            0u8, 101u8, 4u8, 0u8, 0u8, 126u8, 0u8, 0u8, 0u8, 99u8, 3u8, 0u8, 0u8, 115u8, 3u8, 0u8,
+            0u8, 114u8, 3u8, 0u8, 0u8, 113u8, 3u8, 0u8, 0u8, 121u8, 4u8, 0u8, 0u8, 51u8, 4u8, 0u8,
+            0u8, 193u8, 3u8, 0u8, 0u8, 188u8, 3u8, 0u8, 0u8, 33u8, 0u8, 0u8, 0u8, 133u8, 0u8, 0u8,
+            0u8, 187u8, 1u8, 0u8, 0u8, 161u8, 1u8, 0u8, 0u8, 72u8, 1u8, 0u8, 0u8, 128u8, 0u8, 0u8,
+            0u8, 193u8, 0u8, 0u8, 0u8, 140u8, 1u8, 0u8, 0u8, 129u8, 0u8, 0u8, 0u8, 131u8, 0u8, 0u8,
+            0u8, 157u8, 1u8, 0u8, 0u8, 229u8, 1u8, 0u8, 0u8, 159u8, 1u8, 0u8, 0u8, 191u8, 1u8, 0u8,
+            0u8, 127u8, 0u8, 0u8, 0u8, 193u8, 1u8, 0u8, 0u8, 191u8, 0u8, 0u8, 0u8, 195u8, 0u8, 0u8,
+            0u8, 132u8, 0u8, 0u8, 0u8, 185u8, 1u8, 0u8, 0u8, 249u8, 1u8, 0u8, 0u8, 130u8, 0u8, 0u8,
+            0u8, 34u8, 0u8, 0u8, 0u8, 64u8, 1u8, 0u8, 0u8, 2u8, 2u8, 0u8, 0u8, 65u8, 1u8, 0u8, 0u8,
+            35u8, 0u8, 0u8, 0u8, 197u8, 0u8, 0u8, 0u8, 203u8, 0u8, 0u8, 0u8, 134u8, 0u8, 0u8, 0u8,
+            199u8, 0u8, 0u8, 0u8, 201u8, 0u8, 0u8, 0u8, 70u8, 1u8, 0u8, 0u8, 250u8, 1u8, 0u8, 0u8,
+            36u8, 0u8, 0u8, 0u8, 205u8, 0u8, 0u8, 0u8, 73u8, 1u8, 0u8, 0u8, 177u8, 1u8, 0u8, 0u8,
+            132u8, 1u8, 0u8, 0u8, 207u8, 0u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 176u8, 1u8, 0u8, 0u8,
+            131u8, 1u8, 0u8, 0u8, 37u8, 0u8, 0u8, 0u8, 9u8, 1u8, 0u8, 0u8, 104u8, 1u8, 0u8, 0u8,
+            143u8, 0u8, 0u8, 0u8, 136u8, 0u8, 0u8, 0u8, 211u8, 0u8, 0u8, 0u8, 217u8, 0u8, 0u8, 0u8,
+            231u8, 1u8, 0u8, 0u8, 137u8, 0u8, 0u8, 0u8, 138u8, 0u8, 0u8, 0u8, 213u8, 0u8, 0u8, 0u8,
+            195u8, 1u8, 0u8, 0u8, 135u8, 0u8, 0u8, 0u8, 197u8, 1u8, 0u8, 0u8, 209u8, 0u8, 0u8, 0u8,
+            215u8, 0u8, 0u8, 0u8, 5u8, 2u8, 0u8, 0u8, 118u8, 1u8, 0u8, 0u8, 119u8, 1u8, 0u8, 0u8,
+            173u8, 1u8, 0u8, 0u8, 38u8, 0u8, 0u8, 0u8, 80u8, 1u8, 0u8, 0u8, 39u8, 0u8, 0u8, 0u8,
+            83u8, 1u8, 0u8, 0u8, 169u8, 5u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 179u8, 1u8, 0u8, 0u8,
+            221u8, 0u8, 0u8, 0u8, 165u8, 1u8, 0u8, 0u8, 225u8, 0u8, 0u8, 0u8, 219u8, 0u8, 0u8, 0u8,
+            223u8, 0u8, 0u8, 0u8, 82u8, 1u8, 0u8, 0u8, 163u8, 1u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8,
+            181u8, 1u8, 0u8, 0u8, 221u8, 1u8, 0u8, 0u8, 227u8, 0u8, 0u8, 0u8, 229u8, 0u8, 0u8, 0u8,
+            41u8, 0u8, 0u8, 0u8, 85u8, 1u8, 0u8, 0u8, 140u8, 0u8, 0u8, 0u8, 235u8, 0u8, 0u8, 0u8,
+            142u8, 1u8, 0u8, 0u8, 141u8, 0u8, 0u8, 0u8, 142u8, 0u8, 0u8, 0u8, 239u8, 0u8, 0u8, 0u8,
+            199u8, 1u8, 0u8, 0u8, 139u8, 0u8, 0u8, 0u8, 201u8, 1u8, 0u8, 0u8, 233u8, 0u8, 0u8, 0u8,
+            237u8, 0u8, 0u8, 0u8, 86u8, 1u8, 0u8, 0u8, 231u8, 0u8, 0u8, 0u8, 42u8, 0u8, 0u8, 0u8,
+            243u8, 0u8, 0u8, 0u8, 7u8, 2u8, 0u8, 0u8, 43u8, 0u8, 0u8, 0u8, 167u8, 1u8, 0u8, 0u8,
+            245u8, 0u8, 0u8, 0u8, 87u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 0u8, 134u8, 1u8, 0u8, 0u8,
+            248u8, 0u8, 0u8, 0u8, 252u8, 1u8, 0u8, 0u8, 252u8, 0u8, 0u8, 0u8, 250u8, 0u8, 0u8, 0u8,
+            254u8, 0u8, 0u8, 0u8, 135u8, 1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8,
+            46u8, 0u8, 0u8, 0u8, 137u8, 1u8, 0u8, 0u8, 2u8, 1u8, 0u8, 0u8, 6u8, 1u8, 0u8, 0u8, 4u8,
+            1u8, 0u8, 0u8, 183u8, 1u8, 0u8, 0u8, 92u8, 1u8, 0u8, 0u8, 223u8, 1u8, 0u8, 0u8, 138u8,
+            1u8, 0u8, 0u8, 144u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8, 0u8, 97u8, 1u8, 0u8, 0u8, 79u8,
+            1u8, 0u8, 0u8, 69u8, 1u8, 0u8, 0u8, 225u8, 1u8, 0u8, 0u8, 146u8, 0u8, 0u8, 0u8, 13u8,
+            1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 147u8, 0u8, 0u8, 0u8, 149u8, 0u8, 0u8, 0u8, 233u8,
+            1u8, 0u8, 0u8, 237u8, 1u8, 0u8, 0u8, 239u8, 1u8, 0u8, 0u8, 15u8, 1u8, 0u8, 0u8, 203u8,
+            1u8, 0u8, 0u8, 145u8, 0u8, 0u8, 0u8, 95u8, 1u8, 0u8, 0u8, 205u8, 1u8, 0u8, 0u8, 11u8,
+            1u8, 0u8, 0u8, 94u8, 1u8, 0u8, 0u8, 169u8, 1u8, 0u8, 0u8, 171u8, 1u8, 0u8, 0u8, 151u8,
+            0u8, 0u8, 0u8, 189u8, 1u8, 0u8, 0u8, 148u8, 0u8, 0u8, 0u8, 235u8, 1u8, 0u8, 0u8, 48u8,
+            0u8, 0u8, 0u8, 99u8, 1u8, 0u8, 0u8, 49u8, 0u8, 0u8, 0u8, 50u8, 0u8, 0u8, 0u8, 77u8,
+            1u8, 0u8, 0u8, 19u8, 1u8, 0u8, 0u8, 23u8, 1u8, 0u8, 0u8, 21u8, 1u8, 0u8, 0u8, 207u8,
+            1u8, 0u8, 0u8, 209u8, 1u8, 0u8, 0u8, 11u8, 2u8, 0u8, 0u8, 51u8, 0u8, 0u8, 0u8, 78u8,
+            1u8, 0u8, 0u8, 9u8, 2u8, 0u8, 0u8, 25u8, 1u8, 0u8, 0u8, 31u8, 1u8, 0u8, 0u8, 29u8, 1u8,
+            0u8, 0u8, 27u8, 1u8, 0u8, 0u8, 215u8, 1u8, 0u8, 0u8, 52u8, 0u8, 0u8, 0u8, 157u8, 0u8,
+            0u8, 0u8, 123u8, 1u8, 0u8, 0u8, 67u8, 1u8, 0u8, 0u8, 102u8, 1u8, 0u8, 0u8, 91u8, 1u8,
+            0u8, 0u8, 4u8, 2u8, 0u8, 0u8, 35u8, 1u8, 0u8, 0u8, 33u8, 1u8, 0u8, 0u8, 217u8, 1u8,
+            0u8, 0u8, 253u8, 1u8, 0u8, 0u8, 107u8, 1u8, 0u8, 0u8, 109u8, 1u8, 0u8, 0u8, 37u8, 1u8,
+            0u8, 0u8, 53u8, 0u8, 0u8, 0u8, 3u8, 2u8, 0u8, 0u8, 112u8, 1u8, 0u8, 0u8, 153u8, 0u8,
+            0u8, 0u8, 43u8, 1u8, 0u8, 0u8, 146u8, 1u8, 0u8, 0u8, 154u8, 0u8, 0u8, 0u8, 155u8, 0u8,
+            0u8, 0u8, 150u8, 1u8, 0u8, 0u8, 152u8, 1u8, 0u8, 0u8, 154u8, 1u8, 0u8, 0u8, 148u8, 1u8,
+            0u8, 0u8, 47u8, 1u8, 0u8, 0u8, 211u8, 1u8, 0u8, 0u8, 152u8, 0u8, 0u8, 0u8, 110u8, 1u8,
+            0u8, 0u8, 213u8, 1u8, 0u8, 0u8, 41u8, 1u8, 0u8, 0u8, 49u8, 1u8, 0u8, 0u8, 45u8, 1u8,
+            0u8, 0u8, 39u8, 1u8, 0u8, 0u8, 54u8, 0u8, 0u8, 0u8, 113u8, 1u8, 0u8, 0u8, 55u8, 0u8,
+            0u8, 0u8, 51u8, 1u8, 0u8, 0u8, 182u8, 1u8, 0u8, 0u8, 56u8, 0u8, 0u8, 0u8, 57u8, 0u8,
+            0u8, 0u8, 219u8, 1u8, 0u8, 0u8, 156u8, 0u8, 0u8, 0u8, 53u8, 1u8, 0u8, 0u8, 55u8, 1u8,
+            0u8, 0u8, 114u8, 1u8, 0u8, 0u8, 241u8, 1u8, 0u8, 0u8, 13u8, 2u8, 0u8, 0u8, 58u8, 0u8,
+            0u8, 0u8, 56u8, 1u8, 0u8, 0u8, 60u8, 1u8, 0u8, 0u8, 58u8, 1u8, 0u8, 0u8, 227u8, 1u8,
+            0u8, 0u8, 116u8, 1u8, 0u8, 0u8, 241u8, 0u8, 0u8, 0u8, 17u8, 1u8, 0u8, 0u8, 129u8, 1u8,
+            0u8, 0u8, 127u8, 1u8, 0u8, 0u8, 125u8, 1u8, 0u8, 0u8, 128u8, 1u8, 0u8, 0u8, 130u8, 1u8,
+            0u8, 0u8, 105u8, 1u8, 0u8, 0u8, 122u8, 1u8, 0u8, 0u8, 126u8, 1u8, 0u8, 0u8, 101u8, 1u8,
+            0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 165u8, 0u8, 0u8, 0u8, 188u8, 1u8, 0u8, 0u8, 162u8, 1u8,
+            0u8, 0u8, 160u8, 0u8, 0u8, 0u8, 194u8, 0u8, 0u8, 0u8, 141u8, 1u8, 0u8, 0u8, 161u8, 0u8,
+            0u8, 0u8, 163u8, 0u8, 0u8, 0u8, 158u8, 1u8, 0u8, 0u8, 230u8, 1u8, 0u8, 0u8, 160u8, 1u8,
+            0u8, 0u8, 192u8, 1u8, 0u8, 0u8, 159u8, 0u8, 0u8, 0u8, 194u8, 1u8, 0u8, 0u8, 192u8, 0u8,
+            0u8, 0u8, 196u8, 0u8, 0u8, 0u8, 164u8, 0u8, 0u8, 0u8, 186u8, 1u8, 0u8, 0u8, 162u8, 0u8,
+            0u8, 0u8, 66u8, 0u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8, 66u8, 1u8, 0u8, 0u8, 67u8, 0u8,
+            0u8, 0u8, 198u8, 0u8, 0u8, 0u8, 204u8, 0u8, 0u8, 0u8, 166u8, 0u8, 0u8, 0u8, 200u8, 0u8,
+            0u8, 0u8, 202u8, 0u8, 0u8, 0u8, 71u8, 1u8, 0u8, 0u8, 251u8, 1u8, 0u8, 0u8, 68u8, 0u8,
+            0u8, 0u8, 247u8, 1u8, 0u8, 0u8, 240u8, 0u8, 0u8, 0u8, 246u8, 1u8, 0u8, 0u8, 206u8, 0u8,
+            0u8, 0u8, 224u8, 1u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 75u8, 1u8, 0u8, 0u8, 178u8, 1u8,
+            0u8, 0u8, 133u8, 1u8, 0u8, 0u8, 69u8, 0u8, 0u8, 0u8, 10u8, 1u8, 0u8, 0u8, 175u8, 0u8,
+            0u8, 0u8, 168u8, 0u8, 0u8, 0u8, 212u8, 0u8, 0u8, 0u8, 218u8, 0u8, 0u8, 0u8, 232u8, 1u8,
+            0u8, 0u8, 169u8, 0u8, 0u8, 0u8, 170u8, 0u8, 0u8, 0u8, 214u8, 0u8, 0u8, 0u8, 196u8, 1u8,
+            0u8, 0u8, 167u8, 0u8, 0u8, 0u8, 198u8, 1u8, 0u8, 0u8, 210u8, 0u8, 0u8, 0u8, 216u8, 0u8,
+            0u8, 0u8, 6u8, 2u8, 0u8, 0u8, 120u8, 1u8, 0u8, 0u8, 174u8, 1u8, 0u8, 0u8, 121u8, 1u8,
+            0u8, 0u8, 70u8, 0u8, 0u8, 0u8, 81u8, 1u8, 0u8, 0u8, 71u8, 0u8, 0u8, 0u8, 170u8, 5u8,
+            0u8, 0u8, 1u8, 2u8, 0u8, 0u8, 180u8, 1u8, 0u8, 0u8, 222u8, 0u8, 0u8, 0u8, 166u8, 1u8,
+            0u8, 0u8, 226u8, 0u8, 0u8, 0u8, 220u8, 0u8, 0u8, 0u8, 224u8, 0u8, 0u8, 0u8, 164u8, 1u8,
+            0u8, 0u8, 72u8, 0u8, 0u8, 0u8, 84u8, 1u8, 0u8, 0u8, 222u8, 1u8, 0u8, 0u8, 228u8, 0u8,
+            0u8, 0u8, 230u8, 0u8, 0u8, 0u8, 73u8, 0u8, 0u8, 0u8, 172u8, 0u8, 0u8, 0u8, 236u8, 0u8,
+            0u8, 0u8, 143u8, 1u8, 0u8, 0u8, 173u8, 0u8, 0u8, 0u8, 174u8, 0u8, 0u8, 0u8, 200u8, 1u8,
+            0u8, 0u8, 171u8, 0u8, 0u8, 0u8, 202u8, 1u8, 0u8, 0u8, 234u8, 0u8, 0u8, 0u8, 238u8, 0u8,
+            0u8, 0u8, 232u8, 0u8, 0u8, 0u8, 74u8, 0u8, 0u8, 0u8, 175u8, 1u8, 0u8, 0u8, 244u8, 0u8,
+            0u8, 0u8, 8u8, 2u8, 0u8, 0u8, 75u8, 0u8, 0u8, 0u8, 247u8, 0u8, 0u8, 0u8, 168u8, 1u8,
+            0u8, 0u8, 246u8, 0u8, 0u8, 0u8, 88u8, 1u8, 0u8, 0u8, 76u8, 0u8, 0u8, 0u8, 90u8, 1u8,
+            0u8, 0u8, 136u8, 1u8, 0u8, 0u8, 62u8, 1u8, 0u8, 0u8, 249u8, 0u8, 0u8, 0u8, 89u8, 1u8,
+            0u8, 0u8, 253u8, 0u8, 0u8, 0u8, 251u8, 0u8, 0u8, 0u8, 243u8, 1u8, 0u8, 0u8, 255u8, 0u8,
+            0u8, 0u8, 1u8, 1u8, 0u8, 0u8, 77u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 139u8, 1u8,
+            0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 3u8, 1u8, 0u8, 0u8, 7u8, 1u8, 0u8, 0u8, 5u8, 1u8, 0u8,
+            0u8, 244u8, 1u8, 0u8, 0u8, 184u8, 1u8, 0u8, 0u8, 93u8, 1u8, 0u8, 0u8, 176u8, 0u8, 0u8,
+            0u8, 79u8, 0u8, 0u8, 0u8, 98u8, 1u8, 0u8, 0u8, 226u8, 1u8, 0u8, 0u8, 178u8, 0u8, 0u8,
+            0u8, 14u8, 1u8, 0u8, 0u8, 145u8, 1u8, 0u8, 0u8, 179u8, 0u8, 0u8, 0u8, 181u8, 0u8, 0u8,
+            0u8, 234u8, 1u8, 0u8, 0u8, 238u8, 1u8, 0u8, 0u8, 240u8, 1u8, 0u8, 0u8, 16u8, 1u8, 0u8,
+            0u8, 204u8, 1u8, 0u8, 0u8, 177u8, 0u8, 0u8, 0u8, 96u8, 1u8, 0u8, 0u8, 206u8, 1u8, 0u8,
+            0u8, 12u8, 1u8, 0u8, 0u8, 170u8, 1u8, 0u8, 0u8, 172u8, 1u8, 0u8, 0u8, 183u8, 0u8, 0u8,
+            0u8, 190u8, 1u8, 0u8, 0u8, 180u8, 0u8, 0u8, 0u8, 236u8, 1u8, 0u8, 0u8, 80u8, 0u8, 0u8,
+            0u8, 100u8, 1u8, 0u8, 0u8, 81u8, 0u8, 0u8, 0u8, 248u8, 1u8, 0u8, 0u8, 10u8, 2u8, 0u8,
+            0u8, 82u8, 0u8, 0u8, 0u8, 20u8, 1u8, 0u8, 0u8, 24u8, 1u8, 0u8, 0u8, 22u8, 1u8, 0u8,
+            0u8, 208u8, 1u8, 0u8, 0u8, 210u8, 1u8, 0u8, 0u8, 12u8, 2u8, 0u8, 0u8, 83u8, 0u8, 0u8,
+            0u8, 158u8, 0u8, 0u8, 0u8, 26u8, 1u8, 0u8, 0u8, 32u8, 1u8, 0u8, 0u8, 30u8, 1u8, 0u8,
+            0u8, 28u8, 1u8, 0u8, 0u8, 216u8, 1u8, 0u8, 0u8, 254u8, 1u8, 0u8, 0u8, 84u8, 0u8, 0u8,
+            0u8, 189u8, 0u8, 0u8, 0u8, 124u8, 1u8, 0u8, 0u8, 68u8, 1u8, 0u8, 0u8, 103u8, 1u8, 0u8,
+            0u8, 76u8, 1u8, 0u8, 0u8, 156u8, 1u8, 0u8, 0u8, 36u8, 1u8, 0u8, 0u8, 34u8, 1u8, 0u8,
+            0u8, 218u8, 1u8, 0u8, 0u8, 245u8, 1u8, 0u8, 0u8, 108u8, 1u8, 0u8, 0u8, 106u8, 1u8, 0u8,
+            0u8, 38u8, 1u8, 0u8, 0u8, 85u8, 0u8, 0u8, 0u8, 185u8, 0u8, 0u8, 0u8, 44u8, 1u8, 0u8,
+            0u8, 147u8, 1u8, 0u8, 0u8, 186u8, 0u8, 0u8, 0u8, 187u8, 0u8, 0u8, 0u8, 151u8, 1u8, 0u8,
+            0u8, 153u8, 1u8, 0u8, 0u8, 155u8, 1u8, 0u8, 0u8, 149u8, 1u8, 0u8, 0u8, 48u8, 1u8, 0u8,
+            0u8, 212u8, 1u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 111u8, 1u8, 0u8, 0u8, 214u8, 1u8, 0u8,
+            0u8, 42u8, 1u8, 0u8, 0u8, 50u8, 1u8, 0u8, 0u8, 46u8, 1u8, 0u8, 0u8, 40u8, 1u8, 0u8,
+            0u8, 86u8, 0u8, 0u8, 0u8, 87u8, 0u8, 0u8, 0u8, 52u8, 1u8, 0u8, 0u8, 88u8, 0u8, 0u8,
+            0u8, 89u8, 0u8, 0u8, 0u8, 220u8, 1u8, 0u8, 0u8, 188u8, 0u8, 0u8, 0u8, 54u8, 1u8, 0u8,
+            0u8, 190u8, 0u8, 0u8, 0u8, 115u8, 1u8, 0u8, 0u8, 242u8, 1u8, 0u8, 0u8, 14u8, 2u8, 0u8,
+            0u8, 90u8, 0u8, 0u8, 0u8, 57u8, 1u8, 0u8, 0u8, 61u8, 1u8, 0u8, 0u8, 59u8, 1u8, 0u8,
+            0u8, 228u8, 1u8, 0u8, 0u8, 117u8, 1u8, 0u8, 0u8, 255u8, 1u8, 0u8, 0u8, 242u8, 0u8, 0u8,
+            0u8, 18u8, 1u8, 0u8, 0u8, 90u8, 4u8, 0u8, 0u8, 91u8, 0u8, 0u8, 0u8, 43u8, 3u8, 0u8,
+            0u8, 100u8, 4u8, 0u8, 0u8, 8u8, 0u8, 0u8, 0u8, 106u8, 0u8, 0u8, 0u8, 39u8, 3u8, 0u8,
+            0u8, 59u8, 0u8, 0u8, 0u8, 84u8, 3u8, 0u8, 0u8, 57u8, 3u8, 0u8, 0u8, 116u8, 3u8, 0u8,
+            0u8, 29u8, 3u8, 0u8, 0u8, 60u8, 3u8, 0u8, 0u8, 202u8, 3u8, 0u8, 0u8, 28u8, 0u8, 0u8,
+            0u8, 36u8, 5u8, 0u8, 0u8, 204u8, 3u8, 0u8, 0u8, 133u8, 3u8, 0u8, 0u8, 33u8, 5u8, 0u8,
+            0u8, 55u8, 3u8, 0u8, 0u8, 39u8, 5u8, 0u8, 0u8, 113u8, 5u8, 0u8, 0u8, 47u8, 5u8, 0u8,
+            0u8, 50u8, 5u8, 0u8, 0u8, 93u8, 3u8, 0u8, 0u8, 63u8, 0u8, 0u8, 0u8, 180u8, 5u8, 0u8,
+            0u8, 188u8, 5u8, 0u8, 0u8, 178u8, 5u8, 0u8, 0u8, 183u8, 5u8, 0u8, 0u8, 110u8, 0u8, 0u8,
+            0u8, 35u8, 4u8, 0u8, 0u8, 192u8, 3u8, 0u8, 0u8, 37u8, 4u8, 0u8, 0u8, 36u8, 4u8, 0u8,
+            0u8, 109u8, 4u8, 0u8, 0u8, 43u8, 4u8, 0u8, 0u8, 121u8, 0u8, 0u8, 0u8, 41u8, 4u8, 0u8,
+            0u8, 110u8, 3u8, 0u8, 0u8, 42u8, 4u8, 0u8, 0u8, 40u8, 4u8, 0u8, 0u8, 189u8, 3u8, 0u8,
+            0u8, 143u8, 5u8, 0u8, 0u8, 116u8, 0u8, 0u8, 0u8, 118u8, 0u8, 0u8, 0u8, 186u8, 5u8, 0u8,
+            0u8, 8u8, 4u8, 0u8, 0u8, 9u8, 4u8, 0u8, 0u8, 110u8, 4u8, 0u8, 0u8, 150u8, 0u8, 0u8,
+            0u8, 235u8, 3u8, 0u8, 0u8, 236u8, 3u8, 0u8, 0u8, 237u8, 3u8, 0u8, 0u8, 144u8, 5u8, 0u8,
+            0u8, 63u8, 5u8, 0u8, 0u8, 62u8, 3u8, 0u8, 0u8, 124u8, 3u8, 0u8, 0u8, 98u8, 5u8, 0u8,
+            0u8, 167u8, 5u8, 0u8, 0u8, 62u8, 5u8, 0u8, 0u8, 102u8, 5u8, 0u8, 0u8, 196u8, 3u8, 0u8,
+            0u8, 48u8, 4u8, 0u8, 0u8, 34u8, 5u8, 0u8, 0u8, 101u8, 5u8, 0u8, 0u8, 37u8, 5u8, 0u8,
+            0u8, 185u8, 5u8, 0u8, 0u8, 97u8, 5u8, 0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 82u8, 4u8, 0u8,
+            0u8, 125u8, 3u8, 0u8, 0u8, 32u8, 3u8, 0u8, 0u8, 107u8, 0u8, 0u8, 0u8, 12u8, 5u8, 0u8,
+            0u8, 11u8, 5u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 51u8, 3u8, 0u8, 0u8, 161u8, 5u8, 0u8,
+            0u8, 76u8, 4u8, 0u8, 0u8, 139u8, 3u8, 0u8, 0u8, 164u8, 3u8, 0u8, 0u8, 162u8, 5u8, 0u8,
+            0u8, 27u8, 4u8, 0u8, 0u8, 77u8, 3u8, 0u8, 0u8, 92u8, 5u8, 0u8, 0u8, 91u8, 5u8, 0u8,
+            0u8, 50u8, 4u8, 0u8, 0u8, 56u8, 3u8, 0u8, 0u8, 119u8, 5u8, 0u8, 0u8, 117u8, 5u8, 0u8,
+            0u8, 116u8, 5u8, 0u8, 0u8, 120u8, 5u8, 0u8, 0u8, 251u8, 3u8, 0u8, 0u8, 182u8, 5u8, 0u8,
+            0u8, 191u8, 5u8, 0u8, 0u8, 172u8, 3u8, 0u8, 0u8, 98u8, 4u8, 0u8, 0u8, 5u8, 0u8, 0u8,
+            0u8, 252u8, 3u8, 0u8, 0u8, 63u8, 3u8, 0u8, 0u8, 119u8, 4u8, 0u8, 0u8, 64u8, 3u8, 0u8,
+            0u8, 77u8, 4u8, 0u8, 0u8, 117u8, 0u8, 0u8, 0u8, 209u8, 3u8, 0u8, 0u8, 118u8, 5u8, 0u8,
+            0u8, 121u8, 5u8, 0u8, 0u8, 112u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 0u8, 197u8, 3u8, 0u8,
+            0u8, 164u8, 5u8, 0u8, 0u8, 59u8, 3u8, 0u8, 0u8, 119u8, 3u8, 0u8, 0u8, 98u8, 0u8, 0u8,
+            0u8, 65u8, 3u8, 0u8, 0u8, 163u8, 5u8, 0u8, 0u8, 134u8, 5u8, 0u8, 0u8, 137u8, 5u8, 0u8,
+            0u8, 133u8, 5u8, 0u8, 0u8, 136u8, 5u8, 0u8, 0u8, 135u8, 5u8, 0u8, 0u8, 138u8, 5u8, 0u8,
+            0u8, 23u8, 3u8, 0u8, 0u8, 102u8, 3u8, 0u8, 0u8, 231u8, 3u8, 0u8, 0u8, 87u8, 3u8, 0u8,
+            0u8, 31u8, 0u8, 0u8, 0u8, 57u8, 4u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 160u8, 3u8, 0u8,
+            0u8, 68u8, 4u8, 0u8, 0u8, 83u8, 5u8, 0u8, 0u8, 250u8, 3u8, 0u8, 0u8, 248u8, 3u8, 0u8,
+            0u8, 241u8, 3u8, 0u8, 0u8, 242u8, 3u8, 0u8, 0u8, 243u8, 3u8, 0u8, 0u8, 244u8, 3u8, 0u8,
+            0u8, 245u8, 3u8, 0u8, 0u8, 246u8, 3u8, 0u8, 0u8, 247u8, 3u8, 0u8, 0u8, 74u8, 3u8, 0u8,
+            0u8, 109u8, 0u8, 0u8, 0u8, 94u8, 4u8, 0u8, 0u8, 95u8, 4u8, 0u8, 0u8, 69u8, 3u8, 0u8,
+            0u8, 90u8, 3u8, 0u8, 0u8, 68u8, 3u8, 0u8, 0u8, 151u8, 3u8, 0u8, 0u8, 94u8, 3u8, 0u8,
+            0u8, 70u8, 3u8, 0u8, 0u8, 123u8, 5u8, 0u8, 0u8, 122u8, 5u8, 0u8, 0u8, 60u8, 0u8, 0u8,
+            0u8, 124u8, 5u8, 0u8, 0u8, 93u8, 0u8, 0u8, 0u8, 44u8, 3u8, 0u8, 0u8, 99u8, 4u8, 0u8,
+            0u8, 9u8, 0u8, 0u8, 0u8, 122u8, 0u8, 0u8, 0u8, 40u8, 3u8, 0u8, 0u8, 61u8, 0u8, 0u8,
+            0u8, 85u8, 3u8, 0u8, 0u8, 58u8, 3u8, 0u8, 0u8, 117u8, 3u8, 0u8, 0u8, 30u8, 3u8, 0u8,
+            0u8, 61u8, 3u8, 0u8, 0u8, 181u8, 5u8, 0u8, 0u8, 189u8, 5u8, 0u8, 0u8, 179u8, 5u8, 0u8,
+            0u8, 184u8, 5u8, 0u8, 0u8, 84u8, 5u8, 0u8, 0u8, 206u8, 3u8, 0u8, 0u8, 115u8, 4u8, 0u8,
+            0u8, 145u8, 3u8, 0u8, 0u8, 194u8, 3u8, 0u8, 0u8, 20u8, 4u8, 0u8, 0u8, 156u8, 5u8, 0u8,
+            0u8, 205u8, 3u8, 0u8, 0u8, 102u8, 0u8, 0u8, 0u8, 27u8, 0u8, 0u8, 0u8, 56u8, 4u8, 0u8,
+            0u8, 58u8, 4u8, 0u8, 0u8, 139u8, 5u8, 0u8, 0u8, 55u8, 4u8, 0u8, 0u8, 154u8, 5u8, 0u8,
+            0u8, 150u8, 3u8, 0u8, 0u8, 49u8, 5u8, 0u8, 0u8, 46u8, 5u8, 0u8, 0u8, 103u8, 4u8, 0u8,
+            0u8, 168u8, 5u8, 0u8, 0u8, 51u8, 5u8, 0u8, 0u8, 155u8, 5u8, 0u8, 0u8, 153u8, 5u8, 0u8,
+            0u8, 42u8, 3u8, 0u8, 0u8, 72u8, 3u8, 0u8, 0u8, 41u8, 3u8, 0u8, 0u8, 73u8, 3u8, 0u8,
+            0u8, 126u8, 5u8, 0u8, 0u8, 129u8, 5u8, 0u8, 0u8, 128u8, 5u8, 0u8, 0u8, 125u8, 5u8, 0u8,
+            0u8, 21u8, 3u8, 0u8, 0u8, 117u8, 4u8, 0u8, 0u8, 158u8, 3u8, 0u8, 0u8, 129u8, 3u8, 0u8,
+            0u8, 66u8, 4u8, 0u8, 0u8, 48u8, 5u8, 0u8, 0u8, 59u8, 4u8, 0u8, 0u8, 108u8, 0u8, 0u8,
+            0u8, 14u8, 5u8, 0u8, 0u8, 15u8, 0u8, 0u8, 0u8, 152u8, 5u8, 0u8, 0u8, 88u8, 5u8, 0u8,
+            0u8, 147u8, 5u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 61u8, 4u8, 0u8, 0u8, 91u8, 4u8, 0u8,
+            0u8, 116u8, 4u8, 0u8, 0u8, 127u8, 5u8, 0u8, 0u8, 130u8, 5u8, 0u8, 0u8, 104u8, 5u8, 0u8,
+            0u8, 160u8, 5u8, 0u8, 0u8, 19u8, 4u8, 0u8, 0u8, 24u8, 4u8, 0u8, 0u8, 168u8, 3u8, 0u8,
+            0u8, 145u8, 5u8, 0u8, 0u8, 13u8, 5u8, 0u8, 0u8, 146u8, 5u8, 0u8, 0u8, 103u8, 5u8, 0u8,
+            0u8, 151u8, 5u8, 0u8, 0u8, 15u8, 5u8, 0u8, 0u8, 159u8, 5u8, 0u8, 0u8, 140u8, 5u8, 0u8,
+            0u8, 16u8, 5u8, 0u8, 0u8, 73u8, 5u8, 0u8, 0u8, 72u8, 5u8, 0u8, 0u8, 135u8, 3u8, 0u8,
+            0u8, 67u8, 4u8, 0u8, 0u8, 120u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 113u8, 0u8, 0u8,
+            0u8, 98u8, 3u8, 0u8, 0u8, 65u8, 5u8, 0u8, 0u8, 64u8, 5u8, 0u8, 0u8, 32u8, 5u8, 0u8,
+            0u8, 199u8, 3u8, 0u8, 0u8, 120u8, 4u8, 0u8, 0u8, 24u8, 3u8, 0u8, 0u8, 101u8, 3u8, 0u8,
+            0u8, 29u8, 4u8, 0u8, 0u8, 28u8, 4u8, 0u8, 0u8, 19u8, 3u8, 0u8, 0u8, 70u8, 4u8, 0u8,
+            0u8, 134u8, 3u8, 0u8, 0u8, 94u8, 0u8, 0u8, 0u8, 89u8, 3u8, 0u8, 0u8, 50u8, 3u8, 0u8,
+            0u8, 108u8, 3u8, 0u8, 0u8, 181u8, 3u8, 0u8, 0u8, 176u8, 3u8, 0u8, 0u8, 174u8, 3u8, 0u8,
+            0u8, 175u8, 3u8, 0u8, 0u8, 180u8, 3u8, 0u8, 0u8, 177u8, 3u8, 0u8, 0u8, 179u8, 3u8, 0u8,
+            0u8, 178u8, 3u8, 0u8, 0u8, 67u8, 3u8, 0u8, 0u8, 72u8, 4u8, 0u8, 0u8, 71u8, 4u8, 0u8,
+            0u8, 96u8, 3u8, 0u8, 0u8, 52u8, 3u8, 0u8, 0u8, 105u8, 3u8, 0u8, 0u8, 128u8, 3u8, 0u8,
+            0u8, 111u8, 4u8, 0u8, 0u8, 146u8, 3u8, 0u8, 0u8, 78u8, 3u8, 0u8, 0u8, 86u8, 5u8, 0u8,
+            0u8, 240u8, 3u8, 0u8, 0u8, 45u8, 4u8, 0u8, 0u8, 195u8, 3u8, 0u8, 0u8, 87u8, 5u8, 0u8,
+            0u8, 109u8, 3u8, 0u8, 0u8, 92u8, 0u8, 0u8, 0u8, 38u8, 4u8, 0u8, 0u8, 40u8, 5u8, 0u8,
+            0u8, 114u8, 5u8, 0u8, 0u8, 52u8, 4u8, 0u8, 0u8, 203u8, 3u8, 0u8, 0u8, 41u8, 5u8, 0u8,
+            0u8, 115u8, 5u8, 0u8, 0u8, 124u8, 0u8, 0u8, 0u8, 123u8, 0u8, 0u8, 0u8, 125u8, 0u8, 0u8,
+            0u8, 30u8, 4u8, 0u8, 0u8, 238u8, 3u8, 0u8, 0u8, 253u8, 3u8, 0u8, 0u8, 182u8, 3u8, 0u8,
+            0u8, 213u8, 3u8, 0u8, 0u8, 210u8, 3u8, 0u8, 0u8, 214u8, 3u8, 0u8, 0u8, 215u8, 3u8, 0u8,
+            0u8, 211u8, 3u8, 0u8, 0u8, 212u8, 3u8, 0u8, 0u8, 4u8, 4u8, 0u8, 0u8, 5u8, 4u8, 0u8,
+            0u8, 229u8, 3u8, 0u8, 0u8, 73u8, 4u8, 0u8, 0u8, 224u8, 3u8, 0u8, 0u8, 157u8, 3u8, 0u8,
+            0u8, 63u8, 4u8, 0u8, 0u8, 62u8, 4u8, 0u8, 0u8, 14u8, 4u8, 0u8, 0u8, 125u8, 4u8, 0u8,
+            0u8, 183u8, 3u8, 0u8, 0u8, 223u8, 3u8, 0u8, 0u8, 87u8, 4u8, 0u8, 0u8, 154u8, 3u8, 0u8,
+            0u8, 156u8, 3u8, 0u8, 0u8, 148u8, 3u8, 0u8, 0u8, 184u8, 3u8, 0u8, 0u8, 226u8, 3u8, 0u8,
+            0u8, 132u8, 3u8, 0u8, 0u8, 186u8, 3u8, 0u8, 0u8, 141u8, 3u8, 0u8, 0u8, 85u8, 4u8, 0u8,
+            0u8, 155u8, 3u8, 0u8, 0u8, 187u8, 5u8, 0u8, 0u8, 111u8, 3u8, 0u8, 0u8, 100u8, 0u8, 0u8,
+            0u8, 173u8, 3u8, 0u8, 0u8, 28u8, 3u8, 0u8, 0u8, 10u8, 5u8, 0u8, 0u8, 27u8, 3u8, 0u8,
+            0u8, 26u8, 3u8, 0u8, 0u8, 177u8, 5u8, 0u8, 0u8, 194u8, 5u8, 0u8, 0u8, 176u8, 5u8, 0u8,
+            0u8, 175u8, 5u8, 0u8, 0u8,
+        ])
+    },
+    code_point_ranges: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 52u8, 0u8, 0u8, 191u8, 77u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 255u8, 159u8, 0u8,
+            0u8, 0u8, 249u8, 0u8, 0u8, 109u8, 250u8, 0u8, 0u8, 112u8, 250u8, 0u8, 0u8, 217u8,
+            250u8, 0u8, 0u8, 0u8, 112u8, 1u8, 0u8, 247u8, 135u8, 1u8, 0u8, 0u8, 139u8, 1u8, 0u8,
+            213u8, 140u8, 1u8, 0u8, 0u8, 141u8, 1u8, 0u8, 8u8, 141u8, 1u8, 0u8, 112u8, 177u8, 1u8,
+            0u8, 251u8, 178u8, 1u8, 0u8, 0u8, 0u8, 2u8, 0u8, 223u8, 166u8, 2u8, 0u8, 0u8, 167u8,
+            2u8, 0u8, 56u8, 183u8, 2u8, 0u8, 64u8, 183u8, 2u8, 0u8, 29u8, 184u8, 2u8, 0u8, 32u8,
+            184u8, 2u8, 0u8, 161u8, 206u8, 2u8, 0u8, 176u8, 206u8, 2u8, 0u8, 224u8, 235u8, 2u8,
+            0u8, 0u8, 248u8, 2u8, 0u8, 29u8, 250u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 74u8, 19u8, 3u8,
+            0u8,
+        ])
+    },
+    code_point_prefixes: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            15u8, 0u8, 0u8, 0u8, 0u8, 0u8, 22u8, 0u8, 44u8, 0u8, 72u8, 0u8, 100u8, 0u8, 117u8, 0u8,
+            147u8, 0u8, 164u8, 0u8, 180u8, 0u8, 202u8, 0u8, 224u8, 0u8, 246u8, 0u8, 12u8, 1u8,
+            34u8, 1u8, 62u8, 1u8, 67u8, 74u8, 75u8, 32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8,
+            32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8,
+            32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8,
+            82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8, 32u8, 67u8, 79u8, 77u8, 80u8, 65u8,
+            84u8, 73u8, 66u8, 73u8, 76u8, 73u8, 84u8, 89u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8,
+            82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8, 32u8, 67u8, 79u8, 77u8, 80u8, 65u8,
+            84u8, 73u8, 66u8, 73u8, 76u8, 73u8, 84u8, 89u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8,
+            82u8, 65u8, 80u8, 72u8, 45u8, 84u8, 65u8, 78u8, 71u8, 85u8, 84u8, 32u8, 73u8, 68u8,
+            69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 75u8, 72u8, 73u8, 84u8, 65u8, 78u8,
+            32u8, 83u8, 77u8, 65u8, 76u8, 76u8, 32u8, 83u8, 67u8, 82u8, 73u8, 80u8, 84u8, 32u8,
+            67u8, 72u8, 65u8, 82u8, 65u8, 67u8, 84u8, 69u8, 82u8, 45u8, 84u8, 65u8, 78u8, 71u8,
+            85u8, 84u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 78u8,
+            85u8, 83u8, 72u8, 85u8, 32u8, 67u8, 72u8, 65u8, 82u8, 65u8, 67u8, 84u8, 69u8, 82u8,
+            45u8, 67u8, 74u8, 75u8, 32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8, 32u8, 73u8,
+            68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8, 32u8, 85u8,
+            78u8, 73u8, 70u8, 73u8, 69u8, 68u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8,
+            80u8, 72u8, 45u8, 67u8, 74u8, 75u8, 32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8,
+            32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8,
+            32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8,
+            82u8, 65u8, 80u8, 72u8, 45u8, 67u8, 74u8, 75u8, 32u8, 85u8, 78u8, 73u8, 70u8, 73u8,
+            69u8, 68u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 67u8,
+            74u8, 75u8, 32u8, 67u8, 79u8, 77u8, 80u8, 65u8, 84u8, 73u8, 66u8, 73u8, 76u8, 73u8,
+            84u8, 89u8, 32u8, 73u8, 68u8, 69u8, 79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8, 67u8,
+            74u8, 75u8, 32u8, 85u8, 78u8, 73u8, 70u8, 73u8, 69u8, 68u8, 32u8, 73u8, 68u8, 69u8,
+            79u8, 71u8, 82u8, 65u8, 80u8, 72u8, 45u8,
+        ])
+    },
+};
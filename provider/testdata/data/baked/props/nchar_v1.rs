@@ -18,9 +18,10 @@ static UND: &DataStruct =
                     0u8, 0u8, 0u8, 10u8, 0u8, 254u8, 255u8, 10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 254u8,
                     255u8, 11u8, 0u8, 0u8, 0u8, 12u8, 0u8, 254u8, 255u8, 12u8, 0u8, 0u8, 0u8, 13u8,
                     0u8, 254u8, 255u8, 13u8, 0u8, 0u8, 0u8, 14u8, 0u8, 254u8, 255u8, 14u8, 0u8,
-                    0u8, 0u8, 15u8, 0u8, 254u8, 255u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                    0u8, 0u8, 15u8, 0u8, 254u8, 255u8, 15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 254u8,
+                    255u8, 16u8, 0u8, 0u8, 0u8, 17u8, 0u8,
                 ])
             },
-            64usize,
+            66usize,
         )
     });
@@ -0,0 +1,383 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::NumericTypeV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 195072u32,
+            shifted12_high_start: 48u16,
+            index3_null_offset: 307u16,
+            data_null_offset: 64u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 78u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 16u8, 0u8, 64u8, 0u8, 0u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 10u8,
+                0u8, 64u8, 0u8, 141u8, 0u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8,
+                199u8, 0u8, 64u8, 0u8, 255u8, 0u8, 64u8, 0u8, 50u8, 1u8, 64u8, 0u8, 10u8, 0u8,
+                64u8, 0u8, 113u8, 1u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8, 32u8, 0u8, 64u8, 0u8, 32u8,
+                0u8, 170u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 46u8, 7u8, 76u8, 7u8, 108u8, 7u8,
+                130u8, 7u8, 162u8, 7u8, 181u8, 7u8, 205u8, 7u8, 228u8, 7u8, 4u8, 8u8, 34u8, 8u8,
+                205u8, 7u8, 52u8, 8u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8,
+                0u8, 96u8, 0u8, 112u8, 0u8, 78u8, 0u8, 94u8, 0u8, 110u8, 0u8, 126u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8,
+                96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8,
+                42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 141u8, 0u8,
+                157u8, 0u8, 173u8, 0u8, 189u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8,
+                0u8, 10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 199u8, 0u8, 215u8, 0u8, 231u8, 0u8, 247u8, 0u8, 64u8, 0u8, 80u8, 0u8,
+                96u8, 0u8, 112u8, 0u8, 255u8, 0u8, 15u8, 1u8, 31u8, 1u8, 47u8, 1u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 50u8, 1u8, 66u8, 1u8, 82u8, 1u8, 98u8, 1u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 113u8, 1u8, 129u8, 1u8, 145u8, 1u8,
+                161u8, 1u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8,
+                42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 80u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 170u8, 1u8, 186u8, 1u8, 202u8, 1u8, 218u8, 1u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                225u8, 1u8, 239u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 123u8, 1u8, 140u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 212u8, 1u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 42u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 255u8, 1u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                15u8, 2u8, 31u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 47u8, 2u8,
+                47u8, 2u8, 47u8, 2u8, 60u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 76u8, 2u8, 88u8, 2u8, 101u8, 2u8, 116u8, 2u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 128u8, 2u8, 139u8, 2u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 70u8, 2u8, 144u8,
+                2u8, 157u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 161u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 167u8, 2u8, 64u8, 0u8, 182u8, 2u8, 192u8,
+                2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 206u8, 2u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 212u8, 1u8, 64u8,
+                0u8, 175u8, 2u8, 46u8, 2u8, 64u8, 0u8, 64u8, 0u8, 212u8, 1u8, 64u8, 0u8, 64u8, 0u8,
+                46u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 217u8, 2u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 219u8, 2u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 164u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 161u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 235u8, 2u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 161u8, 2u8, 64u8, 0u8, 64u8, 0u8, 162u8, 2u8, 247u8,
+                2u8, 64u8, 0u8, 122u8, 1u8, 140u8, 0u8, 122u8, 1u8, 166u8, 2u8, 64u8, 0u8, 161u8,
+                2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 140u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 218u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 216u8, 2u8, 64u8, 0u8,
+                254u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 13u8, 3u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 28u8, 3u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 163u8, 2u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 43u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 164u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 123u8, 1u8, 53u8, 3u8, 140u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                68u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 162u8, 2u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 80u8, 3u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 216u8,
+                2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 216u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 68u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 68u8, 3u8, 219u8,
+                2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                140u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 81u8, 3u8, 64u8, 0u8,
+                91u8, 3u8, 64u8, 0u8, 64u8, 0u8, 166u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 216u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 41u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 193u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 163u8, 2u8,
+                100u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 80u8, 3u8, 64u8, 0u8, 115u8, 3u8,
+                64u8, 0u8, 161u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 176u8, 2u8, 47u8, 2u8, 47u8, 2u8, 195u8, 0u8,
+                47u8, 2u8, 47u8, 2u8, 47u8, 2u8, 161u8, 1u8, 121u8, 3u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 46u8, 2u8, 137u8, 3u8, 64u8, 0u8,
+                64u8, 0u8, 195u8, 0u8, 64u8, 0u8, 152u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 167u8, 3u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 175u8, 2u8, 64u8, 0u8, 128u8, 1u8, 64u8, 0u8,
+                64u8, 0u8, 176u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 126u8, 1u8,
+                64u8, 0u8, 177u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 119u8, 3u8, 47u8, 2u8, 45u8, 2u8, 47u8, 2u8,
+                47u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 193u8, 3u8, 64u8, 0u8, 64u8,
+                0u8, 202u8, 3u8, 64u8, 0u8, 124u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                126u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                175u8, 2u8, 64u8, 0u8, 175u8, 2u8, 64u8, 0u8, 64u8, 0u8, 128u8, 1u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 127u8, 1u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 76u8, 2u8, 48u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 124u8, 1u8, 106u8, 1u8, 64u8, 0u8,
+                64u8, 0u8, 28u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 101u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 74u8, 2u8,
+                218u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 42u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 48u8, 0u8, 46u8, 2u8, 194u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 224u8, 3u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 151u8, 1u8, 138u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                151u8, 1u8, 240u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 47u8, 2u8, 194u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 47u8, 2u8, 47u8, 2u8, 47u8, 2u8, 47u8, 2u8, 47u8, 2u8, 47u8,
+                2u8, 48u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 4u8, 139u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                47u8, 2u8, 106u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 47u8, 2u8, 195u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 47u8, 2u8, 161u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 34u8, 0u8, 16u8, 4u8, 16u8, 4u8, 16u8, 4u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 176u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 46u8, 2u8, 47u8, 2u8, 47u8, 2u8, 32u8, 4u8, 28u8, 3u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 46u8, 2u8, 47u8, 2u8, 45u8, 4u8, 49u8, 2u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 61u8,
+                4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 48u8, 0u8, 81u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 218u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 80u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 81u8, 3u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 164u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 150u8, 3u8, 162u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 164u8, 2u8, 161u8, 2u8, 64u8, 0u8, 165u8, 2u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 140u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 166u8, 2u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                163u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                161u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 76u8, 0u8, 88u8, 0u8, 88u8, 0u8, 116u8, 0u8,
+                145u8, 0u8, 177u8, 0u8, 209u8, 0u8, 241u8, 0u8, 17u8, 1u8, 27u8, 1u8, 51u8, 1u8,
+                69u8, 1u8, 101u8, 1u8, 131u8, 1u8, 159u8, 1u8, 51u8, 1u8, 184u8, 1u8, 51u8, 1u8,
+                210u8, 1u8, 226u8, 1u8, 51u8, 1u8, 51u8, 1u8, 252u8, 1u8, 51u8, 1u8, 28u8, 2u8,
+                58u8, 2u8, 90u8, 2u8, 51u8, 1u8, 120u8, 2u8, 132u8, 2u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 164u8, 2u8, 188u8, 2u8,
+                211u8, 2u8, 51u8, 1u8, 240u8, 2u8, 1u8, 3u8, 51u8, 1u8, 51u8, 1u8, 26u8, 3u8, 51u8,
+                1u8, 44u8, 3u8, 51u8, 1u8, 65u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 95u8, 3u8,
+                51u8, 1u8, 112u8, 3u8, 51u8, 1u8, 138u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 103u8, 3u8, 51u8, 1u8, 62u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                160u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 190u8, 3u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 220u8, 3u8,
+                249u8, 3u8, 25u8, 4u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                57u8, 4u8, 51u8, 1u8, 51u8, 1u8, 39u8, 4u8, 89u8, 4u8, 114u8, 4u8, 46u8, 4u8, 51u8,
+                1u8, 144u8, 4u8, 176u8, 4u8, 203u8, 4u8, 229u8, 4u8, 2u8, 5u8, 41u8, 4u8, 34u8,
+                5u8, 61u8, 5u8, 81u8, 5u8, 51u8, 1u8, 108u8, 5u8, 135u8, 5u8, 51u8, 1u8, 51u8, 1u8,
+                167u8, 5u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 193u8,
+                5u8, 51u8, 1u8, 217u8, 5u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 235u8, 5u8, 51u8, 1u8, 3u8, 6u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 36u8, 4u8, 41u8, 4u8, 51u8, 1u8, 51u8,
+                1u8, 35u8, 6u8, 51u8, 1u8, 60u8, 6u8, 51u8, 1u8, 80u8, 6u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 97u8, 6u8, 51u8, 1u8, 51u8, 1u8, 129u8, 6u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 148u8, 6u8, 174u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 192u8,
+                6u8, 51u8, 1u8, 51u8, 1u8, 218u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 244u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 14u8,
+                7u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 208u8, 6u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8,
+                3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 1u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+                0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8,
+                0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 3u8, 0u8,
+                3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 3u8, 2u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::NumericType(0u8),
+    ),
+);
@@ -0,0 +1,911 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct =
+    <::icu_properties::provider::NumericValueV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 195072u32,
+            shifted12_high_start: 48u16,
+            index3_null_offset: 307u16,
+            data_null_offset: 64u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 78u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 16u8, 0u8, 64u8, 0u8, 0u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 10u8,
+                0u8, 64u8, 0u8, 141u8, 0u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8,
+                199u8, 0u8, 64u8, 0u8, 255u8, 0u8, 64u8, 0u8, 50u8, 1u8, 64u8, 0u8, 10u8, 0u8,
+                64u8, 0u8, 113u8, 1u8, 64u8, 0u8, 10u8, 0u8, 64u8, 0u8, 32u8, 0u8, 64u8, 0u8, 32u8,
+                0u8, 170u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 123u8, 7u8, 153u8, 7u8, 185u8,
+                7u8, 207u8, 7u8, 239u8, 7u8, 2u8, 8u8, 26u8, 8u8, 49u8, 8u8, 81u8, 8u8, 111u8, 8u8,
+                26u8, 8u8, 129u8, 8u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8,
+                0u8, 96u8, 0u8, 112u8, 0u8, 78u8, 0u8, 94u8, 0u8, 110u8, 0u8, 126u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8,
+                96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8,
+                42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 141u8, 0u8,
+                157u8, 0u8, 173u8, 0u8, 189u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8,
+                10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8,
+                0u8, 10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 199u8, 0u8, 215u8, 0u8, 231u8, 0u8, 247u8, 0u8, 64u8, 0u8, 80u8, 0u8,
+                96u8, 0u8, 112u8, 0u8, 255u8, 0u8, 15u8, 1u8, 31u8, 1u8, 47u8, 1u8, 64u8, 0u8,
+                80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 50u8, 1u8, 66u8, 1u8, 82u8, 1u8, 98u8, 1u8, 64u8,
+                0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8, 42u8, 0u8, 58u8, 0u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 113u8, 1u8, 129u8, 1u8, 145u8, 1u8,
+                161u8, 1u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 10u8, 0u8, 26u8, 0u8,
+                42u8, 0u8, 58u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 80u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 170u8, 1u8, 186u8, 1u8, 202u8, 1u8, 218u8, 1u8,
+                64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8,
+                112u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                225u8, 1u8, 241u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 254u8, 1u8, 14u8, 2u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 42u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 30u8, 2u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                46u8, 2u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 62u8, 2u8,
+                77u8, 2u8, 77u8, 2u8, 92u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 108u8, 2u8, 124u8, 2u8, 120u8, 2u8, 136u8, 2u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 148u8, 2u8, 164u8, 2u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 228u8, 1u8,
+                180u8, 2u8, 196u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 200u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 214u8, 2u8, 64u8, 0u8, 229u8, 2u8,
+                239u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 253u8,
+                2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 190u8, 2u8,
+                64u8, 0u8, 5u8, 3u8, 21u8, 3u8, 64u8, 0u8, 64u8, 0u8, 190u8, 2u8, 64u8, 0u8, 64u8,
+                0u8, 37u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 53u8, 3u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 66u8,
+                3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 72u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 83u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 99u8, 3u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 109u8, 3u8, 64u8, 0u8, 64u8, 0u8, 123u8, 3u8,
+                136u8, 3u8, 64u8, 0u8, 143u8, 3u8, 159u8, 3u8, 160u8, 3u8, 176u8, 3u8, 64u8, 0u8,
+                185u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                201u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 213u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 223u8, 3u8,
+                64u8, 0u8, 230u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 245u8,
+                3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                4u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 9u8, 4u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 24u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 34u8, 4u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 45u8, 4u8, 61u8, 4u8,
+                77u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 79u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 94u8, 4u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 108u8, 4u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 118u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 116u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 125u8, 4u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 140u8,
+                4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 147u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 160u8, 4u8, 66u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 77u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 175u8, 4u8, 64u8, 0u8, 185u8, 4u8, 64u8, 0u8, 64u8, 0u8, 198u8, 4u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 208u8, 4u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 218u8, 4u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 249u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 234u8, 4u8, 247u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 5u8, 5u8,
+                64u8, 0u8, 20u8, 5u8, 64u8, 0u8, 80u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 227u8, 1u8, 36u8, 5u8, 52u8,
+                5u8, 68u8, 5u8, 84u8, 5u8, 100u8, 5u8, 116u8, 5u8, 131u8, 5u8, 140u8, 5u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 233u8, 1u8,
+                156u8, 5u8, 64u8, 0u8, 64u8, 0u8, 172u8, 5u8, 64u8, 0u8, 187u8, 5u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 202u8,
+                5u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 210u8, 5u8, 64u8, 0u8,
+                226u8, 5u8, 64u8, 0u8, 64u8, 0u8, 242u8, 5u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 2u8, 6u8, 64u8, 0u8, 18u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 30u8, 6u8, 234u8, 1u8,
+                44u8, 6u8, 60u8, 6u8, 76u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 92u8,
+                6u8, 64u8, 0u8, 64u8, 0u8, 101u8, 6u8, 64u8, 0u8, 116u8, 6u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 2u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 132u8, 6u8, 64u8, 0u8, 132u8, 6u8, 64u8, 0u8, 64u8, 0u8, 148u8,
+                6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 164u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 234u8, 1u8, 180u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 195u8, 6u8,
+                211u8, 6u8, 64u8, 0u8, 64u8, 0u8, 226u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 152u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 232u8, 1u8, 242u8, 6u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 42u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 233u8, 1u8, 2u8, 7u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 18u8,
+                7u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 34u8,
+                7u8, 50u8, 7u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 66u8, 7u8, 82u8, 7u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                98u8, 7u8, 114u8, 7u8, 64u8, 0u8, 64u8, 0u8, 130u8, 7u8, 146u8, 7u8, 162u8, 7u8,
+                178u8, 7u8, 194u8, 7u8, 210u8, 7u8, 226u8, 7u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 242u8,
+                7u8, 2u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 18u8, 8u8, 34u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 18u8, 8u8, 50u8, 8u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 234u8, 1u8, 66u8, 8u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 34u8, 0u8, 82u8, 8u8, 88u8,
+                8u8, 84u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 227u8, 1u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                48u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 233u8, 1u8, 42u8, 5u8, 59u8, 6u8, 104u8, 8u8,
+                120u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 233u8, 1u8, 42u8, 5u8,
+                136u8, 8u8, 152u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 168u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 48u8, 0u8, 123u8, 4u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 142u8, 4u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 182u8, 8u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 197u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 34u8, 4u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 210u8, 8u8, 223u8, 8u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 181u8, 4u8, 236u8, 8u8,
+                64u8, 0u8, 240u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 77u8, 4u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 176u8, 3u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 234u8, 4u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 250u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 57u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                76u8, 0u8, 88u8, 0u8, 88u8, 0u8, 116u8, 0u8, 145u8, 0u8, 177u8, 0u8, 209u8, 0u8,
+                241u8, 0u8, 17u8, 1u8, 27u8, 1u8, 51u8, 1u8, 69u8, 1u8, 101u8, 1u8, 131u8, 1u8,
+                159u8, 1u8, 51u8, 1u8, 184u8, 1u8, 51u8, 1u8, 210u8, 1u8, 226u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 252u8, 1u8, 51u8, 1u8, 28u8, 2u8, 58u8, 2u8, 90u8, 2u8, 51u8, 1u8,
+                120u8, 2u8, 132u8, 2u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 164u8, 2u8, 188u8, 2u8, 211u8, 2u8, 51u8, 1u8, 240u8, 2u8,
+                1u8, 3u8, 51u8, 1u8, 51u8, 1u8, 26u8, 3u8, 51u8, 1u8, 44u8, 3u8, 51u8, 1u8, 65u8,
+                3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 95u8, 3u8, 51u8, 1u8, 112u8, 3u8, 51u8, 1u8,
+                138u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 162u8, 3u8, 51u8, 1u8,
+                192u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 214u8, 3u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 244u8, 3u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 18u8, 4u8, 47u8, 4u8, 79u8, 4u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 111u8, 4u8, 51u8, 1u8, 51u8, 1u8, 93u8, 4u8,
+                143u8, 4u8, 168u8, 4u8, 100u8, 4u8, 51u8, 1u8, 198u8, 4u8, 230u8, 4u8, 1u8, 5u8,
+                27u8, 5u8, 56u8, 5u8, 95u8, 4u8, 88u8, 5u8, 115u8, 5u8, 135u8, 5u8, 51u8, 1u8,
+                162u8, 5u8, 189u8, 5u8, 51u8, 1u8, 51u8, 1u8, 221u8, 5u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 247u8, 5u8, 51u8, 1u8, 15u8, 6u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 33u8, 6u8, 51u8, 1u8, 57u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 90u8, 4u8, 95u8, 4u8, 51u8, 1u8, 51u8, 1u8, 89u8, 6u8, 51u8, 1u8, 114u8,
+                6u8, 51u8, 1u8, 134u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 151u8, 6u8,
+                51u8, 1u8, 51u8, 1u8, 183u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 202u8, 6u8,
+                228u8, 6u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 246u8, 6u8, 51u8, 1u8, 51u8, 1u8,
+                16u8, 7u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 42u8, 7u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 68u8, 7u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8,
+                51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 51u8,
+                1u8, 51u8, 1u8, 51u8, 1u8, 51u8, 1u8, 91u8, 7u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8,
+                4u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8,
+                1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 16u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8, 0u8, 16u8, 128u8, 1u8,
+                0u8, 4u8, 128u8, 0u8, 0u8, 4u8, 128u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8,
+                0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 4u8, 128u8,
+                1u8, 0u8, 16u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8, 0u8, 16u8, 128u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8,
+                0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8,
+                132u8, 0u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 160u8, 128u8, 0u8, 0u8, 40u8, 128u8,
+                0u8, 0u8, 80u8, 128u8, 1u8, 0u8, 20u8, 128u8, 0u8, 0u8, 10u8, 128u8, 0u8, 0u8,
+                20u8, 128u8, 1u8, 0u8, 5u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8,
+                0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8,
+                0u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 2u8,
+                128u8, 0u8, 0u8, 4u8, 128u8, 1u8, 0u8, 16u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8, 0u8,
+                16u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 2u8, 128u8, 0u8,
+                0u8, 2u8, 128u8, 1u8, 0u8, 2u8, 128u8, 2u8, 0u8, 2u8, 128u8, 3u8, 0u8, 2u8, 128u8,
+                4u8, 0u8, 2u8, 128u8, 5u8, 0u8, 2u8, 128u8, 6u8, 0u8, 2u8, 128u8, 7u8, 0u8, 2u8,
+                128u8, 8u8, 0u8, 2u8, 128u8, 255u8, 255u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8, 4u8, 2u8, 0u8, 1u8, 132u8, 2u8,
+                0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8, 4u8, 0u8, 1u8, 132u8, 4u8,
+                0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 144u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 128u8, 8u8, 0u8, 1u8, 0u8, 9u8, 0u8, 1u8, 128u8, 9u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8,
+                0u8, 1u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 7u8, 128u8, 0u8, 0u8, 9u8, 128u8, 0u8,
+                0u8, 10u8, 128u8, 0u8, 0u8, 3u8, 128u8, 0u8, 0u8, 3u8, 0u8, 1u8, 0u8, 5u8, 128u8,
+                0u8, 0u8, 5u8, 0u8, 1u8, 0u8, 5u8, 128u8, 1u8, 0u8, 5u8, 0u8, 2u8, 0u8, 6u8, 128u8,
+                0u8, 0u8, 6u8, 128u8, 2u8, 0u8, 8u8, 128u8, 0u8, 0u8, 8u8, 128u8, 1u8, 0u8, 8u8,
+                128u8, 2u8, 0u8, 8u8, 128u8, 3u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 1u8, 128u8, 5u8, 0u8, 1u8, 0u8, 6u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 136u8,
+                0u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 140u8, 2u8, 0u8, 1u8,
+                144u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8,
+                132u8, 2u8, 0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 148u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 1u8, 128u8, 5u8, 0u8, 1u8, 0u8, 6u8, 0u8, 1u8, 128u8, 6u8, 0u8, 1u8, 0u8, 7u8,
+                0u8, 1u8, 128u8, 7u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 128u8, 8u8, 0u8, 1u8, 0u8, 9u8,
+                0u8, 1u8, 128u8, 9u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8,
+                0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8,
+                0u8, 0u8, 1u8, 128u8, 5u8, 0u8, 1u8, 0u8, 6u8, 0u8, 1u8, 128u8, 6u8, 0u8, 1u8, 0u8,
+                7u8, 0u8, 1u8, 128u8, 7u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 128u8, 8u8, 0u8, 1u8, 0u8,
+                9u8, 0u8, 1u8, 128u8, 9u8, 0u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 5u8, 0u8, 1u8, 0u8, 6u8, 0u8, 1u8, 128u8,
+                6u8, 0u8, 1u8, 0u8, 7u8, 0u8, 1u8, 128u8, 7u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 128u8,
+                8u8, 0u8, 1u8, 0u8, 9u8, 0u8, 1u8, 128u8, 9u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 128u8,
+                0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8,
+                2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8,
+                4u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8,
+                3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8,
+                132u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                2u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8,
+                1u8, 132u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8, 4u8, 2u8, 0u8,
+                1u8, 132u8, 2u8, 0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 10u8, 0u8, 1u8, 0u8, 11u8, 0u8, 1u8, 128u8, 11u8,
+                0u8, 1u8, 0u8, 12u8, 0u8, 1u8, 128u8, 12u8, 0u8, 1u8, 0u8, 13u8, 0u8, 1u8, 128u8,
+                13u8, 0u8, 1u8, 0u8, 14u8, 0u8, 1u8, 128u8, 14u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8,
+                128u8, 15u8, 0u8, 1u8, 0u8, 16u8, 0u8, 1u8, 128u8, 16u8, 0u8, 1u8, 0u8, 17u8, 0u8,
+                1u8, 128u8, 17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 18u8, 0u8, 1u8, 128u8, 18u8,
+                0u8, 1u8, 0u8, 19u8, 0u8, 1u8, 128u8, 19u8, 0u8, 1u8, 4u8, 2u8, 0u8, 1u8, 128u8,
+                20u8, 0u8, 1u8, 0u8, 21u8, 0u8, 1u8, 128u8, 21u8, 0u8, 1u8, 0u8, 22u8, 0u8, 1u8,
+                128u8, 22u8, 0u8, 1u8, 0u8, 23u8, 0u8, 1u8, 128u8, 23u8, 0u8, 1u8, 0u8, 24u8, 0u8,
+                1u8, 128u8, 24u8, 0u8, 1u8, 132u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 144u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 160u8, 0u8,
+                0u8, 1u8, 132u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 140u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 160u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 176u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 4u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 4u8, 2u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 4u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 144u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8,
+                0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8,
+                0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8,
+                0u8, 1u8, 4u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8,
+                0u8, 1u8, 4u8, 4u8, 0u8, 1u8, 132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 8u8, 1u8,
+                0u8, 1u8, 136u8, 1u8, 0u8, 1u8, 8u8, 2u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 8u8, 3u8,
+                0u8, 1u8, 136u8, 3u8, 0u8, 1u8, 8u8, 4u8, 0u8, 1u8, 136u8, 4u8, 0u8, 1u8, 140u8,
+                0u8, 0u8, 1u8, 12u8, 1u8, 0u8, 1u8, 140u8, 1u8, 0u8, 1u8, 12u8, 2u8, 0u8, 1u8,
+                140u8, 2u8, 0u8, 1u8, 12u8, 3u8, 0u8, 1u8, 140u8, 3u8, 0u8, 1u8, 12u8, 4u8, 0u8,
+                1u8, 140u8, 4u8, 0u8, 1u8, 144u8, 0u8, 0u8, 1u8, 16u8, 1u8, 0u8, 1u8, 144u8, 1u8,
+                0u8, 1u8, 16u8, 2u8, 0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 16u8, 3u8, 0u8, 1u8, 144u8,
+                3u8, 0u8, 1u8, 16u8, 4u8, 0u8, 1u8, 144u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 2u8, 128u8,
+                0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8,
+                136u8, 2u8, 0u8, 1u8, 140u8, 2u8, 0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 136u8, 2u8,
+                0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 140u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8,
+                0u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8,
+                140u8, 0u8, 0u8, 1u8, 144u8, 0u8, 0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 132u8,
+                0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8,
+                132u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 136u8, 0u8, 0u8,
+                1u8, 136u8, 1u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 136u8, 2u8,
+                0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 140u8, 2u8, 0u8, 1u8, 128u8,
+                2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 2u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 3u8,
+                0u8, 1u8, 0u8, 4u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8, 4u8, 0u8, 1u8,
+                132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 8u8, 1u8, 0u8, 1u8, 136u8, 1u8, 0u8,
+                1u8, 8u8, 2u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 8u8, 3u8, 0u8, 1u8, 136u8, 3u8, 0u8,
+                1u8, 8u8, 4u8, 0u8, 1u8, 136u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 132u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 136u8, 4u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 132u8, 0u8, 0u8,
+                1u8, 4u8, 1u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 144u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8,
+                1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8,
+                0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 0u8,
+                2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8,
+                136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                128u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8,
+                1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 12u8, 128u8, 5u8, 0u8, 2u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 8u8, 1u8, 0u8, 1u8, 136u8, 1u8,
+                0u8, 1u8, 8u8, 2u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 8u8, 3u8, 0u8, 1u8, 136u8, 3u8,
+                0u8, 1u8, 8u8, 4u8, 0u8, 1u8, 136u8, 4u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 12u8,
+                1u8, 0u8, 1u8, 140u8, 1u8, 0u8, 1u8, 12u8, 2u8, 0u8, 1u8, 140u8, 2u8, 0u8, 1u8,
+                12u8, 3u8, 0u8, 1u8, 140u8, 3u8, 0u8, 1u8, 12u8, 4u8, 0u8, 1u8, 140u8, 4u8, 0u8,
+                1u8, 144u8, 0u8, 0u8, 1u8, 16u8, 1u8, 0u8, 1u8, 144u8, 1u8, 0u8, 1u8, 16u8, 2u8,
+                0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 16u8, 3u8, 0u8, 1u8, 144u8, 3u8, 0u8, 1u8, 16u8,
+                4u8, 0u8, 1u8, 144u8, 4u8, 0u8, 1u8, 148u8, 0u8, 0u8, 1u8, 20u8, 1u8, 0u8, 1u8,
+                148u8, 1u8, 0u8, 1u8, 20u8, 2u8, 0u8, 1u8, 148u8, 2u8, 0u8, 1u8, 20u8, 3u8, 0u8,
+                1u8, 148u8, 3u8, 0u8, 1u8, 20u8, 4u8, 0u8, 1u8, 148u8, 4u8, 0u8, 12u8, 128u8, 0u8,
+                0u8, 6u8, 128u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 3u8, 128u8, 0u8, 0u8, 12u8, 128u8,
+                2u8, 0u8, 2u8, 128u8, 0u8, 0u8, 12u8, 128u8, 3u8, 0u8, 3u8, 0u8, 1u8, 0u8, 4u8,
+                128u8, 1u8, 0u8, 6u8, 128u8, 2u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8,
+                1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 132u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8,
+                0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 132u8, 0u8,
+                0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 132u8,
+                2u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8, 4u8, 4u8, 0u8, 1u8,
+                132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 8u8, 1u8, 0u8, 1u8, 136u8, 1u8, 0u8,
+                1u8, 8u8, 2u8, 0u8, 1u8, 136u8, 2u8, 0u8, 1u8, 8u8, 3u8, 0u8, 1u8, 136u8, 3u8, 0u8,
+                1u8, 8u8, 4u8, 0u8, 1u8, 136u8, 4u8, 0u8, 2u8, 128u8, 0u8, 0u8, 4u8, 128u8, 0u8,
+                0u8, 3u8, 128u8, 0u8, 0u8, 3u8, 0u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8, 136u8,
+                0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 132u8,
+                0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8,
+                4u8, 0u8, 1u8, 132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8,
+                0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8,
+                0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8, 4u8, 0u8, 1u8,
+                132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 1u8, 140u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8,
+                1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8, 0u8, 1u8, 4u8, 2u8, 0u8,
+                1u8, 132u8, 2u8, 0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8, 0u8, 1u8, 4u8, 4u8, 0u8,
+                1u8, 132u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8,
+                1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 4u8, 1u8, 0u8, 1u8, 132u8, 1u8,
+                0u8, 1u8, 4u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8, 1u8, 4u8, 3u8, 0u8, 1u8, 132u8, 3u8,
+                0u8, 1u8, 4u8, 4u8, 0u8, 1u8, 132u8, 4u8, 0u8, 1u8, 136u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 64u8, 129u8, 0u8, 0u8, 160u8, 128u8,
+                0u8, 0u8, 80u8, 128u8, 0u8, 0u8, 64u8, 128u8, 0u8, 0u8, 40u8, 128u8, 0u8, 0u8,
+                32u8, 128u8, 0u8, 0u8, 80u8, 128u8, 1u8, 0u8, 64u8, 128u8, 1u8, 0u8, 20u8, 128u8,
+                0u8, 0u8, 16u8, 128u8, 0u8, 0u8, 16u8, 128u8, 0u8, 0u8, 10u8, 128u8, 0u8, 0u8, 8u8,
+                128u8, 0u8, 0u8, 20u8, 128u8, 1u8, 0u8, 16u8, 128u8, 1u8, 0u8, 5u8, 128u8, 0u8,
+                0u8, 4u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 4u8, 128u8,
+                1u8, 0u8, 64u8, 129u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8,
+                2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8,
+                4u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8,
+                3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 0u8,
+                2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8,
+                4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8,
+                128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8,
+                1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8,
+                0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 12u8,
+                108u8, 0u8, 1u8, 12u8, 216u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                128u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 0u8, 4u8, 0u8,
+                1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 128u8, 4u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8,
+                0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 128u8, 2u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 3u8, 128u8, 0u8, 0u8, 3u8, 0u8, 1u8, 0u8, 6u8, 128u8, 2u8, 0u8, 3u8, 128u8,
+                0u8, 0u8, 3u8, 0u8, 1u8, 0u8, 8u8, 128u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 6u8,
+                128u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 4u8, 128u8, 0u8, 0u8, 2u8, 128u8, 0u8, 0u8,
+                3u8, 128u8, 0u8, 0u8, 3u8, 0u8, 1u8, 0u8, 1u8, 4u8, 2u8, 0u8, 1u8, 132u8, 2u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8,
+                1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 136u8, 0u8,
+                0u8, 1u8, 144u8, 0u8, 0u8, 1u8, 152u8, 0u8, 0u8, 1u8, 160u8, 0u8, 0u8, 1u8, 168u8,
+                0u8, 0u8, 1u8, 176u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8,
+                0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8,
+                2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8,
+                4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8, 0u8, 1u8, 128u8, 5u8, 0u8, 1u8,
+                0u8, 6u8, 0u8, 1u8, 128u8, 6u8, 0u8, 1u8, 0u8, 7u8, 0u8, 1u8, 128u8, 7u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 1u8, 128u8, 8u8, 0u8, 1u8, 0u8, 9u8, 0u8, 1u8, 128u8, 9u8, 0u8, 1u8,
+                128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 1u8, 128u8, 8u8, 0u8, 1u8, 0u8, 9u8, 0u8, 1u8, 128u8, 9u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                4u8, 4u8, 0u8, 1u8, 132u8, 4u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 128u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8,
+                1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8, 3u8, 0u8,
+                1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 128u8, 1u8, 0u8, 1u8, 148u8, 0u8, 0u8, 1u8, 156u8, 0u8,
+                0u8, 1u8, 28u8, 1u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 128u8,
+                1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8, 128u8,
+                3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 128u8,
+                0u8, 0u8, 2u8, 128u8, 0u8, 0u8, 4u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+                128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 144u8, 0u8, 0u8, 1u8, 148u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 140u8, 2u8, 0u8,
+                1u8, 12u8, 3u8, 0u8, 1u8, 140u8, 3u8, 0u8, 1u8, 12u8, 4u8, 0u8, 1u8, 140u8, 4u8,
+                0u8, 1u8, 144u8, 0u8, 0u8, 1u8, 16u8, 1u8, 0u8, 1u8, 144u8, 1u8, 0u8, 1u8, 16u8,
+                2u8, 0u8, 1u8, 144u8, 2u8, 0u8, 1u8, 16u8, 3u8, 0u8, 1u8, 144u8, 3u8, 0u8, 1u8,
+                16u8, 4u8, 0u8, 1u8, 144u8, 4u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8, 1u8,
+                128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 132u8, 0u8, 0u8,
+                1u8, 8u8, 2u8, 0u8, 1u8, 8u8, 3u8, 0u8, 1u8, 12u8, 1u8, 0u8, 1u8, 144u8, 0u8, 0u8,
+                2u8, 128u8, 0u8, 0u8, 6u8, 128u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 1u8, 0u8, 0u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 128u8, 1u8, 0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 128u8, 2u8, 0u8, 1u8, 0u8, 3u8, 0u8,
+                1u8, 128u8, 3u8, 0u8, 1u8, 0u8, 4u8, 0u8, 1u8, 128u8, 4u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                1u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 132u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 4u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 4u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 1u8, 128u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::NumericValue(0u32),
+    ),
+);
@@ -0,0 +1,224 @@
+// @generated
+#![cfg(feature = "icu_properties")]
+type DataStruct = < :: icu_properties :: provider :: VerticalOrientationV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_properties::provider::PropertyCodePointMapV1::CodePointTrie(
+    ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 1114112u32,
+            shifted12_high_start: 272u16,
+            index3_null_offset: 12u16,
+            data_null_offset: 0u32,
+            null_value: 0u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 89u8, 0u8, 152u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 208u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                73u8, 3u8, 99u8, 3u8, 113u8, 3u8, 135u8, 3u8, 167u8, 3u8, 197u8, 3u8, 223u8, 3u8,
+                249u8, 3u8, 99u8, 3u8, 99u8, 3u8, 99u8, 3u8, 25u8, 4u8, 99u8, 3u8, 99u8, 3u8, 99u8,
+                3u8, 25u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8,
+                57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8,
+                4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8,
+                57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8,
+                4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8,
+                57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8, 4u8, 57u8,
+                4u8, 99u8, 3u8, 99u8, 3u8, 99u8, 3u8, 25u8, 4u8, 99u8, 3u8, 99u8, 3u8, 99u8, 3u8,
+                25u8, 4u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8,
+                0u8, 112u8, 0u8, 89u8, 0u8, 105u8, 0u8, 121u8, 0u8, 137u8, 0u8, 152u8, 0u8, 168u8,
+                0u8, 184u8, 0u8, 200u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8,
+                16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8,
+                0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 208u8, 0u8, 224u8, 0u8, 240u8, 0u8, 0u8, 1u8,
+                0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8,
+                0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 16u8, 0u8,
+                32u8, 0u8, 48u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 15u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 169u8, 0u8, 150u8, 0u8, 30u8, 1u8, 44u8, 1u8, 174u8, 0u8,
+                170u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 1u8,
+                60u8, 1u8, 0u8, 0u8, 76u8, 1u8, 88u8, 1u8, 102u8, 1u8, 11u8, 1u8, 117u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 132u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 114u8, 0u8, 0u8, 0u8, 246u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 144u8, 1u8, 16u8, 1u8, 152u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 3u8, 1u8, 16u8, 1u8, 21u8, 1u8, 0u8, 0u8, 236u8, 0u8, 168u8, 1u8, 182u8,
+                1u8, 14u8, 1u8, 16u8, 1u8, 16u8, 1u8, 198u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 22u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 24u8, 1u8, 10u8, 1u8, 16u8, 1u8, 210u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 14u8, 1u8, 16u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 22u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 168u8, 0u8, 0u8,
+                0u8, 8u8, 1u8, 16u8, 1u8, 226u8, 1u8, 20u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 150u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 241u8,
+                1u8, 255u8, 1u8, 16u8, 1u8, 14u8, 2u8, 29u8, 2u8, 16u8, 1u8, 42u8, 2u8, 16u8, 1u8,
+                55u8, 2u8, 70u8, 2u8, 86u8, 2u8, 16u8, 1u8, 42u8, 2u8, 16u8, 1u8, 55u8, 2u8, 97u8,
+                2u8, 16u8, 1u8, 16u8, 1u8, 110u8, 2u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 126u8, 2u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 15u8, 2u8, 126u8, 2u8, 126u8, 2u8, 126u8, 2u8, 126u8, 2u8, 126u8,
+                2u8, 134u8, 2u8, 16u8, 1u8, 142u8, 2u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 0u8, 0u8,
+                16u8, 1u8, 23u8, 1u8, 155u8, 2u8, 170u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 186u8, 2u8, 201u8, 2u8,
+                16u8, 1u8, 217u8, 2u8, 16u8, 1u8, 233u8, 2u8, 248u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 3u8, 24u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8,
+                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8,
+                16u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 40u8, 3u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8,
+                16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8,
+                1u8, 18u8, 1u8, 132u8, 0u8, 152u8, 0u8, 168u8, 0u8, 168u8, 0u8, 168u8, 0u8, 168u8,
+                0u8, 168u8, 0u8, 168u8, 0u8, 200u8, 0u8, 12u8, 0u8, 232u8, 0u8, 0u8, 1u8, 21u8,
+                1u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 52u8, 1u8, 83u8, 1u8, 114u8, 1u8, 145u8, 1u8,
+                12u8, 0u8, 171u8, 1u8, 12u8, 0u8, 203u8, 1u8, 235u8, 1u8, 11u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 251u8, 0u8, 12u8, 0u8, 67u8, 2u8, 12u8, 0u8, 35u8, 2u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 248u8, 0u8, 12u8, 0u8, 98u8, 2u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 130u8, 2u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 156u8, 2u8, 12u8, 0u8, 12u8, 0u8, 252u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 35u8, 2u8, 35u8, 2u8, 184u8, 2u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 35u8, 2u8, 0u8, 1u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 188u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 240u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 220u8, 2u8, 35u8, 2u8, 248u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 235u8,
+                2u8, 35u8, 2u8, 8u8, 3u8, 12u8, 0u8, 12u8, 0u8, 35u8, 2u8, 253u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 35u8, 2u8, 40u8, 3u8, 35u8, 2u8, 35u8, 2u8, 200u8, 0u8, 248u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8,
+                2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 35u8, 2u8,
+                35u8, 2u8, 35u8, 2u8, 35u8, 2u8, 41u8, 3u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8,
+                0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+                12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8, 12u8, 0u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 0u8, 0u8, 3u8, 0u8,
+                0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 3u8, 3u8,
+                3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 0u8, 3u8, 0u8, 3u8, 0u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 1u8, 1u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 3u8, 3u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8,
+                1u8, 3u8, 3u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 3u8,
+                2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8, 3u8, 3u8, 3u8,
+                3u8, 2u8, 2u8, 3u8, 3u8, 3u8, 1u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8, 3u8, 2u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 1u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8,
+                2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 2u8, 2u8, 2u8, 2u8, 2u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 0u8, 1u8, 1u8, 1u8, 1u8, 1u8, 1u8, 3u8, 3u8, 3u8, 0u8, 0u8, 0u8,
+                0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 2u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 1u8, 1u8, 3u8, 3u8, 2u8, 0u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 1u8, 1u8, 0u8, 0u8, 0u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 1u8, 3u8, 1u8, 3u8, 1u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 1u8, 1u8, 1u8, 1u8, 1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 1u8, 3u8, 3u8, 3u8, 3u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 0u8, 0u8, 0u8, 3u8, 3u8, 0u8, 0u8, 2u8, 2u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 0u8, 0u8,
+            ])
+        },
+        ::icu_properties::VerticalOrientation(0u8),
+    ),
+);
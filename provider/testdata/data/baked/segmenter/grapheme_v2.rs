@@ -661,4 +661,7 @@ static UND: &DataStruct = &::icu_segmenter::provider::RuleBreakDataV1 {
     sot_property: 18u8,
     eot_property: 19u8,
     complex_property: 127u8,
+    rule_status_table: ::icu_segmenter::provider::RuleStatusTable(unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[])
+    }),
 };
@@ -0,0 +1,60 @@
+// @generated
+#![cfg(feature = "icu_segmenter")]
+type DataStruct = < :: icu_segmenter :: provider :: HyphenationPatternsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("en", EN)]);
+static EN: &DataStruct = &::icu_segmenter::provider::HyphenationPatternsV1 {
+    patterns: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 7u8, 0u8, 12u8, 0u8, 16u8, 0u8, 18u8,
+                    0u8, 20u8, 0u8, 23u8, 0u8, 25u8, 0u8, 104u8, 101u8, 110u8, 104u8, 101u8, 110u8,
+                    97u8, 104u8, 101u8, 110u8, 97u8, 116u8, 104u8, 121u8, 112u8, 104u8, 105u8,
+                    111u8, 110u8, 97u8, 110u8, 97u8, 116u8, 111u8, 110u8, 116u8, 105u8, 111u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    9u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 9u8, 0u8, 15u8, 0u8, 20u8, 0u8, 23u8,
+                    0u8, 26u8, 0u8, 30u8, 0u8, 33u8, 0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                    4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 2u8, 0u8, 0u8, 1u8,
+                    0u8, 0u8, 0u8, 2u8, 0u8, 0u8, 0u8, 2u8, 0u8, 1u8, 0u8, 0u8, 0u8,
+                ])
+            },
+        )
+    },
+    exceptions: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 19u8, 0u8, 30u8, 0u8, 40u8, 0u8, 53u8,
+                    0u8, 60u8, 0u8, 68u8, 0u8, 75u8, 0u8, 83u8, 0u8, 94u8, 0u8, 106u8, 0u8, 117u8,
+                    0u8, 128u8, 0u8, 97u8, 115u8, 115u8, 111u8, 99u8, 105u8, 97u8, 116u8, 101u8,
+                    97u8, 115u8, 115u8, 111u8, 99u8, 105u8, 97u8, 116u8, 101u8, 115u8, 100u8,
+                    101u8, 99u8, 108u8, 105u8, 110u8, 97u8, 116u8, 105u8, 111u8, 110u8, 111u8,
+                    98u8, 108u8, 105u8, 103u8, 97u8, 116u8, 111u8, 114u8, 121u8, 112u8, 104u8,
+                    105u8, 108u8, 97u8, 110u8, 116u8, 104u8, 114u8, 111u8, 112u8, 105u8, 99u8,
+                    112u8, 114u8, 101u8, 115u8, 101u8, 110u8, 116u8, 112u8, 114u8, 101u8, 115u8,
+                    101u8, 110u8, 116u8, 115u8, 112u8, 114u8, 111u8, 106u8, 101u8, 99u8, 116u8,
+                    112u8, 114u8, 111u8, 106u8, 101u8, 99u8, 116u8, 115u8, 114u8, 101u8, 99u8,
+                    105u8, 112u8, 114u8, 111u8, 99u8, 105u8, 116u8, 121u8, 114u8, 101u8, 99u8,
+                    111u8, 103u8, 110u8, 105u8, 122u8, 97u8, 110u8, 99u8, 101u8, 114u8, 101u8,
+                    102u8, 111u8, 114u8, 109u8, 97u8, 116u8, 105u8, 111u8, 110u8, 114u8, 101u8,
+                    116u8, 114u8, 105u8, 98u8, 117u8, 116u8, 105u8, 111u8, 110u8, 116u8, 97u8,
+                    98u8, 108u8, 101u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    14u8, 0u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 4u8, 0u8, 7u8, 0u8, 9u8, 0u8, 11u8,
+                    0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 11u8, 0u8, 12u8, 0u8, 15u8, 0u8, 18u8,
+                    0u8, 21u8, 0u8, 2u8, 4u8, 2u8, 4u8, 3u8, 5u8, 7u8, 5u8, 6u8, 4u8, 6u8, 4u8,
+                    2u8, 5u8, 7u8, 3u8, 5u8, 7u8, 3u8, 5u8, 7u8, 2u8,
+                ])
+            },
+        )
+    },
+};
@@ -1462,4 +1462,7 @@ static UND: &DataStruct = &::icu_segmenter::provider::RuleBreakDataV1 {
     sot_property: 60u8,
     eot_property: 61u8,
     complex_property: 40u8,
+    rule_status_table: ::icu_segmenter::provider::RuleStatusTable(unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[])
+    }),
 };
@@ -1,7 +1,9 @@
 // @generated
 pub mod dictionary_v1;
-pub mod grapheme_v1;
-pub mod line_v1;
+pub mod grapheme_v2;
+pub mod hyphenation_v1;
+pub mod line_v2;
 pub mod lstm_v1;
-pub mod sentence_v1;
-pub mod word_v1;
+pub mod sentence;
+pub mod sentence_v2;
+pub mod word_v2;
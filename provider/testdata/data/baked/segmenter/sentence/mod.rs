@@ -0,0 +1,2 @@
+// @generated
+pub mod suppressions_v1;
@@ -0,0 +1,129 @@
+// @generated
+#![cfg(feature = "icu_segmenter")]
+type DataStruct = < :: icu_segmenter :: provider :: SentenceBreakSuppressionsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("en", EN),
+        ("es", ES),
+        ("fr", FR),
+        ("ru", RU),
+        ("und", UND),
+    ]);
+static EN: &DataStruct = &::icu_segmenter::provider::SentenceBreakSuppressionsV1 {
+    suppressions: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 12u8, 0u8, 16u8, 0u8, 20u8, 0u8,
+            24u8, 0u8, 31u8, 0u8, 35u8, 0u8, 40u8, 0u8, 46u8, 0u8, 51u8, 0u8, 55u8, 0u8, 59u8, 0u8,
+            63u8, 0u8, 67u8, 0u8, 72u8, 0u8, 77u8, 0u8, 82u8, 0u8, 87u8, 0u8, 91u8, 0u8, 96u8, 0u8,
+            99u8, 0u8, 103u8, 0u8, 109u8, 0u8, 113u8, 0u8, 118u8, 0u8, 122u8, 0u8, 126u8, 0u8,
+            129u8, 0u8, 133u8, 0u8, 138u8, 0u8, 141u8, 0u8, 145u8, 0u8, 149u8, 0u8, 153u8, 0u8,
+            157u8, 0u8, 161u8, 0u8, 165u8, 0u8, 169u8, 0u8, 173u8, 0u8, 178u8, 0u8, 182u8, 0u8,
+            187u8, 0u8, 191u8, 0u8, 194u8, 0u8, 198u8, 0u8, 202u8, 0u8, 205u8, 0u8, 209u8, 0u8,
+            213u8, 0u8, 216u8, 0u8, 219u8, 0u8, 223u8, 0u8, 226u8, 0u8, 230u8, 0u8, 234u8, 0u8,
+            238u8, 0u8, 245u8, 0u8, 250u8, 0u8, 254u8, 0u8, 1u8, 1u8, 5u8, 1u8, 8u8, 1u8, 13u8,
+            1u8, 16u8, 1u8, 20u8, 1u8, 24u8, 1u8, 27u8, 1u8, 31u8, 1u8, 35u8, 1u8, 38u8, 1u8, 42u8,
+            1u8, 46u8, 1u8, 51u8, 1u8, 56u8, 1u8, 61u8, 1u8, 65u8, 1u8, 69u8, 1u8, 74u8, 1u8, 78u8,
+            1u8, 82u8, 1u8, 85u8, 1u8, 89u8, 1u8, 93u8, 1u8, 98u8, 1u8, 102u8, 1u8, 107u8, 1u8,
+            111u8, 1u8, 114u8, 1u8, 117u8, 1u8, 122u8, 1u8, 127u8, 1u8, 131u8, 1u8, 135u8, 1u8,
+            139u8, 1u8, 145u8, 1u8, 149u8, 1u8, 153u8, 1u8, 157u8, 1u8, 161u8, 1u8, 65u8, 46u8,
+            68u8, 46u8, 65u8, 46u8, 77u8, 46u8, 65u8, 100u8, 106u8, 46u8, 65u8, 100u8, 109u8, 46u8,
+            65u8, 100u8, 118u8, 46u8, 65u8, 108u8, 116u8, 46u8, 65u8, 112u8, 112u8, 114u8, 111u8,
+            120u8, 46u8, 65u8, 112u8, 114u8, 46u8, 65u8, 115u8, 115u8, 110u8, 46u8, 65u8, 115u8,
+            115u8, 111u8, 99u8, 46u8, 65u8, 115u8, 115u8, 116u8, 46u8, 65u8, 117u8, 103u8, 46u8,
+            65u8, 118u8, 101u8, 46u8, 66u8, 46u8, 65u8, 46u8, 66u8, 46u8, 83u8, 46u8, 66u8, 108u8,
+            118u8, 100u8, 46u8, 66u8, 114u8, 105u8, 103u8, 46u8, 66u8, 114u8, 111u8, 115u8, 46u8,
+            67u8, 97u8, 112u8, 116u8, 46u8, 67u8, 100u8, 114u8, 46u8, 67u8, 109u8, 100u8, 114u8,
+            46u8, 67u8, 111u8, 46u8, 67u8, 111u8, 108u8, 46u8, 67u8, 111u8, 109u8, 100u8, 114u8,
+            46u8, 67u8, 111u8, 110u8, 46u8, 67u8, 111u8, 114u8, 112u8, 46u8, 67u8, 112u8, 108u8,
+            46u8, 68u8, 46u8, 67u8, 46u8, 68u8, 82u8, 46u8, 68u8, 101u8, 99u8, 46u8, 68u8, 101u8,
+            112u8, 116u8, 46u8, 68u8, 114u8, 46u8, 69u8, 46u8, 85u8, 46u8, 69u8, 110u8, 115u8,
+            46u8, 69u8, 115u8, 113u8, 46u8, 70u8, 101u8, 98u8, 46u8, 70u8, 114u8, 105u8, 46u8,
+            71u8, 101u8, 110u8, 46u8, 71u8, 111u8, 118u8, 46u8, 72u8, 111u8, 110u8, 46u8, 72u8,
+            111u8, 115u8, 112u8, 46u8, 73u8, 110u8, 99u8, 46u8, 73u8, 110u8, 115u8, 112u8, 46u8,
+            74u8, 97u8, 110u8, 46u8, 74u8, 114u8, 46u8, 74u8, 117u8, 108u8, 46u8, 74u8, 117u8,
+            110u8, 46u8, 76u8, 116u8, 46u8, 76u8, 116u8, 100u8, 46u8, 77u8, 46u8, 68u8, 46u8, 77u8,
+            77u8, 46u8, 77u8, 82u8, 46u8, 77u8, 82u8, 83u8, 46u8, 77u8, 83u8, 46u8, 77u8, 83u8,
+            82u8, 46u8, 77u8, 97u8, 106u8, 46u8, 77u8, 97u8, 114u8, 46u8, 77u8, 101u8, 115u8,
+            115u8, 114u8, 115u8, 46u8, 77u8, 108u8, 108u8, 101u8, 46u8, 77u8, 109u8, 101u8, 46u8,
+            77u8, 114u8, 46u8, 77u8, 114u8, 115u8, 46u8, 77u8, 115u8, 46u8, 77u8, 115u8, 103u8,
+            114u8, 46u8, 77u8, 116u8, 46u8, 78u8, 46u8, 86u8, 46u8, 78u8, 46u8, 89u8, 46u8, 78u8,
+            111u8, 46u8, 78u8, 111u8, 118u8, 46u8, 79u8, 99u8, 116u8, 46u8, 79u8, 112u8, 46u8,
+            80u8, 46u8, 77u8, 46u8, 80u8, 46u8, 79u8, 46u8, 80u8, 104u8, 46u8, 68u8, 46u8, 80u8,
+            114u8, 101u8, 115u8, 46u8, 80u8, 114u8, 111u8, 102u8, 46u8, 80u8, 118u8, 116u8, 46u8,
+            82u8, 101u8, 112u8, 46u8, 82u8, 101u8, 112u8, 115u8, 46u8, 82u8, 101u8, 115u8, 46u8,
+            82u8, 101u8, 118u8, 46u8, 82u8, 116u8, 46u8, 83u8, 46u8, 65u8, 46u8, 83u8, 101u8,
+            110u8, 46u8, 83u8, 101u8, 110u8, 115u8, 46u8, 83u8, 101u8, 112u8, 46u8, 83u8, 101u8,
+            112u8, 116u8, 46u8, 83u8, 103u8, 116u8, 46u8, 83u8, 114u8, 46u8, 83u8, 116u8, 46u8,
+            83u8, 117u8, 112u8, 116u8, 46u8, 83u8, 117u8, 114u8, 103u8, 46u8, 84u8, 117u8, 101u8,
+            46u8, 85u8, 46u8, 75u8, 46u8, 85u8, 46u8, 83u8, 46u8, 85u8, 46u8, 83u8, 46u8, 65u8,
+            46u8, 86u8, 46u8, 80u8, 46u8, 87u8, 101u8, 100u8, 46u8, 101u8, 46u8, 103u8, 46u8,
+            105u8, 46u8, 101u8, 46u8, 118u8, 115u8, 46u8,
+        ])
+    },
+};
+static ES: &DataStruct = &::icu_segmenter::provider::SentenceBreakSuppressionsV1 {
+    suppressions: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            41u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 7u8, 0u8, 12u8, 0u8, 15u8, 0u8, 20u8, 0u8,
+            24u8, 0u8, 28u8, 0u8, 33u8, 0u8, 35u8, 0u8, 38u8, 0u8, 42u8, 0u8, 46u8, 0u8, 52u8, 0u8,
+            58u8, 0u8, 62u8, 0u8, 67u8, 0u8, 72u8, 0u8, 76u8, 0u8, 80u8, 0u8, 85u8, 0u8, 89u8, 0u8,
+            94u8, 0u8, 99u8, 0u8, 103u8, 0u8, 107u8, 0u8, 110u8, 0u8, 114u8, 0u8, 119u8, 0u8,
+            123u8, 0u8, 127u8, 0u8, 130u8, 0u8, 134u8, 0u8, 138u8, 0u8, 142u8, 0u8, 148u8, 0u8,
+            152u8, 0u8, 156u8, 0u8, 161u8, 0u8, 164u8, 0u8, 169u8, 0u8, 65u8, 46u8, 67u8, 46u8,
+            65u8, 65u8, 46u8, 65u8, 112u8, 100u8, 111u8, 46u8, 65u8, 118u8, 46u8, 65u8, 118u8,
+            100u8, 97u8, 46u8, 66u8, 99u8, 111u8, 46u8, 67u8, 46u8, 86u8, 46u8, 67u8, 195u8, 173u8,
+            97u8, 46u8, 68u8, 46u8, 68u8, 114u8, 46u8, 68u8, 114u8, 97u8, 46u8, 68u8, 194u8, 170u8,
+            46u8, 69u8, 69u8, 46u8, 85u8, 85u8, 46u8, 69u8, 120u8, 99u8, 109u8, 111u8, 46u8, 70u8,
+            100u8, 111u8, 46u8, 71u8, 114u8, 97u8, 108u8, 46u8, 73u8, 108u8, 109u8, 111u8, 46u8,
+            76u8, 100u8, 111u8, 46u8, 76u8, 105u8, 99u8, 46u8, 77u8, 116u8, 114u8, 111u8, 46u8,
+            78u8, 46u8, 194u8, 186u8, 80u8, 114u8, 111u8, 102u8, 46u8, 80u8, 195u8, 161u8, 103u8,
+            46u8, 83u8, 46u8, 65u8, 46u8, 83u8, 46u8, 76u8, 46u8, 83u8, 114u8, 46u8, 83u8, 114u8,
+            97u8, 46u8, 83u8, 114u8, 116u8, 97u8, 46u8, 83u8, 116u8, 97u8, 46u8, 83u8, 116u8,
+            111u8, 46u8, 85u8, 100u8, 46u8, 85u8, 100u8, 115u8, 46u8, 97u8, 46u8, 67u8, 46u8, 97u8,
+            100u8, 106u8, 46u8, 97u8, 112u8, 114u8, 111u8, 120u8, 46u8, 100u8, 46u8, 67u8, 46u8,
+            101u8, 116u8, 99u8, 46u8, 112u8, 46u8, 101u8, 106u8, 46u8, 112u8, 112u8, 46u8, 112u8,
+            195u8, 161u8, 103u8, 46u8, 118u8, 115u8, 46u8,
+        ])
+    },
+};
+static FR: &DataStruct = &::icu_segmenter::provider::SentenceBreakSuppressionsV1 {
+    suppressions: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            30u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 13u8, 0u8, 16u8, 0u8, 18u8, 0u8, 21u8, 0u8,
+            24u8, 0u8, 28u8, 0u8, 33u8, 0u8, 37u8, 0u8, 41u8, 0u8, 45u8, 0u8, 51u8, 0u8, 54u8, 0u8,
+            64u8, 0u8, 69u8, 0u8, 73u8, 0u8, 76u8, 0u8, 80u8, 0u8, 88u8, 0u8, 93u8, 0u8, 97u8, 0u8,
+            103u8, 0u8, 108u8, 0u8, 113u8, 0u8, 117u8, 0u8, 121u8, 0u8, 123u8, 0u8, 128u8, 0u8,
+            133u8, 0u8, 66u8, 111u8, 117u8, 108u8, 46u8, 67u8, 46u8, 45u8, 195u8, 160u8, 45u8,
+            100u8, 46u8, 68u8, 114u8, 46u8, 77u8, 46u8, 77u8, 77u8, 46u8, 77u8, 101u8, 46u8, 77u8,
+            103u8, 114u8, 46u8, 77u8, 108u8, 108u8, 101u8, 46u8, 77u8, 109u8, 101u8, 46u8, 78u8,
+            46u8, 66u8, 46u8, 78u8, 46u8, 68u8, 46u8, 78u8, 46u8, 68u8, 46u8, 65u8, 46u8, 80u8,
+            114u8, 46u8, 83u8, 46u8, 77u8, 46u8, 65u8, 46u8, 82u8, 46u8, 84u8, 46u8, 83u8, 116u8,
+            195u8, 169u8, 46u8, 85u8, 46u8, 69u8, 46u8, 97u8, 118u8, 46u8, 97u8, 118u8, 114u8,
+            46u8, 99u8, 46u8, 45u8, 195u8, 160u8, 45u8, 100u8, 46u8, 100u8, 195u8, 169u8, 99u8,
+            46u8, 101u8, 116u8, 99u8, 46u8, 102u8, 195u8, 169u8, 118u8, 114u8, 46u8, 106u8, 97u8,
+            110u8, 118u8, 46u8, 106u8, 117u8, 105u8, 108u8, 46u8, 110u8, 111u8, 118u8, 46u8, 111u8,
+            99u8, 116u8, 46u8, 112u8, 46u8, 112u8, 46u8, 101u8, 120u8, 46u8, 115u8, 101u8, 112u8,
+            116u8, 46u8, 195u8, 169u8, 100u8, 46u8,
+        ])
+    },
+};
+static RU: &DataStruct = &::icu_segmenter::provider::SentenceBreakSuppressionsV1 {
+    suppressions: unsafe {
+        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+            17u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 8u8, 0u8, 13u8, 0u8, 23u8, 0u8, 33u8, 0u8,
+            38u8, 0u8, 43u8, 0u8, 52u8, 0u8, 57u8, 0u8, 60u8, 0u8, 65u8, 0u8, 72u8, 0u8, 79u8, 0u8,
+            86u8, 0u8, 93u8, 0u8, 100u8, 0u8, 208u8, 179u8, 46u8, 208u8, 179u8, 208u8, 179u8, 46u8,
+            208u8, 180u8, 209u8, 128u8, 46u8, 208u8, 184u8, 32u8, 209u8, 130u8, 46u8, 32u8, 208u8,
+            180u8, 46u8, 208u8, 184u8, 32u8, 209u8, 130u8, 46u8, 32u8, 208u8, 191u8, 46u8, 208u8,
+            184u8, 208u8, 188u8, 46u8, 208u8, 186u8, 208u8, 178u8, 46u8, 208u8, 189u8, 208u8,
+            176u8, 208u8, 191u8, 209u8, 128u8, 46u8, 208u8, 191u8, 209u8, 128u8, 46u8, 209u8,
+            129u8, 46u8, 209u8, 129u8, 209u8, 130u8, 46u8, 209u8, 129u8, 209u8, 130u8, 209u8,
+            128u8, 46u8, 209u8, 130u8, 46u8, 32u8, 208u8, 180u8, 46u8, 209u8, 130u8, 46u8, 32u8,
+            208u8, 181u8, 46u8, 209u8, 130u8, 46u8, 32u8, 208u8, 186u8, 46u8, 209u8, 130u8, 46u8,
+            32u8, 208u8, 191u8, 46u8, 209u8, 131u8, 208u8, 187u8, 46u8,
+        ])
+    },
+};
+static UND: &DataStruct = &::icu_segmenter::provider::SentenceBreakSuppressionsV1 {
+    suppressions: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+};
@@ -1019,4 +1019,7 @@ static UND: &DataStruct = &::icu_segmenter::provider::RuleBreakDataV1 {
     sot_property: 24u8,
     eot_property: 25u8,
     complex_property: 127u8,
+    rule_status_table: ::icu_segmenter::provider::RuleStatusTable(unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[])
+    }),
 };
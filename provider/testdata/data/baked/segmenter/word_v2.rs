@@ -1097,4 +1097,11 @@ static UND: &DataStruct = &::icu_segmenter::provider::RuleBreakDataV1 {
     sot_property: 41u8,
     eot_property: 42u8,
     complex_property: 20u8,
+    rule_status_table: ::icu_segmenter::provider::RuleStatusTable(unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8,
+            2u8, 0u8, 0u8, 2u8, 2u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ])
+    }),
 };
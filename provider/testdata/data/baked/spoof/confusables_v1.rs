@@ -0,0 +1,4185 @@
+// @generated
+#![cfg(feature = "icu_spoof")]
+type DataStruct =
+    <::icu_spoof::provider::ConfusablesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_spoof::provider::ConfusablesV1 {
+    prototypes: unsafe {
+        #[allow(unused_unsafe)]
+        ::zerovec::ZeroMap::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    34u8, 0u8, 0u8, 37u8, 0u8, 0u8, 48u8, 0u8, 0u8, 49u8, 0u8, 0u8, 73u8, 0u8, 0u8,
+                    96u8, 0u8, 0u8, 109u8, 0u8, 0u8, 124u8, 0u8, 0u8, 160u8, 0u8, 0u8, 162u8, 0u8,
+                    0u8, 165u8, 0u8, 0u8, 175u8, 0u8, 0u8, 180u8, 0u8, 0u8, 181u8, 0u8, 0u8, 184u8,
+                    0u8, 0u8, 198u8, 0u8, 0u8, 199u8, 0u8, 0u8, 208u8, 0u8, 0u8, 215u8, 0u8, 0u8,
+                    216u8, 0u8, 0u8, 230u8, 0u8, 0u8, 231u8, 0u8, 0u8, 240u8, 0u8, 0u8, 246u8, 0u8,
+                    0u8, 248u8, 0u8, 0u8, 16u8, 1u8, 0u8, 17u8, 1u8, 0u8, 26u8, 1u8, 0u8, 27u8,
+                    1u8, 0u8, 38u8, 1u8, 0u8, 39u8, 1u8, 0u8, 49u8, 1u8, 0u8, 50u8, 1u8, 0u8, 51u8,
+                    1u8, 0u8, 63u8, 1u8, 0u8, 64u8, 1u8, 0u8, 65u8, 1u8, 0u8, 66u8, 1u8, 0u8, 70u8,
+                    1u8, 0u8, 73u8, 1u8, 0u8, 80u8, 1u8, 0u8, 82u8, 1u8, 0u8, 83u8, 1u8, 0u8, 99u8,
+                    1u8, 0u8, 102u8, 1u8, 0u8, 103u8, 1u8, 0u8, 127u8, 1u8, 0u8, 128u8, 1u8, 0u8,
+                    129u8, 1u8, 0u8, 130u8, 1u8, 0u8, 131u8, 1u8, 0u8, 132u8, 1u8, 0u8, 135u8, 1u8,
+                    0u8, 137u8, 1u8, 0u8, 138u8, 1u8, 0u8, 140u8, 1u8, 0u8, 141u8, 1u8, 0u8, 145u8,
+                    1u8, 0u8, 146u8, 1u8, 0u8, 147u8, 1u8, 0u8, 150u8, 1u8, 0u8, 151u8, 1u8, 0u8,
+                    152u8, 1u8, 0u8, 153u8, 1u8, 0u8, 154u8, 1u8, 0u8, 157u8, 1u8, 0u8, 158u8, 1u8,
+                    0u8, 159u8, 1u8, 0u8, 160u8, 1u8, 0u8, 161u8, 1u8, 0u8, 164u8, 1u8, 0u8, 165u8,
+                    1u8, 0u8, 166u8, 1u8, 0u8, 167u8, 1u8, 0u8, 172u8, 1u8, 0u8, 173u8, 1u8, 0u8,
+                    174u8, 1u8, 0u8, 179u8, 1u8, 0u8, 180u8, 1u8, 0u8, 181u8, 1u8, 0u8, 182u8, 1u8,
+                    0u8, 183u8, 1u8, 0u8, 187u8, 1u8, 0u8, 188u8, 1u8, 0u8, 189u8, 1u8, 0u8, 191u8,
+                    1u8, 0u8, 192u8, 1u8, 0u8, 193u8, 1u8, 0u8, 195u8, 1u8, 0u8, 196u8, 1u8, 0u8,
+                    197u8, 1u8, 0u8, 198u8, 1u8, 0u8, 199u8, 1u8, 0u8, 200u8, 1u8, 0u8, 201u8, 1u8,
+                    0u8, 202u8, 1u8, 0u8, 203u8, 1u8, 0u8, 204u8, 1u8, 0u8, 205u8, 1u8, 0u8, 206u8,
+                    1u8, 0u8, 207u8, 1u8, 0u8, 208u8, 1u8, 0u8, 209u8, 1u8, 0u8, 210u8, 1u8, 0u8,
+                    211u8, 1u8, 0u8, 212u8, 1u8, 0u8, 228u8, 1u8, 0u8, 229u8, 1u8, 0u8, 230u8, 1u8,
+                    0u8, 231u8, 1u8, 0u8, 241u8, 1u8, 0u8, 242u8, 1u8, 0u8, 243u8, 1u8, 0u8, 245u8,
+                    1u8, 0u8, 254u8, 1u8, 0u8, 26u8, 2u8, 0u8, 27u8, 2u8, 0u8, 28u8, 2u8, 0u8,
+                    34u8, 2u8, 0u8, 35u8, 2u8, 0u8, 36u8, 2u8, 0u8, 37u8, 2u8, 0u8, 38u8, 2u8, 0u8,
+                    39u8, 2u8, 0u8, 60u8, 2u8, 0u8, 62u8, 2u8, 0u8, 65u8, 2u8, 0u8, 68u8, 2u8, 0u8,
+                    70u8, 2u8, 0u8, 71u8, 2u8, 0u8, 72u8, 2u8, 0u8, 73u8, 2u8, 0u8, 77u8, 2u8, 0u8,
+                    78u8, 2u8, 0u8, 79u8, 2u8, 0u8, 81u8, 2u8, 0u8, 83u8, 2u8, 0u8, 86u8, 2u8, 0u8,
+                    87u8, 2u8, 0u8, 89u8, 2u8, 0u8, 90u8, 2u8, 0u8, 91u8, 2u8, 0u8, 96u8, 2u8, 0u8,
+                    97u8, 2u8, 0u8, 99u8, 2u8, 0u8, 102u8, 2u8, 0u8, 104u8, 2u8, 0u8, 105u8, 2u8,
+                    0u8, 106u8, 2u8, 0u8, 107u8, 2u8, 0u8, 109u8, 2u8, 0u8, 110u8, 2u8, 0u8, 111u8,
+                    2u8, 0u8, 113u8, 2u8, 0u8, 115u8, 2u8, 0u8, 117u8, 2u8, 0u8, 118u8, 2u8, 0u8,
+                    124u8, 2u8, 0u8, 125u8, 2u8, 0u8, 130u8, 2u8, 0u8, 139u8, 2u8, 0u8, 143u8, 2u8,
+                    0u8, 144u8, 2u8, 0u8, 146u8, 2u8, 0u8, 148u8, 2u8, 0u8, 160u8, 2u8, 0u8, 163u8,
+                    2u8, 0u8, 164u8, 2u8, 0u8, 165u8, 2u8, 0u8, 166u8, 2u8, 0u8, 167u8, 2u8, 0u8,
+                    168u8, 2u8, 0u8, 169u8, 2u8, 0u8, 170u8, 2u8, 0u8, 171u8, 2u8, 0u8, 179u8, 2u8,
+                    0u8, 185u8, 2u8, 0u8, 186u8, 2u8, 0u8, 187u8, 2u8, 0u8, 188u8, 2u8, 0u8, 189u8,
+                    2u8, 0u8, 190u8, 2u8, 0u8, 191u8, 2u8, 0u8, 194u8, 2u8, 0u8, 195u8, 2u8, 0u8,
+                    196u8, 2u8, 0u8, 198u8, 2u8, 0u8, 200u8, 2u8, 0u8, 202u8, 2u8, 0u8, 203u8, 2u8,
+                    0u8, 208u8, 2u8, 0u8, 211u8, 2u8, 0u8, 215u8, 2u8, 0u8, 216u8, 2u8, 0u8, 217u8,
+                    2u8, 0u8, 218u8, 2u8, 0u8, 219u8, 2u8, 0u8, 220u8, 2u8, 0u8, 221u8, 2u8, 0u8,
+                    225u8, 2u8, 0u8, 226u8, 2u8, 0u8, 228u8, 2u8, 0u8, 238u8, 2u8, 0u8, 244u8, 2u8,
+                    0u8, 246u8, 2u8, 0u8, 248u8, 2u8, 0u8, 251u8, 2u8, 0u8, 5u8, 3u8, 0u8, 12u8,
+                    3u8, 0u8, 13u8, 3u8, 0u8, 16u8, 3u8, 0u8, 17u8, 3u8, 0u8, 21u8, 3u8, 0u8, 23u8,
+                    3u8, 0u8, 32u8, 3u8, 0u8, 33u8, 3u8, 0u8, 34u8, 3u8, 0u8, 39u8, 3u8, 0u8, 54u8,
+                    3u8, 0u8, 55u8, 3u8, 0u8, 57u8, 3u8, 0u8, 64u8, 3u8, 0u8, 65u8, 3u8, 0u8, 66u8,
+                    3u8, 0u8, 67u8, 3u8, 0u8, 69u8, 3u8, 0u8, 71u8, 3u8, 0u8, 87u8, 3u8, 0u8, 88u8,
+                    3u8, 0u8, 102u8, 3u8, 0u8, 110u8, 3u8, 0u8, 112u8, 3u8, 0u8, 116u8, 3u8, 0u8,
+                    117u8, 3u8, 0u8, 118u8, 3u8, 0u8, 119u8, 3u8, 0u8, 122u8, 3u8, 0u8, 123u8, 3u8,
+                    0u8, 125u8, 3u8, 0u8, 126u8, 3u8, 0u8, 127u8, 3u8, 0u8, 132u8, 3u8, 0u8, 135u8,
+                    3u8, 0u8, 145u8, 3u8, 0u8, 146u8, 3u8, 0u8, 149u8, 3u8, 0u8, 150u8, 3u8, 0u8,
+                    151u8, 3u8, 0u8, 152u8, 3u8, 0u8, 153u8, 3u8, 0u8, 154u8, 3u8, 0u8, 155u8, 3u8,
+                    0u8, 156u8, 3u8, 0u8, 157u8, 3u8, 0u8, 159u8, 3u8, 0u8, 161u8, 3u8, 0u8, 163u8,
+                    3u8, 0u8, 164u8, 3u8, 0u8, 165u8, 3u8, 0u8, 167u8, 3u8, 0u8, 177u8, 3u8, 0u8,
+                    178u8, 3u8, 0u8, 179u8, 3u8, 0u8, 180u8, 3u8, 0u8, 181u8, 3u8, 0u8, 183u8, 3u8,
+                    0u8, 184u8, 3u8, 0u8, 185u8, 3u8, 0u8, 186u8, 3u8, 0u8, 189u8, 3u8, 0u8, 191u8,
+                    3u8, 0u8, 193u8, 3u8, 0u8, 195u8, 3u8, 0u8, 196u8, 3u8, 0u8, 197u8, 3u8, 0u8,
+                    198u8, 3u8, 0u8, 208u8, 3u8, 0u8, 209u8, 3u8, 0u8, 210u8, 3u8, 0u8, 213u8, 3u8,
+                    0u8, 214u8, 3u8, 0u8, 219u8, 3u8, 0u8, 220u8, 3u8, 0u8, 232u8, 3u8, 0u8, 233u8,
+                    3u8, 0u8, 240u8, 3u8, 0u8, 241u8, 3u8, 0u8, 242u8, 3u8, 0u8, 243u8, 3u8, 0u8,
+                    244u8, 3u8, 0u8, 245u8, 3u8, 0u8, 247u8, 3u8, 0u8, 248u8, 3u8, 0u8, 249u8, 3u8,
+                    0u8, 250u8, 3u8, 0u8, 253u8, 3u8, 0u8, 255u8, 3u8, 0u8, 4u8, 4u8, 0u8, 5u8,
+                    4u8, 0u8, 6u8, 4u8, 0u8, 8u8, 4u8, 0u8, 16u8, 4u8, 0u8, 17u8, 4u8, 0u8, 18u8,
+                    4u8, 0u8, 19u8, 4u8, 0u8, 21u8, 4u8, 0u8, 23u8, 4u8, 0u8, 25u8, 4u8, 0u8, 26u8,
+                    4u8, 0u8, 27u8, 4u8, 0u8, 28u8, 4u8, 0u8, 29u8, 4u8, 0u8, 30u8, 4u8, 0u8, 31u8,
+                    4u8, 0u8, 32u8, 4u8, 0u8, 33u8, 4u8, 0u8, 34u8, 4u8, 0u8, 35u8, 4u8, 0u8, 36u8,
+                    4u8, 0u8, 37u8, 4u8, 0u8, 43u8, 4u8, 0u8, 44u8, 4u8, 0u8, 46u8, 4u8, 0u8, 48u8,
+                    4u8, 0u8, 49u8, 4u8, 0u8, 50u8, 4u8, 0u8, 51u8, 4u8, 0u8, 53u8, 4u8, 0u8, 55u8,
+                    4u8, 0u8, 56u8, 4u8, 0u8, 58u8, 4u8, 0u8, 60u8, 4u8, 0u8, 61u8, 4u8, 0u8, 62u8,
+                    4u8, 0u8, 63u8, 4u8, 0u8, 64u8, 4u8, 0u8, 65u8, 4u8, 0u8, 66u8, 4u8, 0u8, 67u8,
+                    4u8, 0u8, 68u8, 4u8, 0u8, 69u8, 4u8, 0u8, 74u8, 4u8, 0u8, 75u8, 4u8, 0u8, 76u8,
+                    4u8, 0u8, 79u8, 4u8, 0u8, 84u8, 4u8, 0u8, 85u8, 4u8, 0u8, 86u8, 4u8, 0u8, 88u8,
+                    4u8, 0u8, 91u8, 4u8, 0u8, 93u8, 4u8, 0u8, 97u8, 4u8, 0u8, 98u8, 4u8, 0u8, 99u8,
+                    4u8, 0u8, 112u8, 4u8, 0u8, 113u8, 4u8, 0u8, 114u8, 4u8, 0u8, 115u8, 4u8, 0u8,
+                    116u8, 4u8, 0u8, 117u8, 4u8, 0u8, 124u8, 4u8, 0u8, 125u8, 4u8, 0u8, 138u8, 4u8,
+                    0u8, 139u8, 4u8, 0u8, 140u8, 4u8, 0u8, 141u8, 4u8, 0u8, 144u8, 4u8, 0u8, 145u8,
+                    4u8, 0u8, 146u8, 4u8, 0u8, 147u8, 4u8, 0u8, 150u8, 4u8, 0u8, 151u8, 4u8, 0u8,
+                    152u8, 4u8, 0u8, 153u8, 4u8, 0u8, 154u8, 4u8, 0u8, 155u8, 4u8, 0u8, 158u8, 4u8,
+                    0u8, 159u8, 4u8, 0u8, 162u8, 4u8, 0u8, 163u8, 4u8, 0u8, 170u8, 4u8, 0u8, 171u8,
+                    4u8, 0u8, 172u8, 4u8, 0u8, 173u8, 4u8, 0u8, 174u8, 4u8, 0u8, 175u8, 4u8, 0u8,
+                    176u8, 4u8, 0u8, 177u8, 4u8, 0u8, 178u8, 4u8, 0u8, 187u8, 4u8, 0u8, 189u8, 4u8,
+                    0u8, 190u8, 4u8, 0u8, 191u8, 4u8, 0u8, 192u8, 4u8, 0u8, 197u8, 4u8, 0u8, 198u8,
+                    4u8, 0u8, 199u8, 4u8, 0u8, 200u8, 4u8, 0u8, 201u8, 4u8, 0u8, 202u8, 4u8, 0u8,
+                    203u8, 4u8, 0u8, 204u8, 4u8, 0u8, 205u8, 4u8, 0u8, 206u8, 4u8, 0u8, 207u8, 4u8,
+                    0u8, 212u8, 4u8, 0u8, 213u8, 4u8, 0u8, 216u8, 4u8, 0u8, 217u8, 4u8, 0u8, 224u8,
+                    4u8, 0u8, 225u8, 4u8, 0u8, 232u8, 4u8, 0u8, 233u8, 4u8, 0u8, 1u8, 5u8, 0u8,
+                    10u8, 5u8, 0u8, 12u8, 5u8, 0u8, 13u8, 5u8, 0u8, 16u8, 5u8, 0u8, 17u8, 5u8, 0u8,
+                    27u8, 5u8, 0u8, 28u8, 5u8, 0u8, 29u8, 5u8, 0u8, 59u8, 5u8, 0u8, 68u8, 5u8, 0u8,
+                    74u8, 5u8, 0u8, 76u8, 5u8, 0u8, 77u8, 5u8, 0u8, 79u8, 5u8, 0u8, 83u8, 5u8, 0u8,
+                    85u8, 5u8, 0u8, 90u8, 5u8, 0u8, 93u8, 5u8, 0u8, 97u8, 5u8, 0u8, 99u8, 5u8, 0u8,
+                    102u8, 5u8, 0u8, 110u8, 5u8, 0u8, 112u8, 5u8, 0u8, 117u8, 5u8, 0u8, 120u8, 5u8,
+                    0u8, 122u8, 5u8, 0u8, 124u8, 5u8, 0u8, 125u8, 5u8, 0u8, 129u8, 5u8, 0u8, 132u8,
+                    5u8, 0u8, 133u8, 5u8, 0u8, 135u8, 5u8, 0u8, 137u8, 5u8, 0u8, 156u8, 5u8, 0u8,
+                    157u8, 5u8, 0u8, 164u8, 5u8, 0u8, 168u8, 5u8, 0u8, 173u8, 5u8, 0u8, 174u8, 5u8,
+                    0u8, 175u8, 5u8, 0u8, 180u8, 5u8, 0u8, 185u8, 5u8, 0u8, 186u8, 5u8, 0u8, 192u8,
+                    5u8, 0u8, 193u8, 5u8, 0u8, 194u8, 5u8, 0u8, 195u8, 5u8, 0u8, 196u8, 5u8, 0u8,
+                    197u8, 5u8, 0u8, 213u8, 5u8, 0u8, 216u8, 5u8, 0u8, 217u8, 5u8, 0u8, 223u8, 5u8,
+                    0u8, 225u8, 5u8, 0u8, 240u8, 5u8, 0u8, 241u8, 5u8, 0u8, 242u8, 5u8, 0u8, 243u8,
+                    5u8, 0u8, 244u8, 5u8, 0u8, 9u8, 6u8, 0u8, 10u8, 6u8, 0u8, 13u8, 6u8, 0u8, 15u8,
+                    6u8, 0u8, 24u8, 6u8, 0u8, 25u8, 6u8, 0u8, 26u8, 6u8, 0u8, 35u8, 6u8, 0u8, 36u8,
+                    6u8, 0u8, 37u8, 6u8, 0u8, 38u8, 6u8, 0u8, 39u8, 6u8, 0u8, 43u8, 6u8, 0u8, 52u8,
+                    6u8, 0u8, 61u8, 6u8, 0u8, 63u8, 6u8, 0u8, 71u8, 6u8, 0u8, 74u8, 6u8, 0u8, 75u8,
+                    6u8, 0u8, 78u8, 6u8, 0u8, 79u8, 6u8, 0u8, 82u8, 6u8, 0u8, 83u8, 6u8, 0u8, 86u8,
+                    6u8, 0u8, 87u8, 6u8, 0u8, 88u8, 6u8, 0u8, 89u8, 6u8, 0u8, 90u8, 6u8, 0u8, 91u8,
+                    6u8, 0u8, 92u8, 6u8, 0u8, 93u8, 6u8, 0u8, 95u8, 6u8, 0u8, 96u8, 6u8, 0u8, 97u8,
+                    6u8, 0u8, 101u8, 6u8, 0u8, 103u8, 6u8, 0u8, 104u8, 6u8, 0u8, 106u8, 6u8, 0u8,
+                    107u8, 6u8, 0u8, 108u8, 6u8, 0u8, 109u8, 6u8, 0u8, 110u8, 6u8, 0u8, 111u8, 6u8,
+                    0u8, 114u8, 6u8, 0u8, 115u8, 6u8, 0u8, 117u8, 6u8, 0u8, 118u8, 6u8, 0u8, 119u8,
+                    6u8, 0u8, 120u8, 6u8, 0u8, 121u8, 6u8, 0u8, 126u8, 6u8, 0u8, 129u8, 6u8, 0u8,
+                    133u8, 6u8, 0u8, 136u8, 6u8, 0u8, 139u8, 6u8, 0u8, 142u8, 6u8, 0u8, 145u8, 6u8,
+                    0u8, 146u8, 6u8, 0u8, 152u8, 6u8, 0u8, 158u8, 6u8, 0u8, 159u8, 6u8, 0u8, 164u8,
+                    6u8, 0u8, 167u8, 6u8, 0u8, 168u8, 6u8, 0u8, 169u8, 6u8, 0u8, 170u8, 6u8, 0u8,
+                    173u8, 6u8, 0u8, 180u8, 6u8, 0u8, 181u8, 6u8, 0u8, 183u8, 6u8, 0u8, 186u8, 6u8,
+                    0u8, 187u8, 6u8, 0u8, 189u8, 6u8, 0u8, 190u8, 6u8, 0u8, 193u8, 6u8, 0u8, 194u8,
+                    6u8, 0u8, 195u8, 6u8, 0u8, 198u8, 6u8, 0u8, 199u8, 6u8, 0u8, 200u8, 6u8, 0u8,
+                    201u8, 6u8, 0u8, 203u8, 6u8, 0u8, 204u8, 6u8, 0u8, 206u8, 6u8, 0u8, 208u8, 6u8,
+                    0u8, 209u8, 6u8, 0u8, 210u8, 6u8, 0u8, 212u8, 6u8, 0u8, 213u8, 6u8, 0u8, 223u8,
+                    6u8, 0u8, 232u8, 6u8, 0u8, 236u8, 6u8, 0u8, 238u8, 6u8, 0u8, 239u8, 6u8, 0u8,
+                    240u8, 6u8, 0u8, 241u8, 6u8, 0u8, 242u8, 6u8, 0u8, 243u8, 6u8, 0u8, 244u8, 6u8,
+                    0u8, 245u8, 6u8, 0u8, 246u8, 6u8, 0u8, 247u8, 6u8, 0u8, 248u8, 6u8, 0u8, 249u8,
+                    6u8, 0u8, 253u8, 6u8, 0u8, 254u8, 6u8, 0u8, 255u8, 6u8, 0u8, 1u8, 7u8, 0u8,
+                    2u8, 7u8, 0u8, 3u8, 7u8, 0u8, 4u8, 7u8, 0u8, 64u8, 7u8, 0u8, 65u8, 7u8, 0u8,
+                    66u8, 7u8, 0u8, 71u8, 7u8, 0u8, 81u8, 7u8, 0u8, 86u8, 7u8, 0u8, 98u8, 7u8, 0u8,
+                    99u8, 7u8, 0u8, 103u8, 7u8, 0u8, 104u8, 7u8, 0u8, 105u8, 7u8, 0u8, 108u8, 7u8,
+                    0u8, 113u8, 7u8, 0u8, 114u8, 7u8, 0u8, 126u8, 7u8, 0u8, 192u8, 7u8, 0u8, 202u8,
+                    7u8, 0u8, 235u8, 7u8, 0u8, 237u8, 7u8, 0u8, 238u8, 7u8, 0u8, 243u8, 7u8, 0u8,
+                    244u8, 7u8, 0u8, 245u8, 7u8, 0u8, 250u8, 7u8, 0u8, 161u8, 8u8, 0u8, 164u8, 8u8,
+                    0u8, 167u8, 8u8, 0u8, 168u8, 8u8, 0u8, 169u8, 8u8, 0u8, 174u8, 8u8, 0u8, 175u8,
+                    8u8, 0u8, 176u8, 8u8, 0u8, 177u8, 8u8, 0u8, 178u8, 8u8, 0u8, 182u8, 8u8, 0u8,
+                    183u8, 8u8, 0u8, 185u8, 8u8, 0u8, 186u8, 8u8, 0u8, 187u8, 8u8, 0u8, 188u8, 8u8,
+                    0u8, 189u8, 8u8, 0u8, 229u8, 8u8, 0u8, 232u8, 8u8, 0u8, 234u8, 8u8, 0u8, 235u8,
+                    8u8, 0u8, 237u8, 8u8, 0u8, 238u8, 8u8, 0u8, 240u8, 8u8, 0u8, 241u8, 8u8, 0u8,
+                    242u8, 8u8, 0u8, 243u8, 8u8, 0u8, 248u8, 8u8, 0u8, 249u8, 8u8, 0u8, 250u8, 8u8,
+                    0u8, 255u8, 8u8, 0u8, 0u8, 9u8, 0u8, 1u8, 9u8, 0u8, 2u8, 9u8, 0u8, 3u8, 9u8,
+                    0u8, 4u8, 9u8, 0u8, 6u8, 9u8, 0u8, 8u8, 9u8, 0u8, 13u8, 9u8, 0u8, 14u8, 9u8,
+                    0u8, 16u8, 9u8, 0u8, 17u8, 9u8, 0u8, 18u8, 9u8, 0u8, 19u8, 9u8, 0u8, 20u8, 9u8,
+                    0u8, 60u8, 9u8, 0u8, 82u8, 9u8, 0u8, 83u8, 9u8, 0u8, 84u8, 9u8, 0u8, 101u8,
+                    9u8, 0u8, 102u8, 9u8, 0u8, 103u8, 9u8, 0u8, 125u8, 9u8, 0u8, 129u8, 9u8, 0u8,
+                    134u8, 9u8, 0u8, 188u8, 9u8, 0u8, 224u8, 9u8, 0u8, 225u8, 9u8, 0u8, 230u8, 9u8,
+                    0u8, 234u8, 9u8, 0u8, 237u8, 9u8, 0u8, 2u8, 10u8, 0u8, 3u8, 10u8, 0u8, 6u8,
+                    10u8, 0u8, 7u8, 10u8, 0u8, 8u8, 10u8, 0u8, 9u8, 10u8, 0u8, 10u8, 10u8, 0u8,
+                    15u8, 10u8, 0u8, 16u8, 10u8, 0u8, 20u8, 10u8, 0u8, 60u8, 10u8, 0u8, 75u8, 10u8,
+                    0u8, 77u8, 10u8, 0u8, 102u8, 10u8, 0u8, 103u8, 10u8, 0u8, 106u8, 10u8, 0u8,
+                    129u8, 10u8, 0u8, 130u8, 10u8, 0u8, 131u8, 10u8, 0u8, 134u8, 10u8, 0u8, 141u8,
+                    10u8, 0u8, 143u8, 10u8, 0u8, 144u8, 10u8, 0u8, 145u8, 10u8, 0u8, 147u8, 10u8,
+                    0u8, 148u8, 10u8, 0u8, 188u8, 10u8, 0u8, 189u8, 10u8, 0u8, 193u8, 10u8, 0u8,
+                    194u8, 10u8, 0u8, 205u8, 10u8, 0u8, 230u8, 10u8, 0u8, 232u8, 10u8, 0u8, 233u8,
+                    10u8, 0u8, 234u8, 10u8, 0u8, 238u8, 10u8, 0u8, 240u8, 10u8, 0u8, 1u8, 11u8,
+                    0u8, 3u8, 11u8, 0u8, 6u8, 11u8, 0u8, 32u8, 11u8, 0u8, 60u8, 11u8, 0u8, 102u8,
+                    11u8, 0u8, 104u8, 11u8, 0u8, 130u8, 11u8, 0u8, 138u8, 11u8, 0u8, 156u8, 11u8,
+                    0u8, 176u8, 11u8, 0u8, 190u8, 11u8, 0u8, 200u8, 11u8, 0u8, 202u8, 11u8, 0u8,
+                    203u8, 11u8, 0u8, 204u8, 11u8, 0u8, 205u8, 11u8, 0u8, 215u8, 11u8, 0u8, 230u8,
+                    11u8, 0u8, 231u8, 11u8, 0u8, 232u8, 11u8, 0u8, 234u8, 11u8, 0u8, 235u8, 11u8,
+                    0u8, 236u8, 11u8, 0u8, 237u8, 11u8, 0u8, 238u8, 11u8, 0u8, 240u8, 11u8, 0u8,
+                    242u8, 11u8, 0u8, 244u8, 11u8, 0u8, 245u8, 11u8, 0u8, 247u8, 11u8, 0u8, 248u8,
+                    11u8, 0u8, 250u8, 11u8, 0u8, 0u8, 12u8, 0u8, 2u8, 12u8, 0u8, 3u8, 12u8, 0u8,
+                    19u8, 12u8, 0u8, 20u8, 12u8, 0u8, 32u8, 12u8, 0u8, 34u8, 12u8, 0u8, 37u8, 12u8,
+                    0u8, 45u8, 12u8, 0u8, 46u8, 12u8, 0u8, 55u8, 12u8, 0u8, 57u8, 12u8, 0u8, 66u8,
+                    12u8, 0u8, 68u8, 12u8, 0u8, 96u8, 12u8, 0u8, 97u8, 12u8, 0u8, 102u8, 12u8, 0u8,
+                    129u8, 12u8, 0u8, 130u8, 12u8, 0u8, 131u8, 12u8, 0u8, 133u8, 12u8, 0u8, 134u8,
+                    12u8, 0u8, 135u8, 12u8, 0u8, 146u8, 12u8, 0u8, 147u8, 12u8, 0u8, 148u8, 12u8,
+                    0u8, 156u8, 12u8, 0u8, 158u8, 12u8, 0u8, 163u8, 12u8, 0u8, 175u8, 12u8, 0u8,
+                    177u8, 12u8, 0u8, 178u8, 12u8, 0u8, 225u8, 12u8, 0u8, 230u8, 12u8, 0u8, 231u8,
+                    12u8, 0u8, 232u8, 12u8, 0u8, 239u8, 12u8, 0u8, 1u8, 13u8, 0u8, 2u8, 13u8, 0u8,
+                    3u8, 13u8, 0u8, 8u8, 13u8, 0u8, 9u8, 13u8, 0u8, 10u8, 13u8, 0u8, 12u8, 13u8,
+                    0u8, 16u8, 13u8, 0u8, 19u8, 13u8, 0u8, 20u8, 13u8, 0u8, 25u8, 13u8, 0u8, 28u8,
+                    13u8, 0u8, 32u8, 13u8, 0u8, 35u8, 13u8, 0u8, 49u8, 13u8, 0u8, 52u8, 13u8, 0u8,
+                    54u8, 13u8, 0u8, 58u8, 13u8, 0u8, 63u8, 13u8, 0u8, 64u8, 13u8, 0u8, 66u8, 13u8,
+                    0u8, 67u8, 13u8, 0u8, 72u8, 13u8, 0u8, 78u8, 13u8, 0u8, 90u8, 13u8, 0u8, 95u8,
+                    13u8, 0u8, 97u8, 13u8, 0u8, 102u8, 13u8, 0u8, 106u8, 13u8, 0u8, 107u8, 13u8,
+                    0u8, 108u8, 13u8, 0u8, 109u8, 13u8, 0u8, 110u8, 13u8, 0u8, 111u8, 13u8, 0u8,
+                    118u8, 13u8, 0u8, 121u8, 13u8, 0u8, 123u8, 13u8, 0u8, 124u8, 13u8, 0u8, 130u8,
+                    13u8, 0u8, 131u8, 13u8, 0u8, 233u8, 13u8, 0u8, 234u8, 13u8, 0u8, 235u8, 13u8,
+                    0u8, 239u8, 13u8, 0u8, 3u8, 14u8, 0u8, 11u8, 14u8, 0u8, 15u8, 14u8, 0u8, 20u8,
+                    14u8, 0u8, 21u8, 14u8, 0u8, 23u8, 14u8, 0u8, 33u8, 14u8, 0u8, 38u8, 14u8, 0u8,
+                    51u8, 14u8, 0u8, 65u8, 14u8, 0u8, 69u8, 14u8, 0u8, 77u8, 14u8, 0u8, 80u8, 14u8,
+                    0u8, 136u8, 14u8, 0u8, 141u8, 14u8, 0u8, 154u8, 14u8, 0u8, 155u8, 14u8, 0u8,
+                    157u8, 14u8, 0u8, 158u8, 14u8, 0u8, 159u8, 14u8, 0u8, 179u8, 14u8, 0u8, 184u8,
+                    14u8, 0u8, 185u8, 14u8, 0u8, 200u8, 14u8, 0u8, 201u8, 14u8, 0u8, 202u8, 14u8,
+                    0u8, 203u8, 14u8, 0u8, 205u8, 14u8, 0u8, 208u8, 14u8, 0u8, 220u8, 14u8, 0u8,
+                    221u8, 14u8, 0u8, 0u8, 15u8, 0u8, 2u8, 15u8, 0u8, 3u8, 15u8, 0u8, 12u8, 15u8,
+                    0u8, 14u8, 15u8, 0u8, 27u8, 15u8, 0u8, 30u8, 15u8, 0u8, 31u8, 15u8, 0u8, 55u8,
+                    15u8, 0u8, 106u8, 15u8, 0u8, 119u8, 15u8, 0u8, 121u8, 15u8, 0u8, 206u8, 15u8,
+                    0u8, 213u8, 15u8, 0u8, 214u8, 15u8, 0u8, 0u8, 16u8, 0u8, 16u8, 16u8, 0u8, 29u8,
+                    16u8, 0u8, 31u8, 16u8, 0u8, 41u8, 16u8, 0u8, 42u8, 16u8, 0u8, 54u8, 16u8, 0u8,
+                    56u8, 16u8, 0u8, 64u8, 16u8, 0u8, 75u8, 16u8, 0u8, 101u8, 16u8, 0u8, 102u8,
+                    16u8, 0u8, 111u8, 16u8, 0u8, 112u8, 16u8, 0u8, 126u8, 16u8, 0u8, 129u8, 16u8,
+                    0u8, 158u8, 16u8, 0u8, 160u8, 16u8, 0u8, 231u8, 16u8, 0u8, 243u8, 16u8, 0u8,
+                    255u8, 16u8, 0u8, 1u8, 17u8, 0u8, 4u8, 17u8, 0u8, 8u8, 17u8, 0u8, 10u8, 17u8,
+                    0u8, 13u8, 17u8, 0u8, 19u8, 17u8, 0u8, 20u8, 17u8, 0u8, 21u8, 17u8, 0u8, 22u8,
+                    17u8, 0u8, 23u8, 17u8, 0u8, 24u8, 17u8, 0u8, 25u8, 17u8, 0u8, 26u8, 17u8, 0u8,
+                    27u8, 17u8, 0u8, 28u8, 17u8, 0u8, 29u8, 17u8, 0u8, 30u8, 17u8, 0u8, 31u8, 17u8,
+                    0u8, 32u8, 17u8, 0u8, 33u8, 17u8, 0u8, 34u8, 17u8, 0u8, 35u8, 17u8, 0u8, 36u8,
+                    17u8, 0u8, 37u8, 17u8, 0u8, 38u8, 17u8, 0u8, 39u8, 17u8, 0u8, 40u8, 17u8, 0u8,
+                    41u8, 17u8, 0u8, 42u8, 17u8, 0u8, 43u8, 17u8, 0u8, 44u8, 17u8, 0u8, 45u8, 17u8,
+                    0u8, 46u8, 17u8, 0u8, 47u8, 17u8, 0u8, 48u8, 17u8, 0u8, 49u8, 17u8, 0u8, 50u8,
+                    17u8, 0u8, 51u8, 17u8, 0u8, 52u8, 17u8, 0u8, 53u8, 17u8, 0u8, 54u8, 17u8, 0u8,
+                    55u8, 17u8, 0u8, 56u8, 17u8, 0u8, 57u8, 17u8, 0u8, 58u8, 17u8, 0u8, 59u8, 17u8,
+                    0u8, 61u8, 17u8, 0u8, 63u8, 17u8, 0u8, 65u8, 17u8, 0u8, 66u8, 17u8, 0u8, 67u8,
+                    17u8, 0u8, 68u8, 17u8, 0u8, 69u8, 17u8, 0u8, 70u8, 17u8, 0u8, 71u8, 17u8, 0u8,
+                    72u8, 17u8, 0u8, 73u8, 17u8, 0u8, 74u8, 17u8, 0u8, 75u8, 17u8, 0u8, 77u8, 17u8,
+                    0u8, 79u8, 17u8, 0u8, 81u8, 17u8, 0u8, 82u8, 17u8, 0u8, 83u8, 17u8, 0u8, 86u8,
+                    17u8, 0u8, 87u8, 17u8, 0u8, 88u8, 17u8, 0u8, 90u8, 17u8, 0u8, 91u8, 17u8, 0u8,
+                    92u8, 17u8, 0u8, 93u8, 17u8, 0u8, 94u8, 17u8, 0u8, 98u8, 17u8, 0u8, 100u8,
+                    17u8, 0u8, 102u8, 17u8, 0u8, 104u8, 17u8, 0u8, 106u8, 17u8, 0u8, 107u8, 17u8,
+                    0u8, 108u8, 17u8, 0u8, 111u8, 17u8, 0u8, 112u8, 17u8, 0u8, 113u8, 17u8, 0u8,
+                    115u8, 17u8, 0u8, 116u8, 17u8, 0u8, 117u8, 17u8, 0u8, 118u8, 17u8, 0u8, 119u8,
+                    17u8, 0u8, 120u8, 17u8, 0u8, 121u8, 17u8, 0u8, 122u8, 17u8, 0u8, 123u8, 17u8,
+                    0u8, 124u8, 17u8, 0u8, 125u8, 17u8, 0u8, 126u8, 17u8, 0u8, 127u8, 17u8, 0u8,
+                    128u8, 17u8, 0u8, 129u8, 17u8, 0u8, 130u8, 17u8, 0u8, 131u8, 17u8, 0u8, 132u8,
+                    17u8, 0u8, 133u8, 17u8, 0u8, 134u8, 17u8, 0u8, 135u8, 17u8, 0u8, 136u8, 17u8,
+                    0u8, 137u8, 17u8, 0u8, 138u8, 17u8, 0u8, 139u8, 17u8, 0u8, 140u8, 17u8, 0u8,
+                    141u8, 17u8, 0u8, 142u8, 17u8, 0u8, 143u8, 17u8, 0u8, 144u8, 17u8, 0u8, 145u8,
+                    17u8, 0u8, 146u8, 17u8, 0u8, 147u8, 17u8, 0u8, 148u8, 17u8, 0u8, 149u8, 17u8,
+                    0u8, 150u8, 17u8, 0u8, 151u8, 17u8, 0u8, 152u8, 17u8, 0u8, 153u8, 17u8, 0u8,
+                    154u8, 17u8, 0u8, 155u8, 17u8, 0u8, 156u8, 17u8, 0u8, 157u8, 17u8, 0u8, 159u8,
+                    17u8, 0u8, 160u8, 17u8, 0u8, 161u8, 17u8, 0u8, 162u8, 17u8, 0u8, 163u8, 17u8,
+                    0u8, 164u8, 17u8, 0u8, 165u8, 17u8, 0u8, 166u8, 17u8, 0u8, 167u8, 17u8, 0u8,
+                    168u8, 17u8, 0u8, 169u8, 17u8, 0u8, 170u8, 17u8, 0u8, 171u8, 17u8, 0u8, 172u8,
+                    17u8, 0u8, 173u8, 17u8, 0u8, 174u8, 17u8, 0u8, 175u8, 17u8, 0u8, 176u8, 17u8,
+                    0u8, 177u8, 17u8, 0u8, 178u8, 17u8, 0u8, 179u8, 17u8, 0u8, 180u8, 17u8, 0u8,
+                    181u8, 17u8, 0u8, 182u8, 17u8, 0u8, 183u8, 17u8, 0u8, 184u8, 17u8, 0u8, 185u8,
+                    17u8, 0u8, 186u8, 17u8, 0u8, 187u8, 17u8, 0u8, 188u8, 17u8, 0u8, 189u8, 17u8,
+                    0u8, 190u8, 17u8, 0u8, 191u8, 17u8, 0u8, 192u8, 17u8, 0u8, 193u8, 17u8, 0u8,
+                    194u8, 17u8, 0u8, 195u8, 17u8, 0u8, 196u8, 17u8, 0u8, 197u8, 17u8, 0u8, 198u8,
+                    17u8, 0u8, 199u8, 17u8, 0u8, 200u8, 17u8, 0u8, 201u8, 17u8, 0u8, 202u8, 17u8,
+                    0u8, 203u8, 17u8, 0u8, 204u8, 17u8, 0u8, 205u8, 17u8, 0u8, 206u8, 17u8, 0u8,
+                    207u8, 17u8, 0u8, 208u8, 17u8, 0u8, 209u8, 17u8, 0u8, 210u8, 17u8, 0u8, 211u8,
+                    17u8, 0u8, 212u8, 17u8, 0u8, 213u8, 17u8, 0u8, 214u8, 17u8, 0u8, 215u8, 17u8,
+                    0u8, 216u8, 17u8, 0u8, 217u8, 17u8, 0u8, 218u8, 17u8, 0u8, 219u8, 17u8, 0u8,
+                    220u8, 17u8, 0u8, 221u8, 17u8, 0u8, 222u8, 17u8, 0u8, 223u8, 17u8, 0u8, 224u8,
+                    17u8, 0u8, 225u8, 17u8, 0u8, 226u8, 17u8, 0u8, 227u8, 17u8, 0u8, 228u8, 17u8,
+                    0u8, 229u8, 17u8, 0u8, 230u8, 17u8, 0u8, 231u8, 17u8, 0u8, 232u8, 17u8, 0u8,
+                    233u8, 17u8, 0u8, 234u8, 17u8, 0u8, 235u8, 17u8, 0u8, 236u8, 17u8, 0u8, 237u8,
+                    17u8, 0u8, 238u8, 17u8, 0u8, 239u8, 17u8, 0u8, 240u8, 17u8, 0u8, 241u8, 17u8,
+                    0u8, 242u8, 17u8, 0u8, 243u8, 17u8, 0u8, 244u8, 17u8, 0u8, 245u8, 17u8, 0u8,
+                    246u8, 17u8, 0u8, 247u8, 17u8, 0u8, 248u8, 17u8, 0u8, 249u8, 17u8, 0u8, 250u8,
+                    17u8, 0u8, 251u8, 17u8, 0u8, 252u8, 17u8, 0u8, 253u8, 17u8, 0u8, 254u8, 17u8,
+                    0u8, 255u8, 17u8, 0u8, 0u8, 18u8, 0u8, 35u8, 18u8, 0u8, 64u8, 18u8, 0u8, 96u8,
+                    18u8, 0u8, 148u8, 18u8, 0u8, 208u8, 18u8, 0u8, 160u8, 19u8, 0u8, 161u8, 19u8,
+                    0u8, 162u8, 19u8, 0u8, 164u8, 19u8, 0u8, 165u8, 19u8, 0u8, 168u8, 19u8, 0u8,
+                    169u8, 19u8, 0u8, 170u8, 19u8, 0u8, 171u8, 19u8, 0u8, 172u8, 19u8, 0u8, 174u8,
+                    19u8, 0u8, 176u8, 19u8, 0u8, 177u8, 19u8, 0u8, 179u8, 19u8, 0u8, 183u8, 19u8,
+                    0u8, 187u8, 19u8, 0u8, 189u8, 19u8, 0u8, 190u8, 19u8, 0u8, 191u8, 19u8, 0u8,
+                    192u8, 19u8, 0u8, 194u8, 19u8, 0u8, 195u8, 19u8, 0u8, 199u8, 19u8, 0u8, 203u8,
+                    19u8, 0u8, 204u8, 19u8, 0u8, 206u8, 19u8, 0u8, 207u8, 19u8, 0u8, 210u8, 19u8,
+                    0u8, 212u8, 19u8, 0u8, 213u8, 19u8, 0u8, 217u8, 19u8, 0u8, 218u8, 19u8, 0u8,
+                    222u8, 19u8, 0u8, 223u8, 19u8, 0u8, 226u8, 19u8, 0u8, 230u8, 19u8, 0u8, 231u8,
+                    19u8, 0u8, 235u8, 19u8, 0u8, 238u8, 19u8, 0u8, 240u8, 19u8, 0u8, 242u8, 19u8,
+                    0u8, 243u8, 19u8, 0u8, 244u8, 19u8, 0u8, 251u8, 19u8, 0u8, 252u8, 19u8, 0u8,
+                    0u8, 20u8, 0u8, 3u8, 20u8, 0u8, 12u8, 20u8, 0u8, 13u8, 20u8, 0u8, 14u8, 20u8,
+                    0u8, 15u8, 20u8, 0u8, 16u8, 20u8, 0u8, 17u8, 20u8, 0u8, 18u8, 20u8, 0u8, 19u8,
+                    20u8, 0u8, 20u8, 20u8, 0u8, 21u8, 20u8, 0u8, 23u8, 20u8, 0u8, 24u8, 20u8, 0u8,
+                    25u8, 20u8, 0u8, 26u8, 20u8, 0u8, 39u8, 20u8, 0u8, 43u8, 20u8, 0u8, 44u8, 20u8,
+                    0u8, 45u8, 20u8, 0u8, 46u8, 20u8, 0u8, 47u8, 20u8, 0u8, 49u8, 20u8, 0u8, 51u8,
+                    20u8, 0u8, 55u8, 20u8, 0u8, 56u8, 20u8, 0u8, 58u8, 20u8, 0u8, 59u8, 20u8, 0u8,
+                    60u8, 20u8, 0u8, 61u8, 20u8, 0u8, 62u8, 20u8, 0u8, 63u8, 20u8, 0u8, 64u8, 20u8,
+                    0u8, 65u8, 20u8, 0u8, 66u8, 20u8, 0u8, 67u8, 20u8, 0u8, 68u8, 20u8, 0u8, 69u8,
+                    20u8, 0u8, 70u8, 20u8, 0u8, 71u8, 20u8, 0u8, 74u8, 20u8, 0u8, 76u8, 20u8, 0u8,
+                    78u8, 20u8, 0u8, 84u8, 20u8, 0u8, 87u8, 20u8, 0u8, 88u8, 20u8, 0u8, 89u8, 20u8,
+                    0u8, 90u8, 20u8, 0u8, 91u8, 20u8, 0u8, 92u8, 20u8, 0u8, 93u8, 20u8, 0u8, 94u8,
+                    20u8, 0u8, 95u8, 20u8, 0u8, 96u8, 20u8, 0u8, 97u8, 20u8, 0u8, 98u8, 20u8, 0u8,
+                    99u8, 20u8, 0u8, 100u8, 20u8, 0u8, 103u8, 20u8, 0u8, 104u8, 20u8, 0u8, 105u8,
+                    20u8, 0u8, 106u8, 20u8, 0u8, 109u8, 20u8, 0u8, 111u8, 20u8, 0u8, 114u8, 20u8,
+                    0u8, 115u8, 20u8, 0u8, 116u8, 20u8, 0u8, 117u8, 20u8, 0u8, 118u8, 20u8, 0u8,
+                    119u8, 20u8, 0u8, 120u8, 20u8, 0u8, 121u8, 20u8, 0u8, 122u8, 20u8, 0u8, 123u8,
+                    20u8, 0u8, 124u8, 20u8, 0u8, 125u8, 20u8, 0u8, 126u8, 20u8, 0u8, 127u8, 20u8,
+                    0u8, 128u8, 20u8, 0u8, 129u8, 20u8, 0u8, 133u8, 20u8, 0u8, 134u8, 20u8, 0u8,
+                    135u8, 20u8, 0u8, 136u8, 20u8, 0u8, 141u8, 20u8, 0u8, 146u8, 20u8, 0u8, 147u8,
+                    20u8, 0u8, 148u8, 20u8, 0u8, 149u8, 20u8, 0u8, 150u8, 20u8, 0u8, 151u8, 20u8,
+                    0u8, 152u8, 20u8, 0u8, 153u8, 20u8, 0u8, 154u8, 20u8, 0u8, 155u8, 20u8, 0u8,
+                    156u8, 20u8, 0u8, 157u8, 20u8, 0u8, 158u8, 20u8, 0u8, 159u8, 20u8, 0u8, 165u8,
+                    20u8, 0u8, 170u8, 20u8, 0u8, 172u8, 20u8, 0u8, 173u8, 20u8, 0u8, 174u8, 20u8,
+                    0u8, 175u8, 20u8, 0u8, 176u8, 20u8, 0u8, 177u8, 20u8, 0u8, 178u8, 20u8, 0u8,
+                    179u8, 20u8, 0u8, 180u8, 20u8, 0u8, 181u8, 20u8, 0u8, 182u8, 20u8, 0u8, 183u8,
+                    20u8, 0u8, 184u8, 20u8, 0u8, 185u8, 20u8, 0u8, 191u8, 20u8, 0u8, 201u8, 20u8,
+                    0u8, 202u8, 20u8, 0u8, 203u8, 20u8, 0u8, 204u8, 20u8, 0u8, 205u8, 20u8, 0u8,
+                    206u8, 20u8, 0u8, 209u8, 20u8, 0u8, 220u8, 20u8, 0u8, 221u8, 20u8, 0u8, 222u8,
+                    20u8, 0u8, 223u8, 20u8, 0u8, 224u8, 20u8, 0u8, 225u8, 20u8, 0u8, 226u8, 20u8,
+                    0u8, 227u8, 20u8, 0u8, 228u8, 20u8, 0u8, 229u8, 20u8, 0u8, 230u8, 20u8, 0u8,
+                    231u8, 20u8, 0u8, 232u8, 20u8, 0u8, 233u8, 20u8, 0u8, 246u8, 20u8, 0u8, 247u8,
+                    20u8, 0u8, 248u8, 20u8, 0u8, 249u8, 20u8, 0u8, 250u8, 20u8, 0u8, 251u8, 20u8,
+                    0u8, 252u8, 20u8, 0u8, 253u8, 20u8, 0u8, 254u8, 20u8, 0u8, 255u8, 20u8, 0u8,
+                    0u8, 21u8, 0u8, 1u8, 21u8, 0u8, 2u8, 21u8, 0u8, 3u8, 21u8, 0u8, 12u8, 21u8,
+                    0u8, 13u8, 21u8, 0u8, 14u8, 21u8, 0u8, 15u8, 21u8, 0u8, 23u8, 21u8, 0u8, 24u8,
+                    21u8, 0u8, 25u8, 21u8, 0u8, 26u8, 21u8, 0u8, 27u8, 21u8, 0u8, 28u8, 21u8, 0u8,
+                    29u8, 21u8, 0u8, 30u8, 21u8, 0u8, 31u8, 21u8, 0u8, 32u8, 21u8, 0u8, 33u8, 21u8,
+                    0u8, 34u8, 21u8, 0u8, 35u8, 21u8, 0u8, 36u8, 21u8, 0u8, 47u8, 21u8, 0u8, 48u8,
+                    21u8, 0u8, 49u8, 21u8, 0u8, 50u8, 21u8, 0u8, 51u8, 21u8, 0u8, 52u8, 21u8, 0u8,
+                    53u8, 21u8, 0u8, 54u8, 21u8, 0u8, 55u8, 21u8, 0u8, 56u8, 21u8, 0u8, 57u8, 21u8,
+                    0u8, 58u8, 21u8, 0u8, 59u8, 21u8, 0u8, 60u8, 21u8, 0u8, 64u8, 21u8, 0u8, 65u8,
+                    21u8, 0u8, 78u8, 21u8, 0u8, 79u8, 21u8, 0u8, 91u8, 21u8, 0u8, 92u8, 21u8, 0u8,
+                    104u8, 21u8, 0u8, 105u8, 21u8, 0u8, 119u8, 21u8, 0u8, 124u8, 21u8, 0u8, 125u8,
+                    21u8, 0u8, 126u8, 21u8, 0u8, 127u8, 21u8, 0u8, 128u8, 21u8, 0u8, 129u8, 21u8,
+                    0u8, 130u8, 21u8, 0u8, 131u8, 21u8, 0u8, 132u8, 21u8, 0u8, 133u8, 21u8, 0u8,
+                    135u8, 21u8, 0u8, 142u8, 21u8, 0u8, 143u8, 21u8, 0u8, 144u8, 21u8, 0u8, 145u8,
+                    21u8, 0u8, 146u8, 21u8, 0u8, 147u8, 21u8, 0u8, 148u8, 21u8, 0u8, 175u8, 21u8,
+                    0u8, 180u8, 21u8, 0u8, 181u8, 21u8, 0u8, 183u8, 21u8, 0u8, 196u8, 21u8, 0u8,
+                    197u8, 21u8, 0u8, 222u8, 21u8, 0u8, 234u8, 21u8, 0u8, 239u8, 21u8, 0u8, 240u8,
+                    21u8, 0u8, 247u8, 21u8, 0u8, 2u8, 22u8, 0u8, 3u8, 22u8, 0u8, 4u8, 22u8, 0u8,
+                    7u8, 22u8, 0u8, 34u8, 22u8, 0u8, 35u8, 22u8, 0u8, 36u8, 22u8, 0u8, 46u8, 22u8,
+                    0u8, 47u8, 22u8, 0u8, 52u8, 22u8, 0u8, 53u8, 22u8, 0u8, 109u8, 22u8, 0u8,
+                    110u8, 22u8, 0u8, 111u8, 22u8, 0u8, 112u8, 22u8, 0u8, 113u8, 22u8, 0u8, 114u8,
+                    22u8, 0u8, 115u8, 22u8, 0u8, 116u8, 22u8, 0u8, 117u8, 22u8, 0u8, 118u8, 22u8,
+                    0u8, 119u8, 22u8, 0u8, 120u8, 22u8, 0u8, 121u8, 22u8, 0u8, 122u8, 22u8, 0u8,
+                    123u8, 22u8, 0u8, 124u8, 22u8, 0u8, 125u8, 22u8, 0u8, 128u8, 22u8, 0u8, 178u8,
+                    22u8, 0u8, 183u8, 22u8, 0u8, 193u8, 22u8, 0u8, 194u8, 22u8, 0u8, 204u8, 22u8,
+                    0u8, 213u8, 22u8, 0u8, 214u8, 22u8, 0u8, 216u8, 22u8, 0u8, 225u8, 22u8, 0u8,
+                    235u8, 22u8, 0u8, 236u8, 22u8, 0u8, 237u8, 22u8, 0u8, 240u8, 22u8, 0u8, 53u8,
+                    23u8, 0u8, 163u8, 23u8, 0u8, 183u8, 23u8, 0u8, 184u8, 23u8, 0u8, 185u8, 23u8,
+                    0u8, 186u8, 23u8, 0u8, 198u8, 23u8, 0u8, 203u8, 23u8, 0u8, 211u8, 23u8, 0u8,
+                    212u8, 23u8, 0u8, 213u8, 23u8, 0u8, 217u8, 23u8, 0u8, 218u8, 23u8, 0u8, 3u8,
+                    24u8, 0u8, 9u8, 24u8, 0u8, 85u8, 24u8, 0u8, 150u8, 24u8, 0u8, 179u8, 24u8, 0u8,
+                    182u8, 24u8, 0u8, 185u8, 24u8, 0u8, 194u8, 24u8, 0u8, 198u8, 24u8, 0u8, 199u8,
+                    24u8, 0u8, 200u8, 24u8, 0u8, 201u8, 24u8, 0u8, 202u8, 24u8, 0u8, 203u8, 24u8,
+                    0u8, 204u8, 24u8, 0u8, 205u8, 24u8, 0u8, 206u8, 24u8, 0u8, 207u8, 24u8, 0u8,
+                    208u8, 24u8, 0u8, 209u8, 24u8, 0u8, 210u8, 24u8, 0u8, 211u8, 24u8, 0u8, 219u8,
+                    24u8, 0u8, 220u8, 24u8, 0u8, 221u8, 24u8, 0u8, 224u8, 24u8, 0u8, 227u8, 24u8,
+                    0u8, 228u8, 24u8, 0u8, 229u8, 24u8, 0u8, 232u8, 24u8, 0u8, 234u8, 24u8, 0u8,
+                    237u8, 24u8, 0u8, 240u8, 24u8, 0u8, 242u8, 24u8, 0u8, 208u8, 25u8, 0u8, 209u8,
+                    25u8, 0u8, 128u8, 26u8, 0u8, 144u8, 26u8, 0u8, 169u8, 26u8, 0u8, 171u8, 26u8,
+                    0u8, 180u8, 26u8, 0u8, 183u8, 26u8, 0u8, 82u8, 27u8, 0u8, 83u8, 27u8, 0u8,
+                    88u8, 27u8, 0u8, 92u8, 27u8, 0u8, 95u8, 27u8, 0u8, 60u8, 28u8, 0u8, 127u8,
+                    28u8, 0u8, 208u8, 28u8, 0u8, 210u8, 28u8, 0u8, 211u8, 28u8, 0u8, 213u8, 28u8,
+                    0u8, 216u8, 28u8, 0u8, 217u8, 28u8, 0u8, 218u8, 28u8, 0u8, 220u8, 28u8, 0u8,
+                    221u8, 28u8, 0u8, 222u8, 28u8, 0u8, 237u8, 28u8, 0u8, 4u8, 29u8, 0u8, 8u8,
+                    29u8, 0u8, 11u8, 29u8, 0u8, 13u8, 29u8, 0u8, 15u8, 29u8, 0u8, 16u8, 29u8, 0u8,
+                    17u8, 29u8, 0u8, 20u8, 29u8, 0u8, 28u8, 29u8, 0u8, 32u8, 29u8, 0u8, 33u8, 29u8,
+                    0u8, 34u8, 29u8, 0u8, 36u8, 29u8, 0u8, 38u8, 29u8, 0u8, 39u8, 29u8, 0u8, 40u8,
+                    29u8, 0u8, 41u8, 29u8, 0u8, 43u8, 29u8, 0u8, 62u8, 29u8, 0u8, 82u8, 29u8, 0u8,
+                    107u8, 29u8, 0u8, 110u8, 29u8, 0u8, 111u8, 29u8, 0u8, 112u8, 29u8, 0u8, 114u8,
+                    29u8, 0u8, 115u8, 29u8, 0u8, 116u8, 29u8, 0u8, 117u8, 29u8, 0u8, 118u8, 29u8,
+                    0u8, 120u8, 29u8, 0u8, 123u8, 29u8, 0u8, 124u8, 29u8, 0u8, 125u8, 29u8, 0u8,
+                    126u8, 29u8, 0u8, 127u8, 29u8, 0u8, 131u8, 29u8, 0u8, 140u8, 29u8, 0u8, 144u8,
+                    29u8, 0u8, 159u8, 29u8, 0u8, 162u8, 29u8, 0u8, 186u8, 29u8, 0u8, 187u8, 29u8,
+                    0u8, 238u8, 29u8, 0u8, 67u8, 30u8, 0u8, 154u8, 30u8, 0u8, 157u8, 30u8, 0u8,
+                    255u8, 30u8, 0u8, 125u8, 31u8, 0u8, 189u8, 31u8, 0u8, 190u8, 31u8, 0u8, 191u8,
+                    31u8, 0u8, 192u8, 31u8, 0u8, 239u8, 31u8, 0u8, 246u8, 31u8, 0u8, 253u8, 31u8,
+                    0u8, 254u8, 31u8, 0u8, 0u8, 32u8, 0u8, 1u8, 32u8, 0u8, 2u8, 32u8, 0u8, 3u8,
+                    32u8, 0u8, 4u8, 32u8, 0u8, 5u8, 32u8, 0u8, 6u8, 32u8, 0u8, 7u8, 32u8, 0u8, 8u8,
+                    32u8, 0u8, 9u8, 32u8, 0u8, 10u8, 32u8, 0u8, 16u8, 32u8, 0u8, 17u8, 32u8, 0u8,
+                    18u8, 32u8, 0u8, 19u8, 32u8, 0u8, 20u8, 32u8, 0u8, 21u8, 32u8, 0u8, 22u8, 32u8,
+                    0u8, 24u8, 32u8, 0u8, 25u8, 32u8, 0u8, 26u8, 32u8, 0u8, 27u8, 32u8, 0u8, 28u8,
+                    32u8, 0u8, 29u8, 32u8, 0u8, 31u8, 32u8, 0u8, 34u8, 32u8, 0u8, 36u8, 32u8, 0u8,
+                    37u8, 32u8, 0u8, 38u8, 32u8, 0u8, 39u8, 32u8, 0u8, 40u8, 32u8, 0u8, 41u8, 32u8,
+                    0u8, 47u8, 32u8, 0u8, 48u8, 32u8, 0u8, 49u8, 32u8, 0u8, 50u8, 32u8, 0u8, 51u8,
+                    32u8, 0u8, 52u8, 32u8, 0u8, 53u8, 32u8, 0u8, 54u8, 32u8, 0u8, 55u8, 32u8, 0u8,
+                    57u8, 32u8, 0u8, 58u8, 32u8, 0u8, 60u8, 32u8, 0u8, 62u8, 32u8, 0u8, 65u8, 32u8,
+                    0u8, 67u8, 32u8, 0u8, 68u8, 32u8, 0u8, 71u8, 32u8, 0u8, 72u8, 32u8, 0u8, 73u8,
+                    32u8, 0u8, 78u8, 32u8, 0u8, 82u8, 32u8, 0u8, 83u8, 32u8, 0u8, 87u8, 32u8, 0u8,
+                    90u8, 32u8, 0u8, 93u8, 32u8, 0u8, 94u8, 32u8, 0u8, 95u8, 32u8, 0u8, 112u8,
+                    32u8, 0u8, 121u8, 32u8, 0u8, 161u8, 32u8, 0u8, 164u8, 32u8, 0u8, 165u8, 32u8,
+                    0u8, 168u8, 32u8, 0u8, 169u8, 32u8, 0u8, 171u8, 32u8, 0u8, 172u8, 32u8, 0u8,
+                    173u8, 32u8, 0u8, 174u8, 32u8, 0u8, 182u8, 32u8, 0u8, 189u8, 32u8, 0u8, 219u8,
+                    32u8, 0u8, 0u8, 33u8, 0u8, 1u8, 33u8, 0u8, 2u8, 33u8, 0u8, 3u8, 33u8, 0u8, 5u8,
+                    33u8, 0u8, 6u8, 33u8, 0u8, 7u8, 33u8, 0u8, 8u8, 33u8, 0u8, 9u8, 33u8, 0u8,
+                    10u8, 33u8, 0u8, 11u8, 33u8, 0u8, 12u8, 33u8, 0u8, 13u8, 33u8, 0u8, 14u8, 33u8,
+                    0u8, 15u8, 33u8, 0u8, 16u8, 33u8, 0u8, 17u8, 33u8, 0u8, 18u8, 33u8, 0u8, 19u8,
+                    33u8, 0u8, 21u8, 33u8, 0u8, 22u8, 33u8, 0u8, 25u8, 33u8, 0u8, 26u8, 33u8, 0u8,
+                    27u8, 33u8, 0u8, 28u8, 33u8, 0u8, 29u8, 33u8, 0u8, 33u8, 33u8, 0u8, 36u8, 33u8,
+                    0u8, 38u8, 33u8, 0u8, 39u8, 33u8, 0u8, 40u8, 33u8, 0u8, 41u8, 33u8, 0u8, 42u8,
+                    33u8, 0u8, 44u8, 33u8, 0u8, 45u8, 33u8, 0u8, 46u8, 33u8, 0u8, 47u8, 33u8, 0u8,
+                    48u8, 33u8, 0u8, 49u8, 33u8, 0u8, 51u8, 33u8, 0u8, 52u8, 33u8, 0u8, 53u8, 33u8,
+                    0u8, 54u8, 33u8, 0u8, 55u8, 33u8, 0u8, 56u8, 33u8, 0u8, 57u8, 33u8, 0u8, 59u8,
+                    33u8, 0u8, 60u8, 33u8, 0u8, 61u8, 33u8, 0u8, 62u8, 33u8, 0u8, 63u8, 33u8, 0u8,
+                    64u8, 33u8, 0u8, 65u8, 33u8, 0u8, 66u8, 33u8, 0u8, 67u8, 33u8, 0u8, 69u8, 33u8,
+                    0u8, 70u8, 33u8, 0u8, 71u8, 33u8, 0u8, 72u8, 33u8, 0u8, 73u8, 33u8, 0u8, 96u8,
+                    33u8, 0u8, 97u8, 33u8, 0u8, 98u8, 33u8, 0u8, 99u8, 33u8, 0u8, 100u8, 33u8, 0u8,
+                    101u8, 33u8, 0u8, 102u8, 33u8, 0u8, 103u8, 33u8, 0u8, 104u8, 33u8, 0u8, 105u8,
+                    33u8, 0u8, 106u8, 33u8, 0u8, 107u8, 33u8, 0u8, 108u8, 33u8, 0u8, 109u8, 33u8,
+                    0u8, 110u8, 33u8, 0u8, 111u8, 33u8, 0u8, 112u8, 33u8, 0u8, 113u8, 33u8, 0u8,
+                    114u8, 33u8, 0u8, 115u8, 33u8, 0u8, 116u8, 33u8, 0u8, 117u8, 33u8, 0u8, 118u8,
+                    33u8, 0u8, 119u8, 33u8, 0u8, 120u8, 33u8, 0u8, 121u8, 33u8, 0u8, 122u8, 33u8,
+                    0u8, 123u8, 33u8, 0u8, 124u8, 33u8, 0u8, 125u8, 33u8, 0u8, 126u8, 33u8, 0u8,
+                    127u8, 33u8, 0u8, 131u8, 33u8, 0u8, 132u8, 33u8, 0u8, 145u8, 33u8, 0u8, 149u8,
+                    33u8, 0u8, 181u8, 33u8, 0u8, 186u8, 33u8, 0u8, 190u8, 33u8, 0u8, 191u8, 33u8,
+                    0u8, 0u8, 34u8, 0u8, 3u8, 34u8, 0u8, 6u8, 34u8, 0u8, 15u8, 34u8, 0u8, 17u8,
+                    34u8, 0u8, 18u8, 34u8, 0u8, 20u8, 34u8, 0u8, 21u8, 34u8, 0u8, 22u8, 34u8, 0u8,
+                    23u8, 34u8, 0u8, 24u8, 34u8, 0u8, 25u8, 34u8, 0u8, 30u8, 34u8, 0u8, 35u8, 34u8,
+                    0u8, 37u8, 34u8, 0u8, 40u8, 34u8, 0u8, 41u8, 34u8, 0u8, 42u8, 34u8, 0u8, 43u8,
+                    34u8, 0u8, 44u8, 34u8, 0u8, 45u8, 34u8, 0u8, 47u8, 34u8, 0u8, 48u8, 34u8, 0u8,
+                    54u8, 34u8, 0u8, 56u8, 34u8, 0u8, 60u8, 34u8, 0u8, 80u8, 34u8, 0u8, 81u8, 34u8,
+                    0u8, 87u8, 34u8, 0u8, 89u8, 34u8, 0u8, 90u8, 34u8, 0u8, 94u8, 34u8, 0u8, 99u8,
+                    34u8, 0u8, 106u8, 34u8, 0u8, 107u8, 34u8, 0u8, 130u8, 34u8, 0u8, 131u8, 34u8,
+                    0u8, 149u8, 34u8, 0u8, 150u8, 34u8, 0u8, 153u8, 34u8, 0u8, 157u8, 34u8, 0u8,
+                    164u8, 34u8, 0u8, 165u8, 34u8, 0u8, 192u8, 34u8, 0u8, 193u8, 34u8, 0u8, 194u8,
+                    34u8, 0u8, 195u8, 34u8, 0u8, 196u8, 34u8, 0u8, 197u8, 34u8, 0u8, 200u8, 34u8,
+                    0u8, 214u8, 34u8, 0u8, 215u8, 34u8, 0u8, 216u8, 34u8, 0u8, 217u8, 34u8, 0u8,
+                    238u8, 34u8, 0u8, 239u8, 34u8, 0u8, 244u8, 34u8, 0u8, 255u8, 34u8, 0u8, 0u8,
+                    35u8, 0u8, 37u8, 35u8, 0u8, 41u8, 35u8, 0u8, 42u8, 35u8, 0u8, 65u8, 35u8, 0u8,
+                    89u8, 35u8, 0u8, 90u8, 35u8, 0u8, 92u8, 35u8, 0u8, 95u8, 35u8, 0u8, 97u8, 35u8,
+                    0u8, 98u8, 35u8, 0u8, 99u8, 35u8, 0u8, 100u8, 35u8, 0u8, 101u8, 35u8, 0u8,
+                    104u8, 35u8, 0u8, 105u8, 35u8, 0u8, 107u8, 35u8, 0u8, 108u8, 35u8, 0u8, 115u8,
+                    35u8, 0u8, 116u8, 35u8, 0u8, 117u8, 35u8, 0u8, 118u8, 35u8, 0u8, 119u8, 35u8,
+                    0u8, 120u8, 35u8, 0u8, 121u8, 35u8, 0u8, 122u8, 35u8, 0u8, 127u8, 35u8, 0u8,
+                    156u8, 35u8, 0u8, 159u8, 35u8, 0u8, 162u8, 35u8, 0u8, 165u8, 35u8, 0u8, 170u8,
+                    35u8, 0u8, 174u8, 35u8, 0u8, 193u8, 35u8, 0u8, 194u8, 35u8, 0u8, 195u8, 35u8,
+                    0u8, 198u8, 35u8, 0u8, 232u8, 35u8, 0u8, 252u8, 35u8, 0u8, 253u8, 35u8, 0u8,
+                    254u8, 35u8, 0u8, 74u8, 36u8, 0u8, 96u8, 36u8, 0u8, 97u8, 36u8, 0u8, 98u8,
+                    36u8, 0u8, 99u8, 36u8, 0u8, 100u8, 36u8, 0u8, 101u8, 36u8, 0u8, 102u8, 36u8,
+                    0u8, 103u8, 36u8, 0u8, 104u8, 36u8, 0u8, 105u8, 36u8, 0u8, 116u8, 36u8, 0u8,
+                    117u8, 36u8, 0u8, 118u8, 36u8, 0u8, 119u8, 36u8, 0u8, 120u8, 36u8, 0u8, 121u8,
+                    36u8, 0u8, 122u8, 36u8, 0u8, 123u8, 36u8, 0u8, 124u8, 36u8, 0u8, 125u8, 36u8,
+                    0u8, 126u8, 36u8, 0u8, 127u8, 36u8, 0u8, 128u8, 36u8, 0u8, 129u8, 36u8, 0u8,
+                    130u8, 36u8, 0u8, 131u8, 36u8, 0u8, 132u8, 36u8, 0u8, 133u8, 36u8, 0u8, 134u8,
+                    36u8, 0u8, 135u8, 36u8, 0u8, 136u8, 36u8, 0u8, 137u8, 36u8, 0u8, 138u8, 36u8,
+                    0u8, 139u8, 36u8, 0u8, 140u8, 36u8, 0u8, 141u8, 36u8, 0u8, 142u8, 36u8, 0u8,
+                    143u8, 36u8, 0u8, 144u8, 36u8, 0u8, 145u8, 36u8, 0u8, 146u8, 36u8, 0u8, 147u8,
+                    36u8, 0u8, 148u8, 36u8, 0u8, 149u8, 36u8, 0u8, 150u8, 36u8, 0u8, 151u8, 36u8,
+                    0u8, 152u8, 36u8, 0u8, 153u8, 36u8, 0u8, 154u8, 36u8, 0u8, 155u8, 36u8, 0u8,
+                    156u8, 36u8, 0u8, 157u8, 36u8, 0u8, 158u8, 36u8, 0u8, 159u8, 36u8, 0u8, 160u8,
+                    36u8, 0u8, 161u8, 36u8, 0u8, 162u8, 36u8, 0u8, 163u8, 36u8, 0u8, 164u8, 36u8,
+                    0u8, 165u8, 36u8, 0u8, 166u8, 36u8, 0u8, 167u8, 36u8, 0u8, 168u8, 36u8, 0u8,
+                    169u8, 36u8, 0u8, 170u8, 36u8, 0u8, 171u8, 36u8, 0u8, 172u8, 36u8, 0u8, 173u8,
+                    36u8, 0u8, 174u8, 36u8, 0u8, 175u8, 36u8, 0u8, 176u8, 36u8, 0u8, 177u8, 36u8,
+                    0u8, 178u8, 36u8, 0u8, 179u8, 36u8, 0u8, 180u8, 36u8, 0u8, 181u8, 36u8, 0u8,
+                    184u8, 36u8, 0u8, 197u8, 36u8, 0u8, 199u8, 36u8, 0u8, 219u8, 36u8, 0u8, 234u8,
+                    36u8, 0u8, 0u8, 37u8, 0u8, 1u8, 37u8, 0u8, 3u8, 37u8, 0u8, 15u8, 37u8, 0u8,
+                    35u8, 37u8, 0u8, 113u8, 37u8, 0u8, 115u8, 37u8, 0u8, 136u8, 37u8, 0u8, 144u8,
+                    37u8, 0u8, 148u8, 37u8, 0u8, 151u8, 37u8, 0u8, 157u8, 37u8, 0u8, 160u8, 37u8,
+                    0u8, 177u8, 37u8, 0u8, 179u8, 37u8, 0u8, 183u8, 37u8, 0u8, 184u8, 37u8, 0u8,
+                    186u8, 37u8, 0u8, 189u8, 37u8, 0u8, 193u8, 37u8, 0u8, 199u8, 37u8, 0u8, 202u8,
+                    37u8, 0u8, 203u8, 37u8, 0u8, 206u8, 37u8, 0u8, 224u8, 37u8, 0u8, 230u8, 37u8,
+                    0u8, 9u8, 38u8, 0u8, 16u8, 38u8, 0u8, 37u8, 38u8, 0u8, 48u8, 38u8, 0u8, 56u8,
+                    38u8, 0u8, 78u8, 38u8, 0u8, 98u8, 38u8, 0u8, 105u8, 38u8, 0u8, 106u8, 38u8,
+                    0u8, 172u8, 38u8, 0u8, 104u8, 39u8, 0u8, 105u8, 39u8, 0u8, 110u8, 39u8, 0u8,
+                    111u8, 39u8, 0u8, 114u8, 39u8, 0u8, 115u8, 39u8, 0u8, 116u8, 39u8, 0u8, 117u8,
+                    39u8, 0u8, 149u8, 39u8, 0u8, 150u8, 39u8, 0u8, 151u8, 39u8, 0u8, 194u8, 39u8,
+                    0u8, 200u8, 39u8, 0u8, 201u8, 39u8, 0u8, 203u8, 39u8, 0u8, 205u8, 39u8, 0u8,
+                    217u8, 39u8, 0u8, 232u8, 39u8, 0u8, 233u8, 39u8, 0u8, 43u8, 41u8, 0u8, 44u8,
+                    41u8, 0u8, 99u8, 41u8, 0u8, 101u8, 41u8, 0u8, 110u8, 41u8, 0u8, 111u8, 41u8,
+                    0u8, 153u8, 41u8, 0u8, 176u8, 41u8, 0u8, 190u8, 41u8, 0u8, 196u8, 41u8, 0u8,
+                    197u8, 41u8, 0u8, 199u8, 41u8, 0u8, 214u8, 41u8, 0u8, 217u8, 41u8, 0u8, 244u8,
+                    41u8, 0u8, 245u8, 41u8, 0u8, 246u8, 41u8, 0u8, 248u8, 41u8, 0u8, 249u8, 41u8,
+                    0u8, 0u8, 42u8, 0u8, 1u8, 42u8, 0u8, 2u8, 42u8, 0u8, 3u8, 42u8, 0u8, 4u8, 42u8,
+                    0u8, 5u8, 42u8, 0u8, 6u8, 42u8, 0u8, 12u8, 42u8, 0u8, 29u8, 42u8, 0u8, 32u8,
+                    42u8, 0u8, 33u8, 42u8, 0u8, 34u8, 42u8, 0u8, 35u8, 42u8, 0u8, 36u8, 42u8, 0u8,
+                    37u8, 42u8, 0u8, 38u8, 42u8, 0u8, 39u8, 42u8, 0u8, 41u8, 42u8, 0u8, 42u8, 42u8,
+                    0u8, 47u8, 42u8, 0u8, 48u8, 42u8, 0u8, 61u8, 42u8, 0u8, 62u8, 42u8, 0u8, 63u8,
+                    42u8, 0u8, 106u8, 42u8, 0u8, 110u8, 42u8, 0u8, 116u8, 42u8, 0u8, 117u8, 42u8,
+                    0u8, 118u8, 42u8, 0u8, 165u8, 42u8, 0u8, 170u8, 42u8, 0u8, 171u8, 42u8, 0u8,
+                    215u8, 42u8, 0u8, 251u8, 42u8, 0u8, 253u8, 42u8, 0u8, 236u8, 43u8, 0u8, 237u8,
+                    43u8, 0u8, 238u8, 43u8, 0u8, 239u8, 43u8, 0u8, 103u8, 44u8, 0u8, 105u8, 44u8,
+                    0u8, 132u8, 44u8, 0u8, 133u8, 44u8, 0u8, 134u8, 44u8, 0u8, 136u8, 44u8, 0u8,
+                    137u8, 44u8, 0u8, 142u8, 44u8, 0u8, 146u8, 44u8, 0u8, 148u8, 44u8, 0u8, 149u8,
+                    44u8, 0u8, 150u8, 44u8, 0u8, 152u8, 44u8, 0u8, 154u8, 44u8, 0u8, 158u8, 44u8,
+                    0u8, 159u8, 44u8, 0u8, 160u8, 44u8, 0u8, 162u8, 44u8, 0u8, 163u8, 44u8, 0u8,
+                    164u8, 44u8, 0u8, 165u8, 44u8, 0u8, 166u8, 44u8, 0u8, 168u8, 44u8, 0u8, 170u8,
+                    44u8, 0u8, 171u8, 44u8, 0u8, 172u8, 44u8, 0u8, 173u8, 44u8, 0u8, 174u8, 44u8,
+                    0u8, 177u8, 44u8, 0u8, 180u8, 44u8, 0u8, 186u8, 44u8, 0u8, 188u8, 44u8, 0u8,
+                    189u8, 44u8, 0u8, 198u8, 44u8, 0u8, 202u8, 44u8, 0u8, 204u8, 44u8, 0u8, 205u8,
+                    44u8, 0u8, 208u8, 44u8, 0u8, 209u8, 44u8, 0u8, 210u8, 44u8, 0u8, 220u8, 44u8,
+                    0u8, 228u8, 44u8, 0u8, 233u8, 44u8, 0u8, 249u8, 44u8, 0u8, 49u8, 45u8, 0u8,
+                    55u8, 45u8, 0u8, 56u8, 45u8, 0u8, 57u8, 45u8, 0u8, 58u8, 45u8, 0u8, 65u8, 45u8,
+                    0u8, 72u8, 45u8, 0u8, 73u8, 45u8, 0u8, 79u8, 45u8, 0u8, 81u8, 45u8, 0u8, 84u8,
+                    45u8, 0u8, 85u8, 45u8, 0u8, 89u8, 45u8, 0u8, 93u8, 45u8, 0u8, 96u8, 45u8, 0u8,
+                    99u8, 45u8, 0u8, 232u8, 45u8, 0u8, 234u8, 45u8, 0u8, 237u8, 45u8, 0u8, 239u8,
+                    45u8, 0u8, 246u8, 45u8, 0u8, 247u8, 45u8, 0u8, 26u8, 46u8, 0u8, 30u8, 46u8,
+                    0u8, 31u8, 46u8, 0u8, 38u8, 46u8, 0u8, 39u8, 46u8, 0u8, 40u8, 46u8, 0u8, 41u8,
+                    46u8, 0u8, 42u8, 46u8, 0u8, 43u8, 46u8, 0u8, 44u8, 46u8, 0u8, 46u8, 46u8, 0u8,
+                    48u8, 46u8, 0u8, 49u8, 46u8, 0u8, 50u8, 46u8, 0u8, 53u8, 46u8, 0u8, 57u8, 46u8,
+                    0u8, 61u8, 46u8, 0u8, 63u8, 46u8, 0u8, 64u8, 46u8, 0u8, 130u8, 46u8, 0u8,
+                    131u8, 46u8, 0u8, 133u8, 46u8, 0u8, 137u8, 46u8, 0u8, 139u8, 46u8, 0u8, 142u8,
+                    46u8, 0u8, 143u8, 46u8, 0u8, 144u8, 46u8, 0u8, 146u8, 46u8, 0u8, 147u8, 46u8,
+                    0u8, 148u8, 46u8, 0u8, 150u8, 46u8, 0u8, 151u8, 46u8, 0u8, 152u8, 46u8, 0u8,
+                    153u8, 46u8, 0u8, 155u8, 46u8, 0u8, 158u8, 46u8, 0u8, 159u8, 46u8, 0u8, 160u8,
+                    46u8, 0u8, 161u8, 46u8, 0u8, 162u8, 46u8, 0u8, 163u8, 46u8, 0u8, 164u8, 46u8,
+                    0u8, 166u8, 46u8, 0u8, 168u8, 46u8, 0u8, 171u8, 46u8, 0u8, 173u8, 46u8, 0u8,
+                    175u8, 46u8, 0u8, 177u8, 46u8, 0u8, 178u8, 46u8, 0u8, 185u8, 46u8, 0u8, 186u8,
+                    46u8, 0u8, 190u8, 46u8, 0u8, 191u8, 46u8, 0u8, 192u8, 46u8, 0u8, 193u8, 46u8,
+                    0u8, 194u8, 46u8, 0u8, 195u8, 46u8, 0u8, 196u8, 46u8, 0u8, 197u8, 46u8, 0u8,
+                    200u8, 46u8, 0u8, 201u8, 46u8, 0u8, 203u8, 46u8, 0u8, 204u8, 46u8, 0u8, 205u8,
+                    46u8, 0u8, 207u8, 46u8, 0u8, 208u8, 46u8, 0u8, 209u8, 46u8, 0u8, 210u8, 46u8,
+                    0u8, 211u8, 46u8, 0u8, 212u8, 46u8, 0u8, 214u8, 46u8, 0u8, 216u8, 46u8, 0u8,
+                    217u8, 46u8, 0u8, 218u8, 46u8, 0u8, 219u8, 46u8, 0u8, 220u8, 46u8, 0u8, 221u8,
+                    46u8, 0u8, 223u8, 46u8, 0u8, 224u8, 46u8, 0u8, 226u8, 46u8, 0u8, 228u8, 46u8,
+                    0u8, 229u8, 46u8, 0u8, 232u8, 46u8, 0u8, 233u8, 46u8, 0u8, 235u8, 46u8, 0u8,
+                    236u8, 46u8, 0u8, 237u8, 46u8, 0u8, 238u8, 46u8, 0u8, 239u8, 46u8, 0u8, 240u8,
+                    46u8, 0u8, 242u8, 46u8, 0u8, 243u8, 46u8, 0u8, 0u8, 47u8, 0u8, 1u8, 47u8, 0u8,
+                    2u8, 47u8, 0u8, 3u8, 47u8, 0u8, 4u8, 47u8, 0u8, 5u8, 47u8, 0u8, 6u8, 47u8, 0u8,
+                    7u8, 47u8, 0u8, 8u8, 47u8, 0u8, 9u8, 47u8, 0u8, 10u8, 47u8, 0u8, 11u8, 47u8,
+                    0u8, 12u8, 47u8, 0u8, 13u8, 47u8, 0u8, 14u8, 47u8, 0u8, 15u8, 47u8, 0u8, 16u8,
+                    47u8, 0u8, 17u8, 47u8, 0u8, 18u8, 47u8, 0u8, 19u8, 47u8, 0u8, 20u8, 47u8, 0u8,
+                    21u8, 47u8, 0u8, 22u8, 47u8, 0u8, 23u8, 47u8, 0u8, 24u8, 47u8, 0u8, 25u8, 47u8,
+                    0u8, 26u8, 47u8, 0u8, 27u8, 47u8, 0u8, 28u8, 47u8, 0u8, 29u8, 47u8, 0u8, 30u8,
+                    47u8, 0u8, 31u8, 47u8, 0u8, 32u8, 47u8, 0u8, 33u8, 47u8, 0u8, 34u8, 47u8, 0u8,
+                    35u8, 47u8, 0u8, 36u8, 47u8, 0u8, 37u8, 47u8, 0u8, 38u8, 47u8, 0u8, 39u8, 47u8,
+                    0u8, 40u8, 47u8, 0u8, 41u8, 47u8, 0u8, 42u8, 47u8, 0u8, 43u8, 47u8, 0u8, 44u8,
+                    47u8, 0u8, 45u8, 47u8, 0u8, 46u8, 47u8, 0u8, 47u8, 47u8, 0u8, 48u8, 47u8, 0u8,
+                    49u8, 47u8, 0u8, 50u8, 47u8, 0u8, 51u8, 47u8, 0u8, 52u8, 47u8, 0u8, 53u8, 47u8,
+                    0u8, 54u8, 47u8, 0u8, 55u8, 47u8, 0u8, 56u8, 47u8, 0u8, 57u8, 47u8, 0u8, 58u8,
+                    47u8, 0u8, 59u8, 47u8, 0u8, 60u8, 47u8, 0u8, 61u8, 47u8, 0u8, 62u8, 47u8, 0u8,
+                    63u8, 47u8, 0u8, 64u8, 47u8, 0u8, 65u8, 47u8, 0u8, 66u8, 47u8, 0u8, 67u8, 47u8,
+                    0u8, 68u8, 47u8, 0u8, 69u8, 47u8, 0u8, 70u8, 47u8, 0u8, 71u8, 47u8, 0u8, 72u8,
+                    47u8, 0u8, 73u8, 47u8, 0u8, 74u8, 47u8, 0u8, 75u8, 47u8, 0u8, 76u8, 47u8, 0u8,
+                    77u8, 47u8, 0u8, 78u8, 47u8, 0u8, 79u8, 47u8, 0u8, 80u8, 47u8, 0u8, 81u8, 47u8,
+                    0u8, 82u8, 47u8, 0u8, 83u8, 47u8, 0u8, 84u8, 47u8, 0u8, 85u8, 47u8, 0u8, 86u8,
+                    47u8, 0u8, 87u8, 47u8, 0u8, 88u8, 47u8, 0u8, 89u8, 47u8, 0u8, 90u8, 47u8, 0u8,
+                    91u8, 47u8, 0u8, 92u8, 47u8, 0u8, 93u8, 47u8, 0u8, 94u8, 47u8, 0u8, 95u8, 47u8,
+                    0u8, 96u8, 47u8, 0u8, 97u8, 47u8, 0u8, 98u8, 47u8, 0u8, 99u8, 47u8, 0u8, 100u8,
+                    47u8, 0u8, 101u8, 47u8, 0u8, 102u8, 47u8, 0u8, 103u8, 47u8, 0u8, 104u8, 47u8,
+                    0u8, 105u8, 47u8, 0u8, 106u8, 47u8, 0u8, 107u8, 47u8, 0u8, 108u8, 47u8, 0u8,
+                    109u8, 47u8, 0u8, 110u8, 47u8, 0u8, 111u8, 47u8, 0u8, 112u8, 47u8, 0u8, 113u8,
+                    47u8, 0u8, 114u8, 47u8, 0u8, 115u8, 47u8, 0u8, 116u8, 47u8, 0u8, 117u8, 47u8,
+                    0u8, 118u8, 47u8, 0u8, 119u8, 47u8, 0u8, 120u8, 47u8, 0u8, 121u8, 47u8, 0u8,
+                    122u8, 47u8, 0u8, 123u8, 47u8, 0u8, 124u8, 47u8, 0u8, 125u8, 47u8, 0u8, 126u8,
+                    47u8, 0u8, 127u8, 47u8, 0u8, 128u8, 47u8, 0u8, 129u8, 47u8, 0u8, 130u8, 47u8,
+                    0u8, 131u8, 47u8, 0u8, 132u8, 47u8, 0u8, 133u8, 47u8, 0u8, 134u8, 47u8, 0u8,
+                    135u8, 47u8, 0u8, 136u8, 47u8, 0u8, 137u8, 47u8, 0u8, 138u8, 47u8, 0u8, 139u8,
+                    47u8, 0u8, 140u8, 47u8, 0u8, 141u8, 47u8, 0u8, 142u8, 47u8, 0u8, 143u8, 47u8,
+                    0u8, 144u8, 47u8, 0u8, 145u8, 47u8, 0u8, 146u8, 47u8, 0u8, 147u8, 47u8, 0u8,
+                    148u8, 47u8, 0u8, 149u8, 47u8, 0u8, 150u8, 47u8, 0u8, 151u8, 47u8, 0u8, 152u8,
+                    47u8, 0u8, 153u8, 47u8, 0u8, 154u8, 47u8, 0u8, 155u8, 47u8, 0u8, 156u8, 47u8,
+                    0u8, 157u8, 47u8, 0u8, 158u8, 47u8, 0u8, 159u8, 47u8, 0u8, 160u8, 47u8, 0u8,
+                    161u8, 47u8, 0u8, 162u8, 47u8, 0u8, 163u8, 47u8, 0u8, 164u8, 47u8, 0u8, 165u8,
+                    47u8, 0u8, 166u8, 47u8, 0u8, 167u8, 47u8, 0u8, 168u8, 47u8, 0u8, 169u8, 47u8,
+                    0u8, 170u8, 47u8, 0u8, 171u8, 47u8, 0u8, 172u8, 47u8, 0u8, 173u8, 47u8, 0u8,
+                    174u8, 47u8, 0u8, 175u8, 47u8, 0u8, 176u8, 47u8, 0u8, 177u8, 47u8, 0u8, 178u8,
+                    47u8, 0u8, 179u8, 47u8, 0u8, 180u8, 47u8, 0u8, 181u8, 47u8, 0u8, 182u8, 47u8,
+                    0u8, 183u8, 47u8, 0u8, 184u8, 47u8, 0u8, 185u8, 47u8, 0u8, 186u8, 47u8, 0u8,
+                    187u8, 47u8, 0u8, 188u8, 47u8, 0u8, 189u8, 47u8, 0u8, 190u8, 47u8, 0u8, 191u8,
+                    47u8, 0u8, 192u8, 47u8, 0u8, 193u8, 47u8, 0u8, 194u8, 47u8, 0u8, 195u8, 47u8,
+                    0u8, 196u8, 47u8, 0u8, 197u8, 47u8, 0u8, 198u8, 47u8, 0u8, 199u8, 47u8, 0u8,
+                    200u8, 47u8, 0u8, 201u8, 47u8, 0u8, 202u8, 47u8, 0u8, 203u8, 47u8, 0u8, 204u8,
+                    47u8, 0u8, 205u8, 47u8, 0u8, 206u8, 47u8, 0u8, 207u8, 47u8, 0u8, 208u8, 47u8,
+                    0u8, 209u8, 47u8, 0u8, 210u8, 47u8, 0u8, 211u8, 47u8, 0u8, 212u8, 47u8, 0u8,
+                    213u8, 47u8, 0u8, 2u8, 48u8, 0u8, 3u8, 48u8, 0u8, 7u8, 48u8, 0u8, 8u8, 48u8,
+                    0u8, 9u8, 48u8, 0u8, 18u8, 48u8, 0u8, 20u8, 48u8, 0u8, 21u8, 48u8, 0u8, 26u8,
+                    48u8, 0u8, 27u8, 48u8, 0u8, 44u8, 48u8, 0u8, 45u8, 48u8, 0u8, 51u8, 48u8, 0u8,
+                    54u8, 48u8, 0u8, 56u8, 48u8, 0u8, 57u8, 48u8, 0u8, 58u8, 48u8, 0u8, 79u8, 48u8,
+                    0u8, 154u8, 48u8, 0u8, 155u8, 48u8, 0u8, 156u8, 48u8, 0u8, 160u8, 48u8, 0u8,
+                    164u8, 48u8, 0u8, 168u8, 48u8, 0u8, 171u8, 48u8, 0u8, 191u8, 48u8, 0u8, 200u8,
+                    48u8, 0u8, 203u8, 48u8, 0u8, 206u8, 48u8, 0u8, 207u8, 48u8, 0u8, 216u8, 48u8,
+                    0u8, 237u8, 48u8, 0u8, 251u8, 48u8, 0u8, 49u8, 49u8, 0u8, 50u8, 49u8, 0u8,
+                    51u8, 49u8, 0u8, 52u8, 49u8, 0u8, 53u8, 49u8, 0u8, 54u8, 49u8, 0u8, 55u8, 49u8,
+                    0u8, 56u8, 49u8, 0u8, 57u8, 49u8, 0u8, 58u8, 49u8, 0u8, 59u8, 49u8, 0u8, 60u8,
+                    49u8, 0u8, 61u8, 49u8, 0u8, 62u8, 49u8, 0u8, 63u8, 49u8, 0u8, 64u8, 49u8, 0u8,
+                    65u8, 49u8, 0u8, 66u8, 49u8, 0u8, 67u8, 49u8, 0u8, 68u8, 49u8, 0u8, 69u8, 49u8,
+                    0u8, 70u8, 49u8, 0u8, 71u8, 49u8, 0u8, 72u8, 49u8, 0u8, 73u8, 49u8, 0u8, 74u8,
+                    49u8, 0u8, 75u8, 49u8, 0u8, 76u8, 49u8, 0u8, 77u8, 49u8, 0u8, 78u8, 49u8, 0u8,
+                    79u8, 49u8, 0u8, 80u8, 49u8, 0u8, 81u8, 49u8, 0u8, 82u8, 49u8, 0u8, 83u8, 49u8,
+                    0u8, 84u8, 49u8, 0u8, 85u8, 49u8, 0u8, 86u8, 49u8, 0u8, 87u8, 49u8, 0u8, 88u8,
+                    49u8, 0u8, 89u8, 49u8, 0u8, 90u8, 49u8, 0u8, 91u8, 49u8, 0u8, 92u8, 49u8, 0u8,
+                    93u8, 49u8, 0u8, 94u8, 49u8, 0u8, 95u8, 49u8, 0u8, 96u8, 49u8, 0u8, 97u8, 49u8,
+                    0u8, 98u8, 49u8, 0u8, 99u8, 49u8, 0u8, 100u8, 49u8, 0u8, 101u8, 49u8, 0u8,
+                    102u8, 49u8, 0u8, 103u8, 49u8, 0u8, 104u8, 49u8, 0u8, 105u8, 49u8, 0u8, 106u8,
+                    49u8, 0u8, 107u8, 49u8, 0u8, 108u8, 49u8, 0u8, 109u8, 49u8, 0u8, 110u8, 49u8,
+                    0u8, 111u8, 49u8, 0u8, 112u8, 49u8, 0u8, 113u8, 49u8, 0u8, 114u8, 49u8, 0u8,
+                    115u8, 49u8, 0u8, 116u8, 49u8, 0u8, 117u8, 49u8, 0u8, 118u8, 49u8, 0u8, 119u8,
+                    49u8, 0u8, 120u8, 49u8, 0u8, 121u8, 49u8, 0u8, 122u8, 49u8, 0u8, 123u8, 49u8,
+                    0u8, 124u8, 49u8, 0u8, 125u8, 49u8, 0u8, 126u8, 49u8, 0u8, 127u8, 49u8, 0u8,
+                    128u8, 49u8, 0u8, 129u8, 49u8, 0u8, 130u8, 49u8, 0u8, 131u8, 49u8, 0u8, 132u8,
+                    49u8, 0u8, 133u8, 49u8, 0u8, 134u8, 49u8, 0u8, 135u8, 49u8, 0u8, 136u8, 49u8,
+                    0u8, 137u8, 49u8, 0u8, 138u8, 49u8, 0u8, 139u8, 49u8, 0u8, 140u8, 49u8, 0u8,
+                    141u8, 49u8, 0u8, 142u8, 49u8, 0u8, 208u8, 49u8, 0u8, 209u8, 49u8, 0u8, 211u8,
+                    49u8, 0u8, 212u8, 49u8, 0u8, 214u8, 49u8, 0u8, 218u8, 49u8, 0u8, 219u8, 49u8,
+                    0u8, 223u8, 49u8, 0u8, 224u8, 49u8, 0u8, 0u8, 50u8, 0u8, 1u8, 50u8, 0u8, 2u8,
+                    50u8, 0u8, 3u8, 50u8, 0u8, 4u8, 50u8, 0u8, 5u8, 50u8, 0u8, 6u8, 50u8, 0u8, 7u8,
+                    50u8, 0u8, 8u8, 50u8, 0u8, 9u8, 50u8, 0u8, 10u8, 50u8, 0u8, 11u8, 50u8, 0u8,
+                    12u8, 50u8, 0u8, 13u8, 50u8, 0u8, 14u8, 50u8, 0u8, 15u8, 50u8, 0u8, 16u8, 50u8,
+                    0u8, 17u8, 50u8, 0u8, 18u8, 50u8, 0u8, 19u8, 50u8, 0u8, 20u8, 50u8, 0u8, 21u8,
+                    50u8, 0u8, 22u8, 50u8, 0u8, 23u8, 50u8, 0u8, 24u8, 50u8, 0u8, 25u8, 50u8, 0u8,
+                    26u8, 50u8, 0u8, 27u8, 50u8, 0u8, 28u8, 50u8, 0u8, 29u8, 50u8, 0u8, 30u8, 50u8,
+                    0u8, 32u8, 50u8, 0u8, 33u8, 50u8, 0u8, 34u8, 50u8, 0u8, 35u8, 50u8, 0u8, 36u8,
+                    50u8, 0u8, 37u8, 50u8, 0u8, 38u8, 50u8, 0u8, 39u8, 50u8, 0u8, 40u8, 50u8, 0u8,
+                    41u8, 50u8, 0u8, 42u8, 50u8, 0u8, 43u8, 50u8, 0u8, 44u8, 50u8, 0u8, 45u8, 50u8,
+                    0u8, 46u8, 50u8, 0u8, 47u8, 50u8, 0u8, 48u8, 50u8, 0u8, 49u8, 50u8, 0u8, 50u8,
+                    50u8, 0u8, 51u8, 50u8, 0u8, 52u8, 50u8, 0u8, 53u8, 50u8, 0u8, 54u8, 50u8, 0u8,
+                    55u8, 50u8, 0u8, 56u8, 50u8, 0u8, 57u8, 50u8, 0u8, 58u8, 50u8, 0u8, 59u8, 50u8,
+                    0u8, 60u8, 50u8, 0u8, 61u8, 50u8, 0u8, 62u8, 50u8, 0u8, 63u8, 50u8, 0u8, 64u8,
+                    50u8, 0u8, 65u8, 50u8, 0u8, 66u8, 50u8, 0u8, 67u8, 50u8, 0u8, 192u8, 50u8, 0u8,
+                    193u8, 50u8, 0u8, 194u8, 50u8, 0u8, 195u8, 50u8, 0u8, 196u8, 50u8, 0u8, 197u8,
+                    50u8, 0u8, 198u8, 50u8, 0u8, 199u8, 50u8, 0u8, 200u8, 50u8, 0u8, 201u8, 50u8,
+                    0u8, 202u8, 50u8, 0u8, 203u8, 50u8, 0u8, 88u8, 51u8, 0u8, 89u8, 51u8, 0u8,
+                    90u8, 51u8, 0u8, 91u8, 51u8, 0u8, 92u8, 51u8, 0u8, 93u8, 51u8, 0u8, 94u8, 51u8,
+                    0u8, 95u8, 51u8, 0u8, 96u8, 51u8, 0u8, 97u8, 51u8, 0u8, 98u8, 51u8, 0u8, 99u8,
+                    51u8, 0u8, 100u8, 51u8, 0u8, 101u8, 51u8, 0u8, 102u8, 51u8, 0u8, 103u8, 51u8,
+                    0u8, 104u8, 51u8, 0u8, 105u8, 51u8, 0u8, 106u8, 51u8, 0u8, 107u8, 51u8, 0u8,
+                    108u8, 51u8, 0u8, 109u8, 51u8, 0u8, 110u8, 51u8, 0u8, 111u8, 51u8, 0u8, 112u8,
+                    51u8, 0u8, 224u8, 51u8, 0u8, 225u8, 51u8, 0u8, 226u8, 51u8, 0u8, 227u8, 51u8,
+                    0u8, 228u8, 51u8, 0u8, 229u8, 51u8, 0u8, 230u8, 51u8, 0u8, 231u8, 51u8, 0u8,
+                    232u8, 51u8, 0u8, 233u8, 51u8, 0u8, 234u8, 51u8, 0u8, 235u8, 51u8, 0u8, 236u8,
+                    51u8, 0u8, 237u8, 51u8, 0u8, 238u8, 51u8, 0u8, 239u8, 51u8, 0u8, 240u8, 51u8,
+                    0u8, 241u8, 51u8, 0u8, 242u8, 51u8, 0u8, 243u8, 51u8, 0u8, 244u8, 51u8, 0u8,
+                    245u8, 51u8, 0u8, 246u8, 51u8, 0u8, 247u8, 51u8, 0u8, 248u8, 51u8, 0u8, 249u8,
+                    51u8, 0u8, 250u8, 51u8, 0u8, 251u8, 51u8, 0u8, 252u8, 51u8, 0u8, 253u8, 51u8,
+                    0u8, 254u8, 51u8, 0u8, 179u8, 57u8, 0u8, 155u8, 67u8, 0u8, 32u8, 68u8, 0u8,
+                    0u8, 78u8, 0u8, 54u8, 78u8, 0u8, 63u8, 78u8, 0u8, 2u8, 80u8, 0u8, 60u8, 80u8,
+                    0u8, 95u8, 85u8, 0u8, 215u8, 86u8, 0u8, 107u8, 88u8, 0u8, 235u8, 88u8, 0u8,
+                    255u8, 88u8, 0u8, 0u8, 91u8, 0u8, 50u8, 94u8, 0u8, 80u8, 94u8, 0u8, 56u8, 98u8,
+                    0u8, 9u8, 100u8, 0u8, 99u8, 102u8, 0u8, 105u8, 102u8, 0u8, 246u8, 102u8, 0u8,
+                    38u8, 103u8, 0u8, 255u8, 103u8, 0u8, 233u8, 105u8, 0u8, 39u8, 106u8, 0u8, 89u8,
+                    111u8, 0u8, 79u8, 120u8, 0u8, 118u8, 125u8, 0u8, 166u8, 128u8, 0u8, 202u8,
+                    128u8, 0u8, 208u8, 128u8, 0u8, 246u8, 128u8, 0u8, 1u8, 129u8, 0u8, 39u8, 129u8,
+                    0u8, 65u8, 129u8, 0u8, 167u8, 129u8, 0u8, 63u8, 133u8, 0u8, 65u8, 134u8, 0u8,
+                    30u8, 138u8, 0u8, 125u8, 138u8, 0u8, 143u8, 139u8, 0u8, 99u8, 140u8, 0u8,
+                    134u8, 141u8, 0u8, 250u8, 141u8, 0u8, 155u8, 142u8, 0u8, 39u8, 143u8, 0u8,
+                    222u8, 144u8, 0u8, 174u8, 147u8, 0u8, 184u8, 150u8, 0u8, 67u8, 158u8, 0u8,
+                    210u8, 158u8, 0u8, 195u8, 159u8, 0u8, 148u8, 164u8, 0u8, 156u8, 164u8, 0u8,
+                    158u8, 164u8, 0u8, 167u8, 164u8, 0u8, 168u8, 164u8, 0u8, 172u8, 164u8, 0u8,
+                    176u8, 164u8, 0u8, 186u8, 164u8, 0u8, 190u8, 164u8, 0u8, 191u8, 164u8, 0u8,
+                    192u8, 164u8, 0u8, 194u8, 164u8, 0u8, 208u8, 164u8, 0u8, 209u8, 164u8, 0u8,
+                    210u8, 164u8, 0u8, 211u8, 164u8, 0u8, 212u8, 164u8, 0u8, 214u8, 164u8, 0u8,
+                    215u8, 164u8, 0u8, 217u8, 164u8, 0u8, 218u8, 164u8, 0u8, 219u8, 164u8, 0u8,
+                    220u8, 164u8, 0u8, 221u8, 164u8, 0u8, 222u8, 164u8, 0u8, 223u8, 164u8, 0u8,
+                    224u8, 164u8, 0u8, 225u8, 164u8, 0u8, 226u8, 164u8, 0u8, 227u8, 164u8, 0u8,
+                    229u8, 164u8, 0u8, 230u8, 164u8, 0u8, 231u8, 164u8, 0u8, 234u8, 164u8, 0u8,
+                    235u8, 164u8, 0u8, 236u8, 164u8, 0u8, 237u8, 164u8, 0u8, 238u8, 164u8, 0u8,
+                    239u8, 164u8, 0u8, 240u8, 164u8, 0u8, 241u8, 164u8, 0u8, 242u8, 164u8, 0u8,
+                    243u8, 164u8, 0u8, 244u8, 164u8, 0u8, 245u8, 164u8, 0u8, 247u8, 164u8, 0u8,
+                    248u8, 164u8, 0u8, 249u8, 164u8, 0u8, 250u8, 164u8, 0u8, 251u8, 164u8, 0u8,
+                    253u8, 164u8, 0u8, 254u8, 164u8, 0u8, 255u8, 164u8, 0u8, 14u8, 166u8, 0u8,
+                    68u8, 166u8, 0u8, 69u8, 166u8, 0u8, 71u8, 166u8, 0u8, 77u8, 166u8, 0u8, 80u8,
+                    166u8, 0u8, 81u8, 166u8, 0u8, 104u8, 166u8, 0u8, 111u8, 166u8, 0u8, 124u8,
+                    166u8, 0u8, 126u8, 166u8, 0u8, 149u8, 166u8, 0u8, 152u8, 166u8, 0u8, 153u8,
+                    166u8, 0u8, 154u8, 166u8, 0u8, 161u8, 166u8, 0u8, 176u8, 166u8, 0u8, 177u8,
+                    166u8, 0u8, 205u8, 166u8, 0u8, 206u8, 166u8, 0u8, 219u8, 166u8, 0u8, 223u8,
+                    166u8, 0u8, 235u8, 166u8, 0u8, 239u8, 166u8, 0u8, 240u8, 166u8, 0u8, 241u8,
+                    166u8, 0u8, 244u8, 166u8, 0u8, 20u8, 167u8, 0u8, 22u8, 167u8, 0u8, 40u8, 167u8,
+                    0u8, 41u8, 167u8, 0u8, 49u8, 167u8, 0u8, 50u8, 167u8, 0u8, 51u8, 167u8, 0u8,
+                    52u8, 167u8, 0u8, 53u8, 167u8, 0u8, 54u8, 167u8, 0u8, 55u8, 167u8, 0u8, 56u8,
+                    167u8, 0u8, 57u8, 167u8, 0u8, 58u8, 167u8, 0u8, 59u8, 167u8, 0u8, 60u8, 167u8,
+                    0u8, 61u8, 167u8, 0u8, 64u8, 167u8, 0u8, 74u8, 167u8, 0u8, 75u8, 167u8, 0u8,
+                    78u8, 167u8, 0u8, 79u8, 167u8, 0u8, 90u8, 167u8, 0u8, 97u8, 167u8, 0u8, 106u8,
+                    167u8, 0u8, 107u8, 167u8, 0u8, 110u8, 167u8, 0u8, 119u8, 167u8, 0u8, 120u8,
+                    167u8, 0u8, 122u8, 167u8, 0u8, 137u8, 167u8, 0u8, 140u8, 167u8, 0u8, 143u8,
+                    167u8, 0u8, 149u8, 167u8, 0u8, 152u8, 167u8, 0u8, 153u8, 167u8, 0u8, 154u8,
+                    167u8, 0u8, 155u8, 167u8, 0u8, 157u8, 167u8, 0u8, 158u8, 167u8, 0u8, 159u8,
+                    167u8, 0u8, 171u8, 167u8, 0u8, 177u8, 167u8, 0u8, 178u8, 167u8, 0u8, 179u8,
+                    167u8, 0u8, 180u8, 167u8, 0u8, 181u8, 167u8, 0u8, 182u8, 167u8, 0u8, 183u8,
+                    167u8, 0u8, 247u8, 167u8, 0u8, 48u8, 168u8, 0u8, 96u8, 169u8, 0u8, 97u8, 169u8,
+                    0u8, 98u8, 169u8, 0u8, 99u8, 169u8, 0u8, 100u8, 169u8, 0u8, 101u8, 169u8, 0u8,
+                    102u8, 169u8, 0u8, 103u8, 169u8, 0u8, 104u8, 169u8, 0u8, 105u8, 169u8, 0u8,
+                    106u8, 169u8, 0u8, 107u8, 169u8, 0u8, 108u8, 169u8, 0u8, 109u8, 169u8, 0u8,
+                    110u8, 169u8, 0u8, 111u8, 169u8, 0u8, 112u8, 169u8, 0u8, 113u8, 169u8, 0u8,
+                    114u8, 169u8, 0u8, 115u8, 169u8, 0u8, 116u8, 169u8, 0u8, 117u8, 169u8, 0u8,
+                    118u8, 169u8, 0u8, 119u8, 169u8, 0u8, 120u8, 169u8, 0u8, 121u8, 169u8, 0u8,
+                    122u8, 169u8, 0u8, 123u8, 169u8, 0u8, 124u8, 169u8, 0u8, 146u8, 169u8, 0u8,
+                    163u8, 169u8, 0u8, 198u8, 169u8, 0u8, 207u8, 169u8, 0u8, 83u8, 170u8, 0u8,
+                    86u8, 170u8, 0u8, 50u8, 171u8, 0u8, 53u8, 171u8, 0u8, 61u8, 171u8, 0u8, 62u8,
+                    171u8, 0u8, 63u8, 171u8, 0u8, 65u8, 171u8, 0u8, 66u8, 171u8, 0u8, 71u8, 171u8,
+                    0u8, 72u8, 171u8, 0u8, 77u8, 171u8, 0u8, 78u8, 171u8, 0u8, 82u8, 171u8, 0u8,
+                    83u8, 171u8, 0u8, 85u8, 171u8, 0u8, 90u8, 171u8, 0u8, 96u8, 171u8, 0u8, 98u8,
+                    171u8, 0u8, 99u8, 171u8, 0u8, 112u8, 171u8, 0u8, 113u8, 171u8, 0u8, 114u8,
+                    171u8, 0u8, 116u8, 171u8, 0u8, 117u8, 171u8, 0u8, 122u8, 171u8, 0u8, 123u8,
+                    171u8, 0u8, 124u8, 171u8, 0u8, 126u8, 171u8, 0u8, 128u8, 171u8, 0u8, 129u8,
+                    171u8, 0u8, 131u8, 171u8, 0u8, 135u8, 171u8, 0u8, 139u8, 171u8, 0u8, 142u8,
+                    171u8, 0u8, 144u8, 171u8, 0u8, 147u8, 171u8, 0u8, 155u8, 171u8, 0u8, 156u8,
+                    171u8, 0u8, 159u8, 171u8, 0u8, 162u8, 171u8, 0u8, 169u8, 171u8, 0u8, 170u8,
+                    171u8, 0u8, 174u8, 171u8, 0u8, 175u8, 171u8, 0u8, 178u8, 171u8, 0u8, 182u8,
+                    171u8, 0u8, 187u8, 171u8, 0u8, 176u8, 215u8, 0u8, 177u8, 215u8, 0u8, 178u8,
+                    215u8, 0u8, 179u8, 215u8, 0u8, 180u8, 215u8, 0u8, 181u8, 215u8, 0u8, 182u8,
+                    215u8, 0u8, 183u8, 215u8, 0u8, 184u8, 215u8, 0u8, 185u8, 215u8, 0u8, 186u8,
+                    215u8, 0u8, 187u8, 215u8, 0u8, 188u8, 215u8, 0u8, 189u8, 215u8, 0u8, 190u8,
+                    215u8, 0u8, 191u8, 215u8, 0u8, 192u8, 215u8, 0u8, 193u8, 215u8, 0u8, 194u8,
+                    215u8, 0u8, 195u8, 215u8, 0u8, 196u8, 215u8, 0u8, 197u8, 215u8, 0u8, 198u8,
+                    215u8, 0u8, 203u8, 215u8, 0u8, 204u8, 215u8, 0u8, 205u8, 215u8, 0u8, 206u8,
+                    215u8, 0u8, 207u8, 215u8, 0u8, 208u8, 215u8, 0u8, 209u8, 215u8, 0u8, 210u8,
+                    215u8, 0u8, 211u8, 215u8, 0u8, 212u8, 215u8, 0u8, 213u8, 215u8, 0u8, 214u8,
+                    215u8, 0u8, 215u8, 215u8, 0u8, 216u8, 215u8, 0u8, 217u8, 215u8, 0u8, 218u8,
+                    215u8, 0u8, 219u8, 215u8, 0u8, 220u8, 215u8, 0u8, 221u8, 215u8, 0u8, 222u8,
+                    215u8, 0u8, 223u8, 215u8, 0u8, 224u8, 215u8, 0u8, 225u8, 215u8, 0u8, 226u8,
+                    215u8, 0u8, 227u8, 215u8, 0u8, 228u8, 215u8, 0u8, 229u8, 215u8, 0u8, 230u8,
+                    215u8, 0u8, 231u8, 215u8, 0u8, 232u8, 215u8, 0u8, 233u8, 215u8, 0u8, 234u8,
+                    215u8, 0u8, 235u8, 215u8, 0u8, 236u8, 215u8, 0u8, 237u8, 215u8, 0u8, 238u8,
+                    215u8, 0u8, 239u8, 215u8, 0u8, 240u8, 215u8, 0u8, 241u8, 215u8, 0u8, 242u8,
+                    215u8, 0u8, 243u8, 215u8, 0u8, 244u8, 215u8, 0u8, 245u8, 215u8, 0u8, 246u8,
+                    215u8, 0u8, 247u8, 215u8, 0u8, 248u8, 215u8, 0u8, 249u8, 215u8, 0u8, 250u8,
+                    215u8, 0u8, 251u8, 215u8, 0u8, 0u8, 249u8, 0u8, 1u8, 249u8, 0u8, 2u8, 249u8,
+                    0u8, 3u8, 249u8, 0u8, 4u8, 249u8, 0u8, 5u8, 249u8, 0u8, 6u8, 249u8, 0u8, 7u8,
+                    249u8, 0u8, 8u8, 249u8, 0u8, 9u8, 249u8, 0u8, 10u8, 249u8, 0u8, 11u8, 249u8,
+                    0u8, 12u8, 249u8, 0u8, 13u8, 249u8, 0u8, 14u8, 249u8, 0u8, 15u8, 249u8, 0u8,
+                    16u8, 249u8, 0u8, 17u8, 249u8, 0u8, 18u8, 249u8, 0u8, 19u8, 249u8, 0u8, 20u8,
+                    249u8, 0u8, 21u8, 249u8, 0u8, 22u8, 249u8, 0u8, 23u8, 249u8, 0u8, 24u8, 249u8,
+                    0u8, 25u8, 249u8, 0u8, 26u8, 249u8, 0u8, 27u8, 249u8, 0u8, 28u8, 249u8, 0u8,
+                    29u8, 249u8, 0u8, 30u8, 249u8, 0u8, 31u8, 249u8, 0u8, 32u8, 249u8, 0u8, 33u8,
+                    249u8, 0u8, 34u8, 249u8, 0u8, 35u8, 249u8, 0u8, 36u8, 249u8, 0u8, 37u8, 249u8,
+                    0u8, 38u8, 249u8, 0u8, 39u8, 249u8, 0u8, 40u8, 249u8, 0u8, 41u8, 249u8, 0u8,
+                    42u8, 249u8, 0u8, 43u8, 249u8, 0u8, 44u8, 249u8, 0u8, 45u8, 249u8, 0u8, 46u8,
+                    249u8, 0u8, 47u8, 249u8, 0u8, 48u8, 249u8, 0u8, 49u8, 249u8, 0u8, 50u8, 249u8,
+                    0u8, 51u8, 249u8, 0u8, 52u8, 249u8, 0u8, 53u8, 249u8, 0u8, 54u8, 249u8, 0u8,
+                    55u8, 249u8, 0u8, 56u8, 249u8, 0u8, 57u8, 249u8, 0u8, 58u8, 249u8, 0u8, 59u8,
+                    249u8, 0u8, 60u8, 249u8, 0u8, 61u8, 249u8, 0u8, 62u8, 249u8, 0u8, 63u8, 249u8,
+                    0u8, 64u8, 249u8, 0u8, 65u8, 249u8, 0u8, 66u8, 249u8, 0u8, 67u8, 249u8, 0u8,
+                    68u8, 249u8, 0u8, 69u8, 249u8, 0u8, 70u8, 249u8, 0u8, 71u8, 249u8, 0u8, 72u8,
+                    249u8, 0u8, 73u8, 249u8, 0u8, 74u8, 249u8, 0u8, 75u8, 249u8, 0u8, 76u8, 249u8,
+                    0u8, 77u8, 249u8, 0u8, 78u8, 249u8, 0u8, 79u8, 249u8, 0u8, 80u8, 249u8, 0u8,
+                    81u8, 249u8, 0u8, 82u8, 249u8, 0u8, 83u8, 249u8, 0u8, 84u8, 249u8, 0u8, 85u8,
+                    249u8, 0u8, 86u8, 249u8, 0u8, 87u8, 249u8, 0u8, 88u8, 249u8, 0u8, 89u8, 249u8,
+                    0u8, 90u8, 249u8, 0u8, 91u8, 249u8, 0u8, 92u8, 249u8, 0u8, 93u8, 249u8, 0u8,
+                    94u8, 249u8, 0u8, 95u8, 249u8, 0u8, 96u8, 249u8, 0u8, 97u8, 249u8, 0u8, 98u8,
+                    249u8, 0u8, 99u8, 249u8, 0u8, 100u8, 249u8, 0u8, 101u8, 249u8, 0u8, 102u8,
+                    249u8, 0u8, 103u8, 249u8, 0u8, 104u8, 249u8, 0u8, 105u8, 249u8, 0u8, 106u8,
+                    249u8, 0u8, 107u8, 249u8, 0u8, 108u8, 249u8, 0u8, 109u8, 249u8, 0u8, 110u8,
+                    249u8, 0u8, 111u8, 249u8, 0u8, 112u8, 249u8, 0u8, 113u8, 249u8, 0u8, 114u8,
+                    249u8, 0u8, 115u8, 249u8, 0u8, 116u8, 249u8, 0u8, 117u8, 249u8, 0u8, 118u8,
+                    249u8, 0u8, 119u8, 249u8, 0u8, 120u8, 249u8, 0u8, 121u8, 249u8, 0u8, 122u8,
+                    249u8, 0u8, 123u8, 249u8, 0u8, 124u8, 249u8, 0u8, 125u8, 249u8, 0u8, 126u8,
+                    249u8, 0u8, 127u8, 249u8, 0u8, 128u8, 249u8, 0u8, 129u8, 249u8, 0u8, 130u8,
+                    249u8, 0u8, 131u8, 249u8, 0u8, 132u8, 249u8, 0u8, 133u8, 249u8, 0u8, 134u8,
+                    249u8, 0u8, 135u8, 249u8, 0u8, 136u8, 249u8, 0u8, 137u8, 249u8, 0u8, 138u8,
+                    249u8, 0u8, 139u8, 249u8, 0u8, 140u8, 249u8, 0u8, 141u8, 249u8, 0u8, 142u8,
+                    249u8, 0u8, 143u8, 249u8, 0u8, 144u8, 249u8, 0u8, 145u8, 249u8, 0u8, 146u8,
+                    249u8, 0u8, 147u8, 249u8, 0u8, 148u8, 249u8, 0u8, 149u8, 249u8, 0u8, 150u8,
+                    249u8, 0u8, 151u8, 249u8, 0u8, 152u8, 249u8, 0u8, 153u8, 249u8, 0u8, 154u8,
+                    249u8, 0u8, 155u8, 249u8, 0u8, 156u8, 249u8, 0u8, 157u8, 249u8, 0u8, 158u8,
+                    249u8, 0u8, 159u8, 249u8, 0u8, 160u8, 249u8, 0u8, 161u8, 249u8, 0u8, 162u8,
+                    249u8, 0u8, 163u8, 249u8, 0u8, 164u8, 249u8, 0u8, 165u8, 249u8, 0u8, 166u8,
+                    249u8, 0u8, 167u8, 249u8, 0u8, 168u8, 249u8, 0u8, 169u8, 249u8, 0u8, 170u8,
+                    249u8, 0u8, 171u8, 249u8, 0u8, 172u8, 249u8, 0u8, 173u8, 249u8, 0u8, 174u8,
+                    249u8, 0u8, 175u8, 249u8, 0u8, 176u8, 249u8, 0u8, 177u8, 249u8, 0u8, 178u8,
+                    249u8, 0u8, 179u8, 249u8, 0u8, 180u8, 249u8, 0u8, 181u8, 249u8, 0u8, 182u8,
+                    249u8, 0u8, 183u8, 249u8, 0u8, 184u8, 249u8, 0u8, 185u8, 249u8, 0u8, 186u8,
+                    249u8, 0u8, 187u8, 249u8, 0u8, 188u8, 249u8, 0u8, 189u8, 249u8, 0u8, 190u8,
+                    249u8, 0u8, 191u8, 249u8, 0u8, 192u8, 249u8, 0u8, 193u8, 249u8, 0u8, 194u8,
+                    249u8, 0u8, 195u8, 249u8, 0u8, 196u8, 249u8, 0u8, 197u8, 249u8, 0u8, 198u8,
+                    249u8, 0u8, 199u8, 249u8, 0u8, 200u8, 249u8, 0u8, 201u8, 249u8, 0u8, 202u8,
+                    249u8, 0u8, 203u8, 249u8, 0u8, 204u8, 249u8, 0u8, 205u8, 249u8, 0u8, 206u8,
+                    249u8, 0u8, 207u8, 249u8, 0u8, 208u8, 249u8, 0u8, 209u8, 249u8, 0u8, 210u8,
+                    249u8, 0u8, 211u8, 249u8, 0u8, 212u8, 249u8, 0u8, 213u8, 249u8, 0u8, 214u8,
+                    249u8, 0u8, 215u8, 249u8, 0u8, 216u8, 249u8, 0u8, 217u8, 249u8, 0u8, 218u8,
+                    249u8, 0u8, 219u8, 249u8, 0u8, 220u8, 249u8, 0u8, 221u8, 249u8, 0u8, 222u8,
+                    249u8, 0u8, 223u8, 249u8, 0u8, 224u8, 249u8, 0u8, 225u8, 249u8, 0u8, 226u8,
+                    249u8, 0u8, 227u8, 249u8, 0u8, 228u8, 249u8, 0u8, 229u8, 249u8, 0u8, 230u8,
+                    249u8, 0u8, 231u8, 249u8, 0u8, 232u8, 249u8, 0u8, 233u8, 249u8, 0u8, 234u8,
+                    249u8, 0u8, 235u8, 249u8, 0u8, 236u8, 249u8, 0u8, 237u8, 249u8, 0u8, 238u8,
+                    249u8, 0u8, 239u8, 249u8, 0u8, 240u8, 249u8, 0u8, 241u8, 249u8, 0u8, 242u8,
+                    249u8, 0u8, 243u8, 249u8, 0u8, 244u8, 249u8, 0u8, 245u8, 249u8, 0u8, 246u8,
+                    249u8, 0u8, 247u8, 249u8, 0u8, 248u8, 249u8, 0u8, 249u8, 249u8, 0u8, 250u8,
+                    249u8, 0u8, 251u8, 249u8, 0u8, 252u8, 249u8, 0u8, 253u8, 249u8, 0u8, 254u8,
+                    249u8, 0u8, 255u8, 249u8, 0u8, 0u8, 250u8, 0u8, 1u8, 250u8, 0u8, 2u8, 250u8,
+                    0u8, 3u8, 250u8, 0u8, 4u8, 250u8, 0u8, 5u8, 250u8, 0u8, 6u8, 250u8, 0u8, 7u8,
+                    250u8, 0u8, 8u8, 250u8, 0u8, 9u8, 250u8, 0u8, 10u8, 250u8, 0u8, 11u8, 250u8,
+                    0u8, 12u8, 250u8, 0u8, 13u8, 250u8, 0u8, 16u8, 250u8, 0u8, 18u8, 250u8, 0u8,
+                    21u8, 250u8, 0u8, 22u8, 250u8, 0u8, 23u8, 250u8, 0u8, 24u8, 250u8, 0u8, 25u8,
+                    250u8, 0u8, 26u8, 250u8, 0u8, 27u8, 250u8, 0u8, 28u8, 250u8, 0u8, 29u8, 250u8,
+                    0u8, 30u8, 250u8, 0u8, 32u8, 250u8, 0u8, 34u8, 250u8, 0u8, 37u8, 250u8, 0u8,
+                    38u8, 250u8, 0u8, 42u8, 250u8, 0u8, 43u8, 250u8, 0u8, 44u8, 250u8, 0u8, 45u8,
+                    250u8, 0u8, 46u8, 250u8, 0u8, 47u8, 250u8, 0u8, 48u8, 250u8, 0u8, 49u8, 250u8,
+                    0u8, 50u8, 250u8, 0u8, 51u8, 250u8, 0u8, 52u8, 250u8, 0u8, 53u8, 250u8, 0u8,
+                    54u8, 250u8, 0u8, 55u8, 250u8, 0u8, 56u8, 250u8, 0u8, 57u8, 250u8, 0u8, 58u8,
+                    250u8, 0u8, 59u8, 250u8, 0u8, 60u8, 250u8, 0u8, 61u8, 250u8, 0u8, 62u8, 250u8,
+                    0u8, 63u8, 250u8, 0u8, 64u8, 250u8, 0u8, 65u8, 250u8, 0u8, 66u8, 250u8, 0u8,
+                    67u8, 250u8, 0u8, 68u8, 250u8, 0u8, 69u8, 250u8, 0u8, 70u8, 250u8, 0u8, 71u8,
+                    250u8, 0u8, 72u8, 250u8, 0u8, 73u8, 250u8, 0u8, 74u8, 250u8, 0u8, 75u8, 250u8,
+                    0u8, 76u8, 250u8, 0u8, 77u8, 250u8, 0u8, 78u8, 250u8, 0u8, 79u8, 250u8, 0u8,
+                    80u8, 250u8, 0u8, 81u8, 250u8, 0u8, 82u8, 250u8, 0u8, 83u8, 250u8, 0u8, 84u8,
+                    250u8, 0u8, 85u8, 250u8, 0u8, 86u8, 250u8, 0u8, 87u8, 250u8, 0u8, 88u8, 250u8,
+                    0u8, 89u8, 250u8, 0u8, 90u8, 250u8, 0u8, 91u8, 250u8, 0u8, 92u8, 250u8, 0u8,
+                    93u8, 250u8, 0u8, 94u8, 250u8, 0u8, 95u8, 250u8, 0u8, 96u8, 250u8, 0u8, 97u8,
+                    250u8, 0u8, 98u8, 250u8, 0u8, 99u8, 250u8, 0u8, 100u8, 250u8, 0u8, 101u8,
+                    250u8, 0u8, 102u8, 250u8, 0u8, 103u8, 250u8, 0u8, 104u8, 250u8, 0u8, 105u8,
+                    250u8, 0u8, 106u8, 250u8, 0u8, 107u8, 250u8, 0u8, 108u8, 250u8, 0u8, 109u8,
+                    250u8, 0u8, 112u8, 250u8, 0u8, 113u8, 250u8, 0u8, 114u8, 250u8, 0u8, 115u8,
+                    250u8, 0u8, 116u8, 250u8, 0u8, 117u8, 250u8, 0u8, 118u8, 250u8, 0u8, 119u8,
+                    250u8, 0u8, 120u8, 250u8, 0u8, 121u8, 250u8, 0u8, 122u8, 250u8, 0u8, 123u8,
+                    250u8, 0u8, 124u8, 250u8, 0u8, 125u8, 250u8, 0u8, 126u8, 250u8, 0u8, 127u8,
+                    250u8, 0u8, 128u8, 250u8, 0u8, 129u8, 250u8, 0u8, 130u8, 250u8, 0u8, 131u8,
+                    250u8, 0u8, 132u8, 250u8, 0u8, 133u8, 250u8, 0u8, 134u8, 250u8, 0u8, 135u8,
+                    250u8, 0u8, 136u8, 250u8, 0u8, 137u8, 250u8, 0u8, 138u8, 250u8, 0u8, 139u8,
+                    250u8, 0u8, 140u8, 250u8, 0u8, 141u8, 250u8, 0u8, 142u8, 250u8, 0u8, 143u8,
+                    250u8, 0u8, 144u8, 250u8, 0u8, 145u8, 250u8, 0u8, 146u8, 250u8, 0u8, 147u8,
+                    250u8, 0u8, 148u8, 250u8, 0u8, 149u8, 250u8, 0u8, 150u8, 250u8, 0u8, 151u8,
+                    250u8, 0u8, 152u8, 250u8, 0u8, 153u8, 250u8, 0u8, 154u8, 250u8, 0u8, 155u8,
+                    250u8, 0u8, 156u8, 250u8, 0u8, 157u8, 250u8, 0u8, 158u8, 250u8, 0u8, 159u8,
+                    250u8, 0u8, 160u8, 250u8, 0u8, 161u8, 250u8, 0u8, 162u8, 250u8, 0u8, 163u8,
+                    250u8, 0u8, 164u8, 250u8, 0u8, 165u8, 250u8, 0u8, 166u8, 250u8, 0u8, 167u8,
+                    250u8, 0u8, 168u8, 250u8, 0u8, 169u8, 250u8, 0u8, 170u8, 250u8, 0u8, 171u8,
+                    250u8, 0u8, 172u8, 250u8, 0u8, 173u8, 250u8, 0u8, 174u8, 250u8, 0u8, 175u8,
+                    250u8, 0u8, 176u8, 250u8, 0u8, 177u8, 250u8, 0u8, 178u8, 250u8, 0u8, 179u8,
+                    250u8, 0u8, 180u8, 250u8, 0u8, 181u8, 250u8, 0u8, 182u8, 250u8, 0u8, 183u8,
+                    250u8, 0u8, 184u8, 250u8, 0u8, 185u8, 250u8, 0u8, 186u8, 250u8, 0u8, 187u8,
+                    250u8, 0u8, 188u8, 250u8, 0u8, 189u8, 250u8, 0u8, 190u8, 250u8, 0u8, 191u8,
+                    250u8, 0u8, 192u8, 250u8, 0u8, 193u8, 250u8, 0u8, 194u8, 250u8, 0u8, 195u8,
+                    250u8, 0u8, 196u8, 250u8, 0u8, 197u8, 250u8, 0u8, 198u8, 250u8, 0u8, 199u8,
+                    250u8, 0u8, 200u8, 250u8, 0u8, 201u8, 250u8, 0u8, 202u8, 250u8, 0u8, 203u8,
+                    250u8, 0u8, 204u8, 250u8, 0u8, 205u8, 250u8, 0u8, 206u8, 250u8, 0u8, 207u8,
+                    250u8, 0u8, 208u8, 250u8, 0u8, 209u8, 250u8, 0u8, 210u8, 250u8, 0u8, 211u8,
+                    250u8, 0u8, 212u8, 250u8, 0u8, 213u8, 250u8, 0u8, 214u8, 250u8, 0u8, 215u8,
+                    250u8, 0u8, 216u8, 250u8, 0u8, 217u8, 250u8, 0u8, 0u8, 251u8, 0u8, 1u8, 251u8,
+                    0u8, 2u8, 251u8, 0u8, 3u8, 251u8, 0u8, 4u8, 251u8, 0u8, 6u8, 251u8, 0u8, 19u8,
+                    251u8, 0u8, 20u8, 251u8, 0u8, 21u8, 251u8, 0u8, 22u8, 251u8, 0u8, 23u8, 251u8,
+                    0u8, 32u8, 251u8, 0u8, 33u8, 251u8, 0u8, 34u8, 251u8, 0u8, 35u8, 251u8, 0u8,
+                    36u8, 251u8, 0u8, 37u8, 251u8, 0u8, 38u8, 251u8, 0u8, 39u8, 251u8, 0u8, 40u8,
+                    251u8, 0u8, 41u8, 251u8, 0u8, 43u8, 251u8, 0u8, 45u8, 251u8, 0u8, 47u8, 251u8,
+                    0u8, 48u8, 251u8, 0u8, 57u8, 251u8, 0u8, 73u8, 251u8, 0u8, 79u8, 251u8, 0u8,
+                    80u8, 251u8, 0u8, 81u8, 251u8, 0u8, 82u8, 251u8, 0u8, 83u8, 251u8, 0u8, 84u8,
+                    251u8, 0u8, 85u8, 251u8, 0u8, 86u8, 251u8, 0u8, 87u8, 251u8, 0u8, 88u8, 251u8,
+                    0u8, 89u8, 251u8, 0u8, 90u8, 251u8, 0u8, 91u8, 251u8, 0u8, 92u8, 251u8, 0u8,
+                    93u8, 251u8, 0u8, 94u8, 251u8, 0u8, 95u8, 251u8, 0u8, 96u8, 251u8, 0u8, 97u8,
+                    251u8, 0u8, 98u8, 251u8, 0u8, 99u8, 251u8, 0u8, 100u8, 251u8, 0u8, 101u8,
+                    251u8, 0u8, 102u8, 251u8, 0u8, 103u8, 251u8, 0u8, 104u8, 251u8, 0u8, 105u8,
+                    251u8, 0u8, 106u8, 251u8, 0u8, 107u8, 251u8, 0u8, 108u8, 251u8, 0u8, 109u8,
+                    251u8, 0u8, 110u8, 251u8, 0u8, 111u8, 251u8, 0u8, 112u8, 251u8, 0u8, 113u8,
+                    251u8, 0u8, 114u8, 251u8, 0u8, 115u8, 251u8, 0u8, 116u8, 251u8, 0u8, 117u8,
+                    251u8, 0u8, 118u8, 251u8, 0u8, 119u8, 251u8, 0u8, 120u8, 251u8, 0u8, 121u8,
+                    251u8, 0u8, 122u8, 251u8, 0u8, 123u8, 251u8, 0u8, 124u8, 251u8, 0u8, 125u8,
+                    251u8, 0u8, 126u8, 251u8, 0u8, 127u8, 251u8, 0u8, 128u8, 251u8, 0u8, 129u8,
+                    251u8, 0u8, 130u8, 251u8, 0u8, 131u8, 251u8, 0u8, 132u8, 251u8, 0u8, 133u8,
+                    251u8, 0u8, 134u8, 251u8, 0u8, 135u8, 251u8, 0u8, 136u8, 251u8, 0u8, 137u8,
+                    251u8, 0u8, 138u8, 251u8, 0u8, 139u8, 251u8, 0u8, 140u8, 251u8, 0u8, 141u8,
+                    251u8, 0u8, 142u8, 251u8, 0u8, 143u8, 251u8, 0u8, 144u8, 251u8, 0u8, 145u8,
+                    251u8, 0u8, 146u8, 251u8, 0u8, 147u8, 251u8, 0u8, 148u8, 251u8, 0u8, 149u8,
+                    251u8, 0u8, 150u8, 251u8, 0u8, 151u8, 251u8, 0u8, 152u8, 251u8, 0u8, 153u8,
+                    251u8, 0u8, 154u8, 251u8, 0u8, 155u8, 251u8, 0u8, 156u8, 251u8, 0u8, 157u8,
+                    251u8, 0u8, 158u8, 251u8, 0u8, 159u8, 251u8, 0u8, 160u8, 251u8, 0u8, 161u8,
+                    251u8, 0u8, 162u8, 251u8, 0u8, 163u8, 251u8, 0u8, 164u8, 251u8, 0u8, 165u8,
+                    251u8, 0u8, 166u8, 251u8, 0u8, 167u8, 251u8, 0u8, 168u8, 251u8, 0u8, 169u8,
+                    251u8, 0u8, 170u8, 251u8, 0u8, 171u8, 251u8, 0u8, 172u8, 251u8, 0u8, 173u8,
+                    251u8, 0u8, 174u8, 251u8, 0u8, 175u8, 251u8, 0u8, 176u8, 251u8, 0u8, 177u8,
+                    251u8, 0u8, 211u8, 251u8, 0u8, 212u8, 251u8, 0u8, 213u8, 251u8, 0u8, 214u8,
+                    251u8, 0u8, 215u8, 251u8, 0u8, 216u8, 251u8, 0u8, 217u8, 251u8, 0u8, 218u8,
+                    251u8, 0u8, 219u8, 251u8, 0u8, 220u8, 251u8, 0u8, 221u8, 251u8, 0u8, 222u8,
+                    251u8, 0u8, 223u8, 251u8, 0u8, 224u8, 251u8, 0u8, 225u8, 251u8, 0u8, 226u8,
+                    251u8, 0u8, 227u8, 251u8, 0u8, 228u8, 251u8, 0u8, 229u8, 251u8, 0u8, 230u8,
+                    251u8, 0u8, 231u8, 251u8, 0u8, 232u8, 251u8, 0u8, 233u8, 251u8, 0u8, 234u8,
+                    251u8, 0u8, 235u8, 251u8, 0u8, 236u8, 251u8, 0u8, 237u8, 251u8, 0u8, 238u8,
+                    251u8, 0u8, 239u8, 251u8, 0u8, 240u8, 251u8, 0u8, 241u8, 251u8, 0u8, 242u8,
+                    251u8, 0u8, 243u8, 251u8, 0u8, 244u8, 251u8, 0u8, 245u8, 251u8, 0u8, 246u8,
+                    251u8, 0u8, 247u8, 251u8, 0u8, 248u8, 251u8, 0u8, 249u8, 251u8, 0u8, 250u8,
+                    251u8, 0u8, 251u8, 251u8, 0u8, 252u8, 251u8, 0u8, 253u8, 251u8, 0u8, 254u8,
+                    251u8, 0u8, 255u8, 251u8, 0u8, 0u8, 252u8, 0u8, 1u8, 252u8, 0u8, 2u8, 252u8,
+                    0u8, 3u8, 252u8, 0u8, 4u8, 252u8, 0u8, 5u8, 252u8, 0u8, 6u8, 252u8, 0u8, 7u8,
+                    252u8, 0u8, 8u8, 252u8, 0u8, 9u8, 252u8, 0u8, 10u8, 252u8, 0u8, 11u8, 252u8,
+                    0u8, 12u8, 252u8, 0u8, 13u8, 252u8, 0u8, 14u8, 252u8, 0u8, 15u8, 252u8, 0u8,
+                    16u8, 252u8, 0u8, 17u8, 252u8, 0u8, 18u8, 252u8, 0u8, 19u8, 252u8, 0u8, 20u8,
+                    252u8, 0u8, 21u8, 252u8, 0u8, 22u8, 252u8, 0u8, 23u8, 252u8, 0u8, 24u8, 252u8,
+                    0u8, 25u8, 252u8, 0u8, 26u8, 252u8, 0u8, 27u8, 252u8, 0u8, 28u8, 252u8, 0u8,
+                    29u8, 252u8, 0u8, 30u8, 252u8, 0u8, 31u8, 252u8, 0u8, 32u8, 252u8, 0u8, 33u8,
+                    252u8, 0u8, 34u8, 252u8, 0u8, 35u8, 252u8, 0u8, 36u8, 252u8, 0u8, 37u8, 252u8,
+                    0u8, 38u8, 252u8, 0u8, 39u8, 252u8, 0u8, 40u8, 252u8, 0u8, 41u8, 252u8, 0u8,
+                    42u8, 252u8, 0u8, 43u8, 252u8, 0u8, 44u8, 252u8, 0u8, 45u8, 252u8, 0u8, 46u8,
+                    252u8, 0u8, 47u8, 252u8, 0u8, 48u8, 252u8, 0u8, 49u8, 252u8, 0u8, 50u8, 252u8,
+                    0u8, 51u8, 252u8, 0u8, 52u8, 252u8, 0u8, 53u8, 252u8, 0u8, 54u8, 252u8, 0u8,
+                    55u8, 252u8, 0u8, 56u8, 252u8, 0u8, 57u8, 252u8, 0u8, 58u8, 252u8, 0u8, 59u8,
+                    252u8, 0u8, 60u8, 252u8, 0u8, 61u8, 252u8, 0u8, 62u8, 252u8, 0u8, 63u8, 252u8,
+                    0u8, 64u8, 252u8, 0u8, 65u8, 252u8, 0u8, 66u8, 252u8, 0u8, 67u8, 252u8, 0u8,
+                    68u8, 252u8, 0u8, 69u8, 252u8, 0u8, 70u8, 252u8, 0u8, 71u8, 252u8, 0u8, 72u8,
+                    252u8, 0u8, 73u8, 252u8, 0u8, 74u8, 252u8, 0u8, 75u8, 252u8, 0u8, 76u8, 252u8,
+                    0u8, 77u8, 252u8, 0u8, 78u8, 252u8, 0u8, 79u8, 252u8, 0u8, 80u8, 252u8, 0u8,
+                    81u8, 252u8, 0u8, 82u8, 252u8, 0u8, 83u8, 252u8, 0u8, 84u8, 252u8, 0u8, 85u8,
+                    252u8, 0u8, 86u8, 252u8, 0u8, 87u8, 252u8, 0u8, 88u8, 252u8, 0u8, 89u8, 252u8,
+                    0u8, 90u8, 252u8, 0u8, 91u8, 252u8, 0u8, 92u8, 252u8, 0u8, 93u8, 252u8, 0u8,
+                    94u8, 252u8, 0u8, 95u8, 252u8, 0u8, 96u8, 252u8, 0u8, 97u8, 252u8, 0u8, 98u8,
+                    252u8, 0u8, 99u8, 252u8, 0u8, 100u8, 252u8, 0u8, 101u8, 252u8, 0u8, 102u8,
+                    252u8, 0u8, 103u8, 252u8, 0u8, 104u8, 252u8, 0u8, 105u8, 252u8, 0u8, 106u8,
+                    252u8, 0u8, 107u8, 252u8, 0u8, 108u8, 252u8, 0u8, 109u8, 252u8, 0u8, 110u8,
+                    252u8, 0u8, 111u8, 252u8, 0u8, 112u8, 252u8, 0u8, 113u8, 252u8, 0u8, 114u8,
+                    252u8, 0u8, 115u8, 252u8, 0u8, 116u8, 252u8, 0u8, 117u8, 252u8, 0u8, 118u8,
+                    252u8, 0u8, 119u8, 252u8, 0u8, 120u8, 252u8, 0u8, 121u8, 252u8, 0u8, 122u8,
+                    252u8, 0u8, 123u8, 252u8, 0u8, 124u8, 252u8, 0u8, 125u8, 252u8, 0u8, 126u8,
+                    252u8, 0u8, 127u8, 252u8, 0u8, 128u8, 252u8, 0u8, 129u8, 252u8, 0u8, 130u8,
+                    252u8, 0u8, 131u8, 252u8, 0u8, 132u8, 252u8, 0u8, 133u8, 252u8, 0u8, 134u8,
+                    252u8, 0u8, 135u8, 252u8, 0u8, 136u8, 252u8, 0u8, 137u8, 252u8, 0u8, 138u8,
+                    252u8, 0u8, 139u8, 252u8, 0u8, 140u8, 252u8, 0u8, 141u8, 252u8, 0u8, 142u8,
+                    252u8, 0u8, 143u8, 252u8, 0u8, 144u8, 252u8, 0u8, 145u8, 252u8, 0u8, 146u8,
+                    252u8, 0u8, 147u8, 252u8, 0u8, 148u8, 252u8, 0u8, 149u8, 252u8, 0u8, 150u8,
+                    252u8, 0u8, 151u8, 252u8, 0u8, 152u8, 252u8, 0u8, 153u8, 252u8, 0u8, 154u8,
+                    252u8, 0u8, 155u8, 252u8, 0u8, 156u8, 252u8, 0u8, 157u8, 252u8, 0u8, 158u8,
+                    252u8, 0u8, 159u8, 252u8, 0u8, 160u8, 252u8, 0u8, 161u8, 252u8, 0u8, 162u8,
+                    252u8, 0u8, 163u8, 252u8, 0u8, 164u8, 252u8, 0u8, 165u8, 252u8, 0u8, 166u8,
+                    252u8, 0u8, 167u8, 252u8, 0u8, 168u8, 252u8, 0u8, 169u8, 252u8, 0u8, 170u8,
+                    252u8, 0u8, 171u8, 252u8, 0u8, 172u8, 252u8, 0u8, 173u8, 252u8, 0u8, 174u8,
+                    252u8, 0u8, 175u8, 252u8, 0u8, 176u8, 252u8, 0u8, 177u8, 252u8, 0u8, 178u8,
+                    252u8, 0u8, 179u8, 252u8, 0u8, 180u8, 252u8, 0u8, 181u8, 252u8, 0u8, 182u8,
+                    252u8, 0u8, 183u8, 252u8, 0u8, 184u8, 252u8, 0u8, 185u8, 252u8, 0u8, 186u8,
+                    252u8, 0u8, 187u8, 252u8, 0u8, 188u8, 252u8, 0u8, 189u8, 252u8, 0u8, 190u8,
+                    252u8, 0u8, 191u8, 252u8, 0u8, 192u8, 252u8, 0u8, 193u8, 252u8, 0u8, 194u8,
+                    252u8, 0u8, 195u8, 252u8, 0u8, 196u8, 252u8, 0u8, 197u8, 252u8, 0u8, 198u8,
+                    252u8, 0u8, 199u8, 252u8, 0u8, 200u8, 252u8, 0u8, 201u8, 252u8, 0u8, 202u8,
+                    252u8, 0u8, 203u8, 252u8, 0u8, 204u8, 252u8, 0u8, 205u8, 252u8, 0u8, 206u8,
+                    252u8, 0u8, 207u8, 252u8, 0u8, 208u8, 252u8, 0u8, 209u8, 252u8, 0u8, 210u8,
+                    252u8, 0u8, 211u8, 252u8, 0u8, 212u8, 252u8, 0u8, 213u8, 252u8, 0u8, 214u8,
+                    252u8, 0u8, 215u8, 252u8, 0u8, 216u8, 252u8, 0u8, 217u8, 252u8, 0u8, 218u8,
+                    252u8, 0u8, 219u8, 252u8, 0u8, 220u8, 252u8, 0u8, 221u8, 252u8, 0u8, 222u8,
+                    252u8, 0u8, 223u8, 252u8, 0u8, 224u8, 252u8, 0u8, 225u8, 252u8, 0u8, 226u8,
+                    252u8, 0u8, 227u8, 252u8, 0u8, 228u8, 252u8, 0u8, 229u8, 252u8, 0u8, 230u8,
+                    252u8, 0u8, 231u8, 252u8, 0u8, 232u8, 252u8, 0u8, 233u8, 252u8, 0u8, 234u8,
+                    252u8, 0u8, 235u8, 252u8, 0u8, 236u8, 252u8, 0u8, 237u8, 252u8, 0u8, 238u8,
+                    252u8, 0u8, 239u8, 252u8, 0u8, 240u8, 252u8, 0u8, 241u8, 252u8, 0u8, 242u8,
+                    252u8, 0u8, 243u8, 252u8, 0u8, 244u8, 252u8, 0u8, 245u8, 252u8, 0u8, 246u8,
+                    252u8, 0u8, 247u8, 252u8, 0u8, 248u8, 252u8, 0u8, 249u8, 252u8, 0u8, 250u8,
+                    252u8, 0u8, 251u8, 252u8, 0u8, 252u8, 252u8, 0u8, 253u8, 252u8, 0u8, 254u8,
+                    252u8, 0u8, 255u8, 252u8, 0u8, 0u8, 253u8, 0u8, 1u8, 253u8, 0u8, 2u8, 253u8,
+                    0u8, 3u8, 253u8, 0u8, 4u8, 253u8, 0u8, 5u8, 253u8, 0u8, 6u8, 253u8, 0u8, 7u8,
+                    253u8, 0u8, 8u8, 253u8, 0u8, 9u8, 253u8, 0u8, 10u8, 253u8, 0u8, 11u8, 253u8,
+                    0u8, 12u8, 253u8, 0u8, 13u8, 253u8, 0u8, 14u8, 253u8, 0u8, 15u8, 253u8, 0u8,
+                    16u8, 253u8, 0u8, 17u8, 253u8, 0u8, 18u8, 253u8, 0u8, 19u8, 253u8, 0u8, 20u8,
+                    253u8, 0u8, 21u8, 253u8, 0u8, 22u8, 253u8, 0u8, 23u8, 253u8, 0u8, 24u8, 253u8,
+                    0u8, 25u8, 253u8, 0u8, 26u8, 253u8, 0u8, 27u8, 253u8, 0u8, 28u8, 253u8, 0u8,
+                    29u8, 253u8, 0u8, 30u8, 253u8, 0u8, 31u8, 253u8, 0u8, 32u8, 253u8, 0u8, 33u8,
+                    253u8, 0u8, 34u8, 253u8, 0u8, 35u8, 253u8, 0u8, 36u8, 253u8, 0u8, 37u8, 253u8,
+                    0u8, 38u8, 253u8, 0u8, 39u8, 253u8, 0u8, 40u8, 253u8, 0u8, 41u8, 253u8, 0u8,
+                    42u8, 253u8, 0u8, 43u8, 253u8, 0u8, 44u8, 253u8, 0u8, 45u8, 253u8, 0u8, 46u8,
+                    253u8, 0u8, 47u8, 253u8, 0u8, 48u8, 253u8, 0u8, 49u8, 253u8, 0u8, 50u8, 253u8,
+                    0u8, 51u8, 253u8, 0u8, 52u8, 253u8, 0u8, 53u8, 253u8, 0u8, 54u8, 253u8, 0u8,
+                    55u8, 253u8, 0u8, 56u8, 253u8, 0u8, 57u8, 253u8, 0u8, 58u8, 253u8, 0u8, 59u8,
+                    253u8, 0u8, 60u8, 253u8, 0u8, 61u8, 253u8, 0u8, 62u8, 253u8, 0u8, 63u8, 253u8,
+                    0u8, 80u8, 253u8, 0u8, 81u8, 253u8, 0u8, 82u8, 253u8, 0u8, 83u8, 253u8, 0u8,
+                    84u8, 253u8, 0u8, 85u8, 253u8, 0u8, 86u8, 253u8, 0u8, 87u8, 253u8, 0u8, 88u8,
+                    253u8, 0u8, 89u8, 253u8, 0u8, 90u8, 253u8, 0u8, 91u8, 253u8, 0u8, 92u8, 253u8,
+                    0u8, 93u8, 253u8, 0u8, 94u8, 253u8, 0u8, 95u8, 253u8, 0u8, 96u8, 253u8, 0u8,
+                    97u8, 253u8, 0u8, 98u8, 253u8, 0u8, 99u8, 253u8, 0u8, 100u8, 253u8, 0u8, 101u8,
+                    253u8, 0u8, 102u8, 253u8, 0u8, 103u8, 253u8, 0u8, 104u8, 253u8, 0u8, 105u8,
+                    253u8, 0u8, 106u8, 253u8, 0u8, 107u8, 253u8, 0u8, 108u8, 253u8, 0u8, 109u8,
+                    253u8, 0u8, 110u8, 253u8, 0u8, 111u8, 253u8, 0u8, 112u8, 253u8, 0u8, 113u8,
+                    253u8, 0u8, 114u8, 253u8, 0u8, 115u8, 253u8, 0u8, 116u8, 253u8, 0u8, 117u8,
+                    253u8, 0u8, 118u8, 253u8, 0u8, 119u8, 253u8, 0u8, 120u8, 253u8, 0u8, 121u8,
+                    253u8, 0u8, 122u8, 253u8, 0u8, 123u8, 253u8, 0u8, 124u8, 253u8, 0u8, 125u8,
+                    253u8, 0u8, 126u8, 253u8, 0u8, 127u8, 253u8, 0u8, 128u8, 253u8, 0u8, 129u8,
+                    253u8, 0u8, 130u8, 253u8, 0u8, 131u8, 253u8, 0u8, 132u8, 253u8, 0u8, 133u8,
+                    253u8, 0u8, 134u8, 253u8, 0u8, 135u8, 253u8, 0u8, 136u8, 253u8, 0u8, 137u8,
+                    253u8, 0u8, 138u8, 253u8, 0u8, 139u8, 253u8, 0u8, 140u8, 253u8, 0u8, 141u8,
+                    253u8, 0u8, 142u8, 253u8, 0u8, 143u8, 253u8, 0u8, 146u8, 253u8, 0u8, 147u8,
+                    253u8, 0u8, 148u8, 253u8, 0u8, 149u8, 253u8, 0u8, 150u8, 253u8, 0u8, 151u8,
+                    253u8, 0u8, 152u8, 253u8, 0u8, 153u8, 253u8, 0u8, 154u8, 253u8, 0u8, 155u8,
+                    253u8, 0u8, 156u8, 253u8, 0u8, 157u8, 253u8, 0u8, 158u8, 253u8, 0u8, 159u8,
+                    253u8, 0u8, 160u8, 253u8, 0u8, 161u8, 253u8, 0u8, 162u8, 253u8, 0u8, 163u8,
+                    253u8, 0u8, 164u8, 253u8, 0u8, 165u8, 253u8, 0u8, 166u8, 253u8, 0u8, 167u8,
+                    253u8, 0u8, 168u8, 253u8, 0u8, 169u8, 253u8, 0u8, 170u8, 253u8, 0u8, 171u8,
+                    253u8, 0u8, 172u8, 253u8, 0u8, 173u8, 253u8, 0u8, 174u8, 253u8, 0u8, 175u8,
+                    253u8, 0u8, 176u8, 253u8, 0u8, 177u8, 253u8, 0u8, 178u8, 253u8, 0u8, 179u8,
+                    253u8, 0u8, 180u8, 253u8, 0u8, 181u8, 253u8, 0u8, 182u8, 253u8, 0u8, 183u8,
+                    253u8, 0u8, 184u8, 253u8, 0u8, 185u8, 253u8, 0u8, 186u8, 253u8, 0u8, 187u8,
+                    253u8, 0u8, 188u8, 253u8, 0u8, 189u8, 253u8, 0u8, 190u8, 253u8, 0u8, 191u8,
+                    253u8, 0u8, 192u8, 253u8, 0u8, 193u8, 253u8, 0u8, 194u8, 253u8, 0u8, 195u8,
+                    253u8, 0u8, 196u8, 253u8, 0u8, 197u8, 253u8, 0u8, 198u8, 253u8, 0u8, 199u8,
+                    253u8, 0u8, 240u8, 253u8, 0u8, 241u8, 253u8, 0u8, 242u8, 253u8, 0u8, 243u8,
+                    253u8, 0u8, 244u8, 253u8, 0u8, 245u8, 253u8, 0u8, 246u8, 253u8, 0u8, 247u8,
+                    253u8, 0u8, 248u8, 253u8, 0u8, 249u8, 253u8, 0u8, 250u8, 253u8, 0u8, 251u8,
+                    253u8, 0u8, 252u8, 253u8, 0u8, 25u8, 254u8, 0u8, 48u8, 254u8, 0u8, 49u8, 254u8,
+                    0u8, 52u8, 254u8, 0u8, 53u8, 254u8, 0u8, 54u8, 254u8, 0u8, 55u8, 254u8, 0u8,
+                    56u8, 254u8, 0u8, 57u8, 254u8, 0u8, 58u8, 254u8, 0u8, 73u8, 254u8, 0u8, 74u8,
+                    254u8, 0u8, 75u8, 254u8, 0u8, 76u8, 254u8, 0u8, 77u8, 254u8, 0u8, 78u8, 254u8,
+                    0u8, 79u8, 254u8, 0u8, 88u8, 254u8, 0u8, 104u8, 254u8, 0u8, 128u8, 254u8, 0u8,
+                    129u8, 254u8, 0u8, 130u8, 254u8, 0u8, 131u8, 254u8, 0u8, 132u8, 254u8, 0u8,
+                    133u8, 254u8, 0u8, 134u8, 254u8, 0u8, 135u8, 254u8, 0u8, 136u8, 254u8, 0u8,
+                    137u8, 254u8, 0u8, 138u8, 254u8, 0u8, 139u8, 254u8, 0u8, 140u8, 254u8, 0u8,
+                    141u8, 254u8, 0u8, 142u8, 254u8, 0u8, 143u8, 254u8, 0u8, 144u8, 254u8, 0u8,
+                    145u8, 254u8, 0u8, 146u8, 254u8, 0u8, 147u8, 254u8, 0u8, 148u8, 254u8, 0u8,
+                    149u8, 254u8, 0u8, 150u8, 254u8, 0u8, 151u8, 254u8, 0u8, 152u8, 254u8, 0u8,
+                    153u8, 254u8, 0u8, 154u8, 254u8, 0u8, 155u8, 254u8, 0u8, 156u8, 254u8, 0u8,
+                    157u8, 254u8, 0u8, 158u8, 254u8, 0u8, 159u8, 254u8, 0u8, 160u8, 254u8, 0u8,
+                    161u8, 254u8, 0u8, 162u8, 254u8, 0u8, 163u8, 254u8, 0u8, 164u8, 254u8, 0u8,
+                    165u8, 254u8, 0u8, 166u8, 254u8, 0u8, 167u8, 254u8, 0u8, 168u8, 254u8, 0u8,
+                    169u8, 254u8, 0u8, 170u8, 254u8, 0u8, 171u8, 254u8, 0u8, 172u8, 254u8, 0u8,
+                    173u8, 254u8, 0u8, 174u8, 254u8, 0u8, 175u8, 254u8, 0u8, 176u8, 254u8, 0u8,
+                    177u8, 254u8, 0u8, 178u8, 254u8, 0u8, 179u8, 254u8, 0u8, 180u8, 254u8, 0u8,
+                    181u8, 254u8, 0u8, 182u8, 254u8, 0u8, 183u8, 254u8, 0u8, 184u8, 254u8, 0u8,
+                    185u8, 254u8, 0u8, 186u8, 254u8, 0u8, 187u8, 254u8, 0u8, 188u8, 254u8, 0u8,
+                    189u8, 254u8, 0u8, 190u8, 254u8, 0u8, 191u8, 254u8, 0u8, 192u8, 254u8, 0u8,
+                    193u8, 254u8, 0u8, 194u8, 254u8, 0u8, 195u8, 254u8, 0u8, 196u8, 254u8, 0u8,
+                    197u8, 254u8, 0u8, 198u8, 254u8, 0u8, 199u8, 254u8, 0u8, 200u8, 254u8, 0u8,
+                    201u8, 254u8, 0u8, 202u8, 254u8, 0u8, 203u8, 254u8, 0u8, 204u8, 254u8, 0u8,
+                    205u8, 254u8, 0u8, 206u8, 254u8, 0u8, 207u8, 254u8, 0u8, 208u8, 254u8, 0u8,
+                    209u8, 254u8, 0u8, 210u8, 254u8, 0u8, 211u8, 254u8, 0u8, 212u8, 254u8, 0u8,
+                    213u8, 254u8, 0u8, 214u8, 254u8, 0u8, 215u8, 254u8, 0u8, 216u8, 254u8, 0u8,
+                    217u8, 254u8, 0u8, 218u8, 254u8, 0u8, 219u8, 254u8, 0u8, 220u8, 254u8, 0u8,
+                    221u8, 254u8, 0u8, 222u8, 254u8, 0u8, 223u8, 254u8, 0u8, 224u8, 254u8, 0u8,
+                    225u8, 254u8, 0u8, 226u8, 254u8, 0u8, 227u8, 254u8, 0u8, 228u8, 254u8, 0u8,
+                    229u8, 254u8, 0u8, 230u8, 254u8, 0u8, 231u8, 254u8, 0u8, 232u8, 254u8, 0u8,
+                    233u8, 254u8, 0u8, 234u8, 254u8, 0u8, 235u8, 254u8, 0u8, 236u8, 254u8, 0u8,
+                    237u8, 254u8, 0u8, 238u8, 254u8, 0u8, 239u8, 254u8, 0u8, 240u8, 254u8, 0u8,
+                    241u8, 254u8, 0u8, 242u8, 254u8, 0u8, 243u8, 254u8, 0u8, 244u8, 254u8, 0u8,
+                    245u8, 254u8, 0u8, 246u8, 254u8, 0u8, 247u8, 254u8, 0u8, 248u8, 254u8, 0u8,
+                    249u8, 254u8, 0u8, 250u8, 254u8, 0u8, 251u8, 254u8, 0u8, 252u8, 254u8, 0u8,
+                    1u8, 255u8, 0u8, 2u8, 255u8, 0u8, 7u8, 255u8, 0u8, 13u8, 255u8, 0u8, 26u8,
+                    255u8, 0u8, 33u8, 255u8, 0u8, 34u8, 255u8, 0u8, 35u8, 255u8, 0u8, 37u8, 255u8,
+                    0u8, 40u8, 255u8, 0u8, 41u8, 255u8, 0u8, 42u8, 255u8, 0u8, 43u8, 255u8, 0u8,
+                    45u8, 255u8, 0u8, 46u8, 255u8, 0u8, 47u8, 255u8, 0u8, 48u8, 255u8, 0u8, 51u8,
+                    255u8, 0u8, 52u8, 255u8, 0u8, 56u8, 255u8, 0u8, 57u8, 255u8, 0u8, 58u8, 255u8,
+                    0u8, 59u8, 255u8, 0u8, 60u8, 255u8, 0u8, 61u8, 255u8, 0u8, 62u8, 255u8, 0u8,
+                    64u8, 255u8, 0u8, 65u8, 255u8, 0u8, 67u8, 255u8, 0u8, 69u8, 255u8, 0u8, 71u8,
+                    255u8, 0u8, 72u8, 255u8, 0u8, 73u8, 255u8, 0u8, 74u8, 255u8, 0u8, 76u8, 255u8,
+                    0u8, 79u8, 255u8, 0u8, 80u8, 255u8, 0u8, 83u8, 255u8, 0u8, 86u8, 255u8, 0u8,
+                    88u8, 255u8, 0u8, 89u8, 255u8, 0u8, 92u8, 255u8, 0u8, 94u8, 255u8, 0u8, 101u8,
+                    255u8, 0u8, 227u8, 255u8, 0u8, 232u8, 255u8, 0u8, 237u8, 255u8, 0u8, 1u8, 1u8,
+                    1u8, 142u8, 1u8, 1u8, 150u8, 1u8, 1u8, 151u8, 1u8, 1u8, 152u8, 1u8, 1u8, 153u8,
+                    1u8, 1u8, 160u8, 1u8, 1u8, 130u8, 2u8, 1u8, 133u8, 2u8, 1u8, 134u8, 2u8, 1u8,
+                    135u8, 2u8, 1u8, 138u8, 2u8, 1u8, 141u8, 2u8, 1u8, 144u8, 2u8, 1u8, 146u8, 2u8,
+                    1u8, 148u8, 2u8, 1u8, 149u8, 2u8, 1u8, 150u8, 2u8, 1u8, 151u8, 2u8, 1u8, 155u8,
+                    2u8, 1u8, 160u8, 2u8, 1u8, 161u8, 2u8, 1u8, 162u8, 2u8, 1u8, 163u8, 2u8, 1u8,
+                    165u8, 2u8, 1u8, 171u8, 2u8, 1u8, 173u8, 2u8, 1u8, 176u8, 2u8, 1u8, 177u8, 2u8,
+                    1u8, 178u8, 2u8, 1u8, 179u8, 2u8, 1u8, 180u8, 2u8, 1u8, 181u8, 2u8, 1u8, 182u8,
+                    2u8, 1u8, 184u8, 2u8, 1u8, 207u8, 2u8, 1u8, 225u8, 2u8, 1u8, 228u8, 2u8, 1u8,
+                    232u8, 2u8, 1u8, 242u8, 2u8, 1u8, 245u8, 2u8, 1u8, 1u8, 3u8, 1u8, 2u8, 3u8,
+                    1u8, 9u8, 3u8, 1u8, 17u8, 3u8, 1u8, 18u8, 3u8, 1u8, 21u8, 3u8, 1u8, 23u8, 3u8,
+                    1u8, 26u8, 3u8, 1u8, 31u8, 3u8, 1u8, 32u8, 3u8, 1u8, 34u8, 3u8, 1u8, 209u8,
+                    3u8, 1u8, 211u8, 3u8, 1u8, 1u8, 4u8, 1u8, 4u8, 4u8, 1u8, 17u8, 4u8, 1u8, 21u8,
+                    4u8, 1u8, 27u8, 4u8, 1u8, 31u8, 4u8, 1u8, 32u8, 4u8, 1u8, 35u8, 4u8, 1u8, 37u8,
+                    4u8, 1u8, 41u8, 4u8, 1u8, 42u8, 4u8, 1u8, 44u8, 4u8, 1u8, 61u8, 4u8, 1u8, 63u8,
+                    4u8, 1u8, 66u8, 4u8, 1u8, 67u8, 4u8, 1u8, 72u8, 4u8, 1u8, 75u8, 4u8, 1u8, 77u8,
+                    4u8, 1u8, 160u8, 4u8, 1u8, 176u8, 4u8, 1u8, 180u8, 4u8, 1u8, 188u8, 4u8, 1u8,
+                    194u8, 4u8, 1u8, 195u8, 4u8, 1u8, 196u8, 4u8, 1u8, 205u8, 4u8, 1u8, 206u8, 4u8,
+                    1u8, 208u8, 4u8, 1u8, 209u8, 4u8, 1u8, 210u8, 4u8, 1u8, 216u8, 4u8, 1u8, 219u8,
+                    4u8, 1u8, 234u8, 4u8, 1u8, 235u8, 4u8, 1u8, 246u8, 4u8, 1u8, 249u8, 4u8, 1u8,
+                    19u8, 5u8, 1u8, 22u8, 5u8, 1u8, 24u8, 5u8, 1u8, 28u8, 5u8, 1u8, 29u8, 5u8, 1u8,
+                    37u8, 5u8, 1u8, 38u8, 5u8, 1u8, 39u8, 5u8, 1u8, 58u8, 10u8, 1u8, 80u8, 10u8,
+                    1u8, 87u8, 10u8, 1u8, 250u8, 12u8, 1u8, 252u8, 12u8, 1u8, 187u8, 16u8, 1u8,
+                    199u8, 17u8, 1u8, 202u8, 17u8, 1u8, 203u8, 17u8, 1u8, 219u8, 17u8, 1u8, 220u8,
+                    17u8, 1u8, 222u8, 17u8, 1u8, 0u8, 19u8, 1u8, 19u8, 20u8, 1u8, 25u8, 20u8, 1u8,
+                    36u8, 20u8, 1u8, 42u8, 20u8, 1u8, 45u8, 20u8, 1u8, 47u8, 20u8, 1u8, 76u8, 20u8,
+                    1u8, 146u8, 20u8, 1u8, 148u8, 20u8, 1u8, 150u8, 20u8, 1u8, 152u8, 20u8, 1u8,
+                    153u8, 20u8, 1u8, 155u8, 20u8, 1u8, 157u8, 20u8, 1u8, 158u8, 20u8, 1u8, 159u8,
+                    20u8, 1u8, 160u8, 20u8, 1u8, 161u8, 20u8, 1u8, 162u8, 20u8, 1u8, 163u8, 20u8,
+                    1u8, 167u8, 20u8, 1u8, 168u8, 20u8, 1u8, 169u8, 20u8, 1u8, 170u8, 20u8, 1u8,
+                    171u8, 20u8, 1u8, 173u8, 20u8, 1u8, 174u8, 20u8, 1u8, 176u8, 20u8, 1u8, 177u8,
+                    20u8, 1u8, 185u8, 20u8, 1u8, 188u8, 20u8, 1u8, 189u8, 20u8, 1u8, 190u8, 20u8,
+                    1u8, 191u8, 20u8, 1u8, 193u8, 20u8, 1u8, 194u8, 20u8, 1u8, 195u8, 20u8, 1u8,
+                    196u8, 20u8, 1u8, 197u8, 20u8, 1u8, 208u8, 20u8, 1u8, 209u8, 20u8, 1u8, 210u8,
+                    20u8, 1u8, 214u8, 20u8, 1u8, 216u8, 21u8, 1u8, 217u8, 21u8, 1u8, 218u8, 21u8,
+                    1u8, 219u8, 21u8, 1u8, 220u8, 21u8, 1u8, 221u8, 21u8, 1u8, 66u8, 22u8, 1u8,
+                    0u8, 23u8, 1u8, 6u8, 23u8, 1u8, 10u8, 23u8, 1u8, 14u8, 23u8, 1u8, 15u8, 23u8,
+                    1u8, 160u8, 24u8, 1u8, 162u8, 24u8, 1u8, 163u8, 24u8, 1u8, 164u8, 24u8, 1u8,
+                    166u8, 24u8, 1u8, 168u8, 24u8, 1u8, 169u8, 24u8, 1u8, 172u8, 24u8, 1u8, 174u8,
+                    24u8, 1u8, 175u8, 24u8, 1u8, 178u8, 24u8, 1u8, 181u8, 24u8, 1u8, 183u8, 24u8,
+                    1u8, 184u8, 24u8, 1u8, 187u8, 24u8, 1u8, 188u8, 24u8, 1u8, 192u8, 24u8, 1u8,
+                    193u8, 24u8, 1u8, 194u8, 24u8, 1u8, 195u8, 24u8, 1u8, 196u8, 24u8, 1u8, 198u8,
+                    24u8, 1u8, 200u8, 24u8, 1u8, 202u8, 24u8, 1u8, 204u8, 24u8, 1u8, 206u8, 24u8,
+                    1u8, 213u8, 24u8, 1u8, 214u8, 24u8, 1u8, 215u8, 24u8, 1u8, 216u8, 24u8, 1u8,
+                    220u8, 24u8, 1u8, 224u8, 24u8, 1u8, 227u8, 24u8, 1u8, 228u8, 24u8, 1u8, 229u8,
+                    24u8, 1u8, 230u8, 24u8, 1u8, 233u8, 24u8, 1u8, 236u8, 24u8, 1u8, 239u8, 24u8,
+                    1u8, 242u8, 24u8, 1u8, 230u8, 26u8, 1u8, 231u8, 26u8, 1u8, 232u8, 26u8, 1u8,
+                    233u8, 26u8, 1u8, 234u8, 26u8, 1u8, 236u8, 26u8, 1u8, 237u8, 26u8, 1u8, 238u8,
+                    26u8, 1u8, 244u8, 26u8, 1u8, 245u8, 26u8, 1u8, 246u8, 26u8, 1u8, 247u8, 26u8,
+                    1u8, 248u8, 26u8, 1u8, 66u8, 28u8, 1u8, 178u8, 28u8, 1u8, 56u8, 32u8, 1u8,
+                    249u8, 50u8, 1u8, 7u8, 111u8, 1u8, 8u8, 111u8, 1u8, 10u8, 111u8, 1u8, 22u8,
+                    111u8, 1u8, 26u8, 111u8, 1u8, 28u8, 111u8, 1u8, 38u8, 111u8, 1u8, 40u8, 111u8,
+                    1u8, 45u8, 111u8, 1u8, 53u8, 111u8, 1u8, 58u8, 111u8, 1u8, 59u8, 111u8, 1u8,
+                    61u8, 111u8, 1u8, 63u8, 111u8, 1u8, 64u8, 111u8, 1u8, 66u8, 111u8, 1u8, 67u8,
+                    111u8, 1u8, 81u8, 111u8, 1u8, 82u8, 111u8, 1u8, 20u8, 209u8, 1u8, 109u8, 209u8,
+                    1u8, 2u8, 210u8, 1u8, 6u8, 210u8, 1u8, 11u8, 210u8, 1u8, 13u8, 210u8, 1u8,
+                    15u8, 210u8, 1u8, 18u8, 210u8, 1u8, 19u8, 210u8, 1u8, 20u8, 210u8, 1u8, 21u8,
+                    210u8, 1u8, 22u8, 210u8, 1u8, 23u8, 210u8, 1u8, 26u8, 210u8, 1u8, 27u8, 210u8,
+                    1u8, 28u8, 210u8, 1u8, 33u8, 210u8, 1u8, 34u8, 210u8, 1u8, 42u8, 210u8, 1u8,
+                    43u8, 210u8, 1u8, 48u8, 210u8, 1u8, 54u8, 210u8, 1u8, 55u8, 210u8, 1u8, 56u8,
+                    210u8, 1u8, 57u8, 210u8, 1u8, 58u8, 210u8, 1u8, 59u8, 210u8, 1u8, 63u8, 210u8,
+                    1u8, 69u8, 210u8, 1u8, 0u8, 212u8, 1u8, 1u8, 212u8, 1u8, 2u8, 212u8, 1u8, 3u8,
+                    212u8, 1u8, 4u8, 212u8, 1u8, 5u8, 212u8, 1u8, 6u8, 212u8, 1u8, 7u8, 212u8, 1u8,
+                    8u8, 212u8, 1u8, 9u8, 212u8, 1u8, 10u8, 212u8, 1u8, 11u8, 212u8, 1u8, 12u8,
+                    212u8, 1u8, 13u8, 212u8, 1u8, 14u8, 212u8, 1u8, 15u8, 212u8, 1u8, 16u8, 212u8,
+                    1u8, 17u8, 212u8, 1u8, 18u8, 212u8, 1u8, 19u8, 212u8, 1u8, 20u8, 212u8, 1u8,
+                    21u8, 212u8, 1u8, 22u8, 212u8, 1u8, 23u8, 212u8, 1u8, 24u8, 212u8, 1u8, 25u8,
+                    212u8, 1u8, 26u8, 212u8, 1u8, 27u8, 212u8, 1u8, 28u8, 212u8, 1u8, 29u8, 212u8,
+                    1u8, 30u8, 212u8, 1u8, 31u8, 212u8, 1u8, 32u8, 212u8, 1u8, 33u8, 212u8, 1u8,
+                    34u8, 212u8, 1u8, 35u8, 212u8, 1u8, 36u8, 212u8, 1u8, 37u8, 212u8, 1u8, 38u8,
+                    212u8, 1u8, 39u8, 212u8, 1u8, 40u8, 212u8, 1u8, 41u8, 212u8, 1u8, 42u8, 212u8,
+                    1u8, 43u8, 212u8, 1u8, 44u8, 212u8, 1u8, 45u8, 212u8, 1u8, 46u8, 212u8, 1u8,
+                    47u8, 212u8, 1u8, 48u8, 212u8, 1u8, 49u8, 212u8, 1u8, 50u8, 212u8, 1u8, 51u8,
+                    212u8, 1u8, 52u8, 212u8, 1u8, 53u8, 212u8, 1u8, 54u8, 212u8, 1u8, 55u8, 212u8,
+                    1u8, 56u8, 212u8, 1u8, 57u8, 212u8, 1u8, 58u8, 212u8, 1u8, 59u8, 212u8, 1u8,
+                    60u8, 212u8, 1u8, 61u8, 212u8, 1u8, 62u8, 212u8, 1u8, 63u8, 212u8, 1u8, 64u8,
+                    212u8, 1u8, 65u8, 212u8, 1u8, 66u8, 212u8, 1u8, 67u8, 212u8, 1u8, 68u8, 212u8,
+                    1u8, 69u8, 212u8, 1u8, 70u8, 212u8, 1u8, 71u8, 212u8, 1u8, 72u8, 212u8, 1u8,
+                    73u8, 212u8, 1u8, 74u8, 212u8, 1u8, 75u8, 212u8, 1u8, 76u8, 212u8, 1u8, 77u8,
+                    212u8, 1u8, 78u8, 212u8, 1u8, 79u8, 212u8, 1u8, 80u8, 212u8, 1u8, 81u8, 212u8,
+                    1u8, 82u8, 212u8, 1u8, 83u8, 212u8, 1u8, 84u8, 212u8, 1u8, 86u8, 212u8, 1u8,
+                    87u8, 212u8, 1u8, 88u8, 212u8, 1u8, 89u8, 212u8, 1u8, 90u8, 212u8, 1u8, 91u8,
+                    212u8, 1u8, 92u8, 212u8, 1u8, 93u8, 212u8, 1u8, 94u8, 212u8, 1u8, 95u8, 212u8,
+                    1u8, 96u8, 212u8, 1u8, 97u8, 212u8, 1u8, 98u8, 212u8, 1u8, 99u8, 212u8, 1u8,
+                    100u8, 212u8, 1u8, 101u8, 212u8, 1u8, 102u8, 212u8, 1u8, 103u8, 212u8, 1u8,
+                    104u8, 212u8, 1u8, 105u8, 212u8, 1u8, 106u8, 212u8, 1u8, 107u8, 212u8, 1u8,
+                    108u8, 212u8, 1u8, 109u8, 212u8, 1u8, 110u8, 212u8, 1u8, 111u8, 212u8, 1u8,
+                    112u8, 212u8, 1u8, 113u8, 212u8, 1u8, 114u8, 212u8, 1u8, 115u8, 212u8, 1u8,
+                    116u8, 212u8, 1u8, 117u8, 212u8, 1u8, 118u8, 212u8, 1u8, 119u8, 212u8, 1u8,
+                    120u8, 212u8, 1u8, 121u8, 212u8, 1u8, 122u8, 212u8, 1u8, 123u8, 212u8, 1u8,
+                    124u8, 212u8, 1u8, 125u8, 212u8, 1u8, 126u8, 212u8, 1u8, 127u8, 212u8, 1u8,
+                    128u8, 212u8, 1u8, 129u8, 212u8, 1u8, 130u8, 212u8, 1u8, 131u8, 212u8, 1u8,
+                    132u8, 212u8, 1u8, 133u8, 212u8, 1u8, 134u8, 212u8, 1u8, 135u8, 212u8, 1u8,
+                    136u8, 212u8, 1u8, 137u8, 212u8, 1u8, 138u8, 212u8, 1u8, 139u8, 212u8, 1u8,
+                    140u8, 212u8, 1u8, 141u8, 212u8, 1u8, 142u8, 212u8, 1u8, 143u8, 212u8, 1u8,
+                    144u8, 212u8, 1u8, 145u8, 212u8, 1u8, 146u8, 212u8, 1u8, 147u8, 212u8, 1u8,
+                    148u8, 212u8, 1u8, 149u8, 212u8, 1u8, 150u8, 212u8, 1u8, 151u8, 212u8, 1u8,
+                    152u8, 212u8, 1u8, 153u8, 212u8, 1u8, 154u8, 212u8, 1u8, 155u8, 212u8, 1u8,
+                    156u8, 212u8, 1u8, 158u8, 212u8, 1u8, 159u8, 212u8, 1u8, 162u8, 212u8, 1u8,
+                    165u8, 212u8, 1u8, 166u8, 212u8, 1u8, 169u8, 212u8, 1u8, 170u8, 212u8, 1u8,
+                    171u8, 212u8, 1u8, 172u8, 212u8, 1u8, 174u8, 212u8, 1u8, 175u8, 212u8, 1u8,
+                    176u8, 212u8, 1u8, 177u8, 212u8, 1u8, 178u8, 212u8, 1u8, 179u8, 212u8, 1u8,
+                    180u8, 212u8, 1u8, 181u8, 212u8, 1u8, 182u8, 212u8, 1u8, 183u8, 212u8, 1u8,
+                    184u8, 212u8, 1u8, 185u8, 212u8, 1u8, 187u8, 212u8, 1u8, 189u8, 212u8, 1u8,
+                    190u8, 212u8, 1u8, 191u8, 212u8, 1u8, 192u8, 212u8, 1u8, 193u8, 212u8, 1u8,
+                    194u8, 212u8, 1u8, 195u8, 212u8, 1u8, 197u8, 212u8, 1u8, 198u8, 212u8, 1u8,
+                    199u8, 212u8, 1u8, 200u8, 212u8, 1u8, 201u8, 212u8, 1u8, 202u8, 212u8, 1u8,
+                    203u8, 212u8, 1u8, 204u8, 212u8, 1u8, 205u8, 212u8, 1u8, 206u8, 212u8, 1u8,
+                    207u8, 212u8, 1u8, 208u8, 212u8, 1u8, 209u8, 212u8, 1u8, 210u8, 212u8, 1u8,
+                    211u8, 212u8, 1u8, 212u8, 212u8, 1u8, 213u8, 212u8, 1u8, 214u8, 212u8, 1u8,
+                    215u8, 212u8, 1u8, 216u8, 212u8, 1u8, 217u8, 212u8, 1u8, 218u8, 212u8, 1u8,
+                    219u8, 212u8, 1u8, 220u8, 212u8, 1u8, 221u8, 212u8, 1u8, 222u8, 212u8, 1u8,
+                    223u8, 212u8, 1u8, 224u8, 212u8, 1u8, 225u8, 212u8, 1u8, 226u8, 212u8, 1u8,
+                    227u8, 212u8, 1u8, 228u8, 212u8, 1u8, 229u8, 212u8, 1u8, 230u8, 212u8, 1u8,
+                    231u8, 212u8, 1u8, 232u8, 212u8, 1u8, 233u8, 212u8, 1u8, 234u8, 212u8, 1u8,
+                    235u8, 212u8, 1u8, 236u8, 212u8, 1u8, 237u8, 212u8, 1u8, 238u8, 212u8, 1u8,
+                    239u8, 212u8, 1u8, 240u8, 212u8, 1u8, 241u8, 212u8, 1u8, 242u8, 212u8, 1u8,
+                    243u8, 212u8, 1u8, 244u8, 212u8, 1u8, 245u8, 212u8, 1u8, 246u8, 212u8, 1u8,
+                    247u8, 212u8, 1u8, 248u8, 212u8, 1u8, 249u8, 212u8, 1u8, 250u8, 212u8, 1u8,
+                    251u8, 212u8, 1u8, 252u8, 212u8, 1u8, 253u8, 212u8, 1u8, 254u8, 212u8, 1u8,
+                    255u8, 212u8, 1u8, 0u8, 213u8, 1u8, 1u8, 213u8, 1u8, 2u8, 213u8, 1u8, 3u8,
+                    213u8, 1u8, 4u8, 213u8, 1u8, 5u8, 213u8, 1u8, 7u8, 213u8, 1u8, 8u8, 213u8, 1u8,
+                    9u8, 213u8, 1u8, 10u8, 213u8, 1u8, 13u8, 213u8, 1u8, 14u8, 213u8, 1u8, 15u8,
+                    213u8, 1u8, 16u8, 213u8, 1u8, 17u8, 213u8, 1u8, 18u8, 213u8, 1u8, 19u8, 213u8,
+                    1u8, 20u8, 213u8, 1u8, 22u8, 213u8, 1u8, 23u8, 213u8, 1u8, 24u8, 213u8, 1u8,
+                    25u8, 213u8, 1u8, 26u8, 213u8, 1u8, 27u8, 213u8, 1u8, 28u8, 213u8, 1u8, 30u8,
+                    213u8, 1u8, 31u8, 213u8, 1u8, 32u8, 213u8, 1u8, 33u8, 213u8, 1u8, 34u8, 213u8,
+                    1u8, 35u8, 213u8, 1u8, 36u8, 213u8, 1u8, 37u8, 213u8, 1u8, 38u8, 213u8, 1u8,
+                    39u8, 213u8, 1u8, 40u8, 213u8, 1u8, 41u8, 213u8, 1u8, 42u8, 213u8, 1u8, 43u8,
+                    213u8, 1u8, 44u8, 213u8, 1u8, 45u8, 213u8, 1u8, 46u8, 213u8, 1u8, 47u8, 213u8,
+                    1u8, 48u8, 213u8, 1u8, 49u8, 213u8, 1u8, 50u8, 213u8, 1u8, 51u8, 213u8, 1u8,
+                    52u8, 213u8, 1u8, 53u8, 213u8, 1u8, 54u8, 213u8, 1u8, 55u8, 213u8, 1u8, 56u8,
+                    213u8, 1u8, 57u8, 213u8, 1u8, 59u8, 213u8, 1u8, 60u8, 213u8, 1u8, 61u8, 213u8,
+                    1u8, 62u8, 213u8, 1u8, 64u8, 213u8, 1u8, 65u8, 213u8, 1u8, 66u8, 213u8, 1u8,
+                    67u8, 213u8, 1u8, 68u8, 213u8, 1u8, 70u8, 213u8, 1u8, 74u8, 213u8, 1u8, 75u8,
+                    213u8, 1u8, 76u8, 213u8, 1u8, 77u8, 213u8, 1u8, 78u8, 213u8, 1u8, 79u8, 213u8,
+                    1u8, 80u8, 213u8, 1u8, 82u8, 213u8, 1u8, 83u8, 213u8, 1u8, 84u8, 213u8, 1u8,
+                    85u8, 213u8, 1u8, 86u8, 213u8, 1u8, 87u8, 213u8, 1u8, 88u8, 213u8, 1u8, 89u8,
+                    213u8, 1u8, 90u8, 213u8, 1u8, 91u8, 213u8, 1u8, 92u8, 213u8, 1u8, 93u8, 213u8,
+                    1u8, 94u8, 213u8, 1u8, 95u8, 213u8, 1u8, 96u8, 213u8, 1u8, 97u8, 213u8, 1u8,
+                    98u8, 213u8, 1u8, 99u8, 213u8, 1u8, 100u8, 213u8, 1u8, 101u8, 213u8, 1u8,
+                    102u8, 213u8, 1u8, 103u8, 213u8, 1u8, 104u8, 213u8, 1u8, 105u8, 213u8, 1u8,
+                    106u8, 213u8, 1u8, 107u8, 213u8, 1u8, 108u8, 213u8, 1u8, 109u8, 213u8, 1u8,
+                    110u8, 213u8, 1u8, 111u8, 213u8, 1u8, 112u8, 213u8, 1u8, 113u8, 213u8, 1u8,
+                    114u8, 213u8, 1u8, 115u8, 213u8, 1u8, 116u8, 213u8, 1u8, 117u8, 213u8, 1u8,
+                    118u8, 213u8, 1u8, 119u8, 213u8, 1u8, 120u8, 213u8, 1u8, 121u8, 213u8, 1u8,
+                    122u8, 213u8, 1u8, 123u8, 213u8, 1u8, 124u8, 213u8, 1u8, 125u8, 213u8, 1u8,
+                    126u8, 213u8, 1u8, 127u8, 213u8, 1u8, 128u8, 213u8, 1u8, 129u8, 213u8, 1u8,
+                    130u8, 213u8, 1u8, 131u8, 213u8, 1u8, 132u8, 213u8, 1u8, 133u8, 213u8, 1u8,
+                    134u8, 213u8, 1u8, 135u8, 213u8, 1u8, 136u8, 213u8, 1u8, 137u8, 213u8, 1u8,
+                    138u8, 213u8, 1u8, 139u8, 213u8, 1u8, 140u8, 213u8, 1u8, 141u8, 213u8, 1u8,
+                    142u8, 213u8, 1u8, 143u8, 213u8, 1u8, 144u8, 213u8, 1u8, 145u8, 213u8, 1u8,
+                    146u8, 213u8, 1u8, 147u8, 213u8, 1u8, 148u8, 213u8, 1u8, 149u8, 213u8, 1u8,
+                    150u8, 213u8, 1u8, 151u8, 213u8, 1u8, 152u8, 213u8, 1u8, 153u8, 213u8, 1u8,
+                    154u8, 213u8, 1u8, 155u8, 213u8, 1u8, 156u8, 213u8, 1u8, 157u8, 213u8, 1u8,
+                    158u8, 213u8, 1u8, 159u8, 213u8, 1u8, 160u8, 213u8, 1u8, 161u8, 213u8, 1u8,
+                    162u8, 213u8, 1u8, 163u8, 213u8, 1u8, 164u8, 213u8, 1u8, 165u8, 213u8, 1u8,
+                    166u8, 213u8, 1u8, 167u8, 213u8, 1u8, 168u8, 213u8, 1u8, 169u8, 213u8, 1u8,
+                    170u8, 213u8, 1u8, 171u8, 213u8, 1u8, 172u8, 213u8, 1u8, 173u8, 213u8, 1u8,
+                    174u8, 213u8, 1u8, 175u8, 213u8, 1u8, 176u8, 213u8, 1u8, 177u8, 213u8, 1u8,
+                    178u8, 213u8, 1u8, 179u8, 213u8, 1u8, 180u8, 213u8, 1u8, 181u8, 213u8, 1u8,
+                    182u8, 213u8, 1u8, 183u8, 213u8, 1u8, 184u8, 213u8, 1u8, 185u8, 213u8, 1u8,
+                    186u8, 213u8, 1u8, 187u8, 213u8, 1u8, 188u8, 213u8, 1u8, 189u8, 213u8, 1u8,
+                    190u8, 213u8, 1u8, 191u8, 213u8, 1u8, 192u8, 213u8, 1u8, 193u8, 213u8, 1u8,
+                    194u8, 213u8, 1u8, 195u8, 213u8, 1u8, 196u8, 213u8, 1u8, 197u8, 213u8, 1u8,
+                    198u8, 213u8, 1u8, 199u8, 213u8, 1u8, 200u8, 213u8, 1u8, 201u8, 213u8, 1u8,
+                    202u8, 213u8, 1u8, 203u8, 213u8, 1u8, 204u8, 213u8, 1u8, 205u8, 213u8, 1u8,
+                    206u8, 213u8, 1u8, 207u8, 213u8, 1u8, 208u8, 213u8, 1u8, 209u8, 213u8, 1u8,
+                    210u8, 213u8, 1u8, 211u8, 213u8, 1u8, 212u8, 213u8, 1u8, 213u8, 213u8, 1u8,
+                    214u8, 213u8, 1u8, 215u8, 213u8, 1u8, 216u8, 213u8, 1u8, 217u8, 213u8, 1u8,
+                    218u8, 213u8, 1u8, 219u8, 213u8, 1u8, 220u8, 213u8, 1u8, 221u8, 213u8, 1u8,
+                    222u8, 213u8, 1u8, 223u8, 213u8, 1u8, 224u8, 213u8, 1u8, 225u8, 213u8, 1u8,
+                    226u8, 213u8, 1u8, 227u8, 213u8, 1u8, 228u8, 213u8, 1u8, 229u8, 213u8, 1u8,
+                    230u8, 213u8, 1u8, 231u8, 213u8, 1u8, 232u8, 213u8, 1u8, 233u8, 213u8, 1u8,
+                    234u8, 213u8, 1u8, 235u8, 213u8, 1u8, 236u8, 213u8, 1u8, 237u8, 213u8, 1u8,
+                    238u8, 213u8, 1u8, 239u8, 213u8, 1u8, 240u8, 213u8, 1u8, 241u8, 213u8, 1u8,
+                    242u8, 213u8, 1u8, 243u8, 213u8, 1u8, 244u8, 213u8, 1u8, 245u8, 213u8, 1u8,
+                    246u8, 213u8, 1u8, 247u8, 213u8, 1u8, 248u8, 213u8, 1u8, 249u8, 213u8, 1u8,
+                    250u8, 213u8, 1u8, 251u8, 213u8, 1u8, 252u8, 213u8, 1u8, 253u8, 213u8, 1u8,
+                    254u8, 213u8, 1u8, 255u8, 213u8, 1u8, 0u8, 214u8, 1u8, 1u8, 214u8, 1u8, 2u8,
+                    214u8, 1u8, 3u8, 214u8, 1u8, 4u8, 214u8, 1u8, 5u8, 214u8, 1u8, 6u8, 214u8, 1u8,
+                    7u8, 214u8, 1u8, 8u8, 214u8, 1u8, 9u8, 214u8, 1u8, 10u8, 214u8, 1u8, 11u8,
+                    214u8, 1u8, 12u8, 214u8, 1u8, 13u8, 214u8, 1u8, 14u8, 214u8, 1u8, 15u8, 214u8,
+                    1u8, 16u8, 214u8, 1u8, 17u8, 214u8, 1u8, 18u8, 214u8, 1u8, 19u8, 214u8, 1u8,
+                    20u8, 214u8, 1u8, 21u8, 214u8, 1u8, 22u8, 214u8, 1u8, 23u8, 214u8, 1u8, 24u8,
+                    214u8, 1u8, 25u8, 214u8, 1u8, 26u8, 214u8, 1u8, 27u8, 214u8, 1u8, 28u8, 214u8,
+                    1u8, 29u8, 214u8, 1u8, 30u8, 214u8, 1u8, 31u8, 214u8, 1u8, 32u8, 214u8, 1u8,
+                    33u8, 214u8, 1u8, 34u8, 214u8, 1u8, 35u8, 214u8, 1u8, 36u8, 214u8, 1u8, 37u8,
+                    214u8, 1u8, 38u8, 214u8, 1u8, 39u8, 214u8, 1u8, 40u8, 214u8, 1u8, 41u8, 214u8,
+                    1u8, 42u8, 214u8, 1u8, 43u8, 214u8, 1u8, 44u8, 214u8, 1u8, 45u8, 214u8, 1u8,
+                    46u8, 214u8, 1u8, 47u8, 214u8, 1u8, 48u8, 214u8, 1u8, 49u8, 214u8, 1u8, 50u8,
+                    214u8, 1u8, 51u8, 214u8, 1u8, 52u8, 214u8, 1u8, 53u8, 214u8, 1u8, 54u8, 214u8,
+                    1u8, 55u8, 214u8, 1u8, 56u8, 214u8, 1u8, 57u8, 214u8, 1u8, 58u8, 214u8, 1u8,
+                    59u8, 214u8, 1u8, 60u8, 214u8, 1u8, 61u8, 214u8, 1u8, 62u8, 214u8, 1u8, 63u8,
+                    214u8, 1u8, 64u8, 214u8, 1u8, 65u8, 214u8, 1u8, 66u8, 214u8, 1u8, 67u8, 214u8,
+                    1u8, 68u8, 214u8, 1u8, 69u8, 214u8, 1u8, 70u8, 214u8, 1u8, 71u8, 214u8, 1u8,
+                    72u8, 214u8, 1u8, 73u8, 214u8, 1u8, 74u8, 214u8, 1u8, 75u8, 214u8, 1u8, 76u8,
+                    214u8, 1u8, 77u8, 214u8, 1u8, 78u8, 214u8, 1u8, 79u8, 214u8, 1u8, 80u8, 214u8,
+                    1u8, 81u8, 214u8, 1u8, 82u8, 214u8, 1u8, 83u8, 214u8, 1u8, 84u8, 214u8, 1u8,
+                    85u8, 214u8, 1u8, 86u8, 214u8, 1u8, 87u8, 214u8, 1u8, 88u8, 214u8, 1u8, 89u8,
+                    214u8, 1u8, 90u8, 214u8, 1u8, 91u8, 214u8, 1u8, 92u8, 214u8, 1u8, 93u8, 214u8,
+                    1u8, 94u8, 214u8, 1u8, 95u8, 214u8, 1u8, 96u8, 214u8, 1u8, 97u8, 214u8, 1u8,
+                    98u8, 214u8, 1u8, 99u8, 214u8, 1u8, 100u8, 214u8, 1u8, 101u8, 214u8, 1u8,
+                    102u8, 214u8, 1u8, 103u8, 214u8, 1u8, 104u8, 214u8, 1u8, 105u8, 214u8, 1u8,
+                    106u8, 214u8, 1u8, 107u8, 214u8, 1u8, 108u8, 214u8, 1u8, 109u8, 214u8, 1u8,
+                    110u8, 214u8, 1u8, 111u8, 214u8, 1u8, 112u8, 214u8, 1u8, 113u8, 214u8, 1u8,
+                    114u8, 214u8, 1u8, 115u8, 214u8, 1u8, 116u8, 214u8, 1u8, 117u8, 214u8, 1u8,
+                    118u8, 214u8, 1u8, 119u8, 214u8, 1u8, 120u8, 214u8, 1u8, 121u8, 214u8, 1u8,
+                    122u8, 214u8, 1u8, 123u8, 214u8, 1u8, 124u8, 214u8, 1u8, 125u8, 214u8, 1u8,
+                    126u8, 214u8, 1u8, 127u8, 214u8, 1u8, 128u8, 214u8, 1u8, 129u8, 214u8, 1u8,
+                    130u8, 214u8, 1u8, 131u8, 214u8, 1u8, 132u8, 214u8, 1u8, 133u8, 214u8, 1u8,
+                    134u8, 214u8, 1u8, 135u8, 214u8, 1u8, 136u8, 214u8, 1u8, 137u8, 214u8, 1u8,
+                    138u8, 214u8, 1u8, 139u8, 214u8, 1u8, 140u8, 214u8, 1u8, 141u8, 214u8, 1u8,
+                    142u8, 214u8, 1u8, 143u8, 214u8, 1u8, 144u8, 214u8, 1u8, 145u8, 214u8, 1u8,
+                    146u8, 214u8, 1u8, 147u8, 214u8, 1u8, 148u8, 214u8, 1u8, 149u8, 214u8, 1u8,
+                    150u8, 214u8, 1u8, 151u8, 214u8, 1u8, 152u8, 214u8, 1u8, 153u8, 214u8, 1u8,
+                    154u8, 214u8, 1u8, 155u8, 214u8, 1u8, 156u8, 214u8, 1u8, 157u8, 214u8, 1u8,
+                    158u8, 214u8, 1u8, 159u8, 214u8, 1u8, 160u8, 214u8, 1u8, 161u8, 214u8, 1u8,
+                    162u8, 214u8, 1u8, 163u8, 214u8, 1u8, 164u8, 214u8, 1u8, 165u8, 214u8, 1u8,
+                    168u8, 214u8, 1u8, 169u8, 214u8, 1u8, 170u8, 214u8, 1u8, 171u8, 214u8, 1u8,
+                    172u8, 214u8, 1u8, 173u8, 214u8, 1u8, 174u8, 214u8, 1u8, 175u8, 214u8, 1u8,
+                    176u8, 214u8, 1u8, 177u8, 214u8, 1u8, 178u8, 214u8, 1u8, 179u8, 214u8, 1u8,
+                    180u8, 214u8, 1u8, 181u8, 214u8, 1u8, 182u8, 214u8, 1u8, 183u8, 214u8, 1u8,
+                    184u8, 214u8, 1u8, 185u8, 214u8, 1u8, 186u8, 214u8, 1u8, 187u8, 214u8, 1u8,
+                    188u8, 214u8, 1u8, 189u8, 214u8, 1u8, 190u8, 214u8, 1u8, 191u8, 214u8, 1u8,
+                    192u8, 214u8, 1u8, 193u8, 214u8, 1u8, 194u8, 214u8, 1u8, 195u8, 214u8, 1u8,
+                    196u8, 214u8, 1u8, 197u8, 214u8, 1u8, 198u8, 214u8, 1u8, 199u8, 214u8, 1u8,
+                    200u8, 214u8, 1u8, 201u8, 214u8, 1u8, 202u8, 214u8, 1u8, 203u8, 214u8, 1u8,
+                    204u8, 214u8, 1u8, 205u8, 214u8, 1u8, 206u8, 214u8, 1u8, 207u8, 214u8, 1u8,
+                    208u8, 214u8, 1u8, 209u8, 214u8, 1u8, 210u8, 214u8, 1u8, 211u8, 214u8, 1u8,
+                    212u8, 214u8, 1u8, 213u8, 214u8, 1u8, 214u8, 214u8, 1u8, 215u8, 214u8, 1u8,
+                    216u8, 214u8, 1u8, 217u8, 214u8, 1u8, 218u8, 214u8, 1u8, 219u8, 214u8, 1u8,
+                    220u8, 214u8, 1u8, 221u8, 214u8, 1u8, 222u8, 214u8, 1u8, 223u8, 214u8, 1u8,
+                    224u8, 214u8, 1u8, 225u8, 214u8, 1u8, 226u8, 214u8, 1u8, 227u8, 214u8, 1u8,
+                    228u8, 214u8, 1u8, 229u8, 214u8, 1u8, 230u8, 214u8, 1u8, 231u8, 214u8, 1u8,
+                    232u8, 214u8, 1u8, 233u8, 214u8, 1u8, 234u8, 214u8, 1u8, 235u8, 214u8, 1u8,
+                    236u8, 214u8, 1u8, 237u8, 214u8, 1u8, 238u8, 214u8, 1u8, 239u8, 214u8, 1u8,
+                    240u8, 214u8, 1u8, 241u8, 214u8, 1u8, 242u8, 214u8, 1u8, 243u8, 214u8, 1u8,
+                    244u8, 214u8, 1u8, 245u8, 214u8, 1u8, 246u8, 214u8, 1u8, 247u8, 214u8, 1u8,
+                    248u8, 214u8, 1u8, 249u8, 214u8, 1u8, 250u8, 214u8, 1u8, 251u8, 214u8, 1u8,
+                    252u8, 214u8, 1u8, 253u8, 214u8, 1u8, 254u8, 214u8, 1u8, 255u8, 214u8, 1u8,
+                    0u8, 215u8, 1u8, 1u8, 215u8, 1u8, 2u8, 215u8, 1u8, 3u8, 215u8, 1u8, 4u8, 215u8,
+                    1u8, 5u8, 215u8, 1u8, 6u8, 215u8, 1u8, 7u8, 215u8, 1u8, 8u8, 215u8, 1u8, 9u8,
+                    215u8, 1u8, 10u8, 215u8, 1u8, 11u8, 215u8, 1u8, 12u8, 215u8, 1u8, 13u8, 215u8,
+                    1u8, 14u8, 215u8, 1u8, 15u8, 215u8, 1u8, 16u8, 215u8, 1u8, 17u8, 215u8, 1u8,
+                    18u8, 215u8, 1u8, 19u8, 215u8, 1u8, 20u8, 215u8, 1u8, 21u8, 215u8, 1u8, 22u8,
+                    215u8, 1u8, 23u8, 215u8, 1u8, 24u8, 215u8, 1u8, 25u8, 215u8, 1u8, 26u8, 215u8,
+                    1u8, 27u8, 215u8, 1u8, 28u8, 215u8, 1u8, 29u8, 215u8, 1u8, 30u8, 215u8, 1u8,
+                    31u8, 215u8, 1u8, 32u8, 215u8, 1u8, 33u8, 215u8, 1u8, 34u8, 215u8, 1u8, 35u8,
+                    215u8, 1u8, 36u8, 215u8, 1u8, 37u8, 215u8, 1u8, 38u8, 215u8, 1u8, 39u8, 215u8,
+                    1u8, 40u8, 215u8, 1u8, 41u8, 215u8, 1u8, 42u8, 215u8, 1u8, 43u8, 215u8, 1u8,
+                    44u8, 215u8, 1u8, 45u8, 215u8, 1u8, 46u8, 215u8, 1u8, 47u8, 215u8, 1u8, 48u8,
+                    215u8, 1u8, 49u8, 215u8, 1u8, 50u8, 215u8, 1u8, 51u8, 215u8, 1u8, 52u8, 215u8,
+                    1u8, 53u8, 215u8, 1u8, 54u8, 215u8, 1u8, 55u8, 215u8, 1u8, 56u8, 215u8, 1u8,
+                    57u8, 215u8, 1u8, 58u8, 215u8, 1u8, 59u8, 215u8, 1u8, 60u8, 215u8, 1u8, 61u8,
+                    215u8, 1u8, 62u8, 215u8, 1u8, 63u8, 215u8, 1u8, 64u8, 215u8, 1u8, 65u8, 215u8,
+                    1u8, 66u8, 215u8, 1u8, 67u8, 215u8, 1u8, 68u8, 215u8, 1u8, 69u8, 215u8, 1u8,
+                    70u8, 215u8, 1u8, 71u8, 215u8, 1u8, 72u8, 215u8, 1u8, 73u8, 215u8, 1u8, 74u8,
+                    215u8, 1u8, 75u8, 215u8, 1u8, 76u8, 215u8, 1u8, 77u8, 215u8, 1u8, 78u8, 215u8,
+                    1u8, 79u8, 215u8, 1u8, 80u8, 215u8, 1u8, 81u8, 215u8, 1u8, 82u8, 215u8, 1u8,
+                    83u8, 215u8, 1u8, 84u8, 215u8, 1u8, 85u8, 215u8, 1u8, 86u8, 215u8, 1u8, 87u8,
+                    215u8, 1u8, 88u8, 215u8, 1u8, 89u8, 215u8, 1u8, 90u8, 215u8, 1u8, 91u8, 215u8,
+                    1u8, 92u8, 215u8, 1u8, 93u8, 215u8, 1u8, 94u8, 215u8, 1u8, 95u8, 215u8, 1u8,
+                    96u8, 215u8, 1u8, 97u8, 215u8, 1u8, 98u8, 215u8, 1u8, 99u8, 215u8, 1u8, 100u8,
+                    215u8, 1u8, 101u8, 215u8, 1u8, 102u8, 215u8, 1u8, 103u8, 215u8, 1u8, 104u8,
+                    215u8, 1u8, 105u8, 215u8, 1u8, 106u8, 215u8, 1u8, 107u8, 215u8, 1u8, 108u8,
+                    215u8, 1u8, 109u8, 215u8, 1u8, 110u8, 215u8, 1u8, 111u8, 215u8, 1u8, 112u8,
+                    215u8, 1u8, 113u8, 215u8, 1u8, 114u8, 215u8, 1u8, 115u8, 215u8, 1u8, 116u8,
+                    215u8, 1u8, 117u8, 215u8, 1u8, 118u8, 215u8, 1u8, 119u8, 215u8, 1u8, 120u8,
+                    215u8, 1u8, 121u8, 215u8, 1u8, 122u8, 215u8, 1u8, 123u8, 215u8, 1u8, 124u8,
+                    215u8, 1u8, 125u8, 215u8, 1u8, 126u8, 215u8, 1u8, 127u8, 215u8, 1u8, 128u8,
+                    215u8, 1u8, 129u8, 215u8, 1u8, 130u8, 215u8, 1u8, 131u8, 215u8, 1u8, 132u8,
+                    215u8, 1u8, 133u8, 215u8, 1u8, 134u8, 215u8, 1u8, 135u8, 215u8, 1u8, 136u8,
+                    215u8, 1u8, 137u8, 215u8, 1u8, 138u8, 215u8, 1u8, 139u8, 215u8, 1u8, 140u8,
+                    215u8, 1u8, 141u8, 215u8, 1u8, 142u8, 215u8, 1u8, 143u8, 215u8, 1u8, 144u8,
+                    215u8, 1u8, 145u8, 215u8, 1u8, 146u8, 215u8, 1u8, 147u8, 215u8, 1u8, 148u8,
+                    215u8, 1u8, 149u8, 215u8, 1u8, 150u8, 215u8, 1u8, 151u8, 215u8, 1u8, 152u8,
+                    215u8, 1u8, 153u8, 215u8, 1u8, 154u8, 215u8, 1u8, 155u8, 215u8, 1u8, 156u8,
+                    215u8, 1u8, 157u8, 215u8, 1u8, 158u8, 215u8, 1u8, 159u8, 215u8, 1u8, 160u8,
+                    215u8, 1u8, 161u8, 215u8, 1u8, 162u8, 215u8, 1u8, 163u8, 215u8, 1u8, 164u8,
+                    215u8, 1u8, 165u8, 215u8, 1u8, 166u8, 215u8, 1u8, 167u8, 215u8, 1u8, 168u8,
+                    215u8, 1u8, 169u8, 215u8, 1u8, 170u8, 215u8, 1u8, 171u8, 215u8, 1u8, 172u8,
+                    215u8, 1u8, 173u8, 215u8, 1u8, 174u8, 215u8, 1u8, 175u8, 215u8, 1u8, 176u8,
+                    215u8, 1u8, 177u8, 215u8, 1u8, 178u8, 215u8, 1u8, 179u8, 215u8, 1u8, 180u8,
+                    215u8, 1u8, 181u8, 215u8, 1u8, 182u8, 215u8, 1u8, 183u8, 215u8, 1u8, 184u8,
+                    215u8, 1u8, 185u8, 215u8, 1u8, 186u8, 215u8, 1u8, 187u8, 215u8, 1u8, 188u8,
+                    215u8, 1u8, 189u8, 215u8, 1u8, 190u8, 215u8, 1u8, 191u8, 215u8, 1u8, 192u8,
+                    215u8, 1u8, 193u8, 215u8, 1u8, 194u8, 215u8, 1u8, 195u8, 215u8, 1u8, 196u8,
+                    215u8, 1u8, 197u8, 215u8, 1u8, 198u8, 215u8, 1u8, 199u8, 215u8, 1u8, 200u8,
+                    215u8, 1u8, 201u8, 215u8, 1u8, 202u8, 215u8, 1u8, 203u8, 215u8, 1u8, 206u8,
+                    215u8, 1u8, 207u8, 215u8, 1u8, 208u8, 215u8, 1u8, 209u8, 215u8, 1u8, 210u8,
+                    215u8, 1u8, 211u8, 215u8, 1u8, 212u8, 215u8, 1u8, 213u8, 215u8, 1u8, 214u8,
+                    215u8, 1u8, 215u8, 215u8, 1u8, 216u8, 215u8, 1u8, 217u8, 215u8, 1u8, 218u8,
+                    215u8, 1u8, 219u8, 215u8, 1u8, 220u8, 215u8, 1u8, 221u8, 215u8, 1u8, 222u8,
+                    215u8, 1u8, 223u8, 215u8, 1u8, 224u8, 215u8, 1u8, 225u8, 215u8, 1u8, 226u8,
+                    215u8, 1u8, 227u8, 215u8, 1u8, 228u8, 215u8, 1u8, 229u8, 215u8, 1u8, 230u8,
+                    215u8, 1u8, 231u8, 215u8, 1u8, 232u8, 215u8, 1u8, 233u8, 215u8, 1u8, 234u8,
+                    215u8, 1u8, 235u8, 215u8, 1u8, 236u8, 215u8, 1u8, 237u8, 215u8, 1u8, 238u8,
+                    215u8, 1u8, 239u8, 215u8, 1u8, 240u8, 215u8, 1u8, 241u8, 215u8, 1u8, 242u8,
+                    215u8, 1u8, 243u8, 215u8, 1u8, 244u8, 215u8, 1u8, 245u8, 215u8, 1u8, 246u8,
+                    215u8, 1u8, 247u8, 215u8, 1u8, 248u8, 215u8, 1u8, 249u8, 215u8, 1u8, 250u8,
+                    215u8, 1u8, 251u8, 215u8, 1u8, 252u8, 215u8, 1u8, 253u8, 215u8, 1u8, 254u8,
+                    215u8, 1u8, 255u8, 215u8, 1u8, 199u8, 232u8, 1u8, 200u8, 232u8, 1u8, 201u8,
+                    232u8, 1u8, 203u8, 232u8, 1u8, 204u8, 232u8, 1u8, 205u8, 232u8, 1u8, 0u8,
+                    238u8, 1u8, 1u8, 238u8, 1u8, 2u8, 238u8, 1u8, 3u8, 238u8, 1u8, 5u8, 238u8, 1u8,
+                    6u8, 238u8, 1u8, 7u8, 238u8, 1u8, 8u8, 238u8, 1u8, 9u8, 238u8, 1u8, 10u8,
+                    238u8, 1u8, 11u8, 238u8, 1u8, 12u8, 238u8, 1u8, 13u8, 238u8, 1u8, 14u8, 238u8,
+                    1u8, 15u8, 238u8, 1u8, 16u8, 238u8, 1u8, 17u8, 238u8, 1u8, 18u8, 238u8, 1u8,
+                    19u8, 238u8, 1u8, 20u8, 238u8, 1u8, 21u8, 238u8, 1u8, 22u8, 238u8, 1u8, 23u8,
+                    238u8, 1u8, 24u8, 238u8, 1u8, 25u8, 238u8, 1u8, 26u8, 238u8, 1u8, 27u8, 238u8,
+                    1u8, 28u8, 238u8, 1u8, 29u8, 238u8, 1u8, 30u8, 238u8, 1u8, 31u8, 238u8, 1u8,
+                    33u8, 238u8, 1u8, 34u8, 238u8, 1u8, 36u8, 238u8, 1u8, 39u8, 238u8, 1u8, 41u8,
+                    238u8, 1u8, 42u8, 238u8, 1u8, 43u8, 238u8, 1u8, 44u8, 238u8, 1u8, 45u8, 238u8,
+                    1u8, 46u8, 238u8, 1u8, 47u8, 238u8, 1u8, 48u8, 238u8, 1u8, 49u8, 238u8, 1u8,
+                    50u8, 238u8, 1u8, 52u8, 238u8, 1u8, 53u8, 238u8, 1u8, 54u8, 238u8, 1u8, 55u8,
+                    238u8, 1u8, 57u8, 238u8, 1u8, 59u8, 238u8, 1u8, 66u8, 238u8, 1u8, 71u8, 238u8,
+                    1u8, 73u8, 238u8, 1u8, 75u8, 238u8, 1u8, 77u8, 238u8, 1u8, 78u8, 238u8, 1u8,
+                    79u8, 238u8, 1u8, 81u8, 238u8, 1u8, 82u8, 238u8, 1u8, 84u8, 238u8, 1u8, 87u8,
+                    238u8, 1u8, 89u8, 238u8, 1u8, 91u8, 238u8, 1u8, 93u8, 238u8, 1u8, 95u8, 238u8,
+                    1u8, 97u8, 238u8, 1u8, 98u8, 238u8, 1u8, 100u8, 238u8, 1u8, 103u8, 238u8, 1u8,
+                    104u8, 238u8, 1u8, 105u8, 238u8, 1u8, 106u8, 238u8, 1u8, 108u8, 238u8, 1u8,
+                    109u8, 238u8, 1u8, 110u8, 238u8, 1u8, 111u8, 238u8, 1u8, 112u8, 238u8, 1u8,
+                    113u8, 238u8, 1u8, 114u8, 238u8, 1u8, 116u8, 238u8, 1u8, 117u8, 238u8, 1u8,
+                    118u8, 238u8, 1u8, 119u8, 238u8, 1u8, 121u8, 238u8, 1u8, 122u8, 238u8, 1u8,
+                    123u8, 238u8, 1u8, 124u8, 238u8, 1u8, 126u8, 238u8, 1u8, 128u8, 238u8, 1u8,
+                    129u8, 238u8, 1u8, 130u8, 238u8, 1u8, 131u8, 238u8, 1u8, 132u8, 238u8, 1u8,
+                    133u8, 238u8, 1u8, 134u8, 238u8, 1u8, 135u8, 238u8, 1u8, 136u8, 238u8, 1u8,
+                    137u8, 238u8, 1u8, 139u8, 238u8, 1u8, 140u8, 238u8, 1u8, 141u8, 238u8, 1u8,
+                    142u8, 238u8, 1u8, 143u8, 238u8, 1u8, 144u8, 238u8, 1u8, 145u8, 238u8, 1u8,
+                    146u8, 238u8, 1u8, 147u8, 238u8, 1u8, 148u8, 238u8, 1u8, 149u8, 238u8, 1u8,
+                    150u8, 238u8, 1u8, 151u8, 238u8, 1u8, 152u8, 238u8, 1u8, 153u8, 238u8, 1u8,
+                    154u8, 238u8, 1u8, 155u8, 238u8, 1u8, 161u8, 238u8, 1u8, 162u8, 238u8, 1u8,
+                    163u8, 238u8, 1u8, 165u8, 238u8, 1u8, 166u8, 238u8, 1u8, 167u8, 238u8, 1u8,
+                    168u8, 238u8, 1u8, 169u8, 238u8, 1u8, 171u8, 238u8, 1u8, 172u8, 238u8, 1u8,
+                    173u8, 238u8, 1u8, 174u8, 238u8, 1u8, 175u8, 238u8, 1u8, 176u8, 238u8, 1u8,
+                    177u8, 238u8, 1u8, 178u8, 238u8, 1u8, 179u8, 238u8, 1u8, 180u8, 238u8, 1u8,
+                    181u8, 238u8, 1u8, 182u8, 238u8, 1u8, 183u8, 238u8, 1u8, 184u8, 238u8, 1u8,
+                    185u8, 238u8, 1u8, 186u8, 238u8, 1u8, 187u8, 238u8, 1u8, 0u8, 241u8, 1u8, 1u8,
+                    241u8, 1u8, 2u8, 241u8, 1u8, 3u8, 241u8, 1u8, 4u8, 241u8, 1u8, 5u8, 241u8, 1u8,
+                    6u8, 241u8, 1u8, 7u8, 241u8, 1u8, 8u8, 241u8, 1u8, 9u8, 241u8, 1u8, 10u8,
+                    241u8, 1u8, 15u8, 241u8, 1u8, 16u8, 241u8, 1u8, 17u8, 241u8, 1u8, 18u8, 241u8,
+                    1u8, 19u8, 241u8, 1u8, 20u8, 241u8, 1u8, 21u8, 241u8, 1u8, 22u8, 241u8, 1u8,
+                    23u8, 241u8, 1u8, 24u8, 241u8, 1u8, 25u8, 241u8, 1u8, 26u8, 241u8, 1u8, 27u8,
+                    241u8, 1u8, 28u8, 241u8, 1u8, 29u8, 241u8, 1u8, 30u8, 241u8, 1u8, 31u8, 241u8,
+                    1u8, 32u8, 241u8, 1u8, 33u8, 241u8, 1u8, 34u8, 241u8, 1u8, 35u8, 241u8, 1u8,
+                    36u8, 241u8, 1u8, 37u8, 241u8, 1u8, 38u8, 241u8, 1u8, 39u8, 241u8, 1u8, 40u8,
+                    241u8, 1u8, 41u8, 241u8, 1u8, 42u8, 241u8, 1u8, 109u8, 241u8, 1u8, 110u8,
+                    241u8, 1u8, 64u8, 242u8, 1u8, 65u8, 242u8, 1u8, 66u8, 242u8, 1u8, 67u8, 242u8,
+                    1u8, 68u8, 242u8, 1u8, 69u8, 242u8, 1u8, 70u8, 242u8, 1u8, 71u8, 242u8, 1u8,
+                    72u8, 242u8, 1u8, 18u8, 243u8, 1u8, 24u8, 243u8, 1u8, 25u8, 243u8, 1u8, 0u8,
+                    247u8, 1u8, 1u8, 247u8, 1u8, 2u8, 247u8, 1u8, 4u8, 247u8, 1u8, 7u8, 247u8, 1u8,
+                    8u8, 247u8, 1u8, 10u8, 247u8, 1u8, 20u8, 247u8, 1u8, 40u8, 247u8, 1u8, 58u8,
+                    247u8, 1u8, 76u8, 247u8, 1u8, 84u8, 247u8, 1u8, 85u8, 247u8, 1u8, 92u8, 247u8,
+                    1u8, 94u8, 247u8, 1u8, 104u8, 247u8, 1u8, 107u8, 247u8, 1u8, 108u8, 247u8, 1u8,
+                    113u8, 247u8, 1u8, 240u8, 251u8, 1u8, 241u8, 251u8, 1u8, 242u8, 251u8, 1u8,
+                    243u8, 251u8, 1u8, 244u8, 251u8, 1u8, 245u8, 251u8, 1u8, 246u8, 251u8, 1u8,
+                    247u8, 251u8, 1u8, 248u8, 251u8, 1u8, 249u8, 251u8, 1u8, 232u8, 31u8, 2u8, 0u8,
+                    248u8, 2u8, 1u8, 248u8, 2u8, 2u8, 248u8, 2u8, 3u8, 248u8, 2u8, 4u8, 248u8, 2u8,
+                    5u8, 248u8, 2u8, 6u8, 248u8, 2u8, 7u8, 248u8, 2u8, 8u8, 248u8, 2u8, 9u8, 248u8,
+                    2u8, 10u8, 248u8, 2u8, 11u8, 248u8, 2u8, 12u8, 248u8, 2u8, 13u8, 248u8, 2u8,
+                    14u8, 248u8, 2u8, 15u8, 248u8, 2u8, 16u8, 248u8, 2u8, 17u8, 248u8, 2u8, 18u8,
+                    248u8, 2u8, 19u8, 248u8, 2u8, 20u8, 248u8, 2u8, 21u8, 248u8, 2u8, 22u8, 248u8,
+                    2u8, 23u8, 248u8, 2u8, 24u8, 248u8, 2u8, 25u8, 248u8, 2u8, 26u8, 248u8, 2u8,
+                    27u8, 248u8, 2u8, 28u8, 248u8, 2u8, 29u8, 248u8, 2u8, 30u8, 248u8, 2u8, 31u8,
+                    248u8, 2u8, 32u8, 248u8, 2u8, 33u8, 248u8, 2u8, 34u8, 248u8, 2u8, 35u8, 248u8,
+                    2u8, 36u8, 248u8, 2u8, 37u8, 248u8, 2u8, 38u8, 248u8, 2u8, 39u8, 248u8, 2u8,
+                    40u8, 248u8, 2u8, 41u8, 248u8, 2u8, 42u8, 248u8, 2u8, 43u8, 248u8, 2u8, 44u8,
+                    248u8, 2u8, 45u8, 248u8, 2u8, 46u8, 248u8, 2u8, 47u8, 248u8, 2u8, 48u8, 248u8,
+                    2u8, 49u8, 248u8, 2u8, 50u8, 248u8, 2u8, 51u8, 248u8, 2u8, 52u8, 248u8, 2u8,
+                    53u8, 248u8, 2u8, 54u8, 248u8, 2u8, 55u8, 248u8, 2u8, 56u8, 248u8, 2u8, 57u8,
+                    248u8, 2u8, 58u8, 248u8, 2u8, 59u8, 248u8, 2u8, 60u8, 248u8, 2u8, 61u8, 248u8,
+                    2u8, 62u8, 248u8, 2u8, 63u8, 248u8, 2u8, 64u8, 248u8, 2u8, 65u8, 248u8, 2u8,
+                    66u8, 248u8, 2u8, 67u8, 248u8, 2u8, 68u8, 248u8, 2u8, 69u8, 248u8, 2u8, 70u8,
+                    248u8, 2u8, 71u8, 248u8, 2u8, 72u8, 248u8, 2u8, 73u8, 248u8, 2u8, 74u8, 248u8,
+                    2u8, 75u8, 248u8, 2u8, 76u8, 248u8, 2u8, 77u8, 248u8, 2u8, 78u8, 248u8, 2u8,
+                    79u8, 248u8, 2u8, 80u8, 248u8, 2u8, 81u8, 248u8, 2u8, 82u8, 248u8, 2u8, 83u8,
+                    248u8, 2u8, 84u8, 248u8, 2u8, 85u8, 248u8, 2u8, 86u8, 248u8, 2u8, 87u8, 248u8,
+                    2u8, 88u8, 248u8, 2u8, 89u8, 248u8, 2u8, 90u8, 248u8, 2u8, 91u8, 248u8, 2u8,
+                    92u8, 248u8, 2u8, 93u8, 248u8, 2u8, 94u8, 248u8, 2u8, 95u8, 248u8, 2u8, 96u8,
+                    248u8, 2u8, 97u8, 248u8, 2u8, 98u8, 248u8, 2u8, 99u8, 248u8, 2u8, 100u8, 248u8,
+                    2u8, 101u8, 248u8, 2u8, 102u8, 248u8, 2u8, 103u8, 248u8, 2u8, 104u8, 248u8,
+                    2u8, 105u8, 248u8, 2u8, 106u8, 248u8, 2u8, 107u8, 248u8, 2u8, 108u8, 248u8,
+                    2u8, 109u8, 248u8, 2u8, 110u8, 248u8, 2u8, 111u8, 248u8, 2u8, 112u8, 248u8,
+                    2u8, 113u8, 248u8, 2u8, 114u8, 248u8, 2u8, 115u8, 248u8, 2u8, 116u8, 248u8,
+                    2u8, 117u8, 248u8, 2u8, 118u8, 248u8, 2u8, 119u8, 248u8, 2u8, 120u8, 248u8,
+                    2u8, 121u8, 248u8, 2u8, 122u8, 248u8, 2u8, 123u8, 248u8, 2u8, 124u8, 248u8,
+                    2u8, 125u8, 248u8, 2u8, 126u8, 248u8, 2u8, 127u8, 248u8, 2u8, 128u8, 248u8,
+                    2u8, 129u8, 248u8, 2u8, 130u8, 248u8, 2u8, 131u8, 248u8, 2u8, 132u8, 248u8,
+                    2u8, 133u8, 248u8, 2u8, 134u8, 248u8, 2u8, 135u8, 248u8, 2u8, 136u8, 248u8,
+                    2u8, 137u8, 248u8, 2u8, 138u8, 248u8, 2u8, 139u8, 248u8, 2u8, 140u8, 248u8,
+                    2u8, 141u8, 248u8, 2u8, 142u8, 248u8, 2u8, 143u8, 248u8, 2u8, 144u8, 248u8,
+                    2u8, 145u8, 248u8, 2u8, 146u8, 248u8, 2u8, 147u8, 248u8, 2u8, 148u8, 248u8,
+                    2u8, 149u8, 248u8, 2u8, 150u8, 248u8, 2u8, 151u8, 248u8, 2u8, 152u8, 248u8,
+                    2u8, 153u8, 248u8, 2u8, 154u8, 248u8, 2u8, 155u8, 248u8, 2u8, 156u8, 248u8,
+                    2u8, 157u8, 248u8, 2u8, 158u8, 248u8, 2u8, 159u8, 248u8, 2u8, 160u8, 248u8,
+                    2u8, 161u8, 248u8, 2u8, 162u8, 248u8, 2u8, 163u8, 248u8, 2u8, 164u8, 248u8,
+                    2u8, 165u8, 248u8, 2u8, 166u8, 248u8, 2u8, 167u8, 248u8, 2u8, 168u8, 248u8,
+                    2u8, 169u8, 248u8, 2u8, 170u8, 248u8, 2u8, 171u8, 248u8, 2u8, 172u8, 248u8,
+                    2u8, 173u8, 248u8, 2u8, 174u8, 248u8, 2u8, 175u8, 248u8, 2u8, 176u8, 248u8,
+                    2u8, 177u8, 248u8, 2u8, 178u8, 248u8, 2u8, 179u8, 248u8, 2u8, 180u8, 248u8,
+                    2u8, 181u8, 248u8, 2u8, 182u8, 248u8, 2u8, 183u8, 248u8, 2u8, 184u8, 248u8,
+                    2u8, 185u8, 248u8, 2u8, 186u8, 248u8, 2u8, 187u8, 248u8, 2u8, 188u8, 248u8,
+                    2u8, 189u8, 248u8, 2u8, 190u8, 248u8, 2u8, 191u8, 248u8, 2u8, 192u8, 248u8,
+                    2u8, 193u8, 248u8, 2u8, 194u8, 248u8, 2u8, 195u8, 248u8, 2u8, 196u8, 248u8,
+                    2u8, 197u8, 248u8, 2u8, 198u8, 248u8, 2u8, 199u8, 248u8, 2u8, 200u8, 248u8,
+                    2u8, 201u8, 248u8, 2u8, 202u8, 248u8, 2u8, 203u8, 248u8, 2u8, 204u8, 248u8,
+                    2u8, 205u8, 248u8, 2u8, 206u8, 248u8, 2u8, 207u8, 248u8, 2u8, 208u8, 248u8,
+                    2u8, 209u8, 248u8, 2u8, 210u8, 248u8, 2u8, 211u8, 248u8, 2u8, 212u8, 248u8,
+                    2u8, 213u8, 248u8, 2u8, 214u8, 248u8, 2u8, 215u8, 248u8, 2u8, 216u8, 248u8,
+                    2u8, 217u8, 248u8, 2u8, 218u8, 248u8, 2u8, 219u8, 248u8, 2u8, 220u8, 248u8,
+                    2u8, 221u8, 248u8, 2u8, 222u8, 248u8, 2u8, 223u8, 248u8, 2u8, 224u8, 248u8,
+                    2u8, 225u8, 248u8, 2u8, 226u8, 248u8, 2u8, 227u8, 248u8, 2u8, 228u8, 248u8,
+                    2u8, 229u8, 248u8, 2u8, 230u8, 248u8, 2u8, 231u8, 248u8, 2u8, 232u8, 248u8,
+                    2u8, 233u8, 248u8, 2u8, 234u8, 248u8, 2u8, 235u8, 248u8, 2u8, 236u8, 248u8,
+                    2u8, 237u8, 248u8, 2u8, 238u8, 248u8, 2u8, 239u8, 248u8, 2u8, 240u8, 248u8,
+                    2u8, 241u8, 248u8, 2u8, 242u8, 248u8, 2u8, 243u8, 248u8, 2u8, 244u8, 248u8,
+                    2u8, 245u8, 248u8, 2u8, 246u8, 248u8, 2u8, 247u8, 248u8, 2u8, 248u8, 248u8,
+                    2u8, 249u8, 248u8, 2u8, 250u8, 248u8, 2u8, 251u8, 248u8, 2u8, 252u8, 248u8,
+                    2u8, 253u8, 248u8, 2u8, 254u8, 248u8, 2u8, 255u8, 248u8, 2u8, 0u8, 249u8, 2u8,
+                    1u8, 249u8, 2u8, 2u8, 249u8, 2u8, 3u8, 249u8, 2u8, 4u8, 249u8, 2u8, 5u8, 249u8,
+                    2u8, 6u8, 249u8, 2u8, 7u8, 249u8, 2u8, 8u8, 249u8, 2u8, 9u8, 249u8, 2u8, 10u8,
+                    249u8, 2u8, 11u8, 249u8, 2u8, 12u8, 249u8, 2u8, 13u8, 249u8, 2u8, 14u8, 249u8,
+                    2u8, 15u8, 249u8, 2u8, 16u8, 249u8, 2u8, 17u8, 249u8, 2u8, 18u8, 249u8, 2u8,
+                    19u8, 249u8, 2u8, 20u8, 249u8, 2u8, 21u8, 249u8, 2u8, 22u8, 249u8, 2u8, 23u8,
+                    249u8, 2u8, 24u8, 249u8, 2u8, 25u8, 249u8, 2u8, 26u8, 249u8, 2u8, 27u8, 249u8,
+                    2u8, 28u8, 249u8, 2u8, 29u8, 249u8, 2u8, 30u8, 249u8, 2u8, 31u8, 249u8, 2u8,
+                    32u8, 249u8, 2u8, 33u8, 249u8, 2u8, 34u8, 249u8, 2u8, 35u8, 249u8, 2u8, 36u8,
+                    249u8, 2u8, 37u8, 249u8, 2u8, 38u8, 249u8, 2u8, 39u8, 249u8, 2u8, 40u8, 249u8,
+                    2u8, 41u8, 249u8, 2u8, 42u8, 249u8, 2u8, 43u8, 249u8, 2u8, 44u8, 249u8, 2u8,
+                    45u8, 249u8, 2u8, 46u8, 249u8, 2u8, 47u8, 249u8, 2u8, 48u8, 249u8, 2u8, 49u8,
+                    249u8, 2u8, 50u8, 249u8, 2u8, 51u8, 249u8, 2u8, 52u8, 249u8, 2u8, 53u8, 249u8,
+                    2u8, 54u8, 249u8, 2u8, 55u8, 249u8, 2u8, 56u8, 249u8, 2u8, 57u8, 249u8, 2u8,
+                    58u8, 249u8, 2u8, 59u8, 249u8, 2u8, 60u8, 249u8, 2u8, 61u8, 249u8, 2u8, 62u8,
+                    249u8, 2u8, 63u8, 249u8, 2u8, 64u8, 249u8, 2u8, 65u8, 249u8, 2u8, 66u8, 249u8,
+                    2u8, 67u8, 249u8, 2u8, 68u8, 249u8, 2u8, 69u8, 249u8, 2u8, 70u8, 249u8, 2u8,
+                    71u8, 249u8, 2u8, 72u8, 249u8, 2u8, 73u8, 249u8, 2u8, 74u8, 249u8, 2u8, 75u8,
+                    249u8, 2u8, 76u8, 249u8, 2u8, 77u8, 249u8, 2u8, 78u8, 249u8, 2u8, 79u8, 249u8,
+                    2u8, 80u8, 249u8, 2u8, 81u8, 249u8, 2u8, 82u8, 249u8, 2u8, 83u8, 249u8, 2u8,
+                    84u8, 249u8, 2u8, 85u8, 249u8, 2u8, 86u8, 249u8, 2u8, 87u8, 249u8, 2u8, 88u8,
+                    249u8, 2u8, 89u8, 249u8, 2u8, 90u8, 249u8, 2u8, 91u8, 249u8, 2u8, 92u8, 249u8,
+                    2u8, 93u8, 249u8, 2u8, 94u8, 249u8, 2u8, 95u8, 249u8, 2u8, 96u8, 249u8, 2u8,
+                    97u8, 249u8, 2u8, 98u8, 249u8, 2u8, 99u8, 249u8, 2u8, 100u8, 249u8, 2u8, 101u8,
+                    249u8, 2u8, 102u8, 249u8, 2u8, 103u8, 249u8, 2u8, 104u8, 249u8, 2u8, 105u8,
+                    249u8, 2u8, 106u8, 249u8, 2u8, 107u8, 249u8, 2u8, 108u8, 249u8, 2u8, 109u8,
+                    249u8, 2u8, 110u8, 249u8, 2u8, 111u8, 249u8, 2u8, 112u8, 249u8, 2u8, 113u8,
+                    249u8, 2u8, 114u8, 249u8, 2u8, 115u8, 249u8, 2u8, 116u8, 249u8, 2u8, 117u8,
+                    249u8, 2u8, 118u8, 249u8, 2u8, 119u8, 249u8, 2u8, 120u8, 249u8, 2u8, 121u8,
+                    249u8, 2u8, 122u8, 249u8, 2u8, 123u8, 249u8, 2u8, 124u8, 249u8, 2u8, 125u8,
+                    249u8, 2u8, 126u8, 249u8, 2u8, 127u8, 249u8, 2u8, 128u8, 249u8, 2u8, 129u8,
+                    249u8, 2u8, 130u8, 249u8, 2u8, 131u8, 249u8, 2u8, 132u8, 249u8, 2u8, 133u8,
+                    249u8, 2u8, 134u8, 249u8, 2u8, 135u8, 249u8, 2u8, 136u8, 249u8, 2u8, 137u8,
+                    249u8, 2u8, 138u8, 249u8, 2u8, 139u8, 249u8, 2u8, 140u8, 249u8, 2u8, 141u8,
+                    249u8, 2u8, 142u8, 249u8, 2u8, 143u8, 249u8, 2u8, 144u8, 249u8, 2u8, 145u8,
+                    249u8, 2u8, 146u8, 249u8, 2u8, 147u8, 249u8, 2u8, 148u8, 249u8, 2u8, 149u8,
+                    249u8, 2u8, 150u8, 249u8, 2u8, 151u8, 249u8, 2u8, 152u8, 249u8, 2u8, 153u8,
+                    249u8, 2u8, 154u8, 249u8, 2u8, 155u8, 249u8, 2u8, 156u8, 249u8, 2u8, 157u8,
+                    249u8, 2u8, 158u8, 249u8, 2u8, 159u8, 249u8, 2u8, 160u8, 249u8, 2u8, 161u8,
+                    249u8, 2u8, 162u8, 249u8, 2u8, 163u8, 249u8, 2u8, 164u8, 249u8, 2u8, 165u8,
+                    249u8, 2u8, 166u8, 249u8, 2u8, 167u8, 249u8, 2u8, 168u8, 249u8, 2u8, 169u8,
+                    249u8, 2u8, 170u8, 249u8, 2u8, 171u8, 249u8, 2u8, 172u8, 249u8, 2u8, 173u8,
+                    249u8, 2u8, 174u8, 249u8, 2u8, 175u8, 249u8, 2u8, 176u8, 249u8, 2u8, 177u8,
+                    249u8, 2u8, 178u8, 249u8, 2u8, 179u8, 249u8, 2u8, 180u8, 249u8, 2u8, 181u8,
+                    249u8, 2u8, 182u8, 249u8, 2u8, 183u8, 249u8, 2u8, 184u8, 249u8, 2u8, 185u8,
+                    249u8, 2u8, 186u8, 249u8, 2u8, 187u8, 249u8, 2u8, 188u8, 249u8, 2u8, 189u8,
+                    249u8, 2u8, 190u8, 249u8, 2u8, 191u8, 249u8, 2u8, 192u8, 249u8, 2u8, 193u8,
+                    249u8, 2u8, 194u8, 249u8, 2u8, 195u8, 249u8, 2u8, 196u8, 249u8, 2u8, 197u8,
+                    249u8, 2u8, 198u8, 249u8, 2u8, 199u8, 249u8, 2u8, 200u8, 249u8, 2u8, 201u8,
+                    249u8, 2u8, 202u8, 249u8, 2u8, 203u8, 249u8, 2u8, 204u8, 249u8, 2u8, 205u8,
+                    249u8, 2u8, 206u8, 249u8, 2u8, 207u8, 249u8, 2u8, 208u8, 249u8, 2u8, 209u8,
+                    249u8, 2u8, 210u8, 249u8, 2u8, 211u8, 249u8, 2u8, 212u8, 249u8, 2u8, 213u8,
+                    249u8, 2u8, 214u8, 249u8, 2u8, 215u8, 249u8, 2u8, 216u8, 249u8, 2u8, 217u8,
+                    249u8, 2u8, 218u8, 249u8, 2u8, 219u8, 249u8, 2u8, 220u8, 249u8, 2u8, 221u8,
+                    249u8, 2u8, 222u8, 249u8, 2u8, 223u8, 249u8, 2u8, 224u8, 249u8, 2u8, 225u8,
+                    249u8, 2u8, 226u8, 249u8, 2u8, 227u8, 249u8, 2u8, 228u8, 249u8, 2u8, 229u8,
+                    249u8, 2u8, 230u8, 249u8, 2u8, 231u8, 249u8, 2u8, 232u8, 249u8, 2u8, 233u8,
+                    249u8, 2u8, 234u8, 249u8, 2u8, 235u8, 249u8, 2u8, 236u8, 249u8, 2u8, 237u8,
+                    249u8, 2u8, 238u8, 249u8, 2u8, 239u8, 249u8, 2u8, 240u8, 249u8, 2u8, 241u8,
+                    249u8, 2u8, 242u8, 249u8, 2u8, 243u8, 249u8, 2u8, 244u8, 249u8, 2u8, 245u8,
+                    249u8, 2u8, 246u8, 249u8, 2u8, 247u8, 249u8, 2u8, 248u8, 249u8, 2u8, 249u8,
+                    249u8, 2u8, 250u8, 249u8, 2u8, 251u8, 249u8, 2u8, 252u8, 249u8, 2u8, 253u8,
+                    249u8, 2u8, 254u8, 249u8, 2u8, 255u8, 249u8, 2u8, 0u8, 250u8, 2u8, 1u8, 250u8,
+                    2u8, 2u8, 250u8, 2u8, 3u8, 250u8, 2u8, 4u8, 250u8, 2u8, 5u8, 250u8, 2u8, 6u8,
+                    250u8, 2u8, 7u8, 250u8, 2u8, 8u8, 250u8, 2u8, 9u8, 250u8, 2u8, 10u8, 250u8,
+                    2u8, 11u8, 250u8, 2u8, 12u8, 250u8, 2u8, 13u8, 250u8, 2u8, 14u8, 250u8, 2u8,
+                    15u8, 250u8, 2u8, 16u8, 250u8, 2u8, 17u8, 250u8, 2u8, 18u8, 250u8, 2u8, 19u8,
+                    250u8, 2u8, 20u8, 250u8, 2u8, 21u8, 250u8, 2u8, 22u8, 250u8, 2u8, 23u8, 250u8,
+                    2u8, 24u8, 250u8, 2u8, 25u8, 250u8, 2u8, 26u8, 250u8, 2u8, 27u8, 250u8, 2u8,
+                    28u8, 250u8, 2u8, 29u8, 250u8, 2u8,
+                ])
+            },
+            unsafe {
+                ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                    167u8, 24u8, 0u8, 0u8, 0u8, 0u8, 2u8, 0u8, 8u8, 0u8, 9u8, 0u8, 10u8, 0u8, 11u8,
+                    0u8, 12u8, 0u8, 14u8, 0u8, 15u8, 0u8, 16u8, 0u8, 19u8, 0u8, 22u8, 0u8, 24u8,
+                    0u8, 25u8, 0u8, 27u8, 0u8, 28u8, 0u8, 30u8, 0u8, 33u8, 0u8, 36u8, 0u8, 37u8,
+                    0u8, 40u8, 0u8, 42u8, 0u8, 45u8, 0u8, 50u8, 0u8, 52u8, 0u8, 55u8, 0u8, 58u8,
+                    0u8, 61u8, 0u8, 63u8, 0u8, 65u8, 0u8, 68u8, 0u8, 71u8, 0u8, 72u8, 0u8, 74u8,
+                    0u8, 76u8, 0u8, 79u8, 0u8, 82u8, 0u8, 85u8, 0u8, 88u8, 0u8, 90u8, 0u8, 92u8,
+                    0u8, 94u8, 0u8, 96u8, 0u8, 98u8, 0u8, 100u8, 0u8, 103u8, 0u8, 106u8, 0u8,
+                    107u8, 0u8, 110u8, 0u8, 112u8, 0u8, 115u8, 0u8, 118u8, 0u8, 119u8, 0u8, 121u8,
+                    0u8, 124u8, 0u8, 126u8, 0u8, 129u8, 0u8, 130u8, 0u8, 133u8, 0u8, 136u8, 0u8,
+                    138u8, 0u8, 139u8, 0u8, 142u8, 0u8, 144u8, 0u8, 147u8, 0u8, 150u8, 0u8, 153u8,
+                    0u8, 156u8, 0u8, 159u8, 0u8, 161u8, 0u8, 163u8, 0u8, 165u8, 0u8, 168u8, 0u8,
+                    169u8, 0u8, 170u8, 0u8, 172u8, 0u8, 175u8, 0u8, 178u8, 0u8, 180u8, 0u8, 183u8,
+                    0u8, 186u8, 0u8, 189u8, 0u8, 190u8, 0u8, 193u8, 0u8, 194u8, 0u8, 195u8, 0u8,
+                    197u8, 0u8, 198u8, 0u8, 200u8, 0u8, 201u8, 0u8, 204u8, 0u8, 207u8, 0u8, 210u8,
+                    0u8, 212u8, 0u8, 214u8, 0u8, 216u8, 0u8, 218u8, 0u8, 220u8, 0u8, 222u8, 0u8,
+                    224u8, 0u8, 226u8, 0u8, 228u8, 0u8, 230u8, 0u8, 232u8, 0u8, 234u8, 0u8, 236u8,
+                    0u8, 238u8, 0u8, 241u8, 0u8, 244u8, 0u8, 246u8, 0u8, 248u8, 0u8, 250u8, 0u8,
+                    252u8, 0u8, 254u8, 0u8, 0u8, 1u8, 5u8, 1u8, 7u8, 1u8, 9u8, 1u8, 10u8, 1u8,
+                    11u8, 1u8, 12u8, 1u8, 15u8, 1u8, 18u8, 1u8, 20u8, 1u8, 22u8, 1u8, 25u8, 1u8,
+                    28u8, 1u8, 29u8, 1u8, 32u8, 1u8, 35u8, 1u8, 38u8, 1u8, 41u8, 1u8, 44u8, 1u8,
+                    47u8, 1u8, 50u8, 1u8, 53u8, 1u8, 54u8, 1u8, 57u8, 1u8, 60u8, 1u8, 63u8, 1u8,
+                    65u8, 1u8, 69u8, 1u8, 72u8, 1u8, 75u8, 1u8, 76u8, 1u8, 77u8, 1u8, 80u8, 1u8,
+                    83u8, 1u8, 84u8, 1u8, 85u8, 1u8, 88u8, 1u8, 91u8, 1u8, 94u8, 1u8, 95u8, 1u8,
+                    99u8, 1u8, 102u8, 1u8, 105u8, 1u8, 109u8, 1u8, 112u8, 1u8, 115u8, 1u8, 118u8,
+                    1u8, 119u8, 1u8, 120u8, 1u8, 123u8, 1u8, 125u8, 1u8, 126u8, 1u8, 129u8, 1u8,
+                    131u8, 1u8, 134u8, 1u8, 137u8, 1u8, 139u8, 1u8, 142u8, 1u8, 145u8, 1u8, 148u8,
+                    1u8, 150u8, 1u8, 152u8, 1u8, 155u8, 1u8, 156u8, 1u8, 158u8, 1u8, 159u8, 1u8,
+                    160u8, 1u8, 161u8, 1u8, 162u8, 1u8, 164u8, 1u8, 165u8, 1u8, 166u8, 1u8, 167u8,
+                    1u8, 168u8, 1u8, 169u8, 1u8, 170u8, 1u8, 171u8, 1u8, 172u8, 1u8, 174u8, 1u8,
+                    175u8, 1u8, 177u8, 1u8, 180u8, 1u8, 182u8, 1u8, 183u8, 1u8, 184u8, 1u8, 186u8,
+                    1u8, 189u8, 1u8, 192u8, 1u8, 194u8, 1u8, 196u8, 1u8, 197u8, 1u8, 199u8, 1u8,
+                    200u8, 1u8, 202u8, 1u8, 204u8, 1u8, 206u8, 1u8, 208u8, 1u8, 212u8, 1u8, 214u8,
+                    1u8, 216u8, 1u8, 218u8, 1u8, 220u8, 1u8, 222u8, 1u8, 224u8, 1u8, 226u8, 1u8,
+                    228u8, 1u8, 230u8, 1u8, 232u8, 1u8, 234u8, 1u8, 236u8, 1u8, 238u8, 1u8, 240u8,
+                    1u8, 242u8, 1u8, 244u8, 1u8, 246u8, 1u8, 248u8, 1u8, 250u8, 1u8, 252u8, 1u8,
+                    255u8, 1u8, 0u8, 2u8, 2u8, 2u8, 4u8, 2u8, 7u8, 2u8, 8u8, 2u8, 10u8, 2u8, 13u8,
+                    2u8, 14u8, 2u8, 15u8, 2u8, 16u8, 2u8, 18u8, 2u8, 19u8, 2u8, 20u8, 2u8, 21u8,
+                    2u8, 22u8, 2u8, 23u8, 2u8, 26u8, 2u8, 27u8, 2u8, 28u8, 2u8, 30u8, 2u8, 31u8,
+                    2u8, 32u8, 2u8, 33u8, 2u8, 34u8, 2u8, 36u8, 2u8, 37u8, 2u8, 38u8, 2u8, 39u8,
+                    2u8, 40u8, 2u8, 42u8, 2u8, 43u8, 2u8, 46u8, 2u8, 49u8, 2u8, 52u8, 2u8, 55u8,
+                    2u8, 56u8, 2u8, 58u8, 2u8, 59u8, 2u8, 60u8, 2u8, 61u8, 2u8, 62u8, 2u8, 65u8,
+                    2u8, 66u8, 2u8, 68u8, 2u8, 70u8, 2u8, 73u8, 2u8, 74u8, 2u8, 76u8, 2u8, 78u8,
+                    2u8, 80u8, 2u8, 81u8, 2u8, 82u8, 2u8, 84u8, 2u8, 86u8, 2u8, 87u8, 2u8, 88u8,
+                    2u8, 89u8, 2u8, 92u8, 2u8, 95u8, 2u8, 97u8, 2u8, 99u8, 2u8, 100u8, 2u8, 101u8,
+                    2u8, 103u8, 2u8, 106u8, 2u8, 109u8, 2u8, 110u8, 2u8, 111u8, 2u8, 112u8, 2u8,
+                    113u8, 2u8, 116u8, 2u8, 117u8, 2u8, 119u8, 2u8, 120u8, 2u8, 121u8, 2u8, 123u8,
+                    2u8, 124u8, 2u8, 126u8, 2u8, 127u8, 2u8, 128u8, 2u8, 129u8, 2u8, 131u8, 2u8,
+                    132u8, 2u8, 133u8, 2u8, 134u8, 2u8, 135u8, 2u8, 137u8, 2u8, 138u8, 2u8, 140u8,
+                    2u8, 141u8, 2u8, 143u8, 2u8, 144u8, 2u8, 145u8, 2u8, 147u8, 2u8, 148u8, 2u8,
+                    149u8, 2u8, 151u8, 2u8, 154u8, 2u8, 156u8, 2u8, 158u8, 2u8, 160u8, 2u8, 161u8,
+                    2u8, 163u8, 2u8, 164u8, 2u8, 165u8, 2u8, 168u8, 2u8, 169u8, 2u8, 171u8, 2u8,
+                    172u8, 2u8, 175u8, 2u8, 178u8, 2u8, 180u8, 2u8, 183u8, 2u8, 186u8, 2u8, 187u8,
+                    2u8, 188u8, 2u8, 189u8, 2u8, 192u8, 2u8, 194u8, 2u8, 195u8, 2u8, 198u8, 2u8,
+                    201u8, 2u8, 203u8, 2u8, 205u8, 2u8, 208u8, 2u8, 211u8, 2u8, 212u8, 2u8, 213u8,
+                    2u8, 219u8, 2u8, 224u8, 2u8, 228u8, 2u8, 232u8, 2u8, 235u8, 2u8, 238u8, 2u8,
+                    241u8, 2u8, 243u8, 2u8, 247u8, 2u8, 250u8, 2u8, 254u8, 2u8, 2u8, 3u8, 5u8, 3u8,
+                    9u8, 3u8, 12u8, 3u8, 16u8, 3u8, 19u8, 3u8, 23u8, 3u8, 26u8, 3u8, 30u8, 3u8,
+                    33u8, 3u8, 36u8, 3u8, 39u8, 3u8, 44u8, 3u8, 45u8, 3u8, 46u8, 3u8, 49u8, 3u8,
+                    52u8, 3u8, 55u8, 3u8, 56u8, 3u8, 57u8, 3u8, 61u8, 3u8, 64u8, 3u8, 65u8, 3u8,
+                    69u8, 3u8, 73u8, 3u8, 76u8, 3u8, 80u8, 3u8, 83u8, 3u8, 87u8, 3u8, 89u8, 3u8,
+                    91u8, 3u8, 94u8, 3u8, 98u8, 3u8, 99u8, 3u8, 101u8, 3u8, 103u8, 3u8, 105u8, 3u8,
+                    107u8, 3u8, 108u8, 3u8, 110u8, 3u8, 113u8, 3u8, 116u8, 3u8, 117u8, 3u8, 119u8,
+                    3u8, 120u8, 3u8, 122u8, 3u8, 124u8, 3u8, 127u8, 3u8, 128u8, 3u8, 129u8, 3u8,
+                    130u8, 3u8, 133u8, 3u8, 136u8, 3u8, 139u8, 3u8, 142u8, 3u8, 143u8, 3u8, 144u8,
+                    3u8, 146u8, 3u8, 147u8, 3u8, 148u8, 3u8, 149u8, 3u8, 150u8, 3u8, 151u8, 3u8,
+                    152u8, 3u8, 155u8, 3u8, 156u8, 3u8, 158u8, 3u8, 159u8, 3u8, 161u8, 3u8, 162u8,
+                    3u8, 163u8, 3u8, 164u8, 3u8, 165u8, 3u8, 166u8, 3u8, 170u8, 3u8, 171u8, 3u8,
+                    173u8, 3u8, 175u8, 3u8, 177u8, 3u8, 179u8, 3u8, 181u8, 3u8, 183u8, 3u8, 185u8,
+                    3u8, 187u8, 3u8, 189u8, 3u8, 191u8, 3u8, 192u8, 3u8, 194u8, 3u8, 196u8, 3u8,
+                    197u8, 3u8, 199u8, 3u8, 201u8, 3u8, 202u8, 3u8, 203u8, 3u8, 204u8, 3u8, 205u8,
+                    3u8, 206u8, 3u8, 208u8, 3u8, 210u8, 3u8, 212u8, 3u8, 213u8, 3u8, 215u8, 3u8,
+                    224u8, 3u8, 236u8, 3u8, 237u8, 3u8, 239u8, 3u8, 241u8, 3u8, 243u8, 3u8, 245u8,
+                    3u8, 248u8, 3u8, 252u8, 3u8, 255u8, 3u8, 3u8, 4u8, 4u8, 4u8, 8u8, 4u8, 12u8,
+                    4u8, 16u8, 4u8, 20u8, 4u8, 21u8, 4u8, 23u8, 4u8, 25u8, 4u8, 27u8, 4u8, 29u8,
+                    4u8, 31u8, 4u8, 33u8, 4u8, 35u8, 4u8, 37u8, 4u8, 39u8, 4u8, 41u8, 4u8, 43u8,
+                    4u8, 45u8, 4u8, 47u8, 4u8, 49u8, 4u8, 51u8, 4u8, 52u8, 4u8, 53u8, 4u8, 54u8,
+                    4u8, 55u8, 4u8, 57u8, 4u8, 63u8, 4u8, 64u8, 4u8, 66u8, 4u8, 67u8, 4u8, 69u8,
+                    4u8, 71u8, 4u8, 74u8, 4u8, 77u8, 4u8, 80u8, 4u8, 84u8, 4u8, 90u8, 4u8, 94u8,
+                    4u8, 98u8, 4u8, 102u8, 4u8, 106u8, 4u8, 110u8, 4u8, 114u8, 4u8, 118u8, 4u8,
+                    122u8, 4u8, 126u8, 4u8, 130u8, 4u8, 134u8, 4u8, 138u8, 4u8, 142u8, 4u8, 146u8,
+                    4u8, 148u8, 4u8, 152u8, 4u8, 154u8, 4u8, 156u8, 4u8, 160u8, 4u8, 164u8, 4u8,
+                    168u8, 4u8, 172u8, 4u8, 174u8, 4u8, 178u8, 4u8, 182u8, 4u8, 183u8, 4u8, 184u8,
+                    4u8, 186u8, 4u8, 188u8, 4u8, 192u8, 4u8, 196u8, 4u8, 200u8, 4u8, 204u8, 4u8,
+                    208u8, 4u8, 210u8, 4u8, 214u8, 4u8, 216u8, 4u8, 220u8, 4u8, 222u8, 4u8, 223u8,
+                    4u8, 224u8, 4u8, 226u8, 4u8, 230u8, 4u8, 232u8, 4u8, 236u8, 4u8, 240u8, 4u8,
+                    241u8, 4u8, 242u8, 4u8, 244u8, 4u8, 246u8, 4u8, 248u8, 4u8, 249u8, 4u8, 251u8,
+                    4u8, 252u8, 4u8, 254u8, 4u8, 0u8, 5u8, 4u8, 5u8, 8u8, 5u8, 11u8, 5u8, 12u8,
+                    5u8, 13u8, 5u8, 14u8, 5u8, 15u8, 5u8, 17u8, 5u8, 19u8, 5u8, 21u8, 5u8, 23u8,
+                    5u8, 27u8, 5u8, 31u8, 5u8, 33u8, 5u8, 37u8, 5u8, 39u8, 5u8, 43u8, 5u8, 47u8,
+                    5u8, 51u8, 5u8, 55u8, 5u8, 59u8, 5u8, 63u8, 5u8, 64u8, 5u8, 65u8, 5u8, 67u8,
+                    5u8, 69u8, 5u8, 71u8, 5u8, 73u8, 5u8, 74u8, 5u8, 75u8, 5u8, 76u8, 5u8, 80u8,
+                    5u8, 84u8, 5u8, 88u8, 5u8, 92u8, 5u8, 94u8, 5u8, 100u8, 5u8, 106u8, 5u8, 108u8,
+                    5u8, 110u8, 5u8, 114u8, 5u8, 118u8, 5u8, 124u8, 5u8, 130u8, 5u8, 136u8, 5u8,
+                    138u8, 5u8, 140u8, 5u8, 142u8, 5u8, 144u8, 5u8, 146u8, 5u8, 148u8, 5u8, 150u8,
+                    5u8, 152u8, 5u8, 154u8, 5u8, 156u8, 5u8, 158u8, 5u8, 160u8, 5u8, 162u8, 5u8,
+                    164u8, 5u8, 166u8, 5u8, 168u8, 5u8, 170u8, 5u8, 172u8, 5u8, 176u8, 5u8, 178u8,
+                    5u8, 179u8, 5u8, 185u8, 5u8, 191u8, 5u8, 200u8, 5u8, 206u8, 5u8, 212u8, 5u8,
+                    218u8, 5u8, 224u8, 5u8, 233u8, 5u8, 242u8, 5u8, 251u8, 5u8, 253u8, 5u8, 255u8,
+                    5u8, 1u8, 6u8, 3u8, 6u8, 9u8, 6u8, 10u8, 6u8, 12u8, 6u8, 13u8, 6u8, 17u8, 6u8,
+                    23u8, 6u8, 25u8, 6u8, 31u8, 6u8, 37u8, 6u8, 38u8, 6u8, 39u8, 6u8, 40u8, 6u8,
+                    42u8, 6u8, 45u8, 6u8, 51u8, 6u8, 57u8, 6u8, 63u8, 6u8, 69u8, 6u8, 75u8, 6u8,
+                    81u8, 6u8, 87u8, 6u8, 93u8, 6u8, 95u8, 6u8, 98u8, 6u8, 101u8, 6u8, 102u8, 6u8,
+                    103u8, 6u8, 104u8, 6u8, 108u8, 6u8, 110u8, 6u8, 111u8, 6u8, 117u8, 6u8, 123u8,
+                    6u8, 129u8, 6u8, 135u8, 6u8, 144u8, 6u8, 153u8, 6u8, 162u8, 6u8, 164u8, 6u8,
+                    167u8, 6u8, 170u8, 6u8, 173u8, 6u8, 176u8, 6u8, 177u8, 6u8, 180u8, 6u8, 183u8,
+                    6u8, 186u8, 6u8, 189u8, 6u8, 192u8, 6u8, 196u8, 6u8, 197u8, 6u8, 203u8, 6u8,
+                    204u8, 6u8, 206u8, 6u8, 207u8, 6u8, 208u8, 6u8, 210u8, 6u8, 216u8, 6u8, 219u8,
+                    6u8, 222u8, 6u8, 225u8, 6u8, 228u8, 6u8, 234u8, 6u8, 240u8, 6u8, 246u8, 6u8,
+                    248u8, 6u8, 251u8, 6u8, 252u8, 6u8, 255u8, 6u8, 2u8, 7u8, 5u8, 7u8, 11u8, 7u8,
+                    17u8, 7u8, 20u8, 7u8, 23u8, 7u8, 26u8, 7u8, 32u8, 7u8, 38u8, 7u8, 41u8, 7u8,
+                    47u8, 7u8, 50u8, 7u8, 56u8, 7u8, 60u8, 7u8, 61u8, 7u8, 64u8, 7u8, 70u8, 7u8,
+                    76u8, 7u8, 81u8, 7u8, 86u8, 7u8, 91u8, 7u8, 96u8, 7u8, 102u8, 7u8, 107u8, 7u8,
+                    113u8, 7u8, 119u8, 7u8, 125u8, 7u8, 131u8, 7u8, 137u8, 7u8, 138u8, 7u8, 142u8,
+                    7u8, 143u8, 7u8, 146u8, 7u8, 149u8, 7u8, 152u8, 7u8, 155u8, 7u8, 158u8, 7u8,
+                    164u8, 7u8, 170u8, 7u8, 173u8, 7u8, 176u8, 7u8, 179u8, 7u8, 182u8, 7u8, 185u8,
+                    7u8, 188u8, 7u8, 194u8, 7u8, 195u8, 7u8, 198u8, 7u8, 201u8, 7u8, 204u8, 7u8,
+                    208u8, 7u8, 209u8, 7u8, 212u8, 7u8, 218u8, 7u8, 221u8, 7u8, 227u8, 7u8, 233u8,
+                    7u8, 239u8, 7u8, 245u8, 7u8, 251u8, 7u8, 1u8, 8u8, 4u8, 8u8, 5u8, 8u8, 8u8,
+                    8u8, 11u8, 8u8, 14u8, 8u8, 17u8, 8u8, 23u8, 8u8, 26u8, 8u8, 29u8, 8u8, 32u8,
+                    8u8, 35u8, 8u8, 41u8, 8u8, 44u8, 8u8, 53u8, 8u8, 58u8, 8u8, 61u8, 8u8, 62u8,
+                    8u8, 68u8, 8u8, 77u8, 8u8, 86u8, 8u8, 87u8, 8u8, 96u8, 8u8, 102u8, 8u8, 111u8,
+                    8u8, 117u8, 8u8, 123u8, 8u8, 129u8, 8u8, 130u8, 8u8, 133u8, 8u8, 139u8, 8u8,
+                    142u8, 8u8, 145u8, 8u8, 151u8, 8u8, 154u8, 8u8, 157u8, 8u8, 160u8, 8u8, 163u8,
+                    8u8, 166u8, 8u8, 169u8, 8u8, 172u8, 8u8, 175u8, 8u8, 180u8, 8u8, 186u8, 8u8,
+                    189u8, 8u8, 191u8, 8u8, 192u8, 8u8, 195u8, 8u8, 198u8, 8u8, 201u8, 8u8, 204u8,
+                    8u8, 207u8, 8u8, 210u8, 8u8, 213u8, 8u8, 218u8, 8u8, 221u8, 8u8, 224u8, 8u8,
+                    227u8, 8u8, 230u8, 8u8, 233u8, 8u8, 236u8, 8u8, 238u8, 8u8, 239u8, 8u8, 245u8,
+                    8u8, 251u8, 8u8, 4u8, 9u8, 16u8, 9u8, 28u8, 9u8, 31u8, 9u8, 37u8, 9u8, 43u8,
+                    9u8, 49u8, 9u8, 55u8, 9u8, 57u8, 9u8, 60u8, 9u8, 69u8, 9u8, 78u8, 9u8, 84u8,
+                    9u8, 87u8, 9u8, 90u8, 9u8, 96u8, 9u8, 100u8, 9u8, 101u8, 9u8, 107u8, 9u8,
+                    113u8, 9u8, 128u8, 9u8, 130u8, 9u8, 133u8, 9u8, 134u8, 9u8, 140u8, 9u8, 143u8,
+                    9u8, 149u8, 9u8, 158u8, 9u8, 164u8, 9u8, 170u8, 9u8, 176u8, 9u8, 181u8, 9u8,
+                    184u8, 9u8, 185u8, 9u8, 187u8, 9u8, 188u8, 9u8, 194u8, 9u8, 200u8, 9u8, 206u8,
+                    9u8, 212u8, 9u8, 218u8, 9u8, 224u8, 9u8, 230u8, 9u8, 236u8, 9u8, 242u8, 9u8,
+                    248u8, 9u8, 254u8, 9u8, 4u8, 10u8, 10u8, 10u8, 16u8, 10u8, 22u8, 10u8, 28u8,
+                    10u8, 34u8, 10u8, 40u8, 10u8, 46u8, 10u8, 52u8, 10u8, 61u8, 10u8, 70u8, 10u8,
+                    79u8, 10u8, 88u8, 10u8, 97u8, 10u8, 103u8, 10u8, 109u8, 10u8, 115u8, 10u8,
+                    121u8, 10u8, 127u8, 10u8, 136u8, 10u8, 142u8, 10u8, 148u8, 10u8, 154u8, 10u8,
+                    160u8, 10u8, 166u8, 10u8, 172u8, 10u8, 181u8, 10u8, 190u8, 10u8, 196u8, 10u8,
+                    202u8, 10u8, 208u8, 10u8, 214u8, 10u8, 220u8, 10u8, 226u8, 10u8, 232u8, 10u8,
+                    238u8, 10u8, 244u8, 10u8, 250u8, 10u8, 0u8, 11u8, 6u8, 11u8, 12u8, 11u8, 18u8,
+                    11u8, 24u8, 11u8, 30u8, 11u8, 36u8, 11u8, 42u8, 11u8, 48u8, 11u8, 54u8, 11u8,
+                    60u8, 11u8, 66u8, 11u8, 72u8, 11u8, 78u8, 11u8, 84u8, 11u8, 90u8, 11u8, 96u8,
+                    11u8, 102u8, 11u8, 108u8, 11u8, 114u8, 11u8, 120u8, 11u8, 126u8, 11u8, 132u8,
+                    11u8, 138u8, 11u8, 144u8, 11u8, 150u8, 11u8, 156u8, 11u8, 162u8, 11u8, 171u8,
+                    11u8, 177u8, 11u8, 183u8, 11u8, 192u8, 11u8, 198u8, 11u8, 201u8, 11u8, 207u8,
+                    11u8, 210u8, 11u8, 216u8, 11u8, 222u8, 11u8, 228u8, 11u8, 234u8, 11u8, 240u8,
+                    11u8, 246u8, 11u8, 252u8, 11u8, 2u8, 12u8, 8u8, 12u8, 14u8, 12u8, 23u8, 12u8,
+                    32u8, 12u8, 38u8, 12u8, 44u8, 12u8, 50u8, 12u8, 59u8, 12u8, 65u8, 12u8, 71u8,
+                    12u8, 77u8, 12u8, 83u8, 12u8, 92u8, 12u8, 101u8, 12u8, 110u8, 12u8, 116u8,
+                    12u8, 122u8, 12u8, 128u8, 12u8, 137u8, 12u8, 143u8, 12u8, 152u8, 12u8, 158u8,
+                    12u8, 164u8, 12u8, 170u8, 12u8, 176u8, 12u8, 185u8, 12u8, 191u8, 12u8, 197u8,
+                    12u8, 203u8, 12u8, 209u8, 12u8, 215u8, 12u8, 221u8, 12u8, 227u8, 12u8, 233u8,
+                    12u8, 239u8, 12u8, 245u8, 12u8, 251u8, 12u8, 1u8, 13u8, 7u8, 13u8, 13u8, 13u8,
+                    22u8, 13u8, 25u8, 13u8, 31u8, 13u8, 37u8, 13u8, 40u8, 13u8, 46u8, 13u8, 52u8,
+                    13u8, 55u8, 13u8, 58u8, 13u8, 64u8, 13u8, 70u8, 13u8, 76u8, 13u8, 82u8, 13u8,
+                    88u8, 13u8, 94u8, 13u8, 100u8, 13u8, 103u8, 13u8, 106u8, 13u8, 112u8, 13u8,
+                    115u8, 13u8, 121u8, 13u8, 124u8, 13u8, 127u8, 13u8, 130u8, 13u8, 133u8, 13u8,
+                    136u8, 13u8, 139u8, 13u8, 142u8, 13u8, 148u8, 13u8, 157u8, 13u8, 163u8, 13u8,
+                    169u8, 13u8, 175u8, 13u8, 181u8, 13u8, 187u8, 13u8, 193u8, 13u8, 199u8, 13u8,
+                    208u8, 13u8, 214u8, 13u8, 220u8, 13u8, 229u8, 13u8, 235u8, 13u8, 244u8, 13u8,
+                    253u8, 13u8, 6u8, 14u8, 15u8, 14u8, 24u8, 14u8, 33u8, 14u8, 39u8, 14u8, 45u8,
+                    14u8, 51u8, 14u8, 57u8, 14u8, 63u8, 14u8, 69u8, 14u8, 75u8, 14u8, 84u8, 14u8,
+                    90u8, 14u8, 96u8, 14u8, 102u8, 14u8, 108u8, 14u8, 114u8, 14u8, 120u8, 14u8,
+                    126u8, 14u8, 132u8, 14u8, 138u8, 14u8, 144u8, 14u8, 150u8, 14u8, 156u8, 14u8,
+                    159u8, 14u8, 165u8, 14u8, 174u8, 14u8, 180u8, 14u8, 186u8, 14u8, 189u8, 14u8,
+                    195u8, 14u8, 201u8, 14u8, 207u8, 14u8, 213u8, 14u8, 219u8, 14u8, 225u8, 14u8,
+                    231u8, 14u8, 237u8, 14u8, 240u8, 14u8, 246u8, 14u8, 252u8, 14u8, 2u8, 15u8,
+                    8u8, 15u8, 14u8, 15u8, 20u8, 15u8, 21u8, 15u8, 23u8, 15u8, 25u8, 15u8, 27u8,
+                    15u8, 29u8, 15u8, 30u8, 15u8, 31u8, 15u8, 32u8, 15u8, 33u8, 15u8, 35u8, 15u8,
+                    36u8, 15u8, 39u8, 15u8, 40u8, 15u8, 41u8, 15u8, 42u8, 15u8, 43u8, 15u8, 44u8,
+                    15u8, 47u8, 15u8, 49u8, 15u8, 50u8, 15u8, 51u8, 15u8, 52u8, 15u8, 53u8, 15u8,
+                    56u8, 15u8, 58u8, 15u8, 59u8, 15u8, 60u8, 15u8, 61u8, 15u8, 63u8, 15u8, 65u8,
+                    15u8, 68u8, 15u8, 69u8, 15u8, 70u8, 15u8, 71u8, 15u8, 72u8, 15u8, 73u8, 15u8,
+                    74u8, 15u8, 75u8, 15u8, 76u8, 15u8, 77u8, 15u8, 78u8, 15u8, 79u8, 15u8, 80u8,
+                    15u8, 83u8, 15u8, 84u8, 15u8, 86u8, 15u8, 89u8, 15u8, 90u8, 15u8, 91u8, 15u8,
+                    93u8, 15u8, 95u8, 15u8, 96u8, 15u8, 98u8, 15u8, 103u8, 15u8, 108u8, 15u8,
+                    112u8, 15u8, 116u8, 15u8, 121u8, 15u8, 126u8, 15u8, 131u8, 15u8, 136u8, 15u8,
+                    141u8, 15u8, 146u8, 15u8, 151u8, 15u8, 156u8, 15u8, 161u8, 15u8, 166u8, 15u8,
+                    168u8, 15u8, 174u8, 15u8, 179u8, 15u8, 185u8, 15u8, 191u8, 15u8, 192u8, 15u8,
+                    194u8, 15u8, 195u8, 15u8, 198u8, 15u8, 199u8, 15u8, 202u8, 15u8, 205u8, 15u8,
+                    209u8, 15u8, 213u8, 15u8, 218u8, 15u8, 223u8, 15u8, 226u8, 15u8, 229u8, 15u8,
+                    234u8, 15u8, 239u8, 15u8, 242u8, 15u8, 245u8, 15u8, 250u8, 15u8, 255u8, 15u8,
+                    0u8, 16u8, 1u8, 16u8, 3u8, 16u8, 8u8, 16u8, 11u8, 16u8, 14u8, 16u8, 18u8, 16u8,
+                    22u8, 16u8, 27u8, 16u8, 32u8, 16u8, 37u8, 16u8, 42u8, 16u8, 47u8, 16u8, 52u8,
+                    16u8, 57u8, 16u8, 62u8, 16u8, 67u8, 16u8, 72u8, 16u8, 74u8, 16u8, 77u8, 16u8,
+                    81u8, 16u8, 85u8, 16u8, 86u8, 16u8, 87u8, 16u8, 88u8, 16u8, 91u8, 16u8, 96u8,
+                    16u8, 101u8, 16u8, 104u8, 16u8, 107u8, 16u8, 112u8, 16u8, 117u8, 16u8, 120u8,
+                    16u8, 123u8, 16u8, 128u8, 16u8, 133u8, 16u8, 136u8, 16u8, 139u8, 16u8, 144u8,
+                    16u8, 149u8, 16u8, 153u8, 16u8, 155u8, 16u8, 157u8, 16u8, 159u8, 16u8, 160u8,
+                    16u8, 165u8, 16u8, 170u8, 16u8, 175u8, 16u8, 180u8, 16u8, 185u8, 16u8, 190u8,
+                    16u8, 193u8, 16u8, 196u8, 16u8, 201u8, 16u8, 206u8, 16u8, 211u8, 16u8, 216u8,
+                    16u8, 221u8, 16u8, 226u8, 16u8, 228u8, 16u8, 229u8, 16u8, 234u8, 16u8, 239u8,
+                    16u8, 243u8, 16u8, 247u8, 16u8, 252u8, 16u8, 1u8, 17u8, 6u8, 17u8, 11u8, 17u8,
+                    16u8, 17u8, 21u8, 17u8, 24u8, 17u8, 27u8, 17u8, 32u8, 17u8, 37u8, 17u8, 38u8,
+                    17u8, 43u8, 17u8, 48u8, 17u8, 53u8, 17u8, 58u8, 17u8, 63u8, 17u8, 68u8, 17u8,
+                    71u8, 17u8, 76u8, 17u8, 81u8, 17u8, 86u8, 17u8, 91u8, 17u8, 96u8, 17u8, 101u8,
+                    17u8, 106u8, 17u8, 111u8, 17u8, 116u8, 17u8, 121u8, 17u8, 126u8, 17u8, 131u8,
+                    17u8, 136u8, 17u8, 141u8, 17u8, 146u8, 17u8, 151u8, 17u8, 156u8, 17u8, 161u8,
+                    17u8, 166u8, 17u8, 171u8, 17u8, 176u8, 17u8, 181u8, 17u8, 186u8, 17u8, 191u8,
+                    17u8, 196u8, 17u8, 201u8, 17u8, 206u8, 17u8, 211u8, 17u8, 215u8, 17u8, 221u8,
+                    17u8, 225u8, 17u8, 231u8, 17u8, 236u8, 17u8, 241u8, 17u8, 246u8, 17u8, 251u8,
+                    17u8, 0u8, 18u8, 5u8, 18u8, 10u8, 18u8, 15u8, 18u8, 20u8, 18u8, 25u8, 18u8,
+                    30u8, 18u8, 35u8, 18u8, 40u8, 18u8, 45u8, 18u8, 48u8, 18u8, 51u8, 18u8, 56u8,
+                    18u8, 61u8, 18u8, 66u8, 18u8, 71u8, 18u8, 76u8, 18u8, 81u8, 18u8, 86u8, 18u8,
+                    91u8, 18u8, 96u8, 18u8, 101u8, 18u8, 106u8, 18u8, 111u8, 18u8, 114u8, 18u8,
+                    115u8, 18u8, 120u8, 18u8, 125u8, 18u8, 130u8, 18u8, 135u8, 18u8, 140u8, 18u8,
+                    145u8, 18u8, 148u8, 18u8, 149u8, 18u8, 150u8, 18u8, 156u8, 18u8, 160u8, 18u8,
+                    166u8, 18u8, 170u8, 18u8, 176u8, 18u8, 180u8, 18u8, 186u8, 18u8, 192u8, 18u8,
+                    193u8, 18u8, 199u8, 18u8, 205u8, 18u8, 211u8, 18u8, 215u8, 18u8, 221u8, 18u8,
+                    227u8, 18u8, 233u8, 18u8, 234u8, 18u8, 235u8, 18u8, 238u8, 18u8, 241u8, 18u8,
+                    244u8, 18u8, 245u8, 18u8, 246u8, 18u8, 247u8, 18u8, 249u8, 18u8, 250u8, 18u8,
+                    251u8, 18u8, 254u8, 18u8, 1u8, 19u8, 4u8, 19u8, 7u8, 19u8, 10u8, 19u8, 13u8,
+                    19u8, 16u8, 19u8, 18u8, 19u8, 20u8, 19u8, 22u8, 19u8, 24u8, 19u8, 25u8, 19u8,
+                    26u8, 19u8, 32u8, 19u8, 38u8, 19u8, 44u8, 19u8, 50u8, 19u8, 54u8, 19u8, 60u8,
+                    19u8, 66u8, 19u8, 72u8, 19u8, 77u8, 19u8, 82u8, 19u8, 87u8, 19u8, 92u8, 19u8,
+                    97u8, 19u8, 102u8, 19u8, 107u8, 19u8, 108u8, 19u8, 109u8, 19u8, 110u8, 19u8,
+                    111u8, 19u8, 114u8, 19u8, 115u8, 19u8, 116u8, 19u8, 117u8, 19u8, 119u8, 19u8,
+                    122u8, 19u8, 124u8, 19u8, 125u8, 19u8, 126u8, 19u8, 128u8, 19u8, 129u8, 19u8,
+                    132u8, 19u8, 135u8, 19u8, 138u8, 19u8, 141u8, 19u8, 144u8, 19u8, 146u8, 19u8,
+                    149u8, 19u8, 151u8, 19u8, 154u8, 19u8, 157u8, 19u8, 160u8, 19u8, 163u8, 19u8,
+                    164u8, 19u8, 165u8, 19u8, 168u8, 19u8, 171u8, 19u8, 176u8, 19u8, 181u8, 19u8,
+                    186u8, 19u8, 191u8, 19u8, 196u8, 19u8, 201u8, 19u8, 206u8, 19u8, 211u8, 19u8,
+                    216u8, 19u8, 221u8, 19u8, 226u8, 19u8, 231u8, 19u8, 236u8, 19u8, 241u8, 19u8,
+                    246u8, 19u8, 251u8, 19u8, 0u8, 20u8, 5u8, 20u8, 8u8, 20u8, 14u8, 20u8, 20u8,
+                    20u8, 25u8, 20u8, 30u8, 20u8, 35u8, 20u8, 40u8, 20u8, 45u8, 20u8, 50u8, 20u8,
+                    54u8, 20u8, 59u8, 20u8, 64u8, 20u8, 67u8, 20u8, 70u8, 20u8, 73u8, 20u8, 76u8,
+                    20u8, 82u8, 20u8, 88u8, 20u8, 90u8, 20u8, 92u8, 20u8, 95u8, 20u8, 98u8, 20u8,
+                    101u8, 20u8, 104u8, 20u8, 110u8, 20u8, 116u8, 20u8, 122u8, 20u8, 124u8, 20u8,
+                    126u8, 20u8, 128u8, 20u8, 130u8, 20u8, 132u8, 20u8, 134u8, 20u8, 136u8, 20u8,
+                    138u8, 20u8, 140u8, 20u8, 142u8, 20u8, 144u8, 20u8, 145u8, 20u8, 147u8, 20u8,
+                    149u8, 20u8, 151u8, 20u8, 152u8, 20u8, 154u8, 20u8, 155u8, 20u8, 158u8, 20u8,
+                    159u8, 20u8, 160u8, 20u8, 161u8, 20u8, 162u8, 20u8, 164u8, 20u8, 165u8, 20u8,
+                    167u8, 20u8, 169u8, 20u8, 172u8, 20u8, 174u8, 20u8, 177u8, 20u8, 179u8, 20u8,
+                    181u8, 20u8, 184u8, 20u8, 188u8, 20u8, 191u8, 20u8, 194u8, 20u8, 198u8, 20u8,
+                    201u8, 20u8, 204u8, 20u8, 207u8, 20u8, 210u8, 20u8, 213u8, 20u8, 216u8, 20u8,
+                    219u8, 20u8, 222u8, 20u8, 226u8, 20u8, 227u8, 20u8, 228u8, 20u8, 230u8, 20u8,
+                    233u8, 20u8, 236u8, 20u8, 239u8, 20u8, 242u8, 20u8, 245u8, 20u8, 248u8, 20u8,
+                    251u8, 20u8, 252u8, 20u8, 253u8, 20u8, 0u8, 21u8, 1u8, 21u8, 2u8, 21u8, 3u8,
+                    21u8, 4u8, 21u8, 5u8, 21u8, 8u8, 21u8, 9u8, 21u8, 10u8, 21u8, 11u8, 21u8, 12u8,
+                    21u8, 13u8, 21u8, 14u8, 21u8, 15u8, 21u8, 16u8, 21u8, 17u8, 21u8, 18u8, 21u8,
+                    19u8, 21u8, 20u8, 21u8, 21u8, 21u8, 22u8, 21u8, 23u8, 21u8, 24u8, 21u8, 25u8,
+                    21u8, 28u8, 21u8, 31u8, 21u8, 33u8, 21u8, 34u8, 21u8, 35u8, 21u8, 36u8, 21u8,
+                    37u8, 21u8, 39u8, 21u8, 41u8, 21u8, 43u8, 21u8, 45u8, 21u8, 46u8, 21u8, 48u8,
+                    21u8, 51u8, 21u8, 53u8, 21u8, 54u8, 21u8, 55u8, 21u8, 56u8, 21u8, 65u8, 21u8,
+                    77u8, 21u8, 78u8, 21u8, 80u8, 21u8, 83u8, 21u8, 84u8, 21u8, 86u8, 21u8, 89u8,
+                    21u8, 90u8, 21u8, 91u8, 21u8, 93u8, 21u8, 95u8, 21u8, 96u8, 21u8, 97u8, 21u8,
+                    98u8, 21u8, 100u8, 21u8, 102u8, 21u8, 104u8, 21u8, 105u8, 21u8, 111u8, 21u8,
+                    112u8, 21u8, 116u8, 21u8, 117u8, 21u8, 120u8, 21u8, 123u8, 21u8, 124u8, 21u8,
+                    126u8, 21u8, 129u8, 21u8, 133u8, 21u8, 135u8, 21u8, 139u8, 21u8, 141u8, 21u8,
+                    144u8, 21u8, 149u8, 21u8, 152u8, 21u8, 155u8, 21u8, 159u8, 21u8, 161u8, 21u8,
+                    163u8, 21u8, 165u8, 21u8, 168u8, 21u8, 171u8, 21u8, 172u8, 21u8, 175u8, 21u8,
+                    178u8, 21u8, 181u8, 21u8, 183u8, 21u8, 185u8, 21u8, 188u8, 21u8, 189u8, 21u8,
+                    190u8, 21u8, 191u8, 21u8, 192u8, 21u8, 193u8, 21u8, 196u8, 21u8, 197u8, 21u8,
+                    198u8, 21u8, 199u8, 21u8, 200u8, 21u8, 201u8, 21u8, 203u8, 21u8, 204u8, 21u8,
+                    205u8, 21u8, 206u8, 21u8, 207u8, 21u8, 208u8, 21u8, 211u8, 21u8, 212u8, 21u8,
+                    214u8, 21u8, 216u8, 21u8, 217u8, 21u8, 219u8, 21u8, 220u8, 21u8, 221u8, 21u8,
+                    222u8, 21u8, 223u8, 21u8, 224u8, 21u8, 225u8, 21u8, 226u8, 21u8, 227u8, 21u8,
+                    228u8, 21u8, 230u8, 21u8, 232u8, 21u8, 234u8, 21u8, 236u8, 21u8, 237u8, 21u8,
+                    240u8, 21u8, 242u8, 21u8, 243u8, 21u8, 245u8, 21u8, 247u8, 21u8, 249u8, 21u8,
+                    252u8, 21u8, 255u8, 21u8, 3u8, 22u8, 4u8, 22u8, 5u8, 22u8, 6u8, 22u8, 7u8,
+                    22u8, 8u8, 22u8, 9u8, 22u8, 11u8, 22u8, 14u8, 22u8, 16u8, 22u8, 17u8, 22u8,
+                    19u8, 22u8, 22u8, 22u8, 26u8, 22u8, 28u8, 22u8, 29u8, 22u8, 31u8, 22u8, 34u8,
+                    22u8, 35u8, 22u8, 36u8, 22u8, 37u8, 22u8, 38u8, 22u8, 39u8, 22u8, 41u8, 22u8,
+                    44u8, 22u8, 46u8, 22u8, 47u8, 22u8, 49u8, 22u8, 52u8, 22u8, 56u8, 22u8, 58u8,
+                    22u8, 59u8, 22u8, 61u8, 22u8, 64u8, 22u8, 65u8, 22u8, 66u8, 22u8, 67u8, 22u8,
+                    69u8, 22u8, 71u8, 22u8, 73u8, 22u8, 76u8, 22u8, 79u8, 22u8, 82u8, 22u8, 86u8,
+                    22u8, 89u8, 22u8, 92u8, 22u8, 95u8, 22u8, 97u8, 22u8, 99u8, 22u8, 101u8, 22u8,
+                    103u8, 22u8, 104u8, 22u8, 107u8, 22u8, 108u8, 22u8, 109u8, 22u8, 110u8, 22u8,
+                    112u8, 22u8, 114u8, 22u8, 116u8, 22u8, 117u8, 22u8, 119u8, 22u8, 120u8, 22u8,
+                    122u8, 22u8, 123u8, 22u8, 125u8, 22u8, 129u8, 22u8, 135u8, 22u8, 141u8, 22u8,
+                    150u8, 22u8, 151u8, 22u8, 154u8, 22u8, 155u8, 22u8, 158u8, 22u8, 163u8, 22u8,
+                    166u8, 22u8, 169u8, 22u8, 172u8, 22u8, 175u8, 22u8, 178u8, 22u8, 180u8, 22u8,
+                    182u8, 22u8, 185u8, 22u8, 188u8, 22u8, 192u8, 22u8, 195u8, 22u8, 197u8, 22u8,
+                    200u8, 22u8, 201u8, 22u8, 204u8, 22u8, 207u8, 22u8, 208u8, 22u8, 210u8, 22u8,
+                    211u8, 22u8, 214u8, 22u8, 216u8, 22u8, 219u8, 22u8, 222u8, 22u8, 225u8, 22u8,
+                    228u8, 22u8, 231u8, 22u8, 234u8, 22u8, 240u8, 22u8, 243u8, 22u8, 244u8, 22u8,
+                    247u8, 22u8, 250u8, 22u8, 253u8, 22u8, 0u8, 23u8, 3u8, 23u8, 7u8, 23u8, 12u8,
+                    23u8, 16u8, 23u8, 19u8, 23u8, 22u8, 23u8, 27u8, 23u8, 32u8, 23u8, 36u8, 23u8,
+                    38u8, 23u8, 41u8, 23u8, 44u8, 23u8, 49u8, 23u8, 52u8, 23u8, 53u8, 23u8, 54u8,
+                    23u8, 56u8, 23u8, 59u8, 23u8, 64u8, 23u8, 67u8, 23u8, 71u8, 23u8, 72u8, 23u8,
+                    75u8, 23u8, 78u8, 23u8, 81u8, 23u8, 84u8, 23u8, 87u8, 23u8, 90u8, 23u8, 93u8,
+                    23u8, 96u8, 23u8, 99u8, 23u8, 102u8, 23u8, 105u8, 23u8, 111u8, 23u8, 114u8,
+                    23u8, 115u8, 23u8, 118u8, 23u8, 120u8, 23u8, 123u8, 23u8, 126u8, 23u8, 129u8,
+                    23u8, 132u8, 23u8, 135u8, 23u8, 138u8, 23u8, 141u8, 23u8, 144u8, 23u8, 147u8,
+                    23u8, 150u8, 23u8, 153u8, 23u8, 156u8, 23u8, 159u8, 23u8, 162u8, 23u8, 165u8,
+                    23u8, 168u8, 23u8, 171u8, 23u8, 174u8, 23u8, 177u8, 23u8, 181u8, 23u8, 185u8,
+                    23u8, 189u8, 23u8, 193u8, 23u8, 197u8, 23u8, 201u8, 23u8, 205u8, 23u8, 209u8,
+                    23u8, 213u8, 23u8, 217u8, 23u8, 221u8, 23u8, 223u8, 23u8, 225u8, 23u8, 227u8,
+                    23u8, 229u8, 23u8, 231u8, 23u8, 233u8, 23u8, 235u8, 23u8, 237u8, 23u8, 239u8,
+                    23u8, 242u8, 23u8, 245u8, 23u8, 248u8, 23u8, 251u8, 23u8, 254u8, 23u8, 1u8,
+                    24u8, 4u8, 24u8, 7u8, 24u8, 10u8, 24u8, 13u8, 24u8, 16u8, 24u8, 19u8, 24u8,
+                    22u8, 24u8, 25u8, 24u8, 28u8, 24u8, 31u8, 24u8, 34u8, 24u8, 37u8, 24u8, 40u8,
+                    24u8, 43u8, 24u8, 46u8, 24u8, 49u8, 24u8, 52u8, 24u8, 56u8, 24u8, 59u8, 24u8,
+                    62u8, 24u8, 65u8, 24u8, 68u8, 24u8, 71u8, 24u8, 74u8, 24u8, 77u8, 24u8, 80u8,
+                    24u8, 83u8, 24u8, 86u8, 24u8, 89u8, 24u8, 92u8, 24u8, 95u8, 24u8, 97u8, 24u8,
+                    100u8, 24u8, 102u8, 24u8, 105u8, 24u8, 109u8, 24u8, 112u8, 24u8, 115u8, 24u8,
+                    118u8, 24u8, 121u8, 24u8, 124u8, 24u8, 125u8, 24u8, 126u8, 24u8, 129u8, 24u8,
+                    132u8, 24u8, 134u8, 24u8, 137u8, 24u8, 140u8, 24u8, 143u8, 24u8, 146u8, 24u8,
+                    148u8, 24u8, 151u8, 24u8, 154u8, 24u8, 157u8, 24u8, 161u8, 24u8, 164u8, 24u8,
+                    167u8, 24u8, 170u8, 24u8, 172u8, 24u8, 175u8, 24u8, 178u8, 24u8, 180u8, 24u8,
+                    182u8, 24u8, 185u8, 24u8, 189u8, 24u8, 192u8, 24u8, 195u8, 24u8, 198u8, 24u8,
+                    201u8, 24u8, 209u8, 24u8, 221u8, 24u8, 224u8, 24u8, 225u8, 24u8, 226u8, 24u8,
+                    227u8, 24u8, 228u8, 24u8, 229u8, 24u8, 230u8, 24u8, 231u8, 24u8, 232u8, 24u8,
+                    233u8, 24u8, 234u8, 24u8, 236u8, 24u8, 239u8, 24u8, 243u8, 24u8, 247u8, 24u8,
+                    248u8, 24u8, 249u8, 24u8, 250u8, 24u8, 253u8, 24u8, 0u8, 25u8, 1u8, 25u8, 2u8,
+                    25u8, 8u8, 25u8, 14u8, 25u8, 20u8, 25u8, 26u8, 25u8, 29u8, 25u8, 32u8, 25u8,
+                    35u8, 25u8, 38u8, 25u8, 41u8, 25u8, 44u8, 25u8, 48u8, 25u8, 51u8, 25u8, 55u8,
+                    25u8, 56u8, 25u8, 59u8, 25u8, 60u8, 25u8, 61u8, 25u8, 63u8, 25u8, 67u8, 25u8,
+                    70u8, 25u8, 73u8, 25u8, 76u8, 25u8, 79u8, 25u8, 82u8, 25u8, 90u8, 25u8, 93u8,
+                    25u8, 95u8, 25u8, 98u8, 25u8, 101u8, 25u8, 104u8, 25u8, 107u8, 25u8, 110u8,
+                    25u8, 113u8, 25u8, 117u8, 25u8, 120u8, 25u8, 123u8, 25u8, 124u8, 25u8, 127u8,
+                    25u8, 130u8, 25u8, 133u8, 25u8, 136u8, 25u8, 139u8, 25u8, 143u8, 25u8, 146u8,
+                    25u8, 148u8, 25u8, 151u8, 25u8, 153u8, 25u8, 156u8, 25u8, 159u8, 25u8, 165u8,
+                    25u8, 168u8, 25u8, 170u8, 25u8, 173u8, 25u8, 176u8, 25u8, 179u8, 25u8, 182u8,
+                    25u8, 185u8, 25u8, 188u8, 25u8, 190u8, 25u8, 191u8, 25u8, 193u8, 25u8, 196u8,
+                    25u8, 199u8, 25u8, 200u8, 25u8, 201u8, 25u8, 202u8, 25u8, 204u8, 25u8, 206u8,
+                    25u8, 207u8, 25u8, 208u8, 25u8, 209u8, 25u8, 210u8, 25u8, 212u8, 25u8, 213u8,
+                    25u8, 214u8, 25u8, 215u8, 25u8, 216u8, 25u8, 217u8, 25u8, 218u8, 25u8, 220u8,
+                    25u8, 222u8, 25u8, 223u8, 25u8, 225u8, 25u8, 227u8, 25u8, 229u8, 25u8, 232u8,
+                    25u8, 233u8, 25u8, 235u8, 25u8, 237u8, 25u8, 238u8, 25u8, 239u8, 25u8, 240u8,
+                    25u8, 242u8, 25u8, 243u8, 25u8, 245u8, 25u8, 246u8, 25u8, 248u8, 25u8, 250u8,
+                    25u8, 253u8, 25u8, 255u8, 25u8, 2u8, 26u8, 4u8, 26u8, 5u8, 26u8, 6u8, 26u8,
+                    8u8, 26u8, 11u8, 26u8, 17u8, 26u8, 19u8, 26u8, 20u8, 26u8, 21u8, 26u8, 22u8,
+                    26u8, 23u8, 26u8, 25u8, 26u8, 26u8, 26u8, 28u8, 26u8, 31u8, 26u8, 34u8, 26u8,
+                    36u8, 26u8, 38u8, 26u8, 40u8, 26u8, 42u8, 26u8, 44u8, 26u8, 47u8, 26u8, 50u8,
+                    26u8, 53u8, 26u8, 56u8, 26u8, 59u8, 26u8, 61u8, 26u8, 63u8, 26u8, 66u8, 26u8,
+                    69u8, 26u8, 72u8, 26u8, 74u8, 26u8, 76u8, 26u8, 78u8, 26u8, 80u8, 26u8, 82u8,
+                    26u8, 85u8, 26u8, 88u8, 26u8, 90u8, 26u8, 91u8, 26u8, 94u8, 26u8, 97u8, 26u8,
+                    100u8, 26u8, 103u8, 26u8, 106u8, 26u8, 109u8, 26u8, 112u8, 26u8, 115u8, 26u8,
+                    118u8, 26u8, 121u8, 26u8, 124u8, 26u8, 127u8, 26u8, 130u8, 26u8, 133u8, 26u8,
+                    136u8, 26u8, 139u8, 26u8, 142u8, 26u8, 145u8, 26u8, 148u8, 26u8, 151u8, 26u8,
+                    154u8, 26u8, 157u8, 26u8, 160u8, 26u8, 163u8, 26u8, 166u8, 26u8, 169u8, 26u8,
+                    172u8, 26u8, 175u8, 26u8, 178u8, 26u8, 181u8, 26u8, 184u8, 26u8, 187u8, 26u8,
+                    190u8, 26u8, 193u8, 26u8, 196u8, 26u8, 199u8, 26u8, 202u8, 26u8, 205u8, 26u8,
+                    208u8, 26u8, 211u8, 26u8, 214u8, 26u8, 217u8, 26u8, 220u8, 26u8, 223u8, 26u8,
+                    226u8, 26u8, 229u8, 26u8, 232u8, 26u8, 235u8, 26u8, 238u8, 26u8, 241u8, 26u8,
+                    244u8, 26u8, 247u8, 26u8, 250u8, 26u8, 253u8, 26u8, 0u8, 27u8, 3u8, 27u8, 6u8,
+                    27u8, 9u8, 27u8, 12u8, 27u8, 15u8, 27u8, 18u8, 27u8, 21u8, 27u8, 24u8, 27u8,
+                    27u8, 27u8, 30u8, 27u8, 33u8, 27u8, 36u8, 27u8, 39u8, 27u8, 42u8, 27u8, 45u8,
+                    27u8, 48u8, 27u8, 51u8, 27u8, 54u8, 27u8, 57u8, 27u8, 60u8, 27u8, 61u8, 27u8,
+                    62u8, 27u8, 65u8, 27u8, 68u8, 27u8, 71u8, 27u8, 74u8, 27u8, 77u8, 27u8, 80u8,
+                    27u8, 83u8, 27u8, 86u8, 27u8, 89u8, 27u8, 92u8, 27u8, 95u8, 27u8, 98u8, 27u8,
+                    101u8, 27u8, 104u8, 27u8, 107u8, 27u8, 110u8, 27u8, 113u8, 27u8, 116u8, 27u8,
+                    119u8, 27u8, 122u8, 27u8, 125u8, 27u8, 128u8, 27u8, 131u8, 27u8, 134u8, 27u8,
+                    137u8, 27u8, 140u8, 27u8, 143u8, 27u8, 146u8, 27u8, 149u8, 27u8, 152u8, 27u8,
+                    155u8, 27u8, 158u8, 27u8, 161u8, 27u8, 164u8, 27u8, 167u8, 27u8, 170u8, 27u8,
+                    173u8, 27u8, 176u8, 27u8, 179u8, 27u8, 182u8, 27u8, 185u8, 27u8, 188u8, 27u8,
+                    191u8, 27u8, 194u8, 27u8, 197u8, 27u8, 200u8, 27u8, 203u8, 27u8, 206u8, 27u8,
+                    209u8, 27u8, 212u8, 27u8, 215u8, 27u8, 218u8, 27u8, 221u8, 27u8, 224u8, 27u8,
+                    227u8, 27u8, 230u8, 27u8, 233u8, 27u8, 236u8, 27u8, 239u8, 27u8, 242u8, 27u8,
+                    245u8, 27u8, 248u8, 27u8, 251u8, 27u8, 254u8, 27u8, 1u8, 28u8, 4u8, 28u8, 7u8,
+                    28u8, 10u8, 28u8, 13u8, 28u8, 16u8, 28u8, 19u8, 28u8, 22u8, 28u8, 25u8, 28u8,
+                    28u8, 28u8, 31u8, 28u8, 34u8, 28u8, 37u8, 28u8, 40u8, 28u8, 43u8, 28u8, 46u8,
+                    28u8, 49u8, 28u8, 52u8, 28u8, 55u8, 28u8, 58u8, 28u8, 61u8, 28u8, 64u8, 28u8,
+                    67u8, 28u8, 70u8, 28u8, 73u8, 28u8, 76u8, 28u8, 79u8, 28u8, 82u8, 28u8, 85u8,
+                    28u8, 88u8, 28u8, 91u8, 28u8, 94u8, 28u8, 97u8, 28u8, 100u8, 28u8, 103u8, 28u8,
+                    106u8, 28u8, 109u8, 28u8, 112u8, 28u8, 115u8, 28u8, 118u8, 28u8, 121u8, 28u8,
+                    124u8, 28u8, 127u8, 28u8, 130u8, 28u8, 133u8, 28u8, 136u8, 28u8, 139u8, 28u8,
+                    142u8, 28u8, 145u8, 28u8, 148u8, 28u8, 151u8, 28u8, 154u8, 28u8, 157u8, 28u8,
+                    160u8, 28u8, 163u8, 28u8, 166u8, 28u8, 169u8, 28u8, 172u8, 28u8, 175u8, 28u8,
+                    178u8, 28u8, 181u8, 28u8, 184u8, 28u8, 187u8, 28u8, 190u8, 28u8, 193u8, 28u8,
+                    196u8, 28u8, 199u8, 28u8, 202u8, 28u8, 205u8, 28u8, 208u8, 28u8, 211u8, 28u8,
+                    214u8, 28u8, 217u8, 28u8, 220u8, 28u8, 223u8, 28u8, 226u8, 28u8, 229u8, 28u8,
+                    232u8, 28u8, 235u8, 28u8, 238u8, 28u8, 241u8, 28u8, 244u8, 28u8, 247u8, 28u8,
+                    250u8, 28u8, 253u8, 28u8, 0u8, 29u8, 3u8, 29u8, 6u8, 29u8, 9u8, 29u8, 12u8,
+                    29u8, 15u8, 29u8, 18u8, 29u8, 21u8, 29u8, 24u8, 29u8, 27u8, 29u8, 30u8, 29u8,
+                    33u8, 29u8, 36u8, 29u8, 39u8, 29u8, 42u8, 29u8, 45u8, 29u8, 48u8, 29u8, 51u8,
+                    29u8, 54u8, 29u8, 57u8, 29u8, 60u8, 29u8, 63u8, 29u8, 66u8, 29u8, 69u8, 29u8,
+                    72u8, 29u8, 75u8, 29u8, 78u8, 29u8, 81u8, 29u8, 84u8, 29u8, 87u8, 29u8, 90u8,
+                    29u8, 93u8, 29u8, 96u8, 29u8, 99u8, 29u8, 102u8, 29u8, 105u8, 29u8, 108u8,
+                    29u8, 111u8, 29u8, 114u8, 29u8, 117u8, 29u8, 120u8, 29u8, 123u8, 29u8, 126u8,
+                    29u8, 129u8, 29u8, 132u8, 29u8, 135u8, 29u8, 138u8, 29u8, 141u8, 29u8, 144u8,
+                    29u8, 147u8, 29u8, 150u8, 29u8, 153u8, 29u8, 156u8, 29u8, 159u8, 29u8, 162u8,
+                    29u8, 165u8, 29u8, 168u8, 29u8, 171u8, 29u8, 174u8, 29u8, 177u8, 29u8, 180u8,
+                    29u8, 182u8, 29u8, 184u8, 29u8, 185u8, 29u8, 188u8, 29u8, 191u8, 29u8, 194u8,
+                    29u8, 195u8, 29u8, 196u8, 29u8, 199u8, 29u8, 202u8, 29u8, 204u8, 29u8, 206u8,
+                    29u8, 207u8, 29u8, 210u8, 29u8, 213u8, 29u8, 216u8, 29u8, 219u8, 29u8, 222u8,
+                    29u8, 224u8, 29u8, 227u8, 29u8, 230u8, 29u8, 231u8, 29u8, 234u8, 29u8, 237u8,
+                    29u8, 240u8, 29u8, 243u8, 29u8, 246u8, 29u8, 249u8, 29u8, 250u8, 29u8, 253u8,
+                    29u8, 0u8, 30u8, 3u8, 30u8, 5u8, 30u8, 8u8, 30u8, 14u8, 30u8, 20u8, 30u8, 23u8,
+                    30u8, 29u8, 30u8, 35u8, 30u8, 38u8, 30u8, 44u8, 30u8, 47u8, 30u8, 53u8, 30u8,
+                    59u8, 30u8, 65u8, 30u8, 71u8, 30u8, 77u8, 30u8, 83u8, 30u8, 89u8, 30u8, 92u8,
+                    30u8, 95u8, 30u8, 101u8, 30u8, 107u8, 30u8, 110u8, 30u8, 116u8, 30u8, 119u8,
+                    30u8, 122u8, 30u8, 128u8, 30u8, 131u8, 30u8, 134u8, 30u8, 137u8, 30u8, 140u8,
+                    30u8, 143u8, 30u8, 146u8, 30u8, 152u8, 30u8, 155u8, 30u8, 161u8, 30u8, 164u8,
+                    30u8, 170u8, 30u8, 173u8, 30u8, 179u8, 30u8, 182u8, 30u8, 188u8, 30u8, 197u8,
+                    30u8, 203u8, 30u8, 206u8, 30u8, 209u8, 30u8, 215u8, 30u8, 224u8, 30u8, 230u8,
+                    30u8, 233u8, 30u8, 236u8, 30u8, 242u8, 30u8, 245u8, 30u8, 248u8, 30u8, 254u8,
+                    30u8, 4u8, 31u8, 10u8, 31u8, 16u8, 31u8, 25u8, 31u8, 31u8, 31u8, 40u8, 31u8,
+                    46u8, 31u8, 52u8, 31u8, 58u8, 31u8, 64u8, 31u8, 70u8, 31u8, 76u8, 31u8, 82u8,
+                    31u8, 88u8, 31u8, 97u8, 31u8, 106u8, 31u8, 112u8, 31u8, 118u8, 31u8, 124u8,
+                    31u8, 133u8, 31u8, 139u8, 31u8, 145u8, 31u8, 151u8, 31u8, 157u8, 31u8, 163u8,
+                    31u8, 166u8, 31u8, 172u8, 31u8, 175u8, 31u8, 181u8, 31u8, 187u8, 31u8, 193u8,
+                    31u8, 199u8, 31u8, 202u8, 31u8, 208u8, 31u8, 217u8, 31u8, 223u8, 31u8, 229u8,
+                    31u8, 238u8, 31u8, 244u8, 31u8, 247u8, 31u8, 253u8, 31u8, 0u8, 32u8, 3u8, 32u8,
+                    4u8, 32u8, 5u8, 32u8, 8u8, 32u8, 11u8, 32u8, 14u8, 32u8, 17u8, 32u8, 20u8,
+                    32u8, 25u8, 32u8, 30u8, 32u8, 35u8, 32u8, 40u8, 32u8, 45u8, 32u8, 50u8, 32u8,
+                    55u8, 32u8, 60u8, 32u8, 65u8, 32u8, 70u8, 32u8, 75u8, 32u8, 80u8, 32u8, 85u8,
+                    32u8, 90u8, 32u8, 95u8, 32u8, 100u8, 32u8, 105u8, 32u8, 110u8, 32u8, 115u8,
+                    32u8, 120u8, 32u8, 125u8, 32u8, 130u8, 32u8, 135u8, 32u8, 140u8, 32u8, 145u8,
+                    32u8, 150u8, 32u8, 155u8, 32u8, 160u8, 32u8, 165u8, 32u8, 173u8, 32u8, 181u8,
+                    32u8, 186u8, 32u8, 191u8, 32u8, 196u8, 32u8, 201u8, 32u8, 206u8, 32u8, 211u8,
+                    32u8, 216u8, 32u8, 221u8, 32u8, 226u8, 32u8, 231u8, 32u8, 236u8, 32u8, 241u8,
+                    32u8, 246u8, 32u8, 251u8, 32u8, 0u8, 33u8, 5u8, 33u8, 10u8, 33u8, 15u8, 33u8,
+                    20u8, 33u8, 25u8, 33u8, 30u8, 33u8, 35u8, 33u8, 40u8, 33u8, 45u8, 33u8, 50u8,
+                    33u8, 55u8, 33u8, 60u8, 33u8, 65u8, 33u8, 70u8, 33u8, 75u8, 33u8, 80u8, 33u8,
+                    85u8, 33u8, 90u8, 33u8, 95u8, 33u8, 100u8, 33u8, 105u8, 33u8, 109u8, 33u8,
+                    113u8, 33u8, 117u8, 33u8, 121u8, 33u8, 125u8, 33u8, 129u8, 33u8, 133u8, 33u8,
+                    137u8, 33u8, 141u8, 33u8, 146u8, 33u8, 151u8, 33u8, 156u8, 33u8, 160u8, 33u8,
+                    164u8, 33u8, 168u8, 33u8, 172u8, 33u8, 176u8, 33u8, 180u8, 33u8, 184u8, 33u8,
+                    188u8, 33u8, 192u8, 33u8, 196u8, 33u8, 201u8, 33u8, 206u8, 33u8, 211u8, 33u8,
+                    216u8, 33u8, 221u8, 33u8, 226u8, 33u8, 231u8, 33u8, 236u8, 33u8, 241u8, 33u8,
+                    246u8, 33u8, 251u8, 33u8, 0u8, 34u8, 5u8, 34u8, 10u8, 34u8, 15u8, 34u8, 19u8,
+                    34u8, 23u8, 34u8, 27u8, 34u8, 31u8, 34u8, 35u8, 34u8, 39u8, 34u8, 43u8, 34u8,
+                    47u8, 34u8, 51u8, 34u8, 56u8, 34u8, 61u8, 34u8, 66u8, 34u8, 71u8, 34u8, 76u8,
+                    34u8, 81u8, 34u8, 86u8, 34u8, 91u8, 34u8, 96u8, 34u8, 101u8, 34u8, 106u8, 34u8,
+                    111u8, 34u8, 116u8, 34u8, 121u8, 34u8, 126u8, 34u8, 131u8, 34u8, 136u8, 34u8,
+                    141u8, 34u8, 146u8, 34u8, 151u8, 34u8, 156u8, 34u8, 161u8, 34u8, 164u8, 34u8,
+                    167u8, 34u8, 170u8, 34u8, 173u8, 34u8, 174u8, 34u8, 175u8, 34u8, 178u8, 34u8,
+                    181u8, 34u8, 184u8, 34u8, 187u8, 34u8, 190u8, 34u8, 193u8, 34u8, 196u8, 34u8,
+                    199u8, 34u8, 202u8, 34u8, 205u8, 34u8, 208u8, 34u8, 211u8, 34u8, 214u8, 34u8,
+                    217u8, 34u8, 220u8, 34u8, 223u8, 34u8, 226u8, 34u8, 229u8, 34u8, 232u8, 34u8,
+                    235u8, 34u8, 238u8, 34u8, 241u8, 34u8, 244u8, 34u8, 247u8, 34u8, 250u8, 34u8,
+                    253u8, 34u8, 0u8, 35u8, 3u8, 35u8, 6u8, 35u8, 9u8, 35u8, 12u8, 35u8, 15u8,
+                    35u8, 18u8, 35u8, 21u8, 35u8, 24u8, 35u8, 27u8, 35u8, 30u8, 35u8, 33u8, 35u8,
+                    36u8, 35u8, 39u8, 35u8, 42u8, 35u8, 45u8, 35u8, 48u8, 35u8, 51u8, 35u8, 54u8,
+                    35u8, 57u8, 35u8, 60u8, 35u8, 63u8, 35u8, 66u8, 35u8, 69u8, 35u8, 72u8, 35u8,
+                    75u8, 35u8, 78u8, 35u8, 81u8, 35u8, 84u8, 35u8, 87u8, 35u8, 90u8, 35u8, 93u8,
+                    35u8, 94u8, 35u8, 95u8, 35u8, 96u8, 35u8, 97u8, 35u8, 98u8, 35u8, 99u8, 35u8,
+                    100u8, 35u8, 101u8, 35u8, 102u8, 35u8, 104u8, 35u8, 105u8, 35u8, 106u8, 35u8,
+                    109u8, 35u8, 110u8, 35u8, 111u8, 35u8, 112u8, 35u8, 113u8, 35u8, 114u8, 35u8,
+                    116u8, 35u8, 117u8, 35u8, 118u8, 35u8, 119u8, 35u8, 120u8, 35u8, 121u8, 35u8,
+                    124u8, 35u8, 125u8, 35u8, 128u8, 35u8, 129u8, 35u8, 131u8, 35u8, 132u8, 35u8,
+                    133u8, 35u8, 134u8, 35u8, 136u8, 35u8, 139u8, 35u8, 140u8, 35u8, 141u8, 35u8,
+                    143u8, 35u8, 145u8, 35u8, 146u8, 35u8, 148u8, 35u8, 149u8, 35u8, 150u8, 35u8,
+                    151u8, 35u8, 153u8, 35u8, 154u8, 35u8, 156u8, 35u8, 159u8, 35u8, 163u8, 35u8,
+                    165u8, 35u8, 168u8, 35u8, 170u8, 35u8, 172u8, 35u8, 175u8, 35u8, 177u8, 35u8,
+                    179u8, 35u8, 183u8, 35u8, 185u8, 35u8, 188u8, 35u8, 191u8, 35u8, 193u8, 35u8,
+                    195u8, 35u8, 197u8, 35u8, 198u8, 35u8, 199u8, 35u8, 200u8, 35u8, 202u8, 35u8,
+                    204u8, 35u8, 210u8, 35u8, 212u8, 35u8, 214u8, 35u8, 216u8, 35u8, 219u8, 35u8,
+                    220u8, 35u8, 222u8, 35u8, 224u8, 35u8, 226u8, 35u8, 228u8, 35u8, 230u8, 35u8,
+                    232u8, 35u8, 234u8, 35u8, 236u8, 35u8, 238u8, 35u8, 240u8, 35u8, 242u8, 35u8,
+                    244u8, 35u8, 247u8, 35u8, 250u8, 35u8, 253u8, 35u8, 255u8, 35u8, 1u8, 36u8,
+                    2u8, 36u8, 5u8, 36u8, 6u8, 36u8, 8u8, 36u8, 9u8, 36u8, 11u8, 36u8, 12u8, 36u8,
+                    15u8, 36u8, 16u8, 36u8, 17u8, 36u8, 19u8, 36u8, 22u8, 36u8, 23u8, 36u8, 24u8,
+                    36u8, 28u8, 36u8, 32u8, 36u8, 34u8, 36u8, 37u8, 36u8, 38u8, 36u8, 39u8, 36u8,
+                    42u8, 36u8, 43u8, 36u8, 44u8, 36u8, 45u8, 36u8, 47u8, 36u8, 50u8, 36u8, 52u8,
+                    36u8, 55u8, 36u8, 58u8, 36u8, 64u8, 36u8, 70u8, 36u8, 76u8, 36u8, 82u8, 36u8,
+                    88u8, 36u8, 97u8, 36u8, 103u8, 36u8, 112u8, 36u8, 118u8, 36u8, 124u8, 36u8,
+                    133u8, 36u8, 142u8, 36u8, 148u8, 36u8, 154u8, 36u8, 160u8, 36u8, 166u8, 36u8,
+                    172u8, 36u8, 178u8, 36u8, 187u8, 36u8, 193u8, 36u8, 199u8, 36u8, 208u8, 36u8,
+                    214u8, 36u8, 220u8, 36u8, 229u8, 36u8, 235u8, 36u8, 241u8, 36u8, 247u8, 36u8,
+                    253u8, 36u8, 0u8, 37u8, 3u8, 37u8, 6u8, 37u8, 8u8, 37u8, 11u8, 37u8, 14u8,
+                    37u8, 15u8, 37u8, 16u8, 37u8, 17u8, 37u8, 20u8, 37u8, 24u8, 37u8, 29u8, 37u8,
+                    34u8, 37u8, 35u8, 37u8, 36u8, 37u8, 38u8, 37u8, 39u8, 37u8, 40u8, 37u8, 42u8,
+                    37u8, 44u8, 37u8, 45u8, 37u8, 47u8, 37u8, 50u8, 37u8, 52u8, 37u8, 55u8, 37u8,
+                    57u8, 37u8, 60u8, 37u8, 63u8, 37u8, 64u8, 37u8, 67u8, 37u8, 70u8, 37u8, 73u8,
+                    37u8, 75u8, 37u8, 78u8, 37u8, 79u8, 37u8, 80u8, 37u8, 82u8, 37u8, 84u8, 37u8,
+                    87u8, 37u8, 89u8, 37u8, 90u8, 37u8, 93u8, 37u8, 96u8, 37u8, 98u8, 37u8, 100u8,
+                    37u8, 101u8, 37u8, 102u8, 37u8, 104u8, 37u8, 105u8, 37u8, 108u8, 37u8, 110u8,
+                    37u8, 113u8, 37u8, 119u8, 37u8, 128u8, 37u8, 134u8, 37u8, 143u8, 37u8, 149u8,
+                    37u8, 155u8, 37u8, 164u8, 37u8, 173u8, 37u8, 179u8, 37u8, 185u8, 37u8, 191u8,
+                    37u8, 200u8, 37u8, 206u8, 37u8, 215u8, 37u8, 224u8, 37u8, 230u8, 37u8, 239u8,
+                    37u8, 248u8, 37u8, 254u8, 37u8, 4u8, 38u8, 10u8, 38u8, 16u8, 38u8, 25u8, 38u8,
+                    31u8, 38u8, 37u8, 38u8, 43u8, 38u8, 52u8, 38u8, 58u8, 38u8, 64u8, 38u8, 73u8,
+                    38u8, 79u8, 38u8, 85u8, 38u8, 91u8, 38u8, 100u8, 38u8, 109u8, 38u8, 118u8,
+                    38u8, 127u8, 38u8, 136u8, 38u8, 145u8, 38u8, 151u8, 38u8, 160u8, 38u8, 166u8,
+                    38u8, 172u8, 38u8, 181u8, 38u8, 187u8, 38u8, 196u8, 38u8, 202u8, 38u8, 208u8,
+                    38u8, 217u8, 38u8, 223u8, 38u8, 229u8, 38u8, 238u8, 38u8, 244u8, 38u8, 250u8,
+                    38u8, 0u8, 39u8, 9u8, 39u8, 18u8, 39u8, 27u8, 39u8, 33u8, 39u8, 39u8, 39u8,
+                    45u8, 39u8, 51u8, 39u8, 57u8, 39u8, 63u8, 39u8, 72u8, 39u8, 78u8, 39u8, 84u8,
+                    39u8, 90u8, 39u8, 99u8, 39u8, 105u8, 39u8, 111u8, 39u8, 117u8, 39u8, 120u8,
+                    39u8, 123u8, 39u8, 126u8, 39u8, 129u8, 39u8, 132u8, 39u8, 135u8, 39u8, 138u8,
+                    39u8, 141u8, 39u8, 144u8, 39u8, 147u8, 39u8, 150u8, 39u8, 153u8, 39u8, 156u8,
+                    39u8, 159u8, 39u8, 162u8, 39u8, 165u8, 39u8, 168u8, 39u8, 171u8, 39u8, 174u8,
+                    39u8, 177u8, 39u8, 180u8, 39u8, 183u8, 39u8, 186u8, 39u8, 189u8, 39u8, 192u8,
+                    39u8, 195u8, 39u8, 198u8, 39u8, 201u8, 39u8, 204u8, 39u8, 207u8, 39u8, 210u8,
+                    39u8, 213u8, 39u8, 216u8, 39u8, 219u8, 39u8, 222u8, 39u8, 225u8, 39u8, 228u8,
+                    39u8, 231u8, 39u8, 234u8, 39u8, 237u8, 39u8, 240u8, 39u8, 243u8, 39u8, 246u8,
+                    39u8, 249u8, 39u8, 252u8, 39u8, 255u8, 39u8, 2u8, 40u8, 5u8, 40u8, 8u8, 40u8,
+                    11u8, 40u8, 14u8, 40u8, 17u8, 40u8, 20u8, 40u8, 23u8, 40u8, 26u8, 40u8, 29u8,
+                    40u8, 32u8, 40u8, 35u8, 40u8, 38u8, 40u8, 41u8, 40u8, 44u8, 40u8, 47u8, 40u8,
+                    50u8, 40u8, 53u8, 40u8, 56u8, 40u8, 59u8, 40u8, 62u8, 40u8, 65u8, 40u8, 68u8,
+                    40u8, 71u8, 40u8, 74u8, 40u8, 77u8, 40u8, 80u8, 40u8, 83u8, 40u8, 86u8, 40u8,
+                    89u8, 40u8, 92u8, 40u8, 95u8, 40u8, 98u8, 40u8, 101u8, 40u8, 104u8, 40u8,
+                    107u8, 40u8, 110u8, 40u8, 113u8, 40u8, 116u8, 40u8, 119u8, 40u8, 122u8, 40u8,
+                    125u8, 40u8, 128u8, 40u8, 131u8, 40u8, 134u8, 40u8, 137u8, 40u8, 140u8, 40u8,
+                    143u8, 40u8, 146u8, 40u8, 149u8, 40u8, 152u8, 40u8, 155u8, 40u8, 158u8, 40u8,
+                    161u8, 40u8, 164u8, 40u8, 167u8, 40u8, 170u8, 40u8, 173u8, 40u8, 176u8, 40u8,
+                    179u8, 40u8, 182u8, 40u8, 185u8, 40u8, 188u8, 40u8, 191u8, 40u8, 194u8, 40u8,
+                    197u8, 40u8, 200u8, 40u8, 203u8, 40u8, 206u8, 40u8, 209u8, 40u8, 212u8, 40u8,
+                    215u8, 40u8, 218u8, 40u8, 221u8, 40u8, 224u8, 40u8, 227u8, 40u8, 230u8, 40u8,
+                    233u8, 40u8, 236u8, 40u8, 239u8, 40u8, 242u8, 40u8, 245u8, 40u8, 248u8, 40u8,
+                    251u8, 40u8, 254u8, 40u8, 1u8, 41u8, 4u8, 41u8, 7u8, 41u8, 10u8, 41u8, 13u8,
+                    41u8, 16u8, 41u8, 19u8, 41u8, 22u8, 41u8, 25u8, 41u8, 28u8, 41u8, 31u8, 41u8,
+                    34u8, 41u8, 37u8, 41u8, 40u8, 41u8, 43u8, 41u8, 46u8, 41u8, 49u8, 41u8, 52u8,
+                    41u8, 55u8, 41u8, 58u8, 41u8, 61u8, 41u8, 64u8, 41u8, 67u8, 41u8, 70u8, 41u8,
+                    73u8, 41u8, 76u8, 41u8, 79u8, 41u8, 82u8, 41u8, 85u8, 41u8, 88u8, 41u8, 91u8,
+                    41u8, 94u8, 41u8, 97u8, 41u8, 100u8, 41u8, 103u8, 41u8, 106u8, 41u8, 109u8,
+                    41u8, 112u8, 41u8, 115u8, 41u8, 118u8, 41u8, 121u8, 41u8, 124u8, 41u8, 127u8,
+                    41u8, 130u8, 41u8, 133u8, 41u8, 136u8, 41u8, 139u8, 41u8, 142u8, 41u8, 145u8,
+                    41u8, 148u8, 41u8, 151u8, 41u8, 154u8, 41u8, 157u8, 41u8, 160u8, 41u8, 163u8,
+                    41u8, 166u8, 41u8, 169u8, 41u8, 172u8, 41u8, 175u8, 41u8, 178u8, 41u8, 181u8,
+                    41u8, 184u8, 41u8, 187u8, 41u8, 190u8, 41u8, 193u8, 41u8, 196u8, 41u8, 199u8,
+                    41u8, 202u8, 41u8, 205u8, 41u8, 208u8, 41u8, 211u8, 41u8, 214u8, 41u8, 217u8,
+                    41u8, 220u8, 41u8, 223u8, 41u8, 226u8, 41u8, 229u8, 41u8, 232u8, 41u8, 235u8,
+                    41u8, 238u8, 41u8, 241u8, 41u8, 244u8, 41u8, 247u8, 41u8, 250u8, 41u8, 253u8,
+                    41u8, 0u8, 42u8, 3u8, 42u8, 6u8, 42u8, 9u8, 42u8, 12u8, 42u8, 15u8, 42u8, 18u8,
+                    42u8, 21u8, 42u8, 24u8, 42u8, 27u8, 42u8, 30u8, 42u8, 33u8, 42u8, 36u8, 42u8,
+                    39u8, 42u8, 42u8, 42u8, 45u8, 42u8, 48u8, 42u8, 51u8, 42u8, 54u8, 42u8, 57u8,
+                    42u8, 60u8, 42u8, 63u8, 42u8, 66u8, 42u8, 69u8, 42u8, 72u8, 42u8, 75u8, 42u8,
+                    78u8, 42u8, 81u8, 42u8, 84u8, 42u8, 87u8, 42u8, 90u8, 42u8, 93u8, 42u8, 96u8,
+                    42u8, 99u8, 42u8, 102u8, 42u8, 105u8, 42u8, 108u8, 42u8, 111u8, 42u8, 114u8,
+                    42u8, 117u8, 42u8, 120u8, 42u8, 123u8, 42u8, 126u8, 42u8, 129u8, 42u8, 132u8,
+                    42u8, 135u8, 42u8, 138u8, 42u8, 141u8, 42u8, 144u8, 42u8, 147u8, 42u8, 150u8,
+                    42u8, 153u8, 42u8, 156u8, 42u8, 159u8, 42u8, 162u8, 42u8, 165u8, 42u8, 168u8,
+                    42u8, 171u8, 42u8, 174u8, 42u8, 177u8, 42u8, 180u8, 42u8, 183u8, 42u8, 186u8,
+                    42u8, 189u8, 42u8, 192u8, 42u8, 195u8, 42u8, 198u8, 42u8, 201u8, 42u8, 204u8,
+                    42u8, 207u8, 42u8, 210u8, 42u8, 213u8, 42u8, 216u8, 42u8, 219u8, 42u8, 222u8,
+                    42u8, 225u8, 42u8, 228u8, 42u8, 231u8, 42u8, 234u8, 42u8, 237u8, 42u8, 240u8,
+                    42u8, 243u8, 42u8, 246u8, 42u8, 249u8, 42u8, 252u8, 42u8, 255u8, 42u8, 2u8,
+                    43u8, 5u8, 43u8, 8u8, 43u8, 11u8, 43u8, 14u8, 43u8, 17u8, 43u8, 20u8, 43u8,
+                    23u8, 43u8, 26u8, 43u8, 29u8, 43u8, 32u8, 43u8, 35u8, 43u8, 38u8, 43u8, 41u8,
+                    43u8, 44u8, 43u8, 47u8, 43u8, 50u8, 43u8, 53u8, 43u8, 56u8, 43u8, 59u8, 43u8,
+                    62u8, 43u8, 65u8, 43u8, 68u8, 43u8, 71u8, 43u8, 74u8, 43u8, 77u8, 43u8, 80u8,
+                    43u8, 83u8, 43u8, 86u8, 43u8, 89u8, 43u8, 92u8, 43u8, 95u8, 43u8, 98u8, 43u8,
+                    101u8, 43u8, 104u8, 43u8, 107u8, 43u8, 110u8, 43u8, 113u8, 43u8, 116u8, 43u8,
+                    119u8, 43u8, 122u8, 43u8, 125u8, 43u8, 128u8, 43u8, 131u8, 43u8, 134u8, 43u8,
+                    137u8, 43u8, 140u8, 43u8, 143u8, 43u8, 146u8, 43u8, 149u8, 43u8, 153u8, 43u8,
+                    156u8, 43u8, 159u8, 43u8, 162u8, 43u8, 165u8, 43u8, 168u8, 43u8, 171u8, 43u8,
+                    174u8, 43u8, 177u8, 43u8, 180u8, 43u8, 183u8, 43u8, 186u8, 43u8, 189u8, 43u8,
+                    192u8, 43u8, 195u8, 43u8, 198u8, 43u8, 201u8, 43u8, 204u8, 43u8, 207u8, 43u8,
+                    210u8, 43u8, 213u8, 43u8, 216u8, 43u8, 219u8, 43u8, 222u8, 43u8, 225u8, 43u8,
+                    228u8, 43u8, 231u8, 43u8, 234u8, 43u8, 237u8, 43u8, 240u8, 43u8, 243u8, 43u8,
+                    246u8, 43u8, 249u8, 43u8, 252u8, 43u8, 255u8, 43u8, 2u8, 44u8, 5u8, 44u8, 8u8,
+                    44u8, 11u8, 44u8, 14u8, 44u8, 17u8, 44u8, 20u8, 44u8, 23u8, 44u8, 26u8, 44u8,
+                    29u8, 44u8, 32u8, 44u8, 35u8, 44u8, 38u8, 44u8, 41u8, 44u8, 44u8, 44u8, 47u8,
+                    44u8, 50u8, 44u8, 53u8, 44u8, 56u8, 44u8, 59u8, 44u8, 62u8, 44u8, 65u8, 44u8,
+                    68u8, 44u8, 71u8, 44u8, 74u8, 44u8, 77u8, 44u8, 80u8, 44u8, 83u8, 44u8, 86u8,
+                    44u8, 89u8, 44u8, 92u8, 44u8, 95u8, 44u8, 98u8, 44u8, 101u8, 44u8, 104u8, 44u8,
+                    107u8, 44u8, 110u8, 44u8, 113u8, 44u8, 116u8, 44u8, 119u8, 44u8, 122u8, 44u8,
+                    125u8, 44u8, 128u8, 44u8, 131u8, 44u8, 134u8, 44u8, 137u8, 44u8, 140u8, 44u8,
+                    143u8, 44u8, 146u8, 44u8, 149u8, 44u8, 152u8, 44u8, 155u8, 44u8, 158u8, 44u8,
+                    161u8, 44u8, 164u8, 44u8, 167u8, 44u8, 170u8, 44u8, 173u8, 44u8, 176u8, 44u8,
+                    179u8, 44u8, 182u8, 44u8, 185u8, 44u8, 189u8, 44u8, 193u8, 44u8, 197u8, 44u8,
+                    200u8, 44u8, 203u8, 44u8, 206u8, 44u8, 210u8, 44u8, 214u8, 44u8, 218u8, 44u8,
+                    221u8, 44u8, 224u8, 44u8, 226u8, 44u8, 228u8, 44u8, 230u8, 44u8, 233u8, 44u8,
+                    236u8, 44u8, 238u8, 44u8, 242u8, 44u8, 246u8, 44u8, 250u8, 44u8, 254u8, 44u8,
+                    2u8, 45u8, 4u8, 45u8, 6u8, 45u8, 8u8, 45u8, 10u8, 45u8, 12u8, 45u8, 14u8, 45u8,
+                    16u8, 45u8, 18u8, 45u8, 20u8, 45u8, 23u8, 45u8, 26u8, 45u8, 29u8, 45u8, 32u8,
+                    45u8, 35u8, 45u8, 38u8, 45u8, 41u8, 45u8, 45u8, 45u8, 47u8, 45u8, 49u8, 45u8,
+                    51u8, 45u8, 53u8, 45u8, 55u8, 45u8, 57u8, 45u8, 61u8, 45u8, 65u8, 45u8, 69u8,
+                    45u8, 73u8, 45u8, 75u8, 45u8, 77u8, 45u8, 79u8, 45u8, 81u8, 45u8, 83u8, 45u8,
+                    85u8, 45u8, 87u8, 45u8, 89u8, 45u8, 91u8, 45u8, 93u8, 45u8, 95u8, 45u8, 97u8,
+                    45u8, 101u8, 45u8, 105u8, 45u8, 109u8, 45u8, 113u8, 45u8, 117u8, 45u8, 121u8,
+                    45u8, 125u8, 45u8, 129u8, 45u8, 131u8, 45u8, 133u8, 45u8, 135u8, 45u8, 137u8,
+                    45u8, 139u8, 45u8, 141u8, 45u8, 143u8, 45u8, 145u8, 45u8, 147u8, 45u8, 149u8,
+                    45u8, 151u8, 45u8, 153u8, 45u8, 155u8, 45u8, 157u8, 45u8, 159u8, 45u8, 161u8,
+                    45u8, 163u8, 45u8, 165u8, 45u8, 167u8, 45u8, 169u8, 45u8, 171u8, 45u8, 173u8,
+                    45u8, 175u8, 45u8, 177u8, 45u8, 181u8, 45u8, 185u8, 45u8, 189u8, 45u8, 193u8,
+                    45u8, 197u8, 45u8, 201u8, 45u8, 205u8, 45u8, 209u8, 45u8, 211u8, 45u8, 213u8,
+                    45u8, 215u8, 45u8, 217u8, 45u8, 219u8, 45u8, 221u8, 45u8, 223u8, 45u8, 225u8,
+                    45u8, 227u8, 45u8, 229u8, 45u8, 231u8, 45u8, 233u8, 45u8, 235u8, 45u8, 237u8,
+                    45u8, 239u8, 45u8, 241u8, 45u8, 243u8, 45u8, 245u8, 45u8, 249u8, 45u8, 253u8,
+                    45u8, 1u8, 46u8, 5u8, 46u8, 7u8, 46u8, 9u8, 46u8, 10u8, 46u8, 11u8, 46u8, 12u8,
+                    46u8, 13u8, 46u8, 14u8, 46u8, 15u8, 46u8, 16u8, 46u8, 17u8, 46u8, 19u8, 46u8,
+                    21u8, 46u8, 23u8, 46u8, 25u8, 46u8, 29u8, 46u8, 33u8, 46u8, 37u8, 46u8, 41u8,
+                    46u8, 45u8, 46u8, 49u8, 46u8, 53u8, 46u8, 57u8, 46u8, 61u8, 46u8, 65u8, 46u8,
+                    71u8, 46u8, 75u8, 46u8, 79u8, 46u8, 81u8, 46u8, 83u8, 46u8, 87u8, 46u8, 91u8,
+                    46u8, 93u8, 46u8, 95u8, 46u8, 97u8, 46u8, 99u8, 46u8, 101u8, 46u8, 103u8, 46u8,
+                    108u8, 46u8, 113u8, 46u8, 118u8, 46u8, 123u8, 46u8, 129u8, 46u8, 135u8, 46u8,
+                    143u8, 46u8, 151u8, 46u8, 159u8, 46u8, 167u8, 46u8, 175u8, 46u8, 183u8, 46u8,
+                    189u8, 46u8, 195u8, 46u8, 201u8, 46u8, 207u8, 46u8, 213u8, 46u8, 219u8, 46u8,
+                    221u8, 46u8, 223u8, 46u8, 225u8, 46u8, 227u8, 46u8, 233u8, 46u8, 239u8, 46u8,
+                    245u8, 46u8, 251u8, 46u8, 1u8, 47u8, 5u8, 47u8, 9u8, 47u8, 13u8, 47u8, 17u8,
+                    47u8, 21u8, 47u8, 25u8, 47u8, 29u8, 47u8, 33u8, 47u8, 37u8, 47u8, 41u8, 47u8,
+                    45u8, 47u8, 49u8, 47u8, 55u8, 47u8, 61u8, 47u8, 67u8, 47u8, 73u8, 47u8, 77u8,
+                    47u8, 81u8, 47u8, 85u8, 47u8, 89u8, 47u8, 93u8, 47u8, 97u8, 47u8, 101u8, 47u8,
+                    105u8, 47u8, 109u8, 47u8, 113u8, 47u8, 117u8, 47u8, 121u8, 47u8, 125u8, 47u8,
+                    129u8, 47u8, 133u8, 47u8, 137u8, 47u8, 141u8, 47u8, 145u8, 47u8, 149u8, 47u8,
+                    153u8, 47u8, 157u8, 47u8, 161u8, 47u8, 165u8, 47u8, 169u8, 47u8, 173u8, 47u8,
+                    177u8, 47u8, 181u8, 47u8, 185u8, 47u8, 189u8, 47u8, 193u8, 47u8, 197u8, 47u8,
+                    201u8, 47u8, 205u8, 47u8, 209u8, 47u8, 212u8, 47u8, 216u8, 47u8, 220u8, 47u8,
+                    224u8, 47u8, 228u8, 47u8, 232u8, 47u8, 236u8, 47u8, 240u8, 47u8, 244u8, 47u8,
+                    248u8, 47u8, 252u8, 47u8, 0u8, 48u8, 4u8, 48u8, 8u8, 48u8, 12u8, 48u8, 16u8,
+                    48u8, 20u8, 48u8, 24u8, 48u8, 28u8, 48u8, 32u8, 48u8, 36u8, 48u8, 40u8, 48u8,
+                    44u8, 48u8, 48u8, 48u8, 52u8, 48u8, 56u8, 48u8, 59u8, 48u8, 62u8, 48u8, 65u8,
+                    48u8, 68u8, 48u8, 72u8, 48u8, 76u8, 48u8, 80u8, 48u8, 84u8, 48u8, 88u8, 48u8,
+                    92u8, 48u8, 96u8, 48u8, 100u8, 48u8, 104u8, 48u8, 109u8, 48u8, 114u8, 48u8,
+                    119u8, 48u8, 124u8, 48u8, 129u8, 48u8, 134u8, 48u8, 140u8, 48u8, 146u8, 48u8,
+                    152u8, 48u8, 158u8, 48u8, 164u8, 48u8, 170u8, 48u8, 174u8, 48u8, 178u8, 48u8,
+                    182u8, 48u8, 186u8, 48u8, 190u8, 48u8, 194u8, 48u8, 198u8, 48u8, 202u8, 48u8,
+                    206u8, 48u8, 210u8, 48u8, 214u8, 48u8, 218u8, 48u8, 224u8, 48u8, 230u8, 48u8,
+                    236u8, 48u8, 242u8, 48u8, 248u8, 48u8, 254u8, 48u8, 2u8, 49u8, 6u8, 49u8, 10u8,
+                    49u8, 14u8, 49u8, 17u8, 49u8, 21u8, 49u8, 25u8, 49u8, 29u8, 49u8, 33u8, 49u8,
+                    37u8, 49u8, 41u8, 49u8, 45u8, 49u8, 48u8, 49u8, 52u8, 49u8, 56u8, 49u8, 60u8,
+                    49u8, 64u8, 49u8, 68u8, 49u8, 72u8, 49u8, 76u8, 49u8, 80u8, 49u8, 84u8, 49u8,
+                    88u8, 49u8, 92u8, 49u8, 96u8, 49u8, 100u8, 49u8, 104u8, 49u8, 110u8, 49u8,
+                    116u8, 49u8, 122u8, 49u8, 128u8, 49u8, 133u8, 49u8, 137u8, 49u8, 141u8, 49u8,
+                    145u8, 49u8, 149u8, 49u8, 152u8, 49u8, 156u8, 49u8, 160u8, 49u8, 164u8, 49u8,
+                    168u8, 49u8, 171u8, 49u8, 177u8, 49u8, 181u8, 49u8, 185u8, 49u8, 189u8, 49u8,
+                    193u8, 49u8, 197u8, 49u8, 201u8, 49u8, 205u8, 49u8, 209u8, 49u8, 213u8, 49u8,
+                    217u8, 49u8, 221u8, 49u8, 225u8, 49u8, 229u8, 49u8, 233u8, 49u8, 237u8, 49u8,
+                    241u8, 49u8, 245u8, 49u8, 249u8, 49u8, 253u8, 49u8, 1u8, 50u8, 5u8, 50u8, 9u8,
+                    50u8, 13u8, 50u8, 17u8, 50u8, 21u8, 50u8, 25u8, 50u8, 29u8, 50u8, 33u8, 50u8,
+                    37u8, 50u8, 41u8, 50u8, 45u8, 50u8, 49u8, 50u8, 53u8, 50u8, 57u8, 50u8, 61u8,
+                    50u8, 65u8, 50u8, 69u8, 50u8, 73u8, 50u8, 76u8, 50u8, 80u8, 50u8, 84u8, 50u8,
+                    88u8, 50u8, 92u8, 50u8, 96u8, 50u8, 100u8, 50u8, 104u8, 50u8, 108u8, 50u8,
+                    111u8, 50u8, 114u8, 50u8, 117u8, 50u8, 120u8, 50u8, 124u8, 50u8, 128u8, 50u8,
+                    132u8, 50u8, 136u8, 50u8, 139u8, 50u8, 145u8, 50u8, 150u8, 50u8, 154u8, 50u8,
+                    157u8, 50u8, 161u8, 50u8, 164u8, 50u8, 170u8, 50u8, 175u8, 50u8, 179u8, 50u8,
+                    182u8, 50u8, 188u8, 50u8, 193u8, 50u8, 197u8, 50u8, 201u8, 50u8, 205u8, 50u8,
+                    209u8, 50u8, 212u8, 50u8, 216u8, 50u8, 219u8, 50u8, 224u8, 50u8, 229u8, 50u8,
+                    234u8, 50u8, 238u8, 50u8, 242u8, 50u8, 246u8, 50u8, 250u8, 50u8, 254u8, 50u8,
+                    2u8, 51u8, 6u8, 51u8, 10u8, 51u8, 16u8, 51u8, 22u8, 51u8, 26u8, 51u8, 30u8,
+                    51u8, 34u8, 51u8, 38u8, 51u8, 42u8, 51u8, 46u8, 51u8, 50u8, 51u8, 54u8, 51u8,
+                    58u8, 51u8, 62u8, 51u8, 68u8, 51u8, 74u8, 51u8, 80u8, 51u8, 86u8, 51u8, 92u8,
+                    51u8, 96u8, 51u8, 100u8, 51u8, 104u8, 51u8, 108u8, 51u8, 112u8, 51u8, 116u8,
+                    51u8, 120u8, 51u8, 124u8, 51u8, 128u8, 51u8, 132u8, 51u8, 136u8, 51u8, 142u8,
+                    51u8, 148u8, 51u8, 152u8, 51u8, 156u8, 51u8, 160u8, 51u8, 164u8, 51u8, 168u8,
+                    51u8, 172u8, 51u8, 176u8, 51u8, 180u8, 51u8, 184u8, 51u8, 188u8, 51u8, 194u8,
+                    51u8, 200u8, 51u8, 206u8, 51u8, 212u8, 51u8, 218u8, 51u8, 222u8, 51u8, 226u8,
+                    51u8, 230u8, 51u8, 236u8, 51u8, 242u8, 51u8, 248u8, 51u8, 254u8, 51u8, 1u8,
+                    52u8, 6u8, 52u8, 10u8, 52u8, 14u8, 52u8, 18u8, 52u8, 22u8, 52u8, 28u8, 52u8,
+                    34u8, 52u8, 40u8, 52u8, 44u8, 52u8, 48u8, 52u8, 51u8, 52u8, 54u8, 52u8, 55u8,
+                    52u8, 56u8, 52u8, 62u8, 52u8, 68u8, 52u8, 74u8, 52u8, 80u8, 52u8, 86u8, 52u8,
+                    92u8, 52u8, 98u8, 52u8, 104u8, 52u8, 110u8, 52u8, 116u8, 52u8, 122u8, 52u8,
+                    128u8, 52u8, 134u8, 52u8, 140u8, 52u8, 146u8, 52u8, 152u8, 52u8, 158u8, 52u8,
+                    164u8, 52u8, 170u8, 52u8, 176u8, 52u8, 182u8, 52u8, 188u8, 52u8, 194u8, 52u8,
+                    202u8, 52u8, 210u8, 52u8, 218u8, 52u8, 226u8, 52u8, 234u8, 52u8, 242u8, 52u8,
+                    250u8, 52u8, 0u8, 53u8, 6u8, 53u8, 12u8, 53u8, 18u8, 53u8, 24u8, 53u8, 30u8,
+                    53u8, 36u8, 53u8, 42u8, 53u8, 48u8, 53u8, 54u8, 53u8, 60u8, 53u8, 66u8, 53u8,
+                    72u8, 53u8, 78u8, 53u8, 84u8, 53u8, 90u8, 53u8, 96u8, 53u8, 102u8, 53u8, 108u8,
+                    53u8, 114u8, 53u8, 120u8, 53u8, 126u8, 53u8, 132u8, 53u8, 138u8, 53u8, 144u8,
+                    53u8, 150u8, 53u8, 156u8, 53u8, 162u8, 53u8, 168u8, 53u8, 174u8, 53u8, 180u8,
+                    53u8, 186u8, 53u8, 192u8, 53u8, 198u8, 53u8, 204u8, 53u8, 209u8, 53u8, 214u8,
+                    53u8, 220u8, 53u8, 226u8, 53u8, 232u8, 53u8, 238u8, 53u8, 244u8, 53u8, 250u8,
+                    53u8, 0u8, 54u8, 6u8, 54u8, 12u8, 54u8, 18u8, 54u8, 24u8, 54u8, 30u8, 54u8,
+                    36u8, 54u8, 42u8, 54u8, 48u8, 54u8, 54u8, 54u8, 60u8, 54u8, 66u8, 54u8, 72u8,
+                    54u8, 78u8, 54u8, 84u8, 54u8, 92u8, 54u8, 98u8, 54u8, 104u8, 54u8, 110u8, 54u8,
+                    116u8, 54u8, 122u8, 54u8, 128u8, 54u8, 134u8, 54u8, 140u8, 54u8, 146u8, 54u8,
+                    152u8, 54u8, 158u8, 54u8, 164u8, 54u8, 170u8, 54u8, 176u8, 54u8, 182u8, 54u8,
+                    188u8, 54u8, 194u8, 54u8, 200u8, 54u8, 206u8, 54u8, 212u8, 54u8, 218u8, 54u8,
+                    224u8, 54u8, 230u8, 54u8, 236u8, 54u8, 242u8, 54u8, 248u8, 54u8, 254u8, 54u8,
+                    4u8, 55u8, 10u8, 55u8, 16u8, 55u8, 22u8, 55u8, 32u8, 55u8, 39u8, 55u8, 47u8,
+                    55u8, 55u8, 55u8, 63u8, 55u8, 70u8, 55u8, 78u8, 55u8, 84u8, 55u8, 114u8, 55u8,
+                    127u8, 55u8, 134u8, 55u8, 137u8, 55u8, 138u8, 55u8, 141u8, 55u8, 144u8, 55u8,
+                    147u8, 55u8, 150u8, 55u8, 153u8, 55u8, 156u8, 55u8, 159u8, 55u8, 162u8, 55u8,
+                    164u8, 55u8, 166u8, 55u8, 168u8, 55u8, 170u8, 55u8, 171u8, 55u8, 172u8, 55u8,
+                    173u8, 55u8, 174u8, 55u8, 175u8, 55u8, 177u8, 55u8, 179u8, 55u8, 181u8, 55u8,
+                    184u8, 55u8, 187u8, 55u8, 191u8, 55u8, 195u8, 55u8, 198u8, 55u8, 201u8, 55u8,
+                    205u8, 55u8, 209u8, 55u8, 213u8, 55u8, 217u8, 55u8, 218u8, 55u8, 219u8, 55u8,
+                    221u8, 55u8, 223u8, 55u8, 225u8, 55u8, 227u8, 55u8, 229u8, 55u8, 231u8, 55u8,
+                    233u8, 55u8, 235u8, 55u8, 237u8, 55u8, 239u8, 55u8, 243u8, 55u8, 247u8, 55u8,
+                    251u8, 55u8, 255u8, 55u8, 1u8, 56u8, 3u8, 56u8, 5u8, 56u8, 7u8, 56u8, 9u8,
+                    56u8, 11u8, 56u8, 13u8, 56u8, 15u8, 56u8, 17u8, 56u8, 19u8, 56u8, 21u8, 56u8,
+                    23u8, 56u8, 25u8, 56u8, 27u8, 56u8, 29u8, 56u8, 31u8, 56u8, 33u8, 56u8, 35u8,
+                    56u8, 37u8, 56u8, 39u8, 56u8, 41u8, 56u8, 43u8, 56u8, 45u8, 56u8, 47u8, 56u8,
+                    51u8, 56u8, 55u8, 56u8, 59u8, 56u8, 63u8, 56u8, 65u8, 56u8, 67u8, 56u8, 69u8,
+                    56u8, 71u8, 56u8, 73u8, 56u8, 75u8, 56u8, 77u8, 56u8, 79u8, 56u8, 81u8, 56u8,
+                    83u8, 56u8, 85u8, 56u8, 87u8, 56u8, 89u8, 56u8, 91u8, 56u8, 93u8, 56u8, 95u8,
+                    56u8, 97u8, 56u8, 99u8, 56u8, 101u8, 56u8, 103u8, 56u8, 105u8, 56u8, 107u8,
+                    56u8, 109u8, 56u8, 111u8, 56u8, 113u8, 56u8, 115u8, 56u8, 117u8, 56u8, 119u8,
+                    56u8, 121u8, 56u8, 123u8, 56u8, 125u8, 56u8, 127u8, 56u8, 129u8, 56u8, 131u8,
+                    56u8, 133u8, 56u8, 135u8, 56u8, 137u8, 56u8, 139u8, 56u8, 141u8, 56u8, 143u8,
+                    56u8, 145u8, 56u8, 147u8, 56u8, 149u8, 56u8, 151u8, 56u8, 153u8, 56u8, 155u8,
+                    56u8, 157u8, 56u8, 159u8, 56u8, 160u8, 56u8, 161u8, 56u8, 162u8, 56u8, 163u8,
+                    56u8, 165u8, 56u8, 167u8, 56u8, 169u8, 56u8, 171u8, 56u8, 173u8, 56u8, 175u8,
+                    56u8, 177u8, 56u8, 179u8, 56u8, 183u8, 56u8, 187u8, 56u8, 192u8, 56u8, 197u8,
+                    56u8, 202u8, 56u8, 207u8, 56u8, 210u8, 56u8, 213u8, 56u8, 214u8, 56u8, 216u8,
+                    56u8, 217u8, 56u8, 220u8, 56u8, 221u8, 56u8, 222u8, 56u8, 223u8, 56u8, 224u8,
+                    56u8, 225u8, 56u8, 226u8, 56u8, 227u8, 56u8, 228u8, 56u8, 229u8, 56u8, 230u8,
+                    56u8, 231u8, 56u8, 232u8, 56u8, 233u8, 56u8, 234u8, 56u8, 235u8, 56u8, 236u8,
+                    56u8, 237u8, 56u8, 238u8, 56u8, 239u8, 56u8, 240u8, 56u8, 241u8, 56u8, 244u8,
+                    56u8, 245u8, 56u8, 246u8, 56u8, 247u8, 56u8, 248u8, 56u8, 249u8, 56u8, 250u8,
+                    56u8, 251u8, 56u8, 252u8, 56u8, 253u8, 56u8, 254u8, 56u8, 255u8, 56u8, 0u8,
+                    57u8, 1u8, 57u8, 2u8, 57u8, 3u8, 57u8, 6u8, 57u8, 9u8, 57u8, 11u8, 57u8, 13u8,
+                    57u8, 14u8, 57u8, 17u8, 57u8, 19u8, 57u8, 22u8, 57u8, 25u8, 57u8, 28u8, 57u8,
+                    37u8, 57u8, 43u8, 57u8, 46u8, 57u8, 47u8, 57u8, 49u8, 57u8, 50u8, 57u8, 51u8,
+                    57u8, 52u8, 57u8, 54u8, 57u8, 55u8, 57u8, 56u8, 57u8, 59u8, 57u8, 60u8, 57u8,
+                    61u8, 57u8, 62u8, 57u8, 63u8, 57u8, 64u8, 57u8, 65u8, 57u8, 66u8, 57u8, 68u8,
+                    57u8, 69u8, 57u8, 70u8, 57u8, 72u8, 57u8, 73u8, 57u8, 74u8, 57u8, 75u8, 57u8,
+                    77u8, 57u8, 78u8, 57u8, 80u8, 57u8, 82u8, 57u8, 85u8, 57u8, 86u8, 57u8, 88u8,
+                    57u8, 90u8, 57u8, 92u8, 57u8, 94u8, 57u8, 95u8, 57u8, 96u8, 57u8, 97u8, 57u8,
+                    98u8, 57u8, 99u8, 57u8, 101u8, 57u8, 102u8, 57u8, 103u8, 57u8, 104u8, 57u8,
+                    105u8, 57u8, 106u8, 57u8, 107u8, 57u8, 111u8, 57u8, 115u8, 57u8, 117u8, 57u8,
+                    118u8, 57u8, 121u8, 57u8, 122u8, 57u8, 123u8, 57u8, 126u8, 57u8, 127u8, 57u8,
+                    129u8, 57u8, 131u8, 57u8, 134u8, 57u8, 136u8, 57u8, 137u8, 57u8, 138u8, 57u8,
+                    140u8, 57u8, 142u8, 57u8, 144u8, 57u8, 145u8, 57u8, 147u8, 57u8, 150u8, 57u8,
+                    154u8, 57u8, 156u8, 57u8, 157u8, 57u8, 159u8, 57u8, 160u8, 57u8, 162u8, 57u8,
+                    164u8, 57u8, 166u8, 57u8, 167u8, 57u8, 170u8, 57u8, 172u8, 57u8, 173u8, 57u8,
+                    175u8, 57u8, 177u8, 57u8, 178u8, 57u8, 181u8, 57u8, 182u8, 57u8, 184u8, 57u8,
+                    185u8, 57u8, 186u8, 57u8, 187u8, 57u8, 188u8, 57u8, 189u8, 57u8, 190u8, 57u8,
+                    191u8, 57u8, 192u8, 57u8, 194u8, 57u8, 195u8, 57u8, 203u8, 57u8, 207u8, 57u8,
+                    211u8, 57u8, 214u8, 57u8, 217u8, 57u8, 219u8, 57u8, 222u8, 57u8, 225u8, 57u8,
+                    228u8, 57u8, 231u8, 57u8, 233u8, 57u8, 245u8, 57u8, 1u8, 58u8, 13u8, 58u8,
+                    25u8, 58u8, 37u8, 58u8, 49u8, 58u8, 57u8, 58u8, 60u8, 58u8, 63u8, 58u8, 66u8,
+                    58u8, 69u8, 58u8, 72u8, 58u8, 75u8, 58u8, 78u8, 58u8, 81u8, 58u8, 84u8, 58u8,
+                    87u8, 58u8, 90u8, 58u8, 93u8, 58u8, 96u8, 58u8, 99u8, 58u8, 102u8, 58u8, 105u8,
+                    58u8, 108u8, 58u8, 111u8, 58u8, 114u8, 58u8, 117u8, 58u8, 120u8, 58u8, 123u8,
+                    58u8, 126u8, 58u8, 129u8, 58u8, 132u8, 58u8, 135u8, 58u8, 139u8, 58u8, 142u8,
+                    58u8, 145u8, 58u8, 147u8, 58u8, 150u8, 58u8, 153u8, 58u8, 154u8, 58u8, 157u8,
+                    58u8, 160u8, 58u8, 163u8, 58u8, 167u8, 58u8, 171u8, 58u8, 175u8, 58u8, 179u8,
+                    58u8, 183u8, 58u8, 187u8, 58u8, 195u8, 58u8, 197u8, 58u8, 198u8, 58u8, 199u8,
+                    58u8, 200u8, 58u8, 201u8, 58u8, 202u8, 58u8, 203u8, 58u8, 204u8, 58u8, 205u8,
+                    58u8, 206u8, 58u8, 209u8, 58u8, 210u8, 58u8, 211u8, 58u8, 212u8, 58u8, 213u8,
+                    58u8, 214u8, 58u8, 215u8, 58u8, 218u8, 58u8, 219u8, 58u8, 220u8, 58u8, 221u8,
+                    58u8, 222u8, 58u8, 223u8, 58u8, 224u8, 58u8, 225u8, 58u8, 226u8, 58u8, 227u8,
+                    58u8, 228u8, 58u8, 229u8, 58u8, 230u8, 58u8, 233u8, 58u8, 234u8, 58u8, 235u8,
+                    58u8, 236u8, 58u8, 237u8, 58u8, 238u8, 58u8, 239u8, 58u8, 241u8, 58u8, 243u8,
+                    58u8, 244u8, 58u8, 245u8, 58u8, 246u8, 58u8, 247u8, 58u8, 248u8, 58u8, 249u8,
+                    58u8, 1u8, 59u8, 9u8, 59u8, 17u8, 59u8, 29u8, 59u8, 41u8, 59u8, 49u8, 59u8,
+                    57u8, 59u8, 69u8, 59u8, 77u8, 59u8, 85u8, 59u8, 93u8, 59u8, 105u8, 59u8, 117u8,
+                    59u8, 125u8, 59u8, 129u8, 59u8, 133u8, 59u8, 137u8, 59u8, 139u8, 59u8, 140u8,
+                    59u8, 141u8, 59u8, 142u8, 59u8, 144u8, 59u8, 147u8, 59u8, 150u8, 59u8, 151u8,
+                    59u8, 153u8, 59u8, 154u8, 59u8, 155u8, 59u8, 156u8, 59u8, 158u8, 59u8, 159u8,
+                    59u8, 160u8, 59u8, 161u8, 59u8, 162u8, 59u8, 163u8, 59u8, 164u8, 59u8, 165u8,
+                    59u8, 166u8, 59u8, 168u8, 59u8, 169u8, 59u8, 171u8, 59u8, 172u8, 59u8, 173u8,
+                    59u8, 174u8, 59u8, 175u8, 59u8, 179u8, 59u8, 182u8, 59u8, 183u8, 59u8, 186u8,
+                    59u8, 189u8, 59u8, 192u8, 59u8, 195u8, 59u8, 197u8, 59u8, 199u8, 59u8, 200u8,
+                    59u8, 203u8, 59u8, 206u8, 59u8, 207u8, 59u8, 208u8, 59u8, 211u8, 59u8, 214u8,
+                    59u8, 215u8, 59u8, 216u8, 59u8, 219u8, 59u8, 221u8, 59u8, 222u8, 59u8, 223u8,
+                    59u8, 224u8, 59u8, 225u8, 59u8, 226u8, 59u8, 227u8, 59u8, 228u8, 59u8, 229u8,
+                    59u8, 230u8, 59u8, 231u8, 59u8, 232u8, 59u8, 233u8, 59u8, 234u8, 59u8, 235u8,
+                    59u8, 236u8, 59u8, 237u8, 59u8, 238u8, 59u8, 239u8, 59u8, 240u8, 59u8, 241u8,
+                    59u8, 242u8, 59u8, 243u8, 59u8, 244u8, 59u8, 245u8, 59u8, 246u8, 59u8, 247u8,
+                    59u8, 248u8, 59u8, 249u8, 59u8, 250u8, 59u8, 251u8, 59u8, 252u8, 59u8, 253u8,
+                    59u8, 254u8, 59u8, 255u8, 59u8, 0u8, 60u8, 1u8, 60u8, 2u8, 60u8, 3u8, 60u8,
+                    5u8, 60u8, 6u8, 60u8, 7u8, 60u8, 8u8, 60u8, 9u8, 60u8, 10u8, 60u8, 11u8, 60u8,
+                    12u8, 60u8, 13u8, 60u8, 14u8, 60u8, 15u8, 60u8, 16u8, 60u8, 17u8, 60u8, 18u8,
+                    60u8, 19u8, 60u8, 20u8, 60u8, 21u8, 60u8, 22u8, 60u8, 23u8, 60u8, 24u8, 60u8,
+                    25u8, 60u8, 26u8, 60u8, 27u8, 60u8, 28u8, 60u8, 29u8, 60u8, 30u8, 60u8, 31u8,
+                    60u8, 32u8, 60u8, 33u8, 60u8, 34u8, 60u8, 35u8, 60u8, 36u8, 60u8, 37u8, 60u8,
+                    38u8, 60u8, 39u8, 60u8, 40u8, 60u8, 41u8, 60u8, 42u8, 60u8, 43u8, 60u8, 44u8,
+                    60u8, 45u8, 60u8, 46u8, 60u8, 47u8, 60u8, 48u8, 60u8, 49u8, 60u8, 50u8, 60u8,
+                    51u8, 60u8, 52u8, 60u8, 53u8, 60u8, 54u8, 60u8, 55u8, 60u8, 57u8, 60u8, 58u8,
+                    60u8, 59u8, 60u8, 60u8, 60u8, 61u8, 60u8, 62u8, 60u8, 63u8, 60u8, 64u8, 60u8,
+                    65u8, 60u8, 66u8, 60u8, 67u8, 60u8, 68u8, 60u8, 69u8, 60u8, 70u8, 60u8, 71u8,
+                    60u8, 72u8, 60u8, 73u8, 60u8, 74u8, 60u8, 75u8, 60u8, 76u8, 60u8, 77u8, 60u8,
+                    78u8, 60u8, 79u8, 60u8, 80u8, 60u8, 81u8, 60u8, 82u8, 60u8, 83u8, 60u8, 84u8,
+                    60u8, 85u8, 60u8, 86u8, 60u8, 87u8, 60u8, 88u8, 60u8, 89u8, 60u8, 90u8, 60u8,
+                    91u8, 60u8, 92u8, 60u8, 93u8, 60u8, 94u8, 60u8, 95u8, 60u8, 96u8, 60u8, 97u8,
+                    60u8, 98u8, 60u8, 99u8, 60u8, 100u8, 60u8, 101u8, 60u8, 102u8, 60u8, 103u8,
+                    60u8, 104u8, 60u8, 105u8, 60u8, 106u8, 60u8, 107u8, 60u8, 108u8, 60u8, 110u8,
+                    60u8, 111u8, 60u8, 112u8, 60u8, 113u8, 60u8, 114u8, 60u8, 115u8, 60u8, 116u8,
+                    60u8, 117u8, 60u8, 118u8, 60u8, 119u8, 60u8, 120u8, 60u8, 121u8, 60u8, 122u8,
+                    60u8, 123u8, 60u8, 124u8, 60u8, 125u8, 60u8, 126u8, 60u8, 127u8, 60u8, 128u8,
+                    60u8, 129u8, 60u8, 130u8, 60u8, 131u8, 60u8, 132u8, 60u8, 133u8, 60u8, 134u8,
+                    60u8, 135u8, 60u8, 136u8, 60u8, 137u8, 60u8, 138u8, 60u8, 139u8, 60u8, 140u8,
+                    60u8, 141u8, 60u8, 142u8, 60u8, 143u8, 60u8, 144u8, 60u8, 145u8, 60u8, 146u8,
+                    60u8, 147u8, 60u8, 148u8, 60u8, 149u8, 60u8, 150u8, 60u8, 151u8, 60u8, 153u8,
+                    60u8, 154u8, 60u8, 155u8, 60u8, 156u8, 60u8, 157u8, 60u8, 158u8, 60u8, 159u8,
+                    60u8, 160u8, 60u8, 161u8, 60u8, 162u8, 60u8, 163u8, 60u8, 164u8, 60u8, 165u8,
+                    60u8, 166u8, 60u8, 167u8, 60u8, 168u8, 60u8, 169u8, 60u8, 170u8, 60u8, 171u8,
+                    60u8, 172u8, 60u8, 173u8, 60u8, 174u8, 60u8, 175u8, 60u8, 176u8, 60u8, 177u8,
+                    60u8, 178u8, 60u8, 179u8, 60u8, 180u8, 60u8, 181u8, 60u8, 182u8, 60u8, 183u8,
+                    60u8, 184u8, 60u8, 185u8, 60u8, 186u8, 60u8, 187u8, 60u8, 188u8, 60u8, 189u8,
+                    60u8, 190u8, 60u8, 191u8, 60u8, 192u8, 60u8, 193u8, 60u8, 194u8, 60u8, 195u8,
+                    60u8, 196u8, 60u8, 197u8, 60u8, 198u8, 60u8, 199u8, 60u8, 200u8, 60u8, 201u8,
+                    60u8, 202u8, 60u8, 203u8, 60u8, 205u8, 60u8, 206u8, 60u8, 207u8, 60u8, 208u8,
+                    60u8, 209u8, 60u8, 210u8, 60u8, 211u8, 60u8, 212u8, 60u8, 213u8, 60u8, 214u8,
+                    60u8, 215u8, 60u8, 216u8, 60u8, 217u8, 60u8, 218u8, 60u8, 219u8, 60u8, 220u8,
+                    60u8, 221u8, 60u8, 222u8, 60u8, 223u8, 60u8, 224u8, 60u8, 225u8, 60u8, 226u8,
+                    60u8, 227u8, 60u8, 228u8, 60u8, 229u8, 60u8, 230u8, 60u8, 231u8, 60u8, 232u8,
+                    60u8, 233u8, 60u8, 234u8, 60u8, 235u8, 60u8, 236u8, 60u8, 237u8, 60u8, 238u8,
+                    60u8, 239u8, 60u8, 240u8, 60u8, 241u8, 60u8, 242u8, 60u8, 243u8, 60u8, 244u8,
+                    60u8, 245u8, 60u8, 246u8, 60u8, 247u8, 60u8, 248u8, 60u8, 249u8, 60u8, 250u8,
+                    60u8, 251u8, 60u8, 253u8, 60u8, 254u8, 60u8, 255u8, 60u8, 0u8, 61u8, 1u8, 61u8,
+                    2u8, 61u8, 3u8, 61u8, 4u8, 61u8, 5u8, 61u8, 6u8, 61u8, 7u8, 61u8, 8u8, 61u8,
+                    9u8, 61u8, 10u8, 61u8, 11u8, 61u8, 12u8, 61u8, 13u8, 61u8, 14u8, 61u8, 15u8,
+                    61u8, 16u8, 61u8, 17u8, 61u8, 18u8, 61u8, 19u8, 61u8, 20u8, 61u8, 21u8, 61u8,
+                    22u8, 61u8, 23u8, 61u8, 24u8, 61u8, 25u8, 61u8, 26u8, 61u8, 27u8, 61u8, 28u8,
+                    61u8, 29u8, 61u8, 30u8, 61u8, 31u8, 61u8, 32u8, 61u8, 33u8, 61u8, 34u8, 61u8,
+                    35u8, 61u8, 36u8, 61u8, 37u8, 61u8, 38u8, 61u8, 39u8, 61u8, 40u8, 61u8, 41u8,
+                    61u8, 43u8, 61u8, 44u8, 61u8, 45u8, 61u8, 46u8, 61u8, 47u8, 61u8, 48u8, 61u8,
+                    49u8, 61u8, 50u8, 61u8, 51u8, 61u8, 52u8, 61u8, 53u8, 61u8, 54u8, 61u8, 55u8,
+                    61u8, 56u8, 61u8, 57u8, 61u8, 58u8, 61u8, 59u8, 61u8, 60u8, 61u8, 61u8, 61u8,
+                    62u8, 61u8, 63u8, 61u8, 64u8, 61u8, 65u8, 61u8, 66u8, 61u8, 67u8, 61u8, 68u8,
+                    61u8, 69u8, 61u8, 70u8, 61u8, 71u8, 61u8, 72u8, 61u8, 73u8, 61u8, 74u8, 61u8,
+                    75u8, 61u8, 76u8, 61u8, 77u8, 61u8, 78u8, 61u8, 79u8, 61u8, 80u8, 61u8, 81u8,
+                    61u8, 82u8, 61u8, 83u8, 61u8, 84u8, 61u8, 85u8, 61u8, 86u8, 61u8, 87u8, 61u8,
+                    88u8, 61u8, 89u8, 61u8, 90u8, 61u8, 91u8, 61u8, 92u8, 61u8, 93u8, 61u8, 94u8,
+                    61u8, 96u8, 61u8, 97u8, 61u8, 98u8, 61u8, 99u8, 61u8, 100u8, 61u8, 101u8, 61u8,
+                    102u8, 61u8, 103u8, 61u8, 104u8, 61u8, 105u8, 61u8, 106u8, 61u8, 107u8, 61u8,
+                    108u8, 61u8, 109u8, 61u8, 110u8, 61u8, 111u8, 61u8, 112u8, 61u8, 113u8, 61u8,
+                    114u8, 61u8, 115u8, 61u8, 116u8, 61u8, 117u8, 61u8, 118u8, 61u8, 119u8, 61u8,
+                    120u8, 61u8, 121u8, 61u8, 122u8, 61u8, 123u8, 61u8, 124u8, 61u8, 125u8, 61u8,
+                    126u8, 61u8, 127u8, 61u8, 128u8, 61u8, 129u8, 61u8, 130u8, 61u8, 131u8, 61u8,
+                    132u8, 61u8, 133u8, 61u8, 134u8, 61u8, 135u8, 61u8, 136u8, 61u8, 137u8, 61u8,
+                    138u8, 61u8, 139u8, 61u8, 140u8, 61u8, 141u8, 61u8, 142u8, 61u8, 143u8, 61u8,
+                    144u8, 61u8, 145u8, 61u8, 146u8, 61u8, 147u8, 61u8, 149u8, 61u8, 150u8, 61u8,
+                    151u8, 61u8, 152u8, 61u8, 153u8, 61u8, 154u8, 61u8, 155u8, 61u8, 156u8, 61u8,
+                    157u8, 61u8, 158u8, 61u8, 159u8, 61u8, 160u8, 61u8, 161u8, 61u8, 162u8, 61u8,
+                    163u8, 61u8, 164u8, 61u8, 165u8, 61u8, 166u8, 61u8, 167u8, 61u8, 168u8, 61u8,
+                    169u8, 61u8, 170u8, 61u8, 171u8, 61u8, 172u8, 61u8, 173u8, 61u8, 174u8, 61u8,
+                    175u8, 61u8, 176u8, 61u8, 177u8, 61u8, 178u8, 61u8, 179u8, 61u8, 180u8, 61u8,
+                    181u8, 61u8, 182u8, 61u8, 183u8, 61u8, 184u8, 61u8, 185u8, 61u8, 186u8, 61u8,
+                    187u8, 61u8, 188u8, 61u8, 189u8, 61u8, 190u8, 61u8, 191u8, 61u8, 192u8, 61u8,
+                    193u8, 61u8, 194u8, 61u8, 195u8, 61u8, 196u8, 61u8, 197u8, 61u8, 198u8, 61u8,
+                    199u8, 61u8, 200u8, 61u8, 202u8, 61u8, 203u8, 61u8, 204u8, 61u8, 205u8, 61u8,
+                    206u8, 61u8, 207u8, 61u8, 208u8, 61u8, 209u8, 61u8, 210u8, 61u8, 211u8, 61u8,
+                    212u8, 61u8, 213u8, 61u8, 214u8, 61u8, 215u8, 61u8, 216u8, 61u8, 217u8, 61u8,
+                    218u8, 61u8, 219u8, 61u8, 220u8, 61u8, 221u8, 61u8, 222u8, 61u8, 223u8, 61u8,
+                    224u8, 61u8, 225u8, 61u8, 226u8, 61u8, 227u8, 61u8, 228u8, 61u8, 229u8, 61u8,
+                    230u8, 61u8, 231u8, 61u8, 232u8, 61u8, 233u8, 61u8, 234u8, 61u8, 235u8, 61u8,
+                    236u8, 61u8, 237u8, 61u8, 238u8, 61u8, 239u8, 61u8, 240u8, 61u8, 241u8, 61u8,
+                    242u8, 61u8, 243u8, 61u8, 244u8, 61u8, 245u8, 61u8, 246u8, 61u8, 247u8, 61u8,
+                    248u8, 61u8, 249u8, 61u8, 250u8, 61u8, 251u8, 61u8, 252u8, 61u8, 253u8, 61u8,
+                    255u8, 61u8, 0u8, 62u8, 1u8, 62u8, 2u8, 62u8, 3u8, 62u8, 4u8, 62u8, 5u8, 62u8,
+                    6u8, 62u8, 7u8, 62u8, 8u8, 62u8, 9u8, 62u8, 10u8, 62u8, 11u8, 62u8, 12u8, 62u8,
+                    13u8, 62u8, 14u8, 62u8, 15u8, 62u8, 16u8, 62u8, 17u8, 62u8, 18u8, 62u8, 19u8,
+                    62u8, 20u8, 62u8, 21u8, 62u8, 22u8, 62u8, 23u8, 62u8, 24u8, 62u8, 25u8, 62u8,
+                    26u8, 62u8, 27u8, 62u8, 28u8, 62u8, 29u8, 62u8, 30u8, 62u8, 31u8, 62u8, 32u8,
+                    62u8, 33u8, 62u8, 34u8, 62u8, 35u8, 62u8, 36u8, 62u8, 37u8, 62u8, 38u8, 62u8,
+                    39u8, 62u8, 40u8, 62u8, 41u8, 62u8, 42u8, 62u8, 43u8, 62u8, 44u8, 62u8, 45u8,
+                    62u8, 46u8, 62u8, 47u8, 62u8, 48u8, 62u8, 49u8, 62u8, 50u8, 62u8, 52u8, 62u8,
+                    53u8, 62u8, 54u8, 62u8, 55u8, 62u8, 56u8, 62u8, 57u8, 62u8, 58u8, 62u8, 59u8,
+                    62u8, 60u8, 62u8, 61u8, 62u8, 62u8, 62u8, 63u8, 62u8, 64u8, 62u8, 65u8, 62u8,
+                    66u8, 62u8, 67u8, 62u8, 68u8, 62u8, 69u8, 62u8, 70u8, 62u8, 71u8, 62u8, 72u8,
+                    62u8, 73u8, 62u8, 74u8, 62u8, 75u8, 62u8, 76u8, 62u8, 77u8, 62u8, 78u8, 62u8,
+                    79u8, 62u8, 80u8, 62u8, 81u8, 62u8, 82u8, 62u8, 83u8, 62u8, 84u8, 62u8, 85u8,
+                    62u8, 86u8, 62u8, 87u8, 62u8, 88u8, 62u8, 89u8, 62u8, 90u8, 62u8, 91u8, 62u8,
+                    92u8, 62u8, 93u8, 62u8, 94u8, 62u8, 95u8, 62u8, 96u8, 62u8, 97u8, 62u8, 98u8,
+                    62u8, 99u8, 62u8, 100u8, 62u8, 101u8, 62u8, 102u8, 62u8, 103u8, 62u8, 105u8,
+                    62u8, 106u8, 62u8, 107u8, 62u8, 108u8, 62u8, 109u8, 62u8, 110u8, 62u8, 111u8,
+                    62u8, 112u8, 62u8, 113u8, 62u8, 114u8, 62u8, 115u8, 62u8, 116u8, 62u8, 117u8,
+                    62u8, 118u8, 62u8, 119u8, 62u8, 121u8, 62u8, 122u8, 62u8, 123u8, 62u8, 125u8,
+                    62u8, 127u8, 62u8, 128u8, 62u8, 129u8, 62u8, 130u8, 62u8, 133u8, 62u8, 134u8,
+                    62u8, 135u8, 62u8, 137u8, 62u8, 138u8, 62u8, 139u8, 62u8, 141u8, 62u8, 142u8,
+                    62u8, 144u8, 62u8, 145u8, 62u8, 148u8, 62u8, 150u8, 62u8, 151u8, 62u8, 152u8,
+                    62u8, 154u8, 62u8, 155u8, 62u8, 157u8, 62u8, 159u8, 62u8, 162u8, 62u8, 163u8,
+                    62u8, 165u8, 62u8, 166u8, 62u8, 169u8, 62u8, 172u8, 62u8, 174u8, 62u8, 177u8,
+                    62u8, 180u8, 62u8, 181u8, 62u8, 183u8, 62u8, 185u8, 62u8, 187u8, 62u8, 188u8,
+                    62u8, 190u8, 62u8, 191u8, 62u8, 193u8, 62u8, 194u8, 62u8, 196u8, 62u8, 197u8,
+                    62u8, 200u8, 62u8, 201u8, 62u8, 203u8, 62u8, 205u8, 62u8, 207u8, 62u8, 209u8,
+                    62u8, 212u8, 62u8, 215u8, 62u8, 218u8, 62u8, 220u8, 62u8, 222u8, 62u8, 223u8,
+                    62u8, 225u8, 62u8, 226u8, 62u8, 227u8, 62u8, 229u8, 62u8, 231u8, 62u8, 232u8,
+                    62u8, 233u8, 62u8, 234u8, 62u8, 237u8, 62u8, 238u8, 62u8, 239u8, 62u8, 241u8,
+                    62u8, 242u8, 62u8, 243u8, 62u8, 245u8, 62u8, 246u8, 62u8, 248u8, 62u8, 249u8,
+                    62u8, 252u8, 62u8, 254u8, 62u8, 255u8, 62u8, 0u8, 63u8, 2u8, 63u8, 3u8, 63u8,
+                    5u8, 63u8, 7u8, 63u8, 10u8, 63u8, 11u8, 63u8, 13u8, 63u8, 14u8, 63u8, 17u8,
+                    63u8, 20u8, 63u8, 22u8, 63u8, 25u8, 63u8, 28u8, 63u8, 29u8, 63u8, 31u8, 63u8,
+                    33u8, 63u8, 35u8, 63u8, 36u8, 63u8, 38u8, 63u8, 39u8, 63u8, 41u8, 63u8, 42u8,
+                    63u8, 44u8, 63u8, 45u8, 63u8, 48u8, 63u8, 49u8, 63u8, 51u8, 63u8, 53u8, 63u8,
+                    55u8, 63u8, 57u8, 63u8, 60u8, 63u8, 63u8, 63u8, 66u8, 63u8, 68u8, 63u8, 70u8,
+                    63u8, 71u8, 63u8, 73u8, 63u8, 74u8, 63u8, 75u8, 63u8, 77u8, 63u8, 79u8, 63u8,
+                    80u8, 63u8, 81u8, 63u8, 82u8, 63u8, 85u8, 63u8, 86u8, 63u8, 87u8, 63u8, 89u8,
+                    63u8, 90u8, 63u8, 91u8, 63u8, 93u8, 63u8, 94u8, 63u8, 96u8, 63u8, 97u8, 63u8,
+                    100u8, 63u8, 102u8, 63u8, 103u8, 63u8, 104u8, 63u8, 106u8, 63u8, 107u8, 63u8,
+                    109u8, 63u8, 111u8, 63u8, 114u8, 63u8, 115u8, 63u8, 117u8, 63u8, 118u8, 63u8,
+                    121u8, 63u8, 124u8, 63u8, 126u8, 63u8, 129u8, 63u8, 132u8, 63u8, 133u8, 63u8,
+                    135u8, 63u8, 137u8, 63u8, 139u8, 63u8, 140u8, 63u8, 142u8, 63u8, 143u8, 63u8,
+                    145u8, 63u8, 146u8, 63u8, 148u8, 63u8, 149u8, 63u8, 152u8, 63u8, 153u8, 63u8,
+                    155u8, 63u8, 157u8, 63u8, 159u8, 63u8, 161u8, 63u8, 164u8, 63u8, 167u8, 63u8,
+                    170u8, 63u8, 172u8, 63u8, 174u8, 63u8, 175u8, 63u8, 177u8, 63u8, 178u8, 63u8,
+                    179u8, 63u8, 181u8, 63u8, 183u8, 63u8, 184u8, 63u8, 185u8, 63u8, 186u8, 63u8,
+                    189u8, 63u8, 190u8, 63u8, 191u8, 63u8, 193u8, 63u8, 194u8, 63u8, 195u8, 63u8,
+                    197u8, 63u8, 198u8, 63u8, 200u8, 63u8, 201u8, 63u8, 204u8, 63u8, 206u8, 63u8,
+                    207u8, 63u8, 208u8, 63u8, 210u8, 63u8, 211u8, 63u8, 213u8, 63u8, 215u8, 63u8,
+                    218u8, 63u8, 219u8, 63u8, 221u8, 63u8, 222u8, 63u8, 225u8, 63u8, 228u8, 63u8,
+                    230u8, 63u8, 233u8, 63u8, 236u8, 63u8, 237u8, 63u8, 239u8, 63u8, 241u8, 63u8,
+                    243u8, 63u8, 244u8, 63u8, 246u8, 63u8, 247u8, 63u8, 249u8, 63u8, 250u8, 63u8,
+                    252u8, 63u8, 253u8, 63u8, 0u8, 64u8, 1u8, 64u8, 3u8, 64u8, 5u8, 64u8, 7u8,
+                    64u8, 9u8, 64u8, 12u8, 64u8, 15u8, 64u8, 18u8, 64u8, 20u8, 64u8, 22u8, 64u8,
+                    23u8, 64u8, 25u8, 64u8, 26u8, 64u8, 27u8, 64u8, 29u8, 64u8, 31u8, 64u8, 32u8,
+                    64u8, 33u8, 64u8, 34u8, 64u8, 37u8, 64u8, 38u8, 64u8, 39u8, 64u8, 41u8, 64u8,
+                    42u8, 64u8, 43u8, 64u8, 45u8, 64u8, 46u8, 64u8, 48u8, 64u8, 49u8, 64u8, 52u8,
+                    64u8, 54u8, 64u8, 55u8, 64u8, 56u8, 64u8, 58u8, 64u8, 59u8, 64u8, 61u8, 64u8,
+                    63u8, 64u8, 66u8, 64u8, 67u8, 64u8, 69u8, 64u8, 70u8, 64u8, 73u8, 64u8, 76u8,
+                    64u8, 78u8, 64u8, 81u8, 64u8, 84u8, 64u8, 85u8, 64u8, 87u8, 64u8, 89u8, 64u8,
+                    91u8, 64u8, 92u8, 64u8, 94u8, 64u8, 95u8, 64u8, 97u8, 64u8, 98u8, 64u8, 100u8,
+                    64u8, 101u8, 64u8, 104u8, 64u8, 105u8, 64u8, 107u8, 64u8, 109u8, 64u8, 111u8,
+                    64u8, 113u8, 64u8, 116u8, 64u8, 119u8, 64u8, 122u8, 64u8, 124u8, 64u8, 126u8,
+                    64u8, 127u8, 64u8, 129u8, 64u8, 130u8, 64u8, 132u8, 64u8, 133u8, 64u8, 134u8,
+                    64u8, 135u8, 64u8, 136u8, 64u8, 137u8, 64u8, 138u8, 64u8, 139u8, 64u8, 140u8,
+                    64u8, 141u8, 64u8, 142u8, 64u8, 143u8, 64u8, 144u8, 64u8, 145u8, 64u8, 146u8,
+                    64u8, 147u8, 64u8, 148u8, 64u8, 149u8, 64u8, 150u8, 64u8, 151u8, 64u8, 152u8,
+                    64u8, 153u8, 64u8, 154u8, 64u8, 155u8, 64u8, 156u8, 64u8, 157u8, 64u8, 158u8,
+                    64u8, 159u8, 64u8, 160u8, 64u8, 161u8, 64u8, 162u8, 64u8, 163u8, 64u8, 164u8,
+                    64u8, 165u8, 64u8, 166u8, 64u8, 167u8, 64u8, 168u8, 64u8, 169u8, 64u8, 170u8,
+                    64u8, 171u8, 64u8, 172u8, 64u8, 173u8, 64u8, 174u8, 64u8, 175u8, 64u8, 176u8,
+                    64u8, 177u8, 64u8, 178u8, 64u8, 179u8, 64u8, 180u8, 64u8, 181u8, 64u8, 182u8,
+                    64u8, 183u8, 64u8, 186u8, 64u8, 188u8, 64u8, 189u8, 64u8, 192u8, 64u8, 197u8,
+                    64u8, 198u8, 64u8, 200u8, 64u8, 202u8, 64u8, 204u8, 64u8, 206u8, 64u8, 208u8,
+                    64u8, 210u8, 64u8, 212u8, 64u8, 214u8, 64u8, 216u8, 64u8, 218u8, 64u8, 220u8,
+                    64u8, 222u8, 64u8, 224u8, 64u8, 226u8, 64u8, 228u8, 64u8, 230u8, 64u8, 232u8,
+                    64u8, 234u8, 64u8, 238u8, 64u8, 240u8, 64u8, 244u8, 64u8, 246u8, 64u8, 248u8,
+                    64u8, 250u8, 64u8, 252u8, 64u8, 254u8, 64u8, 0u8, 65u8, 2u8, 65u8, 4u8, 65u8,
+                    6u8, 65u8, 8u8, 65u8, 10u8, 65u8, 11u8, 65u8, 13u8, 65u8, 15u8, 65u8, 17u8,
+                    65u8, 19u8, 65u8, 21u8, 65u8, 23u8, 65u8, 25u8, 65u8, 27u8, 65u8, 29u8, 65u8,
+                    31u8, 65u8, 33u8, 65u8, 37u8, 65u8, 39u8, 65u8, 43u8, 65u8, 45u8, 65u8, 47u8,
+                    65u8, 49u8, 65u8, 51u8, 65u8, 53u8, 65u8, 55u8, 65u8, 57u8, 65u8, 59u8, 65u8,
+                    61u8, 65u8, 63u8, 65u8, 65u8, 65u8, 67u8, 65u8, 71u8, 65u8, 73u8, 65u8, 75u8,
+                    65u8, 77u8, 65u8, 79u8, 65u8, 81u8, 65u8, 83u8, 65u8, 85u8, 65u8, 86u8, 65u8,
+                    88u8, 65u8, 90u8, 65u8, 92u8, 65u8, 94u8, 65u8, 96u8, 65u8, 98u8, 65u8, 100u8,
+                    65u8, 102u8, 65u8, 104u8, 65u8, 106u8, 65u8, 108u8, 65u8, 112u8, 65u8, 114u8,
+                    65u8, 118u8, 65u8, 120u8, 65u8, 122u8, 65u8, 124u8, 65u8, 126u8, 65u8, 128u8,
+                    65u8, 130u8, 65u8, 131u8, 65u8, 133u8, 65u8, 135u8, 65u8, 137u8, 65u8, 138u8,
+                    65u8, 140u8, 65u8, 142u8, 65u8, 144u8, 65u8, 146u8, 65u8, 148u8, 65u8, 150u8,
+                    65u8, 152u8, 65u8, 154u8, 65u8, 156u8, 65u8, 158u8, 65u8, 160u8, 65u8, 162u8,
+                    65u8, 164u8, 65u8, 166u8, 65u8, 170u8, 65u8, 172u8, 65u8, 176u8, 65u8, 178u8,
+                    65u8, 180u8, 65u8, 182u8, 65u8, 184u8, 65u8, 186u8, 65u8, 188u8, 65u8, 190u8,
+                    65u8, 192u8, 65u8, 194u8, 65u8, 196u8, 65u8, 198u8, 65u8, 200u8, 65u8, 202u8,
+                    65u8, 204u8, 65u8, 206u8, 65u8, 208u8, 65u8, 210u8, 65u8, 212u8, 65u8, 214u8,
+                    65u8, 216u8, 65u8, 218u8, 65u8, 220u8, 65u8, 224u8, 65u8, 226u8, 65u8, 230u8,
+                    65u8, 232u8, 65u8, 234u8, 65u8, 236u8, 65u8, 238u8, 65u8, 240u8, 65u8, 242u8,
+                    65u8, 244u8, 65u8, 246u8, 65u8, 248u8, 65u8, 250u8, 65u8, 252u8, 65u8, 254u8,
+                    65u8, 0u8, 66u8, 2u8, 66u8, 4u8, 66u8, 6u8, 66u8, 10u8, 66u8, 13u8, 66u8, 16u8,
+                    66u8, 19u8, 66u8, 22u8, 66u8, 25u8, 66u8, 28u8, 66u8, 31u8, 66u8, 34u8, 66u8,
+                    37u8, 66u8, 40u8, 66u8, 43u8, 66u8, 46u8, 66u8, 49u8, 66u8, 52u8, 66u8, 55u8,
+                    66u8, 58u8, 66u8, 61u8, 66u8, 64u8, 66u8, 67u8, 66u8, 70u8, 66u8, 73u8, 66u8,
+                    76u8, 66u8, 79u8, 66u8, 82u8, 66u8, 85u8, 66u8, 88u8, 66u8, 91u8, 66u8, 98u8,
+                    66u8, 102u8, 66u8, 107u8, 66u8, 112u8, 66u8, 117u8, 66u8, 122u8, 66u8, 127u8,
+                    66u8, 132u8, 66u8, 137u8, 66u8, 142u8, 66u8, 147u8, 66u8, 150u8, 66u8, 153u8,
+                    66u8, 156u8, 66u8, 158u8, 66u8, 161u8, 66u8, 163u8, 66u8, 167u8, 66u8, 169u8,
+                    66u8, 173u8, 66u8, 176u8, 66u8, 179u8, 66u8, 183u8, 66u8, 186u8, 66u8, 187u8,
+                    66u8, 190u8, 66u8, 193u8, 66u8, 196u8, 66u8, 199u8, 66u8, 200u8, 66u8, 202u8,
+                    66u8, 204u8, 66u8, 207u8, 66u8, 208u8, 66u8, 209u8, 66u8, 210u8, 66u8, 211u8,
+                    66u8, 212u8, 66u8, 213u8, 66u8, 214u8, 66u8, 215u8, 66u8, 216u8, 66u8, 217u8,
+                    66u8, 220u8, 66u8, 223u8, 66u8, 226u8, 66u8, 229u8, 66u8, 233u8, 66u8, 236u8,
+                    66u8, 239u8, 66u8, 242u8, 66u8, 245u8, 66u8, 248u8, 66u8, 251u8, 66u8, 254u8,
+                    66u8, 1u8, 67u8, 4u8, 67u8, 8u8, 67u8, 11u8, 67u8, 14u8, 67u8, 17u8, 67u8,
+                    20u8, 67u8, 24u8, 67u8, 27u8, 67u8, 30u8, 67u8, 33u8, 67u8, 37u8, 67u8, 40u8,
+                    67u8, 43u8, 67u8, 46u8, 67u8, 49u8, 67u8, 52u8, 67u8, 56u8, 67u8, 59u8, 67u8,
+                    62u8, 67u8, 65u8, 67u8, 68u8, 67u8, 71u8, 67u8, 74u8, 67u8, 77u8, 67u8, 80u8,
+                    67u8, 83u8, 67u8, 86u8, 67u8, 89u8, 67u8, 92u8, 67u8, 95u8, 67u8, 98u8, 67u8,
+                    101u8, 67u8, 104u8, 67u8, 107u8, 67u8, 110u8, 67u8, 113u8, 67u8, 116u8, 67u8,
+                    119u8, 67u8, 122u8, 67u8, 125u8, 67u8, 129u8, 67u8, 132u8, 67u8, 135u8, 67u8,
+                    138u8, 67u8, 142u8, 67u8, 145u8, 67u8, 148u8, 67u8, 151u8, 67u8, 154u8, 67u8,
+                    157u8, 67u8, 160u8, 67u8, 163u8, 67u8, 166u8, 67u8, 169u8, 67u8, 172u8, 67u8,
+                    175u8, 67u8, 178u8, 67u8, 181u8, 67u8, 184u8, 67u8, 187u8, 67u8, 190u8, 67u8,
+                    193u8, 67u8, 196u8, 67u8, 199u8, 67u8, 202u8, 67u8, 205u8, 67u8, 208u8, 67u8,
+                    211u8, 67u8, 214u8, 67u8, 217u8, 67u8, 220u8, 67u8, 223u8, 67u8, 226u8, 67u8,
+                    229u8, 67u8, 232u8, 67u8, 235u8, 67u8, 238u8, 67u8, 242u8, 67u8, 245u8, 67u8,
+                    248u8, 67u8, 251u8, 67u8, 254u8, 67u8, 1u8, 68u8, 4u8, 68u8, 8u8, 68u8, 12u8,
+                    68u8, 15u8, 68u8, 18u8, 68u8, 21u8, 68u8, 24u8, 68u8, 27u8, 68u8, 30u8, 68u8,
+                    33u8, 68u8, 36u8, 68u8, 39u8, 68u8, 42u8, 68u8, 46u8, 68u8, 49u8, 68u8, 52u8,
+                    68u8, 55u8, 68u8, 58u8, 68u8, 62u8, 68u8, 65u8, 68u8, 68u8, 68u8, 71u8, 68u8,
+                    74u8, 68u8, 77u8, 68u8, 80u8, 68u8, 83u8, 68u8, 86u8, 68u8, 89u8, 68u8, 93u8,
+                    68u8, 96u8, 68u8, 100u8, 68u8, 103u8, 68u8, 106u8, 68u8, 109u8, 68u8, 112u8,
+                    68u8, 115u8, 68u8, 118u8, 68u8, 121u8, 68u8, 124u8, 68u8, 127u8, 68u8, 130u8,
+                    68u8, 133u8, 68u8, 137u8, 68u8, 140u8, 68u8, 143u8, 68u8, 146u8, 68u8, 149u8,
+                    68u8, 152u8, 68u8, 156u8, 68u8, 159u8, 68u8, 163u8, 68u8, 167u8, 68u8, 170u8,
+                    68u8, 173u8, 68u8, 176u8, 68u8, 179u8, 68u8, 183u8, 68u8, 187u8, 68u8, 190u8,
+                    68u8, 193u8, 68u8, 196u8, 68u8, 199u8, 68u8, 202u8, 68u8, 205u8, 68u8, 208u8,
+                    68u8, 211u8, 68u8, 214u8, 68u8, 217u8, 68u8, 220u8, 68u8, 224u8, 68u8, 227u8,
+                    68u8, 230u8, 68u8, 233u8, 68u8, 236u8, 68u8, 239u8, 68u8, 242u8, 68u8, 245u8,
+                    68u8, 248u8, 68u8, 251u8, 68u8, 254u8, 68u8, 1u8, 69u8, 4u8, 69u8, 7u8, 69u8,
+                    10u8, 69u8, 13u8, 69u8, 16u8, 69u8, 19u8, 69u8, 22u8, 69u8, 25u8, 69u8, 29u8,
+                    69u8, 32u8, 69u8, 35u8, 69u8, 38u8, 69u8, 41u8, 69u8, 44u8, 69u8, 48u8, 69u8,
+                    51u8, 69u8, 54u8, 69u8, 57u8, 69u8, 60u8, 69u8, 63u8, 69u8, 66u8, 69u8, 69u8,
+                    69u8, 72u8, 69u8, 75u8, 69u8, 78u8, 69u8, 81u8, 69u8, 85u8, 69u8, 88u8, 69u8,
+                    91u8, 69u8, 94u8, 69u8, 97u8, 69u8, 100u8, 69u8, 103u8, 69u8, 106u8, 69u8,
+                    109u8, 69u8, 112u8, 69u8, 115u8, 69u8, 118u8, 69u8, 121u8, 69u8, 124u8, 69u8,
+                    127u8, 69u8, 130u8, 69u8, 133u8, 69u8, 136u8, 69u8, 139u8, 69u8, 143u8, 69u8,
+                    146u8, 69u8, 149u8, 69u8, 152u8, 69u8, 155u8, 69u8, 158u8, 69u8, 162u8, 69u8,
+                    165u8, 69u8, 168u8, 69u8, 171u8, 69u8, 174u8, 69u8, 177u8, 69u8, 180u8, 69u8,
+                    183u8, 69u8, 186u8, 69u8, 190u8, 69u8, 193u8, 69u8, 196u8, 69u8, 199u8, 69u8,
+                    203u8, 69u8, 206u8, 69u8, 209u8, 69u8, 212u8, 69u8, 215u8, 69u8, 218u8, 69u8,
+                    221u8, 69u8, 225u8, 69u8, 229u8, 69u8, 233u8, 69u8, 236u8, 69u8, 240u8, 69u8,
+                    243u8, 69u8, 246u8, 69u8, 249u8, 69u8, 252u8, 69u8, 255u8, 69u8, 2u8, 70u8,
+                    5u8, 70u8, 8u8, 70u8, 11u8, 70u8, 14u8, 70u8, 18u8, 70u8, 21u8, 70u8, 24u8,
+                    70u8, 27u8, 70u8, 30u8, 70u8, 33u8, 70u8, 36u8, 70u8, 40u8, 70u8, 43u8, 70u8,
+                    46u8, 70u8, 50u8, 70u8, 54u8, 70u8, 57u8, 70u8, 60u8, 70u8, 63u8, 70u8, 66u8,
+                    70u8, 69u8, 70u8, 72u8, 70u8, 75u8, 70u8, 78u8, 70u8, 81u8, 70u8, 85u8, 70u8,
+                    88u8, 70u8, 92u8, 70u8, 95u8, 70u8, 99u8, 70u8, 102u8, 70u8, 105u8, 70u8,
+                    108u8, 70u8, 112u8, 70u8, 115u8, 70u8, 118u8, 70u8, 122u8, 70u8, 126u8, 70u8,
+                    129u8, 70u8, 132u8, 70u8, 135u8, 70u8, 138u8, 70u8, 141u8, 70u8, 144u8, 70u8,
+                    147u8, 70u8, 150u8, 70u8, 153u8, 70u8, 156u8, 70u8, 159u8, 70u8, 162u8, 70u8,
+                    165u8, 70u8, 169u8, 70u8, 172u8, 70u8, 176u8, 70u8, 179u8, 70u8, 183u8, 70u8,
+                    186u8, 70u8, 190u8, 70u8, 194u8, 70u8, 198u8, 70u8, 201u8, 70u8, 204u8, 70u8,
+                    207u8, 70u8, 211u8, 70u8, 215u8, 70u8, 219u8, 70u8, 223u8, 70u8, 226u8, 70u8,
+                    229u8, 70u8, 232u8, 70u8, 235u8, 70u8, 238u8, 70u8, 241u8, 70u8, 244u8, 70u8,
+                    247u8, 70u8, 251u8, 70u8, 254u8, 70u8, 1u8, 71u8, 4u8, 71u8, 7u8, 71u8, 11u8,
+                    71u8, 14u8, 71u8, 18u8, 71u8, 22u8, 71u8, 25u8, 71u8, 28u8, 71u8, 31u8, 71u8,
+                    34u8, 71u8, 37u8, 71u8, 40u8, 71u8, 44u8, 71u8, 48u8, 71u8, 52u8, 71u8, 55u8,
+                    71u8, 58u8, 71u8, 62u8, 71u8, 65u8, 71u8, 68u8, 71u8, 71u8, 71u8, 75u8, 71u8,
+                    78u8, 71u8, 81u8, 71u8, 84u8, 71u8, 87u8, 71u8, 90u8, 71u8, 94u8, 71u8, 97u8,
+                    71u8, 100u8, 71u8, 103u8, 71u8, 106u8, 71u8, 109u8, 71u8, 112u8, 71u8, 116u8,
+                    71u8, 120u8, 71u8, 123u8, 71u8, 127u8, 71u8, 130u8, 71u8, 134u8, 71u8, 137u8,
+                    71u8, 140u8, 71u8, 143u8, 71u8, 147u8, 71u8, 151u8, 71u8, 154u8, 71u8, 158u8,
+                    71u8, 161u8, 71u8, 165u8, 71u8, 168u8, 71u8, 171u8, 71u8, 174u8, 71u8, 177u8,
+                    71u8, 180u8, 71u8, 183u8, 71u8, 187u8, 71u8, 191u8, 71u8, 195u8, 71u8, 199u8,
+                    71u8, 202u8, 71u8, 205u8, 71u8, 208u8, 71u8, 211u8, 71u8, 214u8, 71u8, 217u8,
+                    71u8, 220u8, 71u8, 223u8, 71u8, 226u8, 71u8, 229u8, 71u8, 232u8, 71u8, 235u8,
+                    71u8, 239u8, 71u8, 242u8, 71u8, 245u8, 71u8, 248u8, 71u8, 251u8, 71u8, 254u8,
+                    71u8, 1u8, 72u8, 4u8, 72u8, 7u8, 72u8, 10u8, 72u8, 13u8, 72u8, 16u8, 72u8,
+                    19u8, 72u8, 23u8, 72u8, 27u8, 72u8, 31u8, 72u8, 34u8, 72u8, 37u8, 72u8, 40u8,
+                    72u8, 43u8, 72u8, 47u8, 72u8, 50u8, 72u8, 54u8, 72u8, 57u8, 72u8, 60u8, 72u8,
+                    64u8, 72u8, 68u8, 72u8, 71u8, 72u8, 74u8, 72u8, 77u8, 72u8, 80u8, 72u8, 83u8,
+                    72u8, 86u8, 72u8, 89u8, 72u8, 92u8, 72u8, 95u8, 72u8, 98u8, 72u8, 101u8, 72u8,
+                    104u8, 72u8, 107u8, 72u8, 110u8, 72u8, 113u8, 72u8, 116u8, 72u8, 119u8, 72u8,
+                    122u8, 72u8, 125u8, 72u8, 129u8, 72u8, 132u8, 72u8, 135u8, 72u8, 138u8, 72u8,
+                    141u8, 72u8, 144u8, 72u8, 148u8, 72u8, 152u8, 72u8, 155u8, 72u8, 158u8, 72u8,
+                    161u8, 72u8, 164u8, 72u8, 167u8, 72u8, 170u8, 72u8, 174u8, 72u8, 177u8, 72u8,
+                    180u8, 72u8, 183u8, 72u8, 186u8, 72u8, 190u8, 72u8, 194u8, 72u8, 197u8, 72u8,
+                    200u8, 72u8, 203u8, 72u8, 207u8, 72u8, 210u8, 72u8, 213u8, 72u8, 217u8, 72u8,
+                    221u8, 72u8, 224u8, 72u8, 227u8, 72u8, 230u8, 72u8, 234u8, 72u8, 237u8, 72u8,
+                    240u8, 72u8, 243u8, 72u8, 246u8, 72u8, 249u8, 72u8, 252u8, 72u8, 255u8, 72u8,
+                    3u8, 73u8, 6u8, 73u8, 9u8, 73u8, 12u8, 73u8, 16u8, 73u8, 19u8, 73u8, 22u8,
+                    73u8, 25u8, 73u8, 28u8, 73u8, 32u8, 73u8, 36u8, 73u8, 39u8, 73u8, 42u8, 73u8,
+                    45u8, 73u8, 49u8, 73u8, 52u8, 73u8, 56u8, 73u8, 59u8, 73u8, 62u8, 73u8, 65u8,
+                    73u8, 69u8, 73u8, 72u8, 73u8, 75u8, 73u8, 78u8, 73u8, 81u8, 73u8, 84u8, 73u8,
+                    87u8, 73u8, 90u8, 73u8, 94u8, 73u8, 97u8, 73u8, 100u8, 73u8, 103u8, 73u8,
+                    106u8, 73u8, 109u8, 73u8, 112u8, 73u8, 116u8, 73u8, 119u8, 73u8, 123u8, 73u8,
+                    127u8, 73u8, 131u8, 73u8, 134u8, 73u8, 137u8, 73u8, 140u8, 73u8, 143u8, 73u8,
+                    146u8, 73u8, 149u8, 73u8, 152u8, 73u8, 155u8, 73u8, 39u8, 39u8, 194u8, 186u8,
+                    47u8, 226u8, 130u8, 128u8, 79u8, 108u8, 108u8, 39u8, 114u8, 110u8, 108u8, 32u8,
+                    99u8, 204u8, 184u8, 89u8, 204u8, 181u8, 203u8, 137u8, 39u8, 206u8, 188u8, 44u8,
+                    65u8, 69u8, 67u8, 204u8, 166u8, 68u8, 204u8, 181u8, 120u8, 79u8, 204u8, 184u8,
+                    97u8, 101u8, 99u8, 204u8, 166u8, 226u8, 136u8, 130u8, 204u8, 181u8, 216u8,
+                    169u8, 111u8, 204u8, 184u8, 68u8, 204u8, 181u8, 100u8, 204u8, 181u8, 196u8,
+                    148u8, 196u8, 149u8, 72u8, 204u8, 181u8, 104u8, 204u8, 181u8, 105u8, 108u8,
+                    74u8, 105u8, 106u8, 108u8, 194u8, 183u8, 108u8, 194u8, 183u8, 76u8, 204u8,
+                    184u8, 108u8, 204u8, 184u8, 201u8, 178u8, 39u8, 110u8, 195u8, 150u8, 79u8,
+                    69u8, 111u8, 101u8, 198u8, 171u8, 84u8, 204u8, 181u8, 116u8, 204u8, 181u8,
+                    102u8, 98u8, 204u8, 181u8, 39u8, 66u8, 98u8, 204u8, 132u8, 98u8, 204u8, 132u8,
+                    98u8, 67u8, 39u8, 68u8, 204u8, 181u8, 39u8, 68u8, 100u8, 204u8, 132u8, 103u8,
+                    70u8, 204u8, 166u8, 102u8, 204u8, 166u8, 71u8, 39u8, 108u8, 108u8, 204u8,
+                    181u8, 75u8, 39u8, 107u8, 204u8, 148u8, 108u8, 204u8, 181u8, 78u8, 204u8,
+                    166u8, 110u8, 204u8, 169u8, 79u8, 204u8, 181u8, 79u8, 39u8, 111u8, 39u8, 39u8,
+                    80u8, 112u8, 204u8, 148u8, 82u8, 50u8, 39u8, 84u8, 116u8, 204u8, 148u8, 84u8,
+                    204u8, 168u8, 39u8, 89u8, 121u8, 204u8, 148u8, 90u8, 204u8, 181u8, 122u8,
+                    204u8, 181u8, 51u8, 50u8, 204u8, 181u8, 53u8, 115u8, 195u8, 190u8, 108u8,
+                    108u8, 108u8, 33u8, 68u8, 197u8, 189u8, 68u8, 197u8, 190u8, 100u8, 197u8,
+                    190u8, 76u8, 74u8, 76u8, 106u8, 108u8, 106u8, 78u8, 74u8, 78u8, 106u8, 110u8,
+                    106u8, 196u8, 130u8, 196u8, 131u8, 196u8, 172u8, 196u8, 173u8, 197u8, 142u8,
+                    197u8, 143u8, 197u8, 172u8, 197u8, 173u8, 71u8, 204u8, 181u8, 103u8, 204u8,
+                    181u8, 196u8, 158u8, 196u8, 159u8, 68u8, 90u8, 68u8, 122u8, 100u8, 122u8,
+                    196u8, 163u8, 79u8, 204u8, 184u8, 204u8, 129u8, 197u8, 162u8, 198u8, 171u8,
+                    51u8, 56u8, 56u8, 90u8, 204u8, 166u8, 122u8, 204u8, 166u8, 195u8, 133u8, 195u8,
+                    165u8, 99u8, 204u8, 184u8, 84u8, 204u8, 184u8, 63u8, 85u8, 204u8, 181u8, 69u8,
+                    204u8, 184u8, 101u8, 204u8, 184u8, 74u8, 204u8, 181u8, 106u8, 204u8, 181u8,
+                    114u8, 204u8, 181u8, 89u8, 204u8, 181u8, 121u8, 204u8, 181u8, 97u8, 98u8,
+                    204u8, 148u8, 100u8, 204u8, 168u8, 100u8, 204u8, 148u8, 199u8, 157u8, 199u8,
+                    157u8, 203u8, 158u8, 234u8, 158u8, 147u8, 103u8, 204u8, 148u8, 103u8, 121u8,
+                    104u8, 204u8, 148u8, 105u8, 204u8, 181u8, 105u8, 105u8, 108u8, 204u8, 180u8,
+                    108u8, 204u8, 168u8, 108u8, 200u8, 157u8, 119u8, 114u8, 110u8, 204u8, 166u8,
+                    110u8, 204u8, 168u8, 111u8, 204u8, 181u8, 111u8, 225u8, 180u8, 135u8, 114u8,
+                    204u8, 169u8, 114u8, 204u8, 168u8, 115u8, 204u8, 168u8, 117u8, 121u8, 122u8,
+                    204u8, 168u8, 200u8, 157u8, 63u8, 113u8, 204u8, 148u8, 100u8, 122u8, 100u8,
+                    200u8, 157u8, 100u8, 202u8, 145u8, 116u8, 115u8, 116u8, 202u8, 131u8, 116u8,
+                    201u8, 149u8, 102u8, 197u8, 139u8, 108u8, 115u8, 108u8, 122u8, 225u8, 163u8,
+                    180u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 213u8, 153u8, 60u8, 62u8,
+                    94u8, 94u8, 39u8, 39u8, 39u8, 58u8, 213u8, 153u8, 45u8, 203u8, 135u8, 224u8,
+                    165u8, 177u8, 194u8, 176u8, 105u8, 126u8, 39u8, 39u8, 225u8, 163u8, 179u8,
+                    225u8, 163u8, 181u8, 203u8, 129u8, 39u8, 39u8, 39u8, 39u8, 39u8, 58u8, 203u8,
+                    170u8, 204u8, 132u8, 204u8, 134u8, 217u8, 176u8, 204u8, 134u8, 204u8, 135u8,
+                    204u8, 130u8, 204u8, 147u8, 217u8, 144u8, 204u8, 177u8, 204u8, 166u8, 204u8,
+                    168u8, 204u8, 166u8, 204u8, 181u8, 204u8, 184u8, 204u8, 166u8, 204u8, 128u8,
+                    204u8, 129u8, 204u8, 131u8, 204u8, 147u8, 204u8, 168u8, 204u8, 179u8, 205u8,
+                    144u8, 204u8, 135u8, 204u8, 138u8, 204u8, 134u8, 226u8, 177u8, 181u8, 39u8,
+                    203u8, 143u8, 208u8, 152u8, 225u8, 180u8, 142u8, 105u8, 201u8, 148u8, 234u8,
+                    156u8, 191u8, 59u8, 74u8, 39u8, 194u8, 183u8, 65u8, 66u8, 69u8, 90u8, 72u8,
+                    79u8, 204u8, 181u8, 108u8, 75u8, 201u8, 133u8, 77u8, 78u8, 79u8, 80u8, 198u8,
+                    169u8, 84u8, 89u8, 88u8, 97u8, 195u8, 159u8, 121u8, 225u8, 186u8, 159u8, 234u8,
+                    158u8, 147u8, 110u8, 204u8, 169u8, 79u8, 204u8, 181u8, 105u8, 196u8, 184u8,
+                    118u8, 111u8, 112u8, 111u8, 225u8, 180u8, 155u8, 117u8, 201u8, 184u8, 195u8,
+                    159u8, 79u8, 204u8, 181u8, 89u8, 201u8, 184u8, 207u8, 128u8, 207u8, 130u8,
+                    70u8, 50u8, 198u8, 168u8, 196u8, 184u8, 112u8, 99u8, 106u8, 79u8, 204u8, 181u8,
+                    234u8, 158u8, 147u8, 195u8, 158u8, 195u8, 190u8, 67u8, 77u8, 198u8, 134u8,
+                    234u8, 156u8, 190u8, 234u8, 158u8, 146u8, 83u8, 108u8, 74u8, 65u8, 98u8, 204u8,
+                    132u8, 66u8, 206u8, 147u8, 69u8, 51u8, 208u8, 141u8, 75u8, 201u8, 133u8, 77u8,
+                    72u8, 79u8, 206u8, 160u8, 80u8, 67u8, 84u8, 89u8, 206u8, 166u8, 88u8, 98u8,
+                    108u8, 98u8, 108u8, 79u8, 97u8, 54u8, 202u8, 153u8, 114u8, 101u8, 201u8, 156u8,
+                    225u8, 180u8, 142u8, 196u8, 184u8, 202u8, 141u8, 202u8, 156u8, 111u8, 207u8,
+                    128u8, 112u8, 99u8, 225u8, 180u8, 155u8, 121u8, 201u8, 184u8, 120u8, 203u8,
+                    137u8, 98u8, 198u8, 133u8, 105u8, 198u8, 133u8, 225u8, 180u8, 153u8, 234u8,
+                    158u8, 147u8, 115u8, 105u8, 106u8, 104u8, 204u8, 181u8, 208u8, 185u8, 119u8,
+                    98u8, 204u8, 181u8, 98u8, 204u8, 181u8, 206u8, 168u8, 207u8, 136u8, 79u8,
+                    204u8, 181u8, 111u8, 204u8, 181u8, 86u8, 118u8, 209u8, 160u8, 210u8, 134u8,
+                    210u8, 135u8, 119u8, 210u8, 134u8, 210u8, 135u8, 208u8, 141u8, 204u8, 166u8,
+                    208u8, 185u8, 204u8, 166u8, 98u8, 204u8, 181u8, 98u8, 204u8, 181u8, 206u8,
+                    147u8, 39u8, 114u8, 39u8, 206u8, 147u8, 204u8, 181u8, 114u8, 204u8, 181u8,
+                    208u8, 150u8, 204u8, 169u8, 208u8, 182u8, 204u8, 169u8, 51u8, 204u8, 166u8,
+                    201u8, 156u8, 204u8, 166u8, 75u8, 204u8, 169u8, 196u8, 184u8, 204u8, 169u8,
+                    75u8, 204u8, 181u8, 196u8, 184u8, 204u8, 181u8, 72u8, 204u8, 169u8, 202u8,
+                    156u8, 204u8, 169u8, 67u8, 204u8, 166u8, 99u8, 204u8, 166u8, 84u8, 204u8,
+                    169u8, 225u8, 180u8, 155u8, 204u8, 169u8, 89u8, 121u8, 89u8, 204u8, 181u8,
+                    121u8, 204u8, 181u8, 88u8, 204u8, 169u8, 104u8, 101u8, 210u8, 188u8, 204u8,
+                    168u8, 101u8, 204u8, 168u8, 108u8, 201u8, 133u8, 204u8, 166u8, 208u8, 187u8,
+                    204u8, 166u8, 72u8, 204u8, 166u8, 202u8, 156u8, 204u8, 166u8, 72u8, 204u8,
+                    166u8, 202u8, 156u8, 204u8, 166u8, 210u8, 182u8, 210u8, 183u8, 77u8, 204u8,
+                    166u8, 202u8, 141u8, 204u8, 166u8, 105u8, 65u8, 69u8, 97u8, 101u8, 198u8,
+                    143u8, 199u8, 157u8, 51u8, 200u8, 157u8, 79u8, 204u8, 181u8, 111u8, 204u8,
+                    181u8, 100u8, 199u8, 182u8, 71u8, 201u8, 162u8, 198u8, 144u8, 234u8, 158u8,
+                    147u8, 113u8, 87u8, 119u8, 225u8, 138u8, 174u8, 225u8, 136u8, 134u8, 225u8,
+                    140u8, 163u8, 225u8, 137u8, 161u8, 85u8, 83u8, 206u8, 166u8, 79u8, 39u8, 39u8,
+                    119u8, 113u8, 113u8, 225u8, 186u8, 159u8, 104u8, 200u8, 183u8, 110u8, 201u8,
+                    176u8, 110u8, 117u8, 103u8, 102u8, 111u8, 213u8, 165u8, 214u8, 130u8, 58u8,
+                    204u8, 129u8, 204u8, 129u8, 214u8, 154u8, 214u8, 153u8, 214u8, 150u8, 214u8,
+                    152u8, 204u8, 138u8, 204u8, 163u8, 204u8, 135u8, 204u8, 135u8, 108u8, 204u8,
+                    135u8, 204u8, 135u8, 58u8, 204u8, 135u8, 204u8, 163u8, 108u8, 118u8, 39u8,
+                    108u8, 111u8, 108u8, 108u8, 108u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 194u8,
+                    186u8, 47u8, 226u8, 130u8, 128u8, 226u8, 130u8, 128u8, 194u8, 186u8, 47u8,
+                    226u8, 130u8, 128u8, 226u8, 130u8, 128u8, 226u8, 130u8, 128u8, 44u8, 216u8,
+                    185u8, 204u8, 129u8, 204u8, 147u8, 217u8, 144u8, 108u8, 217u8, 180u8, 217u8,
+                    136u8, 217u8, 180u8, 108u8, 217u8, 149u8, 217u8, 137u8, 217u8, 180u8, 108u8,
+                    217u8, 137u8, 219u8, 155u8, 216u8, 179u8, 219u8, 155u8, 217u8, 137u8, 204u8,
+                    130u8, 217u8, 137u8, 219u8, 155u8, 111u8, 217u8, 137u8, 204u8, 139u8, 204u8,
+                    129u8, 204u8, 147u8, 204u8, 138u8, 204u8, 131u8, 204u8, 169u8, 204u8, 146u8,
+                    204u8, 134u8, 204u8, 132u8, 204u8, 134u8, 204u8, 130u8, 204u8, 163u8, 204u8,
+                    148u8, 217u8, 149u8, 46u8, 108u8, 111u8, 86u8, 201u8, 133u8, 194u8, 186u8,
+                    47u8, 226u8, 130u8, 128u8, 44u8, 216u8, 140u8, 42u8, 217u8, 137u8, 218u8,
+                    161u8, 108u8, 217u8, 180u8, 108u8, 217u8, 149u8, 108u8, 217u8, 180u8, 217u8,
+                    136u8, 217u8, 180u8, 217u8, 136u8, 204u8, 147u8, 217u8, 180u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 137u8, 216u8, 149u8, 217u8, 137u8, 219u8, 155u8, 216u8,
+                    173u8, 217u8, 148u8, 216u8, 173u8, 219u8, 155u8, 216u8, 175u8, 216u8, 149u8,
+                    218u8, 138u8, 216u8, 149u8, 216u8, 175u8, 219u8, 155u8, 216u8, 177u8, 216u8,
+                    149u8, 216u8, 177u8, 204u8, 134u8, 216u8, 177u8, 219u8, 155u8, 216u8, 181u8,
+                    219u8, 155u8, 216u8, 183u8, 219u8, 155u8, 218u8, 161u8, 219u8, 155u8, 217u8,
+                    129u8, 218u8, 161u8, 219u8, 155u8, 217u8, 131u8, 217u8, 131u8, 217u8, 131u8,
+                    219u8, 155u8, 218u8, 175u8, 219u8, 155u8, 217u8, 132u8, 204u8, 134u8, 217u8,
+                    132u8, 219u8, 155u8, 217u8, 137u8, 217u8, 137u8, 216u8, 149u8, 217u8, 137u8,
+                    219u8, 155u8, 111u8, 111u8, 219u8, 128u8, 216u8, 169u8, 217u8, 136u8, 204u8,
+                    134u8, 217u8, 136u8, 204u8, 147u8, 217u8, 136u8, 217u8, 176u8, 217u8, 136u8,
+                    204u8, 130u8, 217u8, 136u8, 219u8, 155u8, 217u8, 137u8, 217u8, 137u8, 204u8,
+                    134u8, 217u8, 187u8, 217u8, 137u8, 219u8, 155u8, 217u8, 137u8, 45u8, 111u8,
+                    204u8, 138u8, 204u8, 134u8, 204u8, 135u8, 204u8, 135u8, 216u8, 175u8, 204u8,
+                    130u8, 216u8, 177u8, 204u8, 130u8, 46u8, 108u8, 217u8, 162u8, 217u8, 163u8,
+                    217u8, 164u8, 111u8, 217u8, 166u8, 86u8, 201u8, 133u8, 217u8, 169u8, 216u8,
+                    161u8, 205u8, 136u8, 217u8, 133u8, 205u8, 136u8, 111u8, 204u8, 130u8, 46u8,
+                    46u8, 58u8, 58u8, 204u8, 135u8, 204u8, 135u8, 220u8, 188u8, 204u8, 129u8,
+                    216u8, 168u8, 219u8, 155u8, 217u8, 137u8, 204u8, 134u8, 218u8, 172u8, 217u8,
+                    131u8, 219u8, 155u8, 221u8, 148u8, 217u8, 134u8, 216u8, 149u8, 217u8, 134u8,
+                    204u8, 134u8, 216u8, 177u8, 217u8, 148u8, 218u8, 151u8, 216u8, 149u8, 216u8,
+                    173u8, 217u8, 148u8, 216u8, 179u8, 204u8, 130u8, 79u8, 108u8, 204u8, 132u8,
+                    204u8, 135u8, 204u8, 130u8, 204u8, 136u8, 39u8, 39u8, 95u8, 216u8, 168u8,
+                    217u8, 148u8, 218u8, 162u8, 219u8, 155u8, 217u8, 133u8, 219u8, 155u8, 217u8,
+                    137u8, 217u8, 148u8, 221u8, 148u8, 216u8, 175u8, 204u8, 164u8, 204u8, 163u8,
+                    216u8, 181u8, 204u8, 164u8, 204u8, 163u8, 218u8, 175u8, 217u8, 136u8, 216u8,
+                    178u8, 204u8, 130u8, 216u8, 168u8, 219u8, 162u8, 217u8, 137u8, 219u8, 155u8,
+                    219u8, 162u8, 216u8, 177u8, 204u8, 134u8, 204u8, 135u8, 217u8, 137u8, 204u8,
+                    134u8, 204u8, 135u8, 218u8, 161u8, 218u8, 161u8, 217u8, 137u8, 217u8, 140u8,
+                    217u8, 140u8, 204u8, 135u8, 204u8, 136u8, 204u8, 163u8, 204u8, 164u8, 204u8,
+                    139u8, 217u8, 140u8, 217u8, 141u8, 204u8, 147u8, 205u8, 144u8, 205u8, 148u8,
+                    205u8, 149u8, 205u8, 144u8, 205u8, 146u8, 204u8, 134u8, 204u8, 135u8, 204u8,
+                    135u8, 58u8, 224u8, 164u8, 133u8, 224u8, 165u8, 134u8, 224u8, 164u8, 133u8,
+                    224u8, 164u8, 190u8, 224u8, 164u8, 176u8, 224u8, 165u8, 141u8, 224u8, 164u8,
+                    135u8, 224u8, 164u8, 143u8, 224u8, 165u8, 133u8, 224u8, 164u8, 143u8, 224u8,
+                    165u8, 134u8, 224u8, 164u8, 143u8, 224u8, 165u8, 135u8, 224u8, 164u8, 133u8,
+                    224u8, 165u8, 137u8, 224u8, 164u8, 133u8, 224u8, 164u8, 190u8, 224u8, 165u8,
+                    134u8, 224u8, 164u8, 133u8, 224u8, 164u8, 190u8, 224u8, 165u8, 135u8, 224u8,
+                    164u8, 133u8, 224u8, 164u8, 190u8, 224u8, 165u8, 136u8, 204u8, 163u8, 204u8,
+                    177u8, 204u8, 128u8, 204u8, 129u8, 224u8, 165u8, 164u8, 224u8, 165u8, 164u8,
+                    111u8, 217u8, 169u8, 63u8, 204u8, 134u8, 204u8, 135u8, 224u8, 166u8, 133u8,
+                    224u8, 166u8, 190u8, 204u8, 163u8, 224u8, 166u8, 139u8, 224u8, 167u8, 131u8,
+                    224u8, 166u8, 139u8, 224u8, 167u8, 131u8, 79u8, 56u8, 57u8, 204u8, 135u8,
+                    224u8, 166u8, 131u8, 224u8, 168u8, 133u8, 224u8, 168u8, 190u8, 224u8, 169u8,
+                    178u8, 224u8, 168u8, 191u8, 224u8, 169u8, 178u8, 224u8, 169u8, 128u8, 224u8,
+                    169u8, 179u8, 224u8, 169u8, 129u8, 224u8, 169u8, 179u8, 224u8, 169u8, 130u8,
+                    224u8, 169u8, 178u8, 224u8, 169u8, 135u8, 224u8, 168u8, 133u8, 224u8, 169u8,
+                    136u8, 224u8, 168u8, 133u8, 224u8, 169u8, 140u8, 204u8, 163u8, 224u8, 165u8,
+                    134u8, 224u8, 165u8, 141u8, 111u8, 57u8, 56u8, 204u8, 134u8, 204u8, 135u8,
+                    204u8, 135u8, 58u8, 224u8, 170u8, 133u8, 224u8, 170u8, 190u8, 224u8, 170u8,
+                    133u8, 224u8, 171u8, 133u8, 224u8, 170u8, 133u8, 224u8, 171u8, 135u8, 224u8,
+                    170u8, 133u8, 224u8, 171u8, 136u8, 224u8, 170u8, 133u8, 224u8, 170u8, 190u8,
+                    224u8, 171u8, 133u8, 224u8, 170u8, 133u8, 224u8, 170u8, 190u8, 224u8, 171u8,
+                    135u8, 224u8, 170u8, 133u8, 224u8, 170u8, 190u8, 224u8, 171u8, 136u8, 204u8,
+                    163u8, 224u8, 164u8, 189u8, 224u8, 165u8, 129u8, 224u8, 165u8, 130u8, 224u8,
+                    165u8, 141u8, 111u8, 224u8, 165u8, 168u8, 224u8, 165u8, 169u8, 224u8, 165u8,
+                    170u8, 224u8, 165u8, 174u8, 224u8, 165u8, 176u8, 204u8, 134u8, 204u8, 135u8,
+                    56u8, 224u8, 172u8, 133u8, 224u8, 172u8, 190u8, 79u8, 204u8, 163u8, 79u8, 57u8,
+                    204u8, 138u8, 224u8, 174u8, 137u8, 224u8, 174u8, 179u8, 224u8, 174u8, 144u8,
+                    224u8, 174u8, 136u8, 224u8, 174u8, 136u8, 224u8, 174u8, 169u8, 224u8, 175u8,
+                    134u8, 224u8, 174u8, 136u8, 224u8, 175u8, 135u8, 224u8, 174u8, 136u8, 224u8,
+                    175u8, 134u8, 224u8, 174u8, 179u8, 204u8, 135u8, 224u8, 174u8, 179u8, 111u8,
+                    224u8, 174u8, 149u8, 224u8, 174u8, 137u8, 224u8, 174u8, 154u8, 224u8, 174u8,
+                    136u8, 224u8, 175u8, 129u8, 224u8, 174u8, 154u8, 224u8, 175u8, 129u8, 224u8,
+                    174u8, 142u8, 224u8, 174u8, 133u8, 224u8, 174u8, 175u8, 224u8, 174u8, 154u8,
+                    224u8, 175u8, 130u8, 224u8, 174u8, 174u8, 224u8, 175u8, 128u8, 224u8, 175u8,
+                    179u8, 224u8, 174u8, 142u8, 224u8, 174u8, 181u8, 224u8, 174u8, 183u8, 224u8,
+                    174u8, 168u8, 224u8, 175u8, 128u8, 204u8, 134u8, 204u8, 135u8, 111u8, 224u8,
+                    166u8, 131u8, 224u8, 176u8, 146u8, 224u8, 177u8, 149u8, 224u8, 176u8, 146u8,
+                    224u8, 177u8, 140u8, 224u8, 176u8, 176u8, 214u8, 188u8, 224u8, 176u8, 161u8,
+                    204u8, 163u8, 224u8, 176u8, 167u8, 214u8, 188u8, 224u8, 176u8, 172u8, 204u8,
+                    163u8, 224u8, 176u8, 181u8, 224u8, 177u8, 129u8, 224u8, 176u8, 181u8, 204u8,
+                    163u8, 224u8, 176u8, 181u8, 224u8, 176u8, 190u8, 224u8, 177u8, 129u8, 224u8,
+                    176u8, 190u8, 224u8, 177u8, 131u8, 224u8, 176u8, 190u8, 224u8, 176u8, 139u8,
+                    224u8, 176u8, 190u8, 224u8, 176u8, 140u8, 224u8, 176u8, 190u8, 111u8, 204u8,
+                    134u8, 204u8, 135u8, 111u8, 224u8, 166u8, 131u8, 224u8, 176u8, 133u8, 224u8,
+                    176u8, 134u8, 224u8, 176u8, 135u8, 224u8, 176u8, 146u8, 224u8, 176u8, 146u8,
+                    224u8, 177u8, 149u8, 224u8, 176u8, 146u8, 224u8, 177u8, 140u8, 224u8, 176u8,
+                    156u8, 224u8, 176u8, 158u8, 224u8, 176u8, 163u8, 224u8, 176u8, 175u8, 224u8,
+                    176u8, 177u8, 224u8, 176u8, 178u8, 224u8, 178u8, 140u8, 224u8, 178u8, 190u8,
+                    111u8, 224u8, 177u8, 167u8, 224u8, 177u8, 168u8, 224u8, 177u8, 175u8, 204u8,
+                    134u8, 204u8, 135u8, 111u8, 224u8, 166u8, 131u8, 224u8, 180u8, 135u8, 224u8,
+                    181u8, 151u8, 224u8, 174u8, 137u8, 224u8, 174u8, 137u8, 224u8, 181u8, 151u8,
+                    224u8, 180u8, 168u8, 224u8, 181u8, 129u8, 224u8, 180u8, 142u8, 224u8, 181u8,
+                    134u8, 224u8, 180u8, 146u8, 224u8, 180u8, 190u8, 224u8, 180u8, 146u8, 224u8,
+                    181u8, 151u8, 224u8, 180u8, 168u8, 224u8, 181u8, 129u8, 224u8, 174u8, 144u8,
+                    111u8, 224u8, 174u8, 163u8, 224u8, 180u8, 176u8, 224u8, 174u8, 180u8, 224u8,
+                    174u8, 182u8, 224u8, 174u8, 159u8, 224u8, 174u8, 191u8, 224u8, 174u8, 191u8,
+                    224u8, 174u8, 191u8, 224u8, 181u8, 129u8, 224u8, 181u8, 129u8, 224u8, 181u8,
+                    134u8, 224u8, 181u8, 134u8, 224u8, 165u8, 177u8, 224u8, 180u8, 168u8, 224u8,
+                    181u8, 141u8, 224u8, 180u8, 174u8, 111u8, 224u8, 180u8, 176u8, 111u8, 224u8,
+                    180u8, 158u8, 111u8, 224u8, 180u8, 176u8, 224u8, 181u8, 141u8, 224u8, 180u8,
+                    166u8, 224u8, 181u8, 141u8, 224u8, 180u8, 176u8, 224u8, 180u8, 168u8, 224u8,
+                    181u8, 141u8, 224u8, 180u8, 168u8, 57u8, 224u8, 180u8, 181u8, 224u8, 181u8,
+                    141u8, 224u8, 180u8, 176u8, 224u8, 180u8, 168u8, 224u8, 181u8, 141u8, 224u8,
+                    180u8, 185u8, 224u8, 181u8, 141u8, 224u8, 180u8, 174u8, 224u8, 180u8, 168u8,
+                    224u8, 181u8, 129u8, 224u8, 180u8, 168u8, 224u8, 181u8, 141u8, 224u8, 180u8,
+                    176u8, 224u8, 181u8, 141u8, 111u8, 224u8, 166u8, 131u8, 224u8, 183u8, 168u8,
+                    224u8, 183u8, 143u8, 224u8, 182u8, 162u8, 224u8, 182u8, 175u8, 224u8, 183u8,
+                    168u8, 224u8, 183u8, 147u8, 224u8, 184u8, 130u8, 224u8, 184u8, 138u8, 224u8,
+                    184u8, 142u8, 224u8, 184u8, 132u8, 224u8, 184u8, 132u8, 224u8, 184u8, 145u8,
+                    224u8, 184u8, 134u8, 224u8, 184u8, 160u8, 204u8, 138u8, 224u8, 184u8, 178u8,
+                    224u8, 185u8, 128u8, 224u8, 185u8, 128u8, 224u8, 184u8, 178u8, 204u8, 138u8,
+                    111u8, 224u8, 184u8, 136u8, 224u8, 184u8, 162u8, 224u8, 184u8, 154u8, 224u8,
+                    184u8, 155u8, 224u8, 184u8, 157u8, 224u8, 184u8, 158u8, 224u8, 184u8, 159u8,
+                    204u8, 138u8, 224u8, 186u8, 178u8, 224u8, 184u8, 184u8, 224u8, 184u8, 185u8,
+                    224u8, 185u8, 136u8, 224u8, 185u8, 137u8, 224u8, 185u8, 138u8, 224u8, 185u8,
+                    139u8, 204u8, 138u8, 111u8, 224u8, 186u8, 171u8, 224u8, 186u8, 153u8, 224u8,
+                    186u8, 171u8, 224u8, 186u8, 161u8, 224u8, 189u8, 168u8, 224u8, 189u8, 188u8,
+                    224u8, 189u8, 190u8, 224u8, 189u8, 160u8, 224u8, 189u8, 180u8, 224u8, 190u8,
+                    130u8, 224u8, 189u8, 191u8, 224u8, 189u8, 160u8, 224u8, 189u8, 180u8, 224u8,
+                    190u8, 130u8, 224u8, 188u8, 148u8, 224u8, 188u8, 139u8, 224u8, 188u8, 141u8,
+                    224u8, 188u8, 141u8, 224u8, 188u8, 154u8, 224u8, 188u8, 154u8, 224u8, 188u8,
+                    157u8, 224u8, 188u8, 157u8, 224u8, 188u8, 154u8, 224u8, 188u8, 157u8, 204u8,
+                    165u8, 224u8, 189u8, 162u8, 224u8, 190u8, 178u8, 224u8, 189u8, 177u8, 224u8,
+                    190u8, 128u8, 224u8, 190u8, 179u8, 224u8, 189u8, 177u8, 224u8, 190u8, 128u8,
+                    224u8, 188u8, 157u8, 224u8, 188u8, 154u8, 229u8, 141u8, 144u8, 229u8, 141u8,
+                    141u8, 225u8, 128u8, 130u8, 225u8, 128u8, 172u8, 111u8, 225u8, 128u8, 172u8,
+                    111u8, 225u8, 128u8, 149u8, 225u8, 128u8, 172u8, 225u8, 128u8, 158u8, 225u8,
+                    128u8, 188u8, 225u8, 128u8, 158u8, 225u8, 128u8, 188u8, 225u8, 128u8, 177u8,
+                    225u8, 128u8, 172u8, 225u8, 128u8, 186u8, 204u8, 138u8, 224u8, 166u8, 131u8,
+                    111u8, 225u8, 129u8, 138u8, 225u8, 129u8, 138u8, 225u8, 129u8, 129u8, 225u8,
+                    128u8, 149u8, 225u8, 128u8, 190u8, 225u8, 128u8, 149u8, 225u8, 128u8, 172u8,
+                    225u8, 128u8, 190u8, 225u8, 128u8, 131u8, 225u8, 128u8, 190u8, 225u8, 129u8,
+                    189u8, 225u8, 128u8, 190u8, 225u8, 128u8, 130u8, 225u8, 128u8, 190u8, 225u8,
+                    130u8, 131u8, 204u8, 138u8, 234u8, 158u8, 134u8, 121u8, 200u8, 157u8, 111u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 128u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    131u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 137u8, 225u8, 132u8, 140u8, 225u8, 132u8, 140u8, 225u8, 132u8, 130u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 130u8, 225u8, 132u8, 130u8, 225u8, 132u8,
+                    130u8, 225u8, 132u8, 131u8, 225u8, 132u8, 130u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 131u8, 225u8, 132u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8, 130u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 133u8, 225u8, 132u8,
+                    146u8, 225u8, 132u8, 133u8, 225u8, 132u8, 139u8, 225u8, 132u8, 134u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 134u8, 225u8, 132u8, 139u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 135u8, 225u8, 132u8, 130u8, 225u8, 132u8,
+                    135u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 137u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 140u8,
+                    225u8, 132u8, 135u8, 225u8, 132u8, 140u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    142u8, 225u8, 132u8, 135u8, 225u8, 132u8, 144u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 145u8, 225u8, 132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    128u8, 225u8, 132u8, 137u8, 225u8, 132u8, 130u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 131u8, 225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 137u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 135u8, 225u8, 132u8, 128u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 140u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    142u8, 225u8, 132u8, 137u8, 225u8, 132u8, 143u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 144u8, 225u8, 132u8, 137u8, 225u8, 132u8, 145u8, 225u8, 132u8, 133u8,
+                    225u8, 132u8, 146u8, 225u8, 132u8, 188u8, 225u8, 132u8, 188u8, 225u8, 132u8,
+                    190u8, 225u8, 132u8, 190u8, 225u8, 132u8, 139u8, 225u8, 132u8, 128u8, 225u8,
+                    132u8, 139u8, 225u8, 132u8, 131u8, 225u8, 132u8, 139u8, 225u8, 132u8, 134u8,
+                    225u8, 132u8, 139u8, 225u8, 132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 139u8, 225u8, 133u8, 128u8, 225u8, 132u8, 139u8, 225u8,
+                    132u8, 139u8, 225u8, 132u8, 139u8, 225u8, 132u8, 140u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 142u8, 225u8, 132u8, 139u8, 225u8, 132u8, 144u8, 225u8, 132u8,
+                    139u8, 225u8, 132u8, 145u8, 225u8, 132u8, 140u8, 225u8, 132u8, 139u8, 225u8,
+                    133u8, 142u8, 225u8, 133u8, 142u8, 225u8, 133u8, 144u8, 225u8, 133u8, 144u8,
+                    225u8, 132u8, 142u8, 225u8, 132u8, 143u8, 225u8, 132u8, 142u8, 225u8, 132u8,
+                    146u8, 225u8, 132u8, 145u8, 225u8, 132u8, 135u8, 225u8, 132u8, 145u8, 225u8,
+                    132u8, 139u8, 225u8, 132u8, 146u8, 225u8, 132u8, 146u8, 225u8, 132u8, 128u8,
+                    225u8, 132u8, 131u8, 225u8, 132u8, 130u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    130u8, 225u8, 132u8, 140u8, 225u8, 132u8, 130u8, 225u8, 132u8, 146u8, 225u8,
+                    132u8, 131u8, 225u8, 132u8, 133u8, 225u8, 133u8, 161u8, 228u8, 184u8, 168u8,
+                    225u8, 133u8, 163u8, 228u8, 184u8, 168u8, 225u8, 133u8, 165u8, 228u8, 184u8,
+                    168u8, 225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8, 169u8, 225u8,
+                    133u8, 161u8, 225u8, 133u8, 169u8, 225u8, 133u8, 161u8, 228u8, 184u8, 168u8,
+                    225u8, 133u8, 169u8, 228u8, 184u8, 168u8, 225u8, 133u8, 174u8, 225u8, 133u8,
+                    165u8, 225u8, 133u8, 174u8, 225u8, 133u8, 165u8, 228u8, 184u8, 168u8, 225u8,
+                    133u8, 174u8, 228u8, 184u8, 168u8, 227u8, 131u8, 188u8, 227u8, 131u8, 188u8,
+                    228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 225u8, 133u8, 161u8, 225u8, 133u8,
+                    169u8, 225u8, 133u8, 161u8, 225u8, 133u8, 174u8, 225u8, 133u8, 163u8, 225u8,
+                    133u8, 169u8, 225u8, 133u8, 163u8, 225u8, 133u8, 173u8, 225u8, 133u8, 165u8,
+                    225u8, 133u8, 169u8, 225u8, 133u8, 165u8, 225u8, 133u8, 174u8, 225u8, 133u8,
+                    165u8, 227u8, 131u8, 188u8, 225u8, 133u8, 167u8, 225u8, 133u8, 169u8, 225u8,
+                    133u8, 167u8, 225u8, 133u8, 174u8, 225u8, 133u8, 169u8, 225u8, 133u8, 165u8,
+                    225u8, 133u8, 169u8, 225u8, 133u8, 165u8, 228u8, 184u8, 168u8, 225u8, 133u8,
+                    169u8, 225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8, 169u8, 225u8,
+                    133u8, 169u8, 225u8, 133u8, 169u8, 225u8, 133u8, 174u8, 225u8, 133u8, 173u8,
+                    225u8, 133u8, 163u8, 225u8, 133u8, 173u8, 225u8, 133u8, 163u8, 228u8, 184u8,
+                    168u8, 225u8, 133u8, 173u8, 225u8, 133u8, 163u8, 225u8, 133u8, 173u8, 225u8,
+                    133u8, 169u8, 225u8, 133u8, 173u8, 228u8, 184u8, 168u8, 225u8, 133u8, 174u8,
+                    225u8, 133u8, 161u8, 225u8, 133u8, 174u8, 225u8, 133u8, 161u8, 228u8, 184u8,
+                    168u8, 225u8, 133u8, 174u8, 225u8, 133u8, 165u8, 227u8, 131u8, 188u8, 225u8,
+                    133u8, 174u8, 225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8, 174u8,
+                    225u8, 133u8, 174u8, 225u8, 133u8, 178u8, 225u8, 133u8, 161u8, 225u8, 133u8,
+                    178u8, 225u8, 133u8, 165u8, 225u8, 133u8, 178u8, 225u8, 133u8, 165u8, 228u8,
+                    184u8, 168u8, 225u8, 133u8, 178u8, 225u8, 133u8, 167u8, 225u8, 133u8, 178u8,
+                    225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8, 225u8, 133u8,
+                    174u8, 225u8, 133u8, 178u8, 228u8, 184u8, 168u8, 227u8, 131u8, 188u8, 225u8,
+                    133u8, 174u8, 227u8, 131u8, 188u8, 227u8, 131u8, 188u8, 227u8, 131u8, 188u8,
+                    228u8, 184u8, 168u8, 225u8, 133u8, 174u8, 228u8, 184u8, 168u8, 225u8, 133u8,
+                    161u8, 228u8, 184u8, 168u8, 225u8, 133u8, 163u8, 228u8, 184u8, 168u8, 225u8,
+                    133u8, 169u8, 228u8, 184u8, 168u8, 225u8, 133u8, 174u8, 228u8, 184u8, 168u8,
+                    227u8, 131u8, 188u8, 228u8, 184u8, 168u8, 225u8, 134u8, 158u8, 225u8, 134u8,
+                    158u8, 225u8, 133u8, 165u8, 225u8, 134u8, 158u8, 225u8, 133u8, 174u8, 225u8,
+                    134u8, 158u8, 228u8, 184u8, 168u8, 225u8, 134u8, 158u8, 225u8, 134u8, 158u8,
+                    225u8, 133u8, 161u8, 227u8, 131u8, 188u8, 225u8, 133u8, 163u8, 225u8, 133u8,
+                    174u8, 225u8, 133u8, 167u8, 225u8, 133u8, 163u8, 225u8, 133u8, 169u8, 225u8,
+                    133u8, 163u8, 225u8, 133u8, 169u8, 225u8, 133u8, 163u8, 228u8, 184u8, 168u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 128u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    128u8, 225u8, 132u8, 137u8, 225u8, 132u8, 130u8, 225u8, 132u8, 130u8, 225u8,
+                    132u8, 140u8, 225u8, 132u8, 130u8, 225u8, 132u8, 146u8, 225u8, 132u8, 131u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 134u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 133u8, 225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8, 132u8, 144u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 145u8, 225u8, 132u8, 133u8, 225u8, 132u8,
+                    146u8, 225u8, 132u8, 134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 139u8, 225u8, 132u8, 140u8, 225u8, 132u8, 142u8, 225u8, 132u8,
+                    143u8, 225u8, 132u8, 144u8, 225u8, 132u8, 145u8, 225u8, 132u8, 146u8, 225u8,
+                    132u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 130u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    130u8, 225u8, 132u8, 131u8, 225u8, 132u8, 130u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 130u8, 225u8, 133u8, 128u8, 225u8, 132u8, 130u8, 225u8, 132u8, 144u8,
+                    225u8, 132u8, 131u8, 225u8, 132u8, 128u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 133u8, 225u8, 132u8, 130u8, 225u8, 132u8, 133u8, 225u8, 132u8, 131u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 131u8, 225u8, 132u8, 146u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 134u8, 225u8,
+                    132u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8, 134u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 135u8, 225u8, 132u8, 146u8, 225u8, 132u8, 133u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 132u8, 133u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8, 133u8, 128u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 143u8, 225u8, 132u8, 133u8, 225u8, 133u8, 153u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 128u8, 225u8, 132u8, 134u8, 225u8, 132u8, 133u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 134u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 134u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 134u8, 225u8, 133u8, 128u8, 225u8, 132u8, 134u8, 225u8, 132u8, 142u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 146u8, 225u8, 132u8, 134u8, 225u8, 132u8,
+                    139u8, 225u8, 132u8, 135u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 145u8, 225u8, 132u8, 135u8, 225u8, 132u8, 146u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 139u8, 225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8,
+                    132u8, 137u8, 225u8, 132u8, 135u8, 225u8, 133u8, 128u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 128u8, 225u8, 132u8, 139u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    128u8, 225u8, 132u8, 139u8, 225u8, 132u8, 139u8, 225u8, 132u8, 139u8, 225u8,
+                    132u8, 143u8, 225u8, 133u8, 140u8, 225u8, 132u8, 139u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 139u8, 225u8, 133u8, 128u8, 225u8, 132u8, 145u8, 225u8, 132u8,
+                    135u8, 225u8, 132u8, 145u8, 225u8, 132u8, 139u8, 225u8, 132u8, 146u8, 225u8,
+                    132u8, 130u8, 225u8, 132u8, 146u8, 225u8, 132u8, 133u8, 225u8, 132u8, 146u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 146u8, 225u8, 132u8, 135u8, 225u8, 133u8,
+                    153u8, 225u8, 132u8, 128u8, 225u8, 132u8, 130u8, 225u8, 132u8, 128u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 128u8, 225u8, 132u8, 142u8, 225u8, 132u8, 128u8,
+                    225u8, 132u8, 143u8, 225u8, 132u8, 128u8, 225u8, 132u8, 146u8, 225u8, 132u8,
+                    130u8, 225u8, 132u8, 130u8, 85u8, 201u8, 176u8, 206u8, 166u8, 213u8, 136u8,
+                    213u8, 177u8, 79u8, 68u8, 82u8, 84u8, 79u8, 39u8, 105u8, 226u8, 177u8, 181u8,
+                    89u8, 65u8, 74u8, 69u8, 63u8, 226u8, 177u8, 181u8, 206u8, 147u8, 87u8, 77u8,
+                    72u8, 89u8, 79u8, 204u8, 181u8, 198u8, 171u8, 71u8, 104u8, 90u8, 209u8, 160u8,
+                    198u8, 144u8, 85u8, 204u8, 181u8, 52u8, 98u8, 82u8, 87u8, 83u8, 86u8, 83u8,
+                    76u8, 67u8, 80u8, 75u8, 100u8, 79u8, 204u8, 181u8, 54u8, 195u8, 159u8, 104u8,
+                    204u8, 148u8, 71u8, 66u8, 201u8, 162u8, 202u8, 153u8, 61u8, 206u8, 148u8,
+                    194u8, 183u8, 225u8, 144u8, 129u8, 225u8, 144u8, 129u8, 194u8, 183u8, 194u8,
+                    183u8, 206u8, 148u8, 206u8, 148u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8,
+                    132u8, 225u8, 144u8, 132u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 133u8,
+                    225u8, 144u8, 133u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 134u8, 225u8,
+                    144u8, 134u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 138u8, 225u8, 144u8,
+                    138u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 139u8, 225u8, 144u8, 139u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 129u8, 225u8, 144u8, 160u8, 206u8,
+                    148u8, 225u8, 144u8, 160u8, 225u8, 144u8, 133u8, 225u8, 144u8, 160u8, 225u8,
+                    144u8, 138u8, 225u8, 144u8, 160u8, 86u8, 201u8, 133u8, 62u8, 194u8, 183u8,
+                    62u8, 60u8, 194u8, 183u8, 86u8, 86u8, 194u8, 183u8, 194u8, 183u8, 201u8, 133u8,
+                    201u8, 133u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 178u8, 225u8, 144u8,
+                    178u8, 194u8, 183u8, 194u8, 183u8, 62u8, 62u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 144u8, 180u8, 225u8, 144u8, 180u8, 194u8, 183u8, 194u8, 183u8, 60u8,
+                    60u8, 194u8, 183u8, 194u8, 183u8, 225u8, 144u8, 185u8, 225u8, 144u8, 185u8,
+                    194u8, 183u8, 39u8, 85u8, 213u8, 136u8, 194u8, 183u8, 225u8, 145u8, 144u8,
+                    194u8, 183u8, 85u8, 85u8, 194u8, 183u8, 194u8, 183u8, 213u8, 136u8, 213u8,
+                    136u8, 194u8, 183u8, 194u8, 183u8, 225u8, 145u8, 143u8, 225u8, 145u8, 143u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 145u8, 144u8, 225u8, 145u8, 144u8, 194u8,
+                    183u8, 194u8, 183u8, 225u8, 145u8, 145u8, 225u8, 145u8, 145u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 145u8, 149u8, 225u8, 145u8, 149u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 145u8, 150u8, 225u8, 145u8, 150u8, 194u8, 183u8, 85u8, 39u8,
+                    213u8, 136u8, 39u8, 225u8, 145u8, 144u8, 39u8, 225u8, 145u8, 149u8, 39u8, 80u8,
+                    100u8, 98u8, 98u8, 204u8, 135u8, 194u8, 183u8, 225u8, 145u8, 171u8, 225u8,
+                    145u8, 171u8, 194u8, 183u8, 194u8, 183u8, 80u8, 112u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 145u8, 174u8, 225u8, 145u8, 174u8, 194u8, 183u8, 194u8, 183u8,
+                    100u8, 100u8, 194u8, 183u8, 194u8, 183u8, 225u8, 145u8, 176u8, 225u8, 145u8,
+                    176u8, 194u8, 183u8, 194u8, 183u8, 98u8, 98u8, 194u8, 183u8, 194u8, 183u8,
+                    98u8, 204u8, 135u8, 98u8, 204u8, 135u8, 194u8, 183u8, 225u8, 145u8, 171u8,
+                    39u8, 80u8, 39u8, 100u8, 39u8, 98u8, 39u8, 74u8, 194u8, 183u8, 225u8, 146u8,
+                    137u8, 225u8, 146u8, 137u8, 194u8, 183u8, 194u8, 183u8, 225u8, 146u8, 139u8,
+                    225u8, 146u8, 139u8, 194u8, 183u8, 194u8, 183u8, 225u8, 146u8, 140u8, 225u8,
+                    146u8, 140u8, 194u8, 183u8, 194u8, 183u8, 74u8, 74u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 146u8, 142u8, 225u8, 146u8, 142u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 146u8, 144u8, 225u8, 146u8, 144u8, 194u8, 183u8, 194u8, 183u8, 225u8,
+                    146u8, 145u8, 225u8, 146u8, 145u8, 194u8, 183u8, 206u8, 147u8, 76u8, 194u8,
+                    183u8, 225u8, 146u8, 163u8, 225u8, 146u8, 163u8, 194u8, 183u8, 194u8, 183u8,
+                    206u8, 147u8, 206u8, 147u8, 194u8, 183u8, 194u8, 183u8, 225u8, 146u8, 166u8,
+                    225u8, 146u8, 166u8, 194u8, 183u8, 194u8, 183u8, 225u8, 146u8, 167u8, 225u8,
+                    146u8, 167u8, 194u8, 183u8, 194u8, 183u8, 225u8, 146u8, 168u8, 225u8, 146u8,
+                    168u8, 194u8, 183u8, 194u8, 183u8, 76u8, 108u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 146u8, 171u8, 225u8, 146u8, 171u8, 194u8, 183u8, 50u8, 194u8, 183u8,
+                    225u8, 147u8, 128u8, 225u8, 147u8, 128u8, 194u8, 183u8, 194u8, 183u8, 225u8,
+                    147u8, 135u8, 225u8, 147u8, 135u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8,
+                    136u8, 225u8, 147u8, 136u8, 194u8, 183u8, 225u8, 144u8, 161u8, 194u8, 183u8,
+                    225u8, 147u8, 147u8, 225u8, 147u8, 147u8, 194u8, 183u8, 194u8, 183u8, 225u8,
+                    147u8, 149u8, 225u8, 147u8, 149u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8,
+                    150u8, 225u8, 147u8, 150u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 151u8,
+                    225u8, 147u8, 151u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 152u8, 225u8,
+                    147u8, 152u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 154u8, 225u8, 147u8,
+                    154u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 155u8, 225u8, 147u8, 155u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 173u8, 225u8, 147u8, 173u8, 194u8,
+                    183u8, 194u8, 183u8, 225u8, 147u8, 175u8, 225u8, 147u8, 175u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 147u8, 176u8, 225u8, 147u8, 176u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 147u8, 177u8, 225u8, 147u8, 177u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 147u8, 178u8, 225u8, 147u8, 178u8, 194u8, 183u8, 194u8, 183u8, 225u8,
+                    147u8, 180u8, 225u8, 147u8, 180u8, 194u8, 183u8, 194u8, 183u8, 225u8, 147u8,
+                    181u8, 225u8, 147u8, 181u8, 194u8, 183u8, 225u8, 148u8, 139u8, 60u8, 225u8,
+                    148u8, 139u8, 225u8, 145u8, 149u8, 225u8, 148u8, 139u8, 98u8, 225u8, 148u8,
+                    139u8, 225u8, 146u8, 144u8, 194u8, 183u8, 225u8, 148u8, 144u8, 225u8, 148u8,
+                    144u8, 194u8, 183u8, 194u8, 183u8, 225u8, 148u8, 145u8, 225u8, 148u8, 145u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 148u8, 146u8, 225u8, 148u8, 146u8, 194u8,
+                    183u8, 194u8, 183u8, 225u8, 148u8, 147u8, 225u8, 148u8, 147u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 148u8, 148u8, 225u8, 148u8, 148u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 148u8, 149u8, 225u8, 148u8, 149u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 148u8, 150u8, 225u8, 148u8, 150u8, 194u8, 183u8, 194u8, 183u8, 52u8,
+                    52u8, 194u8, 183u8, 194u8, 183u8, 225u8, 148u8, 168u8, 225u8, 148u8, 168u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 148u8, 169u8, 225u8, 148u8, 169u8, 194u8,
+                    183u8, 194u8, 183u8, 225u8, 148u8, 170u8, 225u8, 148u8, 170u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 148u8, 171u8, 225u8, 148u8, 171u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 148u8, 173u8, 225u8, 148u8, 173u8, 194u8, 183u8, 194u8, 183u8,
+                    225u8, 148u8, 174u8, 225u8, 148u8, 174u8, 194u8, 183u8, 225u8, 144u8, 169u8,
+                    120u8, 194u8, 183u8, 225u8, 149u8, 140u8, 225u8, 149u8, 140u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 149u8, 154u8, 225u8, 149u8, 154u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 149u8, 167u8, 225u8, 149u8, 167u8, 194u8, 183u8, 225u8, 186u8,
+                    159u8, 72u8, 120u8, 225u8, 149u8, 144u8, 225u8, 145u8, 172u8, 225u8, 149u8,
+                    144u8, 80u8, 225u8, 149u8, 144u8, 225u8, 145u8, 174u8, 225u8, 149u8, 144u8,
+                    100u8, 225u8, 149u8, 144u8, 225u8, 145u8, 176u8, 225u8, 149u8, 144u8, 98u8,
+                    225u8, 149u8, 144u8, 98u8, 204u8, 135u8, 225u8, 149u8, 144u8, 225u8, 146u8,
+                    131u8, 82u8, 225u8, 150u8, 149u8, 225u8, 146u8, 138u8, 225u8, 150u8, 149u8,
+                    225u8, 146u8, 139u8, 225u8, 150u8, 149u8, 225u8, 146u8, 140u8, 225u8, 150u8,
+                    149u8, 74u8, 225u8, 150u8, 149u8, 225u8, 146u8, 142u8, 225u8, 150u8, 149u8,
+                    225u8, 146u8, 144u8, 225u8, 150u8, 149u8, 225u8, 146u8, 145u8, 98u8, 70u8,
+                    226u8, 132u8, 178u8, 234u8, 159u8, 187u8, 226u8, 177u8, 175u8, 65u8, 68u8,
+                    68u8, 209u8, 160u8, 77u8, 66u8, 225u8, 146u8, 144u8, 225u8, 146u8, 137u8,
+                    225u8, 147u8, 147u8, 225u8, 147u8, 154u8, 225u8, 149u8, 131u8, 225u8, 149u8,
+                    134u8, 225u8, 149u8, 138u8, 198u8, 177u8, 206u8, 169u8, 198u8, 177u8, 206u8,
+                    169u8, 88u8, 120u8, 225u8, 149u8, 144u8, 225u8, 145u8, 171u8, 225u8, 150u8,
+                    149u8, 225u8, 146u8, 137u8, 225u8, 150u8, 150u8, 225u8, 146u8, 139u8, 225u8,
+                    150u8, 150u8, 225u8, 146u8, 140u8, 225u8, 150u8, 150u8, 74u8, 225u8, 150u8,
+                    150u8, 225u8, 146u8, 142u8, 225u8, 150u8, 150u8, 225u8, 146u8, 144u8, 225u8,
+                    150u8, 150u8, 225u8, 146u8, 145u8, 225u8, 150u8, 167u8, 194u8, 183u8, 225u8,
+                    150u8, 168u8, 194u8, 183u8, 225u8, 150u8, 169u8, 194u8, 183u8, 225u8, 150u8,
+                    170u8, 194u8, 183u8, 225u8, 150u8, 171u8, 194u8, 183u8, 225u8, 150u8, 172u8,
+                    194u8, 183u8, 225u8, 150u8, 173u8, 194u8, 183u8, 32u8, 60u8, 88u8, 108u8,
+                    225u8, 154u8, 189u8, 39u8, 75u8, 77u8, 206u8, 168u8, 225u8, 154u8, 188u8,
+                    194u8, 183u8, 58u8, 43u8, 206u8, 166u8, 47u8, 225u8, 158u8, 162u8, 224u8,
+                    184u8, 180u8, 224u8, 184u8, 181u8, 224u8, 184u8, 182u8, 224u8, 184u8, 183u8,
+                    204u8, 138u8, 224u8, 185u8, 136u8, 204u8, 138u8, 224u8, 184u8, 175u8, 224u8,
+                    185u8, 154u8, 224u8, 185u8, 143u8, 224u8, 185u8, 155u8, 58u8, 58u8, 225u8,
+                    160u8, 181u8, 225u8, 161u8, 156u8, 194u8, 183u8, 225u8, 162u8, 177u8, 194u8,
+                    183u8, 225u8, 162u8, 180u8, 194u8, 183u8, 225u8, 162u8, 184u8, 194u8, 183u8,
+                    225u8, 163u8, 128u8, 194u8, 183u8, 225u8, 147u8, 130u8, 225u8, 147u8, 130u8,
+                    194u8, 183u8, 194u8, 183u8, 225u8, 147u8, 131u8, 225u8, 147u8, 131u8, 194u8,
+                    183u8, 194u8, 183u8, 225u8, 147u8, 132u8, 225u8, 147u8, 132u8, 194u8, 183u8,
+                    194u8, 183u8, 225u8, 147u8, 133u8, 225u8, 147u8, 133u8, 194u8, 183u8, 194u8,
+                    183u8, 225u8, 149u8, 131u8, 194u8, 183u8, 225u8, 149u8, 134u8, 194u8, 183u8,
+                    225u8, 149u8, 135u8, 194u8, 183u8, 225u8, 149u8, 136u8, 194u8, 183u8, 225u8,
+                    149u8, 137u8, 194u8, 183u8, 225u8, 149u8, 139u8, 225u8, 163u8, 181u8, 225u8,
+                    163u8, 159u8, 225u8, 144u8, 158u8, 225u8, 144u8, 158u8, 225u8, 163u8, 159u8,
+                    225u8, 149u8, 131u8, 194u8, 183u8, 225u8, 149u8, 158u8, 194u8, 183u8, 225u8,
+                    149u8, 166u8, 194u8, 183u8, 225u8, 149u8, 171u8, 194u8, 183u8, 225u8, 150u8,
+                    134u8, 194u8, 183u8, 225u8, 150u8, 151u8, 194u8, 183u8, 209u8, 160u8, 194u8,
+                    183u8, 225u8, 151u8, 180u8, 194u8, 183u8, 225u8, 152u8, 155u8, 194u8, 183u8,
+                    225u8, 166u8, 158u8, 225u8, 166u8, 177u8, 225u8, 169u8, 133u8, 225u8, 169u8,
+                    133u8, 225u8, 170u8, 168u8, 225u8, 170u8, 168u8, 225u8, 170u8, 170u8, 225u8,
+                    170u8, 168u8, 219u8, 155u8, 204u8, 168u8, 225u8, 172u8, 141u8, 225u8, 172u8,
+                    145u8, 225u8, 172u8, 168u8, 225u8, 173u8, 144u8, 225u8, 173u8, 158u8, 225u8,
+                    173u8, 158u8, 225u8, 176u8, 187u8, 225u8, 176u8, 187u8, 225u8, 177u8, 190u8,
+                    225u8, 177u8, 190u8, 204u8, 130u8, 204u8, 132u8, 39u8, 39u8, 204u8, 171u8,
+                    204u8, 174u8, 204u8, 173u8, 204u8, 142u8, 204u8, 169u8, 204u8, 163u8, 204u8,
+                    164u8, 204u8, 150u8, 99u8, 201u8, 156u8, 196u8, 184u8, 202u8, 141u8, 111u8,
+                    201u8, 148u8, 111u8, 199u8, 157u8, 111u8, 117u8, 118u8, 119u8, 122u8, 198u8,
+                    168u8, 114u8, 202u8, 140u8, 207u8, 128u8, 225u8, 180u8, 152u8, 208u8, 187u8,
+                    225u8, 163u8, 150u8, 194u8, 186u8, 117u8, 101u8, 102u8, 204u8, 180u8, 114u8,
+                    110u8, 204u8, 180u8, 110u8, 204u8, 180u8, 114u8, 204u8, 180u8, 201u8, 190u8,
+                    204u8, 180u8, 115u8, 204u8, 180u8, 116u8, 204u8, 180u8, 122u8, 204u8, 180u8,
+                    225u8, 180u8, 180u8, 105u8, 204u8, 181u8, 105u8, 204u8, 181u8, 112u8, 204u8,
+                    181u8, 117u8, 204u8, 181u8, 202u8, 138u8, 204u8, 181u8, 103u8, 121u8, 201u8,
+                    139u8, 225u8, 181u8, 139u8, 225u8, 181u8, 141u8, 225u8, 163u8, 148u8, 225u8,
+                    153u8, 134u8, 226u8, 183u8, 172u8, 234u8, 173u8, 145u8, 225u8, 186u8, 163u8,
+                    102u8, 121u8, 225u8, 191u8, 180u8, 39u8, 105u8, 39u8, 126u8, 39u8, 225u8,
+                    143u8, 175u8, 39u8, 39u8, 32u8, 32u8, 32u8, 32u8, 32u8, 32u8, 32u8, 32u8, 32u8,
+                    32u8, 32u8, 45u8, 45u8, 45u8, 45u8, 227u8, 131u8, 188u8, 227u8, 131u8, 188u8,
+                    108u8, 108u8, 39u8, 39u8, 44u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8,
+                    194u8, 183u8, 46u8, 46u8, 46u8, 46u8, 46u8, 46u8, 194u8, 183u8, 32u8, 32u8,
+                    32u8, 194u8, 186u8, 47u8, 226u8, 130u8, 128u8, 226u8, 130u8, 128u8, 194u8,
+                    186u8, 47u8, 226u8, 130u8, 128u8, 226u8, 130u8, 128u8, 226u8, 130u8, 128u8,
+                    39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 39u8, 60u8,
+                    62u8, 33u8, 33u8, 203u8, 137u8, 47u8, 45u8, 47u8, 63u8, 63u8, 63u8, 33u8, 33u8,
+                    63u8, 42u8, 194u8, 186u8, 47u8, 226u8, 130u8, 128u8, 126u8, 39u8, 39u8, 39u8,
+                    39u8, 58u8, 226u8, 181u8, 151u8, 226u8, 181u8, 130u8, 32u8, 194u8, 186u8,
+                    234u8, 157u8, 176u8, 67u8, 226u8, 131u8, 171u8, 194u8, 163u8, 114u8, 110u8,
+                    204u8, 184u8, 82u8, 115u8, 87u8, 204u8, 181u8, 100u8, 204u8, 181u8, 204u8,
+                    177u8, 234u8, 158u8, 146u8, 75u8, 204u8, 181u8, 84u8, 226u8, 131u8, 171u8,
+                    108u8, 116u8, 213u8, 148u8, 219u8, 155u8, 97u8, 47u8, 99u8, 97u8, 47u8, 115u8,
+                    67u8, 194u8, 176u8, 67u8, 99u8, 47u8, 111u8, 99u8, 47u8, 117u8, 198u8, 144u8,
+                    208u8, 173u8, 194u8, 176u8, 70u8, 103u8, 72u8, 72u8, 72u8, 104u8, 104u8, 204u8,
+                    181u8, 108u8, 108u8, 76u8, 108u8, 78u8, 78u8, 111u8, 80u8, 81u8, 82u8, 82u8,
+                    82u8, 84u8, 69u8, 76u8, 90u8, 206u8, 169u8, 198u8, 177u8, 90u8, 201u8, 191u8,
+                    75u8, 66u8, 67u8, 101u8, 101u8, 69u8, 70u8, 77u8, 111u8, 215u8, 144u8, 215u8,
+                    145u8, 215u8, 146u8, 215u8, 147u8, 105u8, 70u8, 65u8, 88u8, 207u8, 128u8,
+                    121u8, 206u8, 147u8, 206u8, 160u8, 198u8, 169u8, 234u8, 147u8, 168u8, 234u8,
+                    147u8, 182u8, 240u8, 150u8, 188u8, 128u8, 68u8, 100u8, 101u8, 105u8, 106u8,
+                    108u8, 108u8, 108u8, 108u8, 108u8, 108u8, 108u8, 86u8, 86u8, 86u8, 108u8, 86u8,
+                    108u8, 108u8, 86u8, 108u8, 108u8, 108u8, 108u8, 88u8, 88u8, 88u8, 108u8, 88u8,
+                    108u8, 108u8, 76u8, 67u8, 68u8, 77u8, 105u8, 105u8, 105u8, 105u8, 105u8, 105u8,
+                    105u8, 118u8, 118u8, 118u8, 105u8, 118u8, 105u8, 105u8, 118u8, 105u8, 105u8,
+                    105u8, 105u8, 120u8, 120u8, 120u8, 105u8, 120u8, 105u8, 105u8, 108u8, 99u8,
+                    100u8, 114u8, 110u8, 198u8, 134u8, 201u8, 148u8, 225u8, 155u8, 143u8, 225u8,
+                    155u8, 168u8, 226u8, 134u8, 178u8, 240u8, 159u8, 132u8, 142u8, 225u8, 155u8,
+                    154u8, 225u8, 155u8, 144u8, 226u8, 177u8, 175u8, 198u8, 142u8, 206u8, 148u8,
+                    206u8, 160u8, 198u8, 169u8, 45u8, 43u8, 204u8, 135u8, 47u8, 92u8, 42u8, 194u8,
+                    176u8, 194u8, 183u8, 111u8, 111u8, 108u8, 108u8, 108u8, 118u8, 213u8, 136u8,
+                    85u8, 202u8, 131u8, 202u8, 131u8, 202u8, 131u8, 202u8, 131u8, 202u8, 131u8,
+                    202u8, 131u8, 226u8, 136u8, 174u8, 226u8, 136u8, 174u8, 226u8, 136u8, 174u8,
+                    226u8, 136u8, 174u8, 226u8, 136u8, 174u8, 58u8, 45u8, 204u8, 135u8, 126u8,
+                    61u8, 204u8, 135u8, 61u8, 204u8, 135u8, 204u8, 163u8, 61u8, 204u8, 138u8, 61u8,
+                    204u8, 130u8, 61u8, 204u8, 134u8, 61u8, 205u8, 171u8, 226u8, 137u8, 161u8,
+                    60u8, 60u8, 62u8, 62u8, 225u8, 145u8, 149u8, 225u8, 145u8, 144u8, 240u8, 144u8,
+                    138u8, 168u8, 79u8, 204u8, 181u8, 202u8, 152u8, 79u8, 204u8, 181u8, 84u8,
+                    234u8, 147u8, 149u8, 226u8, 136u8, 167u8, 118u8, 213u8, 136u8, 85u8, 225u8,
+                    155u8, 156u8, 194u8, 183u8, 225u8, 155u8, 158u8, 60u8, 194u8, 183u8, 194u8,
+                    183u8, 62u8, 60u8, 60u8, 60u8, 62u8, 62u8, 62u8, 226u8, 181u8, 151u8, 194u8,
+                    183u8, 194u8, 183u8, 194u8, 183u8, 234u8, 158u8, 147u8, 69u8, 226u8, 136u8,
+                    133u8, 226u8, 140u8, 164u8, 226u8, 157u8, 172u8, 226u8, 157u8, 173u8, 227u8,
+                    128u8, 188u8, 206u8, 148u8, 204u8, 178u8, 225u8, 155u8, 156u8, 204u8, 178u8,
+                    194u8, 176u8, 204u8, 178u8, 226u8, 138u8, 155u8, 84u8, 204u8, 136u8, 226u8,
+                    136u8, 135u8, 204u8, 136u8, 226u8, 139u8, 134u8, 204u8, 136u8, 194u8, 176u8,
+                    204u8, 136u8, 216u8, 169u8, 126u8, 204u8, 136u8, 225u8, 144u8, 181u8, 226u8,
+                    136u8, 135u8, 204u8, 180u8, 79u8, 204u8, 181u8, 105u8, 112u8, 207u8, 137u8,
+                    97u8, 204u8, 178u8, 234u8, 158u8, 147u8, 204u8, 178u8, 105u8, 204u8, 178u8,
+                    207u8, 137u8, 204u8, 178u8, 97u8, 225u8, 154u8, 189u8, 228u8, 184u8, 168u8,
+                    228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 228u8, 184u8,
+                    168u8, 228u8, 184u8, 168u8, 226u8, 141u8, 149u8, 226u8, 141u8, 142u8, 226u8,
+                    141u8, 139u8, 226u8, 141u8, 173u8, 226u8, 130u8, 129u8, 226u8, 130u8, 128u8,
+                    226u8, 143u8, 187u8, 108u8, 226u8, 152u8, 190u8, 92u8, 92u8, 226u8, 158u8,
+                    128u8, 226u8, 158u8, 129u8, 226u8, 158u8, 130u8, 226u8, 158u8, 131u8, 226u8,
+                    158u8, 132u8, 226u8, 158u8, 133u8, 226u8, 158u8, 134u8, 226u8, 158u8, 135u8,
+                    226u8, 158u8, 136u8, 226u8, 158u8, 137u8, 40u8, 108u8, 41u8, 40u8, 50u8, 41u8,
+                    40u8, 51u8, 41u8, 40u8, 52u8, 41u8, 40u8, 53u8, 41u8, 40u8, 54u8, 41u8, 40u8,
+                    55u8, 41u8, 40u8, 56u8, 41u8, 40u8, 57u8, 41u8, 40u8, 108u8, 79u8, 41u8, 40u8,
+                    108u8, 108u8, 41u8, 40u8, 108u8, 50u8, 41u8, 40u8, 108u8, 51u8, 41u8, 40u8,
+                    108u8, 52u8, 41u8, 40u8, 108u8, 53u8, 41u8, 40u8, 108u8, 54u8, 41u8, 40u8,
+                    108u8, 55u8, 41u8, 40u8, 108u8, 56u8, 41u8, 40u8, 108u8, 57u8, 41u8, 40u8,
+                    50u8, 79u8, 41u8, 108u8, 46u8, 50u8, 46u8, 51u8, 46u8, 52u8, 46u8, 53u8, 46u8,
+                    54u8, 46u8, 55u8, 46u8, 56u8, 46u8, 57u8, 46u8, 108u8, 79u8, 46u8, 108u8,
+                    108u8, 46u8, 108u8, 50u8, 46u8, 108u8, 51u8, 46u8, 108u8, 52u8, 46u8, 108u8,
+                    53u8, 46u8, 108u8, 54u8, 46u8, 108u8, 55u8, 46u8, 108u8, 56u8, 46u8, 108u8,
+                    57u8, 46u8, 50u8, 79u8, 46u8, 40u8, 97u8, 41u8, 40u8, 98u8, 41u8, 40u8, 99u8,
+                    41u8, 40u8, 100u8, 41u8, 40u8, 101u8, 41u8, 40u8, 102u8, 41u8, 40u8, 103u8,
+                    41u8, 40u8, 104u8, 41u8, 40u8, 105u8, 41u8, 40u8, 106u8, 41u8, 40u8, 107u8,
+                    41u8, 40u8, 108u8, 41u8, 40u8, 114u8, 110u8, 41u8, 40u8, 110u8, 41u8, 40u8,
+                    111u8, 41u8, 40u8, 112u8, 41u8, 40u8, 113u8, 41u8, 40u8, 114u8, 41u8, 40u8,
+                    115u8, 41u8, 40u8, 116u8, 41u8, 40u8, 117u8, 41u8, 40u8, 118u8, 41u8, 40u8,
+                    119u8, 41u8, 40u8, 120u8, 41u8, 40u8, 121u8, 41u8, 40u8, 122u8, 41u8, 194u8,
+                    169u8, 226u8, 132u8, 151u8, 194u8, 174u8, 226u8, 146u8, 190u8, 240u8, 159u8,
+                    132u8, 141u8, 227u8, 131u8, 188u8, 227u8, 131u8, 188u8, 226u8, 148u8, 130u8,
+                    226u8, 148u8, 140u8, 226u8, 148u8, 156u8, 47u8, 88u8, 226u8, 136u8, 142u8,
+                    226u8, 150u8, 140u8, 203u8, 137u8, 226u8, 150u8, 150u8, 226u8, 150u8, 152u8,
+                    226u8, 136u8, 142u8, 226u8, 143u8, 165u8, 206u8, 148u8, 226u8, 138u8, 179u8,
+                    226u8, 150u8, 182u8, 226u8, 150u8, 182u8, 240u8, 144u8, 138u8, 188u8, 226u8,
+                    138u8, 178u8, 225u8, 155u8, 156u8, 225u8, 155u8, 156u8, 194u8, 176u8, 226u8,
+                    140u8, 190u8, 226u8, 140u8, 146u8, 194u8, 176u8, 202u8, 152u8, 226u8, 150u8,
+                    161u8, 240u8, 144u8, 166u8, 158u8, 226u8, 178u8, 182u8, 226u8, 142u8, 136u8,
+                    226u8, 137u8, 143u8, 225u8, 155u8, 156u8, 240u8, 157u8, 133u8, 152u8, 240u8,
+                    157u8, 133u8, 165u8, 240u8, 157u8, 133u8, 152u8, 240u8, 157u8, 133u8, 165u8,
+                    240u8, 157u8, 133u8, 174u8, 224u8, 165u8, 176u8, 40u8, 41u8, 60u8, 62u8, 40u8,
+                    41u8, 123u8, 125u8, 43u8, 45u8, 195u8, 183u8, 234u8, 147u8, 149u8, 92u8, 225u8,
+                    145u8, 149u8, 225u8, 145u8, 144u8, 47u8, 47u8, 92u8, 84u8, 226u8, 157u8, 172u8,
+                    226u8, 157u8, 173u8, 120u8, 120u8, 225u8, 155u8, 144u8, 225u8, 155u8, 154u8,
+                    226u8, 135u8, 131u8, 226u8, 135u8, 130u8, 225u8, 155u8, 144u8, 226u8, 135u8,
+                    130u8, 226u8, 135u8, 131u8, 225u8, 155u8, 154u8, 226u8, 181u8, 130u8, 226u8,
+                    141u8, 137u8, 226u8, 140u8, 190u8, 227u8, 128u8, 188u8, 226u8, 141u8, 130u8,
+                    226u8, 140u8, 187u8, 240u8, 144u8, 139u8, 128u8, 226u8, 166u8, 154u8, 58u8,
+                    226u8, 134u8, 146u8, 92u8, 47u8, 204u8, 132u8, 47u8, 92u8, 202u8, 152u8, 240u8,
+                    144u8, 138u8, 168u8, 226u8, 138u8, 151u8, 226u8, 138u8, 141u8, 226u8, 138u8,
+                    142u8, 226u8, 138u8, 147u8, 226u8, 138u8, 148u8, 202u8, 131u8, 202u8, 131u8,
+                    202u8, 131u8, 202u8, 131u8, 225u8, 155u8, 158u8, 62u8, 62u8, 225u8, 155u8,
+                    154u8, 43u8, 204u8, 138u8, 43u8, 204u8, 130u8, 43u8, 204u8, 131u8, 43u8, 204u8,
+                    163u8, 43u8, 204u8, 176u8, 43u8, 226u8, 130u8, 130u8, 45u8, 204u8, 147u8, 45u8,
+                    204u8, 163u8, 120u8, 120u8, 204u8, 135u8, 226u8, 140u8, 153u8, 226u8, 168u8,
+                    159u8, 226u8, 136u8, 144u8, 126u8, 204u8, 135u8, 61u8, 226u8, 131u8, 176u8,
+                    58u8, 58u8, 61u8, 61u8, 61u8, 61u8, 61u8, 61u8, 62u8, 60u8, 225u8, 151u8,
+                    149u8, 225u8, 151u8, 146u8, 225u8, 145u8, 144u8, 225u8, 145u8, 149u8, 47u8,
+                    47u8, 47u8, 47u8, 47u8, 226u8, 134u8, 158u8, 226u8, 134u8, 159u8, 226u8, 134u8,
+                    160u8, 226u8, 134u8, 161u8, 72u8, 204u8, 169u8, 75u8, 204u8, 169u8, 206u8,
+                    147u8, 114u8, 206u8, 148u8, 234u8, 158u8, 146u8, 234u8, 158u8, 147u8, 72u8,
+                    108u8, 75u8, 196u8, 184u8, 206u8, 187u8, 77u8, 78u8, 79u8, 111u8, 206u8, 160u8,
+                    80u8, 112u8, 67u8, 99u8, 84u8, 89u8, 206u8, 166u8, 201u8, 184u8, 88u8, 207u8,
+                    135u8, 206u8, 168u8, 207u8, 137u8, 60u8, 194u8, 183u8, 45u8, 208u8, 168u8,
+                    209u8, 136u8, 47u8, 57u8, 51u8, 200u8, 157u8, 76u8, 202u8, 159u8, 54u8, 207u8,
+                    172u8, 207u8, 151u8, 226u8, 152u8, 167u8, 92u8, 92u8, 79u8, 204u8, 181u8,
+                    201u8, 133u8, 86u8, 69u8, 198u8, 142u8, 79u8, 204u8, 184u8, 194u8, 183u8,
+                    194u8, 183u8, 194u8, 183u8, 198u8, 169u8, 108u8, 33u8, 79u8, 81u8, 202u8,
+                    152u8, 88u8, 206u8, 148u8, 225u8, 155u8, 175u8, 225u8, 183u8, 159u8, 204u8,
+                    138u8, 205u8, 168u8, 205u8, 175u8, 205u8, 163u8, 205u8, 164u8, 45u8, 204u8,
+                    136u8, 126u8, 204u8, 135u8, 126u8, 204u8, 163u8, 225u8, 145u8, 149u8, 225u8,
+                    145u8, 144u8, 40u8, 40u8, 41u8, 41u8, 226u8, 136u8, 181u8, 226u8, 136u8, 180u8,
+                    226u8, 136u8, 183u8, 216u8, 159u8, 194u8, 176u8, 194u8, 183u8, 216u8, 140u8,
+                    216u8, 155u8, 225u8, 186u8, 159u8, 226u8, 181u8, 130u8, 194u8, 182u8, 61u8,
+                    228u8, 185u8, 155u8, 228u8, 185u8, 154u8, 228u8, 186u8, 187u8, 229u8, 136u8,
+                    130u8, 227u8, 148u8, 190u8, 229u8, 133u8, 128u8, 229u8, 176u8, 163u8, 229u8,
+                    176u8, 162u8, 229u8, 183u8, 179u8, 229u8, 185u8, 186u8, 229u8, 189u8, 145u8,
+                    229u8, 191u8, 132u8, 227u8, 163u8, 186u8, 230u8, 137u8, 140u8, 230u8, 148u8,
+                    181u8, 230u8, 151u8, 161u8, 230u8, 173u8, 186u8, 230u8, 175u8, 141u8, 230u8,
+                    176u8, 145u8, 230u8, 176u8, 181u8, 230u8, 176u8, 186u8, 231u8, 129u8, 172u8,
+                    231u8, 136u8, 171u8, 228u8, 184u8, 172u8, 231u8, 138u8, 173u8, 231u8, 189u8,
+                    146u8, 231u8, 164u8, 187u8, 231u8, 179u8, 185u8, 231u8, 189u8, 147u8, 231u8,
+                    189u8, 146u8, 232u8, 128u8, 130u8, 232u8, 130u8, 128u8, 232u8, 137u8, 185u8,
+                    232u8, 137u8, 185u8, 232u8, 137u8, 185u8, 232u8, 153u8, 142u8, 232u8, 161u8,
+                    164u8, 232u8, 166u8, 128u8, 232u8, 165u8, 191u8, 232u8, 167u8, 129u8, 232u8,
+                    174u8, 160u8, 232u8, 180u8, 157u8, 232u8, 189u8, 166u8, 232u8, 190u8, 182u8,
+                    232u8, 190u8, 182u8, 233u8, 152u8, 157u8, 233u8, 146u8, 133u8, 233u8, 149u8,
+                    183u8, 233u8, 149u8, 184u8, 233u8, 149u8, 191u8, 233u8, 151u8, 168u8, 233u8,
+                    152u8, 157u8, 233u8, 157u8, 146u8, 233u8, 159u8, 166u8, 233u8, 161u8, 181u8,
+                    233u8, 163u8, 142u8, 233u8, 163u8, 158u8, 233u8, 163u8, 159u8, 233u8, 163u8,
+                    160u8, 233u8, 165u8, 163u8, 233u8, 169u8, 172u8, 233u8, 172u8, 188u8, 233u8,
+                    177u8, 188u8, 233u8, 186u8, 166u8, 233u8, 187u8, 132u8, 230u8, 150u8, 137u8,
+                    233u8, 189u8, 144u8, 230u8, 173u8, 175u8, 233u8, 189u8, 191u8, 231u8, 171u8,
+                    156u8, 233u8, 190u8, 153u8, 228u8, 186u8, 128u8, 233u8, 190u8, 159u8, 227u8,
+                    131u8, 188u8, 228u8, 184u8, 168u8, 92u8, 47u8, 228u8, 185u8, 153u8, 228u8,
+                    186u8, 133u8, 228u8, 186u8, 140u8, 228u8, 186u8, 160u8, 228u8, 186u8, 186u8,
+                    229u8, 132u8, 191u8, 229u8, 133u8, 165u8, 229u8, 133u8, 171u8, 229u8, 134u8,
+                    130u8, 229u8, 134u8, 150u8, 229u8, 134u8, 171u8, 229u8, 135u8, 160u8, 229u8,
+                    135u8, 181u8, 229u8, 136u8, 128u8, 229u8, 138u8, 155u8, 229u8, 139u8, 185u8,
+                    229u8, 140u8, 149u8, 229u8, 140u8, 154u8, 229u8, 140u8, 184u8, 229u8, 141u8,
+                    129u8, 229u8, 141u8, 156u8, 229u8, 141u8, 169u8, 229u8, 142u8, 130u8, 229u8,
+                    142u8, 182u8, 229u8, 143u8, 136u8, 229u8, 143u8, 163u8, 229u8, 143u8, 163u8,
+                    229u8, 156u8, 159u8, 229u8, 156u8, 159u8, 229u8, 164u8, 130u8, 229u8, 164u8,
+                    138u8, 229u8, 164u8, 149u8, 229u8, 164u8, 167u8, 229u8, 165u8, 179u8, 229u8,
+                    173u8, 144u8, 229u8, 174u8, 128u8, 229u8, 175u8, 184u8, 229u8, 176u8, 143u8,
+                    229u8, 176u8, 162u8, 229u8, 176u8, 184u8, 229u8, 177u8, 174u8, 229u8, 177u8,
+                    177u8, 229u8, 183u8, 155u8, 229u8, 183u8, 165u8, 229u8, 183u8, 177u8, 229u8,
+                    183u8, 190u8, 229u8, 185u8, 178u8, 229u8, 185u8, 186u8, 229u8, 185u8, 191u8,
+                    229u8, 187u8, 180u8, 229u8, 187u8, 190u8, 229u8, 188u8, 139u8, 229u8, 188u8,
+                    147u8, 229u8, 189u8, 144u8, 229u8, 189u8, 161u8, 229u8, 189u8, 179u8, 229u8,
+                    191u8, 131u8, 230u8, 136u8, 136u8, 230u8, 136u8, 182u8, 230u8, 137u8, 139u8,
+                    230u8, 148u8, 175u8, 230u8, 148u8, 180u8, 230u8, 150u8, 135u8, 230u8, 150u8,
+                    151u8, 230u8, 150u8, 164u8, 230u8, 150u8, 185u8, 230u8, 151u8, 160u8, 230u8,
+                    151u8, 165u8, 230u8, 155u8, 176u8, 230u8, 156u8, 136u8, 230u8, 156u8, 168u8,
+                    230u8, 172u8, 160u8, 230u8, 173u8, 162u8, 230u8, 173u8, 185u8, 230u8, 174u8,
+                    179u8, 230u8, 175u8, 139u8, 230u8, 175u8, 148u8, 230u8, 175u8, 155u8, 230u8,
+                    176u8, 143u8, 230u8, 176u8, 148u8, 230u8, 176u8, 180u8, 231u8, 129u8, 171u8,
+                    231u8, 136u8, 170u8, 231u8, 136u8, 182u8, 231u8, 136u8, 187u8, 231u8, 136u8,
+                    191u8, 231u8, 137u8, 135u8, 231u8, 137u8, 153u8, 231u8, 137u8, 155u8, 231u8,
+                    138u8, 172u8, 231u8, 142u8, 132u8, 231u8, 142u8, 137u8, 231u8, 147u8, 156u8,
+                    231u8, 147u8, 166u8, 231u8, 148u8, 152u8, 231u8, 148u8, 159u8, 231u8, 148u8,
+                    168u8, 231u8, 148u8, 176u8, 231u8, 150u8, 139u8, 231u8, 150u8, 146u8, 231u8,
+                    153u8, 182u8, 231u8, 153u8, 189u8, 231u8, 154u8, 174u8, 231u8, 154u8, 191u8,
+                    231u8, 155u8, 174u8, 231u8, 159u8, 155u8, 231u8, 159u8, 162u8, 231u8, 159u8,
+                    179u8, 231u8, 164u8, 186u8, 231u8, 166u8, 184u8, 231u8, 166u8, 190u8, 231u8,
+                    169u8, 180u8, 231u8, 171u8, 139u8, 231u8, 171u8, 185u8, 231u8, 177u8, 179u8,
+                    231u8, 179u8, 184u8, 231u8, 188u8, 182u8, 231u8, 189u8, 145u8, 231u8, 190u8,
+                    138u8, 231u8, 190u8, 189u8, 232u8, 128u8, 129u8, 232u8, 128u8, 140u8, 232u8,
+                    128u8, 146u8, 232u8, 128u8, 179u8, 232u8, 129u8, 191u8, 232u8, 130u8, 137u8,
+                    232u8, 135u8, 163u8, 232u8, 135u8, 170u8, 232u8, 135u8, 179u8, 232u8, 135u8,
+                    188u8, 232u8, 136u8, 140u8, 232u8, 136u8, 155u8, 232u8, 136u8, 159u8, 232u8,
+                    137u8, 174u8, 232u8, 137u8, 178u8, 232u8, 137u8, 184u8, 232u8, 153u8, 141u8,
+                    232u8, 153u8, 171u8, 232u8, 161u8, 128u8, 232u8, 161u8, 140u8, 232u8, 161u8,
+                    163u8, 232u8, 165u8, 190u8, 232u8, 166u8, 139u8, 232u8, 167u8, 146u8, 232u8,
+                    168u8, 128u8, 232u8, 176u8, 183u8, 232u8, 177u8, 134u8, 232u8, 177u8, 149u8,
+                    232u8, 177u8, 184u8, 232u8, 178u8, 157u8, 232u8, 181u8, 164u8, 232u8, 181u8,
+                    176u8, 232u8, 182u8, 179u8, 232u8, 186u8, 171u8, 232u8, 187u8, 138u8, 232u8,
+                    190u8, 155u8, 232u8, 190u8, 176u8, 232u8, 190u8, 181u8, 233u8, 130u8, 145u8,
+                    233u8, 133u8, 137u8, 233u8, 135u8, 134u8, 233u8, 135u8, 140u8, 233u8, 135u8,
+                    145u8, 233u8, 149u8, 183u8, 233u8, 150u8, 128u8, 233u8, 152u8, 156u8, 233u8,
+                    154u8, 182u8, 233u8, 154u8, 185u8, 233u8, 155u8, 168u8, 233u8, 157u8, 145u8,
+                    233u8, 157u8, 158u8, 233u8, 157u8, 162u8, 233u8, 157u8, 169u8, 233u8, 159u8,
+                    139u8, 233u8, 159u8, 173u8, 233u8, 159u8, 179u8, 233u8, 160u8, 129u8, 233u8,
+                    162u8, 168u8, 233u8, 163u8, 155u8, 233u8, 163u8, 159u8, 233u8, 166u8, 150u8,
+                    233u8, 166u8, 153u8, 233u8, 166u8, 172u8, 233u8, 170u8, 168u8, 233u8, 171u8,
+                    152u8, 233u8, 171u8, 159u8, 233u8, 172u8, 165u8, 233u8, 172u8, 175u8, 233u8,
+                    172u8, 178u8, 233u8, 172u8, 188u8, 233u8, 173u8, 154u8, 233u8, 179u8, 165u8,
+                    233u8, 185u8, 181u8, 233u8, 185u8, 191u8, 233u8, 186u8, 165u8, 233u8, 186u8,
+                    187u8, 233u8, 187u8, 131u8, 233u8, 187u8, 141u8, 233u8, 187u8, 145u8, 233u8,
+                    187u8, 185u8, 233u8, 187u8, 189u8, 233u8, 188u8, 142u8, 233u8, 188u8, 147u8,
+                    233u8, 188u8, 160u8, 233u8, 188u8, 187u8, 233u8, 189u8, 138u8, 233u8, 189u8,
+                    146u8, 233u8, 190u8, 141u8, 233u8, 190u8, 156u8, 233u8, 190u8, 160u8, 203u8,
+                    179u8, 39u8, 39u8, 79u8, 226u8, 157u8, 172u8, 226u8, 157u8, 173u8, 226u8,
+                    130u8, 184u8, 40u8, 41u8, 226u8, 159u8, 166u8, 226u8, 159u8, 167u8, 204u8,
+                    137u8, 204u8, 165u8, 47u8, 226u8, 130u8, 184u8, 229u8, 141u8, 129u8, 229u8,
+                    141u8, 132u8, 229u8, 141u8, 133u8, 226u8, 157u8, 172u8, 204u8, 138u8, 239u8,
+                    190u8, 158u8, 239u8, 190u8, 159u8, 61u8, 228u8, 186u8, 187u8, 229u8, 183u8,
+                    165u8, 229u8, 138u8, 155u8, 229u8, 164u8, 149u8, 229u8, 141u8, 156u8, 228u8,
+                    186u8, 140u8, 47u8, 229u8, 133u8, 171u8, 227u8, 129u8, 184u8, 229u8, 143u8,
+                    163u8, 194u8, 183u8, 225u8, 132u8, 128u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    128u8, 225u8, 132u8, 128u8, 225u8, 132u8, 137u8, 225u8, 132u8, 130u8, 225u8,
+                    132u8, 130u8, 225u8, 132u8, 140u8, 225u8, 132u8, 130u8, 225u8, 132u8, 146u8,
+                    225u8, 132u8, 131u8, 225u8, 132u8, 131u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 133u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8, 132u8, 133u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8, 132u8, 144u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 145u8, 225u8, 132u8, 133u8, 225u8, 132u8, 146u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 139u8, 225u8, 132u8, 140u8, 225u8,
+                    132u8, 140u8, 225u8, 132u8, 140u8, 225u8, 132u8, 142u8, 225u8, 132u8, 143u8,
+                    225u8, 132u8, 144u8, 225u8, 132u8, 145u8, 225u8, 132u8, 146u8, 225u8, 133u8,
+                    161u8, 225u8, 133u8, 161u8, 228u8, 184u8, 168u8, 225u8, 133u8, 163u8, 225u8,
+                    133u8, 163u8, 228u8, 184u8, 168u8, 225u8, 133u8, 165u8, 225u8, 133u8, 165u8,
+                    228u8, 184u8, 168u8, 225u8, 133u8, 167u8, 225u8, 133u8, 167u8, 228u8, 184u8,
+                    168u8, 225u8, 133u8, 169u8, 225u8, 133u8, 169u8, 225u8, 133u8, 161u8, 225u8,
+                    133u8, 169u8, 225u8, 133u8, 161u8, 228u8, 184u8, 168u8, 225u8, 133u8, 169u8,
+                    228u8, 184u8, 168u8, 225u8, 133u8, 173u8, 225u8, 133u8, 174u8, 225u8, 133u8,
+                    174u8, 225u8, 133u8, 165u8, 225u8, 133u8, 174u8, 225u8, 133u8, 165u8, 228u8,
+                    184u8, 168u8, 225u8, 133u8, 174u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8,
+                    227u8, 131u8, 188u8, 227u8, 131u8, 188u8, 228u8, 184u8, 168u8, 228u8, 184u8,
+                    168u8, 225u8, 133u8, 160u8, 225u8, 132u8, 130u8, 225u8, 132u8, 130u8, 225u8,
+                    132u8, 130u8, 225u8, 132u8, 131u8, 225u8, 132u8, 130u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 130u8, 225u8, 133u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8,
+                    128u8, 225u8, 132u8, 137u8, 225u8, 132u8, 133u8, 225u8, 132u8, 131u8, 225u8,
+                    132u8, 133u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 133u8,
+                    225u8, 133u8, 128u8, 225u8, 132u8, 133u8, 225u8, 133u8, 153u8, 225u8, 132u8,
+                    134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 134u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 134u8, 225u8, 133u8, 128u8, 225u8, 132u8, 134u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 135u8, 225u8, 132u8, 128u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    131u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 140u8, 225u8, 132u8, 135u8, 225u8, 132u8, 144u8, 225u8, 132u8,
+                    135u8, 225u8, 132u8, 139u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 139u8, 225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 130u8, 225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 140u8, 225u8,
+                    133u8, 128u8, 225u8, 132u8, 139u8, 225u8, 132u8, 139u8, 225u8, 133u8, 140u8,
+                    225u8, 132u8, 139u8, 225u8, 132u8, 137u8, 225u8, 132u8, 139u8, 225u8, 133u8,
+                    128u8, 225u8, 132u8, 145u8, 225u8, 132u8, 139u8, 225u8, 132u8, 146u8, 225u8,
+                    132u8, 146u8, 225u8, 133u8, 153u8, 225u8, 133u8, 173u8, 225u8, 133u8, 163u8,
+                    225u8, 133u8, 173u8, 225u8, 133u8, 163u8, 228u8, 184u8, 168u8, 225u8, 133u8,
+                    173u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8, 225u8, 133u8, 167u8, 225u8,
+                    133u8, 178u8, 225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8,
+                    228u8, 184u8, 168u8, 225u8, 134u8, 158u8, 225u8, 134u8, 158u8, 228u8, 184u8,
+                    168u8, 227u8, 131u8, 188u8, 228u8, 184u8, 168u8, 47u8, 92u8, 228u8, 185u8,
+                    155u8, 228u8, 186u8, 133u8, 226u8, 157u8, 172u8, 228u8, 185u8, 154u8, 228u8,
+                    185u8, 153u8, 40u8, 225u8, 132u8, 128u8, 41u8, 40u8, 225u8, 132u8, 130u8, 41u8,
+                    40u8, 225u8, 132u8, 131u8, 41u8, 40u8, 225u8, 132u8, 133u8, 41u8, 40u8, 225u8,
+                    132u8, 134u8, 41u8, 40u8, 225u8, 132u8, 135u8, 41u8, 40u8, 225u8, 132u8, 137u8,
+                    41u8, 40u8, 225u8, 132u8, 139u8, 41u8, 40u8, 225u8, 132u8, 140u8, 41u8, 40u8,
+                    225u8, 132u8, 142u8, 41u8, 40u8, 225u8, 132u8, 143u8, 41u8, 40u8, 225u8, 132u8,
+                    144u8, 41u8, 40u8, 225u8, 132u8, 145u8, 41u8, 40u8, 225u8, 132u8, 146u8, 41u8,
+                    40u8, 234u8, 176u8, 128u8, 41u8, 40u8, 235u8, 130u8, 152u8, 41u8, 40u8, 235u8,
+                    139u8, 164u8, 41u8, 40u8, 235u8, 157u8, 188u8, 41u8, 40u8, 235u8, 167u8, 136u8,
+                    41u8, 40u8, 235u8, 176u8, 148u8, 41u8, 40u8, 236u8, 130u8, 172u8, 41u8, 40u8,
+                    236u8, 149u8, 132u8, 41u8, 40u8, 236u8, 158u8, 144u8, 41u8, 40u8, 236u8, 176u8,
+                    168u8, 41u8, 40u8, 236u8, 185u8, 180u8, 41u8, 40u8, 237u8, 131u8, 128u8, 41u8,
+                    40u8, 237u8, 140u8, 140u8, 41u8, 40u8, 237u8, 149u8, 152u8, 41u8, 40u8, 236u8,
+                    163u8, 188u8, 41u8, 40u8, 236u8, 152u8, 164u8, 236u8, 160u8, 132u8, 41u8, 40u8,
+                    236u8, 152u8, 164u8, 237u8, 155u8, 132u8, 41u8, 40u8, 227u8, 131u8, 188u8,
+                    41u8, 40u8, 228u8, 186u8, 140u8, 41u8, 40u8, 228u8, 184u8, 137u8, 41u8, 40u8,
+                    229u8, 155u8, 155u8, 41u8, 40u8, 228u8, 186u8, 148u8, 41u8, 40u8, 229u8, 133u8,
+                    173u8, 41u8, 40u8, 228u8, 184u8, 131u8, 41u8, 40u8, 229u8, 133u8, 171u8, 41u8,
+                    40u8, 228u8, 185u8, 157u8, 41u8, 40u8, 229u8, 141u8, 129u8, 41u8, 40u8, 230u8,
+                    156u8, 136u8, 41u8, 40u8, 231u8, 129u8, 171u8, 41u8, 40u8, 230u8, 176u8, 180u8,
+                    41u8, 40u8, 230u8, 156u8, 168u8, 41u8, 40u8, 233u8, 135u8, 145u8, 41u8, 40u8,
+                    229u8, 156u8, 159u8, 41u8, 40u8, 230u8, 151u8, 165u8, 41u8, 40u8, 230u8, 160u8,
+                    170u8, 41u8, 40u8, 230u8, 156u8, 137u8, 41u8, 40u8, 231u8, 164u8, 190u8, 41u8,
+                    40u8, 229u8, 144u8, 141u8, 41u8, 40u8, 231u8, 137u8, 185u8, 41u8, 40u8, 232u8,
+                    178u8, 161u8, 41u8, 40u8, 231u8, 165u8, 157u8, 41u8, 40u8, 229u8, 138u8, 180u8,
+                    41u8, 40u8, 228u8, 187u8, 163u8, 41u8, 40u8, 229u8, 145u8, 188u8, 41u8, 40u8,
+                    229u8, 173u8, 166u8, 41u8, 40u8, 231u8, 155u8, 163u8, 41u8, 40u8, 228u8, 188u8,
+                    129u8, 41u8, 40u8, 232u8, 179u8, 135u8, 41u8, 40u8, 229u8, 141u8, 148u8, 41u8,
+                    40u8, 231u8, 165u8, 173u8, 41u8, 40u8, 228u8, 188u8, 145u8, 41u8, 40u8, 232u8,
+                    135u8, 170u8, 41u8, 40u8, 232u8, 135u8, 179u8, 41u8, 108u8, 230u8, 156u8,
+                    136u8, 50u8, 230u8, 156u8, 136u8, 51u8, 230u8, 156u8, 136u8, 52u8, 230u8,
+                    156u8, 136u8, 53u8, 230u8, 156u8, 136u8, 54u8, 230u8, 156u8, 136u8, 55u8,
+                    230u8, 156u8, 136u8, 56u8, 230u8, 156u8, 136u8, 57u8, 230u8, 156u8, 136u8,
+                    108u8, 79u8, 230u8, 156u8, 136u8, 108u8, 108u8, 230u8, 156u8, 136u8, 108u8,
+                    50u8, 230u8, 156u8, 136u8, 79u8, 231u8, 130u8, 185u8, 108u8, 231u8, 130u8,
+                    185u8, 50u8, 231u8, 130u8, 185u8, 51u8, 231u8, 130u8, 185u8, 52u8, 231u8,
+                    130u8, 185u8, 53u8, 231u8, 130u8, 185u8, 54u8, 231u8, 130u8, 185u8, 55u8,
+                    231u8, 130u8, 185u8, 56u8, 231u8, 130u8, 185u8, 57u8, 231u8, 130u8, 185u8,
+                    108u8, 79u8, 231u8, 130u8, 185u8, 108u8, 108u8, 231u8, 130u8, 185u8, 108u8,
+                    50u8, 231u8, 130u8, 185u8, 108u8, 51u8, 231u8, 130u8, 185u8, 108u8, 52u8,
+                    231u8, 130u8, 185u8, 108u8, 53u8, 231u8, 130u8, 185u8, 108u8, 54u8, 231u8,
+                    130u8, 185u8, 108u8, 55u8, 231u8, 130u8, 185u8, 108u8, 56u8, 231u8, 130u8,
+                    185u8, 108u8, 57u8, 231u8, 130u8, 185u8, 50u8, 79u8, 231u8, 130u8, 185u8, 50u8,
+                    108u8, 231u8, 130u8, 185u8, 50u8, 50u8, 231u8, 130u8, 185u8, 50u8, 51u8, 231u8,
+                    130u8, 185u8, 50u8, 52u8, 231u8, 130u8, 185u8, 108u8, 230u8, 151u8, 165u8,
+                    50u8, 230u8, 151u8, 165u8, 51u8, 230u8, 151u8, 165u8, 52u8, 230u8, 151u8,
+                    165u8, 53u8, 230u8, 151u8, 165u8, 54u8, 230u8, 151u8, 165u8, 55u8, 230u8,
+                    151u8, 165u8, 56u8, 230u8, 151u8, 165u8, 57u8, 230u8, 151u8, 165u8, 108u8,
+                    79u8, 230u8, 151u8, 165u8, 108u8, 108u8, 230u8, 151u8, 165u8, 108u8, 50u8,
+                    230u8, 151u8, 165u8, 108u8, 51u8, 230u8, 151u8, 165u8, 108u8, 52u8, 230u8,
+                    151u8, 165u8, 108u8, 53u8, 230u8, 151u8, 165u8, 108u8, 54u8, 230u8, 151u8,
+                    165u8, 108u8, 55u8, 230u8, 151u8, 165u8, 108u8, 56u8, 230u8, 151u8, 165u8,
+                    108u8, 57u8, 230u8, 151u8, 165u8, 50u8, 79u8, 230u8, 151u8, 165u8, 50u8, 108u8,
+                    230u8, 151u8, 165u8, 50u8, 50u8, 230u8, 151u8, 165u8, 50u8, 51u8, 230u8, 151u8,
+                    165u8, 50u8, 52u8, 230u8, 151u8, 165u8, 50u8, 53u8, 230u8, 151u8, 165u8, 50u8,
+                    54u8, 230u8, 151u8, 165u8, 50u8, 55u8, 230u8, 151u8, 165u8, 50u8, 56u8, 230u8,
+                    151u8, 165u8, 50u8, 57u8, 230u8, 151u8, 165u8, 51u8, 79u8, 230u8, 151u8, 165u8,
+                    51u8, 108u8, 230u8, 151u8, 165u8, 227u8, 152u8, 189u8, 227u8, 150u8, 136u8,
+                    227u8, 172u8, 187u8, 227u8, 131u8, 188u8, 92u8, 47u8, 228u8, 189u8, 181u8,
+                    229u8, 128u8, 164u8, 229u8, 149u8, 147u8, 229u8, 143u8, 163u8, 229u8, 161u8,
+                    161u8, 229u8, 156u8, 159u8, 229u8, 162u8, 171u8, 229u8, 170u8, 175u8, 229u8,
+                    184u8, 161u8, 227u8, 172u8, 186u8, 230u8, 136u8, 182u8, 227u8, 169u8, 129u8,
+                    228u8, 128u8, 191u8, 230u8, 153u8, 154u8, 227u8, 171u8, 154u8, 228u8, 145u8,
+                    131u8, 230u8, 157u8, 174u8, 227u8, 174u8, 163u8, 230u8, 166u8, 157u8, 230u8,
+                    186u8, 136u8, 231u8, 160u8, 148u8, 231u8, 181u8, 149u8, 230u8, 156u8, 140u8,
+                    230u8, 156u8, 144u8, 230u8, 156u8, 143u8, 227u8, 172u8, 181u8, 230u8, 156u8,
+                    147u8, 230u8, 156u8, 152u8, 232u8, 131u8, 188u8, 230u8, 156u8, 163u8, 232u8,
+                    146u8, 141u8, 232u8, 152u8, 183u8, 228u8, 154u8, 182u8, 232u8, 168u8, 174u8,
+                    232u8, 174u8, 134u8, 232u8, 177u8, 156u8, 232u8, 181u8, 191u8, 232u8, 183u8,
+                    165u8, 232u8, 186u8, 151u8, 232u8, 187u8, 191u8, 233u8, 131u8, 142u8, 233u8,
+                    142u8, 173u8, 233u8, 154u8, 183u8, 233u8, 185u8, 130u8, 233u8, 187u8, 145u8,
+                    228u8, 128u8, 185u8, 234u8, 139u8, 141u8, 234u8, 131u8, 128u8, 234u8, 129u8,
+                    138u8, 234u8, 145u8, 152u8, 234u8, 132u8, 178u8, 234u8, 129u8, 144u8, 234u8,
+                    143u8, 130u8, 234u8, 142u8, 191u8, 234u8, 138u8, 177u8, 234u8, 137u8, 153u8,
+                    234u8, 142u8, 171u8, 234u8, 142u8, 181u8, 66u8, 80u8, 100u8, 68u8, 84u8, 71u8,
+                    75u8, 74u8, 67u8, 198u8, 134u8, 90u8, 70u8, 226u8, 132u8, 178u8, 77u8, 78u8,
+                    76u8, 83u8, 82u8, 201u8, 133u8, 86u8, 72u8, 87u8, 88u8, 89u8, 225u8, 153u8,
+                    160u8, 65u8, 226u8, 177u8, 175u8, 69u8, 198u8, 142u8, 108u8, 79u8, 85u8, 213u8,
+                    136u8, 225u8, 151u8, 161u8, 46u8, 44u8, 46u8, 46u8, 46u8, 44u8, 58u8, 45u8,
+                    46u8, 61u8, 46u8, 50u8, 198u8, 168u8, 105u8, 207u8, 137u8, 208u8, 170u8, 108u8,
+                    203u8, 137u8, 98u8, 105u8, 202u8, 152u8, 226u8, 131u8, 169u8, 204u8, 134u8,
+                    203u8, 135u8, 104u8, 204u8, 148u8, 79u8, 79u8, 111u8, 111u8, 240u8, 144u8,
+                    138u8, 168u8, 208u8, 152u8, 225u8, 154u8, 185u8, 226u8, 177u8, 181u8, 202u8,
+                    161u8, 201u8, 133u8, 206u8, 160u8, 86u8, 63u8, 50u8, 204u8, 130u8, 204u8,
+                    132u8, 234u8, 155u8, 179u8, 234u8, 155u8, 179u8, 203u8, 171u8, 203u8, 170u8,
+                    84u8, 51u8, 116u8, 200u8, 157u8, 115u8, 65u8, 65u8, 97u8, 97u8, 65u8, 79u8,
+                    97u8, 111u8, 65u8, 85u8, 97u8, 117u8, 65u8, 86u8, 97u8, 118u8, 65u8, 86u8,
+                    97u8, 118u8, 65u8, 89u8, 97u8, 121u8, 75u8, 204u8, 181u8, 79u8, 204u8, 181u8,
+                    111u8, 204u8, 181u8, 79u8, 79u8, 111u8, 111u8, 50u8, 119u8, 204u8, 166u8, 51u8,
+                    200u8, 157u8, 57u8, 116u8, 102u8, 38u8, 234u8, 157u8, 185u8, 58u8, 39u8, 194u8,
+                    183u8, 234u8, 156u8, 167u8, 70u8, 102u8, 240u8, 144u8, 144u8, 146u8, 240u8,
+                    144u8, 144u8, 186u8, 202u8, 154u8, 234u8, 147u8, 164u8, 117u8, 51u8, 234u8,
+                    147u8, 149u8, 74u8, 88u8, 66u8, 195u8, 159u8, 234u8, 153u8, 140u8, 207u8,
+                    137u8, 227u8, 131u8, 188u8, 224u8, 165u8, 164u8, 225u8, 132u8, 131u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8, 225u8, 132u8, 131u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 140u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8,
+                    132u8, 128u8, 225u8, 132u8, 133u8, 225u8, 132u8, 131u8, 225u8, 132u8, 133u8,
+                    225u8, 132u8, 131u8, 225u8, 132u8, 131u8, 225u8, 132u8, 133u8, 225u8, 132u8,
+                    134u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8, 132u8, 133u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 135u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 139u8, 225u8, 132u8, 133u8, 225u8, 132u8, 137u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 140u8, 225u8, 132u8, 133u8, 225u8, 132u8, 143u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 128u8, 225u8, 132u8, 134u8, 225u8, 132u8, 131u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 137u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 144u8, 225u8, 132u8, 135u8, 225u8, 132u8, 143u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 146u8, 225u8, 132u8, 137u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 132u8, 133u8, 225u8, 132u8,
+                    139u8, 225u8, 132u8, 146u8, 225u8, 132u8, 140u8, 225u8, 132u8, 140u8, 225u8,
+                    132u8, 146u8, 225u8, 132u8, 144u8, 225u8, 132u8, 144u8, 225u8, 132u8, 145u8,
+                    225u8, 132u8, 146u8, 225u8, 132u8, 146u8, 225u8, 132u8, 137u8, 225u8, 133u8,
+                    153u8, 225u8, 133u8, 153u8, 226u8, 176u8, 191u8, 234u8, 166u8, 157u8, 234u8,
+                    167u8, 144u8, 217u8, 162u8, 234u8, 168u8, 129u8, 234u8, 168u8, 163u8, 101u8,
+                    102u8, 111u8, 111u8, 204u8, 184u8, 201u8, 148u8, 204u8, 184u8, 199u8, 157u8,
+                    111u8, 204u8, 184u8, 199u8, 157u8, 111u8, 204u8, 181u8, 114u8, 114u8, 202u8,
+                    131u8, 117u8, 117u8, 207u8, 135u8, 207u8, 135u8, 121u8, 209u8, 153u8, 201u8,
+                    148u8, 101u8, 117u8, 111u8, 225u8, 180u8, 133u8, 202u8, 128u8, 225u8, 180u8,
+                    155u8, 111u8, 204u8, 155u8, 105u8, 225u8, 180u8, 128u8, 225u8, 180u8, 138u8,
+                    225u8, 180u8, 135u8, 201u8, 130u8, 226u8, 177u8, 182u8, 114u8, 119u8, 202u8,
+                    141u8, 202u8, 156u8, 111u8, 204u8, 181u8, 201u8, 162u8, 122u8, 234u8, 158u8,
+                    147u8, 117u8, 204u8, 181u8, 198u8, 133u8, 202u8, 128u8, 118u8, 115u8, 202u8,
+                    159u8, 99u8, 225u8, 180u8, 152u8, 196u8, 184u8, 111u8, 204u8, 181u8, 225u8,
+                    133u8, 169u8, 225u8, 133u8, 167u8, 225u8, 133u8, 169u8, 225u8, 133u8, 169u8,
+                    228u8, 184u8, 168u8, 225u8, 133u8, 173u8, 225u8, 133u8, 161u8, 225u8, 133u8,
+                    173u8, 225u8, 133u8, 161u8, 228u8, 184u8, 168u8, 225u8, 133u8, 173u8, 225u8,
+                    133u8, 165u8, 225u8, 133u8, 174u8, 225u8, 133u8, 167u8, 225u8, 133u8, 174u8,
+                    228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8, 225u8, 133u8,
+                    161u8, 228u8, 184u8, 168u8, 225u8, 133u8, 178u8, 225u8, 133u8, 169u8, 227u8,
+                    131u8, 188u8, 225u8, 133u8, 161u8, 227u8, 131u8, 188u8, 225u8, 133u8, 165u8,
+                    227u8, 131u8, 188u8, 225u8, 133u8, 165u8, 228u8, 184u8, 168u8, 227u8, 131u8,
+                    188u8, 225u8, 133u8, 169u8, 228u8, 184u8, 168u8, 225u8, 133u8, 163u8, 225u8,
+                    133u8, 169u8, 228u8, 184u8, 168u8, 225u8, 133u8, 163u8, 228u8, 184u8, 168u8,
+                    228u8, 184u8, 168u8, 225u8, 133u8, 167u8, 228u8, 184u8, 168u8, 225u8, 133u8,
+                    167u8, 228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 225u8, 133u8, 169u8, 228u8,
+                    184u8, 168u8, 228u8, 184u8, 168u8, 225u8, 133u8, 173u8, 228u8, 184u8, 168u8,
+                    225u8, 133u8, 178u8, 228u8, 184u8, 168u8, 228u8, 184u8, 168u8, 225u8, 134u8,
+                    158u8, 225u8, 133u8, 161u8, 225u8, 134u8, 158u8, 225u8, 133u8, 165u8, 228u8,
+                    184u8, 168u8, 225u8, 132u8, 130u8, 225u8, 132u8, 133u8, 225u8, 132u8, 130u8,
+                    225u8, 132u8, 142u8, 225u8, 132u8, 131u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    131u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8, 225u8, 132u8, 131u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 131u8, 225u8, 132u8, 137u8, 225u8, 132u8, 131u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    140u8, 225u8, 132u8, 131u8, 225u8, 132u8, 142u8, 225u8, 132u8, 131u8, 225u8,
+                    132u8, 144u8, 225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 128u8,
+                    225u8, 132u8, 133u8, 225u8, 132u8, 128u8, 225u8, 132u8, 146u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 133u8, 225u8, 132u8, 143u8, 225u8, 132u8, 133u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 146u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 131u8, 225u8, 132u8, 133u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    145u8, 225u8, 132u8, 133u8, 225u8, 133u8, 140u8, 225u8, 132u8, 133u8, 225u8,
+                    133u8, 153u8, 225u8, 132u8, 146u8, 225u8, 132u8, 133u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 134u8, 225u8, 132u8, 130u8, 225u8, 132u8, 134u8, 225u8, 132u8,
+                    130u8, 225u8, 132u8, 130u8, 225u8, 132u8, 134u8, 225u8, 132u8, 134u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 137u8, 225u8, 132u8, 134u8,
+                    225u8, 132u8, 140u8, 225u8, 132u8, 135u8, 225u8, 132u8, 131u8, 225u8, 132u8,
+                    135u8, 225u8, 132u8, 133u8, 225u8, 132u8, 145u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8, 225u8, 132u8, 135u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 135u8, 225u8, 132u8,
+                    140u8, 225u8, 132u8, 135u8, 225u8, 132u8, 142u8, 225u8, 132u8, 137u8, 225u8,
+                    132u8, 134u8, 225u8, 132u8, 137u8, 225u8, 132u8, 135u8, 225u8, 132u8, 139u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 128u8, 225u8, 132u8,
+                    137u8, 225u8, 132u8, 137u8, 225u8, 132u8, 131u8, 225u8, 132u8, 137u8, 225u8,
+                    133u8, 128u8, 225u8, 132u8, 137u8, 225u8, 132u8, 140u8, 225u8, 132u8, 137u8,
+                    225u8, 132u8, 142u8, 225u8, 132u8, 137u8, 225u8, 132u8, 144u8, 225u8, 132u8,
+                    133u8, 225u8, 132u8, 146u8, 225u8, 133u8, 128u8, 225u8, 132u8, 135u8, 225u8,
+                    133u8, 128u8, 225u8, 132u8, 135u8, 225u8, 132u8, 139u8, 225u8, 133u8, 140u8,
+                    225u8, 132u8, 134u8, 225u8, 133u8, 140u8, 225u8, 132u8, 146u8, 225u8, 132u8,
+                    140u8, 225u8, 132u8, 135u8, 225u8, 132u8, 140u8, 225u8, 132u8, 135u8, 225u8,
+                    132u8, 135u8, 225u8, 132u8, 140u8, 225u8, 132u8, 140u8, 225u8, 132u8, 145u8,
+                    225u8, 132u8, 137u8, 225u8, 132u8, 145u8, 225u8, 132u8, 144u8, 232u8, 177u8,
+                    136u8, 230u8, 155u8, 180u8, 232u8, 187u8, 138u8, 232u8, 179u8, 136u8, 230u8,
+                    187u8, 145u8, 228u8, 184u8, 178u8, 229u8, 143u8, 165u8, 233u8, 190u8, 156u8,
+                    233u8, 190u8, 156u8, 229u8, 165u8, 145u8, 233u8, 135u8, 145u8, 229u8, 150u8,
+                    135u8, 229u8, 165u8, 136u8, 230u8, 135u8, 182u8, 231u8, 153u8, 169u8, 231u8,
+                    190u8, 133u8, 232u8, 152u8, 191u8, 232u8, 158u8, 186u8, 232u8, 163u8, 184u8,
+                    233u8, 130u8, 143u8, 230u8, 168u8, 130u8, 230u8, 180u8, 155u8, 231u8, 131u8,
+                    153u8, 231u8, 143u8, 158u8, 232u8, 144u8, 189u8, 233u8, 133u8, 170u8, 233u8,
+                    167u8, 177u8, 228u8, 186u8, 130u8, 229u8, 141u8, 181u8, 230u8, 172u8, 132u8,
+                    231u8, 136u8, 155u8, 232u8, 152u8, 173u8, 233u8, 184u8, 158u8, 229u8, 181u8,
+                    144u8, 230u8, 191u8, 171u8, 232u8, 151u8, 141u8, 232u8, 165u8, 164u8, 230u8,
+                    139u8, 137u8, 232u8, 135u8, 152u8, 232u8, 160u8, 159u8, 229u8, 187u8, 138u8,
+                    230u8, 156u8, 151u8, 230u8, 181u8, 170u8, 231u8, 139u8, 188u8, 233u8, 131u8,
+                    142u8, 228u8, 190u8, 134u8, 229u8, 134u8, 183u8, 229u8, 139u8, 158u8, 230u8,
+                    147u8, 132u8, 230u8, 171u8, 147u8, 231u8, 136u8, 144u8, 231u8, 155u8, 167u8,
+                    232u8, 128u8, 129u8, 232u8, 152u8, 134u8, 232u8, 153u8, 156u8, 232u8, 183u8,
+                    175u8, 233u8, 156u8, 178u8, 233u8, 173u8, 175u8, 233u8, 183u8, 186u8, 231u8,
+                    162u8, 140u8, 231u8, 165u8, 191u8, 231u8, 182u8, 160u8, 232u8, 143u8, 137u8,
+                    233u8, 140u8, 132u8, 233u8, 185u8, 191u8, 232u8, 171u8, 150u8, 229u8, 163u8,
+                    159u8, 229u8, 188u8, 132u8, 231u8, 177u8, 160u8, 232u8, 129u8, 190u8, 231u8,
+                    137u8, 162u8, 231u8, 163u8, 138u8, 232u8, 179u8, 130u8, 233u8, 155u8, 183u8,
+                    229u8, 163u8, 152u8, 229u8, 177u8, 162u8, 230u8, 168u8, 147u8, 230u8, 183u8,
+                    154u8, 230u8, 188u8, 143u8, 231u8, 180u8, 175u8, 231u8, 184u8, 183u8, 233u8,
+                    153u8, 139u8, 229u8, 139u8, 146u8, 232u8, 130u8, 139u8, 229u8, 135u8, 156u8,
+                    229u8, 135u8, 140u8, 231u8, 168u8, 156u8, 231u8, 182u8, 190u8, 232u8, 143u8,
+                    177u8, 233u8, 153u8, 181u8, 232u8, 174u8, 128u8, 230u8, 139u8, 143u8, 230u8,
+                    168u8, 130u8, 232u8, 171u8, 190u8, 228u8, 184u8, 185u8, 229u8, 175u8, 167u8,
+                    230u8, 128u8, 146u8, 231u8, 142u8, 135u8, 231u8, 149u8, 176u8, 229u8, 140u8,
+                    151u8, 231u8, 163u8, 187u8, 228u8, 190u8, 191u8, 229u8, 190u8, 169u8, 228u8,
+                    184u8, 141u8, 230u8, 179u8, 140u8, 230u8, 149u8, 184u8, 231u8, 180u8, 162u8,
+                    229u8, 143u8, 131u8, 229u8, 161u8, 158u8, 231u8, 156u8, 129u8, 232u8, 145u8,
+                    137u8, 232u8, 170u8, 170u8, 230u8, 174u8, 186u8, 232u8, 190u8, 176u8, 230u8,
+                    178u8, 136u8, 230u8, 139u8, 190u8, 232u8, 139u8, 165u8, 230u8, 142u8, 160u8,
+                    231u8, 149u8, 165u8, 228u8, 186u8, 174u8, 229u8, 133u8, 169u8, 229u8, 135u8,
+                    137u8, 230u8, 162u8, 129u8, 231u8, 179u8, 167u8, 232u8, 137u8, 175u8, 232u8,
+                    171u8, 146u8, 233u8, 135u8, 143u8, 229u8, 139u8, 181u8, 229u8, 145u8, 130u8,
+                    229u8, 165u8, 179u8, 229u8, 187u8, 172u8, 230u8, 151u8, 133u8, 230u8, 191u8,
+                    190u8, 231u8, 164u8, 170u8, 233u8, 150u8, 173u8, 233u8, 169u8, 170u8, 233u8,
+                    186u8, 151u8, 233u8, 187u8, 142u8, 229u8, 138u8, 155u8, 230u8, 155u8, 134u8,
+                    230u8, 173u8, 183u8, 232u8, 189u8, 162u8, 229u8, 185u8, 180u8, 230u8, 134u8,
+                    144u8, 230u8, 136u8, 128u8, 230u8, 146u8, 154u8, 230u8, 188u8, 163u8, 231u8,
+                    133u8, 137u8, 231u8, 146u8, 137u8, 231u8, 167u8, 138u8, 231u8, 183u8, 180u8,
+                    232u8, 129u8, 175u8, 232u8, 188u8, 166u8, 232u8, 147u8, 174u8, 233u8, 128u8,
+                    163u8, 233u8, 141u8, 138u8, 229u8, 136u8, 151u8, 229u8, 138u8, 163u8, 229u8,
+                    146u8, 189u8, 231u8, 131u8, 136u8, 232u8, 163u8, 130u8, 232u8, 170u8, 170u8,
+                    229u8, 187u8, 137u8, 229u8, 191u8, 181u8, 230u8, 141u8, 187u8, 230u8, 174u8,
+                    174u8, 231u8, 176u8, 190u8, 231u8, 141u8, 181u8, 228u8, 187u8, 164u8, 229u8,
+                    155u8, 185u8, 229u8, 175u8, 167u8, 229u8, 182u8, 186u8, 230u8, 128u8, 156u8,
+                    231u8, 142u8, 178u8, 231u8, 145u8, 169u8, 231u8, 190u8, 154u8, 232u8, 129u8,
+                    134u8, 233u8, 136u8, 180u8, 233u8, 155u8, 182u8, 233u8, 157u8, 136u8, 233u8,
+                    160u8, 152u8, 228u8, 190u8, 139u8, 231u8, 166u8, 174u8, 233u8, 134u8, 180u8,
+                    233u8, 154u8, 183u8, 230u8, 131u8, 161u8, 228u8, 186u8, 134u8, 229u8, 131u8,
+                    154u8, 229u8, 175u8, 174u8, 229u8, 176u8, 191u8, 230u8, 150u8, 153u8, 230u8,
+                    168u8, 130u8, 231u8, 135u8, 142u8, 231u8, 153u8, 130u8, 232u8, 147u8, 188u8,
+                    233u8, 129u8, 188u8, 233u8, 190u8, 141u8, 230u8, 154u8, 136u8, 233u8, 152u8,
+                    174u8, 229u8, 138u8, 137u8, 230u8, 157u8, 187u8, 230u8, 159u8, 179u8, 230u8,
+                    181u8, 129u8, 230u8, 186u8, 156u8, 231u8, 144u8, 137u8, 231u8, 149u8, 153u8,
+                    231u8, 161u8, 171u8, 231u8, 180u8, 144u8, 233u8, 161u8, 158u8, 229u8, 133u8,
+                    173u8, 230u8, 136u8, 174u8, 233u8, 153u8, 184u8, 229u8, 128u8, 171u8, 229u8,
+                    180u8, 153u8, 230u8, 183u8, 170u8, 232u8, 188u8, 170u8, 229u8, 190u8, 139u8,
+                    230u8, 133u8, 132u8, 230u8, 160u8, 151u8, 231u8, 142u8, 135u8, 233u8, 154u8,
+                    134u8, 229u8, 136u8, 169u8, 229u8, 144u8, 143u8, 229u8, 177u8, 165u8, 230u8,
+                    152u8, 147u8, 230u8, 157u8, 142u8, 230u8, 162u8, 168u8, 230u8, 179u8, 165u8,
+                    231u8, 144u8, 134u8, 231u8, 151u8, 162u8, 231u8, 189u8, 185u8, 232u8, 163u8,
+                    143u8, 232u8, 163u8, 161u8, 233u8, 135u8, 140u8, 233u8, 155u8, 162u8, 229u8,
+                    140u8, 191u8, 230u8, 186u8, 186u8, 229u8, 144u8, 157u8, 231u8, 135u8, 144u8,
+                    231u8, 146u8, 152u8, 232u8, 151u8, 186u8, 233u8, 154u8, 163u8, 233u8, 177u8,
+                    151u8, 233u8, 186u8, 159u8, 230u8, 158u8, 151u8, 230u8, 183u8, 139u8, 232u8,
+                    135u8, 168u8, 231u8, 171u8, 139u8, 231u8, 172u8, 160u8, 231u8, 178u8, 146u8,
+                    231u8, 139u8, 128u8, 231u8, 130u8, 153u8, 232u8, 173u8, 152u8, 228u8, 187u8,
+                    128u8, 232u8, 140u8, 182u8, 229u8, 136u8, 186u8, 229u8, 136u8, 135u8, 229u8,
+                    186u8, 166u8, 230u8, 139u8, 147u8, 231u8, 179u8, 150u8, 229u8, 174u8, 133u8,
+                    230u8, 180u8, 158u8, 230u8, 154u8, 180u8, 232u8, 188u8, 187u8, 232u8, 161u8,
+                    140u8, 233u8, 153u8, 141u8, 232u8, 166u8, 139u8, 229u8, 187u8, 147u8, 229u8,
+                    133u8, 128u8, 229u8, 151u8, 128u8, 229u8, 161u8, 154u8, 230u8, 153u8, 180u8,
+                    229u8, 135u8, 158u8, 231u8, 140u8, 170u8, 231u8, 155u8, 138u8, 231u8, 164u8,
+                    188u8, 231u8, 165u8, 158u8, 231u8, 165u8, 165u8, 231u8, 166u8, 143u8, 233u8,
+                    157u8, 150u8, 231u8, 178u8, 190u8, 231u8, 190u8, 189u8, 232u8, 152u8, 146u8,
+                    232u8, 171u8, 184u8, 233u8, 128u8, 184u8, 233u8, 131u8, 189u8, 233u8, 163u8,
+                    175u8, 233u8, 163u8, 188u8, 233u8, 164u8, 168u8, 233u8, 182u8, 180u8, 233u8,
+                    131u8, 142u8, 233u8, 154u8, 183u8, 228u8, 190u8, 174u8, 229u8, 131u8, 167u8,
+                    229u8, 133u8, 141u8, 229u8, 139u8, 137u8, 229u8, 139u8, 164u8, 229u8, 141u8,
+                    145u8, 229u8, 150u8, 157u8, 229u8, 152u8, 134u8, 229u8, 153u8, 168u8, 229u8,
+                    161u8, 128u8, 229u8, 162u8, 168u8, 229u8, 177u8, 164u8, 229u8, 177u8, 174u8,
+                    230u8, 130u8, 148u8, 230u8, 133u8, 168u8, 230u8, 134u8, 142u8, 230u8, 135u8,
+                    178u8, 230u8, 149u8, 143u8, 230u8, 151u8, 162u8, 230u8, 154u8, 145u8, 230u8,
+                    162u8, 133u8, 230u8, 181u8, 183u8, 230u8, 184u8, 154u8, 230u8, 188u8, 162u8,
+                    231u8, 133u8, 174u8, 231u8, 136u8, 171u8, 231u8, 144u8, 162u8, 231u8, 162u8,
+                    145u8, 231u8, 164u8, 190u8, 231u8, 165u8, 137u8, 231u8, 165u8, 136u8, 231u8,
+                    165u8, 144u8, 231u8, 165u8, 150u8, 231u8, 165u8, 157u8, 231u8, 166u8, 141u8,
+                    231u8, 166u8, 142u8, 231u8, 169u8, 128u8, 231u8, 170u8, 129u8, 231u8, 175u8,
+                    128u8, 231u8, 183u8, 180u8, 231u8, 184u8, 137u8, 231u8, 185u8, 129u8, 231u8,
+                    189u8, 178u8, 232u8, 128u8, 133u8, 232u8, 135u8, 173u8, 232u8, 137u8, 185u8,
+                    232u8, 137u8, 185u8, 232u8, 145u8, 151u8, 232u8, 164u8, 144u8, 232u8, 166u8,
+                    150u8, 232u8, 172u8, 129u8, 232u8, 172u8, 185u8, 232u8, 179u8, 147u8, 232u8,
+                    180u8, 136u8, 232u8, 190u8, 182u8, 233u8, 128u8, 184u8, 233u8, 155u8, 163u8,
+                    233u8, 159u8, 191u8, 233u8, 160u8, 187u8, 230u8, 129u8, 181u8, 240u8, 164u8,
+                    139u8, 174u8, 232u8, 136u8, 152u8, 228u8, 184u8, 166u8, 229u8, 134u8, 181u8,
+                    229u8, 133u8, 168u8, 228u8, 190u8, 128u8, 229u8, 133u8, 133u8, 229u8, 134u8,
+                    128u8, 229u8, 139u8, 135u8, 229u8, 139u8, 186u8, 229u8, 150u8, 157u8, 229u8,
+                    149u8, 149u8, 229u8, 150u8, 153u8, 229u8, 151u8, 162u8, 229u8, 161u8, 154u8,
+                    229u8, 162u8, 179u8, 229u8, 165u8, 132u8, 229u8, 165u8, 148u8, 229u8, 169u8,
+                    162u8, 229u8, 172u8, 168u8, 229u8, 187u8, 146u8, 229u8, 187u8, 153u8, 229u8,
+                    189u8, 169u8, 229u8, 190u8, 173u8, 230u8, 131u8, 152u8, 230u8, 133u8, 142u8,
+                    230u8, 132u8, 136u8, 230u8, 134u8, 142u8, 230u8, 133u8, 160u8, 230u8, 135u8,
+                    178u8, 230u8, 136u8, 180u8, 230u8, 143u8, 132u8, 230u8, 144u8, 156u8, 230u8,
+                    145u8, 146u8, 230u8, 149u8, 150u8, 230u8, 153u8, 180u8, 230u8, 156u8, 151u8,
+                    230u8, 156u8, 155u8, 230u8, 157u8, 150u8, 230u8, 173u8, 185u8, 230u8, 174u8,
+                    186u8, 230u8, 181u8, 129u8, 230u8, 187u8, 155u8, 230u8, 187u8, 139u8, 230u8,
+                    188u8, 162u8, 231u8, 128u8, 158u8, 231u8, 133u8, 174u8, 231u8, 158u8, 167u8,
+                    231u8, 136u8, 181u8, 231u8, 138u8, 175u8, 231u8, 140u8, 170u8, 231u8, 145u8,
+                    177u8, 231u8, 148u8, 134u8, 231u8, 148u8, 187u8, 231u8, 152u8, 157u8, 231u8,
+                    152u8, 159u8, 231u8, 155u8, 138u8, 231u8, 155u8, 155u8, 231u8, 155u8, 180u8,
+                    231u8, 157u8, 138u8, 231u8, 157u8, 128u8, 231u8, 163u8, 140u8, 231u8, 170u8,
+                    177u8, 231u8, 175u8, 128u8, 231u8, 177u8, 187u8, 231u8, 181u8, 155u8, 231u8,
+                    183u8, 180u8, 231u8, 188u8, 190u8, 232u8, 128u8, 133u8, 232u8, 141u8, 146u8,
+                    232u8, 143u8, 175u8, 232u8, 157u8, 185u8, 232u8, 165u8, 129u8, 232u8, 166u8,
+                    134u8, 232u8, 166u8, 150u8, 232u8, 170u8, 191u8, 232u8, 171u8, 184u8, 232u8,
+                    171u8, 139u8, 232u8, 172u8, 129u8, 232u8, 171u8, 190u8, 232u8, 171u8, 173u8,
+                    232u8, 172u8, 185u8, 232u8, 174u8, 138u8, 232u8, 180u8, 136u8, 232u8, 188u8,
+                    184u8, 233u8, 129u8, 178u8, 233u8, 134u8, 153u8, 233u8, 137u8, 182u8, 233u8,
+                    153u8, 188u8, 233u8, 155u8, 163u8, 233u8, 157u8, 150u8, 233u8, 159u8, 155u8,
+                    233u8, 159u8, 191u8, 233u8, 160u8, 139u8, 233u8, 160u8, 187u8, 233u8, 172u8,
+                    146u8, 233u8, 190u8, 156u8, 240u8, 162u8, 161u8, 138u8, 240u8, 162u8, 161u8,
+                    132u8, 240u8, 163u8, 143u8, 149u8, 227u8, 174u8, 157u8, 228u8, 128u8, 152u8,
+                    228u8, 128u8, 185u8, 240u8, 165u8, 137u8, 137u8, 240u8, 165u8, 179u8, 144u8,
+                    240u8, 167u8, 187u8, 147u8, 233u8, 189u8, 131u8, 233u8, 190u8, 142u8, 102u8,
+                    102u8, 102u8, 105u8, 102u8, 108u8, 102u8, 102u8, 105u8, 102u8, 102u8, 108u8,
+                    115u8, 116u8, 213u8, 180u8, 213u8, 182u8, 213u8, 180u8, 213u8, 165u8, 213u8,
+                    180u8, 213u8, 171u8, 213u8, 190u8, 213u8, 182u8, 213u8, 180u8, 213u8, 173u8,
+                    215u8, 162u8, 215u8, 144u8, 215u8, 147u8, 215u8, 148u8, 215u8, 155u8, 215u8,
+                    156u8, 215u8, 157u8, 215u8, 168u8, 215u8, 170u8, 45u8, 204u8, 135u8, 239u8,
+                    172u8, 170u8, 239u8, 172u8, 172u8, 239u8, 172u8, 174u8, 239u8, 172u8, 174u8,
+                    239u8, 172u8, 157u8, 239u8, 172u8, 170u8, 215u8, 144u8, 215u8, 156u8, 217u8,
+                    177u8, 217u8, 177u8, 217u8, 187u8, 217u8, 187u8, 217u8, 187u8, 217u8, 187u8,
+                    217u8, 137u8, 219u8, 155u8, 217u8, 137u8, 219u8, 155u8, 217u8, 137u8, 219u8,
+                    155u8, 217u8, 137u8, 219u8, 155u8, 218u8, 128u8, 218u8, 128u8, 218u8, 128u8,
+                    218u8, 128u8, 217u8, 186u8, 217u8, 186u8, 217u8, 186u8, 217u8, 186u8, 217u8,
+                    191u8, 217u8, 191u8, 217u8, 191u8, 217u8, 191u8, 217u8, 137u8, 216u8, 149u8,
+                    217u8, 137u8, 216u8, 149u8, 217u8, 137u8, 216u8, 149u8, 217u8, 137u8, 216u8,
+                    149u8, 218u8, 161u8, 219u8, 155u8, 218u8, 161u8, 219u8, 155u8, 218u8, 161u8,
+                    219u8, 155u8, 218u8, 161u8, 219u8, 155u8, 218u8, 166u8, 218u8, 166u8, 218u8,
+                    166u8, 218u8, 166u8, 218u8, 132u8, 218u8, 132u8, 218u8, 132u8, 218u8, 132u8,
+                    218u8, 131u8, 218u8, 131u8, 218u8, 131u8, 218u8, 131u8, 218u8, 134u8, 218u8,
+                    134u8, 218u8, 134u8, 218u8, 134u8, 218u8, 135u8, 218u8, 135u8, 218u8, 135u8,
+                    218u8, 135u8, 218u8, 141u8, 218u8, 141u8, 218u8, 140u8, 218u8, 140u8, 216u8,
+                    175u8, 219u8, 155u8, 216u8, 175u8, 219u8, 155u8, 216u8, 175u8, 216u8, 149u8,
+                    216u8, 175u8, 216u8, 149u8, 216u8, 177u8, 219u8, 155u8, 216u8, 177u8, 219u8,
+                    155u8, 216u8, 177u8, 216u8, 149u8, 216u8, 177u8, 216u8, 149u8, 217u8, 131u8,
+                    217u8, 131u8, 217u8, 131u8, 217u8, 131u8, 218u8, 175u8, 218u8, 175u8, 218u8,
+                    175u8, 218u8, 175u8, 218u8, 179u8, 218u8, 179u8, 218u8, 179u8, 218u8, 179u8,
+                    218u8, 177u8, 218u8, 177u8, 218u8, 177u8, 218u8, 177u8, 217u8, 137u8, 217u8,
+                    137u8, 217u8, 137u8, 216u8, 149u8, 217u8, 137u8, 216u8, 149u8, 217u8, 137u8,
+                    216u8, 149u8, 217u8, 137u8, 216u8, 149u8, 219u8, 128u8, 219u8, 128u8, 111u8,
+                    111u8, 111u8, 111u8, 111u8, 111u8, 111u8, 111u8, 217u8, 137u8, 217u8, 137u8,
+                    219u8, 147u8, 219u8, 147u8, 217u8, 131u8, 219u8, 155u8, 217u8, 131u8, 219u8,
+                    155u8, 217u8, 131u8, 219u8, 155u8, 217u8, 131u8, 219u8, 155u8, 217u8, 136u8,
+                    204u8, 147u8, 217u8, 136u8, 204u8, 147u8, 217u8, 136u8, 204u8, 134u8, 217u8,
+                    136u8, 204u8, 134u8, 217u8, 136u8, 217u8, 176u8, 217u8, 136u8, 217u8, 176u8,
+                    217u8, 136u8, 204u8, 147u8, 217u8, 180u8, 217u8, 136u8, 219u8, 155u8, 217u8,
+                    136u8, 219u8, 155u8, 219u8, 133u8, 219u8, 133u8, 217u8, 136u8, 204u8, 130u8,
+                    217u8, 136u8, 204u8, 130u8, 217u8, 187u8, 217u8, 187u8, 217u8, 187u8, 217u8,
+                    187u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 180u8, 108u8, 217u8,
+                    137u8, 217u8, 180u8, 108u8, 217u8, 137u8, 217u8, 180u8, 111u8, 217u8, 137u8,
+                    217u8, 180u8, 111u8, 217u8, 137u8, 217u8, 180u8, 217u8, 136u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 136u8, 217u8, 137u8, 217u8, 180u8, 217u8, 136u8, 204u8,
+                    147u8, 217u8, 137u8, 217u8, 180u8, 217u8, 136u8, 204u8, 147u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 136u8, 204u8, 134u8, 217u8, 137u8, 217u8, 180u8, 217u8,
+                    136u8, 204u8, 134u8, 217u8, 137u8, 217u8, 180u8, 217u8, 136u8, 217u8, 176u8,
+                    217u8, 137u8, 217u8, 180u8, 217u8, 136u8, 217u8, 176u8, 217u8, 137u8, 217u8,
+                    180u8, 217u8, 187u8, 217u8, 137u8, 217u8, 180u8, 217u8, 187u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 187u8, 217u8, 137u8, 217u8, 180u8, 217u8, 137u8, 217u8,
+                    137u8, 217u8, 180u8, 217u8, 137u8, 217u8, 137u8, 217u8, 180u8, 217u8, 137u8,
+                    217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8,
+                    180u8, 216u8, 172u8, 217u8, 137u8, 217u8, 180u8, 216u8, 173u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 133u8, 217u8, 137u8, 217u8, 180u8, 217u8, 137u8, 217u8,
+                    137u8, 217u8, 180u8, 217u8, 137u8, 216u8, 168u8, 216u8, 172u8, 216u8, 168u8,
+                    216u8, 173u8, 216u8, 168u8, 216u8, 174u8, 216u8, 168u8, 217u8, 133u8, 216u8,
+                    168u8, 217u8, 137u8, 216u8, 168u8, 217u8, 137u8, 216u8, 170u8, 216u8, 172u8,
+                    216u8, 170u8, 216u8, 173u8, 216u8, 170u8, 216u8, 174u8, 216u8, 170u8, 217u8,
+                    133u8, 216u8, 170u8, 217u8, 137u8, 216u8, 170u8, 217u8, 137u8, 217u8, 137u8,
+                    219u8, 155u8, 216u8, 172u8, 217u8, 137u8, 219u8, 155u8, 217u8, 133u8, 217u8,
+                    137u8, 219u8, 155u8, 217u8, 137u8, 217u8, 137u8, 219u8, 155u8, 217u8, 137u8,
+                    216u8, 172u8, 216u8, 173u8, 216u8, 172u8, 217u8, 133u8, 216u8, 173u8, 216u8,
+                    172u8, 216u8, 173u8, 217u8, 133u8, 216u8, 174u8, 216u8, 172u8, 216u8, 174u8,
+                    216u8, 173u8, 216u8, 174u8, 217u8, 133u8, 216u8, 179u8, 216u8, 172u8, 216u8,
+                    179u8, 216u8, 173u8, 216u8, 179u8, 216u8, 174u8, 216u8, 179u8, 217u8, 133u8,
+                    216u8, 181u8, 216u8, 173u8, 216u8, 181u8, 217u8, 133u8, 216u8, 182u8, 216u8,
+                    172u8, 216u8, 182u8, 216u8, 173u8, 216u8, 182u8, 216u8, 174u8, 216u8, 182u8,
+                    217u8, 133u8, 216u8, 183u8, 216u8, 173u8, 216u8, 183u8, 217u8, 133u8, 216u8,
+                    184u8, 217u8, 133u8, 216u8, 185u8, 216u8, 172u8, 216u8, 185u8, 217u8, 133u8,
+                    216u8, 186u8, 216u8, 172u8, 216u8, 186u8, 217u8, 133u8, 217u8, 129u8, 216u8,
+                    172u8, 217u8, 129u8, 216u8, 173u8, 217u8, 129u8, 216u8, 174u8, 217u8, 129u8,
+                    217u8, 133u8, 217u8, 129u8, 217u8, 137u8, 217u8, 129u8, 217u8, 137u8, 217u8,
+                    130u8, 216u8, 173u8, 217u8, 130u8, 217u8, 133u8, 217u8, 130u8, 217u8, 137u8,
+                    217u8, 130u8, 217u8, 137u8, 217u8, 131u8, 108u8, 217u8, 131u8, 216u8, 172u8,
+                    217u8, 131u8, 216u8, 173u8, 217u8, 131u8, 216u8, 174u8, 217u8, 131u8, 217u8,
+                    132u8, 217u8, 131u8, 217u8, 133u8, 217u8, 131u8, 217u8, 137u8, 217u8, 131u8,
+                    217u8, 137u8, 217u8, 132u8, 216u8, 172u8, 217u8, 132u8, 216u8, 173u8, 217u8,
+                    132u8, 216u8, 174u8, 217u8, 132u8, 217u8, 133u8, 217u8, 132u8, 217u8, 137u8,
+                    217u8, 132u8, 217u8, 137u8, 217u8, 133u8, 216u8, 172u8, 217u8, 133u8, 216u8,
+                    173u8, 217u8, 133u8, 216u8, 174u8, 217u8, 133u8, 217u8, 133u8, 217u8, 133u8,
+                    217u8, 137u8, 217u8, 133u8, 217u8, 137u8, 216u8, 168u8, 216u8, 174u8, 217u8,
+                    134u8, 216u8, 173u8, 217u8, 134u8, 216u8, 174u8, 217u8, 134u8, 217u8, 133u8,
+                    217u8, 134u8, 217u8, 137u8, 217u8, 134u8, 217u8, 137u8, 111u8, 216u8, 172u8,
+                    111u8, 217u8, 133u8, 111u8, 217u8, 137u8, 111u8, 217u8, 137u8, 217u8, 137u8,
+                    216u8, 172u8, 217u8, 137u8, 216u8, 173u8, 217u8, 137u8, 216u8, 174u8, 217u8,
+                    137u8, 217u8, 133u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8,
+                    216u8, 176u8, 217u8, 176u8, 216u8, 177u8, 217u8, 176u8, 217u8, 137u8, 217u8,
+                    176u8, 239u8, 185u8, 178u8, 217u8, 145u8, 239u8, 185u8, 180u8, 217u8, 145u8,
+                    239u8, 185u8, 182u8, 217u8, 145u8, 239u8, 185u8, 184u8, 217u8, 145u8, 239u8,
+                    185u8, 186u8, 217u8, 145u8, 239u8, 185u8, 188u8, 217u8, 176u8, 217u8, 137u8,
+                    217u8, 180u8, 216u8, 177u8, 217u8, 137u8, 217u8, 180u8, 216u8, 178u8, 217u8,
+                    137u8, 217u8, 180u8, 217u8, 133u8, 217u8, 137u8, 217u8, 180u8, 217u8, 134u8,
+                    217u8, 137u8, 217u8, 180u8, 217u8, 137u8, 217u8, 137u8, 217u8, 180u8, 217u8,
+                    137u8, 216u8, 168u8, 216u8, 177u8, 216u8, 168u8, 216u8, 178u8, 216u8, 168u8,
+                    217u8, 133u8, 216u8, 168u8, 217u8, 134u8, 216u8, 168u8, 217u8, 137u8, 216u8,
+                    168u8, 217u8, 137u8, 216u8, 170u8, 216u8, 177u8, 216u8, 170u8, 216u8, 178u8,
+                    216u8, 170u8, 217u8, 133u8, 216u8, 170u8, 217u8, 134u8, 216u8, 170u8, 217u8,
+                    137u8, 216u8, 170u8, 217u8, 137u8, 217u8, 137u8, 219u8, 155u8, 216u8, 177u8,
+                    217u8, 137u8, 219u8, 155u8, 216u8, 178u8, 217u8, 137u8, 219u8, 155u8, 217u8,
+                    133u8, 217u8, 137u8, 219u8, 155u8, 217u8, 134u8, 217u8, 137u8, 219u8, 155u8,
+                    217u8, 137u8, 217u8, 137u8, 219u8, 155u8, 217u8, 137u8, 217u8, 129u8, 217u8,
+                    137u8, 217u8, 129u8, 217u8, 137u8, 217u8, 130u8, 217u8, 137u8, 217u8, 130u8,
+                    217u8, 137u8, 217u8, 131u8, 108u8, 217u8, 131u8, 217u8, 132u8, 217u8, 131u8,
+                    217u8, 133u8, 217u8, 131u8, 217u8, 137u8, 217u8, 131u8, 217u8, 137u8, 217u8,
+                    132u8, 217u8, 133u8, 217u8, 132u8, 217u8, 137u8, 217u8, 132u8, 217u8, 137u8,
+                    217u8, 133u8, 108u8, 217u8, 133u8, 217u8, 133u8, 217u8, 134u8, 216u8, 177u8,
+                    217u8, 134u8, 216u8, 178u8, 217u8, 134u8, 217u8, 133u8, 217u8, 134u8, 217u8,
+                    134u8, 217u8, 134u8, 217u8, 137u8, 217u8, 134u8, 217u8, 137u8, 217u8, 137u8,
+                    217u8, 176u8, 217u8, 137u8, 216u8, 177u8, 217u8, 137u8, 216u8, 178u8, 217u8,
+                    137u8, 217u8, 133u8, 217u8, 137u8, 217u8, 134u8, 217u8, 137u8, 217u8, 137u8,
+                    217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 180u8, 216u8, 172u8, 217u8,
+                    137u8, 217u8, 180u8, 216u8, 173u8, 217u8, 137u8, 217u8, 180u8, 216u8, 174u8,
+                    217u8, 137u8, 217u8, 180u8, 217u8, 133u8, 217u8, 137u8, 217u8, 180u8, 111u8,
+                    216u8, 168u8, 216u8, 172u8, 216u8, 168u8, 216u8, 173u8, 216u8, 168u8, 216u8,
+                    174u8, 216u8, 168u8, 217u8, 133u8, 216u8, 168u8, 111u8, 216u8, 170u8, 216u8,
+                    172u8, 216u8, 170u8, 216u8, 173u8, 216u8, 170u8, 216u8, 174u8, 216u8, 170u8,
+                    217u8, 133u8, 216u8, 170u8, 111u8, 217u8, 137u8, 219u8, 155u8, 217u8, 133u8,
+                    216u8, 172u8, 216u8, 173u8, 216u8, 172u8, 217u8, 133u8, 216u8, 173u8, 216u8,
+                    172u8, 216u8, 173u8, 217u8, 133u8, 216u8, 174u8, 216u8, 172u8, 216u8, 174u8,
+                    217u8, 133u8, 216u8, 179u8, 216u8, 172u8, 216u8, 179u8, 216u8, 173u8, 216u8,
+                    179u8, 216u8, 174u8, 216u8, 179u8, 217u8, 133u8, 216u8, 181u8, 216u8, 173u8,
+                    216u8, 181u8, 216u8, 174u8, 216u8, 181u8, 217u8, 133u8, 216u8, 182u8, 216u8,
+                    172u8, 216u8, 182u8, 216u8, 173u8, 216u8, 182u8, 216u8, 174u8, 216u8, 182u8,
+                    217u8, 133u8, 216u8, 183u8, 216u8, 173u8, 216u8, 184u8, 217u8, 133u8, 216u8,
+                    185u8, 216u8, 172u8, 216u8, 185u8, 217u8, 133u8, 216u8, 186u8, 216u8, 172u8,
+                    216u8, 186u8, 217u8, 133u8, 217u8, 129u8, 216u8, 172u8, 217u8, 129u8, 216u8,
+                    173u8, 217u8, 129u8, 216u8, 174u8, 217u8, 129u8, 217u8, 133u8, 217u8, 130u8,
+                    216u8, 173u8, 217u8, 130u8, 217u8, 133u8, 217u8, 131u8, 216u8, 172u8, 217u8,
+                    131u8, 216u8, 173u8, 217u8, 131u8, 216u8, 174u8, 217u8, 131u8, 217u8, 132u8,
+                    217u8, 131u8, 217u8, 133u8, 217u8, 132u8, 216u8, 172u8, 217u8, 132u8, 216u8,
+                    173u8, 217u8, 132u8, 216u8, 174u8, 217u8, 132u8, 217u8, 133u8, 217u8, 132u8,
+                    111u8, 217u8, 133u8, 216u8, 172u8, 217u8, 133u8, 216u8, 173u8, 217u8, 133u8,
+                    216u8, 174u8, 217u8, 133u8, 217u8, 133u8, 216u8, 168u8, 216u8, 174u8, 217u8,
+                    134u8, 216u8, 173u8, 217u8, 134u8, 216u8, 174u8, 217u8, 134u8, 217u8, 133u8,
+                    217u8, 134u8, 111u8, 111u8, 216u8, 172u8, 111u8, 217u8, 133u8, 111u8, 217u8,
+                    176u8, 217u8, 137u8, 216u8, 172u8, 217u8, 137u8, 216u8, 173u8, 217u8, 137u8,
+                    216u8, 174u8, 217u8, 137u8, 217u8, 133u8, 217u8, 137u8, 111u8, 217u8, 137u8,
+                    217u8, 180u8, 217u8, 133u8, 217u8, 137u8, 217u8, 180u8, 111u8, 216u8, 168u8,
+                    217u8, 133u8, 216u8, 168u8, 111u8, 216u8, 170u8, 217u8, 133u8, 216u8, 170u8,
+                    111u8, 217u8, 137u8, 219u8, 155u8, 217u8, 133u8, 217u8, 137u8, 219u8, 155u8,
+                    111u8, 216u8, 179u8, 217u8, 133u8, 216u8, 179u8, 111u8, 216u8, 179u8, 219u8,
+                    155u8, 217u8, 133u8, 216u8, 179u8, 219u8, 155u8, 111u8, 217u8, 131u8, 217u8,
+                    132u8, 217u8, 131u8, 217u8, 133u8, 217u8, 132u8, 217u8, 133u8, 217u8, 134u8,
+                    217u8, 133u8, 217u8, 134u8, 111u8, 217u8, 137u8, 217u8, 133u8, 217u8, 137u8,
+                    111u8, 239u8, 185u8, 183u8, 217u8, 145u8, 239u8, 185u8, 185u8, 217u8, 145u8,
+                    239u8, 185u8, 187u8, 217u8, 145u8, 216u8, 183u8, 217u8, 137u8, 216u8, 183u8,
+                    217u8, 137u8, 216u8, 185u8, 217u8, 137u8, 216u8, 185u8, 217u8, 137u8, 216u8,
+                    186u8, 217u8, 137u8, 216u8, 186u8, 217u8, 137u8, 216u8, 179u8, 217u8, 137u8,
+                    216u8, 179u8, 217u8, 137u8, 216u8, 179u8, 219u8, 155u8, 217u8, 137u8, 216u8,
+                    179u8, 219u8, 155u8, 217u8, 137u8, 216u8, 173u8, 217u8, 137u8, 216u8, 173u8,
+                    217u8, 137u8, 216u8, 172u8, 217u8, 137u8, 216u8, 172u8, 217u8, 137u8, 216u8,
+                    174u8, 217u8, 137u8, 216u8, 174u8, 217u8, 137u8, 216u8, 181u8, 217u8, 137u8,
+                    216u8, 181u8, 217u8, 137u8, 216u8, 182u8, 217u8, 137u8, 216u8, 182u8, 217u8,
+                    137u8, 216u8, 179u8, 219u8, 155u8, 216u8, 172u8, 216u8, 179u8, 219u8, 155u8,
+                    216u8, 173u8, 216u8, 179u8, 219u8, 155u8, 216u8, 174u8, 216u8, 179u8, 219u8,
+                    155u8, 217u8, 133u8, 216u8, 179u8, 219u8, 155u8, 216u8, 177u8, 216u8, 179u8,
+                    216u8, 177u8, 216u8, 181u8, 216u8, 177u8, 216u8, 182u8, 216u8, 177u8, 216u8,
+                    183u8, 217u8, 137u8, 216u8, 183u8, 217u8, 137u8, 216u8, 185u8, 217u8, 137u8,
+                    216u8, 185u8, 217u8, 137u8, 216u8, 186u8, 217u8, 137u8, 216u8, 186u8, 217u8,
+                    137u8, 216u8, 179u8, 217u8, 137u8, 216u8, 179u8, 217u8, 137u8, 216u8, 179u8,
+                    219u8, 155u8, 217u8, 137u8, 216u8, 179u8, 219u8, 155u8, 217u8, 137u8, 216u8,
+                    173u8, 217u8, 137u8, 216u8, 173u8, 217u8, 137u8, 216u8, 172u8, 217u8, 137u8,
+                    216u8, 172u8, 217u8, 137u8, 216u8, 174u8, 217u8, 137u8, 216u8, 174u8, 217u8,
+                    137u8, 216u8, 181u8, 217u8, 137u8, 216u8, 181u8, 217u8, 137u8, 216u8, 182u8,
+                    217u8, 137u8, 216u8, 182u8, 217u8, 137u8, 216u8, 179u8, 219u8, 155u8, 216u8,
+                    172u8, 216u8, 179u8, 219u8, 155u8, 216u8, 173u8, 216u8, 179u8, 219u8, 155u8,
+                    216u8, 174u8, 216u8, 179u8, 219u8, 155u8, 217u8, 133u8, 216u8, 179u8, 219u8,
+                    155u8, 216u8, 177u8, 216u8, 179u8, 216u8, 177u8, 216u8, 181u8, 216u8, 177u8,
+                    216u8, 182u8, 216u8, 177u8, 216u8, 179u8, 219u8, 155u8, 216u8, 172u8, 216u8,
+                    179u8, 219u8, 155u8, 216u8, 173u8, 216u8, 179u8, 219u8, 155u8, 216u8, 174u8,
+                    216u8, 179u8, 219u8, 155u8, 217u8, 133u8, 216u8, 179u8, 111u8, 216u8, 179u8,
+                    219u8, 155u8, 111u8, 216u8, 183u8, 217u8, 133u8, 216u8, 179u8, 216u8, 172u8,
+                    216u8, 179u8, 216u8, 173u8, 216u8, 179u8, 216u8, 174u8, 216u8, 179u8, 219u8,
+                    155u8, 216u8, 172u8, 216u8, 179u8, 219u8, 155u8, 216u8, 173u8, 216u8, 179u8,
+                    219u8, 155u8, 216u8, 174u8, 216u8, 183u8, 217u8, 133u8, 216u8, 184u8, 217u8,
+                    133u8, 108u8, 204u8, 139u8, 108u8, 204u8, 139u8, 40u8, 41u8, 216u8, 170u8,
+                    216u8, 172u8, 217u8, 133u8, 216u8, 170u8, 216u8, 173u8, 216u8, 172u8, 216u8,
+                    170u8, 216u8, 173u8, 216u8, 172u8, 216u8, 170u8, 216u8, 173u8, 217u8, 133u8,
+                    216u8, 170u8, 216u8, 174u8, 217u8, 133u8, 216u8, 170u8, 217u8, 133u8, 216u8,
+                    172u8, 216u8, 170u8, 217u8, 133u8, 216u8, 173u8, 216u8, 170u8, 217u8, 133u8,
+                    216u8, 174u8, 216u8, 172u8, 217u8, 133u8, 216u8, 173u8, 216u8, 172u8, 217u8,
+                    133u8, 216u8, 173u8, 216u8, 173u8, 217u8, 133u8, 217u8, 137u8, 216u8, 173u8,
+                    217u8, 133u8, 217u8, 137u8, 216u8, 179u8, 216u8, 173u8, 216u8, 172u8, 216u8,
+                    179u8, 216u8, 172u8, 216u8, 173u8, 216u8, 179u8, 216u8, 172u8, 217u8, 137u8,
+                    216u8, 179u8, 217u8, 133u8, 216u8, 173u8, 216u8, 179u8, 217u8, 133u8, 216u8,
+                    173u8, 216u8, 179u8, 217u8, 133u8, 216u8, 172u8, 216u8, 179u8, 217u8, 133u8,
+                    217u8, 133u8, 216u8, 179u8, 217u8, 133u8, 217u8, 133u8, 216u8, 181u8, 216u8,
+                    173u8, 216u8, 173u8, 216u8, 181u8, 216u8, 173u8, 216u8, 173u8, 216u8, 181u8,
+                    217u8, 133u8, 217u8, 133u8, 216u8, 179u8, 219u8, 155u8, 216u8, 173u8, 217u8,
+                    133u8, 216u8, 179u8, 219u8, 155u8, 216u8, 173u8, 217u8, 133u8, 216u8, 179u8,
+                    219u8, 155u8, 216u8, 172u8, 217u8, 137u8, 216u8, 179u8, 219u8, 155u8, 217u8,
+                    133u8, 216u8, 174u8, 216u8, 179u8, 219u8, 155u8, 217u8, 133u8, 216u8, 174u8,
+                    216u8, 179u8, 219u8, 155u8, 217u8, 133u8, 217u8, 133u8, 216u8, 179u8, 219u8,
+                    155u8, 217u8, 133u8, 217u8, 133u8, 216u8, 182u8, 216u8, 173u8, 217u8, 137u8,
+                    216u8, 182u8, 216u8, 174u8, 217u8, 133u8, 216u8, 182u8, 216u8, 174u8, 217u8,
+                    133u8, 216u8, 183u8, 217u8, 133u8, 216u8, 173u8, 216u8, 183u8, 217u8, 133u8,
+                    216u8, 173u8, 216u8, 183u8, 217u8, 133u8, 217u8, 133u8, 216u8, 183u8, 217u8,
+                    133u8, 217u8, 137u8, 216u8, 185u8, 216u8, 172u8, 217u8, 133u8, 216u8, 185u8,
+                    217u8, 133u8, 217u8, 133u8, 216u8, 185u8, 217u8, 133u8, 217u8, 133u8, 216u8,
+                    185u8, 217u8, 133u8, 217u8, 137u8, 216u8, 186u8, 217u8, 133u8, 217u8, 133u8,
+                    216u8, 186u8, 217u8, 133u8, 217u8, 137u8, 216u8, 186u8, 217u8, 133u8, 217u8,
+                    137u8, 217u8, 129u8, 216u8, 174u8, 217u8, 133u8, 217u8, 129u8, 216u8, 174u8,
+                    217u8, 133u8, 217u8, 130u8, 217u8, 133u8, 216u8, 173u8, 217u8, 130u8, 217u8,
+                    133u8, 217u8, 133u8, 217u8, 132u8, 216u8, 173u8, 217u8, 133u8, 217u8, 132u8,
+                    216u8, 173u8, 217u8, 137u8, 217u8, 132u8, 216u8, 173u8, 217u8, 137u8, 217u8,
+                    132u8, 216u8, 172u8, 216u8, 172u8, 217u8, 132u8, 216u8, 172u8, 216u8, 172u8,
+                    217u8, 132u8, 216u8, 174u8, 217u8, 133u8, 217u8, 132u8, 216u8, 174u8, 217u8,
+                    133u8, 217u8, 132u8, 217u8, 133u8, 216u8, 173u8, 217u8, 132u8, 217u8, 133u8,
+                    216u8, 173u8, 217u8, 133u8, 216u8, 173u8, 216u8, 172u8, 217u8, 133u8, 216u8,
+                    173u8, 217u8, 133u8, 217u8, 133u8, 216u8, 173u8, 217u8, 137u8, 217u8, 133u8,
+                    216u8, 172u8, 216u8, 173u8, 217u8, 133u8, 216u8, 172u8, 217u8, 133u8, 217u8,
+                    133u8, 216u8, 174u8, 216u8, 172u8, 217u8, 133u8, 216u8, 174u8, 217u8, 133u8,
+                    217u8, 133u8, 216u8, 172u8, 216u8, 174u8, 111u8, 217u8, 133u8, 216u8, 172u8,
+                    111u8, 217u8, 133u8, 217u8, 133u8, 217u8, 134u8, 216u8, 173u8, 217u8, 133u8,
+                    217u8, 134u8, 216u8, 173u8, 217u8, 137u8, 217u8, 134u8, 216u8, 172u8, 217u8,
+                    133u8, 217u8, 134u8, 216u8, 172u8, 217u8, 133u8, 217u8, 134u8, 216u8, 172u8,
+                    217u8, 137u8, 217u8, 134u8, 217u8, 133u8, 217u8, 137u8, 217u8, 134u8, 217u8,
+                    133u8, 217u8, 137u8, 217u8, 137u8, 217u8, 133u8, 217u8, 133u8, 217u8, 137u8,
+                    217u8, 133u8, 217u8, 133u8, 216u8, 168u8, 216u8, 174u8, 217u8, 137u8, 216u8,
+                    170u8, 216u8, 172u8, 217u8, 137u8, 216u8, 170u8, 216u8, 172u8, 217u8, 137u8,
+                    216u8, 170u8, 216u8, 174u8, 217u8, 137u8, 216u8, 170u8, 216u8, 174u8, 217u8,
+                    137u8, 216u8, 170u8, 217u8, 133u8, 217u8, 137u8, 216u8, 170u8, 217u8, 133u8,
+                    217u8, 137u8, 216u8, 172u8, 217u8, 133u8, 217u8, 137u8, 216u8, 172u8, 216u8,
+                    173u8, 217u8, 137u8, 216u8, 172u8, 217u8, 133u8, 217u8, 137u8, 216u8, 179u8,
+                    216u8, 174u8, 217u8, 137u8, 216u8, 181u8, 216u8, 173u8, 217u8, 137u8, 216u8,
+                    179u8, 219u8, 155u8, 216u8, 173u8, 217u8, 137u8, 216u8, 182u8, 216u8, 173u8,
+                    217u8, 137u8, 217u8, 132u8, 216u8, 172u8, 217u8, 137u8, 217u8, 132u8, 217u8,
+                    133u8, 217u8, 137u8, 217u8, 137u8, 216u8, 173u8, 217u8, 137u8, 217u8, 137u8,
+                    216u8, 172u8, 217u8, 137u8, 217u8, 137u8, 217u8, 133u8, 217u8, 137u8, 217u8,
+                    133u8, 217u8, 133u8, 217u8, 137u8, 217u8, 130u8, 217u8, 133u8, 217u8, 137u8,
+                    217u8, 134u8, 216u8, 173u8, 217u8, 137u8, 217u8, 130u8, 217u8, 133u8, 216u8,
+                    173u8, 217u8, 132u8, 216u8, 173u8, 217u8, 133u8, 216u8, 185u8, 217u8, 133u8,
+                    217u8, 137u8, 217u8, 131u8, 217u8, 133u8, 217u8, 137u8, 217u8, 134u8, 216u8,
+                    172u8, 216u8, 173u8, 217u8, 133u8, 216u8, 174u8, 217u8, 137u8, 217u8, 132u8,
+                    216u8, 172u8, 217u8, 133u8, 217u8, 131u8, 217u8, 133u8, 217u8, 133u8, 217u8,
+                    132u8, 216u8, 172u8, 217u8, 133u8, 217u8, 134u8, 216u8, 172u8, 216u8, 173u8,
+                    216u8, 172u8, 216u8, 173u8, 217u8, 137u8, 216u8, 173u8, 216u8, 172u8, 217u8,
+                    137u8, 217u8, 133u8, 216u8, 172u8, 217u8, 137u8, 217u8, 129u8, 217u8, 133u8,
+                    217u8, 137u8, 216u8, 168u8, 216u8, 173u8, 217u8, 137u8, 217u8, 131u8, 217u8,
+                    133u8, 217u8, 133u8, 216u8, 185u8, 216u8, 172u8, 217u8, 133u8, 216u8, 181u8,
+                    217u8, 133u8, 217u8, 133u8, 216u8, 179u8, 216u8, 174u8, 217u8, 137u8, 217u8,
+                    134u8, 216u8, 172u8, 217u8, 137u8, 216u8, 181u8, 217u8, 132u8, 217u8, 137u8,
+                    217u8, 130u8, 217u8, 132u8, 217u8, 137u8, 108u8, 217u8, 132u8, 217u8, 132u8,
+                    217u8, 145u8, 217u8, 176u8, 111u8, 108u8, 217u8, 131u8, 216u8, 168u8, 216u8,
+                    177u8, 217u8, 133u8, 216u8, 173u8, 217u8, 133u8, 216u8, 175u8, 216u8, 181u8,
+                    217u8, 132u8, 216u8, 185u8, 217u8, 133u8, 216u8, 177u8, 216u8, 179u8, 217u8,
+                    136u8, 217u8, 132u8, 216u8, 185u8, 217u8, 132u8, 217u8, 137u8, 111u8, 217u8,
+                    136u8, 216u8, 179u8, 217u8, 132u8, 217u8, 133u8, 216u8, 181u8, 217u8, 132u8,
+                    217u8, 137u8, 216u8, 181u8, 217u8, 132u8, 217u8, 137u8, 32u8, 108u8, 217u8,
+                    132u8, 217u8, 132u8, 111u8, 32u8, 216u8, 185u8, 217u8, 132u8, 217u8, 137u8,
+                    111u8, 32u8, 217u8, 136u8, 216u8, 179u8, 217u8, 132u8, 217u8, 133u8, 216u8,
+                    172u8, 217u8, 132u8, 32u8, 216u8, 172u8, 217u8, 132u8, 108u8, 217u8, 132u8,
+                    111u8, 216u8, 177u8, 217u8, 137u8, 108u8, 217u8, 132u8, 226u8, 181u8, 151u8,
+                    58u8, 226u8, 148u8, 130u8, 226u8, 140u8, 135u8, 226u8, 143u8, 156u8, 226u8,
+                    143u8, 157u8, 226u8, 143u8, 158u8, 226u8, 143u8, 159u8, 226u8, 143u8, 160u8,
+                    226u8, 143u8, 161u8, 203u8, 137u8, 203u8, 137u8, 203u8, 137u8, 203u8, 137u8,
+                    95u8, 95u8, 95u8, 45u8, 92u8, 216u8, 161u8, 216u8, 162u8, 216u8, 162u8, 108u8,
+                    217u8, 180u8, 108u8, 217u8, 180u8, 217u8, 136u8, 217u8, 180u8, 217u8, 136u8,
+                    217u8, 180u8, 108u8, 217u8, 149u8, 108u8, 217u8, 149u8, 217u8, 137u8, 217u8,
+                    180u8, 217u8, 137u8, 217u8, 180u8, 217u8, 137u8, 217u8, 180u8, 217u8, 137u8,
+                    217u8, 180u8, 108u8, 108u8, 216u8, 168u8, 216u8, 168u8, 216u8, 168u8, 216u8,
+                    168u8, 216u8, 169u8, 216u8, 169u8, 216u8, 170u8, 216u8, 170u8, 216u8, 170u8,
+                    216u8, 170u8, 217u8, 137u8, 219u8, 155u8, 217u8, 137u8, 219u8, 155u8, 217u8,
+                    137u8, 219u8, 155u8, 217u8, 137u8, 219u8, 155u8, 216u8, 172u8, 216u8, 172u8,
+                    216u8, 172u8, 216u8, 172u8, 216u8, 173u8, 216u8, 173u8, 216u8, 173u8, 216u8,
+                    173u8, 216u8, 174u8, 216u8, 174u8, 216u8, 174u8, 216u8, 174u8, 216u8, 175u8,
+                    216u8, 175u8, 216u8, 176u8, 216u8, 176u8, 216u8, 177u8, 216u8, 177u8, 216u8,
+                    178u8, 216u8, 178u8, 216u8, 179u8, 216u8, 179u8, 216u8, 179u8, 216u8, 179u8,
+                    216u8, 179u8, 219u8, 155u8, 216u8, 179u8, 219u8, 155u8, 216u8, 179u8, 219u8,
+                    155u8, 216u8, 179u8, 219u8, 155u8, 216u8, 181u8, 216u8, 181u8, 216u8, 181u8,
+                    216u8, 181u8, 216u8, 182u8, 216u8, 182u8, 216u8, 182u8, 216u8, 182u8, 216u8,
+                    183u8, 216u8, 183u8, 216u8, 183u8, 216u8, 183u8, 216u8, 184u8, 216u8, 184u8,
+                    216u8, 184u8, 216u8, 184u8, 216u8, 185u8, 216u8, 185u8, 216u8, 185u8, 216u8,
+                    185u8, 216u8, 186u8, 216u8, 186u8, 216u8, 186u8, 216u8, 186u8, 217u8, 129u8,
+                    217u8, 129u8, 217u8, 129u8, 217u8, 129u8, 217u8, 130u8, 217u8, 130u8, 217u8,
+                    130u8, 217u8, 130u8, 217u8, 131u8, 217u8, 131u8, 217u8, 131u8, 217u8, 131u8,
+                    217u8, 132u8, 217u8, 132u8, 217u8, 132u8, 217u8, 132u8, 217u8, 133u8, 217u8,
+                    133u8, 217u8, 133u8, 217u8, 133u8, 217u8, 134u8, 217u8, 134u8, 217u8, 134u8,
+                    217u8, 134u8, 111u8, 111u8, 111u8, 111u8, 217u8, 136u8, 217u8, 136u8, 217u8,
+                    137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8, 217u8, 137u8,
+                    217u8, 132u8, 216u8, 162u8, 217u8, 132u8, 216u8, 162u8, 217u8, 132u8, 108u8,
+                    217u8, 180u8, 217u8, 132u8, 108u8, 217u8, 180u8, 217u8, 132u8, 108u8, 217u8,
+                    149u8, 217u8, 132u8, 108u8, 217u8, 149u8, 217u8, 132u8, 108u8, 217u8, 132u8,
+                    108u8, 33u8, 39u8, 39u8, 39u8, 227u8, 131u8, 188u8, 58u8, 65u8, 66u8, 67u8,
+                    69u8, 72u8, 108u8, 74u8, 75u8, 77u8, 78u8, 79u8, 80u8, 83u8, 84u8, 88u8, 89u8,
+                    90u8, 40u8, 92u8, 41u8, 239u8, 184u8, 191u8, 39u8, 97u8, 99u8, 101u8, 103u8,
+                    104u8, 105u8, 106u8, 108u8, 111u8, 112u8, 115u8, 118u8, 120u8, 121u8, 226u8,
+                    148u8, 130u8, 227u8, 128u8, 156u8, 194u8, 183u8, 203u8, 137u8, 108u8, 226u8,
+                    150u8, 170u8, 194u8, 183u8, 78u8, 204u8, 138u8, 88u8, 204u8, 181u8, 86u8,
+                    204u8, 181u8, 108u8, 204u8, 181u8, 108u8, 204u8, 181u8, 83u8, 204u8, 181u8,
+                    108u8, 204u8, 181u8, 108u8, 204u8, 181u8, 226u8, 179u8, 168u8, 66u8, 206u8,
+                    148u8, 69u8, 70u8, 108u8, 201u8, 133u8, 88u8, 79u8, 225u8, 155u8, 156u8, 80u8,
+                    83u8, 84u8, 43u8, 65u8, 66u8, 67u8, 206u8, 148u8, 70u8, 79u8, 207u8, 152u8,
+                    77u8, 84u8, 89u8, 206u8, 166u8, 88u8, 206u8, 168u8, 206u8, 169u8, 226u8, 181u8,
+                    128u8, 72u8, 216u8, 175u8, 217u8, 136u8, 216u8, 183u8, 216u8, 181u8, 90u8,
+                    66u8, 67u8, 108u8, 77u8, 207u8, 152u8, 84u8, 88u8, 56u8, 42u8, 108u8, 88u8,
+                    240u8, 144u8, 142u8, 130u8, 240u8, 144u8, 142u8, 147u8, 198u8, 144u8, 79u8,
+                    234u8, 147u8, 182u8, 67u8, 76u8, 226u8, 177u8, 176u8, 83u8, 198u8, 134u8,
+                    208u8, 152u8, 234u8, 158u8, 147u8, 202u8, 154u8, 111u8, 99u8, 201u8, 183u8,
+                    201u8, 158u8, 202u8, 159u8, 115u8, 201u8, 148u8, 225u8, 180u8, 142u8, 240u8,
+                    144u8, 146u8, 134u8, 201u8, 133u8, 82u8, 211u8, 131u8, 79u8, 202u8, 152u8,
+                    195u8, 158u8, 208u8, 139u8, 85u8, 225u8, 155u8, 166u8, 206u8, 168u8, 55u8,
+                    202u8, 140u8, 206u8, 187u8, 111u8, 234u8, 153u8, 169u8, 117u8, 207u8, 136u8,
+                    78u8, 79u8, 75u8, 67u8, 86u8, 70u8, 76u8, 88u8, 204u8, 163u8, 46u8, 240u8,
+                    144u8, 169u8, 150u8, 240u8, 144u8, 169u8, 150u8, 240u8, 144u8, 178u8, 165u8,
+                    240u8, 144u8, 178u8, 130u8, 224u8, 165u8, 176u8, 224u8, 165u8, 176u8, 204u8,
+                    163u8, 224u8, 164u8, 186u8, 234u8, 163u8, 188u8, 234u8, 163u8, 187u8, 226u8,
+                    137u8, 136u8, 204u8, 138u8, 240u8, 145u8, 144u8, 180u8, 240u8, 145u8, 145u8,
+                    130u8, 240u8, 145u8, 144u8, 146u8, 240u8, 145u8, 144u8, 180u8, 240u8, 145u8,
+                    145u8, 130u8, 240u8, 145u8, 144u8, 152u8, 240u8, 145u8, 144u8, 180u8, 240u8,
+                    145u8, 145u8, 130u8, 240u8, 145u8, 144u8, 163u8, 240u8, 145u8, 144u8, 180u8,
+                    240u8, 145u8, 145u8, 130u8, 240u8, 145u8, 144u8, 169u8, 240u8, 145u8, 144u8,
+                    180u8, 240u8, 145u8, 145u8, 130u8, 240u8, 145u8, 144u8, 172u8, 240u8, 145u8,
+                    144u8, 180u8, 240u8, 145u8, 145u8, 130u8, 240u8, 145u8, 144u8, 174u8, 240u8,
+                    145u8, 145u8, 139u8, 240u8, 145u8, 145u8, 139u8, 224u8, 166u8, 152u8, 224u8,
+                    166u8, 154u8, 224u8, 166u8, 156u8, 224u8, 166u8, 158u8, 224u8, 166u8, 159u8,
+                    224u8, 166u8, 161u8, 224u8, 166u8, 178u8, 224u8, 166u8, 164u8, 224u8, 166u8,
+                    165u8, 224u8, 166u8, 166u8, 224u8, 166u8, 167u8, 224u8, 166u8, 168u8, 224u8,
+                    166u8, 170u8, 224u8, 166u8, 174u8, 224u8, 166u8, 175u8, 224u8, 166u8, 172u8,
+                    224u8, 166u8, 163u8, 224u8, 166u8, 176u8, 224u8, 166u8, 183u8, 224u8, 166u8,
+                    184u8, 224u8, 166u8, 190u8, 224u8, 166u8, 191u8, 224u8, 167u8, 135u8, 224u8,
+                    167u8, 139u8, 224u8, 167u8, 151u8, 224u8, 167u8, 140u8, 204u8, 134u8, 204u8,
+                    135u8, 224u8, 166u8, 131u8, 224u8, 167u8, 141u8, 204u8, 163u8, 224u8, 166u8,
+                    189u8, 119u8, 204u8, 135u8, 79u8, 224u8, 167u8, 167u8, 224u8, 167u8, 168u8,
+                    224u8, 167u8, 172u8, 240u8, 145u8, 150u8, 130u8, 240u8, 145u8, 150u8, 130u8,
+                    240u8, 145u8, 150u8, 131u8, 240u8, 145u8, 150u8, 132u8, 240u8, 145u8, 150u8,
+                    178u8, 240u8, 145u8, 150u8, 179u8, 240u8, 145u8, 153u8, 129u8, 240u8, 145u8,
+                    153u8, 129u8, 114u8, 110u8, 118u8, 119u8, 119u8, 119u8, 86u8, 70u8, 76u8, 89u8,
+                    69u8, 226u8, 136u8, 135u8, 90u8, 57u8, 69u8, 52u8, 76u8, 79u8, 225u8, 155u8,
+                    156u8, 85u8, 53u8, 84u8, 118u8, 115u8, 70u8, 105u8, 122u8, 55u8, 111u8, 51u8,
+                    57u8, 234u8, 158u8, 147u8, 54u8, 57u8, 111u8, 117u8, 121u8, 79u8, 114u8, 110u8,
+                    217u8, 169u8, 90u8, 87u8, 67u8, 88u8, 87u8, 67u8, 240u8, 145u8, 171u8, 165u8,
+                    240u8, 145u8, 171u8, 175u8, 240u8, 145u8, 171u8, 165u8, 240u8, 145u8, 171u8,
+                    176u8, 240u8, 145u8, 171u8, 165u8, 240u8, 145u8, 171u8, 165u8, 240u8, 145u8,
+                    171u8, 165u8, 240u8, 145u8, 171u8, 165u8, 240u8, 145u8, 171u8, 175u8, 240u8,
+                    145u8, 171u8, 165u8, 240u8, 145u8, 171u8, 165u8, 240u8, 145u8, 171u8, 176u8,
+                    240u8, 145u8, 171u8, 171u8, 240u8, 145u8, 171u8, 175u8, 240u8, 145u8, 171u8,
+                    171u8, 240u8, 145u8, 171u8, 171u8, 240u8, 145u8, 171u8, 171u8, 240u8, 145u8,
+                    171u8, 171u8, 240u8, 145u8, 171u8, 175u8, 240u8, 145u8, 171u8, 179u8, 240u8,
+                    145u8, 171u8, 175u8, 240u8, 145u8, 171u8, 179u8, 240u8, 145u8, 171u8, 176u8,
+                    240u8, 145u8, 171u8, 179u8, 240u8, 145u8, 171u8, 179u8, 240u8, 145u8, 171u8,
+                    179u8, 240u8, 145u8, 171u8, 179u8, 240u8, 145u8, 171u8, 175u8, 240u8, 145u8,
+                    171u8, 179u8, 240u8, 145u8, 171u8, 179u8, 240u8, 145u8, 171u8, 176u8, 240u8,
+                    145u8, 177u8, 129u8, 240u8, 145u8, 177u8, 129u8, 240u8, 145u8, 178u8, 170u8,
+                    240u8, 144u8, 142u8, 154u8, 240u8, 144u8, 166u8, 158u8, 206u8, 147u8, 86u8,
+                    84u8, 76u8, 206u8, 148u8, 234u8, 153u8, 152u8, 234u8, 147u8, 182u8, 108u8,
+                    198u8, 144u8, 82u8, 83u8, 51u8, 201u8, 133u8, 62u8, 65u8, 85u8, 89u8, 39u8,
+                    39u8, 123u8, 46u8, 211u8, 190u8, 51u8, 208u8, 152u8, 86u8, 92u8, 55u8, 70u8,
+                    240u8, 144u8, 138u8, 188u8, 234u8, 147u8, 182u8, 82u8, 226u8, 177u8, 175u8,
+                    79u8, 204u8, 181u8, 226u8, 133u8, 132u8, 234u8, 147u8, 149u8, 198u8, 144u8,
+                    209u8, 160u8, 76u8, 234u8, 147u8, 182u8, 234u8, 159u8, 187u8, 60u8, 62u8,
+                    226u8, 138u8, 143u8, 226u8, 138u8, 144u8, 47u8, 92u8, 225u8, 155u8, 139u8,
+                    213u8, 136u8, 65u8, 66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8, 74u8,
+                    75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8, 87u8,
+                    88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8,
+                    106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8,
+                    116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 67u8, 68u8, 69u8,
+                    70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 82u8,
+                    83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8, 101u8,
+                    102u8, 103u8, 105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 111u8, 112u8,
+                    113u8, 114u8, 115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 65u8,
+                    66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8,
+                    79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8,
+                    98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8, 108u8,
+                    114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8, 118u8,
+                    119u8, 120u8, 121u8, 122u8, 65u8, 67u8, 68u8, 71u8, 74u8, 75u8, 78u8, 79u8,
+                    80u8, 81u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8,
+                    100u8, 102u8, 104u8, 105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 112u8,
+                    113u8, 114u8, 115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 65u8,
+                    66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8,
+                    79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8,
+                    98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8, 108u8,
+                    114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8, 118u8,
+                    119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 68u8, 69u8, 70u8, 71u8, 74u8, 75u8,
+                    76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8,
+                    97u8, 98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8,
+                    108u8, 114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8,
+                    118u8, 119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 68u8, 69u8, 70u8, 71u8, 108u8,
+                    74u8, 75u8, 76u8, 77u8, 79u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 97u8,
+                    98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8, 108u8,
+                    114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8, 118u8,
+                    119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8,
+                    108u8, 74u8, 75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8,
+                    86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8, 101u8, 102u8, 103u8,
+                    104u8, 105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 111u8, 112u8, 113u8,
+                    114u8, 115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 65u8, 66u8,
+                    67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8, 79u8,
+                    80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8,
+                    99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8, 108u8, 114u8,
+                    110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8, 118u8, 119u8,
+                    120u8, 121u8, 122u8, 65u8, 66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8,
+                    74u8, 75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8,
+                    87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8,
+                    105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8,
+                    115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 67u8, 68u8,
+                    69u8, 70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8,
+                    82u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8,
+                    101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8,
+                    111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8,
+                    122u8, 65u8, 66u8, 67u8, 68u8, 69u8, 70u8, 71u8, 72u8, 108u8, 74u8, 75u8, 76u8,
+                    77u8, 78u8, 79u8, 80u8, 81u8, 82u8, 83u8, 84u8, 85u8, 86u8, 87u8, 88u8, 89u8,
+                    90u8, 97u8, 98u8, 99u8, 100u8, 101u8, 102u8, 103u8, 104u8, 105u8, 106u8, 107u8,
+                    108u8, 114u8, 110u8, 110u8, 111u8, 112u8, 113u8, 114u8, 115u8, 116u8, 117u8,
+                    118u8, 119u8, 120u8, 121u8, 122u8, 65u8, 66u8, 67u8, 68u8, 69u8, 70u8, 71u8,
+                    72u8, 108u8, 74u8, 75u8, 76u8, 77u8, 78u8, 79u8, 80u8, 81u8, 82u8, 83u8, 84u8,
+                    85u8, 86u8, 87u8, 88u8, 89u8, 90u8, 97u8, 98u8, 99u8, 100u8, 101u8, 102u8,
+                    103u8, 104u8, 105u8, 106u8, 107u8, 108u8, 114u8, 110u8, 110u8, 111u8, 112u8,
+                    113u8, 114u8, 115u8, 116u8, 117u8, 118u8, 119u8, 120u8, 121u8, 122u8, 105u8,
+                    200u8, 183u8, 65u8, 66u8, 206u8, 147u8, 206u8, 148u8, 69u8, 90u8, 72u8, 79u8,
+                    204u8, 181u8, 108u8, 75u8, 201u8, 133u8, 77u8, 78u8, 206u8, 158u8, 79u8, 206u8,
+                    160u8, 80u8, 79u8, 204u8, 181u8, 198u8, 169u8, 84u8, 89u8, 206u8, 166u8, 88u8,
+                    206u8, 168u8, 206u8, 169u8, 226u8, 136u8, 135u8, 97u8, 195u8, 159u8, 121u8,
+                    225u8, 186u8, 159u8, 234u8, 158u8, 147u8, 206u8, 182u8, 110u8, 204u8, 169u8,
+                    79u8, 204u8, 181u8, 105u8, 196u8, 184u8, 206u8, 187u8, 206u8, 188u8, 118u8,
+                    206u8, 190u8, 111u8, 207u8, 128u8, 112u8, 207u8, 130u8, 111u8, 225u8, 180u8,
+                    155u8, 117u8, 201u8, 184u8, 207u8, 135u8, 207u8, 136u8, 207u8, 137u8, 226u8,
+                    136u8, 130u8, 234u8, 158u8, 147u8, 79u8, 204u8, 181u8, 196u8, 184u8, 201u8,
+                    184u8, 112u8, 207u8, 128u8, 65u8, 66u8, 206u8, 147u8, 206u8, 148u8, 69u8, 90u8,
+                    72u8, 79u8, 204u8, 181u8, 108u8, 75u8, 201u8, 133u8, 77u8, 78u8, 206u8, 158u8,
+                    79u8, 206u8, 160u8, 80u8, 79u8, 204u8, 181u8, 198u8, 169u8, 84u8, 89u8, 206u8,
+                    166u8, 88u8, 206u8, 168u8, 206u8, 169u8, 226u8, 136u8, 135u8, 97u8, 195u8,
+                    159u8, 121u8, 225u8, 186u8, 159u8, 234u8, 158u8, 147u8, 206u8, 182u8, 110u8,
+                    204u8, 169u8, 79u8, 204u8, 181u8, 105u8, 196u8, 184u8, 206u8, 187u8, 206u8,
+                    188u8, 118u8, 206u8, 190u8, 111u8, 207u8, 128u8, 112u8, 207u8, 130u8, 111u8,
+                    225u8, 180u8, 155u8, 117u8, 201u8, 184u8, 207u8, 135u8, 207u8, 136u8, 207u8,
+                    137u8, 226u8, 136u8, 130u8, 234u8, 158u8, 147u8, 79u8, 204u8, 181u8, 196u8,
+                    184u8, 201u8, 184u8, 112u8, 207u8, 128u8, 65u8, 66u8, 206u8, 147u8, 206u8,
+                    148u8, 69u8, 90u8, 72u8, 79u8, 204u8, 181u8, 108u8, 75u8, 201u8, 133u8, 77u8,
+                    78u8, 206u8, 158u8, 79u8, 206u8, 160u8, 80u8, 79u8, 204u8, 181u8, 198u8, 169u8,
+                    84u8, 89u8, 206u8, 166u8, 88u8, 206u8, 168u8, 206u8, 169u8, 226u8, 136u8,
+                    135u8, 97u8, 195u8, 159u8, 121u8, 225u8, 186u8, 159u8, 234u8, 158u8, 147u8,
+                    206u8, 182u8, 110u8, 204u8, 169u8, 79u8, 204u8, 181u8, 105u8, 196u8, 184u8,
+                    206u8, 187u8, 206u8, 188u8, 118u8, 206u8, 190u8, 111u8, 207u8, 128u8, 112u8,
+                    207u8, 130u8, 111u8, 225u8, 180u8, 155u8, 117u8, 201u8, 184u8, 207u8, 135u8,
+                    207u8, 136u8, 207u8, 137u8, 226u8, 136u8, 130u8, 234u8, 158u8, 147u8, 79u8,
+                    204u8, 181u8, 196u8, 184u8, 201u8, 184u8, 112u8, 207u8, 128u8, 65u8, 66u8,
+                    206u8, 147u8, 206u8, 148u8, 69u8, 90u8, 72u8, 79u8, 204u8, 181u8, 108u8, 75u8,
+                    201u8, 133u8, 77u8, 78u8, 206u8, 158u8, 79u8, 206u8, 160u8, 80u8, 79u8, 204u8,
+                    181u8, 198u8, 169u8, 84u8, 89u8, 206u8, 166u8, 88u8, 206u8, 168u8, 206u8,
+                    169u8, 226u8, 136u8, 135u8, 97u8, 195u8, 159u8, 121u8, 225u8, 186u8, 159u8,
+                    234u8, 158u8, 147u8, 206u8, 182u8, 110u8, 204u8, 169u8, 79u8, 204u8, 181u8,
+                    105u8, 196u8, 184u8, 206u8, 187u8, 206u8, 188u8, 118u8, 206u8, 190u8, 111u8,
+                    207u8, 128u8, 112u8, 207u8, 130u8, 111u8, 225u8, 180u8, 155u8, 117u8, 201u8,
+                    184u8, 207u8, 135u8, 207u8, 136u8, 207u8, 137u8, 226u8, 136u8, 130u8, 234u8,
+                    158u8, 147u8, 79u8, 204u8, 181u8, 196u8, 184u8, 201u8, 184u8, 112u8, 207u8,
+                    128u8, 65u8, 66u8, 206u8, 147u8, 206u8, 148u8, 69u8, 90u8, 72u8, 79u8, 204u8,
+                    181u8, 108u8, 75u8, 201u8, 133u8, 77u8, 78u8, 206u8, 158u8, 79u8, 206u8, 160u8,
+                    80u8, 79u8, 204u8, 181u8, 198u8, 169u8, 84u8, 89u8, 206u8, 166u8, 88u8, 206u8,
+                    168u8, 206u8, 169u8, 226u8, 136u8, 135u8, 97u8, 195u8, 159u8, 121u8, 225u8,
+                    186u8, 159u8, 234u8, 158u8, 147u8, 206u8, 182u8, 110u8, 204u8, 169u8, 79u8,
+                    204u8, 181u8, 105u8, 196u8, 184u8, 206u8, 187u8, 206u8, 188u8, 118u8, 206u8,
+                    190u8, 111u8, 207u8, 128u8, 112u8, 207u8, 130u8, 111u8, 225u8, 180u8, 155u8,
+                    117u8, 201u8, 184u8, 207u8, 135u8, 207u8, 136u8, 207u8, 137u8, 226u8, 136u8,
+                    130u8, 234u8, 158u8, 147u8, 79u8, 204u8, 181u8, 196u8, 184u8, 201u8, 184u8,
+                    112u8, 207u8, 128u8, 70u8, 207u8, 157u8, 79u8, 108u8, 50u8, 51u8, 52u8, 53u8,
+                    54u8, 55u8, 56u8, 57u8, 79u8, 108u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8,
+                    57u8, 79u8, 108u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8, 57u8, 79u8, 108u8,
+                    50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8, 57u8, 79u8, 108u8, 50u8, 51u8, 52u8,
+                    53u8, 54u8, 55u8, 56u8, 57u8, 108u8, 226u8, 136u8, 160u8, 217u8, 163u8, 56u8,
+                    226u8, 136u8, 130u8, 226u8, 136u8, 130u8, 204u8, 181u8, 108u8, 216u8, 168u8,
+                    216u8, 172u8, 216u8, 175u8, 217u8, 136u8, 216u8, 178u8, 216u8, 173u8, 216u8,
+                    183u8, 217u8, 137u8, 217u8, 131u8, 217u8, 132u8, 217u8, 133u8, 217u8, 134u8,
+                    216u8, 179u8, 216u8, 185u8, 217u8, 129u8, 216u8, 181u8, 217u8, 130u8, 216u8,
+                    177u8, 216u8, 179u8, 219u8, 155u8, 216u8, 170u8, 217u8, 137u8, 219u8, 155u8,
+                    216u8, 174u8, 216u8, 176u8, 216u8, 182u8, 216u8, 184u8, 216u8, 186u8, 217u8,
+                    137u8, 217u8, 137u8, 218u8, 161u8, 218u8, 161u8, 216u8, 168u8, 216u8, 172u8,
+                    111u8, 216u8, 173u8, 217u8, 137u8, 217u8, 131u8, 217u8, 132u8, 217u8, 133u8,
+                    217u8, 134u8, 216u8, 179u8, 216u8, 185u8, 217u8, 129u8, 216u8, 181u8, 217u8,
+                    130u8, 216u8, 179u8, 219u8, 155u8, 216u8, 170u8, 217u8, 137u8, 219u8, 155u8,
+                    216u8, 174u8, 216u8, 182u8, 216u8, 186u8, 216u8, 172u8, 216u8, 173u8, 217u8,
+                    137u8, 217u8, 132u8, 217u8, 134u8, 216u8, 179u8, 216u8, 185u8, 216u8, 181u8,
+                    217u8, 130u8, 216u8, 179u8, 219u8, 155u8, 216u8, 174u8, 216u8, 182u8, 216u8,
+                    186u8, 217u8, 137u8, 218u8, 161u8, 216u8, 168u8, 216u8, 172u8, 111u8, 216u8,
+                    173u8, 216u8, 183u8, 217u8, 137u8, 217u8, 131u8, 217u8, 133u8, 217u8, 134u8,
+                    216u8, 179u8, 216u8, 185u8, 217u8, 129u8, 216u8, 181u8, 217u8, 130u8, 216u8,
+                    179u8, 219u8, 155u8, 216u8, 170u8, 217u8, 137u8, 219u8, 155u8, 216u8, 174u8,
+                    216u8, 182u8, 216u8, 184u8, 216u8, 186u8, 217u8, 137u8, 218u8, 161u8, 108u8,
+                    216u8, 168u8, 216u8, 172u8, 216u8, 175u8, 111u8, 217u8, 136u8, 216u8, 178u8,
+                    216u8, 173u8, 216u8, 183u8, 217u8, 137u8, 217u8, 132u8, 217u8, 133u8, 217u8,
+                    134u8, 216u8, 179u8, 216u8, 185u8, 217u8, 129u8, 216u8, 181u8, 217u8, 130u8,
+                    216u8, 177u8, 216u8, 179u8, 219u8, 155u8, 216u8, 170u8, 217u8, 137u8, 219u8,
+                    155u8, 216u8, 174u8, 216u8, 176u8, 216u8, 182u8, 216u8, 184u8, 216u8, 186u8,
+                    216u8, 168u8, 216u8, 172u8, 216u8, 175u8, 217u8, 136u8, 216u8, 178u8, 216u8,
+                    173u8, 216u8, 183u8, 217u8, 137u8, 217u8, 132u8, 217u8, 133u8, 217u8, 134u8,
+                    216u8, 179u8, 216u8, 185u8, 217u8, 129u8, 216u8, 181u8, 217u8, 130u8, 216u8,
+                    177u8, 216u8, 179u8, 219u8, 155u8, 216u8, 170u8, 217u8, 137u8, 219u8, 155u8,
+                    216u8, 174u8, 216u8, 176u8, 216u8, 182u8, 216u8, 184u8, 216u8, 186u8, 79u8,
+                    46u8, 79u8, 44u8, 108u8, 44u8, 50u8, 44u8, 51u8, 44u8, 52u8, 44u8, 53u8, 44u8,
+                    54u8, 44u8, 55u8, 44u8, 56u8, 44u8, 57u8, 44u8, 36u8, 226u8, 131u8, 160u8,
+                    40u8, 65u8, 41u8, 40u8, 66u8, 41u8, 40u8, 67u8, 41u8, 40u8, 68u8, 41u8, 40u8,
+                    69u8, 41u8, 40u8, 70u8, 41u8, 40u8, 71u8, 41u8, 40u8, 72u8, 41u8, 40u8, 108u8,
+                    41u8, 40u8, 74u8, 41u8, 40u8, 75u8, 41u8, 40u8, 76u8, 41u8, 40u8, 77u8, 41u8,
+                    40u8, 78u8, 41u8, 40u8, 79u8, 41u8, 40u8, 80u8, 41u8, 40u8, 81u8, 41u8, 40u8,
+                    82u8, 41u8, 40u8, 83u8, 41u8, 40u8, 84u8, 41u8, 40u8, 85u8, 41u8, 40u8, 86u8,
+                    41u8, 40u8, 87u8, 41u8, 40u8, 88u8, 41u8, 40u8, 89u8, 41u8, 40u8, 90u8, 41u8,
+                    40u8, 83u8, 41u8, 227u8, 143u8, 132u8, 9u8, 226u8, 131u8, 157u8, 67u8, 226u8,
+                    131u8, 160u8, 40u8, 230u8, 156u8, 172u8, 41u8, 40u8, 228u8, 184u8, 137u8, 41u8,
+                    40u8, 228u8, 186u8, 140u8, 41u8, 40u8, 229u8, 174u8, 137u8, 41u8, 40u8, 231u8,
+                    130u8, 185u8, 41u8, 40u8, 230u8, 137u8, 147u8, 41u8, 40u8, 231u8, 155u8, 151u8,
+                    41u8, 40u8, 229u8, 139u8, 157u8, 41u8, 40u8, 230u8, 149u8, 151u8, 41u8, 226u8,
+                    152u8, 189u8, 226u8, 152u8, 190u8, 226u8, 152u8, 189u8, 81u8, 69u8, 234u8,
+                    153u8, 152u8, 206u8, 148u8, 240u8, 144u8, 138u8, 188u8, 65u8, 82u8, 86u8,
+                    225u8, 183u8, 164u8, 226u8, 152u8, 169u8, 79u8, 204u8, 181u8, 240u8, 144u8,
+                    138u8, 168u8, 226u8, 167u8, 159u8, 67u8, 225u8, 155u8, 156u8, 226u8, 138u8,
+                    161u8, 115u8, 115u8, 115u8, 226u8, 137u8, 143u8, 84u8, 77u8, 66u8, 86u8, 66u8,
+                    226u8, 138u8, 160u8, 79u8, 108u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8,
+                    57u8, 226u8, 157u8, 172u8, 228u8, 184u8, 189u8, 228u8, 184u8, 184u8, 228u8,
+                    185u8, 129u8, 240u8, 160u8, 132u8, 162u8, 228u8, 189u8, 160u8, 228u8, 190u8,
+                    174u8, 228u8, 190u8, 187u8, 228u8, 189u8, 181u8, 229u8, 129u8, 186u8, 229u8,
+                    130u8, 153u8, 229u8, 131u8, 167u8, 229u8, 131u8, 143u8, 227u8, 146u8, 158u8,
+                    240u8, 160u8, 152u8, 186u8, 229u8, 133u8, 141u8, 229u8, 133u8, 148u8, 229u8,
+                    133u8, 164u8, 229u8, 133u8, 183u8, 240u8, 160u8, 148u8, 156u8, 227u8, 146u8,
+                    185u8, 229u8, 133u8, 167u8, 229u8, 134u8, 141u8, 240u8, 160u8, 149u8, 139u8,
+                    229u8, 134u8, 151u8, 229u8, 134u8, 164u8, 228u8, 187u8, 140u8, 229u8, 134u8,
+                    172u8, 229u8, 134u8, 181u8, 240u8, 169u8, 135u8, 159u8, 229u8, 135u8, 181u8,
+                    229u8, 136u8, 131u8, 227u8, 147u8, 159u8, 229u8, 136u8, 187u8, 229u8, 137u8,
+                    134u8, 229u8, 137u8, 178u8, 229u8, 137u8, 183u8, 227u8, 148u8, 149u8, 229u8,
+                    139u8, 135u8, 229u8, 139u8, 137u8, 229u8, 139u8, 164u8, 229u8, 139u8, 186u8,
+                    229u8, 140u8, 133u8, 229u8, 140u8, 134u8, 229u8, 140u8, 151u8, 229u8, 141u8,
+                    137u8, 229u8, 141u8, 145u8, 229u8, 141u8, 154u8, 229u8, 141u8, 179u8, 229u8,
+                    141u8, 189u8, 229u8, 141u8, 191u8, 229u8, 141u8, 191u8, 229u8, 141u8, 191u8,
+                    240u8, 160u8, 168u8, 172u8, 231u8, 129u8, 176u8, 229u8, 143u8, 138u8, 229u8,
+                    143u8, 159u8, 240u8, 160u8, 173u8, 163u8, 229u8, 143u8, 171u8, 229u8, 143u8,
+                    177u8, 229u8, 144u8, 134u8, 229u8, 146u8, 158u8, 229u8, 144u8, 184u8, 229u8,
+                    145u8, 136u8, 229u8, 145u8, 168u8, 229u8, 146u8, 162u8, 229u8, 147u8, 182u8,
+                    229u8, 148u8, 144u8, 229u8, 149u8, 147u8, 229u8, 149u8, 163u8, 229u8, 150u8,
+                    132u8, 229u8, 150u8, 132u8, 229u8, 150u8, 153u8, 229u8, 150u8, 171u8, 229u8,
+                    150u8, 179u8, 229u8, 151u8, 130u8, 229u8, 156u8, 150u8, 229u8, 152u8, 134u8,
+                    229u8, 156u8, 151u8, 229u8, 153u8, 145u8, 229u8, 153u8, 180u8, 229u8, 136u8,
+                    135u8, 229u8, 163u8, 174u8, 229u8, 159u8, 142u8, 229u8, 159u8, 180u8, 229u8,
+                    160u8, 141u8, 229u8, 158u8, 139u8, 229u8, 160u8, 178u8, 229u8, 160u8, 177u8,
+                    229u8, 162u8, 172u8, 240u8, 161u8, 147u8, 164u8, 229u8, 163u8, 178u8, 229u8,
+                    163u8, 183u8, 229u8, 164u8, 134u8, 229u8, 164u8, 154u8, 229u8, 164u8, 162u8,
+                    229u8, 165u8, 162u8, 240u8, 161u8, 154u8, 168u8, 240u8, 161u8, 155u8, 170u8,
+                    229u8, 167u8, 172u8, 229u8, 168u8, 155u8, 229u8, 168u8, 167u8, 229u8, 167u8,
+                    152u8, 229u8, 169u8, 166u8, 227u8, 155u8, 174u8, 227u8, 155u8, 188u8, 229u8,
+                    172u8, 136u8, 229u8, 172u8, 190u8, 229u8, 172u8, 190u8, 240u8, 161u8, 167u8,
+                    136u8, 229u8, 175u8, 131u8, 229u8, 175u8, 152u8, 229u8, 175u8, 167u8, 229u8,
+                    175u8, 179u8, 240u8, 161u8, 172u8, 152u8, 229u8, 175u8, 191u8, 229u8, 176u8,
+                    134u8, 229u8, 189u8, 147u8, 229u8, 176u8, 162u8, 227u8, 158u8, 129u8, 229u8,
+                    177u8, 160u8, 229u8, 177u8, 174u8, 229u8, 179u8, 128u8, 229u8, 178u8, 141u8,
+                    240u8, 161u8, 183u8, 164u8, 229u8, 181u8, 131u8, 240u8, 161u8, 183u8, 166u8,
+                    229u8, 181u8, 174u8, 229u8, 181u8, 171u8, 229u8, 181u8, 188u8, 229u8, 183u8,
+                    161u8, 229u8, 183u8, 162u8, 227u8, 160u8, 175u8, 229u8, 183u8, 189u8, 229u8,
+                    184u8, 168u8, 229u8, 184u8, 189u8, 229u8, 185u8, 169u8, 227u8, 161u8, 162u8,
+                    240u8, 162u8, 134u8, 131u8, 227u8, 161u8, 188u8, 229u8, 186u8, 176u8, 229u8,
+                    186u8, 179u8, 229u8, 186u8, 182u8, 229u8, 187u8, 138u8, 240u8, 170u8, 142u8,
+                    146u8, 229u8, 187u8, 190u8, 240u8, 162u8, 140u8, 177u8, 240u8, 162u8, 140u8,
+                    177u8, 232u8, 136u8, 129u8, 229u8, 188u8, 162u8, 229u8, 188u8, 162u8, 227u8,
+                    163u8, 135u8, 240u8, 163u8, 138u8, 184u8, 240u8, 166u8, 135u8, 154u8, 229u8,
+                    189u8, 162u8, 229u8, 189u8, 171u8, 227u8, 163u8, 163u8, 229u8, 190u8, 154u8,
+                    229u8, 191u8, 141u8, 229u8, 191u8, 151u8, 229u8, 191u8, 185u8, 230u8, 130u8,
+                    129u8, 227u8, 164u8, 186u8, 227u8, 164u8, 156u8, 230u8, 130u8, 148u8, 240u8,
+                    162u8, 155u8, 148u8, 230u8, 131u8, 135u8, 230u8, 133u8, 136u8, 230u8, 133u8,
+                    140u8, 230u8, 133u8, 142u8, 230u8, 133u8, 140u8, 230u8, 133u8, 186u8, 230u8,
+                    134u8, 142u8, 230u8, 134u8, 178u8, 230u8, 134u8, 164u8, 230u8, 134u8, 175u8,
+                    230u8, 135u8, 158u8, 230u8, 135u8, 178u8, 230u8, 135u8, 182u8, 230u8, 136u8,
+                    144u8, 230u8, 136u8, 155u8, 230u8, 137u8, 157u8, 230u8, 138u8, 177u8, 230u8,
+                    139u8, 148u8, 230u8, 141u8, 144u8, 240u8, 162u8, 172u8, 140u8, 230u8, 140u8,
+                    189u8, 230u8, 139u8, 188u8, 230u8, 141u8, 168u8, 230u8, 142u8, 131u8, 230u8,
+                    143u8, 164u8, 240u8, 162u8, 175u8, 177u8, 230u8, 144u8, 162u8, 230u8, 143u8,
+                    133u8, 230u8, 142u8, 169u8, 227u8, 168u8, 174u8, 230u8, 145u8, 169u8, 230u8,
+                    145u8, 190u8, 230u8, 146u8, 157u8, 230u8, 145u8, 183u8, 227u8, 169u8, 172u8,
+                    230u8, 149u8, 143u8, 230u8, 149u8, 172u8, 240u8, 163u8, 128u8, 138u8, 230u8,
+                    151u8, 163u8, 230u8, 155u8, 184u8, 230u8, 153u8, 137u8, 227u8, 172u8, 153u8,
+                    230u8, 154u8, 145u8, 227u8, 172u8, 136u8, 227u8, 171u8, 164u8, 229u8, 134u8,
+                    146u8, 229u8, 134u8, 149u8, 230u8, 156u8, 128u8, 230u8, 154u8, 156u8, 232u8,
+                    130u8, 173u8, 228u8, 143u8, 153u8, 230u8, 156u8, 151u8, 230u8, 156u8, 155u8,
+                    230u8, 156u8, 161u8, 230u8, 157u8, 158u8, 230u8, 157u8, 147u8, 240u8, 163u8,
+                    143u8, 131u8, 227u8, 173u8, 137u8, 230u8, 159u8, 186u8, 230u8, 158u8, 133u8,
+                    230u8, 161u8, 146u8, 230u8, 162u8, 133u8, 240u8, 163u8, 145u8, 173u8, 230u8,
+                    162u8, 142u8, 230u8, 160u8, 159u8, 230u8, 164u8, 148u8, 227u8, 174u8, 157u8,
+                    230u8, 165u8, 130u8, 230u8, 166u8, 163u8, 230u8, 167u8, 170u8, 230u8, 170u8,
+                    168u8, 240u8, 163u8, 154u8, 163u8, 230u8, 171u8, 155u8, 227u8, 176u8, 152u8,
+                    230u8, 172u8, 161u8, 240u8, 163u8, 162u8, 167u8, 230u8, 173u8, 148u8, 227u8,
+                    177u8, 142u8, 230u8, 173u8, 178u8, 230u8, 174u8, 159u8, 230u8, 174u8, 186u8,
+                    230u8, 174u8, 187u8, 240u8, 163u8, 170u8, 141u8, 240u8, 161u8, 180u8, 139u8,
+                    240u8, 163u8, 171u8, 186u8, 230u8, 177u8, 142u8, 240u8, 163u8, 178u8, 188u8,
+                    230u8, 178u8, 191u8, 230u8, 179u8, 141u8, 230u8, 177u8, 167u8, 230u8, 180u8,
+                    150u8, 230u8, 180u8, 190u8, 230u8, 181u8, 183u8, 230u8, 181u8, 129u8, 230u8,
+                    181u8, 169u8, 230u8, 181u8, 184u8, 230u8, 182u8, 133u8, 240u8, 163u8, 180u8,
+                    158u8, 230u8, 180u8, 180u8, 230u8, 184u8, 175u8, 230u8, 185u8, 174u8, 227u8,
+                    180u8, 179u8, 230u8, 187u8, 139u8, 230u8, 187u8, 135u8, 240u8, 163u8, 187u8,
+                    145u8, 230u8, 183u8, 185u8, 230u8, 189u8, 174u8, 240u8, 163u8, 189u8, 158u8,
+                    240u8, 163u8, 190u8, 142u8, 230u8, 191u8, 134u8, 231u8, 128u8, 185u8, 231u8,
+                    128u8, 158u8, 231u8, 128u8, 155u8, 227u8, 182u8, 150u8, 231u8, 129u8, 138u8,
+                    231u8, 129u8, 189u8, 231u8, 129u8, 183u8, 231u8, 130u8, 173u8, 240u8, 160u8,
+                    148u8, 165u8, 231u8, 133u8, 133u8, 240u8, 164u8, 137u8, 163u8, 231u8, 134u8,
+                    156u8, 240u8, 164u8, 142u8, 171u8, 231u8, 136u8, 168u8, 231u8, 136u8, 181u8,
+                    231u8, 137u8, 144u8, 240u8, 164u8, 152u8, 136u8, 231u8, 138u8, 128u8, 231u8,
+                    138u8, 149u8, 240u8, 164u8, 156u8, 181u8, 240u8, 164u8, 160u8, 148u8, 231u8,
+                    141u8, 186u8, 231u8, 142u8, 139u8, 227u8, 186u8, 172u8, 231u8, 142u8, 165u8,
+                    227u8, 186u8, 184u8, 227u8, 186u8, 184u8, 231u8, 145u8, 135u8, 231u8, 145u8,
+                    156u8, 231u8, 145u8, 177u8, 231u8, 146u8, 133u8, 231u8, 147u8, 138u8, 227u8,
+                    188u8, 155u8, 231u8, 148u8, 164u8, 240u8, 164u8, 176u8, 182u8, 231u8, 148u8,
+                    190u8, 240u8, 164u8, 178u8, 146u8, 231u8, 149u8, 176u8, 240u8, 162u8, 134u8,
+                    159u8, 231u8, 152u8, 144u8, 240u8, 164u8, 190u8, 161u8, 240u8, 164u8, 190u8,
+                    184u8, 240u8, 165u8, 129u8, 132u8, 227u8, 191u8, 188u8, 228u8, 128u8, 136u8,
+                    231u8, 155u8, 180u8, 240u8, 165u8, 131u8, 179u8, 240u8, 165u8, 131u8, 178u8,
+                    240u8, 165u8, 132u8, 153u8, 240u8, 165u8, 132u8, 179u8, 231u8, 156u8, 158u8,
+                    231u8, 156u8, 159u8, 231u8, 156u8, 159u8, 231u8, 157u8, 138u8, 228u8, 128u8,
+                    185u8, 231u8, 158u8, 139u8, 228u8, 129u8, 134u8, 228u8, 130u8, 150u8, 240u8,
+                    165u8, 144u8, 157u8, 231u8, 161u8, 142u8, 231u8, 162u8, 140u8, 231u8, 163u8,
+                    140u8, 228u8, 131u8, 163u8, 240u8, 165u8, 152u8, 166u8, 231u8, 165u8, 150u8,
+                    240u8, 165u8, 154u8, 154u8, 240u8, 165u8, 155u8, 133u8, 231u8, 166u8, 143u8,
+                    231u8, 167u8, 171u8, 228u8, 132u8, 175u8, 231u8, 169u8, 128u8, 231u8, 169u8,
+                    138u8, 231u8, 169u8, 143u8, 240u8, 165u8, 165u8, 188u8, 240u8, 165u8, 170u8,
+                    167u8, 240u8, 165u8, 170u8, 167u8, 231u8, 171u8, 174u8, 228u8, 136u8, 130u8,
+                    240u8, 165u8, 174u8, 171u8, 231u8, 175u8, 134u8, 231u8, 175u8, 137u8, 228u8,
+                    136u8, 167u8, 240u8, 165u8, 178u8, 128u8, 231u8, 179u8, 146u8, 228u8, 138u8,
+                    160u8, 231u8, 179u8, 168u8, 231u8, 179u8, 163u8, 231u8, 180u8, 128u8, 240u8,
+                    165u8, 190u8, 134u8, 231u8, 181u8, 163u8, 228u8, 140u8, 129u8, 231u8, 183u8,
+                    135u8, 231u8, 184u8, 130u8, 231u8, 185u8, 133u8, 228u8, 140u8, 180u8, 240u8,
+                    166u8, 136u8, 168u8, 240u8, 166u8, 137u8, 135u8, 228u8, 141u8, 153u8, 240u8,
+                    166u8, 139u8, 153u8, 231u8, 189u8, 186u8, 240u8, 166u8, 140u8, 190u8, 231u8,
+                    190u8, 149u8, 231u8, 191u8, 186u8, 232u8, 128u8, 133u8, 240u8, 166u8, 147u8,
+                    154u8, 240u8, 166u8, 148u8, 163u8, 232u8, 129u8, 160u8, 240u8, 166u8, 150u8,
+                    168u8, 232u8, 129u8, 176u8, 240u8, 163u8, 141u8, 159u8, 228u8, 143u8, 149u8,
+                    232u8, 130u8, 178u8, 232u8, 132u8, 131u8, 228u8, 144u8, 139u8, 232u8, 132u8,
+                    190u8, 229u8, 170u8, 181u8, 240u8, 166u8, 158u8, 167u8, 240u8, 166u8, 158u8,
+                    181u8, 240u8, 163u8, 142u8, 147u8, 240u8, 163u8, 142u8, 156u8, 232u8, 136u8,
+                    129u8, 232u8, 136u8, 132u8, 232u8, 190u8, 158u8, 228u8, 145u8, 171u8, 232u8,
+                    138u8, 145u8, 232u8, 138u8, 139u8, 232u8, 138u8, 157u8, 229u8, 138u8, 179u8,
+                    232u8, 138u8, 177u8, 232u8, 138u8, 179u8, 232u8, 138u8, 189u8, 232u8, 139u8,
+                    166u8, 240u8, 166u8, 172u8, 188u8, 232u8, 139u8, 165u8, 232u8, 140u8, 157u8,
+                    232u8, 141u8, 163u8, 232u8, 142u8, 173u8, 232u8, 140u8, 163u8, 232u8, 142u8,
+                    189u8, 232u8, 143u8, 167u8, 232u8, 145u8, 151u8, 232u8, 141u8, 147u8, 232u8,
+                    143u8, 138u8, 232u8, 143u8, 140u8, 232u8, 143u8, 156u8, 240u8, 166u8, 176u8,
+                    182u8, 240u8, 166u8, 181u8, 171u8, 240u8, 166u8, 179u8, 149u8, 228u8, 148u8,
+                    171u8, 232u8, 147u8, 177u8, 232u8, 147u8, 179u8, 232u8, 148u8, 150u8, 240u8,
+                    167u8, 143u8, 138u8, 232u8, 149u8, 164u8, 240u8, 166u8, 188u8, 172u8, 228u8,
+                    149u8, 157u8, 228u8, 149u8, 161u8, 240u8, 166u8, 190u8, 177u8, 240u8, 167u8,
+                    131u8, 146u8, 228u8, 149u8, 171u8, 232u8, 153u8, 144u8, 232u8, 153u8, 156u8,
+                    232u8, 153u8, 167u8, 232u8, 153u8, 169u8, 232u8, 154u8, 169u8, 232u8, 154u8,
+                    136u8, 232u8, 156u8, 142u8, 232u8, 155u8, 162u8, 232u8, 157u8, 185u8, 232u8,
+                    156u8, 168u8, 232u8, 157u8, 171u8, 232u8, 158u8, 134u8, 228u8, 151u8, 151u8,
+                    232u8, 159u8, 161u8, 232u8, 160u8, 129u8, 228u8, 151u8, 185u8, 232u8, 161u8,
+                    160u8, 232u8, 161u8, 163u8, 240u8, 167u8, 153u8, 167u8, 232u8, 163u8, 151u8,
+                    232u8, 163u8, 158u8, 228u8, 152u8, 181u8, 232u8, 163u8, 186u8, 227u8, 146u8,
+                    187u8, 240u8, 167u8, 162u8, 174u8, 240u8, 167u8, 165u8, 166u8, 228u8, 154u8,
+                    190u8, 228u8, 155u8, 135u8, 232u8, 170u8, 160u8, 232u8, 171u8, 173u8, 232u8,
+                    174u8, 138u8, 232u8, 177u8, 149u8, 240u8, 167u8, 178u8, 168u8, 232u8, 178u8,
+                    171u8, 232u8, 179u8, 129u8, 232u8, 180u8, 155u8, 232u8, 181u8, 183u8, 240u8,
+                    167u8, 188u8, 175u8, 240u8, 160u8, 160u8, 132u8, 232u8, 183u8, 139u8, 232u8,
+                    182u8, 188u8, 232u8, 183u8, 176u8, 240u8, 160u8, 163u8, 158u8, 232u8, 187u8,
+                    148u8, 232u8, 188u8, 184u8, 240u8, 168u8, 151u8, 146u8, 240u8, 168u8, 151u8,
+                    173u8, 233u8, 130u8, 148u8, 233u8, 131u8, 177u8, 233u8, 132u8, 145u8, 240u8,
+                    168u8, 156u8, 174u8, 233u8, 132u8, 155u8, 233u8, 136u8, 184u8, 233u8, 139u8,
+                    151u8, 233u8, 139u8, 152u8, 233u8, 137u8, 188u8, 233u8, 143u8, 185u8, 233u8,
+                    144u8, 149u8, 240u8, 168u8, 175u8, 186u8, 233u8, 150u8, 139u8, 228u8, 166u8,
+                    149u8, 233u8, 150u8, 183u8, 240u8, 168u8, 181u8, 183u8, 228u8, 167u8, 166u8,
+                    233u8, 155u8, 131u8, 229u8, 182u8, 178u8, 233u8, 156u8, 163u8, 240u8, 169u8,
+                    133u8, 133u8, 240u8, 169u8, 136u8, 154u8, 228u8, 169u8, 174u8, 228u8, 169u8,
+                    182u8, 233u8, 159u8, 160u8, 240u8, 169u8, 144u8, 138u8, 228u8, 170u8, 178u8,
+                    240u8, 169u8, 146u8, 150u8, 233u8, 160u8, 139u8, 233u8, 160u8, 139u8, 233u8,
+                    160u8, 169u8, 240u8, 169u8, 150u8, 182u8, 233u8, 163u8, 162u8, 228u8, 172u8,
+                    179u8, 233u8, 164u8, 169u8, 233u8, 166u8, 167u8, 233u8, 167u8, 130u8, 233u8,
+                    167u8, 190u8, 228u8, 175u8, 142u8, 240u8, 169u8, 172u8, 176u8, 233u8, 172u8,
+                    146u8, 233u8, 177u8, 128u8, 233u8, 179u8, 189u8, 228u8, 179u8, 142u8, 228u8,
+                    179u8, 173u8, 233u8, 181u8, 167u8, 240u8, 170u8, 131u8, 142u8, 228u8, 179u8,
+                    184u8, 240u8, 170u8, 132u8, 133u8, 240u8, 170u8, 136u8, 142u8, 240u8, 170u8,
+                    138u8, 145u8, 233u8, 186u8, 187u8, 228u8, 181u8, 150u8, 233u8, 187u8, 185u8,
+                    233u8, 187u8, 190u8, 233u8, 188u8, 133u8, 233u8, 188u8, 143u8, 233u8, 188u8,
+                    150u8, 233u8, 188u8, 187u8, 240u8, 170u8, 152u8, 128u8,
+                ])
+            },
+        )
+    },
+};
@@ -0,0 +1,244 @@
+// @generated
+#![cfg(feature = "icu_spoof")]
+type DataStruct =
+    <::icu_spoof::provider::IdentifierStatusV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_spoof::provider::IdentifierStatusV1 {
+    allowed: unsafe {
+        #[allow(unused_unsafe)]
+        ::icu_collections::codepointinvlist::CodePointInversionList::from_parts_unchecked(
+            unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    39u8, 0u8, 0u8, 0u8, 40u8, 0u8, 0u8, 0u8, 45u8, 0u8, 0u8, 0u8, 47u8, 0u8, 0u8,
+                    0u8, 48u8, 0u8, 0u8, 0u8, 59u8, 0u8, 0u8, 0u8, 65u8, 0u8, 0u8, 0u8, 91u8, 0u8,
+                    0u8, 0u8, 95u8, 0u8, 0u8, 0u8, 96u8, 0u8, 0u8, 0u8, 97u8, 0u8, 0u8, 0u8, 123u8,
+                    0u8, 0u8, 0u8, 183u8, 0u8, 0u8, 0u8, 184u8, 0u8, 0u8, 0u8, 192u8, 0u8, 0u8,
+                    0u8, 215u8, 0u8, 0u8, 0u8, 216u8, 0u8, 0u8, 0u8, 247u8, 0u8, 0u8, 0u8, 248u8,
+                    0u8, 0u8, 0u8, 50u8, 1u8, 0u8, 0u8, 52u8, 1u8, 0u8, 0u8, 63u8, 1u8, 0u8, 0u8,
+                    65u8, 1u8, 0u8, 0u8, 73u8, 1u8, 0u8, 0u8, 74u8, 1u8, 0u8, 0u8, 127u8, 1u8, 0u8,
+                    0u8, 143u8, 1u8, 0u8, 0u8, 144u8, 1u8, 0u8, 0u8, 160u8, 1u8, 0u8, 0u8, 162u8,
+                    1u8, 0u8, 0u8, 175u8, 1u8, 0u8, 0u8, 177u8, 1u8, 0u8, 0u8, 205u8, 1u8, 0u8,
+                    0u8, 221u8, 1u8, 0u8, 0u8, 222u8, 1u8, 0u8, 0u8, 228u8, 1u8, 0u8, 0u8, 230u8,
+                    1u8, 0u8, 0u8, 241u8, 1u8, 0u8, 0u8, 244u8, 1u8, 0u8, 0u8, 246u8, 1u8, 0u8,
+                    0u8, 248u8, 1u8, 0u8, 0u8, 28u8, 2u8, 0u8, 0u8, 30u8, 2u8, 0u8, 0u8, 32u8, 2u8,
+                    0u8, 0u8, 38u8, 2u8, 0u8, 0u8, 52u8, 2u8, 0u8, 0u8, 89u8, 2u8, 0u8, 0u8, 90u8,
+                    2u8, 0u8, 0u8, 187u8, 2u8, 0u8, 0u8, 189u8, 2u8, 0u8, 0u8, 236u8, 2u8, 0u8,
+                    0u8, 237u8, 2u8, 0u8, 0u8, 0u8, 3u8, 0u8, 0u8, 5u8, 3u8, 0u8, 0u8, 6u8, 3u8,
+                    0u8, 0u8, 13u8, 3u8, 0u8, 0u8, 15u8, 3u8, 0u8, 0u8, 18u8, 3u8, 0u8, 0u8, 19u8,
+                    3u8, 0u8, 0u8, 21u8, 3u8, 0u8, 0u8, 27u8, 3u8, 0u8, 0u8, 28u8, 3u8, 0u8, 0u8,
+                    35u8, 3u8, 0u8, 0u8, 41u8, 3u8, 0u8, 0u8, 45u8, 3u8, 0u8, 0u8, 47u8, 3u8, 0u8,
+                    0u8, 48u8, 3u8, 0u8, 0u8, 50u8, 3u8, 0u8, 0u8, 53u8, 3u8, 0u8, 0u8, 54u8, 3u8,
+                    0u8, 0u8, 56u8, 3u8, 0u8, 0u8, 58u8, 3u8, 0u8, 0u8, 66u8, 3u8, 0u8, 0u8, 67u8,
+                    3u8, 0u8, 0u8, 69u8, 3u8, 0u8, 0u8, 70u8, 3u8, 0u8, 0u8, 117u8, 3u8, 0u8, 0u8,
+                    118u8, 3u8, 0u8, 0u8, 123u8, 3u8, 0u8, 0u8, 126u8, 3u8, 0u8, 0u8, 134u8, 3u8,
+                    0u8, 0u8, 135u8, 3u8, 0u8, 0u8, 136u8, 3u8, 0u8, 0u8, 139u8, 3u8, 0u8, 0u8,
+                    140u8, 3u8, 0u8, 0u8, 141u8, 3u8, 0u8, 0u8, 142u8, 3u8, 0u8, 0u8, 162u8, 3u8,
+                    0u8, 0u8, 163u8, 3u8, 0u8, 0u8, 207u8, 3u8, 0u8, 0u8, 252u8, 3u8, 0u8, 0u8,
+                    96u8, 4u8, 0u8, 0u8, 138u8, 4u8, 0u8, 0u8, 0u8, 5u8, 0u8, 0u8, 16u8, 5u8, 0u8,
+                    0u8, 42u8, 5u8, 0u8, 0u8, 46u8, 5u8, 0u8, 0u8, 48u8, 5u8, 0u8, 0u8, 49u8, 5u8,
+                    0u8, 0u8, 87u8, 5u8, 0u8, 0u8, 89u8, 5u8, 0u8, 0u8, 90u8, 5u8, 0u8, 0u8, 97u8,
+                    5u8, 0u8, 0u8, 135u8, 5u8, 0u8, 0u8, 138u8, 5u8, 0u8, 0u8, 139u8, 5u8, 0u8,
+                    0u8, 180u8, 5u8, 0u8, 0u8, 181u8, 5u8, 0u8, 0u8, 208u8, 5u8, 0u8, 0u8, 235u8,
+                    5u8, 0u8, 0u8, 239u8, 5u8, 0u8, 0u8, 245u8, 5u8, 0u8, 0u8, 32u8, 6u8, 0u8, 0u8,
+                    64u8, 6u8, 0u8, 0u8, 65u8, 6u8, 0u8, 0u8, 86u8, 6u8, 0u8, 0u8, 96u8, 6u8, 0u8,
+                    0u8, 106u8, 6u8, 0u8, 0u8, 112u8, 6u8, 0u8, 0u8, 115u8, 6u8, 0u8, 0u8, 116u8,
+                    6u8, 0u8, 0u8, 117u8, 6u8, 0u8, 0u8, 121u8, 6u8, 0u8, 0u8, 142u8, 6u8, 0u8,
+                    0u8, 143u8, 6u8, 0u8, 0u8, 161u8, 6u8, 0u8, 0u8, 162u8, 6u8, 0u8, 0u8, 212u8,
+                    6u8, 0u8, 0u8, 213u8, 6u8, 0u8, 0u8, 214u8, 6u8, 0u8, 0u8, 229u8, 6u8, 0u8,
+                    0u8, 231u8, 6u8, 0u8, 0u8, 238u8, 6u8, 0u8, 0u8, 0u8, 7u8, 0u8, 0u8, 80u8, 7u8,
+                    0u8, 0u8, 178u8, 7u8, 0u8, 0u8, 112u8, 8u8, 0u8, 0u8, 136u8, 8u8, 0u8, 0u8,
+                    137u8, 8u8, 0u8, 0u8, 143u8, 8u8, 0u8, 0u8, 160u8, 8u8, 0u8, 0u8, 173u8, 8u8,
+                    0u8, 0u8, 178u8, 8u8, 0u8, 0u8, 179u8, 8u8, 0u8, 0u8, 181u8, 8u8, 0u8, 0u8,
+                    202u8, 8u8, 0u8, 0u8, 1u8, 9u8, 0u8, 0u8, 78u8, 9u8, 0u8, 0u8, 79u8, 9u8, 0u8,
+                    0u8, 81u8, 9u8, 0u8, 0u8, 86u8, 9u8, 0u8, 0u8, 88u8, 9u8, 0u8, 0u8, 96u8, 9u8,
+                    0u8, 0u8, 100u8, 9u8, 0u8, 0u8, 102u8, 9u8, 0u8, 0u8, 112u8, 9u8, 0u8, 0u8,
+                    113u8, 9u8, 0u8, 0u8, 120u8, 9u8, 0u8, 0u8, 121u8, 9u8, 0u8, 0u8, 128u8, 9u8,
+                    0u8, 0u8, 129u8, 9u8, 0u8, 0u8, 132u8, 9u8, 0u8, 0u8, 133u8, 9u8, 0u8, 0u8,
+                    141u8, 9u8, 0u8, 0u8, 143u8, 9u8, 0u8, 0u8, 145u8, 9u8, 0u8, 0u8, 147u8, 9u8,
+                    0u8, 0u8, 169u8, 9u8, 0u8, 0u8, 170u8, 9u8, 0u8, 0u8, 177u8, 9u8, 0u8, 0u8,
+                    178u8, 9u8, 0u8, 0u8, 179u8, 9u8, 0u8, 0u8, 182u8, 9u8, 0u8, 0u8, 186u8, 9u8,
+                    0u8, 0u8, 188u8, 9u8, 0u8, 0u8, 197u8, 9u8, 0u8, 0u8, 199u8, 9u8, 0u8, 0u8,
+                    201u8, 9u8, 0u8, 0u8, 203u8, 9u8, 0u8, 0u8, 207u8, 9u8, 0u8, 0u8, 215u8, 9u8,
+                    0u8, 0u8, 216u8, 9u8, 0u8, 0u8, 224u8, 9u8, 0u8, 0u8, 228u8, 9u8, 0u8, 0u8,
+                    230u8, 9u8, 0u8, 0u8, 242u8, 9u8, 0u8, 0u8, 254u8, 9u8, 0u8, 0u8, 255u8, 9u8,
+                    0u8, 0u8, 1u8, 10u8, 0u8, 0u8, 4u8, 10u8, 0u8, 0u8, 5u8, 10u8, 0u8, 0u8, 11u8,
+                    10u8, 0u8, 0u8, 15u8, 10u8, 0u8, 0u8, 17u8, 10u8, 0u8, 0u8, 19u8, 10u8, 0u8,
+                    0u8, 41u8, 10u8, 0u8, 0u8, 42u8, 10u8, 0u8, 0u8, 49u8, 10u8, 0u8, 0u8, 50u8,
+                    10u8, 0u8, 0u8, 51u8, 10u8, 0u8, 0u8, 53u8, 10u8, 0u8, 0u8, 54u8, 10u8, 0u8,
+                    0u8, 56u8, 10u8, 0u8, 0u8, 58u8, 10u8, 0u8, 0u8, 60u8, 10u8, 0u8, 0u8, 61u8,
+                    10u8, 0u8, 0u8, 62u8, 10u8, 0u8, 0u8, 67u8, 10u8, 0u8, 0u8, 71u8, 10u8, 0u8,
+                    0u8, 73u8, 10u8, 0u8, 0u8, 75u8, 10u8, 0u8, 0u8, 78u8, 10u8, 0u8, 0u8, 92u8,
+                    10u8, 0u8, 0u8, 93u8, 10u8, 0u8, 0u8, 102u8, 10u8, 0u8, 0u8, 117u8, 10u8, 0u8,
+                    0u8, 129u8, 10u8, 0u8, 0u8, 132u8, 10u8, 0u8, 0u8, 133u8, 10u8, 0u8, 0u8,
+                    142u8, 10u8, 0u8, 0u8, 143u8, 10u8, 0u8, 0u8, 146u8, 10u8, 0u8, 0u8, 147u8,
+                    10u8, 0u8, 0u8, 169u8, 10u8, 0u8, 0u8, 170u8, 10u8, 0u8, 0u8, 177u8, 10u8, 0u8,
+                    0u8, 178u8, 10u8, 0u8, 0u8, 180u8, 10u8, 0u8, 0u8, 181u8, 10u8, 0u8, 0u8,
+                    186u8, 10u8, 0u8, 0u8, 188u8, 10u8, 0u8, 0u8, 198u8, 10u8, 0u8, 0u8, 199u8,
+                    10u8, 0u8, 0u8, 202u8, 10u8, 0u8, 0u8, 203u8, 10u8, 0u8, 0u8, 206u8, 10u8, 0u8,
+                    0u8, 208u8, 10u8, 0u8, 0u8, 209u8, 10u8, 0u8, 0u8, 224u8, 10u8, 0u8, 0u8,
+                    228u8, 10u8, 0u8, 0u8, 230u8, 10u8, 0u8, 0u8, 240u8, 10u8, 0u8, 0u8, 250u8,
+                    10u8, 0u8, 0u8, 0u8, 11u8, 0u8, 0u8, 1u8, 11u8, 0u8, 0u8, 4u8, 11u8, 0u8, 0u8,
+                    5u8, 11u8, 0u8, 0u8, 13u8, 11u8, 0u8, 0u8, 15u8, 11u8, 0u8, 0u8, 17u8, 11u8,
+                    0u8, 0u8, 19u8, 11u8, 0u8, 0u8, 41u8, 11u8, 0u8, 0u8, 42u8, 11u8, 0u8, 0u8,
+                    49u8, 11u8, 0u8, 0u8, 50u8, 11u8, 0u8, 0u8, 52u8, 11u8, 0u8, 0u8, 53u8, 11u8,
+                    0u8, 0u8, 58u8, 11u8, 0u8, 0u8, 60u8, 11u8, 0u8, 0u8, 68u8, 11u8, 0u8, 0u8,
+                    71u8, 11u8, 0u8, 0u8, 73u8, 11u8, 0u8, 0u8, 75u8, 11u8, 0u8, 0u8, 78u8, 11u8,
+                    0u8, 0u8, 85u8, 11u8, 0u8, 0u8, 88u8, 11u8, 0u8, 0u8, 95u8, 11u8, 0u8, 0u8,
+                    98u8, 11u8, 0u8, 0u8, 102u8, 11u8, 0u8, 0u8, 112u8, 11u8, 0u8, 0u8, 113u8,
+                    11u8, 0u8, 0u8, 114u8, 11u8, 0u8, 0u8, 130u8, 11u8, 0u8, 0u8, 132u8, 11u8, 0u8,
+                    0u8, 133u8, 11u8, 0u8, 0u8, 139u8, 11u8, 0u8, 0u8, 142u8, 11u8, 0u8, 0u8,
+                    145u8, 11u8, 0u8, 0u8, 146u8, 11u8, 0u8, 0u8, 150u8, 11u8, 0u8, 0u8, 153u8,
+                    11u8, 0u8, 0u8, 155u8, 11u8, 0u8, 0u8, 156u8, 11u8, 0u8, 0u8, 157u8, 11u8, 0u8,
+                    0u8, 158u8, 11u8, 0u8, 0u8, 160u8, 11u8, 0u8, 0u8, 163u8, 11u8, 0u8, 0u8,
+                    165u8, 11u8, 0u8, 0u8, 168u8, 11u8, 0u8, 0u8, 171u8, 11u8, 0u8, 0u8, 174u8,
+                    11u8, 0u8, 0u8, 186u8, 11u8, 0u8, 0u8, 190u8, 11u8, 0u8, 0u8, 195u8, 11u8, 0u8,
+                    0u8, 198u8, 11u8, 0u8, 0u8, 201u8, 11u8, 0u8, 0u8, 202u8, 11u8, 0u8, 0u8,
+                    206u8, 11u8, 0u8, 0u8, 208u8, 11u8, 0u8, 0u8, 209u8, 11u8, 0u8, 0u8, 215u8,
+                    11u8, 0u8, 0u8, 216u8, 11u8, 0u8, 0u8, 230u8, 11u8, 0u8, 0u8, 240u8, 11u8, 0u8,
+                    0u8, 1u8, 12u8, 0u8, 0u8, 13u8, 12u8, 0u8, 0u8, 14u8, 12u8, 0u8, 0u8, 17u8,
+                    12u8, 0u8, 0u8, 18u8, 12u8, 0u8, 0u8, 41u8, 12u8, 0u8, 0u8, 42u8, 12u8, 0u8,
+                    0u8, 52u8, 12u8, 0u8, 0u8, 53u8, 12u8, 0u8, 0u8, 58u8, 12u8, 0u8, 0u8, 60u8,
+                    12u8, 0u8, 0u8, 69u8, 12u8, 0u8, 0u8, 70u8, 12u8, 0u8, 0u8, 73u8, 12u8, 0u8,
+                    0u8, 74u8, 12u8, 0u8, 0u8, 78u8, 12u8, 0u8, 0u8, 85u8, 12u8, 0u8, 0u8, 87u8,
+                    12u8, 0u8, 0u8, 93u8, 12u8, 0u8, 0u8, 94u8, 12u8, 0u8, 0u8, 96u8, 12u8, 0u8,
+                    0u8, 98u8, 12u8, 0u8, 0u8, 102u8, 12u8, 0u8, 0u8, 112u8, 12u8, 0u8, 0u8, 128u8,
+                    12u8, 0u8, 0u8, 129u8, 12u8, 0u8, 0u8, 130u8, 12u8, 0u8, 0u8, 132u8, 12u8, 0u8,
+                    0u8, 133u8, 12u8, 0u8, 0u8, 141u8, 12u8, 0u8, 0u8, 142u8, 12u8, 0u8, 0u8,
+                    145u8, 12u8, 0u8, 0u8, 146u8, 12u8, 0u8, 0u8, 169u8, 12u8, 0u8, 0u8, 170u8,
+                    12u8, 0u8, 0u8, 180u8, 12u8, 0u8, 0u8, 181u8, 12u8, 0u8, 0u8, 186u8, 12u8, 0u8,
+                    0u8, 188u8, 12u8, 0u8, 0u8, 197u8, 12u8, 0u8, 0u8, 198u8, 12u8, 0u8, 0u8,
+                    201u8, 12u8, 0u8, 0u8, 202u8, 12u8, 0u8, 0u8, 206u8, 12u8, 0u8, 0u8, 213u8,
+                    12u8, 0u8, 0u8, 215u8, 12u8, 0u8, 0u8, 221u8, 12u8, 0u8, 0u8, 222u8, 12u8, 0u8,
+                    0u8, 224u8, 12u8, 0u8, 0u8, 228u8, 12u8, 0u8, 0u8, 230u8, 12u8, 0u8, 0u8,
+                    240u8, 12u8, 0u8, 0u8, 241u8, 12u8, 0u8, 0u8, 243u8, 12u8, 0u8, 0u8, 0u8, 13u8,
+                    0u8, 0u8, 1u8, 13u8, 0u8, 0u8, 2u8, 13u8, 0u8, 0u8, 4u8, 13u8, 0u8, 0u8, 5u8,
+                    13u8, 0u8, 0u8, 13u8, 13u8, 0u8, 0u8, 14u8, 13u8, 0u8, 0u8, 17u8, 13u8, 0u8,
+                    0u8, 18u8, 13u8, 0u8, 0u8, 59u8, 13u8, 0u8, 0u8, 61u8, 13u8, 0u8, 0u8, 68u8,
+                    13u8, 0u8, 0u8, 70u8, 13u8, 0u8, 0u8, 73u8, 13u8, 0u8, 0u8, 74u8, 13u8, 0u8,
+                    0u8, 79u8, 13u8, 0u8, 0u8, 84u8, 13u8, 0u8, 0u8, 88u8, 13u8, 0u8, 0u8, 96u8,
+                    13u8, 0u8, 0u8, 98u8, 13u8, 0u8, 0u8, 102u8, 13u8, 0u8, 0u8, 112u8, 13u8, 0u8,
+                    0u8, 122u8, 13u8, 0u8, 0u8, 128u8, 13u8, 0u8, 0u8, 130u8, 13u8, 0u8, 0u8,
+                    132u8, 13u8, 0u8, 0u8, 133u8, 13u8, 0u8, 0u8, 143u8, 13u8, 0u8, 0u8, 145u8,
+                    13u8, 0u8, 0u8, 151u8, 13u8, 0u8, 0u8, 154u8, 13u8, 0u8, 0u8, 166u8, 13u8, 0u8,
+                    0u8, 167u8, 13u8, 0u8, 0u8, 178u8, 13u8, 0u8, 0u8, 179u8, 13u8, 0u8, 0u8,
+                    188u8, 13u8, 0u8, 0u8, 189u8, 13u8, 0u8, 0u8, 190u8, 13u8, 0u8, 0u8, 192u8,
+                    13u8, 0u8, 0u8, 199u8, 13u8, 0u8, 0u8, 202u8, 13u8, 0u8, 0u8, 203u8, 13u8, 0u8,
+                    0u8, 207u8, 13u8, 0u8, 0u8, 213u8, 13u8, 0u8, 0u8, 214u8, 13u8, 0u8, 0u8,
+                    215u8, 13u8, 0u8, 0u8, 216u8, 13u8, 0u8, 0u8, 223u8, 13u8, 0u8, 0u8, 242u8,
+                    13u8, 0u8, 0u8, 243u8, 13u8, 0u8, 0u8, 1u8, 14u8, 0u8, 0u8, 51u8, 14u8, 0u8,
+                    0u8, 52u8, 14u8, 0u8, 0u8, 59u8, 14u8, 0u8, 0u8, 64u8, 14u8, 0u8, 0u8, 79u8,
+                    14u8, 0u8, 0u8, 80u8, 14u8, 0u8, 0u8, 90u8, 14u8, 0u8, 0u8, 129u8, 14u8, 0u8,
+                    0u8, 131u8, 14u8, 0u8, 0u8, 132u8, 14u8, 0u8, 0u8, 133u8, 14u8, 0u8, 0u8,
+                    134u8, 14u8, 0u8, 0u8, 139u8, 14u8, 0u8, 0u8, 140u8, 14u8, 0u8, 0u8, 164u8,
+                    14u8, 0u8, 0u8, 165u8, 14u8, 0u8, 0u8, 166u8, 14u8, 0u8, 0u8, 167u8, 14u8, 0u8,
+                    0u8, 179u8, 14u8, 0u8, 0u8, 180u8, 14u8, 0u8, 0u8, 190u8, 14u8, 0u8, 0u8,
+                    192u8, 14u8, 0u8, 0u8, 197u8, 14u8, 0u8, 0u8, 198u8, 14u8, 0u8, 0u8, 199u8,
+                    14u8, 0u8, 0u8, 200u8, 14u8, 0u8, 0u8, 206u8, 14u8, 0u8, 0u8, 208u8, 14u8, 0u8,
+                    0u8, 218u8, 14u8, 0u8, 0u8, 222u8, 14u8, 0u8, 0u8, 224u8, 14u8, 0u8, 0u8, 0u8,
+                    15u8, 0u8, 0u8, 1u8, 15u8, 0u8, 0u8, 11u8, 15u8, 0u8, 0u8, 12u8, 15u8, 0u8,
+                    0u8, 32u8, 15u8, 0u8, 0u8, 42u8, 15u8, 0u8, 0u8, 53u8, 15u8, 0u8, 0u8, 54u8,
+                    15u8, 0u8, 0u8, 55u8, 15u8, 0u8, 0u8, 56u8, 15u8, 0u8, 0u8, 62u8, 15u8, 0u8,
+                    0u8, 67u8, 15u8, 0u8, 0u8, 68u8, 15u8, 0u8, 0u8, 72u8, 15u8, 0u8, 0u8, 73u8,
+                    15u8, 0u8, 0u8, 77u8, 15u8, 0u8, 0u8, 78u8, 15u8, 0u8, 0u8, 82u8, 15u8, 0u8,
+                    0u8, 83u8, 15u8, 0u8, 0u8, 87u8, 15u8, 0u8, 0u8, 88u8, 15u8, 0u8, 0u8, 92u8,
+                    15u8, 0u8, 0u8, 93u8, 15u8, 0u8, 0u8, 105u8, 15u8, 0u8, 0u8, 106u8, 15u8, 0u8,
+                    0u8, 109u8, 15u8, 0u8, 0u8, 113u8, 15u8, 0u8, 0u8, 115u8, 15u8, 0u8, 0u8,
+                    116u8, 15u8, 0u8, 0u8, 117u8, 15u8, 0u8, 0u8, 122u8, 15u8, 0u8, 0u8, 129u8,
+                    15u8, 0u8, 0u8, 130u8, 15u8, 0u8, 0u8, 133u8, 15u8, 0u8, 0u8, 134u8, 15u8, 0u8,
+                    0u8, 147u8, 15u8, 0u8, 0u8, 148u8, 15u8, 0u8, 0u8, 152u8, 15u8, 0u8, 0u8,
+                    153u8, 15u8, 0u8, 0u8, 157u8, 15u8, 0u8, 0u8, 158u8, 15u8, 0u8, 0u8, 162u8,
+                    15u8, 0u8, 0u8, 163u8, 15u8, 0u8, 0u8, 167u8, 15u8, 0u8, 0u8, 168u8, 15u8, 0u8,
+                    0u8, 172u8, 15u8, 0u8, 0u8, 173u8, 15u8, 0u8, 0u8, 185u8, 15u8, 0u8, 0u8,
+                    186u8, 15u8, 0u8, 0u8, 189u8, 15u8, 0u8, 0u8, 198u8, 15u8, 0u8, 0u8, 199u8,
+                    15u8, 0u8, 0u8, 0u8, 16u8, 0u8, 0u8, 74u8, 16u8, 0u8, 0u8, 80u8, 16u8, 0u8,
+                    0u8, 158u8, 16u8, 0u8, 0u8, 199u8, 16u8, 0u8, 0u8, 200u8, 16u8, 0u8, 0u8,
+                    205u8, 16u8, 0u8, 0u8, 206u8, 16u8, 0u8, 0u8, 208u8, 16u8, 0u8, 0u8, 241u8,
+                    16u8, 0u8, 0u8, 247u8, 16u8, 0u8, 0u8, 251u8, 16u8, 0u8, 0u8, 253u8, 16u8, 0u8,
+                    0u8, 0u8, 17u8, 0u8, 0u8, 0u8, 18u8, 0u8, 0u8, 73u8, 18u8, 0u8, 0u8, 74u8,
+                    18u8, 0u8, 0u8, 78u8, 18u8, 0u8, 0u8, 80u8, 18u8, 0u8, 0u8, 87u8, 18u8, 0u8,
+                    0u8, 88u8, 18u8, 0u8, 0u8, 89u8, 18u8, 0u8, 0u8, 90u8, 18u8, 0u8, 0u8, 94u8,
+                    18u8, 0u8, 0u8, 96u8, 18u8, 0u8, 0u8, 137u8, 18u8, 0u8, 0u8, 138u8, 18u8, 0u8,
+                    0u8, 142u8, 18u8, 0u8, 0u8, 144u8, 18u8, 0u8, 0u8, 177u8, 18u8, 0u8, 0u8,
+                    178u8, 18u8, 0u8, 0u8, 182u8, 18u8, 0u8, 0u8, 184u8, 18u8, 0u8, 0u8, 191u8,
+                    18u8, 0u8, 0u8, 192u8, 18u8, 0u8, 0u8, 193u8, 18u8, 0u8, 0u8, 194u8, 18u8, 0u8,
+                    0u8, 198u8, 18u8, 0u8, 0u8, 200u8, 18u8, 0u8, 0u8, 215u8, 18u8, 0u8, 0u8,
+                    216u8, 18u8, 0u8, 0u8, 17u8, 19u8, 0u8, 0u8, 18u8, 19u8, 0u8, 0u8, 22u8, 19u8,
+                    0u8, 0u8, 24u8, 19u8, 0u8, 0u8, 91u8, 19u8, 0u8, 0u8, 93u8, 19u8, 0u8, 0u8,
+                    96u8, 19u8, 0u8, 0u8, 128u8, 19u8, 0u8, 0u8, 144u8, 19u8, 0u8, 0u8, 128u8,
+                    23u8, 0u8, 0u8, 163u8, 23u8, 0u8, 0u8, 165u8, 23u8, 0u8, 0u8, 168u8, 23u8, 0u8,
+                    0u8, 169u8, 23u8, 0u8, 0u8, 180u8, 23u8, 0u8, 0u8, 182u8, 23u8, 0u8, 0u8,
+                    206u8, 23u8, 0u8, 0u8, 208u8, 23u8, 0u8, 0u8, 209u8, 23u8, 0u8, 0u8, 210u8,
+                    23u8, 0u8, 0u8, 211u8, 23u8, 0u8, 0u8, 215u8, 23u8, 0u8, 0u8, 216u8, 23u8, 0u8,
+                    0u8, 220u8, 23u8, 0u8, 0u8, 221u8, 23u8, 0u8, 0u8, 224u8, 23u8, 0u8, 0u8,
+                    234u8, 23u8, 0u8, 0u8, 144u8, 28u8, 0u8, 0u8, 187u8, 28u8, 0u8, 0u8, 189u8,
+                    28u8, 0u8, 0u8, 192u8, 28u8, 0u8, 0u8, 0u8, 30u8, 0u8, 0u8, 154u8, 30u8, 0u8,
+                    0u8, 158u8, 30u8, 0u8, 0u8, 159u8, 30u8, 0u8, 0u8, 160u8, 30u8, 0u8, 0u8,
+                    250u8, 30u8, 0u8, 0u8, 0u8, 31u8, 0u8, 0u8, 22u8, 31u8, 0u8, 0u8, 24u8, 31u8,
+                    0u8, 0u8, 30u8, 31u8, 0u8, 0u8, 32u8, 31u8, 0u8, 0u8, 70u8, 31u8, 0u8, 0u8,
+                    72u8, 31u8, 0u8, 0u8, 78u8, 31u8, 0u8, 0u8, 80u8, 31u8, 0u8, 0u8, 88u8, 31u8,
+                    0u8, 0u8, 89u8, 31u8, 0u8, 0u8, 90u8, 31u8, 0u8, 0u8, 91u8, 31u8, 0u8, 0u8,
+                    92u8, 31u8, 0u8, 0u8, 93u8, 31u8, 0u8, 0u8, 94u8, 31u8, 0u8, 0u8, 95u8, 31u8,
+                    0u8, 0u8, 113u8, 31u8, 0u8, 0u8, 114u8, 31u8, 0u8, 0u8, 115u8, 31u8, 0u8, 0u8,
+                    116u8, 31u8, 0u8, 0u8, 117u8, 31u8, 0u8, 0u8, 118u8, 31u8, 0u8, 0u8, 119u8,
+                    31u8, 0u8, 0u8, 120u8, 31u8, 0u8, 0u8, 121u8, 31u8, 0u8, 0u8, 122u8, 31u8, 0u8,
+                    0u8, 123u8, 31u8, 0u8, 0u8, 124u8, 31u8, 0u8, 0u8, 125u8, 31u8, 0u8, 0u8,
+                    128u8, 31u8, 0u8, 0u8, 181u8, 31u8, 0u8, 0u8, 182u8, 31u8, 0u8, 0u8, 187u8,
+                    31u8, 0u8, 0u8, 188u8, 31u8, 0u8, 0u8, 189u8, 31u8, 0u8, 0u8, 194u8, 31u8, 0u8,
+                    0u8, 197u8, 31u8, 0u8, 0u8, 198u8, 31u8, 0u8, 0u8, 201u8, 31u8, 0u8, 0u8,
+                    202u8, 31u8, 0u8, 0u8, 203u8, 31u8, 0u8, 0u8, 204u8, 31u8, 0u8, 0u8, 205u8,
+                    31u8, 0u8, 0u8, 208u8, 31u8, 0u8, 0u8, 211u8, 31u8, 0u8, 0u8, 214u8, 31u8, 0u8,
+                    0u8, 219u8, 31u8, 0u8, 0u8, 224u8, 31u8, 0u8, 0u8, 227u8, 31u8, 0u8, 0u8,
+                    228u8, 31u8, 0u8, 0u8, 235u8, 31u8, 0u8, 0u8, 236u8, 31u8, 0u8, 0u8, 237u8,
+                    31u8, 0u8, 0u8, 242u8, 31u8, 0u8, 0u8, 245u8, 31u8, 0u8, 0u8, 246u8, 31u8, 0u8,
+                    0u8, 249u8, 31u8, 0u8, 0u8, 250u8, 31u8, 0u8, 0u8, 251u8, 31u8, 0u8, 0u8,
+                    252u8, 31u8, 0u8, 0u8, 253u8, 31u8, 0u8, 0u8, 12u8, 32u8, 0u8, 0u8, 14u8, 32u8,
+                    0u8, 0u8, 16u8, 32u8, 0u8, 0u8, 17u8, 32u8, 0u8, 0u8, 25u8, 32u8, 0u8, 0u8,
+                    26u8, 32u8, 0u8, 0u8, 39u8, 32u8, 0u8, 0u8, 40u8, 32u8, 0u8, 0u8, 39u8, 45u8,
+                    0u8, 0u8, 40u8, 45u8, 0u8, 0u8, 45u8, 45u8, 0u8, 0u8, 46u8, 45u8, 0u8, 0u8,
+                    128u8, 45u8, 0u8, 0u8, 151u8, 45u8, 0u8, 0u8, 160u8, 45u8, 0u8, 0u8, 167u8,
+                    45u8, 0u8, 0u8, 168u8, 45u8, 0u8, 0u8, 175u8, 45u8, 0u8, 0u8, 176u8, 45u8, 0u8,
+                    0u8, 183u8, 45u8, 0u8, 0u8, 184u8, 45u8, 0u8, 0u8, 191u8, 45u8, 0u8, 0u8,
+                    192u8, 45u8, 0u8, 0u8, 199u8, 45u8, 0u8, 0u8, 200u8, 45u8, 0u8, 0u8, 207u8,
+                    45u8, 0u8, 0u8, 208u8, 45u8, 0u8, 0u8, 215u8, 45u8, 0u8, 0u8, 216u8, 45u8, 0u8,
+                    0u8, 223u8, 45u8, 0u8, 0u8, 5u8, 48u8, 0u8, 0u8, 8u8, 48u8, 0u8, 0u8, 65u8,
+                    48u8, 0u8, 0u8, 151u8, 48u8, 0u8, 0u8, 153u8, 48u8, 0u8, 0u8, 155u8, 48u8, 0u8,
+                    0u8, 157u8, 48u8, 0u8, 0u8, 159u8, 48u8, 0u8, 0u8, 160u8, 48u8, 0u8, 0u8,
+                    255u8, 48u8, 0u8, 0u8, 5u8, 49u8, 0u8, 0u8, 46u8, 49u8, 0u8, 0u8, 47u8, 49u8,
+                    0u8, 0u8, 48u8, 49u8, 0u8, 0u8, 160u8, 49u8, 0u8, 0u8, 192u8, 49u8, 0u8, 0u8,
+                    0u8, 52u8, 0u8, 0u8, 192u8, 77u8, 0u8, 0u8, 0u8, 78u8, 0u8, 0u8, 0u8, 160u8,
+                    0u8, 0u8, 127u8, 166u8, 0u8, 0u8, 128u8, 166u8, 0u8, 0u8, 23u8, 167u8, 0u8,
+                    0u8, 32u8, 167u8, 0u8, 0u8, 136u8, 167u8, 0u8, 0u8, 137u8, 167u8, 0u8, 0u8,
+                    141u8, 167u8, 0u8, 0u8, 142u8, 167u8, 0u8, 0u8, 146u8, 167u8, 0u8, 0u8, 148u8,
+                    167u8, 0u8, 0u8, 170u8, 167u8, 0u8, 0u8, 171u8, 167u8, 0u8, 0u8, 174u8, 167u8,
+                    0u8, 0u8, 175u8, 167u8, 0u8, 0u8, 184u8, 167u8, 0u8, 0u8, 186u8, 167u8, 0u8,
+                    0u8, 192u8, 167u8, 0u8, 0u8, 203u8, 167u8, 0u8, 0u8, 208u8, 167u8, 0u8, 0u8,
+                    210u8, 167u8, 0u8, 0u8, 211u8, 167u8, 0u8, 0u8, 212u8, 167u8, 0u8, 0u8, 213u8,
+                    167u8, 0u8, 0u8, 218u8, 167u8, 0u8, 0u8, 231u8, 169u8, 0u8, 0u8, 255u8, 169u8,
+                    0u8, 0u8, 96u8, 170u8, 0u8, 0u8, 119u8, 170u8, 0u8, 0u8, 122u8, 170u8, 0u8,
+                    0u8, 128u8, 170u8, 0u8, 0u8, 1u8, 171u8, 0u8, 0u8, 7u8, 171u8, 0u8, 0u8, 9u8,
+                    171u8, 0u8, 0u8, 15u8, 171u8, 0u8, 0u8, 17u8, 171u8, 0u8, 0u8, 23u8, 171u8,
+                    0u8, 0u8, 32u8, 171u8, 0u8, 0u8, 39u8, 171u8, 0u8, 0u8, 40u8, 171u8, 0u8, 0u8,
+                    47u8, 171u8, 0u8, 0u8, 102u8, 171u8, 0u8, 0u8, 104u8, 171u8, 0u8, 0u8, 0u8,
+                    172u8, 0u8, 0u8, 164u8, 215u8, 0u8, 0u8, 14u8, 250u8, 0u8, 0u8, 16u8, 250u8,
+                    0u8, 0u8, 17u8, 250u8, 0u8, 0u8, 18u8, 250u8, 0u8, 0u8, 19u8, 250u8, 0u8, 0u8,
+                    21u8, 250u8, 0u8, 0u8, 31u8, 250u8, 0u8, 0u8, 32u8, 250u8, 0u8, 0u8, 33u8,
+                    250u8, 0u8, 0u8, 34u8, 250u8, 0u8, 0u8, 35u8, 250u8, 0u8, 0u8, 37u8, 250u8,
+                    0u8, 0u8, 39u8, 250u8, 0u8, 0u8, 42u8, 250u8, 0u8, 0u8, 1u8, 19u8, 1u8, 0u8,
+                    2u8, 19u8, 1u8, 0u8, 3u8, 19u8, 1u8, 0u8, 4u8, 19u8, 1u8, 0u8, 59u8, 19u8, 1u8,
+                    0u8, 61u8, 19u8, 1u8, 0u8, 240u8, 111u8, 1u8, 0u8, 242u8, 111u8, 1u8, 0u8,
+                    31u8, 177u8, 1u8, 0u8, 35u8, 177u8, 1u8, 0u8, 80u8, 177u8, 1u8, 0u8, 83u8,
+                    177u8, 1u8, 0u8, 100u8, 177u8, 1u8, 0u8, 104u8, 177u8, 1u8, 0u8, 0u8, 223u8,
+                    1u8, 0u8, 31u8, 223u8, 1u8, 0u8, 224u8, 231u8, 1u8, 0u8, 231u8, 231u8, 1u8,
+                    0u8, 232u8, 231u8, 1u8, 0u8, 236u8, 231u8, 1u8, 0u8, 237u8, 231u8, 1u8, 0u8,
+                    239u8, 231u8, 1u8, 0u8, 240u8, 231u8, 1u8, 0u8, 255u8, 231u8, 1u8, 0u8, 0u8,
+                    0u8, 2u8, 0u8, 224u8, 166u8, 2u8, 0u8, 0u8, 167u8, 2u8, 0u8, 57u8, 183u8, 2u8,
+                    0u8, 64u8, 183u8, 2u8, 0u8, 30u8, 184u8, 2u8, 0u8, 32u8, 184u8, 2u8, 0u8,
+                    162u8, 206u8, 2u8, 0u8, 176u8, 206u8, 2u8, 0u8, 225u8, 235u8, 2u8, 0u8, 0u8,
+                    0u8, 3u8, 0u8, 75u8, 19u8, 3u8, 0u8,
+                ])
+            },
+            107957usize,
+        )
+    },
+};
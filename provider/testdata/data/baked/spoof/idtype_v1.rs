@@ -0,0 +1,1588 @@
+// @generated
+#![cfg(feature = "icu_spoof")]
+type DataStruct =
+    <::icu_spoof::provider::IdentifierTypeV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("und", UND)]);
+static UND: &DataStruct = &::icu_spoof::provider::IdentifierTypeV1 {
+    trie: ::icu_collections::codepointtrie::CodePointTrie::from_parts(
+        ::icu_collections::codepointtrie::CodePointTrieHeader {
+            high_start: 918016u32,
+            shifted12_high_start: 225u16,
+            index3_null_offset: 1147u16,
+            data_null_offset: 14u32,
+            null_value: 1u32,
+            trie_type: ::icu_collections::codepointtrie::TrieType::Small,
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                0u8, 0u8, 64u8, 0u8, 127u8, 0u8, 191u8, 0u8, 247u8, 0u8, 54u8, 1u8, 118u8, 1u8,
+                182u8, 1u8, 238u8, 1u8, 37u8, 2u8, 98u8, 2u8, 160u8, 2u8, 224u8, 2u8, 32u8, 3u8,
+                96u8, 3u8, 145u8, 3u8, 205u8, 3u8, 237u8, 3u8, 43u8, 4u8, 205u8, 3u8, 107u8, 4u8,
+                156u8, 4u8, 213u8, 4u8, 19u8, 5u8, 83u8, 5u8, 147u8, 5u8, 204u8, 5u8, 248u8, 5u8,
+                56u8, 6u8, 120u8, 6u8, 136u8, 6u8, 200u8, 6u8, 8u8, 7u8, 72u8, 7u8, 128u8, 7u8,
+                182u8, 7u8, 245u8, 7u8, 39u8, 8u8, 103u8, 8u8, 163u8, 8u8, 226u8, 8u8, 32u8, 9u8,
+                95u8, 9u8, 155u8, 9u8, 219u8, 9u8, 23u8, 10u8, 85u8, 10u8, 147u8, 10u8, 211u8,
+                10u8, 15u8, 11u8, 79u8, 11u8, 139u8, 11u8, 203u8, 11u8, 8u8, 12u8, 72u8, 12u8,
+                136u8, 12u8, 199u8, 12u8, 7u8, 13u8, 70u8, 13u8, 134u8, 13u8, 198u8, 13u8, 4u8,
+                14u8, 67u8, 14u8, 129u8, 14u8, 131u8, 11u8, 157u8, 11u8, 173u8, 11u8, 195u8, 11u8,
+                227u8, 11u8, 1u8, 12u8, 30u8, 12u8, 61u8, 12u8, 93u8, 12u8, 93u8, 12u8, 106u8,
+                12u8, 135u8, 12u8, 167u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 177u8, 12u8,
+                177u8, 12u8, 177u8, 12u8, 177u8, 12u8, 209u8, 12u8, 0u8, 0u8, 16u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 64u8, 0u8, 80u8, 0u8, 96u8, 0u8, 112u8, 0u8, 127u8, 0u8, 143u8, 0u8,
+                159u8, 0u8, 175u8, 0u8, 191u8, 0u8, 207u8, 0u8, 223u8, 0u8, 239u8, 0u8, 247u8, 0u8,
+                7u8, 1u8, 23u8, 1u8, 39u8, 1u8, 54u8, 1u8, 70u8, 1u8, 86u8, 1u8, 102u8, 1u8, 118u8,
+                1u8, 134u8, 1u8, 150u8, 1u8, 166u8, 1u8, 182u8, 1u8, 198u8, 1u8, 214u8, 1u8, 230u8,
+                1u8, 238u8, 1u8, 254u8, 1u8, 14u8, 2u8, 30u8, 2u8, 37u8, 2u8, 53u8, 2u8, 69u8, 2u8,
+                85u8, 2u8, 98u8, 2u8, 114u8, 2u8, 130u8, 2u8, 146u8, 2u8, 160u8, 2u8, 176u8, 2u8,
+                192u8, 2u8, 208u8, 2u8, 224u8, 2u8, 240u8, 2u8, 0u8, 3u8, 16u8, 3u8, 32u8, 3u8,
+                48u8, 3u8, 64u8, 3u8, 80u8, 3u8, 96u8, 3u8, 112u8, 3u8, 128u8, 3u8, 144u8, 3u8,
+                145u8, 3u8, 161u8, 3u8, 177u8, 3u8, 193u8, 3u8, 205u8, 3u8, 221u8, 3u8, 237u8, 3u8,
+                253u8, 3u8, 237u8, 3u8, 253u8, 3u8, 13u8, 4u8, 29u8, 4u8, 43u8, 4u8, 59u8, 4u8,
+                75u8, 4u8, 91u8, 4u8, 205u8, 3u8, 221u8, 3u8, 237u8, 3u8, 253u8, 3u8, 107u8, 4u8,
+                123u8, 4u8, 139u8, 4u8, 155u8, 4u8, 156u8, 4u8, 172u8, 4u8, 188u8, 4u8, 204u8, 4u8,
+                213u8, 4u8, 229u8, 4u8, 245u8, 4u8, 5u8, 5u8, 19u8, 5u8, 35u8, 5u8, 51u8, 5u8,
+                67u8, 5u8, 83u8, 5u8, 99u8, 5u8, 115u8, 5u8, 131u8, 5u8, 147u8, 5u8, 163u8, 5u8,
+                179u8, 5u8, 195u8, 5u8, 204u8, 5u8, 220u8, 5u8, 236u8, 5u8, 252u8, 5u8, 248u8, 5u8,
+                8u8, 6u8, 24u8, 6u8, 40u8, 6u8, 56u8, 6u8, 72u8, 6u8, 88u8, 6u8, 104u8, 6u8, 120u8,
+                6u8, 136u8, 6u8, 152u8, 6u8, 168u8, 6u8, 136u8, 6u8, 152u8, 6u8, 168u8, 6u8, 184u8,
+                6u8, 200u8, 6u8, 216u8, 6u8, 232u8, 6u8, 248u8, 6u8, 8u8, 7u8, 24u8, 7u8, 40u8,
+                7u8, 56u8, 7u8, 72u8, 7u8, 88u8, 7u8, 104u8, 7u8, 120u8, 7u8, 128u8, 7u8, 144u8,
+                7u8, 160u8, 7u8, 176u8, 7u8, 182u8, 7u8, 198u8, 7u8, 214u8, 7u8, 230u8, 7u8, 245u8,
+                7u8, 5u8, 8u8, 21u8, 8u8, 37u8, 8u8, 39u8, 8u8, 55u8, 8u8, 71u8, 8u8, 87u8, 8u8,
+                103u8, 8u8, 119u8, 8u8, 135u8, 8u8, 151u8, 8u8, 163u8, 8u8, 179u8, 8u8, 195u8, 8u8,
+                211u8, 8u8, 226u8, 8u8, 242u8, 8u8, 2u8, 9u8, 18u8, 9u8, 32u8, 9u8, 48u8, 9u8,
+                64u8, 9u8, 80u8, 9u8, 95u8, 9u8, 111u8, 9u8, 127u8, 9u8, 143u8, 9u8, 155u8, 9u8,
+                171u8, 9u8, 187u8, 9u8, 203u8, 9u8, 219u8, 9u8, 235u8, 9u8, 251u8, 9u8, 11u8, 10u8,
+                23u8, 10u8, 39u8, 10u8, 55u8, 10u8, 71u8, 10u8, 85u8, 10u8, 101u8, 10u8, 117u8,
+                10u8, 133u8, 10u8, 147u8, 10u8, 163u8, 10u8, 179u8, 10u8, 195u8, 10u8, 211u8, 10u8,
+                227u8, 10u8, 243u8, 10u8, 3u8, 11u8, 15u8, 11u8, 31u8, 11u8, 47u8, 11u8, 63u8,
+                11u8, 79u8, 11u8, 95u8, 11u8, 111u8, 11u8, 127u8, 11u8, 139u8, 11u8, 155u8, 11u8,
+                171u8, 11u8, 187u8, 11u8, 203u8, 11u8, 219u8, 11u8, 235u8, 11u8, 251u8, 11u8, 8u8,
+                12u8, 24u8, 12u8, 40u8, 12u8, 56u8, 12u8, 72u8, 12u8, 88u8, 12u8, 104u8, 12u8,
+                120u8, 12u8, 136u8, 12u8, 152u8, 12u8, 168u8, 12u8, 184u8, 12u8, 199u8, 12u8,
+                215u8, 12u8, 231u8, 12u8, 247u8, 12u8, 7u8, 13u8, 23u8, 13u8, 39u8, 13u8, 55u8,
+                13u8, 70u8, 13u8, 86u8, 13u8, 102u8, 13u8, 118u8, 13u8, 134u8, 13u8, 150u8, 13u8,
+                166u8, 13u8, 182u8, 13u8, 198u8, 13u8, 214u8, 13u8, 230u8, 13u8, 246u8, 13u8, 4u8,
+                14u8, 20u8, 14u8, 36u8, 14u8, 52u8, 14u8, 67u8, 14u8, 83u8, 14u8, 99u8, 14u8,
+                115u8, 14u8, 129u8, 14u8, 145u8, 14u8, 161u8, 14u8, 177u8, 14u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 203u8, 8u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 77u8, 0u8, 67u8, 3u8, 67u8, 3u8, 193u8, 14u8, 205u8, 3u8, 205u8, 3u8, 209u8,
+                14u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 225u8, 14u8, 240u8,
+                14u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8,
+                67u8, 3u8, 67u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 0u8, 15u8,
+                16u8, 15u8, 205u8, 3u8, 205u8, 3u8, 0u8, 15u8, 205u8, 3u8, 205u8, 3u8, 8u8, 15u8,
+                24u8, 15u8, 123u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 24u8, 15u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 32u8, 15u8, 48u8, 15u8, 62u8, 15u8, 205u8, 3u8, 146u8,
+                14u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 78u8, 15u8, 71u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 94u8, 15u8, 72u8, 6u8, 110u8, 15u8, 115u8, 15u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 131u8, 15u8, 145u8, 15u8, 8u8, 7u8, 148u8, 15u8, 8u8, 7u8,
+                163u8, 15u8, 8u8, 7u8, 179u8, 15u8, 195u8, 15u8, 207u8, 15u8, 205u8, 3u8, 205u8,
+                3u8, 223u8, 15u8, 235u8, 15u8, 20u8, 2u8, 250u8, 15u8, 176u8, 6u8, 146u8, 14u8,
+                10u8, 16u8, 26u8, 16u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 145u8,
+                15u8, 8u8, 7u8, 8u8, 7u8, 42u8, 16u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                58u8, 16u8, 72u8, 6u8, 85u8, 7u8, 74u8, 16u8, 74u8, 16u8, 90u8, 16u8, 72u8, 6u8,
+                86u8, 7u8, 101u8, 16u8, 72u8, 6u8, 72u8, 6u8, 74u8, 16u8, 72u8, 6u8, 96u8, 16u8,
+                117u8, 16u8, 207u8, 2u8, 207u8, 2u8, 8u8, 7u8, 42u8, 7u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 85u8, 7u8, 72u8, 6u8, 133u8, 16u8, 96u8, 16u8, 96u8, 16u8, 149u8, 16u8,
+                165u8, 16u8, 180u8, 16u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 196u8, 16u8, 212u8, 16u8, 222u8, 16u8, 234u8, 16u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 250u8, 16u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 10u8, 17u8, 199u8, 16u8, 72u8, 6u8, 72u8, 6u8,
+                26u8, 17u8, 42u8, 17u8, 205u8, 3u8, 205u8, 3u8, 32u8, 15u8, 58u8, 17u8, 74u8, 17u8,
+                67u8, 3u8, 80u8, 17u8, 129u8, 2u8, 129u8, 2u8, 96u8, 17u8, 112u8, 17u8, 124u8,
+                17u8, 139u8, 17u8, 144u8, 17u8, 155u8, 17u8, 129u8, 2u8, 135u8, 2u8, 139u8, 17u8,
+                139u8, 17u8, 171u8, 17u8, 186u8, 17u8, 195u8, 17u8, 202u8, 17u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 218u8, 17u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                234u8, 17u8, 205u8, 3u8, 250u8, 17u8, 205u8, 3u8, 205u8, 3u8, 250u8, 17u8, 10u8,
+                18u8, 205u8, 3u8, 25u8, 18u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 41u8, 18u8, 55u8,
+                18u8, 71u8, 18u8, 87u8, 18u8, 103u8, 18u8, 119u8, 18u8, 135u8, 18u8, 147u8, 18u8,
+                163u8, 18u8, 178u8, 18u8, 190u8, 18u8, 206u8, 18u8, 222u8, 18u8, 226u8, 18u8,
+                228u8, 18u8, 244u8, 18u8, 207u8, 2u8, 13u8, 0u8, 4u8, 19u8, 19u8, 19u8, 34u8, 19u8,
+                50u8, 19u8, 62u8, 19u8, 78u8, 19u8, 93u8, 19u8, 108u8, 19u8, 139u8, 17u8, 139u8,
+                17u8, 139u8, 17u8, 124u8, 19u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 140u8, 19u8, 155u8, 19u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 162u8, 19u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 149u8,
+                14u8, 14u8, 0u8, 195u8, 10u8, 14u8, 0u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 178u8, 19u8,
+                189u8, 19u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 189u8, 19u8,
+                189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8,
+                189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8,
+                189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 205u8, 19u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 218u8,
+                19u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 205u8, 19u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 230u8, 19u8, 207u8, 2u8, 136u8, 14u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 236u8, 19u8, 207u8, 2u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 252u8, 19u8, 9u8, 20u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 25u8, 20u8, 41u8, 20u8, 67u8, 3u8, 67u8, 3u8, 193u8,
+                14u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 57u8, 20u8, 73u8, 20u8, 205u8, 3u8, 179u8,
+                6u8, 137u8, 8u8, 137u8, 8u8, 137u8, 8u8, 137u8, 8u8, 67u8, 3u8, 67u8, 3u8, 89u8,
+                20u8, 103u8, 20u8, 110u8, 20u8, 123u8, 20u8, 207u8, 2u8, 133u8, 20u8, 14u8, 0u8,
+                14u8, 0u8, 207u8, 2u8, 149u8, 20u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 161u8, 20u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8,
+                17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 177u8, 20u8, 14u8, 0u8, 144u8, 14u8, 193u8, 20u8, 201u8,
+                20u8, 216u8, 20u8, 232u8, 20u8, 109u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 248u8, 20u8, 8u8, 21u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                13u8, 21u8, 30u8, 5u8, 205u8, 3u8, 133u8, 5u8, 225u8, 18u8, 139u8, 17u8, 139u8,
+                17u8, 28u8, 21u8, 139u8, 17u8, 226u8, 18u8, 44u8, 21u8, 205u8, 3u8, 205u8, 3u8,
+                207u8, 2u8, 207u8, 2u8, 10u8, 0u8, 67u8, 3u8, 139u8, 17u8, 226u8, 18u8, 139u8,
+                17u8, 139u8, 17u8, 52u8, 21u8, 139u8, 17u8, 139u8, 17u8, 68u8, 21u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 196u8, 16u8, 84u8, 21u8, 84u8, 21u8, 84u8, 21u8, 93u8, 21u8,
+                72u8, 6u8, 72u8, 6u8, 26u8, 17u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 109u8, 21u8, 125u8, 21u8, 131u8, 21u8,
+                14u8, 0u8, 67u8, 3u8, 67u8, 3u8, 147u8, 21u8, 163u8, 21u8, 67u8, 3u8, 179u8, 21u8,
+                72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 195u8, 21u8, 211u8, 21u8,
+                220u8, 21u8, 236u8, 21u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 252u8, 21u8,
+                4u8, 22u8, 20u8, 22u8, 26u8, 22u8, 42u8, 22u8, 175u8, 6u8, 58u8, 22u8, 14u8, 0u8,
+                72u8, 22u8, 72u8, 6u8, 72u8, 6u8, 88u8, 22u8, 146u8, 14u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 104u8, 22u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 120u8, 22u8, 96u8,
+                16u8, 67u8, 3u8, 136u8, 22u8, 72u8, 6u8, 72u8, 6u8, 152u8, 22u8, 8u8, 7u8, 8u8,
+                7u8, 168u8, 22u8, 67u8, 3u8, 184u8, 22u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 200u8, 22u8, 216u8, 22u8, 116u8, 4u8, 115u8, 3u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 99u8, 16u8, 86u8, 7u8, 232u8, 22u8, 205u8, 3u8, 248u8, 22u8, 72u8, 6u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 8u8, 23u8, 13u8, 23u8, 72u8, 6u8, 27u8, 23u8, 42u8,
+                23u8, 50u8, 23u8, 137u8, 8u8, 67u8, 3u8, 67u8, 3u8, 66u8, 23u8, 82u8, 23u8, 72u8,
+                6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 98u8, 23u8,
+                96u8, 16u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 182u8, 6u8,
+                67u8, 3u8, 114u8, 23u8, 67u8, 3u8, 67u8, 3u8, 125u8, 23u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 141u8, 23u8, 157u8, 23u8,
+                171u8, 23u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 227u8, 18u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 181u8, 23u8, 14u8,
+                0u8, 14u8, 0u8, 184u8, 23u8, 197u8, 23u8, 139u8, 17u8, 212u8, 23u8, 228u8, 23u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                187u8, 19u8, 244u8, 23u8, 138u8, 23u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 4u8, 24u8, 189u8, 19u8, 139u8,
+                17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 224u8, 18u8, 139u8, 17u8, 139u8, 17u8,
+                20u8, 24u8, 14u8, 0u8, 14u8, 0u8, 36u8, 24u8, 52u8, 24u8, 181u8, 23u8, 68u8, 24u8,
+                139u8, 17u8, 84u8, 24u8, 97u8, 24u8, 106u8, 24u8, 122u8, 24u8, 139u8, 17u8, 139u8,
+                17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 128u8, 24u8,
+                225u8, 18u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 143u8, 24u8, 226u8, 18u8,
+                159u8, 24u8, 167u8, 24u8, 183u8, 24u8, 0u8, 0u8, 199u8, 24u8, 8u8, 7u8, 204u8,
+                24u8, 212u8, 24u8, 40u8, 7u8, 40u8, 7u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 227u8, 24u8, 243u8, 24u8, 250u8,
+                24u8, 250u8, 24u8, 6u8, 25u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 59u8, 15u8, 22u8,
+                25u8, 143u8, 14u8, 13u8, 0u8, 14u8, 0u8, 14u8, 0u8, 38u8, 25u8, 38u8, 25u8, 41u8,
+                25u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 8u8, 7u8, 57u8, 25u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 69u8, 25u8, 85u8,
+                25u8, 89u8, 25u8, 8u8, 7u8, 8u8, 7u8, 105u8, 25u8, 8u8, 7u8, 227u8, 24u8, 8u8, 7u8,
+                8u8, 7u8, 227u8, 24u8, 8u8, 7u8, 118u8, 25u8, 8u8, 7u8, 8u8, 7u8, 134u8, 25u8,
+                150u8, 25u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 40u8, 7u8, 26u8, 16u8, 72u8, 6u8,
+                72u8, 6u8, 166u8, 25u8, 72u8, 6u8, 74u8, 16u8, 8u8, 7u8, 8u8, 7u8, 146u8, 15u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 168u8, 22u8, 200u8, 24u8, 200u8, 24u8, 220u8, 24u8,
+                182u8, 25u8, 196u8, 25u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 147u8, 15u8, 8u8, 7u8, 64u8, 25u8, 146u8, 15u8, 14u8, 0u8,
+                212u8, 25u8, 139u8, 17u8, 139u8, 17u8, 217u8, 25u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 233u8, 25u8, 8u8, 7u8, 8u8, 7u8, 243u8, 25u8, 8u8, 7u8, 2u8, 26u8,
+                8u8, 7u8, 18u8, 26u8, 8u8, 7u8, 39u8, 7u8, 34u8, 26u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 8u8, 7u8, 50u8, 26u8, 8u8, 7u8, 66u8, 26u8, 8u8, 7u8, 228u8, 24u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 82u8, 26u8, 250u8,
+                24u8, 54u8, 7u8, 250u8, 24u8, 250u8, 24u8, 96u8, 26u8, 108u8, 26u8, 8u8, 7u8,
+                118u8, 26u8, 25u8, 26u8, 25u8, 26u8, 8u8, 7u8, 133u8, 26u8, 8u8, 7u8, 133u8, 26u8,
+                14u8, 0u8, 14u8, 0u8, 149u8, 26u8, 8u8, 7u8, 158u8, 26u8, 169u8, 26u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 185u8, 26u8, 8u8, 7u8, 48u8, 7u8, 8u8, 7u8, 201u8, 26u8, 8u8,
+                7u8, 217u8, 26u8, 230u8, 26u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 145u8, 15u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 67u8, 25u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                246u8, 26u8, 72u8, 6u8, 72u8, 6u8, 98u8, 16u8, 96u8, 16u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 207u8, 2u8, 22u8, 25u8, 8u8, 7u8, 8u8, 7u8, 6u8, 27u8,
+                68u8, 25u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 133u8, 26u8,
+                22u8, 27u8, 8u8, 7u8, 8u8, 7u8, 24u8, 26u8, 14u8, 0u8, 8u8, 7u8, 38u8, 27u8, 14u8,
+                0u8, 14u8, 0u8, 8u8, 7u8, 54u8, 27u8, 14u8, 0u8, 8u8, 7u8, 147u8, 15u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 70u8, 27u8, 54u8, 7u8, 0u8, 27u8, 148u8, 15u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 86u8, 27u8, 100u8, 27u8, 8u8, 7u8, 145u8, 15u8, 26u8,
+                16u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 116u8, 27u8, 132u8, 27u8, 8u8, 7u8, 8u8,
+                7u8, 148u8, 27u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 164u8, 27u8, 178u8, 27u8,
+                82u8, 5u8, 9u8, 0u8, 8u8, 7u8, 182u8, 25u8, 8u8, 7u8, 194u8, 27u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 210u8, 27u8, 225u8, 27u8, 240u8, 27u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 227u8, 24u8, 26u8, 16u8, 0u8, 28u8, 12u8, 28u8, 202u8, 24u8, 27u8,
+                28u8, 40u8, 28u8, 53u8, 28u8, 66u8, 28u8, 65u8, 25u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8,
+                72u8, 6u8, 72u8, 6u8, 82u8, 28u8, 98u8, 28u8, 112u8, 28u8, 14u8, 0u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 128u8, 28u8, 26u8, 16u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 7u8, 28u8, 144u8, 28u8, 152u8, 28u8, 14u8, 0u8, 14u8,
+                0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 168u8, 28u8, 26u8, 16u8, 253u8, 24u8,
+                14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 240u8, 27u8, 26u8, 16u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 8u8, 7u8, 184u8, 28u8, 58u8, 25u8, 197u8, 28u8, 147u8, 15u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 213u8, 28u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 197u8, 28u8, 229u8, 28u8, 244u8, 28u8, 219u8, 24u8, 8u8, 7u8,
+                0u8, 29u8, 12u8, 29u8, 26u8, 16u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 5u8,
+                28u8, 8u8, 7u8, 8u8, 7u8, 5u8, 28u8, 28u8, 29u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 111u8, 15u8, 22u8, 27u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 44u8,
+                29u8, 58u8, 29u8, 72u8, 6u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 145u8, 15u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                202u8, 24u8, 8u8, 7u8, 8u8, 7u8, 204u8, 24u8, 74u8, 29u8, 197u8, 28u8, 253u8, 24u8,
+                90u8, 29u8, 8u8, 7u8, 116u8, 25u8, 203u8, 24u8, 147u8, 15u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 216u8, 24u8, 8u8, 7u8, 8u8, 7u8, 99u8, 29u8, 146u8, 15u8,
+                26u8, 16u8, 115u8, 29u8, 72u8, 6u8, 85u8, 7u8, 122u8, 29u8, 96u8, 16u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 138u8, 29u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 154u8, 29u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 170u8, 29u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 26u8, 16u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 39u8, 7u8,
+                186u8, 29u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 179u8, 15u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 202u8, 29u8,
+                8u8, 7u8, 8u8, 7u8, 39u8, 7u8, 25u8, 26u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 147u8, 15u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 97u8,
+                16u8, 218u8, 29u8, 219u8, 29u8, 224u8, 29u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 39u8, 7u8, 26u8, 16u8, 8u8, 7u8, 40u8, 7u8, 240u8, 29u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 18u8, 26u8, 0u8, 30u8, 16u8, 30u8, 30u8, 30u8, 38u8, 30u8, 8u8, 7u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 250u8, 24u8, 54u8, 30u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8,
+                6u8, 70u8, 30u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 85u8, 30u8, 72u8, 6u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 101u8, 30u8, 184u8, 6u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 146u8, 15u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 64u8, 25u8, 14u8, 0u8, 14u8, 0u8, 145u8, 15u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                117u8, 30u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8,
+                67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 67u8,
+                3u8, 67u8, 3u8, 67u8, 3u8, 67u8, 3u8, 108u8, 4u8, 183u8, 6u8, 14u8, 0u8, 14u8, 0u8,
+                183u8, 6u8, 132u8, 30u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 58u8, 25u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8,
+                7u8, 8u8, 7u8, 227u8, 24u8, 57u8, 25u8, 145u8, 15u8, 148u8, 30u8, 164u8, 30u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 129u8, 2u8,
+                129u8, 2u8, 180u8, 30u8, 129u8, 2u8, 28u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8,
+                19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 196u8, 30u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8,
+                19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8,
+                189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8, 212u8, 30u8, 189u8, 19u8,
+                189u8, 19u8, 228u8, 30u8, 189u8, 19u8, 189u8, 19u8, 237u8, 30u8, 251u8, 30u8, 8u8,
+                31u8, 21u8, 31u8, 189u8, 19u8, 33u8, 31u8, 240u8, 30u8, 188u8, 19u8, 47u8, 31u8,
+                61u8, 31u8, 14u8, 0u8, 38u8, 25u8, 38u8, 25u8, 38u8, 25u8, 38u8, 25u8, 77u8, 31u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 207u8, 2u8, 10u8, 0u8, 189u8, 19u8, 189u8, 19u8, 189u8, 19u8,
+                189u8, 19u8, 189u8, 19u8, 93u8, 31u8, 207u8, 2u8, 147u8, 14u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 95u8, 24u8, 139u8, 17u8, 139u8,
+                17u8, 139u8, 17u8, 109u8, 31u8, 125u8, 31u8, 139u8, 31u8, 96u8, 24u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 152u8, 31u8, 165u8, 31u8, 139u8, 17u8, 103u8, 24u8,
+                179u8, 31u8, 194u8, 31u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                204u8, 31u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                147u8, 24u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 250u8, 24u8, 250u8, 24u8,
+                250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8,
+                250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8,
+                250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8,
+                250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8,
+                250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 250u8, 24u8, 8u8,
+                7u8, 8u8, 7u8, 8u8, 7u8, 220u8, 31u8, 8u8, 7u8, 8u8, 7u8, 133u8, 26u8, 236u8, 31u8,
+                248u8, 31u8, 184u8, 15u8, 7u8, 32u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 205u8, 3u8, 115u8, 3u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 204u8, 24u8, 186u8, 28u8, 21u8, 32u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8, 196u8,
+                16u8, 86u8, 7u8, 37u8, 32u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 8u8, 7u8, 39u8,
+                7u8, 14u8, 0u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 53u8, 32u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 69u8, 32u8, 115u8, 3u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8,
+                8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 8u8, 7u8, 49u8, 7u8, 147u8, 15u8, 14u8, 0u8, 14u8,
+                0u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 72u8, 6u8, 74u8, 16u8, 216u8, 22u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 82u8, 5u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 9u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 82u8, 5u8, 207u8, 2u8, 207u8, 2u8,
+                133u8, 20u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 96u8, 24u8,
+                139u8, 17u8, 84u8, 32u8, 97u8, 32u8, 111u8, 32u8, 127u8, 32u8, 141u8, 32u8, 149u8,
+                32u8, 165u8, 32u8, 176u8, 32u8, 191u8, 32u8, 176u8, 32u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 12u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 207u8, 2u8, 207u8, 2u8, 144u8, 14u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 10u8, 0u8, 22u8, 25u8,
+                82u8, 5u8, 82u8, 5u8, 82u8, 5u8, 207u8, 2u8, 150u8, 14u8, 49u8, 21u8, 139u8, 17u8,
+                202u8, 32u8, 139u8, 17u8, 139u8, 17u8, 207u8, 2u8, 217u8, 32u8, 207u8, 2u8, 207u8,
+                2u8, 155u8, 19u8, 133u8, 20u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 77u8, 5u8, 207u8,
+                2u8, 233u8, 32u8, 139u8, 17u8, 139u8, 17u8, 176u8, 32u8, 182u8, 23u8, 234u8, 32u8,
+                150u8, 14u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 250u8, 32u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 10u8, 33u8, 143u8, 14u8, 143u8, 14u8, 207u8, 2u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 10u8, 0u8,
+                207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 147u8, 14u8, 144u8,
+                14u8, 13u8, 0u8, 144u8, 14u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 148u8, 14u8,
+                146u8, 14u8, 207u8, 2u8, 207u8, 2u8, 148u8, 14u8, 207u8, 2u8, 133u8, 20u8, 12u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 10u8,
+                0u8, 133u8, 20u8, 23u8, 33u8, 149u8, 14u8, 207u8, 2u8, 143u8, 14u8, 195u8, 10u8,
+                150u8, 14u8, 146u8, 14u8, 148u8, 14u8, 149u8, 14u8, 207u8, 2u8, 207u8, 2u8, 207u8,
+                2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 207u8, 2u8, 139u8,
+                14u8, 207u8, 2u8, 207u8, 2u8, 195u8, 10u8, 14u8, 0u8, 14u8, 0u8, 181u8, 23u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                14u8, 0u8, 14u8, 0u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                177u8, 6u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 165u8, 4u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 184u8, 6u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8,
+                205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 171u8, 9u8, 14u8, 0u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8, 139u8, 17u8,
+                139u8, 17u8, 139u8, 17u8, 227u8, 18u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8, 3u8, 205u8,
+                3u8, 205u8, 3u8, 205u8, 3u8, 175u8, 6u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8,
+                38u8, 33u8, 14u8, 0u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8,
+                52u8, 24u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8, 0u8, 14u8,
+                0u8, 14u8, 0u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8,
+                24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8, 24u8, 52u8,
+                24u8, 52u8, 24u8, 52u8, 24u8, 14u8, 0u8, 121u8, 0u8, 153u8, 0u8, 185u8, 0u8, 217u8,
+                0u8, 249u8, 0u8, 25u8, 1u8, 57u8, 1u8, 89u8, 1u8, 121u8, 1u8, 153u8, 1u8, 185u8,
+                1u8, 211u8, 1u8, 243u8, 1u8, 19u8, 2u8, 51u8, 2u8, 83u8, 2u8, 115u8, 2u8, 145u8,
+                2u8, 175u8, 2u8, 191u8, 2u8, 223u8, 2u8, 255u8, 2u8, 31u8, 3u8, 63u8, 3u8, 95u8,
+                3u8, 127u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 163u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 195u8, 3u8, 195u8,
+                3u8, 219u8, 3u8, 251u8, 3u8, 27u8, 4u8, 59u8, 4u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 91u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8,
+                4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8,
+                4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 139u8, 4u8, 171u8, 4u8, 201u8,
+                4u8, 233u8, 4u8, 9u8, 5u8, 41u8, 5u8, 73u8, 5u8, 105u8, 5u8, 137u8, 5u8, 169u8,
+                5u8, 201u8, 5u8, 227u8, 5u8, 3u8, 6u8, 35u8, 6u8, 67u8, 6u8, 99u8, 6u8, 131u8, 6u8,
+                163u8, 6u8, 195u8, 6u8, 222u8, 6u8, 254u8, 6u8, 5u8, 7u8, 37u8, 7u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 58u8, 7u8, 254u8, 6u8, 254u8, 6u8, 90u8, 7u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 254u8, 6u8, 122u8, 7u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 195u8, 3u8, 154u8, 7u8, 123u8, 4u8, 182u8, 7u8, 254u8, 6u8,
+                254u8, 6u8, 254u8, 6u8, 254u8, 6u8, 254u8, 6u8, 254u8, 6u8, 254u8, 6u8, 254u8, 6u8,
+                214u8, 7u8, 254u8, 6u8, 254u8, 6u8, 246u8, 7u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 7u8, 8u8,
+                39u8, 8u8, 62u8, 8u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 94u8, 8u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                110u8, 8u8, 142u8, 8u8, 174u8, 8u8, 206u8, 8u8, 228u8, 8u8, 4u8, 9u8, 36u8, 9u8,
+                123u8, 4u8, 52u8, 9u8, 84u8, 9u8, 107u8, 9u8, 123u8, 4u8, 123u8, 9u8, 155u8, 9u8,
+                123u8, 4u8, 180u8, 9u8, 212u8, 9u8, 244u8, 9u8, 20u8, 10u8, 36u8, 10u8, 57u8, 10u8,
+                89u8, 10u8, 116u8, 10u8, 123u8, 4u8, 123u8, 4u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 148u8, 10u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8,
+                159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 164u8, 10u8, 195u8, 10u8, 159u8, 3u8, 159u8,
+                3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 217u8, 10u8,
+                159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8,
+                159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 228u8,
+                10u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 4u8,
+                11u8, 35u8, 11u8, 123u8, 4u8, 123u8, 4u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8,
+                159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 159u8, 3u8, 67u8, 11u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8,
+                123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 123u8, 4u8, 99u8, 11u8,
+            ])
+        },
+        unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                0u8, 4u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 4u8, 0u8,
+                4u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 4u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 0u8, 8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 4u8, 0u8, 16u8,
+                0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8,
+                0u8, 0u8, 4u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 2u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8,
+                0u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 192u8, 0u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 192u8, 0u8, 192u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 64u8, 0u8, 192u8, 0u8, 192u8, 0u8, 0u8, 1u8, 0u8, 1u8, 192u8, 0u8,
+                64u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 64u8,
+                0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 1u8, 128u8, 1u8, 128u8, 0u8,
+                128u8, 1u8, 128u8, 1u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8, 128u8, 1u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 1u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 1u8, 128u8, 1u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 1u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 192u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 192u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 1u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 1u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 192u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 0u8, 8u8, 16u8, 0u8, 128u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 128u8, 0u8, 0u8, 1u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 128u8, 0u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8,
+                128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 4u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 1u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 8u8, 0u8, 0u8, 4u8, 64u8, 0u8, 64u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 64u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 128u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 192u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 64u8, 0u8, 64u8, 0u8, 8u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 80u8, 0u8, 64u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 80u8,
+                0u8, 80u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 128u8, 0u8, 16u8, 0u8, 0u8, 4u8, 1u8, 0u8, 1u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 64u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 16u8, 0u8, 0u8, 1u8, 0u8, 1u8, 16u8, 0u8, 0u8, 1u8, 64u8, 1u8, 80u8, 0u8,
+                128u8, 1u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 4u8, 0u8, 4u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 16u8, 0u8, 4u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 2u8, 0u8, 0u8, 8u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 16u8, 0u8, 16u8, 0u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8,
+                16u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 4u8, 0u8, 4u8, 0u8, 8u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 1u8, 0u8, 16u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8,
+                128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 64u8, 2u8, 64u8, 2u8, 64u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 64u8, 2u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 1u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 16u8, 0u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8,
+                64u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 64u8, 0u8,
+                16u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 8u8, 0u8, 1u8, 0u8, 0u8, 8u8,
+                8u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 16u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8,
+                0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 0u8, 8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 64u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 64u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 192u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 128u8, 1u8, 128u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 128u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 128u8, 1u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 128u8,
+                1u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8,
+                8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 4u8, 8u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 128u8, 0u8, 128u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 16u8, 0u8, 0u8, 8u8, 16u8, 0u8, 0u8,
+                1u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 8u8,
+                0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 2u8, 0u8, 8u8, 0u8, 2u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8,
+                8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 4u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 2u8, 0u8, 2u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 4u8, 0u8, 4u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 192u8, 0u8, 0u8, 8u8, 64u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 80u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                0u8, 8u8, 192u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 1u8,
+                32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8,
+                16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 16u8, 0u8, 128u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 128u8,
+                2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 128u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 80u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 8u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 192u8, 0u8, 128u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8,
+                0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8,
+                0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 2u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 8u8, 128u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8,
+                0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 4u8, 0u8, 0u8, 4u8, 0u8, 4u8, 4u8, 0u8, 4u8, 0u8, 0u8, 4u8, 8u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 16u8, 0u8, 0u8,
+                4u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 0u8, 4u8, 16u8, 0u8, 16u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8,
+                0u8, 16u8, 0u8, 8u8, 0u8, 128u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 1u8, 16u8, 0u8, 80u8, 0u8, 8u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 8u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 1u8, 0u8, 4u8, 0u8, 4u8,
+                0u8, 4u8, 0u8, 4u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 16u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 80u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 64u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 64u8, 0u8, 80u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 8u8, 0u8, 16u8, 1u8,
+                16u8, 1u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 16u8, 1u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 8u8, 0u8, 8u8, 0u8, 64u8, 0u8, 64u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 160u8, 0u8,
+                160u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 16u8,
+                2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 208u8,
+                0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8,
+                0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 208u8, 0u8, 80u8,
+                0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 80u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 80u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 80u8, 0u8, 16u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 192u8, 0u8, 192u8, 0u8,
+                16u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 128u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 8u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 4u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 4u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 144u8, 0u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                64u8, 2u8, 64u8, 2u8, 64u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                64u8, 2u8, 64u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 1u8, 80u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 1u8, 0u8, 1u8, 16u8, 0u8, 0u8, 8u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 8u8, 0u8, 8u8, 0u8, 64u8, 1u8,
+                64u8, 0u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 192u8, 0u8,
+                192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8,
+                192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 8u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 128u8, 0u8, 0u8, 1u8,
+                64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 0u8, 8u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 128u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 128u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8, 2u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 64u8, 0u8, 80u8, 1u8, 64u8, 1u8, 64u8, 0u8, 64u8,
+                0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 0u8, 16u8,
+                2u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8,
+                2u8, 1u8, 0u8, 32u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 16u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 64u8, 0u8, 64u8, 0u8, 0u8, 8u8, 0u8, 8u8, 128u8, 0u8, 8u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8,
+                64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 0u8,
+                8u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 0u8, 8u8, 8u8, 0u8, 0u8, 8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 128u8,
+                1u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                144u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 144u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8,
+                128u8, 0u8, 128u8, 1u8, 128u8, 1u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 128u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 4u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 1u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8,
+                0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 64u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 64u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8,
+                80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8,
+                0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 80u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 1u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 16u8, 2u8,
+                16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 32u8, 0u8, 0u8, 8u8, 32u8, 0u8, 0u8, 8u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 16u8, 2u8, 16u8, 2u8, 1u8, 0u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 1u8,
+                0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 1u8, 32u8, 1u8,
+                32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8,
+                1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 32u8, 1u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                1u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 1u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8,
+                2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 32u8, 0u8, 32u8, 0u8, 16u8,
+                0u8, 64u8, 0u8, 32u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 1u8, 0u8, 0u8, 1u8, 0u8, 1u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 1u8, 0u8, 1u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 1u8, 0u8, 1u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8,
+                4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 4u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 128u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8,
+                0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 1u8, 144u8, 1u8, 144u8,
+                1u8, 144u8, 1u8, 144u8, 1u8, 144u8, 1u8, 144u8, 1u8, 144u8, 1u8, 144u8, 1u8, 144u8,
+                0u8, 144u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 80u8, 0u8, 80u8,
+                0u8, 192u8, 0u8, 192u8, 0u8, 192u8, 0u8, 80u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 144u8, 0u8,
+                144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 144u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8,
+                48u8, 0u8, 32u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8, 0u8, 48u8,
+                0u8, 48u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8,
+                32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8, 32u8, 0u8, 32u8,
+                0u8, 1u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 32u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 0u8, 2u8, 16u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8,
+                0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 0u8, 2u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 16u8, 2u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8,
+                1u8, 0u8, 0u8, 8u8, 0u8, 8u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                1u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 8u8, 0u8, 8u8, 0u8, 8u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8,
+                16u8, 0u8, 16u8, 0u8, 16u8, 1u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8, 0u8,
+                1u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 16u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 2u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8, 0u8, 1u8,
+                0u8,
+            ])
+        },
+        1u16,
+    ),
+};
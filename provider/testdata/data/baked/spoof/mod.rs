@@ -0,0 +1,4 @@
+// @generated
+pub mod confusables_v1;
+pub mod idstatus_v1;
+pub mod idtype_v1;
@@ -39,6 +39,10 @@ fn main() {
         .with_cldr(paths::cldr_json_root(), CldrLocaleSubset::Full)
         .unwrap()
         .with_icuexport(paths::icuexport_toml_root())
+        .unwrap()
+        .with_hyphenation(paths::hyphenation_root())
+        .unwrap()
+        .with_ucd(paths::ucd_root())
         .unwrap();
     let locales = metadata::load().unwrap().package_metadata.locales;
 
@@ -72,6 +76,7 @@ fn main() {
         &icu_datagen::all_keys()
             .into_iter()
             .filter(|k| !IGNORED_KEYS.contains(&&*k.path()))
+            .chain(icu_datagen::keys(&["segmenter/hyphenation@1"]))
             .chain(core::iter::once(
                 icu_provider::hello_world::HelloWorldV1Marker::KEY,
             ))
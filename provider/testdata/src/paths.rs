@@ -21,6 +21,16 @@ pub fn icuexport_toml_root() -> PathBuf {
     data_root().join("icuexport")
 }
 
+/// Returns the absolute path to the hyphenation pattern root directory.
+pub fn hyphenation_root() -> PathBuf {
+    data_root().join("hyphenation")
+}
+
+/// Returns the absolute path to the Unicode Character Database root directory.
+pub fn ucd_root() -> PathBuf {
+    data_root().join("ucd")
+}
+
 /// Returns the absolute path to the collation tailoring TOML root directory.
 pub fn coll_toml_root() -> PathBuf {
     data_root().join("coll")
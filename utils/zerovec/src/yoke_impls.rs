@@ -10,6 +10,7 @@ use crate::map::ZeroMapBorrowed;
 use crate::map::ZeroMapKV;
 use crate::map2d::ZeroMap2dBorrowed;
 use crate::ule::*;
+use crate::vecs::VarZeroVecFormat;
 use crate::{VarZeroVec, ZeroMap, ZeroMap2d, ZeroVec};
 use core::{mem, ptr};
 use yoke::*;
@@ -44,8 +45,10 @@ unsafe impl<'a, T: 'static + AsULE + ?Sized> Yokeable<'a> for ZeroVec<'static, T
 
 // This impl is similar to the impl on Cow and is safe for the same reasons
 /// This impl can be made available by enabling the optional `yoke` feature of the `zerovec` crate
-unsafe impl<'a, T: 'static + VarULE + ?Sized> Yokeable<'a> for VarZeroVec<'static, T> {
-    type Output = VarZeroVec<'a, T>;
+unsafe impl<'a, T: 'static + VarULE + ?Sized, F: 'static + VarZeroVecFormat> Yokeable<'a>
+    for VarZeroVec<'static, T, F>
+{
+    type Output = VarZeroVec<'a, T, F>;
     #[inline]
     fn transform(&'a self) -> &'a Self::Output {
         self
@@ -62,9 +65,9 @@ unsafe impl<'a, T: 'static + VarULE + ?Sized> Yokeable<'a> for VarZeroVec<'stati
         ptr::read(ptr)
     }
     #[inline]
-    fn transform_mut<F>(&'a mut self, f: F)
+    fn transform_mut<M>(&'a mut self, f: M)
     where
-        F: 'static + for<'b> FnOnce(&'b mut Self::Output),
+        M: 'static + for<'b> FnOnce(&'b mut Self::Output),
     {
         unsafe { f(mem::transmute::<&mut Self, &mut Self::Output>(self)) }
     }
@@ -4,7 +4,7 @@
 
 use crate::map::ZeroMapKV;
 use crate::ule::*;
-use crate::vecs::{FlexZeroSlice, FlexZeroVec};
+use crate::vecs::{FlexZeroSlice, FlexZeroVec, VarZeroVecFormat};
 use crate::{VarZeroSlice, VarZeroVec, ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 use zerofrom::ZeroFrom;
 
@@ -59,32 +59,35 @@ impl<'zf> ZeroFrom<'zf, FlexZeroSlice> for &'zf FlexZeroSlice {
     }
 }
 
-impl<'zf, T> ZeroFrom<'zf, VarZeroSlice<T>> for VarZeroVec<'zf, T>
+impl<'zf, T, F> ZeroFrom<'zf, VarZeroSlice<T, F>> for VarZeroVec<'zf, T, F>
 where
     T: 'static + VarULE + ?Sized,
+    F: VarZeroVecFormat,
 {
     #[inline]
-    fn zero_from(other: &'zf VarZeroSlice<T>) -> Self {
+    fn zero_from(other: &'zf VarZeroSlice<T, F>) -> Self {
         other.into()
     }
 }
 
-impl<'zf, T> ZeroFrom<'zf, VarZeroVec<'_, T>> for VarZeroVec<'zf, T>
+impl<'zf, T, F> ZeroFrom<'zf, VarZeroVec<'_, T, F>> for VarZeroVec<'zf, T, F>
 where
     T: 'static + VarULE + ?Sized,
+    F: VarZeroVecFormat,
 {
     #[inline]
-    fn zero_from(other: &'zf VarZeroVec<'_, T>) -> Self {
+    fn zero_from(other: &'zf VarZeroVec<'_, T, F>) -> Self {
         other.as_slice().into()
     }
 }
 
-impl<'zf, T> ZeroFrom<'zf, VarZeroSlice<T>> for &'zf VarZeroSlice<T>
+impl<'zf, T, F> ZeroFrom<'zf, VarZeroSlice<T, F>> for &'zf VarZeroSlice<T, F>
 where
     T: 'static + VarULE + ?Sized,
+    F: VarZeroVecFormat,
 {
     #[inline]
-    fn zero_from(other: &'zf VarZeroSlice<T>) -> Self {
+    fn zero_from(other: &'zf VarZeroSlice<T, F>) -> Self {
         other
     }
 }